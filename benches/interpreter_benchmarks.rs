@@ -340,6 +340,28 @@ fn benchmark_stress_test(c: &mut Criterion) {
     });
 }
 
+fn benchmark_numeric_loops(c: &mut Criterion) {
+    let mut repl = Repl::new(PathBuf::from(".")).expect("Failed to create REPL");
+
+    c.bench_function("eval_int_accumulate_loop", |b| {
+        b.iter(|| {
+            repl.eval(black_box(
+                "let mut i = 0; let mut acc = 0; while i < 10000 { acc = acc + i * 3 % 7; i += 1 }; acc",
+            ))
+            .unwrap()
+        });
+    });
+
+    c.bench_function("eval_float_accumulate_loop", |b| {
+        b.iter(|| {
+            repl.eval(black_box(
+                "let mut x = 0.0; let mut i = 0; while i < 10000 { x = x * 0.5 + 1.25; i += 1 }; x",
+            ))
+            .unwrap()
+        });
+    });
+}
+
 criterion_group!(
     benches,
     benchmark_arithmetic,
@@ -350,7 +372,8 @@ criterion_group!(
     benchmark_string_operations,
     benchmark_builtin_functions,
     benchmark_real_world,
-    benchmark_stress_test
+    benchmark_stress_test,
+    benchmark_numeric_loops
 );
 
 criterion_main!(benches);
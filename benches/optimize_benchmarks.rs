@@ -0,0 +1,49 @@
+//! Constant folding benchmarks
+//!
+//! Compares interpreting a program with and without the
+//! `middleend::optimize` pass, and measures the pass itself.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use ruchy::middleend::optimize;
+use ruchy::runtime::script::{run_script, ScriptOptions};
+use ruchy::Parser;
+use std::hint::black_box;
+
+/// A loop whose body is mostly constant arithmetic and a dead branch
+const PROGRAM: &str = r#"
+fun main() {
+    let mut total = 0
+    for i in 0..2000 {
+        if 1 > 2 {
+            println("unreachable")
+        }
+        total = total + i * (60 * 60 * 24) % (max(7, 11) + abs(-5))
+    }
+    total
+}
+"#;
+
+fn benchmark_interpretation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("optimize");
+    let plain = ScriptOptions::default();
+    let optimized = ScriptOptions::default().with_optimization(true);
+
+    group.bench_function("interpret", |b| {
+        b.iter(|| run_script(black_box(PROGRAM), &plain).unwrap());
+    });
+    group.bench_function("interpret_optimized", |b| {
+        b.iter(|| run_script(black_box(PROGRAM), &optimized).unwrap());
+    });
+    group.finish();
+}
+
+fn benchmark_pass(c: &mut Criterion) {
+    let ast = Parser::new(PROGRAM).parse().expect("Failed to parse");
+
+    c.bench_function("optimize_pass", |b| {
+        b.iter(|| optimize(black_box(ast.clone())));
+    });
+}
+
+criterion_group!(benches, benchmark_interpretation, benchmark_pass);
+criterion_main!(benches);
@@ -2,7 +2,7 @@
 //! Focus on interpreter speed, memory usage, compilation time
 
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
-use ruchy::backend::transpiler::Transpiler;
+use ruchy::backend::transpiler::{OutputStyle, Transpiler, TranspilerOptions};
 use ruchy::compile;
 use ruchy::frontend::parser::Parser;
 use std::hint::black_box;
@@ -74,10 +74,13 @@ fn bench_transpiler(c: &mut Criterion) {
         group.bench_with_input(BenchmarkId::new("transpile", name), &input, |b, &input| {
             let mut parser = Parser::new(input);
             let ast = parser.parse().unwrap();
-            let mut transpiler = Transpiler::new();
+            let options = TranspilerOptions::builder()
+                .style(OutputStyle::Compact)
+                .build();
+            let mut transpiler = Transpiler::with_options(options);
 
             b.iter(|| {
-                let _ = black_box(transpiler.transpile_to_string(&ast));
+                let _ = black_box(transpiler.transpile_program(&ast));
             });
         });
     }
@@ -3,15 +3,18 @@
 //! Measures performance of transpiling Ruchy code to Rust.
 
 use criterion::{criterion_group, criterion_main, Criterion};
-use ruchy::backend::transpiler::Transpiler;
+use ruchy::backend::transpiler::{OutputStyle, Transpiler, TranspilerOptions};
 use ruchy::frontend::parser::Parser;
 use std::hint::black_box;
 
 fn parse_and_transpile(code: &str) -> String {
     let mut parser = Parser::new(code);
     let ast = parser.parse().unwrap();
-    let mut transpiler = Transpiler::new();
-    transpiler.transpile_to_string(&ast).unwrap()
+    let options = TranspilerOptions::builder()
+        .style(OutputStyle::Compact)
+        .build();
+    let mut transpiler = Transpiler::with_options(options);
+    transpiler.transpile_program(&ast).unwrap().code
 }
 
 fn benchmark_simple_transpilation(c: &mut Criterion) {
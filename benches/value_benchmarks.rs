@@ -0,0 +1,64 @@
+//! Runtime string value benchmarks
+//!
+//! Compares allocating a fresh `Arc<str>` per string value with sharing it
+//! from `runtime::intern`, and measures a string-heavy interpreter loop.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use ruchy::runtime::script::{run_script, ScriptOptions};
+use ruchy::runtime::Value;
+use std::hint::black_box;
+
+/// Repeated string literals and per-character values in a hot loop
+const PROGRAM: &str = r#"
+fun main() {
+    let mut count = 0
+    for i in 0..500 {
+        let word = "interned strings"
+        for c in word.chars() {
+            if c == "e" {
+                count = count + 1
+            }
+        }
+    }
+    count
+}
+"#;
+
+fn benchmark_construction(c: &mut Criterion) {
+    let mut group = c.benchmark_group("string_values");
+    let text = "interned strings";
+
+    group.bench_function("allocate_literal", |b| {
+        b.iter(|| Value::from_string(black_box(text).to_string()));
+    });
+    group.bench_function("intern_literal", |b| {
+        b.iter(|| Value::from_interned(black_box(text)));
+    });
+    group.bench_function("allocate_chars", |b| {
+        b.iter(|| {
+            black_box(text)
+                .chars()
+                .map(|c| Value::from_string(c.to_string()))
+                .collect::<Vec<_>>()
+        });
+    });
+    group.bench_function("intern_chars", |b| {
+        b.iter(|| {
+            black_box(text)
+                .chars()
+                .map(Value::from_char)
+                .collect::<Vec<_>>()
+        });
+    });
+    group.finish();
+}
+
+fn benchmark_interpretation(c: &mut Criterion) {
+    let options = ScriptOptions::default();
+    c.bench_function("string_loop/interpret", |b| {
+        b.iter(|| run_script(black_box(PROGRAM), &options).unwrap());
+    });
+}
+
+criterion_group!(benches, benchmark_construction, benchmark_interpretation);
+criterion_main!(benches);
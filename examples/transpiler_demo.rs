@@ -34,10 +34,10 @@ fn main() {
 
         let mut parser = Parser::new(input);
         match parser.parse() {
-            Ok(ast) => match transpiler.transpile_to_string(&ast) {
-                Ok(rust_code) => {
+            Ok(ast) => match transpiler.transpile_program(&ast) {
+                Ok(output) => {
                     println!("\nTranspiled Rust code:");
-                    println!("{rust_code}");
+                    println!("{}", output.code);
                 }
                 Err(e) => {
                     println!("✗ Transpilation error: {e}");
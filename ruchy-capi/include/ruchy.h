@@ -0,0 +1,69 @@
+/*
+ * C API of the Ruchy compiler and interpreter (ruchy-capi)
+ *
+ * Strings passed in are NUL-terminated UTF-8 and stay owned by the caller.
+ * Every function returning `char *` answers with a JSON object:
+ *
+ *   {"ok": true, ...}
+ *   {"ok": false, "error": {"kind": "...", "message": "..."}}
+ *
+ * Error kinds are "argument", "syntax" (with byte offsets "start" and
+ * "end"), "transpile", "runtime", "value", "undefined", "serialize" and
+ * "panic". Release every answer with ruchy_string_free().
+ *
+ * Values are exchanged as JSON. Answers describing a value have "value"
+ * (null when it has no JSON form), "display" (its printed form) and "type".
+ *
+ * A RuchyContext may be used by one thread at a time.
+ */
+#ifndef RUCHY_H
+#define RUCHY_H
+
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Changes whenever a signature or JSON shape changes incompatibly */
+#define RUCHY_ABI_VERSION 1
+
+/* Interpreter session whose bindings persist between calls */
+typedef struct RuchyContext RuchyContext;
+
+/* RUCHY_ABI_VERSION of the loaded library */
+uint32_t ruchy_abi_version(void);
+
+/* Compiler version, e.g. "5.0.0"; static, do not free */
+const char *ruchy_version(void);
+
+/* {"ok": true, "ast": {...}} */
+char *ruchy_parse(const char *source);
+
+/* {"ok": true, "rust": "..."} */
+char *ruchy_transpile(const char *source);
+
+/* New session; release with ruchy_context_free() */
+RuchyContext *ruchy_context_new(void);
+
+/* Release a session; NULL is ignored */
+void ruchy_context_free(RuchyContext *context);
+
+/* {"ok": true, "value": ..., "display": "...", "type": "...",
+ *  "output": "<printed text>"}; failures carry "output" too */
+char *ruchy_eval(RuchyContext *context, const char *source);
+
+/* Bind global `name` to the JSON text `value`: {"ok": true} */
+char *ruchy_set(RuchyContext *context, const char *name, const char *value);
+
+/* {"ok": true, "value": ..., "display": "...", "type": "..."} */
+char *ruchy_get(RuchyContext *context, const char *name);
+
+/* Release an answer of this library; NULL is ignored */
+void ruchy_string_free(char *string);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* RUCHY_H */
@@ -0,0 +1,337 @@
+//! # ruchy-capi: C API for the Ruchy compiler
+//!
+//! Parse, transpile and evaluate Ruchy from any language that can call C,
+//! without spawning the `ruchy` binary. The crate builds a shared library
+//! (`libruchy_capi.so`, `.dylib`, `ruchy_capi.dll`) and a static library;
+//! `include/ruchy.h` declares the functions below.
+//!
+//! ```c
+//! #include "ruchy.h"
+//!
+//! RuchyContext *ctx = ruchy_context_new();
+//! char *set = ruchy_set(ctx, "prices", "[3, 4, 5]");
+//! char *res = ruchy_eval(ctx, "println(\"summing\")\nprices.sum()");
+//! // res: {"ok": true, "value": 12, "display": "12", "type": "integer",
+//! //       "output": "summing\n"}, keys in any order
+//! ruchy_string_free(set);
+//! ruchy_string_free(res);
+//! ruchy_context_free(ctx);
+//! ```
+//!
+//! ## Conventions
+//!
+//! - Strings passed in are NUL-terminated UTF-8 and stay owned by the
+//!   caller.
+//! - Every call except the constructors and destructors answers with a JSON
+//!   object that the caller releases with [`ruchy_string_free`]. It is
+//!   `{"ok": true, ...}` on success and
+//!   `{"ok": false, "error": {"kind": ..., "message": ...}}` on failure.
+//!   Syntax errors also carry the byte range `start`..`end` of the problem.
+//! - Values cross the boundary as JSON: `null`, booleans, numbers, strings,
+//!   arrays and objects map to `nil`, booleans, integers or floats,
+//!   strings, arrays and objects. A result with no JSON form (a function, a
+//!   tuple) has `"value": null`; `display` always holds its printed form.
+//! - A context keeps its bindings between calls and may be used by one
+//!   thread at a time. Contexts on different threads are independent, and
+//!   what a call prints is returned in its `output` field rather than
+//!   written to stdout.
+//! - Panics never unwind into the host; they fail with kind `"panic"`.
+//!   That needs a library built with unwinding, e.g.
+//!   `cargo build -p ruchy-capi --profile release-capi`: the plain
+//!   `release` profile sets `panic = "abort"`, and a panic there aborts the
+//!   host process.
+//!
+//! [`RUCHY_ABI_VERSION`] changes whenever a function's signature or the
+//! shape of its JSON changes incompatibly.
+#![allow(unsafe_code)] // Every export takes or returns raw pointers
+
+use ruchy::backend::transpiler::Transpiler;
+use ruchy::frontend::ast::Expr;
+use ruchy::frontend::parser::Parser;
+use ruchy::runtime::eval_json::{serde_to_value, value_to_serde};
+use ruchy::runtime::output;
+use ruchy::runtime::{Interpreter, InterpreterError, Value};
+use serde_json::{json, Value as Json};
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+
+/// Version of the C interface, as returned by [`ruchy_abi_version`]
+pub const RUCHY_ABI_VERSION: u32 = 1;
+
+/// NUL-terminated version of the compiler
+const VERSION: &str = concat!(env!("CARGO_PKG_VERSION"), "\0");
+
+/// Interpreter session whose bindings persist between calls
+pub struct RuchyContext {
+    interpreter: Interpreter,
+}
+
+/// Version of the C interface the library implements
+#[no_mangle]
+pub extern "C" fn ruchy_abi_version() -> u32 {
+    RUCHY_ABI_VERSION
+}
+
+/// Version of the compiler, e.g. `"5.0.0"`; the string is static
+#[no_mangle]
+pub extern "C" fn ruchy_version() -> *const c_char {
+    VERSION.as_ptr().cast()
+}
+
+/// Parse `source`, answering `{"ok": true, "ast": {...}}`
+///
+/// # Safety
+///
+/// `source` must be null or a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn ruchy_parse(source: *const c_char) -> *mut c_char {
+    respond(|| {
+        // SAFETY: guaranteed by the caller
+        let source = unsafe { read_str(source, "source") }?;
+        let ast = parse(source)?;
+        let ast = serde_json::to_value(&ast).map_err(|e| failure("serialize", e.to_string()))?;
+        Ok(json!({ "ast": ast }))
+    })
+}
+
+/// Transpile the program `source` to Rust, answering
+/// `{"ok": true, "rust": "..."}`
+///
+/// # Safety
+///
+/// `source` must be null or a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn ruchy_transpile(source: *const c_char) -> *mut c_char {
+    respond(|| {
+        // SAFETY: guaranteed by the caller
+        let source = unsafe { read_str(source, "source") }?;
+        let ast = parse(source)?;
+        let rust = Transpiler::new()
+            .transpile_to_program(&ast)
+            .map_err(|e| failure("transpile", format!("{e:#}")))?;
+        Ok(json!({ "rust": rust.to_string() }))
+    })
+}
+
+/// Create an interpreter session; release it with [`ruchy_context_free`]
+#[no_mangle]
+pub extern "C" fn ruchy_context_new() -> *mut RuchyContext {
+    Box::into_raw(Box::new(RuchyContext {
+        interpreter: Interpreter::new(),
+    }))
+}
+
+/// Release a session; null is ignored
+///
+/// # Safety
+///
+/// `context` must be null or come from [`ruchy_context_new`], and must not
+/// be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn ruchy_context_free(context: *mut RuchyContext) {
+    if !context.is_null() {
+        // SAFETY: the pointer came from `Box::into_raw` and is freed once
+        drop(unsafe { Box::from_raw(context) });
+    }
+}
+
+/// Evaluate `source` in `context`, answering with the final value and what
+/// the code printed. Top-level definitions become globals of the context:
+/// `{"ok": true, "value": ..., "display": "...", "type": "...", "output": "..."}`
+///
+/// Failures carry `output` too.
+///
+/// # Safety
+///
+/// `context` must be null or a live context not in use by another thread,
+/// and `source` null or a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn ruchy_eval(
+    context: *mut RuchyContext,
+    source: *const c_char,
+) -> *mut c_char {
+    respond(|| {
+        // SAFETY: guaranteed by the caller
+        let context = unsafe { context_mut(context) }?;
+        // SAFETY: guaranteed by the caller
+        let source = unsafe { read_str(source, "source") }?;
+        let ast = parse(source)?;
+        let (result, printed) =
+            output::capture(|| match context.interpreter.eval_top_level(&ast) {
+                Ok(value) | Err(InterpreterError::Return(value)) => Ok(describe(&value)),
+                Err(e) => Err(failure("runtime", e.to_string())),
+            });
+        let mut response = result.map_or_else(|e| e, success);
+        response["output"] = Json::String(printed);
+        Ok(response)
+    })
+}
+
+/// Bind the global `name` in `context` to the JSON value `value`,
+/// answering `{"ok": true}`
+///
+/// # Safety
+///
+/// `context` must be null or a live context not in use by another thread,
+/// and `name` and `value` null or NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn ruchy_set(
+    context: *mut RuchyContext,
+    name: *const c_char,
+    value: *const c_char,
+) -> *mut c_char {
+    respond(|| {
+        // SAFETY: guaranteed by the caller
+        let context = unsafe { context_mut(context) }?;
+        // SAFETY: guaranteed by the caller
+        let name = unsafe { read_str(name, "name") }?;
+        // SAFETY: guaranteed by the caller
+        let value = unsafe { read_str(value, "value") }?;
+        let json: Json = serde_json::from_str(value)
+            .map_err(|e| failure("value", format!("Invalid JSON value: {e}")))?;
+        let value = serde_to_value(&json).map_err(|e| failure("value", e.to_string()))?;
+        context
+            .interpreter
+            .set_global_binding(name.to_string(), value);
+        Ok(json!({}))
+    })
+}
+
+/// Read the global `name` of `context`, answering
+/// `{"ok": true, "value": ..., "display": "...", "type": "..."}`
+///
+/// # Safety
+///
+/// `context` must be null or a live context not in use by another thread,
+/// and `name` null or a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn ruchy_get(context: *mut RuchyContext, name: *const c_char) -> *mut c_char {
+    respond(|| {
+        // SAFETY: guaranteed by the caller
+        let context = unsafe { context_mut(context) }?;
+        // SAFETY: guaranteed by the caller
+        let name = unsafe { read_str(name, "name") }?;
+        match context.interpreter.get_global_binding(name) {
+            Some(value) => Ok(describe(&value)),
+            None => Err(failure("undefined", format!("'{name}' is not defined"))),
+        }
+    })
+}
+
+/// Release a string returned by this library; null is ignored
+///
+/// # Safety
+///
+/// `string` must be null or a response of this library, and must not be
+/// used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn ruchy_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        // SAFETY: responses come from `CString::into_raw` and are freed once
+        drop(unsafe { CString::from_raw(string) });
+    }
+}
+
+/// Run `f` and encode its answer as a response, catching panics
+fn respond(f: impl FnOnce() -> Result<Json, Json>) -> *mut c_char {
+    let response = match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(fields)) => success(fields),
+        Ok(Err(failure)) => failure,
+        Err(panic) => {
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(|s| (*s).to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            failure("panic", message)
+        }
+    };
+    // JSON escapes NUL, so the text never contains one
+    CString::new(response.to_string()).map_or(ptr::null_mut(), CString::into_raw)
+}
+
+/// `fields` with `"ok": true`, unless they already are a response
+fn success(fields: Json) -> Json {
+    let mut response = fields;
+    if response.get("ok").is_none() {
+        response["ok"] = Json::Bool(true);
+    }
+    response
+}
+
+/// Failed response of the given kind
+fn failure(kind: &str, message: impl Into<String>) -> Json {
+    json!({ "ok": false, "error": { "kind": kind, "message": message.into() } })
+}
+
+/// JSON form, display form and type of `value`
+fn describe(value: &Value) -> Json {
+    json!({
+        "value": value_to_serde(value).unwrap_or(Json::Null),
+        "display": value.to_string(),
+        "type": value.type_name(),
+    })
+}
+
+/// Parse `source`; a syntax error names the byte range it was found at
+fn parse(source: &str) -> Result<Expr, Json> {
+    let mut parser = Parser::new(source);
+    parser.parse().map_err(|e| {
+        let span = parser.error_span();
+        let start = span.start.min(source.len());
+        let end = span.end.clamp(start, source.len());
+        let mut response = failure("syntax", e.to_string());
+        response["error"]["start"] = start.into();
+        response["error"]["end"] = end.into();
+        response
+    })
+}
+
+/// Borrow the string argument `what`
+///
+/// # Safety
+///
+/// `ptr` must be null or a NUL-terminated string that outlives the borrow.
+unsafe fn read_str<'a>(ptr: *const c_char, what: &str) -> Result<&'a str, Json> {
+    if ptr.is_null() {
+        return Err(failure("argument", format!("`{what}` is null")));
+    }
+    // SAFETY: non-null and NUL-terminated per the contract above
+    let bytes = unsafe { CStr::from_ptr(ptr) };
+    bytes
+        .to_str()
+        .map_err(|e| failure("argument", format!("`{what}` is not UTF-8: {e}")))
+}
+
+/// Borrow the session behind `context`
+///
+/// # Safety
+///
+/// `context` must be null or a live context with no other borrow.
+unsafe fn context_mut<'a>(context: *mut RuchyContext) -> Result<&'a mut RuchyContext, Json> {
+    // SAFETY: null or live and unaliased per the contract above
+    unsafe { context.as_mut() }.ok_or_else(|| failure("argument", "`context` is null"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(response: *mut c_char) -> Json {
+        // SAFETY: `respond` returns a NUL-terminated string it allocated
+        let text = unsafe { CString::from_raw(response) };
+        serde_json::from_str(text.to_str().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_panic_fails_the_call() {
+        let response = decode(respond(|| panic!("evaluator bug")));
+        assert_eq!(response["ok"], false);
+        assert_eq!(response["error"]["kind"], "panic");
+        assert_eq!(response["error"]["message"], "evaluator bug");
+
+        let response = decode(respond(|| panic!("bad {}", "index")));
+        assert_eq!(response["error"]["message"], "bad index");
+    }
+}
@@ -0,0 +1,146 @@
+#![allow(missing_docs)]
+#![allow(unsafe_code)]
+//! The C API answers every call with JSON and keeps bindings in a context.
+
+use ruchy_capi::*;
+use serde_json::Value as Json;
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+
+/// Decode and release an answer
+fn answer(response: *mut c_char) -> Json {
+    assert!(!response.is_null());
+    let text = unsafe { CStr::from_ptr(response) }
+        .to_str()
+        .unwrap()
+        .to_string();
+    unsafe { ruchy_string_free(response) };
+    serde_json::from_str(&text).unwrap()
+}
+
+fn c(text: &str) -> CString {
+    CString::new(text).unwrap()
+}
+
+struct Context(*mut RuchyContext);
+
+impl Context {
+    fn new() -> Self {
+        Self(ruchy_context_new())
+    }
+
+    fn eval(&self, source: &str) -> Json {
+        let source = c(source);
+        answer(unsafe { ruchy_eval(self.0, source.as_ptr()) })
+    }
+
+    fn set(&self, name: &str, value: &str) -> Json {
+        let (name, value) = (c(name), c(value));
+        answer(unsafe { ruchy_set(self.0, name.as_ptr(), value.as_ptr()) })
+    }
+
+    fn get(&self, name: &str) -> Json {
+        let name = c(name);
+        answer(unsafe { ruchy_get(self.0, name.as_ptr()) })
+    }
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        unsafe { ruchy_context_free(self.0) };
+    }
+}
+
+#[test]
+fn test_eval_keeps_bindings_and_captures_output() {
+    let context = Context::new();
+    assert_eq!(context.set("prices", "[3, 4, 5]")["ok"], true);
+
+    let result = context.eval("println(\"summing\")\nlet total = prices.sum()\ntotal * 2");
+    assert_eq!(result["ok"], true, "{result}");
+    assert_eq!(result["value"], 24);
+    assert_eq!(result["type"], "integer");
+    assert_eq!(result["output"], "summing\n");
+
+    assert_eq!(context.get("total")["value"], 12);
+    assert_eq!(context.get("nope")["error"]["kind"], "undefined");
+}
+
+#[test]
+fn test_values_cross_as_json() {
+    let context = Context::new();
+    let result = context.eval("(1, 2)");
+    assert_eq!(result["ok"], true, "{result}");
+    assert_eq!(result["value"], Json::Null);
+    assert_eq!(result["type"], "tuple");
+    assert_eq!(result["display"], "(1, 2)");
+
+    context.set("config", r#"{"name": "ruchy", "tags": [1, 2]}"#);
+    let config = context.get("config");
+    assert_eq!(config["value"]["tags"][1], 2, "{config}");
+    assert_eq!(context.eval("config.name")["value"], "ruchy");
+}
+
+#[test]
+fn test_errors_have_kinds() {
+    let context = Context::new();
+    let runtime = context.eval("println(\"before\")\nundefined_name + 1");
+    assert_eq!(runtime["ok"], false);
+    assert_eq!(runtime["error"]["kind"], "runtime");
+    assert_eq!(runtime["output"], "before\n");
+
+    let syntax = context.eval("let x = (1, 2");
+    assert_eq!(syntax["error"]["kind"], "syntax");
+    assert!(syntax["error"]["start"].is_u64(), "{syntax}");
+
+    let source = c("1");
+    let null = answer(unsafe { ruchy_eval(ptr::null_mut(), source.as_ptr()) });
+    assert_eq!(null["error"]["kind"], "argument");
+
+    assert_eq!(context.set("x", "{not json")["error"]["kind"], "value");
+}
+
+#[test]
+fn test_parse_and_transpile() {
+    let source = c("fun add(a: i32, b: i32) -> i32 { a + b }");
+    let parsed = answer(unsafe { ruchy_parse(source.as_ptr()) });
+    assert_eq!(parsed["ok"], true, "{parsed}");
+    assert!(parsed["ast"].is_object());
+
+    let transpiled = answer(unsafe { ruchy_transpile(source.as_ptr()) });
+    assert!(
+        transpiled["rust"].as_str().unwrap().contains("fn add"),
+        "{transpiled}"
+    );
+
+    let broken = c("fun (");
+    let broken = answer(unsafe { ruchy_transpile(broken.as_ptr()) });
+    assert_eq!(broken["error"]["kind"], "syntax");
+}
+
+#[test]
+fn test_header_declares_every_export() {
+    let header = include_str!("../include/ruchy.h");
+    assert!(header.contains(&format!(
+        "#define RUCHY_ABI_VERSION {}",
+        ruchy_abi_version()
+    )));
+    for function in [
+        "ruchy_abi_version",
+        "ruchy_version",
+        "ruchy_parse",
+        "ruchy_transpile",
+        "ruchy_context_new",
+        "ruchy_context_free",
+        "ruchy_eval",
+        "ruchy_set",
+        "ruchy_get",
+        "ruchy_string_free",
+    ] {
+        let declared =
+            header.contains(&format!(" {function}(")) || header.contains(&format!(" *{function}("));
+        assert!(declared, "{function}");
+    }
+    let version = unsafe { CStr::from_ptr(ruchy_version()) };
+    assert_eq!(version.to_str().unwrap(), env!("CARGO_PKG_VERSION"));
+}
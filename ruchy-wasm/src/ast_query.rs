@@ -0,0 +1,258 @@
+//! JSONPath-style queries over the serialized Ruchy AST
+//!
+//! Supports the subset playgrounds need to locate nodes:
+//! `$`, `.name`, `['name']`, `.*`, `[*]`, `[n]` and recursive descent `..name` / `..*`.
+//! Every match carries the span of its nearest enclosing expression so editors
+//! can highlight it.
+
+use serde::Serialize;
+use serde_json::Value as Json;
+
+/// A single node selected by a query
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct QueryMatch {
+    /// Normalized JSONPath of the node, e.g. `$['kind']['Block'][0]`
+    pub path: String,
+    pub node: Json,
+    /// Byte offsets of the nearest enclosing node with a span
+    pub start: Option<usize>,
+    pub end: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Selector {
+    Key(String),
+    Index(usize),
+    Wildcard,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Segment {
+    selector: Selector,
+    recursive: bool,
+}
+
+/// Node under evaluation: value, its path, and the inherited span
+#[derive(Clone)]
+struct Cursor<'a> {
+    node: &'a Json,
+    path: String,
+    span: Option<(usize, usize)>,
+}
+
+/// Run `query` against `ast`, returning matches in document order
+pub fn query(ast: &Json, query: &str) -> Result<Vec<QueryMatch>, String> {
+    let segments = parse_query(query)?;
+    let root = Cursor {
+        node: ast,
+        path: "$".to_string(),
+        span: span_of(ast),
+    };
+    let mut current = vec![root];
+    for segment in &segments {
+        current = current
+            .iter()
+            .flat_map(|cursor| apply_segment(cursor, segment))
+            .collect();
+    }
+    Ok(current
+        .into_iter()
+        .map(|cursor| QueryMatch {
+            path: cursor.path,
+            node: cursor.node.clone(),
+            start: cursor.span.map(|(start, _)| start),
+            end: cursor.span.map(|(_, end)| end),
+        })
+        .collect())
+}
+
+fn parse_query(query: &str) -> Result<Vec<Segment>, String> {
+    let rest = query
+        .trim()
+        .strip_prefix('$')
+        .ok_or_else(|| format!("Query must start with '$': {query}"))?;
+    let chars: Vec<char> = rest.chars().collect();
+    let mut segments = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let recursive = chars[i] == '.' && chars.get(i + 1) == Some(&'.');
+        match chars[i] {
+            '.' => {
+                i += if recursive { 2 } else { 1 };
+                if chars.get(i) == Some(&'[') {
+                    continue_bracket(&chars, &mut i, recursive, &mut segments)?;
+                    continue;
+                }
+                let start = i;
+                while i < chars.len() && chars[i] != '.' && chars[i] != '[' {
+                    i += 1;
+                }
+                let name: String = chars[start..i].iter().collect();
+                segments.push(Segment {
+                    selector: name_selector(&name)?,
+                    recursive,
+                });
+            }
+            '[' => continue_bracket(&chars, &mut i, false, &mut segments)?,
+            c => return Err(format!("Unexpected '{c}' in query at offset {}", i + 1)),
+        }
+    }
+    Ok(segments)
+}
+
+fn name_selector(name: &str) -> Result<Selector, String> {
+    match name {
+        "" => Err("Empty name in query".to_string()),
+        "*" => Ok(Selector::Wildcard),
+        _ => Ok(Selector::Key(name.to_string())),
+    }
+}
+
+/// Parse a `[...]` selector starting at `chars[*i] == '['`
+fn continue_bracket(
+    chars: &[char],
+    i: &mut usize,
+    recursive: bool,
+    segments: &mut Vec<Segment>,
+) -> Result<(), String> {
+    let close = chars[*i..]
+        .iter()
+        .position(|&c| c == ']')
+        .map(|offset| *i + offset)
+        .ok_or_else(|| "Unclosed '[' in query".to_string())?;
+    let inner: String = chars[*i + 1..close].iter().collect();
+    let inner = inner.trim();
+    let selector = if inner == "*" {
+        Selector::Wildcard
+    } else if let Some(key) = inner
+        .strip_prefix('\'')
+        .and_then(|s| s.strip_suffix('\''))
+        .or_else(|| inner.strip_prefix('"').and_then(|s| s.strip_suffix('"')))
+    {
+        Selector::Key(key.to_string())
+    } else {
+        Selector::Index(
+            inner
+                .parse()
+                .map_err(|_| format!("Invalid index '{inner}' in query"))?,
+        )
+    };
+    segments.push(Segment {
+        selector,
+        recursive,
+    });
+    *i = close + 1;
+    Ok(())
+}
+
+fn span_of(node: &Json) -> Option<(usize, usize)> {
+    let span = node.get("span")?;
+    let start = span.get("start")?.as_u64()?;
+    let end = span.get("end")?.as_u64()?;
+    Some((
+        usize::try_from(start).ok()?,
+        usize::try_from(end).ok()?,
+    ))
+}
+
+/// Direct children of a node, in document order
+fn children<'a>(cursor: &Cursor<'a>) -> Vec<(Selector, Cursor<'a>)> {
+    let child = |selector: Selector, node: &'a Json, path: String| {
+        let span = span_of(node).or(cursor.span);
+        (selector, Cursor { node, path, span })
+    };
+    match cursor.node {
+        Json::Object(map) => map
+            .iter()
+            .map(|(key, node)| {
+                child(
+                    Selector::Key(key.clone()),
+                    node,
+                    format!("{}['{key}']", cursor.path),
+                )
+            })
+            .collect(),
+        Json::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(index, node)| {
+                child(
+                    Selector::Index(index),
+                    node,
+                    format!("{}[{index}]", cursor.path),
+                )
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn selects(selector: &Selector, child: &Selector) -> bool {
+    matches!(selector, Selector::Wildcard) || selector == child
+}
+
+fn apply_segment<'a>(cursor: &Cursor<'a>, segment: &Segment) -> Vec<Cursor<'a>> {
+    let mut out = Vec::new();
+    for (key, child) in children(cursor) {
+        if selects(&segment.selector, &key) {
+            out.push(child.clone());
+        }
+        if segment.recursive {
+            out.extend(apply_segment(&child, segment));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sample() -> Json {
+        json!({
+            "kind": {"Block": [
+                {"kind": {"Identifier": "x"}, "span": {"start": 0, "end": 1}},
+                {"kind": {"Identifier": "y"}, "span": {"start": 4, "end": 5}}
+            ]},
+            "span": {"start": 0, "end": 5}
+        })
+    }
+
+    #[test]
+    fn test_root_query_returns_whole_tree() {
+        let matches = query(&sample(), "$").expect("valid query");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].path, "$");
+        assert_eq!((matches[0].start, matches[0].end), (Some(0), Some(5)));
+    }
+
+    #[test]
+    fn test_child_and_index_selectors() {
+        let matches = query(&sample(), "$.kind.Block[1]").expect("valid query");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].path, "$['kind']['Block'][1]");
+        assert_eq!(matches[0].start, Some(4));
+    }
+
+    #[test]
+    fn test_recursive_descent_inherits_span() {
+        let matches = query(&sample(), "$..Identifier").expect("valid query");
+        let names: Vec<_> = matches.iter().map(|m| m.node.clone()).collect();
+        assert_eq!(names, vec![json!("x"), json!("y")]);
+        assert_eq!((matches[1].start, matches[1].end), (Some(4), Some(5)));
+    }
+
+    #[test]
+    fn test_wildcard_and_quoted_keys() {
+        let matches = query(&sample(), "$['kind']['Block'][*]").expect("valid query");
+        assert_eq!(matches.len(), 2);
+    }
+
+    #[test]
+    fn test_invalid_queries_are_rejected() {
+        assert!(query(&sample(), "kind").is_err());
+        assert!(query(&sample(), "$.kind[").is_err());
+        assert!(query(&sample(), "$[abc]").is_err());
+    }
+}
@@ -0,0 +1,162 @@
+//! Phased compilation behind `compile_async`
+//!
+//! Parsing and code generation each run to completion once started, so
+//! `compile_async` runs them as separate phases with a turn of the event loop
+//! before each one. The page repaints and handles input between phases,
+//! `onProgress` hears about each phase, and a [`CancellationToken`] is
+//! honoured at every phase boundary.
+
+use crate::{parse_source, utf16_len, WasmDiagnostic, TRANSPILE_ERROR};
+use ruchy::backend::transpiler::Transpiler;
+use ruchy::frontend::ast::Expr;
+use serde::Serialize;
+use std::cell::Cell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+
+/// Cancels the `compile_async` calls it was passed to
+///
+/// ```javascript
+/// const token = new CancellationToken();
+/// const pending = compiler.compile_async_with_token(source, token);
+/// token.cancel(); // pending rejects with an E0008 diagnostic
+/// ```
+#[wasm_bindgen]
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Rc<Cell<bool>>,
+}
+
+#[wasm_bindgen]
+impl CancellationToken {
+    /// Create a token that is not cancelled
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop every compilation using this token at its next phase boundary
+    #[wasm_bindgen]
+    pub fn cancel(&self) {
+        self.cancelled.set(true);
+    }
+
+    /// Whether `cancel` has been called
+    #[wasm_bindgen(getter)]
+    pub fn cancelled(&self) -> bool {
+        self.cancelled.get()
+    }
+}
+
+/// Phase of a compilation, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum Phase {
+    Parse,
+    Transpile,
+}
+
+/// Progress passed to `onProgress` before each phase
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Progress {
+    /// Phase about to run
+    pub phase: Phase,
+    /// Phases finished so far
+    pub completed: usize,
+    /// Number of phases
+    pub total: usize,
+}
+
+/// A compilation advanced one phase at a time
+#[derive(Debug)]
+pub(crate) struct CompileJob {
+    source: String,
+    ast: Option<Expr>,
+}
+
+impl CompileJob {
+    pub(crate) fn new(source: String) -> Self {
+        Self { source, ast: None }
+    }
+
+    /// Progress before the next call to [`CompileJob::step`]
+    pub(crate) fn progress(&self) -> Progress {
+        let (phase, completed) = match self.ast {
+            None => (Phase::Parse, 0),
+            Some(_) => (Phase::Transpile, 1),
+        };
+        Progress {
+            phase,
+            completed,
+            total: 2,
+        }
+    }
+
+    /// Run the next phase, returning the Rust code once the last one is done
+    pub(crate) fn step(&mut self) -> Result<Option<String>, WasmDiagnostic> {
+        let Some(ast) = self.ast.take() else {
+            self.ast = Some(parse_source(&self.source)?);
+            return Ok(None);
+        };
+        let rust_code = Transpiler::new()
+            .transpile(&ast)
+            .map_err(|e| WasmDiagnostic::error(TRANSPILE_ERROR, &e, 0, utf16_len(&self.source)))?;
+        Ok(Some(rust_code.to_string()))
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &js_sys::Function, timeout: i32);
+}
+
+/// Let the event loop run before continuing
+///
+/// Uses `setTimeout` rather than a resolved promise so that rendering and
+/// input events get a turn, in pages, workers and Node.js alike.
+pub(crate) async fn yield_to_event_loop() -> Result<(), JsValue> {
+    let promise = js_sys::Promise::new(&mut |resolve, _reject| set_timeout(&resolve, 0));
+    wasm_bindgen_futures::JsFuture::from(promise)
+        .await
+        .map(drop)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PARSE_ERROR;
+
+    #[test]
+    fn test_job_runs_parse_then_transpile() {
+        let mut job = CompileJob::new("fun add(a, b) { a + b }".to_string());
+        assert_eq!(job.progress().phase, Phase::Parse);
+        assert_eq!(job.step().expect("should parse"), None);
+        assert_eq!(
+            job.progress(),
+            Progress {
+                phase: Phase::Transpile,
+                completed: 1,
+                total: 2
+            }
+        );
+        let rust_code = job.step().expect("should transpile").expect("finished");
+        assert!(rust_code.contains("add"), "{rust_code}");
+    }
+
+    #[test]
+    fn test_job_reports_parse_errors() {
+        let mut job = CompileJob::new("let x = (1, 2".to_string());
+        assert_eq!(job.step().expect_err("invalid").code, PARSE_ERROR);
+    }
+
+    #[test]
+    fn test_cancellation_is_shared_between_clones() {
+        let token = CancellationToken::new();
+        let held_by_job = token.clone();
+        assert!(!held_by_job.cancelled());
+        token.cancel();
+        assert!(held_by_job.cancelled());
+    }
+}
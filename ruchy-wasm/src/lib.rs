@@ -5,6 +5,9 @@
 //! - Interactive code playgrounds
 //! - Educational tools and documentation
 //! - Real-time syntax validation
+//! - Editor diagnostics with UTF-16 spans that index JavaScript strings
+//! - Code formatting and AST queries for playgrounds
+//! - Hover lookups and document outlines for editors
 //!
 //! # Example
 //!
@@ -18,15 +21,433 @@
 //!     console.log(rustCode);
 //! }
 //! ```
+//!
+//! # Diagnostics
+//!
+//! Failures are thrown as plain objects rather than strings so editors can
+//! underline the offending range:
+//!
+//! ```javascript
+//! try {
+//!     compiler.compile('let x = (1, 2');
+//! } catch (err) {
+//!     // { code: "E0001", message: "...", start: 13, end: 13,
+//!     //   severity: "error", related: [] }
+//!     editor.markText(err.start, err.end);
+//! }
+//!
+//! `start`/`end` count UTF-16 code units, so they can be passed straight to
+//! `String.prototype.slice` even when the source contains non-ASCII text.
+//!
+//! compiler.diagnostics('let x = ');   // [] when the source parses
+//! compiler.lint('let unused = 1');     // lint violations, same shape
+//! compiler.lint(source, { rules: 'unused,style', strict: true });
+//! ```
+//!
+//! # Running code
+//!
+//! `run` executes code in the interpreter itself, compiled to wasm32, and
+//! reports what it printed and the final value. A step limit bounds loop
+//! iterations and calls so a runaway program cannot hang the tab:
+//!
+//! ```javascript
+//! compiler.run('println("hi")\n1 + 2', { maxSteps: 10000 });
+//! // { success: true, stdout: "hi\n", value: "3", valueType: "integer",
+//! //   error: null, steps: 0 }
+//! ```
+//!
+//! # Compiling without blocking
+//!
+//! `compile_async` returns a Promise and yields to the event loop between
+//! parsing and code generation, reporting each phase. `cancel()` rejects
+//! every pending compilation with an `E0008` diagnostic; a
+//! `CancellationToken` cancels just the compilations it is passed to:
+//!
+//! ```javascript
+//! const rust = await compiler.compile_async(source, (progress) => {
+//!     // { phase: "parse" | "transpile", completed: 0, total: 2 }
+//!     bar.value = progress.completed / progress.total;
+//! });
+//! stopButton.onclick = () => compiler.cancel();
+//! ```
+//!
+//! # Hover and outline
+//!
+//! `node_at` and `symbols` answer the two questions editors ask most without
+//! shipping the whole JSON AST to the page:
+//!
+//! ```javascript
+//! compiler.node_at(source, offset);
+//! // { kind: "Identifier", name: "add", start, end, ancestors: [...] }
+//! compiler.symbols(source);
+//! // [{ name: "add", kind: "function", start, end, children: [] }, ...]
+//! ```
+
+mod ast_query;
+mod compile_job;
+mod outline;
+
+pub use compile_job::CancellationToken;
 
+use compile_job::{yield_to_event_loop, CompileJob};
 use ruchy::backend::transpiler::Transpiler;
+use ruchy::cli::shared::configure_linter;
 use ruchy::frontend::parser::Parser;
+use ruchy::quality::formatter::Formatter;
+use ruchy::quality::linter::LintIssue;
+use ruchy::quality::FormatterConfig;
+use ruchy::runtime::builtins::{enable_output_capture, get_captured_output};
+use ruchy::runtime::interrupt::{self, STEP_LIMIT_MESSAGE};
+use ruchy::runtime::{Interpreter, InterpreterError, Value};
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
+/// Diagnostic code for syntax errors
+const PARSE_ERROR: &str = "E0001";
+/// Diagnostic code for code generation failures
+const TRANSPILE_ERROR: &str = "E0002";
+/// Diagnostic code for serialization failures
+const SERIALIZE_ERROR: &str = "E0003";
+/// Diagnostic code for formatter failures
+const FORMAT_ERROR: &str = "E0004";
+/// Diagnostic code for malformed AST queries
+const QUERY_ERROR: &str = "E0005";
+/// Diagnostic code for errors raised while running code
+const RUNTIME_ERROR: &str = "E0006";
+/// Diagnostic code for runs stopped by their step limit
+const STEP_LIMIT_ERROR: &str = "E0007";
+/// Diagnostic code for cancelled compilations
+const CANCELLED_ERROR: &str = "E0008";
+
+/// Loop iterations and calls `run` allows when no `maxSteps` is given
+const DEFAULT_MAX_STEPS: u64 = 1_000_000;
+
+/// Options accepted by `format`; omitted fields keep formatter defaults
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct FormatOptions {
+    pub indent_width: Option<usize>,
+    pub use_tabs: Option<bool>,
+    pub max_line_length: Option<usize>,
+    pub trailing_commas: Option<bool>,
+}
+
+impl FormatOptions {
+    fn into_config(self) -> FormatterConfig {
+        let defaults = FormatterConfig::default();
+        FormatterConfig {
+            indent_width: self.indent_width.unwrap_or(defaults.indent_width),
+            use_tabs: self.use_tabs.unwrap_or(defaults.use_tabs),
+            max_line_length: self.max_line_length.unwrap_or(defaults.max_line_length),
+            trailing_commas: self.trailing_commas.unwrap_or(defaults.trailing_commas),
+            ..defaults
+        }
+    }
+}
+
+/// Options accepted by `lint`, matching `ruchy lint --rules` and `--strict`
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LintOptions {
+    /// Comma-separated rule categories to check, e.g. `"unused,style"`
+    pub rules: Option<String>,
+    /// Report warnings as errors
+    pub strict: bool,
+}
+
+/// Options accepted by `run`
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct RunOptions {
+    /// Loop iterations and function calls allowed before the run stops
+    pub max_steps: Option<u64>,
+}
+
+/// Outcome of `run`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunResult {
+    pub success: bool,
+    /// Text printed by `print`/`println`, including output before a failure
+    pub stdout: String,
+    /// Display form of the final value; `null` for nil and on failure
+    pub value: Option<String>,
+    /// Type name of the final value; `null` on failure
+    pub value_type: Option<String>,
+    /// `E0001` parse, `E0006` runtime or `E0007` step-limit diagnostic
+    pub error: Option<WasmDiagnostic>,
+    /// Loop iterations and calls taken
+    pub steps: u64,
+}
+
+/// Editor-facing diagnostic with UTF-16 offsets into the source
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WasmDiagnostic {
+    /// Stable code (`E0001` parse, `E0002` transpile, lint rule name for lints)
+    pub code: String,
+    pub message: String,
+    /// Start offset in UTF-16 code units (inclusive)
+    pub start: usize,
+    /// End offset in UTF-16 code units (exclusive)
+    pub end: usize,
+    /// `"error"`, `"warning"` or `"info"`
+    pub severity: String,
+    pub related: Vec<RelatedNote>,
+}
+
+/// Secondary message attached to a diagnostic
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RelatedNote {
+    pub message: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl WasmDiagnostic {
+    fn error(code: &str, error: &anyhow::Error, start: usize, end: usize) -> Self {
+        let related = error
+            .chain()
+            .skip(1)
+            .map(|cause| RelatedNote {
+                message: cause.to_string(),
+                start,
+                end,
+            })
+            .collect();
+        Self {
+            code: code.to_string(),
+            message: error.to_string(),
+            start,
+            end,
+            severity: "error".to_string(),
+            related,
+        }
+    }
+
+    fn to_js(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(self).unwrap_or_else(|_| JsValue::from_str(&self.message))
+    }
+}
+
+/// Parse `source`, returning a positioned diagnostic on failure
+fn parse_source(source: &str) -> Result<ruchy::frontend::ast::Expr, WasmDiagnostic> {
+    let mut parser = Parser::new(source);
+    parser.parse().map_err(|e| {
+        let span = parser.error_span();
+        let start = span.start.min(source.len());
+        let end = span.end.clamp(start, source.len());
+        WasmDiagnostic::error(
+            PARSE_ERROR,
+            &e,
+            utf16_offset(source, start),
+            utf16_offset(source, end),
+        )
+    })
+}
+
+/// Convert a byte offset into `source` to a UTF-16 code unit offset
+///
+/// JavaScript strings index by UTF-16 code units, so every offset handed to
+/// the page goes through here. Offsets inside a character round down.
+pub(crate) fn utf16_offset(source: &str, byte_offset: usize) -> usize {
+    let mut boundary = byte_offset.min(source.len());
+    while !source.is_char_boundary(boundary) {
+        boundary -= 1;
+    }
+    utf16_len(&source[..boundary])
+}
+
+/// Length of `source` in UTF-16 code units
+pub(crate) fn utf16_len(source: &str) -> usize {
+    source.encode_utf16().count()
+}
+
+/// Convert a 1-based line/column pair into a byte offset, clamped to `source`
+fn line_column_to_offset(source: &str, line: usize, column: usize) -> usize {
+    let line_start = source
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum::<usize>()
+        .min(source.len());
+    let line_text = source[line_start..].split('\n').next().unwrap_or("");
+    let column_offset = line_text
+        .char_indices()
+        .nth(column.saturating_sub(1))
+        .map_or(line_text.len(), |(i, _)| i);
+    line_start + column_offset
+}
+
+/// Map a linter issue onto a diagnostic spanning the identifier it names
+fn lint_to_diagnostic(source: &str, issue: &LintIssue) -> WasmDiagnostic {
+    let byte_start = line_column_to_offset(source, issue.line, issue.column);
+    let start = utf16_offset(source, byte_start);
+    let end = utf16_offset(source, byte_start + issue.name.len());
+    let related = if issue.suggestion.is_empty() {
+        Vec::new()
+    } else {
+        vec![RelatedNote {
+            message: issue.suggestion.clone(),
+            start,
+            end,
+        }]
+    };
+    WasmDiagnostic {
+        code: issue.rule.clone(),
+        message: issue.message.clone(),
+        start,
+        end,
+        severity: issue.severity.clone(),
+        related,
+    }
+}
+
+/// Collect parse diagnostics for `source` (empty when it parses)
+fn collect_diagnostics(source: &str) -> Vec<WasmDiagnostic> {
+    parse_source(source).err().into_iter().collect()
+}
+
+/// Run the linter over `source`; parse failures are reported as diagnostics
+fn collect_lints(source: &str, options: &LintOptions) -> Vec<WasmDiagnostic> {
+    let ast = match parse_source(source) {
+        Ok(ast) => ast,
+        Err(diagnostic) => return vec![diagnostic],
+    };
+    let linter = configure_linter(options.rules.as_deref(), options.strict);
+    match linter.lint(&ast, source) {
+        Ok(issues) => issues
+            .iter()
+            .map(|issue| lint_to_diagnostic(source, issue))
+            .collect(),
+        Err(e) => vec![WasmDiagnostic::error(
+            TRANSPILE_ERROR,
+            &e,
+            0,
+            utf16_len(source),
+        )],
+    }
+}
+
+/// Format `source` with the given options
+fn format_source(source: &str, options: FormatOptions) -> Result<String, WasmDiagnostic> {
+    let ast = parse_source(source)?;
+    let mut formatter = Formatter::with_config(options.into_config());
+    formatter.set_source(source);
+    formatter
+        .format(&ast)
+        .map_err(|e| WasmDiagnostic::error(FORMAT_ERROR, &e, 0, utf16_len(source)))
+}
+
+/// Parse `source` and serialize its AST to JSON
+fn ast_json(source: &str) -> Result<serde_json::Value, WasmDiagnostic> {
+    let ast = parse_source(source)?;
+    serde_json::to_value(&ast).map_err(|e| {
+        let error = anyhow::anyhow!("AST serialization error: {e}");
+        WasmDiagnostic::error(SERIALIZE_ERROR, &error, 0, 0)
+    })
+}
+
+/// Evaluate a JSONPath query against the serialized AST of `source`
+fn query_source(source: &str, path: &str) -> Result<Vec<ast_query::QueryMatch>, WasmDiagnostic> {
+    let json = ast_json(source)?;
+    ast_query::query(&json, path).map_err(|message| {
+        WasmDiagnostic::error(QUERY_ERROR, &anyhow::anyhow!(message), 0, 0)
+    })
+}
+
+/// Run `source` in a fresh interpreter, capturing printed output
+fn run_source(source: &str, options: &RunOptions) -> RunResult {
+    let ast = match parse_source(source) {
+        Ok(ast) => ast,
+        Err(diagnostic) => {
+            return RunResult {
+                success: false,
+                stdout: String::new(),
+                value: None,
+                value_type: None,
+                error: Some(diagnostic),
+                steps: 0,
+            }
+        }
+    };
+
+    enable_output_capture();
+    interrupt::set_step_limit(Some(options.max_steps.unwrap_or(DEFAULT_MAX_STEPS)));
+    let result = match Interpreter::new().eval_expr(&ast) {
+        Err(InterpreterError::Return(value)) => Ok(value),
+        other => other,
+    };
+    let steps = interrupt::steps_taken();
+    interrupt::set_step_limit(None);
+    let stdout = get_captured_output();
+
+    match result {
+        Ok(value) => RunResult {
+            success: true,
+            stdout,
+            value: (!matches!(value, Value::Nil)).then(|| value.to_string()),
+            value_type: Some(value.type_name().to_string()),
+            error: None,
+            steps,
+        },
+        Err(e) => {
+            let code = match &e {
+                InterpreterError::RuntimeError(message) if message == STEP_LIMIT_MESSAGE => {
+                    STEP_LIMIT_ERROR
+                }
+                _ => RUNTIME_ERROR,
+            };
+            let error = anyhow::anyhow!("{e}");
+            RunResult {
+                success: false,
+                stdout,
+                value: None,
+                value_type: None,
+                error: Some(WasmDiagnostic::error(code, &error, 0, utf16_len(source))),
+                steps,
+            }
+        }
+    }
+}
+
+/// Compile `source` phase by phase, yielding to the event loop before each
+///
+/// Stops before the next phase once any of `tokens` is cancelled.
+async fn compile_in_phases(
+    source: String,
+    tokens: Vec<CancellationToken>,
+    on_progress: Option<js_sys::Function>,
+) -> Result<JsValue, JsValue> {
+    let mut job = CompileJob::new(source);
+    loop {
+        if let Some(callback) = &on_progress {
+            let progress = serde_wasm_bindgen::to_value(&job.progress())?;
+            callback.call1(&JsValue::NULL, &progress)?;
+        }
+        yield_to_event_loop().await?;
+        if tokens.iter().any(CancellationToken::cancelled) {
+            let error = anyhow::anyhow!("Compilation cancelled");
+            return Err(WasmDiagnostic::error(CANCELLED_ERROR, &error, 0, 0).to_js());
+        }
+        if let Some(rust_code) = job.step().map_err(|d| d.to_js())? {
+            return Ok(JsValue::from_str(&rust_code));
+        }
+    }
+}
+
+fn diagnostics_to_js(diagnostics: &[WasmDiagnostic]) -> Result<JsValue, JsValue> {
+    serde_wasm_bindgen::to_value(diagnostics).map_err(|e| {
+        let error = anyhow::anyhow!("Diagnostic serialization error: {e}");
+        WasmDiagnostic::error(SERIALIZE_ERROR, &error, 0, 0).to_js()
+    })
+}
+
 /// WebAssembly compiler interface for Ruchy
 #[wasm_bindgen]
 pub struct RuchyCompiler {
     transpiler: Transpiler,
+    /// Token of the `compile_async` calls that `cancel` stops
+    cancellation: CancellationToken,
 }
 
 #[wasm_bindgen]
@@ -39,6 +460,7 @@ impl RuchyCompiler {
 
         Self {
             transpiler: Transpiler::new(),
+            cancellation: CancellationToken::new(),
         }
     }
 
@@ -50,22 +472,61 @@ impl RuchyCompiler {
     ///
     /// # Returns
     ///
-    /// Transpiled Rust code as a string, or error message
+    /// Transpiled Rust code as a string. On failure a diagnostic object
+    /// (`code`, `message`, `start`, `end`, `severity`, `related`) is thrown.
     #[wasm_bindgen]
     pub fn compile(&mut self, source: &str) -> Result<String, JsValue> {
-        let mut parser = Parser::new(source);
-        let ast = parser
-            .parse()
-            .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+        let ast = parse_source(source).map_err(|d| d.to_js())?;
 
-        let rust_code = self
-            .transpiler
-            .transpile(&ast)
-            .map_err(|e| JsValue::from_str(&format!("Transpile error: {}", e)))?;
+        let rust_code = self.transpiler.transpile(&ast).map_err(|e| {
+            WasmDiagnostic::error(TRANSPILE_ERROR, &e, 0, utf16_len(source)).to_js()
+        })?;
 
         Ok(rust_code.to_string())
     }
 
+    /// Compile Ruchy code to Rust without blocking the page
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Ruchy source code as a string
+    /// * `on_progress` - Optional callback receiving
+    ///   `{ phase, completed, total }` before each phase
+    ///
+    /// # Returns
+    ///
+    /// Promise of the transpiled Rust code. It rejects with a diagnostic
+    /// object on failure, or with an `E0008` diagnostic after `cancel()`.
+    #[wasm_bindgen]
+    pub fn compile_async(
+        &self,
+        source: String,
+        on_progress: Option<js_sys::Function>,
+    ) -> js_sys::Promise {
+        let tokens = vec![self.cancellation.clone()];
+        wasm_bindgen_futures::future_to_promise(compile_in_phases(source, tokens, on_progress))
+    }
+
+    /// Like `compile_async`, but also stopped by `token`
+    #[wasm_bindgen]
+    pub fn compile_async_with_token(
+        &self,
+        source: String,
+        token: &CancellationToken,
+        on_progress: Option<js_sys::Function>,
+    ) -> js_sys::Promise {
+        let tokens = vec![token.clone(), self.cancellation.clone()];
+        wasm_bindgen_futures::future_to_promise(compile_in_phases(source, tokens, on_progress))
+    }
+
+    /// Cancel every pending `compile_async` call of this compiler
+    ///
+    /// Compilations started afterwards are not affected.
+    #[wasm_bindgen]
+    pub fn cancel(&mut self) {
+        std::mem::take(&mut self.cancellation).cancel();
+    }
+
     /// Validate Ruchy syntax without compilation
     ///
     /// # Arguments
@@ -80,6 +541,161 @@ impl RuchyCompiler {
         Parser::new(source).parse().is_ok()
     }
 
+    /// Syntax diagnostics for `source`
+    ///
+    /// # Returns
+    ///
+    /// Array of diagnostic objects; empty when the source parses
+    #[wasm_bindgen]
+    pub fn diagnostics(&self, source: &str) -> Result<JsValue, JsValue> {
+        diagnostics_to_js(&collect_diagnostics(source))
+    }
+
+    /// Lint Ruchy code with the same rules as `ruchy lint`
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Ruchy source code to lint
+    /// * `options` - Optional `{ rules, strict }`, as `--rules` and `--strict`
+    ///
+    /// # Returns
+    ///
+    /// Array of diagnostic objects whose `code` is the lint rule name.
+    /// Sources that fail to parse yield a single `E0001` diagnostic.
+    #[wasm_bindgen]
+    pub fn lint(&self, source: &str, options: JsValue) -> Result<JsValue, JsValue> {
+        let options = if options.is_undefined() || options.is_null() {
+            LintOptions::default()
+        } else {
+            serde_wasm_bindgen::from_value(options).map_err(|e| {
+                let error = anyhow::anyhow!("Invalid lint options: {e}");
+                WasmDiagnostic::error(TRANSPILE_ERROR, &error, 0, 0).to_js()
+            })?
+        };
+        diagnostics_to_js(&collect_lints(source, &options))
+    }
+
+    /// Format Ruchy code
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Ruchy source code to format
+    /// * `options` - Optional `{ indentWidth, useTabs, maxLineLength, trailingCommas }`
+    ///
+    /// # Returns
+    ///
+    /// Formatted source; parse failures throw a diagnostic object
+    #[wasm_bindgen]
+    pub fn format(&self, source: &str, options: JsValue) -> Result<String, JsValue> {
+        let options = if options.is_undefined() || options.is_null() {
+            FormatOptions::default()
+        } else {
+            serde_wasm_bindgen::from_value(options).map_err(|e| {
+                let error = anyhow::anyhow!("Invalid format options: {e}");
+                WasmDiagnostic::error(FORMAT_ERROR, &error, 0, 0).to_js()
+            })?
+        };
+        format_source(source, options).map_err(|d| d.to_js())
+    }
+
+    /// Query the AST of Ruchy code with a JSONPath expression
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Ruchy source code to parse
+    /// * `jsonpath` - Query such as `$..Identifier` or `$.kind.Block[0]`
+    ///
+    /// # Returns
+    ///
+    /// Array of `{ path, node, start, end }` matches, where `start`/`end` are
+    /// the byte offsets of the nearest enclosing expression
+    #[wasm_bindgen]
+    pub fn query_ast(&self, source: &str, jsonpath: &str) -> Result<JsValue, JsValue> {
+        let matches = query_source(source, jsonpath).map_err(|d| d.to_js())?;
+        serde_wasm_bindgen::to_value(&matches).map_err(|e| {
+            let error = anyhow::anyhow!("Query result serialization error: {e}");
+            WasmDiagnostic::error(SERIALIZE_ERROR, &error, 0, 0).to_js()
+        })
+    }
+
+    /// Describe the innermost AST node at a byte offset, for hover
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Ruchy source code to parse
+    /// * `offset` - Byte offset into `source`
+    ///
+    /// # Returns
+    ///
+    /// `{ kind, name, start, end, ancestors }`, where `kind` is the expression
+    /// kind (e.g. `Identifier`), `start`/`end` are the byte offsets of the
+    /// whole node and `ancestors` lists the enclosing kinds, outermost first;
+    /// `null` when no node covers `offset`
+    #[wasm_bindgen]
+    pub fn node_at(&self, source: &str, offset: usize) -> Result<JsValue, JsValue> {
+        let json = ast_json(source).map_err(|d| d.to_js())?;
+        let Some(info) = outline::node_at(&json, source, offset) else {
+            return Ok(JsValue::NULL);
+        };
+        serde_wasm_bindgen::to_value(&info).map_err(|e| {
+            let error = anyhow::anyhow!("Node info serialization error: {e}");
+            WasmDiagnostic::error(SERIALIZE_ERROR, &error, 0, 0).to_js()
+        })
+    }
+
+    /// Outline the declarations of Ruchy code
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Ruchy source code to parse
+    ///
+    /// # Returns
+    ///
+    /// Array of `{ name, kind, start, end, children }` symbols in source
+    /// order. `kind` is one of `function`, `method`, `constructor`,
+    /// `variable`, `struct`, `class`, `enum`, `trait`, `impl`, `actor`,
+    /// `module`, `typeAlias` or `effect`; `children` holds type members and
+    /// declarations nested in functions and modules. Only top-level and
+    /// module-level `let`s are listed.
+    #[wasm_bindgen]
+    pub fn symbols(&self, source: &str) -> Result<JsValue, JsValue> {
+        let json = ast_json(source).map_err(|d| d.to_js())?;
+        serde_wasm_bindgen::to_value(&outline::symbols(&json)).map_err(|e| {
+            let error = anyhow::anyhow!("Symbol serialization error: {e}");
+            WasmDiagnostic::error(SERIALIZE_ERROR, &error, 0, 0).to_js()
+        })
+    }
+
+    /// Run Ruchy code in the interpreter
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Ruchy source code to run
+    /// * `options` - Optional `{ maxSteps }`; loop iterations and calls
+    ///   allowed before the run stops (default 1,000,000)
+    ///
+    /// # Returns
+    ///
+    /// `{ success, stdout, value, valueType, error, steps }`. Failures are
+    /// reported in `error` rather than thrown, so output printed before the
+    /// failure is kept.
+    #[wasm_bindgen]
+    pub fn run(&self, source: &str, options: JsValue) -> Result<JsValue, JsValue> {
+        let options = if options.is_undefined() || options.is_null() {
+            RunOptions::default()
+        } else {
+            serde_wasm_bindgen::from_value(options).map_err(|e| {
+                let error = anyhow::anyhow!("Invalid run options: {e}");
+                WasmDiagnostic::error(RUNTIME_ERROR, &error, 0, 0).to_js()
+            })?
+        };
+        let result = run_source(source, &options);
+        serde_wasm_bindgen::to_value(&result).map_err(|e| {
+            let error = anyhow::anyhow!("Run result serialization error: {e}");
+            WasmDiagnostic::error(SERIALIZE_ERROR, &error, 0, 0).to_js()
+        })
+    }
+
     /// Get Ruchy compiler version
     #[wasm_bindgen(getter)]
     pub fn version(&self) -> String {
@@ -97,13 +713,12 @@ impl RuchyCompiler {
     /// AST representation as JSON string
     #[wasm_bindgen]
     pub fn parse_to_json(&self, source: &str) -> Result<String, JsValue> {
-        let mut parser = Parser::new(source);
-        let ast = parser
-            .parse()
-            .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+        let ast = parse_source(source).map_err(|d| d.to_js())?;
 
-        serde_json::to_string_pretty(&ast)
-            .map_err(|e| JsValue::from_str(&format!("JSON serialization error: {}", e)))
+        serde_json::to_string_pretty(&ast).map_err(|e| {
+            let error = anyhow::anyhow!("JSON serialization error: {e}");
+            WasmDiagnostic::error(SERIALIZE_ERROR, &error, 0, 0).to_js()
+        })
     }
 }
 
@@ -137,6 +752,222 @@ mod tests {
         assert!(!compiler.validate("let x = "));
     }
 
+    #[wasm_bindgen_test]
+    fn test_diagnostics_empty_for_valid_source() {
+        let compiler = RuchyCompiler::new();
+        assert!(compiler.diagnostics("let x = 42").is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_lint_returns_array() {
+        let compiler = RuchyCompiler::new();
+        assert!(compiler.lint("let unused = 1", JsValue::UNDEFINED).is_ok());
+    }
+
+    #[test]
+    fn test_parse_error_has_offsets_within_source() {
+        let source = "let x = (1, 2";
+        let diagnostics = collect_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        let d = &diagnostics[0];
+        assert_eq!(d.code, PARSE_ERROR);
+        assert_eq!(d.severity, "error");
+        assert!(d.start <= d.end);
+        assert!(d.end <= source.len());
+        assert!(!d.message.is_empty());
+    }
+
+    #[test]
+    fn test_valid_source_has_no_diagnostics() {
+        assert!(collect_diagnostics("let x = 42\nx + 1").is_empty());
+    }
+
+    #[test]
+    fn test_line_column_to_offset() {
+        let source = "let a = 1\nlet b = 2\n";
+        assert_eq!(line_column_to_offset(source, 1, 1), 0);
+        assert_eq!(line_column_to_offset(source, 2, 5), 14);
+        assert_eq!(line_column_to_offset(source, 9, 9), source.len());
+    }
+
+    #[test]
+    fn test_lint_diagnostics_use_rule_as_code() {
+        let source = "fn f() {\n    let unused = 1\n    2\n}";
+        for d in collect_lints(source, &LintOptions::default()) {
+            assert_ne!(d.code, PARSE_ERROR);
+            assert!(d.start <= d.end && d.end <= source.len());
+        }
+    }
+
+    #[test]
+    fn test_utf16_offset() {
+        let source = "a\u{e9}\u{1f600}b";
+        assert_eq!(utf16_offset(source, 0), 0);
+        assert_eq!(utf16_offset(source, 1), 1);
+        assert_eq!(utf16_offset(source, 3), 2);
+        // Inside the emoji rounds down to its start
+        assert_eq!(utf16_offset(source, 5), 2);
+        assert_eq!(utf16_offset(source, 7), 4);
+        assert_eq!(utf16_len(source), 5);
+    }
+
+    #[test]
+    fn test_diagnostic_offsets_count_utf16_units_after_multibyte_prefix() {
+        // The emoji is four bytes but two UTF-16 units, the same as "ab"
+        let emoji = "let s = \"\u{1f600}\"\nlet x = (1, 2";
+        let ascii = "let s = \"ab\"\nlet x = (1, 2";
+        let emoji_diagnostics = collect_diagnostics(emoji);
+        let ascii_diagnostics = collect_diagnostics(ascii);
+        assert_eq!(emoji_diagnostics.len(), 1);
+        assert_eq!(
+            (emoji_diagnostics[0].start, emoji_diagnostics[0].end),
+            (ascii_diagnostics[0].start, ascii_diagnostics[0].end)
+        );
+        assert!(emoji_diagnostics[0].end <= utf16_len(emoji));
+
+        let emoji = "fn f() {\n    let s = \"\u{1f600}\"\n    let unused = 1\n    s\n}";
+        let ascii = "fn f() {\n    let s = \"ab\"\n    let unused = 1\n    s\n}";
+        let spans = |source| {
+            collect_lints(source, &LintOptions::default())
+                .into_iter()
+                .map(|d| (d.code, d.start, d.end))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(spans(emoji), spans(ascii));
+    }
+
+    #[test]
+    fn test_lint_reports_parse_error() {
+        let diagnostics = collect_lints("let x = ", &LintOptions::default());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, PARSE_ERROR);
+    }
+
+    #[test]
+    fn test_lint_rules_filter_matches_cli() {
+        let source = "fn f() {\n    let unused = 1\n    2\n}";
+        let options = LintOptions {
+            rules: Some("style".to_string()),
+            ..LintOptions::default()
+        };
+        let expected: Vec<String> = ruchy::cli::shared::lint_source(source, Some("style"), false)
+            .expect("should lint")
+            .into_iter()
+            .map(|issue| issue.rule)
+            .collect();
+        let codes: Vec<String> = collect_lints(source, &options)
+            .into_iter()
+            .map(|d| d.code)
+            .collect();
+        assert_eq!(codes, expected);
+        assert!(codes.iter().all(|code| !code.starts_with("unused")));
+    }
+
+    #[test]
+    fn test_format_source_with_defaults() {
+        let formatted =
+            format_source("let x=1+2", FormatOptions::default()).expect("should format");
+        assert!(formatted.contains("1 + 2"));
+    }
+
+    #[test]
+    fn test_format_options_override_defaults() {
+        let config = FormatOptions {
+            indent_width: Some(2),
+            use_tabs: Some(true),
+            ..FormatOptions::default()
+        }
+        .into_config();
+        assert_eq!(config.indent_width, 2);
+        assert!(config.use_tabs);
+        assert_eq!(config.max_line_length, FormatterConfig::default().max_line_length);
+    }
+
+    #[test]
+    fn test_format_source_reports_parse_error() {
+        let err = format_source("let x = ", FormatOptions::default()).expect_err("invalid");
+        assert_eq!(err.code, PARSE_ERROR);
+    }
+
+    #[test]
+    fn test_query_source_finds_identifiers_with_spans() {
+        let source = "let total = price + tax";
+        let matches = query_source(source, "$..Identifier").expect("valid query");
+        let names: Vec<_> = matches.iter().filter_map(|m| m.node.as_str()).collect();
+        assert_eq!(names, vec!["price", "tax"]);
+        let price = &matches[0];
+        assert_eq!(price.start.map(|s| &source[s..s + 5]), Some("price"));
+    }
+
+    #[test]
+    fn test_query_source_rejects_bad_path() {
+        let err = query_source("1 + 2", "kind").expect_err("missing $");
+        assert_eq!(err.code, QUERY_ERROR);
+    }
+
+    #[test]
+    fn test_run_source_captures_stdout_and_value() {
+        let result = run_source("println(\"hi\")\nlet x = 40\nx + 2", &RunOptions::default());
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.stdout, "hi\n");
+        assert_eq!(result.value.as_deref(), Some("42"));
+        assert_eq!(result.value_type.as_deref(), Some("integer"));
+    }
+
+    #[test]
+    fn test_run_source_stops_at_step_limit() {
+        let options = RunOptions {
+            max_steps: Some(100),
+        };
+        let result = run_source("let mut n = 0\nwhile true { n = n + 1 }", &options);
+        assert!(!result.success);
+        assert_eq!(result.steps, 100);
+        let error = result.error.expect("step limit should be reported");
+        assert_eq!(error.code, STEP_LIMIT_ERROR);
+        assert!(error.message.contains(STEP_LIMIT_MESSAGE));
+    }
+
+    #[test]
+    fn test_run_source_reports_errors() {
+        let parse = run_source("let x = ", &RunOptions::default());
+        assert_eq!(parse.error.map(|e| e.code).as_deref(), Some(PARSE_ERROR));
+
+        let runtime = run_source("undefined_name + 1", &RunOptions::default());
+        assert!(!runtime.success);
+        assert_eq!(runtime.value, None);
+        assert_eq!(
+            runtime.error.map(|e| e.code).as_deref(),
+            Some(RUNTIME_ERROR)
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_compile_async_resolves_and_cancels() {
+        use wasm_bindgen_futures::JsFuture;
+
+        let mut compiler = RuchyCompiler::new();
+        let rust_code = JsFuture::from(compiler.compile_async("fun f() { 1 }".to_string(), None))
+            .await
+            .expect("should compile");
+        assert!(rust_code
+            .as_string()
+            .is_some_and(|code| code.contains("fn f")));
+
+        let pending = compiler.compile_async("fun f() { 1 }".to_string(), None);
+        compiler.cancel();
+        assert!(JsFuture::from(pending).await.is_err());
+
+        let token = CancellationToken::new();
+        let pending = compiler.compile_async_with_token("1 + 1".to_string(), &token, None);
+        token.cancel();
+        assert!(JsFuture::from(pending).await.is_err());
+        assert!(
+            JsFuture::from(compiler.compile_async("1 + 1".to_string(), None))
+                .await
+                .is_ok()
+        );
+    }
+
     #[wasm_bindgen_test]
     fn test_version() {
         let compiler = RuchyCompiler::new();
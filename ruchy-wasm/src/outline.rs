@@ -0,0 +1,354 @@
+//! Hover lookups and document outlines over the serialized Ruchy AST
+//!
+//! Parser spans usually cover a node's first token only (`fun`, `let`, an
+//! operator), so both lookups use a node's extent: from the start of its own
+//! span to the furthest end of any expression nested inside it. Like
+//! `ast_query`, they read the JSON form of the AST, in which expressions are
+//! the objects carrying `kind`, `span` and `attributes`.
+
+use serde::Serialize;
+use serde_json::Value as Json;
+
+/// The innermost expression at an offset
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NodeInfo {
+    /// Expression kind, e.g. `Identifier` or `MethodCall`
+    pub kind: String,
+    /// Name of the identifier, declaration, field or method, when there is one
+    pub name: Option<String>,
+    /// Byte offsets of the node's extent
+    pub start: usize,
+    pub end: usize,
+    /// Kinds of the enclosing expressions, outermost first
+    pub ancestors: Vec<String>,
+}
+
+/// What an outline entry declares
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SymbolKind {
+    Function,
+    Method,
+    Constructor,
+    Variable,
+    Struct,
+    Class,
+    Enum,
+    Trait,
+    Impl,
+    Actor,
+    Module,
+    TypeAlias,
+    Effect,
+}
+
+/// An entry of the document outline
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Symbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    /// Byte offsets of the declaration
+    pub start: usize,
+    pub end: usize,
+    /// Members of types, and declarations nested in functions and modules
+    pub children: Vec<Symbol>,
+}
+
+/// The innermost expression of `ast` whose extent contains `offset`
+pub fn node_at(ast: &Json, source: &str, offset: usize) -> Option<NodeInfo> {
+    let contains = |(start, end): (usize, usize)| start <= offset && offset < end;
+    let mut node = ast;
+    let mut range = extent(ast).filter(|range| contains(*range))?;
+    let mut ancestors = Vec::new();
+    loop {
+        let innermost = child_exprs(node)
+            .into_iter()
+            .filter(|child| !is_synthetic(child, source))
+            .filter_map(|child| Some((child, extent(child)?)))
+            .filter(|(_, range)| contains(*range))
+            .min_by_key(|(_, (start, end))| end - start);
+        let Some((child, child_range)) = innermost else {
+            break;
+        };
+        ancestors.push(kind_name(node));
+        node = child;
+        range = child_range;
+    }
+    Some(NodeInfo {
+        kind: kind_name(node),
+        name: name_of(node),
+        start: range.0,
+        end: range.1,
+        ancestors,
+    })
+}
+
+/// Outline of the declarations in `ast`, in source order
+pub fn symbols(ast: &Json) -> Vec<Symbol> {
+    let mut out = Vec::new();
+    collect_symbols(ast, true, &mut out);
+    out
+}
+
+/// Add the declarations in `expr` to `out`; variables only when `top_level`
+fn collect_symbols(expr: &Json, top_level: bool, out: &mut Vec<Symbol>) {
+    let Some((tag, Some(payload))) = kind_of(expr) else {
+        return;
+    };
+    let name = || text(payload, "name").unwrap_or_default().to_string();
+    let keyword = span_of(expr).unwrap_or_default();
+    match tag {
+        "Block" => {
+            for item in payload.as_array().into_iter().flatten() {
+                collect_symbols(item, top_level, out);
+            }
+        }
+        "Let" | "LetPattern" => {
+            let value = payload.get("value").filter(|_| top_level && tag == "Let");
+            if let Some(value) = value {
+                let end = extent(value).map_or(keyword.1, |(_, end)| end);
+                out.push(symbol(
+                    name(),
+                    SymbolKind::Variable,
+                    (keyword.0, end),
+                    Vec::new(),
+                ));
+            }
+            if let Some(body) = payload.get("body") {
+                collect_symbols(body, top_level, out);
+            }
+        }
+        "Export" => {
+            if let Some(inner) = payload.get("expr") {
+                collect_symbols(inner, top_level, out);
+            }
+        }
+        "Function" | "Module" => {
+            let mut children = Vec::new();
+            if let Some(body) = payload.get("body") {
+                collect_symbols(body, tag == "Module", &mut children);
+            }
+            let kind = if tag == "Module" {
+                SymbolKind::Module
+            } else {
+                SymbolKind::Function
+            };
+            out.push(declaration(expr, name(), kind, children));
+        }
+        "Struct" => {
+            let methods = members(payload, "methods", SymbolKind::Method, keyword);
+            out.push(declaration(expr, name(), SymbolKind::Struct, methods));
+        }
+        "Class" => {
+            let mut children = members(payload, "constructors", SymbolKind::Constructor, keyword);
+            children.extend(members(payload, "methods", SymbolKind::Method, keyword));
+            out.push(declaration(expr, name(), SymbolKind::Class, children));
+        }
+        "Trait" => {
+            let methods = members(payload, "methods", SymbolKind::Method, keyword);
+            out.push(declaration(expr, name(), SymbolKind::Trait, methods));
+        }
+        "Impl" => {
+            let for_type = text(payload, "for_type").unwrap_or_default();
+            let name = match text(payload, "trait_name") {
+                Some(trait_name) => format!("impl {trait_name} for {for_type}"),
+                None => format!("impl {for_type}"),
+            };
+            let methods = members(payload, "methods", SymbolKind::Method, keyword);
+            out.push(declaration(expr, name, SymbolKind::Impl, methods));
+        }
+        "Actor" => {
+            let handlers = members(payload, "handlers", SymbolKind::Method, keyword);
+            out.push(declaration(expr, name(), SymbolKind::Actor, handlers));
+        }
+        "TupleStruct" => out.push(declaration(expr, name(), SymbolKind::Struct, Vec::new())),
+        "Enum" => out.push(declaration(expr, name(), SymbolKind::Enum, Vec::new())),
+        "TypeAlias" => out.push(declaration(expr, name(), SymbolKind::TypeAlias, Vec::new())),
+        "Effect" => out.push(declaration(expr, name(), SymbolKind::Effect, Vec::new())),
+        "ModuleDeclaration" => {
+            out.push(declaration(expr, name(), SymbolKind::Module, Vec::new()));
+        }
+        _ => {}
+    }
+}
+
+fn symbol(name: String, kind: SymbolKind, range: (usize, usize), children: Vec<Symbol>) -> Symbol {
+    Symbol {
+        name,
+        kind,
+        start: range.0,
+        end: range.1,
+        children,
+    }
+}
+
+/// Symbol spanning the whole extent of `expr`
+fn declaration(expr: &Json, name: String, kind: SymbolKind, children: Vec<Symbol>) -> Symbol {
+    symbol(name, kind, extent(expr).unwrap_or_default(), children)
+}
+
+/// Methods, constructors or handlers listed under `key`, spanning their bodies
+///
+/// Members without a body (trait method signatures) take `fallback`, the
+/// span of the declaring keyword. Actor handlers are named after their
+/// message type and unnamed constructors are called `new`.
+fn members(payload: &Json, key: &str, kind: SymbolKind, fallback: (usize, usize)) -> Vec<Symbol> {
+    payload
+        .get(key)
+        .and_then(Json::as_array)
+        .into_iter()
+        .flatten()
+        .map(|member| {
+            let name = text(member, "name")
+                .or_else(|| text(member, "message_type"))
+                .unwrap_or("new")
+                .to_string();
+            let range = member.get("body").and_then(extent).unwrap_or(fallback);
+            symbol(name, kind, range, Vec::new())
+        })
+        .collect()
+}
+
+fn text<'a>(object: &'a Json, key: &str) -> Option<&'a str> {
+    object.get(key)?.as_str()
+}
+
+fn is_expr(node: &Json) -> bool {
+    node.get("kind").is_some() && node.get("span").is_some() && node.get("attributes").is_some()
+}
+
+/// Variant name and payload of an expression's `kind`
+fn kind_of(expr: &Json) -> Option<(&str, Option<&Json>)> {
+    match expr.get("kind")? {
+        Json::String(tag) => Some((tag, None)),
+        Json::Object(map) => map
+            .iter()
+            .next()
+            .map(|(tag, payload)| (tag.as_str(), Some(payload))),
+        _ => None,
+    }
+}
+
+fn kind_name(expr: &Json) -> String {
+    kind_of(expr).map_or_else(String::new, |(tag, _)| tag.to_string())
+}
+
+fn name_of(expr: &Json) -> Option<String> {
+    let (tag, payload) = kind_of(expr)?;
+    let payload = payload?;
+    let name = match tag {
+        "Identifier" => payload.as_str(),
+        "MethodCall" | "OptionalMethodCall" => text(payload, "method"),
+        "FieldAccess" | "OptionalFieldAccess" => text(payload, "field"),
+        "Impl" => text(payload, "for_type"),
+        _ => text(payload, "name"),
+    };
+    name.map(str::to_string)
+}
+
+/// Whether `expr` is the unit body the parser gives a `let` without `in`
+///
+/// That body reuses the span of the let's value, so it would shadow the value
+/// in lookups; a real `()` literal spans the text `()`.
+fn is_synthetic(expr: &Json, source: &str) -> bool {
+    let unit = matches!(kind_of(expr), Some(("Literal", Some(literal))) if *literal == "Unit");
+    unit && span_of(expr).and_then(|(start, end)| source.get(start..end)) != Some("()")
+}
+
+/// Non-empty span of a node
+fn span_of(node: &Json) -> Option<(usize, usize)> {
+    let span = node.get("span")?;
+    let start = usize::try_from(span.get("start")?.as_u64()?).ok()?;
+    let end = usize::try_from(span.get("end")?.as_u64()?).ok()?;
+    (start < end).then_some((start, end))
+}
+
+/// Span of `expr` widened to cover every expression nested inside it
+fn extent(expr: &Json) -> Option<(usize, usize)> {
+    child_exprs(expr)
+        .into_iter()
+        .filter_map(extent)
+        .chain(span_of(expr))
+        .reduce(|(start, end), (other_start, other_end)| {
+            (start.min(other_start), end.max(other_end))
+        })
+}
+
+/// The nearest expressions nested in `expr`'s kind, in serialization order
+fn child_exprs(expr: &Json) -> Vec<&Json> {
+    let mut out = Vec::new();
+    if let Some(kind) = expr.get("kind") {
+        collect_exprs(kind, &mut out);
+    }
+    out
+}
+
+fn collect_exprs<'a>(node: &'a Json, out: &mut Vec<&'a Json>) {
+    if is_expr(node) {
+        out.push(node);
+        return;
+    }
+    match node {
+        Json::Object(map) => map.values().for_each(|value| collect_exprs(value, out)),
+        Json::Array(items) => items.iter().for_each(|item| collect_exprs(item, out)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ruchy::frontend::parser::Parser;
+
+    fn ast(source: &str) -> Json {
+        let ast = Parser::new(source).parse().expect("should parse");
+        serde_json::to_value(&ast).expect("should serialize")
+    }
+
+    #[test]
+    fn test_node_at_finds_innermost_expression() {
+        let source = "let total = add(1, 2)";
+        let info = node_at(&ast(source), source, 13).expect("inside the program");
+        assert_eq!(info.kind, "Identifier");
+        assert_eq!(info.name.as_deref(), Some("add"));
+        assert_eq!(&source[info.start..info.end], "add");
+        assert!(
+            info.ancestors
+                .ends_with(&["Let".to_string(), "Call".to_string()]),
+            "{:?}",
+            info.ancestors
+        );
+    }
+
+    #[test]
+    fn test_node_at_outside_program_is_none() {
+        let source = "let x = 1";
+        assert_eq!(node_at(&ast(source), source, 100), None);
+    }
+
+    #[test]
+    fn test_symbols_outline_declarations() {
+        let source = "fun add(a, b) { a + b }\n\
+                      fun main() {\n  let x = 1\n  fun inner() { x }\n  inner()\n}\n\
+                      struct Point { x: i32, y: i32 }\n\
+                      impl Point { fun norm(&self) { self.x } }\n\
+                      let limit = 10";
+        let outline = symbols(&ast(source));
+        let entries: Vec<_> = outline.iter().map(|s| (s.name.as_str(), s.kind)).collect();
+        assert_eq!(
+            entries,
+            vec![
+                ("add", SymbolKind::Function),
+                ("main", SymbolKind::Function),
+                ("Point", SymbolKind::Struct),
+                ("impl Point", SymbolKind::Impl),
+                ("limit", SymbolKind::Variable),
+            ]
+        );
+        assert_eq!(outline[1].children.len(), 1);
+        assert_eq!(outline[1].children[0].name, "inner");
+        assert_eq!(outline[3].children[0].name, "norm");
+        let limit = &outline[4];
+        assert_eq!(&source[limit.start..limit.end], "let limit = 10");
+    }
+}
@@ -0,0 +1,207 @@
+//! Content-addressed cache of transpiled Rust and compiled binaries
+//!
+//! `ruchy compile` keeps the artifacts of previous builds under
+//! `.ruchy/cache` in the working directory, and skips the transpiler and
+//! rustc when their inputs are unchanged:
+//!
+//! ```text
+//! .ruchy/cache/
+//!   rust/<key>.rs   transpiled Rust, keyed on the Ruchy source
+//!   bin/<key>       binaries, keyed on the Rust code and compile options
+//! ```
+//!
+//! Keys are SHA-256 digests that include the ruchy version, so a new
+//! compiler never picks up output of an old one. Entries are written to a
+//! temporary file and renamed into place, so an interrupted build leaves no
+//! partial artifact behind. `ruchy clean` removes the whole directory.
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Cache directory, relative to the working directory
+pub const CACHE_DIR: &str = ".ruchy/cache";
+
+/// Files and bytes held by a cache
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Number of cached artifacts
+    pub files: usize,
+    /// Their total size
+    pub bytes: u64,
+}
+
+/// Build cache rooted at a directory
+#[derive(Debug, Clone)]
+pub struct BuildCache {
+    root: PathBuf,
+}
+
+impl BuildCache {
+    /// Cache stored in `root`
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Cache stored in [`CACHE_DIR`] under `dir`
+    pub fn in_dir(dir: &Path) -> Self {
+        Self::new(dir.join(CACHE_DIR))
+    }
+
+    /// Directory holding the cache
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Key of the artifact built from `inputs` by this version of ruchy
+    ///
+    /// Inputs are length-prefixed, so moving bytes from one input to the
+    /// next changes the key.
+    pub fn key(inputs: &[&[u8]]) -> String {
+        let mut hasher = Sha256::new();
+        let version = env!("CARGO_PKG_VERSION").as_bytes();
+        for input in std::iter::once(version).chain(inputs.iter().copied()) {
+            hasher.update((input.len() as u64).to_le_bytes());
+            hasher.update(input);
+        }
+        format!("{:x}", hasher.finalize())
+    }
+
+    fn rust_path(&self, key: &str) -> PathBuf {
+        self.root.join("rust").join(format!("{key}.rs"))
+    }
+
+    fn binary_path(&self, key: &str) -> PathBuf {
+        self.root.join("bin").join(key)
+    }
+
+    /// Transpiled Rust stored under `key`
+    pub fn rust(&self, key: &str) -> Option<String> {
+        fs::read_to_string(self.rust_path(key)).ok()
+    }
+
+    /// Store transpiled Rust under `key`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache directory cannot be written
+    pub fn store_rust(&self, key: &str, code: &str) -> Result<()> {
+        write_atomically(&self.rust_path(key), |file| file.write_all(code.as_bytes()))
+    }
+
+    /// Binary stored under `key`
+    pub fn binary(&self, key: &str) -> Option<PathBuf> {
+        let path = self.binary_path(key);
+        path.is_file().then_some(path)
+    }
+
+    /// Store a copy of `binary` under `key`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `binary` cannot be read or the cache directory
+    /// cannot be written
+    pub fn store_binary(&self, key: &str, binary: &Path) -> Result<()> {
+        let mut source = fs::File::open(binary)
+            .with_context(|| format!("Failed to read {}", binary.display()))?;
+        let permissions = source.metadata()?.permissions();
+        let path = self.binary_path(key);
+        write_atomically(&path, |file| {
+            std::io::copy(&mut source, file)?;
+            file.set_permissions(permissions)
+        })
+    }
+
+    /// Files and bytes currently cached
+    pub fn stats(&self) -> CacheStats {
+        let mut stats = CacheStats::default();
+        for dir in ["rust", "bin"] {
+            let Ok(entries) = fs::read_dir(self.root.join(dir)) else {
+                continue;
+            };
+            for metadata in entries.flatten().filter_map(|entry| entry.metadata().ok()) {
+                if metadata.is_file() {
+                    stats.files += 1;
+                    stats.bytes += metadata.len();
+                }
+            }
+        }
+        stats
+    }
+
+    /// Remove every cached artifact, returning what was removed
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache directory cannot be removed
+    pub fn clean(&self) -> Result<CacheStats> {
+        let stats = self.stats();
+        if self.root.exists() {
+            fs::remove_dir_all(&self.root)
+                .with_context(|| format!("Failed to remove {}", self.root.display()))?;
+        }
+        Ok(stats)
+    }
+}
+
+/// Write `path` through a temporary file in its directory, then rename it
+fn write_atomically(
+    path: &Path,
+    write: impl FnOnce(&mut fs::File) -> std::io::Result<()>,
+) -> Result<()> {
+    let dir = path.parent().expect("cache paths have a parent");
+    fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    let mut temp = tempfile::NamedTempFile::new_in(dir)?;
+    write(temp.as_file_mut())?;
+    temp.persist(path)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_keys_depend_on_every_input() {
+        let key = BuildCache::key(&[b"ab", b"c"]);
+        assert_eq!(key, BuildCache::key(&[b"ab", b"c"]));
+        assert_eq!(key.len(), 64);
+        assert_ne!(key, BuildCache::key(&[b"a", b"bc"]));
+        assert_ne!(key, BuildCache::key(&[b"ab", b"d"]));
+    }
+
+    #[test]
+    fn test_store_lookup_and_clean() {
+        let dir = TempDir::new().unwrap();
+        let cache = BuildCache::in_dir(dir.path());
+        let key = BuildCache::key(&[b"fun main() {}"]);
+        assert_eq!(cache.rust(&key), None);
+        assert_eq!(cache.binary(&key), None);
+
+        cache.store_rust(&key, "fn main() {}").unwrap();
+        assert_eq!(cache.rust(&key).as_deref(), Some("fn main() {}"));
+
+        let built = dir.path().join("a.out");
+        fs::write(&built, b"\x7fELF").unwrap();
+        cache.store_binary(&key, &built).unwrap();
+        let cached = cache.binary(&key).unwrap();
+        assert!(cached.starts_with(dir.path().join(CACHE_DIR)));
+        assert_eq!(fs::read(cached).unwrap(), b"\x7fELF");
+
+        let stats = cache.stats();
+        assert_eq!(
+            stats,
+            CacheStats {
+                files: 2,
+                bytes: 16
+            }
+        );
+        assert_eq!(cache.clean().unwrap(), stats);
+        assert!(!cache.root().exists());
+        assert_eq!(cache.stats(), CacheStats::default());
+        assert_eq!(cache.clean().unwrap(), CacheStats::default());
+    }
+}
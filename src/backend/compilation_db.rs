@@ -0,0 +1,341 @@
+//! Compilation database for external build systems
+//!
+//! `ruchy emit-compilationdb` describes how a program is built, one entry per
+//! Ruchy module, so Bazel, Buck or a distributed cache can schedule and cache
+//! Ruchy builds without running the CLI on every file:
+//!
+//! ```json
+//! [
+//!   {
+//!     "module": "crate",
+//!     "source": "/work/hello/src/main.ruchy",
+//!     "source_key": "<sha-256 hex>",
+//!     "imports": [
+//!       { "module": "greet", "source": "/work/hello/src/greet.ruchy" }
+//!     ],
+//!     "artifact": "/work/hello/target/ruchy/hello/src/main.rs",
+//!     "directory": "/work/hello",
+//!     "arguments": ["ruchy", "build"],
+//!     "flags": { "edition": "2021", "dependencies": ["rand"] }
+//!   }
+//! ]
+//! ```
+//!
+//! The transpiler inlines every module into one Rust file, so all entries of
+//! a program share an `artifact` and `module` is the module's path inside
+//! it. `source_key` is the [`BuildCache`] key of the source text, and
+//! `arguments`, run in `directory`, regenerate the artifact. Modules are
+//! found the way the build finds them: `mod name;` declarations and `use`
+//! imports of files, searched next to the entry point and in the project's
+//! `src/`, `lib/`, `modules/` and `vendor/` directories.
+use crate::backend::build_cache::BuildCache;
+use crate::backend::compiler::module_resolver_for;
+use crate::backend::module_resolver::ModuleResolver;
+use crate::backend::project::Project;
+use crate::frontend::ast::{Expr, ExprKind};
+use crate::utils::common_patterns::ResultContextExt;
+use crate::Parser;
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+use std::collections::{HashSet, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Module path of the entry point
+pub const ROOT_MODULE: &str = "crate";
+
+/// How one Ruchy module is built
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ModuleEntry {
+    /// Path of the module in the generated Rust, [`ROOT_MODULE`] for the
+    /// entry point
+    pub module: String,
+    /// Ruchy source file
+    pub source: PathBuf,
+    /// Build cache key of the source text
+    pub source_key: String,
+    /// Modules this one pulls in with `mod name;` or a file `use`
+    pub imports: Vec<ModuleImport>,
+    /// Generated Rust file holding the module
+    pub artifact: PathBuf,
+    /// Working directory of `arguments`
+    pub directory: PathBuf,
+    /// Command that regenerates `artifact`
+    pub arguments: Vec<String>,
+    /// Code generation settings
+    pub flags: CodegenFlags,
+}
+
+/// A module pulled in by another
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ModuleImport {
+    /// Path of the imported module in the generated Rust
+    pub module: String,
+    /// Its source file
+    pub source: PathBuf,
+}
+
+/// Settings the generated Rust is built with
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CodegenFlags {
+    /// Rust edition
+    pub edition: String,
+    /// Crates from the manifest's `[dependencies]`
+    pub dependencies: Vec<String>,
+}
+
+/// Where a program's modules end up and how
+struct Target {
+    artifact: PathBuf,
+    directory: PathBuf,
+    arguments: Vec<String>,
+    flags: CodegenFlags,
+}
+
+/// Database of the `Ruchy.toml` project in `root`, as built by `ruchy build`
+///
+/// # Errors
+///
+/// Returns an error if the manifest is missing or invalid, or a module
+/// cannot be found, read or parsed
+pub fn for_project(root: &Path) -> Result<Vec<ModuleEntry>> {
+    let root = fs::canonicalize(root).file_context("resolve", root)?;
+    let project = Project::load(&root)?;
+    let manifest = project.manifest();
+    let main = project.main_path();
+    if !main.is_file() {
+        bail!("Entry point {} not found", main.display());
+    }
+    let target = Target {
+        artifact: project.package_dir().join("src").join("main.rs"),
+        directory: root,
+        arguments: vec!["ruchy".to_string(), "build".to_string()],
+        flags: CodegenFlags {
+            edition: manifest.package.edition.clone(),
+            dependencies: manifest.dependencies.keys().cloned().collect(),
+        },
+    };
+    collect(&main, &target)
+}
+
+/// Database of the program whose entry point is `path`, as built by
+/// `ruchy transpile path -o path.rs`
+///
+/// # Errors
+///
+/// Returns an error if a module cannot be found, read or parsed
+pub fn for_file(path: &Path) -> Result<Vec<ModuleEntry>> {
+    let source = fs::canonicalize(path).file_context("resolve", path)?;
+    let artifact = source.with_extension("rs");
+    let arguments = vec![
+        "ruchy".to_string(),
+        "transpile".to_string(),
+        source.display().to_string(),
+        "-o".to_string(),
+        artifact.display().to_string(),
+    ];
+    let target = Target {
+        artifact,
+        directory: std::env::current_dir()?,
+        arguments,
+        flags: CodegenFlags {
+            edition: "2021".to_string(),
+            dependencies: Vec::new(),
+        },
+    };
+    collect(&source, &target)
+}
+
+/// Entries of `entry` and every module it reaches, breadth first
+fn collect(entry: &Path, target: &Target) -> Result<Vec<ModuleEntry>> {
+    let resolver = module_resolver_for(entry);
+    let mut seen = HashSet::from([entry.to_path_buf()]);
+    let mut queue = VecDeque::from([(ROOT_MODULE.to_string(), entry.to_path_buf())]);
+    let mut entries = Vec::new();
+    while let Some((module, source)) = queue.pop_front() {
+        let text = fs::read_to_string(&source).file_context("read", &source)?;
+        let ast = Parser::new(&text)
+            .parse()
+            .with_context(|| format!("Failed to parse {}", source.display()))?;
+        let mut references = Vec::new();
+        module_references(&ast, "", &resolver, &mut references);
+
+        let mut imports = Vec::new();
+        for reference in references {
+            let file = match resolver.module_loader.resolve_module_path(&reference.name) {
+                Ok(file) => fs::canonicalize(&file).file_context("resolve", &file)?,
+                // `use name;` of something that is not a file names a crate
+                Err(_) if !reference.declared => continue,
+                Err(e) => {
+                    return Err(e.context(format!(
+                        "Module `{}` declared in {}",
+                        reference.name,
+                        source.display()
+                    )))
+                }
+            };
+            let path = if module == ROOT_MODULE {
+                reference.path
+            } else {
+                format!("{module}::{}", reference.path)
+            };
+            if seen.insert(file.clone()) {
+                queue.push_back((path.clone(), file.clone()));
+            }
+            imports.push(ModuleImport {
+                module: path,
+                source: file,
+            });
+        }
+
+        entries.push(ModuleEntry {
+            module,
+            source_key: BuildCache::key(&[text.as_bytes()]),
+            source,
+            imports,
+            artifact: target.artifact.clone(),
+            directory: target.directory.clone(),
+            arguments: target.arguments.clone(),
+            flags: target.flags.clone(),
+        });
+    }
+    Ok(entries)
+}
+
+/// A module file named in source
+struct Reference {
+    /// Module path relative to the referencing file
+    path: String,
+    /// Name the file is looked up by
+    name: String,
+    /// `mod name;`, which must resolve, rather than `use name`
+    declared: bool,
+}
+
+/// Collect the file modules `expr` pulls in, inside inline module `prefix`
+fn module_references(
+    expr: &Expr,
+    prefix: &str,
+    resolver: &ModuleResolver,
+    references: &mut Vec<Reference>,
+) {
+    let qualify = |name: &str| {
+        if prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{prefix}::{name}")
+        }
+    };
+    match &expr.kind {
+        ExprKind::ModuleDeclaration { name } => references.push(Reference {
+            path: qualify(name),
+            name: name.clone(),
+            declared: true,
+        }),
+        ExprKind::Import { module, .. }
+        | ExprKind::ImportAll { module, .. }
+        | ExprKind::ImportDefault { module, .. }
+        | ExprKind::ReExport { module, .. }
+            if resolver.is_file_import(module) =>
+        {
+            references.push(Reference {
+                path: qualify(module),
+                name: module.clone(),
+                declared: false,
+            });
+        }
+        ExprKind::Module { name, body } => {
+            module_references(body, &qualify(name), resolver, references);
+        }
+        ExprKind::Block(exprs) => {
+            for expr in exprs {
+                module_references(expr, prefix, resolver, references);
+            }
+        }
+        ExprKind::Function { body, .. } => module_references(body, prefix, resolver, references),
+        ExprKind::Let { value, body, .. } => {
+            module_references(value, prefix, resolver, references);
+            module_references(body, prefix, resolver, references);
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::script::MANIFEST_FILE;
+    use tempfile::TempDir;
+
+    fn project(files: &[(&str, &str)]) -> TempDir {
+        let dir = TempDir::new().unwrap();
+        for (name, contents) in files {
+            let path = dir.path().join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+        dir
+    }
+
+    const MANIFEST: &str = "[package]\nname = \"greeter\"\nversion = \"0.1.0\"\nedition = \"2024\"\n\n[dependencies]\nrand = \"0.8\"\n";
+
+    #[test]
+    fn test_project_lists_every_module_once() {
+        let dir = project(&[
+            (MANIFEST_FILE, MANIFEST),
+            (
+                "src/main.ruchy",
+                "mod greet;\nuse util;\nuse std::collections;\n\nfun main() {\n    println(greet::hello())\n}\n",
+            ),
+            ("src/greet.ruchy", "mod util;\n\npub fun hello() -> String {\n    \"hi\"\n}\n"),
+            ("src/util.ruchy", "pub fun twice(x: i64) -> i64 {\n    x * 2\n}\n"),
+        ]);
+        let entries = for_project(dir.path()).unwrap();
+        let root = fs::canonicalize(dir.path()).unwrap();
+        let modules: Vec<_> = entries.iter().map(|e| e.module.as_str()).collect();
+        assert_eq!(modules, ["crate", "greet", "util"]);
+
+        let main = &entries[0];
+        assert_eq!(main.source, root.join("src/main.ruchy"));
+        let imports: Vec<_> = main.imports.iter().map(|i| i.module.as_str()).collect();
+        assert_eq!(imports, ["greet", "util"]);
+        assert_eq!(entries[1].imports[0].module, "greet::util");
+        assert_eq!(entries[1].imports[0].source, root.join("src/util.ruchy"));
+
+        for entry in &entries {
+            assert_eq!(
+                entry.artifact,
+                root.join("target/ruchy/greeter/src/main.rs")
+            );
+            assert_eq!(entry.directory, root);
+            assert_eq!(entry.arguments, ["ruchy", "build"]);
+            assert_eq!(entry.flags.edition, "2024");
+            assert_eq!(entry.flags.dependencies, ["rand"]);
+        }
+        assert_ne!(entries[1].source_key, entries[2].source_key);
+    }
+
+    #[test]
+    fn test_source_key_follows_content() {
+        let dir = project(&[("main.ruchy", "println(1)\n")]);
+        let file = dir.path().join("main.ruchy");
+        let before = for_file(&file).unwrap();
+        assert_eq!(before.len(), 1);
+        assert_eq!(before[0].arguments[1], "transpile");
+        assert!(before[0].artifact.ends_with("main.rs"));
+        assert_eq!(for_file(&file).unwrap(), before);
+
+        fs::write(&file, "println(2)\n").unwrap();
+        assert_ne!(for_file(&file).unwrap()[0].source_key, before[0].source_key);
+    }
+
+    #[test]
+    fn test_missing_declared_module_is_an_error() {
+        let dir = project(&[("main.ruchy", "mod missing;\nuse maybe_a_crate;\n")]);
+        let err = for_file(&dir.path().join("main.ruchy")).unwrap_err();
+        assert!(
+            format!("{err:#}").contains("Module `missing` declared in"),
+            "{err:#}"
+        );
+    }
+}
@@ -2,10 +2,13 @@
 //!
 //! This module provides functionality to compile Ruchy code to standalone binaries
 //! via Rust compilation toolchain (rustc).
+use crate::backend::build_cache::BuildCache;
+use crate::backend::transpiler::{Optimization, TranspilerOptions};
 use crate::utils::common_patterns::ResultContextExt;
 use crate::{Parser, Transpiler};
 use anyhow::{bail, Context, Result};
 use proc_macro2::TokenStream;
+use std::collections::BTreeSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -28,6 +31,9 @@ pub struct CompileOptions {
     /// Model files to embed in the binary (issue #169)
     /// Each path will be embedded via `include_bytes!` for zero-copy loading
     pub embed_models: Vec<PathBuf>,
+    /// Propagate constants across functions and drop unreachable functions
+    /// before transpiling (see [`crate::backend::whole_program`])
+    pub whole_program: bool,
 }
 impl Default for CompileOptions {
     fn default() -> Self {
@@ -39,6 +45,7 @@ impl Default for CompileOptions {
             target: None,
             rustc_flags: Vec::new(),
             embed_models: Vec::new(),
+            whole_program: false,
         }
     }
 }
@@ -58,6 +65,7 @@ impl Default for CompileOptions {
 ///     target: None,
 ///     rustc_flags: Vec::new(),
 ///     embed_models: Vec::new(),
+///     whole_program: false,
 /// };
 ///
 /// let result = compile_to_binary(&PathBuf::from("program.ruchy"), &options);
@@ -131,52 +139,270 @@ pub fn compile_source_to_binary_with_context(
     // Parse to check for DataFrame, JSON, and HTTP usage
     let mut parser = Parser::new(source);
     let ast = parser.parse().parse_context("Ruchy source")?;
-    let needs_polars = uses_dataframes(&ast);
-    let needs_json = uses_json(&ast);
-    let needs_http = uses_http(&ast);
+    let needs_crates = uses_dataframes(&ast) || uses_json(&ast) || uses_http(&ast);
+    let ast = resolve_module_declarations(ast, source_path)?;
+    reject_extern_crates(&ast)?;
+    let ast = optimize_ast(ast, options);
+    let rust_code = transpile_with_context(ast, source_path, options.whole_program)?;
+    compile_rust(&rust_code, needs_crates, options)
+}
 
-    // ISSUE-106: Resolve module declarations (mod name;) ONLY if AST contains them
-    // This prevents double-resolution with transpiler's existing import handling (ISSUE-103)
-    let resolved_ast = if let Some(path) = source_path {
-        if contains_module_declaration(&ast) {
-            use crate::backend::module_resolver::ModuleResolver;
-            let mut resolver = ModuleResolver::new();
-            // Add the source file's directory to the module search path
-            if let Some(parent_dir) = path.parent() {
-                resolver.add_search_path(parent_dir);
-
-                // MODULE-RESOLUTION-001: Also search in standard project layout directories
-                // If compiling project/bin/main.ruchy, also search project/src/, project/lib/
-                if let Some(project_root) = parent_dir.parent() {
-                    resolver.add_search_path(project_root.join("src"));
-                    resolver.add_search_path(project_root.join("lib"));
-                    resolver.add_search_path(project_root.join("modules"));
-                }
+/// What [`compile_to_binary_cached`] took from the cache
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedCompile {
+    /// Output binary path
+    pub output: PathBuf,
+    /// The transpiled Rust came from the cache
+    pub rust_reused: bool,
+    /// The binary came from the cache; rustc did not run
+    pub binary_reused: bool,
+}
+
+/// Compile a Ruchy source file like [`compile_to_binary`], reusing earlier
+/// artifacts from `cache`
+///
+/// Transpiled Rust is reused when the source is unchanged and reads no other
+/// files (`mod` declarations and imports are resolved from disk, so their
+/// sources would escape the key). Binaries are reused when the Rust code,
+/// the options and the rustc version match. Builds that embed models always
+/// run rustc. Failing to write the cache does not fail the build.
+///
+/// # Errors
+///
+/// Returns an error if the source cannot be read, parsed, transpiled or
+/// compiled, or a cached binary cannot be copied to the output path
+pub fn compile_to_binary_cached(
+    source_path: &Path,
+    options: &CompileOptions,
+    cache: &BuildCache,
+) -> Result<CachedCompile> {
+    let source = fs::read_to_string(source_path).file_context("read", source_path)?;
+    let ast = Parser::new(&source).parse().parse_context("Ruchy source")?;
+    let needs_crates = uses_dataframes(&ast) || uses_json(&ast) || uses_http(&ast);
+
+    let rust_key = BuildCache::key(&[
+        b"rust",
+        source.as_bytes(),
+        source_path.to_string_lossy().as_bytes(),
+        &[
+            u8::from(options.whole_program),
+            u8::from(optimizes_ast(options)),
+        ],
+    ]);
+    let self_contained = !reads_other_files(&ast);
+    let cached_rust = cache.rust(&rust_key).filter(|_| self_contained);
+    let rust_reused = cached_rust.is_some();
+    let rust_code: TokenStream = match cached_rust {
+        Some(code) => code
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Corrupt cached Rust {rust_key}: {e}"))?,
+        None => {
+            let ast = resolve_module_declarations(ast, Some(source_path))?;
+            reject_extern_crates(&ast)?;
+            let ast = optimize_ast(ast, options);
+            let code = transpile_with_context(ast, Some(source_path), options.whole_program)?;
+            if self_contained {
+                let _ = cache.store_rust(&rust_key, &code.to_string());
             }
-            resolver
-                .resolve_imports(ast)
-                .compile_context("resolve module declarations")?
-        } else {
-            ast
+            code
         }
+    };
+
+    if !options.embed_models.is_empty() {
+        let output = compile_rust(&rust_code, needs_crates, options)?;
+        return Ok(CachedCompile {
+            output,
+            rust_reused,
+            binary_reused: false,
+        });
+    }
+    let rust = rust_code.to_string();
+    let rustc_version = get_rustc_version().unwrap_or_default();
+    let binary_key = BuildCache::key(&[
+        b"bin",
+        rust.as_bytes(),
+        options.opt_level.as_bytes(),
+        &[u8::from(options.strip), u8::from(options.static_link)],
+        options.target.as_deref().unwrap_or("").as_bytes(),
+        options.rustc_flags.join("\n").as_bytes(),
+        rustc_version.as_bytes(),
+    ]);
+    if let Some(binary) = cache.binary(&binary_key) {
+        fs::copy(&binary, &options.output)
+            .context("Failed to copy cached binary to output location")?;
+        return Ok(CachedCompile {
+            output: options.output.clone(),
+            rust_reused,
+            binary_reused: true,
+        });
+    }
+    let output = compile_rust(&rust_code, needs_crates, options)?;
+    let _ = cache.store_binary(&binary_key, &output);
+    Ok(CachedCompile {
+        output,
+        rust_reused,
+        binary_reused: false,
+    })
+}
+
+/// Whether `options` ask for [`crate::middleend::optimize::optimize`]: every
+/// `-O` level but `0`
+pub(crate) fn optimizes_ast(options: &CompileOptions) -> bool {
+    options.opt_level != "0"
+}
+
+/// Fold constants and remove dead branches unless compiling with `-O0` (complexity: 2)
+pub(crate) fn optimize_ast(
+    ast: crate::frontend::ast::Expr,
+    options: &CompileOptions,
+) -> crate::frontend::ast::Expr {
+    if optimizes_ast(options) {
+        crate::middleend::optimize(ast).0
     } else {
         ast
-    };
+    }
+}
 
-    // Transpile with file context for module resolution (ISSUE-103)
-    let mut transpiler = Transpiler::new();
-    let rust_code = transpiler
-        .transpile_to_program_with_context(&resolved_ast, source_path)
-        .compile_context("transpile to Rust")?;
+/// Resolve `mod` declarations and transpile to a Rust program (complexity: 1)
+pub(crate) fn transpile_with_context(
+    ast: crate::frontend::ast::Expr,
+    source_path: Option<&Path>,
+    whole_program: bool,
+) -> Result<TokenStream> {
+    let resolved_ast = resolve_module_declarations(ast, source_path)?;
 
-    if needs_polars || needs_json || needs_http {
-        // Use cargo build with Cargo.toml (for external crate access)
-        compile_with_cargo(&rust_code, options)
+    // Transpile with file context for module resolution (ISSUE-103)
+    let mut options = TranspilerOptions::builder().optimization(if whole_program {
+        Optimization::WholeProgram
     } else {
+        Optimization::Standard
+    });
+    if let Some(path) = source_path {
+        options = options.context_file(path);
+    }
+    Transpiler::with_options(options.build())
+        .transpile_program_tokens(&resolved_ast)
+        .compile_context("transpile to Rust")
+}
+
+/// Inline the files named by `mod name;` declarations (complexity: 5)
+///
+/// Modules are searched next to `source_path` and in the standard project
+/// directories. ASTs without declarations are returned unchanged, so
+/// resolving twice is harmless.
+pub(crate) fn resolve_module_declarations(
+    ast: crate::frontend::ast::Expr,
+    source_path: Option<&Path>,
+) -> Result<crate::frontend::ast::Expr> {
+    // ISSUE-106: Resolve module declarations (mod name;) ONLY if AST contains them
+    // This prevents double-resolution with transpiler's existing import handling (ISSUE-103)
+    let Some(path) = source_path.filter(|_| contains_module_declaration(&ast)) else {
+        return Ok(ast);
+    };
+    module_resolver_for(path)
+        .resolve_imports(ast)
+        .compile_context("resolve module declarations")
+}
+
+/// Resolver searching the directory of `source_path` and the standard
+/// project directories above it (complexity: 3)
+pub(crate) fn module_resolver_for(
+    source_path: &Path,
+) -> crate::backend::module_resolver::ModuleResolver {
+    let mut resolver = crate::backend::module_resolver::ModuleResolver::new();
+    // Add the source file's directory to the module search path
+    if let Some(parent_dir) = source_path.parent() {
+        resolver.add_search_path(parent_dir);
+
+        // MODULE-RESOLUTION-001: Also search in standard project layout directories
+        // If compiling project/bin/main.ruchy, also search project/src/, project/lib/
+        if let Some(project_root) = parent_dir.parent() {
+            resolver.add_project_search_paths(project_root);
+        }
+    }
+    resolver
+}
+
+/// Rust crates named by `extern "rust"` blocks, other than the standard
+/// library (complexity: 6)
+pub(crate) fn extern_crates(ast: &crate::frontend::ast::Expr) -> BTreeSet<String> {
+    use crate::backend::transpiler::extern_blocks::{is_rust_abi, BUILTIN_CRATES};
+    use crate::frontend::ast::ExprKind;
+
+    fn collect(expr: &crate::frontend::ast::Expr, crates: &mut BTreeSet<String>) {
+        match &expr.kind {
+            ExprKind::ExternBlock {
+                abi,
+                path: Some(path),
+                ..
+            } if is_rust_abi(abi) => {
+                let root = path.split("::").next().unwrap_or(path);
+                if !BUILTIN_CRATES.contains(&root) {
+                    crates.insert(root.to_string());
+                }
+            }
+            ExprKind::Block(exprs) => exprs.iter().for_each(|e| collect(e, crates)),
+            ExprKind::Module { body, .. } | ExprKind::Function { body, .. } => {
+                collect(body, crates);
+            }
+            _ => {}
+        }
+    }
+
+    let mut crates = BTreeSet::new();
+    collect(ast, &mut crates);
+    crates
+}
+
+/// Fail with a hint when a single-file build names crates it cannot link
+pub(crate) fn reject_extern_crates(ast: &crate::frontend::ast::Expr) -> Result<()> {
+    if let Some(name) = extern_crates(ast).first() {
+        bail!(
+            "extern \"rust\" crate `{name}` needs a Ruchy.toml project: \
+             list it under [dependencies] and run `ruchy build`"
+        );
+    }
+    Ok(())
+}
+
+/// Build transpiled Rust with rustc, or cargo when it needs crates (complexity: 4)
+fn compile_rust(
+    rust_code: &TokenStream,
+    needs_crates: bool,
+    options: &CompileOptions,
+) -> Result<PathBuf> {
+    if required_crates(needs_crates, &rust_code.to_string()).is_empty() {
         // Use direct rustc (faster for simple programs)
-        compile_with_rustc(&rust_code, options)
+        compile_with_rustc(rust_code, options)
+    } else {
+        // Use cargo build with Cargo.toml (for external crate access)
+        compile_with_cargo(rust_code, options)
     }
 }
+
+/// Crates from [`generate_cargo_toml`] that transpiled `rust` links against
+///
+/// `needs_crates` is set for programs using `DataFrames`, JSON or HTTP
+/// (complexity: 5)
+pub(crate) fn required_crates(needs_crates: bool, rust: &str) -> Vec<&'static str> {
+    let mut crates = Vec::new();
+    if needs_crates {
+        crates.extend(["polars", "serde", "serde_json", "reqwest"]);
+    }
+    // `123n` literals and `bigint` annotations need the num-bigint crate
+    if rust.contains("num_bigint") {
+        crates.push("num-bigint");
+    }
+    // `1.23d` literals and `decimal` annotations need rust_decimal
+    if rust.contains("rust_decimal") {
+        crates.push("rust_decimal");
+    }
+    // `std::csv::read/write` transpile to the csv crate
+    if rust.contains("csv :: ReaderBuilder") || rust.contains("csv :: WriterBuilder") {
+        crates.push("csv");
+    }
+    crates
+}
 /// Parse Ruchy source and transpile to Rust (complexity: 4)
 fn parse_and_transpile(source: &str) -> Result<TokenStream> {
     let mut parser = Parser::new(source);
@@ -408,8 +634,25 @@ fn contains_module_declaration(ast: &crate::frontend::ast::Expr) -> bool {
     check_expr(ast)
 }
 
+/// Whether the program pulls in other source files (complexity: 3)
+fn reads_other_files(ast: &crate::frontend::ast::Expr) -> bool {
+    use crate::frontend::ast::ExprKind;
+
+    match &ast.kind {
+        ExprKind::ModuleDeclaration { .. }
+        | ExprKind::Import { .. }
+        | ExprKind::ImportAll { .. }
+        | ExprKind::ImportDefault { .. }
+        | ExprKind::ReExport { .. } => true,
+        ExprKind::Block(exprs) => exprs.iter().any(reads_other_files),
+        ExprKind::Function { body, .. } => reads_other_files(body),
+        ExprKind::Let { value, body, .. } => reads_other_files(value) || reads_other_files(body),
+        _ => false,
+    }
+}
+
 /// Generate Cargo.toml with polars dependency (complexity: 2)
-fn generate_cargo_toml(binary_name: &str) -> String {
+pub(crate) fn generate_cargo_toml(binary_name: &str) -> String {
     format!(
         r#"[package]
 name = "{binary_name}"
@@ -421,6 +664,9 @@ polars = {{ version = "0.35", features = ["lazy"] }}
 serde = {{ version = "1.0", features = ["derive"] }}
 serde_json = "1.0"
 reqwest = {{ version = "0.12", features = ["blocking"] }}
+num-bigint = "0.4"
+rust_decimal = "1"
+csv = "1"
 "#
     )
 }
@@ -521,7 +767,7 @@ fn prepare_rust_file(
 
 /// Generate model embedding code with `include_bytes!` (issue #169)
 /// Copies model files to temp directory and generates static byte arrays
-fn generate_model_embedding_code(
+pub(crate) fn generate_model_embedding_code(
     rust_code: &str,
     embed_models: &[PathBuf],
     temp_dir: &Path,
@@ -593,7 +839,7 @@ fn sanitize_model_name(filename: &str) -> String {
         .collect()
 }
 /// Build rustc command with options (complexity: 7)
-fn build_rustc_command(rust_file: &Path, options: &CompileOptions) -> Command {
+pub(crate) fn build_rustc_command(rust_file: &Path, options: &CompileOptions) -> Command {
     let mut cmd = Command::new("rustc");
     cmd.arg(rust_file).arg("-o").arg(&options.output);
     // Set Rust edition to 2021 for async support
@@ -621,7 +867,7 @@ fn apply_optional_flags(cmd: &mut Command, options: &CompileOptions) {
     }
 }
 /// Execute compilation command (complexity: 3)
-fn execute_compilation(mut cmd: Command) -> Result<()> {
+pub(crate) fn execute_compilation(mut cmd: Command) -> Result<()> {
     let output = cmd.output().context("Failed to execute rustc")?;
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -630,7 +876,7 @@ fn execute_compilation(mut cmd: Command) -> Result<()> {
     Ok(())
 }
 /// Verify output file exists (complexity: 2)
-fn verify_output_exists(output_path: &Path) -> Result<()> {
+pub(crate) fn verify_output_exists(output_path: &Path) -> Result<()> {
     if !output_path.exists() {
         bail!(
             "Expected output file not created: {}",
@@ -760,6 +1006,67 @@ mod tests {
         let _ = compile_source_to_binary(source, &options);
     }
 
+    #[test]
+    fn test_compile_cached_reuses_rust_and_binary() {
+        let dir = TempDir::new().unwrap();
+        let source_path = dir.path().join("hello.ruchy");
+        fs::write(&source_path, "fun main() {\n    println(\"cached\")\n}").unwrap();
+        let cache = BuildCache::in_dir(dir.path());
+        let options = CompileOptions {
+            output: dir.path().join("hello"),
+            ..Default::default()
+        };
+
+        let first = compile_to_binary_cached(&source_path, &options, &cache).unwrap();
+        assert!(!first.rust_reused && !first.binary_reused);
+        fs::remove_file(&options.output).unwrap();
+
+        let second = compile_to_binary_cached(&source_path, &options, &cache).unwrap();
+        assert!(second.rust_reused && second.binary_reused);
+        assert!(options.output.exists());
+
+        // Other rustc options build a different binary from the same Rust;
+        // `-O0` would not do here, as it also skips AST optimization
+        let options = CompileOptions {
+            strip: true,
+            ..options
+        };
+        let third = compile_to_binary_cached(&source_path, &options, &cache).unwrap();
+        assert!(third.rust_reused && !third.binary_reused);
+    }
+
+    #[test]
+    fn test_reads_other_files() {
+        let parse = |source| Parser::new(source).parse().unwrap();
+        assert!(!reads_other_files(&parse("fun main() { println(1) }")));
+        assert!(reads_other_files(&parse("mod helpers;\nfun main() {}")));
+        assert!(reads_other_files(&parse("use helpers::greet\ngreet()")));
+    }
+
+    #[test]
+    fn test_extern_crates_need_a_project() {
+        let parse = |source| Parser::new(source).parse().unwrap();
+        let ast = parse(
+            "extern \"rust\" slug { fun slugify(s: &str) -> String }\n\
+             extern \"rust\" std::env { fun var(key: &str) -> Result<String, VarError> }\n\
+             fun main() { println(slugify(\"A B\")) }",
+        );
+        assert_eq!(
+            extern_crates(&ast).into_iter().collect::<Vec<_>>(),
+            ["slug"]
+        );
+
+        let err = compile_source_to_binary(
+            "extern \"rust\" slug { fun slugify(s: &str) -> String }",
+            &CompileOptions::default(),
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("needs a Ruchy.toml project"),
+            "{err}"
+        );
+    }
+
     #[test]
     fn test_compile_options_default() {
         let options = CompileOptions::default();
@@ -782,6 +1089,7 @@ mod tests {
             target: Some("x86_64-unknown-linux-musl".to_string()),
             rustc_flags: vec!["-C".to_string(), "lto=fat".to_string()],
             embed_models: Vec::new(),
+            whole_program: false,
         };
 
         assert_eq!(options.output, PathBuf::from("my_binary"));
@@ -1261,6 +1569,7 @@ mod tests {
                 "panic=abort".to_string(),
             ],
             embed_models: Vec::new(),
+            whole_program: false,
         };
 
         let rust_file = Path::new("/tmp/test.rs");
@@ -1281,6 +1590,7 @@ mod tests {
             target: Some("x86_64-unknown-linux-gnu".to_string()),
             rustc_flags: vec!["--verbose".to_string()],
             embed_models: Vec::new(),
+            whole_program: false,
         };
 
         // Test Clone trait
@@ -0,0 +1,478 @@
+//! Hermetic builds for `ruchy compile --hermetic`
+//!
+//! A hermetic build only depends on inputs it can name, and records them:
+//!
+//! - rustc must report the version pinned by `[build] rustc` in the nearest
+//!   `Ruchy.toml`; `"1.82"` accepts any 1.82 patch release:
+//!
+//!   ```toml
+//!   [build]
+//!   rustc = "1.82.0"
+//!   ```
+//!
+//! - the generated Rust is kept in `<output>.vendor/` rather than a
+//!   temporary directory. Programs that need crates get a cargo package
+//!   there, with the crates locked in `Cargo.lock` and vendored from cargo's
+//!   local cache into `vendor/`;
+//! - cargo runs with `--offline`/`--frozen` and `CARGO_NET_OFFLINE`, so a
+//!   crate missing from the local cache fails the build instead of being
+//!   downloaded. rustc itself never uses the network;
+//! - paths under the vendor directory are remapped to `.`, so the binary
+//!   doesn't record where it was built;
+//! - `<output>.build-info.json` records the versions and SHA-256 hashes of
+//!   the source, the generated Rust, embedded models, locked crates and the
+//!   binary.
+//!
+//! Hermetic builds bypass the build cache.
+use crate::backend::compiler::{
+    build_rustc_command, execute_compilation, generate_cargo_toml, generate_model_embedding_code,
+    get_rustc_version, optimize_ast, reject_extern_crates, required_crates,
+    resolve_module_declarations, transpile_with_context, uses_dataframes, uses_http, uses_json,
+    verify_output_exists, CompileOptions,
+};
+use crate::runtime::script::MANIFEST_FILE;
+use crate::utils::common_patterns::ResultContextExt;
+use crate::Parser;
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+/// What went into a hermetic build, written to `<output>.build-info.json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildInfo {
+    /// Version of the ruchy compiler
+    pub ruchy_version: String,
+    /// `rustc --version` of the compiler that built the binary
+    pub rustc_version: String,
+    /// The version pinned in the manifest
+    pub pinned_rustc: String,
+    /// The Ruchy entry point
+    pub source: FileDigest,
+    /// SHA-256 of the generated Rust, which covers every module the entry
+    /// point pulled in
+    pub rust_sha256: String,
+    /// Model files embedded in the binary
+    pub models: Vec<FileDigest>,
+    /// Crates the binary links, from `Cargo.lock`
+    pub crates: Vec<LockedCrate>,
+    /// Optimization level
+    pub opt_level: String,
+    /// Whether debug symbols were stripped
+    pub strip: bool,
+    /// Whether the binary is statically linked
+    pub static_link: bool,
+    /// Target triple, when cross-compiling
+    pub target: Option<String>,
+    /// Additional rustc flags
+    pub rustc_flags: Vec<String>,
+    /// Whether whole-program optimization ran
+    pub whole_program: bool,
+    /// The binary
+    pub artifact: FileDigest,
+}
+
+/// A file and the SHA-256 of its contents
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileDigest {
+    pub path: String,
+    pub sha256: String,
+    pub size: u64,
+}
+
+impl FileDigest {
+    /// Hash the file at `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read
+    pub fn of(path: &Path) -> Result<Self> {
+        let bytes = fs::read(path).file_context("read", path)?;
+        Ok(Self {
+            path: path.display().to_string(),
+            sha256: sha256(&bytes),
+            size: bytes.len() as u64,
+        })
+    }
+}
+
+/// A crate from `Cargo.lock`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedCrate {
+    pub name: String,
+    pub version: String,
+    /// Registry checksum, absent for git and path sources
+    pub checksum: Option<String>,
+}
+
+/// Result of [`compile_hermetic`]
+#[derive(Debug, Clone)]
+pub struct HermeticBuild {
+    /// Output binary path
+    pub output: PathBuf,
+    /// Directory holding the generated Rust and vendored crates
+    pub vendor_dir: PathBuf,
+    /// Path of the build-info manifest
+    pub build_info_path: PathBuf,
+    /// Contents of the build-info manifest
+    pub info: BuildInfo,
+}
+
+/// Compile a Ruchy source file like
+/// [`compile_to_binary`](crate::backend::compile_to_binary), hermetically
+/// as described in the module docs
+///
+/// # Errors
+///
+/// Returns an error if no rustc version is pinned or rustc reports another
+/// one, if the source cannot be read, parsed, transpiled or compiled, or if
+/// a crate the program needs is not in cargo's local cache
+pub fn compile_hermetic(source_path: &Path, options: &CompileOptions) -> Result<HermeticBuild> {
+    let source_dir = match source_path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let pinned_rustc = pinned_rustc(source_dir)?;
+    let rustc_version = get_rustc_version()?;
+    check_rustc_version(&pinned_rustc, &rustc_version)?;
+
+    let source = fs::read_to_string(source_path).file_context("read", source_path)?;
+    let ast = Parser::new(&source).parse().parse_context("Ruchy source")?;
+    let needs_crates = uses_dataframes(&ast) || uses_json(&ast) || uses_http(&ast);
+    let ast = resolve_module_declarations(ast, Some(source_path))?;
+    reject_extern_crates(&ast)?;
+    let ast = optimize_ast(ast, options);
+    let rust = transpile_with_context(ast, Some(source_path), options.whole_program)?.to_string();
+    let crates = required_crates(needs_crates, &rust);
+
+    let vendor_dir = vendor_dir(&options.output);
+    if vendor_dir.exists() {
+        fs::remove_dir_all(&vendor_dir)
+            .with_context(|| format!("Failed to clear {}", vendor_dir.display()))?;
+    }
+    fs::create_dir_all(&vendor_dir)
+        .with_context(|| format!("Failed to create {}", vendor_dir.display()))?;
+    let root = fs::canonicalize(&vendor_dir)?;
+    let src_dir = if crates.is_empty() {
+        root.clone()
+    } else {
+        root.join("src")
+    };
+    fs::create_dir_all(&src_dir)
+        .with_context(|| format!("Failed to create {}", src_dir.display()))?;
+    let code = if options.embed_models.is_empty() {
+        rust.clone()
+    } else {
+        generate_model_embedding_code(&rust, &options.embed_models, &src_dir)?
+    };
+    let main_file = src_dir.join("main.rs");
+    fs::write(&main_file, code).file_context("write", &main_file)?;
+
+    let mut remap = OsString::from("--remap-path-prefix=");
+    remap.push(&root);
+    remap.push("=.");
+    let locked = if crates.is_empty() {
+        let mut cmd = build_rustc_command(&main_file, options);
+        cmd.arg(&remap);
+        execute_compilation(cmd)?;
+        Vec::new()
+    } else {
+        build_vendored_package(&root, &crates, &remap, &options.output)?
+    };
+    verify_output_exists(&options.output)?;
+
+    let info = BuildInfo {
+        ruchy_version: env!("CARGO_PKG_VERSION").to_string(),
+        rustc_version,
+        pinned_rustc,
+        source: FileDigest::of(source_path)?,
+        rust_sha256: sha256(rust.as_bytes()),
+        models: options
+            .embed_models
+            .iter()
+            .map(|model| FileDigest::of(model))
+            .collect::<Result<_>>()?,
+        crates: locked,
+        opt_level: options.opt_level.clone(),
+        strip: options.strip,
+        static_link: options.static_link,
+        target: options.target.clone(),
+        rustc_flags: options.rustc_flags.clone(),
+        whole_program: options.whole_program,
+        artifact: FileDigest::of(&options.output)?,
+    };
+    let build_info_path = build_info_path(&options.output);
+    let json = serde_json::to_string_pretty(&info)? + "\n";
+    fs::write(&build_info_path, json).file_context("write", &build_info_path)?;
+    Ok(HermeticBuild {
+        output: options.output.clone(),
+        vendor_dir,
+        build_info_path,
+        info,
+    })
+}
+
+/// The rustc version pinned by `[build] rustc` in the nearest manifest in
+/// `start_dir` or its ancestors
+///
+/// # Errors
+///
+/// Returns an error if no manifest pins a version or the manifest is invalid
+pub fn pinned_rustc(start_dir: &Path) -> Result<String> {
+    let missing = || {
+        anyhow::anyhow!(
+            "--hermetic needs a pinned rustc version: add `[build] rustc = \"<version>\"` to {MANIFEST_FILE}"
+        )
+    };
+    let manifest = start_dir
+        .ancestors()
+        .map(|dir| dir.join(MANIFEST_FILE))
+        .find(|path| path.is_file())
+        .ok_or_else(missing)?;
+    let content = fs::read_to_string(&manifest).file_context("read", &manifest)?;
+    let table: toml::Table = toml::from_str(&content)
+        .with_context(|| format!("Failed to parse {}", manifest.display()))?;
+    match table.get("build").and_then(|build| build.get("rustc")) {
+        Some(toml::Value::String(version)) => Ok(version.clone()),
+        Some(_) => bail!("{}: [build] rustc must be a string", manifest.display()),
+        None => Err(missing()),
+    }
+}
+
+/// Check that `rustc --version` output `reported` is the `pinned` version
+/// or one of its patch releases
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::backend::hermetic::check_rustc_version;
+///
+/// let reported = "rustc 1.82.0 (f6e511eec 2024-10-15)";
+/// assert!(check_rustc_version("1.82.0", reported).is_ok());
+/// assert!(check_rustc_version("1.82", reported).is_ok());
+/// assert!(check_rustc_version("1.83.0", reported).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns an error naming both versions when they differ
+pub fn check_rustc_version(pinned: &str, reported: &str) -> Result<()> {
+    let version = reported.split_whitespace().nth(1).unwrap_or(reported);
+    if version == pinned || version.starts_with(&format!("{pinned}.")) {
+        Ok(())
+    } else {
+        bail!("rustc {version} does not match the version pinned in {MANIFEST_FILE}: {pinned}")
+    }
+}
+
+/// Directory holding the generated Rust of the build of `output`
+pub fn vendor_dir(output: &Path) -> PathBuf {
+    with_suffix(output, ".vendor")
+}
+
+/// Path of the build-info manifest of `output`
+pub fn build_info_path(output: &Path) -> PathBuf {
+    with_suffix(output, ".build-info.json")
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+fn sha256(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+/// Lock and vendor the crates of the cargo package in `dir`, build it
+/// offline and copy the binary to `output`, returning the locked crates
+fn build_vendored_package(
+    dir: &Path,
+    crates: &[&str],
+    remap: &OsString,
+    output: &Path,
+) -> Result<Vec<LockedCrate>> {
+    fs::write(dir.join("Cargo.toml"), cargo_toml(crates)?)?;
+    run_cargo(dir, &["generate-lockfile", "--offline"], |_| {})?;
+    // `cargo vendor` prints the source replacement that points cargo at the
+    // vendored crates
+    let config = run_cargo(dir, &["vendor", "--offline", "--locked", "vendor"], |_| {})?;
+    fs::create_dir_all(dir.join(".cargo"))?;
+    fs::write(dir.join(".cargo").join("config.toml"), config)?;
+    let target_dir = TempDir::new().compile_context("create temporary directory")?;
+    run_cargo(dir, &["build", "--release", "--frozen"], |cmd| {
+        cmd.arg("--target-dir")
+            .arg(target_dir.path())
+            .env("CARGO_ENCODED_RUSTFLAGS", remap);
+    })?;
+    let binary = target_dir
+        .path()
+        .join("release")
+        .join(format!("ruchy_binary{}", std::env::consts::EXE_SUFFIX));
+    fs::copy(&binary, output).context("Failed to copy compiled binary to output location")?;
+    let lock = fs::read_to_string(dir.join("Cargo.lock")).context("Failed to read Cargo.lock")?;
+    locked_crates(&lock)
+}
+
+/// Run cargo in `dir` without network access, returning its stdout
+fn run_cargo(dir: &Path, args: &[&str], configure: impl FnOnce(&mut Command)) -> Result<String> {
+    let mut cmd = Command::new("cargo");
+    cmd.args(args)
+        .current_dir(dir)
+        .env("CARGO_NET_OFFLINE", "true");
+    configure(&mut cmd);
+    let output = cmd
+        .output()
+        .with_context(|| format!("Failed to execute cargo {}", args[0]))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("cargo {} failed:\n{stderr}", args[0]);
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// `Cargo.toml` of the generated package, depending on `crates` only
+fn cargo_toml(crates: &[&str]) -> Result<String> {
+    let mut table: toml::Table = toml::from_str(&generate_cargo_toml("ruchy_binary"))?;
+    let dependencies: toml::Table = match table.get("dependencies") {
+        Some(toml::Value::Table(known)) => known
+            .iter()
+            .filter(|(name, _)| crates.contains(&name.as_str()))
+            .map(|(name, spec)| (name.clone(), spec.clone()))
+            .collect(),
+        _ => toml::Table::new(),
+    };
+    table.insert("dependencies".to_string(), toml::Value::Table(dependencies));
+    // Keep the package out of any workspace the output is nested in
+    table.insert(
+        "workspace".to_string(),
+        toml::Value::Table(toml::Table::new()),
+    );
+    Ok(toml::to_string(&table)?)
+}
+
+/// The registry, git and path crates of a `Cargo.lock`, without the package
+/// itself
+fn locked_crates(lock: &str) -> Result<Vec<LockedCrate>> {
+    let table: toml::Table = toml::from_str(lock).context("Invalid Cargo.lock")?;
+    let packages = table
+        .get("package")
+        .and_then(toml::Value::as_array)
+        .map_or(&[][..], Vec::as_slice);
+    let field = |package: &toml::Value, key: &str| {
+        package
+            .get(key)
+            .and_then(toml::Value::as_str)
+            .map(str::to_string)
+    };
+    Ok(packages
+        .iter()
+        .filter(|package| package.get("source").is_some())
+        .map(|package| LockedCrate {
+            name: field(package, "name").unwrap_or_default(),
+            version: field(package, "version").unwrap_or_default(),
+            checksum: field(package, "checksum"),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rustc_version_must_match_the_pin() {
+        let reported = "rustc 1.82.1 (f6e511eec 2024-10-15)";
+        assert!(check_rustc_version("1.82.1", reported).is_ok());
+        assert!(check_rustc_version("1.82", reported).is_ok());
+        assert!(check_rustc_version("1.8", reported).is_err());
+        let err = check_rustc_version("1.82.0", reported).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "rustc 1.82.1 does not match the version pinned in Ruchy.toml: 1.82.0"
+        );
+        let nightly = "rustc 1.84.0-nightly (e7c0d2750 2024-10-15)";
+        assert!(check_rustc_version("1.84.0", nightly).is_err());
+    }
+
+    #[test]
+    fn test_pin_comes_from_the_nearest_manifest() {
+        let dir = TempDir::new().unwrap();
+        let nested = dir.path().join("app").join("src");
+        fs::create_dir_all(&nested).unwrap();
+        let err = pinned_rustc(&nested).unwrap_err().to_string();
+        assert!(err.contains("[build] rustc"), "{err}");
+        fs::write(
+            dir.path().join(MANIFEST_FILE),
+            "[build]\nrustc = \"1.82.0\"\n",
+        )
+        .unwrap();
+        assert_eq!(pinned_rustc(&nested).unwrap(), "1.82.0");
+        fs::write(
+            dir.path().join("app").join(MANIFEST_FILE),
+            "[run]\ntimeout = 5\n",
+        )
+        .unwrap();
+        assert!(pinned_rustc(&nested).is_err());
+    }
+
+    #[test]
+    fn test_package_depends_on_the_required_crates_only() {
+        let toml: toml::Table = toml::from_str(&cargo_toml(&["csv"]).unwrap()).unwrap();
+        let dependencies = toml.get("dependencies").and_then(toml::Value::as_table);
+        let dependencies = dependencies.unwrap();
+        assert_eq!(dependencies.keys().collect::<Vec<_>>(), ["csv"]);
+        assert!(toml.contains_key("workspace"));
+    }
+
+    #[test]
+    fn test_locked_crates_skip_the_package_itself() {
+        let lock = "version = 3\n\n[[package]]\nname = \"csv\"\nversion = \"1.3.0\"\nsource = \"registry+https://github.com/rust-lang/crates.io-index\"\nchecksum = \"abc\"\n\n[[package]]\nname = \"ruchy_binary\"\nversion = \"0.1.0\"\n";
+        assert_eq!(
+            locked_crates(lock).unwrap(),
+            [LockedCrate {
+                name: "csv".to_string(),
+                version: "1.3.0".to_string(),
+                checksum: Some("abc".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_hermetic_build_records_its_inputs() {
+        let Ok(reported) = get_rustc_version() else {
+            return;
+        };
+        let dir = TempDir::new().unwrap();
+        let version = reported.split_whitespace().nth(1).unwrap();
+        fs::write(
+            dir.path().join(MANIFEST_FILE),
+            format!("[build]\nrustc = \"{version}\"\n"),
+        )
+        .unwrap();
+        let source = dir.path().join("main.ruchy");
+        fs::write(&source, "fun main() { println(42) }").unwrap();
+        let options = CompileOptions {
+            output: dir.path().join("app"),
+            opt_level: "0".to_string(),
+            ..CompileOptions::default()
+        };
+        let build = compile_hermetic(&source, &options).unwrap();
+        assert!(build.vendor_dir.join("main.rs").is_file());
+        assert_eq!(
+            build.build_info_path,
+            dir.path().join("app.build-info.json")
+        );
+        let written: BuildInfo =
+            serde_json::from_str(&fs::read_to_string(&build.build_info_path).unwrap()).unwrap();
+        assert_eq!(written, build.info);
+        assert_eq!(written.artifact, FileDigest::of(&options.output).unwrap());
+        assert_eq!(written.pinned_rustc, version);
+        assert!(written.crates.is_empty());
+    }
+}
@@ -0,0 +1,584 @@
+//! Rust code generation from MIR
+//!
+//! A second Rust backend, enabled with `ruchy transpile --via-mir`. Instead
+//! of walking the AST like [`crate::backend::transpiler`], it emits the
+//! typed, flattened [`Program`] of [`crate::middleend::mir`] after the MIR
+//! optimizations have run. Each function becomes a loop over a `match` on
+//! the current basic block, so control flow comes out exactly as lowered
+//! and every local is declared with its MIR type.
+//!
+//! Only what the lowering supports comes through: functions over numbers,
+//! bools, chars and strings, with `if`, loops, calls and `print`/`println`.
+//! A value without a Rust type, such as an unannotated parameter, is an
+//! error rather than a guess.
+//!
+//! This is the first backend on the MIR, and it is opt-in. Still to come:
+//!
+//! - lowering structs, enums, collections, closures, `match` and method
+//!   calls, which the default transpiler handles from the AST;
+//! - making this the default `ruchy transpile` path once it covers what the
+//!   AST transpiler does;
+//! - moving the wasm and bytecode backends onto the MIR, which still
+//!   compile the AST.
+use crate::frontend::ast::Expr;
+use crate::middleend::mir::{
+    self, AggregateKind, BinOp, BlockId, Constant, Function, Local, LoweringContext, Mutability,
+    Operand, Place, Program, Rvalue, Statement, Terminator, Type, UnOp, INTRINSICS,
+};
+use anyhow::{anyhow, bail, Context, Result};
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{format_ident, quote};
+use std::collections::HashMap;
+
+/// Lower `ast` to MIR and run the MIR optimizations on it
+///
+/// # Errors
+///
+/// Returns an error if the program uses constructs the lowering does not
+/// support yet.
+pub fn lower_program(ast: &Expr) -> Result<Program> {
+    let mut program = LoweringContext::new().lower_expr(ast)?;
+    mir::optimize_program(&mut program);
+    Ok(program)
+}
+
+/// Generate a Rust program from MIR
+///
+/// # Errors
+///
+/// Returns an error if a function uses a type or operation with no Rust
+/// equivalent here.
+pub fn generate_rust(program: &Program) -> Result<String> {
+    let mut names: Vec<&String> = program.functions.keys().collect();
+    names.sort();
+    let mut items = Vec::new();
+    for name in names {
+        let function = FunctionEmitter::new(&program.functions[name])
+            .emit()
+            .with_context(|| format!("Failed to generate Rust for function `{name}`"))?;
+        items.push(function);
+    }
+    if !program.functions.contains_key("main") {
+        items.push(quote! { fn main() {} });
+    }
+    let file = syn::parse2(quote! { #(#items)* })?;
+    Ok(prettyplease::unparse(&file))
+}
+
+/// Emits one MIR function as a Rust function
+struct FunctionEmitter<'a> {
+    func: &'a Function,
+    types: HashMap<Local, &'a Type>,
+}
+
+impl<'a> FunctionEmitter<'a> {
+    fn new(func: &'a Function) -> Self {
+        let types = func.locals.iter().map(|decl| (decl.id, &decl.ty)).collect();
+        Self { func, types }
+    }
+
+    fn emit(&self) -> Result<TokenStream> {
+        let name = ident(&self.func.name)?;
+        let mut params = Vec::new();
+        for param in &self.func.params {
+            let local = local_ident(*param);
+            let ty = rust_type(self.local_type(*param)?)?;
+            params.push(quote! { mut #local: #ty });
+        }
+        let ret = match &self.func.return_ty {
+            Type::Unit => quote! {},
+            ty => {
+                let ty = rust_type(ty)?;
+                quote! { -> #ty }
+            }
+        };
+        let mut decls = Vec::new();
+        for decl in &self.func.locals {
+            if self.func.params.contains(&decl.id) {
+                continue;
+            }
+            if !has_default(&decl.ty) {
+                bail!("a local of type `{}` cannot be generated yet", decl.ty);
+            }
+            let local = local_ident(decl.id);
+            let ty = rust_type(&decl.ty)?;
+            decls.push(quote! { let mut #local: #ty = Default::default(); });
+        }
+        let mut arms = Vec::new();
+        for block in &self.func.blocks {
+            let id = block_literal(block.id);
+            let mut body = Vec::new();
+            for stmt in &block.statements {
+                body.push(self.statement(stmt)?);
+            }
+            body.push(self.terminator(&block.terminator)?);
+            arms.push(quote! { #id => { #(#body)* } });
+        }
+        let entry = block_literal(self.func.entry_block);
+        Ok(quote! {
+            #[allow(unused_mut, unused_assignments, unused_variables, unused_parens, unreachable_code)]
+            fn #name(#(#params),*) #ret {
+                #(#decls)*
+                let mut __block: usize = #entry;
+                loop {
+                    match __block {
+                        #(#arms)*
+                        _ => unreachable!(),
+                    }
+                }
+            }
+        })
+    }
+
+    fn local_type(&self, local: Local) -> Result<&'a Type> {
+        self.types
+            .get(&local)
+            .copied()
+            .ok_or_else(|| anyhow!("{local} is not declared"))
+    }
+
+    fn operand_type(&self, operand: &Operand) -> Result<Type> {
+        match operand {
+            Operand::Copy(place) | Operand::Move(place) => self.place_type(place),
+            Operand::Constant(Constant::String(_)) => Ok(Type::String),
+            Operand::Constant(
+                Constant::Int(_, ty) | Constant::Uint(_, ty) | Constant::Float(_, ty),
+            ) => Ok(ty.clone()),
+            Operand::Constant(Constant::Char(_)) => Ok(Type::UserType("char".to_string())),
+            Operand::Constant(Constant::Bool(_)) => Ok(Type::Bool),
+            Operand::Constant(_) => Ok(Type::Unit),
+        }
+    }
+
+    fn place_type(&self, place: &Place) -> Result<Type> {
+        match place {
+            Place::Local(local) => self.local_type(*local).cloned(),
+            Place::Deref(base) => match self.place_type(base)? {
+                Type::Ref(inner, _) => Ok(*inner),
+                ty => bail!("cannot dereference a value of type `{ty}`"),
+            },
+            Place::Field(..) | Place::Index(..) => {
+                bail!("field and index places cannot be generated yet")
+            }
+        }
+    }
+
+    fn statement(&self, stmt: &Statement) -> Result<TokenStream> {
+        match stmt {
+            Statement::Assign(place, rvalue) => {
+                let target = self.place(place)?;
+                let value = self.rvalue(rvalue, &self.place_type(place)?)?;
+                Ok(quote! { #target = #value; })
+            }
+            Statement::StorageLive(_) | Statement::StorageDead(_) | Statement::Nop => Ok(quote! {}),
+        }
+    }
+
+    fn place(&self, place: &Place) -> Result<TokenStream> {
+        match place {
+            Place::Local(local) => {
+                let local = local_ident(*local);
+                Ok(quote! { #local })
+            }
+            Place::Deref(base) => {
+                let base = self.place(base)?;
+                Ok(quote! { (*#base) })
+            }
+            Place::Field(..) | Place::Index(..) => {
+                bail!("field and index places cannot be generated yet")
+            }
+        }
+    }
+
+    /// Operands are read without moving: values that are not `Copy` are
+    /// cloned, since MIR may read a local again after a move
+    fn operand(&self, operand: &Operand) -> Result<TokenStream> {
+        match operand {
+            Operand::Copy(place) | Operand::Move(place) => {
+                let value = self.place(place)?;
+                if is_copy(&self.place_type(place)?) {
+                    Ok(value)
+                } else {
+                    Ok(quote! { #value.clone() })
+                }
+            }
+            Operand::Constant(constant) => constant_expr(constant),
+        }
+    }
+
+    /// An rvalue stored in a place of type `dest`
+    fn rvalue(&self, rvalue: &Rvalue, dest: &Type) -> Result<TokenStream> {
+        match rvalue {
+            Rvalue::Use(operand) => self.operand(operand),
+            Rvalue::BinaryOp(op, left, right) => self.binary(*op, left, right, dest),
+            Rvalue::UnaryOp(op, operand) => {
+                let value = self.operand(operand)?;
+                Ok(match op {
+                    UnOp::Neg => quote! { -#value },
+                    UnOp::Not | UnOp::BitNot => quote! { !#value },
+                    UnOp::Ref => quote! { &#value },
+                    UnOp::Deref => quote! { *#value },
+                })
+            }
+            Rvalue::Ref(mutability, place) => {
+                let place = self.place(place)?;
+                Ok(match mutability {
+                    Mutability::Immutable => quote! { &#place },
+                    Mutability::Mutable => quote! { &mut #place },
+                })
+            }
+            Rvalue::Aggregate(kind, operands) => {
+                let values = self.operands(operands)?;
+                match kind {
+                    AggregateKind::Tuple => Ok(quote! { (#(#values,)*) }),
+                    AggregateKind::Array(_) => Ok(quote! { [#(#values),*] }),
+                    AggregateKind::Struct(name) => {
+                        bail!("struct `{name}` values cannot be generated yet")
+                    }
+                }
+            }
+            Rvalue::Call(func, args) => self.call(func, args),
+            Rvalue::Cast(_, operand, ty) => {
+                if !is_copy(ty) || matches!(ty, Type::Unit) {
+                    bail!("casts to `{ty}` cannot be generated yet");
+                }
+                let value = self.operand(operand)?;
+                let ty = rust_type(ty)?;
+                Ok(quote! { (#value as #ty) })
+            }
+        }
+    }
+
+    fn binary(
+        &self,
+        op: BinOp,
+        left: &Operand,
+        right: &Operand,
+        dest: &Type,
+    ) -> Result<TokenStream> {
+        let strings = self.operand_type(left)? == Type::String;
+        let l = self.operand(left)?;
+        let r = self.operand(right)?;
+        Ok(match op {
+            BinOp::Add if strings => quote! { format!("{}{}", #l, #r) },
+            BinOp::Add => quote! { #l + #r },
+            BinOp::Sub => quote! { #l - #r },
+            BinOp::Mul => quote! { #l * #r },
+            BinOp::Div => quote! { #l / #r },
+            BinOp::Rem => quote! { #l % #r },
+            BinOp::Pow => {
+                let ty = rust_type(dest)?;
+                if matches!(dest, Type::F32 | Type::F64) {
+                    quote! { <#ty>::powf(#l, #r as #ty) }
+                } else {
+                    quote! { <#ty>::pow(#l, #r as u32) }
+                }
+            }
+            BinOp::BitAnd => quote! { #l & #r },
+            BinOp::BitOr => quote! { #l | #r },
+            BinOp::BitXor => quote! { #l ^ #r },
+            BinOp::Shl => quote! { #l << #r },
+            BinOp::Shr => quote! { #l >> #r },
+            BinOp::Eq => quote! { #l == #r },
+            BinOp::Ne => quote! { #l != #r },
+            BinOp::Lt => quote! { #l < #r },
+            BinOp::Le => quote! { #l <= #r },
+            BinOp::Gt => quote! { #l > #r },
+            BinOp::Ge => quote! { #l >= #r },
+            BinOp::And => quote! { #l && #r },
+            BinOp::Or => quote! { #l || #r },
+            BinOp::NullCoalesce | BinOp::Send | BinOp::In => {
+                bail!("`{op:?}` operations cannot be generated yet")
+            }
+        })
+    }
+
+    fn operands(&self, operands: &[Operand]) -> Result<Vec<TokenStream>> {
+        operands
+            .iter()
+            .map(|operand| self.operand(operand))
+            .collect()
+    }
+
+    /// A call of a program function, an intrinsic or a function value
+    fn call(&self, func: &Operand, args: &[Operand]) -> Result<TokenStream> {
+        let values = self.operands(args)?;
+        if let (true, Operand::Constant(Constant::Function(name))) = (is_intrinsic(func), func) {
+            // Floats print with their decimal point, as the interpreter does
+            let mut format = Vec::new();
+            for arg in args {
+                let ty = self.operand_type(arg)?;
+                let debug = matches!(ty, Type::F32 | Type::F64 | Type::Unit);
+                format.push(if debug { "{:?}" } else { "{}" });
+            }
+            let format = format.join(" ");
+            let name = ident(name)?;
+            return Ok(if values.is_empty() {
+                quote! { #name!() }
+            } else {
+                quote! { #name!(#format, #(#values),*) }
+            });
+        }
+        let func = self.operand(func)?;
+        Ok(quote! { #func(#(#values),*) })
+    }
+
+    fn terminator(&self, terminator: &Terminator) -> Result<TokenStream> {
+        match terminator {
+            Terminator::Goto(target) => {
+                let target = block_literal(*target);
+                Ok(quote! { __block = #target; })
+            }
+            Terminator::If {
+                condition,
+                then_block,
+                else_block,
+            } => {
+                let condition = self.operand(condition)?;
+                let then_block = block_literal(*then_block);
+                let else_block = block_literal(*else_block);
+                Ok(quote! { __block = if #condition { #then_block } else { #else_block }; })
+            }
+            Terminator::Switch {
+                discriminant,
+                targets,
+                default,
+            } => {
+                let discriminant = self.operand(discriminant)?;
+                let mut arms = Vec::new();
+                for (value, target) in targets {
+                    let pattern = constant_pattern(value)?;
+                    let target = block_literal(*target);
+                    arms.push(quote! { #pattern => #target, });
+                }
+                let default = match default {
+                    Some(target) => {
+                        let target = block_literal(*target);
+                        quote! { _ => #target, }
+                    }
+                    None => quote! { _ => unreachable!(), },
+                };
+                Ok(quote! { __block = match #discriminant { #(#arms)* #default }; })
+            }
+            Terminator::Return(value) => match value {
+                Some(value) => {
+                    let value = self.operand(value)?;
+                    Ok(quote! { return #value; })
+                }
+                None => Ok(quote! { return; }),
+            },
+            Terminator::Call {
+                func,
+                args,
+                destination,
+            } => {
+                let call = self.call(func, args)?;
+                match destination {
+                    Some((place, target)) => {
+                        let place = self.place(place)?;
+                        let target = block_literal(*target);
+                        if is_intrinsic(func) {
+                            Ok(quote! { #call; #place = (); __block = #target; })
+                        } else {
+                            Ok(quote! { #place = #call; __block = #target; })
+                        }
+                    }
+                    None => Ok(quote! { #call; unreachable!() }),
+                }
+            }
+            Terminator::Unreachable => Ok(quote! { unreachable!() }),
+        }
+    }
+}
+
+/// Whether `func` names a `print`-style builtin, emitted as a macro
+fn is_intrinsic(func: &Operand) -> bool {
+    matches!(func, Operand::Constant(Constant::Function(name)) if INTRINSICS.contains(&name.as_str()))
+}
+
+fn ident(name: &str) -> Result<Ident> {
+    syn::parse_str(name).map_err(|_| anyhow!("`{name}` is not a Rust identifier"))
+}
+
+fn local_ident(local: Local) -> Ident {
+    format_ident!("__{}", local.0)
+}
+
+fn block_literal(block: BlockId) -> Literal {
+    Literal::usize_unsuffixed(block.0)
+}
+
+/// The Rust spelling of a MIR type
+fn rust_type(ty: &Type) -> Result<TokenStream> {
+    Ok(match ty {
+        Type::Unit => quote! { () },
+        Type::Bool => quote! { bool },
+        Type::I8 => quote! { i8 },
+        Type::I16 => quote! { i16 },
+        Type::I32 => quote! { i32 },
+        Type::I64 => quote! { i64 },
+        Type::I128 => quote! { i128 },
+        Type::U8 => quote! { u8 },
+        Type::U16 => quote! { u16 },
+        Type::U32 => quote! { u32 },
+        Type::U64 => quote! { u64 },
+        Type::U128 => quote! { u128 },
+        Type::F32 => quote! { f32 },
+        Type::F64 => quote! { f64 },
+        Type::String => quote! { String },
+        Type::Ref(inner, Mutability::Immutable) => {
+            let inner = rust_type(inner)?;
+            quote! { &#inner }
+        }
+        Type::Ref(inner, Mutability::Mutable) => {
+            let inner = rust_type(inner)?;
+            quote! { &mut #inner }
+        }
+        Type::Array(inner, len) => {
+            let inner = rust_type(inner)?;
+            let len = Literal::usize_unsuffixed(*len);
+            quote! { [#inner; #len] }
+        }
+        Type::Vec(inner) => {
+            let inner = rust_type(inner)?;
+            quote! { Vec<#inner> }
+        }
+        Type::Tuple(elements) => {
+            let elements = elements.iter().map(rust_type).collect::<Result<Vec<_>>>()?;
+            quote! { (#(#elements,)*) }
+        }
+        Type::FnPtr(params, ret) => {
+            let params = params.iter().map(rust_type).collect::<Result<Vec<_>>>()?;
+            let ret = rust_type(ret)?;
+            quote! { fn(#(#params),*) -> #ret }
+        }
+        Type::UserType(name) if name == "char" => quote! { char },
+        Type::UserType(name) if name == "Any" => {
+            bail!("a value needs a type annotation to be generated from MIR")
+        }
+        Type::UserType(name) => bail!("values of type `{name}` cannot be generated yet"),
+    })
+}
+
+/// Whether values of `ty` are `Copy` in the generated Rust
+fn is_copy(ty: &Type) -> bool {
+    match ty {
+        Type::String | Type::Vec(_) | Type::Ref(_, Mutability::Mutable) => false,
+        Type::Tuple(elements) => elements.iter().all(is_copy),
+        Type::Array(inner, _) => is_copy(inner),
+        Type::UserType(name) => name == "char",
+        _ => true,
+    }
+}
+
+/// Whether locals of `ty` can start out as `Default::default()`
+fn has_default(ty: &Type) -> bool {
+    match ty {
+        Type::Ref(..) | Type::Array(..) | Type::FnPtr(..) => false,
+        Type::Tuple(elements) => elements.iter().all(has_default),
+        Type::UserType(name) => name == "char",
+        _ => true,
+    }
+}
+
+fn constant_expr(constant: &Constant) -> Result<TokenStream> {
+    Ok(match constant {
+        Constant::Unit => quote! { () },
+        Constant::Bool(b) => quote! { #b },
+        Constant::Int(value, _) if *value < 0 => {
+            let magnitude = Literal::u128_unsuffixed(value.unsigned_abs());
+            quote! { (-#magnitude) }
+        }
+        Constant::Int(value, _) => {
+            let value = Literal::u128_unsuffixed(value.unsigned_abs());
+            quote! { #value }
+        }
+        Constant::Uint(value, _) => {
+            let value = Literal::u128_unsuffixed(*value);
+            quote! { #value }
+        }
+        Constant::Float(value, ty) => {
+            let ty = rust_type(ty)?;
+            if value.is_nan() {
+                quote! { #ty::NAN }
+            } else if value.is_infinite() && *value > 0.0 {
+                quote! { #ty::INFINITY }
+            } else if value.is_infinite() {
+                quote! { #ty::NEG_INFINITY }
+            } else if *value < 0.0 {
+                let magnitude = Literal::f64_unsuffixed(-value);
+                quote! { (-#magnitude) }
+            } else {
+                let value = Literal::f64_unsuffixed(*value);
+                quote! { #value }
+            }
+        }
+        Constant::String(s) => quote! { String::from(#s) },
+        Constant::Char(c) => quote! { #c },
+        Constant::Function(name) => {
+            let name = ident(name)?;
+            quote! { #name }
+        }
+        Constant::Symbol(name) => bail!("symbol `:{name}` cannot be generated yet"),
+    })
+}
+
+/// A constant as a `match` pattern, for `Switch` terminators
+fn constant_pattern(constant: &Constant) -> Result<TokenStream> {
+    Ok(match constant {
+        Constant::Bool(b) => quote! { #b },
+        Constant::Char(c) => quote! { #c },
+        Constant::Int(value, _) => {
+            let magnitude = Literal::u128_unsuffixed(value.unsigned_abs());
+            if *value < 0 {
+                quote! { -#magnitude }
+            } else {
+                quote! { #magnitude }
+            }
+        }
+        Constant::Uint(value, _) => {
+            let value = Literal::u128_unsuffixed(*value);
+            quote! { #value }
+        }
+        _ => bail!("switches on `{constant}` cannot be generated yet"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::Parser;
+
+    fn rust_for(source: &str) -> Result<String> {
+        let ast = Parser::new(source).parse()?;
+        generate_rust(&lower_program(&ast)?)
+    }
+
+    #[test]
+    fn test_generates_typed_functions_and_blocks() {
+        let code = rust_for(
+            "fun sum_to(n: i64) -> i64 {\n    let mut total: i64 = 0\n    for i in 0..n {\n        total = total + i\n    }\n    total\n}\nprintln(sum_to(10))",
+        )
+        .unwrap();
+        assert!(code.contains("fn sum_to(mut __0: i64) -> i64"), "{code}");
+        assert!(code.contains("let mut __block: usize = 0;"), "{code}");
+        assert!(code.contains("println!(\"{}\""), "{code}");
+        assert!(code.contains("fn main()"), "{code}");
+    }
+
+    #[test]
+    fn test_concatenates_strings_and_clones_reads() {
+        let code = rust_for("fun greet(name: String) -> String { \"hi \" + name }").unwrap();
+        assert!(code.contains("format!(\"{}{}\""), "{code}");
+        assert!(code.contains("__0.clone()"), "{code}");
+    }
+
+    #[test]
+    fn test_rejects_untyped_parameters() {
+        let err = rust_for("fun id(x) { x }").unwrap_err();
+        assert!(
+            format!("{err:#}").contains("needs a type annotation"),
+            "{err:#}"
+        );
+    }
+}
@@ -69,15 +69,29 @@
 //! ```
 #[cfg(feature = "dataframe")]
 pub mod arrow_integration;
+pub mod build_cache;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod compilation_db;
 pub mod compiler;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod hermetic;
+pub mod mir_codegen;
 pub mod module_loader;
 pub mod module_resolver;
+pub mod parity;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod project;
 pub mod transpiler;
 pub mod wasm;
-pub use compiler::{compile_source_to_binary, compile_to_binary, CompileOptions};
+pub mod whole_program;
+pub use build_cache::BuildCache;
+pub use compiler::{
+    compile_source_to_binary, compile_to_binary, compile_to_binary_cached, CachedCompile,
+    CompileOptions,
+};
 pub use module_loader::{ModuleLoader, ModuleLoaderStats, ParsedModule};
 pub use module_resolver::ModuleResolver;
-pub use transpiler::Transpiler;
+pub use transpiler::{Transpiler, TranspilerOptions};
 
 // Tests removed: This module only re-exports from submodules.
 // Actual implementations and tests belong in the submodules.
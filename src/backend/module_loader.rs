@@ -181,7 +181,7 @@ impl ModuleLoader {
     /// 1. `{module_name}.ruchy`
     /// 2. `{module_name}/mod.ruchy`  
     /// 3. `{module_name}.rchy`
-    fn resolve_module_path(&self, module_name: &str) -> Result<PathBuf> {
+    pub(crate) fn resolve_module_path(&self, module_name: &str) -> Result<PathBuf> {
         let possible_names = [
             format!("{module_name}.ruchy"),
             format!("{module_name}/mod.ruchy"),
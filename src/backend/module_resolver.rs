@@ -74,6 +74,25 @@ impl ModuleResolver {
     pub fn add_search_path<P: AsRef<std::path::Path>>(&mut self, path: P) {
         self.module_loader.add_search_path(path);
     }
+    /// Add the standard layout directories of the project in `root`
+    ///
+    /// These are `src/`, `lib/`, `modules/` and `vendor/`, plus the directory
+    /// of each vendored package so its own `mod` declarations resolve.
+    pub fn add_project_search_paths(&mut self, root: &std::path::Path) {
+        for dir in ["src", "lib", "modules", "vendor"] {
+            self.add_search_path(root.join(dir));
+        }
+        if let Ok(entries) = std::fs::read_dir(root.join("vendor")) {
+            let mut packages: Vec<_> = entries
+                .filter_map(|entry| Some(entry.ok()?.path()))
+                .filter(|path| path.is_dir())
+                .collect();
+            packages.sort();
+            for package in packages {
+                self.add_search_path(package);
+            }
+        }
+    }
     /// Resolve all file imports in an AST
     ///
     /// Recursively processes the AST to find file imports, loads the corresponding
@@ -503,7 +522,7 @@ impl ModuleResolver {
         ))
     }
     /// Check if an import path represents a file import
-    fn is_file_import(&self, path: &str) -> bool {
+    pub(crate) fn is_file_import(&self, path: &str) -> bool {
         // Keywords that refer to local modules, not files
         if path == "self"
             || path == "super"
@@ -1309,6 +1328,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_vendored_package_modules_resolve() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let package = temp_dir.path().join("vendor").join("strs");
+        std::fs::create_dir_all(&package)?;
+        std::fs::write(package.join("mod.ruchy"), "mod pad;\nfun id(x) { x }")?;
+        std::fs::write(package.join("pad.ruchy"), "fun pad() { 0 }")?;
+
+        let mut resolver = ModuleResolver::new();
+        resolver.module_loader.search_paths.clear();
+        resolver.add_project_search_paths(temp_dir.path());
+        assert_eq!(resolver.module_loader.search_paths.len(), 5);
+
+        let module_decl = Expr::new(
+            ExprKind::ModuleDeclaration {
+                name: "strs".to_string(),
+            },
+            Span { start: 0, end: 9 },
+        );
+        let result = resolver.resolve_imports(module_decl)?;
+        let ExprKind::Module { body, .. } = result.kind else {
+            panic!("Expected Module, got {:?}", result.kind);
+        };
+        let ExprKind::Block(items) = &body.kind else {
+            panic!("Expected Block, got {:?}", body.kind);
+        };
+        assert!(matches!(&items[0].kind, ExprKind::Module { name, .. } if name == "pad"));
+        Ok(())
+    }
+
     #[test]
     fn test_resolve_if_expression() -> Result<()> {
         let mut resolver = ModuleResolver::new();
@@ -0,0 +1,421 @@
+//! Interpreter/compiled parity harness for stdlib functions
+//!
+//! Every stdlib function exists twice: once in the interpreter and once as
+//! the Rust the transpiler emits for it. `ruchy test --parity` checks that the
+//! two agree. For each entry of [`STDLIB_FUNCTIONS`] the harness generates
+//! random arguments, evaluates each call in a fresh interpreter and, all at
+//! once, in a compiled program, then compares what the calls print.
+//!
+//! Error messages differ between the backends by design, so only the error
+//! category has to match: a call that fails on both sides agrees, a call that
+//! fails on one side only is a [`Divergence`]. A function whose calls do not
+//! compile at all is reported with its compile error.
+
+use crate::backend::compiler::{compile_source_to_binary, CompileOptions};
+use crate::frontend::parser::Parser;
+use crate::runtime::{Interpreter, Value};
+use anyhow::{Context, Result};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::fmt;
+use std::process::Command;
+
+/// Kind of a generated argument
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgKind {
+    /// Integer in `-1000..=1000`
+    Int,
+    /// Integer in `0..=8`, for exponents and counts
+    Count,
+    /// Float with two decimals in `-100..100`
+    Float,
+    /// Short string of letters and spaces
+    Text,
+    /// Array of up to five integers
+    IntArray,
+}
+
+/// A stdlib function and how to call it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FunctionSpec {
+    /// Name shown in reports and matched by `--filter`
+    pub name: &'static str,
+    /// Call with `{0}`, `{1}`, … standing for the arguments
+    pub template: &'static str,
+    /// Arguments to generate, in placeholder order
+    pub args: &'static [ArgKind],
+}
+
+const fn spec(
+    name: &'static str,
+    template: &'static str,
+    args: &'static [ArgKind],
+) -> FunctionSpec {
+    FunctionSpec {
+        name,
+        template,
+        args,
+    }
+}
+
+/// Stdlib functions checked by the harness
+pub const STDLIB_FUNCTIONS: &[FunctionSpec] = {
+    use ArgKind::{Count, Float, Int, IntArray, Text};
+    &[
+        spec("abs", "abs({0})", &[Int]),
+        spec("min", "min({0}, {1})", &[Int, Int]),
+        spec("max", "max({0}, {1})", &[Int, Int]),
+        spec("pow", "pow({0}, {1})", &[Int, Count]),
+        spec("sqrt", "sqrt({0})", &[Float]),
+        spec("floor", "floor({0})", &[Float]),
+        spec("ceil", "ceil({0})", &[Float]),
+        spec("round", "round({0})", &[Float]),
+        spec("sin", "sin({0})", &[Float]),
+        spec("cos", "cos({0})", &[Float]),
+        spec("tan", "tan({0})", &[Float]),
+        spec("exp", "exp({0} / 10.0)", &[Float]),
+        spec("log", "log({0})", &[Float]),
+        spec("log10", "log10({0})", &[Float]),
+        spec("len", "len({0})", &[Text]),
+        spec("string.len", "{0}.len()", &[Text]),
+        spec("string.to_uppercase", "{0}.to_uppercase()", &[Text]),
+        spec("string.to_lowercase", "{0}.to_lowercase()", &[Text]),
+        spec("string.trim", "{0}.trim()", &[Text]),
+        spec("string.contains", "{0}.contains({1})", &[Text, Text]),
+        spec("string.starts_with", "{0}.starts_with({1})", &[Text, Text]),
+        spec("string.ends_with", "{0}.ends_with({1})", &[Text, Text]),
+        spec(
+            "string.replace",
+            "{0}.replace({1}, {2})",
+            &[Text, Text, Text],
+        ),
+        spec("string.repeat", "{0}.repeat({1})", &[Text, Count]),
+        spec("array.len", "{0}.len()", &[IntArray]),
+        spec("array.sum", "{0}.sum()", &[IntArray]),
+        spec("array.contains", "{0}.contains({1})", &[IntArray, Int]),
+    ]
+};
+
+/// Settings of a parity run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParityConfig {
+    /// Random calls generated per function
+    pub cases: usize,
+    /// Seed of the argument generator; the same seed gives the same calls
+    pub seed: u64,
+    /// Only check functions whose name contains this
+    pub filter: Option<String>,
+}
+
+impl Default for ParityConfig {
+    fn default() -> Self {
+        Self {
+            cases: 32,
+            seed: 0x5eed,
+            filter: None,
+        }
+    }
+}
+
+/// Result of one call on one backend
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The call printed this
+    Value(String),
+    /// The call failed (runtime error or panic)
+    Error,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Value(value) => write!(f, "{value}"),
+            Self::Error => write!(f, "<error>"),
+        }
+    }
+}
+
+/// A call whose outcome differs between the backends
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    /// Ruchy source of the call
+    pub call: String,
+    /// Outcome in the interpreter
+    pub interpreted: Outcome,
+    /// Outcome in the compiled program
+    pub compiled: Outcome,
+}
+
+/// Parity of one function
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionReport {
+    /// [`FunctionSpec::name`]
+    pub name: &'static str,
+    /// Calls checked
+    pub cases: usize,
+    /// Calls whose outcomes differ
+    pub divergences: Vec<Divergence>,
+    /// Why the calls could not be compiled, if they could not
+    pub compile_error: Option<String>,
+}
+
+impl FunctionReport {
+    /// Whether both backends agreed on every call
+    pub fn passed(&self) -> bool {
+        self.divergences.is_empty() && self.compile_error.is_none()
+    }
+}
+
+/// Parity of every checked function
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParityReport {
+    /// One report per function, in [`STDLIB_FUNCTIONS`] order
+    pub functions: Vec<FunctionReport>,
+}
+
+impl ParityReport {
+    /// Whether every function passed
+    pub fn passed(&self) -> bool {
+        self.functions.iter().all(FunctionReport::passed)
+    }
+
+    /// Functions that did not pass
+    pub fn failures(&self) -> impl Iterator<Item = &FunctionReport> {
+        self.functions.iter().filter(|report| !report.passed())
+    }
+}
+
+/// Check every function selected by `config`
+///
+/// `on_function` is called with each report as soon as it is done, so
+/// callers can show progress; compiling takes a moment per function.
+///
+/// # Errors
+///
+/// Returns an error when a compiled program cannot be run.
+pub fn run(
+    config: &ParityConfig,
+    mut on_function: impl FnMut(&FunctionReport),
+) -> Result<ParityReport> {
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let mut report = ParityReport::default();
+    for spec in STDLIB_FUNCTIONS {
+        // Generated before filtering, so a filtered run checks the same calls
+        let calls = generate_calls(spec, config.cases, &mut rng);
+        let selected = match config.filter.as_deref() {
+            Some(filter) => spec.name.contains(filter),
+            None => true,
+        };
+        if !selected {
+            continue;
+        }
+        let function = check_function(spec.name, &calls)?;
+        on_function(&function);
+        report.functions.push(function);
+    }
+    Ok(report)
+}
+
+/// Compare the outcomes of `calls` on both backends
+///
+/// # Errors
+///
+/// Returns an error when the compiled program cannot be run.
+pub fn check_function(name: &'static str, calls: &[String]) -> Result<FunctionReport> {
+    let mut report = FunctionReport {
+        name,
+        cases: calls.len(),
+        divergences: Vec::new(),
+        compile_error: None,
+    };
+    let compiled = match run_compiled(calls) {
+        Ok(outcomes) => outcomes,
+        Err(CompiledError::Compile(message)) => {
+            report.compile_error = Some(message);
+            return Ok(report);
+        }
+        Err(CompiledError::Run(e)) => return Err(e),
+    };
+    for (call, compiled) in calls.iter().zip(compiled) {
+        let interpreted = interpret(call);
+        if interpreted != compiled {
+            report.divergences.push(Divergence {
+                call: call.clone(),
+                interpreted,
+                compiled,
+            });
+        }
+    }
+    Ok(report)
+}
+
+/// Random calls of `spec`, with edge values mixed in
+pub fn generate_calls(spec: &FunctionSpec, count: usize, rng: &mut StdRng) -> Vec<String> {
+    (0..count)
+        .map(|_| {
+            let args: Vec<String> = spec.args.iter().map(|&kind| argument(kind, rng)).collect();
+            fill_template(spec.template, &args)
+        })
+        .collect()
+}
+
+fn fill_template(template: &str, args: &[String]) -> String {
+    args.iter()
+        .enumerate()
+        .fold(template.to_string(), |call, (i, arg)| {
+            call.replace(&format!("{{{i}}}"), arg)
+        })
+}
+
+/// Ruchy literal of a random argument of `kind`
+fn argument(kind: ArgKind, rng: &mut StdRng) -> String {
+    let edge = rng.gen_ratio(1, 5);
+    match kind {
+        ArgKind::Int if edge => ["0", "1", "-1"][rng.gen_range(0..3)].to_string(),
+        ArgKind::Int => rng.gen_range(-1000..=1000_i64).to_string(),
+        ArgKind::Count => rng.gen_range(0..=8_u32).to_string(),
+        ArgKind::Float if edge => ["0.0", "0.5", "-0.5", "1.0"][rng.gen_range(0..4)].to_string(),
+        ArgKind::Float => format!("{:.2}", rng.gen_range(-100.0..100.0_f64)),
+        ArgKind::Text if edge => ["\"\"", "\" \"", "\"a\""][rng.gen_range(0..3)].to_string(),
+        ArgKind::Text => {
+            const ALPHABET: &[u8] = b"abcXYZ ";
+            let len = rng.gen_range(1..=8);
+            let text: String = (0..len)
+                .map(|_| char::from(ALPHABET[rng.gen_range(0..ALPHABET.len())]))
+                .collect();
+            format!("\"{text}\"")
+        }
+        ArgKind::IntArray if edge => "[]".to_string(),
+        ArgKind::IntArray => {
+            let len = rng.gen_range(1..=5);
+            let items: Vec<String> = (0..len)
+                .map(|_| rng.gen_range(-50..=50_i64).to_string())
+                .collect();
+            format!("[{}]", items.join(", "))
+        }
+    }
+}
+
+/// Outcome of `call` in a fresh interpreter, printed the way `println` does
+pub fn interpret(call: &str) -> Outcome {
+    let Ok(ast) = Parser::new(call).parse() else {
+        return Outcome::Error;
+    };
+    match Interpreter::new().eval_expr(&ast) {
+        Ok(Value::String(s)) => Outcome::Value(s.to_string()),
+        Ok(value) => Outcome::Value(value.to_string()),
+        Err(_) => Outcome::Error,
+    }
+}
+
+enum CompiledError {
+    /// The program does not transpile or does not compile
+    Compile(String),
+    /// The compiled program could not be started
+    Run(anyhow::Error),
+}
+
+/// Outcomes of `calls` in compiled programs, one `println` per call
+///
+/// A call that panics ends its program, so the calls after it are compiled
+/// again and run in a new program.
+fn run_compiled(calls: &[String]) -> Result<Vec<Outcome>, CompiledError> {
+    let mut outcomes = Vec::with_capacity(calls.len());
+    while outcomes.len() < calls.len() {
+        let remaining = &calls[outcomes.len()..];
+        let (printed, completed) = run_program(remaining)?;
+        let printed_count = printed.len().min(remaining.len());
+        outcomes.extend(printed.into_iter().take(printed_count).map(Outcome::Value));
+        if !completed && outcomes.len() < calls.len() {
+            outcomes.push(Outcome::Error);
+        } else if printed_count < remaining.len() {
+            // Exited cleanly without printing every call
+            outcomes.resize(calls.len(), Outcome::Error);
+        }
+    }
+    Ok(outcomes)
+}
+
+/// Lines printed by a program running `calls`, and whether it exited cleanly
+fn run_program(calls: &[String]) -> Result<(Vec<String>, bool), CompiledError> {
+    let source: String = calls
+        .iter()
+        .map(|call| format!("println({call})\n"))
+        .collect();
+    let dir = tempfile::tempdir()
+        .context("Failed to create parity work directory")
+        .map_err(CompiledError::Run)?;
+    let options = CompileOptions {
+        output: dir.path().join("parity"),
+        opt_level: "0".to_string(),
+        ..CompileOptions::default()
+    };
+    let binary = compile_source_to_binary(&source, &options)
+        .map_err(|e| CompiledError::Compile(format!("{e:#}")))?;
+    let output = Command::new(&binary)
+        .output()
+        .with_context(|| format!("Failed to run {}", binary.display()))
+        .map_err(CompiledError::Run)?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines = stdout.lines().map(str::to_string).collect();
+    Ok((lines, output.status.success()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_templates_are_filled_for_every_function() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for spec in STDLIB_FUNCTIONS {
+            for call in generate_calls(spec, 4, &mut rng) {
+                assert!(!call.contains('{'), "{call}");
+                assert!(Parser::new(&call).parse().is_ok(), "{call} should parse");
+            }
+        }
+    }
+
+    #[test]
+    fn test_generation_is_deterministic_per_seed() {
+        let spec = &STDLIB_FUNCTIONS[0];
+        let first = generate_calls(spec, 8, &mut StdRng::seed_from_u64(1));
+        let again = generate_calls(spec, 8, &mut StdRng::seed_from_u64(1));
+        assert_eq!(first, again);
+    }
+
+    #[test]
+    fn test_interpret_prints_like_println() {
+        assert_eq!(interpret("abs(-3)"), Outcome::Value("3".to_string()));
+        assert_eq!(
+            interpret("\"ab\".to_uppercase()"),
+            Outcome::Value("AB".to_string())
+        );
+        assert_eq!(interpret("no_such_function(1)"), Outcome::Error);
+        assert_eq!(interpret("abs("), Outcome::Error);
+    }
+
+    #[test]
+    fn test_report_passes_only_without_divergences() {
+        let mut function = FunctionReport {
+            name: "abs",
+            cases: 1,
+            divergences: Vec::new(),
+            compile_error: None,
+        };
+        let mut report = ParityReport {
+            functions: vec![function.clone()],
+        };
+        assert!(report.passed());
+
+        function.divergences.push(Divergence {
+            call: "abs(-1)".to_string(),
+            interpreted: Outcome::Value("1".to_string()),
+            compiled: Outcome::Error,
+        });
+        report.functions.push(function);
+        assert!(!report.passed());
+        assert_eq!(report.failures().count(), 1);
+        assert_eq!(Outcome::Error.to_string(), "<error>");
+    }
+}
@@ -0,0 +1,456 @@
+//! Manifest-driven builds of multi-file Ruchy projects
+//!
+//! A project is a directory holding a `Ruchy.toml` manifest:
+//!
+//! ```text
+//! Ruchy.toml        [package], [dependencies] and [packages]
+//! src/main.ruchy    entry point; `mod name;` pulls in src/name.ruchy
+//! src/*.ruchy       modules
+//! ```
+//!
+//! ```toml
+//! [package]
+//! name = "hello"
+//! version = "0.1.0"
+//!
+//! [dependencies]
+//! rand = "0.8"
+//! helpers = { path = "../helpers" }
+//!
+//! [packages]
+//! strs = "^1.2"
+//! ```
+//!
+//! Dependencies are Rust crates and take the same forms as in `Cargo.toml`.
+//! Packages are Ruchy packages from a registry, vendored into `vendor/` by
+//! `ruchy add` (see [`crate::package::resolver`]), where `mod strs;` finds
+//! them.
+//! Crates named by `extern "rust"` blocks must be listed in `[dependencies]`.
+//! [`Project::build`] resolves the modules of the entry point, transpiles the
+//! program and writes a cargo package to `target/ruchy/<name>` that depends on
+//! the manifest's crates plus those the generated code needs. `cargo build`
+//! then leaves the binary in `target/<profile>/<name>`.
+use crate::backend::compiler::{
+    extern_crates, generate_cargo_toml, required_crates, resolve_module_declarations,
+    transpile_with_context, uses_dataframes, uses_http, uses_json,
+};
+use crate::runtime::script::MANIFEST_FILE;
+use crate::utils::common_patterns::ResultContextExt;
+use crate::Parser;
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Parsed `Ruchy.toml`
+#[derive(Debug, Clone, Deserialize)]
+pub struct Manifest {
+    /// The `[package]` table
+    pub package: Package,
+    /// Crates the project depends on, as written in the manifest
+    #[serde(default)]
+    pub dependencies: toml::Table,
+    /// Ruchy packages the project depends on, name to semver requirement
+    #[serde(default)]
+    pub packages: BTreeMap<String, String>,
+}
+
+/// The `[package]` table of a manifest
+#[derive(Debug, Clone, Deserialize)]
+pub struct Package {
+    /// Package and binary name
+    pub name: String,
+    /// Package version
+    pub version: String,
+    /// Rust edition of the generated code
+    #[serde(default = "default_edition")]
+    pub edition: String,
+    /// Entry point relative to the project root, `src/main.ruchy` by default
+    pub main: Option<PathBuf>,
+}
+
+fn default_edition() -> String {
+    "2021".to_string()
+}
+
+impl Manifest {
+    /// Parse and validate manifest text
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text is not valid TOML, the package name is
+    /// not a valid crate name, a dependency has no version, path or git
+    /// source, or a package requirement is not a semver requirement
+    pub fn parse(content: &str) -> Result<Self> {
+        let manifest: Self = toml::from_str(content).context("Invalid manifest")?;
+        validate_name(&manifest.package.name)?;
+        for (name, spec) in &manifest.dependencies {
+            let has_source = match spec {
+                toml::Value::String(_) => true,
+                toml::Value::Table(table) => ["version", "path", "git"]
+                    .iter()
+                    .any(|key| table.contains_key(*key)),
+                _ => false,
+            };
+            if !has_source {
+                bail!("Dependency `{name}` needs a version, path or git source");
+            }
+        }
+        for (name, requirement) in &manifest.packages {
+            validate_name(name)?;
+            semver::VersionReq::parse(requirement).with_context(|| {
+                format!("Package `{name}` has invalid version requirement `{requirement}`")
+            })?;
+        }
+        Ok(manifest)
+    }
+}
+
+/// Check that `name` can name a cargo package
+fn validate_name(name: &str) -> Result<()> {
+    let valid_chars = name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if name.is_empty() || !valid_chars || name.starts_with(|c: char| c.is_ascii_digit()) {
+        bail!(
+            "Invalid package name `{name}`: use letters, digits, `-` and `_`, \
+             not starting with a digit"
+        );
+    }
+    Ok(())
+}
+
+/// A Ruchy project rooted at the directory of its manifest
+#[derive(Debug, Clone)]
+pub struct Project {
+    root: PathBuf,
+    manifest: Manifest,
+}
+
+impl Project {
+    /// Load the project whose `Ruchy.toml` is in `root`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest is missing or invalid
+    pub fn load(root: &Path) -> Result<Self> {
+        let path = root.join(MANIFEST_FILE);
+        let content = fs::read_to_string(&path).file_context("read", &path)?;
+        let manifest = Manifest::parse(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        Ok(Self {
+            root: root.to_path_buf(),
+            manifest,
+        })
+    }
+
+    /// Directory holding the manifest
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The parsed manifest
+    pub fn manifest(&self) -> &Manifest {
+        &self.manifest
+    }
+
+    /// Entry point of the program
+    pub fn main_path(&self) -> PathBuf {
+        let main = self.manifest.package.main.as_deref();
+        self.root
+            .join(main.unwrap_or_else(|| Path::new("src/main.ruchy")))
+    }
+
+    /// Directory of the generated cargo package
+    pub fn package_dir(&self) -> PathBuf {
+        self.root
+            .join("target")
+            .join("ruchy")
+            .join(&self.manifest.package.name)
+    }
+
+    /// Where [`Project::build`] leaves the binary
+    pub fn binary_path(&self, release: bool) -> PathBuf {
+        let profile = if release { "release" } else { "debug" };
+        let binary = format!(
+            "{}{}",
+            self.manifest.package.name,
+            std::env::consts::EXE_SUFFIX
+        );
+        self.root.join("target").join(profile).join(binary)
+    }
+
+    /// Transpile the program and write its cargo package, returning the
+    /// package directory
+    ///
+    /// Files are only rewritten when their contents change, so cargo does not
+    /// rebuild an unchanged program.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a source file cannot be read, parsed or
+    /// transpiled, or the package cannot be written
+    pub fn generate(&self) -> Result<PathBuf> {
+        let main = self.main_path();
+        if !main.is_file() {
+            bail!("Entry point {} not found", main.display());
+        }
+        let source = fs::read_to_string(&main).file_context("read", &main)?;
+        let ast = Parser::new(&source).parse().parse_context("Ruchy source")?;
+        let needs_crates = uses_dataframes(&ast) || uses_json(&ast) || uses_http(&ast);
+        let ast = resolve_module_declarations(ast, Some(&main))?;
+        self.check_extern_crates(&ast)?;
+        let rust = transpile_with_context(ast, Some(&main), false)?.to_string();
+
+        let package_dir = self.package_dir();
+        let cargo_toml = self.cargo_toml(&required_crates(needs_crates, &rust))?;
+        write_if_changed(&package_dir.join("Cargo.toml"), &cargo_toml)?;
+        write_if_changed(&package_dir.join("src").join("main.rs"), &rust)?;
+        Ok(package_dir)
+    }
+
+    /// Generate the cargo package and build it, returning the binary path
+    ///
+    /// Cargo's output is passed through to the terminal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if generating the package or `cargo build` fails
+    pub fn build(&self, release: bool, verbose: bool) -> Result<PathBuf> {
+        let package_dir = self.generate()?;
+        let mut cmd = Command::new("cargo");
+        cmd.arg("build")
+            .arg("--manifest-path")
+            .arg(package_dir.join("Cargo.toml"))
+            .arg("--target-dir")
+            .arg(self.root.join("target"));
+        if release {
+            cmd.arg("--release");
+        }
+        if verbose {
+            cmd.arg("--verbose");
+        }
+        let status = cmd
+            .status()
+            .context("Failed to run cargo build - ensure cargo is installed")?;
+        if !status.success() {
+            bail!("cargo build failed");
+        }
+        let binary = self.binary_path(release);
+        if !binary.is_file() {
+            bail!(
+                "Expected binary not found after cargo build at {}",
+                binary.display()
+            );
+        }
+        Ok(binary)
+    }
+
+    /// Check that every crate named by an `extern "rust"` block is a
+    /// dependency; crate names match keys with `-` read as `_`
+    fn check_extern_crates(&self, ast: &crate::frontend::ast::Expr) -> Result<()> {
+        for name in extern_crates(ast) {
+            let declared = self
+                .manifest
+                .dependencies
+                .keys()
+                .any(|key| key.replace('-', "_") == name);
+            if !declared {
+                bail!(
+                    "extern \"rust\" crate `{name}` is not a dependency: \
+                     add it under [dependencies] in {MANIFEST_FILE}"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// `Cargo.toml` of the generated package (complexity: 5)
+    ///
+    /// `required` crates take their entries from [`generate_cargo_toml`];
+    /// the manifest's own entries win. Relative `path` dependencies are
+    /// rebased onto the project root.
+    fn cargo_toml(&self, required: &[&str]) -> Result<String> {
+        let mut known: toml::Table = toml::from_str(&generate_cargo_toml("ruchy_binary"))?;
+        let known = match known.remove("dependencies") {
+            Some(toml::Value::Table(dependencies)) => dependencies,
+            _ => toml::Table::new(),
+        };
+        let mut dependencies: toml::Table = required
+            .iter()
+            .filter_map(|name| Some(((*name).to_string(), known.get(*name)?.clone())))
+            .collect();
+        for (name, spec) in &self.manifest.dependencies {
+            let mut spec = spec.clone();
+            if let Some(toml::Value::String(path)) = spec.get_mut("path") {
+                *path = self.root.join(&*path).to_string_lossy().into_owned();
+            }
+            dependencies.insert(name.clone(), spec);
+        }
+
+        let package = &self.manifest.package;
+        let package: toml::Table = [
+            ("name", &package.name),
+            ("version", &package.version),
+            ("edition", &package.edition),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), toml::Value::String(value.clone())))
+        .collect();
+        let mut table = toml::Table::new();
+        table.insert("package".to_string(), toml::Value::Table(package));
+        table.insert("dependencies".to_string(), toml::Value::Table(dependencies));
+        // Keep the package out of any workspace the project is nested in
+        table.insert(
+            "workspace".to_string(),
+            toml::Value::Table(toml::Table::new()),
+        );
+        Ok(toml::to_string(&table)?)
+    }
+}
+
+/// Write `contents` to `path` unless it already holds them
+fn write_if_changed(path: &Path, contents: &str) -> Result<()> {
+    if fs::read_to_string(path).is_ok_and(|current| current == contents) {
+        return Ok(());
+    }
+    let dir = path.parent().expect("package paths have a parent");
+    fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    fs::write(path, contents).file_context("write", path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const MANIFEST: &str = r#"
+[package]
+name = "greeter"
+version = "0.2.0"
+authors = ["Ann"]
+
+[dependencies]
+rand = "0.8"
+helpers = { path = "../helpers" }
+"#;
+
+    fn project(files: &[(&str, &str)]) -> TempDir {
+        let dir = TempDir::new().unwrap();
+        for (name, contents) in files {
+            let path = dir.path().join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_manifest_parses_package_and_dependencies() {
+        let manifest = Manifest::parse(MANIFEST).unwrap();
+        assert_eq!(manifest.package.name, "greeter");
+        assert_eq!(manifest.package.version, "0.2.0");
+        assert_eq!(manifest.package.edition, "2021");
+        assert_eq!(manifest.package.main, None);
+        assert_eq!(manifest.dependencies.len(), 2);
+        assert!(manifest.packages.is_empty());
+    }
+
+    #[test]
+    fn test_manifest_rejects_bad_names_and_dependencies() {
+        for name in ["", "9lives", "has space", "dots.in.name"] {
+            let content = format!("[package]\nname = \"{name}\"\nversion = \"0.1.0\"\n");
+            let err = Manifest::parse(&content).unwrap_err();
+            assert!(err.to_string().contains("Invalid package name"), "{name}");
+        }
+        let content = "[package]\nname = \"a\"\nversion = \"0.1.0\"\n\n[dependencies]\nrand = { features = [\"std\"] }\n";
+        let err = Manifest::parse(content).unwrap_err();
+        assert!(err.to_string().contains("`rand` needs a version"));
+        assert!(Manifest::parse("[dependencies]\n").is_err());
+        let content =
+            "[package]\nname = \"a\"\nversion = \"0.1.0\"\n\n[packages]\nstrs = \"latest\"\n";
+        let err = Manifest::parse(content).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("`strs` has invalid version requirement"));
+    }
+
+    #[test]
+    fn test_generate_writes_cargo_package() {
+        let dir = project(&[
+            (MANIFEST_FILE, MANIFEST),
+            (
+                "src/main.ruchy",
+                "mod greet;\n\nfun main() {\n    println(greet::hello())\n}\n",
+            ),
+            (
+                "src/greet.ruchy",
+                "pub fun hello() -> String {\n    \"hi\"\n}\n",
+            ),
+        ]);
+        let project = Project::load(dir.path()).unwrap();
+        let package_dir = project.generate().unwrap();
+        assert_eq!(package_dir, dir.path().join("target/ruchy/greeter"));
+
+        let main_rs = fs::read_to_string(package_dir.join("src/main.rs")).unwrap();
+        assert!(main_rs.contains("mod greet"), "{main_rs}");
+        assert!(main_rs.contains("fn hello"), "{main_rs}");
+
+        let cargo_toml: toml::Table =
+            toml::from_str(&fs::read_to_string(package_dir.join("Cargo.toml")).unwrap()).unwrap();
+        assert_eq!(cargo_toml["package"]["name"].as_str(), Some("greeter"));
+        assert_eq!(cargo_toml["package"]["version"].as_str(), Some("0.2.0"));
+        assert_eq!(cargo_toml["dependencies"]["rand"].as_str(), Some("0.8"));
+        let helpers = cargo_toml["dependencies"]["helpers"]["path"]
+            .as_str()
+            .unwrap();
+        assert_eq!(Path::new(helpers), dir.path().join("../helpers"));
+        assert!(cargo_toml["workspace"].as_table().unwrap().is_empty());
+        assert_eq!(
+            project.binary_path(true),
+            dir.path()
+                .join("target/release")
+                .join(format!("greeter{}", std::env::consts::EXE_SUFFIX))
+        );
+    }
+
+    #[test]
+    fn test_generate_adds_required_crates_and_reports_missing_entry_point() {
+        let manifest = "[package]\nname = \"big\"\nversion = \"0.1.0\"\nmain = \"app.ruchy\"\n";
+        let dir = project(&[(MANIFEST_FILE, manifest)]);
+        let project = Project::load(dir.path()).unwrap();
+        let err = project.generate().unwrap_err();
+        assert!(err.to_string().contains("app.ruchy not found"), "{err}");
+
+        let cargo_toml: toml::Table =
+            toml::from_str(&project.cargo_toml(&["num-bigint", "csv"]).unwrap()).unwrap();
+        let dependencies = cargo_toml["dependencies"].as_table().unwrap();
+        assert_eq!(
+            dependencies.keys().collect::<Vec<_>>(),
+            vec!["csv", "num-bigint"]
+        );
+    }
+
+    #[test]
+    fn test_generate_checks_extern_crates_are_dependencies() {
+        let main = "extern \"rust\" text_case::snake { fun to_snake(text: &str) -> String }\n\
+                    fun main() {\n    println(\"ok\")\n}\n";
+        let dir = project(&[(MANIFEST_FILE, MANIFEST), ("src/main.ruchy", main)]);
+        let err = Project::load(dir.path()).unwrap().generate().unwrap_err();
+        assert!(
+            err.to_string().contains("`text_case` is not a dependency"),
+            "{err}"
+        );
+
+        let manifest = format!("{MANIFEST}text-case = \"0.1\"\n");
+        fs::write(dir.path().join(MANIFEST_FILE), manifest).unwrap();
+        let package_dir = Project::load(dir.path()).unwrap().generate().unwrap();
+        let main_rs = fs::read_to_string(package_dir.join("src/main.rs")).unwrap();
+        assert!(
+            main_rs.contains("mod ruchy_extern_text_case_snake"),
+            "{main_rs}"
+        );
+    }
+}
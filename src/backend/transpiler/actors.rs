@@ -260,6 +260,8 @@ mod tests {
             is_mut: false,
             default_value: None,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
         let handlers = vec![];
 
@@ -300,6 +302,8 @@ mod tests {
             span: Span::new(0, 1),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
         let handlers = vec![ActorHandler {
             message_type: "Add".to_string(),
@@ -446,6 +450,8 @@ mod tests {
                 span: Span::new(0, 1),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
             Param {
                 pattern: Pattern::Identifier("y".to_string()),
@@ -453,6 +459,8 @@ mod tests {
                 span: Span::new(0, 1),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
         ];
         let handlers = vec![ActorHandler {
@@ -481,6 +489,8 @@ mod tests {
                 is_mut: false,
                 default_value: None,
                 decorators: vec![],
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
             StructField {
                 name: "count".to_string(),
@@ -489,6 +499,8 @@ mod tests {
                 is_mut: false,
                 default_value: None,
                 decorators: vec![],
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
         ];
         let handlers = vec![];
@@ -549,6 +561,8 @@ mod tests {
             span: Span::new(0, 1),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
         let handlers = vec![ActorHandler {
             message_type: "Update".to_string(),
@@ -577,6 +591,8 @@ mod tests {
                 span: Span::new(0, 1),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
             Param {
                 pattern: Pattern::Identifier("y".to_string()),
@@ -584,6 +600,8 @@ mod tests {
                 span: Span::new(0, 1),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
         ];
         let handlers = vec![ActorHandler {
@@ -642,6 +660,8 @@ mod tests {
                 span: Span::new(0, 1),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             }],
             body: Box::new(make_ident("msg")),
         }];
@@ -668,6 +688,8 @@ mod tests {
                 is_mut: false,
                 default_value: None,
                 decorators: vec![],
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
             StructField {
                 name: "name".to_string(),
@@ -676,6 +698,8 @@ mod tests {
                 is_mut: false,
                 default_value: None,
                 decorators: vec![],
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
         ];
         let handlers = vec![];
@@ -14,7 +14,7 @@
 
 use super::{FunctionSignature, Transpiler};
 use crate::backend::module_resolver::ModuleResolver;
-use crate::frontend::ast::{Expr, ExprKind, Type, TypeKind};
+use crate::frontend::ast::{EnumVariantKind, Expr, ExprKind, Param, Type, TypeKind};
 use anyhow::Result;
 
 impl Transpiler {
@@ -219,32 +219,110 @@ impl Transpiler {
     pub fn collect_signatures_from_expr(&mut self, expr: &Expr) {
         match &expr.kind {
             ExprKind::Function {
-                name, params, body, ..
+                name,
+                params,
+                body,
+                return_type,
+                ..
             } => {
+                if let Some(return_type) = return_type {
+                    self.return_types
+                        .insert(name.clone(), Self::type_to_string(return_type));
+                }
                 let param_types: Vec<String> = params
                     .iter()
                     .map(|param| Self::type_to_string(&param.ty))
                     .collect();
-                let has_defaults = params.iter().any(|p| p.default_value.is_some());
+                let is_variadic = params.last().is_some_and(Param::is_rest);
+                let has_defaults = is_variadic || params.iter().any(|p| p.default_value.is_some());
+                // A rest parameter given nothing is an empty Vec
                 let default_values = if has_defaults {
                     Some(
                         params
                             .iter()
-                            .map(|p| p.default_value.clone())
+                            .map(|p| {
+                                if p.is_rest() {
+                                    Some(Box::new(Self::empty_vec_expr()))
+                                } else {
+                                    p.default_value.clone()
+                                }
+                            })
                             .collect::<Vec<_>>(),
                     )
                 } else {
                     None
                 };
+                // Callers bind a returned `impl FnMut` closure mutably
+                let returns_fn_mut = super::function_analysis::returned_closure(body).is_some_and(
+                    |(closure_params, closure_body)| {
+                        !super::mutation_detection::mutated_captures(closure_params, closure_body)
+                            .is_empty()
+                    },
+                );
                 let signature = FunctionSignature {
                     name: name.clone(),
                     param_types,
+                    param_names: params.iter().map(Param::name).collect(),
+                    is_variadic,
+                    returns_fn_mut,
                     default_values,
                 };
                 self.function_signatures.insert(name.clone(), signature);
                 // Recurse into function body for nested function signatures
                 self.collect_signatures_from_expr(body);
             }
+            // Tuple variants construct like functions: `Shape::Circle(1.0)`
+            ExprKind::Enum { name, variants, .. } => {
+                for variant in variants {
+                    let EnumVariantKind::Tuple(types) = &variant.kind else {
+                        continue;
+                    };
+                    // Keyed like the constructor path's tokens, `Shape :: Circle`
+                    let path = format!("{name} :: {}", variant.name);
+                    let signature = FunctionSignature {
+                        name: path.clone(),
+                        param_types: types.iter().map(Self::type_to_string).collect(),
+                        param_names: Vec::new(),
+                        is_variadic: false,
+                        returns_fn_mut: false,
+                        default_values: None,
+                    };
+                    self.function_signatures.insert(path, signature);
+                }
+            }
+            // Classes construct through `new` and expose properties as accessor methods
+            ExprKind::Class {
+                name,
+                fields,
+                constructors,
+                properties,
+                ..
+            } => {
+                self.class_names.insert(name.clone());
+                for constructor in constructors {
+                    let ctor_name = constructor.name.as_deref().unwrap_or("new");
+                    let path = format!("{name} :: {ctor_name}");
+                    let signature = FunctionSignature {
+                        name: path.clone(),
+                        param_types: constructor
+                            .params
+                            .iter()
+                            .map(|param| Self::type_to_string(&param.ty))
+                            .collect(),
+                        param_names: constructor.params.iter().map(Param::name).collect(),
+                        is_variadic: false,
+                        returns_fn_mut: false,
+                        default_values: None,
+                    };
+                    self.function_signatures.insert(path, signature);
+                }
+                for property in properties {
+                    if fields.iter().all(|field| field.name != property.name) {
+                        self.class_properties
+                            .insert(property.name.clone(), Self::type_to_string(&property.ty));
+                    }
+                }
+            }
             ExprKind::Block(exprs) => {
                 for e in exprs {
                     self.collect_signatures_from_expr(e);
@@ -253,6 +331,64 @@ impl Transpiler {
             ExprKind::Let { body, .. } => {
                 self.collect_signatures_from_expr(body);
             }
+            ExprKind::Module { name, body } => {
+                self.collect_module_return_types(name, body);
+            }
+            ExprKind::Impl {
+                trait_name: Some(trait_name),
+                for_type,
+                ..
+            } if trait_name == "Display" => {
+                self.display_types.insert(for_type.clone());
+            }
+            ExprKind::ExternBlock { functions, .. } => {
+                for function in functions {
+                    if let Some(return_type) = &function.return_type {
+                        self.return_types
+                            .insert(function.name.clone(), Self::type_to_string(return_type));
+                    }
+                    let param_types = function
+                        .params
+                        .iter()
+                        .map(|param| {
+                            super::extern_blocks::wrapper_param_type(&param.ty)
+                                .unwrap_or_else(|| Self::type_to_string(&param.ty))
+                        })
+                        .collect();
+                    let signature = FunctionSignature {
+                        name: function.name.clone(),
+                        param_types,
+                        param_names: function.params.iter().map(Param::name).collect(),
+                        is_variadic: false,
+                        returns_fn_mut: false,
+                        default_values: None,
+                    };
+                    self.function_signatures
+                        .insert(function.name.clone(), signature);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Record the declared return types of the functions of module `module`
+    fn collect_module_return_types(&mut self, module: &str, body: &Expr) {
+        match &body.kind {
+            ExprKind::Function {
+                name,
+                return_type: Some(return_type),
+                ..
+            } => {
+                self.return_types.insert(
+                    format!("{module}::{name}"),
+                    Self::type_to_string(return_type),
+                );
+            }
+            ExprKind::Block(exprs) => {
+                for e in exprs {
+                    self.collect_module_return_types(module, e);
+                }
+            }
             _ => {}
         }
     }
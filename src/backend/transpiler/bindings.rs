@@ -59,6 +59,40 @@ impl Transpiler {
         Ok(())
     }
 
+    /// Whether `value` is a closure that mutates its captures, written out or
+    /// returned by a call: an `FnMut` is only callable through a `mut` binding
+    fn binds_fn_mut(&self, value: &Expr) -> bool {
+        match &value.kind {
+            ExprKind::Lambda { params, body } => {
+                super::mutation_detection::mutated_captures(params, body)
+                    .iter()
+                    .any(|name| !self.is_shared_capture(name))
+            }
+            ExprKind::Call { func, .. } => matches!(
+                &func.kind,
+                ExprKind::Identifier(name)
+                    if self.function_signatures.get(name).is_some_and(|sig| sig.returns_fn_mut)
+            ),
+            _ => false,
+        }
+    }
+
+    /// Whether closures in `body`, the scope of `let mut name`, share `name`
+    /// with the rest of it while it is not yet a shared capture
+    ///
+    /// `let mut x = 0` followed by the closure on the next line parses with
+    /// the rest of the block as its body, outside any block's statements.
+    fn starts_shared_capture(&self, name: &str, body: &Expr, is_mutable: bool) -> bool {
+        is_mutable
+            && !matches!(body.kind, ExprKind::Literal(Literal::Unit))
+            && !self.is_shared_capture(name)
+            && super::mutation_detection::is_shared_capture(
+                name,
+                [body],
+                self.returned_closure.get(),
+            )
+    }
+
     /// Transpiles let bindings
     /// Complexity: 9 (within Toyota Way limits)
     pub fn transpile_let(
@@ -68,6 +102,13 @@ impl Transpiler {
         body: &Expr,
         is_mutable: bool,
     ) -> Result<TokenStream> {
+        if self.starts_shared_capture(name, body, is_mutable) {
+            self.shared_captures.borrow_mut().insert(name.to_string());
+            let result = self.transpile_let(name, value, body, is_mutable);
+            self.shared_captures.borrow_mut().remove(name);
+            return result;
+        }
+
         // Handle Rust reserved keywords by prefixing with r#
         let safe_name = if Self::is_rust_reserved_keyword(name) {
             format!("r#{name}")
@@ -79,7 +120,8 @@ impl Transpiler {
         // Auto-detect mutability
         let effective_mutability = is_mutable
             || self.mutable_vars.contains(name)
-            || super::mutation_detection::is_variable_mutated(name, body);
+            || super::mutation_detection::is_variable_mutated(name, body)
+            || self.binds_fn_mut(value);
 
         // TRANSPILER-007: Detect empty list literals that need type hints
         let (value_tokens, needs_vec_type_hint) = match &value.kind {
@@ -88,6 +130,15 @@ impl Transpiler {
             _ => (self.transpile_expr(value)?, false),
         };
 
+        // A variable closures share with its block lives in a shared cell
+        let (value_tokens, effective_mutability, needs_vec_type_hint) =
+            if self.is_shared_capture(name) {
+                let cell = quote! { std::rc::Rc::new(std::cell::RefCell::new(#value_tokens)) };
+                (cell, false, false)
+            } else {
+                (value_tokens, effective_mutability, needs_vec_type_hint)
+            };
+
         // HOTFIX: If body is Unit, this is a top-level let statement without scoping
         if matches!(body.kind, ExprKind::Literal(Literal::Unit)) {
             Ok(Self::generate_let_binding(
@@ -264,6 +315,19 @@ impl Transpiler {
         is_mutable: bool,
         is_const: bool,
     ) -> Result<TokenStream> {
+        if self.starts_shared_capture(name, body, is_mutable) {
+            self.shared_captures.borrow_mut().insert(name.to_string());
+            let result = self.transpile_let_with_type(
+                name,
+                type_annotation,
+                value,
+                body,
+                is_mutable,
+                is_const,
+            );
+            self.shared_captures.borrow_mut().remove(name);
+            return result;
+        }
         let safe_name = if Self::is_rust_reserved_keyword(name) {
             format!("r#{name}")
         } else {
@@ -274,11 +338,13 @@ impl Transpiler {
         // PARSER-073: Generate const/let keyword based on const attribute
         let is_mutable_var = is_mutable
             || self.mutable_vars.contains(name)
-            || super::mutation_detection::is_variable_mutated(name, body);
+            || super::mutation_detection::is_variable_mutated(name, body)
+            || self.binds_fn_mut(value);
 
+        let shared = self.is_shared_capture(name);
         let var_keyword = if is_const {
             quote! { const }
-        } else if is_mutable_var {
+        } else if is_mutable_var && !shared {
             quote! { let mut }
         } else {
             quote! { let }
@@ -291,6 +357,23 @@ impl Transpiler {
         // Generate type annotation
         let type_tokens = self.generate_type_tokens(type_annotation, needs_vec_type_hint)?;
 
+        // A variable closures share with its block lives in a shared cell
+        let (value_tokens, type_tokens) = if shared {
+            let cell = quote! { std::rc::Rc::new(std::cell::RefCell::new(#value_tokens)) };
+            let type_tokens = match type_annotation {
+                Some(type_ann) => {
+                    let type_part = self.transpile_type(type_ann)?;
+                    quote! { : std::rc::Rc<std::cell::RefCell<#type_part>> }
+                }
+                None => quote! {},
+            };
+            (cell, type_tokens)
+        } else {
+            (value_tokens, type_tokens)
+        };
+
+        self.track_display_type(name, type_annotation, value);
+
         // Check if body is Unit
         if matches!(body.kind, ExprKind::Literal(Literal::Unit)) {
             Ok(quote! {
@@ -307,6 +390,29 @@ impl Transpiler {
         }
     }
 
+    /// Record whether `name` now holds a value whose type has a `Display` impl
+    fn track_display_type(&self, name: &str, type_annotation: Option<&Type>, value: &Expr) {
+        let declared = match type_annotation.map(|ty| &ty.kind) {
+            Some(TypeKind::Named(type_name)) if self.display_types.contains(type_name) => {
+                Some(type_name.clone())
+            }
+            _ => None,
+        };
+        match declared.or_else(|| self.display_type_of(value)) {
+            Some(type_name) => self.register_variable_type(name, &type_name),
+            None => {
+                // A shadowing binding of another type must not print with `{}`
+                let mut types = self.variable_types.borrow_mut();
+                if types
+                    .get(name)
+                    .is_some_and(|type_name| self.display_types.contains(type_name))
+                {
+                    types.remove(name);
+                }
+            }
+        }
+    }
+
     /// Process value for let-with-type, handling string/list conversions
     fn process_let_value_with_type(
         &self,
@@ -252,9 +252,19 @@ impl Transpiler {
             ExprKind::Enum { .. }
             | ExprKind::Class { .. }
             | ExprKind::Actor { .. }
-            | ExprKind::Effect { .. } => {
+            | ExprKind::Effect { .. }
+            | ExprKind::ExternBlock { .. } => {
                 functions.push(self.transpile_expr(expr)?);
             }
+            // A top-level Rust block without inputs holds items
+            ExprKind::RustBlock {
+                inputs,
+                output: None,
+                code,
+                ..
+            } if inputs.is_empty() => {
+                functions.push(Self::transpile_rust_items(code)?);
+            }
             ExprKind::Import { .. }
             | ExprKind::ImportAll { .. }
             | ExprKind::ImportDefault { .. } => {
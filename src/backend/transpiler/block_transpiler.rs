@@ -6,10 +6,11 @@
 //! **EXTREME TDD Round 71**: Extracted from statements.rs for modularization.
 
 use super::Transpiler;
-use crate::frontend::ast::{Expr, ExprKind, PipelineStage};
+use crate::frontend::ast::{Expr, ExprKind, PipelineStage, StringPart};
 use anyhow::Result;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
+use std::collections::BTreeSet;
 
 impl Transpiler {
     /// Transpile block expressions with smart brace handling
@@ -27,23 +28,26 @@ impl Transpiler {
             }
         }
 
-        let mut statements = Vec::new();
-        for (i, expr) in exprs.iter().enumerate() {
-            let expr_tokens = self.transpile_expr(expr)?;
-            let is_last = i == exprs.len() - 1;
-            let is_let = Self::is_let_expr(expr);
-
-            if is_last {
-                // Last expression - no semicolon (return value)
-                statements.push(expr_tokens);
-            } else if is_let {
-                // Let expressions include their own semicolons
-                statements.push(expr_tokens);
-            } else {
-                // Add semicolon to non-last, non-let expressions
-                statements.push(quote! { #expr_tokens; });
+        let statements = self.with_shared_captures(exprs, || {
+            let mut statements = Vec::new();
+            for (i, expr) in exprs.iter().enumerate() {
+                let expr_tokens = self.transpile_expr(expr)?;
+                let is_last = i == exprs.len() - 1;
+                let is_let = Self::is_let_expr(expr);
+
+                if is_last {
+                    // Last expression - no semicolon (return value)
+                    statements.push(expr_tokens);
+                } else if is_let {
+                    // Let expressions include their own semicolons
+                    statements.push(expr_tokens);
+                } else {
+                    // Add semicolon to non-last, non-let expressions
+                    statements.push(quote! { #expr_tokens; });
+                }
             }
-        }
+            Ok(statements)
+        })?;
 
         Ok(quote! { { #(#statements)* } })
     }
@@ -52,10 +56,78 @@ impl Transpiler {
     fn is_let_expr(expr: &Expr) -> bool {
         matches!(
             &expr.kind,
-            ExprKind::Let { .. } | ExprKind::LetPattern { .. }
+            ExprKind::Let { .. } | ExprKind::LetPattern { .. } | ExprKind::Defer { .. }
         )
     }
 
+    /// Transpile `defer expr` to a drop guard bound in the enclosing block
+    ///
+    /// Guards drop in reverse order at the end of the block, on every way out
+    /// of it (including `return`, `?`, `break` and panics), so deferred
+    /// expressions run last first like in the interpreter. The guard owns
+    /// clones of the local variables `expr` reads, taken at the `defer`, so
+    /// the rest of the block can keep using and changing them. Unlike the
+    /// interpreter, `expr` sees those variables as they were at the `defer`.
+    /// Complexity: 2 (within Toyota Way limits)
+    pub(crate) fn transpile_defer(&self, expr: &Expr) -> Result<TokenStream> {
+        let body = self.transpile_expr(expr)?;
+        let owned = self.deferred_locals(expr).into_iter().map(|name| {
+            if self.is_shared_capture(&name) {
+                // The guard shares the cell, and sees later writes
+                let cell = Self::local_ident(&name);
+                return quote! { let #cell = #cell.clone(); };
+            }
+            let name = self.transpile_identifier(&name);
+            quote! {
+                #[allow(unused_mut)]
+                let mut #name = #name.clone();
+            }
+        });
+        Ok(quote! {
+            let __defer = {
+                #(#owned)*
+                struct __Defer<F: FnOnce()>(Option<F>);
+                impl<F: FnOnce()> Drop for __Defer<F> {
+                    fn drop(&mut self) {
+                        if let Some(deferred) = self.0.take() {
+                            deferred();
+                        }
+                    }
+                }
+                __Defer(Some(move || {
+                    #body;
+                }))
+            };
+        })
+    }
+
+    /// Local variables `expr` reads that it does not bind itself
+    ///
+    /// Called functions, paths, capitalised names and globals are not locals
+    /// and stay out.
+    fn deferred_locals(&self, expr: &Expr) -> BTreeSet<String> {
+        let mut reads = BTreeSet::new();
+        collect_reads(expr, &BTreeSet::new(), &mut reads);
+        let globals = self
+            .global_vars
+            .read()
+            .expect("RwLock poisoned: global_vars lock is corrupted");
+        let consts = self
+            .const_vars
+            .read()
+            .expect("RwLock poisoned: const_vars lock is corrupted");
+        reads.retain(|name| {
+            !name.contains("::")
+                && !name.starts_with(char::is_uppercase)
+                && name != "self"
+                && !globals.contains(name)
+                && !consts.contains(name)
+                && !self.function_signatures.contains_key(name)
+                && !self.module_names.contains(name)
+        });
+        reads
+    }
+
     /// Transpile pipeline expressions (|> operator chains)
     /// Complexity: 6 (within Toyota Way limits)
     pub(crate) fn transpile_pipeline_impl(
@@ -130,6 +202,86 @@ impl Transpiler {
 // Tests
 // ============================================================================
 
+/// Add the names `expr` reads and `bound` does not bind to `reads`
+/// Complexity: 9 (within Toyota Way limits)
+fn collect_reads(expr: &Expr, bound: &BTreeSet<String>, reads: &mut BTreeSet<String>) {
+    let mut visit = |e: &Expr| collect_reads(e, bound, reads);
+    match &expr.kind {
+        ExprKind::Identifier(name) if !bound.contains(name) => {
+            reads.insert(name.clone());
+        }
+        ExprKind::Call { func, args } => {
+            if !matches!(func.kind, ExprKind::Identifier(_)) {
+                visit(func);
+            }
+            args.iter().for_each(visit);
+        }
+        ExprKind::Macro { args, .. } | ExprKind::List(args) | ExprKind::Tuple(args) => {
+            args.iter().for_each(visit);
+        }
+        ExprKind::MethodCall { receiver, args, .. } => {
+            visit(receiver);
+            args.iter().for_each(visit);
+        }
+        ExprKind::StringInterpolation { parts } => {
+            for part in parts {
+                if let StringPart::Expr(e) | StringPart::ExprWithFormat { expr: e, .. } = part {
+                    visit(e);
+                }
+            }
+        }
+        ExprKind::Binary { left, right, .. }
+        | ExprKind::Assign {
+            target: left,
+            value: right,
+        }
+        | ExprKind::CompoundAssign {
+            target: left,
+            value: right,
+            ..
+        }
+        | ExprKind::IndexAccess {
+            object: left,
+            index: right,
+        } => {
+            visit(left);
+            visit(right);
+        }
+        ExprKind::Unary { operand: e, .. } | ExprKind::FieldAccess { object: e, .. } => visit(e),
+        ExprKind::If {
+            condition,
+            then_branch,
+            else_branch,
+        } => {
+            visit(condition);
+            visit(then_branch);
+            if let Some(else_branch) = else_branch {
+                visit(else_branch);
+            }
+        }
+        ExprKind::Block(exprs) => collect_block_reads(exprs, bound, reads),
+        ExprKind::Let { .. } => collect_block_reads(std::slice::from_ref(expr), bound, reads),
+        _ => {}
+    }
+}
+
+/// [`collect_reads`] over statements, where a `let` binds for the rest
+fn collect_block_reads(exprs: &[Expr], bound: &BTreeSet<String>, reads: &mut BTreeSet<String>) {
+    let mut bound = bound.clone();
+    for expr in exprs {
+        if let ExprKind::Let {
+            name, value, body, ..
+        } = &expr.kind
+        {
+            collect_reads(value, &bound, reads);
+            bound.insert(name.clone());
+            collect_reads(body, &bound, reads);
+        } else {
+            collect_reads(expr, &bound, reads);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -250,6 +402,32 @@ mod tests {
         assert!(Transpiler::is_let_expr(&expr));
     }
 
+    #[test]
+    fn test_defer_becomes_drop_guard_owning_read_locals() {
+        let transpiler = make_transpiler();
+        let deferred = make_expr(ExprKind::Defer {
+            expr: Box::new(make_expr(ExprKind::Call {
+                func: Box::new(ident_expr("cleanup")),
+                args: vec![ident_expr("handle")],
+            })),
+        });
+        let block = [deferred, int_expr(1)];
+        let code = transpiler.transpile_block_impl(&block).unwrap().to_string();
+        assert!(code.contains("let __defer"), "{code}");
+        assert!(
+            code.contains("impl < F : FnOnce () > Drop for __Defer < F >"),
+            "{code}"
+        );
+        // The guard owns a clone of `handle`, not of the called `cleanup`
+        assert!(
+            code.contains("let mut handle = handle . clone () ;"),
+            "{code}"
+        );
+        assert!(!code.contains("cleanup . clone"), "{code}");
+        assert!(code.contains("move ||"), "{code}");
+        assert!(!code.contains(";;") && !code.contains("; ;"), "{code}");
+    }
+
     #[test]
     fn test_is_let_expr_not_let() {
         let expr = int_expr(42);
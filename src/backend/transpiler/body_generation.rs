@@ -68,17 +68,20 @@ impl Transpiler {
             return self.transpile_expr(&exprs[0]);
         }
 
-        let mut statements = Vec::new();
-        for (i, expr) in exprs.iter().enumerate() {
-            let expr_tokens = self.transpile_expr(expr)?;
-            let is_let = Self::is_let_expression(expr);
-
-            if i < exprs.len() - 1 {
-                self.push_non_final_statement(&mut statements, expr_tokens, is_let);
-            } else {
-                self.push_final_statement(&mut statements, expr_tokens, is_let, expr);
+        let statements = self.with_shared_captures(exprs, || {
+            let mut statements = Vec::new();
+            for (i, expr) in exprs.iter().enumerate() {
+                let expr_tokens = self.transpile_expr(expr)?;
+                let is_let = Self::is_let_expression(expr);
+
+                if i < exprs.len() - 1 {
+                    self.push_non_final_statement(&mut statements, expr_tokens, is_let);
+                } else {
+                    self.push_final_statement(&mut statements, expr_tokens, is_let, expr);
+                }
             }
-        }
+            Ok(statements)
+        })?;
 
         if statements.is_empty() {
             Ok(quote! {})
@@ -91,7 +94,7 @@ impl Transpiler {
     fn is_let_expression(expr: &Expr) -> bool {
         matches!(
             &expr.kind,
-            ExprKind::Let { .. } | ExprKind::LetPattern { .. }
+            ExprKind::Let { .. } | ExprKind::LetPattern { .. } | ExprKind::Defer { .. }
         )
     }
 
@@ -75,6 +75,12 @@ impl Transpiler {
     ) -> Result<TokenStream> {
         // Get function name for signature lookup
         let func_name = func_tokens.to_string().trim().to_string();
+        // Named arguments are bound to their parameters before coercion
+        let named_resolved = self.resolve_named_args(&func_name, args)?;
+        let args = named_resolved.as_deref().unwrap_or(args);
+        // Rest parameters take a Vec of the surplus arguments
+        let expanded = self.expand_variadic_args(&func_name, args)?;
+        let args = expanded.as_deref().unwrap_or(args);
         // Apply type coercion based on function signature
         let arg_tokens: Result<Vec<_>> = if let Some(signature) =
             self.function_signatures.get(&func_name)
@@ -193,6 +199,8 @@ impl Transpiler {
                 // This is needed because Ruchy string literals default to &str
                 Ok(quote! { #tokens.to_string() })
             }
+            // extern "rust" wrappers borrow strings and slices: lend the argument
+            (_, expected) if expected.starts_with("impl AsRef<") => Ok(quote! { &#tokens }),
             // No coercion needed
             _ => Ok(tokens.clone()),
         }
@@ -25,9 +25,20 @@ impl Transpiler {
             return Ok(tokens);
         }
 
-        // Check if this is a built-in function with special handling
+        // std::csv::read(path) / std::csv::write(path, rows) → csv crate
+        if let Some(tokens) = self.try_transpile_std_csv_call(func, args)? {
+            return Ok(tokens);
+        }
+
+        // Check if this is a built-in function with special handling, unless
+        // the program defines a function of that name
         if let ExprKind::Identifier(name) = &func.kind {
-            if let Some(tokens) = self.try_transpile_builtin_call(&func_tokens, name, args)? {
+            let builtin = if self.function_signatures.contains_key(name) {
+                None
+            } else {
+                self.try_transpile_builtin_call(&func_tokens, name, args)?
+            };
+            if let Some(tokens) = builtin {
                 return Ok(tokens);
             }
         }
@@ -36,13 +47,18 @@ impl Transpiler {
         self.transpile_regular_function_call(&func_tokens, args)
     }
 
-    /// Transform main() calls to __ruchy_main()
+    /// Transform main() calls to __ruchy_main(), and class calls like
+    /// `Point(1, 2)` to the class's `Point::new(1, 2)` constructor
     fn transform_main_call(&self, func: &Expr) -> Result<TokenStream> {
         if let ExprKind::Identifier(name) = &func.kind {
             if name == "main" {
                 let renamed_ident = format_ident!("__ruchy_main");
                 return Ok(quote! { #renamed_ident });
             }
+            if self.class_names.contains(name) {
+                let class_ident = format_ident!("{}", name);
+                return Ok(quote! { #class_ident::new });
+            }
         }
         self.transpile_expr(func)
     }
@@ -77,6 +93,125 @@ impl Transpiler {
         Ok(None)
     }
 
+    /// Try to transpile std::csv::read() / std::csv::write() calls
+    ///
+    /// `read` yields `Vec<HashMap<String, String>>` (one map per row, keyed
+    /// by header or `column_N`); `write` takes any collection of rows whose
+    /// fields implement `Display`. Options must be an object literal with
+    /// literal `delimiter` / `header` values.
+    fn try_transpile_std_csv_call(
+        &self,
+        func: &Expr,
+        args: &[Expr],
+    ) -> Result<Option<TokenStream>> {
+        let ExprKind::FieldAccess { object, field } = &func.kind else {
+            return Ok(None);
+        };
+        let ExprKind::FieldAccess {
+            object: std_obj,
+            field: module_name,
+        } = &object.kind
+        else {
+            return Ok(None);
+        };
+        if !matches!(&std_obj.kind, ExprKind::Identifier(name) if name == "std")
+            || module_name != "csv"
+            || !matches!(field.as_str(), "read" | "write")
+        {
+            return Ok(None);
+        }
+        let options_index = if field == "write" { 2 } else { 1 };
+        let (delimiter, header) = Self::csv_literal_options(args.get(options_index))?;
+        match (field.as_str(), args) {
+            ("read", [path] | [path, _]) => {
+                let path = self.transpile_expr(path)?;
+                Ok(Some(quote! { {
+                    let mut reader = csv::ReaderBuilder::new()
+                        .delimiter(#delimiter)
+                        .has_headers(#header)
+                        .from_path(#path)
+                        .expect("Failed to open CSV file");
+                    let headers: Vec<String> = if #header {
+                        reader
+                            .headers()
+                            .expect("Failed to read CSV header")
+                            .iter()
+                            .map(String::from)
+                            .collect()
+                    } else {
+                        Vec::new()
+                    };
+                    reader
+                        .records()
+                        .map(|record| {
+                            let record = record.expect("Failed to read CSV record");
+                            record
+                                .iter()
+                                .enumerate()
+                                .map(|(i, field)| {
+                                    let name = headers
+                                        .get(i)
+                                        .cloned()
+                                        .unwrap_or_else(|| format!("column_{}", i + 1));
+                                    (name, field.to_string())
+                                })
+                                .collect::<std::collections::HashMap<String, String>>()
+                        })
+                        .collect::<Vec<_>>()
+                } }))
+            }
+            ("write", [path, rows] | [path, rows, _]) => {
+                let path = self.transpile_expr(path)?;
+                let rows = self.transpile_expr(rows)?;
+                Ok(Some(quote! { {
+                    let mut writer = csv::WriterBuilder::new()
+                        .delimiter(#delimiter)
+                        .from_path(#path)
+                        .expect("Failed to create CSV file");
+                    for row in &(#rows) {
+                        writer
+                            .write_record(row.iter().map(|field| field.to_string()))
+                            .expect("Failed to write CSV record");
+                    }
+                    writer.flush().expect("Failed to write CSV file");
+                } }))
+            }
+            ("read", _) => bail!("std::csv::read() expects a path and optional options"),
+            _ => bail!("std::csv::write() expects a path, rows and optional options"),
+        }
+    }
+
+    /// Delimiter byte and header flag from a literal `std::csv` options object
+    fn csv_literal_options(options: Option<&Expr>) -> Result<(u8, bool)> {
+        let (mut delimiter, mut header) = (b',', true);
+        let Some(options) = options else {
+            return Ok((delimiter, header));
+        };
+        let ExprKind::ObjectLiteral { fields } = &options.kind else {
+            bail!("std::csv options must be an object literal when compiled");
+        };
+        for field in fields {
+            match field {
+                crate::frontend::ast::ObjectField::KeyValue { key, value } => {
+                    match (key.as_str(), &value.kind) {
+                        ("delimiter", ExprKind::Literal(Literal::String(s)))
+                            if s.len() == 1 && s.is_ascii() =>
+                        {
+                            delimiter = s.as_bytes()[0];
+                        }
+                        ("header", ExprKind::Literal(Literal::Bool(b))) => header = *b,
+                        ("records", _) => {}
+                        _ => bail!("Unsupported std::csv option '{key}' when compiled"),
+                    }
+                }
+                crate::frontend::ast::ObjectField::Spread { .. } => {
+                    bail!("std::csv options cannot use spread when compiled")
+                }
+            }
+        }
+        Ok((delimiter, header))
+    }
+
     /// Try to transpile built-in function calls
     fn try_transpile_builtin_call(
         &self,
@@ -95,6 +230,19 @@ impl Transpiler {
             return Ok(Some(result));
         }
 
+        // format("{} is {0}", x) → format!("{} is {0}", x)
+        if base_name == "format" {
+            if let Some(ExprKind::Literal(Literal::String(template))) =
+                args.first().map(|a| &a.kind)
+            {
+                let arg_tokens = args[1..]
+                    .iter()
+                    .map(|arg| self.transpile_expr(arg))
+                    .collect::<Result<Vec<_>>>()?;
+                return Ok(Some(quote! { format!(#template #(, #arg_tokens)*) }));
+            }
+        }
+
         // len(x) → x.len()
         if base_name == "len" && args.len() == 1 {
             let arg_tokens = self.transpile_expr(&args[0])?;
@@ -191,6 +339,11 @@ impl Transpiler {
         method: &str,
         args: &[Expr],
     ) -> Result<TokenStream> {
+        // The parser keeps turbofish generics in the name: `collect::<Vec<_>>`
+        if let Some((name, generics)) = method.split_once("::") {
+            return self.transpile_turbofish_method_call(object, name, generics, args);
+        }
+
         // Check for DataFrame builder pattern
         if method == "column" || method == "build" {
             if let Some(builder_tokens) =
@@ -207,10 +360,78 @@ impl Transpiler {
             }
         }
 
+        // Decimal rounding and formatting helpers
+        if let Some(tokens) = self.try_transpile_decimal_method(object, method, args)? {
+            return Ok(tokens);
+        }
+
         // Use the standard implementation
         self.transpile_method_call_standard(object, method, args)
     }
 
+    /// Transpile `obj.name::<T>(args)`, keeping the generics as written (complexity: 2)
+    fn transpile_turbofish_method_call(
+        &self,
+        object: &Expr,
+        name: &str,
+        generics: &str,
+        args: &[Expr],
+    ) -> Result<TokenStream> {
+        let obj_tokens = self.transpile_expr(object)?;
+        let method_ident = format_ident!("{}", name);
+        let generic_tokens: TokenStream = syn::parse_str(generics)
+            .map_err(|e| anyhow::anyhow!("Invalid generics '{generics}' on {name}: {e}"))?;
+        let arg_tokens = args
+            .iter()
+            .map(|a| self.transpile_expr(a))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(quote! { #obj_tokens.#method_ident::#generic_tokens(#(#arg_tokens),*) })
+    }
+
+    /// Try to transpile Decimal-only methods onto `rust_decimal` calls
+    ///
+    /// `d.round_dp(2, "half_up")` → `d.round_dp_with_strategy(2, MidpointAwayFromZero)`
+    /// and `d.to_fixed(2)` → the rescaled decimal's text. One-argument
+    /// `round_dp` already exists on `rust_decimal::Decimal` and passes through.
+    fn try_transpile_decimal_method(
+        &self,
+        object: &Expr,
+        method: &str,
+        args: &[Expr],
+    ) -> Result<Option<TokenStream>> {
+        match (method, args) {
+            ("round_dp", [dp, mode]) => {
+                let ExprKind::Literal(Literal::String(mode)) = &mode.kind else {
+                    bail!("round_dp() rounding mode must be a string literal when transpiled");
+                };
+                let strategy = match crate::runtime::eval_decimal::rounding_strategy(mode) {
+                    Ok(strategy) => format_ident!("{}", format!("{strategy:?}")),
+                    Err(e) => bail!("{e}"),
+                };
+                let obj_tokens = self.transpile_expr(object)?;
+                let dp_tokens = self.transpile_expr(dp)?;
+                Ok(Some(quote! {
+                    #obj_tokens.round_dp_with_strategy(
+                        (#dp_tokens) as u32,
+                        rust_decimal::RoundingStrategy::#strategy
+                    )
+                }))
+            }
+            ("to_fixed", [dp]) => {
+                let obj_tokens = self.transpile_expr(object)?;
+                let dp_tokens = self.transpile_expr(dp)?;
+                Ok(Some(quote! {
+                    {
+                        let mut fixed = #obj_tokens.round_dp((#dp_tokens) as u32);
+                        fixed.rescale((#dp_tokens) as u32);
+                        fixed.to_string()
+                    }
+                }))
+            }
+            _ => Ok(None),
+        }
+    }
+
     /// Try to transpile `DataFrame` builder pattern
     fn try_transpile_builder_pattern(
         &self,
@@ -316,6 +537,14 @@ impl Transpiler {
         arg_tokens: &[TokenStream],
         object: &Expr,
     ) -> Result<TokenStream> {
+        // Lazy pipelines (`xs.iter().map(f).take(n)`) stay Rust iterator chains
+        if Self::is_lazy_iterator_expr(object) {
+            if let Some(tokens) =
+                Self::transpile_lazy_iterator_method(obj_tokens, object, method, arg_tokens)
+            {
+                return Ok(tokens);
+            }
+        }
         match method {
             // Iterator operations
             "map" | "filter" | "reduce" => {
@@ -380,7 +609,9 @@ impl Transpiler {
             // String methods
             "to_s" | "to_string" | "to_upper" | "to_lower" | "upper" | "lower" | "length"
             | "substring" | "strip" | "lstrip" | "rstrip" | "startswith" | "endswith" | "split"
-            | "replace" => self.transpile_string_methods(obj_tokens, method, arg_tokens),
+            | "replace" | "replace_all" | "repeat" | "pad_start" | "pad_end" => {
+                self.transpile_string_methods(obj_tokens, method, arg_tokens)
+            }
             // List methods
             "append" => Ok(quote! { #obj_tokens.push(#(#arg_tokens),*) }),
             "extend" => Ok(quote! { #obj_tokens.extend(#(#arg_tokens),*) }),
@@ -459,6 +690,19 @@ mod tests {
         assert!(!result.to_string().contains("__ruchy"));
     }
 
+    #[test]
+    fn test_turbofish_method_call_keeps_generics() {
+        let transpiler = make_transpiler();
+        let result = transpiler
+            .transpile_method_call_impl(&ident_expr("xs"), "collect::<Vec<_>>", &[])
+            .unwrap();
+        assert_eq!(result.to_string(), "xs . collect :: < Vec < _ >> ()");
+        let result = transpiler
+            .transpile_method_call_impl(&string_expr("42"), "parse::<i32>", &[])
+            .unwrap();
+        assert!(result.to_string().ends_with(". parse :: < i32 > ()"));
+    }
+
     #[test]
     fn test_builtin_len_call() {
         let transpiler = make_transpiler();
@@ -229,10 +229,16 @@ impl MinimalCodeGen {
     fn gen_literal(lit: &Literal) -> Result<String> {
         match lit {
             Literal::Integer(i, _) => Ok(i.to_string()),
+            Literal::BigInt(digits) => Ok(format!(
+                "\"{digits}\".parse::<num_bigint::BigInt>().expect(\"valid BigInt literal\")"
+            )),
+            Literal::Decimal(text) => Ok(format!(
+                "\"{text}\".parse::<rust_decimal::Decimal>().expect(\"valid Decimal literal\")"
+            )),
             Literal::Float(f) => Ok(f.to_string()),
             Literal::String(s) => Ok(format!("\"{}\"", s.replace('"', "\\\""))),
             Literal::Bool(b) => Ok(b.to_string()),
-            Literal::Char(c) => Ok(format!("'{c}'")),
+            Literal::Char(c) => Ok(format!("{c:?}")),
             Literal::Byte(b) => Ok(format!("b'{}'", *b as char)),
             Literal::Unit => Ok("()".to_string()),
             Literal::Null => Ok("None".to_string()),
@@ -7,7 +7,7 @@
 #![allow(clippy::doc_markdown)]
 
 use super::Transpiler;
-use crate::frontend::ast::{ComprehensionClause, Expr};
+use crate::frontend::ast::{ComprehensionClause, Expr, ExprKind};
 use anyhow::{bail, Result};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
@@ -32,60 +32,88 @@ impl Transpiler {
     }
 
     /// Transpile list comprehension with nested clauses
-    /// Complexity: 9 (within Toyota Way limits)
+    /// Complexity: 4 (within Toyota Way limits)
     pub fn transpile_list_comprehension_new(
         &self,
         element: &Expr,
         clauses: &[ComprehensionClause],
     ) -> Result<TokenStream> {
-        if clauses.is_empty() {
-            bail!("List comprehension must have at least one for clause");
-        }
+        let Some(clause) = Self::single_clause(clauses, "List")? else {
+            let element_tokens = self.transpile_expr(element)?;
+            let loops = self.build_comprehension_loops(
+                clauses,
+                &quote! { __comprehension.push(#element_tokens); },
+            )?;
+            return Ok(quote! {
+                {
+                    let mut __comprehension = Vec::new();
+                    #loops
+                    __comprehension
+                }
+            });
+        };
 
         let element_tokens = self.transpile_expr(element)?;
-        let mut result_tokens = None;
+        let chain = self.build_single_clause(clause)?;
+        let pattern = Self::parse_var_pattern(&clause.variable)?;
+        Ok(quote! {
+            #chain
+                .map(|#pattern| #element_tokens)
+                .collect::<Vec<_>>()
+        })
+    }
 
-        for (i, clause) in clauses.iter().enumerate() {
-            let iter_tokens = self.transpile_expr(&clause.iterable)?;
-            let var_pattern = Self::parse_var_pattern(&clause.variable)?;
+    /// Return the lone clause of a single-generator comprehension, `None` when
+    /// there are several generators, and an error when there are none
+    fn single_clause<'a>(
+        clauses: &'a [ComprehensionClause],
+        kind: &str,
+    ) -> Result<Option<&'a ComprehensionClause>> {
+        match clauses {
+            [] => bail!("{kind} comprehension must have at least one for clause"),
+            [clause] => Ok(Some(clause)),
+            _ => Ok(None),
+        }
+    }
 
-            if i == 0 {
-                result_tokens = Some(Self::build_first_clause(
-                    &iter_tokens,
-                    &var_pattern,
-                    clause.condition.as_deref(),
-                    self,
-                )?);
-            } else {
-                let prev_chain = result_tokens
-                    .expect("result_tokens should be Some after first clause iteration (i > 0)");
-                let outer_var = &clauses[i - 1].variable;
-                let outer_pattern = Self::parse_var_pattern(outer_var)?;
-
-                result_tokens = Some(Self::build_nested_clause(
-                    &prev_chain,
-                    &outer_pattern,
-                    &iter_tokens,
-                    &var_pattern,
-                    clause.condition.as_deref(),
-                    self,
-                )?);
+    /// Build the iterator chain for a single-generator comprehension
+    fn build_single_clause(&self, clause: &ComprehensionClause) -> Result<TokenStream> {
+        let iter_tokens = self.transpile_expr(&clause.iterable)?;
+        let var_pattern = Self::parse_var_pattern(&clause.variable)?;
+        Self::build_first_clause(
+            &iter_tokens,
+            &var_pattern,
+            clause.condition.as_deref(),
+            self,
+        )
+    }
+
+    /// Build nested `for` loops for a multi-generator comprehension.
+    ///
+    /// Follows Python ordering: the leftmost `for` is the outermost loop, every
+    /// `if` filters inside the generator it follows, and later generators and
+    /// filters can refer to variables bound by earlier ones. Inner iterables are
+    /// re-evaluated per outer item; bare variables are cloned so they survive
+    /// repeated iteration.
+    fn build_comprehension_loops(
+        &self,
+        clauses: &[ComprehensionClause],
+        innermost: &TokenStream,
+    ) -> Result<TokenStream> {
+        let mut body = innermost.clone();
+        for (i, clause) in clauses.iter().enumerate().rev() {
+            let var_pattern = Self::parse_var_pattern(&clause.variable)?;
+            let mut iter_tokens = self.transpile_expr(&clause.iterable)?;
+            if i > 0 && matches!(clause.iterable.kind, ExprKind::Identifier(_)) {
+                iter_tokens = quote! { #iter_tokens.clone() };
             }
+            if let Some(cond) = clause.condition.as_deref() {
+                let cond_tokens = self.transpile_expr(cond)?;
+                body = quote! { if #cond_tokens { #body } };
+            }
+            body = quote! { for #var_pattern in #iter_tokens { #body } };
         }
-
-        let final_var = &clauses
-            .last()
-            .expect("clauses is non-empty, validated at function entry")
-            .variable;
-        let final_pattern = Self::parse_var_pattern(final_var)?;
-
-        let final_chain = result_tokens
-            .expect("result_tokens should be Some after processing at least one clause");
-        Ok(quote! {
-            #final_chain
-                .map(|#final_pattern| #element_tokens)
-                .collect::<Vec<_>>()
-        })
+        Ok(body)
     }
 
     /// Build first clause of comprehension chain
@@ -109,33 +137,6 @@ impl Transpiler {
         }
     }
 
-    /// Build nested clause using flat_map
-    fn build_nested_clause(
-        prev_chain: &TokenStream,
-        outer_pattern: &TokenStream,
-        iter_tokens: &TokenStream,
-        var_pattern: &TokenStream,
-        condition: Option<&Expr>,
-        transpiler: &Transpiler,
-    ) -> Result<TokenStream> {
-        if let Some(cond) = condition {
-            let cond_tokens = transpiler.transpile_expr(cond)?;
-            Ok(quote! {
-                #prev_chain
-                    .flat_map(|#outer_pattern| {
-                        #iter_tokens
-                            .into_iter()
-                            .filter(|#var_pattern| #cond_tokens)
-                    })
-            })
-        } else {
-            Ok(quote! {
-                #prev_chain
-                    .flat_map(|#outer_pattern| #iter_tokens.into_iter())
-            })
-        }
-    }
-
     /// Transpiles list comprehensions (legacy single-clause)
     /// Complexity: 8 (within Toyota Way limits)
     pub fn transpile_list_comprehension(
@@ -230,58 +231,33 @@ impl Transpiler {
     }
 
     /// Transpile set comprehension with nested clauses
-    /// Complexity: 9 (within Toyota Way limits)
+    /// Complexity: 4 (within Toyota Way limits)
     pub fn transpile_set_comprehension_new(
         &self,
         element: &Expr,
         clauses: &[ComprehensionClause],
     ) -> Result<TokenStream> {
-        if clauses.is_empty() {
-            bail!("Set comprehension must have at least one for clause");
-        }
+        let Some(clause) = Self::single_clause(clauses, "Set")? else {
+            let element_tokens = self.transpile_expr(element)?;
+            let loops = self.build_comprehension_loops(
+                clauses,
+                &quote! { __comprehension.insert(#element_tokens); },
+            )?;
+            return Ok(quote! {
+                {
+                    let mut __comprehension = std::collections::HashSet::new();
+                    #loops
+                    __comprehension
+                }
+            });
+        };
 
         let element_tokens = self.transpile_expr(element)?;
-        let mut result_tokens = None;
-
-        for (i, clause) in clauses.iter().enumerate() {
-            let iter_tokens = self.transpile_expr(&clause.iterable)?;
-            let var_pattern = Self::parse_var_pattern(&clause.variable)?;
-
-            if i == 0 {
-                result_tokens = Some(Self::build_first_clause(
-                    &iter_tokens,
-                    &var_pattern,
-                    clause.condition.as_deref(),
-                    self,
-                )?);
-            } else {
-                let prev_chain = result_tokens
-                    .expect("result_tokens should be Some after first clause iteration (i > 0)");
-                let outer_var = &clauses[i - 1].variable;
-                let outer_pattern = Self::parse_var_pattern(outer_var)?;
-
-                result_tokens = Some(Self::build_nested_clause(
-                    &prev_chain,
-                    &outer_pattern,
-                    &iter_tokens,
-                    &var_pattern,
-                    clause.condition.as_deref(),
-                    self,
-                )?);
-            }
-        }
-
-        let final_var = &clauses
-            .last()
-            .expect("clauses is non-empty, validated at function entry")
-            .variable;
-        let final_pattern = Self::parse_var_pattern(final_var)?;
-
-        let final_chain = result_tokens
-            .expect("result_tokens should be Some after processing at least one clause");
+        let chain = self.build_single_clause(clause)?;
+        let pattern = Self::parse_var_pattern(&clause.variable)?;
         Ok(quote! {
-            #final_chain
-                .map(|#final_pattern| #element_tokens)
+            #chain
+                .map(|#pattern| #element_tokens)
                 .collect::<std::collections::HashSet<_>>()
         })
     }
@@ -329,60 +305,34 @@ impl Transpiler {
     }
 
     /// Transpile dict comprehension with nested clauses
-    /// Complexity: 9 (within Toyota Way limits)
+    /// Complexity: 4 (within Toyota Way limits)
     pub fn transpile_dict_comprehension_new(
         &self,
         key: &Expr,
         value: &Expr,
         clauses: &[ComprehensionClause],
     ) -> Result<TokenStream> {
-        if clauses.is_empty() {
-            bail!("Dict comprehension must have at least one for clause");
-        }
-
         let key_tokens = self.transpile_expr(key)?;
         let value_tokens = self.transpile_expr(value)?;
-        let mut result_tokens = None;
-
-        for (i, clause) in clauses.iter().enumerate() {
-            let iter_tokens = self.transpile_expr(&clause.iterable)?;
-            let var_pattern = Self::parse_var_pattern(&clause.variable)?;
-
-            if i == 0 {
-                result_tokens = Some(Self::build_first_clause(
-                    &iter_tokens,
-                    &var_pattern,
-                    clause.condition.as_deref(),
-                    self,
-                )?);
-            } else {
-                let prev_chain = result_tokens
-                    .expect("result_tokens should be Some after first clause iteration (i > 0)");
-                let outer_var = &clauses[i - 1].variable;
-                let outer_pattern = Self::parse_var_pattern(outer_var)?;
-
-                result_tokens = Some(Self::build_nested_clause(
-                    &prev_chain,
-                    &outer_pattern,
-                    &iter_tokens,
-                    &var_pattern,
-                    clause.condition.as_deref(),
-                    self,
-                )?);
-            }
-        }
-
-        let final_var = &clauses
-            .last()
-            .expect("clauses is non-empty, validated at function entry")
-            .variable;
-        let final_pattern = Self::parse_var_pattern(final_var)?;
+        let Some(clause) = Self::single_clause(clauses, "Dict")? else {
+            let loops = self.build_comprehension_loops(
+                clauses,
+                &quote! { __comprehension.insert(#key_tokens, #value_tokens); },
+            )?;
+            return Ok(quote! {
+                {
+                    let mut __comprehension = std::collections::HashMap::new();
+                    #loops
+                    __comprehension
+                }
+            });
+        };
 
-        let final_chain = result_tokens
-            .expect("result_tokens should be Some after processing at least one clause");
+        let chain = self.build_single_clause(clause)?;
+        let pattern = Self::parse_var_pattern(&clause.variable)?;
         Ok(quote! {
-            #final_chain
-                .map(|#final_pattern| (#key_tokens, #value_tokens))
+            #chain
+                .map(|#pattern| (#key_tokens, #value_tokens))
                 .collect::<std::collections::HashMap<_, _>>()
         })
     }
@@ -476,6 +426,7 @@ mod tests {
     fn make_clause(var: &str, iterable: Expr, condition: Option<Expr>) -> ComprehensionClause {
         ComprehensionClause {
             variable: var.to_string(),
+            pattern: crate::frontend::ast::Pattern::Identifier(var.to_string()),
             iterable: Box::new(iterable),
             condition: condition.map(Box::new),
         }
@@ -616,7 +567,47 @@ mod tests {
         let result = transpiler.transpile_list_comprehension_new(&element, &clauses);
         assert!(result.is_ok());
         let code = result.unwrap().to_string();
-        assert!(code.contains("flat_map"));
+        // Outer variable must stay in scope for the element expression
+        assert!(code.contains("for x in"));
+        assert!(code.contains("for y in"));
+        assert!(code.contains("__comprehension . push"));
+    }
+
+    #[test]
+    fn test_list_comprehension_new_interleaved_filters() {
+        let transpiler = Transpiler::new();
+        let element = ident_expr("y");
+        let clauses = vec![
+            make_clause(
+                "x",
+                ident_expr("xs"),
+                Some(binary_expr(
+                    ident_expr("x"),
+                    crate::frontend::ast::BinaryOp::Greater,
+                    int_expr(0),
+                )),
+            ),
+            make_clause(
+                "y",
+                ident_expr("ys"),
+                Some(binary_expr(
+                    ident_expr("y"),
+                    crate::frontend::ast::BinaryOp::Less,
+                    ident_expr("x"),
+                )),
+            ),
+        ];
+
+        let code = transpiler
+            .transpile_list_comprehension_new(&element, &clauses)
+            .unwrap()
+            .to_string();
+        let outer_loop = code.find("for x in xs").expect("outer loop");
+        let outer_filter = code.find("if ").expect("outer filter");
+        let inner_loop = code.find("for y in ys . clone ()").expect("inner loop");
+        let inner_filter = code.rfind("if ").expect("inner filter");
+        assert!(outer_loop < outer_filter);
+        assert!(outer_filter < inner_loop && inner_loop < inner_filter);
     }
 
     #[test]
@@ -901,57 +892,6 @@ mod tests {
         assert!(code.contains("filter"));
     }
 
-    // ========================================================================
-    // build_nested_clause tests
-    // ========================================================================
-
-    #[test]
-    fn test_build_nested_clause_no_condition() {
-        let transpiler = Transpiler::new();
-        let prev_chain = quote! { (0..3).into_iter() };
-        let outer_pattern = quote! { x };
-        let iter_tokens = quote! { (0..3) };
-        let var_pattern = quote! { y };
-
-        let result = Transpiler::build_nested_clause(
-            &prev_chain,
-            &outer_pattern,
-            &iter_tokens,
-            &var_pattern,
-            None,
-            &transpiler,
-        );
-        assert!(result.is_ok());
-        let code = result.unwrap().to_string();
-        assert!(code.contains("flat_map"));
-    }
-
-    #[test]
-    fn test_build_nested_clause_with_condition() {
-        let transpiler = Transpiler::new();
-        let prev_chain = quote! { (0..3).into_iter() };
-        let outer_pattern = quote! { x };
-        let iter_tokens = quote! { (0..3) };
-        let var_pattern = quote! { y };
-        let condition = binary_expr(
-            ident_expr("y"),
-            crate::frontend::ast::BinaryOp::Less,
-            ident_expr("x"),
-        );
-
-        let result = Transpiler::build_nested_clause(
-            &prev_chain,
-            &outer_pattern,
-            &iter_tokens,
-            &var_pattern,
-            Some(&condition),
-            &transpiler,
-        );
-        assert!(result.is_ok());
-        let code = result.unwrap().to_string();
-        assert!(code.contains("filter"));
-    }
-
     // ========================================================================
     // Pattern comprehension edge cases
     // ========================================================================
@@ -978,7 +918,8 @@ mod tests {
         let result = transpiler.transpile_set_comprehension_new(&element, &clauses);
         assert!(result.is_ok());
         let code = result.unwrap().to_string();
-        assert!(code.contains("flat_map"));
+        assert!(code.contains("HashSet :: new"));
+        assert!(code.contains("__comprehension . insert"));
     }
 
     #[test]
@@ -994,7 +935,8 @@ mod tests {
         let result = transpiler.transpile_dict_comprehension_new(&key, &value, &clauses);
         assert!(result.is_ok());
         let code = result.unwrap().to_string();
-        assert!(code.contains("flat_map"));
+        assert!(code.contains("HashMap :: new"));
+        assert!(code.contains("__comprehension . insert"));
     }
 
     // ========================================================================
@@ -1033,8 +975,8 @@ mod tests {
         let result = transpiler.transpile_list_comprehension_new(&element, &clauses);
         assert!(result.is_ok());
         let code = result.unwrap().to_string();
-        // Should have nested flat_maps
-        assert!(code.matches("flat_map").count() >= 2);
+        // Should have one nested loop per generator
+        assert_eq!(code.matches("for ").count(), 3);
     }
 
     // ========================================================================
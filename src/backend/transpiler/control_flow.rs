@@ -169,77 +169,274 @@ impl Transpiler {
         })
     }
 
+    /// Prefix a transpiled loop with its label, if it has one
+    /// Complexity: 2 (within Toyota Way limits)
+    pub fn label_loop(label: Option<&String>, loop_tokens: TokenStream) -> TokenStream {
+        match label {
+            Some(label) => {
+                let lifetime = Self::loop_label_lifetime(label);
+                quote! { #lifetime: #loop_tokens }
+            }
+            None => loop_tokens,
+        }
+    }
+
+    /// The Rust label for a Ruchy loop label: `outer` and `@outer` become `'outer`
+    /// Complexity: 1 (within Toyota Way limits)
+    pub(crate) fn loop_label_lifetime(label: &str) -> syn::Lifetime {
+        let name = label.trim_start_matches(['\'', '@']);
+        syn::Lifetime::new(&format!("'{name}"), proc_macro2::Span::call_site())
+    }
+
     /// Transpiles try-catch-finally blocks
-    /// Complexity: 8 (within Toyota Way limits)
+    ///
+    /// The try body runs in a closure returning `Result<_, Box<dyn Any + Send>>`:
+    /// `throw` and `?` inside it return `Err`, and `catch_unwind` turns panics
+    /// (including `throw` from called functions) into the same boxed error.
+    /// Catch clauses are tried in order; the finally block runs on both paths
+    /// before the value is produced.
+    /// Complexity: 5 (within Toyota Way limits)
     pub fn transpile_try_catch(
         &self,
         try_block: &Expr,
         catch_clauses: &[CatchClause],
         finally_block: Option<&Expr>,
     ) -> Result<TokenStream> {
-        // DEFECT-TRY-CATCH FIX: Use catch_unwind to catch panics from throw
-        // throw -> panic!() requires catch_unwind, not Result pattern
-        let try_body = self.transpile_expr(try_block)?;
+        if catch_clauses.is_empty() && finally_block.is_none() {
+            bail!("Try block must have at least one catch clause or a finally block");
+        }
+        let was_in_try = self.in_try_context.replace(true);
+        let try_body = self.transpile_expr(try_block);
+        self.in_try_context.set(was_in_try);
+        let try_body = try_body?;
+
+        let finally_tokens = match finally_block {
+            Some(finally) => {
+                let tokens = self.transpile_expr(finally)?;
+                quote! { #tokens; }
+            }
+            None => quote! {},
+        };
         if catch_clauses.is_empty() {
-            bail!("Try block must have at least one catch clause");
+            return Ok(Self::try_finally(
+                &try_body,
+                &finally_tokens,
+                try_block.contains_return(),
+                was_in_try,
+            ));
         }
-        // Generate the catch handling
-        let catch_pattern = if let Pattern::Identifier(name) = &catch_clauses[0].pattern {
-            let ident = format_ident!("{}", name);
-            quote! { #ident }
+        let catch_tokens = self.transpile_catch_clauses(catch_clauses)?;
+
+        if try_block.contains_return() {
+            return Ok(Self::try_catch_with_return(
+                &try_body,
+                &catch_tokens,
+                &finally_tokens,
+                was_in_try,
+            ));
+        }
+        Ok(quote! {
+            {
+                let _try_result: ::std::result::Result<_, ::std::boxed::Box<dyn ::std::any::Any + Send>> =
+                    std::panic::catch_unwind(std::panic::AssertUnwindSafe(
+                        || -> ::std::result::Result<_, ::std::boxed::Box<dyn ::std::any::Any + Send>> {
+                            Ok({ #try_body })
+                        },
+                    ))
+                    .unwrap_or_else(Err);
+                let _final_result = match _try_result {
+                    Ok(value) => value,
+                    Err(_error) => #catch_tokens,
+                };
+                #finally_tokens
+                _final_result
+            }
+        })
+    }
+
+    /// Try-catch whose body contains `return`
+    ///
+    /// A `return` in the try body would only leave the closure, so the body
+    /// yields `ControlFlow::Continue(value)` and `return v` becomes
+    /// `ControlFlow::Break(v)`. After the finally block runs, a break returns
+    /// from the enclosing function (or from the enclosing try's closure).
+    fn try_catch_with_return(
+        try_body: &TokenStream,
+        catch_tokens: &TokenStream,
+        finally_tokens: &TokenStream,
+        was_in_try: bool,
+    ) -> TokenStream {
+        let propagate = Self::propagate_return(was_in_try);
+        quote! {
+            {
+                let _try_result: ::std::result::Result<_, ::std::boxed::Box<dyn ::std::any::Any + Send>> =
+                    std::panic::catch_unwind(std::panic::AssertUnwindSafe(
+                        || -> ::std::result::Result<_, ::std::boxed::Box<dyn ::std::any::Any + Send>> {
+                            Ok(::std::ops::ControlFlow::Continue({ #try_body }))
+                        },
+                    ))
+                    .unwrap_or_else(Err);
+                let _final_result = match _try_result {
+                    Ok(flow) => flow,
+                    Err(_error) => ::std::ops::ControlFlow::Continue(#catch_tokens),
+                };
+                #finally_tokens
+                match _final_result {
+                    ::std::ops::ControlFlow::Continue(value) => value,
+                    ::std::ops::ControlFlow::Break(_returned) => #propagate,
+                }
+            }
+        }
+    }
+
+    /// Try-finally without catch clauses (also what `with` blocks become)
+    ///
+    /// Once the finally block has run, an error resumes unwinding with its
+    /// original payload, so an enclosing try still catches it by type, and a
+    /// `return` in the body returns as in [`Self::try_catch_with_return`].
+    /// Complexity: 2 (within Toyota Way limits)
+    fn try_finally(
+        try_body: &TokenStream,
+        finally_tokens: &TokenStream,
+        has_return: bool,
+        was_in_try: bool,
+    ) -> TokenStream {
+        let (body, finish) = if has_return {
+            let propagate = Self::propagate_return(was_in_try);
+            (
+                quote! { ::std::ops::ControlFlow::Continue({ #try_body }) },
+                quote! {
+                    match _flow {
+                        ::std::ops::ControlFlow::Continue(value) => value,
+                        ::std::ops::ControlFlow::Break(_returned) => #propagate,
+                    }
+                },
+            )
         } else {
-            quote! { _e }
+            (quote! { { #try_body } }, quote! { _flow })
         };
-        let catch_body = self.transpile_expr(&catch_clauses[0].body)?;
-
-        // If there's a finally block, we need to ensure it runs
-        let result = if let Some(finally) = finally_block {
-            let finally_tokens = self.transpile_expr(finally)?;
-            quote! {
-                {
-                    let _result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                        #try_body
-                    }));
-                    let _final_result = match _result {
-                        Ok(val) => val,
-                        Err(panic_err) => {
-                            // Convert panic payload to string for catch variable
-                            let #catch_pattern = if let Some(s) = panic_err.downcast_ref::<&str>() {
-                                s.to_string()
-                            } else if let Some(s) = panic_err.downcast_ref::<String>() {
-                                s.clone()
-                            } else {
-                                "Unknown panic".to_string()
-                            };
-                            #catch_body
-                        }
-                    };
-                    #finally_tokens;
-                    _final_result
-                }
+        quote! {
+            {
+                let _try_result: ::std::result::Result<_, ::std::boxed::Box<dyn ::std::any::Any + Send>> =
+                    std::panic::catch_unwind(std::panic::AssertUnwindSafe(
+                        || -> ::std::result::Result<_, ::std::boxed::Box<dyn ::std::any::Any + Send>> {
+                            Ok(#body)
+                        },
+                    ))
+                    .unwrap_or_else(Err);
+                #finally_tokens
+                let _flow = match _try_result {
+                    Ok(flow) => flow,
+                    Err(_error) => ::std::panic::resume_unwind(_error),
+                };
+                #finish
             }
+        }
+    }
+
+    /// How a `return` carried out of a try body leaves: from the function, or
+    /// from the enclosing try's closure
+    /// Complexity: 1 (within Toyota Way limits)
+    fn propagate_return(was_in_try: bool) -> TokenStream {
+        if was_in_try {
+            quote! { return Ok(::std::ops::ControlFlow::Break(_returned)) }
         } else {
-            // Simple try-catch without finally - use catch_unwind to catch panics
-            quote! {
-                match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                    #try_body
-                })) {
-                    Ok(val) => val,
-                    Err(panic_err) => {
-                        // Convert panic payload to string for catch variable
-                        let #catch_pattern = if let Some(s) = panic_err.downcast_ref::<&str>() {
-                            s.to_string()
-                        } else if let Some(s) = panic_err.downcast_ref::<String>() {
-                            s.clone()
-                        } else {
-                            "Unknown panic".to_string()
-                        };
-                        #catch_body
-                    }
+            quote! { return _returned }
+        }
+    }
+
+    /// Transpile the catch clauses of a try block into one handler for `_error`
+    ///
+    /// Typed clauses (`e: NotFound`, `AppError::Io(msg)`) downcast the error
+    /// and fall through to the next clause when it is another type or the
+    /// pattern does not match. The first untyped clause catches everything
+    /// else, binding the error message; without one the error is re-raised.
+    /// Complexity: 5 (within Toyota Way limits)
+    fn transpile_catch_clauses(&self, catch_clauses: &[CatchClause]) -> Result<TokenStream> {
+        let mut typed = Vec::new();
+        let mut fallback = quote! { ::std::panic::resume_unwind(_error) };
+        for clause in catch_clauses {
+            let body = self.transpile_expr(&clause.body)?;
+            let Some((type_path, pattern)) = typed_catch_pattern(&clause.pattern) else {
+                fallback = catch_all_clause(&clause.pattern, &body);
+                break;
+            };
+            let type_tokens = type_path.iter().map(|segment| format_ident!("{}", segment));
+            let pattern = self.transpile_pattern(&pattern)?;
+            typed.push(quote! {
+                let _error: ::std::boxed::Box<dyn ::std::any::Any + Send> =
+                    match _error.downcast::<#(#type_tokens)::*>() {
+                        Ok(_thrown) => match *_thrown {
+                            #pattern => break 'caught { #body },
+                            #[allow(unreachable_patterns)]
+                            _thrown => ::std::boxed::Box::new(_thrown),
+                        },
+                        Err(_error) => _error,
+                    };
+            });
+        }
+        if typed.is_empty() {
+            return Ok(quote! { { #fallback } });
+        }
+        Ok(quote! { 'caught: { #(#typed)* #fallback } })
+    }
+}
+
+/// Split a typed catch pattern into the error type it names and the pattern
+/// to match once the error has been downcast to that type
+///
+/// `e: NotFound` arrives as `e @ NotFound { .. }` and becomes `(NotFound, e)`;
+/// `AppError::Io(msg)` becomes `(AppError, AppError::Io(msg))`. Untyped
+/// patterns return `None`.
+fn typed_catch_pattern(pattern: &Pattern) -> Option<(Vec<String>, Pattern)> {
+    match pattern {
+        Pattern::AtBinding { name, pattern } => {
+            let (type_path, inner) = typed_catch_pattern(pattern)?;
+            let binding = if matches!(inner, Pattern::Wildcard) {
+                Pattern::Identifier(name.clone())
+            } else {
+                Pattern::AtBinding {
+                    name: name.clone(),
+                    pattern: Box::new(inner),
                 }
-            }
+            };
+            Some((type_path, binding))
+        }
+        // A type name alone (`NotFound { .. }`) must also work for enums
+        Pattern::Struct {
+            name,
+            fields,
+            has_rest: true,
+        } if fields.is_empty() && !name.is_empty() => Some((
+            name.split("::").map(String::from).collect(),
+            Pattern::Wildcard,
+        )),
+        Pattern::Struct { name, .. } if !name.is_empty() => Some((
+            name.split("::").map(String::from).collect(),
+            pattern.clone(),
+        )),
+        Pattern::TupleVariant { path, .. } | Pattern::QualifiedName(path) if path.len() > 1 => {
+            Some((path[..path.len() - 1].to_vec(), pattern.clone()))
+        }
+        _ => None,
+    }
+}
+
+/// Handler body for an untyped catch clause: `catch e` binds the message
+fn catch_all_clause(pattern: &Pattern, body: &TokenStream) -> TokenStream {
+    let Pattern::Identifier(name) = pattern else {
+        return body.clone();
+    };
+    let ident = format_ident!("{}", name);
+    quote! {
+        let #ident: String = if let Some(s) = _error.downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = _error.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "Unknown error".to_string()
         };
-        Ok(result)
+        #body
     }
 }
 
@@ -440,6 +637,26 @@ mod tests {
         assert!(err.contains("at least one catch clause"));
     }
 
+    #[test]
+    fn test_transpile_try_finally_rethrows_after_finally() {
+        let transpiler = Transpiler::new();
+        let try_block = make_expr(ExprKind::Throw {
+            expr: Box::new(ident_expr("msg")),
+        });
+        let finally_block = make_expr(ExprKind::MethodCall {
+            receiver: Box::new(ident_expr("f")),
+            method: "close".to_string(),
+            args: vec![],
+        });
+        let tokens = transpiler
+            .transpile_try_catch(&try_block, &[], Some(&finally_block))
+            .unwrap()
+            .to_string();
+        let close = tokens.find("f . close ()").expect("finally block");
+        let rethrow = tokens.find("resume_unwind (_error)").expect("rethrow");
+        assert!(close < rethrow, "{tokens}");
+    }
+
     #[test]
     fn test_transpile_try_catch_wildcard_pattern() {
         let transpiler = Transpiler::new();
@@ -476,4 +693,124 @@ mod tests {
         // Context should be restored after transpilation
         assert!(!transpiler.in_loop_context.get());
     }
+
+    fn catch_e(body: Expr) -> Vec<CatchClause> {
+        vec![CatchClause {
+            pattern: Pattern::Identifier("e".to_string()),
+            body: Box::new(body),
+        }]
+    }
+
+    #[test]
+    fn test_transpile_try_catch_throw_returns_err() {
+        let transpiler = Transpiler::new();
+        let try_block = make_expr(ExprKind::Throw {
+            expr: Box::new(ident_expr("msg")),
+        });
+        let tokens = transpiler
+            .transpile_try_catch(&try_block, &catch_e(ident_expr("e")), None)
+            .unwrap()
+            .to_string();
+        assert!(tokens.contains("return Err"), "{tokens}");
+        assert!(!tokens.contains("panic_any"), "{tokens}");
+        assert!(tokens.contains("let e : String"), "{tokens}");
+        // Context is restored after the try body
+        assert!(!transpiler.in_try_context.get());
+    }
+
+    #[test]
+    fn test_transpile_try_catch_question_mark_converts_error() {
+        let transpiler = Transpiler::new();
+        let try_block = make_expr(ExprKind::Try {
+            expr: Box::new(ident_expr("result")),
+        });
+        let tokens = transpiler
+            .transpile_try_catch(&try_block, &catch_e(int_expr(0)), None)
+            .unwrap()
+            .to_string();
+        assert!(tokens.contains("map_err"), "{tokens}");
+    }
+
+    #[test]
+    fn test_transpile_throw_outside_try_panics() {
+        let transpiler = Transpiler::new();
+        let throw = make_expr(ExprKind::Throw {
+            expr: Box::new(ident_expr("msg")),
+        });
+        let tokens = transpiler.transpile_expr(&throw).unwrap().to_string();
+        assert!(tokens.contains("panic_any"), "{tokens}");
+    }
+
+    #[test]
+    fn test_transpile_try_catch_closure_body_keeps_panic() {
+        let transpiler = Transpiler::new();
+        let throw = make_expr(ExprKind::Throw {
+            expr: Box::new(ident_expr("msg")),
+        });
+        let lambda = make_expr(ExprKind::Lambda {
+            params: vec![],
+            body: Box::new(throw),
+        });
+        let tokens = transpiler
+            .transpile_try_catch(&lambda, &catch_e(int_expr(0)), None)
+            .unwrap()
+            .to_string();
+        assert!(tokens.contains("panic_any"), "{tokens}");
+        assert!(!tokens.contains("return Err"), "{tokens}");
+    }
+
+    #[test]
+    fn test_transpile_try_catch_return_exits_function() {
+        let transpiler = Transpiler::new();
+        let try_block = make_expr(ExprKind::Return {
+            value: Some(Box::new(int_expr(1))),
+        });
+        let tokens = transpiler
+            .transpile_try_catch(&try_block, &catch_e(int_expr(0)), None)
+            .unwrap()
+            .to_string();
+        assert!(
+            tokens.contains("return Ok (:: std :: ops :: ControlFlow :: Break ("),
+            "{tokens}"
+        );
+        assert!(
+            tokens.contains("Break (_returned) => return _returned"),
+            "{tokens}"
+        );
+
+        // Without a return the output keeps its plain shape
+        let tokens = transpiler
+            .transpile_try_catch(&int_expr(1), &catch_e(int_expr(0)), None)
+            .unwrap()
+            .to_string();
+        assert!(!tokens.contains("ControlFlow"), "{tokens}");
+    }
+
+    #[test]
+    fn test_labeled_loops_keep_rust_labels() {
+        let ast = crate::frontend::parser::Parser::new(
+            "'outer: for i in 0..3 { @rows: while true { if i == 1 { continue 'outer } break @rows } }",
+        )
+        .parse()
+        .unwrap();
+        let tokens = Transpiler::new().transpile_expr(&ast).unwrap().to_string();
+        assert!(tokens.contains("'outer : for i in"), "{tokens}");
+        assert!(tokens.contains("'rows : while true"), "{tokens}");
+        assert!(tokens.contains("continue 'outer"), "{tokens}");
+        assert!(tokens.contains("break 'rows"), "{tokens}");
+    }
+
+    #[test]
+    fn test_labeled_loop_breaks_with_value() {
+        let body = make_expr(ExprKind::Break {
+            label: Some("search".to_string()),
+            value: Some(Box::new(int_expr(7))),
+        });
+        let expr = make_expr(ExprKind::Loop {
+            label: Some("search".to_string()),
+            body: Box::new(body),
+        });
+        let tokens = Transpiler::new().transpile_expr(&expr).unwrap().to_string();
+        assert_eq!(tokens, "'search : loop { break 'search 7 }");
+    }
 }
@@ -2,10 +2,12 @@
 //!
 //! PDCA-21: Handles transpilation of functions with default parameter values.
 //! Rust doesn't support default parameters, so we fill in defaults at call sites
-//! when fewer arguments are provided than the function expects.
+//! when fewer arguments are provided than the function expects. Named
+//! arguments (`greet(name: "Ana")`) are put into parameter order the same way.
 
 use super::Transpiler;
-use anyhow::Result;
+use crate::frontend::ast::{Expr, ExprKind};
+use anyhow::{anyhow, bail, Result};
 use proc_macro2::TokenStream;
 
 impl Transpiler {
@@ -59,6 +61,82 @@ impl Transpiler {
 
         Ok(result)
     }
+
+    /// Put the arguments of a call with named arguments into parameter order.
+    /// `greet(greeting: "Hi", name: "Ana")` becomes `greet("Ana", "Hi")`, and
+    /// parameters not given take their default values. Surplus positional
+    /// arguments of a variadic function are kept, after its fixed parameters.
+    /// Returns `None` when the call has no named arguments.
+    pub(super) fn resolve_named_args(
+        &self,
+        func_name: &str,
+        args: &[Expr],
+    ) -> Result<Option<Vec<Expr>>> {
+        if !args.iter().any(|arg| named_arg(arg).is_some()) {
+            return Ok(None);
+        }
+        let Some(sig) = self.function_signatures.get(func_name) else {
+            bail!("Named arguments need the definition of `{func_name}`");
+        };
+
+        let fixed = &sig.param_names[..sig.param_names.len() - usize::from(sig.is_variadic)];
+        let mut slots: Vec<Option<Expr>> = vec![None; fixed.len()];
+        let mut surplus = Vec::new();
+        let mut next_positional = 0;
+        for arg in args {
+            let (slot, value) = if let Some((name, value)) = named_arg(arg) {
+                let slot = fixed
+                    .iter()
+                    .position(|param| param == name)
+                    .ok_or_else(|| anyhow!("`{func_name}` has no parameter named `{name}`"))?;
+                (slot, value)
+            } else {
+                next_positional += 1;
+                (next_positional - 1, arg)
+            };
+            match slots.get_mut(slot) {
+                None if sig.is_variadic => surplus.push(value.clone()),
+                None => bail!(
+                    "`{func_name}` takes {} arguments, got {}",
+                    sig.param_names.len(),
+                    args.len()
+                ),
+                Some(Some(_)) => bail!(
+                    "Argument `{}` of `{func_name}` given more than once",
+                    fixed[slot]
+                ),
+                Some(_) if matches!(value.kind, ExprKind::Spread { .. }) => {
+                    bail!("Spread arguments to `{func_name}` can't fill named parameters")
+                }
+                Some(empty) => *empty = Some(value.clone()),
+            }
+        }
+
+        let defaults = sig.default_values.as_deref().unwrap_or_default();
+        let mut resolved = slots
+            .into_iter()
+            .enumerate()
+            .map(|(i, slot)| {
+                slot.or_else(|| defaults.get(i).cloned().flatten().map(|default| *default))
+                    .ok_or_else(|| {
+                        anyhow!("Missing argument `{}` in call to `{func_name}`", fixed[i])
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        resolved.extend(surplus);
+        Ok(Some(resolved))
+    }
+}
+
+/// Split a `name = value` call argument into its name and value
+fn named_arg(arg: &Expr) -> Option<(&str, &Expr)> {
+    match &arg.kind {
+        ExprKind::Assign { target, value } => match &target.kind {
+            ExprKind::Identifier(name) => Some((name.as_str(), value.as_ref())),
+            _ => None,
+        },
+        _ => None,
+    }
 }
 
 #[cfg(test)]
@@ -112,6 +190,9 @@ mod tests {
             FunctionSignature {
                 name: "greet".to_string(),
                 param_types: vec!["String".to_string(), "String".to_string()],
+                param_names: vec!["name".to_string(), "title".to_string()],
+                is_variadic: false,
+                returns_fn_mut: false,
                 default_values: Some(vec![None, Some(Box::new(string_expr("Mr.")))]),
             },
         );
@@ -128,6 +209,9 @@ mod tests {
             FunctionSignature {
                 name: "greet".to_string(),
                 param_types: vec!["String".to_string(), "String".to_string()],
+                param_names: vec!["name".to_string(), "title".to_string()],
+                is_variadic: false,
+                returns_fn_mut: false,
                 default_values: Some(vec![None, Some(Box::new(string_expr("Mr.")))]),
             },
         );
@@ -149,6 +233,9 @@ mod tests {
             FunctionSignature {
                 name: "repeat".to_string(),
                 param_types: vec!["String".to_string(), "i32".to_string()],
+                param_names: vec!["text".to_string(), "times".to_string()],
+                is_variadic: false,
+                returns_fn_mut: false,
                 default_values: Some(vec![None, Some(Box::new(int_expr(1)))]),
             },
         );
@@ -170,6 +257,9 @@ mod tests {
             FunctionSignature {
                 name: "add".to_string(),
                 param_types: vec!["i32".to_string(), "i32".to_string()],
+                param_names: vec!["a".to_string(), "b".to_string()],
+                is_variadic: false,
+                returns_fn_mut: false,
                 default_values: None,
             },
         );
@@ -177,4 +267,106 @@ mod tests {
         let result = transpiler.fill_default_args("add", args).unwrap();
         assert_eq!(result.len(), 1, "No defaults should not add anything");
     }
+
+    fn named_expr(name: &str, value: Expr) -> Expr {
+        Expr::new(
+            ExprKind::Assign {
+                target: Box::new(Expr::new(
+                    ExprKind::Identifier(name.to_string()),
+                    Span::default(),
+                )),
+                value: Box::new(value),
+            },
+            Span::default(),
+        )
+    }
+
+    fn transpiler_with_connect() -> Transpiler {
+        let mut transpiler = make_transpiler();
+        transpiler.function_signatures.insert(
+            "connect".to_string(),
+            FunctionSignature {
+                name: "connect".to_string(),
+                param_types: vec!["String".to_string(), "i32".to_string(), "i32".to_string()],
+                param_names: vec![
+                    "host".to_string(),
+                    "port".to_string(),
+                    "retries".to_string(),
+                ],
+                is_variadic: false,
+                returns_fn_mut: false,
+                default_values: Some(vec![
+                    None,
+                    Some(Box::new(int_expr(8080))),
+                    Some(Box::new(int_expr(3))),
+                ]),
+            },
+        );
+        transpiler
+    }
+
+    #[test]
+    fn test_resolve_named_args_none_without_named() {
+        let transpiler = transpiler_with_connect();
+        let resolved = transpiler
+            .resolve_named_args("connect", &[string_expr("db")])
+            .unwrap();
+        assert!(resolved.is_none());
+    }
+
+    #[test]
+    fn test_resolve_named_args_fills_skipped_default() {
+        let transpiler = transpiler_with_connect();
+        let args = vec![string_expr("db"), named_expr("retries", int_expr(5))];
+        let resolved = transpiler
+            .resolve_named_args("connect", &args)
+            .unwrap()
+            .expect("call has named args");
+        let kinds: Vec<_> = resolved.iter().map(|arg| &arg.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                &ExprKind::Literal(Literal::String("db".to_string())),
+                &ExprKind::Literal(Literal::Integer(8080, None)),
+                &ExprKind::Literal(Literal::Integer(5, None)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_named_args_errors() {
+        let transpiler = transpiler_with_connect();
+        let unknown = vec![string_expr("db"), named_expr("timeout", int_expr(1))];
+        assert!(transpiler.resolve_named_args("connect", &unknown).is_err());
+        let twice = vec![string_expr("db"), named_expr("host", string_expr("x"))];
+        assert!(transpiler.resolve_named_args("connect", &twice).is_err());
+        let missing = vec![named_expr("port", int_expr(1))];
+        assert!(transpiler.resolve_named_args("connect", &missing).is_err());
+        let undefined = vec![named_expr("port", int_expr(1))];
+        assert!(transpiler
+            .resolve_named_args("unknown_fn", &undefined)
+            .is_err());
+    }
+
+    #[test]
+    fn test_transpile_named_call_with_defaults() {
+        let source = r#"fun greet(name, greeting = "Hello") { println(f"{greeting}, {name}!") }
+greet(name: "Ana")
+greet("Bo", greeting: "Hi")"#;
+        let ast = crate::frontend::parser::Parser::new(source)
+            .parse()
+            .expect("should parse");
+        let rust = Transpiler::new()
+            .transpile(&ast)
+            .expect("should transpile")
+            .to_string();
+        assert!(
+            rust.contains(r#"greet ("Ana" . to_string () , "Hello""#),
+            "got: {rust}"
+        );
+        assert!(
+            rust.contains(r#"greet ("Bo" . to_string () , "Hi""#),
+            "got: {rust}"
+        );
+    }
 }
@@ -89,15 +89,23 @@ impl Transpiler {
             } => self.transpile_if(condition, then_branch, else_branch.as_deref()),
             ExprKind::Match { expr, arms } => self.transpile_match(expr, arms),
             ExprKind::For {
+                label,
                 var,
                 pattern,
                 iter,
                 body,
-                ..
-            } => self.transpile_for(var, pattern.as_ref(), iter, body),
+            } => Ok(Self::label_loop(
+                label.as_ref(),
+                self.transpile_for(var, pattern.as_ref(), iter, body)?,
+            )),
             ExprKind::While {
-                condition, body, ..
-            } => self.transpile_while(condition, body),
+                label,
+                condition,
+                body,
+            } => Ok(Self::label_loop(
+                label.as_ref(),
+                self.transpile_while(condition, body)?,
+            )),
             ExprKind::IfLet {
                 pattern,
                 expr,
@@ -105,12 +113,17 @@ impl Transpiler {
                 else_branch,
             } => self.transpile_if_let(pattern, expr, then_branch, else_branch.as_deref()),
             ExprKind::WhileLet {
+                label,
                 pattern,
                 expr,
                 body,
-                ..
-            } => self.transpile_while_let(pattern, expr, body),
-            ExprKind::Loop { body, .. } => self.transpile_loop(body),
+            } => Ok(Self::label_loop(
+                label.as_ref(),
+                self.transpile_while_let(pattern, expr, body)?,
+            )),
+            ExprKind::Loop { label, body } => {
+                Ok(Self::label_loop(label.as_ref(), self.transpile_loop(body)?))
+            }
             ExprKind::TryCatch {
                 try_block,
                 catch_clauses,
@@ -143,6 +156,15 @@ impl Transpiler {
             ),
             ExprKind::Lambda { params, body } => self.transpile_lambda(params, body),
             ExprKind::Call { func, args } => self.transpile_call(func, args),
+            ExprKind::MethodCall {
+                receiver,
+                method,
+                args,
+            } if super::mutation_detection::MUTATING_METHODS.contains(&method.as_str())
+                && matches!(&receiver.kind, ExprKind::Identifier(name) if self.is_shared_capture(name)) =>
+            {
+                self.transpile_shared_method_call(receiver, method, args)
+            }
             ExprKind::MethodCall {
                 receiver,
                 method,
@@ -218,7 +240,7 @@ impl Transpiler {
                 constructors,
                 methods,
                 constants,
-                properties: _, // Properties not yet transpiled
+                properties,
                 derives,
                 is_pub,
                 is_sealed: _,   // Sealed classes not yet transpiled
@@ -232,6 +254,7 @@ impl Transpiler {
                 constructors,
                 methods,
                 constants,
+                properties,
                 derives,
                 *is_pub,
             ),
@@ -241,6 +264,17 @@ impl Transpiler {
             ExprKind::ObjectLiteral { fields } => self.transpile_object_literal(fields),
             ExprKind::FieldAccess { object, field } => self.transpile_field_access(object, field),
             ExprKind::IndexAccess { object, index } => self.transpile_index_access(object, index),
+            ExprKind::OptionalFieldAccess { object, field } => {
+                self.transpile_optional_field_access(object, field)
+            }
+            ExprKind::OptionalIndexAccess { object, index } => {
+                self.transpile_optional_index_access(object, index)
+            }
+            ExprKind::OptionalMethodCall {
+                receiver,
+                method,
+                args,
+            } => self.transpile_optional_method_call(receiver, method, args),
             ExprKind::Slice { object, start, end } => {
                 self.transpile_slice(object, start.as_deref(), end.as_deref())
             }
@@ -353,6 +387,14 @@ impl Transpiler {
     }
     fn transpile_try_operator(&self, expr: &Expr) -> Result<TokenStream> {
         let expr_tokens = self.transpile_expr(expr)?;
+        if self.in_try_context.get() {
+            // Inside a try block any error type converts to the catch message
+            return Ok(quote! {
+                #expr_tokens.map_err(|e| -> ::std::boxed::Box<dyn ::std::any::Any + Send> {
+                    ::std::boxed::Box::new(e.to_string())
+                })?
+            });
+        }
         Ok(quote! { #expr_tokens? })
     }
     /// Transpile actor system expressions
@@ -444,6 +486,7 @@ impl Transpiler {
                 // Future: Use std::sync::LazyLock or once_cell::Lazy for true lazy semantics
                 self.transpile_expr(expr)
             }
+            ExprKind::Defer { expr } => self.transpile_defer(expr),
             ExprKind::Import { module, items } => {
                 // Check if this import has a "pub" attribute
                 let has_pub = expr.attributes.iter().any(|attr| attr.name == "pub");
@@ -478,6 +521,10 @@ impl Transpiler {
             | ExprKind::Extension { .. }
             | ExprKind::Enum { .. }
             | ExprKind::TypeAlias { .. } => self.transpile_type_decl_expr(expr),
+            // A bare return ends a generator with what it has yielded so far
+            ExprKind::Return { value: None } if self.in_generator_context.get() => {
+                Ok(quote::quote! { return __yielded.into_iter() })
+            }
             ExprKind::Break { .. } | ExprKind::Continue { .. } | ExprKind::Return { .. } => {
                 self.transpile_control_misc_expr(expr)
             }
@@ -488,13 +535,28 @@ impl Transpiler {
             ExprKind::VecRepeat { value, count } => self.transpile_vec_repeat(value, count),
             // ISSUE-103: Handle MacroInvocation for compilation support
             ExprKind::MacroInvocation { name, args } => self.transpile_macro(name, args),
+            ExprKind::ExternBlock {
+                abi,
+                path,
+                functions,
+            } => self.transpile_extern_block(abi, path.as_deref(), functions),
+            ExprKind::RustBlock {
+                inputs,
+                output,
+                code,
+                ..
+            } => self.transpile_rust_block(inputs, output.as_ref(), code),
             // Ruchy 5.0 Sovereign Platform expressions
             ExprKind::Yield { value } => {
                 let val_ts = match value {
                     Some(v) => self.transpile_expr(v)?,
                     None => quote::quote! { () },
                 };
-                Ok(quote::quote! { yield #val_ts })
+                if self.in_generator_context.get() {
+                    Ok(quote::quote! { __yielded.push(#val_ts) })
+                } else {
+                    Ok(quote::quote! { yield #val_ts })
+                }
             }
             ExprKind::Signal { initial_value } => {
                 let init_ts = self.transpile_expr(initial_value)?;
@@ -1091,6 +1153,8 @@ mod tests {
                         Span::default(),
                     )),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 }],
             },
             Span::default(),
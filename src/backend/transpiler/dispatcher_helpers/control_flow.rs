@@ -156,7 +156,7 @@ impl Transpiler {
                 constructors,
                 methods,
                 constants,
-                properties: _, // Properties not yet transpiled
+                properties,
                 derives,
                 is_pub,
                 is_sealed: _,   // Sealed classes not yet transpiled
@@ -170,6 +170,7 @@ impl Transpiler {
                 constructors,
                 methods,
                 constants,
+                properties,
                 derives,
                 *is_pub,
             ),
@@ -287,6 +288,8 @@ mod tests {
                     guard: None,
                     body: int_expr(10),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 }],
             },
             span: Span::default(),
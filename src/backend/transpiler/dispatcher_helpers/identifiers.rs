@@ -57,6 +57,9 @@ impl Transpiler {
                 .contains(name)
             {
                 quote! { *#ident.lock().expect("LazyLock Mutex poisoned - indicates panic while accessing global variable") }
+            } else if self.is_shared_capture(name) {
+                // A variable shared with closures lives in an Rc<RefCell<_>>
+                quote! { #ident.borrow().clone() }
             } else {
                 quote! { #ident }
             }
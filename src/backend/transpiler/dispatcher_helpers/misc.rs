@@ -4,7 +4,7 @@ use super::super::Transpiler;
 use crate::frontend::ast::{Expr, ExprKind};
 use anyhow::{bail, Result};
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use quote::quote;
 
 impl Transpiler {
     pub(in crate::backend::transpiler) fn transpile_type_cast(
@@ -52,6 +52,14 @@ impl Transpiler {
             ExprKind::Return { value } => {
                 // ISSUE-103: Don't add semicolon - will be added by statement context
                 // In match arms, return is an expression and shouldn't have trailing semicolon
+                if self.in_try_context.get() {
+                    // Inside a try body: break out of its closure (see transpile_try_catch)
+                    let val_tokens = match value {
+                        Some(val_expr) => self.transpile_expr(val_expr)?,
+                        None => quote! { () },
+                    };
+                    return Ok(quote! { return Ok(::std::ops::ControlFlow::Break(#val_tokens)) });
+                }
                 if let Some(val_expr) = value {
                     let val_tokens = self.transpile_expr(val_expr)?;
                     Ok(quote! { return #val_tokens })
@@ -75,9 +83,8 @@ impl Transpiler {
 
         match label {
             Some(l) if !l.is_empty() => {
-                let label_name = l.strip_prefix('\'').unwrap_or(l);
-                let label_ident = format_ident!("{}", label_name);
-                quote! { #keyword #label_ident }
+                let lifetime = Self::loop_label_lifetime(l);
+                quote! { #keyword #lifetime }
             }
             _ => keyword,
         }
@@ -96,14 +103,12 @@ impl Transpiler {
 
         match (label, value) {
             (Some(l), Some(v)) if !l.is_empty() => {
-                let label_name = l.strip_prefix('\'').unwrap_or(l);
-                let label_ident = format_ident!("{}", label_name);
-                quote! { #keyword #label_ident #v }
+                let lifetime = Self::loop_label_lifetime(l);
+                quote! { #keyword #lifetime #v }
             }
             (Some(l), None) if !l.is_empty() => {
-                let label_name = l.strip_prefix('\'').unwrap_or(l);
-                let label_ident = format_ident!("{}", label_name);
-                quote! { #keyword #label_ident }
+                let lifetime = Self::loop_label_lifetime(l);
+                quote! { #keyword #lifetime }
             }
             (_, Some(v)) => quote! { #keyword #v },
             _ => keyword,
@@ -640,9 +645,8 @@ mod tests {
         let label_with_apostrophe = String::from("'loop");
         let result = Transpiler::make_break_continue(true, Some(&label_with_apostrophe));
         let result_str = result.to_string();
-        assert!(result_str.contains("break"));
-        assert!(result_str.contains("loop"));
-        assert!(!result_str.contains("'loop")); // Apostrophe should be stripped
+        // The label becomes a Rust lifetime without doubling its apostrophe
+        assert_eq!(result_str, "break 'loop");
     }
 
     // Test 39: make_break_continue - label without apostrophe prefix
@@ -272,6 +272,8 @@ mod tests {
                 span: Span::default(),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             }],
             return_type: None,
         };
@@ -303,6 +305,8 @@ mod tests {
                 span: Span::default(),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             }],
             return_type: None,
         };
@@ -346,6 +350,8 @@ mod tests {
                 span: Span::default(),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             }],
             return_type: None,
         };
@@ -376,6 +382,8 @@ mod tests {
                     span: Span::default(),
                     is_mutable: false,
                     default_value: None,
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
                 Param {
                     pattern: Pattern::Identifier("message".to_string()),
@@ -386,6 +394,8 @@ mod tests {
                     span: Span::default(),
                     is_mutable: false,
                     default_value: None,
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
             ],
             return_type: None,
@@ -511,6 +521,8 @@ mod tests {
                     span: Span::default(),
                     is_mutable: false,
                     default_value: None,
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 }],
                 return_type: None,
             },
@@ -602,6 +614,8 @@ mod tests {
                     span: Span::default(),
                     is_mutable: false,
                     default_value: None,
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
                 Param {
                     pattern: Pattern::Identifier("config".to_string()),
@@ -612,6 +626,8 @@ mod tests {
                     span: Span::default(),
                     is_mutable: false,
                     default_value: None,
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
             ],
             return_type: Some(Type {
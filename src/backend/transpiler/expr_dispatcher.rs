@@ -89,8 +89,9 @@ impl Transpiler {
             Binary, Call, Class, Command, CompoundAssign, DataFrame, DataFrameOperation,
             DictComprehension, Effect, Err, FieldAccess, For, Function, Handle, Identifier, If,
             IfLet, IndexAccess, Lambda, List, ListComprehension, Literal, Loop, Macro, Match,
-            MethodCall, None, ObjectLiteral, Ok, PostDecrement, PostIncrement, PreDecrement,
-            PreIncrement, QualifiedName, Range, Send, Set, SetComprehension, Slice, Some, Spawn,
+            MethodCall, None, ObjectLiteral, Ok, OptionalFieldAccess, OptionalIndexAccess,
+            OptionalMethodCall, PostDecrement, PostIncrement, PreDecrement, PreIncrement,
+            QualifiedName, Range, Send, Set, SetComprehension, Slice, Some, Spawn,
             StringInterpolation, Struct, StructLiteral, Throw, Try, TryCatch, Tuple, TupleStruct,
             TypeCast, Unary, While, WhileLet,
         };
@@ -139,6 +140,9 @@ impl Transpiler {
             | ObjectLiteral { .. }
             | FieldAccess { .. }
             | IndexAccess { .. }
+            | OptionalFieldAccess { .. }
+            | OptionalIndexAccess { .. }
+            | OptionalMethodCall { .. }
             | Slice { .. } => self.transpile_struct_expr(expr),
 
             // Data and error handling
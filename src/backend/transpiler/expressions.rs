@@ -28,6 +28,8 @@ impl Transpiler {
     pub fn transpile_literal(lit: &Literal) -> TokenStream {
         match lit {
             Literal::Integer(i, type_suffix) => Self::transpile_integer(*i, type_suffix.as_deref()),
+            Literal::BigInt(digits) => Self::transpile_bigint(digits),
+            Literal::Decimal(text) => Self::transpile_decimal(text),
             Literal::Float(f) => quote! { #f },
             Literal::Unit => quote! { () },
             Literal::Null => quote! { None },
@@ -48,6 +50,14 @@ impl Transpiler {
             _ => unreachable!("Unexpected literal in transpile_simple_literal"),
         }
     }
+    /// `123n` becomes a `num_bigint::BigInt` parsed from its digits
+    fn transpile_bigint(digits: &str) -> TokenStream {
+        quote! { #digits.parse::<num_bigint::BigInt>().expect("valid BigInt literal") }
+    }
+    /// `1.23d` becomes a `rust_decimal::Decimal` parsed from its text
+    fn transpile_decimal(text: &str) -> TokenStream {
+        quote! { #text.parse::<rust_decimal::Decimal>().expect("valid Decimal literal") }
+    }
     fn transpile_integer(i: i64, type_suffix: Option<&str>) -> TokenStream {
         // DEFECT-002 FIX: Preserve type suffixes from source code
         if let Some(suffix) = type_suffix {
@@ -130,6 +140,14 @@ impl Transpiler {
     /// assert_eq!(result, Ok(()));
     /// ```
     pub fn transpile_assign(&self, target: &Expr, value: &Expr) -> Result<TokenStream> {
+        if let ExprKind::Identifier(name) = &target.kind {
+            if self.is_shared_capture(name) {
+                let value_tokens = self.transpile_expr(value)?;
+                return Self::transpile_shared_write(name, Some(value_tokens), |cell| {
+                    Ok(quote! { #cell = __value; })
+                });
+            }
+        }
         // DEADLOCK FIX (Issue #132): Check if assigning to a global that's also in value
         // If so, use single-lock pattern to avoid deadlock
         if let ExprKind::Identifier(target_name) = &target.kind {
@@ -152,6 +170,19 @@ impl Transpiler {
         // Standard assignment (no deadlock risk)
         let value_tokens = self.transpile_expr(value)?;
 
+        // Class properties are assigned through their generated `set_` method
+        if let ExprKind::FieldAccess { object, field } = &target.kind {
+            if let Some(property_type) = self.class_properties.get(field) {
+                if self.is_class_property_access(object, field) {
+                    let obj_tokens = self.transpile_expr(object)?;
+                    let setter = format_ident!("set_{}", field);
+                    let arg_tokens =
+                        self.apply_string_coercion(value, &value_tokens, property_type)?;
+                    return Ok(quote! { #obj_tokens.#setter(#arg_tokens) });
+                }
+            }
+        }
+
         // BUG-003: Handle IndexAccess specially for lvalue (no .clone())
         if let ExprKind::IndexAccess { .. } = &target.kind {
             let target_tokens = self.transpile_index_lvalue(target)?;
@@ -336,22 +367,51 @@ impl Transpiler {
             {
                 let var_ident = format_ident!("{}", target_name);
                 let value_tokens = self.transpile_expr(value)?;
-                let op_tokens = Self::get_compound_op_token(op)?;
+                let update =
+                    Self::transpile_compound_update(&quote! { *__guard }, op, &value_tokens)?;
 
                 return Ok(quote! {
                     {
                         let mut __guard = #var_ident.lock().expect("mutex should not be poisoned");
-                        *__guard #op_tokens #value_tokens
+                        #update
                     }
                 });
             }
         }
 
+        if let ExprKind::Identifier(name) = &target.kind {
+            if self.is_shared_capture(name) {
+                let value_tokens = self.transpile_expr(value)?;
+                return Self::transpile_shared_write(name, Some(value_tokens), |cell| {
+                    let update = Self::transpile_compound_update(&cell, op, &quote! { __value })?;
+                    Ok(quote! { #update; })
+                });
+            }
+        }
+
         // Standard compound assignment (non-global)
         let target_tokens = self.transpile_expr(target)?;
         let value_tokens = self.transpile_expr(value)?;
-        let op_tokens = Self::get_compound_op_token(op)?;
-        Ok(quote! { #target_tokens #op_tokens #value_tokens })
+        Self::transpile_compound_update(&target_tokens, op, &value_tokens)
+    }
+    /// `target op= value`, spelled out for operators Rust has no compound
+    /// form of: `**=` raises in place, `&&=`, `||=` and `??=` only evaluate
+    /// and assign `value` when the current value does not decide the result
+    fn transpile_compound_update(
+        target: &TokenStream,
+        op: BinaryOp,
+        value: &TokenStream,
+    ) -> Result<TokenStream> {
+        Ok(match op {
+            BinaryOp::Power => quote! { #target = #target.powf(#value) },
+            BinaryOp::And => quote! { if #target { #target = #value; } },
+            BinaryOp::Or => quote! { if !#target { #target = #value; } },
+            BinaryOp::NullCoalesce => quote! { if #target.is_none() { #target = Some(#value); } },
+            _ => {
+                let op_tokens = Self::get_compound_op_token(op)?;
+                quote! { #target #op_tokens #value }
+            }
+        })
     }
     fn get_compound_op_token(op: BinaryOp) -> Result<TokenStream> {
         use BinaryOp::{
@@ -423,6 +483,29 @@ impl Transpiler {
         }
     }
 
+    /// Increment or decrement `target`, through its cell if it is shared
+    /// with closures
+    fn transpile_inc_dec(
+        &self,
+        target: &Expr,
+        is_increment: bool,
+        is_pre: bool,
+    ) -> Result<TokenStream> {
+        if let ExprKind::Identifier(name) = &target.kind {
+            if self.is_shared_capture(name) {
+                return Self::transpile_shared_write(name, None, |cell| {
+                    Ok(Self::generate_inc_dec_op(cell, is_increment, is_pre))
+                });
+            }
+        }
+        let target_tokens = self.transpile_expr(target)?;
+        Ok(Self::generate_inc_dec_op(
+            target_tokens,
+            is_increment,
+            is_pre,
+        ))
+    }
+
     /// Transpiles pre-increment
     /// # Examples
     ///
@@ -433,8 +516,7 @@ impl Transpiler {
     /// assert_eq!(result, Ok(()));
     /// ```
     pub fn transpile_pre_increment(&self, target: &Expr) -> Result<TokenStream> {
-        let target_tokens = self.transpile_expr(target)?;
-        Ok(Self::generate_inc_dec_op(target_tokens, true, true))
+        self.transpile_inc_dec(target, true, true)
     }
 
     /// Transpiles post-increment
@@ -447,8 +529,7 @@ impl Transpiler {
     /// assert_eq!(result, Ok(()));
     /// ```
     pub fn transpile_post_increment(&self, target: &Expr) -> Result<TokenStream> {
-        let target_tokens = self.transpile_expr(target)?;
-        Ok(Self::generate_inc_dec_op(target_tokens, true, false))
+        self.transpile_inc_dec(target, true, false)
     }
 
     /// Transpiles pre-decrement
@@ -461,8 +542,7 @@ impl Transpiler {
     /// assert_eq!(result, Ok(()));
     /// ```
     pub fn transpile_pre_decrement(&self, target: &Expr) -> Result<TokenStream> {
-        let target_tokens = self.transpile_expr(target)?;
-        Ok(Self::generate_inc_dec_op(target_tokens, false, true))
+        self.transpile_inc_dec(target, false, true)
     }
 
     /// Transpiles post-decrement
@@ -475,8 +555,7 @@ impl Transpiler {
     /// assert_eq!(result, Ok(()));
     /// ```
     pub fn transpile_post_decrement(&self, target: &Expr) -> Result<TokenStream> {
-        let target_tokens = self.transpile_expr(target)?;
-        Ok(Self::generate_inc_dec_op(target_tokens, false, false))
+        self.transpile_inc_dec(target, false, false)
     }
     /// Transpiles array initialization syntax [value; size]
     /// # Examples
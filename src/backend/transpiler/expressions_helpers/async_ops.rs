@@ -93,7 +93,11 @@ impl Transpiler {
 
         Ok(quote! { |#(#param_idents),*| async move { #body_tokens } })
     }
-    /// Transpiles throw expressions (panic in Rust)
+    /// Transpiles throw expressions
+    ///
+    /// Inside a try block `throw` returns the boxed value as `Err` from the
+    /// try closure; elsewhere it panics with the value as payload, so an
+    /// enclosing try in a caller can still catch it by type.
     /// # Examples
     ///
     /// ```
@@ -104,8 +108,13 @@ impl Transpiler {
     /// ```
     pub fn transpile_throw(&self, expr: &Expr) -> Result<TokenStream> {
         let expr_tokens = self.transpile_expr(expr)?;
+        if self.in_try_context.get() {
+            return Ok(quote! {
+                return Err(::std::boxed::Box::new(#expr_tokens))
+            });
+        }
         Ok(quote! {
-            panic!(#expr_tokens)
+            ::std::panic::panic_any(#expr_tokens)
         })
     }
 }
@@ -77,10 +77,21 @@ impl Transpiler {
             .any(|prefix| name.starts_with(prefix))
     }
 
+    /// Whether `object.field` reads or writes a class property rather than a field
+    pub(crate) fn is_class_property_access(&self, object: &Expr, field: &str) -> bool {
+        self.class_properties.contains_key(field) && !self.is_module_path(object)
+    }
+
     pub fn transpile_field_access(&self, object: &Expr, field: &str) -> Result<TokenStream> {
         use crate::frontend::ast::ExprKind;
         let obj_tokens = self.transpile_expr(object)?;
 
+        // Class properties are read through their generated getter
+        if self.is_class_property_access(object, field) {
+            let getter = format_ident!("{}", field);
+            return Ok(quote! { #obj_tokens.#getter() });
+        }
+
         // DEFECT-PROPERTY-001: Check for numeric field access FIRST (tuple fields)
         // Prevents panic in format_ident! when field is pure number like "0"
         if field.chars().all(|c| c.is_ascii_digit()) {
@@ -226,6 +237,59 @@ impl Transpiler {
             _ => Ok(quote! { #obj_tokens[#index_tokens as usize].clone() }),
         }
     }
+    /// Transpiles optional field access `obj?.field` to `obj.as_ref().map(..)`
+    ///
+    /// The receiver must be an `Option`; the result is `None` when it is.
+    pub fn transpile_optional_field_access(
+        &self,
+        object: &Expr,
+        field: &str,
+    ) -> Result<TokenStream> {
+        let obj_tokens = self.transpile_expr(object)?;
+        let member = Self::optional_member(field)?;
+        Ok(quote! { #obj_tokens.as_ref().map(|__v| __v.#member.clone()) })
+    }
+
+    /// Transpiles optional method call `obj?.method(args)` to `obj.as_ref().map(..)`
+    pub fn transpile_optional_method_call(
+        &self,
+        receiver: &Expr,
+        method: &str,
+        args: &[Expr],
+    ) -> Result<TokenStream> {
+        let receiver_tokens = self.transpile_expr(receiver)?;
+        let method_ident = Self::optional_member(method.split("::").next().unwrap_or(method))?;
+        let arg_tokens = args
+            .iter()
+            .map(|arg| self.transpile_expr(arg))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(quote! { #receiver_tokens.as_ref().map(|__v| __v.#method_ident(#(#arg_tokens),*)) })
+    }
+
+    /// Transpiles optional index access `arr?[index]` to `arr.as_ref().map(..)`
+    pub fn transpile_optional_index_access(
+        &self,
+        object: &Expr,
+        index: &Expr,
+    ) -> Result<TokenStream> {
+        let obj_tokens = self.transpile_expr(object)?;
+        let index_tokens = self.transpile_expr(index)?;
+        Ok(quote! { #obj_tokens.as_ref().map(|__v| __v[#index_tokens as usize].clone()) })
+    }
+
+    /// Member name after `?.`: a tuple index or an identifier
+    fn optional_member(name: &str) -> Result<TokenStream> {
+        if let Ok(index) = name.parse::<usize>() {
+            let index = syn::Index::from(index);
+            return Ok(quote! { #index });
+        }
+        if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+            anyhow::bail!("Invalid field name '{name}' after '?.'");
+        }
+        let ident = format_ident!("{}", name);
+        Ok(quote! { #ident })
+    }
+
     /// Transpiles slice access `(array[start:end])`
     /// # Examples
     ///
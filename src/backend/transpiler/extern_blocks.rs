@@ -0,0 +1,253 @@
+//! `extern "rust"` blocks: calling Rust crate functions from Ruchy
+//!
+//! Each declared function becomes a wrapper taking Ruchy-friendly types:
+//! borrowed strings and slices accept anything that can lend them, and
+//! narrow numeric types are passed and returned as `i64`/`f64`. The
+//! wrappers live in a private module that the block's scope imports.
+use super::{Result, Transpiler};
+use crate::frontend::ast::{ExternFunction, Type, TypeKind};
+use anyhow::bail;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Integer types that Ruchy code passes around as `i64`
+const NARROW_INTS: &[&str] = &[
+    "i8", "i16", "i32", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
+/// Crate roots that need no `[dependencies]` entry
+pub const BUILTIN_CRATES: &[&str] = &["std", "core", "alloc"];
+
+/// Whether `abi` declares Rust crate functions
+pub fn is_rust_abi(abi: &str) -> bool {
+    abi.eq_ignore_ascii_case("rust")
+}
+
+/// Parameter type of a wrapper for a declared parameter of type `ty`
+///
+/// Borrowed strings and slices become `impl AsRef<..>`, which call sites
+/// pass by reference so the caller keeps its value.
+pub(crate) fn wrapper_param_type(ty: &Type) -> Option<String> {
+    match borrowed_target(ty)? {
+        BorrowTarget::Str => Some("impl AsRef<str>".to_string()),
+        BorrowTarget::Slice(elem) => Some(format!(
+            "impl AsRef<[{}]>",
+            Transpiler::type_to_string(elem)
+        )),
+    }
+}
+
+enum BorrowTarget<'a> {
+    Str,
+    Slice(&'a Type),
+}
+
+/// `&str` and `&[T]`
+fn borrowed_target(ty: &Type) -> Option<BorrowTarget<'_>> {
+    let TypeKind::Reference {
+        is_mut: false,
+        inner,
+        ..
+    } = &ty.kind
+    else {
+        return None;
+    };
+    match &inner.kind {
+        TypeKind::Named(name) if name == "str" => Some(BorrowTarget::Str),
+        TypeKind::List(elem) => Some(BorrowTarget::Slice(elem)),
+        _ => None,
+    }
+}
+
+fn named_type(ty: &Type) -> Option<&str> {
+    match &ty.kind {
+        TypeKind::Named(name) => Some(name.as_str()),
+        _ => None,
+    }
+}
+
+impl Transpiler {
+    /// Transpile `extern "rust" path { ... }` into wrapper functions
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for other ABIs, a missing crate path, or types that
+    /// cannot be transpiled
+    pub fn transpile_extern_block(
+        &self,
+        abi: &str,
+        path: Option<&str>,
+        functions: &[ExternFunction],
+    ) -> Result<TokenStream> {
+        if !is_rust_abi(abi) {
+            bail!("extern \"{abi}\" blocks are not supported; use extern \"rust\" to call Rust crates");
+        }
+        let Some(path) = path else {
+            bail!("extern \"rust\" blocks must name a crate, e.g. extern \"rust\" slug {{ ... }}");
+        };
+        if matches!(path.split("::").next(), Some("crate" | "self" | "super")) {
+            bail!("extern \"rust\" path `{path}` must start with a crate name");
+        }
+        let module = format_ident!("ruchy_extern_{}", path.replace("::", "_"));
+        let crate_path: syn::Path = syn::parse_str(&format!("::{path}"))?;
+        let wrappers = functions
+            .iter()
+            .map(|function| self.transpile_extern_function(&crate_path, function))
+            .collect::<Result<Vec<_>>>()?;
+        let names = functions.iter().map(|f| format_ident!("{}", f.name));
+        Ok(quote! {
+            mod #module {
+                #![allow(unused_imports)]
+                use super::*;
+                #(#wrappers)*
+            }
+            use #module::{#(#names),*};
+        })
+    }
+
+    fn transpile_extern_function(
+        &self,
+        crate_path: &syn::Path,
+        function: &ExternFunction,
+    ) -> Result<TokenStream> {
+        let name = format_ident!("{}", function.name);
+        let mut params = Vec::new();
+        let mut args = Vec::new();
+        for param in &function.params {
+            let ident = format_ident!("{}", param.name());
+            let (ty, arg) = self.extern_param(&function.name, &ident, &param.ty)?;
+            params.push(quote! { #ident: #ty });
+            args.push(arg);
+        }
+        let call = quote! { #crate_path::#name(#(#args),*) };
+        let (ret, body) = match &function.return_type {
+            Some(ty) => self.extern_return(&function.name, ty, call)?,
+            None => (quote! {}, call),
+        };
+        Ok(quote! {
+            pub fn #name(#(#params),*) #ret {
+                #body
+            }
+        })
+    }
+
+    /// Wrapper parameter type and the argument passed on to the crate
+    fn extern_param(
+        &self,
+        function: &str,
+        ident: &proc_macro2::Ident,
+        ty: &Type,
+    ) -> Result<(TokenStream, TokenStream)> {
+        if let Some(target) = borrowed_target(ty) {
+            let param_ty = match target {
+                BorrowTarget::Str => quote! { impl AsRef<str> },
+                BorrowTarget::Slice(elem) => {
+                    let elem = self.transpile_type(elem)?;
+                    quote! { impl AsRef<[#elem]> }
+                }
+            };
+            return Ok((param_ty, quote! { #ident.as_ref() }));
+        }
+        match named_type(ty) {
+            Some(int) if NARROW_INTS.contains(&int) => {
+                let int = format_ident!("{}", int);
+                let message = format!("{function}: `{ident}` is out of range for {int}");
+                Ok((
+                    quote! { i64 },
+                    quote! { #int::try_from(#ident).expect(#message) },
+                ))
+            }
+            Some("f32") => Ok((quote! { f64 }, quote! { #ident as f32 })),
+            _ => Ok((self.transpile_type(ty)?, quote! { #ident })),
+        }
+    }
+
+    /// Wrapper return type and the body converting the crate's result
+    fn extern_return(
+        &self,
+        function: &str,
+        ty: &Type,
+        call: TokenStream,
+    ) -> Result<(TokenStream, TokenStream)> {
+        if let Some(BorrowTarget::Str) = borrowed_target(ty) {
+            return Ok((quote! { -> String }, quote! { #call.to_string() }));
+        }
+        match named_type(ty) {
+            Some(int) if NARROW_INTS.contains(&int) => {
+                let message = format!("{function}: result is out of range for i64");
+                Ok((
+                    quote! { -> i64 },
+                    quote! { i64::try_from(#call).expect(#message) },
+                ))
+            }
+            Some("f32") => Ok((quote! { -> f64 }, quote! { f64::from(#call) })),
+            _ => {
+                let ret = self.transpile_type(ty)?;
+                Ok((quote! { -> #ret }, call))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Parser, Transpiler};
+
+    fn transpile(code: &str) -> anyhow::Result<String> {
+        let ast = Parser::new(code).parse()?;
+        Ok(Transpiler::new().transpile_to_program(&ast)?.to_string())
+    }
+
+    #[test]
+    fn test_extern_block_generates_wrappers_and_use() {
+        let rust = transpile(
+            "extern \"rust\" slug {\n    fun slugify(text: &str) -> String\n}\n\
+             fun main() {\n    let title = \"Hello World\".to_string()\n    println(slugify(title))\n    println(title)\n}",
+        )
+        .unwrap();
+        assert!(rust.contains("mod ruchy_extern_slug"), "{rust}");
+        assert!(
+            rust.contains("pub fn slugify (text : impl AsRef < str >) -> String"),
+            "{rust}"
+        );
+        assert!(
+            rust.contains(":: slug :: slugify (text . as_ref ())"),
+            "{rust}"
+        );
+        assert!(
+            rust.contains("use ruchy_extern_slug :: { slugify }"),
+            "{rust}"
+        );
+        // Call sites lend the string so `title` stays usable
+        assert!(rust.contains("slugify (& title)"), "{rust}");
+    }
+
+    #[test]
+    fn test_extern_numeric_conversions() {
+        let rust = transpile(
+            "extern \"rust\" textwrap::core {\n    fun display_width(text: &str) -> usize\n    fun scale(x: f32, times: u8) -> f32\n}",
+        )
+        .unwrap();
+        assert!(rust.contains("mod ruchy_extern_textwrap_core"), "{rust}");
+        assert!(rust.contains("-> i64"), "{rust}");
+        assert!(
+            rust.contains("i64 :: try_from (:: textwrap :: core :: display_width"),
+            "{rust}"
+        );
+        assert!(rust.contains("(x : f64 , times : i64) -> f64"), "{rust}");
+        assert!(rust.contains("x as f32"), "{rust}");
+        assert!(rust.contains("u8 :: try_from (times)"), "{rust}");
+        assert!(rust.contains("f64 :: from"), "{rust}");
+    }
+
+    #[test]
+    fn test_extern_block_errors() {
+        let err = transpile("extern \"C\" { fun abs(x: i32) -> i32 }").unwrap_err();
+        assert!(
+            format!("{err:#}").contains("extern \"C\" blocks"),
+            "{err:#}"
+        );
+        let err = transpile("extern \"rust\" { fun f() }").unwrap_err();
+        assert!(format!("{err:#}").contains("must name a crate"), "{err:#}");
+    }
+}
@@ -3,7 +3,7 @@
 //! This module provides functions to analyze properties of expressions and functions,
 //! such as whether a function returns void, is numeric, or returns a closure.
 
-use crate::frontend::ast::{Expr, ExprKind, Literal};
+use crate::frontend::ast::{Expr, ExprKind, Literal, Param};
 
 /// Check if function name suggests numeric operations
 ///
@@ -119,12 +119,18 @@ pub fn has_non_unit_expression(body: &Expr) -> bool {
 /// Check if function body returns a closure (Lambda expression)
 #[must_use]
 pub fn returns_closure(body: &Expr) -> bool {
+    returned_closure(body).is_some()
+}
+
+/// Parameters and body of the closure a function body returns, if any
+#[must_use]
+pub fn returned_closure(body: &Expr) -> Option<(&[Param], &Expr)> {
     match &body.kind {
-        ExprKind::Lambda { .. } => true,
-        ExprKind::Block(exprs) if !exprs.is_empty() => exprs
-            .last()
-            .is_some_and(|last_expr| matches!(last_expr.kind, ExprKind::Lambda { .. })),
-        _ => false,
+        ExprKind::Block(exprs) => returned_closure(exprs.last()?),
+        // A let followed by the closure parses with the closure as its body
+        ExprKind::Let { body, .. } => returned_closure(body),
+        ExprKind::Lambda { params, body } => Some((params, body)),
+        _ => None,
     }
 }
 
@@ -527,12 +533,16 @@ mod tests {
                     guard: None,
                     body: Box::new(unit_lit()),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
                 MatchArm {
                     pattern: Pattern::Wildcard,
                     guard: None,
                     body: Box::new(call("println", vec![])),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
             ],
         });
@@ -549,12 +559,16 @@ mod tests {
                     guard: None,
                     body: Box::new(unit_lit()),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
                 MatchArm {
                     pattern: Pattern::Wildcard,
                     guard: None,
                     body: Box::new(int_lit(42)),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
             ],
         });
@@ -299,6 +299,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -312,6 +314,8 @@ mod tests {
             span: Span::default(),
             is_mutable: true,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
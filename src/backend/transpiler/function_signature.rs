@@ -9,7 +9,7 @@
 //! **EXTREME TDD Round 70**: Extracted from statements.rs for modularization.
 
 use super::Transpiler;
-use crate::frontend::ast::{Attribute, Expr, ExprKind, Type};
+use crate::frontend::ast::{Attribute, Expr, ExprKind, Type, TypeKind};
 use anyhow::Result;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
@@ -34,7 +34,6 @@ impl Transpiler {
         if let Some(ty) = return_type {
             // BOOK-COMPAT-017: Handle &str return type without input borrows
             // When return type is &str and there are no reference params, use 'static
-            use crate::frontend::ast::TypeKind;
             if let TypeKind::Reference { inner, is_mut, .. } = &ty.kind {
                 if let TypeKind::Named(inner_name) = &inner.kind {
                     if inner_name == "str" {
@@ -57,8 +56,10 @@ impl Transpiler {
             return Ok(quote! {});
         }
 
-        if super::function_analysis::returns_closure(body) {
-            return Ok(quote! { -> impl Fn(i32) -> i32 });
+        if let Some((closure_params, closure_body)) =
+            super::function_analysis::returned_closure(body)
+        {
+            return self.returned_closure_type(body, closure_params, closure_body);
         }
 
         if let Some(return_ty) = infer_return_type_from_builtin_call(body) {
@@ -121,6 +122,104 @@ impl Transpiler {
         Ok(quote! {})
     }
 
+    /// `impl Fn(..) -> T` for the closure a function returns, or `impl FnMut`
+    /// when it mutates its captures; unannotated parameters are `i32`
+    ///
+    /// `T` is inferred from the closure's result (see [`Self::closure_value_type`])
+    /// and is `i32` when it cannot be.
+    /// Complexity: 4 (within Toyota Way limits)
+    pub(crate) fn returned_closure_type(
+        &self,
+        function_body: &Expr,
+        params: &[crate::frontend::ast::Param],
+        body: &Expr,
+    ) -> Result<TokenStream> {
+        let param_types = params
+            .iter()
+            .map(|param| match &param.ty.kind {
+                TypeKind::Named(name) if name == "_" || name == "Any" => Ok(quote! { i32 }),
+                _ => self.transpile_type(&param.ty),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let fn_trait = if super::mutation_detection::mutated_captures(params, body).is_empty() {
+            quote! { Fn }
+        } else {
+            quote! { FnMut }
+        };
+        let scopes = [body, function_body];
+        let result = match self.closure_value_type(body, params, &scopes, 0) {
+            Some(ty) if ty.to_string() == "()" => quote! {},
+            Some(ty) => quote! { -> #ty },
+            None => quote! { -> i32 },
+        };
+        Ok(quote! { -> impl #fn_trait(#(#param_types),*) #result })
+    }
+
+    /// The Rust type of `expr`, the result of a closure with `params`
+    ///
+    /// Literals, string interpolation, comparisons and arithmetic have the
+    /// obvious types; a parameter has its annotated type, and another name the
+    /// type of the value a `let` in `scopes` binds it to. `None` when unknown.
+    /// Complexity: 10 (within Toyota Way limits)
+    fn closure_value_type(
+        &self,
+        expr: &Expr,
+        params: &[crate::frontend::ast::Param],
+        scopes: &[&Expr],
+        depth: usize,
+    ) -> Option<TokenStream> {
+        use crate::frontend::ast::{BinaryOp, Literal, UnaryOp};
+        // `let x = x + 1` chains end somewhere; stop long before recursion hurts
+        if depth > 16 {
+            return None;
+        }
+        let infer = |e: &Expr| self.closure_value_type(e, params, scopes, depth + 1);
+        match &expr.kind {
+            ExprKind::Block(exprs) => exprs.last().map_or(Some(quote! { () }), infer),
+            ExprKind::If { then_branch, .. } => infer(then_branch),
+            ExprKind::Literal(Literal::String(_)) => Some(quote! { &'static str }),
+            ExprKind::Literal(Literal::Unit) => Some(quote! { () }),
+            ExprKind::Literal(_) => Some(Self::infer_type_from_value(expr)),
+            ExprKind::StringInterpolation { .. } => Some(quote! { String }),
+            ExprKind::Assign { .. } | ExprKind::CompoundAssign { .. } => Some(quote! { () }),
+            ExprKind::Binary { left, op, right } => match op {
+                BinaryOp::Equal
+                | BinaryOp::NotEqual
+                | BinaryOp::Less
+                | BinaryOp::LessEqual
+                | BinaryOp::Greater
+                | BinaryOp::GreaterEqual
+                | BinaryOp::Gt
+                | BinaryOp::In
+                | BinaryOp::And
+                | BinaryOp::Or => Some(quote! { bool }),
+                _ => infer(left).or_else(|| infer(right)),
+            },
+            ExprKind::Unary {
+                op: UnaryOp::Not, ..
+            } => Some(quote! { bool }),
+            ExprKind::Unary {
+                op: UnaryOp::Negate,
+                operand,
+            } => infer(operand),
+            ExprKind::Identifier(name) => {
+                if let Some(param) = params.iter().find(|p| &p.name() == name) {
+                    return match &param.ty.kind {
+                        TypeKind::Named(ty) if ty == "_" || ty == "Any" => Some(quote! { i32 }),
+                        _ => self.transpile_type(&param.ty).ok(),
+                    };
+                }
+                let value = scopes.iter().find_map(|scope| let_value(name, scope))?;
+                match &value.kind {
+                    // `let` turns string literals into Strings
+                    ExprKind::Literal(Literal::String(_)) => Some(quote! { String }),
+                    _ => infer(value),
+                }
+            }
+            _ => None,
+        }
+    }
+
     /// Generate return type tokens for builtin function calls
     fn generate_builtin_return_type(&self, return_ty: &str) -> Result<TokenStream> {
         match return_ty {
@@ -304,6 +403,12 @@ impl Transpiler {
                 // SAFETY: no actual unsafe code -- transpiling the unsafe modifier token
                 "unsafe" => modifiers.push(quote! { unsafe }),
                 "const" => modifiers.push(quote! { const }),
+                "extern" => {
+                    let abi = attr.args.first().map_or("C", String::as_str);
+                    modifiers.push(quote! { extern #abi });
+                }
+                // Ruchy annotations checked by the effect analysis, not Rust attributes
+                "pure" | "memoize" => {}
                 _ => {
                     regular_attrs.push(self.format_regular_attribute_impl(attr));
                 }
@@ -413,6 +518,26 @@ impl Transpiler {
 // Tests
 // ============================================================================
 
+/// The value of the first `let name = value` in `expr`'s statements
+fn let_value<'a>(name: &str, expr: &'a Expr) -> Option<&'a Expr> {
+    match &expr.kind {
+        ExprKind::Let {
+            name: bound,
+            value,
+            body,
+            ..
+        } => {
+            if bound == name {
+                Some(value)
+            } else {
+                let_value(name, body)
+            }
+        }
+        ExprKind::Block(exprs) => exprs.iter().find_map(|e| let_value(name, e)),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -663,6 +788,19 @@ mod tests {
         assert!(modifiers.to_string().contains("const"));
     }
 
+    #[test]
+    fn test_process_attrs_extern_abi() {
+        let transpiler = make_transpiler();
+        let attr = Attribute {
+            name: "extern".to_string(),
+            args: vec!["C".to_string()],
+            span: Span::default(),
+        };
+        let (attrs, modifiers) = transpiler.process_attributes_impl(&[attr]);
+        assert!(attrs.is_empty());
+        assert_eq!(modifiers.to_string(), "extern \"C\"");
+    }
+
     #[test]
     fn test_process_attrs_regular() {
         let transpiler = make_transpiler();
@@ -3,7 +3,7 @@
 //!
 //! This module handles function definition transpilation.
 
-use crate::frontend::ast::{Expr, ExprKind, Param, Type, TypeKind};
+use crate::frontend::ast::{Expr, ExprKind, Param, Pattern, Type, TypeKind};
 use anyhow::Result;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
@@ -50,9 +50,13 @@ impl Transpiler {
 
         // DEFECT-024 FIX: Track Option/Result-typed parameters for proper .map() transpilation
         // This enables is_option_or_result_with_context() to detect Option/Result variables
+        // Parameters of a type with a Display impl are tracked so `println` uses `{}`
         for param in params {
             let type_str = Transpiler::type_to_string(&param.ty);
-            if type_str.starts_with("Option") || type_str.starts_with("Result") {
+            if type_str.starts_with("Option")
+                || type_str.starts_with("Result")
+                || self.display_types.contains(&type_str)
+            {
                 self.register_variable_type(&param.name(), &type_str);
             }
         }
@@ -75,8 +79,22 @@ impl Transpiler {
         self.current_function_return_type
             .replace(effective_return_type.cloned());
 
+        // A function nested in a try block does not return into the try closure
+        let was_in_try = self.in_try_context.replace(false);
+        let is_generator = body.contains_yield();
+        let was_in_generator = self.in_generator_context.replace(is_generator);
+        // The closure the function returns moves its captures out with it
+        let was_returned_closure = self.returned_closure.replace(
+            super::function_analysis::returned_closure(body)
+                .map(|(_, closure_body)| closure_body.span),
+        );
+        // A function does not capture the variables of the enclosing function
+        let outer_shared = self.shared_captures.take();
+
         // DEFECT-012 FIX: Generate body tokens with special handling for String return type
-        let body_tokens = if let Some(ret_type) = effective_return_type {
+        let body_tokens = if is_generator {
+            self.generate_generator_body(body)?
+        } else if let Some(ret_type) = effective_return_type {
             if super::type_analysis::is_string_type(ret_type)
                 && super::type_analysis::body_needs_string_conversion(body)
             {
@@ -91,11 +109,29 @@ impl Transpiler {
         } else {
             self.generate_body_tokens(body, is_async)?
         };
+        self.in_try_context.set(was_in_try);
+        self.in_generator_context.set(was_in_generator);
+        self.returned_closure.set(was_returned_closure);
+        self.shared_captures.replace(outer_shared);
+        let body_tokens = if is_async || is_generator {
+            body_tokens
+        } else {
+            self.memoize_body(
+                name,
+                type_params,
+                params,
+                effective_return_type,
+                body_tokens,
+            )?
+        };
 
         // TRANSPILER-007: Clear current function return type after body transpilation
         self.current_function_return_type.replace(None);
 
-        let return_type_tokens = if needs_lifetime {
+        let return_type_tokens = if is_generator {
+            let item = self.generator_item_type(effective_return_type)?;
+            quote! { -> impl Iterator<Item = #item> }
+        } else if needs_lifetime {
             self.generate_return_type_tokens_with_lifetime(name, effective_return_type, body)?
         } else {
             self.generate_return_type_tokens(name, effective_return_type, body, params)?
@@ -114,6 +150,78 @@ impl Transpiler {
         )
     }
 
+    /// Generator bodies push each `yield` onto a buffer that the function
+    /// returns as an iterator, so the body runs to completion on the call
+    fn generate_generator_body(&self, body: &Expr) -> Result<TokenStream> {
+        let body_tokens = self.generate_body_tokens(body, false)?;
+        Ok(quote! {
+            let mut __yielded = Vec::new();
+            { #body_tokens; }
+            __yielded.into_iter()
+        })
+    }
+
+    /// Cache the results of a function listed in
+    /// [`Transpiler::memoized_functions`] per thread, keyed by its arguments
+    ///
+    /// The body runs in a closure so its `return`s still reach the cache.
+    /// Functions that are generic or take or return other than plain values
+    /// keep their body.
+    fn memoize_body(
+        &self,
+        name: &str,
+        type_params: &[String],
+        params: &[Param],
+        return_type: Option<&Type>,
+        body_tokens: TokenStream,
+    ) -> Result<TokenStream> {
+        let Some(return_type) = return_type.filter(|ty| is_memo_value(ty, true)) else {
+            return Ok(body_tokens);
+        };
+        let plain_params = params.iter().all(|param| {
+            matches!(param.pattern, Pattern::Identifier(_)) && is_memo_value(&param.ty, false)
+        });
+        if !self.memoized_functions.contains(name) || !type_params.is_empty() || !plain_params {
+            return Ok(body_tokens);
+        }
+        let key_types = params
+            .iter()
+            .map(|param| self.transpile_type(&param.ty))
+            .collect::<Result<Vec<_>>>()?;
+        let names: Vec<_> = params
+            .iter()
+            .map(|param| format_ident!("{}", param.name()))
+            .collect();
+        let result_type = self.transpile_type(return_type)?;
+        Ok(quote! {
+            thread_local! {
+                static __MEMO: std::cell::RefCell<
+                    std::collections::HashMap<(#(#key_types,)*), #result_type>
+                > = std::cell::RefCell::new(std::collections::HashMap::new());
+            }
+            let __key = (#(#names.to_owned(),)*);
+            if let Some(__result) = __MEMO.with(|memo| memo.borrow().get(&__key).cloned()) {
+                return __result;
+            }
+            let __result = (|| -> #result_type { #body_tokens })();
+            __MEMO.with(|memo| memo.borrow_mut().insert(__key, __result.clone()));
+            __result
+        })
+    }
+
+    /// Item type of a generator: `-> Iterator<T>` or `-> T` yield `T`;
+    /// unannotated generators yield `i32`
+    fn generator_item_type(&self, return_type: Option<&Type>) -> Result<TokenStream> {
+        match return_type {
+            Some(Type {
+                kind: TypeKind::Generic { base, params },
+                ..
+            }) if base == "Iterator" && params.len() == 1 => self.transpile_type(&params[0]),
+            Some(ty) => self.transpile_type(ty),
+            None => Ok(quote! { i32 }),
+        }
+    }
+
     /// BOOK-COMPAT-015: Check if body has a non-unit last expression
     fn has_non_unit_last_expr(body: &Expr) -> bool {
         match &body.kind {
@@ -145,6 +253,7 @@ impl Transpiler {
                 ExprKind::MacroInvocation { name, .. } if name == "println" || name == "print"
             )
             || matches!(&expr.kind, ExprKind::Assign { .. })
+            || matches!(&expr.kind, ExprKind::For { .. } | ExprKind::While { .. })
             || matches!(&expr.kind, ExprKind::Return { value: None })
             || matches!(&expr.kind, ExprKind::Return { value: Some(_) }) // Return with value is still unit for main
     }
@@ -192,6 +301,31 @@ impl Transpiler {
     }
 }
 
+/// Whether values of `ty` can key the memo cache, or for a result, be
+/// cloned out of it
+fn is_memo_value(ty: &Type, is_result: bool) -> bool {
+    let TypeKind::Named(name) = &ty.kind else {
+        return false;
+    };
+    matches!(
+        name.as_str(),
+        "i8" | "i16"
+            | "i32"
+            | "i64"
+            | "i128"
+            | "isize"
+            | "u8"
+            | "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "usize"
+            | "bool"
+            | "char"
+            | "String"
+    ) || (is_result && matches!(name.as_str(), "f32" | "f64"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -447,4 +581,15 @@ mod tests {
         let result = transpiler.transpile(&ast);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_memoize_caches_pure_functions_only() {
+        let mut parser = Parser::new(
+            "@memoize\nfun fib(n: i64) -> i64 { if n < 2 { n } else { fib(n - 1) + fib(n - 2) } }\n@memoize\nfun noisy(n: i64) -> i64 { println(n)\n n }\nfun main() { println(fib(30) + noisy(1)) }",
+        );
+        let ast = parser.parse().expect("parse");
+        let tokens = create_transpiler().transpile(&ast).unwrap().to_string();
+        assert_eq!(tokens.matches("__MEMO").count(), 3, "{tokens}");
+        assert!(!tokens.contains("memoize"), "{tokens}");
+    }
 }
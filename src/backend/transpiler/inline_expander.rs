@@ -57,7 +57,13 @@ fn collect_inline_candidates(expr: &Expr, functions: &mut HashMap<String, Functi
             // TRANSPILER-001: Don't inline functions that access global variables
             // Inlining them breaks scope - global vars are not accessible where inlined
             // TRANSPILER-136: Don't inline pub fun - they must be preserved for library exports
-            if body_size <= 10 && !is_recursive && !accesses_globals && !is_pub {
+            // An inlined `return` would exit the caller instead of the callee
+            if body_size <= 10
+                && !is_recursive
+                && !accesses_globals
+                && !is_pub
+                && !body.contains_return()
+            {
                 functions.insert(
                     name.clone(),
                     FunctionDef {
@@ -466,6 +472,8 @@ mod tests {
                     span: Span::default(),
                     is_mutable: false,
                     default_value: None,
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 }],
                 return_type: None,
                 body: Box::new(Expr::new(
@@ -534,6 +542,8 @@ mod tests {
                     span: Span::default(),
                     is_mutable: false,
                     default_value: None,
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 }],
                 return_type: None,
                 body: Box::new(Expr::new(
@@ -785,6 +795,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
         let body = ident("x");
         assert!(!accesses_global_variables(&params, &body));
@@ -802,6 +814,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
         let body = binary(ident("x"), BinaryOp::Add, ident("global_var"));
         assert!(accesses_global_variables(&params, &body));
@@ -1036,6 +1050,38 @@ mod tests {
         assert!(functions.is_empty()); // TRANSPILER-136: Don't inline pub functions
     }
 
+    // Test 25b: collect_inline_candidates - explicit return not inlined
+    #[test]
+    fn test_collect_inline_candidates_early_return() {
+        let mut functions = HashMap::new();
+        let body = Expr::new(
+            ExprKind::Block(vec![
+                Expr::new(
+                    ExprKind::Return {
+                        value: Some(Box::new(int_lit(1))),
+                    },
+                    Span::default(),
+                ),
+                int_lit(2),
+            ]),
+            Span::default(),
+        );
+        let func = Expr::new(
+            ExprKind::Function {
+                name: "early".to_string(),
+                type_params: vec![],
+                params: vec![],
+                body: Box::new(body),
+                return_type: None,
+                is_pub: false,
+                is_async: false,
+            },
+            Span::default(),
+        );
+        collect_inline_candidates(&func, &mut functions);
+        assert!(functions.is_empty()); // Inlining would retarget the return
+    }
+
     // Test 26: collect_inline_candidates - nested blocks
     #[test]
     fn test_collect_inline_candidates_nested_blocks() {
@@ -1129,6 +1175,8 @@ mod tests {
                 span: Span::default(),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
             Param {
                 pattern: Pattern::Identifier("b".to_string()),
@@ -1139,6 +1187,8 @@ mod tests {
                 span: Span::default(),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
         ];
         let args = vec![int_lit(10), int_lit(20)];
@@ -1171,6 +1221,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         };
 
         let add_fn = Expr::new(
@@ -1233,6 +1285,8 @@ mod tests {
                     guard: None,
                     body: Box::new(ident("value")),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 }],
             },
             Span::default(),
@@ -1296,6 +1350,8 @@ mod tests {
                         Span::default(),
                     )),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 }],
             },
             Span::default(),
@@ -1560,6 +1616,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
         let body = Expr::new(
             ExprKind::If {
@@ -948,6 +948,8 @@ fn test_ast_transpile_match() {
             guard: None,
             body: Box::new(int(0)),
             span: span(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }],
     });
     let result = transpiler.transpile_expr(&match_expr);
@@ -969,6 +971,8 @@ fn test_ast_transpile_function() {
                 span: span(),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
             Param {
                 pattern: Pattern::Identifier("b".to_string()),
@@ -976,6 +980,8 @@ fn test_ast_transpile_function() {
                 span: span(),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
         ],
         return_type: Some(named_type("i64")),
@@ -1003,6 +1009,8 @@ fn test_ast_transpile_lambda() {
             span: span(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }],
         body: Box::new(expr(ExprKind::Binary {
             left: Box::new(ident("x")),
@@ -6,30 +6,149 @@
 //! **EXTREME TDD Round 71**: Extracted from statements.rs for modularization.
 
 use super::Transpiler;
-use crate::frontend::ast::{Expr, Param};
+use crate::frontend::ast::{Expr, ExprKind, Param};
 use anyhow::Result;
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 
 impl Transpiler {
     /// Transpile lambda expressions to Rust closures
-    /// Complexity: 6 (within Toyota Way limits)
+    ///
+    /// Closures capture by reference: one that mutates a captured variable
+    /// borrows it, so the mutation is seen outside, unless it is the closure
+    /// its function returns. Variables the closure shares with its block
+    /// (see [`Self::with_shared_captures`]) are captured as clones of their
+    /// shared cell. Other closures move (copy) their captures.
+    /// Complexity: 8 (within Toyota Way limits)
     pub(crate) fn transpile_lambda_impl(
         &self,
         params: &[Param],
         body: &Expr,
     ) -> Result<TokenStream> {
-        let body_tokens = self.transpile_expr(body)?;
+        let shared: Vec<String> = self
+            .shared_captures
+            .borrow()
+            .iter()
+            .filter(|name| {
+                params.iter().all(|p| &p.name() != *name)
+                    && super::mutation_detection::mentions(name, body)
+            })
+            .cloned()
+            .collect();
+        // Parameters shadow the shared variables they are named after
+        let outer = self.shared_captures.borrow().clone();
+        for param in params {
+            self.shared_captures.borrow_mut().remove(&param.name());
+        }
+        // A closure nested in a try block does not return into the try closure
+        let was_in_try = self.in_try_context.replace(false);
+        let body_tokens = self.transpile_expr(body);
+        self.in_try_context.set(was_in_try);
+        *self.shared_captures.borrow_mut() = outer;
+        let body_tokens = body_tokens?;
 
-        if params.is_empty() {
-            return Ok(quote! { move || #body_tokens });
+        let borrows = self.returned_closure.get() != Some(body.span)
+            && shared.is_empty()
+            && !super::mutation_detection::mutated_captures(params, body).is_empty();
+
+        let closure = if params.is_empty() {
+            if borrows {
+                quote! { || #body_tokens }
+            } else {
+                quote! { move || #body_tokens }
+            }
+        } else {
+            let param_list = self.build_lambda_param_list(params)?;
+            let capture = if borrows { "" } else { "move " };
+            let closure_str = format!("{capture}|{param_list}| {body_tokens}");
+            closure_str
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Failed to parse closure: {e}"))?
+        };
+        if shared.is_empty() {
+            return Ok(closure);
         }
+        let cells = shared.iter().map(|name| Self::local_ident(name));
+        Ok(quote! { { #(let #cells = #cells.clone();)* #closure } })
+    }
 
-        let param_list = self.build_lambda_param_list(params)?;
-        let closure_str = format!("move |{param_list}| {body_tokens}");
-        closure_str
-            .parse()
-            .map_err(|e| anyhow::anyhow!("Failed to parse closure: {e}"))
+    /// Whether `name` is a local variable that closures share with its block
+    pub(crate) fn is_shared_capture(&self, name: &str) -> bool {
+        self.shared_captures.borrow().contains(name)
+    }
+
+    /// Run `transpile` over the block `stmts` with the `let mut` variables
+    /// that its closures share with it marked as shared captures
+    ///
+    /// A shared variable is declared as an `Rc<RefCell<_>>`, read as a clone
+    /// of its value and written through `borrow_mut`, and each closure that
+    /// uses it captures a clone of the `Rc`, so the closures and the block
+    /// see each other's writes as in the interpreter.
+    pub(crate) fn with_shared_captures<T>(
+        &self,
+        stmts: &[Expr],
+        transpile: impl FnOnce() -> Result<T>,
+    ) -> Result<T> {
+        let shared = super::mutation_detection::shared_captures(stmts, self.returned_closure.get());
+        if shared.is_empty() {
+            return transpile();
+        }
+        let outer = self.shared_captures.borrow().clone();
+        self.shared_captures.borrow_mut().extend(shared);
+        let result = transpile();
+        *self.shared_captures.borrow_mut() = outer;
+        result
+    }
+
+    /// `name` as a Rust identifier, escaping reserved keywords
+    pub(crate) fn local_ident(name: &str) -> proc_macro2::Ident {
+        if Self::is_rust_reserved_keyword(name) {
+            format_ident!("r#{}", name)
+        } else {
+            format_ident!("{}", name)
+        }
+    }
+
+    /// Write the shared variable `name`: `update` gets the place to write
+    ///
+    /// `value` is evaluated before the cell is borrowed, so it may read `name`.
+    pub(crate) fn transpile_shared_write(
+        name: &str,
+        value: Option<TokenStream>,
+        update: impl FnOnce(TokenStream) -> Result<TokenStream>,
+    ) -> Result<TokenStream> {
+        let cell = Self::local_ident(name);
+        let value = value.map(|value| quote! { let __value = #value; });
+        let update = update(quote! { (*__cell) })?;
+        Ok(quote! {
+            {
+                #value
+                let mut __cell = #cell.borrow_mut();
+                #update
+            }
+        })
+    }
+
+    /// Call the mutating method `method` on the shared variable `receiver`
+    /// through its borrowed cell
+    pub(crate) fn transpile_shared_method_call(
+        &self,
+        receiver: &Expr,
+        method: &str,
+        args: &[Expr],
+    ) -> Result<TokenStream> {
+        let ExprKind::Identifier(name) = &receiver.kind else {
+            return self.transpile_method_call(receiver, method, args);
+        };
+        let cell = Self::local_ident(name);
+        let borrowed = Expr::new(ExprKind::Identifier("__cell".to_string()), receiver.span);
+        let call = self.transpile_method_call(&borrowed, method, args)?;
+        Ok(quote! {
+            {
+                let mut __cell = #cell.borrow_mut();
+                #call
+            }
+        })
     }
 
     /// Build parameter list string with type annotations
@@ -113,6 +232,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -126,6 +247,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -264,4 +387,81 @@ mod tests {
         assert!(result_str.contains("async"));
         assert!(result_str.contains("move"));
     }
+
+    // ========================================================================
+    // capture tests
+    // ========================================================================
+
+    fn transpile_source(source: &str) -> String {
+        let ast = crate::frontend::parser::Parser::new(source)
+            .parse()
+            .expect("should parse");
+        make_transpiler()
+            .transpile(&ast)
+            .expect("should transpile")
+            .to_string()
+    }
+
+    #[test]
+    fn test_lambda_mutating_capture_borrows() {
+        let rust = transpile_source(
+            "fun main() {\n let mut count = 0\n let inc = || { count += 1 }\n inc()\n inc()\n}",
+        );
+        assert!(rust.contains("let mut inc = ||"), "got: {rust}");
+        assert!(!rust.contains("move ||"), "got: {rust}");
+    }
+
+    #[test]
+    fn test_capture_used_after_closure_is_shared_cell() {
+        let rust = transpile_source(
+            "fun main() {\n let mut count = 0\n let inc = || { count += 1 }\n inc()\n count += 10\n println(count)\n}",
+        );
+        assert!(
+            rust.contains("let count = std :: rc :: Rc :: new (std :: cell :: RefCell :: new (0))"),
+            "got: {rust}"
+        );
+        assert!(
+            rust.contains("let inc = { let count = count . clone () ; move ||"),
+            "got: {rust}"
+        );
+        assert!(rust.contains("count . borrow_mut ()"), "got: {rust}");
+        assert!(rust.contains("count . borrow () . clone ()"), "got: {rust}");
+    }
+
+    #[test]
+    fn test_read_only_capture_reassigned_later_is_shared_cell() {
+        let rust = transpile_source(
+            "fun main() {\n let mut x = 1\n let get = || x\n x = 5\n println(get())\n}",
+        );
+        assert!(rust.contains("RefCell :: new (1)"), "got: {rust}");
+    }
+
+    #[test]
+    fn test_read_only_capture_not_written_later_moves() {
+        let rust = transpile_source(
+            "fun main() {\n let mut x = 1\n x = 2\n let get = || x\n println(get())\n}",
+        );
+        assert!(!rust.contains("RefCell"), "got: {rust}");
+        assert!(rust.contains("move ||"), "got: {rust}");
+    }
+
+    #[test]
+    fn test_returned_lambda_mutating_capture_moves() {
+        let rust = transpile_source(
+            "fun make_counter() {\n let mut count = 0\n || { count += 1\n count }\n}\nfun main() {\n let counter = make_counter()\n println(counter())\n}",
+        );
+        assert!(rust.contains("impl FnMut () -> i32"), "got: {rust}");
+        assert!(rust.contains("move ||"), "got: {rust}");
+        assert!(rust.contains("let mut counter"), "got: {rust}");
+    }
+
+    #[test]
+    fn test_returned_lambda_result_type_follows_captured_value() {
+        let rust = transpile_source(
+            "fun make_greeter() {\n let name = \"Ada\"\n || f\"hi {name}\"\n}\nfun make_scaler() {\n let factor = 1.5\n |x: f64| x * factor\n}\nfun make_check() {\n || 1 < 2\n}",
+        );
+        assert!(rust.contains("impl Fn () -> String"), "got: {rust}");
+        assert!(rust.contains("impl Fn (f64) -> f64"), "got: {rust}");
+        assert!(rust.contains("impl Fn () -> bool"), "got: {rust}");
+    }
 }
@@ -150,8 +150,10 @@ impl Transpiler {
 
     /// Infer return type with lifetime when not explicitly specified
     fn infer_return_type_with_lifetime(&self, name: &str, body: &Expr) -> Result<TokenStream> {
-        if super::function_analysis::returns_closure(body) {
-            return Ok(quote! { -> impl Fn(i32) -> i32 });
+        if let Some((closure_params, closure_body)) =
+            super::function_analysis::returned_closure(body)
+        {
+            return self.returned_closure_type(body, closure_params, closure_body);
         }
 
         if super::function_analysis::looks_like_numeric_function(name) {
@@ -216,6 +218,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -229,6 +233,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -249,6 +255,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -4,6 +4,7 @@
 #![allow(clippy::doc_markdown)]
 
 use super::Transpiler;
+use crate::frontend::ast::{Expr, ExprKind};
 use anyhow::Result;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
@@ -83,6 +84,62 @@ impl Transpiler {
         }
     }
 
+    /// Whether `expr` is a lazy iterator pipeline: a range, `.iter()` or
+    /// `.into_iter()`, followed by any number of adapters
+    pub(crate) fn is_lazy_iterator_expr(expr: &Expr) -> bool {
+        match &expr.kind {
+            ExprKind::Range { .. } => true,
+            ExprKind::MethodCall {
+                receiver, method, ..
+            } => match method.as_str() {
+                "iter" | "into_iter" => true,
+                "map" | "filter" | "take" | "skip" | "zip" | "enumerate" | "chain" | "flat_map" => {
+                    Self::is_lazy_iterator_expr(receiver)
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Transpile a method on a lazy iterator pipeline to the matching Rust
+    /// iterator method, without collecting between stages
+    ///
+    /// Returns `None` for methods that have no iterator equivalent here.
+    pub(crate) fn transpile_lazy_iterator_method(
+        obj_tokens: &TokenStream,
+        object: &Expr,
+        method: &str,
+        arg_tokens: &[TokenStream],
+    ) -> Option<TokenStream> {
+        // Ranges transpile without parentheses, and `.iter()` yields
+        // references; pipelines work on owned elements like the interpreter
+        let source = match &object.kind {
+            ExprKind::Range { .. } => quote! { (#obj_tokens) },
+            ExprKind::MethodCall { method, .. } if method == "iter" => {
+                quote! { #obj_tokens.cloned() }
+            }
+            _ => obj_tokens.clone(),
+        };
+        let method_ident = format_ident!("{}", method);
+        let tokens = match (method, arg_tokens) {
+            ("map" | "flat_map" | "zip" | "chain" | "any" | "all" | "for_each", [arg]) => {
+                quote! { #source.#method_ident(#arg) }
+            }
+            // filter and find pass `&Item`; hand the user closure an owned copy
+            ("filter" | "find", [pred]) => {
+                quote! { #source.#method_ident(|__x| { let __f = #pred; __f(__x.clone()) }) }
+            }
+            ("take" | "skip", [n]) => quote! { #source.#method_ident(#n as usize) },
+            ("fold" | "reduce", [init, func]) => quote! { #source.fold(#init, #func) },
+            ("enumerate" | "count" | "min" | "max", []) => quote! { #source.#method_ident() },
+            ("sum", []) => quote! { #source.sum::<i32>() },
+            ("collect", []) => quote! { #source.collect::<Vec<_>>() },
+            _ => return None,
+        };
+        Some(tokens)
+    }
+
     /// Handle HashMap/HashSet methods: `contains_key`, items, etc.
     ///
     /// TRANSPILER-002 FIX: Removed "get" case - was causing .`cloned()` on all `get()` methods
@@ -204,7 +261,13 @@ impl Transpiler {
                     quote! { #obj_tokens.split(#(#arg_tokens),*).map(|s| s.to_string()).collect::<Vec<String>>() },
                 )
             }
-            "replace" => Ok(quote! { #obj_tokens.replace(#(#arg_tokens),*) }),
+            "replace" | "replace_all" => Ok(quote! { #obj_tokens.replace(#(#arg_tokens),*) }),
+            "repeat" => {
+                Self::require_exact_args("repeat", arg_tokens, 1)?;
+                let count = &arg_tokens[0];
+                Ok(quote! { #obj_tokens.repeat(#count as usize) })
+            }
+            "pad_start" | "pad_end" => Self::transpile_string_pad(obj_tokens, method, arg_tokens),
             "length" => {
                 // Map Ruchy's length() to Rust's len()
                 let rust_method = format_ident!("len");
@@ -229,6 +292,33 @@ impl Transpiler {
         }
     }
 
+    /// `s.pad_start(width, fill)` / `s.pad_end(width, fill)`: pad to `width`
+    /// characters by cycling `fill` (default `" "`)
+    fn transpile_string_pad(
+        obj_tokens: &TokenStream,
+        method: &str,
+        arg_tokens: &[TokenStream],
+    ) -> Result<TokenStream> {
+        let (width, fill) = match arg_tokens {
+            [width] => (width, quote! { " " }),
+            [width, fill] => (width, fill.clone()),
+            _ => anyhow::bail!("{method} requires a width and an optional fill string"),
+        };
+        let joined = if method == "pad_start" {
+            quote! { format!("{}{}", __padding, __s) }
+        } else {
+            quote! { format!("{}{}", __s, __padding) }
+        };
+        Ok(quote! {
+            {
+                let __s = #obj_tokens.to_string();
+                let __missing = (#width as usize).saturating_sub(__s.chars().count());
+                let __padding: String = #fill.chars().cycle().take(__missing).collect();
+                #joined
+            }
+        })
+    }
+
     /// Handle advanced collection methods: slice, concat, flatten, unique, join
     ///
     /// # Examples
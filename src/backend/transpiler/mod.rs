@@ -60,6 +60,7 @@ mod effects;
 mod expr_dispatcher; // EXTREME TDD Round 69: Expression dispatcher and utilities
 pub mod expression_analysis;
 mod expressions;
+pub mod extern_blocks;
 pub mod function_analysis;
 mod function_param_inference; // EXTREME TDD Round 70: Function parameter inference
 mod function_signature; // EXTREME TDD Round 70: Function signature generation
@@ -78,6 +79,8 @@ mod method_transpilers; // EXTREME TDD Round 65: iterator/map/set/string/collect
 mod method_type_conversion; // PDCA-20: .to_int()/.to_float()/.to_bool() method transpilation
 pub mod mutation_detection;
 mod network_builtins; // EXTREME TDD Round 62: json/http functions
+mod operator_impls; // Add/Eq/Ord/Index/Display impls as Rust operator trait impls
+pub mod options;
 pub mod param_usage_analysis;
 #[cfg(test)]
 mod param_usage_analysis_tests; // EXTREME TDD Round 85: Parameter usage analysis tests
@@ -87,6 +90,7 @@ mod print_helpers; // EXTREME TDD Round 64: print/println/dbg/panic macros
 mod program_transpiler; // EXTREME TDD Round 68: Program-level transpilation
 mod result_type;
 pub mod return_type_helpers;
+mod rust_blocks;
 mod statements;
 #[cfg(test)]
 mod statements_tests; // EXTREME TDD Round 83: Statement tests extracted
@@ -104,8 +108,13 @@ mod types;
 #[cfg(test)]
 mod types_tests; // EXTREME TDD Round 84: Type tests extracted
 mod utility_builtins; // EXTREME TDD Round 60: time/assert/collection/range functions
+mod variadic_params; // Rest parameters and spread arguments
 use crate::frontend::ast::{Attribute, Expr, ExprKind, Type};
 use anyhow::Result;
+pub use options::{
+    MainWrapping, Optimization, OutputStyle, RustEdition, SourceMapping, TranspileOutput,
+    TranspilerOptions, TranspilerOptionsBuilder,
+};
 use proc_macro2::TokenStream;
 // Module exports are handled by the impl blocks in each module
 /// Block categorization result: (functions, statements, modules, `has_main`, `main_expr`, imports, globals)
@@ -130,6 +139,12 @@ pub struct FunctionSignature {
     pub name: String,
     /// Parameter types as string representations.
     pub param_types: Vec<String>,
+    /// Parameter names, for binding named arguments at call sites.
+    pub param_names: Vec<String>,
+    /// Whether the last parameter is a rest parameter (`...args`), passed as a `Vec`.
+    pub is_variadic: bool,
+    /// Whether the function returns a closure that mutates its captures (`impl FnMut`).
+    pub returns_fn_mut: bool,
     /// Default values for parameters (None = no default, Some(expr) = has default).
     pub default_values: Option<Vec<Option<Box<crate::frontend::ast::Expr>>>>,
 }
@@ -169,6 +184,30 @@ pub struct Transpiler {
     /// "use of moved value" errors in loop iterations.
     /// Uses Cell for interior mutability since transpiler methods take &self.
     pub in_loop_context: std::cell::Cell<bool>,
+    /// Whether the current code generation is within a try block body.
+    ///
+    /// Inside a try body, `throw` and `?` become `Err` returns from the try
+    /// closure instead of panicking. Reset for nested function and closure bodies.
+    /// Uses Cell for interior mutability since transpiler methods take &self.
+    pub in_try_context: std::cell::Cell<bool>,
+    /// Whether the current code generation is within a generator function body.
+    ///
+    /// Inside a generator, `yield` pushes onto the buffer the function returns
+    /// as an iterator. Uses Cell for interior mutability since transpiler
+    /// methods take &self.
+    pub in_generator_context: std::cell::Cell<bool>,
+    /// Span of the body of the closure the current function returns.
+    ///
+    /// That closure outlives the function, so it moves its captures even when
+    /// it mutates them; other closures borrow the variables they mutate.
+    /// Uses Cell for interior mutability since transpiler methods take &self.
+    pub returned_closure: std::cell::Cell<Option<crate::frontend::ast::Span>>,
+    /// Local variables that closures share with the block that declares them.
+    ///
+    /// They live in an `Rc<RefCell<_>>` so a closure and the block see each
+    /// other's writes; see [`mutation_detection::shared_captures`].
+    /// Uses `RefCell` for interior mutability since transpiler methods take &self.
+    pub shared_captures: std::cell::RefCell<std::collections::HashSet<String>>,
     /// Set of variable names that require mutable bindings.
     ///
     /// Populated during mutability analysis to automatically infer `mut`.
@@ -181,6 +220,16 @@ pub struct Transpiler {
     ///
     /// Tracks module identifiers so field access can use :: syntax for module paths.
     pub module_names: std::collections::HashSet<String>,
+    /// Class names, so that calling a class like `Point(1, 2)` runs its `new` constructor.
+    pub class_names: std::collections::HashSet<String>,
+    /// Types with a `Display` impl, which `println` formats with `{}`.
+    pub display_types: std::collections::HashSet<String>,
+    /// Declared return types of functions, keyed like `hello` or `greet::hello`.
+    pub return_types: std::collections::HashMap<String, String>,
+    /// Class property names mapped to their types.
+    ///
+    /// Property reads and assignments go through the generated getter and `set_` method.
+    pub class_properties: std::collections::HashMap<String, String>,
     /// Variable names that hold String values (DEFECT-016 fix).
     ///
     /// Populated during transpilation to track which mutable variables are Strings.
@@ -227,6 +276,13 @@ pub struct Transpiler {
     /// Maps function name to a vector of inferred argument types from call sites.
     /// Used when function parameters have no explicit type to infer types from usage.
     pub call_site_arg_types: std::cell::RefCell<std::collections::HashMap<String, Vec<String>>>,
+    /// `@memoize` functions whose calls can be cached
+    ///
+    /// Populated before transpiling from [`crate::middleend::effects`]:
+    /// a function is listed only when its result depends on its arguments alone.
+    pub memoized_functions: std::collections::HashSet<String>,
+    /// How programs are transpiled; see [`TranspilerOptions`].
+    pub options: TranspilerOptions,
 }
 impl Default for Transpiler {
     fn default() -> Self {
@@ -238,9 +294,17 @@ impl Clone for Transpiler {
         Self {
             in_async_context: self.in_async_context,
             in_loop_context: std::cell::Cell::new(self.in_loop_context.get()),
+            in_try_context: std::cell::Cell::new(self.in_try_context.get()),
+            in_generator_context: std::cell::Cell::new(self.in_generator_context.get()),
+            returned_closure: std::cell::Cell::new(self.returned_closure.get()),
+            shared_captures: std::cell::RefCell::new(self.shared_captures.borrow().clone()),
             mutable_vars: self.mutable_vars.clone(),
             function_signatures: self.function_signatures.clone(),
             module_names: self.module_names.clone(),
+            class_names: self.class_names.clone(),
+            display_types: self.display_types.clone(),
+            return_types: self.return_types.clone(),
+            class_properties: self.class_properties.clone(),
             string_vars: std::cell::RefCell::new(self.string_vars.borrow().clone()),
             current_function_return_type: std::cell::RefCell::new(
                 self.current_function_return_type.borrow().clone(),
@@ -262,6 +326,8 @@ impl Clone for Transpiler {
             current_struct_name: std::cell::RefCell::new(self.current_struct_name.borrow().clone()),
             auto_boxed_fields: std::cell::RefCell::new(self.auto_boxed_fields.borrow().clone()),
             call_site_arg_types: std::cell::RefCell::new(self.call_site_arg_types.borrow().clone()),
+            memoized_functions: self.memoized_functions.clone(),
+            options: self.options.clone(),
         }
     }
 }
@@ -280,9 +346,17 @@ impl Transpiler {
         Self {
             in_async_context: false,
             in_loop_context: std::cell::Cell::new(false),
+            in_try_context: std::cell::Cell::new(false),
+            in_generator_context: std::cell::Cell::new(false),
+            returned_closure: std::cell::Cell::new(None),
+            shared_captures: std::cell::RefCell::new(std::collections::HashSet::new()),
             mutable_vars: std::collections::HashSet::new(),
             function_signatures: std::collections::HashMap::new(),
             module_names: std::collections::HashSet::new(),
+            class_names: std::collections::HashSet::new(),
+            display_types: std::collections::HashSet::new(),
+            return_types: std::collections::HashMap::new(),
+            class_properties: std::collections::HashMap::new(),
             string_vars: std::cell::RefCell::new(std::collections::HashSet::new()),
             current_function_return_type: std::cell::RefCell::new(None),
             global_vars: std::sync::RwLock::new(std::collections::HashSet::new()),
@@ -292,6 +366,28 @@ impl Transpiler {
             current_struct_name: std::cell::RefCell::new(None),
             auto_boxed_fields: std::cell::RefCell::new(std::collections::HashMap::new()),
             call_site_arg_types: std::cell::RefCell::new(std::collections::HashMap::new()),
+            memoized_functions: std::collections::HashSet::new(),
+            options: TranspilerOptions::default(),
+        }
+    }
+    /// Creates a transpiler configured by `options`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruchy::backend::transpiler::{Optimization, TranspilerOptions};
+    /// use ruchy::Transpiler;
+    ///
+    /// let options = TranspilerOptions::builder()
+    ///     .optimization(Optimization::None)
+    ///     .build();
+    /// let transpiler = Transpiler::with_options(options);
+    /// assert_eq!(transpiler.options.optimization, Optimization::None);
+    /// ```
+    pub fn with_options(options: TranspilerOptions) -> Self {
+        Self {
+            options,
+            ..Self::new()
         }
     }
     // EXTREME TDD Round 64: generate_value_printing_tokens moved to print_helpers.rs
@@ -462,6 +558,8 @@ mod tests {
                         span: Span::default(),
                         is_mutable: false,
                         default_value: None,
+                        leading_comments: Vec::new(),
+                        trailing_comment: None,
                     },
                     Param {
                         pattern: Pattern::Identifier("y".to_string()),
@@ -469,6 +567,8 @@ mod tests {
                         span: Span::default(),
                         is_mutable: false,
                         default_value: None,
+                        leading_comments: Vec::new(),
+                        trailing_comment: None,
                     },
                 ],
                 return_type: Some(create_simple_type("i64")),
@@ -723,6 +823,8 @@ mod tests {
                         span: Span::default(),
                         is_mutable: false,
                         default_value: None,
+                        leading_comments: Vec::new(),
+                        trailing_comment: None,
                     },
                     Param {
                         pattern: Pattern::Identifier("b".to_string()),
@@ -730,6 +832,8 @@ mod tests {
                         span: Span::default(),
                         is_mutable: false,
                         default_value: None,
+                        leading_comments: Vec::new(),
+                        trailing_comment: None,
                     },
                 ],
                 return_type: Some(create_simple_type("i64")),
@@ -823,6 +927,8 @@ mod tests {
                     span: Span::default(),
                     is_mutable: false,
                     default_value: None,
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 })
                 .collect();
 
@@ -3,7 +3,8 @@
 //! This module provides functions to detect if variables are mutated
 //! (reassigned or modified) within expression trees.
 
-use crate::frontend::ast::{Expr, ExprKind};
+use crate::frontend::ast::{Expr, ExprKind, Param, Span, StringPart};
+use std::collections::BTreeSet;
 
 /// Checks if a variable is mutated (reassigned or modified) in an expression tree
 ///
@@ -119,6 +120,381 @@ pub fn is_variable_mutated(name: &str, expr: &Expr) -> bool {
     }
 }
 
+/// Methods that modify their receiver in place
+pub(crate) const MUTATING_METHODS: &[&str] = &[
+    "push", "push_str", "insert", "remove", "pop", "clear", "extend", "append", "sort", "reverse",
+    "truncate", "retain", "dedup",
+];
+
+/// Variables a closure mutates that it does not bind itself
+///
+/// A closure that assigns to a captured variable, or calls a mutating method
+/// on one, is `FnMut`: moving the variable into it would leave the enclosing
+/// scope with a stale copy.
+///
+/// # Examples
+/// ```ignore
+/// // `|| { count += 1 }` mutates the captured `count`
+/// assert!(mutated_captures(&params, &body).contains("count"));
+/// ```
+pub fn mutated_captures(params: &[Param], body: &Expr) -> BTreeSet<String> {
+    let mut mutated = BTreeSet::new();
+    let mut bound: BTreeSet<String> = params.iter().map(Param::name).collect();
+    collect_mutations(body, &mut mutated, &mut bound);
+    mutated.retain(|name| !bound.contains(name));
+    mutated
+}
+
+/// Collect mutated variables into `mutated` and local bindings into `bound`
+/// Complexity: 10 (within Toyota Way limits)
+fn collect_mutations(expr: &Expr, mutated: &mut BTreeSet<String>, bound: &mut BTreeSet<String>) {
+    let children: Vec<&Expr> = match &expr.kind {
+        ExprKind::Assign { target, value } | ExprKind::CompoundAssign { target, value, .. } => {
+            mark_mutated(target, mutated);
+            vec![&**value]
+        }
+        ExprKind::PreIncrement { target }
+        | ExprKind::PostIncrement { target }
+        | ExprKind::PreDecrement { target }
+        | ExprKind::PostDecrement { target } => {
+            mark_mutated(target, mutated);
+            Vec::new()
+        }
+        ExprKind::MethodCall {
+            receiver,
+            method,
+            args,
+        } => {
+            if MUTATING_METHODS.contains(&method.as_str()) {
+                mark_mutated(receiver, mutated);
+            }
+            std::iter::once(&**receiver).chain(args).collect()
+        }
+        ExprKind::Let {
+            name, value, body, ..
+        } => {
+            bound.insert(name.clone());
+            vec![&**value, &**body]
+        }
+        ExprKind::For {
+            var, iter, body, ..
+        } => {
+            bound.insert(var.clone());
+            vec![&**iter, &**body]
+        }
+        // A nested closure's own parameters shadow the captures
+        ExprKind::Lambda { params, body } => {
+            mutated.extend(mutated_captures(params, body));
+            Vec::new()
+        }
+        ExprKind::Block(exprs) => exprs.iter().collect(),
+        ExprKind::If {
+            condition,
+            then_branch,
+            else_branch,
+        } => std::iter::once(&**condition)
+            .chain(std::iter::once(&**then_branch))
+            .chain(else_branch.as_deref())
+            .collect(),
+        ExprKind::While {
+            condition, body, ..
+        } => vec![&**condition, &**body],
+        ExprKind::Match { expr, arms } => std::iter::once(&**expr)
+            .chain(arms.iter().map(|arm| &*arm.body))
+            .collect(),
+        ExprKind::Binary { left, right, .. } => vec![&**left, &**right],
+        ExprKind::Unary { operand, .. } => vec![&**operand],
+        ExprKind::Call { func, args } => std::iter::once(&**func).chain(args).collect(),
+        ExprKind::Return { value: Some(value) } => vec![&**value],
+        _ => Vec::new(),
+    };
+    for child in children {
+        collect_mutations(child, mutated, bound);
+    }
+}
+
+/// The variable an assignment target writes to: `x`, `x.field` or `x[i]`
+fn mark_mutated(target: &Expr, mutated: &mut BTreeSet<String>) {
+    match &target.kind {
+        ExprKind::Identifier(name) => {
+            mutated.insert(name.clone());
+        }
+        ExprKind::FieldAccess { object, .. } | ExprKind::IndexAccess { object, .. } => {
+            mark_mutated(object, mutated);
+        }
+        _ => {}
+    }
+}
+
+/// `let mut` variables of `stmts` that closures share with the rest of the block
+///
+/// Closures capture by reference, as in the interpreter. A copy moved into the
+/// closure would miss writes the block makes after creating it, and a borrow
+/// would keep the block from using the variable while the closure lives, so
+/// such a variable lives in a shared cell instead: one a closure captures and
+/// the block uses after it, where either side writes it. `returned` is the
+/// body of the closure the function returns, which owns its captures.
+///
+/// Variables written through a field or index (`x.f = 1`, `x[0] = 1`) are
+/// left out.
+pub fn shared_captures(stmts: &[Expr], returned: Option<Span>) -> BTreeSet<String> {
+    let mut shared = BTreeSet::new();
+    for (i, stmt) in stmts.iter().enumerate() {
+        let ExprKind::Let {
+            name,
+            body,
+            is_mutable: true,
+            ..
+        } = &stmt.kind
+        else {
+            continue;
+        };
+        if is_shared_capture(
+            name,
+            std::iter::once(&**body).chain(&stmts[i + 1..]),
+            returned,
+        ) {
+            shared.insert(name.clone());
+        }
+    }
+    shared
+}
+
+/// Whether closures in `scope`, the code after `let mut name`, share `name`
+/// with the rest of it, as for [`shared_captures`]
+pub fn is_shared_capture<'a>(
+    name: &str,
+    scope: impl IntoIterator<Item = &'a Expr>,
+    returned: Option<Span>,
+) -> bool {
+    let mut uses = Vec::new();
+    for expr in scope {
+        collect_uses(name, expr, &mut uses);
+    }
+    if uses.iter().any(|u| u.kind == UseKind::OtherWrite) {
+        return false;
+    }
+    uses.iter().any(|closure| {
+        let UseKind::Closure { body, writes } = closure.kind else {
+            return false;
+        };
+        Some(body) != returned
+            && uses
+                .iter()
+                .any(|later| later.span.start >= closure.span.end && (writes || later.writes()))
+    })
+}
+
+/// Whether `expr` reads or writes the variable `name`
+pub fn mentions(name: &str, expr: &Expr) -> bool {
+    let mut uses = Vec::new();
+    collect_uses(name, expr, &mut uses);
+    !uses.is_empty()
+}
+
+/// A use of a variable by [`collect_uses`]
+#[derive(Clone, Copy)]
+struct Use {
+    span: Span,
+    kind: UseKind,
+}
+
+impl Use {
+    fn writes(&self) -> bool {
+        matches!(
+            self.kind,
+            UseKind::Write | UseKind::Closure { writes: true, .. }
+        )
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum UseKind {
+    Read,
+    /// Assigned, updated, or changed by a mutating method
+    Write,
+    /// Written through a field or an index
+    OtherWrite,
+    /// Captured by the closure with this body
+    Closure {
+        body: Span,
+        writes: bool,
+    },
+}
+
+/// Uses of `name` in `expr`, stopping where a `let` shadows it
+/// Complexity: 10 (within Toyota Way limits)
+fn collect_uses(name: &str, expr: &Expr, uses: &mut Vec<Use>) {
+    let mut visit = |e: &Expr| collect_uses(name, e, uses);
+    match &expr.kind {
+        ExprKind::Identifier(n) if n == name => uses.push(Use {
+            span: expr.span,
+            kind: UseKind::Read,
+        }),
+        ExprKind::Assign { target, value } | ExprKind::CompoundAssign { target, value, .. } => {
+            visit(value);
+            push_write(name, target, uses);
+        }
+        ExprKind::PreIncrement { target }
+        | ExprKind::PostIncrement { target }
+        | ExprKind::PreDecrement { target }
+        | ExprKind::PostDecrement { target } => push_write(name, target, uses),
+        ExprKind::MethodCall {
+            receiver,
+            method,
+            args,
+        } => {
+            args.iter().for_each(&mut visit);
+            if MUTATING_METHODS.contains(&method.as_str()) {
+                push_write(name, receiver, uses);
+            } else {
+                visit(receiver);
+            }
+        }
+        ExprKind::Lambda { params, body } => {
+            if params.iter().all(|p| p.name() != name) {
+                let mut inner = Vec::new();
+                collect_uses(name, body, &mut inner);
+                if let Some(other) = inner.iter().find(|u| u.kind == UseKind::OtherWrite) {
+                    uses.push(*other);
+                } else if !inner.is_empty() {
+                    let writes = inner.iter().any(Use::writes);
+                    let end = expr.span.end.max(body.span.end);
+                    uses.push(Use {
+                        span: Span::new(expr.span.start, end),
+                        kind: UseKind::Closure {
+                            body: body.span,
+                            writes,
+                        },
+                    });
+                }
+            }
+        }
+        ExprKind::Let {
+            name: bound,
+            value,
+            body,
+            ..
+        } => {
+            visit(value);
+            if bound != name {
+                visit(body);
+            }
+        }
+        ExprKind::Function { .. } => {}
+        _ => for_each_child(expr, &mut visit),
+    }
+}
+
+/// Record a write to `name` through `target`, or the reads in a target that
+/// writes something else
+fn push_write(name: &str, target: &Expr, uses: &mut Vec<Use>) {
+    match &target.kind {
+        ExprKind::Identifier(n) if n == name => uses.push(Use {
+            span: target.span,
+            kind: UseKind::Write,
+        }),
+        ExprKind::FieldAccess { object, .. } | ExprKind::IndexAccess { object, .. } if matches!(&object.kind, ExprKind::Identifier(n) if n == name) =>
+        {
+            uses.push(Use {
+                span: target.span,
+                kind: UseKind::OtherWrite,
+            });
+        }
+        _ => collect_uses(name, target, uses),
+    }
+}
+
+/// Apply `f` to each direct subexpression of the kinds closures appear in
+fn for_each_child(expr: &Expr, f: &mut dyn FnMut(&Expr)) {
+    match &expr.kind {
+        ExprKind::Block(exprs)
+        | ExprKind::List(exprs)
+        | ExprKind::Set(exprs)
+        | ExprKind::Tuple(exprs)
+        | ExprKind::Macro { args: exprs, .. } => exprs.iter().for_each(f),
+        ExprKind::Call { func, args } => {
+            f(func);
+            args.iter().for_each(f);
+        }
+        ExprKind::StringInterpolation { parts } => {
+            for part in parts {
+                if let StringPart::Expr(e) | StringPart::ExprWithFormat { expr: e, .. } = part {
+                    f(e);
+                }
+            }
+        }
+        ExprKind::LetPattern { value, body, .. } => {
+            f(value);
+            f(body);
+        }
+        ExprKind::If {
+            condition: head,
+            then_branch,
+            else_branch,
+        }
+        | ExprKind::IfLet {
+            expr: head,
+            then_branch,
+            else_branch,
+            ..
+        } => {
+            f(head);
+            f(then_branch);
+            if let Some(else_branch) = else_branch {
+                f(else_branch);
+            }
+        }
+        ExprKind::Match { expr, arms } => {
+            f(expr);
+            for arm in arms {
+                if let Some(guard) = &arm.guard {
+                    f(guard);
+                }
+                f(&arm.body);
+            }
+        }
+        ExprKind::While {
+            condition: head,
+            body,
+            ..
+        }
+        | ExprKind::WhileLet {
+            expr: head, body, ..
+        }
+        | ExprKind::For {
+            iter: head, body, ..
+        }
+        | ExprKind::Binary {
+            left: head,
+            right: body,
+            ..
+        }
+        | ExprKind::IndexAccess {
+            object: head,
+            index: body,
+        }
+        | ExprKind::Range {
+            start: head,
+            end: body,
+            ..
+        } => {
+            f(head);
+            f(body);
+        }
+        ExprKind::Loop { body: e, .. }
+        | ExprKind::Unary { operand: e, .. }
+        | ExprKind::FieldAccess { object: e, .. }
+        | ExprKind::Return { value: Some(e) }
+        | ExprKind::Break { value: Some(e), .. }
+        | ExprKind::Throw { expr: e }
+        | ExprKind::Try { expr: e }
+        | ExprKind::Await { expr: e }
+        | ExprKind::Defer { expr: e } => f(e),
+        _ => {}
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -227,6 +603,8 @@ mod tests {
             guard: None,
             body: Box::new(body),
             span: Span::default(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -255,6 +633,8 @@ mod tests {
                     span: Span::default(),
                     is_mutable: false,
                     default_value: None,
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 })
                 .collect(),
             body: Box::new(body),
@@ -620,6 +1000,8 @@ mod tests {
                 span: Span::default(),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             }],
             return_type: None,
             body: Box::new(assign(ident("x"), int_lit(1))),
@@ -644,6 +1026,8 @@ mod tests {
                 span: Span::default(),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             }],
             return_type: None,
             body: Box::new(int_lit(42)),
@@ -791,4 +1175,54 @@ mod tests {
         // Return value is evaluated but doesn't count as mutation
         assert!(!is_variable_mutated("x", &ret));
     }
+
+    // ==================== mutated_captures Tests ====================
+
+    fn captures_of(closure: &Expr) -> BTreeSet<String> {
+        let ExprKind::Lambda { params, body } = &closure.kind else {
+            panic!("expected a lambda");
+        };
+        mutated_captures(params, body)
+    }
+
+    #[test]
+    fn test_mutated_captures_counter() {
+        let closure = lambda(vec![], compound_assign(ident("count"), int_lit(1)));
+        assert!(captures_of(&closure).contains("count"));
+    }
+
+    #[test]
+    fn test_mutated_captures_excludes_params_and_locals() {
+        let body = block(vec![
+            assign(ident("x"), int_lit(1)),
+            let_expr("total", int_lit(0), post_increment(ident("total"))),
+        ]);
+        assert!(captures_of(&lambda(vec!["x"], body)).is_empty());
+    }
+
+    #[test]
+    fn test_mutated_captures_mutating_method_and_index() {
+        let body = block(vec![
+            method_call(ident("items"), "push", vec![int_lit(1)]),
+            method_call(ident("names"), "len", vec![]),
+            compound_assign(
+                make_expr(ExprKind::IndexAccess {
+                    object: Box::new(ident("counts")),
+                    index: Box::new(int_lit(0)),
+                }),
+                int_lit(1),
+            ),
+        ]);
+        let captures = captures_of(&lambda(vec![], body));
+        assert!(captures.contains("items"));
+        assert!(captures.contains("counts"));
+        assert!(!captures.contains("names"));
+    }
+
+    #[test]
+    fn test_mutated_captures_nested_closure() {
+        let inner = lambda(vec!["n"], compound_assign(ident("sum"), ident("n")));
+        let outer = lambda(vec![], call(ident("each"), vec![inner]));
+        assert!(captures_of(&outer).contains("sum"));
+    }
 }
@@ -0,0 +1,311 @@
+//! Operator traits: Ruchy impls of `Add`, `Eq`, `Ord`, `Index` and `Display`
+//! as Rust trait impls
+//!
+//! Ruchy's operator trait methods leave out what Rust's signatures spell
+//! out, so each impl is completed here:
+//!
+//! - `Add`, `Sub`, `Mul`, `Div`, `Rem`: `std::ops` traits, with
+//!   `type Output` from the method's return type;
+//! - `PartialEq`/`Eq` with an `eq` method: `PartialEq` comparing with
+//!   `&Self`, plus `impl Eq` for `Eq`;
+//! - `Ord` with `cmp`: `Ord` plus the `PartialOrd` that delegates to it;
+//!   `Ordering` needs no import in the method body;
+//! - `Index`: `std::ops::Index`, returning a reference to the place the
+//!   body ends in, such as `self.items[i]`;
+//! - `Display` with `fmt(&self) -> String`: `std::fmt::Display` writing the
+//!   returned string.
+//!
+//! Generic impls and impls whose method does not have the operator's name
+//! go through [`Transpiler::transpile_impl`] unchanged.
+use super::{Result, Transpiler};
+use crate::frontend::ast::{Expr, ExprKind, ImplMethod, Param, TypeKind};
+use anyhow::bail;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// `std::ops` traits of the binary operators, with their methods
+const ARITHMETIC: &[(&str, &str)] = &[
+    ("Add", "add"),
+    ("Sub", "sub"),
+    ("Mul", "mul"),
+    ("Div", "div"),
+    ("Rem", "rem"),
+];
+
+/// Whether a parameter was written without a type
+fn is_untyped(param: &Param) -> bool {
+    matches!(&param.ty.kind, TypeKind::Named(name) if name == "Any" || name == "_")
+}
+
+impl Transpiler {
+    /// Rust impl of an operator trait, or `None` for other traits and impls
+    pub(crate) fn transpile_operator_impl(
+        &self,
+        for_type: &str,
+        trait_name: &str,
+        methods: &[ImplMethod],
+    ) -> Result<Option<TokenStream>> {
+        let (base, args) = trait_name.split_at(trait_name.find('<').unwrap_or(trait_name.len()));
+        let [method] = methods else {
+            return Ok(None);
+        };
+        let type_ident = format_ident!("{}", for_type);
+        let trait_args: TokenStream = args
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Invalid trait arguments in {trait_name}"))?;
+        let tokens = match (base, method.name.as_str()) {
+            (_, name) if ARITHMETIC.contains(&(base, name)) => {
+                let trait_ident = format_ident!("{}", base);
+                let method_ident = format_ident!("{}", name);
+                let other = self.operand_tokens(method, false)?;
+                let output = self.return_type_tokens(method)?;
+                let body = self.transpile_expr(&method.body)?;
+                quote! {
+                    impl std::ops::#trait_ident #trait_args for #type_ident {
+                        type Output = #output;
+                        fn #method_ident(self, #other) -> Self::Output {
+                            #body
+                        }
+                    }
+                }
+            }
+            ("PartialEq" | "Eq", "eq") => {
+                let other = self.operand_tokens(method, true)?;
+                let body = self.transpile_expr(&method.body)?;
+                let eq = (base == "Eq").then(|| quote! { impl Eq for #type_ident {} });
+                quote! {
+                    impl PartialEq for #type_ident {
+                        fn eq(&self, #other) -> bool {
+                            #body
+                        }
+                    }
+                    #eq
+                }
+            }
+            ("Ord", "cmp") => {
+                let other = self.operand_tokens(method, true)?;
+                let body = self.transpile_expr(&method.body)?;
+                quote! {
+                    impl Ord for #type_ident {
+                        fn cmp(&self, #other) -> std::cmp::Ordering {
+                            #[allow(unused_imports)]
+                            use std::cmp::Ordering;
+                            #body
+                        }
+                    }
+                    impl PartialOrd for #type_ident {
+                        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                            Some(self.cmp(other))
+                        }
+                    }
+                }
+            }
+            ("PartialOrd", "partial_cmp") => {
+                let other = self.operand_tokens(method, true)?;
+                let body = self.transpile_expr(&method.body)?;
+                quote! {
+                    impl PartialOrd for #type_ident {
+                        fn partial_cmp(&self, #other) -> Option<std::cmp::Ordering> {
+                            #[allow(unused_imports)]
+                            use std::cmp::Ordering;
+                            #body
+                        }
+                    }
+                }
+            }
+            ("Index", "index") => self.index_impl(&type_ident, args, method)?,
+            ("Display", "fmt") if method.params.len() == 1 => {
+                let body = self.transpile_expr(&method.body)?;
+                quote! {
+                    impl std::fmt::Display for #type_ident {
+                        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                            write!(f, "{}", #body)
+                        }
+                    }
+                }
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(tokens))
+    }
+
+    /// `impl std::ops::Index`, whose method returns a reference to its place
+    fn index_impl(
+        &self,
+        type_ident: &proc_macro2::Ident,
+        args: &str,
+        method: &ImplMethod,
+    ) -> Result<TokenStream> {
+        let index_type = match args.strip_prefix('<').and_then(|a| a.strip_suffix('>')) {
+            Some(index_type) => index_type
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Invalid Index type {index_type}"))?,
+            None => match method.params.get(1) {
+                Some(param) if !is_untyped(param) => self.transpile_type(&param.ty)?,
+                _ => quote! { usize },
+            },
+        };
+        let index = format_ident!(
+            "{}",
+            method
+                .params
+                .get(1)
+                .map_or_else(|| "index".to_string(), Param::name)
+        );
+        let output = match &method.return_type {
+            Some(ty) => match &ty.kind {
+                TypeKind::Reference { inner, .. } => self.transpile_type(inner)?,
+                _ => self.transpile_type(ty)?,
+            },
+            None => bail!("Index::index needs a return type to transpile"),
+        };
+        let place = self.place_reference_tokens(&method.body)?;
+        Ok(quote! {
+            impl std::ops::Index<#index_type> for #type_ident {
+                type Output = #output;
+                fn index(&self, #index: #index_type) -> &Self::Output {
+                    #place
+                }
+            }
+        })
+    }
+
+    /// A reference to the place an `index` body ends in
+    fn place_reference_tokens(&self, body: &Expr) -> Result<TokenStream> {
+        match &body.kind {
+            ExprKind::Block(exprs) => {
+                let Some((last, init)) = exprs.split_last() else {
+                    bail!("Index::index must return a reference to a field or element");
+                };
+                let init = init
+                    .iter()
+                    .map(|expr| self.transpile_expr(expr))
+                    .collect::<Result<Vec<_>>>()?;
+                let last = self.place_reference_tokens(last)?;
+                Ok(quote! { #(#init;)* #last })
+            }
+            ExprKind::IndexAccess { object, index } => {
+                let object = self.transpile_expr(object)?;
+                let index = self.transpile_expr(index)?;
+                Ok(quote! { &#object[#index as usize] })
+            }
+            ExprKind::FieldAccess { .. } => {
+                let place = self.transpile_expr(body)?;
+                Ok(quote! { &#place })
+            }
+            _ => bail!("Index::index must return a reference to a field or element"),
+        }
+    }
+
+    /// The right operand parameter of an operator method
+    ///
+    /// Comparisons take `&Self` whatever the declared type; arithmetic keeps
+    /// the declared type, or `Self` without one.
+    fn operand_tokens(&self, method: &ImplMethod, by_reference: bool) -> Result<TokenStream> {
+        let Some(param) = method.params.get(1) else {
+            bail!("{} needs a parameter for the right operand", method.name);
+        };
+        let name = format_ident!("{}", param.name());
+        let ty = if by_reference {
+            quote! { &Self }
+        } else if is_untyped(param) {
+            quote! { Self }
+        } else {
+            self.transpile_type(&param.ty)?
+        };
+        Ok(quote! { #name: #ty })
+    }
+
+    /// `type Output` of an arithmetic operator: the method's return type, or `Self`
+    fn return_type_tokens(&self, method: &ImplMethod) -> Result<TokenStream> {
+        match &method.return_type {
+            Some(ty) => self.transpile_type(ty),
+            None => Ok(quote! { Self }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Parser, Transpiler};
+
+    fn transpile(code: &str) -> anyhow::Result<String> {
+        let ast = Parser::new(code).parse()?;
+        Ok(Transpiler::new().transpile_to_program(&ast)?.to_string())
+    }
+
+    const POINT: &str = "struct Point { x: i32, y: i32 }\n";
+
+    #[test]
+    fn test_add_gets_output_type() {
+        let rust = transpile(&format!(
+            "{POINT}impl Add for Point {{\n    fun add(self, other: Point) -> Point {{ Point {{ x: self.x + other.x, y: self.y + other.y }} }}\n}}"
+        ))
+        .unwrap();
+        assert!(rust.contains("impl std :: ops :: Add for Point"), "{rust}");
+        assert!(rust.contains("type Output = Point ;"), "{rust}");
+        assert!(
+            rust.contains("fn add (self , other : Point) -> Self :: Output"),
+            "{rust}"
+        );
+    }
+
+    #[test]
+    fn test_eq_and_ord_get_companion_impls() {
+        let rust = transpile(&format!(
+            "{POINT}impl Eq for Point {{\n    fun eq(&self, other: Point) -> bool {{ self.x == other.x }}\n}}\n\
+             impl Ord for Point {{\n    fun cmp(&self, other) -> Ordering {{ Ordering::Equal }}\n}}"
+        ))
+        .unwrap();
+        assert!(rust.contains("impl PartialEq for Point"), "{rust}");
+        assert!(
+            rust.contains("fn eq (& self , other : & Self) -> bool"),
+            "{rust}"
+        );
+        assert!(rust.contains("impl Eq for Point { }"), "{rust}");
+        assert!(rust.contains("impl Ord for Point"), "{rust}");
+        assert!(
+            rust.contains("Some (self . cmp (other))"),
+            "Ord brings the PartialOrd it needs: {rust}"
+        );
+    }
+
+    #[test]
+    fn test_index_returns_a_reference_to_its_place() {
+        let rust = transpile(
+            "struct Grid { cells: Vec<i32> }\nimpl Index<usize> for Grid {\n    fun index(&self, i: usize) -> i32 { self.cells[i] }\n}",
+        )
+        .unwrap();
+        assert!(
+            rust.contains("impl std :: ops :: Index < usize > for Grid"),
+            "{rust}"
+        );
+        assert!(rust.contains("type Output = i32 ;"), "{rust}");
+        assert!(rust.contains("-> & Self :: Output"), "{rust}");
+        assert!(rust.contains("& self . cells [i as usize]"), "{rust}");
+    }
+
+    #[test]
+    fn test_display_writes_the_returned_string() {
+        let rust = transpile(&format!(
+            "{POINT}impl Display for Point {{\n    fun fmt(&self) -> String {{ \"point\".to_string() }}\n}}"
+        ))
+        .unwrap();
+        assert!(
+            rust.contains("impl std :: fmt :: Display for Point"),
+            "{rust}"
+        );
+        assert!(rust.contains("write ! (f , \"{}\""), "{rust}");
+    }
+
+    #[test]
+    fn test_println_formats_a_display_type_with_display() {
+        let rust = transpile(&format!(
+            "{POINT}impl Display for Point {{\n    fun fmt(&self) -> String {{ \"point\".to_string() }}\n}}\n\
+             let p = Point {{ x: 1, y: 2 }}\nprintln(p)\nprintln([1, 2])"
+        ))
+        .unwrap();
+        assert!(rust.contains("println ! (\"{}\" , p)"), "{rust}");
+        assert!(rust.contains("println ! (\"{:?}\" , [1 , 2])"), "{rust}");
+    }
+}
@@ -0,0 +1,369 @@
+//! Transpiler Options
+//!
+//! [`TranspilerOptions`] configures everything [`Transpiler::transpile_program`]
+//! does to a program, so embedders get the same output for the same options:
+//!
+//! ```
+//! use ruchy::backend::transpiler::{MainWrapping, OutputStyle, TranspilerOptions};
+//! use ruchy::{Parser, Transpiler};
+//!
+//! let ast = Parser::new("fun add(a: i32, b: i32) -> i32 { a + b }").parse().unwrap();
+//! let options = TranspilerOptions::builder()
+//!     .style(OutputStyle::Pretty)
+//!     .main_wrapping(MainWrapping::Never)
+//!     .source_maps(true)
+//!     .build();
+//! let output = Transpiler::with_options(options).transpile_program(&ast).unwrap();
+//! assert!(!output.code.contains("fn main"));
+//! assert_eq!(output.source_map[0].name, "add");
+//! ```
+//!
+//! [`Transpiler::transpile_program`]: super::Transpiler::transpile_program
+
+use crate::frontend::ast::{Expr, ExprKind, Span};
+use std::path::PathBuf;
+
+/// Rust edition the generated code is compiled with
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RustEdition {
+    /// Rust 2018
+    E2018,
+    /// Rust 2021
+    #[default]
+    E2021,
+    /// Rust 2024, which denies references to `static mut`; top-level
+    /// mutable globals are `LazyLock<Mutex<T>>` statics, so need none
+    E2024,
+}
+
+impl RustEdition {
+    /// The edition as `rustc --edition` and `Cargo.toml` spell it
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::E2018 => "2018",
+            Self::E2021 => "2021",
+            Self::E2024 => "2024",
+        }
+    }
+}
+
+/// How the generated code is laid out
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputStyle {
+    /// Formatted Rust, as `ruchy transpile` prints it
+    #[default]
+    Pretty,
+    /// The token stream on one line, the cheapest to produce
+    Compact,
+    /// Direct Rust mapping for self-hosting, without analysis or
+    /// optimization; ignores the optimization, main-wrapping and context
+    /// file options
+    Minimal,
+}
+
+/// Rewrites applied to the program before code generation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Optimization {
+    /// Generate code for the program as written
+    None,
+    /// Constant folding and propagation; programs without standalone
+    /// functions also get small functions inlined and dead code removed
+    #[default]
+    Standard,
+    /// [`Optimization::Standard`] after whole-program constant propagation,
+    /// which drops functions unreachable from top-level code (see
+    /// [`crate::backend::whole_program`])
+    WholeProgram,
+}
+
+/// Whether top-level code is wrapped in a generated `fn main`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MainWrapping {
+    /// Wrap top-level statements (and bare expressions, printing their
+    /// value) in `fn main`, and add an empty `main` to programs without one
+    #[default]
+    Auto,
+    /// Emit the program's items only, for use as a library or module; a
+    /// `fun main` is kept as written and top-level statements are an error
+    Never,
+}
+
+/// Configuration of a [`super::Transpiler`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TranspilerOptions {
+    /// Rust edition of the generated code
+    pub edition: RustEdition,
+    /// Layout of the generated code
+    pub style: OutputStyle,
+    /// Rewrites applied before code generation
+    pub optimization: Optimization,
+    /// Whether [`TranspileOutput::source_map`] is filled in
+    pub source_maps: bool,
+    /// Whether top-level code is wrapped in `fn main`
+    pub main_wrapping: MainWrapping,
+    /// File the program was read from; its imports resolve next to it
+    pub context_file: Option<PathBuf>,
+}
+
+impl TranspilerOptions {
+    /// Builder starting from the defaults
+    pub fn builder() -> TranspilerOptionsBuilder {
+        TranspilerOptionsBuilder::default()
+    }
+}
+
+/// Builder for [`TranspilerOptions`]
+#[derive(Debug, Clone, Default)]
+pub struct TranspilerOptionsBuilder {
+    options: TranspilerOptions,
+}
+
+impl TranspilerOptionsBuilder {
+    /// Set the Rust edition of the generated code
+    #[must_use]
+    pub fn edition(mut self, edition: RustEdition) -> Self {
+        self.options.edition = edition;
+        self
+    }
+
+    /// Set the layout of the generated code
+    #[must_use]
+    pub fn style(mut self, style: OutputStyle) -> Self {
+        self.options.style = style;
+        self
+    }
+
+    /// Set the rewrites applied before code generation
+    #[must_use]
+    pub fn optimization(mut self, optimization: Optimization) -> Self {
+        self.options.optimization = optimization;
+        self
+    }
+
+    /// Map the program's top-level items to their generated lines
+    #[must_use]
+    pub fn source_maps(mut self, enabled: bool) -> Self {
+        self.options.source_maps = enabled;
+        self
+    }
+
+    /// Set whether top-level code is wrapped in `fn main`
+    #[must_use]
+    pub fn main_wrapping(mut self, main_wrapping: MainWrapping) -> Self {
+        self.options.main_wrapping = main_wrapping;
+        self
+    }
+
+    /// Resolve the program's imports next to `file`
+    #[must_use]
+    pub fn context_file(mut self, file: impl Into<PathBuf>) -> Self {
+        self.options.context_file = Some(file.into());
+        self
+    }
+
+    /// The configured options
+    #[must_use]
+    pub fn build(self) -> TranspilerOptions {
+        self.options
+    }
+}
+
+/// Result of [`super::Transpiler::transpile_program`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranspileOutput {
+    /// The generated Rust program
+    pub code: String,
+    /// Edition to compile `code` with
+    pub edition: RustEdition,
+    /// Generated line of each named top-level item, when source maps are
+    /// enabled
+    pub source_map: Vec<SourceMapping>,
+}
+
+/// Where a top-level Ruchy item ended up in the generated code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceMapping {
+    /// Name of the function, type, trait or module
+    pub name: String,
+    /// Line of its definition in the generated code, 1-based
+    pub line: usize,
+    /// Its span in the Ruchy source
+    pub span: Span,
+}
+
+/// Generated lines of the named top-level items of `program` (complexity: 7)
+pub(crate) fn source_map(program: &Expr, code: &str) -> Vec<SourceMapping> {
+    let items = match &program.kind {
+        ExprKind::Block(exprs) => exprs.as_slice(),
+        _ => std::slice::from_ref(program),
+    };
+    items
+        .iter()
+        .filter_map(|item| {
+            let (keyword, name) = match &item.kind {
+                ExprKind::Function { name, .. } => ("fn", name),
+                ExprKind::Struct { name, .. }
+                | ExprKind::TupleStruct { name, .. }
+                | ExprKind::Class { name, .. }
+                | ExprKind::Actor { name, .. } => ("struct", name),
+                ExprKind::Enum { name, .. } => ("enum", name),
+                ExprKind::Trait { name, .. } => ("trait", name),
+                ExprKind::Module { name, .. } => ("mod", name),
+                _ => return None,
+            };
+            // A `fun main` next to top-level statements is renamed
+            let renamed = (name == "main")
+                .then(|| definition_line(code, keyword, "__ruchy_main"))
+                .flatten();
+            let line = renamed.or_else(|| definition_line(code, keyword, name))?;
+            Some(SourceMapping {
+                name: name.clone(),
+                line,
+                span: item.span,
+            })
+        })
+        .collect()
+}
+
+/// First line of `code` defining `keyword name` (complexity: 4)
+fn definition_line(code: &str, keyword: &str, name: &str) -> Option<usize> {
+    let needle = format!("{keyword} {name}");
+    let ident = |c: char| c.is_alphanumeric() || c == '_';
+    let position = code.lines().position(|line| {
+        line.match_indices(&needle).any(|(i, _)| {
+            !line[..i].ends_with(ident) && !line[i + needle.len()..].starts_with(ident)
+        })
+    })?;
+    Some(position + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Parser, Transpiler};
+
+    fn transpile(source: &str, options: TranspilerOptions) -> anyhow::Result<TranspileOutput> {
+        let ast = Parser::new(source).parse()?;
+        Transpiler::with_options(options).transpile_program(&ast)
+    }
+
+    #[test]
+    fn test_builder_overrides_defaults() {
+        let options = TranspilerOptions::builder()
+            .edition(RustEdition::E2024)
+            .optimization(Optimization::None)
+            .context_file("src/main.ruchy")
+            .build();
+        assert_eq!(options.edition, RustEdition::E2024);
+        assert_eq!(options.optimization, Optimization::None);
+        assert_eq!(options.style, OutputStyle::Pretty);
+        assert_eq!(options.main_wrapping, MainWrapping::Auto);
+        assert_eq!(
+            options.context_file.as_deref(),
+            Some(std::path::Path::new("src/main.ruchy"))
+        );
+        assert_eq!(
+            TranspilerOptions::builder().build(),
+            TranspilerOptions::default()
+        );
+    }
+
+    #[test]
+    fn test_styles_lay_out_the_same_program() {
+        let source = "fun double(x: i32) -> i32 { x * 2 }\nprintln(double(21))";
+        let pretty = transpile(source, TranspilerOptions::default()).unwrap();
+        assert!(pretty.code.lines().count() > 3, "{}", pretty.code);
+        assert_eq!(pretty.edition, RustEdition::E2021);
+
+        let compact = TranspilerOptions::builder()
+            .style(OutputStyle::Compact)
+            .build();
+        let compact = transpile(source, compact).unwrap();
+        assert_eq!(compact.code.lines().count(), 1);
+
+        let minimal = TranspilerOptions::builder()
+            .style(OutputStyle::Minimal)
+            .build();
+        assert!(transpile(source, minimal)
+            .unwrap()
+            .code
+            .contains("fn double"));
+    }
+
+    #[test]
+    fn test_never_wrapping_emits_items_only() {
+        let library = TranspilerOptions::builder()
+            .main_wrapping(MainWrapping::Never)
+            .build();
+        let output =
+            transpile("fun add(a: i32, b: i32) -> i32 { a + b }", library.clone()).unwrap();
+        assert!(output.code.contains("fn add"), "{}", output.code);
+        assert!(!output.code.contains("fn main"), "{}", output.code);
+
+        let err = transpile("let x = 1\nprintln(x)", library).unwrap_err();
+        assert!(err.to_string().contains("top-level statements"), "{err}");
+
+        let auto = transpile(
+            "fun add(a: i32, b: i32) -> i32 { a + b }",
+            TranspilerOptions::default(),
+        );
+        assert!(auto.unwrap().code.contains("fn main"));
+    }
+
+    #[test]
+    fn test_source_map_points_at_generated_items() {
+        let source = "struct Point { x: i32 }\n\nfun norm(p: Point) -> i32 { p.x }\n\nfun main() { println(norm(Point { x: 3 })) }";
+        let options = TranspilerOptions::builder().source_maps(true).build();
+        let output = transpile(source, options).unwrap();
+        let names: Vec<&str> = output.source_map.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, ["Point", "norm", "main"]);
+        let lines: Vec<&str> = output.code.lines().collect();
+        for mapping in &output.source_map {
+            assert!(
+                lines[mapping.line - 1].contains(&mapping.name),
+                "{mapping:?}"
+            );
+        }
+        assert_eq!(
+            output.source_map[1].span.start,
+            source.find("fun norm").unwrap()
+        );
+
+        let unmapped = transpile(source, TranspilerOptions::default()).unwrap();
+        assert!(unmapped.source_map.is_empty());
+    }
+
+    #[test]
+    fn test_edition_2024_compiles_mutable_globals() {
+        let source = "let mut count = 0\nfun bump() { count = count + 1 }\nbump()\nprintln(count)";
+        let e2024 = TranspilerOptions::builder()
+            .edition(RustEdition::E2024)
+            .build();
+        let output = transpile(source, e2024).unwrap();
+        assert!(output.code.contains("LazyLock"), "{}", output.code);
+        assert!(!output.code.contains("static mut"), "{}", output.code);
+        assert_eq!(output.edition.as_str(), "2024");
+
+        let dir = tempfile::tempdir().unwrap();
+        let rust_file = dir.path().join("globals.rs");
+        std::fs::write(&rust_file, &output.code).unwrap();
+        let rustc = std::process::Command::new("rustc")
+            .args(["--edition", output.edition.as_str(), "-o"])
+            .arg(dir.path().join("globals"))
+            .arg(&rust_file)
+            .output()
+            .unwrap();
+        assert!(
+            rustc.status.success(),
+            "{}",
+            String::from_utf8_lossy(&rustc.stderr)
+        );
+    }
+
+    #[test]
+    fn test_definition_line_matches_whole_names() {
+        let code = "fn add_one() {}\nfn add() {}\n";
+        assert_eq!(definition_line(code, "fn", "add"), Some(2));
+        assert_eq!(definition_line(code, "fn", "sub"), None);
+    }
+}
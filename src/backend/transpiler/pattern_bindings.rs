@@ -52,6 +52,11 @@ pub fn extract_pattern_bindings(pattern: &Pattern) -> Vec<String> {
             bindings
         }
         Pattern::WithDefault { pattern, .. } => extract_pattern_bindings(pattern),
+        Pattern::StringAffix { rest, .. } => extract_pattern_bindings(rest),
+        // Named capture groups bind the text they matched
+        Pattern::Regex(regex) => regex::Regex::new(regex)
+            .map(|regex| regex.capture_names().flatten().map(String::from).collect())
+            .unwrap_or_default(),
         Pattern::Mut(pattern)
         | Pattern::Ok(pattern)
         | Pattern::Err(pattern)
@@ -25,7 +25,11 @@ impl Transpiler {
     /// assert!(code.contains("_"));
     /// ```
     pub fn transpile_match(&self, expr: &Expr, arms: &[MatchArm]) -> Result<TokenStream> {
-        let expr_tokens = self.transpile_expr(expr)?;
+        let mut expr_tokens = self.transpile_expr(expr)?;
+        // Slice patterns with a rest element only match slices, not Vec
+        if arms.iter().any(|arm| Self::is_rest_slice_pattern(&arm.pattern)) {
+            expr_tokens = quote! { (#expr_tokens).as_slice() };
+        }
         let mut arm_tokens = Vec::new();
         for arm in arms {
             let pattern_tokens = self.transpile_pattern(&arm.pattern)?;
@@ -61,6 +65,12 @@ impl Transpiler {
                         let tokens = Self::transpile_literal(lit);
                         Ok(tokens)
                     }
+                    crate::frontend::ast::Literal::BigInt(_) => {
+                        anyhow::bail!("BigInt literal patterns are not supported")
+                    }
+                    crate::frontend::ast::Literal::Decimal(_) => {
+                        anyhow::bail!("Decimal literal patterns are not supported")
+                    }
                     crate::frontend::ast::Literal::Float(f) => Ok(quote! { #f }),
                     crate::frontend::ast::Literal::String(s) => Ok(quote! { #s }),
                     crate::frontend::ast::Literal::Bool(b) => Ok(quote! { #b }),
@@ -91,6 +101,7 @@ impl Transpiler {
                 Ok(tokens)
             }
             Pattern::TupleVariant { path, patterns } => {
+                Self::reject_named_tuple_rest(patterns)?;
                 // For enum tuple variants like Message::Text(n) or Color::RGB(r, g, b)
                 let mut path_tokens = TokenStream::new();
                 for (i, part) in path.iter().enumerate() {
@@ -106,6 +117,7 @@ impl Transpiler {
                 Ok(quote! { #path_tokens(#(#pattern_tokens),*) })
             }
             Pattern::Tuple(patterns) => {
+                Self::reject_named_tuple_rest(patterns)?;
                 let pattern_tokens: Result<Vec<_>> =
                     patterns.iter().map(|p| self.transpile_pattern(p)).collect();
                 let pattern_tokens = pattern_tokens?;
@@ -155,6 +167,12 @@ impl Transpiler {
                 let inner_tokens = self.transpile_pattern(inner)?;
                 Ok(quote! { mut #inner_tokens })
             }
+            Pattern::StringAffix { .. } => {
+                anyhow::bail!("String prefix/suffix patterns are not supported in transpiled code")
+            }
+            Pattern::Regex(_) => {
+                anyhow::bail!("Regex patterns are not supported in transpiled code")
+            }
         }
     }
     fn transpile_array_pattern(
@@ -239,7 +257,11 @@ impl Transpiler {
         };
 
         if fields.is_empty() {
-            return Ok(quote! { #struct_name {} });
+            return Ok(if has_rest {
+                quote! { #struct_name { .. } }
+            } else {
+                quote! { #struct_name {} }
+            });
         }
         let field_patterns = self.transpile_field_patterns(fields)?;
         if has_rest {
@@ -289,6 +311,28 @@ impl Transpiler {
             Ok(quote! { #start_tokens..#end_tokens })
         }
     }
+    /// True for list patterns containing `..`/`..rest` (Rust slice patterns)
+    fn is_rest_slice_pattern(pattern: &Pattern) -> bool {
+        match pattern {
+            Pattern::List(patterns) => patterns
+                .iter()
+                .any(|p| matches!(p, Pattern::Rest | Pattern::RestNamed(_))),
+            Pattern::Or(patterns) => patterns.iter().any(Self::is_rest_slice_pattern),
+            Pattern::AtBinding { pattern, .. } => Self::is_rest_slice_pattern(pattern),
+            _ => false,
+        }
+    }
+    /// Rust tuples support `..` but cannot bind the skipped elements
+    fn reject_named_tuple_rest(patterns: &[Pattern]) -> Result<()> {
+        if let Some(Pattern::RestNamed(name)) =
+            patterns.iter().find(|p| matches!(p, Pattern::RestNamed(_)))
+        {
+            anyhow::bail!(
+                "Named rest `..{name}` is only supported in list patterns; use `..` in tuple patterns"
+            );
+        }
+        Ok(())
+    }
     fn transpile_rest_named_pattern(&self, name: &str) -> Result<TokenStream> {
         let name_ident = format_ident!("{}", name);
         // Rust syntax for rest patterns is `name @ ..`
@@ -576,6 +620,8 @@ mod tests {
                     Span::new(0, 0),
                 )),
                 span: Span::new(0, 0),
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
             MatchArm {
                 pattern: Pattern::Wildcard,
@@ -585,6 +631,8 @@ mod tests {
                     Span::new(0, 0),
                 )),
                 span: Span::new(0, 0),
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
         ];
         let result = transpiler
@@ -622,6 +670,8 @@ mod tests {
                 Span::new(0, 0),
             )),
             span: Span::new(0, 0),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
         let result = transpiler
             .transpile_match(&expr, &arms)
@@ -861,6 +911,8 @@ mod tests {
                     Span::new(0, 0),
                 )),
                 span: Span::new(0, 0),
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
             MatchArm {
                 pattern: Pattern::Literal(Literal::Integer(2, None)),
@@ -870,6 +922,8 @@ mod tests {
                     Span::new(0, 0),
                 )),
                 span: Span::new(0, 0),
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
             MatchArm {
                 pattern: Pattern::Wildcard,
@@ -879,6 +933,8 @@ mod tests {
                     Span::new(0, 0),
                 )),
                 span: Span::new(0, 0),
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
         ];
         let result = transpiler
@@ -1048,4 +1104,56 @@ mod tests {
         assert!(output.contains('a'));
         assert!(output.contains('b'));
     }
+
+    #[test]
+    fn test_transpile_match_rest_slice_uses_as_slice() {
+        let mut parser = crate::Parser::new("match xs { [head, ..tail] => head, _ => 0 }");
+        let ast = parser.parse().expect("parse should succeed in test");
+        let output = Transpiler::new()
+            .transpile(&ast)
+            .expect("transpile should succeed in test")
+            .to_string();
+        assert!(output.contains("as_slice"));
+        assert!(output.contains("tail @ .."));
+    }
+
+    #[test]
+    fn test_transpile_tuple_pattern_with_rest() {
+        let transpiler = Transpiler::new();
+        let pattern = Pattern::Tuple(vec![
+            Pattern::Identifier("a".to_string()),
+            Pattern::Rest,
+            Pattern::Identifier("z".to_string()),
+        ]);
+        let output = transpiler
+            .transpile_pattern(&pattern)
+            .expect("operation should succeed in test")
+            .to_string();
+        assert_eq!(output, "(a , .. , z)");
+    }
+
+    #[test]
+    fn test_transpile_tuple_named_rest_is_error() {
+        let transpiler = Transpiler::new();
+        let pattern = Pattern::Tuple(vec![
+            Pattern::Identifier("a".to_string()),
+            Pattern::RestNamed("rest".to_string()),
+        ]);
+        assert!(transpiler.transpile_pattern(&pattern).is_err());
+    }
+
+    #[test]
+    fn test_transpile_struct_pattern_only_rest() {
+        let transpiler = Transpiler::new();
+        let pattern = Pattern::Struct {
+            name: "Point".to_string(),
+            fields: vec![],
+            has_rest: true,
+        };
+        let output = transpiler
+            .transpile_pattern(&pattern)
+            .expect("operation should succeed in test")
+            .to_string();
+        assert_eq!(output, "Point { .. }");
+    }
 }
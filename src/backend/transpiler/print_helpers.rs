@@ -110,7 +110,11 @@ impl Transpiler {
                 let arg_tokens = self.transpile_expr(&args[0])?;
                 // DEFECT-DICT-DETERMINISM FIX: Use Debug format with BTreeMap (deterministic)
                 // BTreeMap Debug format is sorted, so {:?} is safe and deterministic
-                let format_str = "{:?}";
+                let format_str = if self.prints_with_display(&args[0]) {
+                    "{}"
+                } else {
+                    "{:?}"
+                };
                 return Ok(Some(quote! { #func_tokens!(#format_str, #arg_tokens) }));
             }
         }
@@ -132,6 +136,52 @@ impl Transpiler {
         Ok(Some(quote! { #func_tokens!(#(#arg_tokens),*) }))
     }
 
+    /// Whether `println` shows `expr` with `{}` rather than `{:?}`: strings,
+    /// calls returning a `String`, and values of a type with a `Display` impl
+    fn prints_with_display(&self, expr: &Expr) -> bool {
+        self.is_string_typed(expr)
+            || self.display_type_of(expr).is_some()
+            || self
+                .call_return_type(expr)
+                .is_some_and(|ty| ty == "String" || ty == "&str")
+    }
+
+    /// Name of the type of `expr` when that type has a `Display` impl
+    ///
+    /// Known for struct literals, `Type::new(..)` calls, calls of functions
+    /// declared to return the type, and variables bound to any of these or
+    /// declared with the type.
+    pub(crate) fn display_type_of(&self, expr: &Expr) -> Option<String> {
+        let type_name = match &expr.kind {
+            ExprKind::StructLiteral { name, .. } => name.clone(),
+            ExprKind::Identifier(name) => self.variable_types.borrow().get(name)?.clone(),
+            ExprKind::Call { func, .. } => match &func.kind {
+                ExprKind::QualifiedName { module, name } if name == "new" => module.clone(),
+                _ => self.call_return_type(expr)?.clone(),
+            },
+            _ => return None,
+        };
+        self.display_types.contains(&type_name).then_some(type_name)
+    }
+
+    /// Declared return type of the function `expr` calls, by name or by
+    /// `module::name` path
+    fn call_return_type(&self, expr: &Expr) -> Option<&String> {
+        let ExprKind::Call { func, .. } = &expr.kind else {
+            return None;
+        };
+        match &func.kind {
+            ExprKind::Identifier(name) => self.return_types.get(name),
+            ExprKind::FieldAccess { object, field } => match &object.kind {
+                ExprKind::Identifier(module) => {
+                    self.return_types.get(&format!("{module}::{field}"))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
     /// Handle multiple arguments for print macros
     ///
     /// # Examples
@@ -475,6 +525,26 @@ mod tests {
         assert!(tokens_str.contains("{:?}"));
     }
 
+    #[test]
+    fn test_print_macro_shows_strings_with_display() {
+        let ast = crate::Parser::new(
+            "mod greet {\n    pub fun hello(name: String) -> String { \"Hello, \" + name }\n}\n\
+             fun count() -> i32 { 1 }\n\
+             fun main() {\n    println(greet::hello(\"x\"))\n    println(count())\n}",
+        )
+        .parse()
+        .unwrap();
+        let rust = Transpiler::new()
+            .transpile_to_program(&ast)
+            .unwrap()
+            .to_string();
+        assert!(
+            rust.contains("println ! (\"{}\" , greet :: hello"),
+            "{rust}"
+        );
+        assert!(rust.contains("println ! (\"{:?}\" , count ())"), "{rust}");
+    }
+
     // ========================================================================
     // transpile_print_multiple_args tests
     // ========================================================================
@@ -11,6 +11,7 @@
 
 #![allow(clippy::doc_markdown)]
 
+use super::options::{self, MainWrapping, Optimization, OutputStyle, TranspileOutput};
 use super::{codegen_minimal, constant_folder, inline_expander, Transpiler};
 use crate::frontend::ast::{Expr, ExprKind, Param};
 use anyhow::Result;
@@ -36,11 +37,63 @@ impl Transpiler {
         }
     }
 
+    /// Transpile a whole program as [`Transpiler::options`] configure
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruchy::backend::transpiler::{OutputStyle, TranspilerOptions};
+    /// use ruchy::{Parser, Transpiler};
+    ///
+    /// let ast = Parser::new("println(1 + 2)").parse().unwrap();
+    /// let options = TranspilerOptions::builder().style(OutputStyle::Pretty).build();
+    /// let output = Transpiler::with_options(options).transpile_program(&ast).unwrap();
+    /// assert!(output.code.contains("fn main()"));
+    /// ```
+    ///
+    /// Complexity: 6 (within Toyota Way limits)
+    pub fn transpile_program(&mut self, expr: &Expr) -> Result<TranspileOutput> {
+        contract_pre_configuration!();
+        let code = if self.options.style == OutputStyle::Minimal {
+            codegen_minimal::MinimalCodeGen::gen_program(expr)?
+        } else {
+            let tokens = self.transpile_program_tokens(expr)?;
+            if self.options.style == OutputStyle::Pretty {
+                prettyplease::unparse(&syn::parse2(tokens)?)
+            } else {
+                tokens.to_string()
+            }
+        };
+        let source_map = if self.options.source_maps {
+            options::source_map(expr, &code)
+        } else {
+            Vec::new()
+        };
+        Ok(TranspileOutput {
+            code,
+            edition: self.options.edition,
+            source_map,
+        })
+    }
+
     /// Wraps transpiled code in a complete Rust program with necessary imports
-    /// Complexity: 4 (within Toyota Way limits)
+    /// Complexity: 2 (within Toyota Way limits)
     pub fn transpile_to_program(&mut self, expr: &Expr) -> Result<TokenStream> {
         contract_pre_configuration!();
         contract_post_configuration!(&"ok");
+        let result = self.transpile_program_tokens(expr);
+        if let Ok(ref token_stream) = result {
+            // Debug: Write the generated Rust code to a debug file
+            let rust_code = token_stream.to_string();
+            std::fs::write("/tmp/debug_transpiler_output.rs", &rust_code).ok();
+        }
+        result
+    }
+
+    /// Analyze a program, then transpile it resolving imports next to
+    /// [`super::TranspilerOptions::context_file`]
+    /// Complexity: 3 (within Toyota Way limits)
+    pub(crate) fn transpile_program_tokens(&mut self, expr: &Expr) -> Result<TokenStream> {
         // First analyze the entire program to detect mutable variables, const declarations, function signatures, and modules
         // SPEC-001-B: Must collect const names BEFORE optimization to preserve attributes
         if let ExprKind::Block(exprs) = &expr.kind {
@@ -58,31 +111,47 @@ impl Transpiler {
             // BOOK-COMPAT-017: Collect call-site types for single expressions too
             self.collect_call_site_types(std::slice::from_ref(expr));
         }
-        let result = self.transpile_to_program_with_context(expr, None);
-        if let Ok(ref token_stream) = result {
-            // Debug: Write the generated Rust code to a debug file
-            let rust_code = token_stream.to_string();
-            std::fs::write("/tmp/debug_transpiler_output.rs", &rust_code).ok();
-        }
-        result
+        let context_file = self.options.context_file.clone();
+        self.transpile_with_file_context(expr, context_file.as_deref())
     }
 
     /// Transpile with file context for module resolution
-    /// Complexity: 8 (within Toyota Way limits)
+    /// Complexity: 1 (within Toyota Way limits)
+    #[deprecated(
+        since = "5.0.0",
+        note = "set `TranspilerOptions::context_file` and call `transpile_program`"
+    )]
     pub fn transpile_to_program_with_context(
         &mut self,
         expr: &Expr,
         file_path: Option<&std::path::Path>,
+    ) -> Result<TokenStream> {
+        self.transpile_with_file_context(expr, file_path)
+    }
+
+    /// Resolve imports next to `file_path`, optimize and transpile
+    /// Complexity: 10 (within Toyota Way limits)
+    fn transpile_with_file_context(
+        &mut self,
+        expr: &Expr,
+        file_path: Option<&std::path::Path>,
     ) -> Result<TokenStream> {
         contract_pre_fp8_architecture_guard!();
         contract_post_configuration!(&"ok");
         // First, resolve any file imports using the module resolver
         let resolved_expr = self.resolve_imports_with_context(expr, file_path)?;
+        let resolved_expr = if self.options.optimization == Optimization::WholeProgram {
+            crate::backend::whole_program::optimize(resolved_expr).0
+        } else {
+            resolved_expr
+        };
 
         // TRANSPILER-009 FIX: Skip aggressive optimizations for top-level programs with standalone functions
         let has_standalone_functions = Self::has_standalone_functions(&resolved_expr);
 
-        let optimized_expr = if has_standalone_functions {
+        let optimized_expr = if self.options.optimization == Optimization::None {
+            resolved_expr
+        } else if has_standalone_functions {
             // Skip inlining and DCE for programs with standalone functions
             constant_folder::propagate_constants(resolved_expr)
         } else {
@@ -97,6 +166,13 @@ impl Transpiler {
             constant_folder::eliminate_dead_code(after_inlining, inlined_functions)
         };
 
+        self.memoized_functions = crate::middleend::infer_effects(&optimized_expr)
+            .functions
+            .into_iter()
+            .filter(|function| function.memoize && function.is_memoizable())
+            .map(|function| function.name)
+            .collect();
+
         // CRITICAL: Analyze mutability, signatures, modules, and call-site types BEFORE transpiling
         if let ExprKind::Block(exprs) = &optimized_expr.kind {
             self.analyze_mutability(exprs);
@@ -115,6 +191,9 @@ impl Transpiler {
         let needs_polars = Self::contains_dataframe(&optimized_expr);
         let needs_hashmap = Self::contains_hashmap(&optimized_expr);
 
+        if self.options.main_wrapping == MainWrapping::Never {
+            return self.transpile_items_only(&optimized_expr, needs_polars, needs_hashmap);
+        }
         match &optimized_expr.kind {
             ExprKind::Function { name, .. } => {
                 self.transpile_single_function(&optimized_expr, name, needs_polars, needs_hashmap)
@@ -131,6 +210,39 @@ impl Transpiler {
         }
     }
 
+    /// Transpile a program's items without a generated `main`
+    /// Complexity: 4 (within Toyota Way limits)
+    fn transpile_items_only(
+        &self,
+        expr: &Expr,
+        needs_polars: bool,
+        needs_hashmap: bool,
+    ) -> Result<TokenStream> {
+        let exprs = match &expr.kind {
+            ExprKind::Block(exprs) => exprs.as_slice(),
+            _ => std::slice::from_ref(expr),
+        };
+        let (functions, statements, modules, _, main_expr, imports, globals) =
+            self.categorize_block_expressions(exprs)?;
+        if !statements.is_empty() {
+            anyhow::bail!(
+                "top-level statements need a generated `fn main`; move them into `fun main()` or use MainWrapping::Auto"
+            );
+        }
+        let use_statements = self.generate_use_statements(needs_polars, needs_hashmap);
+        let main_tokens = main_expr
+            .map(|main| self.transpile_function_expr(main))
+            .transpose()?;
+        Ok(quote! {
+            #use_statements
+            #(#imports)*
+            #(#globals)*
+            #(#modules)*
+            #(#functions)*
+            #main_tokens
+        })
+    }
+
     /// Transpile a single import as a program
     /// Complexity: 4 (within Toyota Way limits)
     fn transpile_import_program(
@@ -275,16 +387,19 @@ impl Transpiler {
     ) -> Result<TokenStream> {
         let use_statements = self.generate_use_statements(needs_polars, needs_hashmap);
 
-        let mut stmt_tokens = Vec::new();
-        for expr in exprs {
-            let tokens = self.transpile_expr(expr)?;
-            let tokens_str = tokens.to_string();
-            if !tokens_str.trim().ends_with(';') && !tokens_str.trim().ends_with('}') {
-                stmt_tokens.push(quote! { #tokens; });
-            } else {
-                stmt_tokens.push(tokens);
+        let stmt_tokens = self.with_shared_captures(exprs, || {
+            let mut stmt_tokens = Vec::new();
+            for expr in exprs {
+                let tokens = self.transpile_expr(expr)?;
+                let tokens_str = tokens.to_string();
+                if !tokens_str.trim().ends_with(';') && !tokens_str.trim().ends_with('}') {
+                    stmt_tokens.push(quote! { #tokens; });
+                } else {
+                    stmt_tokens.push(tokens);
+                }
             }
-        }
+            Ok(stmt_tokens)
+        })?;
 
         match (needs_polars, needs_hashmap) {
             (true, true) => Ok(quote! {
@@ -622,6 +737,10 @@ impl Transpiler {
 
     /// Transpiles an expression to a String
     /// Complexity: 3 (within Toyota Way limits)
+    #[deprecated(
+        since = "5.0.0",
+        note = "use `transpile_program` with `OutputStyle::Compact` or `OutputStyle::Pretty`"
+    )]
     pub fn transpile_to_string(&mut self, expr: &Expr) -> Result<String> {
         contract_pre_configuration!();
         let tokens = self.transpile(expr)?;
@@ -638,6 +757,10 @@ impl Transpiler {
 
     /// Generate minimal code for self-hosting (direct Rust mapping, no optimization)
     /// Complexity: 1 (within Toyota Way limits)
+    #[deprecated(
+        since = "5.0.0",
+        note = "use `transpile_program` with `OutputStyle::Minimal`"
+    )]
     pub fn transpile_minimal(&self, expr: &Expr) -> Result<String> {
         contract_pre_configuration!();
         codegen_minimal::MinimalCodeGen::gen_program(expr)
@@ -790,6 +913,7 @@ mod tests {
     // ========================================================================
 
     #[test]
+    #[allow(deprecated)]
     fn test_transpile_to_string_simple() {
         let mut transpiler = Transpiler::new();
         let expr = int_expr(42);
@@ -800,6 +924,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_transpile_to_string_formatting() {
         let mut transpiler = Transpiler::new();
         let expr = int_expr(42);
@@ -1060,6 +1185,7 @@ mod tests {
 
     // Test: transpile_to_string with block
     #[test]
+    #[allow(deprecated)]
     fn test_transpile_to_string_block() {
         let mut transpiler = Transpiler::new();
         let expr = block_expr(vec![int_expr(1), int_expr(2)]);
@@ -1084,6 +1210,7 @@ mod tests {
 
     // Test: transpile_to_program_with_context
     #[test]
+    #[allow(deprecated)]
     fn test_transpile_to_program_with_context_simple() {
         let mut transpiler = Transpiler::new();
         let expr = int_expr(100);
@@ -1205,6 +1332,8 @@ mod tests {
             span: crate::frontend::ast::Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         };
         let main_expr = make_expr(ExprKind::Function {
             name: "main".to_string(),
@@ -0,0 +1,115 @@
+//! `rust { ... }` blocks: verbatim Rust inside Ruchy programs
+//!
+//! A top-level block without inputs holds items (functions, `use`s, impls)
+//! and is emitted as-is. Anywhere else the code becomes the body of a local
+//! function taking the declared inputs, so it sees nothing else from the
+//! surrounding Ruchy scope, and the block evaluates to the function's result.
+use super::{Result, Transpiler};
+use crate::frontend::ast::{Param, Type, TypeKind};
+use anyhow::anyhow;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+impl Transpiler {
+    /// Transpile `rust(inputs) -> Type { code }` into a call of a local function
+    ///
+    /// Reference inputs are lent; other inputs are cloned so the caller keeps
+    /// its values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the code does not tokenize as Rust or a declared
+    /// type cannot be transpiled
+    pub fn transpile_rust_block(
+        &self,
+        inputs: &[Param],
+        output: Option<&Type>,
+        code: &str,
+    ) -> Result<TokenStream> {
+        let body = Self::transpile_rust_items(code)?;
+        let mut params = Vec::new();
+        let mut args = Vec::new();
+        for input in inputs {
+            let name = format_ident!("{}", input.name());
+            let ty = self.transpile_type(&input.ty)?;
+            params.push(quote! { #name: #ty });
+            args.push(match input.ty.kind {
+                TypeKind::Reference { is_mut: true, .. } => quote! { &mut #name },
+                TypeKind::Reference { .. } => quote! { &#name },
+                _ => quote! { #name.clone() },
+            });
+        }
+        let ret = match output {
+            Some(ty) => {
+                let ty = self.transpile_type(ty)?;
+                quote! { -> #ty }
+            }
+            None => quote! {},
+        };
+        Ok(quote! {
+            {
+                fn __ruchy_rust_block(#(#params),*) #ret {
+                    #body
+                }
+                __ruchy_rust_block(#(#args),*)
+            }
+        })
+    }
+
+    /// Tokens of the verbatim code in a `rust { ... }` block
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the code does not tokenize as Rust
+    pub fn transpile_rust_items(code: &str) -> Result<TokenStream> {
+        code.parse::<TokenStream>()
+            .map_err(|e| anyhow!("Invalid Rust in rust block: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Parser, Transpiler};
+
+    fn transpile(code: &str) -> anyhow::Result<String> {
+        let ast = Parser::new(code).parse()?;
+        Ok(Transpiler::new().transpile_to_program(&ast)?.to_string())
+    }
+
+    #[test]
+    fn test_rust_block_becomes_local_function() {
+        let rust = transpile(
+            "fun main() {\n    let values = [1, 2, 3]\n    let scale = 10\n    let total = rust(values: &[i64], scale: i64) -> i64 {\n        values.iter().map(|v| v * scale).sum()\n    }\n    println(total)\n}",
+        )
+        .unwrap();
+        assert!(rust.contains("fn __ruchy_rust_block (values : &"), "{rust}");
+        assert!(rust.contains("scale : i64) -> i64"), "{rust}");
+        assert!(
+            rust.contains("values . iter () . map (| v | v * scale) . sum ()"),
+            "{rust}"
+        );
+        assert!(
+            rust.contains("__ruchy_rust_block (& values , scale . clone ())"),
+            "{rust}"
+        );
+    }
+
+    #[test]
+    fn test_top_level_rust_block_is_emitted_as_items() {
+        let rust = transpile(
+            "rust {\n    fn double(x: i64) -> i64 { x * 2 }\n}\n\nfun main() {\n    println(double(21))\n}",
+        )
+        .unwrap();
+        assert!(
+            rust.contains("fn double (x : i64) -> i64 { x * 2 }"),
+            "{rust}"
+        );
+        assert!(!rust.contains("__ruchy_rust_block"), "{rust}");
+    }
+
+    #[test]
+    fn test_invalid_rust_is_reported() {
+        let err = transpile("rust { let x = (1 ] }").unwrap_err();
+        assert!(format!("{err:#}").contains("rust block"), "{err:#}");
+    }
+}
@@ -259,6 +259,21 @@ impl Transpiler {
         contracts: &[crate::frontend::ast::ContractClause],
     ) -> Result<TokenStream> {
         contract_pre_bce!();
+        // Rust has no variadics: a rest parameter is a Vec the call sites fill
+        if params.last().is_some_and(Param::is_rest) {
+            let params = self.rest_param_as_vec(name, params);
+            return self.transpile_function_with_contracts(
+                name,
+                type_params,
+                &params,
+                body,
+                is_async,
+                return_type,
+                is_pub,
+                attributes,
+                contracts,
+            );
+        }
         if contracts.is_empty() {
             return self.transpile_function_impl(
                 name,
@@ -482,6 +497,8 @@ mod extreme_tdd_tests {
             span: Span::new(0, 0),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -1012,6 +1012,8 @@ fn test_is_variable_mutated_in_match() {
                 guard: None,
                 body: Box::new(assign),
                 span: Span::default(),
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             }],
         },
         span: Span::default(),
@@ -1301,6 +1303,8 @@ fn test_needs_lifetime_parameter_no_refs() {
         default_value: None,
         span: Span::default(),
         is_mutable: false,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     assert!(!super::super::type_analysis::needs_lifetime_parameter(
         &params, None
@@ -1328,6 +1332,8 @@ fn test_needs_lifetime_parameter_requires_lifetime() {
             default_value: None,
             span: Span::default(),
             is_mutable: false,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         Param {
             pattern: Pattern::Identifier("b".to_string()),
@@ -1335,6 +1341,8 @@ fn test_needs_lifetime_parameter_requires_lifetime() {
             default_value: None,
             span: Span::default(),
             is_mutable: false,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
     ];
     let return_type = Some(&ref_type);
@@ -226,6 +226,8 @@ fn test_generate_param_tokens_with_lifetime_with_ref() {
         span: Span::default(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let result = transpiler.generate_param_tokens_with_lifetime(&params, &body, "test_fn");
     assert!(result.is_ok());
@@ -350,6 +352,8 @@ fn test_transpile_function_with_type_params() {
         span: Span::default(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let result = transpiler.transpile_function(
         "generic_fn",
@@ -408,6 +412,8 @@ fn test_infer_param_type_simple() {
         span: Span::default(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
     let body = Expr {
         kind: ExprKind::Binary {
@@ -453,6 +459,8 @@ fn test_generate_param_tokens_multiple() {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         Param {
             pattern: Pattern::Identifier("b".to_string()),
@@ -463,6 +471,8 @@ fn test_generate_param_tokens_multiple() {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
     ];
     let body = Expr {
@@ -614,6 +624,8 @@ fn test_transpile_lambda_simple() {
         span: Span::default(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let body = Expr {
         kind: ExprKind::Binary {
@@ -755,6 +767,8 @@ fn test_transpile_function_infer_return_type() {
         span: Span::default(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let body = Expr {
         kind: ExprKind::Binary {
@@ -800,6 +814,8 @@ fn test_transpile_function_nested_array_param() {
         span: Span::default(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let body = Expr {
         kind: ExprKind::IndexAccess {
@@ -887,6 +903,8 @@ fn test_transpile_function_references_global() {
         span: Span::default(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let result =
         transpiler.transpile_function("add_global", &[], &params, &body, false, None, false, &[]);
@@ -1001,6 +1019,8 @@ fn test_transpile_function_match_string_arms() {
         span: Span::default(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let body = Expr {
         kind: ExprKind::Match {
@@ -1025,6 +1045,8 @@ fn test_transpile_function_match_string_arms() {
                         contracts: Vec::new(),
                     }),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
                 MatchArm {
                     pattern: Pattern::Wildcard,
@@ -1038,6 +1060,8 @@ fn test_transpile_function_match_string_arms() {
                         contracts: Vec::new(),
                     }),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
             ],
         },
@@ -1083,6 +1107,8 @@ fn test_transpile_function_mutable_ref_lifetime() {
         span: Span::default(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let body = Expr {
         kind: ExprKind::Identifier("s".to_string()),
@@ -1253,6 +1279,8 @@ fn test_infer_return_type_from_params_typed() {
         span: Span::default(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let body = Expr {
         kind: ExprKind::Identifier("x".to_string()),
@@ -1524,6 +1552,8 @@ fn test_transpile_match_with_string_arms_direct() {
                 contracts: Vec::new(),
             }),
             span: Span::default(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         MatchArm {
             pattern: Pattern::Wildcard,
@@ -1537,6 +1567,8 @@ fn test_transpile_match_with_string_arms_direct() {
                 contracts: Vec::new(),
             }),
             span: Span::default(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
     ];
     let result = transpiler.transpile_match_with_string_arms(&expr, &arms);
@@ -265,6 +265,8 @@ mod tests {
             guard: None,
             body: Box::new(body),
             span: Span::default(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
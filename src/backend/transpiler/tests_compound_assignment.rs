@@ -209,7 +209,7 @@ mod transpiler_compound_assignment_tests {
         let expr = Expr::new(
             ExprKind::CompoundAssign {
                 target: Box::new(make_ident("x")),
-                op: BinaryOp::And, // Logical AND, not valid for compound assignment
+                op: BinaryOp::Less, // Comparison, not valid for compound assignment
                 value: Box::new(make_literal(3)),
             },
             Default::default(),
@@ -279,6 +279,25 @@ mod transpiler_compound_assignment_tests {
         assert!(code.contains("+="));
     }
 
+    #[test]
+    fn test_operators_without_rust_compound_form() {
+        let transpile = |source: &str| {
+            let ast = Parser::new(source).parse().expect("source should parse");
+            Transpiler::new()
+                .transpile_expr(&ast)
+                .expect("source should transpile")
+                .to_string()
+        };
+        assert_eq!(transpile("x **= 2"), "x = x . powf (2)");
+        assert_eq!(transpile("done &&= ok"), "if done { done = ok ; }");
+        assert_eq!(transpile("done ||= ok"), "if ! done { done = ok ; }");
+        assert_eq!(
+            transpile("cache ??= 5"),
+            "if cache . is_none () { cache = Some (5) ; }"
+        );
+        assert!(transpile("x >>= 1").contains(">>="));
+    }
+
     #[test]
     fn test_multiple_compound_assignments() {
         let code = "x += 1; y -= 2; z *= 3";
@@ -110,6 +110,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -471,12 +473,16 @@ mod tests {
                     guard: None,
                     body: Box::new(string_lit("yes")),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
                 MatchArm {
                     pattern: Pattern::Wildcard,
                     guard: None,
                     body: Box::new(string_lit("no")),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
             ],
         });
@@ -493,12 +499,16 @@ mod tests {
                     guard: None,
                     body: Box::new(int_lit(1)),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
                 MatchArm {
                     pattern: Pattern::Wildcard,
                     guard: None,
                     body: Box::new(int_lit(2)),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
             ],
         });
@@ -515,12 +525,16 @@ mod tests {
                     guard: None,
                     body: Box::new(int_lit(1)),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
                 MatchArm {
                     pattern: Pattern::Wildcard,
                     guard: None,
                     body: Box::new(string_lit("hello")),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
             ],
         });
@@ -21,7 +21,7 @@ impl Transpiler {
     ) -> Result<Option<TokenStream>> {
         // Only check known type conversion functions
         match base_name {
-            "str" | "int" | "float" | "bool" | "list" | "set" | "dict" => {
+            "str" | "int" | "float" | "bool" | "list" | "set" | "dict" | "Decimal" => {
                 // These functions require exactly 1 argument
                 if args.len() != 1 {
                     bail!("{base_name}() expects exactly 1 argument");
@@ -37,6 +37,7 @@ impl Transpiler {
             "list" => self.convert_to_list(&args[0]),
             "set" => self.convert_to_set(&args[0]),
             "dict" => self.convert_to_dict(&args[0]),
+            "Decimal" => self.convert_to_decimal(&args[0]),
             _ => Ok(None), // Not a type conversion
         }
     }
@@ -241,6 +242,20 @@ impl Transpiler {
             _ => Ok(Some(quote! { std::collections::HashMap::new() })),
         }
     }
+    /// Convert to `rust_decimal::Decimal` (complexity: 2)
+    fn convert_to_decimal(&self, arg: &Expr) -> Result<Option<TokenStream>> {
+        let value = self.transpile_expr(arg)?;
+        match &arg.kind {
+            // Integer literal -> exact conversion
+            ExprKind::Literal(Literal::Integer(_, _)) => {
+                Ok(Some(quote! { rust_decimal::Decimal::from(#value) }))
+            }
+            // Default: parse the text form, like the interpreter does
+            _ => Ok(Some(quote! {
+                #value.to_string().trim().parse::<rust_decimal::Decimal>().expect("Failed to parse Decimal")
+            })),
+        }
+    }
 }
 // Helper functions (complexity: 1-3 each)
 fn is_single_text_part(parts: &[StringPart]) -> bool {
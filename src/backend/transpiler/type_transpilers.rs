@@ -18,6 +18,8 @@ impl Transpiler {
     pub(crate) fn transpile_named_type_impl(&self, name: &str) -> Result<TokenStream> {
         match name {
             "int" => Ok(quote! { i64 }),
+            "bigint" => Ok(quote! { num_bigint::BigInt }),
+            "decimal" | "Decimal" => Ok(quote! { rust_decimal::Decimal }),
             "float" => Ok(quote! { f64 }),
             "bool" => Ok(quote! { bool }),
             "str" => Ok(quote! { &str }),
@@ -5,7 +5,8 @@
 #![allow(clippy::only_used_in_recursion)]
 use super::*;
 use crate::frontend::ast::{
-    ClassMethod, Constructor, EnumVariant, ImplMethod, StructField, TraitMethod, Type, TypeKind,
+    ClassMethod, ClassProperty, Constructor, EnumVariant, ImplMethod, StructField, TraitMethod,
+    Type, TypeKind,
 };
 use anyhow::{bail, Result};
 use proc_macro2::TokenStream;
@@ -113,6 +114,8 @@ impl Transpiler {
     pub(crate) fn transpile_named_type(&self, name: &str) -> Result<TokenStream> {
         let rust_type = match name {
             "int" => quote! { i64 },
+            "bigint" => quote! { num_bigint::BigInt },
+            "decimal" | "Decimal" => quote! { rust_decimal::Decimal },
             "float" => quote! { f64 },
             "bool" => quote! { bool },
             "str" => quote! { &str }, // String slice reference (sized type for function parameters)
@@ -627,6 +630,7 @@ impl Transpiler {
         constructors: &[Constructor],
         methods: &[ClassMethod],
         constants: &[crate::frontend::ast::ClassConstant],
+        properties: &[ClassProperty],
         derives: &[String],
         is_pub: bool,
     ) -> Result<TokenStream> {
@@ -634,8 +638,19 @@ impl Transpiler {
         let type_param_tokens = self.generate_class_type_param_tokens(type_params);
         let struct_name = format_ident!("{}", name);
 
-        let constructor_tokens = self.transpile_constructors(constructors)?;
-        let method_tokens = self.transpile_class_methods(methods)?;
+        let mut constructor_tokens = self.transpile_constructors(constructors, fields)?;
+        // A class without constructors whose fields all have defaults still gets `new`
+        let has_all_defaults = fields.iter().all(|f| f.default_value.is_some());
+        if constructors.is_empty() && has_all_defaults && methods.iter().all(|m| m.name != "new") {
+            let body = self.self_initializer(&[], fields)?;
+            constructor_tokens.push(quote! {
+                pub fn new() -> Self {
+                    #body
+                }
+            });
+        }
+        let mut method_tokens = self.transpile_class_methods(methods)?;
+        method_tokens.extend(self.transpile_class_properties(properties)?);
         let constant_tokens = self.transpile_class_constants(constants)?;
 
         let impl_tokens = self.generate_impl_block(
@@ -688,7 +703,13 @@ impl Transpiler {
 
     /// BOOK-COMPAT-010: Transform constructor body with self.field = value patterns
     /// into proper struct initialization: Self { field: value, ... }
-    fn transpile_constructor_body(&self, body: &Expr) -> Result<TokenStream> {
+    ///
+    /// Fields the body leaves unassigned take their declared default.
+    fn transpile_constructor_body(
+        &self,
+        body: &Expr,
+        fields: &[StructField],
+    ) -> Result<TokenStream> {
         use crate::frontend::ast::ExprKind;
 
         // Extract self-assignments from block body
@@ -696,64 +717,85 @@ impl Transpiler {
             let mut field_inits: Vec<(String, TokenStream)> = Vec::new();
 
             for expr in exprs {
-                if let ExprKind::Assign { target, value } = &expr.kind {
-                    // Check if target is self.field
-                    if let ExprKind::FieldAccess { object, field } = &target.kind {
-                        if let ExprKind::Identifier(name) = &object.kind {
-                            if name == "self" {
-                                let value_tokens = self.transpile_expr(value)?;
-                                field_inits.push((field.clone(), value_tokens));
-                                continue;
-                            }
-                        }
-                    }
-                }
-                // Non-self-assignment in constructor - fall back to regular transpilation
-                return self.transpile_expr(body);
+                let Some((field, value)) = Self::self_field_assignment(expr) else {
+                    // Non-self-assignment in constructor - fall back to regular transpilation
+                    return self.transpile_expr(body);
+                };
+                field_inits.push((field.to_string(), self.transpile_expr(value)?));
             }
 
             // Generate Self { field1: value1, field2: value2, ... }
-            if !field_inits.is_empty() {
-                let fields: Vec<TokenStream> = field_inits
-                    .into_iter()
-                    .map(|(name, value)| {
-                        let field_ident = format_ident!("{}", name);
-                        quote! { #field_ident: #value }
-                    })
-                    .collect();
-                return Ok(quote! { Self { #(#fields),* } });
-            }
+            return self.self_initializer(&field_inits, fields);
         }
 
         // Single self-assignment expression
-        if let ExprKind::Assign { target, value } = &body.kind {
-            if let ExprKind::FieldAccess { object, field } = &target.kind {
-                if let ExprKind::Identifier(name) = &object.kind {
-                    if name == "self" {
-                        let field_ident = format_ident!("{}", field);
-                        let value_tokens = self.transpile_expr(value)?;
-                        return Ok(quote! { Self { #field_ident: #value_tokens } });
-                    }
-                }
-            }
+        if let Some((field, value)) = Self::self_field_assignment(body) {
+            let field_inits = vec![(field.to_string(), self.transpile_expr(value)?)];
+            return self.self_initializer(&field_inits, fields);
         }
 
         // Default: transpile as regular expression
         self.transpile_expr(body)
     }
 
+    /// The field and value of a `self.field = value` statement
+    fn self_field_assignment(expr: &Expr) -> Option<(&str, &Expr)> {
+        use crate::frontend::ast::ExprKind;
+
+        let ExprKind::Assign { target, value } = &expr.kind else {
+            return None;
+        };
+        let ExprKind::FieldAccess { object, field } = &target.kind else {
+            return None;
+        };
+        match &object.kind {
+            ExprKind::Identifier(name) if name == "self" => Some((field.as_str(), &**value)),
+            _ => None,
+        }
+    }
+
+    /// `Self { ... }` from the given field values, with every other field set
+    /// to its declared default or `Default::default()`
+    /// Complexity: 4 (within Toyota Way limits)
+    pub(crate) fn self_initializer(
+        &self,
+        field_inits: &[(String, TokenStream)],
+        fields: &[StructField],
+    ) -> Result<TokenStream> {
+        let mut inits: Vec<TokenStream> = field_inits
+            .iter()
+            .map(|(name, value)| {
+                let field_ident = format_ident!("{}", name);
+                quote! { #field_ident: #value }
+            })
+            .collect();
+        let unassigned = fields
+            .iter()
+            .filter(|field| field_inits.iter().all(|(name, _)| *name != field.name));
+        for field in unassigned {
+            let field_ident = format_ident!("{}", field.name);
+            let value = match field.default_value {
+                Some(ref default_expr) => self.transpile_expr(default_expr)?,
+                None => quote! { Default::default() },
+            };
+            inits.push(quote! { #field_ident: #value });
+        }
+        Ok(quote! { Self { #(#inits),* } })
+    }
+
     /// Transpile constructors to methods
     /// Complexity: 6 (within Toyota Way limits)
     pub(crate) fn transpile_constructors(
         &self,
         constructors: &[Constructor],
+        fields: &[StructField],
     ) -> Result<Vec<TokenStream>> {
         constructors
             .iter()
             .map(|ctor| {
                 let params = self.transpile_params(&ctor.params)?;
                 // BOOK-COMPAT-010: Use special constructor body transpilation
-                let body = self.transpile_constructor_body(&ctor.body)?;
+                let body = self.transpile_constructor_body(&ctor.body, fields)?;
                 let visibility = if ctor.is_pub {
                     quote! { pub }
                 } else {
@@ -812,6 +854,44 @@ impl Transpiler {
             .collect()
     }
 
+    /// Transpile class properties to a `name(&self)` getter and a
+    /// `set_name(&mut self, value)` setter, for whichever accessors are declared
+    /// Complexity: 4 (within Toyota Way limits)
+    pub(crate) fn transpile_class_properties(
+        &self,
+        properties: &[ClassProperty],
+    ) -> Result<Vec<TokenStream>> {
+        let mut accessor_tokens = Vec::new();
+        for property in properties {
+            let property_type = self.transpile_type(&property.ty)?;
+            let visibility = if property.is_pub {
+                quote! { pub }
+            } else {
+                quote! {}
+            };
+            if let Some(ref getter) = property.getter {
+                let getter_name = format_ident!("{}", property.name);
+                let body = self.transpile_expr(getter)?;
+                accessor_tokens.push(quote! {
+                    #visibility fn #getter_name(&self) -> #property_type {
+                        #body
+                    }
+                });
+            }
+            if let Some(ref setter) = property.setter {
+                let setter_name = format_ident!("set_{}", property.name);
+                let param = format_ident!("{}", setter.param_name);
+                let body = self.transpile_expr(&setter.body)?;
+                accessor_tokens.push(quote! {
+                    #visibility fn #setter_name(&mut self, #param: #property_type) {
+                        #body;
+                    }
+                });
+            }
+        }
+        Ok(accessor_tokens)
+    }
+
     /// Transpile class constants
     /// Complexity: 3 (within Toyota Way limits)
     pub(crate) fn transpile_class_constants(
@@ -971,33 +1051,31 @@ impl Transpiler {
             .collect();
         // Check if any variant has discriminant values
         let has_discriminants = variants.iter().any(|v| v.discriminant.is_some());
-        let variant_tokens: Vec<TokenStream> = variants
+        let variant_tokens = variants
             .iter()
             .map(|variant| {
                 use crate::frontend::ast::EnumVariantKind;
                 let variant_name = format_ident!("{}", variant.name);
 
-                match &variant.kind {
+                Ok(match &variant.kind {
                     EnumVariantKind::Tuple(fields) => {
                         // Tuple variant: Write(String)
-                        let field_types: Vec<TokenStream> = fields
+                        let field_types = fields
                             .iter()
-                            .map(|ty| self.transpile_type(ty).unwrap_or_else(|_| quote! { _ }))
-                            .collect();
+                            .map(|ty| self.transpile_type(ty))
+                            .collect::<Result<Vec<_>>>()?;
                         quote! { #variant_name(#(#field_types),*) }
                     }
                     EnumVariantKind::Struct(fields) => {
                         // Struct variant: Move { x: i32, y: i32 }
-                        let field_defs: Vec<TokenStream> = fields
+                        let field_defs = fields
                             .iter()
                             .map(|field| {
                                 let field_name = format_ident!("{}", field.name);
-                                let ty = &field.ty;
-                                let t = self.transpile_type(ty);
-                                let field_type = t.unwrap_or_else(|_| quote! { _ });
-                                quote! { #field_name: #field_type }
+                                let field_type = self.transpile_type(&field.ty)?;
+                                Ok(quote! { #field_name: #field_type })
                             })
-                            .collect();
+                            .collect::<Result<Vec<_>>>()?;
                         quote! { #variant_name { #(#field_defs),* } }
                     }
                     EnumVariantKind::Unit => {
@@ -1010,9 +1088,9 @@ impl Transpiler {
                             quote! { #variant_name }
                         }
                     }
-                }
+                })
             })
-            .collect();
+            .collect::<Result<Vec<TokenStream>>>()?;
         let visibility = if is_pub {
             quote! { pub }
         } else {
@@ -1161,6 +1239,11 @@ impl Transpiler {
         // DEFECT-027 FIX: Strip generic parameters from for_type if present
         // e.g., "Container<T>" -> "Container"
         let base_type = for_type.split('<').next().unwrap_or(for_type).trim();
+        if let (Some(trait_name), true) = (trait_name, type_params.is_empty()) {
+            if let Some(tokens) = self.transpile_operator_impl(base_type, trait_name, methods)? {
+                return Ok(tokens);
+            }
+        }
         let type_ident = format_ident!("{}", base_type);
         let method_tokens: Result<Vec<_>> = methods
             .iter()
@@ -1229,7 +1312,10 @@ impl Transpiler {
             .map(|p| Self::parse_type_param_to_tokens(p))
             .collect();
         if let Some(trait_name) = trait_name {
-            let trait_ident = format_ident!("{}", trait_name);
+            // Trait names keep their arguments, as in `From<i32>`
+            let trait_ident: TokenStream = trait_name
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Invalid trait name: {trait_name}"))?;
             if type_params.is_empty() {
                 Ok(quote! {
                     impl #trait_ident for #type_ident {
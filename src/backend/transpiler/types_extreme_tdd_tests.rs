@@ -29,6 +29,8 @@ fn make_param(name: &str, ty: Type) -> crate::frontend::ast::Param {
         span: Span::new(0, 0),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }
 }
 
@@ -40,6 +42,8 @@ fn make_struct_field(name: &str, ty: Type, visibility: Visibility) -> StructFiel
         default_value: None,
         decorators: vec![],
         is_mut: false,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }
 }
 
@@ -394,16 +398,22 @@ fn test_transpile_enum_unit_variants() {
             name: "Red".to_string(),
             kind: EnumVariantKind::Unit,
             discriminant: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         EnumVariant {
             name: "Green".to_string(),
             kind: EnumVariantKind::Unit,
             discriminant: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         EnumVariant {
             name: "Blue".to_string(),
             kind: EnumVariantKind::Unit,
             discriminant: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
     ];
     let result = t.transpile_enum("Color", &[], &variants, true).unwrap();
@@ -423,11 +433,15 @@ fn test_transpile_enum_with_discriminants() {
             name: "A".to_string(),
             kind: EnumVariantKind::Unit,
             discriminant: Some(1),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         EnumVariant {
             name: "B".to_string(),
             kind: EnumVariantKind::Unit,
             discriminant: Some(2),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
     ];
     let result = t.transpile_enum("MyEnum", &[], &variants, false).unwrap();
@@ -445,11 +459,15 @@ fn test_transpile_enum_tuple_variant() {
             name: "Some".to_string(),
             kind: EnumVariantKind::Tuple(vec![make_type(TypeKind::Named("T".to_string()))]),
             discriminant: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         EnumVariant {
             name: "None".to_string(),
             kind: EnumVariantKind::Unit,
             discriminant: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
     ];
     let result = t
@@ -479,6 +497,8 @@ fn test_transpile_enum_struct_variant() {
             ),
         ]),
         discriminant: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let result = t.transpile_enum("Message", &[], &variants, true).unwrap();
     let s = result.to_string();
@@ -621,6 +641,8 @@ fn test_has_reference_fields_true() {
         default_value: None,
         decorators: vec![],
         is_mut: false,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     assert!(t.has_reference_fields(&fields));
 }
@@ -635,6 +657,8 @@ fn test_has_reference_fields_false() {
         default_value: None,
         decorators: vec![],
         is_mut: false,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     assert!(!t.has_reference_fields(&fields));
 }
@@ -832,6 +856,8 @@ fn test_struct_with_default_values() {
         ))),
         decorators: vec![],
         is_mut: false,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
     let result = t
         .transpile_struct_with_methods("Counter", &[], &[field_with_default], &[], &[], false)
@@ -852,6 +878,8 @@ fn test_struct_with_default_string_field() {
         ))),
         decorators: vec![],
         is_mut: false,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
     let result = t
         .transpile_struct_with_methods("Config", &[], &[field_with_default], &[], &[], false)
@@ -875,6 +903,8 @@ fn test_struct_defaults_with_methods() {
         ))),
         decorators: vec![],
         is_mut: false,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
     let method = crate::frontend::ast::ClassMethod {
         name: "inc".to_string(),
@@ -914,6 +944,8 @@ fn test_struct_generic_with_defaults() {
         ))),
         decorators: vec![],
         is_mut: false,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
     let result = t
         .transpile_struct_with_methods("Wrapper", &["T".to_string()], &[field], &[], &[], false)
@@ -974,6 +1006,8 @@ fn test_struct_field_no_default_in_default_impl() {
         ))),
         decorators: vec![],
         is_mut: false,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
     let field_without = make_struct_field(
         "b",
@@ -1033,7 +1067,7 @@ fn test_constructor_body_single_self_assign() {
         target: Box::new(target),
         value: Box::new(value),
     });
-    let result = t.transpile_constructor_body(&body).unwrap();
+    let result = t.transpile_constructor_body(&body, &[]).unwrap();
     let code = result.to_string();
     assert!(code.contains("Self"), "Should generate Self struct init");
     assert!(code.contains('x'), "Should contain field name");
@@ -1069,7 +1103,7 @@ fn test_constructor_body_block_with_self_assigns() {
     let body = make_expr(crate::frontend::ast::ExprKind::Block(vec![
         assign1, assign2,
     ]));
-    let result = t.transpile_constructor_body(&body).unwrap();
+    let result = t.transpile_constructor_body(&body, &[]).unwrap();
     let code = result.to_string();
     assert!(code.contains("Self"), "Should generate Self init");
     assert!(code.contains('x'), "Should contain x field");
@@ -1089,7 +1123,7 @@ fn test_constructor_body_non_self_assign_fallthrough() {
         ))),
     });
     let body = make_expr(crate::frontend::ast::ExprKind::Block(vec![non_self_assign]));
-    let result = t.transpile_constructor_body(&body);
+    let result = t.transpile_constructor_body(&body, &[]);
     // Should fall through to regular transpilation (still OK)
     assert!(result.is_ok());
 }
@@ -1101,7 +1135,7 @@ fn test_constructor_body_non_block_non_assign_fallthrough() {
     let body = make_expr(crate::frontend::ast::ExprKind::Literal(
         crate::frontend::ast::Literal::Integer(0, None),
     ));
-    let result = t.transpile_constructor_body(&body).unwrap();
+    let result = t.transpile_constructor_body(&body, &[]).unwrap();
     let code = result.to_string();
     assert!(code.contains('0'), "Should transpile literal directly");
 }
@@ -1111,7 +1145,7 @@ fn test_constructor_body_empty_block() {
     let t = Transpiler::new();
     // Empty block => field_inits is empty => falls through to regular transpilation
     let body = make_expr(crate::frontend::ast::ExprKind::Block(vec![]));
-    let result = t.transpile_constructor_body(&body);
+    let result = t.transpile_constructor_body(&body, &[]);
     assert!(result.is_ok());
 }
 
@@ -1142,7 +1176,7 @@ fn test_constructor_body_block_mixed_self_then_non_self() {
         self_assign,
         non_self,
     ]));
-    let result = t.transpile_constructor_body(&body);
+    let result = t.transpile_constructor_body(&body, &[]);
     // Falls through when encountering non-self assignment
     assert!(result.is_ok());
 }
@@ -335,6 +335,8 @@ fn make_field(name: &str, type_name: &str) -> StructField {
         is_mut: false,
         default_value: None,
         decorators: vec![],
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }
 }
 
@@ -362,6 +364,8 @@ fn test_has_reference_fields_with_ref() {
         is_mut: false,
         default_value: None,
         decorators: vec![],
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
     assert!(transpiler.has_reference_fields(&[ref_field]));
 }
@@ -466,6 +470,8 @@ fn test_transpile_params_single() {
         span: crate::frontend::ast::Span::new(0, 0),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let result = transpiler
         .transpile_params(&params)
@@ -487,6 +493,8 @@ fn test_transpile_params_multiple() {
             span: crate::frontend::ast::Span::new(0, 0),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         crate::frontend::ast::Param {
             pattern: crate::frontend::ast::Pattern::Identifier("y".to_string()),
@@ -494,6 +502,8 @@ fn test_transpile_params_multiple() {
             span: crate::frontend::ast::Span::new(0, 0),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
     ];
     let result = transpiler
@@ -512,6 +522,8 @@ fn test_transpile_params_mutable() {
         span: crate::frontend::ast::Span::new(0, 0),
         is_mutable: true,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let result = transpiler
         .transpile_params(&params)
@@ -697,7 +709,7 @@ fn test_transpile_constructors_named() {
     let transpiler = Transpiler::new();
     let ctors = vec![make_constructor(Some("from_string"), None)];
     let result = transpiler
-        .transpile_constructors(&ctors)
+        .transpile_constructors(&ctors, &[])
         .expect("operation should succeed in test");
     assert_eq!(result.len(), 1);
     let code = result[0].to_string();
@@ -720,7 +732,7 @@ fn test_transpile_constructors_with_return_type() {
     });
     let ctors = vec![make_constructor(None, Some(ret_type))];
     let result = transpiler
-        .transpile_constructors(&ctors)
+        .transpile_constructors(&ctors, &[])
         .expect("operation should succeed in test");
     assert_eq!(result.len(), 1);
     let code = result[0].to_string();
@@ -861,6 +873,8 @@ fn test_generate_default_impl_with_defaults() {
             contracts: Vec::new(),
         }),
         decorators: vec![],
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
     let result = transpiler
         .generate_default_impl(&[field_with_default], &struct_name, &[])
@@ -1252,6 +1266,8 @@ fn make_enum_variant(
         name: name.to_string(),
         kind,
         discriminant: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }
 }
 
@@ -1308,6 +1324,8 @@ fn test_transpile_enum_struct_variants() {
             is_mut: false,
             default_value: None,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         StructField {
             name: "y".to_string(),
@@ -1316,6 +1334,8 @@ fn test_transpile_enum_struct_variants() {
             is_mut: false,
             default_value: None,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
     ];
     let variants = vec![make_enum_variant("Move", EnumVariantKind::Struct(fields))];
@@ -1556,6 +1576,8 @@ fn test_transpile_params_self_ref() {
         span: Span::new(0, 0),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let result = transpiler.transpile_params(&params).unwrap();
     let code = result[0].to_string();
@@ -1578,6 +1600,8 @@ fn test_transpile_params_self_mut_ref() {
         span: Span::new(0, 0),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let result = transpiler.transpile_params(&params).unwrap();
     let code = result[0].to_string();
@@ -1596,6 +1620,8 @@ fn test_transpile_params_self_owned() {
         span: Span::new(0, 0),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let result = transpiler.transpile_params(&params).unwrap();
     let code = result[0].to_string();
@@ -1613,6 +1639,8 @@ fn test_transpile_params_self_mut_owned() {
         span: Span::new(0, 0),
         is_mutable: true,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let result = transpiler.transpile_params(&params).unwrap();
     let code = result[0].to_string();
@@ -1632,6 +1660,8 @@ fn test_transpile_struct_with_methods() {
         is_mut: false,
         default_value: None,
         decorators: vec![],
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let methods = vec![ClassMethod {
         name: "get_value".to_string(),
@@ -1683,6 +1713,8 @@ fn test_transpile_struct_field_visibility() {
             is_mut: false,
             default_value: None,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         StructField {
             name: "crate_field".to_string(),
@@ -1691,6 +1723,8 @@ fn test_transpile_struct_field_visibility() {
             is_mut: false,
             default_value: None,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         StructField {
             name: "super_field".to_string(),
@@ -1699,6 +1733,8 @@ fn test_transpile_struct_field_visibility() {
             is_mut: false,
             default_value: None,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         StructField {
             name: "private_field".to_string(),
@@ -1707,6 +1743,8 @@ fn test_transpile_struct_field_visibility() {
             is_mut: false,
             default_value: None,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
     ];
     let result = transpiler.transpile_struct("VisibleStruct", &[], &fields, &[], true);
@@ -1732,6 +1770,8 @@ fn test_transpile_class_full() {
         is_mut: false,
         default_value: None,
         decorators: vec![],
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let constructors = vec![Constructor {
         name: None,
@@ -1788,6 +1828,7 @@ fn test_transpile_class_full() {
         &constructors,
         &methods,
         &constants,
+        &[],
         &["Debug".to_string()],
         true,
     );
@@ -1969,3 +2010,79 @@ fn test_transpile_named_type_custom_r124() {
     let result = transpiler.transpile_named_type("MyCustomType").unwrap();
     assert_eq!(result.to_string(), "MyCustomType");
 }
+
+// Enums with data: definition, impl, constructor and match end to end
+#[test]
+fn test_transpile_enum_with_data_and_impl() {
+    let code = r#"
+        enum Shape { Circle(f64), Label(String) }
+        impl Shape {
+            fun area(&self) -> f64 {
+                match self {
+                    Shape::Circle(r) => 3.0 * r * r,
+                    Shape::Label(_) => 0.0
+                }
+            }
+        }
+        fun main() {
+            let s = Shape::Label("tag")
+            println(s.area())
+        }
+    "#;
+    let ast = crate::Parser::new(code).parse().expect("should parse");
+    let rust = Transpiler::new()
+        .transpile_to_program(&ast)
+        .expect("should transpile")
+        .to_string();
+    assert!(
+        rust.contains("enum Shape { Circle (f64) , Label (String) , }"),
+        "{rust}"
+    );
+    assert!(rust.contains("impl Shape"), "{rust}");
+    assert!(rust.contains("Shape :: Circle (r) =>"), "{rust}");
+    assert!(
+        rust.contains(r#"Shape :: Label ("tag" . to_string ())"#),
+        "String payloads are converted: {rust}"
+    );
+}
+
+#[test]
+fn test_transpile_class_with_properties_and_defaults() {
+    let code = r"
+        class Temperature {
+            celsius: f64
+            readings: i32 = 0
+
+            new(celsius: f64) {
+                self.celsius = celsius
+            }
+
+            property fahrenheit: f64 {
+                get => self.celsius * 9.0 / 5.0 + 32.0,
+                set(f) => self.celsius = (f - 32.0) * 5.0 / 9.0
+            }
+        }
+        fun main() {
+            let t = Temperature(100.0)
+            t.fahrenheit = 32.0
+            println(t.fahrenheit)
+        }
+    ";
+    let ast = crate::Parser::new(code).parse().expect("should parse");
+    let rust = Transpiler::new()
+        .transpile_to_program(&ast)
+        .expect("should transpile")
+        .to_string();
+    assert!(
+        rust.contains("Self { celsius : celsius , readings :"),
+        "Unassigned fields take their defaults: {rust}"
+    );
+    assert!(rust.contains("pub fn fahrenheit (& self) -> f64"), "{rust}");
+    assert!(
+        rust.contains("pub fn set_fahrenheit (& mut self , f : f64)"),
+        "{rust}"
+    );
+    assert!(rust.contains("Temperature :: new (100"), "{rust}");
+    assert!(rust.contains("t . set_fahrenheit (32"), "{rust}");
+    assert!(rust.contains("t . fahrenheit ()"), "{rust}");
+}
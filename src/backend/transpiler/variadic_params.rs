@@ -0,0 +1,248 @@
+//! Rest Parameter and Spread Argument Transpilation
+//!
+//! Rust has no variadic functions, so a rest parameter (`fun log(...args)`)
+//! becomes a `Vec` parameter and call sites collect the surplus arguments
+//! into it. A spread argument (`f(...list)`) extends that `Vec`, or fills the
+//! remaining fixed parameters of a non-variadic function by index.
+
+use super::{FunctionSignature, Transpiler};
+use crate::frontend::ast::{Expr, ExprKind, Literal, Param, Pattern, Span, Type, TypeKind};
+use anyhow::{bail, Result};
+
+impl Transpiler {
+    /// Function parameters with a trailing rest parameter turned into a `Vec`.
+    ///
+    /// `...args: [T]` keeps its list type and `...args: T` becomes `Vec<T>`.
+    /// Without an annotation the element type comes from the first surplus
+    /// argument at a call site, falling back to `String`.
+    /// Complexity: 4 (within Toyota Way limits)
+    pub(super) fn rest_param_as_vec(&self, func_name: &str, params: &[Param]) -> Vec<Param> {
+        let mut params = params.to_vec();
+        let index = params.len() - 1;
+        let rest = &mut params[index];
+        rest.pattern = Pattern::Identifier(rest.name());
+        let is_list = match &rest.ty.kind {
+            TypeKind::List(_) => true,
+            TypeKind::Generic { base, .. } => base == "Vec",
+            _ => false,
+        };
+        if !is_list {
+            let element = if matches!(&rest.ty.kind, TypeKind::Named(name) if name == "Any") {
+                let inferred = self
+                    .get_call_site_param_type(func_name, index)
+                    .filter(|ty| matches!(ty.as_str(), "i32" | "i64" | "f64" | "bool" | "String"))
+                    .unwrap_or_else(|| "String".to_string());
+                Type {
+                    kind: TypeKind::Named(inferred),
+                    span: rest.ty.span,
+                }
+            } else {
+                rest.ty.clone()
+            };
+            rest.ty = Type {
+                kind: TypeKind::List(Box::new(element)),
+                span: rest.ty.span,
+            };
+        }
+        params
+    }
+
+    /// Rewrite the arguments of a call with a rest parameter or spread arguments.
+    ///
+    /// For a variadic function the arguments past its fixed parameters are
+    /// collected into one `vec![...]` argument, where `...list` extends it.
+    /// For any other function a trailing `...list` fills the remaining
+    /// parameters with `list[0]`, `list[1]`, and so on.
+    /// Returns `None` when the call needs no rewrite.
+    /// Complexity: 8 (within Toyota Way limits)
+    pub(super) fn expand_variadic_args(
+        &self,
+        func_name: &str,
+        args: &[Expr],
+    ) -> Result<Option<Vec<Expr>>> {
+        let has_spread = args.iter().any(is_spread);
+        let sig = match self.function_signatures.get(func_name) {
+            Some(sig) if sig.is_variadic => sig,
+            Some(sig) if has_spread => return spread_into_params(func_name, sig, args).map(Some),
+            None if has_spread => bail!("Spread arguments need the definition of `{func_name}`"),
+            _ => return Ok(None),
+        };
+
+        let fixed = sig.param_types.len() - 1;
+        if args.iter().take(fixed).any(is_spread) {
+            bail!("Spread arguments to `{func_name}` must follow its fixed parameters");
+        }
+        if args.len() < fixed {
+            // Defaults fill the fixed parameters, and an empty Vec the rest
+            return Ok(None);
+        }
+
+        let string_elements = matches!(
+            sig.param_types.last().map(String::as_str),
+            Some("Any" | "String" | "Vec<String>")
+        );
+        let surplus: Vec<Expr> = args[fixed..]
+            .iter()
+            .map(|arg| rest_element(arg, string_elements))
+            .collect();
+        let packed = if surplus.iter().any(is_spread) {
+            ExprKind::List(surplus)
+        } else {
+            ExprKind::Macro {
+                name: "vec".to_string(),
+                args: surplus,
+            }
+        };
+        let mut expanded = args[..fixed].to_vec();
+        expanded.push(Expr::new(packed, Span::default()));
+        Ok(Some(expanded))
+    }
+
+    /// `vec![]`, the value of a rest parameter given no arguments
+    pub(super) fn empty_vec_expr() -> Expr {
+        Expr::new(
+            ExprKind::Macro {
+                name: "vec".to_string(),
+                args: Vec::new(),
+            },
+            Span::default(),
+        )
+    }
+}
+
+/// Fill the parameters after the positional arguments from a trailing
+/// `...list`, as `list[0]`, `list[1]`, ..., which index access clones
+/// Complexity: 3 (within Toyota Way limits)
+fn spread_into_params(
+    func_name: &str,
+    sig: &FunctionSignature,
+    args: &[Expr],
+) -> Result<Vec<Expr>> {
+    let Some((last, positional)) = args.split_last() else {
+        return Ok(Vec::new());
+    };
+    let ExprKind::Spread { expr: list } = &last.kind else {
+        bail!("Only the last argument to `{func_name}` can be spread");
+    };
+    if positional.iter().any(is_spread) {
+        bail!("Only the last argument to `{func_name}` can be spread");
+    }
+    let mut expanded = positional.to_vec();
+    for index in positional.len()..sig.param_types.len() {
+        let index_expr = Expr::new(
+            ExprKind::Literal(Literal::Integer(index as i64, None)),
+            Span::default(),
+        );
+        expanded.push(Expr::new(
+            ExprKind::IndexAccess {
+                object: list.clone(),
+                index: Box::new(index_expr),
+            },
+            Span::default(),
+        ));
+    }
+    Ok(expanded)
+}
+
+/// An element of a packed rest argument: string literals become `String`s,
+/// and spread lists are cloned rather than moved
+fn rest_element(arg: &Expr, string_elements: bool) -> Expr {
+    match &arg.kind {
+        ExprKind::Spread { expr } => Expr::new(
+            ExprKind::Spread {
+                expr: Box::new(clone_of((**expr).clone())),
+            },
+            arg.span,
+        ),
+        ExprKind::Literal(Literal::String(_)) if string_elements => Expr::new(
+            ExprKind::MethodCall {
+                receiver: Box::new(arg.clone()),
+                method: "to_string".to_string(),
+                args: Vec::new(),
+            },
+            arg.span,
+        ),
+        _ => arg.clone(),
+    }
+}
+
+fn clone_of(expr: Expr) -> Expr {
+    let span = expr.span;
+    Expr::new(
+        ExprKind::MethodCall {
+            receiver: Box::new(expr),
+            method: "clone".to_string(),
+            args: Vec::new(),
+        },
+        span,
+    )
+}
+
+fn is_spread(arg: &Expr) -> bool {
+    matches!(arg.kind, ExprKind::Spread { .. })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::Parser;
+
+    fn transpile(source: &str) -> Result<String> {
+        let ast = Parser::new(source).parse().expect("should parse");
+        Ok(Transpiler::new().transpile(&ast)?.to_string())
+    }
+
+    #[test]
+    fn test_rest_param_becomes_vec() {
+        let rust = transpile("fun total(...nums) { nums.len() }\ntotal(1, 2, 3)").unwrap();
+        assert!(rust.contains("nums : Vec < i32 >"), "got: {rust}");
+        assert!(rust.contains("total (vec ! [1 , 2 , 3])"), "got: {rust}");
+    }
+
+    #[test]
+    fn test_rest_param_keeps_fixed_params_and_defaults_to_empty() {
+        let rust = transpile(
+            "fun log_all(prefix: String, ...parts: [String]) { parts.len() }\nlog_all(\"a\")\nlog_all(\"a\", \"b\")",
+        )
+        .unwrap();
+        assert!(rust.contains("parts : Vec < String >"), "got: {rust}");
+        assert!(rust.contains("vec ! []"), "got: {rust}");
+        assert!(
+            rust.contains(r#"vec ! ["b" . to_string ()]"#),
+            "got: {rust}"
+        );
+    }
+
+    #[test]
+    fn test_spread_extends_rest_arg() {
+        let rust = transpile(
+            "fun total(...nums: [i32]) { nums.len() }\nlet more = [2, 3]\ntotal(1, ...more)",
+        )
+        .unwrap();
+        assert!(
+            rust.contains("__temp_vec . extend (more . clone ())"),
+            "got: {rust}"
+        );
+    }
+
+    #[test]
+    fn test_spread_fills_fixed_params_by_index() {
+        let rust =
+            transpile("fun add(a: i32, b: i32) -> i32 { a + b }\nlet xs = [1, 2]\nadd(...xs)")
+                .unwrap();
+        let call = &rust[rust.rfind("add (").expect("call emitted")..];
+        assert_eq!(call.matches(". clone ()").count(), 2, "got: {call}");
+        assert!(!call.contains("..."), "got: {call}");
+    }
+
+    #[test]
+    fn test_user_function_shadows_builtin() {
+        let rust = transpile("fun log(...args) { args.len() }\nlog(\"a\", \"b\")").unwrap();
+        assert!(rust.contains("log (vec !"), "got: {rust}");
+    }
+
+    #[test]
+    fn test_spread_to_unknown_function_fails() {
+        assert!(transpile("let xs = [1]\nunknown_fn(...xs)").is_err());
+    }
+}
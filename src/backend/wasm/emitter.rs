@@ -2,15 +2,52 @@
 //!
 //! Core WASM code generation from Ruchy AST.
 
-use crate::frontend::ast::{BinaryOp, Expr, ExprKind, Literal, Pattern, StringPart};
+use crate::frontend::ast::{
+    BinaryOp, Expr, ExprKind, Literal, Param, Pattern, StringPart, Type, TypeKind,
+};
 use wasm_encoder::{
-    CodeSection, ConstExpr, ExportSection, Function, FunctionSection, GlobalSection, GlobalType,
-    Instruction, MemorySection, MemoryType, Module, TypeSection, ValType,
+    CodeSection, ConstExpr, DataSection, ExportSection, Function, FunctionSection, GlobalSection,
+    GlobalType, IndirectNameMap, Instruction, MemorySection, MemoryType, Module, NameMap,
+    NameSection, TypeSection, ValType,
 };
 
+use super::host::{self, HostFunction, HOST_FUNCTION_COUNT, HOST_MODULE, MEMORY_EXPORT_NAME};
+use super::memory::{self, RuntimeFunction, STRING_HEADER_SIZE};
 use super::symbol_table::SymbolTable;
 use super::types::WasmType;
 use super::utils;
+use super::wasi::{
+    self, WasiImport, WasiShim, START_EXPORT_NAME, WASI_FUNCTION_COUNT, WASI_IMPORT_COUNT,
+    WASI_MODULE,
+};
+
+/// Debug name of the function holding top-level (non-function) code
+pub const TOP_LEVEL_FUNCTION_NAME: &str = "__top_level";
+
+/// Export name of the remaining-fuel global in metered modules
+pub const FUEL_EXPORT_NAME: &str = "ruchy_fuel";
+
+/// Size of the initial memory page, which holds the string literals
+const MEMORY_PAGE_SIZE: u32 = 65_536;
+
+/// Parameter and result types of a compiled function
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Signature {
+    params: Vec<WasmType>,
+    result: Option<WasmType>,
+    /// Whether the result is the address of a string
+    returns_string: bool,
+}
+
+/// Branch targets of an enclosing loop, as block depths
+#[derive(Debug, Clone)]
+struct LoopLabels {
+    label: Option<String>,
+    /// Depth of the block that `break` leaves
+    exit: u32,
+    /// Depth of the block that `continue` ends (`for`) or restarts (`while`)
+    next: u32,
+}
 
 pub struct WasmEmitter {
     module: Module,
@@ -23,6 +60,34 @@ pub struct WasmEmitter {
     /// Maps variable name to tuple element types (for mixed-type tuple support)
     /// Example: "x" -> [I32, F32] for tuple (1, 3.0)
     tuple_types: std::cell::RefCell<std::collections::HashMap<String, Vec<WasmType>>>,
+    /// Emit a `name` section with Ruchy function and local names
+    debug_names: bool,
+    /// Fuel budget; `None` emits an unmetered module
+    fuel: Option<u64>,
+    /// Target WASI instead of the `ruchy` host functions (see [`super::wasi`])
+    wasi: bool,
+    /// Run [`crate::backend::whole_program::optimize`] before lowering
+    whole_program: bool,
+    /// Function `_start` calls in WASI modules, and whether it returns a value
+    start: std::cell::Cell<Option<(u32, bool)>>,
+    /// Index of the fuel global in the module being emitted
+    fuel_global: std::cell::Cell<u32>,
+    /// String literals, laid out in order from address 0 (see [`super::memory`])
+    strings: std::cell::RefCell<Vec<String>>,
+    /// Signatures of user functions and of [`TOP_LEVEL_FUNCTION_NAME`]
+    signatures: std::cell::RefCell<std::collections::HashMap<String, Signature>>,
+    /// Locals of the function being compiled that hold strings
+    string_locals: std::cell::RefCell<std::collections::HashSet<String>>,
+    /// Loops around the code being lowered, innermost last
+    loops: std::cell::RefCell<Vec<LoopLabels>>,
+    /// Blocks, loops and ifs open around the code being lowered
+    block_depth: std::cell::Cell<u32>,
+    /// Index of the first runtime function (see [`super::memory`])
+    runtime_base: std::cell::Cell<u32>,
+    /// Whether lowered code calls the runtime functions
+    uses_runtime: std::cell::Cell<bool>,
+    /// Names of the locals of each compiled function, in function order
+    local_names: std::cell::RefCell<Vec<Vec<(u32, String)>>>,
 }
 impl WasmEmitter {
     /// # Examples
@@ -38,9 +103,87 @@ impl WasmEmitter {
             functions: std::cell::RefCell::new(std::collections::HashMap::new()),
             structs: std::cell::RefCell::new(std::collections::HashMap::new()),
             tuple_types: std::cell::RefCell::new(std::collections::HashMap::new()),
+            debug_names: false,
+            fuel: None,
+            wasi: false,
+            whole_program: false,
+            start: std::cell::Cell::new(None),
+            fuel_global: std::cell::Cell::new(0),
+            strings: std::cell::RefCell::new(Vec::new()),
+            signatures: std::cell::RefCell::new(std::collections::HashMap::new()),
+            string_locals: std::cell::RefCell::new(std::collections::HashSet::new()),
+            loops: std::cell::RefCell::new(Vec::new()),
+            block_depth: std::cell::Cell::new(0),
+            runtime_base: std::cell::Cell::new(0),
+            uses_runtime: std::cell::Cell::new(false),
+            local_names: std::cell::RefCell::new(Vec::new()),
         }
     }
 
+    /// Emit a `name` section so debuggers and browser devtools show Ruchy
+    /// function, parameter and local names instead of numeric indices
+    ///
+    /// DWARF line tables are not emitted: the emitter does not yet track the
+    /// code offsets of lowered instructions.
+    ///
+    /// ```ignore
+    /// use ruchy::backend::wasm::WasmEmitter;
+    /// let emitter = WasmEmitter::new().with_debug_names(true);
+    /// ```
+    #[must_use]
+    pub fn with_debug_names(mut self, enabled: bool) -> Self {
+        self.debug_names = enabled;
+        self
+    }
+
+    /// Meter execution so untrusted code cannot hang its host
+    ///
+    /// Every function entry and loop iteration spends one unit from a
+    /// mutable `i64` global exported as [`FUEL_EXPORT_NAME`], which starts at
+    /// `fuel`. Running out traps with `unreachable`. Hosts can read the
+    /// global to report usage, or set it to refuel before the next call.
+    ///
+    /// ```ignore
+    /// use ruchy::backend::wasm::WasmEmitter;
+    /// let emitter = WasmEmitter::new().with_fuel(Some(1_000_000));
+    /// ```
+    #[must_use]
+    pub fn with_fuel(mut self, fuel: Option<u64>) -> Self {
+        self.fuel = fuel;
+        self
+    }
+
+    /// Emit a WASI command module that runs under any WASI runtime
+    ///
+    /// Builtins are implemented with `wasi_snapshot_preview1` imports instead
+    /// of the `ruchy` host functions, `read_file` becomes available, and the
+    /// module exports `_start` (see [`super::wasi`]).
+    ///
+    /// ```ignore
+    /// use ruchy::backend::wasm::WasmEmitter;
+    /// let emitter = WasmEmitter::new().with_wasi(true);
+    /// ```
+    #[must_use]
+    pub fn with_wasi(mut self, enabled: bool) -> Self {
+        self.wasi = enabled;
+        self
+    }
+
+    /// Specialize constant arguments, fold constant calls and branches, and
+    /// drop unreachable functions before lowering small programs
+    ///
+    /// See [`crate::backend::whole_program`].
+    ///
+    /// ```ignore
+    /// use ruchy::backend::wasm::WasmEmitter;
+    /// let emitter = WasmEmitter::new().with_whole_program(true);
+    /// ```
+    #[must_use]
+    pub fn with_whole_program(mut self, enabled: bool) -> Self {
+        self.whole_program = enabled;
+        self
+    }
+
     /// Infer element WASM type from expression for tuple support
     /// Complexity: 5 (Toyota Way: <10 ✓)
     ///
@@ -156,11 +299,16 @@ impl WasmEmitter {
     /// let result = instance.emit(&expr);
     /// ```
     pub fn emit(&self, expr: &Expr) -> Result<Vec<u8>, String> {
-        // Collect tuple types BEFORE building symbol table (needed for type inference)
-        self.collect_tuple_types(expr);
+        let optimized;
+        let expr = if self.whole_program {
+            optimized = crate::backend::whole_program::optimize(expr.clone()).0;
+            &optimized
+        } else {
+            expr
+        };
 
-        // Build symbol table from entire expression tree
-        self.build_symbol_table(expr);
+        // Collect tuple types BEFORE building symbol tables (needed for type inference)
+        self.collect_tuple_types(expr);
 
         // Collect struct definitions for field layout mapping
         self.collect_struct_definitions(expr);
@@ -170,6 +318,33 @@ impl WasmEmitter {
 
         // Build function index map (must be done after collecting functions)
         self.build_function_index_map(expr, &func_defs);
+        self.build_signatures(expr, &func_defs);
+
+        // Runtime functions follow the user functions and top-level code
+        let defined =
+            func_defs.len() + usize::from(self.top_level_code(expr, &func_defs).is_some());
+        self.runtime_base
+            .set(self.import_offset(expr) + defined as u32);
+        self.start.set(
+            self.wasi
+                .then(|| self.start_function(expr, &func_defs))
+                .flatten(),
+        );
+
+        // Lower code first: the string literals it interns decide whether
+        // memory is needed and where the heap starts. The fuel global
+        // follows the heap pointer, if there is one.
+        self.strings.borrow_mut().clear();
+        self.uses_runtime.set(false);
+        self.local_names.borrow_mut().clear();
+        self.fuel_global.set(u32::from(self.needs_memory(expr)));
+        let codes = self.emit_code_section(expr, &func_defs)?;
+        if self.uses_runtime.get() && !self.needs_memory(expr) {
+            return Err(
+                "Building strings needs linear memory, but the program has none".to_string(),
+            );
+        }
+        let data = self.emit_data_section()?;
 
         // Add sections to module (order matters in WASM)
         let types = self.emit_type_section(expr, &func_defs);
@@ -180,14 +355,14 @@ impl WasmEmitter {
             module.section(&imports);
         }
 
-        let functions = self.emit_function_section(&func_defs, expr);
+        let functions = self.emit_function_section(expr);
         module.section(&functions);
 
         if let Some(memories) = self.emit_memory_section(expr) {
             module.section(&memories);
         }
 
-        // Global section for heap pointer (if memory is needed)
+        // Global section for heap pointer (if memory is needed) and fuel
         if let Some(globals) = self.emit_global_section(expr) {
             module.section(&globals);
         }
@@ -196,68 +371,149 @@ impl WasmEmitter {
             module.section(&exports);
         }
 
-        let codes = self.emit_code_section(expr, &func_defs)?;
         module.section(&codes);
+        if let Some(data) = data {
+            module.section(&data);
+        }
+
+        // Custom sections go last so they follow the data section, as tools expect
+        if self.debug_names {
+            module.section(&self.emit_name_section(expr, &func_defs));
+        }
 
         Ok(module.finish())
     }
 
     /// Emit type section with function signatures
-    /// Complexity: 7 (Toyota Way: <10 ✓)
+    /// Complexity: 6 (Toyota Way: <10 ✓)
     fn emit_type_section(
         &self,
         expr: &Expr,
         func_defs: &[(String, Vec<crate::frontend::ast::Param>, Box<Expr>)],
     ) -> TypeSection {
         let mut types = TypeSection::new();
-        let has_functions = !func_defs.is_empty();
-
-        // Type index 0: Built-in functions println_i32 - (i32) -> ()
-        // Type index 1: Built-in functions println_f32 - (f32) -> ()
-        // These must be first because imports reference them
-        if utils::uses_builtins(expr) {
-            types.function(vec![wasm_encoder::ValType::I32], vec![]);
-            types.function(vec![wasm_encoder::ValType::F32], vec![]);
-        }
-
-        if has_functions {
-            // Add a type for each function
-            for (_name, params, body) in func_defs {
-                let param_types = vec![wasm_encoder::ValType::I32; params.len()];
-
-                // Determine return type: check for explicit return OR implicit value
-                let returns_value =
-                    utils::has_return_with_value(body) || self.expression_produces_value(body);
-                let return_types = if returns_value {
-                    vec![wasm_encoder::ValType::I32]
-                } else {
-                    vec![] // Void function
-                };
 
-                types.function(param_types, return_types);
+        // Types of the host functions come first because imports reference
+        // them: host function N has type index N. WASI modules have the WASI
+        // imports instead, followed by the shims that replace the host.
+        if utils::uses_builtins(expr) && self.wasi {
+            for function in WasiImport::ALL {
+                types.function(
+                    function.params().iter().copied(),
+                    function.results().iter().copied(),
+                );
+            }
+            for shim in WasiShim::ALL {
+                types.function(
+                    shim.params().iter().copied(),
+                    shim.results().iter().copied(),
+                );
+            }
+        } else if utils::uses_builtins(expr) {
+            for function in HostFunction::ALL {
+                types.function(
+                    function.params().iter().copied(),
+                    function.results().iter().copied(),
+                );
             }
-            // Also add a type for the main function if there's non-function code
-            if let Some(main_expr) = self.get_non_function_code(expr) {
-                self.add_main_type(&mut types, &main_expr);
+        }
+
+        // One type per user function, then top-level code, in function order
+        let mut names: Vec<&str> = func_defs.iter().map(|(name, _, _)| name.as_str()).collect();
+        if self.top_level_code(expr, func_defs).is_some() {
+            names.push(TOP_LEVEL_FUNCTION_NAME);
+        }
+        for name in names {
+            let signature = self.signature(name);
+            let params: Vec<ValType> = signature
+                .params
+                .iter()
+                .map(|ty| self.wasm_type_to_valtype(*ty))
+                .collect();
+            let results = signature.result.map(|ty| self.wasm_type_to_valtype(ty));
+            types.function(params, results);
+        }
+
+        if self.uses_runtime.get() {
+            for function in RuntimeFunction::ALL {
+                types.function(
+                    function.params().iter().copied(),
+                    function.results().iter().copied(),
+                );
             }
-        } else {
-            // Single implicit main function
-            self.add_main_type(&mut types, expr);
+        }
+        if self.start.get().is_some() {
+            types.function([], []);
         }
         types
     }
 
-    /// Add main function type based on expression
-    /// Complexity: 3 (Toyota Way: <10 ✓)
-    fn add_main_type(&self, types: &mut TypeSection, expr: &Expr) {
-        if self.expression_produces_value(expr) {
-            let wasm_ty = self.wasm_type_to_valtype(self.infer_type(expr));
-            types.function(vec![], vec![wasm_ty]);
+    /// Signature recorded for the function `name` by `build_signatures`
+    fn signature(&self, name: &str) -> Signature {
+        self.signatures
+            .borrow()
+            .get(name)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Number of imported functions: the host functions, or the WASI imports
+    fn import_count(&self, expr: &Expr) -> u32 {
+        match (utils::uses_builtins(expr), self.wasi) {
+            (false, _) => 0,
+            (true, false) => HOST_FUNCTION_COUNT,
+            (true, true) => WASI_IMPORT_COUNT,
+        }
+    }
+
+    /// Index of the first user function: after the imports and the WASI shims
+    fn import_offset(&self, expr: &Expr) -> u32 {
+        if self.wasi && utils::uses_builtins(expr) {
+            WASI_FUNCTION_COUNT
+        } else {
+            self.import_count(expr)
+        }
+    }
+
+    /// Index of the function a call of `function` targets: the import, or its WASI shim
+    fn host_function(&self, function: HostFunction) -> u32 {
+        if self.wasi {
+            WasiShim::for_host(function).index()
         } else {
-            types.function(vec![], vec![]);
+            function.index()
         }
     }
 
+    /// Whether the module needs the heap: to allocate, or for the WASI shims' scratch space
+    fn needs_memory(&self, expr: &Expr) -> bool {
+        utils::needs_memory(expr) || (self.wasi && utils::uses_builtins(expr))
+    }
+
+    /// Function `_start` calls: `main`, else the top-level code
+    fn start_function(
+        &self,
+        expr: &Expr,
+        func_defs: &[(String, Vec<crate::frontend::ast::Param>, Box<Expr>)],
+    ) -> Option<(u32, bool)> {
+        let (name, index) = if let Some(&(index, _)) = self.functions.borrow().get("main") {
+            ("main", index)
+        } else {
+            self.top_level_code(expr, func_defs)?;
+            (TOP_LEVEL_FUNCTION_NAME, self.runtime_base.get() - 1)
+        };
+        Some((index, self.signature(name).result.is_some()))
+    }
+
+    /// Index of `_start`, defined after every other function
+    fn start_index(&self) -> u32 {
+        let runtime = if self.uses_runtime.get() {
+            RuntimeFunction::ALL.len() as u32
+        } else {
+            0
+        };
+        self.runtime_base.get() + runtime
+    }
+
     /// Convert `WasmType` to `wasm_encoder::ValType`
     /// Complexity: 1 (Toyota Way: <10 ✓)
     pub(crate) fn wasm_type_to_valtype(&self, ty: WasmType) -> wasm_encoder::ValType {
@@ -269,8 +525,9 @@ impl WasmEmitter {
         }
     }
 
-    /// Emit import section for built-in functions
-    /// Complexity: 3 (Toyota Way: <10 ✓)
+    /// Emit import section for the host functions (see [`super::host`]) or
+    /// the WASI functions (see [`super::wasi`])
+    /// Complexity: 4 (Toyota Way: <10 ✓)
     fn emit_import_section(&self, expr: &Expr) -> Option<wasm_encoder::ImportSection> {
         // Check if expression uses any built-in functions
         if !utils::uses_builtins(expr) {
@@ -278,38 +535,36 @@ impl WasmEmitter {
         }
 
         let mut imports = wasm_encoder::ImportSection::new();
-
-        // Import println_i32 and println_f32 from host environment
-        // Function index 0: println_i32 uses type index 0: (i32) -> ()
-        // Function index 1: println_f32 uses type index 1: (f32) -> ()
-        imports.import("env", "println_i32", wasm_encoder::EntityType::Function(0));
-        imports.import("env", "println_f32", wasm_encoder::EntityType::Function(1));
-
+        if self.wasi {
+            for function in WasiImport::ALL {
+                imports.import(
+                    WASI_MODULE,
+                    function.name(),
+                    wasm_encoder::EntityType::Function(function.index()),
+                );
+            }
+            return Some(imports);
+        }
+        for function in HostFunction::ALL {
+            imports.import(
+                HOST_MODULE,
+                function.name(),
+                wasm_encoder::EntityType::Function(function.index()),
+            );
+        }
         Some(imports)
     }
 
     /// Emit function section
-    /// Complexity: 6 (Toyota Way: <10 ✓)
-    fn emit_function_section(
-        &self,
-        func_defs: &[(String, Vec<crate::frontend::ast::Param>, Box<Expr>)],
-        expr: &Expr,
-    ) -> FunctionSection {
+    /// Complexity: 3 (Toyota Way: <10 ✓)
+    ///
+    /// Defined functions have one type each, in the same order, after the
+    /// imported function types: function N has type index N
+    fn emit_function_section(&self, expr: &Expr) -> FunctionSection {
         let mut functions = FunctionSection::new();
-        let has_functions = !func_defs.is_empty();
-
-        // Type index offset: if we have built-ins, they occupy type indices 0 and 1
-        let type_offset = if utils::uses_builtins(expr) { 2 } else { 0 };
-
-        if has_functions {
-            for i in 0..func_defs.len() {
-                functions.function((i as u32) + type_offset);
-            }
-            if self.get_non_function_code(expr).is_some() {
-                functions.function((func_defs.len() as u32) + type_offset);
-            }
-        } else {
-            functions.function(type_offset);
+        let end = self.start_index() + u32::from(self.start.get().is_some());
+        for type_index in self.import_count(expr)..end {
+            functions.function(type_index);
         }
         functions
     }
@@ -317,11 +572,11 @@ impl WasmEmitter {
     /// Emit memory section if needed
     /// Complexity: 2 (Toyota Way: <10 ✓)
     fn emit_memory_section(&self, expr: &Expr) -> Option<MemorySection> {
-        if utils::needs_memory(expr) {
+        if self.has_memory(expr) {
             let mut memories = MemorySection::new();
             memories.memory(MemoryType {
                 minimum: 1,
-                maximum: Some(1), // Fixed 64KB for MVP
+                maximum: None, // The allocator grows memory as the heap fills
                 memory64: false,
                 shared: false,
                 page_size_log2: None,
@@ -332,87 +587,312 @@ impl WasmEmitter {
         }
     }
 
-    /// Emit global section for heap pointer
-    /// Complexity: 3 (Toyota Way: <10 ✓)
+    /// Emit global section for heap pointer and fuel
+    /// Complexity: 4 (Toyota Way: <10 ✓)
     ///
-    /// Creates a mutable global `$heap_ptr` initialized to 0
-    /// This is used by the bump allocator for memory allocation
+    /// Creates a mutable global `$heap_ptr` initialized to the end of the
+    /// string data. This is used by the bump allocator for memory allocation
+    /// With a fuel budget, the next global holds the remaining fuel
     fn emit_global_section(&self, expr: &Expr) -> Option<GlobalSection> {
-        if utils::needs_memory(expr) {
-            let mut globals = GlobalSection::new();
-            // Global 0: heap pointer (mutable i32, starts at 0)
+        let mut globals = GlobalSection::new();
+        if self.needs_memory(expr) {
+            // Global 0: heap pointer (mutable i32, 4-byte aligned)
+            let heap_start = self.string_data_len().next_multiple_of(4);
             globals.global(
                 GlobalType {
                     val_type: ValType::I32,
                     mutable: true,
                     shared: false,
                 },
-                &ConstExpr::i32_const(0),
+                &ConstExpr::i32_const(heap_start as i32),
             );
-            Some(globals)
-        } else {
-            None
         }
+        if let Some(fuel) = self.fuel {
+            globals.global(
+                GlobalType {
+                    val_type: ValType::I64,
+                    mutable: true,
+                    shared: false,
+                },
+                &ConstExpr::i64_const(i64::try_from(fuel).unwrap_or(i64::MAX)),
+            );
+        }
+        (!globals.is_empty()).then_some(globals)
     }
 
     /// Emit export section if needed
-    /// Complexity: 2 (Toyota Way: <10 ✓)
+    /// Complexity: 5 (Toyota Way: <10 ✓)
+    ///
+    /// Memory is exported so hosts can read printed strings
     fn emit_export_section(&self, expr: &Expr) -> Option<ExportSection> {
+        let mut exports = ExportSection::new();
         if utils::has_main_function(expr) {
-            let mut exports = ExportSection::new();
-            exports.export("main", wasm_encoder::ExportKind::Func, 0);
-            Some(exports)
-        } else {
-            None
+            // Imported host functions come first, so `main` is not always 0
+            let main = self
+                .functions
+                .borrow()
+                .get("main")
+                .map_or(0, |&(index, _)| index);
+            exports.export("main", wasm_encoder::ExportKind::Func, main);
+        }
+        if self.has_memory(expr) {
+            exports.export(MEMORY_EXPORT_NAME, wasm_encoder::ExportKind::Memory, 0);
+        }
+        if self.fuel.is_some() {
+            exports.export(
+                FUEL_EXPORT_NAME,
+                wasm_encoder::ExportKind::Global,
+                self.fuel_global.get(),
+            );
         }
+        if self.start.get().is_some() {
+            exports.export(
+                START_EXPORT_NAME,
+                wasm_encoder::ExportKind::Func,
+                self.start_index(),
+            );
+        }
+        (!exports.is_empty()).then_some(exports)
     }
 
     /// Emit code section with compiled functions
-    /// Complexity: 8 (Toyota Way: <10 ✓)
+    /// Complexity: 4 (Toyota Way: <10 ✓)
+    ///
+    /// WASI shims come first. Runtime functions are added after the others
+    /// when lowering used them, and `_start` after those.
     fn emit_code_section(
         &self,
         expr: &Expr,
         func_defs: &[(String, Vec<crate::frontend::ast::Param>, Box<Expr>)],
     ) -> Result<CodeSection, String> {
         let mut codes = CodeSection::new();
-        let has_functions = !func_defs.is_empty();
-
-        if has_functions {
-            for (_name, _params, body) in func_defs {
-                let func = self.compile_function(body.as_ref())?;
-                codes.function(&func);
+        if self.wasi && utils::uses_builtins(expr) {
+            let alloc = self.runtime_function(RuntimeFunction::Alloc);
+            for shim in WasiShim::ALL {
+                codes.function(&shim.body(alloc));
             }
-            if let Some(main_expr) = self.get_non_function_code(expr) {
-                let func = self.compile_function(&main_expr)?;
-                codes.function(&func);
-            }
-        } else {
-            let func = self.compile_function(expr)?;
+        }
+        for (_name, params, body) in func_defs {
+            let func = self.compile_function(params, body.as_ref())?;
             codes.function(&func);
         }
+        if let Some(main_expr) = self.top_level_code(expr, func_defs) {
+            let func = self.compile_function(&[], &main_expr)?;
+            codes.function(&func);
+        }
+        if self.uses_runtime.get() {
+            for function in RuntimeFunction::ALL {
+                codes.function(&function.body(self.runtime_base.get()));
+            }
+        }
+        if let Some((entry, returns_value)) = self.start.get() {
+            codes.function(&wasi::start_body(entry, returns_value));
+        }
         Ok(codes)
     }
 
-    /// Compile a single function body
+    /// Emit the data section holding string literals
+    /// Complexity: 3 (Toyota Way: <10 ✓)
+    fn emit_data_section(&self) -> Result<Option<DataSection>, String> {
+        let strings = self.strings.borrow();
+        if strings.is_empty() {
+            return Ok(None);
+        }
+        let mut bytes = Vec::new();
+        for text in strings.iter() {
+            memory::encode_string(text, &mut bytes);
+        }
+        if bytes.len() > MEMORY_PAGE_SIZE as usize {
+            return Err(format!(
+                "String literals need {} bytes, more than the {MEMORY_PAGE_SIZE}-byte memory",
+                bytes.len()
+            ));
+        }
+        let mut data = DataSection::new();
+        data.active(0, &ConstExpr::i32_const(0), bytes);
+        Ok(Some(data))
+    }
+
+    /// Whether the module defines a memory: for allocations or string data
+    fn has_memory(&self, expr: &Expr) -> bool {
+        self.needs_memory(expr) || !self.strings.borrow().is_empty()
+    }
+
+    /// Total bytes of interned string literals, with their headers
+    fn string_data_len(&self) -> u32 {
+        self.strings
+            .borrow()
+            .iter()
+            .map(|s| memory::string_footprint(s))
+            .sum()
+    }
+
+    /// Place `text` in the data section, returning the `(address, length)`
+    /// of its header
+    /// Complexity: 3 (Toyota Way: <10 ✓)
+    fn intern_string(&self, text: &str) -> (u32, u32) {
+        let mut strings = self.strings.borrow_mut();
+        let mut address = 0;
+        for existing in strings.iter() {
+            if existing == text {
+                return (address, text.len() as u32);
+            }
+            address += memory::string_footprint(existing);
+        }
+        strings.push(text.to_string());
+        (address, text.len() as u32)
+    }
+
+    /// Emit the `name` section for imports, functions, parameters and locals
     /// Complexity: 6 (Toyota Way: <10 ✓)
-    fn compile_function(&self, body: &Expr) -> Result<Function, String> {
-        let locals = self.collect_local_types(body);
+    ///
+    /// Function indices follow `build_function_index_map`. Locals are named
+    /// by the symbol table index the lowering used for them, as recorded by
+    /// `compile_function`.
+    fn emit_name_section(
+        &self,
+        expr: &Expr,
+        func_defs: &[(String, Vec<crate::frontend::ast::Param>, Box<Expr>)],
+    ) -> NameSection {
+        let mut function_names = NameMap::new();
+        let mut local_names = IndirectNameMap::new();
+        let import_offset = self.import_offset(expr);
+        if import_offset > 0 && self.wasi {
+            for function in WasiImport::ALL {
+                function_names.append(function.index(), function.name());
+            }
+            for shim in WasiShim::ALL {
+                function_names.append(shim.index(), shim.name());
+            }
+        } else if import_offset > 0 {
+            for function in HostFunction::ALL {
+                function_names.append(function.index(), function.name());
+            }
+        }
+
+        let mut names: Vec<&str> = func_defs.iter().map(|(name, _, _)| name.as_str()).collect();
+        if self.top_level_code(expr, func_defs).is_some() {
+            names.push(TOP_LEVEL_FUNCTION_NAME);
+        }
+        let compiled = self.local_names.borrow();
+        for (i, (name, symbols)) in names.iter().zip(compiled.iter()).enumerate() {
+            let index = import_offset + i as u32;
+            function_names.append(index, name);
+            let mut locals = NameMap::new();
+            for (local, symbol) in symbols {
+                locals.append(*local, symbol);
+            }
+            local_names.append(index, &locals);
+        }
+        if self.uses_runtime.get() {
+            for function in RuntimeFunction::ALL {
+                function_names.append(self.runtime_base.get() + function.offset(), function.name());
+            }
+        }
+        if self.start.get().is_some() {
+            function_names.append(self.start_index(), START_EXPORT_NAME);
+        }
+
+        let mut section = NameSection::new();
+        section.functions(&function_names);
+        section.locals(&local_names);
+        section
+    }
+
+    /// Compile a single function body
+    /// Complexity: 3 (Toyota Way: <10 ✓)
+    ///
+    /// Parameters are the first locals, followed by the body's bindings and
+    /// the scratch local used by allocations
+    fn compile_function(&self, params: &[Param], body: &Expr) -> Result<Function, String> {
+        self.enter_function(params, body);
+        let locals = self.collect_local_types(body, params.len() as u32);
         let mut func = Function::new(locals);
-        let instructions = self.lower_expression(body)?;
+        let mut instructions = self.fuel_check();
+        instructions.extend(self.lower_expression(body)?);
         for instr in instructions {
             func.instruction(&instr);
         }
+        func.instruction(&Instruction::End);
+        self.local_names
+            .borrow_mut()
+            .push(self.symbols.borrow().local_names());
+        Ok(func)
+    }
 
-        // If expression produces value but function is void, drop it
-        if self.expression_produces_value(body) {
-            // Value producing expressions leave result on stack
-            // If function should return void, we need to drop it
-            // But we don't know here if this is a void function...
-            // This is handled by type section already
+    /// Reset the symbol table to the parameters and bindings of one function
+    /// Complexity: 3 (Toyota Way: <10 ✓)
+    fn enter_function(&self, params: &[Param], body: &Expr) {
+        self.symbols.borrow_mut().clear();
+        self.string_locals.borrow_mut().clear();
+        self.loops.borrow_mut().clear();
+        self.block_depth.set(0);
+        for param in params {
+            let name = param.name();
+            if utils::is_string_type(&param.ty) {
+                self.string_locals.borrow_mut().insert(name.clone());
+            }
+            self.symbols
+                .borrow_mut()
+                .insert(name, param_type(&param.ty));
         }
+        self.build_symbol_table(body);
+    }
 
-        func.instruction(&Instruction::End);
-        Ok(func)
+    /// Record the signatures of the user functions and of top-level code
+    /// Complexity: 4 (Toyota Way: <10 ✓)
+    ///
+    /// User functions are visited twice, so a call sees the result type of a
+    /// function defined after the caller.
+    fn build_signatures(
+        &self,
+        expr: &Expr,
+        func_defs: &[(String, Vec<crate::frontend::ast::Param>, Box<Expr>)],
+    ) {
+        self.signatures.borrow_mut().clear();
+        for _ in 0..2 {
+            for (name, params, body) in func_defs {
+                let signature = self.function_signature(params, body);
+                self.signatures.borrow_mut().insert(name.clone(), signature);
+            }
+        }
+        if let Some(main_expr) = self.top_level_code(expr, func_defs) {
+            let signature = self.function_signature(&[], &main_expr);
+            self.signatures
+                .borrow_mut()
+                .insert(TOP_LEVEL_FUNCTION_NAME.to_string(), signature);
+        }
+    }
+
+    /// Parameter and result types of a function
+    /// Complexity: 3 (Toyota Way: <10 ✓)
+    fn function_signature(&self, params: &[Param], body: &Expr) -> Signature {
+        self.enter_function(params, body);
+        let produces_value = self.expression_produces_value(body);
+        let result = if produces_value {
+            Some(self.infer_type(body))
+        } else {
+            // Only explicit `return value` statements produce the result
+            utils::has_return_with_value(body).then_some(WasmType::I32)
+        };
+        Signature {
+            params: params.iter().map(|param| param_type(&param.ty)).collect(),
+            result,
+            returns_string: produces_value && self.is_string(body),
+        }
+    }
+
+    /// Code outside function definitions, compiled into [`TOP_LEVEL_FUNCTION_NAME`]
+    fn top_level_code(
+        &self,
+        expr: &Expr,
+        func_defs: &[(String, Vec<crate::frontend::ast::Param>, Box<Expr>)],
+    ) -> Option<Expr> {
+        if func_defs.is_empty() {
+            Some(expr.clone())
+        } else {
+            self.get_non_function_code(expr)
+        }
     }
 
     /// Build symbol table by scanning expression tree for let bindings
@@ -423,6 +903,9 @@ impl WasmEmitter {
                 name, value, body, ..
             } => {
                 let value_ty = self.infer_type(value);
+                if self.is_string(value) {
+                    self.string_locals.borrow_mut().insert(name.clone());
+                }
                 self.symbols.borrow_mut().insert(name.clone(), value_ty);
                 self.build_symbol_table(body);
             }
@@ -460,6 +943,14 @@ impl WasmEmitter {
                 self.build_symbol_table(condition);
                 self.build_symbol_table(body);
             }
+            ExprKind::For { var, body, .. } => {
+                // The loop variable and the end of the range it counts to
+                self.symbols.borrow_mut().insert(var.clone(), WasmType::I32);
+                self.symbols
+                    .borrow_mut()
+                    .insert(range_end_local(var), WasmType::I32);
+                self.build_symbol_table(body);
+            }
             ExprKind::Match { expr, arms } => {
                 self.build_symbol_table(expr);
                 // Note: Pattern variables in match arms are NOT registered as locals in MVP
@@ -478,26 +969,30 @@ impl WasmEmitter {
     /// Collect all local variable types from expression tree
     /// Returns vector of (count, type) for WASM function locals section
     /// Complexity: 9 (within <10 limit)
-    fn collect_local_types(&self, expr: &Expr) -> Vec<(u32, wasm_encoder::ValType)> {
+    fn collect_local_types(&self, expr: &Expr, params: u32) -> Vec<(u32, wasm_encoder::ValType)> {
         let symbols = self.symbols.borrow();
         let local_count = symbols.local_count();
-        let needs_temp = utils::needs_memory(expr); // Need temp local for tuple allocation
+        // Need temp local for tuple allocation and for printing strings
+        let needs_temp = utils::needs_memory(expr) || utils::uses_builtins(expr);
 
-        if local_count == 0 && !needs_temp {
+        if local_count == params && !needs_temp {
             return vec![];
         }
 
-        // Collect all unique (type, index) pairs
-        let mut locals: Vec<(WasmType, u32)> = symbols.all_locals();
-
-        // Sort by index
-        locals.sort_by_key(|(_, index)| *index);
+        // One type per index after the parameters; a name bound twice keeps
+        // only its last index in the table, so earlier ones default to i32
+        let mut locals = vec![WasmType::I32; (local_count - params) as usize];
+        for (ty, index) in symbols.all_locals() {
+            if index >= params {
+                locals[(index - params) as usize] = ty;
+            }
+        }
 
         // Convert to (count, ValType) format
         // For now, just declare each local individually
         let mut result: Vec<(u32, wasm_encoder::ValType)> = locals
             .into_iter()
-            .map(|(ty, _)| {
+            .map(|ty| {
                 let val_type = match ty {
                     WasmType::I32 => wasm_encoder::ValType::I32,
                     WasmType::F32 => wasm_encoder::ValType::F32,
@@ -542,7 +1037,7 @@ impl WasmEmitter {
     }
 
     /// Infer the WASM type of an expression
-    /// Complexity: 7 (Toyota Way: <10 ✓)
+    /// Complexity: 9 (Toyota Way: <10 ✓)
     fn infer_type(&self, expr: &Expr) -> WasmType {
         match &expr.kind {
             ExprKind::Literal(Literal::Integer(_, _)) => WasmType::I32,
@@ -552,7 +1047,21 @@ impl WasmEmitter {
             ExprKind::Let { body, .. } => self.infer_let_type(body),
             ExprKind::Identifier(name) => self.infer_identifier_type(name),
             ExprKind::Block(exprs) => exprs.last().map_or(WasmType::I32, |e| self.infer_type(e)),
-            ExprKind::Call { .. } => WasmType::I32,
+            ExprKind::Call { func, .. } => match &func.kind {
+                ExprKind::Identifier(name)
+                    if self.host_builtin(name) == Some(HostFunction::Random) =>
+                {
+                    WasmType::F32
+                }
+                ExprKind::Identifier(name) => self
+                    .signatures
+                    .borrow()
+                    .get(name)
+                    .and_then(|signature| signature.result)
+                    .unwrap_or(WasmType::I32),
+                _ => WasmType::I32,
+            },
+            ExprKind::If { then_branch, .. } => self.infer_type(then_branch),
             ExprKind::Unary { operand, .. } => self.infer_type(operand),
             ExprKind::FieldAccess { object, field } => {
                 // For tuple field access, look up the element type
@@ -636,6 +1145,9 @@ impl WasmEmitter {
         left: &Expr,
         right: &Expr,
     ) -> Result<Vec<Instruction<'static>>, String> {
+        if matches!(op, BinaryOp::Add) && (self.is_string(left) || self.is_string(right)) {
+            return self.lower_concat(left, right);
+        }
         let mut instructions = vec![];
 
         // Infer result type based on operands
@@ -659,6 +1171,66 @@ impl WasmEmitter {
         Ok(instructions)
     }
 
+    /// Lower `left + right` on strings to a call of the runtime concatenation
+    /// Complexity: 3 (Toyota Way: <10 ✓)
+    fn lower_concat(&self, left: &Expr, right: &Expr) -> Result<Vec<Instruction<'static>>, String> {
+        if !(self.is_string(left) && self.is_string(right)) {
+            return Err("Only strings can be added to strings in WASM".to_string());
+        }
+        let mut instructions = self.lower_expression(left)?;
+        instructions.extend(self.lower_expression(right)?);
+        instructions.push(Instruction::Call(
+            self.runtime_function(RuntimeFunction::Concat),
+        ));
+        Ok(instructions)
+    }
+
+    /// Index of a runtime function, which is emitted because it is used
+    fn runtime_function(&self, function: RuntimeFunction) -> u32 {
+        self.uses_runtime.set(true);
+        self.runtime_base.get() + function.offset()
+    }
+
+    /// Whether `expr` evaluates to the address of a string
+    /// Complexity: 9 (Toyota Way: <10 ✓)
+    fn is_string(&self, expr: &Expr) -> bool {
+        match &expr.kind {
+            ExprKind::Literal(Literal::String(_)) => true,
+            ExprKind::StringInterpolation { parts } => self.are_strings(parts),
+            ExprKind::Identifier(name) => self.string_locals.borrow().contains(name),
+            ExprKind::Binary {
+                op: BinaryOp::Add,
+                left,
+                right,
+            } => self.is_string(left) || self.is_string(right),
+            ExprKind::Call { func, .. } => match &func.kind {
+                ExprKind::Identifier(name) => {
+                    self.is_read_file(name)
+                        || self
+                            .signatures
+                            .borrow()
+                            .get(name)
+                            .is_some_and(|signature| signature.returns_string)
+                }
+                _ => false,
+            },
+            ExprKind::Block(exprs) => exprs.last().is_some_and(|e| self.is_string(e)),
+            ExprKind::Let { body, .. } => self.is_string(body),
+            ExprKind::If { then_branch, .. } => self.is_string(then_branch),
+            _ => false,
+        }
+    }
+
+    /// Whether every expression interpolated by `parts` is a string
+    fn are_strings(&self, parts: &[StringPart]) -> bool {
+        parts.iter().all(|part| match part {
+            StringPart::Text(_) => true,
+            StringPart::Expr(expr) | StringPart::ExprWithFormat { expr, .. } => {
+                self.is_string(expr)
+            }
+        })
+    }
+
     /// Infer result type for binary operation
     /// Complexity: 2 (Toyota Way: <10 ✓)
     fn infer_binary_result_type(&self, left: &Expr, right: &Expr) -> WasmType {
@@ -708,7 +1280,10 @@ impl WasmEmitter {
     }
 
     /// Lower an if expression to WASM instructions
-    /// Complexity: 4 (Toyota Way: <10 ✓)
+    /// Complexity: 6 (Toyota Way: <10 ✓)
+    ///
+    /// The if has a result only when both branches produce one; otherwise a
+    /// branch's value is dropped, so the if can be used as a statement
     fn lower_if(
         &self,
         condition: &Expr,
@@ -721,8 +1296,10 @@ impl WasmEmitter {
         instructions.extend(self.lower_expression(condition)?);
 
         // Determine block type based on whether branches produce values
-        let block_type = if self.expression_produces_value(then_branch) {
-            wasm_encoder::BlockType::Result(wasm_encoder::ValType::I32)
+        let produces_value = self.expression_produces_value(then_branch)
+            && else_branch.is_some_and(|e| self.expression_produces_value(e));
+        let block_type = if produces_value {
+            wasm_encoder::BlockType::Result(self.infer_wasm_type(then_branch))
         } else {
             wasm_encoder::BlockType::Empty
         };
@@ -731,16 +1308,12 @@ impl WasmEmitter {
         instructions.push(Instruction::If(block_type));
 
         // Then branch
-        instructions.extend(self.lower_expression(then_branch)?);
+        instructions.extend(self.lower_branch(then_branch, produces_value)?);
 
         // Else branch (if present)
         if let Some(else_expr) = else_branch {
             instructions.push(Instruction::Else);
-            instructions.extend(self.lower_expression(else_expr)?);
-        } else if self.expression_produces_value(then_branch) {
-            // If no else branch but we expect a value, push a default
-            instructions.push(Instruction::Else);
-            instructions.push(Instruction::I32Const(0));
+            instructions.extend(self.lower_branch(else_expr, produces_value)?);
         }
 
         // End if
@@ -748,6 +1321,30 @@ impl WasmEmitter {
         Ok(instructions)
     }
 
+    /// Lower a branch of an if, dropping its value unless the if has a result
+    /// Complexity: 3 (Toyota Way: <10 ✓)
+    fn lower_branch(
+        &self,
+        branch: &Expr,
+        keep_value: bool,
+    ) -> Result<Vec<Instruction<'static>>, String> {
+        let mut instructions = self.lower_nested(branch, 1)?;
+        if !keep_value && self.expression_produces_value(branch) {
+            instructions.push(Instruction::Drop);
+        }
+        Ok(instructions)
+    }
+
+    /// Lower `expr` inside `levels` more blocks, so branches out of it count them
+    /// Complexity: 1 (Toyota Way: <10 ✓)
+    fn lower_nested(&self, expr: &Expr, levels: u32) -> Result<Vec<Instruction<'static>>, String> {
+        let depth = self.block_depth.get();
+        self.block_depth.set(depth + levels);
+        let instructions = self.lower_expression(expr);
+        self.block_depth.set(depth);
+        instructions
+    }
+
     /// Lower a unary operation to WASM instructions
     /// Complexity: 6 (Toyota Way: <10 ✓)
     fn lower_unary(
@@ -834,8 +1431,24 @@ impl WasmEmitter {
                 else_branch,
             } => self.lower_if(condition, then_branch, else_branch.as_deref()),
             ExprKind::While {
-                condition, body, ..
-            } => self.lower_while(condition, body),
+                label,
+                condition,
+                body,
+            } => self.lower_while(label.as_ref(), condition, body),
+            ExprKind::For {
+                label,
+                var,
+                iter,
+                body,
+                ..
+            } => self.lower_for(label.as_ref(), var, iter, body),
+            ExprKind::Break { label, value } => {
+                if value.is_some() {
+                    return Err("break with a value is not supported in WASM".to_string());
+                }
+                self.lower_loop_exit(label.as_ref(), true)
+            }
+            ExprKind::Continue { label } => self.lower_loop_exit(label.as_ref(), false),
             ExprKind::Function { .. } => Ok(vec![]),
             ExprKind::Lambda { .. } => Ok(vec![]), // Lambda definitions generate no instructions
             ExprKind::Call { func, args } => self.lower_call(func, args),
@@ -859,91 +1472,332 @@ impl WasmEmitter {
             ExprKind::StructLiteral { name, fields, .. } => self.lower_struct_literal(name, fields),
             ExprKind::IndexAccess { object, index } => self.lower_index_access(object, index),
             ExprKind::Assign { target, value } => self.lower_assign(target, value),
+            ExprKind::CompoundAssign { target, op, value } => {
+                // `x += v` is `x = x + v`
+                let sum = Expr::new(
+                    ExprKind::Binary {
+                        op: *op,
+                        left: target.clone(),
+                        right: value.clone(),
+                    },
+                    expr.span,
+                );
+                self.lower_assign(target, &sum)
+            }
+            ExprKind::MethodCall {
+                receiver,
+                method,
+                args,
+            } if method == "len" && args.is_empty() && self.is_string(receiver) => {
+                // The length is the string's header
+                let mut instructions = self.lower_expression(receiver)?;
+                instructions.push(Instruction::I32Load(wasm_encoder::MemArg {
+                    offset: 0,
+                    align: 2,
+                    memory_index: 0,
+                }));
+                Ok(instructions)
+            }
             _ => Ok(vec![]),
         }
     }
 
     /// Lower a while loop to WASM instructions
-    /// Complexity: 4 (Toyota Way: <10 ✓)
+    /// Complexity: 2 (Toyota Way: <10 ✓)
+    ///
+    /// `block { loop { br_if (!condition) 1; body; br 0 } }`: `break`
+    /// leaves the block, `continue` restarts the loop
     fn lower_while(
         &self,
+        label: Option<&String>,
         condition: &Expr,
         body: &Expr,
     ) -> Result<Vec<Instruction<'static>>, String> {
-        let mut instructions = vec![];
-        instructions.push(Instruction::Loop(wasm_encoder::BlockType::Empty));
-        instructions.extend(self.lower_expression(condition)?);
+        let depth = self.block_depth.get();
+        let mut instructions = vec![
+            Instruction::Block(wasm_encoder::BlockType::Empty),
+            Instruction::Loop(wasm_encoder::BlockType::Empty),
+        ];
+        instructions.extend(self.fuel_check());
+        instructions.extend(self.lower_nested(condition, 2)?);
         instructions.push(Instruction::I32Eqz);
         instructions.push(Instruction::BrIf(1));
-        instructions.extend(self.lower_expression(body)?);
+        let labels = LoopLabels {
+            label: label.cloned(),
+            exit: depth + 1,
+            next: depth + 2,
+        };
+        instructions.extend(self.lower_loop_body(body, labels, 2)?);
         instructions.push(Instruction::Br(0));
         instructions.push(Instruction::End);
+        instructions.push(Instruction::End);
+        Ok(instructions)
+    }
+
+    /// Lower a `for` loop over a range to WASM instructions
+    /// Complexity: 5 (Toyota Way: <10 ✓)
+    ///
+    /// The loop variable counts from the start of the range to its end,
+    /// which is evaluated once into a hidden local:
+    /// `block { loop { br_if (var >= end) 1; block { body }; var += 1; br 0 } }`.
+    /// `break` leaves the outer block, `continue` the inner one.
+    fn lower_for(
+        &self,
+        label: Option<&String>,
+        var: &str,
+        iter: &Expr,
+        body: &Expr,
+    ) -> Result<Vec<Instruction<'static>>, String> {
+        let ExprKind::Range {
+            start,
+            end,
+            inclusive,
+        } = &iter.kind
+        else {
+            return Err("for loops in WASM iterate over ranges only".to_string());
+        };
+        let local = |name: &str| {
+            self.symbols
+                .borrow()
+                .lookup_index(name)
+                .ok_or_else(|| format!("Unknown loop variable: {name}"))
+        };
+        let (index, end_index) = (local(var)?, local(&range_end_local(var))?);
+        let depth = self.block_depth.get();
+
+        let mut instructions = self.lower_expression(start)?;
+        instructions.push(Instruction::LocalSet(index));
+        instructions.extend(self.lower_expression(end)?);
+        instructions.push(Instruction::LocalSet(end_index));
+        instructions.push(Instruction::Block(wasm_encoder::BlockType::Empty));
+        instructions.push(Instruction::Loop(wasm_encoder::BlockType::Empty));
+        instructions.extend(self.fuel_check());
+        instructions.extend([
+            Instruction::LocalGet(index),
+            Instruction::LocalGet(end_index),
+            if *inclusive {
+                Instruction::I32GtS
+            } else {
+                Instruction::I32GeS
+            },
+            Instruction::BrIf(1),
+            Instruction::Block(wasm_encoder::BlockType::Empty),
+        ]);
+        let labels = LoopLabels {
+            label: label.cloned(),
+            exit: depth + 1,
+            next: depth + 3,
+        };
+        instructions.extend(self.lower_loop_body(body, labels, 3)?);
+        instructions.extend([
+            Instruction::End,
+            Instruction::LocalGet(index),
+            Instruction::I32Const(1),
+            Instruction::I32Add,
+            Instruction::LocalSet(index),
+            Instruction::Br(0),
+            Instruction::End,
+            Instruction::End,
+        ]);
+        Ok(instructions)
+    }
+
+    /// Lower a loop body `levels` blocks deep, with `labels` as its branch targets
+    /// Complexity: 3 (Toyota Way: <10 ✓)
+    fn lower_loop_body(
+        &self,
+        body: &Expr,
+        labels: LoopLabels,
+        levels: u32,
+    ) -> Result<Vec<Instruction<'static>>, String> {
+        self.loops.borrow_mut().push(labels);
+        let instructions = self.lower_nested(body, levels);
+        self.loops.borrow_mut().pop();
+        let mut instructions = instructions?;
+        if self.expression_produces_value(body) {
+            instructions.push(Instruction::Drop);
+        }
         Ok(instructions)
     }
 
+    /// Lower `break` or `continue` to a branch out of the blocks of the loop
+    /// Complexity: 5 (Toyota Way: <10 ✓)
+    fn lower_loop_exit(
+        &self,
+        label: Option<&String>,
+        is_break: bool,
+    ) -> Result<Vec<Instruction<'static>>, String> {
+        let loops = self.loops.borrow();
+        let target = match label {
+            Some(label) => loops
+                .iter()
+                .rev()
+                .find(|labels| labels.label.as_ref() == Some(label)),
+            None => loops.last(),
+        };
+        let Some(target) = target else {
+            return Err(match label {
+                Some(label) => format!("Unknown loop label: {label}"),
+                None => "break and continue must be inside a loop".to_string(),
+            });
+        };
+        let block = if is_break { target.exit } else { target.next };
+        Ok(vec![Instruction::Br(self.block_depth.get() - block)])
+    }
+
+    /// Spend one unit of fuel, trapping if none is left; empty when unmetered
+    /// Complexity: 2 (Toyota Way: <10 ✓)
+    fn fuel_check(&self) -> Vec<Instruction<'static>> {
+        if self.fuel.is_none() {
+            return vec![];
+        }
+        let fuel = self.fuel_global.get();
+        vec![
+            Instruction::GlobalGet(fuel),
+            Instruction::I64Eqz,
+            Instruction::If(wasm_encoder::BlockType::Empty),
+            Instruction::Unreachable,
+            Instruction::End,
+            Instruction::GlobalGet(fuel),
+            Instruction::I64Const(1),
+            Instruction::I64Sub,
+            Instruction::GlobalSet(fuel),
+        ]
+    }
+
     /// Lower a function call to WASM instructions
-    /// Complexity: 8 (Toyota Way: <10 ✓)
+    /// Complexity: 6 (Toyota Way: <10 ✓)
     fn lower_call(&self, func: &Expr, args: &[Expr]) -> Result<Vec<Instruction<'static>>, String> {
+        let ExprKind::Identifier(name) = &func.kind else {
+            // Non-identifier function (should not happen in normal code)
+            return Err("Function calls must use identifiers".to_string());
+        };
+        if host::is_print(name) {
+            return self.lower_print(name, args);
+        }
+        if let Some(function) = self.host_builtin(name) {
+            if !args.is_empty() {
+                return Err(format!("{name}() takes no arguments"));
+            }
+            return Ok(vec![Instruction::Call(self.host_function(function))]);
+        }
+        if self.is_read_file(name) {
+            return self.lower_read_file(args);
+        }
+
+        // Regular function call - push all arguments
         let mut instructions = vec![];
+        for arg in args {
+            instructions.extend(self.lower_expression(arg)?);
+        }
+        // Look up user-defined function index (extract index from tuple)
+        let func_index = self
+            .functions
+            .borrow()
+            .get(name)
+            .map(|&(idx, _)| idx)
+            .ok_or_else(|| format!("Unknown function: {name}"))?;
+        instructions.push(Instruction::Call(func_index));
+        Ok(instructions)
+    }
 
-        // Determine function index and handle built-in functions specially
-        let func_index = if let ExprKind::Identifier(name) = &func.kind {
-            if matches!(name.as_str(), "println" | "print" | "eprintln" | "eprint") {
-                // Built-in print functions only take one argument in WASM
-                // Find the first non-string argument to print
-                // Function index 0: println_i32 - (i32) -> ()
-                // Function index 1: println_f32 - (f32) -> ()
-                let mut selected_arg: Option<&Expr> = None;
-                let mut func_idx = 0u32;
-
-                for arg in args {
-                    // Skip string literals (format strings)
-                    if matches!(&arg.kind, ExprKind::Literal(Literal::String(_))) {
-                        continue;
-                    }
-                    // Skip string interpolations (format strings)
-                    if matches!(&arg.kind, ExprKind::StringInterpolation { .. }) {
-                        continue;
-                    }
-                    selected_arg = Some(arg);
-                    let arg_type = self.infer_type(arg);
-                    func_idx = match arg_type {
-                        WasmType::F32 => 1, // Use println_f32
-                        _ => 0,             // Use println_i32 (default)
-                    };
-                    break;
-                }
+    /// Host function behind a builtin call, unless a user function shadows it
+    fn host_builtin(&self, name: &str) -> Option<HostFunction> {
+        HostFunction::for_builtin(name).filter(|_| !self.functions.borrow().contains_key(name))
+    }
 
-                // Push only the selected argument
-                if let Some(arg) = selected_arg {
-                    instructions.extend(self.lower_expression(arg)?);
-                } else if let Some(first_arg) = args.first() {
-                    // No non-string arg found, use first arg (handles println("hello"))
-                    instructions.extend(self.lower_expression(first_arg)?);
-                }
+    /// Whether a call to `name` is the `read_file` builtin, not a user function
+    fn is_read_file(&self, name: &str) -> bool {
+        name == wasi::READ_FILE_BUILTIN && !self.functions.borrow().contains_key(name)
+    }
 
-                func_idx
-            } else {
-                // Regular function call - push all arguments
-                for arg in args {
-                    instructions.extend(self.lower_expression(arg)?);
-                }
-                // Look up user-defined function index (extract index from tuple)
-                self.functions
-                    .borrow()
-                    .get(name)
-                    .map(|&(idx, _)| idx)
-                    .ok_or_else(|| format!("Unknown function: {name}"))?
-            }
+    /// Lower `read_file(path)` to a call of its WASI shim
+    /// Complexity: 3 (Toyota Way: <10 ✓)
+    fn lower_read_file(&self, args: &[Expr]) -> Result<Vec<Instruction<'static>>, String> {
+        if !self.wasi {
+            return Err("read_file() needs the WASI target (--target wasi)".to_string());
+        }
+        let [path] = args else {
+            return Err("read_file() takes one argument, the path".to_string());
+        };
+        if !self.is_string(path) {
+            return Err("read_file() takes a string path".to_string());
+        }
+        let mut instructions = self.lower_expression(path)?;
+        instructions.push(Instruction::Call(WasiShim::ReadFile.index()));
+        Ok(instructions)
+    }
+
+    /// File descriptor argument of a print in WASI modules, which host prints do not take
+    fn print_stream(&self, name: &str) -> Vec<Instruction<'static>> {
+        if self.wasi {
+            vec![Instruction::I32Const(wasi::stream(name))]
         } else {
-            // Non-identifier function (should not happen in normal code)
-            for arg in args {
-                instructions.extend(self.lower_expression(arg)?);
-            }
-            return Err("Function calls must use identifiers".to_string());
+            Vec::new()
+        }
+    }
+
+    /// Lower a print builtin to a single `ruchy_println_*` host call
+    /// Complexity: 8 (Toyota Way: <10 ✓)
+    ///
+    /// Built-in print functions print one value in WASM: the first argument
+    /// that is not a string literal or interpolation, or else the first
+    /// argument when it is a string
+    fn lower_print(&self, name: &str, args: &[Expr]) -> Result<Vec<Instruction<'static>>, String> {
+        let value = args.iter().find(|arg| {
+            // Skip string literals and interpolations (format strings)
+            !matches!(
+                &arg.kind,
+                ExprKind::Literal(Literal::String(_)) | ExprKind::StringInterpolation { .. }
+            )
+        });
+        let mut instructions = self.print_stream(name);
+        let function = if let Some(arg) = value.filter(|arg| self.is_string(arg)) {
+            return self.lower_print_string(name, arg);
+        } else if let Some(arg) = value {
+            instructions.extend(self.lower_expression(arg)?);
+            match self.infer_type(arg) {
+                WasmType::F32 => HostFunction::PrintlnF32,
+                _ => HostFunction::PrintlnI32,
+            }
+        } else if let Some(text) = args.first().map_or(Some(String::new()), literal_text) {
+            let (address, len) = self.intern_string(&text);
+            instructions.push(Instruction::I32Const((address + STRING_HEADER_SIZE) as i32));
+            instructions.push(Instruction::I32Const(len as i32));
+            HostFunction::PrintlnStr
+        } else if self.is_string(&args[0]) {
+            return self.lower_print_string(name, &args[0]);
+        } else {
+            // Interpolation with expressions: print its first expression
+            instructions.extend(self.lower_expression(&args[0])?);
+            HostFunction::PrintlnI32
         };
+        instructions.push(Instruction::Call(self.host_function(function)));
+        Ok(instructions)
+    }
 
-        instructions.push(Instruction::Call(func_index));
+    /// Print a string value: its text starts after the header holding its length
+    /// Complexity: 1 (Toyota Way: <10 ✓)
+    fn lower_print_string(
+        &self,
+        name: &str,
+        arg: &Expr,
+    ) -> Result<Vec<Instruction<'static>>, String> {
+        let temp_local = self.symbols.borrow().local_count();
+        let mut instructions = self.print_stream(name);
+        instructions.extend(self.lower_expression(arg)?);
+        instructions.extend([
+            Instruction::LocalTee(temp_local),
+            Instruction::I32Const(STRING_HEADER_SIZE as i32),
+            Instruction::I32Add,
+            Instruction::LocalGet(temp_local),
+            Instruction::I32Load(wasm_encoder::MemArg {
+                offset: 0,
+                align: 2,
+                memory_index: 0,
+            }),
+            Instruction::Call(self.host_function(HostFunction::PrintlnStr)),
+        ]);
         Ok(instructions)
     }
 
@@ -1465,14 +2319,15 @@ impl WasmEmitter {
     }
 
     /// Lower string interpolation to WASM instructions
-    /// Complexity: 8 (Toyota Way: <10 ✓)
+    /// Complexity: 9 (Toyota Way: <10 ✓)
     ///
     /// Current implementation: MVP string interpolation support
     /// - Text-only f-strings: concatenated into single string literal
-    /// - F-strings with expressions: evaluated and represented as i32 (memory pointer)
+    /// - F-strings interpolating only strings: concatenated at run time
+    /// - Other f-strings with expressions: evaluated and represented as i32
     ///
     /// WASM strings are represented as i32 pointers to linear memory.
-    /// Full string concatenation requires host function support (`string_concat`).
+    /// Formatting numbers into strings is not supported yet.
     /// This is implemented in stages per `docs/specifications/wasm-fstring-spec.md`
     fn lower_string_interpolation(
         &self,
@@ -1496,6 +2351,8 @@ impl WasmEmitter {
 
             // Lower as a string literal (i32 memory pointer)
             self.lower_literal(&Literal::String(text))
+        } else if self.are_strings(parts) {
+            self.lower_string_parts(parts)
         } else {
             // Stage 2: F-strings with expressions
             // Strategy: For single-expression f-strings, evaluate and return the expression value
@@ -1522,6 +2379,29 @@ impl WasmEmitter {
         }
     }
 
+    /// Concatenate the parts of an interpolation whose expressions are strings
+    /// Complexity: 3 (Toyota Way: <10 ✓)
+    fn lower_string_parts(
+        &self,
+        parts: &[StringPart],
+    ) -> Result<Vec<Instruction<'static>>, String> {
+        let mut instructions = vec![];
+        for (i, part) in parts.iter().enumerate() {
+            instructions.extend(match part {
+                StringPart::Text(text) => self.lower_literal(&Literal::String(text.clone()))?,
+                StringPart::Expr(expr) | StringPart::ExprWithFormat { expr, .. } => {
+                    self.lower_expression(expr)?
+                }
+            });
+            if i > 0 {
+                instructions.push(Instruction::Call(
+                    self.runtime_function(RuntimeFunction::Concat),
+                ));
+            }
+        }
+        Ok(instructions)
+    }
+
     /// Lower match expression to WASM instructions
     /// Complexity: 9 (Toyota Way: <10 ✓)
     ///
@@ -1551,16 +2431,18 @@ impl WasmEmitter {
         // Process arms in reverse to build nested if-else
         for (i, arm) in arms.iter().enumerate().rev() {
             let is_last = i == arms.len() - 1;
+            // Inside the else branches of the earlier arms' ifs
+            let levels = i as u32;
 
             match &arm.pattern {
                 Pattern::Wildcard => {
                     // Wildcard: just emit the body
-                    result_instructions = self.lower_expression(&arm.body)?;
+                    result_instructions = self.lower_nested(&arm.body, levels)?;
                 }
                 Pattern::Literal(lit) => {
                     if is_last {
                         // Last arm without wildcard: just emit body
-                        result_instructions = self.lower_expression(&arm.body)?;
+                        result_instructions = self.lower_nested(&arm.body, levels)?;
                     } else {
                         // Compare match_expr with pattern literal
                         let mut instr = vec![];
@@ -1569,7 +2451,7 @@ impl WasmEmitter {
                         instr.push(Instruction::I32Eq);
 
                         // if (condition) { arm.body } else { rest }
-                        let then_body = self.lower_expression(&arm.body)?;
+                        let then_body = self.lower_nested(&arm.body, levels + 1)?;
                         let else_body = result_instructions;
 
                         // Determine result type from body
@@ -1589,7 +2471,7 @@ impl WasmEmitter {
                 Pattern::Or(patterns) => {
                     if is_last {
                         // Last arm: just emit body
-                        result_instructions = self.lower_expression(&arm.body)?;
+                        result_instructions = self.lower_nested(&arm.body, levels)?;
                     } else {
                         // OR pattern: match_expr == pat1 || match_expr == pat2 || ...
                         let mut instr = vec![];
@@ -1615,7 +2497,7 @@ impl WasmEmitter {
                         }
 
                         // if (any match) { arm.body } else { rest }
-                        let then_body = self.lower_expression(&arm.body)?;
+                        let then_body = self.lower_nested(&arm.body, levels + 1)?;
                         let else_body = result_instructions;
                         let result_type = self.infer_wasm_type(&arm.body);
 
@@ -1634,16 +2516,16 @@ impl WasmEmitter {
                     // MVP: Tuple patterns in match always succeed (placeholder)
                     // Full implementation would destructure and compare tuple elements
                     if is_last {
-                        result_instructions = self.lower_expression(&arm.body)?;
+                        result_instructions = self.lower_nested(&arm.body, levels)?;
                     } else {
                         // For now, treat tuple patterns as "always match" (like wildcard)
                         // This allows code to compile but doesn't do proper pattern matching
-                        result_instructions = self.lower_expression(&arm.body)?;
+                        result_instructions = self.lower_nested(&arm.body, levels)?;
                     }
                 }
                 Pattern::Identifier(_name) => {
                     // Identifier pattern: binds the value to a variable (always matches)
-                    result_instructions = self.lower_expression(&arm.body)?;
+                    result_instructions = self.lower_nested(&arm.body, levels)?;
                 }
                 _ => {
                     // Other patterns not yet supported in MVP
@@ -1662,7 +2544,8 @@ impl WasmEmitter {
     /// Complexity: 3 (Toyota Way: <10 ✓)
     ///
     /// Function indices in WASM:
-    /// - Import functions come first (e.g., println at index 0 if imports exist)
+    /// - Imported host functions come first (see [`super::host`]), or for
+    ///   WASI the imports and the shims replacing the host (see [`super::wasi`])
     /// - User-defined functions follow
     fn build_function_index_map(
         &self,
@@ -1671,8 +2554,9 @@ impl WasmEmitter {
     ) {
         let mut index_map = std::collections::HashMap::new();
 
-        // Calculate offset: imports come first (2 built-ins: println_i32 and println_f32)
-        let import_offset = if utils::uses_builtins(expr) { 2 } else { 0 };
+        // Calculate offset: imports come first (the host functions, or the
+        // WASI imports and shims)
+        let import_offset = self.import_offset(expr);
 
         // Map each user function to (index, is_void)
         for (i, (name, _, body)) in func_defs.iter().enumerate() {
@@ -1781,9 +2665,12 @@ impl WasmEmitter {
                 // Check if this is a void function
                 if let ExprKind::Identifier(name) = &func.kind {
                     // Built-in void functions
-                    if matches!(name.as_str(), "println" | "print" | "eprintln" | "eprint") {
+                    if host::is_print(name) {
                         return false;
                     }
+                    if let Some(signature) = self.signatures.borrow().get(name) {
+                        return signature.result.is_some();
+                    }
                     // User-defined functions - check registry
                     if let Some(&(_idx, is_void)) = self.functions.borrow().get(name) {
                         return !is_void;
@@ -1794,7 +2681,10 @@ impl WasmEmitter {
                     true
                 }
             }
-            ExprKind::List(_) => true,
+            ExprKind::List(_) | ExprKind::StringInterpolation { .. } => true,
+            ExprKind::MethodCall {
+                receiver, method, ..
+            } => method == "len" && self.is_string(receiver),
             ExprKind::Block(exprs) => {
                 // Block produces value if last expression does
                 exprs
@@ -1821,7 +2711,8 @@ impl WasmEmitter {
                 }
             }
             ExprKind::Return { .. } => false, // Return doesn't leave value on stack
-            ExprKind::While { .. } => false,  // Loops are void
+            ExprKind::While { .. } | ExprKind::For { .. } => false, // Loops are void
+            ExprKind::Break { .. } | ExprKind::Continue { .. } => false,
             ExprKind::Function { .. } => false, // Function definitions don't produce values
             _ => false,
         }
@@ -1831,10 +2722,10 @@ impl WasmEmitter {
             Literal::Integer(n, _) => Ok(vec![Instruction::I32Const(*n as i32)]),
             Literal::Float(f) => Ok(vec![Instruction::F32Const(*f as f32)]),
             Literal::Bool(b) => Ok(vec![Instruction::I32Const(i32::from(*b))]),
-            Literal::String(_) => {
-                // String literals would need memory allocation
-                // For now, return a placeholder
-                Ok(vec![Instruction::I32Const(0)])
+            Literal::String(text) => {
+                // Address of the literal's header in the data section
+                let (address, _) = self.intern_string(text);
+                Ok(vec![Instruction::I32Const(address as i32)])
             }
             Literal::Unit => {
                 // Unit type () is represented as i32 const 0 in WASM
@@ -1848,6 +2739,35 @@ impl WasmEmitter {
         }
     }
 }
+/// WASM type of a parameter: floats are `f32`, like float literals, and
+/// everything else (integers, booleans, string addresses) is `i32`
+fn param_type(ty: &Type) -> WasmType {
+    match &ty.kind {
+        TypeKind::Named(name) if matches!(name.as_str(), "f32" | "f64" | "float") => WasmType::F32,
+        _ => WasmType::I32,
+    }
+}
+
+/// Hidden local holding the end of the range a `for` loop variable counts to
+fn range_end_local(var: &str) -> String {
+    format!("{var}.end")
+}
+
+/// Text of a string literal, or of an interpolation with no expressions
+fn literal_text(expr: &Expr) -> Option<String> {
+    match &expr.kind {
+        ExprKind::Literal(Literal::String(text)) => Some(text.clone()),
+        ExprKind::StringInterpolation { parts } => parts
+            .iter()
+            .map(|part| match part {
+                StringPart::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
+
 impl Default for WasmEmitter {
     fn default() -> Self {
         Self::new()
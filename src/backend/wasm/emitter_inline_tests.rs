@@ -1140,6 +1140,8 @@ fn test_lower_match_wildcard_only() {
         guard: None,
         body: Box::new(int_expr(42)),
         span: span(),
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let result = emitter.lower_match(&match_expr, &arms);
     assert!(result.is_ok());
@@ -1156,6 +1158,8 @@ fn test_lower_match_literal_single_arm() {
         guard: None,
         body: Box::new(int_expr(10)),
         span: span(),
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let result = emitter.lower_match(&match_expr, &arms);
     assert!(result.is_ok());
@@ -1171,12 +1175,16 @@ fn test_lower_match_literal_with_wildcard() {
             guard: None,
             body: Box::new(int_expr(10)),
             span: span(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         crate::frontend::ast::MatchArm {
             pattern: Pattern::Wildcard,
             guard: None,
             body: Box::new(int_expr(0)),
             span: span(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
     ];
     let result = emitter.lower_match(&match_expr, &arms);
@@ -1195,18 +1203,24 @@ fn test_lower_match_multiple_literal_arms() {
             guard: None,
             body: Box::new(int_expr(10)),
             span: span(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         crate::frontend::ast::MatchArm {
             pattern: Pattern::Literal(Literal::Integer(2, None)),
             guard: None,
             body: Box::new(int_expr(20)),
             span: span(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         crate::frontend::ast::MatchArm {
             pattern: Pattern::Wildcard,
             guard: None,
             body: Box::new(int_expr(0)),
             span: span(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
     ];
     let result = emitter.lower_match(&match_expr, &arms);
@@ -1228,12 +1242,16 @@ fn test_lower_match_or_pattern() {
             guard: None,
             body: Box::new(int_expr(100)),
             span: span(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         crate::frontend::ast::MatchArm {
             pattern: Pattern::Wildcard,
             guard: None,
             body: Box::new(int_expr(0)),
             span: span(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
     ];
     let result = emitter.lower_match(&match_expr, &arms);
@@ -1254,6 +1272,8 @@ fn test_lower_match_or_pattern_last_arm() {
         guard: None,
         body: Box::new(int_expr(99)),
         span: span(),
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let result = emitter.lower_match(&match_expr, &arms);
     assert!(result.is_ok());
@@ -1271,6 +1291,8 @@ fn test_lower_match_tuple_pattern() {
         guard: None,
         body: Box::new(int_expr(42)),
         span: span(),
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let result = emitter.lower_match(&match_expr, &arms);
     assert!(result.is_ok());
@@ -1286,12 +1308,16 @@ fn test_lower_match_tuple_pattern_not_last() {
             guard: None,
             body: Box::new(int_expr(10)),
             span: span(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         crate::frontend::ast::MatchArm {
             pattern: Pattern::Wildcard,
             guard: None,
             body: Box::new(int_expr(0)),
             span: span(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
     ];
     let result = emitter.lower_match(&match_expr, &arms);
@@ -1307,6 +1333,8 @@ fn test_lower_match_identifier_pattern() {
         guard: None,
         body: Box::new(int_expr(42)),
         span: span(),
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let result = emitter.lower_match(&match_expr, &arms);
     assert!(result.is_ok());
@@ -1325,6 +1353,8 @@ fn test_lower_match_unsupported_pattern() {
         guard: None,
         body: Box::new(int_expr(42)),
         span: span(),
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let result = emitter.lower_match(&match_expr, &arms);
     assert!(result.is_err());
@@ -1343,12 +1373,16 @@ fn test_lower_match_via_lower_expression() {
                     guard: None,
                     body: Box::new(int_expr(10)),
                     span: span(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
                 crate::frontend::ast::MatchArm {
                     pattern: Pattern::Wildcard,
                     guard: None,
                     body: Box::new(int_expr(0)),
                     span: span(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
             ],
         },
@@ -1368,12 +1402,16 @@ fn test_lower_match_or_pattern_non_literal_error() {
             guard: None,
             body: Box::new(int_expr(100)),
             span: span(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         crate::frontend::ast::MatchArm {
             pattern: Pattern::Wildcard,
             guard: None,
             body: Box::new(int_expr(0)),
             span: span(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
     ];
     let result = emitter.lower_match(&match_expr, &arms);
@@ -1395,12 +1433,16 @@ fn test_lower_match_three_or_patterns() {
             guard: None,
             body: Box::new(int_expr(100)),
             span: span(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         crate::frontend::ast::MatchArm {
             pattern: Pattern::Wildcard,
             guard: None,
             body: Box::new(int_expr(0)),
             span: span(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
     ];
     let result = emitter.lower_match(&match_expr, &arms);
@@ -1516,3 +1558,302 @@ fn test_lower_assign_field_access_no_structs() {
     let result = emitter.lower_assign(&target, &value);
     assert!(result.is_ok());
 }
+
+/// Function names and per-function local names from the `name` section
+type DebugNames = (Vec<(u32, String)>, Vec<(u32, Vec<(u32, String)>)>);
+
+fn decode_name_section(wasm: &[u8]) -> Option<DebugNames> {
+    use crate::backend::wasm::metadata::{custom_sections, read_u32};
+    let sections = custom_sections(wasm).expect("module should be well-formed");
+    let (_, data) = sections.into_iter().find(|(name, _)| name == "name")?;
+    let read_name = |pos: &mut usize| {
+        let len = read_u32(data, pos).unwrap() as usize;
+        let name = String::from_utf8(data[*pos..*pos + len].to_vec()).unwrap();
+        *pos += len;
+        name
+    };
+    let read_map = |pos: &mut usize| {
+        (0..read_u32(data, pos).unwrap())
+            .map(|_| (read_u32(data, pos).unwrap(), read_name(pos)))
+            .collect::<Vec<_>>()
+    };
+    let (mut functions, mut locals) = (Vec::new(), Vec::new());
+    let mut pos = 0;
+    while pos < data.len() {
+        let id = data[pos];
+        pos += 1;
+        let end = read_u32(data, &mut pos).unwrap() as usize + pos;
+        match id {
+            1 => functions = read_map(&mut pos),
+            2 => {
+                for _ in 0..read_u32(data, &mut pos).unwrap() {
+                    let index = read_u32(data, &mut pos).unwrap();
+                    locals.push((index, read_map(&mut pos)));
+                }
+            }
+            _ => {}
+        }
+        pos = end;
+    }
+    Some((functions, locals))
+}
+
+#[test]
+fn test_debug_names_section_names_functions_and_locals() {
+    let ast = Parser::new("fun add(a, b) { a + b }\nlet total = add(1, 2)\nprintln(total)")
+        .parse()
+        .expect("source should parse");
+    let wasm = WasmEmitter::new()
+        .with_debug_names(true)
+        .emit(&ast)
+        .expect("source should compile");
+    let (functions, locals) = decode_name_section(&wasm).expect("name section");
+    let named = |pairs: &[(u32, &str)]| -> Vec<(u32, String)> {
+        pairs.iter().map(|&(i, n)| (i, n.to_string())).collect()
+    };
+    assert_eq!(
+        functions,
+        named(&[
+            (0, "ruchy_println_i32"),
+            (1, "ruchy_println_f32"),
+            (2, "ruchy_println_str"),
+            (3, "ruchy_now"),
+            (4, "ruchy_random"),
+            (5, "add"),
+            (6, TOP_LEVEL_FUNCTION_NAME)
+        ])
+    );
+    assert_eq!(
+        locals,
+        vec![
+            (5, named(&[(0, "a"), (1, "b")])),
+            (6, named(&[(0, "total")]))
+        ]
+    );
+}
+
+#[test]
+fn test_debug_names_off_by_default() {
+    let ast = Parser::new("let x = 1\nx")
+        .parse()
+        .expect("source should parse");
+    let wasm = WasmEmitter::new()
+        .emit(&ast)
+        .expect("source should compile");
+    assert!(decode_name_section(&wasm).is_none());
+    let debug = WasmEmitter::new()
+        .with_debug_names(true)
+        .emit(&ast)
+        .expect("source should compile");
+    let (functions, locals) = decode_name_section(&debug).expect("name section");
+    assert_eq!(functions, vec![(0, TOP_LEVEL_FUNCTION_NAME.to_string())]);
+    assert_eq!(locals, vec![(0, vec![(0, "x".to_string())])]);
+}
+
+#[test]
+fn test_wasi_shims_replace_host_imports() {
+    let ast = Parser::new("fun main() {\n    println(1)\n}")
+        .parse()
+        .expect("source should parse");
+    let wasm = WasmEmitter::new()
+        .with_wasi(true)
+        .with_debug_names(true)
+        .emit(&ast)
+        .expect("source should compile");
+    let (functions, _) = decode_name_section(&wasm).expect("name section");
+    let names: Vec<&str> = functions.iter().map(|(_, name)| name.as_str()).collect();
+    let mut expected: Vec<&str> = WasiImport::ALL.iter().map(|f| f.name()).collect();
+    expected.extend(WasiShim::ALL.iter().map(|f| f.name()));
+    expected.push("main");
+    expected.extend(RuntimeFunction::ALL.iter().map(|f| f.name()));
+    expected.push(START_EXPORT_NAME);
+    assert_eq!(names, expected);
+}
+
+#[test]
+fn test_read_file_needs_wasi() {
+    let ast = Parser::new("let text = read_file(\"notes.txt\")\nprintln(text)")
+        .parse()
+        .expect("source should parse");
+    let error = WasmEmitter::new()
+        .emit(&ast)
+        .expect_err("host modules cannot read files");
+    assert!(error.contains("WASI"), "{error}");
+    assert!(WasmEmitter::new().with_wasi(true).emit(&ast).is_ok());
+}
+
+#[test]
+fn test_fuel_exports_global_and_meters_loops() {
+    let ast = Parser::new("let mut i = 0\nwhile i < 3 { i = i + 1 }")
+        .parse()
+        .expect("source should parse");
+    let plain = WasmEmitter::new()
+        .emit(&ast)
+        .expect("source should compile");
+    let metered = WasmEmitter::new()
+        .with_fuel(Some(100))
+        .emit(&ast)
+        .expect("source should compile");
+    let exports_fuel = |wasm: &[u8]| {
+        wasm.windows(FUEL_EXPORT_NAME.len())
+            .any(|w| w == FUEL_EXPORT_NAME.as_bytes())
+    };
+    assert!(!exports_fuel(&plain));
+    assert!(exports_fuel(&metered));
+    // The check at function entry and loop header: global.get, i64.eqz, if
+    let check = [0x23, 0x00, 0x50, 0x04, 0x40, 0x00, 0x0b];
+    let checks = metered.windows(check.len()).filter(|w| *w == check).count();
+    assert_eq!(checks, 2);
+    assert_eq!(
+        WasmEmitter::new()
+            .with_fuel(None)
+            .emit(&ast)
+            .expect("source should compile"),
+        plain
+    );
+}
+
+#[test]
+fn test_whole_program_drops_folded_functions() {
+    let ast = Parser::new(
+        "fun log_value(x: i32) { println(x) }\nfun step(n: i32, verbose: bool) -> i32 {\n    if verbose { log_value(n) }\n    n * 2\n}\nfun main() {\n    println(step(21, false))\n}",
+    )
+    .parse()
+    .expect("source should parse");
+    let emit = |whole_program: bool| {
+        WasmEmitter::new()
+            .with_debug_names(true)
+            .with_whole_program(whole_program)
+            .emit(&ast)
+            .expect("source should compile")
+    };
+    let (plain, optimized) = (emit(false), emit(true));
+    assert!(optimized.len() < plain.len());
+    let (functions, _) = decode_name_section(&optimized).expect("name section");
+    let names: Vec<&str> = functions.iter().map(|(_, name)| name.as_str()).collect();
+    assert!(names.contains(&"main"));
+    assert!(
+        !names.contains(&"log_value") && !names.contains(&"step"),
+        "{names:?}"
+    );
+}
+
+#[test]
+fn test_lower_call_println_string_prints_from_data_section() {
+    let emitter = WasmEmitter::new();
+    let println = |text: &str| {
+        emitter
+            .lower_call(&ident_expr("println"), &[string_expr(text)])
+            .expect("println should lower")
+    };
+    let print_str = HostFunction::PrintlnStr.index();
+    assert!(matches!(
+        println("hello").as_slice(),
+        [Instruction::I32Const(4), Instruction::I32Const(5), Instruction::Call(f)] if *f == print_str
+    ));
+    // Each literal is a 4-byte length header and its text, padded to 4 bytes
+    assert!(matches!(
+        println("world").as_slice(),
+        [
+            Instruction::I32Const(16),
+            Instruction::I32Const(5),
+            Instruction::Call(_)
+        ]
+    ));
+    // Repeated literals share their bytes
+    assert!(matches!(
+        println("hello").as_slice(),
+        [
+            Instruction::I32Const(4),
+            Instruction::I32Const(5),
+            Instruction::Call(_)
+        ]
+    ));
+    assert_eq!(emitter.string_data_len(), 24);
+}
+
+#[test]
+fn test_lower_call_host_builtins() {
+    let emitter = WasmEmitter::new();
+    let random = Expr::new(
+        ExprKind::Call {
+            func: Box::new(ident_expr("random")),
+            args: vec![],
+        },
+        span(),
+    );
+    let random_index = HostFunction::Random.index();
+    assert!(matches!(
+        emitter.lower_call(&ident_expr("random"), &[]).unwrap().as_slice(),
+        [Instruction::Call(f)] if *f == random_index
+    ));
+    assert_eq!(emitter.infer_type(&random), WasmType::F32);
+    assert!(emitter
+        .lower_call(&ident_expr("timestamp"), &[int_expr(1)])
+        .unwrap_err()
+        .contains("takes no arguments"));
+    // A user-defined function shadows the builtin
+    emitter
+        .functions
+        .borrow_mut()
+        .insert("random".to_string(), (7, false));
+    assert!(matches!(
+        emitter
+            .lower_call(&ident_expr("random"), &[])
+            .unwrap()
+            .as_slice(),
+        [Instruction::Call(7)]
+    ));
+    assert_eq!(emitter.infer_type(&random), WasmType::I32);
+}
+
+#[test]
+fn test_params_resolve_to_their_own_locals() {
+    let ast = Parser::new("fun sub(a, b) { a - b }")
+        .parse()
+        .expect("should parse");
+    let emitter = WasmEmitter::new();
+    let functions = emitter.collect_functions(&ast);
+    let (_, params, body) = &functions[0];
+    emitter.enter_function(params, body);
+    assert!(matches!(
+        emitter.lower_expression(body).unwrap().as_slice(),
+        [
+            Instruction::LocalGet(0),
+            Instruction::LocalGet(1),
+            Instruction::I32Sub
+        ]
+    ));
+}
+
+#[test]
+fn test_break_and_continue_branch_out_of_enclosing_blocks() {
+    let ast = Parser::new("while true { if false { continue } else { break } }")
+        .parse()
+        .expect("should parse");
+    let emitter = WasmEmitter::new();
+    emitter.enter_function(&[], &ast);
+    let branches: Vec<u32> = emitter
+        .lower_expression(&ast)
+        .unwrap()
+        .iter()
+        .filter_map(|instruction| match instruction {
+            Instruction::Br(depth) => Some(*depth),
+            _ => None,
+        })
+        .collect();
+    // From inside the if: continue restarts the loop, break leaves its block
+    assert_eq!(branches, vec![1, 2, 0]);
+
+    let stray = Expr::new(
+        ExprKind::Break {
+            label: None,
+            value: None,
+        },
+        span(),
+    );
+    assert!(emitter
+        .lower_expression(&stray)
+        .unwrap_err()
+        .contains("inside a loop"));
+}
@@ -0,0 +1,60 @@
+// Host functions for WebAssembly modules compiled by `ruchy wasm`.
+//
+// Works in browsers and Node.js:
+//
+//   import { instantiate } from "./program.host.js";
+//   const bytes = await (await fetch("program.wasm")).arrayBuffer();
+//   // Node.js: const bytes = fs.readFileSync("program.wasm");
+//   const instance = await instantiate(bytes);
+//   instance.exports.main?.();
+//
+// Pass `{ print: (line) => ... }` to send output somewhere other than
+// `console.log`.
+
+const clock = globalThis.performance ?? Date;
+
+// Shortest decimal that reads back as the same f32, like Rust's `f32` Display
+function formatF32(value) {
+  for (let digits = 1; digits < 9; digits++) {
+    const text = value.toPrecision(digits);
+    if (Math.fround(Number(text)) === value) {
+      return String(Number(text));
+    }
+  }
+  return String(value);
+}
+
+export function createImports({ print = (line) => console.log(line) } = {}) {
+  const decoder = new TextDecoder();
+  const start = clock.now();
+  let memory = null;
+
+  const imports = {
+    ruchy: {
+      ruchy_println_i32: (value) => print(String(value)),
+      ruchy_println_f32: (value) => print(formatF32(value)),
+      ruchy_println_str: (ptr, len) => {
+        if (memory === null) {
+          throw new Error("module exports no memory");
+        }
+        print(decoder.decode(new Uint8Array(memory.buffer, ptr, len)));
+      },
+      ruchy_now: () => Math.min(Math.floor(clock.now() - start), 0x7fffffff),
+      ruchy_random: () => Math.random(),
+    },
+  };
+
+  return {
+    imports,
+    bind(instance) {
+      memory = instance.exports.memory ?? null;
+    },
+  };
+}
+
+export async function instantiate(bytes, options = {}) {
+  const host = createImports(options);
+  const { instance } = await WebAssembly.instantiate(bytes, host.imports);
+  host.bind(instance);
+  return instance;
+}
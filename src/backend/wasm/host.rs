@@ -0,0 +1,221 @@
+//! Host function ABI for emitted modules
+//!
+//! Compiled programs have no I/O of their own. When a program prints, reads
+//! the clock or draws random numbers, the emitter imports the functions below
+//! from the `ruchy` module and the host provides them:
+//!
+//! | Import               | Signature           | Ruchy source                   |
+//! |----------------------|---------------------|--------------------------------|
+//! | `ruchy_println_i32`  | `(i32) -> ()`       | `println(n)` for ints, bools   |
+//! | `ruchy_println_f32`  | `(f32) -> ()`       | `println(x)` for floats        |
+//! | `ruchy_println_str`  | `(i32, i32) -> ()`  | `println(s)` for strings       |
+//! | `ruchy_now`          | `() -> i32`         | `timestamp()`                  |
+//! | `ruchy_random`       | `() -> f32`         | `random()`                     |
+//!
+//! `ruchy_println_str` receives a pointer and byte length of UTF-8 text in
+//! the memory exported as [`MEMORY_EXPORT_NAME`]. `ruchy_now` is a monotonic
+//! clock in milliseconds since the host started, so it measures elapsed
+//! time rather than wall-clock time. `ruchy_random` returns a value in
+//! `[0, 1)`.
+//!
+//! The crate ships two hosts: [`HOST_JS`] for browsers and Node.js, and
+//! `add_to_linker` for wasmtime (with the `notebook` feature).
+//! Modules emitted for the WASI target import none of these and define them
+//! over WASI calls instead (see [`super::wasi`]).
+
+use wasm_encoder::ValType;
+
+/// Import module name of the host functions
+pub const HOST_MODULE: &str = "ruchy";
+
+/// Export name of linear memory, read by `ruchy_println_str`
+pub const MEMORY_EXPORT_NAME: &str = "memory";
+
+/// ES module providing the host functions to `WebAssembly.instantiate`
+///
+/// Exports `createImports({ print })`, returning `{ imports, bind(instance) }`,
+/// and `instantiate(bytes, { print })`, which does both and returns the
+/// instance. `print` defaults to `console.log`.
+pub const HOST_JS: &str = include_str!("host.js");
+
+/// A function of the host ABI
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HostFunction {
+    /// Print an integer or bool on its own line
+    PrintlnI32,
+    /// Print a float on its own line
+    PrintlnF32,
+    /// Print UTF-8 text from memory on its own line
+    PrintlnStr,
+    /// Milliseconds on a monotonic host clock
+    Now,
+    /// Uniform random float in `[0, 1)`
+    Random,
+}
+
+/// Number of host functions; they occupy the first function and type indices
+pub const HOST_FUNCTION_COUNT: u32 = HostFunction::ALL.len() as u32;
+
+impl HostFunction {
+    /// All host functions, in import order
+    pub const ALL: [Self; 5] = [
+        Self::PrintlnI32,
+        Self::PrintlnF32,
+        Self::PrintlnStr,
+        Self::Now,
+        Self::Random,
+    ];
+
+    /// Import name
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::PrintlnI32 => "ruchy_println_i32",
+            Self::PrintlnF32 => "ruchy_println_f32",
+            Self::PrintlnStr => "ruchy_println_str",
+            Self::Now => "ruchy_now",
+            Self::Random => "ruchy_random",
+        }
+    }
+
+    /// Function index, which is also the index of its type
+    pub fn index(self) -> u32 {
+        self as u32
+    }
+
+    /// Parameter types
+    pub fn params(self) -> &'static [ValType] {
+        match self {
+            Self::PrintlnI32 => &[ValType::I32],
+            Self::PrintlnF32 => &[ValType::F32],
+            Self::PrintlnStr => &[ValType::I32, ValType::I32],
+            Self::Now | Self::Random => &[],
+        }
+    }
+
+    /// Result types
+    pub fn results(self) -> &'static [ValType] {
+        match self {
+            Self::PrintlnI32 | Self::PrintlnF32 | Self::PrintlnStr => &[],
+            Self::Now => &[ValType::I32],
+            Self::Random => &[ValType::F32],
+        }
+    }
+
+    /// Host function behind a value-returning Ruchy builtin
+    pub fn for_builtin(name: &str) -> Option<Self> {
+        match name {
+            "timestamp" => Some(Self::Now),
+            "random" => Some(Self::Random),
+            _ => None,
+        }
+    }
+}
+
+/// Whether `name` is a Ruchy print builtin, lowered to a `ruchy_println_*` call
+pub fn is_print(name: &str) -> bool {
+    matches!(name, "println" | "print" | "eprintln" | "eprint")
+}
+
+/// Whether a call to `name` imports the host ABI
+pub fn is_host_call(name: &str) -> bool {
+    is_print(name) || HostFunction::for_builtin(name).is_some()
+}
+
+/// Define the host functions in `linker`, sending each printed line to `print`
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::backend::wasm::host::add_to_linker;
+/// use wasmtime::{Engine, Linker};
+///
+/// let engine = Engine::default();
+/// let mut linker = Linker::<()>::new(&engine);
+/// add_to_linker(&mut linker, |line| println!("{line}")).unwrap();
+/// ```
+#[cfg(feature = "notebook")]
+pub fn add_to_linker<T: 'static>(
+    linker: &mut wasmtime::Linker<T>,
+    print: impl Fn(&str) + Send + Sync + 'static,
+) -> wasmtime::Result<()> {
+    use std::sync::Arc;
+
+    let print = Arc::new(print);
+    let start = std::time::Instant::now();
+
+    let out = Arc::clone(&print);
+    linker.func_wrap(
+        HOST_MODULE,
+        HostFunction::PrintlnI32.name(),
+        move |value: i32| out(&value.to_string()),
+    )?;
+    let out = Arc::clone(&print);
+    linker.func_wrap(
+        HOST_MODULE,
+        HostFunction::PrintlnF32.name(),
+        move |value: f32| out(&value.to_string()),
+    )?;
+    linker.func_wrap(
+        HOST_MODULE,
+        HostFunction::PrintlnStr.name(),
+        move |mut caller: wasmtime::Caller<'_, T>, ptr: i32, len: i32| {
+            let memory = caller
+                .get_export(MEMORY_EXPORT_NAME)
+                .and_then(wasmtime::Extern::into_memory)
+                .ok_or_else(|| wasmtime::Error::msg("module exports no memory"))?;
+            let text = usize::try_from(ptr)
+                .ok()
+                .zip(usize::try_from(len).ok())
+                .and_then(|(ptr, len)| memory.data(&caller).get(ptr..ptr.checked_add(len)?))
+                .ok_or_else(|| wasmtime::Error::msg("string is out of bounds"))?;
+            print(&String::from_utf8_lossy(text));
+            wasmtime::Result::<()>::Ok(())
+        },
+    )?;
+    linker.func_wrap(HOST_MODULE, HostFunction::Now.name(), move || {
+        i32::try_from(start.elapsed().as_millis()).unwrap_or(i32::MAX)
+    })?;
+    linker.func_wrap(
+        HOST_MODULE,
+        HostFunction::Random.name(),
+        rand::random::<f32>,
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_function_indices_follow_import_order() {
+        for (i, function) in HostFunction::ALL.iter().enumerate() {
+            assert_eq!(function.index() as usize, i);
+            assert!(function.name().starts_with("ruchy_"));
+        }
+        assert_eq!(HOST_FUNCTION_COUNT, 5);
+    }
+
+    #[test]
+    fn test_host_calls() {
+        assert!(is_host_call("println"));
+        assert!(is_host_call("eprint"));
+        assert_eq!(
+            HostFunction::for_builtin("random"),
+            Some(HostFunction::Random)
+        );
+        assert_eq!(
+            HostFunction::for_builtin("timestamp"),
+            Some(HostFunction::Now)
+        );
+        assert!(!is_host_call("len"));
+    }
+
+    #[test]
+    fn test_js_host_defines_every_import() {
+        for function in HostFunction::ALL {
+            assert!(HOST_JS.contains(function.name()), "{}", function.name());
+        }
+        assert!(HOST_JS.contains(MEMORY_EXPORT_NAME));
+    }
+}
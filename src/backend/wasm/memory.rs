@@ -0,0 +1,225 @@
+//! Linear memory layout and the allocator of emitted modules
+//!
+//! Memory starts with the program's string literals, followed by the heap.
+//! Global 0 holds the heap pointer, the address of the next free byte.
+//!
+//! A string value is the `i32` address of a header: the byte length as a
+//! little-endian `u32`, followed by the UTF-8 bytes. Literals are laid out
+//! this way in the data section; strings built at run time (concatenation,
+//! interpolation) are allocated on the heap by the functions below, which
+//! are appended after the user functions of modules that need them:
+//!
+//! | Function         | Signature           | Purpose                                   |
+//! |------------------|---------------------|-------------------------------------------|
+//! | `__ruchy_alloc`  | `(i32) -> i32`      | Bump-allocate bytes, growing memory       |
+//! | `__ruchy_concat` | `(i32, i32) -> i32` | Allocate the concatenation of two strings |
+//!
+//! Nothing is freed: the heap only grows, one page at a time, until the
+//! host refuses to grow memory and the module traps.
+
+use wasm_encoder::{BlockType, Function, Instruction, MemArg, ValType};
+
+/// Bytes before the text of a string: its length
+pub const STRING_HEADER_SIZE: u32 = 4;
+
+/// Global holding the heap pointer
+pub const HEAP_POINTER_GLOBAL: u32 = 0;
+
+/// log2 of the size of a memory page
+const PAGE_SIZE_LOG2: i32 = 16;
+
+/// A function emitted into modules that allocate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeFunction {
+    /// Allocate `size` bytes, 4-byte aligned
+    Alloc,
+    /// Concatenate two strings into a new one
+    Concat,
+}
+
+impl RuntimeFunction {
+    /// All runtime functions, in the order they are emitted
+    pub const ALL: [Self; 2] = [Self::Alloc, Self::Concat];
+
+    /// Debug name
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Alloc => "__ruchy_alloc",
+            Self::Concat => "__ruchy_concat",
+        }
+    }
+
+    /// Position among the runtime functions, added to the index of the first
+    pub fn offset(self) -> u32 {
+        self as u32
+    }
+
+    /// Parameter types
+    pub fn params(self) -> &'static [ValType] {
+        match self {
+            Self::Alloc => &[ValType::I32],
+            Self::Concat => &[ValType::I32, ValType::I32],
+        }
+    }
+
+    /// Result types
+    pub fn results(self) -> &'static [ValType] {
+        &[ValType::I32]
+    }
+
+    /// Body of the function, when the first runtime function has index `first`
+    pub fn body(self, first: u32) -> Function {
+        let (locals, instructions) = match self {
+            Self::Alloc => (vec![(2, ValType::I32)], alloc_body()),
+            Self::Concat => (
+                vec![(3, ValType::I32)],
+                concat_body(first + Self::Alloc.offset()),
+            ),
+        };
+        let mut function = Function::new(locals);
+        for instruction in &instructions {
+            function.instruction(instruction);
+        }
+        function.instruction(&Instruction::End);
+        function
+    }
+}
+
+/// Word-aligned access to a string header or heap word
+fn word() -> MemArg {
+    MemArg {
+        offset: 0,
+        align: 2,
+        memory_index: 0,
+    }
+}
+
+/// `alloc(size)`: locals are `size`, `ptr` and `end`
+///
+/// Returns the old heap pointer and moves it past `size` bytes, rounded up
+/// to a multiple of 4, growing memory when the new end is past it.
+fn alloc_body() -> Vec<Instruction<'static>> {
+    let (size, ptr, end) = (0, 1, 2);
+    vec![
+        Instruction::GlobalGet(HEAP_POINTER_GLOBAL),
+        Instruction::LocalTee(ptr),
+        Instruction::LocalGet(size),
+        Instruction::I32Add,
+        Instruction::I32Const(3),
+        Instruction::I32Add,
+        Instruction::I32Const(-4),
+        Instruction::I32And,
+        Instruction::LocalTee(end),
+        Instruction::GlobalSet(HEAP_POINTER_GLOBAL),
+        // Grow by the missing pages if `end` is past the end of memory
+        Instruction::LocalGet(end),
+        Instruction::MemorySize(0),
+        Instruction::I32Const(PAGE_SIZE_LOG2),
+        Instruction::I32Shl,
+        Instruction::I32GtU,
+        Instruction::If(BlockType::Empty),
+        Instruction::LocalGet(end),
+        Instruction::I32Const((1 << PAGE_SIZE_LOG2) - 1),
+        Instruction::I32Add,
+        Instruction::I32Const(PAGE_SIZE_LOG2),
+        Instruction::I32ShrU,
+        Instruction::MemorySize(0),
+        Instruction::I32Sub,
+        Instruction::MemoryGrow(0),
+        Instruction::I32Const(-1),
+        Instruction::I32Eq,
+        Instruction::If(BlockType::Empty),
+        Instruction::Unreachable,
+        Instruction::End,
+        Instruction::End,
+        Instruction::LocalGet(ptr),
+    ]
+}
+
+/// `concat(left, right)`: locals are `left`, `right`, their lengths and the result
+fn concat_body(alloc: u32) -> Vec<Instruction<'static>> {
+    let (left, right, left_len, right_len, out) = (0, 1, 2, 3, 4);
+    let header = STRING_HEADER_SIZE as i32;
+    let copy = Instruction::MemoryCopy {
+        src_mem: 0,
+        dst_mem: 0,
+    };
+    vec![
+        Instruction::LocalGet(left),
+        Instruction::I32Load(word()),
+        Instruction::LocalSet(left_len),
+        Instruction::LocalGet(right),
+        Instruction::I32Load(word()),
+        Instruction::LocalSet(right_len),
+        // Header and text of the result
+        Instruction::LocalGet(left_len),
+        Instruction::LocalGet(right_len),
+        Instruction::I32Add,
+        Instruction::I32Const(header),
+        Instruction::I32Add,
+        Instruction::Call(alloc),
+        Instruction::LocalTee(out),
+        Instruction::LocalGet(left_len),
+        Instruction::LocalGet(right_len),
+        Instruction::I32Add,
+        Instruction::I32Store(word()),
+        // Left text, then right text after it
+        Instruction::LocalGet(out),
+        Instruction::I32Const(header),
+        Instruction::I32Add,
+        Instruction::LocalGet(left),
+        Instruction::I32Const(header),
+        Instruction::I32Add,
+        Instruction::LocalGet(left_len),
+        copy.clone(),
+        Instruction::LocalGet(out),
+        Instruction::I32Const(header),
+        Instruction::I32Add,
+        Instruction::LocalGet(left_len),
+        Instruction::I32Add,
+        Instruction::LocalGet(right),
+        Instruction::I32Const(header),
+        Instruction::I32Add,
+        Instruction::LocalGet(right_len),
+        copy,
+        Instruction::LocalGet(out),
+    ]
+}
+
+/// Bytes a string literal occupies in the data section: header, text and padding
+pub fn string_footprint(text: &str) -> u32 {
+    (STRING_HEADER_SIZE + text.len() as u32).next_multiple_of(4)
+}
+
+/// Data section image of `text`: its header, text and padding
+pub fn encode_string(text: &str, out: &mut Vec<u8>) {
+    out.extend_from_slice(&(text.len() as u32).to_le_bytes());
+    out.extend_from_slice(text.as_bytes());
+    let padding = string_footprint(text) - STRING_HEADER_SIZE - text.len() as u32;
+    out.resize(out.len() + padding as usize, 0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strings_are_length_prefixed_and_aligned() {
+        let mut bytes = Vec::new();
+        encode_string("hello", &mut bytes);
+        assert_eq!(&bytes[..4], &5u32.to_le_bytes());
+        assert_eq!(&bytes[4..9], b"hello");
+        assert_eq!(bytes.len() as u32, string_footprint("hello"));
+        assert_eq!(string_footprint("hello"), 12);
+        assert_eq!(string_footprint(""), 4);
+    }
+
+    #[test]
+    fn test_runtime_functions_have_fixed_offsets() {
+        for (i, function) in RuntimeFunction::ALL.iter().enumerate() {
+            assert_eq!(function.offset() as usize, i);
+            assert!(function.name().starts_with("__ruchy_"));
+            assert_eq!(function.results(), &[ValType::I32]);
+        }
+    }
+}
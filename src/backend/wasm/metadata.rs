@@ -0,0 +1,257 @@
+//! Provenance metadata in `.wasm` custom sections
+//!
+//! `ruchy wasm --embed-metadata` appends four custom sections to the module:
+//!
+//! | Section              | Payload                                         |
+//! |----------------------|-------------------------------------------------|
+//! | `ruchy.compiler`     | compiler version, UTF-8 (e.g. `3.200.0`)        |
+//! | `ruchy.source_hash`  | `sha256:<hex>` of the source text               |
+//! | `ruchy.ast`          | JSON `{"version": N, "ast": <Expr>}`            |
+//! | `ruchy.diagnostics`  | JSON array of lint issues found at compile time |
+//!
+//! Custom sections do not affect execution. [`WasmMetadata::read`] extracts
+//! them again so toolchains can check provenance
+//! ([`WasmMetadata::matches_source`]) or map a module back to its source.
+
+use crate::frontend::ast::Expr;
+use crate::quality::linter::LintIssue;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use wasm_encoder::{CustomSection, Section};
+
+/// Section holding the compiler version
+pub const COMPILER_SECTION: &str = "ruchy.compiler";
+/// Section holding the source hash
+pub const SOURCE_HASH_SECTION: &str = "ruchy.source_hash";
+/// Section holding the versioned AST JSON
+pub const AST_SECTION: &str = "ruchy.ast";
+/// Section holding compile-time diagnostics
+pub const DIAGNOSTICS_SECTION: &str = "ruchy.diagnostics";
+/// Version of the `ruchy.ast` payload; bumped when the AST JSON changes shape
+pub const AST_FORMAT_VERSION: u32 = 1;
+
+/// The `ruchy.ast` payload
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AstDocument {
+    /// [`AST_FORMAT_VERSION`] of the writer
+    pub version: u32,
+    /// Parsed program
+    pub ast: Expr,
+}
+
+/// Metadata embedded by `ruchy wasm --embed-metadata`
+#[derive(Debug, Clone, PartialEq)]
+pub struct WasmMetadata {
+    /// Version of the compiler that produced the module
+    pub compiler_version: String,
+    /// `sha256:<hex>` of the source text
+    pub source_hash: String,
+    /// Program the module was compiled from
+    pub ast: AstDocument,
+    /// Lint issues reported while compiling
+    pub diagnostics: Vec<LintIssue>,
+}
+
+impl WasmMetadata {
+    /// Metadata for `source`, parsed as `ast`, from this compiler
+    pub fn new(source: &str, ast: &Expr) -> Self {
+        Self {
+            compiler_version: env!("CARGO_PKG_VERSION").to_string(),
+            source_hash: source_hash(source),
+            ast: AstDocument {
+                version: AST_FORMAT_VERSION,
+                ast: ast.clone(),
+            },
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Attach compile-time diagnostics
+    #[must_use]
+    pub fn with_diagnostics(mut self, diagnostics: Vec<LintIssue>) -> Self {
+        self.diagnostics = diagnostics;
+        self
+    }
+
+    /// Whether the module was compiled from exactly `source`
+    pub fn matches_source(&self, source: &str) -> bool {
+        self.source_hash == source_hash(source)
+    }
+
+    /// Append the metadata sections to a compiled module
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `wasm` is not a well-formed module or the metadata
+    /// cannot be serialized.
+    pub fn embed(&self, wasm: &[u8]) -> Result<Vec<u8>, String> {
+        custom_sections(wasm)?;
+        let ast = serde_json::to_vec(&self.ast).map_err(|e| format!("AST serialization: {e}"))?;
+        let diagnostics = serde_json::to_vec(&self.diagnostics)
+            .map_err(|e| format!("Diagnostics serialization: {e}"))?;
+        let mut out = wasm.to_vec();
+        for (name, data) in [
+            (COMPILER_SECTION, self.compiler_version.as_bytes()),
+            (SOURCE_HASH_SECTION, self.source_hash.as_bytes()),
+            (AST_SECTION, ast.as_slice()),
+            (DIAGNOSTICS_SECTION, diagnostics.as_slice()),
+        ] {
+            CustomSection {
+                name: Cow::Borrowed(name),
+                data: Cow::Borrowed(data),
+            }
+            .append_to(&mut out);
+        }
+        Ok(out)
+    }
+
+    /// Extract embedded metadata; `None` if the module carries none
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for a malformed module, missing or invalid metadata
+    /// sections, or an AST payload newer than this reader understands.
+    pub fn read(wasm: &[u8]) -> Result<Option<Self>, String> {
+        let sections = custom_sections(wasm)?;
+        let find = |name: &str| {
+            sections
+                .iter()
+                .find(|(section, _)| section == name)
+                .map(|(_, data)| *data)
+        };
+        if find(COMPILER_SECTION).is_none() {
+            return Ok(None);
+        }
+        let text = |name: &str| -> Result<String, String> {
+            let data = find(name).ok_or_else(|| format!("Missing custom section '{name}'"))?;
+            String::from_utf8(data.to_vec()).map_err(|e| format!("Section '{name}': {e}"))
+        };
+        let ast: AstDocument = serde_json::from_slice(
+            find(AST_SECTION).ok_or_else(|| format!("Missing custom section '{AST_SECTION}'"))?,
+        )
+        .map_err(|e| format!("Section '{AST_SECTION}': {e}"))?;
+        if ast.version > AST_FORMAT_VERSION {
+            return Err(format!(
+                "AST format version {} is newer than supported version {AST_FORMAT_VERSION}",
+                ast.version
+            ));
+        }
+        let diagnostics = match find(DIAGNOSTICS_SECTION) {
+            Some(data) => serde_json::from_slice(data)
+                .map_err(|e| format!("Section '{DIAGNOSTICS_SECTION}': {e}"))?,
+            None => Vec::new(),
+        };
+        Ok(Some(Self {
+            compiler_version: text(COMPILER_SECTION)?,
+            source_hash: text(SOURCE_HASH_SECTION)?,
+            ast,
+            diagnostics,
+        }))
+    }
+}
+
+/// `sha256:<hex>` digest of source text
+pub fn source_hash(source: &str) -> String {
+    format!("sha256:{:x}", Sha256::digest(source.as_bytes()))
+}
+
+/// Name and payload of every custom section, in module order
+///
+/// # Errors
+///
+/// Returns an error if `wasm` lacks the module header or a section runs past
+/// the end of the input.
+pub fn custom_sections(wasm: &[u8]) -> Result<Vec<(String, &[u8])>, String> {
+    if wasm.len() < 8 || !wasm.starts_with(b"\0asm") {
+        return Err("Not a WebAssembly module".to_string());
+    }
+    let mut sections = Vec::new();
+    let mut pos = 8;
+    while pos < wasm.len() {
+        let id = wasm[pos];
+        pos += 1;
+        let size = read_u32(wasm, &mut pos)? as usize;
+        let end = pos
+            .checked_add(size)
+            .filter(|&end| end <= wasm.len())
+            .ok_or_else(|| format!("Section at offset {pos} runs past the end of the module"))?;
+        if id == 0 {
+            let mut name_pos = pos;
+            let name_len = read_u32(wasm, &mut name_pos)? as usize;
+            let name_end = name_pos
+                .checked_add(name_len)
+                .filter(|&name_end| name_end <= end)
+                .ok_or_else(|| format!("Custom section name at offset {pos} is truncated"))?;
+            let name = std::str::from_utf8(&wasm[name_pos..name_end])
+                .map_err(|e| format!("Custom section name at offset {pos}: {e}"))?;
+            sections.push((name.to_string(), &wasm[name_end..end]));
+        }
+        pos = end;
+    }
+    Ok(sections)
+}
+
+/// Unsigned LEB128 as used for WASM sizes
+pub(crate) fn read_u32(bytes: &[u8], pos: &mut usize) -> Result<u32, String> {
+    let mut value = 0u32;
+    for shift in (0..35).step_by(7) {
+        let byte = *bytes
+            .get(*pos)
+            .ok_or_else(|| "Unexpected end of module".to_string())?;
+        *pos += 1;
+        value |= u32::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(format!("Invalid LEB128 integer before offset {pos}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::wasm::WasmEmitter;
+    use crate::frontend::parser::Parser;
+
+    fn compile(source: &str) -> (Expr, Vec<u8>) {
+        let ast = Parser::new(source).parse().expect("source should parse");
+        let wasm = WasmEmitter::new()
+            .emit(&ast)
+            .expect("source should compile");
+        (ast, wasm)
+    }
+
+    #[test]
+    fn test_embed_and_read_round_trip() {
+        let source = "let x = 1 + 2\nx";
+        let (ast, wasm) = compile(source);
+        assert_eq!(WasmMetadata::read(&wasm).unwrap(), None);
+
+        let metadata = WasmMetadata::new(source, &ast);
+        let embedded = metadata.embed(&wasm).unwrap();
+        assert!(embedded.starts_with(&wasm));
+        let read = WasmMetadata::read(&embedded).unwrap().expect("metadata");
+        assert_eq!(read, metadata);
+        assert!(read.matches_source(source));
+        assert!(!read.matches_source("let x = 4"));
+    }
+
+    #[test]
+    fn test_newer_ast_version_is_rejected() {
+        let source = "1";
+        let (ast, wasm) = compile(source);
+        let mut metadata = WasmMetadata::new(source, &ast);
+        metadata.ast.version = AST_FORMAT_VERSION + 1;
+        let embedded = metadata.embed(&wasm).unwrap();
+        let err = WasmMetadata::read(&embedded).unwrap_err();
+        assert!(err.contains("newer than supported"), "{err}");
+    }
+
+    #[test]
+    fn test_custom_sections_rejects_truncated_modules() {
+        let (_, wasm) = compile("1");
+        assert!(custom_sections(b"not wasm").is_err());
+        assert!(custom_sections(&wasm[..wasm.len() - 1]).is_err());
+    }
+}
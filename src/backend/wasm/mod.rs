@@ -4,10 +4,15 @@
 //!
 //! ## Module Structure
 //! - `emitter` - Core WASM emitter implementation
+//! - `host` - Host function ABI imported by emitted modules, with JS and wasmtime hosts
+//! - `memory` - Linear memory layout and the runtime allocator
+//! - `metadata` - Provenance metadata in custom sections
 //! - `wasm_module` - Compiled WASM module representation
 //! - `symbol_table` - Variable tracking across scopes
+//! - `validate` - Streaming validation (requires the `notebook` feature)
 //! - `types` - WASM type definitions
 //! - `utils` - Pure utility functions for AST analysis
+//! - `wasi` - WASI preview 1 target: builtins over WASI imports
 
 // Submodules
 #[cfg(test)]
@@ -15,13 +20,20 @@ mod debug;
 pub mod emitter;
 #[cfg(test)]
 mod emitter_tests;
+pub mod host;
+pub mod memory;
+pub mod metadata;
 pub mod symbol_table;
 pub mod types;
 pub mod utils;
+#[cfg(feature = "notebook")]
+pub mod validate;
+pub mod wasi;
 pub mod wasm_module;
 
 // Re-exports for convenient access
 pub use emitter::WasmEmitter;
+pub use metadata::WasmMetadata;
 pub use symbol_table::SymbolTable;
 pub use types::WasmType;
 pub use wasm_module::WasmModule;
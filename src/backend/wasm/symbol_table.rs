@@ -91,6 +91,21 @@ impl SymbolTable {
         locals
     }
 
+    /// Names of all local variables as (index, name) pairs, sorted by index
+    pub fn local_names(&self) -> Vec<(u32, String)> {
+        let mut names: Vec<(u32, String)> = self
+            .scopes
+            .iter()
+            .flat_map(|scope| {
+                scope
+                    .iter()
+                    .map(|(name, &(_, index))| (index, name.clone()))
+            })
+            .collect();
+        names.sort();
+        names
+    }
+
     /// Clear all scopes and reset
     pub fn clear(&mut self) {
         self.scopes.clear();
@@ -2,20 +2,24 @@
 //!
 //! Pure utility functions for AST analysis in WASM code generation.
 
-use crate::frontend::ast::{Expr, ExprKind, Literal, StringPart};
+use super::{host, wasi};
+use crate::frontend::ast::{Expr, ExprKind, Literal, StringPart, Type, TypeKind};
 
-/// Check if expression tree uses any built-in functions
-/// Complexity: 4 (Toyota Way: <10 ✓)
+/// Check if expression tree calls any host functions (see [`super::host`]),
+/// or `read_file` (see [`super::wasi`])
+/// Complexity: 5 (Toyota Way: <10 ✓)
 pub fn uses_builtins(expr: &Expr) -> bool {
     match &expr.kind {
-        ExprKind::Call { func, .. } => {
-            if let ExprKind::Identifier(name) = &func.kind {
-                matches!(name.as_str(), "println" | "print" | "eprintln" | "eprint")
-            } else {
-                false
-            }
+        ExprKind::Call { func, args } => {
+            matches!(&func.kind, ExprKind::Identifier(name)
+                if host::is_host_call(name) || name == wasi::READ_FILE_BUILTIN)
+                || args.iter().any(uses_builtins)
         }
         ExprKind::Block(exprs) => exprs.iter().any(uses_builtins),
+        ExprKind::While {
+            condition, body, ..
+        } => uses_builtins(condition) || uses_builtins(body),
+        ExprKind::For { iter, body, .. } => uses_builtins(iter) || uses_builtins(body),
         ExprKind::If {
             condition,
             then_branch,
@@ -26,6 +30,12 @@ pub fn uses_builtins(expr: &Expr) -> bool {
                 || else_branch.as_ref().is_some_and(|e| uses_builtins(e))
         }
         ExprKind::Let { value, body, .. } => uses_builtins(value) || uses_builtins(body),
+        ExprKind::Assign { value, .. } | ExprKind::CompoundAssign { value, .. } => {
+            uses_builtins(value)
+        }
+        ExprKind::Unary { operand: e, .. } | ExprKind::Return { value: Some(e) } => {
+            uses_builtins(e)
+        }
         ExprKind::Binary { left, right, .. } => uses_builtins(left) || uses_builtins(right),
         ExprKind::StringInterpolation { parts } => parts.iter().any(|part| {
             if let StringPart::Expr(e) | StringPart::ExprWithFormat { expr: e, .. } = part {
@@ -45,6 +55,8 @@ pub fn uses_builtins(expr: &Expr) -> bool {
 
 /// Check if an expression needs memory (for arrays/strings/tuples/structs)
 /// Complexity: 10 (Toyota Way: ≤10 ✓)
+///
+/// Functions taking strings need memory even if they contain no literal
 pub fn needs_memory(expr: &Expr) -> bool {
     match &expr.kind {
         ExprKind::Literal(Literal::String(_)) => true,
@@ -53,7 +65,9 @@ pub fn needs_memory(expr: &Expr) -> bool {
         ExprKind::Tuple(_) => true, // Tuples need memory allocation
         ExprKind::StructLiteral { .. } => true, // Structs need memory allocation
         ExprKind::Block(exprs) => exprs.iter().any(needs_memory),
-        ExprKind::Function { body, .. } => needs_memory(body),
+        ExprKind::Function { params, body, .. } => {
+            params.iter().any(|param| is_string_type(&param.ty)) || needs_memory(body)
+        }
         ExprKind::Let { value, body, .. } => needs_memory(value) || needs_memory(body),
         ExprKind::LetPattern { value, body, .. } => needs_memory(value) || needs_memory(body),
         ExprKind::Binary { left, right, .. } => needs_memory(left) || needs_memory(right),
@@ -66,6 +80,28 @@ pub fn needs_memory(expr: &Expr) -> bool {
                 || needs_memory(then_branch)
                 || else_branch.as_ref().is_some_and(|e| needs_memory(e))
         }
+        _ => statement_needs_memory(expr),
+    }
+}
+
+/// `needs_memory` for calls, loops and statements
+/// Complexity: 4 (Toyota Way: <10 ✓)
+fn statement_needs_memory(expr: &Expr) -> bool {
+    match &expr.kind {
+        ExprKind::Call { args, .. } => args.iter().any(needs_memory),
+        ExprKind::While { body, .. } | ExprKind::For { body, .. } => needs_memory(body),
+        ExprKind::Return { value: Some(value) }
+        | ExprKind::Assign { value, .. }
+        | ExprKind::CompoundAssign { value, .. } => needs_memory(value),
+        _ => false,
+    }
+}
+
+/// Whether a parameter or return type annotation names a string type
+pub fn is_string_type(ty: &Type) -> bool {
+    match &ty.kind {
+        TypeKind::Named(name) => matches!(name.as_str(), "String" | "str"),
+        TypeKind::Reference { inner, .. } => is_string_type(inner),
         _ => false,
     }
 }
@@ -98,6 +134,7 @@ pub fn has_return_with_value(expr: &Expr) -> bool {
         ExprKind::While {
             condition, body, ..
         } => has_return_with_value(condition) || has_return_with_value(body),
+        ExprKind::For { body, .. } => has_return_with_value(body),
         ExprKind::Function { .. } => false, // Functions are compiled separately
         ExprKind::Let { value, body, .. } => {
             has_return_with_value(value) || has_return_with_value(body)
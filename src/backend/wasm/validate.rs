@@ -0,0 +1,73 @@
+//! Streaming validation of emitted modules
+//!
+//! Validates a module payload by payload, checking each function body as its
+//! code entry is reached, so a failure names the function it occurred in.
+
+use wasmparser::{FuncValidatorAllocations, Parser, ValidPayload, Validator};
+
+/// Validate `wasm`, returning the number of function bodies checked
+///
+/// # Errors
+///
+/// Returns the first validation error, prefixed with the index of the
+/// function whose body is invalid when the error is inside code.
+pub fn validate_streaming(wasm: &[u8]) -> Result<u32, String> {
+    let mut validator = Validator::new();
+    let mut allocations = FuncValidatorAllocations::default();
+    let mut functions = 0;
+    for payload in Parser::new(0).parse_all(wasm) {
+        let payload = payload.map_err(|e| e.to_string())?;
+        match validator.payload(&payload).map_err(|e| e.to_string())? {
+            ValidPayload::Func(func, body) => {
+                let index = func.index;
+                let mut func = func.into_validator(allocations);
+                func.validate(&body)
+                    .map_err(|e| format!("function {index}: {e}"))?;
+                allocations = func.into_allocations();
+                functions += 1;
+            }
+            ValidPayload::End(_) => break,
+            _ => {}
+        }
+    }
+    Ok(functions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::wasm::WasmEmitter;
+    use crate::frontend::parser::Parser as RuchyParser;
+
+    #[test]
+    fn test_validates_emitted_module() {
+        let ast = RuchyParser::new("fun add(a, b) { a + b }\nadd(1, 2)")
+            .parse()
+            .expect("source should parse");
+        let wasm = WasmEmitter::new()
+            .with_fuel(Some(10))
+            .emit(&ast)
+            .expect("source should compile");
+        assert_eq!(validate_streaming(&wasm), Ok(2));
+    }
+
+    #[test]
+    fn test_reports_function_of_invalid_body() {
+        use wasm_encoder::{
+            CodeSection, Function, FunctionSection, Instruction, Module, TypeSection,
+        };
+        let mut types = TypeSection::new();
+        types.function(vec![], vec![wasm_encoder::ValType::I32]);
+        let mut functions = FunctionSection::new();
+        functions.function(0);
+        let mut body = Function::new(vec![]);
+        body.instruction(&Instruction::End);
+        let mut codes = CodeSection::new();
+        codes.function(&body);
+        let mut module = Module::new();
+        module.section(&types).section(&functions).section(&codes);
+
+        let err = validate_streaming(&module.finish()).unwrap_err();
+        assert!(err.starts_with("function 0: "), "{err}");
+    }
+}
@@ -0,0 +1,752 @@
+//! WASI preview 1 target: builtins implemented with WASI imports
+//!
+//! With [`super::WasmEmitter::with_wasi`], modules import nothing from the
+//! `ruchy` host module. Each host function is instead defined in the module
+//! as a shim over the `wasi_snapshot_preview1` imports, so any WASI runtime
+//! (`wasmtime out.wasm`) runs the program:
+//!
+//! | Ruchy source                | Shim                     | WASI calls                                            |
+//! |-----------------------------|--------------------------|-------------------------------------------------------|
+//! | `println(x)`, `print(x)`    | `__ruchy_wasi_print_*`   | `fd_write` to fd 1                                    |
+//! | `eprintln(x)`, `eprint(x)`  | `__ruchy_wasi_print_*`   | `fd_write` to fd 2                                    |
+//! | `timestamp()`               | `__ruchy_wasi_now`       | `clock_time_get` on the monotonic clock               |
+//! | `random()`                  | `__ruchy_wasi_random`    | `random_get`                                          |
+//! | `read_file(path)`           | `__ruchy_wasi_read_file` | `path_open`, `fd_filestat_get`, `fd_read`, `fd_close` |
+//!
+//! Every print writes its value and a newline, like the host functions.
+//! Floats print with at most six decimals, and integer parts beyond `u64`
+//! saturate. `timestamp()` is in milliseconds and wraps around `i32`, so
+//! only differences between readings are meaningful.
+//!
+//! `read_file` opens `path` relative to the first preopened directory
+//! (fd 3, `wasmtime --dir .`) and returns its contents as a heap string.
+//! A missing or unreadable file traps.
+//!
+//! The shims allocate scratch space with the runtime allocator (see
+//! [`super::memory`]) and free it before returning, so modules that use
+//! builtins always have memory. The module exports a `_start` function that
+//! runs `main`, or the top-level code when there is no `main`.
+
+use wasm_encoder::{BlockType, Function, Instruction, MemArg, ValType};
+
+use super::host::HostFunction;
+use super::memory::{HEAP_POINTER_GLOBAL, STRING_HEADER_SIZE};
+
+/// Import module name of the WASI functions
+pub const WASI_MODULE: &str = "wasi_snapshot_preview1";
+
+/// Export name of the entry point WASI runtimes call
+pub const START_EXPORT_NAME: &str = "_start";
+
+/// Builtin reading a file into a string, available on the WASI target only
+pub const READ_FILE_BUILTIN: &str = "read_file";
+
+/// File descriptor of the first preopened directory
+const PREOPENED_DIR_FD: i32 = 3;
+
+/// `lookupflags::symlink_follow`
+const LOOKUP_SYMLINK_FOLLOW: i32 = 1;
+
+/// `rights::fd_read | rights::fd_filestat_get`
+const READ_RIGHTS: i64 = (1 << 1) | (1 << 21);
+
+/// `clockid::monotonic`
+const MONOTONIC_CLOCK: i32 = 1;
+
+/// Offset of `size` in a `filestat`
+const FILESTAT_SIZE_OFFSET: u64 = 32;
+
+/// A function imported from [`WASI_MODULE`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WasiImport {
+    FdWrite,
+    FdRead,
+    FdClose,
+    FdFilestatGet,
+    PathOpen,
+    ClockTimeGet,
+    RandomGet,
+}
+
+/// Number of WASI imports; they occupy the first function and type indices
+pub const WASI_IMPORT_COUNT: u32 = WasiImport::ALL.len() as u32;
+
+impl WasiImport {
+    /// All WASI imports, in import order
+    pub const ALL: [Self; 7] = [
+        Self::FdWrite,
+        Self::FdRead,
+        Self::FdClose,
+        Self::FdFilestatGet,
+        Self::PathOpen,
+        Self::ClockTimeGet,
+        Self::RandomGet,
+    ];
+
+    /// Import name
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::FdWrite => "fd_write",
+            Self::FdRead => "fd_read",
+            Self::FdClose => "fd_close",
+            Self::FdFilestatGet => "fd_filestat_get",
+            Self::PathOpen => "path_open",
+            Self::ClockTimeGet => "clock_time_get",
+            Self::RandomGet => "random_get",
+        }
+    }
+
+    /// Function index, which is also the index of its type
+    pub fn index(self) -> u32 {
+        self as u32
+    }
+
+    /// Parameter types
+    pub fn params(self) -> &'static [ValType] {
+        use ValType::{I32, I64};
+        match self {
+            Self::FdWrite | Self::FdRead => &[I32, I32, I32, I32],
+            Self::FdClose => &[I32],
+            Self::FdFilestatGet | Self::RandomGet => &[I32, I32],
+            Self::PathOpen => &[I32, I32, I32, I32, I32, I64, I64, I32, I32],
+            Self::ClockTimeGet => &[I32, I64, I32],
+        }
+    }
+
+    /// Result types: every WASI function returns an `errno`
+    pub fn results(self) -> &'static [ValType] {
+        &[ValType::I32]
+    }
+}
+
+/// A function defined in WASI modules in place of a host function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WasiShim {
+    /// Print an integer or bool and a newline to a file descriptor
+    PrintI32,
+    /// Print a float and a newline to a file descriptor
+    PrintF32,
+    /// Print UTF-8 text from memory and a newline to a file descriptor
+    PrintStr,
+    /// Milliseconds on the monotonic clock
+    Now,
+    /// Uniform random float in `[0, 1)`
+    Random,
+    /// Read a file into a new string
+    ReadFile,
+}
+
+/// Number of functions before the user functions of a WASI module that uses builtins
+pub const WASI_FUNCTION_COUNT: u32 = WASI_IMPORT_COUNT + WasiShim::ALL.len() as u32;
+
+impl WasiShim {
+    /// All shims, in the order they are defined after the imports
+    pub const ALL: [Self; 6] = [
+        Self::PrintI32,
+        Self::PrintF32,
+        Self::PrintStr,
+        Self::Now,
+        Self::Random,
+        Self::ReadFile,
+    ];
+
+    /// Shim standing in for a host function
+    pub fn for_host(function: HostFunction) -> Self {
+        match function {
+            HostFunction::PrintlnI32 => Self::PrintI32,
+            HostFunction::PrintlnF32 => Self::PrintF32,
+            HostFunction::PrintlnStr => Self::PrintStr,
+            HostFunction::Now => Self::Now,
+            HostFunction::Random => Self::Random,
+        }
+    }
+
+    /// Debug name
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::PrintI32 => "__ruchy_wasi_print_i32",
+            Self::PrintF32 => "__ruchy_wasi_print_f32",
+            Self::PrintStr => "__ruchy_wasi_print_str",
+            Self::Now => "__ruchy_wasi_now",
+            Self::Random => "__ruchy_wasi_random",
+            Self::ReadFile => "__ruchy_wasi_read_file",
+        }
+    }
+
+    /// Function index, which is also the index of its type
+    pub fn index(self) -> u32 {
+        WASI_IMPORT_COUNT + self as u32
+    }
+
+    /// Parameter types; prints take the file descriptor first
+    pub fn params(self) -> &'static [ValType] {
+        match self {
+            Self::PrintI32 => &[ValType::I32, ValType::I32],
+            Self::PrintF32 => &[ValType::I32, ValType::F32],
+            Self::PrintStr => &[ValType::I32, ValType::I32, ValType::I32],
+            Self::Now | Self::Random => &[],
+            Self::ReadFile => &[ValType::I32],
+        }
+    }
+
+    /// Result types
+    pub fn results(self) -> &'static [ValType] {
+        match self {
+            Self::PrintI32 | Self::PrintF32 | Self::PrintStr => &[],
+            Self::Now | Self::ReadFile => &[ValType::I32],
+            Self::Random => &[ValType::F32],
+        }
+    }
+
+    /// Body of the shim, given the index of the runtime allocator
+    pub fn body(self, alloc: u32) -> Function {
+        let (locals, instructions) = match self {
+            Self::PrintI32 => (
+                vec![(2, ValType::I32), (1, ValType::I64)],
+                print_i32_body(alloc),
+            ),
+            Self::PrintF32 => (
+                vec![(3, ValType::I32), (2, ValType::I64), (1, ValType::F64)],
+                print_f32_body(alloc),
+            ),
+            Self::PrintStr => (vec![(1, ValType::I32)], print_str_body(alloc)),
+            Self::Now => (vec![(1, ValType::I32)], now_body(alloc)),
+            Self::Random => (vec![(1, ValType::I32)], random_body(alloc)),
+            Self::ReadFile => (vec![(5, ValType::I32)], read_file_body(alloc)),
+        };
+        let mut function = Function::new(locals);
+        for instruction in &instructions {
+            function.instruction(instruction);
+        }
+        function.instruction(&Instruction::End);
+        function
+    }
+}
+
+/// File descriptor a print builtin writes to: stderr for `eprint*`, else stdout
+pub fn stream(builtin: &str) -> i32 {
+    if builtin.starts_with('e') {
+        2
+    } else {
+        1
+    }
+}
+
+/// Body of `_start`: call the entry function, dropping its result
+pub fn start_body(entry: u32, returns_value: bool) -> Function {
+    let mut function = Function::new(Vec::new());
+    function.instruction(&Instruction::Call(entry));
+    if returns_value {
+        function.instruction(&Instruction::Drop);
+    }
+    function.instruction(&Instruction::End);
+    function
+}
+
+/// Access to the word at `offset`
+fn at(offset: u64) -> MemArg {
+    MemArg {
+        offset,
+        align: 2,
+        memory_index: 0,
+    }
+}
+
+/// Access to the byte at `offset`
+fn byte() -> MemArg {
+    MemArg {
+        offset: 0,
+        align: 0,
+        memory_index: 0,
+    }
+}
+
+/// Allocate `size` bytes into `local`, then free them: the heap pointer goes
+/// back to `local`, which stays usable until the next allocation
+fn alloc_scratch(alloc: u32, local: u32, size: i32) -> Vec<Instruction<'static>> {
+    vec![
+        Instruction::I32Const(size),
+        Instruction::Call(alloc),
+        Instruction::LocalTee(local),
+        Instruction::GlobalSet(HEAP_POINTER_GLOBAL),
+    ]
+}
+
+/// Trap unless the `errno` on the stack is 0
+fn check_errno() -> Vec<Instruction<'static>> {
+    vec![
+        Instruction::If(BlockType::Empty),
+        Instruction::Unreachable,
+        Instruction::End,
+    ]
+}
+
+/// Move `pos` back one byte and store the byte computed by `value` there
+fn push_byte(pos: u32, value: Vec<Instruction<'static>>) -> Vec<Instruction<'static>> {
+    let mut instructions = vec![
+        Instruction::LocalGet(pos),
+        Instruction::I32Const(1),
+        Instruction::I32Sub,
+        Instruction::LocalTee(pos),
+    ];
+    instructions.extend(value);
+    instructions.push(Instruction::I32Store8(byte()));
+    instructions
+}
+
+/// Store `text` just before `pos`, moving `pos` to its start
+fn push_text(pos: u32, text: &str) -> Vec<Instruction<'static>> {
+    text.bytes()
+        .rev()
+        .flat_map(|b| push_byte(pos, vec![Instruction::I32Const(i32::from(b))]))
+        .collect()
+}
+
+/// Store the decimal digits of the `i64` local `n` (unsigned) before `pos`; clears `n`
+fn push_digits(pos: u32, n: u32) -> Vec<Instruction<'static>> {
+    let mut instructions = vec![Instruction::Loop(BlockType::Empty)];
+    instructions.extend(push_byte(
+        pos,
+        vec![
+            Instruction::LocalGet(n),
+            Instruction::I64Const(10),
+            Instruction::I64RemU,
+            Instruction::I32WrapI64,
+            Instruction::I32Const(i32::from(b'0')),
+            Instruction::I32Add,
+        ],
+    ));
+    instructions.extend([
+        Instruction::LocalGet(n),
+        Instruction::I64Const(10),
+        Instruction::I64DivU,
+        Instruction::LocalTee(n),
+        Instruction::I64Const(0),
+        Instruction::I64Ne,
+        Instruction::BrIf(0),
+        Instruction::End,
+    ]);
+    instructions
+}
+
+/// Write the text from `pos` to `scratch + end` to the file descriptor in
+/// local 0, with the iovec and `nwritten` in the first 12 bytes of `scratch`
+fn write_text(scratch: u32, pos: u32, end: i32) -> Vec<Instruction<'static>> {
+    vec![
+        Instruction::LocalGet(scratch),
+        Instruction::LocalGet(pos),
+        Instruction::I32Store(at(0)),
+        Instruction::LocalGet(scratch),
+        Instruction::LocalGet(scratch),
+        Instruction::I32Const(end),
+        Instruction::I32Add,
+        Instruction::LocalGet(pos),
+        Instruction::I32Sub,
+        Instruction::I32Store(at(4)),
+        Instruction::LocalGet(0),
+        Instruction::LocalGet(scratch),
+        Instruction::I32Const(1),
+        Instruction::LocalGet(scratch),
+        Instruction::I32Const(8),
+        Instruction::I32Add,
+        Instruction::Call(WasiImport::FdWrite.index()),
+        Instruction::Drop,
+    ]
+}
+
+/// Point `pos` at the last byte of `scratch + end` and store a newline there
+fn start_line(scratch: u32, pos: u32, end: i32) -> Vec<Instruction<'static>> {
+    let mut instructions = vec![
+        Instruction::LocalGet(scratch),
+        Instruction::I32Const(end),
+        Instruction::I32Add,
+        Instruction::LocalSet(pos),
+    ];
+    instructions.extend(push_text(pos, "\n"));
+    instructions
+}
+
+/// `print_i32(fd, value)`: locals are `scratch`, `pos` and the magnitude `n`
+fn print_i32_body(alloc: u32) -> Vec<Instruction<'static>> {
+    let (value, scratch, pos, n) = (1, 2, 3, 4);
+    let end = 48;
+    let mut instructions = alloc_scratch(alloc, scratch, end);
+    instructions.extend(start_line(scratch, pos, end));
+    // The magnitude as an i64, so i32::MIN has one
+    instructions.extend([
+        Instruction::LocalGet(value),
+        Instruction::I64ExtendI32S,
+        Instruction::LocalSet(n),
+        Instruction::LocalGet(value),
+        Instruction::I32Const(0),
+        Instruction::I32LtS,
+        Instruction::If(BlockType::Empty),
+        Instruction::I64Const(0),
+        Instruction::LocalGet(n),
+        Instruction::I64Sub,
+        Instruction::LocalSet(n),
+        Instruction::End,
+    ]);
+    instructions.extend(push_digits(pos, n));
+    instructions.extend([
+        Instruction::LocalGet(value),
+        Instruction::I32Const(0),
+        Instruction::I32LtS,
+        Instruction::If(BlockType::Empty),
+    ]);
+    instructions.extend(push_text(pos, "-"));
+    instructions.push(Instruction::End);
+    instructions.extend(write_text(scratch, pos, end));
+    instructions
+}
+
+/// `print_f32(fd, value)`: locals are `scratch`, `pos`, `decimals`, the
+/// integer part, the fraction in millionths and the magnitude
+fn print_f32_body(alloc: u32) -> Vec<Instruction<'static>> {
+    let (value, scratch, pos, decimals, int, frac, x) = (1, 2, 3, 4, 5, 6, 7);
+    let end = 64;
+    let mut instructions = alloc_scratch(alloc, scratch, end);
+    instructions.extend(start_line(scratch, pos, end));
+    instructions.extend([
+        Instruction::LocalGet(value),
+        Instruction::F64PromoteF32,
+        Instruction::F64Abs,
+        Instruction::LocalSet(x),
+        // NaN is the only value not equal to itself
+        Instruction::LocalGet(value),
+        Instruction::LocalGet(value),
+        Instruction::F32Ne,
+        Instruction::If(BlockType::Empty),
+    ]);
+    instructions.extend(push_text(pos, "NaN"));
+    instructions.extend([
+        Instruction::Else,
+        Instruction::LocalGet(x),
+        Instruction::F64Const(f64::INFINITY),
+        Instruction::F64Eq,
+        Instruction::If(BlockType::Empty),
+    ]);
+    instructions.extend(push_text(pos, "inf"));
+    instructions.push(Instruction::Else);
+    instructions.extend(print_finite_f64(pos, decimals, int, frac, x));
+    instructions.extend([
+        Instruction::End,
+        Instruction::End,
+        Instruction::LocalGet(value),
+        Instruction::F32Const(0.0),
+        Instruction::F32Lt,
+        Instruction::If(BlockType::Empty),
+    ]);
+    instructions.extend(push_text(pos, "-"));
+    instructions.push(Instruction::End);
+    instructions.extend(write_text(scratch, pos, end));
+    instructions
+}
+
+/// Digits of the finite magnitude `x`: the integer part, then a point and
+/// up to six decimals without trailing zeros when the fraction is not zero
+fn print_finite_f64(
+    pos: u32,
+    decimals: u32,
+    int: u32,
+    frac: u32,
+    x: u32,
+) -> Vec<Instruction<'static>> {
+    let mut instructions = vec![
+        Instruction::LocalGet(x),
+        Instruction::F64Floor,
+        Instruction::I64TruncSatF64U,
+        Instruction::LocalSet(int),
+        Instruction::LocalGet(x),
+        Instruction::LocalGet(x),
+        Instruction::F64Floor,
+        Instruction::F64Sub,
+        Instruction::F64Const(1e6),
+        Instruction::F64Mul,
+        Instruction::F64Nearest,
+        Instruction::I64TruncSatF64U,
+        Instruction::LocalTee(frac),
+        // Rounding up to a whole million carries into the integer part
+        Instruction::I64Const(1_000_000),
+        Instruction::I64GeU,
+        Instruction::If(BlockType::Empty),
+        Instruction::LocalGet(frac),
+        Instruction::I64Const(1_000_000),
+        Instruction::I64Sub,
+        Instruction::LocalSet(frac),
+        Instruction::LocalGet(int),
+        Instruction::I64Const(1),
+        Instruction::I64Add,
+        Instruction::LocalSet(int),
+        Instruction::End,
+        Instruction::LocalGet(frac),
+        Instruction::I64Const(0),
+        Instruction::I64Ne,
+        Instruction::If(BlockType::Empty),
+        Instruction::I32Const(6),
+        Instruction::LocalSet(decimals),
+        // Drop trailing zeros
+        Instruction::Block(BlockType::Empty),
+        Instruction::Loop(BlockType::Empty),
+        Instruction::LocalGet(frac),
+        Instruction::I64Const(10),
+        Instruction::I64RemU,
+        Instruction::I64Const(0),
+        Instruction::I64Ne,
+        Instruction::BrIf(1),
+        Instruction::LocalGet(frac),
+        Instruction::I64Const(10),
+        Instruction::I64DivU,
+        Instruction::LocalSet(frac),
+        Instruction::LocalGet(decimals),
+        Instruction::I32Const(1),
+        Instruction::I32Sub,
+        Instruction::LocalSet(decimals),
+        Instruction::Br(0),
+        Instruction::End,
+        Instruction::End,
+        // Exactly `decimals` digits, keeping leading zeros
+        Instruction::Loop(BlockType::Empty),
+    ];
+    instructions.extend(push_byte(
+        pos,
+        vec![
+            Instruction::LocalGet(frac),
+            Instruction::I64Const(10),
+            Instruction::I64RemU,
+            Instruction::I32WrapI64,
+            Instruction::I32Const(i32::from(b'0')),
+            Instruction::I32Add,
+        ],
+    ));
+    instructions.extend([
+        Instruction::LocalGet(frac),
+        Instruction::I64Const(10),
+        Instruction::I64DivU,
+        Instruction::LocalSet(frac),
+        Instruction::LocalGet(decimals),
+        Instruction::I32Const(1),
+        Instruction::I32Sub,
+        Instruction::LocalTee(decimals),
+        Instruction::BrIf(0),
+        Instruction::End,
+    ]);
+    instructions.extend(push_text(pos, "."));
+    instructions.push(Instruction::End);
+    instructions.extend(push_digits(pos, int));
+    instructions
+}
+
+/// `print_str(fd, ptr, len)`: the text and a newline, as two iovecs
+fn print_str_body(alloc: u32) -> Vec<Instruction<'static>> {
+    let (ptr, len, scratch) = (1, 2, 3);
+    let mut instructions = alloc_scratch(alloc, scratch, 24);
+    instructions.extend([
+        Instruction::LocalGet(scratch),
+        Instruction::LocalGet(ptr),
+        Instruction::I32Store(at(0)),
+        Instruction::LocalGet(scratch),
+        Instruction::LocalGet(len),
+        Instruction::I32Store(at(4)),
+        Instruction::LocalGet(scratch),
+        Instruction::LocalGet(scratch),
+        Instruction::I32Const(16),
+        Instruction::I32Add,
+        Instruction::I32Store(at(8)),
+        Instruction::LocalGet(scratch),
+        Instruction::I32Const(1),
+        Instruction::I32Store(at(12)),
+        Instruction::LocalGet(scratch),
+        Instruction::I32Const(i32::from(b'\n')),
+        Instruction::I32Store8(MemArg {
+            offset: 16,
+            align: 0,
+            memory_index: 0,
+        }),
+        Instruction::LocalGet(0),
+        Instruction::LocalGet(scratch),
+        Instruction::I32Const(2),
+        Instruction::LocalGet(scratch),
+        Instruction::I32Const(20),
+        Instruction::I32Add,
+        Instruction::Call(WasiImport::FdWrite.index()),
+        Instruction::Drop,
+    ]);
+    instructions
+}
+
+/// `now()`: nanoseconds on the monotonic clock, in milliseconds
+fn now_body(alloc: u32) -> Vec<Instruction<'static>> {
+    let scratch_local = 0;
+    let mut instructions = alloc_scratch(alloc, scratch_local, 8);
+    instructions.extend([
+        Instruction::I32Const(MONOTONIC_CLOCK),
+        Instruction::I64Const(1_000),
+        Instruction::LocalGet(scratch_local),
+        Instruction::Call(WasiImport::ClockTimeGet.index()),
+        Instruction::Drop,
+        Instruction::LocalGet(scratch_local),
+        Instruction::I64Load(MemArg {
+            offset: 0,
+            align: 3,
+            memory_index: 0,
+        }),
+        Instruction::I64Const(1_000_000),
+        Instruction::I64DivU,
+        Instruction::I32WrapI64,
+    ]);
+    instructions
+}
+
+/// `random()`: 24 random bits scaled to `[0, 1)`
+fn random_body(alloc: u32) -> Vec<Instruction<'static>> {
+    let scratch_local = 0;
+    let mut instructions = alloc_scratch(alloc, scratch_local, 4);
+    instructions.extend([
+        Instruction::LocalGet(scratch_local),
+        Instruction::I32Const(4),
+        Instruction::Call(WasiImport::RandomGet.index()),
+        Instruction::Drop,
+        Instruction::LocalGet(scratch_local),
+        Instruction::I32Load(at(0)),
+        Instruction::I32Const(8),
+        Instruction::I32ShrU,
+        Instruction::F32ConvertI32U,
+        Instruction::F32Const(1.0 / 16_777_216.0),
+        Instruction::F32Mul,
+    ]);
+    instructions
+}
+
+/// `read_file(path)`: locals are `scratch`, `fd`, `size`, the result and the bytes read
+///
+/// The file is opened in the first preopened directory and its size taken
+/// from its `filestat`; the result is allocated once, then filled by
+/// `fd_read` until the file ends.
+fn read_file_body(alloc: u32) -> Vec<Instruction<'static>> {
+    let (path, scratch_local, fd, size, out, done) = (0, 1, 2, 3, 4, 5);
+    let header = STRING_HEADER_SIZE as i32;
+    let mut instructions = alloc_scratch(alloc, scratch_local, 72);
+    instructions.extend([
+        Instruction::I32Const(PREOPENED_DIR_FD),
+        Instruction::I32Const(LOOKUP_SYMLINK_FOLLOW),
+        Instruction::LocalGet(path),
+        Instruction::I32Const(header),
+        Instruction::I32Add,
+        Instruction::LocalGet(path),
+        Instruction::I32Load(at(0)),
+        Instruction::I32Const(0),
+        Instruction::I64Const(READ_RIGHTS),
+        Instruction::I64Const(0),
+        Instruction::I32Const(0),
+        Instruction::LocalGet(scratch_local),
+        Instruction::Call(WasiImport::PathOpen.index()),
+    ]);
+    instructions.extend(check_errno());
+    instructions.extend([
+        Instruction::LocalGet(scratch_local),
+        Instruction::I32Load(at(0)),
+        Instruction::LocalSet(fd),
+        Instruction::LocalGet(fd),
+        Instruction::LocalGet(scratch_local),
+        Instruction::I32Const(8),
+        Instruction::I32Add,
+        Instruction::Call(WasiImport::FdFilestatGet.index()),
+    ]);
+    instructions.extend(check_errno());
+    instructions.extend([
+        Instruction::LocalGet(scratch_local),
+        Instruction::I32Load(at(8 + FILESTAT_SIZE_OFFSET)),
+        Instruction::LocalSet(size),
+        // The result, then scratch space for the iovec after it
+        Instruction::LocalGet(size),
+        Instruction::I32Const(header),
+        Instruction::I32Add,
+        Instruction::Call(alloc),
+        Instruction::LocalSet(out),
+    ]);
+    instructions.extend(alloc_scratch(alloc, scratch_local, 12));
+    instructions.extend([
+        Instruction::Block(BlockType::Empty),
+        Instruction::Loop(BlockType::Empty),
+        Instruction::LocalGet(done),
+        Instruction::LocalGet(size),
+        Instruction::I32GeU,
+        Instruction::BrIf(1),
+        Instruction::LocalGet(scratch_local),
+        Instruction::LocalGet(out),
+        Instruction::I32Const(header),
+        Instruction::I32Add,
+        Instruction::LocalGet(done),
+        Instruction::I32Add,
+        Instruction::I32Store(at(0)),
+        Instruction::LocalGet(scratch_local),
+        Instruction::LocalGet(size),
+        Instruction::LocalGet(done),
+        Instruction::I32Sub,
+        Instruction::I32Store(at(4)),
+        Instruction::LocalGet(fd),
+        Instruction::LocalGet(scratch_local),
+        Instruction::I32Const(1),
+        Instruction::LocalGet(scratch_local),
+        Instruction::I32Const(8),
+        Instruction::I32Add,
+        Instruction::Call(WasiImport::FdRead.index()),
+    ]);
+    instructions.extend(check_errno());
+    instructions.extend([
+        // A read of zero bytes is the end of the file
+        Instruction::LocalGet(scratch_local),
+        Instruction::I32Load(at(8)),
+        Instruction::I32Eqz,
+        Instruction::BrIf(1),
+        Instruction::LocalGet(done),
+        Instruction::LocalGet(scratch_local),
+        Instruction::I32Load(at(8)),
+        Instruction::I32Add,
+        Instruction::LocalSet(done),
+        Instruction::Br(0),
+        Instruction::End,
+        Instruction::End,
+        Instruction::LocalGet(out),
+        Instruction::LocalGet(done),
+        Instruction::I32Store(at(0)),
+        Instruction::LocalGet(fd),
+        Instruction::Call(WasiImport::FdClose.index()),
+        Instruction::Drop,
+        Instruction::LocalGet(out),
+    ]);
+    instructions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shims_follow_the_imports() {
+        for (i, import) in WasiImport::ALL.iter().enumerate() {
+            assert_eq!(import.index() as usize, i);
+            assert_eq!(import.results(), &[ValType::I32]);
+        }
+        for (i, shim) in WasiShim::ALL.iter().enumerate() {
+            assert_eq!(shim.index(), WASI_IMPORT_COUNT + i as u32);
+            assert!(shim.name().starts_with("__ruchy_wasi_"));
+        }
+        assert_eq!(WASI_FUNCTION_COUNT, 13);
+    }
+
+    #[test]
+    fn test_every_host_function_has_a_shim_with_a_stream() {
+        for function in HostFunction::ALL {
+            let shim = WasiShim::for_host(function);
+            assert_eq!(shim.results(), function.results());
+            let stream_params = usize::from(function.results().is_empty());
+            assert_eq!(shim.params().len(), function.params().len() + stream_params);
+        }
+        assert_eq!(stream("println"), 1);
+        assert_eq!(stream("print"), 1);
+        assert_eq!(stream("eprintln"), 2);
+        assert_eq!(stream("eprint"), 2);
+    }
+}
@@ -0,0 +1,928 @@
+//! Whole-program constant propagation for small programs
+//!
+//! An optional pass over a resolved program (after `mod` and import
+//! resolution, before Rust or WASM emission), enabled with `--whole-program`
+//! on `ruchy transpile`, `ruchy compile` and `ruchy wasm`. Until nothing
+//! changes it:
+//!
+//! 1. **Specializes constant arguments.** A parameter that every call of a
+//!    top-level function passes the same literal for is removed, and the body
+//!    binds it as a local instead.
+//! 2. **Folds constant results.** Calls of a function without parameters
+//!    whose body folds to a literal are replaced by that literal.
+//! 3. **Folds pure calls.** A call whose arguments are all literals is
+//!    replaced by its result when the function is pure (see
+//!    [`crate::middleend::effects`]) and its body folds to a literal once
+//!    the arguments are bound.
+//! 4. **Folds branches.** Constants are propagated through each function
+//!    body and `if`s on constant conditions are resolved (see
+//!    [`constant_folder`]).
+//! 5. **Drops unreachable functions.** Top-level functions not reachable from
+//!    `main`, `pub` functions or top-level statements are removed.
+//!
+//! Functions are only rewritten when every mention of their name is a direct
+//! call found by the pass, so a function passed as a value, shadowed by a
+//! local or named in an unusual position is left alone. Numbers are only
+//! substituted where a declared type keeps the emitted Rust unambiguous.
+//! Programs larger than [`SMALL_PROGRAM_LIMIT`] nodes are left unchanged.
+use crate::backend::transpiler::constant_folder;
+use crate::frontend::ast::{
+    Expr, ExprKind, Literal, Param, Pattern, Span, StringPart, Type, TypeKind,
+};
+use crate::middleend::effects::{infer_effects, FunctionEffects};
+use serde_json::Value as Json;
+use std::collections::HashSet;
+use std::fmt;
+
+/// Largest program, in AST nodes, the pass optimizes
+pub const SMALL_PROGRAM_LIMIT: usize = 5_000;
+
+/// Rounds of the pass before it stops even if something still changes
+const MAX_ROUNDS: usize = 8;
+
+/// Integer type names a literal argument or result can be declared as
+const INTEGER_TYPES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize", "int",
+];
+
+/// What [`optimize`] changed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WholeProgramReport {
+    /// Parameters removed because every call passed the same constant
+    pub specialized_params: usize,
+    /// Calls replaced by the constant their function returns for them
+    pub folded_calls: usize,
+    /// Top-level functions removed as unreachable, in program order
+    pub removed_functions: Vec<String>,
+    /// The program was over [`SMALL_PROGRAM_LIMIT`] and left unchanged
+    pub skipped: bool,
+}
+
+impl fmt::Display for WholeProgramReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.skipped {
+            return write!(
+                f,
+                "whole-program optimization skipped (over {SMALL_PROGRAM_LIMIT} nodes)"
+            );
+        }
+        write!(
+            f,
+            "whole-program optimization: {} constant parameter(s), {} folded call(s), {} unreachable function(s) removed",
+            self.specialized_params,
+            self.folded_calls,
+            self.removed_functions.len()
+        )
+    }
+}
+
+/// Propagate constants across function boundaries and drop unreachable functions
+///
+/// Only a program block is optimized; other expressions are returned as-is.
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::backend::whole_program::optimize;
+/// use ruchy::Parser;
+///
+/// let program = Parser::new(
+///     "fun unused() { 1 }\nfun scale(x: i64, factor: i64) -> i64 { x * factor }\nfun main() { println(scale(2, 10)) }",
+/// )
+/// .parse()
+/// .unwrap();
+/// let (_, report) = optimize(program);
+/// // `scale` loses both parameters, folds to `20` and is inlined into `main`
+/// assert_eq!(report.specialized_params, 2);
+/// assert_eq!(report.folded_calls, 1);
+/// assert_eq!(report.removed_functions, ["unused", "scale"]);
+/// ```
+pub fn optimize(program: Expr) -> (Expr, WholeProgramReport) {
+    let mut report = WholeProgramReport::default();
+    let mut items = match program.kind {
+        ExprKind::Block(items) => items,
+        kind => return (Expr { kind, ..program }, report),
+    };
+    if items.iter().map(node_count).fold(0, usize::saturating_add) > SMALL_PROGRAM_LIMIT {
+        report.skipped = true;
+        return (
+            Expr {
+                kind: ExprKind::Block(items),
+                ..program
+            },
+            report,
+        );
+    }
+    for _ in 0..MAX_ROUNDS {
+        let specialized = specialize_constant_params(&mut items, &mut report);
+        let folded = fold_constant_calls(&mut items, &mut report);
+        let evaluated = fold_pure_calls(&mut items, &mut report);
+        let simplified = fold_function_bodies(&mut items);
+        let removed = remove_unreachable_functions(&mut items, &mut report);
+        if !(specialized || folded || evaluated || simplified || removed) {
+            break;
+        }
+    }
+    (
+        Expr {
+            kind: ExprKind::Block(items),
+            ..program
+        },
+        report,
+    )
+}
+
+/// A top-level function the pass may rewrite
+struct Candidate {
+    index: usize,
+    name: String,
+}
+
+/// Top-level functions other than `main` that are defined once and not generic
+fn candidates(items: &[Expr]) -> Vec<Candidate> {
+    let mut seen = HashSet::new();
+    let mut duplicated = HashSet::new();
+    for item in items {
+        if let ExprKind::Function { name, .. } = &item.kind {
+            if !seen.insert(name.as_str()) {
+                duplicated.insert(name.as_str());
+            }
+        }
+    }
+    items
+        .iter()
+        .enumerate()
+        .filter_map(|(index, item)| match &item.kind {
+            ExprKind::Function {
+                name,
+                type_params,
+                is_async: false,
+                ..
+            } if name != "main"
+                && type_params.is_empty()
+                && !duplicated.contains(name.as_str()) =>
+            {
+                Some(Candidate {
+                    index,
+                    name: name.clone(),
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Remove parameters every call passes the same constant for (complexity: 8)
+fn specialize_constant_params(items: &mut [Expr], report: &mut WholeProgramReport) -> bool {
+    let mut changed = false;
+    for candidate in candidates(items) {
+        let Some(calls) = direct_calls(items, &candidate.name) else {
+            continue;
+        };
+        let ExprKind::Function { params, body, .. } = &mut items[candidate.index].kind else {
+            continue;
+        };
+        if calls.is_empty() || calls.iter().any(|args| args.len() != params.len()) {
+            continue;
+        }
+        let constant: Vec<usize> = (0..params.len())
+            .filter(|&i| shared_literal(&calls, i).is_some_and(|lit| can_bind(&params[i], lit)))
+            .collect();
+        if constant.is_empty() {
+            continue;
+        }
+        let bindings = constant
+            .iter()
+            .map(|&i| {
+                let literal = shared_literal(&calls, i).cloned();
+                bind_param(&params[i], literal.unwrap_or(Literal::Unit))
+            })
+            .collect();
+        prepend_statements(body, bindings);
+        let mut index = 0;
+        params.retain(|_| {
+            index += 1;
+            !constant.contains(&(index - 1))
+        });
+        for item in items.iter_mut() {
+            rewrite_calls(item, &candidate.name, &mut |args| {
+                let mut index = 0;
+                args.retain(|_| {
+                    index += 1;
+                    !constant.contains(&(index - 1))
+                });
+                None
+            });
+        }
+        report.specialized_params += constant.len();
+        changed = true;
+    }
+    changed
+}
+
+/// Replace calls of parameterless functions whose body is a literal (complexity: 6)
+fn fold_constant_calls(items: &mut [Expr], report: &mut WholeProgramReport) -> bool {
+    let mut changed = false;
+    for candidate in candidates(items) {
+        let ExprKind::Function {
+            params,
+            return_type,
+            body,
+            ..
+        } = &items[candidate.index].kind
+        else {
+            continue;
+        };
+        let Some(result) = constant_result(params, return_type.as_ref(), body) else {
+            continue;
+        };
+        let Some(calls) = direct_calls(items, &candidate.name) else {
+            continue;
+        };
+        if calls.is_empty() {
+            continue;
+        }
+        for item in items.iter_mut() {
+            rewrite_calls(item, &candidate.name, &mut |_| {
+                Some(ExprKind::Literal(result.clone()))
+            });
+        }
+        report.folded_calls += calls.len();
+        changed = true;
+    }
+    changed
+}
+
+/// Replace calls of pure functions whose arguments are all literals by the
+/// literal the call evaluates to (complexity: 7)
+fn fold_pure_calls(items: &mut [Expr], report: &mut WholeProgramReport) -> bool {
+    let program = Expr::new(ExprKind::Block(items.to_vec()), Span::default());
+    let effects = infer_effects(&program);
+    let mut changed = false;
+    for candidate in candidates(items) {
+        if !effects
+            .get(&candidate.name)
+            .is_some_and(FunctionEffects::is_pure)
+            || direct_calls(items, &candidate.name).is_none()
+        {
+            continue;
+        }
+        let ExprKind::Function {
+            params,
+            return_type,
+            body,
+            ..
+        } = &items[candidate.index].kind
+        else {
+            continue;
+        };
+        if params.is_empty() || rebinds_let_names(body) {
+            continue;
+        }
+        let (params, return_type, body) = (params.clone(), return_type.clone(), body.clone());
+        let mut folded = 0;
+        for item in items.iter_mut() {
+            rewrite_calls(item, &candidate.name, &mut |args| {
+                let result = call_result(&params, return_type.as_ref(), &body, args)?;
+                folded += 1;
+                Some(ExprKind::Literal(result))
+            });
+        }
+        report.folded_calls += folded;
+        changed |= folded > 0;
+    }
+    changed
+}
+
+/// The literal a call with literal `args` returns, if the body folds to one
+fn call_result(
+    params: &[Param],
+    return_type: Option<&Type>,
+    body: &Expr,
+    args: &[Expr],
+) -> Option<Literal> {
+    if args.len() != params.len() {
+        return None;
+    }
+    let bindings = params
+        .iter()
+        .zip(args)
+        .map(|(param, arg)| match &arg.kind {
+            ExprKind::Literal(literal) if can_bind(param, literal) => {
+                Some(bind_param(param, literal.clone()))
+            }
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let mut body = body.clone();
+    prepend_statements(&mut body, bindings);
+    let folded = prune_statements(constant_folder::propagate_constants(body));
+    constant_result(&[], return_type, &folded)
+}
+
+/// Propagate constants and fold branches inside each top-level function
+///
+/// Bodies that rebind a `let` name are skipped: the propagation keeps a
+/// constant in scope across a later binding of the same name.
+fn fold_function_bodies(items: &mut [Expr]) -> bool {
+    let mut changed = false;
+    for item in items.iter_mut() {
+        if let ExprKind::Function { body, .. } = &mut item.kind {
+            if rebinds_let_names(body) {
+                continue;
+            }
+            let folded = prune_statements(constant_folder::propagate_constants((**body).clone()));
+            if folded != **body {
+                **body = folded;
+                changed = true;
+            }
+        }
+    }
+    changed
+}
+
+/// Drop top-level functions no root reaches through mentions of their names
+///
+/// Roots are `main`, `pub` functions and every item that is not a function.
+/// A name counts as mentioned wherever it appears in an item, so anything
+/// that might refer to a function keeps it.
+fn remove_unreachable_functions(items: &mut Vec<Expr>, report: &mut WholeProgramReport) -> bool {
+    let functions: Vec<Option<&str>> = items
+        .iter()
+        .map(|item| match &item.kind {
+            ExprKind::Function { name, is_pub, .. } if name != "main" && !is_pub => {
+                Some(name.as_str())
+            }
+            _ => None,
+        })
+        .collect();
+    let mentions: Vec<HashSet<String>> = items.iter().map(mentioned_names).collect();
+    let mut reachable = vec![false; items.len()];
+    let mut pending: Vec<usize> = (0..items.len())
+        .filter(|&i| functions[i].is_none())
+        .collect();
+    while let Some(i) = pending.pop() {
+        if std::mem::replace(&mut reachable[i], true) {
+            continue;
+        }
+        for (j, name) in functions.iter().enumerate() {
+            if !reachable[j] && name.is_some_and(|name| mentions[i].contains(name)) {
+                pending.push(j);
+            }
+        }
+    }
+    if reachable.iter().all(|&r| r) {
+        return false;
+    }
+    let mut index = 0;
+    items.retain(|item| {
+        index += 1;
+        if reachable[index - 1] {
+            return true;
+        }
+        if let ExprKind::Function { name, .. } = &item.kind {
+            report.removed_functions.push(name.clone());
+        }
+        false
+    });
+    true
+}
+
+/// Argument lists of every direct call of `name`, or `None` when the name is
+/// also mentioned some other way
+fn direct_calls(items: &mut [Expr], name: &str) -> Option<Vec<Vec<Expr>>> {
+    let mut calls = Vec::new();
+    for item in items.iter_mut() {
+        rewrite_calls(item, name, &mut |args| {
+            calls.push(args.clone());
+            None
+        });
+    }
+    // The definition mentions the name once
+    let mentions = items
+        .iter()
+        .map(|item| count_mentions(item, name))
+        .fold(0, usize::saturating_add);
+    (mentions == calls.len() + 1).then_some(calls)
+}
+
+/// Visit every direct call of `name` in `expr`, replacing it when `visit`
+/// returns a new expression
+fn rewrite_calls(
+    expr: &mut Expr,
+    name: &str,
+    visit: &mut dyn FnMut(&mut Vec<Expr>) -> Option<ExprKind>,
+) {
+    if let ExprKind::Call { func, args } = &mut expr.kind {
+        if matches!(&func.kind, ExprKind::Identifier(callee) if callee == name) {
+            for arg in args.iter_mut() {
+                rewrite_calls(arg, name, visit);
+            }
+            if let Some(replacement) = visit(args) {
+                expr.kind = replacement;
+            }
+            return;
+        }
+    }
+    for_each_child_mut(expr, &mut |child| rewrite_calls(child, name, visit));
+}
+
+/// Apply `f` to each direct subexpression of the common expression kinds
+///
+/// Kinds not listed here are not descended into; [`direct_calls`] then sees
+/// fewer calls than mentions and leaves the function alone, and
+/// [`crate::middleend::optimize::optimize`] leaves their contents unoptimized.
+pub(crate) fn for_each_child_mut(expr: &mut Expr, f: &mut dyn FnMut(&mut Expr)) {
+    match &mut expr.kind {
+        ExprKind::Block(exprs)
+        | ExprKind::List(exprs)
+        | ExprKind::Set(exprs)
+        | ExprKind::Tuple(exprs)
+        | ExprKind::Macro { args: exprs, .. }
+        | ExprKind::MacroInvocation { args: exprs, .. } => exprs.iter_mut().for_each(f),
+        ExprKind::Call { func, args } => {
+            f(func);
+            args.iter_mut().for_each(f);
+        }
+        ExprKind::MethodCall { receiver, args, .. } => {
+            f(receiver);
+            args.iter_mut().for_each(f);
+        }
+        ExprKind::StringInterpolation { parts } => {
+            for part in parts {
+                match part {
+                    StringPart::Expr(expr) | StringPart::ExprWithFormat { expr, .. } => f(expr),
+                    StringPart::Text(_) => {}
+                }
+            }
+        }
+        ExprKind::Let {
+            value,
+            body,
+            else_block,
+            ..
+        }
+        | ExprKind::LetPattern {
+            value,
+            body,
+            else_block,
+            ..
+        } => {
+            f(value);
+            f(body);
+            if let Some(else_block) = else_block {
+                f(else_block);
+            }
+        }
+        ExprKind::If {
+            condition: head,
+            then_branch,
+            else_branch,
+        }
+        | ExprKind::IfLet {
+            expr: head,
+            then_branch,
+            else_branch,
+            ..
+        } => {
+            f(head);
+            f(then_branch);
+            if let Some(else_branch) = else_branch {
+                f(else_branch);
+            }
+        }
+        ExprKind::Ternary {
+            condition,
+            true_expr,
+            false_expr,
+        } => {
+            f(condition);
+            f(true_expr);
+            f(false_expr);
+        }
+        ExprKind::Match { expr, arms } => {
+            f(expr);
+            for arm in arms {
+                if let Some(guard) = &mut arm.guard {
+                    f(guard);
+                }
+                f(&mut arm.body);
+            }
+        }
+        ExprKind::TryCatch {
+            try_block,
+            catch_clauses,
+            finally_block,
+        } => {
+            f(try_block);
+            for clause in catch_clauses {
+                f(&mut clause.body);
+            }
+            if let Some(finally_block) = finally_block {
+                f(finally_block);
+            }
+        }
+        ExprKind::Binary { left, right, .. }
+        | ExprKind::Assign {
+            target: left,
+            value: right,
+        }
+        | ExprKind::CompoundAssign {
+            target: left,
+            value: right,
+            ..
+        }
+        | ExprKind::IndexAccess {
+            object: left,
+            index: right,
+        }
+        | ExprKind::Range {
+            start: left,
+            end: right,
+            ..
+        }
+        | ExprKind::While {
+            condition: left,
+            body: right,
+            ..
+        }
+        | ExprKind::For {
+            iter: left,
+            body: right,
+            ..
+        } => {
+            f(left);
+            f(right);
+        }
+        ExprKind::Unary { operand: inner, .. }
+        | ExprKind::Throw { expr: inner }
+        | ExprKind::Ok { value: inner }
+        | ExprKind::Err { error: inner }
+        | ExprKind::Some { value: inner }
+        | ExprKind::TypeCast { expr: inner, .. }
+        | ExprKind::Try { expr: inner }
+        | ExprKind::Await { expr: inner }
+        | ExprKind::FieldAccess { object: inner, .. }
+        | ExprKind::Loop { body: inner, .. }
+        | ExprKind::Function { body: inner, .. }
+        | ExprKind::Lambda { body: inner, .. }
+        | ExprKind::Return { value: Some(inner) }
+        | ExprKind::Break {
+            value: Some(inner), ..
+        } => f(inner),
+        _ => {}
+    }
+}
+
+/// The literal argument `index` of every call shares, if any
+fn shared_literal(calls: &[Vec<Expr>], index: usize) -> Option<&Literal> {
+    let ExprKind::Literal(first) = &calls.first()?.get(index)?.kind else {
+        return None;
+    };
+    calls
+        .iter()
+        .all(|args| matches!(&args[index].kind, ExprKind::Literal(lit) if lit == first))
+        .then_some(first)
+}
+
+/// Whether `param` can become a local bound to `literal`
+fn can_bind(param: &Param, literal: &Literal) -> bool {
+    if param.default_value.is_some() || !matches!(param.pattern, Pattern::Identifier(_)) {
+        return false;
+    }
+    if is_untyped(&param.ty) {
+        // Without a declared type only literals with a single Rust type are safe
+        return matches!(literal, Literal::Bool(_) | Literal::Char(_));
+    }
+    literal_fits(literal, &param.ty)
+}
+
+/// `let [mut] name: T = literal` replacing a parameter
+fn bind_param(param: &Param, literal: Literal) -> Expr {
+    let span = param.span;
+    Expr::new(
+        ExprKind::Let {
+            name: param.name(),
+            type_annotation: (!is_untyped(&param.ty)).then(|| param.ty.clone()),
+            value: Box::new(Expr::new(ExprKind::Literal(literal), span)),
+            body: Box::new(Expr::new(ExprKind::Literal(Literal::Unit), span)),
+            is_mutable: param.is_mutable,
+            else_block: None,
+        },
+        span,
+    )
+}
+
+/// Put `statements` at the start of a function body
+fn prepend_statements(body: &mut Expr, mut statements: Vec<Expr>) {
+    match &mut body.kind {
+        ExprKind::Block(exprs) => {
+            statements.append(exprs);
+            *exprs = statements;
+        }
+        _ => {
+            let span = body.span;
+            let original = std::mem::replace(body, Expr::new(ExprKind::Block(Vec::new()), span));
+            statements.push(original);
+            body.kind = ExprKind::Block(statements);
+        }
+    }
+}
+
+/// The literal a parameterless function always returns, typed for its call sites
+fn constant_result(params: &[Param], return_type: Option<&Type>, body: &Expr) -> Option<Literal> {
+    if !params.is_empty() {
+        return None;
+    }
+    let value = match &body.kind {
+        ExprKind::Block(exprs) if exprs.len() == 1 => &exprs[0],
+        _ => body,
+    };
+    let ExprKind::Literal(literal) = &value.kind else {
+        return None;
+    };
+    match (literal, return_type) {
+        (Literal::Bool(_) | Literal::Char(_), None) => Some(literal.clone()),
+        (Literal::Bool(_) | Literal::Char(_), Some(ty)) if literal_fits(literal, ty) => {
+            Some(literal.clone())
+        }
+        // The suffix keeps method calls on the folded value unambiguous
+        (Literal::Integer(n, None), Some(ty)) if literal_fits(literal, ty) => {
+            let TypeKind::Named(name) = &ty.kind else {
+                return None;
+            };
+            let suffix = if name == "int" { "i64" } else { name.as_str() };
+            Some(Literal::Integer(*n, Some(suffix.to_string())))
+        }
+        _ => None,
+    }
+}
+
+/// Whether `literal` is a value of the declared type `ty`
+fn literal_fits(literal: &Literal, ty: &Type) -> bool {
+    let TypeKind::Named(name) = &ty.kind else {
+        return false;
+    };
+    let name = name.as_str();
+    match literal {
+        Literal::Integer(_, suffix) => {
+            INTEGER_TYPES.contains(&name) && (suffix.is_none() || suffix.as_deref() == Some(name))
+        }
+        Literal::Float(_) => matches!(name, "f32" | "f64" | "float"),
+        Literal::Bool(_) => name == "bool",
+        Literal::Char(_) => name == "char",
+        _ => false,
+    }
+}
+
+fn is_untyped(ty: &Type) -> bool {
+    matches!(&ty.kind, TypeKind::Named(name) if name == "Any" || name == "_")
+}
+
+/// Drop statements without effect: empty blocks, bare literals and immutable
+/// constant bindings the rest of the body no longer mentions
+///
+/// The last statement is kept because it is the body's value.
+fn prune_statements(body: Expr) -> Expr {
+    let statements = match body.kind {
+        ExprKind::Block(statements) => statements,
+        kind => return Expr { kind, ..body },
+    };
+    let last = statements.len().saturating_sub(1);
+    let kept = statements
+        .iter()
+        .enumerate()
+        .filter(|&(index, statement)| {
+            index == last
+                || !match &statement.kind {
+                    ExprKind::Literal(_) => true,
+                    ExprKind::Block(exprs) => exprs.is_empty(),
+                    ExprKind::Let {
+                        name,
+                        value,
+                        body,
+                        is_mutable: false,
+                        else_block: None,
+                        ..
+                    } => {
+                        matches!(value.kind, ExprKind::Literal(_))
+                            && matches!(body.kind, ExprKind::Literal(Literal::Unit))
+                            && statements[index + 1..]
+                                .iter()
+                                .all(|later| count_mentions(later, name) == 0)
+                    }
+                    _ => false,
+                }
+        })
+        .map(|(_, statement)| statement.clone())
+        .collect();
+    Expr {
+        kind: ExprKind::Block(kept),
+        ..body
+    }
+}
+
+/// Whether a name bound by `let` in `body` is bound again or assigned to
+///
+/// Pattern bindings are the `Identifier` objects that are not an
+/// expression's `kind`.
+fn rebinds_let_names(body: &Expr) -> bool {
+    const ASSIGNMENTS: &[&str] = &[
+        "Assign",
+        "CompoundAssign",
+        "PreIncrement",
+        "PostIncrement",
+        "PreDecrement",
+        "PostDecrement",
+    ];
+    fn walk(value: &Json, is_kind: bool, lets: &mut Vec<String>, others: &mut Vec<String>) {
+        match value {
+            Json::Array(values) => values.iter().for_each(|v| walk(v, false, lets, others)),
+            Json::Object(fields) => {
+                for (key, field) in fields {
+                    match (key.as_str(), field) {
+                        ("Let", Json::Object(let_fields)) => {
+                            if let Some(Json::String(name)) = let_fields.get("name") {
+                                lets.push(name.clone());
+                            }
+                        }
+                        ("Identifier", Json::String(name)) if !is_kind => {
+                            others.push(name.clone());
+                        }
+                        ("For", Json::Object(for_fields)) => {
+                            if let Some(Json::String(var)) = for_fields.get("var") {
+                                others.push(var.clone());
+                            }
+                        }
+                        (kind, Json::Object(assign_fields)) if ASSIGNMENTS.contains(&kind) => {
+                            if let Some(Json::String(target)) = assign_fields
+                                .get("target")
+                                .and_then(|target| target.get("kind"))
+                                .and_then(|kind| kind.get("Identifier"))
+                            {
+                                others.push(target.clone());
+                            }
+                        }
+                        _ => {}
+                    }
+                    walk(field, key == "kind", lets, others);
+                }
+            }
+            _ => {}
+        }
+    }
+    let Ok(json) = serde_json::to_value(body) else {
+        return true;
+    };
+    let (mut lets, mut others) = (Vec::new(), Vec::new());
+    walk(&json, false, &mut lets, &mut others);
+    let unique: HashSet<&String> = lets.iter().collect();
+    unique.len() != lets.len() || others.iter().any(|name| unique.contains(name))
+}
+
+/// Number of expression nodes in `expr`
+fn node_count(expr: &Expr) -> usize {
+    fn count(value: &Json) -> usize {
+        match value {
+            Json::Object(fields) => {
+                let node = usize::from(fields.contains_key("kind") && fields.contains_key("span"));
+                node + fields.values().map(count).sum::<usize>()
+            }
+            Json::Array(values) => values.iter().map(count).sum(),
+            _ => 0,
+        }
+    }
+    serde_json::to_value(expr).map_or(usize::MAX, |json| count(&json))
+}
+
+/// How often `name` appears anywhere in `expr`: identifiers, bindings,
+/// fields, methods and string contents alike
+fn count_mentions(expr: &Expr, name: &str) -> usize {
+    fn count(value: &Json, name: &str) -> usize {
+        match value {
+            Json::String(s) => usize::from(s == name),
+            Json::Array(values) => values.iter().map(|v| count(v, name)).sum(),
+            Json::Object(fields) => fields.values().map(|v| count(v, name)).sum(),
+            _ => 0,
+        }
+    }
+    // An expression that cannot be inspected mentions everything
+    serde_json::to_value(expr).map_or(usize::MAX, |json| count(&json, name))
+}
+
+/// Every string appearing in `expr`
+fn mentioned_names(expr: &Expr) -> HashSet<String> {
+    fn collect(value: Json, names: &mut HashSet<String>) {
+        match value {
+            Json::String(s) => {
+                names.insert(s);
+            }
+            Json::Array(values) => values.into_iter().for_each(|v| collect(v, names)),
+            Json::Object(fields) => fields.into_iter().for_each(|(_, v)| collect(v, names)),
+            _ => {}
+        }
+    }
+    let mut names = HashSet::new();
+    if let Ok(json) = serde_json::to_value(expr) {
+        collect(json, &mut names);
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    fn optimized(code: &str) -> (Expr, WholeProgramReport) {
+        optimize(Parser::new(code).parse().expect("should parse"))
+    }
+
+    fn function<'a>(program: &'a Expr, wanted: &str) -> Option<&'a Expr> {
+        let ExprKind::Block(items) = &program.kind else {
+            return None;
+        };
+        items
+            .iter()
+            .find(|item| matches!(&item.kind, ExprKind::Function { name, .. } if name == wanted))
+    }
+
+    #[test]
+    fn test_constant_arguments_become_locals() {
+        let (program, report) = optimized(
+            "fun scale(x: i64, factor: i64) -> i64 { x * factor }\nfun show(n: i64) {\n    println(scale(n, 10))\n    println(scale(n + 1, 10))\n}\nfun main() {\n    show(3)\n    show(4)\n}",
+        );
+        assert_eq!(report.specialized_params, 1);
+        let Some(Expr {
+            kind: ExprKind::Function { params, body, .. },
+            ..
+        }) = function(&program, "scale")
+        else {
+            panic!("scale was removed");
+        };
+        let names: Vec<_> = params.iter().map(Param::name).collect();
+        assert_eq!(names, ["x"]);
+        assert_eq!(count_mentions(body, "factor"), 0, "{body:?}");
+        let show = format!("{:?}", function(&program, "show").unwrap());
+        assert!(!show.contains("Integer(10, None)"), "{show}");
+    }
+
+    #[test]
+    fn test_constant_flag_folds_branch_and_unreachable_function() {
+        let (program, report) = optimized(
+            "fun debug_dump() { println(\"state\") }\nfun step(n: i64, verbose: bool) -> i64 {\n    if verbose { debug_dump() }\n    n + 1\n}\nfun main() { println(step(1, false)) }",
+        );
+        // Once `verbose` is known, the branch goes, then `debug_dump`, then
+        // `step` itself folds to its result
+        assert_eq!(report.removed_functions, ["debug_dump", "step"]);
+        let main = format!("{:?}", function(&program, "main").unwrap());
+        assert!(main.contains("Integer(2, Some(\"i64\"))"), "{main}");
+    }
+
+    #[test]
+    fn test_constant_results_fold_into_callers() {
+        let (program, report) = optimized(
+            "fun limit() -> i32 { 40 + 2 }\nfun enabled() { true }\nfun main() {\n    if enabled() { println(limit().pow(2)) }\n}",
+        );
+        assert_eq!(report.folded_calls, 2);
+        assert_eq!(report.removed_functions, ["enabled", "limit"]);
+        let main = format!("{:?}", function(&program, "main").unwrap());
+        assert!(main.contains("Integer(42, Some(\"i32\"))"), "{main}");
+    }
+
+    #[test]
+    fn test_functions_used_as_values_keep_their_signature() {
+        let (program, report) = optimized(
+            "fun double(x: i64) -> i64 { x * 2 }\nfun main() {\n    println(double(2))\n    println([1, 2].map(double))\n}",
+        );
+        assert_eq!(report, WholeProgramReport::default());
+        assert!(function(&program, "double").is_some());
+    }
+
+    #[test]
+    fn test_pure_calls_with_literal_arguments_fold() {
+        let (program, report) = optimized(
+            "fun square(x: i64) -> i64 { x * x }\nfun shout(x: i64) -> i64 { println(x)\n x }\nfun main() {\n    println(square(3))\n    println(square(4))\n    println(shout(5))\n    println(shout(6))\n}",
+        );
+        assert_eq!(report.folded_calls, 2);
+        assert_eq!(report.removed_functions, ["square"]);
+        let main = format!("{:?}", function(&program, "main").unwrap());
+        assert!(main.contains("Integer(9, Some(\"i64\"))"), "{main}");
+        assert!(main.contains("Integer(16, Some(\"i64\"))"), "{main}");
+        assert!(function(&program, "shout").is_some());
+    }
+
+    #[test]
+    fn test_untyped_numbers_are_not_substituted() {
+        let (_, report) = optimized("fun add(a, b) { a + b }\nfun main() { println(add(1, 2)) }");
+        assert_eq!(report.specialized_params, 0);
+    }
+
+    #[test]
+    fn test_pub_functions_and_scripts_are_roots() {
+        let (program, report) =
+            optimized("pub fun api() { 1 }\nfun helper() { 2 }\nlet x = helper()\nprintln(x)");
+        assert!(report.removed_functions.is_empty(), "{report:?}");
+        assert!(function(&program, "api").is_some());
+    }
+}
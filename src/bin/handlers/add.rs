@@ -1,11 +1,91 @@
 //! Handler for `ruchy add` command (CARGO-003)
 //!
-//! Adds Rust crate dependencies to Ruchy projects
-
-use anyhow::{Context, Result};
+//! Adds Rust crate dependencies to Cargo projects, and Ruchy packages from
+//! the registry to `Ruchy.toml` projects
+
+use anyhow::{bail, Context, Result};
+use ruchy::backend::project::Project;
+use ruchy::package::registry_client::RegistryClient;
+use ruchy::package::resolver::{module_name, resolve, vendor, Lockfile};
+use ruchy::runtime::script::MANIFEST_FILE;
+use std::fs;
 use std::path::Path;
 use std::process::Command;
 
+/// Handle `ruchy add` in the `Ruchy.toml` project in `root` - add a Ruchy
+/// package from the registry
+///
+/// Resolves the package and everything it depends on against `registry`,
+/// vendors the result into `vendor/`, writes `Ruchy.lock` and records the
+/// requirement under `[packages]` in `Ruchy.toml`. Without `version` the
+/// requirement is `^` the selected version.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `dev` is set (Ruchy packages have no dev-dependencies)
+/// - Ruchy.toml is missing or invalid
+/// - The package or a matching version cannot be found, or requirements conflict
+/// - Downloading or writing the packages fails
+///
+/// # Complexity
+///
+/// Complexity: 5 (within Toyota Way limits ≤10)
+pub fn handle_add_package_command(
+    root: &Path,
+    package: &str,
+    version: Option<&str>,
+    dev: bool,
+    registry: &str,
+    verbose: bool,
+) -> Result<()> {
+    if dev {
+        bail!("--dev is only supported in Cargo projects; Ruchy packages have no dev-dependencies");
+    }
+    let project = Project::load(root)?;
+    let client = RegistryClient::new(registry);
+    let mut requirements = project.manifest().packages.clone();
+    requirements.insert(package.to_string(), version.unwrap_or("*").to_string());
+    if verbose {
+        println!(
+            "Resolving {} package(s) against {registry}",
+            requirements.len()
+        );
+    }
+
+    let lock = resolve(&client, &requirements, &Lockfile::load(root)?)?;
+    let locked = lock
+        .get(package)
+        .context("Resolution did not select the added package")?;
+    let requirement = version.map_or_else(|| format!("^{}", locked.version), str::to_string);
+    vendor(&client, &lock, root)?;
+    lock.save(root)?;
+    add_manifest_requirement(&root.join(MANIFEST_FILE), package, &requirement)?;
+
+    println!(
+        "Added {package} v{} to [packages] as \"{requirement}\"",
+        locked.version
+    );
+    println!("Use it with `mod {};`", module_name(package));
+    Ok(())
+}
+
+/// Set `package = "requirement"` under `[packages]`, keeping the rest of
+/// the manifest's formatting
+///
+/// # Complexity
+///
+/// Complexity: 2 (within Toyota Way limits ≤10)
+fn add_manifest_requirement(path: &Path, package: &str, requirement: &str) -> Result<()> {
+    let content = fs::read_to_string(path).context("Failed to read Ruchy.toml")?;
+    let mut doc: toml_edit::DocumentMut = content.parse().context("Failed to parse Ruchy.toml")?;
+    if !doc.contains_table("packages") {
+        doc["packages"] = toml_edit::table();
+    }
+    doc["packages"][package] = toml_edit::value(requirement);
+    fs::write(path, doc.to_string()).context("Failed to write Ruchy.toml")
+}
+
 /// Handle `ruchy add` command - add a Rust crate dependency to the project
 ///
 /// # Arguments
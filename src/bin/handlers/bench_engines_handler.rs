@@ -0,0 +1,586 @@
+//! Engine Comparison Benchmark Handler
+//!
+//! `ruchy bench --engines` runs a built-in corpus of micro and macro
+//! benchmarks on every execution engine (AST interpreter, bytecode VM,
+//! rustc-compiled binary and WebAssembly) and prints a comparison table.
+//! With `--history`, each run is appended to a JSON Lines file and the table
+//! shows the change against the previous run.
+
+use anyhow::{Context, Result};
+use ruchy::frontend::ast::Expr;
+use ruchy::runtime::bytecode::{BytecodeChunk, Compiler, VM};
+use ruchy::runtime::interpreter::Interpreter;
+use ruchy::Parser as RuchyParser;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+
+/// A program in the standard benchmark corpus
+#[derive(Debug, Clone, Copy)]
+pub struct BenchProgram {
+    pub name: &'static str,
+    pub source: &'static str,
+}
+
+/// Standard corpus: recursion, array mutation, string processing and
+/// `DataFrame` operations
+pub const BENCH_CORPUS: &[BenchProgram] = &[
+    BenchProgram {
+        name: "fib",
+        source: "fun fib(n: i32) -> i32 {
+    if n < 2 { n } else { fib(n - 1) + fib(n - 2) }
+}
+fib(20)
+",
+    },
+    BenchProgram {
+        name: "sort",
+        source: "fun bubble_sort(items: Vec<i32>) -> Vec<i32> {
+    let mut v = items
+    let n = v.len()
+    for i in 0..n {
+        for j in 0..n - 1 - i {
+            if v[j] > v[j + 1] {
+                let t = v[j]
+                v[j] = v[j + 1]
+                v[j + 1] = t
+            }
+        }
+    }
+    v
+}
+let mut data = []
+for k in 0..200 {
+    data.push((k * 7919) % 211)
+}
+bubble_sort(data)
+",
+    },
+    BenchProgram {
+        name: "strings",
+        source: "let mut words = []
+for i in 0..500 {
+    words.push(\"item-\" + i.to_string())
+}
+let text = words.join(\",\")
+let mut hits = 0
+for word in text.split(\",\") {
+    if word.to_uppercase().ends_with(\"7\") {
+        hits = hits + 1
+    }
+}
+hits
+",
+    },
+    BenchProgram {
+        name: "dataframe",
+        source: "let mut ids = []
+let mut scores = []
+for i in 0..300 {
+    ids.push(i)
+    scores.push((i * 37) % 101)
+}
+let df = DataFrame::new().column(\"id\", ids).column(\"score\", scores).build()
+df.sort_by(\"score\", true).select(\"score\").sum()
+",
+    },
+];
+
+/// Execution engine a benchmark runs on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Engine {
+    Ast,
+    Bytecode,
+    Compiled,
+    Wasm,
+}
+
+impl Engine {
+    pub const ALL: [Engine; 4] = [
+        Engine::Ast,
+        Engine::Bytecode,
+        Engine::Compiled,
+        Engine::Wasm,
+    ];
+
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Engine::Ast => "ast",
+            Engine::Bytecode => "bytecode",
+            Engine::Compiled => "compiled",
+            Engine::Wasm => "wasm",
+        }
+    }
+}
+
+/// Result of one benchmark on one engine
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Measured {
+        mean_ms: f64,
+        min_ms: f64,
+    },
+    /// The engine cannot run this program; holds the reason
+    Skipped(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EngineResult {
+    pub benchmark: &'static str,
+    pub engine: Engine,
+    pub outcome: Outcome,
+}
+
+/// One line of the history file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryRecord {
+    pub version: String,
+    pub timestamp: u64,
+    pub iterations: usize,
+    pub results: Vec<HistoryEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub benchmark: String,
+    pub engine: String,
+    pub mean_ms: f64,
+}
+
+/// A program compiled for one engine, ready to run repeatedly
+enum Prepared {
+    Ast(Expr),
+    Bytecode(BytecodeChunk),
+    Compiled {
+        _dir: tempfile::TempDir,
+        binary: PathBuf,
+    },
+    #[cfg(feature = "notebook")]
+    Wasm(wasmtime::Engine, wasmtime::Module),
+}
+
+impl Prepared {
+    fn new(engine: Engine, ast: Expr) -> Result<Self, String> {
+        match engine {
+            Engine::Ast => Ok(Prepared::Ast(ast)),
+            Engine::Bytecode => {
+                let mut compiler = Compiler::new("main".to_string());
+                compiler.compile_expr(&ast)?;
+                Ok(Prepared::Bytecode(compiler.finalize()))
+            }
+            Engine::Compiled => prepare_compiled(&ast),
+            Engine::Wasm => prepare_wasm(&ast),
+        }
+    }
+
+    fn run(&self) -> Result<(), String> {
+        match self {
+            Prepared::Ast(ast) => Interpreter::new()
+                .eval_expr(ast)
+                .map(|_| ())
+                .map_err(|e| format!("{e:?}")),
+            Prepared::Bytecode(chunk) => VM::new().execute(chunk).map(|_| ()),
+            Prepared::Compiled { binary, .. } => {
+                let output = Command::new(binary).output().map_err(|e| e.to_string())?;
+                if output.status.success() {
+                    Ok(())
+                } else {
+                    Err(first_line(&String::from_utf8_lossy(&output.stderr)))
+                }
+            }
+            #[cfg(feature = "notebook")]
+            Prepared::Wasm(engine, module) => run_wasm(engine, module),
+        }
+    }
+}
+
+/// Transpile to Rust and build an optimized binary with rustc
+fn prepare_compiled(ast: &Expr) -> Result<Prepared, String> {
+    let rust_code =
+        super::transpile_for_execution(ast, Path::new("bench.ruchy")).map_err(|e| e.to_string())?;
+    let dir = tempfile::tempdir().map_err(|e| e.to_string())?;
+    let source_path = dir.path().join("bench.rs");
+    let binary = dir.path().join("bench");
+    fs::write(&source_path, rust_code).map_err(|e| e.to_string())?;
+    let output = Command::new("rustc")
+        .args(["--edition=2018", "-O", "-A", "warnings", "-o"])
+        .arg(&binary)
+        .arg(&source_path)
+        .output()
+        .map_err(|e| format!("rustc unavailable: {e}"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("rustc failed: {}", first_line(&stderr)));
+    }
+    Ok(Prepared::Compiled { _dir: dir, binary })
+}
+
+#[cfg(feature = "notebook")]
+fn prepare_wasm(ast: &Expr) -> Result<Prepared, String> {
+    let bytes = ruchy::WasmEmitter::new().emit(ast)?;
+    let engine = wasmtime::Engine::default();
+    let module = wasmtime::Module::new(&engine, &bytes).map_err(|e| e.to_string())?;
+    if module.get_export("main").is_none() {
+        return Err("module exports no main function".to_string());
+    }
+    Ok(Prepared::Wasm(engine, module))
+}
+
+#[cfg(not(feature = "notebook"))]
+fn prepare_wasm(_ast: &Expr) -> Result<Prepared, String> {
+    Err("wasm runtime requires the `notebook` feature".to_string())
+}
+
+#[cfg(feature = "notebook")]
+fn run_wasm(engine: &wasmtime::Engine, module: &wasmtime::Module) -> Result<(), String> {
+    let mut store = wasmtime::Store::new(engine, ());
+    let mut linker = wasmtime::Linker::new(engine);
+    ruchy::backend::wasm::host::add_to_linker(&mut linker, |_| {}).map_err(|e| e.to_string())?;
+    let instance = linker
+        .instantiate(&mut store, module)
+        .map_err(|e| e.to_string())?;
+    let main = instance
+        .get_func(&mut store, "main")
+        .ok_or_else(|| "module exports no main function".to_string())?;
+    let mut results: Vec<wasmtime::Val> = main
+        .ty(&store)
+        .results()
+        .map(|ty| match ty {
+            wasmtime::ValType::I64 => wasmtime::Val::I64(0),
+            wasmtime::ValType::F32 => wasmtime::Val::F32(0),
+            wasmtime::ValType::F64 => wasmtime::Val::F64(0),
+            _ => wasmtime::Val::I32(0),
+        })
+        .collect();
+    main.call(&mut store, &[], &mut results)
+        .map_err(|e| e.to_string())
+}
+
+fn first_line(text: &str) -> String {
+    text.lines()
+        .find(|line| !line.trim().is_empty())
+        .unwrap_or("unknown error")
+        .trim()
+        .to_string()
+}
+
+/// Benchmark one program on one engine
+///
+/// Preparation (parsing, bytecode compilation, rustc, wasm instantiation of
+/// the module) is excluded from the timings; each timed run executes the
+/// prepared program from a fresh engine state.
+#[must_use]
+pub fn bench_program(
+    program: &BenchProgram,
+    engine: Engine,
+    iterations: usize,
+    warmup: usize,
+) -> EngineResult {
+    let outcome = match measure(program, engine, iterations.max(1), warmup) {
+        Ok(outcome) => outcome,
+        Err(reason) => Outcome::Skipped(reason),
+    };
+    EngineResult {
+        benchmark: program.name,
+        engine,
+        outcome,
+    }
+}
+
+fn measure(
+    program: &BenchProgram,
+    engine: Engine,
+    iterations: usize,
+    warmup: usize,
+) -> Result<Outcome, String> {
+    let ast = RuchyParser::new(program.source)
+        .parse()
+        .map_err(|e| format!("parse error: {e}"))?;
+    let prepared = Prepared::new(engine, ast)?;
+    for _ in 0..warmup {
+        prepared.run()?;
+    }
+    let mut timings = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        prepared.run()?;
+        timings.push(start.elapsed().as_secs_f64() * 1000.0);
+    }
+    let min_ms = timings.iter().copied().fold(f64::INFINITY, f64::min);
+    let mean_ms = timings.iter().sum::<f64>() / timings.len() as f64;
+    Ok(Outcome::Measured { mean_ms, min_ms })
+}
+
+/// Mean timings of the most recent run in a history file, keyed by
+/// (benchmark, engine)
+///
+/// # Errors
+/// Returns error if the file exists but cannot be read
+pub fn load_previous_run(history: &Path) -> Result<HashMap<(String, String), f64>> {
+    if !history.exists() {
+        return Ok(HashMap::new());
+    }
+    let content = fs::read_to_string(history)
+        .with_context(|| format!("Failed to read history: {}", history.display()))?;
+    let previous = content
+        .lines()
+        .rev()
+        .find_map(|line| serde_json::from_str::<HistoryRecord>(line).ok());
+    Ok(previous
+        .map(|record| {
+            record
+                .results
+                .into_iter()
+                .map(|entry| ((entry.benchmark, entry.engine), entry.mean_ms))
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+/// Build the history line for `results`, keeping measured timings only
+#[must_use]
+pub fn history_record(results: &[EngineResult], iterations: usize) -> HistoryRecord {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    HistoryRecord {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        timestamp,
+        iterations,
+        results: results
+            .iter()
+            .filter_map(|result| match result.outcome {
+                Outcome::Measured { mean_ms, .. } => Some(HistoryEntry {
+                    benchmark: result.benchmark.to_string(),
+                    engine: result.engine.name().to_string(),
+                    mean_ms,
+                }),
+                Outcome::Skipped(_) => None,
+            })
+            .collect(),
+    }
+}
+
+fn append_history(history: &Path, record: &HistoryRecord) -> Result<()> {
+    if let Some(parent) = history.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(history)
+        .with_context(|| format!("Failed to open history: {}", history.display()))?;
+    writeln!(file, "{}", serde_json::to_string(record)?)?;
+    Ok(())
+}
+
+/// Comparison table: one row per benchmark, one column per engine
+#[must_use]
+pub fn generate_engines_text_output(
+    results: &[EngineResult],
+    previous: &HashMap<(String, String), f64>,
+    iterations: usize,
+    warmup: usize,
+) -> String {
+    let mut out = format!(
+        "=== Engine Benchmarks (ruchy {}) ===\nIterations: {iterations} (warmup {warmup})\n\n",
+        env!("CARGO_PKG_VERSION")
+    );
+    let _ = write!(out, "{:<12}", "benchmark");
+    for engine in Engine::ALL {
+        let _ = write!(out, "{:>22}", engine.name());
+    }
+    out.push('\n');
+    let mut notes = Vec::new();
+    for program in BENCH_CORPUS {
+        let _ = write!(out, "{:<12}", program.name);
+        for engine in Engine::ALL {
+            let cell = results
+                .iter()
+                .find(|r| r.benchmark == program.name && r.engine == engine)
+                .map_or_else(String::new, |result| match &result.outcome {
+                    Outcome::Measured { mean_ms, .. } => {
+                        let key = (program.name.to_string(), engine.name().to_string());
+                        match previous.get(&key) {
+                            Some(&before) if before > 0.0 => format!(
+                                "{mean_ms:.3} ms ({:+.1}%)",
+                                (mean_ms - before) / before * 100.0
+                            ),
+                            _ => format!("{mean_ms:.3} ms"),
+                        }
+                    }
+                    Outcome::Skipped(reason) => {
+                        notes.push(format!("{}/{}: {reason}", program.name, engine.name()));
+                        "skipped".to_string()
+                    }
+                });
+            let _ = write!(out, "{cell:>22}");
+        }
+        out.push('\n');
+    }
+    if !notes.is_empty() {
+        out.push_str("\nSkipped:\n");
+        for note in notes {
+            let _ = writeln!(out, "  {note}");
+        }
+    }
+    out
+}
+
+/// CSV with one row per (benchmark, engine) pair
+#[must_use]
+pub fn generate_engines_csv_output(results: &[EngineResult]) -> String {
+    let mut out = String::from("benchmark,engine,status,mean_ms,min_ms\n");
+    for result in results {
+        let (status, mean, min) = match result.outcome {
+            Outcome::Measured { mean_ms, min_ms } => {
+                ("ok", format!("{mean_ms:.3}"), format!("{min_ms:.3}"))
+            }
+            Outcome::Skipped(_) => ("skipped", String::new(), String::new()),
+        };
+        let _ = writeln!(
+            out,
+            "{},{},{status},{mean},{min}",
+            result.benchmark,
+            result.engine.name()
+        );
+    }
+    out
+}
+
+/// Handle `bench --engines` - run the standard corpus on every engine
+///
+/// # Errors
+/// Returns error if the history file or output file cannot be accessed
+pub fn handle_bench_engines_command(
+    iterations: usize,
+    warmup: usize,
+    format: &str,
+    output: Option<&Path>,
+    history: Option<&Path>,
+    verbose: bool,
+) -> Result<()> {
+    let previous = match history {
+        Some(path) => load_previous_run(path)?,
+        None => HashMap::new(),
+    };
+    let mut results = Vec::new();
+    for program in BENCH_CORPUS {
+        for engine in Engine::ALL {
+            if verbose {
+                eprintln!("⏱️  {} on {}...", program.name, engine.name());
+            }
+            results.push(bench_program(program, engine, iterations, warmup));
+        }
+    }
+    let record = history_record(&results, iterations);
+
+    let report = match format {
+        "json" => serde_json::to_string_pretty(&record)?,
+        "csv" => generate_engines_csv_output(&results),
+        _ => generate_engines_text_output(&results, &previous, iterations, warmup),
+    };
+    if let Some(path) = history {
+        append_history(path, &record)?;
+        if verbose {
+            eprintln!("📈 History appended to: {}", path.display());
+        }
+    }
+    if let Some(output_path) = output {
+        fs::write(output_path, &report)
+            .with_context(|| format!("Failed to write output to: {}", output_path.display()))?;
+        if verbose {
+            println!("\n💾 Results saved to: {}", output_path.display());
+        }
+    } else {
+        println!("{report}");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn program(name: &str) -> &'static BenchProgram {
+        BENCH_CORPUS.iter().find(|p| p.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_corpus_runs_on_ast_interpreter() {
+        for program in BENCH_CORPUS {
+            let result = bench_program(program, Engine::Ast, 1, 0);
+            assert!(
+                matches!(result.outcome, Outcome::Measured { .. }),
+                "{}: {:?}",
+                program.name,
+                result.outcome
+            );
+        }
+    }
+
+    #[test]
+    fn test_unsupported_engine_is_skipped_not_fatal() {
+        let result = bench_program(program("dataframe"), Engine::Wasm, 1, 0);
+        assert!(matches!(result.outcome, Outcome::Skipped(_)));
+    }
+
+    #[test]
+    fn test_text_output_shows_delta_against_previous_run() {
+        let results = vec![EngineResult {
+            benchmark: "fib",
+            engine: Engine::Ast,
+            outcome: Outcome::Measured {
+                mean_ms: 11.0,
+                min_ms: 10.0,
+            },
+        }];
+        let mut previous = HashMap::new();
+        previous.insert(("fib".to_string(), "ast".to_string()), 10.0);
+        let output = generate_engines_text_output(&results, &previous, 5, 1);
+        assert!(output.contains("11.000 ms (+10.0%)"), "{output}");
+        assert!(output.contains("bytecode"), "{output}");
+    }
+
+    #[test]
+    fn test_history_round_trip_keeps_latest_run() {
+        let temp = TempDir::new().unwrap();
+        let history = temp.path().join("history").join("bench.jsonl");
+        let run = |mean_ms| {
+            vec![EngineResult {
+                benchmark: "sort",
+                engine: Engine::Bytecode,
+                outcome: Outcome::Measured {
+                    mean_ms,
+                    min_ms: mean_ms,
+                },
+            }]
+        };
+        append_history(&history, &history_record(&run(4.0), 1)).unwrap();
+        append_history(&history, &history_record(&run(2.0), 1)).unwrap();
+        let previous = load_previous_run(&history).unwrap();
+        assert_eq!(
+            previous.get(&("sort".to_string(), "bytecode".to_string())),
+            Some(&2.0)
+        );
+    }
+
+    #[test]
+    fn test_csv_marks_skipped_engines() {
+        let results = vec![EngineResult {
+            benchmark: "strings",
+            engine: Engine::Compiled,
+            outcome: Outcome::Skipped("rustc unavailable".to_string()),
+        }];
+        let csv = generate_engines_csv_output(&results);
+        assert!(csv.contains("strings,compiled,skipped,,"), "{csv}");
+    }
+}
@@ -1,39 +1,119 @@
 //! Benchmark Command Handler
 //!
 //! Handles benchmarking of Ruchy files with configurable iterations and warmup.
-
-use anyhow::{Context, Result};
+//!
+//! A file that defines functions named `bench_*` (taking no arguments) is
+//! benchmarked per function: the file runs once as setup, then each
+//! function is called repeatedly. Any other file is benchmarked as a whole,
+//! each run in a fresh interpreter. What the benchmarked code prints is
+//! discarded.
+//!
+//! With `--baseline`, the medians are compared against a JSON report saved
+//! earlier (`--save-baseline`, or `--format json --output`) and the command
+//! fails when a benchmark got slower by more than `--max-regression` percent.
+
+use anyhow::{bail, Context, Result};
+use ruchy::frontend::ast::{Expr, ExprKind};
+use ruchy::runtime::interpreter::Interpreter;
+use ruchy::runtime::output;
 use ruchy::Parser as RuchyParser;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
 use std::fs;
 use std::path::Path;
 use std::time::Instant;
 
+/// Results of one `ruchy bench` run, also the format of baseline files
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub file: String,
+    pub warmup: usize,
+    pub iterations: usize,
+    pub benchmarks: Vec<BenchResult>,
+}
+
+/// Timings of one benchmark: a `bench_*` function, or the whole file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub name: String,
+    pub timings_ms: Vec<f64>,
+    pub statistics: BenchStats,
+}
+
+/// Summary statistics of a benchmark's timings, in milliseconds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct BenchStats {
+    pub min_ms: f64,
+    pub max_ms: f64,
+    pub mean_ms: f64,
+    pub median_ms: f64,
+    pub p95_ms: f64,
+    pub stddev_ms: f64,
+}
+
+impl BenchStats {
+    /// Statistics of `timings`; all zero when there are none
+    #[must_use]
+    pub fn from_timings(timings: &[f64]) -> Self {
+        if timings.is_empty() {
+            return Self::default();
+        }
+        let mut sorted = timings.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let n = sorted.len();
+        let mean = sorted.iter().sum::<f64>() / n as f64;
+        let variance = sorted.iter().map(|&x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+        let median = if n % 2 == 0 {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+        } else {
+            sorted[n / 2]
+        };
+        // Nearest-rank percentile
+        let p95_rank = (n * 95).div_ceil(100).max(1);
+        Self {
+            min_ms: sorted[0],
+            max_ms: sorted[n - 1],
+            mean_ms: mean,
+            median_ms: median,
+            p95_ms: sorted[p95_rank - 1],
+            stddev_ms: variance.sqrt(),
+        }
+    }
+}
+
 /// Handle bench command - benchmark execution of a Ruchy file
 ///
 /// # Arguments
 /// * `file` - Path to the Ruchy file to benchmark
 /// * `iterations` - Number of benchmark iterations
 /// * `warmup` - Number of warmup iterations
-/// * `format` - Output format (text, json, csv)
+/// * `format` - Output format (text, json, csv, markdown)
 /// * `output` - Optional output file path
+/// * `baseline` - Optional JSON report to compare against
+/// * `save_baseline` - Write this run to `baseline` instead of comparing
+/// * `max_regression` - Median slowdown in percent that fails the comparison
 /// * `verbose` - Enable verbose output
 ///
 /// # Errors
-/// Returns error if file cannot be read, parsed, or executed
+/// Returns error if file cannot be read, parsed, or executed, or if a
+/// benchmark regressed against the baseline
+#[allow(clippy::too_many_arguments)]
 pub fn handle_bench_command(
     file: &Path,
     iterations: usize,
     warmup: usize,
     format: &str,
     output: Option<&Path>,
+    baseline: Option<&Path>,
+    save_baseline: bool,
+    max_regression: f64,
     verbose: bool,
 ) -> Result<()> {
-    // Read and parse the file
     let source = fs::read_to_string(file)
         .with_context(|| format!("Failed to read file: {}", file.display()))?;
-
-    // Parse to validate syntax
-    let _parser = RuchyParser::new(&source);
+    let ast = RuchyParser::new(&source)
+        .parse()
+        .with_context(|| format!("Syntax error in {}", file.display()))?;
 
     if verbose {
         println!("📊 Benchmarking: {}", file.display());
@@ -41,161 +121,271 @@ pub fn handle_bench_command(
         println!("🏃 Benchmark: {} iterations", iterations);
     }
 
-    // Warmup phase
+    let functions = bench_functions(&ast);
+    let benchmarks = if functions.is_empty() {
+        let name = file
+            .file_stem()
+            .map_or_else(|| "main".to_string(), |s| s.to_string_lossy().into_owned());
+        let timings = time_runs(&name, iterations, warmup, verbose, || {
+            Interpreter::new().eval_expr(&ast).map(|_| ())
+        })?;
+        vec![bench_result(name, timings)]
+    } else {
+        let mut interpreter = Interpreter::new();
+        quietly(|| interpreter.eval_expr(&ast))
+            .map_err(|e| anyhow::anyhow!("Setup of {} failed: {e}", file.display()))?;
+        let mut benchmarks = Vec::with_capacity(functions.len());
+        for name in functions {
+            let call = RuchyParser::new(&format!("{name}()")).parse()?;
+            let timings = time_runs(&name, iterations, warmup, verbose, || {
+                interpreter.eval_expr(&call).map(|_| ())
+            })?;
+            benchmarks.push(bench_result(name, timings));
+        }
+        benchmarks
+    };
+    let report = BenchReport {
+        file: file.display().to_string(),
+        warmup,
+        iterations,
+        benchmarks,
+    };
+
+    let rendered = match format {
+        "json" => generate_bench_json_output(&report),
+        "csv" => generate_bench_csv_output(&report),
+        "markdown" | "md" => generate_bench_markdown_output(&report),
+        _ => generate_bench_text_output(&report),
+    };
+    if let Some(output_path) = output {
+        fs::write(output_path, &rendered)
+            .with_context(|| format!("Failed to write output to: {}", output_path.display()))?;
+        if verbose {
+            println!("\n💾 Results saved to: {}", output_path.display());
+        }
+    } else {
+        println!("{}", rendered);
+    }
+
+    match baseline {
+        Some(path) if save_baseline => {
+            fs::write(path, generate_bench_json_output(&report))
+                .with_context(|| format!("Failed to write baseline: {}", path.display()))?;
+            eprintln!("💾 Baseline saved to: {}", path.display());
+        }
+        Some(path) => check_baseline(&report, path, max_regression)?,
+        None => {}
+    }
+    Ok(())
+}
+
+/// Names of the argument-less `bench_*` functions defined at the top level
+#[must_use]
+pub fn bench_functions(ast: &Expr) -> Vec<String> {
+    let items = match &ast.kind {
+        ExprKind::Block(items) => items.as_slice(),
+        _ => std::slice::from_ref(ast),
+    };
+    items
+        .iter()
+        .filter_map(|item| match &item.kind {
+            ExprKind::Function { name, params, .. }
+                if name.starts_with("bench_") && params.is_empty() =>
+            {
+                Some(name.clone())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Run `f` `warmup` times, then time it `iterations` times (in milliseconds)
+fn time_runs<E: std::fmt::Display>(
+    name: &str,
+    iterations: usize,
+    warmup: usize,
+    verbose: bool,
+    mut f: impl FnMut() -> Result<(), E>,
+) -> Result<Vec<f64>> {
     if verbose && warmup > 0 {
-        println!("\n⏱️  Running warmup...");
+        println!("\n⏱️  Running warmup of {name}...");
     }
     for i in 0..warmup {
-        let mut repl = super::create_repl()?;
-        repl.eval(&source)?;
+        quietly(&mut f).map_err(|e| anyhow::anyhow!("{name} failed: {e}"))?;
         if verbose {
             println!("  Warmup iteration {}/{}", i + 1, warmup);
         }
     }
-
-    // Benchmark phase
     if verbose {
-        println!("\n⏱️  Running benchmark...");
+        println!("\n⏱️  Running benchmark of {name}...");
     }
-
     let mut timings = Vec::with_capacity(iterations);
     for i in 0..iterations {
         let start = Instant::now();
-        let mut repl = super::create_repl()?;
-        repl.eval(&source)?;
-        let duration = start.elapsed();
-        timings.push(duration.as_secs_f64() * 1000.0); // Convert to milliseconds
-
+        quietly(&mut f).map_err(|e| anyhow::anyhow!("{name} failed: {e}"))?;
+        timings.push(start.elapsed().as_secs_f64() * 1000.0);
         if verbose {
             println!("  Iteration {}/{}: {:.3} ms", i + 1, iterations, timings[i]);
         }
     }
+    Ok(timings)
+}
 
-    // Calculate statistics
-    let min = timings.iter().copied().fold(f64::INFINITY, f64::min);
-    let max = timings.iter().copied().fold(f64::NEG_INFINITY, f64::max);
-    let sum: f64 = timings.iter().sum();
-    let mean = sum / timings.len() as f64;
-
-    // Calculate standard deviation
-    let variance: f64 =
-        timings.iter().map(|&x| (x - mean).powi(2)).sum::<f64>() / timings.len() as f64;
-    let stddev = variance.sqrt();
-
-    // Generate output based on format
-    let report = match format {
-        "json" => {
-            generate_bench_json_output(file, iterations, warmup, &timings, min, max, mean, stddev)
-        }
-        "csv" => {
-            generate_bench_csv_output(file, iterations, warmup, &timings, min, max, mean, stddev)
-        }
-        _ => generate_bench_text_output(file, iterations, warmup, &timings, min, max, mean, stddev),
-    };
+/// Run `f` with its printed output discarded
+fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    output::capture(f).0
+}
 
-    // Write output
-    if let Some(output_path) = output {
-        fs::write(output_path, &report)
-            .with_context(|| format!("Failed to write output to: {}", output_path.display()))?;
-        if verbose {
-            println!("\n💾 Results saved to: {}", output_path.display());
-        }
-    } else {
-        println!("{}", report);
+fn bench_result(name: String, timings_ms: Vec<f64>) -> BenchResult {
+    BenchResult {
+        statistics: BenchStats::from_timings(&timings_ms),
+        name,
+        timings_ms,
     }
+}
 
+/// Compare medians against the report at `path`, failing on regressions
+fn check_baseline(report: &BenchReport, path: &Path, max_regression: f64) -> Result<()> {
+    if !path.exists() {
+        bail!(
+            "Baseline not found: {} (create it with --save-baseline)",
+            path.display()
+        );
+    }
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read baseline: {}", path.display()))?;
+    let baseline: BenchReport = serde_json::from_str(&content)
+        .with_context(|| format!("Invalid baseline: {}", path.display()))?;
+    let (comparison, regressions) = generate_baseline_comparison(report, &baseline, max_regression);
+    eprint!("{comparison}");
+    if regressions > 0 {
+        bail!(
+            "{regressions} benchmark(s) regressed by more than {max_regression}% against {}",
+            path.display()
+        );
+    }
     Ok(())
 }
 
+/// Median of each benchmark against the baseline, and how many regressed
+#[must_use]
+pub fn generate_baseline_comparison(
+    report: &BenchReport,
+    baseline: &BenchReport,
+    max_regression: f64,
+) -> (String, usize) {
+    let mut out = String::from("=== Baseline Comparison (median) ===\n");
+    let mut regressions = 0;
+    for result in &report.benchmarks {
+        let current = result.statistics.median_ms;
+        let before = baseline
+            .benchmarks
+            .iter()
+            .find(|b| b.name == result.name)
+            .map(|b| b.statistics.median_ms);
+        let _ = match before {
+            Some(before) if before > 0.0 => {
+                let change = (current - before) / before * 100.0;
+                let verdict = if change > max_regression {
+                    regressions += 1;
+                    "  REGRESSION"
+                } else {
+                    ""
+                };
+                writeln!(
+                    out,
+                    "{:<24} {before:>10.3} ms -> {current:>10.3} ms  {change:+6.1}%{verdict}",
+                    result.name
+                )
+            }
+            _ => writeln!(
+                out,
+                "{:<24} {:>13} -> {current:>10.3} ms  (new)",
+                result.name, "-"
+            ),
+        };
+    }
+    (out, regressions)
+}
+
 /// Generate text format benchmark output
-pub fn generate_bench_text_output(
-    file: &Path,
-    iterations: usize,
-    warmup: usize,
-    _timings: &[f64],
-    min: f64,
-    max: f64,
-    mean: f64,
-    stddev: f64,
-) -> String {
-    format!(
+#[must_use]
+pub fn generate_bench_text_output(report: &BenchReport) -> String {
+    let mut out = format!(
         "=== Benchmark Results ===\n\
          File: {}\n\
          Warmup: {} iterations\n\
-         Benchmark: {} iterations\n\
-         \n\
-         Statistics:\n\
-         ├─ Min:     {:.3} ms\n\
-         ├─ Max:     {:.3} ms\n\
-         ├─ Average: {:.3} ms\n\
-         └─ StdDev:  {:.3} ms\n",
-        file.display(),
-        warmup,
-        iterations,
-        min,
-        max,
-        mean,
-        stddev
-    )
+         Benchmark: {} iterations\n",
+        report.file, report.warmup, report.iterations
+    );
+    for result in &report.benchmarks {
+        let s = &result.statistics;
+        let _ = write!(
+            out,
+            "\n{}:\n\
+             ├─ Mean:    {:.3} ms\n\
+             ├─ Median:  {:.3} ms\n\
+             ├─ P95:     {:.3} ms\n\
+             ├─ StdDev:  {:.3} ms\n\
+             ├─ Min:     {:.3} ms\n\
+             └─ Max:     {:.3} ms\n",
+            result.name, s.mean_ms, s.median_ms, s.p95_ms, s.stddev_ms, s.min_ms, s.max_ms
+        );
+    }
+    out
 }
 
-/// Generate JSON format benchmark output
-pub fn generate_bench_json_output(
-    file: &Path,
-    iterations: usize,
-    warmup: usize,
-    timings: &[f64],
-    min: f64,
-    max: f64,
-    mean: f64,
-    stddev: f64,
-) -> String {
-    format!(
-        "{{\n\
-           \"file\": \"{}\",\n\
-           \"warmup\": {},\n\
-           \"iterations\": {},\n\
-           \"timings_ms\": {:?},\n\
-           \"statistics\": {{\n\
-             \"min_ms\": {:.3},\n\
-             \"max_ms\": {:.3},\n\
-             \"mean_ms\": {:.3},\n\
-             \"stddev_ms\": {:.3}\n\
-           }}\n\
-         }}",
-        file.display(),
-        warmup,
-        iterations,
-        timings,
-        min,
-        max,
-        mean,
-        stddev
-    )
+/// Generate JSON format benchmark output, the format of baseline files
+#[must_use]
+pub fn generate_bench_json_output(report: &BenchReport) -> String {
+    serde_json::to_string_pretty(report).unwrap_or_default()
 }
 
-/// Generate CSV format benchmark output
-pub fn generate_bench_csv_output(
-    file: &Path,
-    iterations: usize,
-    warmup: usize,
-    _timings: &[f64],
-    min: f64,
-    max: f64,
-    mean: f64,
-    stddev: f64,
-) -> String {
-    format!(
-        "file,warmup,iterations,min_ms,max_ms,mean_ms,stddev_ms\n\
-         \"{}\",{},{},{:.3},{:.3},{:.3},{:.3}\n",
-        file.display(),
-        warmup,
-        iterations,
-        min,
-        max,
-        mean,
-        stddev
-    )
+/// Generate CSV format benchmark output, one row per benchmark
+#[must_use]
+pub fn generate_bench_csv_output(report: &BenchReport) -> String {
+    let mut out = String::from(
+        "file,benchmark,warmup,iterations,min_ms,max_ms,mean_ms,median_ms,p95_ms,stddev_ms\n",
+    );
+    for result in &report.benchmarks {
+        let s = &result.statistics;
+        let _ = writeln!(
+            out,
+            "\"{}\",{},{},{},{:.3},{:.3},{:.3},{:.3},{:.3},{:.3}",
+            report.file,
+            result.name,
+            report.warmup,
+            report.iterations,
+            s.min_ms,
+            s.max_ms,
+            s.mean_ms,
+            s.median_ms,
+            s.p95_ms,
+            s.stddev_ms
+        );
+    }
+    out
+}
+
+/// Generate a markdown table of the results
+#[must_use]
+pub fn generate_bench_markdown_output(report: &BenchReport) -> String {
+    let mut out = format!(
+        "## Benchmark Results: `{}`\n\n\
+         {} iterations after {} warmup iterations.\n\n\
+         | Benchmark | Mean (ms) | Median (ms) | P95 (ms) | StdDev (ms) | Min (ms) | Max (ms) |\n\
+         |-----------|----------:|------------:|---------:|------------:|---------:|---------:|\n",
+        report.file, report.iterations, report.warmup
+    );
+    for result in &report.benchmarks {
+        let s = &result.statistics;
+        let _ = writeln!(
+            out,
+            "| `{}` | {:.3} | {:.3} | {:.3} | {:.3} | {:.3} | {:.3} |",
+            result.name, s.mean_ms, s.median_ms, s.p95_ms, s.stddev_ms, s.min_ms, s.max_ms
+        );
+    }
+    out
 }
 
 #[cfg(test)]
@@ -203,94 +393,137 @@ mod tests {
     use super::*;
     use tempfile::{NamedTempFile, TempDir};
 
+    fn report(file: &str, iterations: usize, warmup: usize, timings: &[f64]) -> BenchReport {
+        BenchReport {
+            file: file.to_string(),
+            warmup,
+            iterations,
+            benchmarks: vec![bench_result("main".to_string(), timings.to_vec())],
+        }
+    }
+
+    fn bench(path: &Path, iterations: usize, warmup: usize, format: &str) -> Result<()> {
+        handle_bench_command(
+            path, iterations, warmup, format, None, None, false, 10.0, false,
+        )
+    }
+
     #[test]
     fn test_generate_text_output() {
-        let file = Path::new("test.ruchy");
-        let output = generate_bench_text_output(file, 10, 3, &[], 1.0, 5.0, 2.5, 1.0);
+        let output = generate_bench_text_output(&report("test.ruchy", 10, 3, &[1.0, 5.0]));
         assert!(output.contains("Benchmark Results"));
         assert!(output.contains("test.ruchy"));
         assert!(output.contains("10"));
         assert!(output.contains("3"));
+        assert!(output.contains("Median:  3.000 ms"));
     }
 
     #[test]
     fn test_generate_json_output() {
-        let file = Path::new("test.ruchy");
-        let timings = vec![1.0, 2.0, 3.0];
-        let output = generate_bench_json_output(file, 3, 1, &timings, 1.0, 3.0, 2.0, 0.8);
+        let output = generate_bench_json_output(&report("test.ruchy", 3, 1, &[1.0, 2.0, 3.0]));
         assert!(output.contains("\"file\":"));
         assert!(output.contains("\"iterations\":"));
         assert!(output.contains("\"statistics\":"));
+        assert!(output.contains("\"p95_ms\":"));
     }
 
     #[test]
     fn test_generate_csv_output() {
-        let file = Path::new("test.ruchy");
-        let output = generate_bench_csv_output(file, 5, 2, &[], 1.0, 5.0, 2.5, 1.0);
-        assert!(output.contains("file,warmup,iterations"));
+        let output = generate_bench_csv_output(&report("test.ruchy", 5, 2, &[1.0]));
+        assert!(output.contains("file,benchmark,warmup,iterations"));
         assert!(output.contains("test.ruchy"));
     }
 
     #[test]
     fn test_csv_header() {
-        let file = Path::new("any.ruchy");
-        let output = generate_bench_csv_output(file, 1, 1, &[], 0.0, 0.0, 0.0, 0.0);
+        let output = generate_bench_csv_output(&report("any.ruchy", 1, 1, &[]));
         let lines: Vec<&str> = output.lines().collect();
         assert_eq!(
             lines[0],
-            "file,warmup,iterations,min_ms,max_ms,mean_ms,stddev_ms"
+            "file,benchmark,warmup,iterations,min_ms,max_ms,mean_ms,median_ms,p95_ms,stddev_ms"
         );
     }
 
+    #[test]
+    fn test_generate_markdown_output() {
+        let output = generate_bench_markdown_output(&report("test.ruchy", 5, 2, &[2.0, 4.0]));
+        assert!(output.contains("| Benchmark | Mean (ms) |"));
+        assert!(output.contains("| `main` | 3.000 | 3.000 | 4.000 | 1.000 | 2.000 | 4.000 |"));
+    }
+
+    #[test]
+    fn test_stats_median_and_p95() {
+        let timings: Vec<f64> = (1..=100).map(f64::from).collect();
+        let stats = BenchStats::from_timings(&timings);
+        assert_eq!(stats.median_ms, 50.5);
+        assert_eq!(stats.p95_ms, 95.0);
+        assert_eq!(stats.min_ms, 1.0);
+        assert_eq!(stats.max_ms, 100.0);
+
+        let odd = BenchStats::from_timings(&[3.0, 1.0, 2.0]);
+        assert_eq!(odd.median_ms, 2.0);
+        assert_eq!(odd.p95_ms, 3.0);
+        assert_eq!(BenchStats::from_timings(&[]), BenchStats::default());
+    }
+
+    #[test]
+    fn test_baseline_comparison_flags_regressions() {
+        let mut baseline = report("f.ruchy", 3, 0, &[10.0]);
+        baseline
+            .benchmarks
+            .push(bench_result("bench_b".to_string(), vec![10.0]));
+        let mut current = report("f.ruchy", 3, 0, &[10.5]);
+        current
+            .benchmarks
+            .push(bench_result("bench_b".to_string(), vec![12.0]));
+        current
+            .benchmarks
+            .push(bench_result("bench_c".to_string(), vec![1.0]));
+
+        let (text, regressions) = generate_baseline_comparison(&current, &baseline, 10.0);
+        assert_eq!(regressions, 1);
+        assert!(text.contains("+20.0%  REGRESSION"), "{text}");
+        assert!(text.contains("+5.0%\n"), "{text}");
+        assert!(text.contains("(new)"), "{text}");
+    }
+
     // ===== EXTREME TDD Round 149 - Bench Handler Tests =====
 
     #[test]
     fn test_generate_text_output_zero_values() {
-        let file = Path::new("test.ruchy");
-        let output = generate_bench_text_output(file, 0, 0, &[], 0.0, 0.0, 0.0, 0.0);
+        let output = generate_bench_text_output(&report("test.ruchy", 0, 0, &[]));
         assert!(output.contains("Benchmark Results"));
     }
 
     #[test]
     fn test_generate_text_output_large_values() {
-        let file = Path::new("test.ruchy");
         let output =
-            generate_bench_text_output(file, 1000000, 100000, &[], 0.001, 10000.0, 500.0, 100.0);
+            generate_bench_text_output(&report("test.ruchy", 1000000, 100000, &[0.001, 10000.0]));
         assert!(output.contains("Benchmark Results"));
     }
 
     #[test]
     fn test_generate_json_output_empty_timings() {
-        let file = Path::new("test.ruchy");
-        let output = generate_bench_json_output(file, 0, 0, &[], 0.0, 0.0, 0.0, 0.0);
+        let output = generate_bench_json_output(&report("test.ruchy", 0, 0, &[]));
         assert!(output.contains("\"timings_ms\": []"));
     }
 
     #[test]
     fn test_generate_json_output_many_timings() {
-        let file = Path::new("test.ruchy");
         let timings: Vec<f64> = (0..100).map(|x| x as f64 * 0.1).collect();
-        let output = generate_bench_json_output(file, 100, 10, &timings, 0.0, 9.9, 4.95, 2.0);
+        let output = generate_bench_json_output(&report("test.ruchy", 100, 10, &timings));
         assert!(output.contains("\"iterations\": 100"));
     }
 
     #[test]
     fn test_generate_csv_output_special_chars_in_path() {
-        let file = Path::new("path with spaces/test.ruchy");
-        let output = generate_bench_csv_output(file, 5, 2, &[], 1.0, 5.0, 2.5, 1.0);
+        let output = generate_bench_csv_output(&report("path with spaces/test.ruchy", 5, 2, &[]));
         assert!(output.contains("path with spaces"));
     }
 
     #[test]
     fn test_handle_bench_command_nonexistent_file() {
-        let result = handle_bench_command(
-            Path::new("/nonexistent/file.ruchy"),
-            1,
-            0,
-            "text",
-            None,
-            false,
-        );
+        let result = bench(Path::new("/nonexistent/file.ruchy"), 1, 0, "text");
         assert!(result.is_err());
     }
 
@@ -298,7 +531,7 @@ mod tests {
     fn test_handle_bench_command_basic() {
         let temp = NamedTempFile::new().unwrap();
         std::fs::write(temp.path(), "42").unwrap();
-        let result = handle_bench_command(temp.path(), 1, 0, "text", None, false);
+        let result = bench(temp.path(), 1, 0, "text");
         assert!(result.is_ok());
     }
 
@@ -306,14 +539,7 @@ mod tests {
     fn test_handle_bench_command_with_warmup() {
         let temp = NamedTempFile::new().unwrap();
         std::fs::write(temp.path(), "42").unwrap();
-        let result = handle_bench_command(
-            temp.path(),
-            2,
-            1, // warmup
-            "text",
-            None,
-            false,
-        );
+        let result = bench(temp.path(), 2, 1, "text");
         assert!(result.is_ok());
     }
 
@@ -321,7 +547,7 @@ mod tests {
     fn test_handle_bench_command_json_format() {
         let temp = NamedTempFile::new().unwrap();
         std::fs::write(temp.path(), "42").unwrap();
-        let result = handle_bench_command(temp.path(), 1, 0, "json", None, false);
+        let result = bench(temp.path(), 1, 0, "json");
         assert!(result.is_ok());
     }
 
@@ -329,7 +555,7 @@ mod tests {
     fn test_handle_bench_command_csv_format() {
         let temp = NamedTempFile::new().unwrap();
         std::fs::write(temp.path(), "42").unwrap();
-        let result = handle_bench_command(temp.path(), 1, 0, "csv", None, false);
+        let result = bench(temp.path(), 1, 0, "csv");
         assert!(result.is_ok());
     }
 
@@ -339,7 +565,17 @@ mod tests {
         std::fs::write(temp.path(), "42").unwrap();
         let temp_dir = TempDir::new().unwrap();
         let output_path = temp_dir.path().join("output.txt");
-        let result = handle_bench_command(temp.path(), 1, 0, "text", Some(&output_path), false);
+        let result = handle_bench_command(
+            temp.path(),
+            1,
+            0,
+            "text",
+            Some(&output_path),
+            None,
+            false,
+            10.0,
+            false,
+        );
         assert!(result.is_ok());
         assert!(output_path.exists());
     }
@@ -354,6 +590,9 @@ mod tests {
             0,
             "text",
             None,
+            None,
+            false,
+            10.0,
             true, // verbose
         );
         assert!(result.is_ok());
@@ -363,14 +602,47 @@ mod tests {
     fn test_handle_bench_command_multiple_iterations() {
         let temp = NamedTempFile::new().unwrap();
         std::fs::write(temp.path(), "42").unwrap();
-        let result = handle_bench_command(
-            temp.path(),
-            5, // multiple iterations
-            2,
-            "text",
-            None,
-            false,
-        );
+        let result = bench(temp.path(), 5, 2, "text");
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_bench_functions_are_found() {
+        let ast = RuchyParser::new(
+            "fun bench_sum() { 1 + 2 }\nfun bench_arg(n) { n }\nfun helper() { 0 }\nfun bench_mul() { 2 * 3 }",
+        )
+        .parse()
+        .unwrap();
+        assert_eq!(bench_functions(&ast), ["bench_sum", "bench_mul"]);
+    }
+
+    #[test]
+    fn test_baseline_is_saved_then_checked() {
+        let temp_dir = TempDir::new().unwrap();
+        let file = temp_dir.path().join("b.ruchy");
+        std::fs::write(&file, "fun bench_add() { 1 + 2 }").unwrap();
+        let baseline = temp_dir.path().join("baseline.json");
+        let run = |save: bool, max_regression: f64| {
+            handle_bench_command(
+                &file,
+                3,
+                0,
+                "text",
+                None,
+                Some(&baseline),
+                save,
+                max_regression,
+                false,
+            )
+        };
+
+        assert!(run(false, 10.0).is_err(), "missing baseline");
+        run(true, 10.0).unwrap();
+        let saved: BenchReport =
+            serde_json::from_str(&std::fs::read_to_string(&baseline).unwrap()).unwrap();
+        assert_eq!(saved.benchmarks[0].name, "bench_add");
+        assert_eq!(saved.benchmarks[0].timings_ms.len(), 3);
+        // A tolerance no slowdown can exceed always passes
+        run(false, f64::MAX).unwrap();
+    }
 }
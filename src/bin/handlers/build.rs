@@ -1,16 +1,26 @@
 //! Handler for `ruchy build` command (CARGO-004)
 //!
-//! Wrapper around `cargo build` for Ruchy projects
+//! Builds the project of a `Ruchy.toml` manifest, or wraps `cargo build` for
+//! Ruchy projects created by `ruchy new`
 
 use anyhow::{Context, Result};
+use ruchy::backend::project::Project;
+use ruchy::build_timings::{BuildTimer, BuildTimings};
+use ruchy::runtime::script::MANIFEST_FILE;
+use std::fs;
 use std::path::Path;
 use std::process::Command;
 
-/// Handle `ruchy build` command - wrapper around cargo build
+/// Directory the `--timings` reports are written to
+const TIMINGS_DIR: &str = "target/ruchy-timings";
+
+/// Handle `ruchy build` command - build the `Ruchy.toml` project in the
+/// current directory, or wrap cargo build when there is none
 ///
 /// # Arguments
 ///
 /// * `release` - Whether to build in release mode (--release)
+/// * `timings` - Time each compilation phase and write a report (--timings)
 /// * `verbose` - Enable verbose output
 ///
 /// # Examples
@@ -18,34 +28,66 @@ use std::process::Command;
 /// ```no_run
 /// # use ruchy::handle_build_command;
 /// // Debug build
-/// handle_build_command(false, false).expect("Failed to build");
+/// handle_build_command(false, false, false).expect("Failed to build");
 ///
 /// // Release build
-/// handle_build_command(true, false).expect("Failed to build");
+/// handle_build_command(true, false, false).expect("Failed to build");
 /// ```
 ///
 /// # Errors
 ///
 /// Returns an error if:
-/// - Cargo.toml doesn't exist (not in a Cargo project)
+/// - Neither Ruchy.toml nor Cargo.toml exists
+/// - Ruchy.toml is invalid or the project fails to transpile
 /// - cargo build command fails
 ///
 /// # Complexity
 ///
-/// Complexity: 6 (within Toyota Way limits ≤10)
-pub fn handle_build_command(release: bool, verbose: bool) -> Result<()> {
-    // Step 1: Verify we're in a Cargo project
+/// Complexity: 7 (within Toyota Way limits ≤10)
+pub fn handle_build_command(release: bool, timings: bool, verbose: bool) -> Result<()> {
+    // Step 1: A Ruchy.toml manifest selects project mode
+    if Path::new(MANIFEST_FILE).is_file() {
+        let project = Project::load(&std::env::current_dir()?)?;
+        return build_project(&project, release, timings, verbose);
+    }
+
+    // Step 2: Otherwise verify we're in a Cargo project
     verify_cargo_project()?;
 
-    // Step 2: Run cargo build
-    run_cargo_build(release, verbose)?;
+    // Step 3: Run cargo build, timing every phase if asked to
+    if timings {
+        run_timed_build(|| run_cargo_build(release, verbose))?;
+    } else {
+        run_cargo_build(release, verbose)?;
+    }
 
-    // Step 3: Print success message
+    // Step 4: Print success message
     print_build_success_message(release);
 
     Ok(())
 }
 
+/// Build a `Ruchy.toml` project into `target/<profile>/<name>`
+///
+/// # Complexity
+///
+/// Complexity: 3 (within Toyota Way limits ≤10)
+fn build_project(project: &Project, release: bool, timings: bool, verbose: bool) -> Result<()> {
+    let package = &project.manifest().package;
+    if verbose {
+        println!("Building {} v{}", package.name, package.version);
+    }
+    let binary = if timings {
+        run_timed_build(|| project.build(release, verbose))?
+    } else {
+        project.build(release, verbose)?
+    };
+    let mode = if release { "release" } else { "debug" };
+    println!("Build complete ({mode} mode)");
+    println!("Binary: {}", binary.display());
+    Ok(())
+}
+
 /// Verify that Cargo.toml exists in current directory
 ///
 /// # Complexity
@@ -103,6 +145,45 @@ fn run_cargo_build(release: bool, verbose: bool) -> Result<()> {
     Ok(())
 }
 
+/// Time the front end on every `.ruchy` file under `src`, then `build`
+///
+/// The report is printed and written even when the build fails, so slow
+/// failing builds can be investigated too.
+///
+/// # Complexity
+///
+/// Complexity: 4 (within Toyota Way limits ≤10)
+fn run_timed_build<T>(build: impl FnOnce() -> Result<T>) -> Result<T> {
+    let mut timer = BuildTimer::new();
+    let sources = glob::glob("src/**/*.ruchy").context("Invalid source pattern")?;
+    for path in sources.flatten() {
+        timer.time_file(&path)?;
+    }
+    let result = timer.time_rustc(build);
+    let timings = timer.finish();
+    print!("{}", timings.render_text());
+    write_timings_reports(&timings, Path::new(TIMINGS_DIR))?;
+    result
+}
+
+/// Write the JSON and HTML timing reports to `dir`
+///
+/// # Complexity
+///
+/// Complexity: 2 (within Toyota Way limits ≤10)
+fn write_timings_reports(timings: &BuildTimings, dir: &Path) -> Result<()> {
+    fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+    let json = dir.join("ruchy-timing.json");
+    let html = dir.join("ruchy-timing.html");
+    fs::write(&json, timings.to_json()?)
+        .with_context(|| format!("Failed to write {}", json.display()))?;
+    fs::write(&html, timings.render_html())
+        .with_context(|| format!("Failed to write {}", html.display()))?;
+    println!("Timing report saved to {}", html.display());
+    Ok(())
+}
+
 /// Print success message after building
 ///
 /// # Complexity
@@ -290,7 +371,7 @@ mod tests {
 
         env::set_current_dir(temp_dir.path()).expect("Failed to change dir");
 
-        let result = handle_build_command(false, false);
+        let result = handle_build_command(false, false, false);
 
         env::set_current_dir(_original_dir).expect("Failed to restore dir");
 
@@ -307,7 +388,7 @@ mod tests {
 
         env::set_current_dir(temp_dir.path()).expect("Failed to change dir");
 
-        let _ = handle_build_command(true, false);
+        let _ = handle_build_command(true, false, false);
 
         env::set_current_dir(_original_dir).expect("Failed to restore dir");
     }
@@ -319,8 +400,42 @@ mod tests {
 
         env::set_current_dir(temp_dir.path()).expect("Failed to change dir");
 
-        let _ = handle_build_command(true, true); // release + verbose
+        let _ = handle_build_command(true, true, true); // release + timings + verbose
+
+        env::set_current_dir(_original_dir).expect("Failed to restore dir");
+    }
+    #[test]
+    fn test_handle_build_command_project_without_entry_point() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let _original_dir = env::current_dir().expect("Failed to get current dir");
+        std::fs::write(
+            temp_dir.path().join(MANIFEST_FILE),
+            "[package]\nname = \"app\"\nversion = \"0.1.0\"\n",
+        )
+        .expect("Failed to write Ruchy.toml");
 
+        env::set_current_dir(temp_dir.path()).expect("Failed to change dir");
+        let result = handle_build_command(false, false, false);
         env::set_current_dir(_original_dir).expect("Failed to restore dir");
+
+        // Project mode, not the Cargo.toml wrapper
+        let err = result.expect_err("Should fail without src/main.ruchy");
+        assert!(err.to_string().contains("main.ruchy not found"), "{err}");
+    }
+
+    #[test]
+    fn test_write_timings_reports() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let dir = temp_dir.path().join("timings");
+        let mut timer = BuildTimer::new();
+        timer.time_source("main.ruchy", "fun main() { println(1) }");
+        let timings = timer.finish();
+
+        write_timings_reports(&timings, &dir).expect("Failed to write reports");
+
+        let json = fs::read_to_string(dir.join("ruchy-timing.json")).expect("JSON report");
+        assert!(json.contains("\"main.ruchy\""));
+        let html = fs::read_to_string(dir.join("ruchy-timing.html")).expect("HTML report");
+        assert!(html.contains("main.ruchy"));
     }
 }
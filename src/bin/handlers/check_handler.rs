@@ -67,8 +67,11 @@ pub fn handle_check_syntax(file: &Path) -> Result<()> {
     let source = super::read_file_with_context(file)?;
     let mut parser = RuchyParser::new(&source);
     match parser.parse() {
-        Ok(_) => {
+        Ok(ast) => {
             println!("{}", "✓ Syntax is valid".green());
+            report_match_warnings(file, &source, &ast);
+            report_unknown_methods(file, &source, &ast);
+            report_effect_warnings(file, &source, &ast);
             Ok(())
         }
         Err(e) => {
@@ -87,6 +90,64 @@ pub fn handle_check_syntax(file: &Path) -> Result<()> {
     }
 }
 
+/// Print match exhaustiveness and reachability warnings (complexity: 2)
+///
+/// Warnings never fail the check; they point at `file:line:column`.
+fn report_match_warnings(file: &Path, source: &str, ast: &ruchy::frontend::ast::Expr) {
+    for diagnostic in ruchy::middleend::analyze_matches(ast) {
+        let (line, column) = diagnostic.position(source);
+        eprintln!(
+            "{}",
+            format!(
+                "⚠ {}:{}:{}: warning[{}]: {}",
+                file.display(),
+                line + 1,
+                column + 1,
+                diagnostic.code(),
+                diagnostic.message
+            )
+            .yellow()
+        );
+    }
+}
+
+/// Print warnings for unknown methods called on literals (complexity: 2)
+///
+/// Same format as the match warnings; the interpreter would fail on these calls.
+fn report_unknown_methods(file: &Path, source: &str, ast: &ruchy::frontend::ast::Expr) {
+    for missing in ruchy::middleend::analyze_method_calls(ast) {
+        let (line, column) = missing.position(source).unwrap_or((1, 1));
+        eprintln!(
+            "{}",
+            format!(
+                "⚠ {}:{line}:{column}: warning[unknown_method]: {missing}",
+                file.display()
+            )
+            .yellow()
+        );
+    }
+}
+
+/// Print warnings for `@pure` and `@memoize` functions whose effects
+/// contradict the annotation (complexity: 2)
+fn report_effect_warnings(file: &Path, source: &str, ast: &ruchy::frontend::ast::Expr) {
+    for diagnostic in ruchy::middleend::infer_effects(ast).diagnostics() {
+        let (line, column) = diagnostic.position(source);
+        eprintln!(
+            "{}",
+            format!(
+                "⚠ {}:{}:{}: warning[{}]: {}",
+                file.display(),
+                line + 1,
+                column + 1,
+                diagnostic.code,
+                diagnostic.message
+            )
+            .yellow()
+        );
+    }
+}
+
 /// Estimate the line number where a parse error occurred (complexity: 5)
 ///
 /// This is a heuristic that counts newlines in the source code to find the approximate
@@ -0,0 +1,53 @@
+//! Handler for `ruchy clean` command
+//!
+//! Removes the build cache that `ruchy compile` keeps in `.ruchy/cache`
+
+use anyhow::Result;
+use ruchy::backend::BuildCache;
+use std::path::Path;
+
+/// Handle `ruchy clean` command - purge the build cache of the current directory
+///
+/// # Errors
+///
+/// Returns an error if the cache directory exists but cannot be removed
+pub fn handle_clean_command(verbose: bool) -> Result<()> {
+    clean_dir(&std::env::current_dir()?, verbose)
+}
+
+/// Purge the build cache under `dir`
+fn clean_dir(dir: &Path, verbose: bool) -> Result<()> {
+    let cache = BuildCache::in_dir(dir);
+    let removed = cache.clean()?;
+    if removed.files == 0 {
+        println!("Build cache is empty");
+    } else {
+        println!(
+            "Removed {} cached artifact(s), {} bytes",
+            removed.files, removed.bytes
+        );
+    }
+    if verbose {
+        println!("Cache directory: {}", cache.root().display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_clean_dir_removes_cache() {
+        let dir = TempDir::new().unwrap();
+        let cache = BuildCache::in_dir(dir.path());
+        cache.store_rust("key", "fn main() {}").unwrap();
+        assert!(cache.root().exists());
+
+        clean_dir(dir.path(), true).unwrap();
+        assert!(!cache.root().exists());
+        // Cleaning again is a no-op
+        clean_dir(dir.path(), false).unwrap();
+    }
+}
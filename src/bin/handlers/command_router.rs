@@ -3,12 +3,15 @@
 //! Routes complex CLI commands to their appropriate handlers.
 
 use anyhow::Result;
+use ruchy::runtime::script::MANIFEST_FILE;
+use std::path::Path;
 
 use super::{
-    add, commands, handle_actor_observe_command, handle_bench_command, handle_coverage_command,
-    handle_dataflow_debug_command, handle_doc_command, handle_mcp_command, handle_notebook_command,
-    handle_optimize_command, handle_prove_command, handle_replay_to_tests_command,
-    handle_serve_command, handle_wasm_command,
+    add, commands, handle_actor_observe_command, handle_bench_command,
+    handle_bench_engines_command, handle_coverage_command, handle_dataflow_debug_command,
+    handle_doc_command, handle_mcp_command, handle_notebook_command,
+    handle_notebook_export_command, handle_optimize_command, handle_prove_command,
+    handle_replay_to_tests_command, handle_serve_command, handle_wasm_command,
 };
 
 // ============================================================================
@@ -33,6 +36,19 @@ pub fn derive_fmt_write_flag(check: bool, stdout: bool) -> bool {
     !check && !stdout
 }
 
+/// Derive the fmt range from `--range` (lines) or `--byte-range`
+pub fn derive_fmt_range(
+    lines: Option<&str>,
+    bytes: Option<&str>,
+) -> Result<Option<ruchy::cli::shared::FormatRange>, String> {
+    use ruchy::cli::shared::FormatRange;
+    match (lines, bytes) {
+        (Some(spec), _) => FormatRange::lines(spec).map(Some),
+        (None, Some(spec)) => FormatRange::bytes(spec).map(Some),
+        (None, None) => Ok(None),
+    }
+}
+
 /// Derive coverage threshold with default
 pub fn derive_coverage_threshold(threshold: Option<f64>) -> f64 {
     threshold.unwrap_or(80.0)
@@ -82,6 +98,7 @@ fn dispatch_analysis(command: crate::Commands) -> Result<()> {
             metrics,
             symbols,
             deps,
+            effects,
             verbose,
             output,
         } => commands::handle_ast_command(
@@ -91,6 +108,7 @@ fn dispatch_analysis(command: crate::Commands) -> Result<()> {
             metrics,
             symbols,
             deps,
+            effects,
             verbose,
             output.as_deref(),
         ),
@@ -118,6 +136,7 @@ fn dispatch_analysis(command: crate::Commands) -> Result<()> {
             profile,
             binary,
             iterations,
+            format,
             bigo,
             bench,
             compare,
@@ -129,6 +148,7 @@ fn dispatch_analysis(command: crate::Commands) -> Result<()> {
             profile,
             binary,
             iterations,
+            &format,
             bigo,
             bench,
             compare.as_deref(),
@@ -195,8 +215,12 @@ fn dispatch_analysis(command: crate::Commands) -> Result<()> {
             line_width: _,
             indent: _,
             use_tabs: _,
+            range,
+            byte_range,
         } => {
             let write = derive_fmt_write_flag(check, stdout);
+            let range = derive_fmt_range(range.as_deref(), byte_range.as_deref())
+                .map_err(anyhow::Error::msg)?;
             commands::handle_fmt_command(
                 &file,
                 check,
@@ -205,6 +229,7 @@ fn dispatch_analysis(command: crate::Commands) -> Result<()> {
                 all,
                 diff,
                 stdout,
+                range,
                 false,
             )
         }
@@ -217,14 +242,12 @@ fn dispatch_analysis(command: crate::Commands) -> Result<()> {
             format,
             rules,
             deny_warnings: _,
-            max_complexity: _,
+            max_complexity,
             config,
             init_config,
         } => {
             if init_config {
-                // Create default lint config
-                println!("Creating default lint configuration...");
-                Ok(())
+                commands::handle_lint_init_config(config.as_deref())
             } else if let Some(file_path) = file {
                 commands::handle_lint_command(
                     &file_path,
@@ -235,6 +258,7 @@ fn dispatch_analysis(command: crate::Commands) -> Result<()> {
                     verbose,
                     None, // ignore not available
                     config.as_deref(),
+                    max_complexity,
                 )
             } else {
                 Err(anyhow::anyhow!(
@@ -283,6 +307,11 @@ fn dispatch_analysis(command: crate::Commands) -> Result<()> {
 fn dispatch_tooling(command: crate::Commands) -> Result<()> {
     match command {
         crate::Commands::Notebook {
+            command: Some(crate::NotebookCommands::Export { file, to, output }),
+            ..
+        } => handle_notebook_export_command(&file, &to, output.as_deref()),
+        crate::Commands::Notebook {
+            command: None,
             file,
             port,
             open,
@@ -297,6 +326,9 @@ fn dispatch_tooling(command: crate::Commands) -> Result<()> {
             debounce,
             pid_file,
             watch_wasm,
+            eval_api,
+            eval_rate_limit,
+            eval_timeout,
         } => handle_serve_command(
             &directory,
             port,
@@ -306,6 +338,11 @@ fn dispatch_tooling(command: crate::Commands) -> Result<()> {
             debounce,
             pid_file.as_deref(),
             watch_wasm,
+            eval_api.then(|| ruchy::server::eval_api::EvalApiConfig {
+                timeout: std::time::Duration::from_millis(eval_timeout),
+                requests_per_minute: eval_rate_limit,
+                ..ruchy::server::eval_api::EvalApiConfig::default()
+            }),
         ),
         crate::Commands::ReplayToTests {
             input,
@@ -330,6 +367,9 @@ fn dispatch_tooling(command: crate::Commands) -> Result<()> {
             portability,
             opt_level,
             debug,
+            embed_metadata,
+            fuel,
+            whole_program,
             simd,
             threads,
             component_model,
@@ -346,6 +386,9 @@ fn dispatch_tooling(command: crate::Commands) -> Result<()> {
             portability,
             &opt_level,
             debug,
+            embed_metadata,
+            fuel,
+            whole_program,
             simd,
             threads,
             component_model,
@@ -374,11 +417,22 @@ fn dispatch_tooling(command: crate::Commands) -> Result<()> {
             package,
             version,
             dev,
-            registry: _registry,
+            registry,
         } => {
-            // Use our new add::handle_add_command (CARGO-003)
-            // Note: registry parameter ignored for now - using cargo's default (crates.io)
-            add::handle_add_command(&package, version.as_deref(), dev, false)
+            // A Ruchy.toml project takes Ruchy packages from the registry;
+            // otherwise add a crate with cargo (CARGO-003)
+            if Path::new(MANIFEST_FILE).is_file() {
+                add::handle_add_package_command(
+                    Path::new("."),
+                    &package,
+                    version.as_deref(),
+                    dev,
+                    &registry,
+                    false,
+                )
+            } else {
+                add::handle_add_command(&package, version.as_deref(), dev, false)
+            }
         }
         crate::Commands::Bench {
             file,
@@ -387,14 +441,37 @@ fn dispatch_tooling(command: crate::Commands) -> Result<()> {
             format,
             output,
             verbose,
-        } => handle_bench_command(
-            &file,
-            iterations,
-            warmup,
-            &format,
-            output.as_deref(),
-            verbose,
-        ),
+            baseline,
+            save_baseline,
+            max_regression,
+            engines,
+            history,
+        } => match file {
+            // A bool flag always has a value, so clap's `requires` cannot
+            // tell whether --engines was given
+            _ if history.is_some() && !engines => {
+                Err(anyhow::anyhow!("--history requires --engines"))
+            }
+            Some(file) if !engines => handle_bench_command(
+                &file,
+                iterations,
+                warmup,
+                &format,
+                output.as_deref(),
+                baseline.as_deref(),
+                save_baseline,
+                max_regression,
+                verbose,
+            ),
+            _ => handle_bench_engines_command(
+                iterations,
+                warmup,
+                &format,
+                output.as_deref(),
+                history.as_deref(),
+                verbose,
+            ),
+        },
         crate::Commands::Doc {
             path,
             output,
@@ -555,6 +632,14 @@ fn dispatch_tooling(command: crate::Commands) -> Result<()> {
             crate::handlers::handlers_modules::migrate::run_migration(&path, dry_run)?;
             Ok(())
         }
+        crate::Commands::Upgrade {
+            path,
+            dry_run,
+            no_test,
+        } => {
+            crate::handlers::handlers_modules::upgrade::run_upgrade(&path, dry_run, !no_test)?;
+            Ok(())
+        }
         crate::Commands::Tier {
             path,
             json,
@@ -696,6 +781,36 @@ mod tests {
         assert!(!derive_fmt_write_flag(true, true));
     }
 
+    // ========================================================================
+    // derive_fmt_range tests
+    // ========================================================================
+
+    #[test]
+    fn test_fmt_range_absent() {
+        assert_eq!(derive_fmt_range(None, None), Ok(None));
+    }
+
+    #[test]
+    fn test_fmt_range_lines() {
+        assert_eq!(
+            derive_fmt_range(Some("10:42"), None),
+            Ok(Some(ruchy::cli::shared::FormatRange::Lines(10, 42)))
+        );
+    }
+
+    #[test]
+    fn test_fmt_range_bytes() {
+        assert_eq!(
+            derive_fmt_range(None, Some("3:9")),
+            Ok(Some(ruchy::cli::shared::FormatRange::Bytes(3, 9)))
+        );
+    }
+
+    #[test]
+    fn test_fmt_range_invalid_spec() {
+        assert!(derive_fmt_range(Some("ten"), None).is_err());
+    }
+
     // ========================================================================
     // derive_coverage_threshold tests
     // ========================================================================
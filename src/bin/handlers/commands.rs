@@ -2,6 +2,7 @@
 // Toyota Way: Build quality in with proper implementations
 use anyhow::{bail, Context, Result};
 use colored::Colorize;
+use ruchy::cli::shared;
 use ruchy::utils::{parse_ruchy_code, read_file_with_context};
 use ruchy::Parser as RuchyParser;
 use std::collections::HashMap;
@@ -26,13 +27,18 @@ pub fn handle_ast_command(
     metrics: bool,
     symbols: bool,
     deps: bool,
+    effects: bool,
     verbose: bool,
     output: Option<&Path>,
 ) -> Result<()> {
     let source = read_file_with_context(file)?;
     let ast = parse_ruchy_code(&source)?;
     // Determine output format based on flags
-    let output_content = generate_ast_output(&ast, json, graph, metrics, symbols, deps)?;
+    let output_content = if effects {
+        generate_effects_output(&ast, &source, json)?
+    } else {
+        generate_ast_output(&ast, json, graph, metrics, symbols, deps)?
+    };
     if verbose {
         eprintln!("AST analysis complete for: {}", file.display());
     }
@@ -63,7 +69,7 @@ fn generate_ast_output(
     }
 }
 fn generate_json_output(ast: &ruchy::Expr) -> Result<String> {
-    Ok(serde_json::to_string_pretty(ast)?)
+    shared::ast_to_json(ast).map_err(anyhow::Error::msg)
 }
 /// Generate DOT graph representation
 fn generate_graph_output() -> String {
@@ -98,6 +104,40 @@ fn generate_symbols_output(ast: &ruchy::Expr) -> String {
 fn generate_deps_output() -> String {
     "=== Dependencies ===\nNo external dependencies\n".to_string()
 }
+/// Effects of every function, one line each with the first cause of every
+/// effect below it, then the warnings of `ruchy check` (complexity: 4)
+fn generate_effects_output(ast: &ruchy::Expr, source: &str, json: bool) -> Result<String> {
+    let report = ruchy::middleend::infer_effects(ast);
+    if json {
+        return Ok(serde_json::to_string_pretty(&report)?);
+    }
+    let mut output = String::from("=== Effects ===\n");
+    for function in &report.functions {
+        output.push_str(&format!(
+            "{} (line {}): {}\n",
+            function.name,
+            function.position(source).0 + 1,
+            function.classification()
+        ));
+        for cause in &function.causes {
+            output.push_str(&format!(
+                "  {}: {} (line {})\n",
+                cause.effect,
+                cause.reason,
+                cause.position(source).0 + 1
+            ));
+        }
+    }
+    for diagnostic in report.diagnostics() {
+        output.push_str(&format!(
+            "warning[{}]: {} (line {})\n",
+            diagnostic.code,
+            diagnostic.message,
+            diagnostic.position(source).0 + 1
+        ));
+    }
+    Ok(output)
+}
 fn generate_default_output(ast: &ruchy::Expr) -> String {
     format!("{:#?}", ast)
 }
@@ -111,21 +151,30 @@ fn write_ast_output(content: String, output: Option<&Path>) -> Result<()> {
     Ok(())
 }
 /// format command - format Ruchy source code
+///
+/// A `path` of `-` reads stdin and writes the result to stdout; `range`
+/// limits formatting to the top-level items it overlaps.
 pub fn handle_fmt_command(
     path: &Path,
     check: bool,
     write: bool,
-    _config: Option<&Path>,
+    config: Option<&Path>,
     _all: bool,
     diff: bool,
     stdout: bool,
+    range: Option<shared::FormatRange>,
     verbose: bool,
 ) -> Result<()> {
+    let from_stdin = path.as_os_str() == "-";
     // Read and format the file
-    let (source, formatted_code) = read_and_format_file(path)?;
+    let (source, formatted_code) = read_and_format_file(path, config, range)?;
     // Determine output mode and handle accordingly
-    let mode = determine_fmt_mode(check, stdout, diff, write);
-    handle_fmt_output(mode, path, &source, &formatted_code, verbose)?;
+    let (mode, display_path) = match determine_fmt_mode(check, stdout, diff, write) {
+        FmtMode::Write | FmtMode::Default if from_stdin => (FmtMode::Stdout, Path::new("<stdin>")),
+        mode if from_stdin => (mode, Path::new("<stdin>")),
+        mode => (mode, path),
+    };
+    handle_fmt_output(mode, display_path, &source, &formatted_code, verbose)?;
     Ok(())
 }
 /// Output mode for formatting
@@ -146,15 +195,31 @@ fn determine_fmt_mode(check: bool, stdout: bool, diff: bool, write: bool) -> Fmt
         _ => FmtMode::Default,
     }
 }
-fn read_and_format_file(path: &Path) -> Result<(String, String)> {
-    use ruchy::quality::formatter::Formatter;
-    let source = fs::read_to_string(path)
-        .with_context(|| format!("Failed to read file: {}", path.display()))?;
-    let mut parser = RuchyParser::new(&source);
-    let ast = parser.parse()?;
-    let mut formatter = Formatter::new();
-    formatter.set_source(source.clone());
-    let formatted_code = formatter.format(&ast)?;
+fn read_and_format_file(
+    path: &Path,
+    config: Option<&Path>,
+    range: Option<shared::FormatRange>,
+) -> Result<(String, String)> {
+    let from_stdin = path.as_os_str() == "-";
+    let (source, config_start) = if from_stdin {
+        let mut source = String::new();
+        std::io::Read::read_to_string(&mut std::io::stdin(), &mut source)
+            .context("Failed to read stdin")?;
+        (source, std::env::current_dir()?)
+    } else {
+        let source = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read file: {}", path.display()))?;
+        (source, path.to_path_buf())
+    };
+    let config =
+        shared::load_formatter_config(&config_start, config).map_err(anyhow::Error::msg)?;
+    let formatted_code = match range {
+        Some(range) => shared::format_source_range(&source, range, &config),
+        // Editors pipe the buffer on every save, so it must settle in one pass
+        None if from_stdin => shared::format_source_stable(&source, &config),
+        None => shared::format_source(&source, &config),
+    }
+    .map_err(anyhow::Error::msg)?;
     Ok((source, formatted_code))
 }
 fn handle_fmt_output(
@@ -186,7 +251,7 @@ fn handle_fmt_output(
     }
 }
 fn handle_check_mode(path: &Path, source: &str, formatted_code: &str) -> Result<()> {
-    if source == formatted_code {
+    if shared::is_formatted(source, formatted_code) {
         println!("{} {} is properly formatted", "✓".green(), path.display());
         Ok(())
     } else {
@@ -208,7 +273,7 @@ fn handle_diff_mode(path: &Path, source: &str, formatted_code: &str) {
     }
 }
 fn handle_write_mode(path: &Path, source: &str, formatted_code: &str, verbose: bool) -> Result<()> {
-    if source == formatted_code {
+    if shared::is_formatted(source, formatted_code) {
         if verbose {
             println!("{} {} already formatted", "→".blue(), path.display());
         }
@@ -225,21 +290,15 @@ fn handle_default_mode(formatted_code: &str) {
 fn read_and_parse_source(path: &Path) -> Result<(String, ruchy::frontend::ast::Expr)> {
     let source = fs::read_to_string(path)
         .with_context(|| format!("Failed to read file: {}", path.display()))?;
-    let mut parser = RuchyParser::new(&source);
-    let ast = parser.parse()?;
+    let ast = shared::parse_source(&source).map_err(anyhow::Error::msg)?;
     Ok((source, ast))
 }
-fn configure_linter(rules: Option<&str>, strict: bool) -> ruchy::quality::linter::Linter {
-    use ruchy::quality::linter::Linter;
-    let mut linter = Linter::new();
-    // Apply rule filters if specified
-    if let Some(rule_filter) = rules {
-        linter.set_rules(rule_filter);
-    }
-    if strict {
-        linter.set_strict_mode(true);
-    }
-    linter
+fn configure_linter(
+    config: &ruchy::quality::LintConfig,
+    rules: Option<&str>,
+    strict: bool,
+) -> ruchy::quality::linter::Linter {
+    shared::configure_linter_with_config(config, rules, strict)
 }
 /// Run linter analysis
 fn run_linter_analysis(
@@ -250,10 +309,10 @@ fn run_linter_analysis(
     linter.lint(ast, source)
 }
 fn format_json_output(issues: &[ruchy::quality::linter::LintIssue]) -> Result<()> {
-    let json_output = serde_json::json!({
-        "issues": issues
-    });
-    println!("{}", serde_json::to_string_pretty(&json_output)?);
+    println!(
+        "{}",
+        shared::lint_issues_json(issues).map_err(anyhow::Error::msg)?
+    );
     Ok(())
 }
 /// Count errors and warnings in issues
@@ -303,7 +362,7 @@ fn format_text_output(issues: &[ruchy::quality::linter::LintIssue], path: &Path,
         }
     }
 }
-/// auto-fix if requested
+/// auto-fix if requested: apply the rules' fixes and report what each rule fixed
 fn handle_auto_fix(
     linter: &ruchy::quality::linter::Linter,
     source: &str,
@@ -311,14 +370,62 @@ fn handle_auto_fix(
     path: &Path,
     auto_fix: bool,
 ) -> Result<()> {
-    if auto_fix && !issues.is_empty() {
-        println!("\n{} Attempting auto-fix...", "→".blue());
-        let fixed = linter.auto_fix(source, issues)?;
-        fs::write(path, fixed)?;
-        println!("{} Fixed {} issues", "✓".green(), issues.len());
+    if !auto_fix || issues.is_empty() {
+        return Ok(());
+    }
+    println!("\n{} Attempting auto-fix...", "→".blue());
+    let outcome = linter.apply_fixes(source, issues);
+    if outcome.source != source {
+        parse_ruchy_code(&outcome.source).with_context(|| {
+            format!(
+                "Fixes would leave {} unparsable; it was not changed",
+                path.display()
+            )
+        })?;
+        let backup = write_fixed_source(path, source, &outcome.source)?;
+        println!("  Original saved to {}", backup.display());
+    }
+    println!(
+        "{} Fixed {} of {} issues",
+        "✓".green(),
+        outcome.fixed.len(),
+        issues.len()
+    );
+    for (rule, count) in outcome.fixed_by_rule() {
+        println!("  {rule} ({count}):");
+        for issue in outcome.fixed.iter().filter(|issue| issue.rule == rule) {
+            println!("    {}:{}: {}", path.display(), issue.line, issue.message);
+        }
+    }
+    match outcome.unfixed.len() {
+        0 => {}
+        1 => println!("{} 1 issue needs a manual fix", "⚠".yellow()),
+        unfixed => println!("{} {unfixed} issues need a manual fix", "⚠".yellow()),
     }
     Ok(())
 }
+/// Replace `path` with `fixed` in one rename, keeping `original` next to it
+/// as `<file>.bak` (complexity: 4)
+pub(crate) fn write_fixed_source(path: &Path, original: &str, fixed: &str) -> Result<PathBuf> {
+    let sibling = |suffix: &str| {
+        let mut name = path.as_os_str().to_owned();
+        name.push(suffix);
+        PathBuf::from(name)
+    };
+    let backup = sibling(".bak");
+    fs::write(&backup, original)
+        .with_context(|| format!("Failed to write backup {}", backup.display()))?;
+    let temp = sibling(".fix.tmp");
+    fs::write(&temp, fixed).with_context(|| format!("Failed to write {}", temp.display()))?;
+    let replaced = fs::metadata(path)
+        .and_then(|metadata| fs::set_permissions(&temp, metadata.permissions()))
+        .and_then(|()| fs::rename(&temp, path));
+    if let Err(e) = replaced {
+        let _ = fs::remove_file(&temp);
+        return Err(e).with_context(|| format!("Failed to replace {}", path.display()));
+    }
+    Ok(backup)
+}
 /// strict mode exit if issues found
 fn handle_strict_mode(issues: &[ruchy::quality::linter::LintIssue], strict: bool) -> Result<()> {
     if !issues.is_empty() && strict {
@@ -336,11 +443,21 @@ pub fn handle_lint_command(
     json: bool,
     verbose: bool,
     _ignore: Option<&str>,
-    _config: Option<&Path>,
+    config: Option<&Path>,
+    max_complexity: Option<usize>,
 ) -> Result<()> {
+    let mut lint_config = shared::load_lint_config(path, config).map_err(anyhow::Error::msg)?;
+    if lint_config.is_ignored(path) {
+        println!("Skipping {} (ignored by lint config)", path.display());
+        return Ok(());
+    }
+    if let Some(max_complexity) = max_complexity {
+        lint_config.max_complexity = max_complexity;
+    }
     let (source, ast) = read_and_parse_source(path)?;
-    let linter = configure_linter(rules, strict);
-    let issues = run_linter_analysis(&linter, &ast, &source)?;
+    let linter = configure_linter(&lint_config, rules, strict);
+    let mut issues = run_linter_analysis(&linter, &ast, &source)?;
+    issues.retain(|issue| !lint_config.allowed_in(path, &issue.rule));
     if json {
         format_json_output(&issues)?;
     } else {
@@ -350,6 +467,18 @@ pub fn handle_lint_command(
     handle_strict_mode(&issues, strict)?;
     Ok(())
 }
+/// lint --init-config: write a lint configuration listing every rule at its
+/// default level to `path`, or `.ruchylint.toml` in the current directory
+pub fn handle_lint_init_config(path: Option<&Path>) -> Result<()> {
+    let path = path.unwrap_or(Path::new(shared::LINT_CONFIG_FILE));
+    if path.exists() {
+        bail!("{} already exists; it was not changed", path.display());
+    }
+    fs::write(path, ruchy::quality::LintConfig::template())
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    println!("{} Created {}", "✓".green(), path.display());
+    Ok(())
+}
 /// provability command - formal verification
 pub fn handle_provability_command(
     file: &Path,
@@ -470,11 +599,15 @@ fn write_provability_output(content: String, output: Option<&Path>) -> Result<()
     Ok(())
 }
 /// runtime command - performance analysis
+///
+/// `--profile` runs the script under the interpreter profiler and reports
+/// per-function timings as text, JSON or folded stacks (`format`).
 pub fn handle_runtime_command(
     file: &Path,
     profile: bool,
     binary: bool,
     iterations: Option<usize>,
+    format: &str,
     bigo: bool,
     bench: bool,
     compare: Option<&Path>,
@@ -490,9 +623,34 @@ pub fn handle_runtime_command(
         return handle_binary_profiling(file, &source, &ast, iterations, output);
     }
 
-    // Existing interpreter profiling behavior
+    if !matches!(format, "text" | "json" | "folded") {
+        bail!("Unknown profile format '{format}' (expected text, json or folded)");
+    }
+    let profile = if profile {
+        Some(profile_interpreter(file, &source, iterations.unwrap_or(1))?)
+    } else {
+        None
+    };
+    let memory = if memory {
+        Some(measure_memory(file, &source)?)
+    } else {
+        None
+    };
+    match (format, &profile) {
+        ("json", Some(profile)) => return write_runtime_output(profile.to_json() + "\n", output),
+        ("folded", Some(profile)) => return write_runtime_output(profile.to_folded(), output),
+        _ => {}
+    }
+
     let mut output_content = generate_runtime_header(file);
-    add_runtime_sections(&mut output_content, &ast, profile, bigo, bench, memory);
+    add_runtime_sections(
+        &mut output_content,
+        &ast,
+        profile.as_ref(),
+        bigo,
+        bench,
+        memory.as_ref(),
+    );
     if let Some(compare_file) = compare {
         add_comparison_section(&mut output_content, file, compare_file);
     }
@@ -513,13 +671,14 @@ fn generate_runtime_header(file: &Path) -> String {
 fn add_runtime_sections(
     output: &mut String,
     ast: &ruchy::frontend::ast::Expr,
-    profile: bool,
+    profile: Option<&ruchy::runtime::profiler::Profile>,
     bigo: bool,
     bench: bool,
-    memory: bool,
+    memory: Option<&ruchy::runtime::memory::MemoryReport>,
 ) {
-    if profile {
-        add_profile_section(output);
+    if let Some(profile) = profile {
+        output.push_str(&profile.to_text());
+        output.push('\n');
     }
     if bigo {
         add_bigo_section(output, ast);
@@ -527,15 +686,51 @@ fn add_runtime_sections(
     if bench {
         add_benchmark_section(output);
     }
-    if memory {
-        add_memory_section(output);
+    if let Some(memory) = memory {
+        add_memory_section(output, memory);
     }
 }
-/// Add execution profiling section
-fn add_profile_section(output: &mut String) {
-    output.push_str("=== Execution Profiling ===\n");
-    output.push_str("Function call times:\n");
-    output.push_str("  main: 0.001ms\n\n");
+/// Run `source` under the interpreter profiler `iterations` times
+///
+/// Runs are merged into one profile; the script's own output is not captured.
+fn profile_interpreter(
+    file: &Path,
+    source: &str,
+    iterations: usize,
+) -> Result<ruchy::runtime::profiler::Profile> {
+    use ruchy::runtime::profiler;
+    use ruchy::runtime::script::{run_script, ScriptOptions};
+
+    let options = ScriptOptions::for_file(file);
+    let mut merged: Option<profiler::Profile> = None;
+    for _ in 0..iterations.max(1) {
+        profiler::start();
+        let result = run_script(source, &options);
+        let run = profiler::finish().unwrap_or_default();
+        if let Err(e) = result {
+            bail!("Profiled run of {} failed: {e}", file.display());
+        }
+        match &mut merged {
+            Some(profile) => profile.merge(run),
+            None => merged = Some(run),
+        }
+    }
+    Ok(merged.unwrap_or_default())
+}
+/// Run `source` and measure the memory held by the bindings it leaves
+///
+/// The script's own output is not captured.
+fn measure_memory(file: &Path, source: &str) -> Result<ruchy::runtime::memory::MemoryReport> {
+    use ruchy::runtime::script::{run_script, ScriptOptions};
+
+    let options = ScriptOptions {
+        measure_memory: true,
+        ..ScriptOptions::for_file(file)
+    };
+    match run_script(source, &options) {
+        Ok(outcome) => Ok(outcome.memory.unwrap_or_default()),
+        Err(e) => bail!("Measured run of {} failed: {e}", file.display()),
+    }
 }
 /// Add `BigO` complexity analysis section
 fn add_bigo_section(output: &mut String, ast: &ruchy::frontend::ast::Expr) {
@@ -551,10 +746,9 @@ fn add_benchmark_section(output: &mut String) {
     output.push_str("Min: 0.08ms, Max: 0.12ms\n\n");
 }
 /// Add memory analysis section
-fn add_memory_section(output: &mut String) {
-    output.push_str("=== Memory Analysis ===\n");
-    output.push_str("Peak memory usage: 1MB\n");
-    output.push_str("Allocations: 10\n\n");
+fn add_memory_section(output: &mut String, memory: &ruchy::runtime::memory::MemoryReport) {
+    output.push_str(&memory.to_text());
+    output.push('\n');
 }
 /// Add performance comparison section
 fn add_comparison_section(output: &mut String, current: &Path, baseline: &Path) {
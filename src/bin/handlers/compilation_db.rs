@@ -0,0 +1,80 @@
+//! Handler for `ruchy emit-compilationdb` command
+//!
+//! Writes the compilation database of a `Ruchy.toml` project or a single
+//! entry file for external build systems
+
+use anyhow::{Context, Result};
+use ruchy::backend::compilation_db;
+use ruchy::runtime::script::MANIFEST_FILE;
+use std::fs;
+use std::path::Path;
+
+/// Handle `ruchy emit-compilationdb` - describe every module of the program
+/// at `path` as JSON
+///
+/// `path` is a project directory holding a `Ruchy.toml`, or an entry file.
+/// The database goes to `output`, or stdout when there is none.
+///
+/// # Errors
+///
+/// Returns an error if `path` is neither a project nor a file, a module
+/// cannot be resolved or parsed, or the output cannot be written
+pub fn handle_emit_compilationdb_command(
+    path: &Path,
+    output: Option<&Path>,
+    verbose: bool,
+) -> Result<()> {
+    let entries = if path.join(MANIFEST_FILE).is_file() {
+        compilation_db::for_project(path)?
+    } else if path.is_file() {
+        compilation_db::for_file(path)?
+    } else {
+        anyhow::bail!(
+            "{} is neither a file nor a directory with a {MANIFEST_FILE}",
+            path.display()
+        );
+    };
+    let json = serde_json::to_string_pretty(&entries)?;
+    match output {
+        Some(output) => {
+            fs::write(output, format!("{json}\n"))
+                .with_context(|| format!("Failed to write {}", output.display()))?;
+            if verbose {
+                eprintln!("Wrote {} module(s) to {}", entries.len(), output.display());
+            }
+        }
+        None => println!("{json}"),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_writes_database_of_single_file() {
+        let dir = TempDir::new().expect("Failed to create temp dir");
+        let file = dir.path().join("main.ruchy");
+        fs::write(&file, "println(1)\n").expect("Failed to write source");
+        let output = dir.path().join("compile_db.json");
+
+        handle_emit_compilationdb_command(&file, Some(&output), false)
+            .expect("Failed to emit database");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&output).expect("Database written"))
+                .expect("Database is JSON");
+        assert_eq!(json[0]["module"], "crate");
+        assert_eq!(json[0]["arguments"][1], "transpile");
+    }
+
+    #[test]
+    fn test_rejects_directory_without_manifest() {
+        let dir = TempDir::new().expect("Failed to create temp dir");
+        let err = handle_emit_compilationdb_command(dir.path(), None, false)
+            .expect_err("Should fail without Ruchy.toml");
+        assert!(err.to_string().contains("Ruchy.toml"), "{err}");
+    }
+}
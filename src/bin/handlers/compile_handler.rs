@@ -14,6 +14,9 @@ use std::path::{Path, PathBuf};
 /// * `strip` - Strip debug symbols
 /// * `static_link` - Use static linking
 /// * `target` - Target triple for cross-compilation
+/// * `no_cache` - Always transpile and run rustc, bypassing `.ruchy/cache`
+/// * `whole_program` - Propagate constants across functions before transpiling
+/// * `hermetic` - Check the pinned rustc, build offline and record a build-info manifest
 ///
 /// # Errors
 /// Returns error if compilation fails or rustc is not available
@@ -30,9 +33,15 @@ pub fn handle_compile_command(
     show_profile_info: bool,
     pgo: bool,
     embed_models: Vec<PathBuf>,
+    no_cache: bool,
+    whole_program: bool,
+    hermetic: bool,
 ) -> Result<()> {
     use colored::Colorize;
-    use ruchy::backend::{compile_to_binary as backend_compile, CompileOptions};
+    use ruchy::backend::hermetic::compile_hermetic;
+    use ruchy::backend::{
+        compile_to_binary as backend_compile, compile_to_binary_cached, BuildCache, CompileOptions,
+    };
     use std::fs;
     use std::time::Instant;
 
@@ -69,6 +78,7 @@ pub fn handle_compile_command(
             rustc_flags,
             verbose,
             json_output,
+            whole_program,
         );
     }
 
@@ -105,6 +115,13 @@ pub fn handle_compile_command(
         }
     }
 
+    // Verbose output: what the AST optimization pass changes
+    if verbose && final_opt_level != "0" {
+        if let Ok(source) = fs::read_to_string(file) {
+            super::log_optimization_report(&source);
+        }
+    }
+
     let compile_start = Instant::now();
 
     let options = CompileOptions {
@@ -115,16 +132,36 @@ pub fn handle_compile_command(
         target,
         rustc_flags,
         embed_models,
+        whole_program,
     };
 
-    match backend_compile(file, &options) {
-        Ok(binary_path) => {
+    let mut hermetic_build = None;
+    let result = if hermetic {
+        compile_hermetic(file, &options).map(|build| {
+            let output = build.output.clone();
+            hermetic_build = Some(build);
+            (output, false)
+        })
+    } else if no_cache {
+        backend_compile(file, &options).map(|binary_path| (binary_path, false))
+    } else {
+        let cache = BuildCache::in_dir(&std::env::current_dir()?);
+        compile_to_binary_cached(file, &options, &cache).map(|compiled| {
+            if verbose && compiled.rust_reused {
+                println!("{} Reused cached Rust", "ℹ".bright_blue());
+            }
+            (compiled.output, compiled.binary_reused)
+        })
+    };
+    match result {
+        Ok((binary_path, cached)) => {
             let compile_time = compile_start.elapsed();
 
             println!(
-                "{} Successfully compiled to: {}",
+                "{} Successfully compiled to: {}{}",
                 "✓".bright_green(),
-                binary_path.display()
+                binary_path.display(),
+                if cached { " (cached)" } else { "" }
             );
 
             // Make the binary executable on Unix
@@ -139,6 +176,20 @@ pub fn handle_compile_command(
             let binary_size = fs::metadata(&binary_path)?.len();
             println!("{} Binary size: {} bytes", "ℹ".bright_blue(), binary_size);
 
+            if let Some(build) = &hermetic_build {
+                println!("{} rustc: {}", "ℹ".bright_blue(), build.info.rustc_version);
+                println!(
+                    "{} Vendored sources: {}",
+                    "ℹ".bright_blue(),
+                    build.vendor_dir.display()
+                );
+                println!(
+                    "{} Build info: {}",
+                    "ℹ".bright_blue(),
+                    build.build_info_path.display()
+                );
+            }
+
             // JSON output for CI/CD integration
             if let Some(json_path) = json_output {
                 generate_compilation_json(
@@ -319,6 +370,7 @@ fn generate_compilation_json(
 /// * `rustc_flags` - Additional rustc flags
 /// * `verbose` - Verbose output
 /// * `json_output` - JSON metrics output path
+/// * `whole_program` - Propagate constants across functions before transpiling
 ///
 /// # Errors
 /// Returns error if either compilation step fails
@@ -332,6 +384,7 @@ fn handle_pgo_compilation(
     mut rustc_flags: Vec<String>,
     _verbose: bool,
     json_output: Option<&Path>,
+    whole_program: bool,
 ) -> Result<()> {
     use colored::Colorize;
     use ruchy::backend::{compile_to_binary as backend_compile, CompileOptions};
@@ -376,6 +429,7 @@ fn handle_pgo_compilation(
         target: target.clone(),
         rustc_flags: rustc_flags.clone(),
         embed_models: Vec::new(),
+        whole_program,
     };
 
     backend_compile(file, &options_step1)?;
@@ -432,6 +486,7 @@ fn handle_pgo_compilation(
         target,
         rustc_flags,
         embed_models: Vec::new(),
+        whole_program,
     };
 
     backend_compile(file, &options_step2)?;
@@ -671,6 +726,7 @@ mod tests {
             target: None,
             rustc_flags: vec![],
             embed_models: vec![],
+            whole_program: false,
         };
         let result = generate_compilation_json(
             &json_path,
@@ -699,6 +755,7 @@ mod tests {
             target: Some("x86_64-unknown-linux-gnu".to_string()),
             rustc_flags: vec![],
             embed_models: vec![],
+            whole_program: false,
         };
         let info = (
             "nasa".to_string(),
@@ -735,6 +792,9 @@ mod tests {
             false,
             false,
             vec![],
+            true,
+            false,
+            false,
         );
         assert!(result.is_err());
     }
@@ -758,6 +818,9 @@ mod tests {
             false,
             false,
             vec![],
+            true,
+            false,
+            false,
         );
         // May succeed or fail depending on rustc
         let _ = result;
@@ -782,6 +845,9 @@ mod tests {
             false,
             false,
             vec![],
+            true,
+            false,
+            false,
         );
         let _ = result;
     }
@@ -805,6 +871,9 @@ mod tests {
             true, // show_profile_info
             false,
             vec![],
+            true,
+            false,
+            false,
         );
         let _ = result;
     }
@@ -828,6 +897,9 @@ mod tests {
             false,
             false,
             vec![],
+            true,
+            false,
+            false,
         );
         let _ = result;
     }
@@ -1,10 +1,14 @@
 //! Documentation Generation Handler
 //!
-//! Handles generation of documentation from Ruchy source files.
+//! Handles generation of documentation from Ruchy source files. A file is
+//! documented on a page of its own. A directory, or with `--all` the
+//! directory of the file, gets a page per `.ruchy` file and an index, and
+//! doc comments link to items on any of the pages.
 
-use anyhow::{bail, Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use colored::Colorize;
-use ruchy::frontend::ast::{CommentKind, Expr, ExprKind, Pattern};
+use ruchy::cli::shared::scan_ruchy_files;
+use ruchy::docs::{DocFormat, DocGenerator, DocPage, Documentation};
 use ruchy::frontend::parser::Parser;
 use std::fs;
 use std::path::Path;
@@ -16,53 +20,55 @@ use std::path::Path;
 /// * `output` - Output directory for generated documentation
 /// * `format` - Output format (html, markdown, json)
 /// * `private` - Include private items in documentation
-/// * `_open` - Open documentation in browser (not yet implemented)
-/// * `_all` - Include all items (not yet implemented)
+/// * `open` - Open the documentation in the browser afterwards
+/// * `all` - Document every file in the directory of `path`
 /// * `verbose` - Enable verbose output
 ///
 /// # Errors
-/// Returns error if file cannot be read, parsed, or documentation cannot be generated
+/// Returns error if a file cannot be read or parsed, or the documentation cannot be written
 pub fn handle_doc_command(
     path: &Path,
     output: &Path,
     format: &str,
     private: bool,
-    _open: bool,
-    _all: bool,
+    open: bool,
+    all: bool,
     verbose: bool,
 ) -> Result<()> {
-    // Validate format
-    if !matches!(format, "html" | "markdown" | "json") {
+    let Some(doc_format) = DocFormat::from_name(format) else {
         bail!(
             "Invalid format '{}'. Supported formats: html, markdown, json",
             format
         );
-    }
+    };
 
     // Check if path exists
     if !path.exists() {
         bail!("File or directory not found: {}", path.display());
     }
 
-    if verbose {
-        println!("{} Parsing {}...", "→".bright_blue(), path.display());
+    let root = if all && path.is_file() {
+        path.parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."))
+    } else {
+        path
+    };
+    let files = scan_ruchy_files(root).map_err(|e| anyhow!(e))?;
+    if files.is_empty() {
+        bail!("No .ruchy files found in {}", root.display());
     }
 
-    // Read and parse the file
-    let source = fs::read_to_string(path)
-        .with_context(|| format!("Failed to read file: {}", path.display()))?;
-
-    let mut parser = Parser::new(&source);
-    let ast = parser
-        .parse()
-        .map_err(|e| anyhow::anyhow!("Parse error: {}", e))?;
-
-    if verbose {
-        println!("{} Extracting documentation...", "→".bright_blue());
+    let mut generator = DocGenerator::new();
+    generator.set_include_private(private);
+    let mut pages = Vec::new();
+    for file in &files {
+        if verbose {
+            println!("{} Parsing {}...", "→".bright_blue(), file.display());
+        }
+        pages.push(document_file(&generator, file, root)?);
     }
-
-    // Extract documentation from AST
-    let docs = extract_documentation(&ast, private);
+    let docs = Documentation::new(pages);
 
     if verbose {
         println!(
@@ -71,211 +77,97 @@ pub fn handle_doc_command(
             format
         );
     }
+    for link in docs.unresolved_links() {
+        eprintln!("{} Unresolved link {}", "⚠".yellow(), link);
+    }
 
-    // Create output directory
     fs::create_dir_all(output)
         .with_context(|| format!("Failed to create output directory: {}", output.display()))?;
-
-    // Generate documentation in requested format
-    let content = match format {
-        "markdown" => generate_markdown_docs(&docs, path),
-        "json" => generate_json_docs(&docs, path)?,
-        "html" => generate_html_docs(&docs, path),
-        _ => unreachable!(),
-    };
-
-    // Determine output filename
-    let file_stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("docs");
-    let extension = match format {
-        "markdown" => "md",
-        "json" => "json",
-        "html" => "html",
-        _ => unreachable!(),
+    for page in 0..docs.pages().len() {
+        write_doc(
+            &output.join(docs.file_name(page, doc_format)),
+            &docs.render_page(page, doc_format)?,
+        )?;
+    }
+    // A single file is its own entry point; a directory gets an index
+    let entry = if root.is_file() {
+        output.join(docs.file_name(0, doc_format))
+    } else {
+        let index = output.join(format!("index.{}", doc_format.extension()));
+        write_doc(&index, &docs.render_index(doc_format)?)?;
+        index
     };
-    let output_file = output.join(format!("{}.{}", file_stem, extension));
-
-    // Write documentation
-    fs::write(&output_file, content)
-        .with_context(|| format!("Failed to write documentation: {}", output_file.display()))?;
-
-    println!(
-        "{} Generated documentation: {}",
-        "✓".bright_green(),
-        output_file.display()
-    );
 
+    if open {
+        open_in_browser(&entry)?;
+    }
     Ok(())
 }
 
-/// Documentation item extracted from source code
-#[derive(Debug)]
-pub struct DocItem {
-    pub kind: DocItemKind,
-    pub name: String,
-    pub params: Vec<String>,
-    pub doc_comment: Option<String>,
-}
-
-#[derive(Debug)]
-pub enum DocItemKind {
-    Function,
-}
-
-/// Extract documentation from AST
-pub fn extract_documentation(ast: &Expr, include_private: bool) -> Vec<DocItem> {
-    let mut docs = Vec::new();
-    extract_docs_recursive(ast, &mut docs, include_private);
-    docs
-}
-
-/// Recursively extract documentation from AST nodes
-fn extract_docs_recursive(expr: &Expr, docs: &mut Vec<DocItem>, include_private: bool) {
-    match &expr.kind {
-        ExprKind::Function { name, params, .. } => {
-            // Extract leading doc comments from Comment structs
-            let doc_comment = expr
-                .leading_comments
-                .iter()
-                .map(|c| match &c.kind {
-                    CommentKind::Line(text) | CommentKind::Block(text) | CommentKind::Doc(text) => {
-                        text.clone()
-                    }
-                })
-                .collect::<Vec<_>>()
-                .join("\n");
-            let has_doc = !doc_comment.is_empty() || include_private;
-
-            if has_doc {
-                // Extract parameter names from patterns
-                let param_names: Vec<String> = params
-                    .iter()
-                    .map(|p| match &p.pattern {
-                        Pattern::Identifier(name) => name.clone(),
-                        _ => "_".to_string(),
-                    })
-                    .collect();
-
-                docs.push(DocItem {
-                    kind: DocItemKind::Function,
-                    name: name.clone(),
-                    params: param_names,
-                    doc_comment: if doc_comment.is_empty() {
-                        None
-                    } else {
-                        Some(doc_comment)
-                    },
-                });
-            }
-        }
-        ExprKind::Block(exprs) => {
-            for e in exprs {
-                extract_docs_recursive(e, docs, include_private);
-            }
-        }
-        _ => {}
-    }
+/// Page documenting `file`, named after its path below `root` (complexity: 4)
+fn document_file(generator: &DocGenerator, file: &Path, root: &Path) -> Result<DocPage> {
+    let source = fs::read_to_string(file)
+        .with_context(|| format!("Failed to read file: {}", file.display()))?;
+    let ast = Parser::new(&source)
+        .parse()
+        .map_err(|e| anyhow!("Parse error in {}: {}", file.display(), e))?;
+    Ok(DocPage {
+        name: page_name(file, root),
+        source: file.display().to_string(),
+        items: generator.extract_docs(&ast),
+    })
 }
 
-/// Generate Markdown documentation
-pub fn generate_markdown_docs(docs: &[DocItem], source_path: &Path) -> String {
-    let mut output = String::new();
-    output.push_str(&format!(
-        "# Documentation for {}\n\n",
-        source_path.display()
-    ));
-
-    for doc in docs {
-        match doc.kind {
-            DocItemKind::Function => {
-                output.push_str(&format!("## `{}({})`\n\n", doc.name, doc.params.join(", ")));
-                if let Some(comment) = &doc.doc_comment {
-                    let clean_comment = comment
-                        .lines()
-                        .map(|line| line.trim_start_matches("///").trim())
-                        .collect::<Vec<_>>()
-                        .join("\n");
-                    output.push_str(&format!("{}\n\n", clean_comment));
-                } else {
-                    output.push_str("*No documentation available*\n\n");
-                }
-            }
-        }
+/// File name of the page for `file`, without extension (complexity: 3)
+///
+/// Directories become dot-separated prefixes so every page sits in the
+/// output directory, and a page never takes the name of the index.
+fn page_name(file: &Path, root: &Path) -> String {
+    let relative = file.strip_prefix(root).unwrap_or(file).with_extension("");
+    let name = relative
+        .iter()
+        .map(|part| part.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join(".");
+    match name.as_str() {
+        // A single file is its own root
+        "" => file.file_stem().map_or_else(
+            || "docs".to_string(),
+            |stem| stem.to_string_lossy().into_owned(),
+        ),
+        "index" => "index_".to_string(),
+        _ => name,
     }
-
-    output
 }
 
-/// Generate JSON documentation
-pub fn generate_json_docs(docs: &[DocItem], source_path: &Path) -> Result<String> {
-    let mut json_docs = Vec::new();
-
-    for doc in docs {
-        let mut obj = serde_json::Map::new();
-        obj.insert("kind".to_string(), serde_json::json!("function"));
-        obj.insert("name".to_string(), serde_json::json!(doc.name));
-        obj.insert("params".to_string(), serde_json::json!(doc.params));
-        if let Some(comment) = &doc.doc_comment {
-            let clean_comment = comment
-                .lines()
-                .map(|line| line.trim_start_matches("///").trim())
-                .collect::<Vec<_>>()
-                .join("\n");
-            obj.insert("doc_comment".to_string(), serde_json::json!(clean_comment));
-        }
-        json_docs.push(serde_json::Value::Object(obj));
-    }
-
-    let result = serde_json::json!({
-        "source": source_path.display().to_string(),
-        "items": json_docs
-    });
-
-    Ok(serde_json::to_string_pretty(&result)?)
+/// Write one documentation file and report it (complexity: 1)
+fn write_doc(path: &Path, content: &str) -> Result<()> {
+    fs::write(path, content)
+        .with_context(|| format!("Failed to write documentation: {}", path.display()))?;
+    println!(
+        "{} Generated documentation: {}",
+        "✓".bright_green(),
+        path.display()
+    );
+    Ok(())
 }
 
-/// Generate HTML documentation
-pub fn generate_html_docs(docs: &[DocItem], source_path: &Path) -> String {
-    let mut output = String::new();
-    output.push_str("<!DOCTYPE html>\n<html>\n<head>\n");
-    output.push_str(&format!(
-        "<title>Documentation for {}</title>\n",
-        source_path.display()
-    ));
-    output.push_str("<style>\n");
-    output.push_str("body { font-family: Arial, sans-serif; margin: 40px; }\n");
-    output.push_str("h1 { color: #333; }\n");
-    output.push_str("h2 { color: #666; border-bottom: 1px solid #ddd; padding-bottom: 5px; }\n");
-    output.push_str("code { background: #f4f4f4; padding: 2px 5px; border-radius: 3px; }\n");
-    output.push_str("</style>\n</head>\n<body>\n");
-    output.push_str(&format!(
-        "<h1>Documentation for {}</h1>\n",
-        source_path.display()
-    ));
-
-    for doc in docs {
-        match doc.kind {
-            DocItemKind::Function => {
-                output.push_str(&format!(
-                    "<h2><code>{}({})</code></h2>\n",
-                    doc.name,
-                    doc.params.join(", ")
-                ));
-                if let Some(comment) = &doc.doc_comment {
-                    let clean_comment = comment
-                        .lines()
-                        .map(|line| line.trim_start_matches("///").trim())
-                        .collect::<Vec<_>>()
-                        .join("<br>\n");
-                    output.push_str(&format!("<p>{}</p>\n", clean_comment));
-                } else {
-                    output.push_str("<p><em>No documentation available</em></p>\n");
-                }
-            }
-        }
-    }
-
-    output.push_str("</body>\n</html>\n");
-    output
+/// Open generated documentation with the system's default program (complexity: 2)
+fn open_in_browser(path: &Path) -> Result<()> {
+    use std::process::Command;
+
+    println!("   Opening {}", path.display());
+    #[cfg(target_os = "macos")]
+    Command::new("open").arg(path).spawn()?;
+    #[cfg(target_os = "linux")]
+    Command::new("xdg-open").arg(path).spawn()?;
+    #[cfg(target_os = "windows")]
+    Command::new("cmd")
+        .arg("/C")
+        .arg("start")
+        .arg(path)
+        .spawn()?;
+    Ok(())
 }
 
 #[cfg(test)]
@@ -283,164 +175,99 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_generate_markdown_empty() {
-        let docs: Vec<DocItem> = vec![];
-        let result = generate_markdown_docs(&docs, Path::new("test.ruchy"));
-        assert!(result.contains("# Documentation for"));
-    }
-
-    #[test]
-    fn test_generate_markdown_with_function() {
-        let docs = vec![DocItem {
-            kind: DocItemKind::Function,
-            name: "foo".to_string(),
-            params: vec!["x".to_string(), "y".to_string()],
-            doc_comment: Some("/// Does something".to_string()),
-        }];
-        let result = generate_markdown_docs(&docs, Path::new("test.ruchy"));
-        assert!(result.contains("## `foo(x, y)`"));
-        assert!(result.contains("Does something"));
-    }
-
-    #[test]
-    fn test_generate_json_empty() {
-        let docs: Vec<DocItem> = vec![];
-        let result = generate_json_docs(&docs, Path::new("test.ruchy")).unwrap();
-        assert!(result.contains("\"items\": []"));
-    }
-
-    #[test]
-    fn test_generate_json_with_function() {
-        let docs = vec![DocItem {
-            kind: DocItemKind::Function,
-            name: "bar".to_string(),
-            params: vec!["a".to_string()],
-            doc_comment: None,
-        }];
-        let result = generate_json_docs(&docs, Path::new("test.ruchy")).unwrap();
-        assert!(result.contains("\"name\": \"bar\""));
-    }
-
-    #[test]
-    fn test_generate_html_empty() {
-        let docs: Vec<DocItem> = vec![];
-        let result = generate_html_docs(&docs, Path::new("test.ruchy"));
-        assert!(result.contains("<!DOCTYPE html>"));
-        assert!(result.contains("</html>"));
-    }
-
-    #[test]
-    fn test_generate_html_with_function() {
-        let docs = vec![DocItem {
-            kind: DocItemKind::Function,
-            name: "baz".to_string(),
-            params: vec![],
-            doc_comment: Some("/// Test function".to_string()),
-        }];
-        let result = generate_html_docs(&docs, Path::new("test.ruchy"));
-        assert!(result.contains("<code>baz()</code>"));
-        assert!(result.contains("Test function"));
-    }
-
-    // ===== EXTREME TDD Round 152 - Doc Handler Tests =====
-
-    #[test]
-    fn test_generate_markdown_no_doc_comment() {
-        let docs = vec![DocItem {
-            kind: DocItemKind::Function,
-            name: "undocumented".to_string(),
-            params: vec!["x".to_string()],
-            doc_comment: None,
-        }];
-        let result = generate_markdown_docs(&docs, Path::new("test.ruchy"));
-        assert!(result.contains("*No documentation available*"));
-    }
-
-    #[test]
-    fn test_generate_html_no_doc_comment() {
-        let docs = vec![DocItem {
-            kind: DocItemKind::Function,
-            name: "undocumented".to_string(),
-            params: vec![],
-            doc_comment: None,
-        }];
-        let result = generate_html_docs(&docs, Path::new("test.ruchy"));
-        assert!(result.contains("<em>No documentation available</em>"));
-    }
-
-    #[test]
-    fn test_generate_json_with_doc_comment() {
-        let docs = vec![DocItem {
-            kind: DocItemKind::Function,
-            name: "documented".to_string(),
-            params: vec!["a".to_string(), "b".to_string()],
-            doc_comment: Some("/// Does something important".to_string()),
-        }];
-        let result = generate_json_docs(&docs, Path::new("test.ruchy")).unwrap();
-        assert!(result.contains("doc_comment"));
-        assert!(result.contains("Does something important"));
+    fn test_page_names() {
+        let root = Path::new("project");
+        assert_eq!(page_name(Path::new("project/math.ruchy"), root), "math");
+        assert_eq!(
+            page_name(Path::new("project/geo/shapes.ruchy"), root),
+            "geo.shapes"
+        );
+        assert_eq!(page_name(Path::new("project/index.ruchy"), root), "index_");
+        let file = Path::new("project/math.ruchy");
+        assert_eq!(page_name(file, file), "math");
     }
 
     #[test]
-    fn test_doc_item_debug() {
-        let doc = DocItem {
-            kind: DocItemKind::Function,
-            name: "test".to_string(),
-            params: vec![],
-            doc_comment: None,
-        };
-        let debug_str = format!("{:?}", doc);
-        assert!(debug_str.contains("Function"));
-        assert!(debug_str.contains("test"));
-    }
+    fn test_handle_doc_command_single_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let source_file = temp_dir.path().join("test.ruchy");
+        std::fs::write(&source_file, "/// Answers\nfun answer() { 42 }").unwrap();
+        let output = temp_dir.path().join("docs");
 
-    #[test]
-    fn test_generate_markdown_multiple_params() {
-        let docs = vec![DocItem {
-            kind: DocItemKind::Function,
-            name: "multi".to_string(),
-            params: vec!["a".to_string(), "b".to_string(), "c".to_string()],
-            doc_comment: None,
-        }];
-        let result = generate_markdown_docs(&docs, Path::new("test.ruchy"));
-        assert!(result.contains("`multi(a, b, c)`"));
+        handle_doc_command(
+            &source_file,
+            &output,
+            "markdown",
+            false,
+            false,
+            false,
+            false,
+        )
+        .unwrap();
+        let markdown = std::fs::read_to_string(output.join("test.md")).unwrap();
+        assert!(markdown.contains("fun answer()"), "{markdown}");
+        assert!(markdown.contains("Answers"), "{markdown}");
+        assert!(!output.join("index.md").exists());
     }
 
     #[test]
-    fn test_generate_html_css_styles() {
-        let docs: Vec<DocItem> = vec![];
-        let result = generate_html_docs(&docs, Path::new("test.ruchy"));
-        assert!(result.contains("<style>"));
-        assert!(result.contains("font-family"));
-        assert!(result.contains("</style>"));
+    fn test_handle_doc_command_directory_links_pages() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let src = temp_dir.path().join("src");
+        std::fs::create_dir_all(src.join("geo")).unwrap();
+        std::fs::write(
+            src.join("geo/point.ruchy"),
+            "/// A point\nstruct Point { x: f64 }",
+        )
+        .unwrap();
+        std::fs::write(
+            src.join("main.ruchy"),
+            "/// Distance to a [`Point`]\nfun norm(p: Point) -> f64 { p.x }",
+        )
+        .unwrap();
+        let output = temp_dir.path().join("docs");
+
+        handle_doc_command(&src, &output, "html", false, false, false, false).unwrap();
+        let main = std::fs::read_to_string(output.join("main.html")).unwrap();
+        assert!(
+            main.contains("href=\"geo.point.html#struct.Point\""),
+            "{main}"
+        );
+        let index = std::fs::read_to_string(output.join("index.html")).unwrap();
+        assert!(index.contains("href=\"main.html#fn.norm\""), "{index}");
     }
 
     #[test]
-    fn test_doc_item_kind_function() {
-        let kind = DocItemKind::Function;
-        let debug_str = format!("{:?}", kind);
-        assert_eq!(debug_str, "Function");
+    fn test_handle_doc_command_all_documents_the_directory() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let source_file = temp_dir.path().join("a.ruchy");
+        std::fs::write(&source_file, "/// A\nfun a() { 1 }").unwrap();
+        std::fs::write(temp_dir.path().join("b.ruchy"), "/// B\nfun b() { 2 }").unwrap();
+        let output = temp_dir.path().join("docs");
+
+        handle_doc_command(&source_file, &output, "json", false, false, true, false).unwrap();
+        assert!(output.join("a.json").exists());
+        assert!(output.join("b.json").exists());
+        assert!(output.join("index.json").exists());
     }
 
     #[test]
-    fn test_generate_json_source_path() {
-        let docs: Vec<DocItem> = vec![];
-        let result = generate_json_docs(&docs, Path::new("/path/to/test.ruchy")).unwrap();
-        assert!(result.contains("/path/to/test.ruchy"));
-    }
+    fn test_handle_doc_command_parse_error() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let source_file = temp_dir.path().join("bad.ruchy");
+        std::fs::write(&source_file, "fun bad( { }").unwrap();
 
-    #[test]
-    fn test_generate_markdown_multiline_doc() {
-        let docs = vec![DocItem {
-            kind: DocItemKind::Function,
-            name: "multiline".to_string(),
-            params: vec![],
-            doc_comment: Some("/// Line 1\n/// Line 2\n/// Line 3".to_string()),
-        }];
-        let result = generate_markdown_docs(&docs, Path::new("test.ruchy"));
-        assert!(result.contains("Line 1"));
-        assert!(result.contains("Line 2"));
-        assert!(result.contains("Line 3"));
+        let err = handle_doc_command(
+            &source_file,
+            &temp_dir.path().join("docs"),
+            "html",
+            false,
+            false,
+            false,
+            false,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Parse error"), "{err}");
     }
 
     #[test]
@@ -477,14 +304,4 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("not found"));
     }
-
-    #[test]
-    fn test_generate_html_head_section() {
-        let docs: Vec<DocItem> = vec![];
-        let result = generate_html_docs(&docs, Path::new("test.ruchy"));
-        assert!(result.contains("<!DOCTYPE html>"));
-        assert!(result.contains("<head>"));
-        assert!(result.contains("<title>"));
-        assert!(result.contains("</head>"));
-    }
 }
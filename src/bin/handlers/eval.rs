@@ -3,6 +3,8 @@
 //! Handles evaluation of one-liner expressions via the `-e` flag.
 
 use anyhow::Result;
+use ruchy::cli::output::{to_json_line, EvalResponse};
+use ruchy::runtime::script::{run_script, ScriptOptions};
 
 /// Handle eval command - evaluate a one-liner expression with -e flag
 ///
@@ -11,11 +13,18 @@ use anyhow::Result;
 /// * `verbose` - Enable verbose output
 /// * `format` - Output format ("json" or default text)
 /// * `trace` - Enable function call tracing (DEBUGGER-014)
+/// * `timeout` - The `--timeout` flag, which wins over configured deadlines
 ///
 /// # Errors
 /// Returns error if expression cannot be parsed or evaluated
 /// Handle eval command (complexity: 5 - reduced from 11)
-pub fn handle_eval_command(expr: &str, verbose: bool, format: &str, trace: bool) -> Result<()> {
+pub fn handle_eval_command(
+    expr: &str,
+    verbose: bool,
+    format: &str,
+    trace: bool,
+    timeout: Option<&str>,
+) -> Result<()> {
     // DEBUGGER-014 Phase 1.3: Set trace flag via environment variable
     if trace {
         std::env::set_var("RUCHY_TRACE", "1");
@@ -26,52 +35,45 @@ pub fn handle_eval_command(expr: &str, verbose: bool, format: &str, trace: bool)
     if verbose {
         eprintln!("Parsing expression: {expr}");
     }
-    let mut repl = super::create_repl()?;
-
-    // If expression defines main(), call it automatically
-    // PARSER-085: Fixed to check for "fun main(" (Ruchy keyword) not "fn main(" (Rust keyword)
-    let expr_to_eval = if expr.contains("fun main(") {
-        format!("{expr}\nmain()")
-    } else {
-        expr.to_string()
-    };
-
-    match repl.eval(&expr_to_eval) {
-        Ok(result) => {
+    // Same semantics as script files (see runtime::script): whole-input parse,
+    // main() auto-call when defined, but the final value is echoed.
+    let options = ScriptOptions::default()
+        .with_timeout_flag(timeout)
+        .with_configured_timeout()
+        .map_err(anyhow::Error::msg)?;
+    match run_script(expr, &options) {
+        Ok(outcome) => {
             if verbose {
                 eprintln!("Evaluation successful");
             }
-            // [CLI-EVAL-001] FIX: Print result for REPL one-liners (unless already printed)
+            // [CLI-EVAL-001] Print result for one-liners (unless nil):
             // - `ruchy -e "42"` → prints "42" (REPL behavior)
-            // - `ruchy -e "println(42)"` → prints "42" only once (println returns "nil", don't double-print)
-            // This fixes tests: non_tty_omits_interactive_features, cli_eval_flag_executes_inline
-            // Preserves: prop_021_consistency_eval_equals_file (println behavior still consistent)
-            if result != "nil" && !result.is_empty() {
-                println!("{result}");
-                // Ensure output is flushed for tests capturing stdout
-                use std::io::Write;
-                let _ = std::io::stdout().flush();
-            }
+            // - `ruchy -e "println(42)"` → prints "42" only once (println returns nil)
+            print_eval_success(outcome.value.as_deref(), format);
+            // Ensure output is flushed for tests capturing stdout
+            use std::io::Write;
+            let _ = std::io::stdout().flush();
             Ok(())
         }
         Err(e) => {
             if verbose {
                 eprintln!("Evaluation failed: {e}");
             }
+            let e = anyhow::Error::new(e);
             print_eval_error(&e, format);
             Err(e)
         }
     }
 }
 
-/// Print successful evaluation result (complexity: 2)
-pub fn print_eval_success(result: &str, format: &str) {
+/// Print successful evaluation result (complexity: 3)
+///
+/// Text output skips nil results; JSON output always prints one
+/// [`EvalResponse`] line.
+pub fn print_eval_success(result: Option<&str>, format: &str) {
     if format == "json" {
-        // Manually construct JSON to ensure field order matches test expectations
-        let result_str = result.replace('"', "\\\"");
-        println!("{{\"success\":true,\"result\":\"{result_str}\"}}");
-    } else {
-        // Default text output - always show result for one-liner evaluation
+        print_eval_response(&EvalResponse::ok(result.map(str::to_string)));
+    } else if let Some(result) = result {
         println!("{result}");
     }
 }
@@ -79,43 +81,29 @@ pub fn print_eval_success(result: &str, format: &str) {
 /// Print evaluation error (complexity: 2)
 pub fn print_eval_error(e: &anyhow::Error, format: &str) {
     if format == "json" {
-        println!(
-            "{}",
-            serde_json::json!({
-                "success": false,
-                "error": e.to_string()
-            })
-        );
+        print_eval_response(&EvalResponse::failed(e.to_string()));
     } else {
         eprintln!("Error: {e}");
     }
 }
 
+fn print_eval_response(response: &EvalResponse) {
+    match to_json_line(response) {
+        Ok(json) => println!("{json}"),
+        Err(e) => eprintln!("Error: {e}"),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_print_eval_success_json() {
-        // Capture output via a simple test of format logic
-        let result = "42";
-        let format = "json";
-        if format == "json" {
-            let result_str = result.replace('"', "\\\"");
-            let output = format!("{{\"success\":true,\"result\":\"{result_str}\"}}");
-            assert!(output.contains("success"));
-            assert!(output.contains("true"));
-            assert!(output.contains("42"));
-        }
-    }
-
-    #[test]
-    fn test_print_eval_success_text() {
-        let result = "42";
-        let format = "text";
-        if format != "json" {
-            assert_eq!(result, "42");
-        }
+    fn test_eval_response_json_escapes_result() {
+        let json = to_json_line(&EvalResponse::ok(Some("say \"hi\"\n".to_string()))).unwrap();
+        assert_eq!(json, r#"{"success":true,"result":"say \"hi\"\n"}"#);
+        let nil = to_json_line(&EvalResponse::ok(None)).unwrap();
+        assert_eq!(nil, r#"{"success":true}"#);
     }
 
     #[test]
@@ -160,10 +148,10 @@ mod tests {
 
     #[test]
     fn test_print_eval_success_formats() {
-        print_eval_success("42", "text");
-        print_eval_success("42", "json");
-        print_eval_success("hello world", "text");
-        print_eval_success("hello world", "json");
+        print_eval_success(Some("42"), "text");
+        print_eval_success(Some("42"), "json");
+        print_eval_success(Some("hello world"), "text");
+        print_eval_success(None, "json");
     }
 
     #[test]
@@ -194,22 +182,15 @@ mod tests {
         }
     }
 
-    #[test]
-    fn test_json_escaping_in_success() {
-        let result = "hello \"world\"";
-        let escaped = result.replace('"', "\\\"");
-        assert_eq!(escaped, "hello \\\"world\\\"");
-    }
-
     #[test]
     fn test_handle_eval_command_simple_expr() {
-        let result = handle_eval_command("2 + 2", false, "text", false);
+        let result = handle_eval_command("2 + 2", false, "text", false, None);
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_handle_eval_command_with_trace() {
-        let result = handle_eval_command("42", false, "text", true);
+        let result = handle_eval_command("42", false, "text", true, None);
         // Clean up env var
         std::env::remove_var("RUCHY_TRACE");
         assert!(result.is_ok());
@@ -217,20 +198,30 @@ mod tests {
 
     #[test]
     fn test_handle_eval_command_verbose() {
-        let result = handle_eval_command("1 + 1", true, "text", false);
+        let result = handle_eval_command("1 + 1", true, "text", false, None);
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_handle_eval_command_json_format() {
-        let result = handle_eval_command("42", false, "json", false);
+        let result = handle_eval_command("42", false, "json", false, None);
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_handle_eval_command_invalid_syntax() {
-        // REPL handles partial expressions gracefully via error recovery
-        let result = handle_eval_command("let x = {", false, "text", false);
+        // Whole-input parse: incomplete code is a syntax error, not REPL continuation
+        let result = handle_eval_command("let x = {", false, "text", false, None);
+        let err = result.expect_err("incomplete input should fail");
+        assert!(matches!(
+            err.downcast_ref::<ruchy::runtime::script::ScriptError>(),
+            Some(ruchy::runtime::script::ScriptError::Syntax(_))
+        ));
+    }
+
+    #[test]
+    fn test_handle_eval_command_calls_main_once() {
+        let result = handle_eval_command("fun main() { 1 }\nmain()", false, "text", false, None);
         assert!(result.is_ok());
     }
 
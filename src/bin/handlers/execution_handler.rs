@@ -3,58 +3,39 @@
 //! Handles direct execution of Ruchy script files and stdin input.
 
 use anyhow::Result;
-use ruchy::backend::module_resolver::ModuleResolver;
-use ruchy::frontend::ast::{Expr, ExprKind};
-use ruchy::runtime::interpreter::Interpreter;
-use ruchy::Parser as RuchyParser;
+use ruchy::runtime::script::{run_script, ScriptOptions, ScriptOutcome};
 use std::path::Path;
 
 /// Handle file execution - run a Ruchy script file directly (not via subcommand)
 ///
 /// # Arguments
 /// * `file` - Path to the Ruchy file to execute
+/// * `timeout` - The `--timeout` flag, which wins over configured deadlines
 ///
 /// # Errors
 /// Returns error if file cannot be read, parsed, or executed
-pub fn handle_file_execution(file: &Path) -> Result<()> {
+pub fn handle_file_execution(file: &Path, timeout: Option<&str>) -> Result<()> {
     let source = super::read_file_with_context(file)?;
+    let options = ScriptOptions::for_file(file)
+        .with_timeout_flag(timeout)
+        .with_configured_timeout()
+        .map_err(anyhow::Error::msg)?;
+    run_script_or_exit(&source, &options);
+    Ok(())
+}
 
-    // ISSUE-106: Parse and check for module declarations
-    let mut parser = RuchyParser::new(&source);
-    let ast = parser
-        .parse()
-        .map_err(|e| anyhow::anyhow!("Syntax error: {e}"))?;
-
-    // Check if we need module resolution
-    if needs_module_resolution(&ast) {
-        // Resolve module declarations (mod name;) and imports
-        let resolved_ast = resolve_modules_for_execution(file, ast)?;
-
-        // Use interpreter to evaluate the resolved AST
-        let mut interpreter = Interpreter::new();
-        interpreter
-            .eval_expr(&resolved_ast)
-            .map_err(|e| anyhow::anyhow!("Evaluation error: {e:?}"))?;
-        return Ok(());
-    }
-
-    // CLI-UNIFY-002: Use REPL-based evaluation for simple scripts
-    let mut repl = super::create_repl()?;
-
-    match repl.eval(&source) {
-        Ok(_result) => {
-            // After evaluating the file, call main() if it exists
-            match repl.eval("main()") {
-                Ok(_) => Ok(()),
-                Err(e) => {
-                    eprintln!("Error: {e}");
-                    std::process::exit(1);
-                }
-            }
-        }
+/// Run a script with the shared semantics, exiting on failure (complexity: 2)
+///
+/// Scripts never print their final value; output comes from `println`.
+/// Failures print `Error: ...` and exit with [`ScriptError::exit_code`].
+///
+/// [`ScriptError::exit_code`]: ruchy::runtime::script::ScriptError::exit_code
+pub(crate) fn run_script_or_exit(source: &str, options: &ScriptOptions) -> ScriptOutcome {
+    match run_script(source, options) {
+        Ok(outcome) => outcome,
         Err(e) => {
             eprintln!("Error: {e}");
-            std::process::exit(1);
+            std::process::exit(e.exit_code());
         }
     }
 }
@@ -80,47 +61,11 @@ pub fn handle_stdin_input(input: &str) -> Result<()> {
     }
 }
 
-/// Check if AST contains module declarations that need resolution
-pub(crate) fn needs_module_resolution(expr: &Expr) -> bool {
-    match &expr.kind {
-        ExprKind::ModuleDeclaration { .. } => true,
-        ExprKind::Module { .. } => true,
-        ExprKind::Import { .. } => true,
-        ExprKind::ImportAll { .. } => true,
-        ExprKind::ImportDefault { .. } => true,
-        ExprKind::Block(exprs) => exprs.iter().any(needs_module_resolution),
-        ExprKind::Function { body, .. } => needs_module_resolution(body),
-        ExprKind::Let { value, body, .. } => {
-            needs_module_resolution(value) || needs_module_resolution(body)
-        }
-        _ => false,
-    }
-}
-
-/// ISSUE-106: Resolve module declarations and imports for script execution
-pub(crate) fn resolve_modules_for_execution(source_path: &Path, ast: Expr) -> Result<Expr> {
-    let mut resolver = ModuleResolver::new();
-
-    // Add the source file's directory to the module search path
-    if let Some(parent_dir) = source_path.parent() {
-        resolver.add_search_path(parent_dir);
-
-        // Also search in standard project layout directories
-        if let Some(project_root) = parent_dir.parent() {
-            resolver.add_search_path(project_root.join("src"));
-            resolver.add_search_path(project_root.join("lib"));
-            resolver.add_search_path(project_root.join("modules"));
-        }
-    }
-
-    resolver
-        .resolve_imports(ast)
-        .map_err(|e| anyhow::anyhow!("Module resolution error: {e}"))
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ruchy::frontend::ast::{Expr, ExprKind};
+    use ruchy::runtime::script::{needs_module_resolution, resolve_modules};
     use std::path::PathBuf;
 
     fn make_expr(kind: ExprKind) -> Expr {
@@ -144,7 +89,7 @@ mod tests {
     #[test]
     fn test_handle_file_execution_nonexistent() {
         let path = PathBuf::from("/nonexistent/file.ruchy");
-        let result = handle_file_execution(&path);
+        let result = handle_file_execution(&path, None);
         assert!(result.is_err());
     }
 
@@ -195,7 +140,7 @@ mod tests {
         let expr = make_expr(ExprKind::Block(vec![]));
         let path = PathBuf::from("/nonexistent/dir/file.ruchy");
         // Should succeed for empty block (no modules to resolve)
-        let result = resolve_modules_for_execution(&path, expr);
+        let result = resolve_modules(&path, expr);
         assert!(result.is_ok());
     }
 
@@ -302,7 +247,7 @@ mod tests {
     fn test_resolve_modules_with_parent_directory() {
         let expr = make_expr(ExprKind::Block(vec![]));
         let path = PathBuf::from("/root/src/test.ruchy");
-        let result = resolve_modules_for_execution(&path, expr);
+        let result = resolve_modules(&path, expr);
         assert!(result.is_ok());
     }
 
@@ -310,7 +255,7 @@ mod tests {
     fn test_resolve_modules_for_execution_relative_path() {
         let expr = make_expr(ExprKind::Block(vec![]));
         let path = PathBuf::from("test.ruchy");
-        let result = resolve_modules_for_execution(&path, expr);
+        let result = resolve_modules(&path, expr);
         assert!(result.is_ok());
     }
 }
@@ -0,0 +1,34 @@
+//! Filter Command Handler
+//!
+//! Handles `ruchy --filter '<expr>'`, which applies an expression to every
+//! line of stdin (or of a file) like awk. See `ruchy::runtime::line_filter`.
+
+use anyhow::{Context, Result};
+use ruchy::runtime::line_filter::LineFilter;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter};
+use std::path::Path;
+
+/// Handle filter mode - run `expr` once per input line
+///
+/// # Arguments
+/// * `expr` - The program to evaluate per line (`line` and `nr` are bound)
+/// * `input` - File to read instead of stdin
+///
+/// # Errors
+/// Returns error if the input cannot be opened, the program does not parse,
+/// or evaluation fails on some line
+pub fn handle_filter_command(expr: &str, input: Option<&Path>) -> Result<()> {
+    let reader: Box<dyn BufRead> = match input {
+        Some(path) => Box::new(BufReader::new(
+            File::open(path).with_context(|| format!("Failed to open {}", path.display()))?,
+        )),
+        None => Box::new(io::stdin().lock()),
+    };
+    let output = BufWriter::new(io::stdout().lock());
+    let result = LineFilter::new(expr).and_then(|mut filter| filter.run(reader, output));
+    if let Err(e) = &result {
+        eprintln!("Error: {e}");
+    }
+    result.map_err(anyhow::Error::new)
+}
@@ -3,6 +3,7 @@
 //! Handles fuzz testing for Ruchy files.
 
 use anyhow::Result;
+use ruchy::cli::output::{to_json_pretty, FuzzFileReport, FuzzStatus, FuzzTargetReport};
 use std::fs;
 use std::path::Path;
 
@@ -53,13 +54,13 @@ fn write_fuzz_json(
     success: bool,
     stdout: &str,
 ) -> Result<()> {
-    let report = serde_json::json!({
-        "target": target,
-        "iterations": iterations,
-        "status": if success { "passed" } else { "failed" },
-        "output": stdout
-    });
-    let json_output = serde_json::to_string_pretty(&report)?;
+    let report = FuzzTargetReport {
+        target: target.to_string(),
+        iterations,
+        status: FuzzStatus::from(success),
+        output: stdout.to_string(),
+    };
+    let json_output = to_json_pretty(&report).map_err(anyhow::Error::msg)?;
     if let Some(out_path) = output {
         fs::write(out_path, json_output)?;
     } else {
@@ -171,17 +172,17 @@ fn write_json_fuzz_report(
     success_rate: f64,
     crash_details: &[String],
 ) -> Result<()> {
-    let report = serde_json::json!({
-        "file": path.display().to_string(),
-        "iterations": iterations,
-        "successes": successes,
-        "crashes": crashes,
-        "timeouts": timeouts,
-        "success_rate": success_rate,
-        "status": if crashes == 0 && timeouts == 0 { "passed" } else { "failed" },
-        "crash_details": crash_details
-    });
-    let json_output = serde_json::to_string_pretty(&report)?;
+    let report = FuzzFileReport {
+        file: path.display().to_string(),
+        iterations,
+        successes,
+        crashes,
+        timeouts,
+        success_rate,
+        status: FuzzStatus::from(crashes == 0 && timeouts == 0),
+        crash_details: crash_details.to_vec(),
+    };
+    let json_output = to_json_pretty(&report).map_err(anyhow::Error::msg)?;
 
     if let Some(out_path) = output {
         super::write_file_with_context(out_path, json_output.as_bytes())?;
@@ -147,7 +147,7 @@ fn is_identifier_usage(line: &str, start: usize, word: &str) -> bool {
 }
 
 /// Scan a single file for identifier conflicts.
-fn scan_file(path: &Path) -> Vec<MigrateRename> {
+pub fn scan_file(path: &Path) -> Vec<MigrateRename> {
     let content = match std::fs::read_to_string(path) {
         Ok(c) => c,
         Err(_) => return Vec::new(),
@@ -195,7 +195,7 @@ fn scan_line_for_keyword(
 }
 
 /// Apply renames to file content and return the modified content.
-fn apply_renames_to_content(content: &str, renames: &[MigrateRename]) -> String {
+pub fn apply_renames_to_content(content: &str, renames: &[MigrateRename]) -> String {
     let rename_map: HashMap<&str, &str> = KEYWORD_RENAMES.iter().copied().collect();
     let mut result = String::with_capacity(content.len());
 
@@ -6,6 +6,7 @@ pub mod prove_helpers;
 pub mod test;
 pub mod test_helpers;
 pub mod migrate;
+pub mod upgrade;
 pub mod provability;
 pub mod sovereign;
 // Re-export main handler functions
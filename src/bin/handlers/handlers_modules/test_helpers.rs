@@ -1,13 +1,12 @@
 //! Helper functions for test command
 //! Extracted to maintain ≤10 complexity per function
-use anyhow::{bail, Context, Result};
+use anyhow::Result;
 use colored::Colorize;
-use ruchy::frontend::ast::Attribute;
-use ruchy::utils::read_file_with_context;
+use ruchy::cli::output::{to_json_pretty, TestFileResult, TestReport};
+use ruchy::cli::shared;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
-use walkdir::WalkDir;
 /// Test result information
 pub struct TestResult {
     pub file: PathBuf,
@@ -24,202 +23,36 @@ pub fn discover_test_files(
     if verbose {
         println!("🔍 Discovering .ruchy test files in {}", path.display());
     }
-    let mut test_files = Vec::new();
-    if path.is_file() {
-        validate_and_add_file(path, &mut test_files)?;
-    } else if path.is_dir() {
-        discover_files_in_directory(path, filter, &mut test_files)?;
-    } else {
-        return Err(anyhow::anyhow!("Path {} does not exist", path.display()));
-    }
-    Ok(test_files)
-}
-/// Validate and add a single file
-fn validate_and_add_file(path: &Path, test_files: &mut Vec<PathBuf>) -> Result<()> {
-    if path.extension().is_some_and(|ext| ext == "ruchy") {
-        test_files.push(path.to_path_buf());
-    } else {
-        return Err(anyhow::anyhow!(
-            "File {} is not a .ruchy file",
-            path.display()
-        ));
-    }
-    Ok(())
-}
-/// Discover files in a directory
-fn discover_files_in_directory(
-    path: &Path,
-    filter: Option<&str>,
-    test_files: &mut Vec<PathBuf>,
-) -> Result<()> {
-    for entry in WalkDir::new(path) {
-        let entry = entry?;
-        if should_include_file(&entry, filter) {
-            test_files.push(entry.path().to_path_buf());
-        }
-    }
-    Ok(())
-}
-/// Check if file should be included based on filter
-fn should_include_file(entry: &walkdir::DirEntry, filter: Option<&str>) -> bool {
-    if entry.path().extension().is_none_or(|ext| ext != "ruchy") {
-        return false;
-    }
-    if let Some(filter_pattern) = filter {
-        let file_name = entry
-            .path()
-            .file_stem()
-            .and_then(|s| s.to_str())
-            .unwrap_or("");
-        file_name.contains(filter_pattern)
-    } else {
-        true
-    }
+    shared::discover_test_files(path, filter).map_err(anyhow::Error::msg)
 }
 /// Run a single .ruchy test file
-/// Complexity: 5 (reduced by extracting helpers)
+/// Complexity: 4
 pub fn run_test_file(test_file: &Path, verbose: bool) -> Result<()> {
-    let test_content = read_file_with_context(test_file)?;
-
-    // Parse and find test functions
-    let test_functions = parse_and_find_tests(&test_content, test_file, verbose)?;
-
-    // Initialize REPL and execute tests
-    execute_test_functions(&test_content, &test_functions, test_file, verbose)?;
-
-    Ok(())
-}
-
-/// Parse test file and find all @test functions
-/// Complexity: 2 (reduced by extracting validation)
-fn parse_and_find_tests(
-    test_content: &str,
-    test_file: &Path,
-    verbose: bool,
-) -> Result<Vec<String>> {
-    use ruchy::frontend::parser::Parser;
-
     if verbose {
         println!("   📖 Parsing test file...");
     }
-
-    let mut parser = Parser::new(test_content);
-    let ast = parser
-        .parse()
-        .with_context(|| format!("Failed to parse test file: {}", test_file.display()))?;
-
-    let test_functions = extract_test_functions(&ast)?;
-    validate_test_functions(&test_functions, test_file, verbose)?;
-
-    Ok(test_functions)
-}
-
-/// Validate that test functions were found
-/// Complexity: 2 (simple validation)
-fn validate_test_functions(
-    test_functions: &[String],
-    test_file: &Path,
-    verbose: bool,
-) -> Result<()> {
-    if test_functions.is_empty() {
-        bail!("No test functions found in {}", test_file.display());
-    }
-
+    let mut file = shared::TestFile::load(test_file).map_err(anyhow::Error::msg)?;
     if verbose {
-        println!("   🧪 Found {} test function(s)", test_functions.len());
+        println!("   🧪 Found {} test function(s)", file.tests.len());
     }
-
-    Ok(())
-}
-
-/// Execute all test functions in REPL
-/// Complexity: 4 (within limit)
-fn execute_test_functions(
-    test_content: &str,
-    test_functions: &[String],
-    test_file: &Path,
-    verbose: bool,
-) -> Result<()> {
-    use ruchy::runtime::repl::Repl;
-
-    // Initialize REPL and load the file (defines all functions)
-    let mut repl = Repl::new(std::env::temp_dir())?;
-    repl.evaluate_expr_str(test_content, None)
-        .with_context(|| format!("Failed to load test file: {}", test_file.display()))?;
-
-    // Execute each test function
-    for test_fn_name in test_functions {
-        execute_single_test(&mut repl, test_fn_name, verbose)?;
-    }
-
-    Ok(())
-}
-
-/// Execute a single test function
-/// Complexity: 3 (within limit)
-fn execute_single_test(
-    repl: &mut ruchy::runtime::repl::Repl,
-    test_fn_name: &str,
-    verbose: bool,
-) -> Result<()> {
-    if verbose {
-        println!("   🏃 Executing test: {}", test_fn_name);
-    }
-
-    let call_expr = format!("{}()", test_fn_name);
-    let result = repl.evaluate_expr_str(&call_expr, None);
-
-    match result {
-        Ok(_) => {
-            if verbose {
-                println!("   ✅ Test passed: {}", test_fn_name);
-            }
-            Ok(())
+    for test_fn_name in file.tests.clone() {
+        if verbose {
+            println!("   🏃 Executing test: {}", test_fn_name);
+        }
+        file.run(&test_fn_name).map_err(anyhow::Error::msg)?;
+        if verbose {
+            println!("   ✅ Test passed: {}", test_fn_name);
         }
-        Err(e) => bail!("Test failed: {} - {}", test_fn_name, e),
     }
+    Ok(())
 }
 
 /// Extract names of functions with @test attribute
 /// Handles both single function and block of expressions
-/// Complexity: 3 (reduced by extracting helpers)
+/// Complexity: 1
 #[allow(clippy::unnecessary_wraps)]
-fn extract_test_functions(ast: &ruchy::frontend::ast::Expr) -> Result<Vec<String>> {
-    use ruchy::frontend::ast::ExprKind;
-
-    let test_functions = match &ast.kind {
-        ExprKind::Block(exprs) => extract_from_block(exprs),
-        ExprKind::Function { name, .. } if has_test_attribute(&ast.attributes) => {
-            vec![name.clone()]
-        }
-        _ => vec![],
-    };
-
-    Ok(test_functions)
-}
-
-/// Extract test functions from block of expressions
-/// Complexity: 2 (simple iteration with filter)
-fn extract_from_block(exprs: &[ruchy::frontend::ast::Expr]) -> Vec<String> {
-    use ruchy::frontend::ast::ExprKind;
-
-    exprs
-        .iter()
-        .filter(|expr| has_test_attribute(&expr.attributes))
-        .filter_map(|expr| {
-            if let ExprKind::Function { name, .. } = &expr.kind {
-                Some(name.clone())
-            } else {
-                None
-            }
-        })
-        .collect()
-}
-
-/// Check if attributes contain @test
-/// Complexity: 1 (simple check)
-fn has_test_attribute(attributes: &[Attribute]) -> bool {
-    attributes.iter().any(|attr| attr.name == "test")
+pub fn extract_test_functions(ast: &ruchy::frontend::ast::Expr) -> Result<Vec<String>> {
+    Ok(shared::find_test_functions(ast))
 }
 /// Execute all test files
 pub fn execute_tests(test_files: &[PathBuf], verbose: bool) -> Vec<TestResult> {
@@ -334,21 +167,22 @@ pub fn generate_json_output(
 ) -> Result<String> {
     let passed = test_results.iter().filter(|r| r.success).count();
     let failed = test_results.len() - passed;
-    let json_output = serde_json::json!({
-        "total": test_results.len(),
-        "passed": passed,
-        "failed": failed,
-        "duration_seconds": total_duration.as_secs_f64(),
-        "results": test_results.iter().map(|r| {
-            serde_json::json!({
-                "file": r.file.display().to_string(),
-                "success": r.success,
-                "duration_ms": r.duration.as_secs_f64() * 1000.0,
-                "error": r.error
+    let report = TestReport {
+        total: test_results.len(),
+        passed,
+        failed,
+        duration_seconds: total_duration.as_secs_f64(),
+        results: test_results
+            .iter()
+            .map(|r| TestFileResult {
+                file: r.file.display().to_string(),
+                success: r.success,
+                duration_ms: r.duration.as_secs_f64() * 1000.0,
+                error: r.error.clone(),
             })
-        }).collect::<Vec<_>>()
-    });
-    Ok(serde_json::to_string_pretty(&json_output)?)
+            .collect(),
+    };
+    to_json_pretty(&report).map_err(anyhow::Error::msg)
 }
 /// Handle coverage reporting
 /// Generate coverage report from test results
@@ -595,134 +429,6 @@ mod tests {
         assert_eq!(files.len(), 2);
     }
 
-    // ========== File Validation Tests ==========
-    #[test]
-    fn test_validate_and_add_file_ruchy() {
-        let temp_dir = TempDir::new().expect("Failed to create temporary test directory");
-        let ruchy_file = temp_dir.path().join("test.ruchy");
-        fs::write(&ruchy_file, "println(\"test\")")
-            .unwrap_or_else(|_| panic!("Failed to write test file: {}", ruchy_file.display()));
-
-        let mut test_files = Vec::new();
-        let result = validate_and_add_file(&ruchy_file, &mut test_files);
-
-        assert!(result.is_ok() || result.is_err()); // Tests that function doesn't panic
-        assert_eq!(test_files.len(), 1);
-        assert_eq!(test_files[0], ruchy_file);
-    }
-
-    #[test]
-    fn test_validate_and_add_file_non_ruchy() {
-        let temp_dir = TempDir::new().expect("Failed to create temporary test directory");
-        let txt_file = temp_dir.path().join("test.txt");
-        fs::write(&txt_file, "not ruchy")
-            .unwrap_or_else(|_| panic!("Failed to write test file: {}", txt_file.display()));
-
-        let mut test_files = Vec::new();
-        let result = validate_and_add_file(&txt_file, &mut test_files);
-
-        assert!(result.is_err());
-        assert_eq!(test_files.len(), 0);
-        assert!(result
-            .unwrap_err()
-            .to_string()
-            .contains("not a .ruchy file"));
-    }
-
-    // ========== Directory Discovery Tests ==========
-    #[test]
-    fn test_discover_files_in_directory_success() {
-        let temp_dir = create_test_directory().expect("Failed to create test directory");
-        let mut test_files = Vec::new();
-
-        let result = discover_files_in_directory(temp_dir.path(), None, &mut test_files);
-        assert!(result.is_ok() || result.is_err()); // Tests that function doesn't panic
-        assert_eq!(test_files.len(), 2); // Only .ruchy files
-    }
-
-    #[test]
-    fn test_discover_files_in_directory_with_filter() {
-        let temp_dir = create_test_directory().expect("Failed to create test directory");
-        let mut test_files = Vec::new();
-
-        let result = discover_files_in_directory(temp_dir.path(), Some("test"), &mut test_files);
-        assert!(result.is_ok() || result.is_err()); // Tests that function doesn't panic
-                                                    // Both test.ruchy and another_test.ruchy match the filter "test"
-        assert_eq!(test_files.len(), 2); // Both files contain "test"
-        assert!(test_files.iter().all(|f| {
-            f.file_name()
-                .expect("Test file path has no filename component")
-                .to_str()
-                .expect("Test filename is not valid UTF-8")
-                .contains("test")
-        }));
-    }
-
-    // ========== File Filtering Tests ==========
-    #[test]
-    fn test_should_include_file_ruchy_no_filter() {
-        let temp_dir = TempDir::new().expect("Failed to create temporary test directory");
-        let ruchy_file = temp_dir.path().join("test.ruchy");
-        fs::write(&ruchy_file, "test")
-            .unwrap_or_else(|_| panic!("Failed to write test file: {}", ruchy_file.display()));
-
-        let entry = WalkDir::new(temp_dir.path())
-            .into_iter()
-            .find(|e| e.as_ref().expect("WalkDir entry error in test").path() == ruchy_file)
-            .expect("Failed to find test file in WalkDir")
-            .expect("WalkDir entry error after find");
-
-        assert!(should_include_file(&entry, None));
-    }
-
-    #[test]
-    fn test_should_include_file_ruchy_with_matching_filter() {
-        let temp_dir = TempDir::new().expect("Failed to create temporary test directory");
-        let ruchy_file = temp_dir.path().join("my_test.ruchy");
-        fs::write(&ruchy_file, "test")
-            .unwrap_or_else(|_| panic!("Failed to write test file: {}", ruchy_file.display()));
-
-        let entry = WalkDir::new(temp_dir.path())
-            .into_iter()
-            .find(|e| e.as_ref().expect("WalkDir entry error in test").path() == ruchy_file)
-            .expect("Failed to find test file in WalkDir")
-            .expect("WalkDir entry error after find");
-
-        assert!(should_include_file(&entry, Some("my")));
-    }
-
-    #[test]
-    fn test_should_include_file_ruchy_with_non_matching_filter() {
-        let temp_dir = TempDir::new().expect("Failed to create temporary test directory");
-        let ruchy_file = temp_dir.path().join("test.ruchy");
-        fs::write(&ruchy_file, "test")
-            .unwrap_or_else(|_| panic!("Failed to write test file: {}", ruchy_file.display()));
-
-        let entry = WalkDir::new(temp_dir.path())
-            .into_iter()
-            .find(|e| e.as_ref().expect("WalkDir entry error in test").path() == ruchy_file)
-            .expect("Failed to find test file in WalkDir")
-            .expect("WalkDir entry error after find");
-
-        assert!(!should_include_file(&entry, Some("nomatch")));
-    }
-
-    #[test]
-    fn test_should_include_file_non_ruchy() {
-        let temp_dir = TempDir::new().expect("Failed to create temporary test directory");
-        let txt_file = temp_dir.path().join("test.txt");
-        fs::write(&txt_file, "test")
-            .unwrap_or_else(|_| panic!("Failed to write test file: {}", txt_file.display()));
-
-        let entry = WalkDir::new(temp_dir.path())
-            .into_iter()
-            .find(|e| e.as_ref().expect("WalkDir entry error in test").path() == txt_file)
-            .expect("Failed to find test file in WalkDir")
-            .expect("WalkDir entry error after find");
-
-        assert!(!should_include_file(&entry, None));
-    }
-
     // ========== Test Execution Tests ==========
     #[test]
     fn test_run_test_file_success() {
@@ -0,0 +1,463 @@
+//! Upgrade assistant: `ruchy upgrade`
+//!
+//! Checks a project against the running Ruchy release and fixes what it
+//! can:
+//!
+//! - every change in [`BREAKING_CHANGES`] made after the release the
+//!   project targets is looked for, with the codemod that fixes it;
+//! - every file must still parse once those fixes are applied; one that
+//!   doesn't needs a manual fix;
+//! - the release the project targets moves to the running one.
+//!
+//! The targeted release is `ruchy = "4.2"` under `[package]` in the nearest
+//! `Ruchy.toml`; without it every change is looked for. `--dry-run` only
+//! lists the findings. Otherwise the fixed files are rewritten, keeping the
+//! originals as `<file>.bak`, and the project's tests (files with `@test`
+//! functions) run again.
+//!
+//! `target/`, `vendor/` and hidden directories are not checked.
+
+use super::migrate::{apply_renames_to_content, scan_file};
+use super::test_helpers::{execute_tests, extract_test_functions, print_test_summary};
+use crate::handlers::commands::write_fixed_source;
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use ruchy::frontend::parser::Parser;
+use ruchy::runtime::script::MANIFEST_FILE;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use walkdir::WalkDir;
+
+/// A Ruchy release as `(major, minor)`
+type Release = (u64, u64);
+
+/// A source-level breaking change and the release that made it
+struct BreakingChange {
+    rule: &'static str,
+    since: Release,
+}
+
+/// Breaking changes the assistant can fix, oldest first
+const BREAKING_CHANGES: &[BreakingChange] = &[BreakingChange {
+    rule: "reserved_keyword",
+    since: (5, 0),
+}];
+
+/// Something that breaks the project on the running release
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpgradeFinding {
+    /// File the finding is in
+    pub file: PathBuf,
+    /// 1-based line and column, when the finding has a position
+    pub position: Option<(usize, usize)>,
+    /// Breaking change the finding comes from
+    pub rule: &'static str,
+    /// What breaks
+    pub message: String,
+    /// The automatic fix, or `None` when it needs a manual fix
+    pub fix: Option<String>,
+}
+
+impl std::fmt::Display for UpgradeFinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "  {}", self.file.display())?;
+        if let Some((line, column)) = self.position {
+            write!(f, ":{line}:{column}")?;
+        }
+        write!(f, "  [{}] {}", self.rule, self.message)?;
+        match &self.fix {
+            Some(fix) => write!(f, "\n      fix: {fix}"),
+            None => write!(f, "\n      needs a manual fix"),
+        }
+    }
+}
+
+/// A checked file with its findings
+struct FileReport {
+    path: PathBuf,
+    source: String,
+    /// The source with the automatic fixes applied, when they change it
+    fixed: Option<String>,
+    findings: Vec<UpgradeFinding>,
+}
+
+/// The manifest's `[package] ruchy` entry
+struct TargetRelease {
+    manifest: PathBuf,
+    release: Release,
+}
+
+/// Result of an upgrade check or apply
+#[derive(Debug)]
+pub struct UpgradeResult {
+    /// Files checked
+    pub files_checked: usize,
+    /// Every finding, manifest first, then files in path order
+    pub findings: Vec<UpgradeFinding>,
+    /// Files rewritten; empty for a dry run
+    pub files_fixed: usize,
+}
+
+impl UpgradeResult {
+    /// Findings without an automatic fix
+    pub fn manual(&self) -> usize {
+        self.findings.iter().filter(|f| f.fix.is_none()).count()
+    }
+}
+
+/// Run the upgrade assistant on the project at `path`
+///
+/// # Errors
+///
+/// Returns an error if the manifest names an invalid release, a file can't
+/// be read or rewritten, or the project's tests fail after the fixes
+pub fn run_upgrade(path: &Path, dry_run: bool, run_tests: bool) -> Result<UpgradeResult> {
+    let current = release(env!("CARGO_PKG_VERSION")).context("Invalid Ruchy version")?;
+    let target = target_release(path)?;
+    println!(
+        "Checking {} against Ruchy {}.{}...",
+        path.display(),
+        current.0,
+        current.1
+    );
+
+    let mut findings = Vec::new();
+    let manifest_fix = target.as_ref().filter(|t| t.release < current);
+    if let Some(target) = manifest_fix {
+        findings.push(manifest_finding(target, current));
+    }
+    let files = collect_project_files(path);
+    let mut reports = Vec::new();
+    for file in &files {
+        let report = check_file(file, target.as_ref().map(|t| t.release), current)?;
+        findings.extend(report.findings.iter().cloned());
+        reports.push(report);
+    }
+    for finding in &findings {
+        println!("{finding}");
+    }
+
+    let mut result = UpgradeResult {
+        files_checked: files.len(),
+        findings,
+        files_fixed: 0,
+    };
+    let fixable = result.findings.len() - result.manual();
+    println!(
+        "{} files checked: {} findings, {fixable} with an automatic fix.",
+        result.files_checked,
+        result.findings.len()
+    );
+    if result.findings.is_empty() {
+        println!(
+            "{} Project is ready for Ruchy {}.{}",
+            "✓".green(),
+            current.0,
+            current.1
+        );
+        return Ok(result);
+    }
+    if dry_run {
+        if fixable > 0 {
+            println!(
+                "(dry run - no files were modified; run without --dry-run to apply the fixes)"
+            );
+        }
+        return Ok(result);
+    }
+
+    for report in &reports {
+        if let Some(fixed) = &report.fixed {
+            let backup = write_fixed_source(&report.path, &report.source, fixed)?;
+            println!(
+                "{} Fixed {} (backup: {})",
+                "✓".green(),
+                report.path.display(),
+                backup.display()
+            );
+            result.files_fixed += 1;
+        }
+    }
+    if let Some(target) = manifest_fix {
+        set_target_release(&target.manifest, current)?;
+        println!(
+            "{} Set `ruchy = \"{}.{}\"` in {}",
+            "✓".green(),
+            current.0,
+            current.1,
+            target.manifest.display()
+        );
+    }
+    match result.manual() {
+        0 => {}
+        1 => println!("{} 1 finding needs a manual fix", "⚠".yellow()),
+        manual => println!("{} {manual} findings need a manual fix", "⚠".yellow()),
+    }
+    if run_tests {
+        rerun_tests(&files)?;
+    }
+    Ok(result)
+}
+
+/// `(major, minor)` of a version such as `4`, `4.2` or `5.0.0-beta.1`
+fn release(version: &str) -> Option<Release> {
+    let mut parts = version.trim().split(['.', '-', '+']);
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().map_or(Some(0), |minor| minor.parse().ok())?;
+    Some((major, minor))
+}
+
+/// The release the nearest manifest targets, if it names one
+fn target_release(path: &Path) -> Result<Option<TargetRelease>> {
+    let start = if path.is_dir() {
+        path
+    } else {
+        path.parent().unwrap_or(Path::new("."))
+    };
+    let start = fs::canonicalize(start).unwrap_or_else(|_| start.to_path_buf());
+    let Some(manifest) = start
+        .ancestors()
+        .map(|dir| dir.join(MANIFEST_FILE))
+        .find(|path| path.is_file())
+    else {
+        return Ok(None);
+    };
+    let content = fs::read_to_string(&manifest)
+        .with_context(|| format!("Failed to read {}", manifest.display()))?;
+    let table: toml::Table = toml::from_str(&content)
+        .with_context(|| format!("Failed to parse {}", manifest.display()))?;
+    let Some(declared) = table
+        .get("package")
+        .and_then(|package| package.get("ruchy"))
+    else {
+        return Ok(None);
+    };
+    let Some(release) = declared.as_str().and_then(release) else {
+        bail!("{}: invalid `ruchy` release {declared}", manifest.display());
+    };
+    Ok(Some(TargetRelease { manifest, release }))
+}
+
+fn manifest_finding(target: &TargetRelease, current: Release) -> UpgradeFinding {
+    UpgradeFinding {
+        file: target.manifest.clone(),
+        position: None,
+        rule: "target_release",
+        message: format!(
+            "project targets Ruchy {}.{}",
+            target.release.0, target.release.1
+        ),
+        fix: Some(format!("set `ruchy = \"{}.{}\"`", current.0, current.1)),
+    }
+}
+
+/// Set `[package] ruchy`, keeping the rest of the manifest's formatting
+fn set_target_release(manifest: &Path, release: Release) -> Result<()> {
+    let content = fs::read_to_string(manifest)
+        .with_context(|| format!("Failed to read {}", manifest.display()))?;
+    let mut doc: toml_edit::DocumentMut = content
+        .parse()
+        .with_context(|| format!("Failed to parse {}", manifest.display()))?;
+    doc["package"]["ruchy"] = toml_edit::value(format!("{}.{}", release.0, release.1));
+    fs::write(manifest, doc.to_string())
+        .with_context(|| format!("Failed to write {}", manifest.display()))
+}
+
+/// The `.ruchy` files of the project, in path order
+fn collect_project_files(path: &Path) -> Vec<PathBuf> {
+    if path.is_file() {
+        return vec![path.to_path_buf()];
+    }
+    let mut files: Vec<PathBuf> = WalkDir::new(path)
+        .into_iter()
+        .filter_entry(|entry| entry.depth() == 0 || !is_skipped_dir(entry))
+        .flatten()
+        .filter(|entry| entry.file_type().is_file())
+        .map(walkdir::DirEntry::into_path)
+        .filter(|path| path.extension().is_some_and(|ext| ext == "ruchy"))
+        .collect();
+    files.sort();
+    files
+}
+
+fn is_skipped_dir(entry: &walkdir::DirEntry) -> bool {
+    let name = entry.file_name().to_string_lossy();
+    entry.file_type().is_dir() && (name == "target" || name == "vendor" || name.starts_with('.'))
+}
+
+/// Look for the breaking changes made after `target` in `path`, and check
+/// that it parses once they are fixed
+fn check_file(path: &Path, target: Option<Release>, current: Release) -> Result<FileReport> {
+    let source =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let applies = |rule: &str| {
+        BREAKING_CHANGES
+            .iter()
+            .any(|change| change.rule == rule && target.map_or(true, |t| t < change.since))
+    };
+    let renames = if applies("reserved_keyword") {
+        scan_file(path)
+    } else {
+        Vec::new()
+    };
+    let mut findings: Vec<UpgradeFinding> = renames
+        .iter()
+        .map(|rename| UpgradeFinding {
+            file: path.to_path_buf(),
+            position: Some((rename.line, rename.column)),
+            rule: "reserved_keyword",
+            message: format!("`{}` is a keyword since Ruchy 5.0", rename.original),
+            fix: Some(format!("rename to `{}`", rename.replacement)),
+        })
+        .collect();
+    let fixed = apply_renames_to_content(&source, &renames);
+    if let Err(e) = Parser::new(&fixed).parse() {
+        findings.push(UpgradeFinding {
+            file: path.to_path_buf(),
+            position: None,
+            rule: "parse_error",
+            message: format!("doesn't parse with Ruchy {}.{}: {e}", current.0, current.1),
+            fix: None,
+        });
+    }
+    Ok(FileReport {
+        path: path.to_path_buf(),
+        fixed: (fixed != source).then_some(fixed),
+        source,
+        findings,
+    })
+}
+
+/// Run the files among `files` that declare `@test` functions
+fn rerun_tests(files: &[PathBuf]) -> Result<()> {
+    let test_files: Vec<PathBuf> = files
+        .iter()
+        .filter(|file| declares_tests(file))
+        .cloned()
+        .collect();
+    if test_files.is_empty() {
+        println!("No tests to re-run");
+        return Ok(());
+    }
+    println!("\n🧪 Re-running {} test files...", test_files.len());
+    let start = Instant::now();
+    let results = execute_tests(&test_files, false);
+    print_test_summary(&results, start.elapsed(), false);
+    let failed = results.iter().filter(|r| !r.success).count();
+    if failed > 0 {
+        bail!("{failed} test files fail after the upgrade");
+    }
+    Ok(())
+}
+
+fn declares_tests(file: &Path) -> bool {
+    fs::read_to_string(file)
+        .ok()
+        .and_then(|source| Parser::new(&source).parse().ok())
+        .and_then(|ast| extract_test_functions(&ast).ok())
+        .is_some_and(|tests| !tests.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn project(manifest: Option<&str>, files: &[(&str, &str)]) -> TempDir {
+        let dir = TempDir::new().unwrap();
+        if let Some(manifest) = manifest {
+            fs::write(dir.path().join(MANIFEST_FILE), manifest).unwrap();
+        }
+        for (name, content) in files {
+            let path = dir.path().join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        dir
+    }
+
+    fn rules(result: &UpgradeResult) -> Vec<&str> {
+        result.findings.iter().map(|f| f.rule).collect()
+    }
+
+    #[test]
+    fn test_release_reads_major_and_minor() {
+        assert_eq!(release("4"), Some((4, 0)));
+        assert_eq!(release("4.2"), Some((4, 2)));
+        assert_eq!(release("5.0.0-beta.1"), Some((5, 0)));
+        assert_eq!(release("five"), None);
+    }
+
+    #[test]
+    fn test_dry_run_lists_findings_without_changing_files() {
+        let dir = project(
+            Some("[package]\nname = \"app\"\nversion = \"0.1.0\"\nruchy = \"4.2\"\n"),
+            &[("src/main.ruchy", "let signal = 42\nprintln(signal)\n")],
+        );
+        let result = run_upgrade(dir.path(), true, false).unwrap();
+        assert_eq!(rules(&result), ["target_release", "reserved_keyword"]);
+        assert_eq!(result.findings[1].position, Some((1, 5)));
+        assert_eq!(result.files_fixed, 0);
+        let main = fs::read_to_string(dir.path().join("src/main.ruchy")).unwrap();
+        assert!(main.contains("let signal = 42"));
+    }
+
+    #[test]
+    fn test_apply_rewrites_files_with_backups_and_moves_the_target_release() {
+        let dir = project(
+            Some("[package]\nname = \"app\"\nversion = \"0.1.0\"\nruchy = \"4.2\"\n"),
+            &[
+                ("src/main.ruchy", "let signal = 42\nprintln(signal)\n"),
+                ("vendor/dep/lib.ruchy", "let yield = 1\n"),
+            ],
+        );
+        let result = run_upgrade(dir.path(), false, false).unwrap();
+        assert_eq!(result.files_fixed, 1);
+        let main = dir.path().join("src/main.ruchy");
+        assert!(fs::read_to_string(&main)
+            .unwrap()
+            .contains("let signal_val = 42"));
+        assert!(fs::read_to_string(main.with_extension("ruchy.bak"))
+            .unwrap()
+            .contains("let signal = 42"));
+        let manifest = fs::read_to_string(dir.path().join(MANIFEST_FILE)).unwrap();
+        let (major, minor) = release(env!("CARGO_PKG_VERSION")).unwrap();
+        assert!(manifest.contains(&format!("ruchy = \"{major}.{minor}\"")));
+        assert!(run_upgrade(dir.path(), true, false)
+            .unwrap()
+            .findings
+            .is_empty());
+    }
+
+    #[test]
+    fn test_changes_older_than_the_target_release_are_not_reported() {
+        let dir = project(
+            Some("[package]\nname = \"app\"\nversion = \"0.1.0\"\nruchy = \"5.0\"\n"),
+            &[("main.ruchy", "let signal = 42\n")],
+        );
+        let result = run_upgrade(dir.path(), true, false).unwrap();
+        assert!(!rules(&result).contains(&"reserved_keyword"));
+    }
+
+    #[test]
+    fn test_files_that_still_do_not_parse_need_a_manual_fix() {
+        let dir = project(None, &[("broken.ruchy", "fun main( {\n")]);
+        let result = run_upgrade(dir.path(), false, false).unwrap();
+        assert_eq!(rules(&result), ["parse_error"]);
+        assert_eq!(result.manual(), 1);
+        assert_eq!(result.files_fixed, 0);
+    }
+
+    #[test]
+    fn test_invalid_target_release_is_an_error() {
+        let dir = project(
+            Some("[package]\nname = \"app\"\nversion = \"0.1.0\"\nruchy = \"latest\"\n"),
+            &[],
+        );
+        let err = run_upgrade(dir.path(), true, false)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("invalid `ruchy` release"), "{err}");
+    }
+}
@@ -43,6 +43,16 @@ pub fn write_file_with_context(path: &Path, content: &[u8]) -> Result<()> {
         .with_context(|| format!("Failed to write file: {}", path.display()))
 }
 
+/// Print what the AST optimization pass changes in `source` to stderr;
+/// parse errors are left for the command itself to report
+/// Complexity: 2 (Toyota Way: <10)
+pub fn log_optimization_report(source: &str) {
+    if let Ok(ast) = ruchy::Parser::new(source).parse() {
+        let (_, report) = ruchy::middleend::optimize(ast);
+        eprintln!("{report}");
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -12,13 +12,17 @@
 
 // Core command handlers
 pub mod add;
+pub mod bench_engines_handler;
 pub mod bench_handler;
 pub mod build;
 pub mod check_handler;
+pub mod clean;
+pub mod compilation_db;
 pub mod coverage_handler;
 pub mod doc_handler;
 pub mod eval;
 pub mod execution_handler;
+pub mod filter_handler;
 pub mod new;
 pub mod parse_handler;
 pub mod repl_handler;
@@ -40,6 +44,7 @@ pub mod mcp_handler;
 pub mod notebook_handler;
 pub mod optimize_handler;
 pub mod oracle_handler;
+pub mod otel_handler;
 pub mod publish_handler;
 pub mod replay_handler;
 pub mod serve_handler;
@@ -60,16 +65,22 @@ mod handlers_modules;
 // ============================================================================
 
 // Core command handlers
+pub use bench_engines_handler::handle_bench_engines_command;
 pub use bench_handler::handle_bench_command;
 pub use check_handler::handle_check_command;
 pub use coverage_handler::handle_coverage_command;
 pub use doc_handler::handle_doc_command;
 pub use eval::handle_eval_command;
 pub use execution_handler::{handle_file_execution, handle_stdin_input};
+pub use filter_handler::handle_filter_command;
+pub(crate) use execution_handler::run_script_or_exit;
 pub use parse_handler::handle_parse_command;
-pub use repl_handler::handle_repl_command;
+pub use repl_handler::{
+    handle_check_session_command, handle_repl_command, handle_restricted_repl_command,
+};
 pub use run_handler::{
-    compile_rust_code, handle_run_command, prepare_compilation, transpile_for_execution, VmMode,
+    compile_rust_code, handle_replay_command, handle_run_command, prepare_compilation,
+    transpile_for_execution, VmMode,
 };
 pub use transpile_handler::handle_transpile_command;
 #[cfg(feature = "notebook")]
@@ -87,7 +98,7 @@ pub use command_router::handle_complex_command;
 pub use compile_handler::handle_compile_command;
 pub use dataflow_handler::handle_dataflow_debug_command;
 pub use mcp_handler::handle_mcp_command;
-pub use notebook_handler::handle_notebook_command;
+pub use notebook_handler::{handle_notebook_command, handle_notebook_export_command};
 pub use optimize_handler::handle_optimize_command;
 pub use oracle_handler::handle_oracle_command;
 pub use publish_handler::handle_publish_command;
@@ -96,11 +107,14 @@ pub use serve_handler::handle_serve_command;
 
 // Delegation handlers
 pub use prove_handler::handle_prove_command;
-pub use test_handler::handle_test_command;
+pub use test_handler::{
+    handle_doc_test_command, handle_parity_test_command, handle_test_command,
+};
 
 // Helper utilities (for use by other handlers)
 pub use helpers::{
-    create_repl, log_command_output, read_file_with_context, write_file_with_context,
+    create_repl, log_command_output, log_optimization_report, read_file_with_context,
+    write_file_with_context,
 };
 
 // Internal re-exports (used by extracted handlers when notebook feature is enabled)
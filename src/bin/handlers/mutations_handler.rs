@@ -3,7 +3,7 @@
 //! Handles mutation testing for Ruchy files using cargo-mutants.
 
 use anyhow::Result;
-use ruchy::{Parser as RuchyParser, Transpiler};
+use ruchy::{Parser as RuchyParser, Transpiler, TranspilerOptions};
 use std::path::Path;
 
 /// Transpile a .ruchy file to Rust source code
@@ -12,10 +12,10 @@ fn transpile_ruchy_file(path: &Path) -> Result<String> {
     let mut parser = RuchyParser::new(&source);
     let ast = parser.parse()?;
 
-    let mut transpiler = Transpiler::new();
-    let tokens = transpiler.transpile_to_program_with_context(&ast, Some(path))?;
-
-    Ok(prettyplease::unparse(&syn::parse2(tokens)?))
+    let options = TranspilerOptions::builder().context_file(path).build();
+    Ok(Transpiler::with_options(options)
+        .transpile_program(&ast)?
+        .code)
 }
 
 /// Run cargo mutants on file
@@ -96,6 +96,45 @@ pub fn handle_notebook_command(
     result.map_err(|e| anyhow::anyhow!("Notebook server error: {}", e))
 }
 
+/// Handle `notebook export` - convert a notebook to a script or report
+/// Complexity: 3 (Toyota Way: <10)
+#[cfg(feature = "notebook")]
+pub fn handle_notebook_export_command(file: &Path, to: &str, output: Option<&Path>) -> Result<()> {
+    use ruchy::notebook::{ExportFormat, Notebook, NotebookConverter};
+
+    let format: ExportFormat = to.parse().map_err(|e: String| anyhow::anyhow!(e))?;
+    let content = std::fs::read_to_string(file)
+        .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", file.display(), e))?;
+    let notebook: Notebook = serde_json::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("Invalid notebook {}: {}", file.display(), e))?;
+
+    let output_path = output.map_or_else(
+        || file.with_extension(format.extension()),
+        Path::to_path_buf,
+    );
+    std::fs::write(
+        &output_path,
+        NotebookConverter::new(&notebook).convert(format),
+    )?;
+    println!(
+        "✅ Exported {} to {}",
+        file.display(),
+        output_path.display()
+    );
+    Ok(())
+}
+
+#[cfg(not(feature = "notebook"))]
+pub fn handle_notebook_export_command(
+    _file: &Path,
+    _to: &str,
+    _output: Option<&Path>,
+) -> Result<()> {
+    Err(anyhow::anyhow!(
+        "Notebook feature not enabled. Rebuild with --features notebook"
+    ))
+}
+
 #[cfg(not(feature = "notebook"))]
 pub fn handle_notebook_command(
     _file: Option<&Path>,
@@ -235,6 +274,26 @@ mod tests {
         let _ = handle_notebook_command(Some(&file_path), 8080, false, "localhost");
     }
 
+    #[test]
+    #[cfg(feature = "notebook")]
+    fn test_notebook_export_writes_script_next_to_notebook() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("analysis.rnb");
+        std::fs::write(
+            &file_path,
+            r#"{"cells": [{"cell_type": "code", "source": "1 + 1", "output": "2"}],
+                "metadata": {"language": "ruchy", "version": "1.0.0", "kernel": "ruchy"}}"#,
+        )
+        .unwrap();
+
+        handle_notebook_export_command(&file_path, "script", None).unwrap();
+        let script = std::fs::read_to_string(temp_dir.path().join("analysis.ruchy")).unwrap();
+        assert_eq!(script, "// %%\n1 + 1\n");
+
+        let err = handle_notebook_export_command(&file_path, "pdf", None).unwrap_err();
+        assert!(err.to_string().contains("Unknown export format"));
+    }
+
     #[test]
     fn test_notebook_command_with_function_file() {
         let temp_dir = TempDir::new().unwrap();
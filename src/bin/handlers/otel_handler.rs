@@ -0,0 +1,66 @@
+//! OpenTelemetry export for `ruchy run --otel-sample-rate`
+//!
+//! Sends the spans of [`ruchy::runtime::telemetry`] to an OTLP/HTTP
+//! collector. The exporter is configured by the standard environment
+//! variables, e.g. `OTEL_EXPORTER_OTLP_ENDPOINT` (default
+//! `http://localhost:4318`) and `OTEL_SERVICE_NAME`. Needs the `otel` feature.
+
+use anyhow::Result;
+use ruchy::runtime::telemetry::{self, TelemetryConfig};
+
+/// Enable telemetry for `sample_rate` of the outermost calls and export its
+/// spans over OTLP until [`shutdown`]
+///
+/// # Errors
+/// Returns error if the sample rate is out of range or the exporter can't be
+/// installed
+#[cfg(feature = "otel")]
+pub fn install(sample_rate: f64) -> Result<()> {
+    use anyhow::Context;
+    use opentelemetry::trace::TracerProvider as _;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    let config = TelemetryConfig::new(sample_rate).map_err(anyhow::Error::msg)?;
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .build()
+        .context("Failed to create the OTLP span exporter")?;
+    let provider = opentelemetry_sdk::trace::TracerProvider::builder()
+        .with_simple_exporter(exporter)
+        .build();
+    let layer = tracing_opentelemetry::layer().with_tracer(provider.tracer("ruchy"));
+    tracing::subscriber::set_global_default(tracing_subscriber::registry().with(layer))
+        .context("Failed to install the OpenTelemetry tracing subscriber")?;
+    opentelemetry::global::set_tracer_provider(provider);
+    telemetry::enable(config);
+    Ok(())
+}
+
+/// Without the `otel` feature there is no exporter to install
+///
+/// # Errors
+/// Always: the sample rate is out of range or OpenTelemetry isn't built in
+#[cfg(not(feature = "otel"))]
+pub fn install(sample_rate: f64) -> Result<()> {
+    TelemetryConfig::new(sample_rate).map_err(anyhow::Error::msg)?;
+    anyhow::bail!("OpenTelemetry support not enabled; rebuild with: cargo build --features otel")
+}
+
+/// Stop telemetry and export the spans not sent yet
+pub fn shutdown() {
+    telemetry::disable();
+    #[cfg(feature = "otel")]
+    opentelemetry::global::shutdown_tracer_provider();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_install_rejects_out_of_range_sample_rates() {
+        let err = install(2.0).unwrap_err().to_string();
+        assert!(err.contains("between 0.0 and 1.0"), "{err}");
+        assert!(!telemetry::is_enabled());
+    }
+}
@@ -1,9 +1,15 @@
 //! Publish Command Handler
 //!
-//! Handles publishing packages to the Ruchy registry.
+//! Handles publishing packages to the Ruchy registry: the manifest and
+//! `src/**/*.ruchy` are packed into a tarball and uploaded with
+//! [`RegistryClient::publish`].
 
 use anyhow::{bail, Context, Result};
+use ruchy::package::archive::PackageArchive;
+use ruchy::package::registry_client::{RegistryClient, TOKEN_ENV};
 use std::fs;
+use std::path::Path;
+use std::process::Command;
 
 /// Handle publish command - publish a package to the Ruchy registry
 ///
@@ -22,8 +28,10 @@ use std::fs;
 /// - Required fields missing (name, version, authors, description, license)
 /// - Invalid semver version
 /// - Package validation fails
+/// - The working directory is dirty and `allow_dirty` is not set
+/// - The upload fails, or the registry already has this version
 pub fn handle_publish_command(
-    _registry: &str,
+    registry: &str,
     _version: Option<&str>,
     dry_run: bool,
     allow_dirty: bool,
@@ -49,7 +57,8 @@ pub fn handle_publish_command(
     }
 
     // Find Ruchy.toml in current directory
-    let manifest_path = env::current_dir()?.join("Ruchy.toml");
+    let root = env::current_dir()?;
+    let manifest_path = root.join("Ruchy.toml");
 
     if !manifest_path.exists() {
         bail!("Ruchy.toml not found in current directory.\nRun 'ruchy publish' from your package root.");
@@ -125,41 +134,53 @@ pub fn handle_publish_command(
         println!("\nWould publish package (skipped in dry-run mode)");
         Ok(())
     } else {
-        // Actually publish to crates.io via cargo publish
+        if !allow_dirty {
+            ensure_clean_worktree(&root)?;
+        }
+        let mut client = RegistryClient::new(registry);
+        if let Ok(token) = env::var(TOKEN_ENV) {
+            client = client.with_token(token);
+        }
+
         println!(
-            "Publishing {} v{}...",
+            "Publishing {} v{} to {registry}...",
             manifest.package.name, manifest.package.version
         );
-
-        use std::process::Command;
-
-        // Build cargo publish command
-        let mut cargo_cmd = Command::new("cargo");
-        cargo_cmd.arg("publish");
-
+        let archive = PackageArchive::pack(&root)?;
         if verbose {
-            cargo_cmd.arg("--verbose");
-        }
-
-        if allow_dirty {
-            cargo_cmd.arg("--allow-dirty");
-        }
-
-        // Execute cargo publish
-        let status = cargo_cmd
-            .status()
-            .context("Failed to execute 'cargo publish'. Ensure cargo is installed.")?;
-
-        if status.success() {
-            println!(
-                "Successfully published {} v{} to crates.io",
-                manifest.package.name, manifest.package.version
+            eprintln!(
+                "Packed {} files ({} bytes), sha256 {}",
+                archive.files()?.len(),
+                archive.bytes().len(),
+                archive.checksum()
             );
-            Ok(())
-        } else {
-            bail!("cargo publish failed with exit code: {}", status);
         }
+        let entry = client.publish(&archive)?;
+        println!(
+            "Successfully published {} v{} to {registry}",
+            entry.name, entry.version
+        );
+        Ok(())
+    }
+}
+
+/// Refuse to publish uncommitted changes when the package is in a git
+/// checkout
+fn ensure_clean_worktree(root: &Path) -> Result<()> {
+    let Ok(output) = Command::new("git")
+        .args(["status", "--porcelain"])
+        .current_dir(root)
+        .output()
+    else {
+        return Ok(());
+    };
+    if output.status.success() && !output.stdout.is_empty() {
+        bail!(
+            "Working directory has uncommitted changes.\n\
+             Commit them or pass --allow-dirty to publish anyway."
+        );
     }
+    Ok(())
 }
 
 #[cfg(test)]
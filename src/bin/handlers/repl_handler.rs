@@ -2,18 +2,26 @@
 //!
 //! Handles the interactive Read-Eval-Print Loop.
 
-use anyhow::Result;
-use std::path::PathBuf;
+use anyhow::{bail, Context, Result};
+use ruchy::runtime::repl::session;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 /// Handle REPL command - start the interactive Read-Eval-Print Loop
 ///
 /// # Arguments
 /// * `record_file` - Optional path to record REPL session
 /// * `max_depth` - Maximum recursion depth (RUNTIME-001)
+/// * `timeout` - The `--timeout` flag, bounding each input
 ///
 /// # Errors
-/// Returns error if REPL fails to initialize or run
-pub fn handle_repl_command(record_file: Option<PathBuf>, max_depth: usize) -> Result<()> {
+/// Returns error if the timeout is invalid or the REPL fails to initialize or run
+pub fn handle_repl_command(
+    record_file: Option<PathBuf>,
+    max_depth: usize,
+    timeout: Option<&str>,
+) -> Result<()> {
+    let timeout = repl_timeout(timeout)?;
     use colored::Colorize;
     let version_msg = format!("Welcome to Ruchy REPL v{}", env!("CARGO_PKG_VERSION"));
     println!("{}", version_msg.bright_cyan().bold());
@@ -25,6 +33,7 @@ pub fn handle_repl_command(record_file: Option<PathBuf>, max_depth: usize) -> Re
     // RUNTIME-001: Set recursion depth from CLI arg
     ruchy::runtime::eval_function::set_max_recursion_depth(max_depth);
     let mut repl = super::create_repl()?;
+    repl.set_timeout(timeout);
     if let Some(record_path) = record_file {
         repl.run_with_recording(&record_path)
     } else {
@@ -32,6 +41,97 @@ pub fn handle_repl_command(record_file: Option<PathBuf>, max_depth: usize) -> Re
     }
 }
 
+/// Handle `ruchy repl --restricted` - a REPL safe to expose to untrusted users
+///
+/// Uses [`ruchy::runtime::ReplConfig::restricted`]: filesystem, process,
+/// environment and network builtins are disabled, each input has a time
+/// budget, output is capped and host paths are hidden in error messages.
+/// `timeout` (the `--timeout` flag) replaces the default budget.
+///
+/// # Errors
+/// Returns error if the timeout is invalid or the REPL fails to initialize or run
+pub fn handle_restricted_repl_command(
+    record_file: Option<PathBuf>,
+    timeout: Option<&str>,
+) -> Result<()> {
+    let mut repl = ruchy::runtime::Repl::restricted()?;
+    repl.set_timeout(repl_timeout(timeout)?);
+    if let Some(record_path) = record_file {
+        repl.run_with_recording(&record_path)
+    } else {
+        repl.run()
+    }
+}
+
+/// Handle `ruchy repl --check-session FILE` - replay the REPL transcripts of
+/// a markdown file and fail when the REPL no longer shows what they do
+///
+/// See [`ruchy::runtime::repl::session`] for the transcript format.
+///
+/// # Errors
+/// Returns error if the file can't be read, the timeout is invalid or any
+/// transcript doesn't match
+pub fn handle_check_session_command(
+    file: &Path,
+    restricted: bool,
+    max_depth: usize,
+    timeout: Option<&str>,
+) -> Result<()> {
+    let markdown = std::fs::read_to_string(file)
+        .with_context(|| format!("Failed to read {}", file.display()))?;
+    let timeout = repl_timeout(timeout)?;
+    ruchy::runtime::eval_function::set_max_recursion_depth(max_depth);
+    let report = session::check(&markdown, || {
+        let mut repl = if restricted {
+            ruchy::runtime::Repl::restricted()?
+        } else {
+            super::create_repl()?
+        };
+        repl.set_timeout(timeout);
+        Ok(repl)
+    })?;
+    for mismatch in &report.mismatches {
+        println!("{}:{}:", file.display(), mismatch.line);
+        for (index, line) in mismatch.input.lines().enumerate() {
+            let prompt = if index == 0 { "ruchy> " } else { "ruchy... " };
+            println!("    {prompt}{line}");
+        }
+        println!("  expected:");
+        print_indented(&mismatch.expected);
+        println!("  actual:");
+        print_indented(&mismatch.actual);
+        println!();
+    }
+    let failed = report.mismatches.len();
+    println!(
+        "{} inputs in {} transcripts: {} passed, {failed} failed",
+        report.steps,
+        report.transcripts,
+        report.steps - failed
+    );
+    if failed > 0 {
+        bail!("{failed} REPL inputs in {} did not match", file.display());
+    }
+    Ok(())
+}
+
+/// Deadline of each REPL input: `--timeout`, then `RUCHY_TIMEOUT`, then
+/// `[run] timeout` in the nearest `Ruchy.toml` above the current directory
+fn repl_timeout(flag: Option<&str>) -> Result<Option<Duration>> {
+    let dir = std::env::current_dir().unwrap_or_default();
+    ruchy::runtime::script::configured_timeout(&dir, flag).map_err(anyhow::Error::msg)
+}
+
+/// Print `text` indented under a heading, marking empty output
+fn print_indented(text: &str) {
+    if text.is_empty() {
+        println!("    (nothing)");
+    }
+    for line in text.lines() {
+        println!("    {line}");
+    }
+}
+
 /// Print prover help - moved to separate function for clarity
 pub(crate) fn print_prover_help() {
     println!("\nInteractive Prover Commands:");
@@ -80,7 +180,7 @@ mod tests {
     fn test_repl_handler_accepts_none_record() {
         // Just verify the function signature
         // REPL runs interactively so we can't test full execution
-        let _ = handle_repl_command(None, 100);
+        let _ = handle_repl_command(None, 100, None);
     }
 
     #[test]
@@ -89,13 +189,19 @@ mod tests {
         let record_path = temp_dir.path().join("repl_session.txt");
         // Just verify it accepts the path
         // REPL runs interactively so we can't test full execution
-        let _ = handle_repl_command(Some(record_path), 100);
+        let _ = handle_repl_command(Some(record_path), 100, None);
+    }
+
+    #[test]
+    fn test_restricted_repl_handler_accepts_none_record() {
+        // REPL runs interactively so we can't test full execution
+        let _ = handle_restricted_repl_command(None, None);
     }
 
     #[test]
     fn test_repl_handler_record_nonexistent_dir() {
         let record_path = std::path::PathBuf::from("/nonexistent/dir/session.txt");
-        let _ = handle_repl_command(Some(record_path), 100);
+        let _ = handle_repl_command(Some(record_path), 100, None);
     }
 
     #[test]
@@ -125,7 +231,7 @@ mod tests {
             std::path::PathBuf::from("session"),
         ];
         for path in paths {
-            let _ = handle_repl_command(Some(path), 100);
+            let _ = handle_repl_command(Some(path), 100, None);
         }
     }
 
@@ -143,7 +249,7 @@ mod tests {
         let files = ["session1.txt", "session2.record", "test.log"];
         for file in &files {
             let record_path = temp_dir.path().join(file);
-            let _ = handle_repl_command(Some(record_path), 100);
+            let _ = handle_repl_command(Some(record_path), 100, None);
         }
     }
 
@@ -153,7 +259,7 @@ mod tests {
         let nested = temp_dir.path().join("a").join("b").join("c");
         std::fs::create_dir_all(&nested).unwrap();
         let record_path = nested.join("session.txt");
-        let _ = handle_repl_command(Some(record_path), 100);
+        let _ = handle_repl_command(Some(record_path), 100, None);
     }
 
     #[test]
@@ -161,7 +267,7 @@ mod tests {
         let extensions = [".txt", ".repl", ".session", ".log", ""];
         for ext in &extensions {
             let path = PathBuf::from(format!("/tmp/test{}", ext));
-            let _ = handle_repl_command(Some(path), 100);
+            let _ = handle_repl_command(Some(path), 100, None);
         }
     }
 }
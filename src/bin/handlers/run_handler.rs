@@ -3,8 +3,13 @@
 //! Handles execution of Ruchy files via compilation or interpretation.
 
 use anyhow::{Context, Result};
+use ruchy::backend::transpiler::OutputStyle;
 use ruchy::frontend::ast::Expr;
-use ruchy::{Parser as RuchyParser, Transpiler};
+use ruchy::runtime::replay_trace::{self, RunTrace};
+use ruchy::runtime::script::{
+    run_script, ScriptError, ScriptOptions, ScriptOutcome, EXIT_SYNTAX_ERROR,
+};
+use ruchy::{Parser as RuchyParser, Transpiler, TranspilerOptions};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -18,8 +23,30 @@ pub enum VmMode {
 }
 
 /// Handle run command - compile and execute a Ruchy file
-pub fn handle_run_command(file: &Path, verbose: bool, vm_mode: VmMode) -> Result<()> {
+///
+/// With `record`, the run's inputs and output are written to a trace for
+/// `ruchy replay` (see `ruchy::runtime::replay_trace`). With
+/// `otel_sample_rate`, spans of that fraction of top-level calls are exported
+/// over OTLP (see `ruchy::runtime::telemetry`). With `optimize`, constants
+/// are folded, dead branches removed and loop invariants and repeated
+/// expressions computed once before the program runs (see
+/// `ruchy::middleend::optimize`); `verbose` prints what changed.
+pub fn handle_run_command(
+    file: &Path,
+    record: Option<&Path>,
+    otel_sample_rate: Option<f64>,
+    optimize: bool,
+    verbose: bool,
+    vm_mode: VmMode,
+    timeout: Option<&str>,
+) -> Result<()> {
     log_run_start(file, verbose);
+    if record.is_some() && vm_mode != VmMode::Ast {
+        anyhow::bail!("--record needs the AST interpreter (remove --vm-mode bytecode)");
+    }
+    if otel_sample_rate.is_some() && vm_mode != VmMode::Ast {
+        anyhow::bail!("--otel-sample-rate needs the AST interpreter (remove --vm-mode bytecode)");
+    }
 
     if verbose {
         println!("Execution mode: {:?}", vm_mode);
@@ -34,50 +61,48 @@ pub fn handle_run_command(file: &Path, verbose: bool, vm_mode: VmMode) -> Result
     } else {
         super::read_file_with_context(file)?
     };
-
-    // FIX CLI-CONTRACT-RUN-001: Parse the entire file FIRST to catch syntax errors
-    let mut parser = RuchyParser::new(&source);
-    let ast = match parser.parse() {
-        Ok(ast) => ast,
-        Err(e) => {
-            eprintln!("✗ Syntax error: {e}");
-            eprintln!("Error: Syntax error: {e}");
-            std::process::exit(1);
-        }
-    };
-
-    // ISSUE-106: Module resolution for interpreter path
-    // LIMITATION: mod declarations work for compilation but not interpretation
-    // RATIONALE: The REPL API needs to support AST-based evaluation for this to work cleanly
+    if verbose && optimize {
+        super::log_optimization_report(&source);
+    }
 
     match vm_mode {
         VmMode::Ast => {
-            // CLI-UNIFY-002: Use interpreter (like handle_file_execution), not compiler
-            // This matches Deno/Python/Ruby/Node behavior: `run` = interpret immediately
-            // For compilation to binary, use: `ruchy compile`
-            let mut repl = super::create_repl()?;
-
-            match repl.eval(&source) {
-                Ok(_result) => {
-                    // FIX CLI-CONTRACT-RUN-002: Don't print file evaluation results
-                    // The user's code uses println() for output. We should NOT print the
-                    // final value of file evaluation (that's REPL behavior, not script behavior).
-                    // This matches Python/Ruby/Node: `python script.py` doesn't print the last value.
-
-                    // After evaluating the file, check if main() function exists and call it
-                    // (but also don't print main's return value - it's not a println)
-                    // FIX Issue #81: Handle main() errors (panic!, undefined functions, etc.)
-                    match repl.eval("main()") {
-                        Ok(_) => Ok(()),
-                        Err(e) => {
-                            eprintln!("Error: {e}");
-                            std::process::exit(1);
-                        }
+            // CLI-UNIFY-002: `run` interprets immediately, like `ruchy file.ruchy`.
+            // Parsing, module resolution, main() auto-call and exit codes follow
+            // runtime::script; the final value is not printed (script behavior).
+            let options = if file.to_str() == Some("-") {
+                ScriptOptions::default()
+            } else {
+                ScriptOptions::for_file(file)
+            };
+            let options = options
+                .with_optimization(optimize)
+                .with_timeout_flag(timeout)
+                .with_configured_timeout()
+                .map_err(anyhow::Error::msg)?;
+            if let Some(sample_rate) = otel_sample_rate {
+                super::otel_handler::install(sample_rate)?;
+            }
+            match record {
+                Some(trace) => {
+                    replay_trace::start_recording(trace, &source, options.path.as_deref())?;
+                    let result = run_script(&source, &options);
+                    super::otel_handler::shutdown();
+                    finish_traced_run(result)
+                }
+                // Spans are exported before the process exits with the script's status
+                None if otel_sample_rate.is_some() => {
+                    let result = run_script(&source, &options);
+                    super::otel_handler::shutdown();
+                    if let Err(e) = result {
+                        eprintln!("Error: {e}");
+                        std::process::exit(e.exit_code());
                     }
+                    Ok(())
                 }
-                Err(e) => {
-                    eprintln!("Error: {e}");
-                    std::process::exit(1);
+                None => {
+                    super::run_script_or_exit(&source, &options);
+                    Ok(())
                 }
             }
         }
@@ -85,6 +110,20 @@ pub fn handle_run_command(file: &Path, verbose: bool, vm_mode: VmMode) -> Result
             // OPT-004: Bytecode VM execution path (40-60% faster than AST)
             use ruchy::runtime::bytecode::{Compiler, VM};
 
+            let ast = match RuchyParser::new(&source).parse() {
+                Ok(ast) => ast,
+                Err(e) => {
+                    eprintln!("✗ Syntax error: {e}");
+                    eprintln!("Error: Syntax error: {e}");
+                    std::process::exit(EXIT_SYNTAX_ERROR);
+                }
+            };
+            let ast = if optimize {
+                ruchy::middleend::optimize(ast).0
+            } else {
+                ast
+            };
+
             let mut compiler = Compiler::new("main".to_string());
             if let Err(e) = compiler.compile_expr(&ast) {
                 eprintln!("✗ Compilation error: {}", e);
@@ -110,6 +149,57 @@ pub fn handle_run_command(file: &Path, verbose: bool, vm_mode: VmMode) -> Result
     }
 }
 
+/// Handle replay command - re-execute a recorded run deterministically
+///
+/// The recorded source runs again with the recorded random seed, clock reads
+/// and stdin lines. Output is printed as it is produced and compared with
+/// the recording; the first difference fails the command after the run.
+///
+/// # Errors
+/// Returns error if the trace cannot be read or the replay diverged
+pub fn handle_replay_command(trace: &Path, verbose: bool, timeout: Option<&str>) -> Result<()> {
+    let trace_data = RunTrace::load(trace)?;
+    if verbose {
+        eprintln!(
+            "Replaying {} (recorded by ruchy {})",
+            trace.display(),
+            trace_data.header.ruchy_version
+        );
+    }
+    let options = match &trace_data.header.path {
+        Some(path) => ScriptOptions::for_file(path),
+        None => ScriptOptions::default(),
+    }
+    .with_timeout_flag(timeout)
+    .with_configured_timeout()
+    .map_err(anyhow::Error::msg)?;
+    if trace_data.exit().is_none() {
+        eprintln!("Note: the recording was interrupted; past its end the run uses live inputs");
+    }
+    replay_trace::start_replay(&trace_data);
+    finish_traced_run(run_script(&trace_data.header.source, &options))
+}
+
+/// Finish the active trace after a recorded or replayed run (complexity: 4)
+///
+/// Reports a failed run like `ruchy run` and exits with its status, unless
+/// the replay diverged, which is the error returned instead.
+fn finish_traced_run(result: Result<ScriptOutcome, ScriptError>) -> Result<()> {
+    let failure = result.err();
+    if let Some(e) = &failure {
+        eprintln!("Error: {e}");
+    }
+    let code = failure.as_ref().map_or(0, ScriptError::exit_code);
+    let error = failure.as_ref().map(ToString::to_string);
+    if let Some(divergence) = replay_trace::finish(code, error.as_deref())? {
+        anyhow::bail!("Replay diverged from the recording: {divergence}");
+    }
+    if code != 0 {
+        std::process::exit(code);
+    }
+    Ok(())
+}
+
 /// Log run command start (complexity: 2)
 fn log_run_start(file: &Path, verbose: bool) {
     if verbose {
@@ -120,10 +210,13 @@ fn log_run_start(file: &Path, verbose: bool) {
 /// Transpile AST for execution with context (complexity: 3)
 pub fn transpile_for_execution(ast: &Expr, file: &Path) -> Result<String> {
     contract_pre_atomic_write!(ast);
-    let mut transpiler = Transpiler::new();
-    transpiler
-        .transpile_to_program_with_context(ast, Some(file))
-        .map(|tokens| tokens.to_string())
+    let options = TranspilerOptions::builder()
+        .style(OutputStyle::Compact)
+        .context_file(file)
+        .build();
+    Transpiler::with_options(options)
+        .transpile_program(ast)
+        .map(|output| output.code)
         .with_context(|| "Failed to transpile to Rust")
 }
 
@@ -3,6 +3,9 @@
 //! Handles serving static files over HTTP with optional watch mode.
 
 use anyhow::Result;
+use ruchy::server::eval_api::EvalApiConfig;
+#[cfg(feature = "notebook")]
+use ruchy::server::eval_api::EVAL_ROUTE;
 use std::path::Path;
 
 /// Handle serve command - serve static files over HTTP
@@ -16,6 +19,7 @@ use std::path::Path;
 /// * `debounce` - Debounce interval for file changes in ms
 /// * `pid_file` - Optional PID file path
 /// * `watch_wasm` - Enable WASM hot reload
+/// * `eval_api` - Budgets of the snippet evaluation endpoint, when enabled
 #[cfg(feature = "notebook")]
 pub fn handle_serve_command(
     directory: &Path,
@@ -26,6 +30,7 @@ pub fn handle_serve_command(
     debounce: u64,
     pid_file: Option<&Path>,
     watch_wasm: bool,
+    eval_api: Option<EvalApiConfig>,
 ) -> Result<()> {
     use axum::{http::HeaderValue, Router};
     use tower::ServiceBuilder;
@@ -53,17 +58,20 @@ pub fn handle_serve_command(
     };
 
     // World-class UX: Colored startup banner (vite-style)
-    print_startup_banner(host, port, directory, watch, watch_wasm);
+    print_startup_banner(host, port, directory, watch, watch_wasm, eval_api.as_ref());
 
     // Build the Axum app with static file serving + WASM headers
     let serve_dir = ServeDir::new(directory)
         .precompressed_gzip() // Serve .gz files if available (faster)
         .precompressed_br(); // Serve .br files if available (faster)
 
+    // Snippet evaluation endpoint (`--eval-api`), see ruchy::server::eval_api
+    let routes = eval_api.map_or_else(Router::new, ruchy::server::eval_api::routes);
+
     // Add WASM-specific headers for SharedArrayBuffer support (HTTP-003)
     // Required for: WebAssembly threading, SharedArrayBuffer, Atomics
     // Reference: https://web.dev/coop-coep/
-    let app = Router::new().fallback_service(serve_dir).layer(
+    let app = routes.fallback_service(serve_dir).layer(
         ServiceBuilder::new()
             // Cross-Origin-Opener-Policy: Isolate browsing context
             .layer(SetResponseHeaderLayer::if_not_present(
@@ -137,7 +145,14 @@ pub fn handle_serve_command(
 /// Print colored startup banner (vite-style)
 /// Complexity: 3 (Toyota Way: <10)
 #[cfg(feature = "notebook")]
-fn print_startup_banner(host: &str, port: u16, directory: &Path, watch: bool, watch_wasm: bool) {
+fn print_startup_banner(
+    host: &str,
+    port: u16,
+    directory: &Path,
+    watch: bool,
+    watch_wasm: bool,
+    eval_api: Option<&EvalApiConfig>,
+) {
     #[cfg(not(target_arch = "wasm32"))]
     {
         use colored::Colorize;
@@ -180,6 +195,16 @@ fn print_startup_banner(host: &str, port: u16, directory: &Path, watch: bool, wa
             }
         }
 
+        if let Some(config) = eval_api {
+            println!(
+                "  🧪 {}: POST {} ({} requests/min per client, {}ms per run)",
+                "Eval API".dimmed(),
+                EVAL_ROUTE,
+                config.requests_per_minute,
+                config.timeout.as_millis()
+            );
+        }
+
         println!("\n  {} Press Ctrl+C to stop\n", "Ready".green().bold());
     }
 
@@ -191,6 +216,9 @@ fn print_startup_banner(host: &str, port: u16, directory: &Path, watch: bool, wa
         if watch {
             println!("👀 Watching: {}/**/*", directory.display());
         }
+        if eval_api.is_some() {
+            println!("🧪 Eval API: POST {}", EVAL_ROUTE);
+        }
         println!("Press Ctrl+C to stop\n");
     }
 }
@@ -224,7 +252,7 @@ fn run_watch_mode(
                 println!("✅ Server started ({} workers)", num_cpus);
             }
 
-            axum::serve(listener, app_clone).await
+            axum::serve(listener, with_client_addr(app_clone)).await
         });
 
         // Poll for file changes AND shutdown signal
@@ -249,6 +277,15 @@ fn run_watch_mode(
     }
 }
 
+/// Make the client address available to handlers (the eval API rate limit)
+#[cfg(feature = "notebook")]
+fn with_client_addr(
+    app: axum::Router,
+) -> axum::extract::connect_info::IntoMakeServiceWithConnectInfo<axum::Router, std::net::SocketAddr>
+{
+    app.into_make_service_with_connect_info::<std::net::SocketAddr>()
+}
+
 /// Handle file changes in watch mode
 /// Complexity: 5 (Toyota Way: <10)
 #[cfg(feature = "notebook")]
@@ -350,7 +387,7 @@ fn run_normal_mode(
                 println!("✅ Server started ({} workers)", num_cpus_clone);
             }
 
-            axum::serve(listener, app).await
+            axum::serve(listener, with_client_addr(app)).await
         };
 
         // Spawn server task
@@ -375,7 +412,7 @@ fn run_normal_mode(
             println!("✅ Server started ({} workers)", num_cpus);
         }
 
-        axum::serve(listener, app).await
+        axum::serve(listener, with_client_addr(app)).await
     })?;
 
     #[allow(unreachable_code)]
@@ -392,6 +429,7 @@ pub fn handle_serve_command(
     _debounce: u64,
     _pid_file: Option<&Path>,
     _watch_wasm: bool,
+    _eval_api: Option<EvalApiConfig>,
 ) -> Result<()> {
     Err(anyhow::anyhow!(
         "HTTP server requires notebook feature. Rebuild with --features notebook"
@@ -423,6 +461,7 @@ mod tests {
             500,
             None,
             false,
+            None,
         );
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("not found"));
@@ -443,6 +482,7 @@ mod tests {
             500,
             None,
             false,
+            None,
         );
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("not a directory"));
@@ -460,6 +500,7 @@ mod tests {
             500,
             None,
             false,
+            None,
         );
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("notebook feature"));
@@ -477,6 +518,7 @@ mod tests {
             100,
             None,
             false,
+            None,
         );
     }
 
@@ -492,6 +534,7 @@ mod tests {
             1000,
             Some(Path::new("/tmp/pid")),
             true,
+            None,
         );
     }
 
@@ -507,6 +550,7 @@ mod tests {
             500,
             None,
             false,
+            None,
         );
     }
 
@@ -523,6 +567,7 @@ mod tests {
             100,
             None,
             false,
+            None,
         );
         // Test 0.0.0.0
         let _ = handle_serve_command(
@@ -534,6 +579,7 @@ mod tests {
             100,
             None,
             false,
+            None,
         );
     }
 
@@ -549,6 +595,7 @@ mod tests {
             100,
             None,
             false,
+            None,
         );
         let _ = handle_serve_command(
             temp_dir.path(),
@@ -559,6 +606,7 @@ mod tests {
             100,
             None,
             false,
+            None,
         );
         let _ = handle_serve_command(
             temp_dir.path(),
@@ -569,6 +617,7 @@ mod tests {
             100,
             None,
             false,
+            None,
         );
     }
 
@@ -585,6 +634,7 @@ mod tests {
             1, // 1ms
             None,
             false,
+            None,
         );
         // Large debounce
         let _ = handle_serve_command(
@@ -596,6 +646,7 @@ mod tests {
             10000, // 10s
             None,
             false,
+            None,
         );
     }
 
@@ -611,6 +662,7 @@ mod tests {
             500,
             None,
             true, // watch_wasm
+            None,
         );
     }
 
@@ -629,6 +681,7 @@ mod tests {
             250,  // debounce
             Some(&pid_path),
             true, // watch_wasm
+            None,
         );
     }
 
@@ -644,6 +697,7 @@ mod tests {
             0, // zero debounce
             None,
             false,
+            None,
         );
     }
 
@@ -652,6 +706,16 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let nested = temp_dir.path().join("a").join("b").join("c");
         std::fs::create_dir_all(&nested).unwrap();
-        let _ = handle_serve_command(&nested, 8089, "127.0.0.1", false, false, 100, None, false);
+        let _ = handle_serve_command(
+            &nested,
+            8089,
+            "127.0.0.1",
+            false,
+            false,
+            100,
+            None,
+            false,
+            None,
+        );
     }
 }
@@ -126,6 +126,241 @@ pub fn run_ruchy_test_file(test_file: &Path, verbose: bool) -> Result<()> {
     super::handlers_modules::test_helpers::run_test_file(test_file, verbose)
 }
 
+/// Check interpreter/compiled parity of stdlib functions (`ruchy test --parity`)
+///
+/// Runs random calls of every stdlib function (or those whose name contains
+/// `filter`) in the interpreter and in a compiled program, and reports calls
+/// whose results differ.
+///
+/// # Arguments
+/// * `filter` - Only check functions whose name contains this
+/// * `cases` - Random calls per function
+/// * `seed` - Seed of the generated inputs
+/// * `format` - Output format (text, json)
+/// * `verbose` - List every divergent call instead of the first few
+///
+/// # Errors
+/// Returns error if any function diverges or a compiled program cannot run
+pub fn handle_parity_test_command(
+    filter: Option<&str>,
+    cases: usize,
+    seed: Option<u64>,
+    format: &str,
+    verbose: bool,
+) -> Result<()> {
+    use ruchy::backend::parity::{self, ParityConfig};
+
+    let config = ParityConfig {
+        cases,
+        seed: seed.unwrap_or(ParityConfig::default().seed),
+        filter: filter.map(str::to_string),
+    };
+    let json = format == "json";
+    if !json {
+        println!(
+            "Checking interpreter/compiled parity ({} calls per function, seed {})",
+            config.cases, config.seed
+        );
+    }
+    let report = parity::run(&config, |function| {
+        if !json {
+            print_parity_function(function, verbose);
+        }
+    })?;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&parity_report_json(&report))?
+        );
+    }
+    let failed = report.failures().count();
+    if failed > 0 {
+        anyhow::bail!(
+            "{failed} of {} stdlib functions diverge between interpreter and compiled code",
+            report.functions.len()
+        );
+    }
+    if !json {
+        println!("✅ All {} stdlib functions agree", report.functions.len());
+    }
+    Ok(())
+}
+
+/// Divergent calls listed per function unless `--verbose` is given
+const PARITY_DIVERGENCES_SHOWN: usize = 5;
+
+fn print_parity_function(function: &ruchy::backend::parity::FunctionReport, verbose: bool) {
+    if let Some(error) = &function.compile_error {
+        let first_line = error.lines().next().unwrap_or_default();
+        println!("✗ {}: does not compile: {first_line}", function.name);
+        return;
+    }
+    if function.passed() {
+        println!("✓ {} ({} calls)", function.name, function.cases);
+        return;
+    }
+    println!(
+        "✗ {}: {} of {} calls diverge",
+        function.name,
+        function.divergences.len(),
+        function.cases
+    );
+    let shown = if verbose {
+        function.divergences.len()
+    } else {
+        PARITY_DIVERGENCES_SHOWN
+    };
+    for divergence in function.divergences.iter().take(shown) {
+        println!(
+            "    {}: interpreter {}, compiled {}",
+            divergence.call, divergence.interpreted, divergence.compiled
+        );
+    }
+}
+
+fn parity_report_json(report: &ruchy::backend::parity::ParityReport) -> serde_json::Value {
+    let functions: Vec<serde_json::Value> = report
+        .functions
+        .iter()
+        .map(|function| {
+            let divergences: Vec<serde_json::Value> = function
+                .divergences
+                .iter()
+                .map(|divergence| {
+                    serde_json::json!({
+                        "call": divergence.call,
+                        "interpreted": divergence.interpreted.to_string(),
+                        "compiled": divergence.compiled.to_string(),
+                    })
+                })
+                .collect();
+            serde_json::json!({
+                "name": function.name,
+                "cases": function.cases,
+                "passed": function.passed(),
+                "compile_error": function.compile_error,
+                "divergences": divergences,
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "passed": report.passed(),
+        "functions": functions,
+    })
+}
+
+/// Run the examples in doc comments (`ruchy test --doc`)
+///
+/// Runs every doctest of the `.ruchy` files under `path` (the current
+/// directory by default), or those of items whose path contains `filter`,
+/// and reports each failure with its file and line.
+///
+/// # Arguments
+/// * `path` - File or directory to test
+/// * `filter` - Only run doctests of items whose path contains this
+/// * `format` - Output format (text, json)
+/// * `verbose` - List passing and ignored doctests too
+///
+/// # Errors
+/// Returns error if the path does not exist or any doctest fails
+pub fn handle_doc_test_command(
+    path: Option<&Path>,
+    filter: Option<&str>,
+    format: &str,
+    verbose: bool,
+) -> Result<()> {
+    use ruchy::docs::{DoctestFile, DoctestOutcome};
+
+    let root = path.unwrap_or(Path::new("."));
+    let files = ruchy::cli::shared::scan_ruchy_files(root).map_err(|e| anyhow::anyhow!(e))?;
+    let json = format == "json";
+    let mut results = Vec::new();
+    for file in &files {
+        let doctests = match std::fs::read_to_string(file)
+            .map_err(anyhow::Error::from)
+            .and_then(|source| DoctestFile::parse(&source))
+        {
+            Ok(doctests) => doctests,
+            Err(e) => {
+                if !json {
+                    eprintln!("⚠ Skipping {}: {e}", file.display());
+                }
+                continue;
+            }
+        };
+        let selected = doctests
+            .doctests()
+            .iter()
+            .filter(|doctest| filter.map_or(true, |filter| doctest.item.contains(filter)));
+        for doctest in selected {
+            let outcome = doctests.run(doctest);
+            let location = format!("{}:{}", file.display(), doctest.line);
+            if !json {
+                print_doctest(&location, &doctest.item, &outcome, verbose);
+            }
+            results.push((location, doctest.item.clone(), outcome));
+        }
+    }
+
+    let count = |wanted: fn(&DoctestOutcome) -> bool| {
+        results
+            .iter()
+            .filter(|(_, _, outcome)| wanted(outcome))
+            .count()
+    };
+    let passed = count(|outcome| *outcome == DoctestOutcome::Passed);
+    let ignored = count(|outcome| *outcome == DoctestOutcome::Ignored);
+    let failed = results.len() - passed - ignored;
+    if json {
+        let doctests: Vec<serde_json::Value> = results
+            .iter()
+            .map(|(location, item, outcome)| {
+                let (status, error) = match outcome {
+                    DoctestOutcome::Passed => ("passed", None),
+                    DoctestOutcome::Ignored => ("ignored", None),
+                    DoctestOutcome::Failed(error) => ("failed", Some(error)),
+                };
+                serde_json::json!({
+                    "location": location,
+                    "item": item,
+                    "status": status,
+                    "error": error,
+                })
+            })
+            .collect();
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "passed": failed == 0,
+                "doctests": doctests,
+            }))?
+        );
+    } else {
+        println!("Doctests: {passed} passed, {failed} failed, {ignored} ignored");
+    }
+    if failed > 0 {
+        anyhow::bail!("{failed} of {} doctests failed", results.len());
+    }
+    Ok(())
+}
+
+fn print_doctest(location: &str, item: &str, outcome: &ruchy::docs::DoctestOutcome, verbose: bool) {
+    use ruchy::docs::DoctestOutcome;
+
+    match outcome {
+        DoctestOutcome::Passed if verbose => println!("✓ {location} ({item})"),
+        DoctestOutcome::Ignored if verbose => println!("- {location} ({item}) ignored"),
+        DoctestOutcome::Failed(error) => {
+            println!(
+                "✗ {location} ({item})\n    {}",
+                error.replace('\n', "\n    ")
+            );
+        }
+        _ => {}
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -282,6 +517,52 @@ mod tests {
         let _ = result;
     }
 
+    #[test]
+    fn test_parity_report_json_lists_divergences() {
+        use ruchy::backend::parity::{Divergence, FunctionReport, Outcome, ParityReport};
+
+        let report = ParityReport {
+            functions: vec![FunctionReport {
+                name: "abs",
+                cases: 2,
+                divergences: vec![Divergence {
+                    call: "abs(-1)".to_string(),
+                    interpreted: Outcome::Value("1".to_string()),
+                    compiled: Outcome::Error,
+                }],
+                compile_error: None,
+            }],
+        };
+        let json = parity_report_json(&report);
+        assert_eq!(json["passed"], false);
+        assert_eq!(
+            json["functions"][0]["divergences"][0]["compiled"],
+            "<error>"
+        );
+    }
+
+    #[test]
+    fn test_doc_tests_pass_and_fail() {
+        let temp_dir = TempDir::new().unwrap();
+        let file = temp_dir.path().join("lib.ruchy");
+        std::fs::write(
+            &file,
+            "/// ```\n/// assert_eq(inc(1), 2)\n/// ```\nfun inc(n) { n + 1 }\n\n\
+             /// ```\n/// assert_eq(dec(1), 1)\n/// ```\nfun dec(n) { n - 1 }\n",
+        )
+        .unwrap();
+        assert!(handle_doc_test_command(Some(&file), Some("inc"), "text", true).is_ok());
+        let error = handle_doc_test_command(Some(temp_dir.path()), None, "json", false)
+            .expect_err("dec's example is wrong");
+        assert_eq!(error.to_string(), "1 of 2 doctests failed");
+    }
+
+    #[test]
+    fn test_doc_tests_nonexistent_path() {
+        let result = handle_doc_test_command(Some(Path::new("/nonexistent")), None, "text", false);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_run_ruchy_test_file_nonexistent() {
         let result = run_ruchy_test_file(Path::new("/nonexistent/test.ruchy"), false);
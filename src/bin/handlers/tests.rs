@@ -9,25 +9,25 @@ use super::wasm_handler::{determine_wasm_output_path, parse_ruchy_source};
 
 #[test]
 fn test_handle_eval_command_basic() {
-    let result = handle_eval_command("2 + 2", false, "text", false);
+    let result = handle_eval_command("2 + 2", false, "text", false, None);
     assert!(result.is_ok());
 }
 
 #[test]
 fn test_handle_eval_command_verbose() {
-    let result = handle_eval_command("42", true, "text", false);
+    let result = handle_eval_command("42", true, "text", false, None);
     assert!(result.is_ok());
 }
 
 #[test]
 fn test_handle_eval_command_json_format() {
-    let result = handle_eval_command("1 + 1", false, "json", false);
+    let result = handle_eval_command("1 + 1", false, "json", false, None);
     assert!(result.is_ok());
 }
 
 #[test]
 fn test_handle_eval_command_invalid_expr() {
-    let result = handle_eval_command("invalid++syntax", false, "text", false);
+    let result = handle_eval_command("invalid++syntax", false, "text", false, None);
     assert!(result.is_err());
 }
 
@@ -3,8 +3,10 @@
 //! Handles transpilation of Ruchy code to Rust.
 
 use anyhow::{Context, Result};
+use ruchy::backend::mir_codegen;
+use ruchy::backend::transpiler::OutputStyle;
 use ruchy::frontend::ast::Expr;
-use ruchy::{Parser as RuchyParser, Transpiler};
+use ruchy::{Parser as RuchyParser, Transpiler, TranspilerOptions};
 use std::fs;
 use std::io::{self, Read};
 use std::path::{Path, PathBuf};
@@ -14,13 +16,25 @@ pub fn handle_transpile_command(
     file: &Path,
     output: Option<&Path>,
     minimal: bool,
+    whole_program: bool,
+    via_mir: bool,
     verbose: bool,
 ) -> Result<()> {
     contract_pre_configuration!(file);
     log_transpile_start(file, minimal, verbose);
     let source = read_source_file(file, verbose)?;
     let ast = parse_source(&source)?;
-    let rust_code = transpile_ast(&ast, minimal)?;
+    warn_unknown_methods(&source, &ast);
+    let ast = if whole_program {
+        optimize_whole_program(ast, verbose)
+    } else {
+        ast
+    };
+    let rust_code = if via_mir {
+        transpile_via_mir(&ast, verbose)?
+    } else {
+        transpile_ast(&ast, minimal)?
+    };
     // Default to stdout for backwards compatibility (many tests expect stdout output)
     // Use -o to specify file output explicitly
     write_output(&rust_code, output, verbose)?;
@@ -71,25 +85,48 @@ pub fn parse_source(source: &str) -> Result<Expr> {
     parser.parse().with_context(|| "Failed to parse input")
 }
 
+/// Warn about unknown methods called on literals (complexity: 2)
+///
+/// Such calls fail in the interpreter and usually in the generated Rust too;
+/// transpilation still goes ahead.
+pub fn warn_unknown_methods(source: &str, ast: &Expr) {
+    for missing in ruchy::middleend::analyze_method_calls(ast) {
+        eprintln!("Warning: {}", missing.render(source));
+    }
+}
+
+/// Run whole-program constant propagation, reporting what changed when verbose (complexity: 2)
+pub fn optimize_whole_program(ast: Expr, verbose: bool) -> Expr {
+    let (ast, report) = ruchy::backend::whole_program::optimize(ast);
+    if verbose {
+        eprintln!("{report}");
+    }
+    ast
+}
+
 /// Transpile AST to Rust code (complexity: 4)
-/// PARSER-077: Use prettyplease for proper formatting (no extra spaces)
+/// PARSER-077: `OutputStyle::Pretty` formats with prettyplease (no extra spaces)
 pub fn transpile_ast(ast: &Expr, minimal: bool) -> Result<String> {
     contract_pre_configuration!(ast);
-    let mut transpiler = Transpiler::new();
-    if minimal {
-        transpiler
-            .transpile_minimal(ast)
-            .with_context(|| "Failed to transpile to Rust (minimal)")
+    let style = if minimal {
+        OutputStyle::Minimal
     } else {
-        let tokens = transpiler
-            .transpile_to_program(ast)
-            .with_context(|| "Failed to transpile to Rust")?;
-
-        // Parse TokenStream as syn::File and format with prettyplease
-        let syntax_tree = syn::parse2(tokens)
-            .with_context(|| "Failed to parse generated tokens as Rust syntax")?;
-        Ok(prettyplease::unparse(&syntax_tree))
+        OutputStyle::Pretty
+    };
+    let options = TranspilerOptions::builder().style(style).build();
+    Transpiler::with_options(options)
+        .transpile_program(ast)
+        .map(|output| output.code)
+        .with_context(|| "Failed to transpile to Rust")
+}
+
+/// Transpile AST to Rust through the typed MIR, printing the MIR when verbose (complexity: 3)
+pub fn transpile_via_mir(ast: &Expr, verbose: bool) -> Result<String> {
+    let program = mir_codegen::lower_program(ast).with_context(|| "Failed to lower to MIR")?;
+    if verbose {
+        eprintln!("{program}");
     }
+    mir_codegen::generate_rust(&program).with_context(|| "Failed to generate Rust from MIR")
 }
 
 /// Write output to file or stdout (complexity: 5)
@@ -232,17 +269,24 @@ mod tests {
     #[test]
     fn test_handle_transpile_command_nonexistent() {
         let path = Path::new("/nonexistent/file.ruchy");
-        let result = handle_transpile_command(path, None, false, false);
+        let result = handle_transpile_command(path, None, false, false, false, false);
         assert!(result.is_err());
     }
 
     #[test]
     fn test_handle_transpile_command_verbose_nonexistent() {
         let path = Path::new("/nonexistent/file.ruchy");
-        let result = handle_transpile_command(path, None, false, true);
+        let result = handle_transpile_command(path, None, false, false, false, true);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_transpile_via_mir_typed_function() {
+        let ast = parse_source("fun double(x: i32) -> i32 { x * 2 }").unwrap();
+        let code = transpile_via_mir(&ast, false).unwrap();
+        assert!(code.contains("fn double(mut __0: i32) -> i32"));
+    }
+
     #[test]
     fn test_write_output_to_stdout() {
         let rust_code = "fn main() {}";
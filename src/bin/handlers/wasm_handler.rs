@@ -3,6 +3,7 @@
 //! Handles compilation of Ruchy files to WebAssembly.
 
 use anyhow::{Context, Result};
+use ruchy::backend::wasm::WasmMetadata;
 use ruchy::frontend::ast::Expr;
 use ruchy::{Parser as RuchyParser, WasmEmitter};
 use std::path::{Path, PathBuf};
@@ -37,11 +38,25 @@ pub(crate) fn parse_ruchy_source(file: &Path) -> Result<Expr> {
 
 /// Generate and validate WASM bytecode with enterprise-grade analysis
 ///
+/// With `debug`, the module carries a `name` section so devtools show Ruchy
+/// function and local names. With `fuel`, every function entry and loop
+/// iteration is metered so the module traps instead of hanging its host.
+/// With `wasi`, builtins use WASI imports and the module exports `_start`.
+///
 /// # Errors
 /// Returns error if WASM generation or validation fails
-pub(crate) fn generate_and_validate_wasm(ast: &Expr, verbose: bool) -> Result<Vec<u8>> {
+pub(crate) fn generate_and_validate_wasm(
+    ast: &Expr,
+    debug: bool,
+    fuel: Option<u64>,
+    wasi: bool,
+    verbose: bool,
+) -> Result<Vec<u8>> {
     use colored::Colorize;
-    let emitter = WasmEmitter::new();
+    let emitter = WasmEmitter::new()
+        .with_debug_names(debug)
+        .with_fuel(fuel)
+        .with_wasi(wasi);
     let wasm_bytes = emitter
         .emit(ast)
         .map_err(|e| anyhow::anyhow!("Failed to generate WASM: {}", e))?;
@@ -52,10 +67,13 @@ pub(crate) fn generate_and_validate_wasm(ast: &Expr, verbose: bool) -> Result<Ve
         if verbose {
             println!("{} Validating WASM module...", "→".bright_cyan());
         }
-        match wasmparser::validate(&wasm_bytes) {
-            Ok(_) => {
+        match ruchy::backend::wasm::validate::validate_streaming(&wasm_bytes) {
+            Ok(functions) => {
                 if verbose {
-                    println!("{} WASM validation successful", "✓".green());
+                    println!(
+                        "{} WASM validation successful ({functions} functions)",
+                        "✓".green()
+                    );
                     println!("{} Security scan: memory bounds verified", "✓".green());
                     println!("{} Formal verification: type safety confirmed", "✓".green());
                 }
@@ -81,6 +99,11 @@ pub(crate) fn generate_and_validate_wasm(ast: &Expr, verbose: bool) -> Result<Ve
     Ok(wasm_bytes)
 }
 
+/// Whether `target` selects the WASI command module (see `ruchy::backend::wasm::wasi`)
+pub(crate) fn is_wasi_target(target: &str) -> bool {
+    matches!(target, "wasi" | "wasm32-wasi" | "wasm32-wasip1")
+}
+
 /// Determine output path for WASM file
 pub(crate) fn determine_wasm_output_path(file: &Path, output: Option<&Path>) -> PathBuf {
     if let Some(out) = output {
@@ -118,6 +141,38 @@ pub(crate) fn write_wasm_output(
     Ok(())
 }
 
+/// Write the JavaScript host shim for browser and Node.js targets
+///
+/// Emitted modules import `println`, clock and random functions from the
+/// host; `program.wasm` gets a `program.host.js` that provides them.
+///
+/// # Errors
+/// Returns error if file writing fails
+fn write_js_host(output_path: &Path, target: &str, verbose: bool) -> Result<()> {
+    if !matches!(target, "browser" | "nodejs") {
+        return Ok(());
+    }
+    let host_path = output_path.with_extension("host.js");
+    super::write_file_with_context(&host_path, ruchy::backend::wasm::host::HOST_JS.as_bytes())?;
+    if verbose {
+        println!("  Host shim: {}", host_path.display());
+    }
+    Ok(())
+}
+
+/// Append `ruchy.*` provenance sections for `--embed-metadata`
+///
+/// # Errors
+/// Returns error if the source cannot be re-read or the module is malformed
+fn embed_wasm_metadata(file: &Path, ast: &Expr, wasm_bytes: &[u8]) -> Result<Vec<u8>> {
+    let source = super::read_file_with_context(file)?;
+    let diagnostics = ruchy::cli::shared::lint_source(&source, None, false).unwrap_or_default();
+    WasmMetadata::new(&source, ast)
+        .with_diagnostics(diagnostics)
+        .embed(wasm_bytes)
+        .map_err(|e| anyhow::anyhow!("Failed to embed metadata: {}", e))
+}
+
 /// Handle post-compilation optimization and deployment
 fn handle_optimization_and_deployment(
     opt_level: &str,
@@ -161,7 +216,7 @@ pub fn compile_ruchy_to_wasm(file: &Path, verbose: bool) -> Result<PathBuf> {
     let ast = parse_ruchy_source(file)?;
 
     // Generate WASM bytes
-    let wasm_bytes = generate_and_validate_wasm(&ast, verbose)?;
+    let wasm_bytes = generate_and_validate_wasm(&ast, false, None, false, verbose)?;
 
     // Determine output path (.ruchy -> .wasm)
     let output_path = file.with_extension("wasm");
@@ -186,7 +241,10 @@ pub fn handle_wasm_command(
     deploy_target: Option<&str>,
     _portability: bool,
     opt_level: &str,
-    _debug: bool,
+    debug: bool,
+    embed_metadata: bool,
+    fuel: Option<u64>,
+    whole_program: bool,
     _simd: bool,
     _threads: bool,
     _component_model: bool,
@@ -196,9 +254,17 @@ pub fn handle_wasm_command(
 ) -> Result<()> {
     print_wasm_compilation_status(file, target, wit, verbose);
     let ast = parse_ruchy_source(file)?;
-    let wasm_bytes = generate_and_validate_wasm(&ast, verbose)?;
+    let wasi = is_wasi_target(target);
+    let optimized = whole_program
+        .then(|| super::transpile_handler::optimize_whole_program(ast.clone(), verbose));
+    let program = optimized.as_ref().unwrap_or(&ast);
+    let mut wasm_bytes = generate_and_validate_wasm(program, debug, fuel, wasi, verbose)?;
+    if embed_metadata {
+        wasm_bytes = embed_wasm_metadata(file, &ast, &wasm_bytes)?;
+    }
     let output_path = determine_wasm_output_path(file, output);
     write_wasm_output(&wasm_bytes, &output_path, target, verbose)?;
+    write_js_host(&output_path, target, verbose)?;
     handle_optimization_and_deployment(opt_level, deploy, deploy_target, verbose);
     Ok(())
 }
@@ -284,6 +350,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_write_js_host_only_for_js_targets() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let output = dir.path().join("program.wasm");
+        write_js_host(&output, "wasm32", false).unwrap();
+        assert!(!dir.path().join("program.host.js").exists());
+        write_js_host(&output, "browser", false).unwrap();
+        let shim = std::fs::read_to_string(dir.path().join("program.host.js")).unwrap();
+        assert!(shim.contains("ruchy_println_str"));
+    }
+
+    #[test]
+    fn test_wasi_targets() {
+        assert!(is_wasi_target("wasi"));
+        assert!(is_wasi_target("wasm32-wasi"));
+        assert!(!is_wasi_target("wasm32"));
+        assert!(!is_wasi_target("browser"));
+    }
+
+    #[test]
+    fn test_handle_wasm_command_wasi_exports_start() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let source = dir.path().join("hello.ruchy");
+        std::fs::write(&source, "fun main() {\n    println(\"hi\")\n}").unwrap();
+        let output = dir.path().join("hello.wasm");
+        let result = handle_wasm_command(
+            &source,
+            Some(&output),
+            "wasi",
+            false,
+            false,
+            None,
+            false,
+            "0",
+            false,
+            false,
+            None,
+            false,
+            false,
+            false,
+            false,
+            None,
+            "0.1.0",
+            false,
+        );
+        assert!(result.is_ok(), "{result:?}");
+        let wasm = std::fs::read(&output).unwrap();
+        let contains = |text: &str| wasm.windows(text.len()).any(|w| w == text.as_bytes());
+        assert!(contains("wasi_snapshot_preview1"));
+        assert!(contains("_start"));
+        assert!(!dir.path().join("hello.host.js").exists());
+    }
+
     #[test]
     fn test_handle_wasm_command_nonexistent() {
         let result = handle_wasm_command(
@@ -297,6 +416,9 @@ mod tests {
             "0",
             false,
             false,
+            None,
+            false,
+            false,
             false,
             false,
             None,
@@ -318,6 +440,9 @@ mod tests {
             true, // portability
             "3",  // opt_level
             true, // debug
+            true, // embed_metadata
+            Some(1_000),
+            true, // whole_program
             true, // simd
             true, // threads
             true, // component_model
@@ -23,17 +23,26 @@ mod report;
 
 use anyhow::Result;
 use clap::{Parser, Subcommand};
-use ruchy::{runtime::repl::Repl, Parser as RuchyParser};
+use ruchy::runtime::records::StreamFormat;
+use ruchy::Parser as RuchyParser;
 use std::fs;
 use std::io::{self, IsTerminal, Read};
 use std::path::{Path, PathBuf};
 mod handlers;
 use handlers::{
-    handle_check_command, handle_compile_command, handle_complex_command, handle_eval_command,
-    handle_file_execution, handle_fuzz_command, handle_mutations_command, handle_parse_command,
-    handle_property_tests_command, handle_repl_command, handle_run_command, handle_stdin_input,
+    handle_check_command, handle_check_session_command, handle_compile_command,
+    handle_complex_command, handle_doc_test_command, handle_eval_command, handle_file_execution, handle_filter_command,
+    handle_fuzz_command, handle_mutations_command, handle_parity_test_command,
+    handle_parse_command, handle_property_tests_command, handle_repl_command,
+    handle_replay_command, handle_restricted_repl_command, handle_run_command, handle_stdin_input,
     handle_test_command, handle_transpile_command, VmMode,
 };
+
+/// Counts allocations for `ruchy runtime --profile`
+#[global_allocator]
+static ALLOCATOR: ruchy::runtime::profiler::CountingAllocator =
+    ruchy::runtime::profiler::CountingAllocator;
+
 /// Configuration for code formatting
 #[derive(Debug, Clone)]
 struct FormatConfig {
@@ -57,6 +66,11 @@ struct Cli {
     /// Evaluate a one-liner expression
     #[arg(short = 'e', long = "eval", value_name = "EXPR")]
     eval: Option<String>,
+    /// Apply an expression to every stdin line (or FILE's lines), awk-style.
+    /// `line` and `nr` are bound; `true` echoes the line, `false`/nil skip it,
+    /// other values are printed
+    #[arg(long = "filter", value_name = "EXPR", conflicts_with = "eval")]
+    filter: Option<String>,
     /// Output format for evaluation results (text, json)
     #[arg(long, default_value = "text")]
     format: String,
@@ -69,6 +83,18 @@ struct Cli {
     /// VM execution mode: ast (default) or bytecode (experimental, faster)
     #[arg(long, value_enum, default_value = "ast")]
     vm_mode: VmMode,
+    /// Abort script evaluation, or each REPL input, after DURATION (e.g. 500ms,
+    /// 30s, 5m; 0 disables). Overrides RUCHY_TIMEOUT and `[run] timeout` in
+    /// Ruchy.toml; no limit by default
+    #[arg(long, value_name = "DURATION")]
+    timeout: Option<String>,
+    /// Record format read by `io::records()`: text (one string per line) or
+    /// json (one JSON value per line)
+    #[arg(long, value_name = "FORMAT", default_value = "text")]
+    input: StreamFormat,
+    /// Record format written by `emit()` and for the script result: text or json
+    #[arg(long, value_name = "FORMAT", default_value = "text")]
+    output: StreamFormat,
     /// Script file to execute (alternative to subcommands)
     file: Option<PathBuf>,
     #[command(subcommand)]
@@ -81,9 +107,18 @@ enum Commands {
         /// Record REPL session to a .replay file
         #[arg(long, value_name = "FILE")]
         record: Option<PathBuf>,
+        /// Replay the REPL transcripts (`ruchy> ` prompts and their output) in
+        /// a markdown file and fail on any mismatch
+        #[arg(long, value_name = "FILE", conflicts_with = "record")]
+        check_session: Option<PathBuf>,
         /// Maximum recursion depth (RUNTIME-001)
         #[arg(long, default_value = "100")]
         max_depth: usize,
+        /// Untrusted-user mode: no filesystem, process or network builtins,
+        /// a 2s budget per input (or --timeout), capped output and host paths
+        /// hidden in errors
+        #[arg(long, conflicts_with = "max_depth")]
+        restricted: bool,
     },
     /// Create a new Ruchy project with Cargo integration
     New {
@@ -93,11 +128,25 @@ enum Commands {
         #[arg(long)]
         lib: bool,
     },
-    /// Build a Ruchy project (wrapper around cargo build)
+    /// Build the Ruchy.toml project here, or wrap cargo build in a Cargo project
     Build {
         /// Build in release mode with optimizations
         #[arg(long)]
         release: bool,
+        /// Time each compilation phase and write a report to target/ruchy-timings
+        #[arg(long)]
+        timings: bool,
+    },
+    /// Remove the build cache in .ruchy/cache
+    Clean,
+    /// Write a JSON compilation database of a project's modules for external build systems
+    EmitCompilationdb {
+        /// Project directory with a Ruchy.toml, or an entry file
+        #[arg(default_value = ".")]
+        path: PathBuf,
+        /// Output file (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
     },
     /// Parse a Ruchy file and show the AST
     Parse {
@@ -114,11 +163,35 @@ enum Commands {
         /// Use minimal codegen for self-hosting (direct Rust mapping, no optimization)
         #[arg(long)]
         minimal: bool,
+        /// Propagate constants across functions and drop unreachable
+        /// functions before emitting (small programs only)
+        #[arg(long)]
+        whole_program: bool,
+        /// Generate Rust from the typed MIR instead of the AST (functions
+        /// over numbers, bools and strings only)
+        #[arg(long)]
+        via_mir: bool,
     },
     /// Compile and run a Ruchy file
     Run {
         /// The file to run
         file: PathBuf,
+        /// Record inputs, random seed, clock reads and output to a trace for `ruchy replay`
+        #[arg(long, value_name = "TRACE")]
+        record: Option<PathBuf>,
+        /// Export OpenTelemetry spans of function calls and I/O builtins for
+        /// this fraction (0.0-1.0) of top-level calls (needs the `otel` feature)
+        #[arg(long, value_name = "RATE")]
+        otel_sample_rate: Option<f64>,
+        /// Fold constants, remove dead branches and compute loop invariants
+        /// and repeated expressions once before running
+        #[arg(short = 'O', long)]
+        optimize: bool,
+    },
+    /// Re-execute a run recorded with `ruchy run --record` deterministically
+    Replay {
+        /// Trace written by `ruchy run --record`
+        trace: PathBuf,
     },
     /// Compile a Ruchy file to a standalone binary (RUCHY-0801)
     Compile {
@@ -127,7 +200,9 @@ enum Commands {
         /// Output binary path
         #[arg(short, long, default_value = "a.out")]
         output: PathBuf,
-        /// Optimization level (0-3, or 's' for size)
+        /// Optimization level (0-3, or 's' for size); every level but 0 also
+        /// folds constants, removes dead branches and hoists loop invariants
+        /// and common subexpressions before transpiling
         #[arg(short = 'O', long, default_value = "2")]
         opt_level: String,
         /// High-level optimization preset (OPTIMIZATION-001)
@@ -158,6 +233,18 @@ enum Commands {
         /// Can be specified multiple times: --embed-model a.safetensors --embed-model b.gguf
         #[arg(long = "embed-model", value_name = "FILE")]
         embed_models: Vec<PathBuf>,
+        /// Always transpile and run rustc, ignoring the build cache in .ruchy/cache
+        #[arg(long)]
+        no_cache: bool,
+        /// Propagate constants across functions and drop unreachable
+        /// functions before transpiling (small programs only)
+        #[arg(long)]
+        whole_program: bool,
+        /// Reproducible build: require the rustc pinned by `[build] rustc` in
+        /// Ruchy.toml, build offline from sources kept in <output>.vendor and
+        /// write <output>.build-info.json
+        #[arg(long, conflicts_with = "pgo")]
+        hermetic: bool,
     },
     /// Check syntax without running
     Check {
@@ -208,9 +295,24 @@ enum Commands {
         /// Mutation testing
         #[arg(long)]
         mutations: bool,
+        /// Check that stdlib functions give the same results interpreted and compiled
+        #[arg(long)]
+        parity: bool,
+        /// Random calls per stdlib function for --parity
+        #[arg(long, default_value = "32", requires = "parity")]
+        parity_cases: usize,
+        /// Seed for the --parity inputs, to reproduce a run
+        #[arg(long, requires = "parity")]
+        parity_seed: Option<u64>,
+        /// Run the examples in doc comments of the .ruchy files under the path
+        #[arg(long, conflicts_with = "parity")]
+        doc: bool,
     },
     /// Launch interactive notebook server
+    #[command(args_conflicts_with_subcommands = true)]
     Notebook {
+        #[command(subcommand)]
+        command: Option<NotebookCommands>,
         /// Optional file to validate in non-interactive mode (TOOL-VALIDATION-003)
         file: Option<PathBuf>,
         /// Port to run the server on
@@ -249,6 +351,15 @@ enum Commands {
         /// Watch .ruchy files and rebuild WASM
         #[arg(long)]
         watch_wasm: bool,
+        /// Evaluate posted snippets at /api/eval in restricted interpreters
+        #[arg(long)]
+        eval_api: bool,
+        /// Evaluation requests allowed per client IP and minute
+        #[arg(long, default_value = "60", requires = "eval_api")]
+        eval_rate_limit: u32,
+        /// Time budget of one evaluation in milliseconds
+        #[arg(long, default_value = "2000", requires = "eval_api")]
+        eval_timeout: u64,
     },
     /// Generate coverage report for Ruchy code
     Coverage {
@@ -283,6 +394,10 @@ enum Commands {
         /// Analyze module dependencies
         #[arg(long)]
         deps: bool,
+        /// Classify each function as pure or by its effects (IO, state, async,
+        /// diverging); combine with --json for machine-readable output
+        #[arg(long)]
+        effects: bool,
         /// Show verbose analysis output
         #[arg(long)]
         verbose: bool,
@@ -326,9 +441,12 @@ enum Commands {
         /// Profile transpiled binary instead of interpreter (PROFILING-001, Issue #138)
         #[arg(long)]
         binary: bool,
-        /// Number of profiling iterations (default: 1 for binary, 10 for interpreter)
+        /// Number of profiling iterations (default: 1)
         #[arg(long)]
         iterations: Option<usize>,
+        /// Interpreter profile format: text, json, or folded (flamegraph stacks)
+        #[arg(long, default_value = "text")]
+        format: String,
         /// Automatic `BigO` algorithmic complexity analysis
         #[arg(long)]
         bigo: bool,
@@ -338,7 +456,7 @@ enum Commands {
         /// Compare performance between two files
         #[arg(long)]
         compare: Option<PathBuf>,
-        /// Memory usage and allocation analysis
+        /// Memory held by each binding when the script finishes, and the heap peak
         #[arg(long)]
         memory: bool,
         /// Show verbose performance output
@@ -414,7 +532,7 @@ enum Commands {
     },
     /// Format Ruchy source code (Enhanced for v0.9.12)
     Fmt {
-        /// The file to format
+        /// The file to format (`-` reads stdin and writes stdout)
         file: PathBuf,
         /// Format all files in project
         #[arg(long)]
@@ -440,6 +558,12 @@ enum Commands {
         /// Use tabs instead of spaces for indentation
         #[arg(long)]
         use_tabs: bool,
+        /// Only format items overlapping these lines (1-based, inclusive)
+        #[arg(long, value_name = "START:END", conflicts_with = "byte_range")]
+        range: Option<String>,
+        /// Only format items overlapping these byte offsets (END exclusive)
+        #[arg(long, value_name = "START:END")]
+        byte_range: Option<String>,
     },
     /// Generate documentation from Ruchy source code
     Doc {
@@ -466,15 +590,16 @@ enum Commands {
     },
     /// Benchmark Ruchy code performance
     Bench {
-        /// The file to benchmark
-        file: PathBuf,
+        /// The file to benchmark (not needed with --engines)
+        #[arg(required_unless_present = "engines")]
+        file: Option<PathBuf>,
         /// Number of iterations to run
         #[arg(long, default_value = "100")]
         iterations: usize,
         /// Number of warmup iterations
         #[arg(long, default_value = "10")]
         warmup: usize,
-        /// Output format (text, json, csv)
+        /// Output format (text, json, csv, markdown)
         #[arg(long, default_value = "text")]
         format: String,
         /// Save results to file
@@ -483,6 +608,22 @@ enum Commands {
         /// Show verbose output including individual runs
         #[arg(long)]
         verbose: bool,
+        /// Compare medians against this JSON report and fail on regression
+        #[arg(long, value_name = "FILE", conflicts_with = "engines")]
+        baseline: Option<PathBuf>,
+        /// Write this run to the --baseline file instead of comparing
+        #[arg(long, requires = "baseline")]
+        save_baseline: bool,
+        /// Median slowdown, in percent, that counts as a regression
+        #[arg(long, value_name = "PERCENT", default_value = "10")]
+        max_regression: f64,
+        /// Run the built-in benchmark corpus on every execution engine
+        #[arg(long, conflicts_with = "file")]
+        engines: bool,
+        /// Append --engines results to this JSON Lines file and compare
+        /// against its previous run
+        #[arg(long, value_name = "PATH")]
+        history: Option<PathBuf>,
     },
     /// Lint Ruchy source code for issues and style violations (Enhanced for v0.9.12)
     Lint {
@@ -509,33 +650,43 @@ enum Commands {
         /// Fail on warnings as well as errors
         #[arg(long)]
         deny_warnings: bool,
-        /// Maximum allowed complexity for functions
-        #[arg(long, default_value = "10")]
-        max_complexity: usize,
-        /// Path to custom lint rules configuration file
+        /// Maximum allowed complexity for functions (default: `max_complexity`
+        /// from .ruchylint.toml, or 10)
+        #[arg(long)]
+        max_complexity: Option<usize>,
+        /// Lint configuration file (default: .ruchylint.toml in the file's
+        /// directory or its nearest ancestor that has one)
         #[arg(long)]
         config: Option<PathBuf>,
-        /// Generate default lint configuration file
+        /// Write a .ruchylint.toml listing every rule at its default level
+        /// (to --config, if given)
         #[arg(long)]
         init_config: bool,
     },
     /// Add a package dependency
+    ///
+    /// In a Ruchy.toml project, fetches a Ruchy package from the registry,
+    /// records it under [packages], locks it in Ruchy.lock and vendors it
+    /// into vendor/. In a Cargo project, runs `cargo add`.
     Add {
         /// Package name to add
         package: String,
-        /// Specific version to add (default: latest)
+        /// Version requirement to add (default: latest)
         #[arg(long)]
         version: Option<String>,
-        /// Add as development dependency
+        /// Add as development dependency (Cargo projects only)
         #[arg(long)]
         dev: bool,
-        /// Registry URL to use
+        /// Registry URL or directory to use
         #[arg(long, default_value = "https://ruchy.dev/registry")]
         registry: String,
     },
     /// Publish a package to the registry
+    ///
+    /// Uploads Ruchy.toml and src/**/*.ruchy as a tarball. HTTP registries
+    /// read the API token from RUCHY_REGISTRY_TOKEN.
     Publish {
-        /// Registry URL to publish to
+        /// Registry URL or directory to publish to
         #[arg(long, default_value = "https://ruchy.dev/registry")]
         registry: String,
         /// Package version to publish (reads from Ruchy.toml if not specified)
@@ -733,9 +884,21 @@ enum Commands {
         /// Optimization level (none, O1, O2, O3, Os, Oz)
         #[arg(long, default_value = "O2")]
         opt_level: String,
-        /// Include debug information
+        /// Emit a `name` section so devtools show Ruchy function and local names
         #[arg(long)]
         debug: bool,
+        /// Embed the AST, source hash, compiler version and lint diagnostics
+        /// as `ruchy.*` custom sections
+        #[arg(long)]
+        embed_metadata: bool,
+        /// Meter the module with this much fuel (one unit per call and loop
+        /// iteration); it traps when the fuel runs out instead of hanging
+        #[arg(long, value_name = "UNITS")]
+        fuel: Option<u64>,
+        /// Propagate constants across functions and drop unreachable
+        /// functions before lowering (small programs only)
+        #[arg(long)]
+        whole_program: bool,
         /// Enable SIMD instructions
         #[arg(long)]
         simd: bool,
@@ -941,6 +1104,22 @@ enum Commands {
         #[arg(long)]
         dry_run: bool,
     },
+    /// Check a project against this Ruchy release and apply the automatic fixes
+    ///
+    /// Lists breaking changes since the release `[package] ruchy` in
+    /// Ruchy.toml targets, with their fixes. Without --dry-run, rewrites the
+    /// fixed files (keeping `<file>.bak` backups) and re-runs the tests.
+    Upgrade {
+        /// Project directory or file
+        #[arg(default_value = ".")]
+        path: PathBuf,
+        /// List the findings without changing any file
+        #[arg(long)]
+        dry_run: bool,
+        /// Don't re-run the project's tests after applying the fixes
+        #[arg(long)]
+        no_test: bool,
+    },
     /// Report §14.2 tier distribution for a path (Bronze/Silver/Gold/Platinum)
     ///
     /// Scans .ruchy files under <path> and prints tier counts + non-bronze
@@ -1026,6 +1205,22 @@ enum Commands {
     },
 }
 
+/// Notebook subcommands
+#[derive(Subcommand, Debug)]
+enum NotebookCommands {
+    /// Export a notebook to a script or a static report
+    Export {
+        /// Notebook file (.rnb)
+        file: PathBuf,
+        /// Target format (script, html, markdown)
+        #[arg(long, default_value = "html")]
+        to: String,
+        /// Output file (defaults to the notebook path with the format's extension)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
 /// Oracle subcommands for ML model management
 #[derive(Subcommand)]
 enum OracleCommands {
@@ -1306,23 +1501,36 @@ enum ContractsCommands {
 fn main() -> Result<()> {
     // CLI-UNIFY-001: No args → open REPL directly (like python, ruby, node)
     if std::env::args().len() == 1 {
-        return handle_repl_command(None, 100);
+        return handle_repl_command(None, 100, None);
     }
 
     let cli = Cli::parse();
+    if let Some(timeout) = &cli.timeout {
+        ruchy::runtime::script::parse_timeout(timeout).map_err(anyhow::Error::msg)?;
+    }
+    ruchy::runtime::records::set_stream_formats(cli.input, cli.output);
     // Try to handle direct evaluation first
     if let Some(result) = try_handle_direct_evaluation(&cli) {
-        return result;
+        return exit_with_script_status(result);
     }
     // Try to handle stdin input
     if let Some(result) = try_handle_stdin(cli.command.as_ref())? {
         return result;
     }
     // Handle subcommands
-    handle_command_dispatch(cli.command, cli.verbose, cli.vm_mode)
+    handle_command_dispatch(
+        cli.command,
+        cli.verbose,
+        cli.vm_mode,
+        cli.timeout.as_deref(),
+    )
 }
-/// Handle direct evaluation via -e flag or file argument (complexity: 4)
+/// Handle direct evaluation via -e, --filter or file argument (complexity: 5)
 fn try_handle_direct_evaluation(cli: &Cli) -> Option<Result<()>> {
+    // Per-line filter mode; a file argument is the input, not a script
+    if let Some(expr) = &cli.filter {
+        return Some(handle_filter_command(expr, cli.file.as_deref()));
+    }
     // Handle one-liner evaluation with -e flag
     if let Some(expr) = &cli.eval {
         return Some(handle_eval_command(
@@ -1330,14 +1538,27 @@ fn try_handle_direct_evaluation(cli: &Cli) -> Option<Result<()>> {
             cli.verbose,
             &cli.format,
             cli.trace,
+            cli.timeout.as_deref(),
         ));
     }
     // Handle script file execution (without subcommand)
     if let Some(file) = &cli.file {
-        return Some(handle_file_execution(file));
+        return Some(handle_file_execution(file, cli.timeout.as_deref()));
     }
     None
 }
+/// Exit with the script exit code when evaluation failed (complexity: 2)
+///
+/// The error has already been reported by the handler; see
+/// `ruchy::runtime::script` for the exit code table.
+fn exit_with_script_status(result: Result<()>) -> Result<()> {
+    if let Err(e) = &result {
+        if let Some(script_error) = e.downcast_ref::<ruchy::runtime::script::ScriptError>() {
+            std::process::exit(script_error.exit_code());
+        }
+    }
+    result
+}
 /// Handle stdin input if present (complexity: 5)
 fn try_handle_stdin(command: Option<&Commands>) -> Result<Option<Result<()>>> {
     // Check if stdin has input (piped mode) - but only when no command is specified
@@ -1351,16 +1572,41 @@ fn try_handle_stdin(command: Option<&Commands>) -> Result<Option<Result<()>>> {
     Ok(None)
 }
 /// Dispatch commands to appropriate handlers (complexity: 6)
+///
+/// `timeout` is the `--timeout` flag, which applies to the scripts `run`
+/// and `replay` evaluate and to each input of `repl`.
 fn handle_command_dispatch(
     command: Option<Commands>,
     verbose: bool,
     vm_mode: VmMode,
+    timeout: Option<&str>,
 ) -> Result<()> {
     match command {
-        Some(Commands::Repl { record, max_depth }) => handle_repl_command(record, max_depth),
+        Some(Commands::Repl {
+            check_session: Some(file),
+            max_depth,
+            restricted,
+            ..
+        }) => handle_check_session_command(&file, restricted, max_depth, timeout),
+        Some(Commands::Repl {
+            record,
+            restricted: true,
+            ..
+        }) => handle_restricted_repl_command(record, timeout),
+        Some(Commands::Repl {
+            record, max_depth, ..
+        }) => handle_repl_command(record, max_depth, timeout),
         Some(Commands::New { name, lib }) => handlers::new::handle_new_command(&name, lib, verbose),
-        Some(Commands::Build { release }) => {
-            handlers::build::handle_build_command(release, verbose)
+        Some(Commands::Build { release, timings }) => {
+            handlers::build::handle_build_command(release, timings, verbose)
+        }
+        Some(Commands::Clean) => handlers::clean::handle_clean_command(verbose),
+        Some(Commands::EmitCompilationdb { path, output }) => {
+            handlers::compilation_db::handle_emit_compilationdb_command(
+                &path,
+                output.as_deref(),
+                verbose,
+            )
         }
         Some(Commands::Publish {
             registry,
@@ -1374,14 +1620,37 @@ fn handle_command_dispatch(
             allow_dirty,
             verbose,
         ),
-        None => handle_repl_command(None, 100),
+        None => handle_repl_command(None, 100, timeout),
         Some(Commands::Parse { file }) => handle_parse_command(&file, verbose),
         Some(Commands::Transpile {
             file,
             output,
             minimal,
-        }) => handle_transpile_command(&file, output.as_deref(), minimal, verbose),
-        Some(Commands::Run { file }) => handle_run_command(&file, verbose, vm_mode),
+            whole_program,
+            via_mir,
+        }) => handle_transpile_command(
+            &file,
+            output.as_deref(),
+            minimal,
+            whole_program,
+            via_mir,
+            verbose,
+        ),
+        Some(Commands::Run {
+            file,
+            record,
+            otel_sample_rate,
+            optimize,
+        }) => handle_run_command(
+            &file,
+            record.as_deref(),
+            otel_sample_rate,
+            optimize,
+            verbose,
+            vm_mode,
+            timeout,
+        ),
+        Some(Commands::Replay { trace }) => handle_replay_command(&trace, verbose, timeout),
         Some(Commands::Compile {
             file,
             output,
@@ -1395,6 +1664,9 @@ fn handle_command_dispatch(
             show_profile_info,
             pgo,
             embed_models,
+            no_cache,
+            whole_program,
+            hermetic,
         }) => handle_compile_command(
             &file,
             output,
@@ -1408,8 +1680,34 @@ fn handle_command_dispatch(
             show_profile_info,
             pgo,
             embed_models,
+            no_cache,
+            whole_program,
+            hermetic,
         ),
         Some(Commands::Check { files, watch }) => handle_check_command(&files, watch),
+        Some(Commands::Test {
+            parity: true,
+            verbose,
+            filter,
+            format,
+            parity_cases,
+            parity_seed,
+            ..
+        }) => handle_parity_test_command(
+            filter.as_deref(),
+            parity_cases,
+            parity_seed,
+            &format,
+            verbose,
+        ),
+        Some(Commands::Test {
+            doc: true,
+            path,
+            verbose,
+            filter,
+            format,
+            ..
+        }) => handle_doc_test_command(path.as_deref(), filter.as_deref(), &format, verbose),
         Some(Commands::Test {
             path,
             watch,
@@ -1424,6 +1722,10 @@ fn handle_command_dispatch(
             playbook: _,
             visual_regression: _,
             mutations: _,
+            parity: false,
+            parity_cases: _,
+            parity_seed: _,
+            doc: false,
         }) => handle_test_dispatch(
             path,
             watch,
@@ -2082,19 +2384,11 @@ Generated by Ruchy Hunt Mode (Issue #171)
 
 fn run_file(file: &Path) -> Result<()> {
     let source = fs::read_to_string(file)?;
-    let mut repl = Repl::new(std::env::temp_dir())?;
-    match repl.eval(&source) {
-        Ok(result) => {
-            if result != "Unit" && result != "()" {
-                println!("{result}");
-            }
-            Ok(())
-        }
-        Err(e) => {
-            eprintln!("Error: {e}");
-            std::process::exit(1);
-        }
-    }
+    let options = ruchy::runtime::script::ScriptOptions::for_file(file)
+        .with_configured_timeout()
+        .map_err(anyhow::Error::msg)?;
+    handlers::run_script_or_exit(&source, &options);
+    Ok(())
 }
 
 fn check_syntax(file: &Path) -> Result<()> {
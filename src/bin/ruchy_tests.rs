@@ -27,9 +27,13 @@ fn test_format_config_creation() {
 fn test_try_handle_direct_evaluation_with_eval() {
     let cli = Cli {
         eval: Some("1 + 1".to_string()),
+        filter: None,
         format: "text".to_string(),
         verbose: false,
         vm_mode: VmMode::Ast,
+        timeout: None,
+        input: StreamFormat::Text,
+        output: StreamFormat::Text,
         file: None,
         command: None,
         trace: false,
@@ -46,9 +50,13 @@ fn test_try_handle_direct_evaluation_with_file() {
 
     let cli = Cli {
         eval: None,
+        filter: None,
         format: "text".to_string(),
         verbose: false,
         vm_mode: VmMode::Ast,
+        timeout: None,
+        input: StreamFormat::Text,
+        output: StreamFormat::Text,
         file: Some(temp_file.path().to_path_buf()),
         command: None,
         trace: false,
@@ -61,9 +69,13 @@ fn test_try_handle_direct_evaluation_with_file() {
 fn test_try_handle_direct_evaluation_none() {
     let cli = Cli {
         eval: None,
+        filter: None,
         format: "text".to_string(),
         verbose: false,
         vm_mode: VmMode::Ast,
+        timeout: None,
+        input: StreamFormat::Text,
+        output: StreamFormat::Text,
         file: None,
         command: None,
         trace: false,
@@ -82,7 +94,9 @@ fn test_try_handle_stdin_no_command() {
 fn test_try_handle_stdin_with_command() {
     let command = Commands::Repl {
         record: None,
+        check_session: None,
         max_depth: 100,
+        restricted: false,
     };
     let result = try_handle_stdin(Some(&command));
     assert!(result.is_ok());
@@ -174,7 +188,9 @@ fn test_handle_test_dispatch_with_filter() {
 fn test_handle_advanced_command_repl() {
     let command = Commands::Repl {
         record: None,
+        check_session: None,
         max_depth: 100,
+        restricted: false,
     };
     let result = handle_advanced_command(command);
     assert!(result.is_ok());
@@ -201,6 +217,8 @@ fn test_handle_advanced_command_transpile() {
         file: temp_file.path().to_path_buf(),
         output: None,
         minimal: false,
+        whole_program: false,
+        via_mir: false,
     };
     let result = handle_advanced_command(command);
     assert!(result.is_ok());
@@ -224,6 +242,9 @@ fn test_handle_advanced_command_compile() {
         show_profile_info: false,
         pgo: false,
         embed_models: Vec::new(),
+        no_cache: true,
+        whole_program: false,
+        hermetic: false,
     };
     let result = handle_advanced_command(command);
     assert!(result.is_ok());
@@ -246,6 +267,7 @@ fn test_handle_advanced_command_check() {
 #[ignore = "notebook server test runs too long for fast tests"]
 fn test_handle_advanced_command_notebook() {
     let command = Commands::Notebook {
+        command: None,
         file: None,
         port: 8080,
         open: false,
@@ -288,6 +310,7 @@ fn test_handle_advanced_command_ast() {
         metrics: false,
         symbols: false,
         deps: false,
+        effects: false,
         verbose: false,
         output: None,
     };
@@ -308,6 +331,7 @@ fn test_handle_advanced_command_ast_with_options() {
         metrics: true,
         symbols: true,
         deps: true,
+        effects: true,
         verbose: true,
         output: Some(output_file.path().to_path_buf()),
     };
@@ -348,12 +372,17 @@ fn test_handle_advanced_command_bench() {
     fs::write(&temp_file, "let x = 42").expect("Failed to write test content to temporary file");
 
     let command = Commands::Bench {
-        file: temp_file.path().to_path_buf(),
+        file: Some(temp_file.path().to_path_buf()),
         iterations: 10,
         warmup: 5,
         format: "json".to_string(),
         output: None,
         verbose: false,
+        baseline: None,
+        save_baseline: false,
+        max_regression: 10.0,
+        engines: false,
+        history: None,
     };
     let result = handle_advanced_command(command);
     assert!(result.is_ok());
@@ -373,7 +402,7 @@ fn test_handle_advanced_command_lint() {
         format: "text".to_string(),
         rules: None,
         deny_warnings: false,
-        max_complexity: 10,
+        max_complexity: None,
         config: None,
         init_config: false,
     };
@@ -444,6 +473,9 @@ fn test_handle_advanced_command_wasm() {
         portability: false,
         opt_level: "O2".to_string(),
         debug: false,
+        embed_metadata: false,
+        fuel: None,
+        whole_program: false,
         simd: false,
         threads: false,
         component_model: true,
@@ -460,17 +492,20 @@ fn test_handle_command_dispatch_repl() {
     let result = handle_command_dispatch(
         Some(Commands::Repl {
             record: None,
+            check_session: None,
             max_depth: 100,
+            restricted: false,
         }),
         false,
         VmMode::Ast,
+        None,
     );
     assert!(result.is_ok());
 }
 
 #[test]
 fn test_handle_command_dispatch_none() {
-    let result = handle_command_dispatch(None, false, VmMode::Ast);
+    let result = handle_command_dispatch(None, false, VmMode::Ast, None);
     assert!(result.is_ok());
 }
 
@@ -485,6 +520,7 @@ fn test_handle_command_dispatch_parse() {
         }),
         false,
         VmMode::Ast,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -499,9 +535,12 @@ fn test_handle_command_dispatch_transpile() {
             file: temp_file.path().to_path_buf(),
             output: None,
             minimal: false,
+            whole_program: false,
+            via_mir: false,
         }),
         true,
         VmMode::Ast,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -514,9 +553,13 @@ fn test_handle_command_dispatch_run() {
     let result = handle_command_dispatch(
         Some(Commands::Run {
             file: temp_file.path().to_path_buf(),
+            record: None,
+            otel_sample_rate: None,
+            optimize: false,
         }),
         false,
         VmMode::Ast,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -0,0 +1,445 @@
+//! Compilation telemetry for `ruchy build --timings`
+//!
+//! Runs every `.ruchy` file of a project through the front end, timing each
+//! phase (lex, parse, infer, transpile) and recording the parser's arena and
+//! string interner statistics, then times the `rustc` build itself. The
+//! result is rendered as a text summary, JSON, and an HTML waterfall with one
+//! row per file on a shared time axis.
+//!
+//! Timing is a measurement pass: a file that fails to parse records the error
+//! and skips the later phases, and type inference errors are ignored, since
+//! the build itself reports both.
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::time::Instant;
+
+use crate::backend::transpiler::Transpiler;
+use crate::frontend::lexer::TokenStream;
+use crate::frontend::parser::Parser;
+use crate::middleend::InferenceContext;
+use crate::runtime::eval_rich_output::escape_html;
+
+/// A timed compilation phase
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    /// Tokenizing the source
+    Lex,
+    /// Building the AST
+    Parse,
+    /// Type inference
+    Infer,
+    /// Generating and formatting Rust code
+    Transpile,
+    /// Compiling the generated Rust with cargo
+    Rustc,
+}
+
+impl Phase {
+    /// All phases, in pipeline order
+    pub const ALL: [Self; 5] = [
+        Self::Lex,
+        Self::Parse,
+        Self::Infer,
+        Self::Transpile,
+        Self::Rustc,
+    ];
+
+    /// Name used in reports
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Lex => "lex",
+            Self::Parse => "parse",
+            Self::Infer => "infer",
+            Self::Transpile => "transpile",
+            Self::Rustc => "rustc",
+        }
+    }
+
+    /// Bar color in the HTML waterfall
+    fn color(self) -> &'static str {
+        match self {
+            Self::Lex => "#8fbcdb",
+            Self::Parse => "#4a90c2",
+            Self::Infer => "#9b7fc8",
+            Self::Transpile => "#e3a04f",
+            Self::Rustc => "#c85a54",
+        }
+    }
+}
+
+/// When a phase ran, relative to the start of the build
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct PhaseTiming {
+    pub phase: Phase,
+    /// Milliseconds from the start of the build
+    pub start_ms: f64,
+    pub duration_ms: f64,
+}
+
+/// Arena and string interner statistics of the parser after parsing a file
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ParserStats {
+    /// Bytes allocated in the AST arena
+    pub arena_allocated: usize,
+    /// Nodes held by the AST arena
+    pub arena_items: usize,
+    /// Distinct interned strings
+    pub interned_strings: usize,
+    /// Total bytes of the interned strings
+    pub interned_bytes: usize,
+}
+
+/// Front end timings of one source file
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FileTimings {
+    pub path: String,
+    /// Source size in bytes
+    pub bytes: usize,
+    pub tokens: usize,
+    /// Phases that ran, in order
+    pub phases: Vec<PhaseTiming>,
+    pub parser: ParserStats,
+    /// Why the pipeline stopped early, if it did
+    pub error: Option<String>,
+}
+
+impl FileTimings {
+    /// Time spent on this file in milliseconds
+    pub fn total_ms(&self) -> f64 {
+        self.phases.iter().map(|timing| timing.duration_ms).sum()
+    }
+
+    /// Time spent in `phase`, if it ran
+    pub fn phase_ms(&self, phase: Phase) -> Option<f64> {
+        self.phases
+            .iter()
+            .find(|timing| timing.phase == phase)
+            .map(|timing| timing.duration_ms)
+    }
+}
+
+/// Timings of a whole build
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct BuildTimings {
+    pub files: Vec<FileTimings>,
+    /// The cargo build, when it was timed
+    pub rustc: Option<PhaseTiming>,
+    /// Wall-clock milliseconds from the start of the build to `finish`
+    pub total_ms: f64,
+}
+
+impl BuildTimings {
+    /// Milliseconds spent in `phase`, summed over all files
+    pub fn phase_total_ms(&self, phase: Phase) -> f64 {
+        if phase == Phase::Rustc {
+            return self.rustc.map_or(0.0, |timing| timing.duration_ms);
+        }
+        self.files
+            .iter()
+            .filter_map(|file| file.phase_ms(phase))
+            .sum()
+    }
+
+    /// Plain text summary: per-phase totals, then the slowest files
+    pub fn render_text(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "Build timings: {} file(s), {:.2} ms total",
+            self.files.len(),
+            self.total_ms
+        );
+        out.push('\n');
+        let _ = writeln!(out, "{:<10} {:>12} {:>7}", "phase", "time (ms)", "share");
+        for phase in Phase::ALL {
+            let ms = self.phase_total_ms(phase);
+            let share = if self.total_ms > 0.0 {
+                ms / self.total_ms * 100.0
+            } else {
+                0.0
+            };
+            let _ = writeln!(out, "{:<10} {ms:>12.2} {share:>6.1}%", phase.name());
+        }
+        if self.files.is_empty() {
+            return out;
+        }
+        let mut slowest: Vec<&FileTimings> = self.files.iter().collect();
+        slowest.sort_by(|a, b| b.total_ms().total_cmp(&a.total_ms()));
+        out.push('\n');
+        let _ = writeln!(
+            out,
+            "{:<40} {:>10} {:>8} {:>8} {:>8} {:>10}",
+            "file", "time (ms)", "bytes", "tokens", "nodes", "interned"
+        );
+        for file in slowest.iter().take(10) {
+            let _ = writeln!(
+                out,
+                "{:<40} {:>10.2} {:>8} {:>8} {:>8} {:>10}",
+                file.path,
+                file.total_ms(),
+                file.bytes,
+                file.tokens,
+                file.parser.arena_items,
+                file.parser.interned_strings
+            );
+            if let Some(error) = &file.error {
+                let _ = writeln!(out, "  stopped: {error}");
+            }
+        }
+        out
+    }
+
+    /// Pretty-printed JSON form
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).context("Failed to serialize build timings")
+    }
+
+    /// Self-contained HTML page with a waterfall of every phase
+    pub fn render_html(&self) -> String {
+        let scale = if self.total_ms > 0.0 {
+            100.0 / self.total_ms
+        } else {
+            0.0
+        };
+        let mut html = String::from(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Ruchy Build Timings</title>\n<style>\n\
+             body { font-family: sans-serif; margin: 20px; }\n\
+             .row { display: flex; align-items: center; height: 22px; }\n\
+             .label { width: 320px; font-family: monospace; overflow: hidden; \
+             text-overflow: ellipsis; white-space: nowrap; }\n\
+             .track { position: relative; flex: 1; height: 16px; background: #f4f4f4; }\n\
+             .bar { position: absolute; top: 0; height: 16px; min-width: 1px; }\n\
+             .legend span { display: inline-block; padding: 2px 8px; margin-right: 6px; color: #fff; }\n\
+             table { border-collapse: collapse; margin-top: 20px; }\n\
+             td, th { border: 1px solid #ddd; padding: 4px 10px; text-align: right; }\n\
+             </style>\n</head>\n<body>\n<h1>Ruchy Build Timings</h1>\n",
+        );
+        let _ = writeln!(
+            html,
+            "<p>{} file(s), {:.2} ms total</p>",
+            self.files.len(),
+            self.total_ms
+        );
+        html.push_str("<div class=\"legend\">");
+        for phase in Phase::ALL {
+            let _ = write!(
+                html,
+                "<span style=\"background:{}\">{}</span>",
+                phase.color(),
+                phase.name()
+            );
+        }
+        html.push_str("</div>\n");
+        for file in &self.files {
+            waterfall_row(&mut html, &file.path, &file.phases, scale);
+        }
+        if let Some(rustc) = self.rustc {
+            waterfall_row(&mut html, "cargo build", &[rustc], scale);
+        }
+        html.push_str("<table>\n<tr><th>phase</th><th>time (ms)</th></tr>\n");
+        for phase in Phase::ALL {
+            let _ = writeln!(
+                html,
+                "<tr><td>{}</td><td>{:.2}</td></tr>",
+                phase.name(),
+                self.phase_total_ms(phase)
+            );
+        }
+        html.push_str("</table>\n</body>\n</html>\n");
+        html
+    }
+}
+
+/// One waterfall row: a label and a bar per phase, positioned in percent of the build
+fn waterfall_row(html: &mut String, label: &str, phases: &[PhaseTiming], scale: f64) {
+    let label = escape_html(label);
+    let _ = write!(
+        html,
+        "<div class=\"row\"><div class=\"label\" title=\"{label}\">{label}</div><div class=\"track\">"
+    );
+    for timing in phases {
+        let _ = write!(
+            html,
+            "<div class=\"bar\" style=\"left:{:.3}%;width:{:.3}%;background:{}\" title=\"{} {:.2} ms\"></div>",
+            timing.start_ms * scale,
+            timing.duration_ms * scale,
+            timing.phase.color(),
+            timing.phase.name(),
+            timing.duration_ms
+        );
+    }
+    html.push_str("</div></div>\n");
+}
+
+/// Collects timings while a build runs
+#[derive(Debug)]
+pub struct BuildTimer {
+    start: Instant,
+    timings: BuildTimings,
+}
+
+impl BuildTimer {
+    /// Start timing a build now
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            timings: BuildTimings::default(),
+        }
+    }
+
+    /// Milliseconds since the build started
+    fn elapsed_ms(&self) -> f64 {
+        self.start.elapsed().as_secs_f64() * 1000.0
+    }
+
+    /// Run `f`, recording it as `phase`
+    fn time<T>(&self, phase: Phase, phases: &mut Vec<PhaseTiming>, f: impl FnOnce() -> T) -> T {
+        let start_ms = self.elapsed_ms();
+        let value = f();
+        phases.push(PhaseTiming {
+            phase,
+            start_ms,
+            duration_ms: self.elapsed_ms() - start_ms,
+        });
+        value
+    }
+
+    /// Time the front end phases on `source`, reported under `path`
+    pub fn time_source(&mut self, path: &str, source: &str) -> &FileTimings {
+        let mut phases = Vec::new();
+        let tokens = self.time(Phase::Lex, &mut phases, || {
+            let mut stream = TokenStream::new(source);
+            std::iter::from_fn(|| stream.next()).count()
+        });
+        let mut parser = Parser::new(source);
+        let parsed = self.time(Phase::Parse, &mut phases, || parser.parse());
+        let (arena_allocated, arena_items) = parser.arena_stats();
+        let (interned_strings, interned_bytes) = parser.interner_stats();
+        let error = match parsed {
+            Ok(ast) => {
+                self.time(Phase::Infer, &mut phases, || {
+                    InferenceContext::new().infer(&ast).ok()
+                });
+                self.time(Phase::Transpile, &mut phases, || transpile(&ast))
+                    .err()
+                    .map(|e| format!("transpile: {e}"))
+            }
+            Err(e) => Some(format!("parse: {e}")),
+        };
+        self.timings.files.push(FileTimings {
+            path: path.to_string(),
+            bytes: source.len(),
+            tokens,
+            phases,
+            parser: ParserStats {
+                arena_allocated,
+                arena_items,
+                interned_strings,
+                interned_bytes,
+            },
+            error,
+        });
+        self.timings.files.last().expect("timings were just pushed")
+    }
+
+    /// Read and time the source file at `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read
+    pub fn time_file(&mut self, path: &Path) -> Result<&FileTimings> {
+        let source = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read file: {}", path.display()))?;
+        Ok(self.time_source(&path.display().to_string(), &source))
+    }
+
+    /// Run the cargo build `f`, recording it as the `rustc` phase
+    pub fn time_rustc<T>(&mut self, f: impl FnOnce() -> T) -> T {
+        let mut phases = Vec::new();
+        let value = self.time(Phase::Rustc, &mut phases, f);
+        self.timings.rustc = phases.pop();
+        value
+    }
+
+    /// Stop the clock and return the timings
+    pub fn finish(mut self) -> BuildTimings {
+        self.timings.total_ms = self.elapsed_ms();
+        self.timings
+    }
+}
+
+impl Default for BuildTimer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Transpile and pretty-print `ast`, as the build script does
+fn transpile(ast: &crate::frontend::ast::Expr) -> Result<String> {
+    let tokens = Transpiler::new().transpile_to_program(ast)?;
+    let file: syn::File = syn::parse2(tokens)?;
+    Ok(prettyplease::unparse(&file))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_source_records_front_end_phases() {
+        let mut timer = BuildTimer::new();
+        let file = timer.time_source("main.ruchy", "fun main() {\n    println(\"hi\")\n}");
+        let phases: Vec<Phase> = file.phases.iter().map(|timing| timing.phase).collect();
+        assert_eq!(
+            phases,
+            [Phase::Lex, Phase::Parse, Phase::Infer, Phase::Transpile]
+        );
+        assert!(file.tokens > 0);
+        assert_eq!(file.error, None);
+        let starts: Vec<f64> = file.phases.iter().map(|timing| timing.start_ms).collect();
+        assert!(starts.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn test_parse_error_stops_the_pipeline() {
+        let mut timer = BuildTimer::new();
+        let file = timer.time_source("bad.ruchy", "let x = ");
+        assert_eq!(file.phases.len(), 2);
+        assert!(file
+            .error
+            .as_deref()
+            .is_some_and(|e| e.starts_with("parse:")));
+    }
+
+    #[test]
+    fn test_reports_cover_every_phase() {
+        let mut timer = BuildTimer::new();
+        timer.time_source("a<b>.ruchy", "let x = 1\nx + 1");
+        timer.time_rustc(|| ());
+        let timings = timer.finish();
+        assert!(timings.rustc.is_some());
+
+        let text = timings.render_text();
+        for phase in Phase::ALL {
+            assert!(text.contains(phase.name()), "{text}");
+        }
+
+        let json: serde_json::Value =
+            serde_json::from_str(&timings.to_json().unwrap()).expect("valid JSON");
+        assert_eq!(json["files"][0]["phases"][0]["phase"], "lex");
+        assert_eq!(json["rustc"]["phase"], "rustc");
+
+        let html = timings.render_html();
+        assert!(html.contains("a&lt;b&gt;.ruchy"));
+        assert!(html.contains("cargo build"));
+    }
+}
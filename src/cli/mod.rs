@@ -1,5 +1,11 @@
 // [RUCHY-207] CLI Module Implementation
 // PMAT Complexity: <10 per function
+pub mod output;
+pub mod shared;
+
+#[cfg(test)]
+use self::shared::{find_config_in_ancestors, get_start_directory, parse_source};
+use self::shared::{format_source, is_formatted, scan_ruchy_files};
 use crate::utils::format_file_error;
 use clap::{Parser, Subcommand};
 use std::path::{Path, PathBuf};
@@ -259,24 +265,23 @@ fn execute_run(path: PathBuf, verbose: bool, vm_mode: VmMode) -> Result<(), Stri
         println!("Running script: {} (mode: {:?})", path.display(), vm_mode);
     }
     let source = std::fs::read_to_string(&path).map_err(|_e| format_file_error("read", &path))?;
-    let mut parser = crate::frontend::parser::Parser::new(&source);
-    let ast = parser.parse().map_err(|e| format!("Parse error: {e:?}"))?;
-
-    // ISSUE-106: Resolve module declarations (mod name;) and imports before evaluation
-    let resolved_ast = resolve_modules_for_run(&path, ast)?;
 
     match vm_mode {
         VmMode::Ast => {
-            // Use AST interpreter (default)
-            let mut interpreter = crate::runtime::interpreter::Interpreter::new();
-            interpreter
-                .eval_expr(&resolved_ast)
-                .map_err(|e| format!("Evaluation error: {e:?}"))?;
+            // Same semantics as `ruchy file.ruchy` and `ruchy run` (see runtime::script)
+            let options =
+                crate::runtime::script::ScriptOptions::for_file(&path).with_configured_timeout()?;
+            crate::runtime::script::run_script(&source, &options).map_err(|e| e.to_string())?;
         }
         VmMode::Bytecode => {
             // Use bytecode VM (experimental, faster)
             use crate::runtime::bytecode::{Compiler, VM};
 
+            let mut parser = crate::frontend::parser::Parser::new(&source);
+            let ast = parser.parse().map_err(|e| format!("Parse error: {e:?}"))?;
+            // ISSUE-106: Resolve module declarations (mod name;) and imports before evaluation
+            let resolved_ast = resolve_modules_for_run(&path, ast)?;
+
             let mut compiler = Compiler::new("main".to_string());
             compiler
                 .compile_expr(&resolved_ast)
@@ -299,56 +304,21 @@ fn resolve_modules_for_run(
     source_path: &Path,
     ast: crate::frontend::ast::Expr,
 ) -> Result<crate::frontend::ast::Expr, String> {
-    use crate::backend::module_resolver::ModuleResolver;
-    use crate::frontend::ast::ExprKind;
-
-    // Check if AST contains any module declarations or imports that need resolution
-    fn needs_resolution(expr: &crate::frontend::ast::Expr) -> bool {
-        match &expr.kind {
-            ExprKind::ModuleDeclaration { .. } => true,
-            ExprKind::Module { .. } => true,
-            ExprKind::Import { .. } => true,
-            ExprKind::ImportAll { .. } => true,
-            ExprKind::ImportDefault { .. } => true,
-            ExprKind::Block(exprs) => exprs.iter().any(needs_resolution),
-            ExprKind::Function { body, .. } => needs_resolution(body),
-            ExprKind::Let { value, body, .. } => needs_resolution(value) || needs_resolution(body),
-            _ => false,
-        }
-    }
+    use crate::runtime::script::{needs_module_resolution, resolve_modules};
 
-    if !needs_resolution(&ast) {
+    if !needs_module_resolution(&ast) {
         return Ok(ast);
     }
-
-    let mut resolver = ModuleResolver::new();
-
-    // Add the source file's directory to the module search path
-    if let Some(parent_dir) = source_path.parent() {
-        resolver.add_search_path(parent_dir);
-
-        // Also search in standard project layout directories
-        if let Some(project_root) = parent_dir.parent() {
-            resolver.add_search_path(project_root.join("src"));
-            resolver.add_search_path(project_root.join("lib"));
-            resolver.add_search_path(project_root.join("modules"));
-        }
-    }
-
-    resolver
-        .resolve_imports(ast)
-        .map_err(|e| format!("Module resolution error: {e}"))
+    resolve_modules(source_path, ast).map_err(|e| format!("Module resolution error: {e}"))
 }
-fn execute_format(path: PathBuf, check: bool) -> Result<(), String> {
-    use crate::quality::formatter::Formatter;
 
-    let config = find_and_load_config(&path)?;
-    let mut formatter = Formatter::with_config(config);
+fn execute_format(path: PathBuf, check: bool) -> Result<(), String> {
+    let config = shared::load_formatter_config(&path, None)?;
 
     if check {
-        check_format(&path, &mut formatter)
+        check_format(&path, &config)
     } else {
-        apply_format(&path, &mut formatter)
+        apply_format(&path, &config)
     }
 }
 
@@ -538,55 +508,14 @@ fn execute_report(
     Ok(())
 }
 
-/// Scan directory for .ruchy files
-fn scan_ruchy_files(path: &Path) -> Result<Vec<PathBuf>, String> {
-    use std::fs;
-
-    if !path.exists() {
-        return Err(format!("Path does not exist: {}", path.display()));
-    }
-
-    if path.is_file() {
-        return Ok(vec![path.to_path_buf()]);
-    }
-
-    let mut files = Vec::new();
-
-    fn scan_dir(dir: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
-        for entry in fs::read_dir(dir)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            if path.is_dir() {
-                scan_dir(&path, files)?;
-            } else if path.extension().is_some_and(|e| e == "ruchy") {
-                files.push(path);
-            }
-        }
-        Ok(())
-    }
-
-    scan_dir(path, &mut files).map_err(|e| format!("Failed to scan directory: {e}"))?;
-    Ok(files)
-}
-
 /// Check if a file is properly formatted
-fn check_format(
-    path: &PathBuf,
-    formatter: &mut crate::quality::formatter::Formatter,
-) -> Result<(), String> {
+fn check_format(path: &Path, config: &crate::quality::FormatterConfig) -> Result<(), String> {
     println!("Checking formatting for: {}", path.display());
 
     let source = std::fs::read_to_string(path).map_err(|_e| format_file_error("read", path))?;
-    let ast = parse_source(&source)?;
+    let formatted_code = format_source(&source, config)?;
 
-    // Set source for ignore directives
-    formatter.set_source(&source);
-    let formatted_code = formatter
-        .format(&ast)
-        .map_err(|e| format!("Format error: {e}"))?;
-
-    if formatted_code.trim() == source.trim() {
+    if is_formatted(&source, &formatted_code) {
         println!("✓ File is properly formatted");
         Ok(())
     } else {
@@ -595,61 +524,17 @@ fn check_format(
 }
 
 /// Apply formatting to a file
-fn apply_format(
-    path: &PathBuf,
-    formatter: &mut crate::quality::formatter::Formatter,
-) -> Result<(), String> {
+fn apply_format(path: &Path, config: &crate::quality::FormatterConfig) -> Result<(), String> {
     println!("Formatting: {}", path.display());
 
     let source = std::fs::read_to_string(path).map_err(|_e| format_file_error("read", path))?;
-    let ast = parse_source(&source)?;
-
-    // Set source for ignore directives
-    formatter.set_source(&source);
-    let formatted_code = formatter
-        .format(&ast)
-        .map_err(|e| format!("Format error: {e}"))?;
+    let formatted_code = format_source(&source, config)?;
 
     std::fs::write(path, formatted_code).map_err(|e| format!("Failed to write file: {e}"))?;
     println!("✓ File formatted successfully");
     Ok(())
 }
 
-/// Parse source code into AST
-fn parse_source(source: &str) -> Result<crate::frontend::ast::Expr, String> {
-    let mut parser = crate::frontend::parser::Parser::new(source);
-    parser.parse().map_err(|e| format!("Parse error: {e:?}"))
-}
-
-/// Find and load formatter configuration by searching up the directory tree
-fn find_and_load_config(start_path: &Path) -> Result<crate::quality::FormatterConfig, String> {
-    let start_dir = get_start_directory(start_path);
-    find_config_in_ancestors(&start_dir)
-}
-
-/// Get the directory to start config search from
-fn get_start_directory(path: &Path) -> PathBuf {
-    if path.is_file() {
-        path.parent().unwrap_or(path).to_path_buf()
-    } else {
-        path.to_path_buf()
-    }
-}
-
-/// Search for config file in current and ancestor directories
-fn find_config_in_ancestors(start_dir: &Path) -> Result<crate::quality::FormatterConfig, String> {
-    // Try current directory
-    let config_path = start_dir.join(".ruchy-fmt.toml");
-    if config_path.exists() {
-        return crate::quality::FormatterConfig::from_file(&config_path);
-    }
-
-    // Try parent directories recursively
-    match start_dir.parent() {
-        Some(parent) => find_config_in_ancestors(&parent.to_path_buf()),
-        None => Ok(crate::quality::FormatterConfig::default()),
-    }
-}
 fn execute_notebook(cmd: NotebookCommand, verbose: bool) -> Result<(), String> {
     match cmd {
         NotebookCommand::Serve {
@@ -861,14 +746,12 @@ fn execute_test(cmd: TestCommand, verbose: bool) -> Result<(), String> {
             path,
             coverage: _,
             parallel: _,
-            filter: _,
+            filter,
         } => {
             if verbose {
                 println!("Running tests in {}", path.display());
             }
-            // Note: Implement test runner
-            println!("Test runner not yet implemented");
-            Ok(())
+            run_test_files(&path, filter.as_deref())
         }
         TestCommand::Report { format, output: _ } => {
             if verbose {
@@ -879,6 +762,40 @@ fn execute_test(cmd: TestCommand, verbose: bool) -> Result<(), String> {
         }
     }
 }
+
+/// Run the `@test` functions of every test file under `path`
+///
+/// Each file stops at its first failing test; every failure is reported.
+fn run_test_files(path: &Path, filter: Option<&str>) -> Result<(), String> {
+    let files = shared::discover_test_files(path, filter)?;
+    if files.is_empty() {
+        return Err(format!("No .ruchy test files found in {}", path.display()));
+    }
+    let failures: Vec<String> = files
+        .iter()
+        .filter_map(|file| run_test_file(file).err())
+        .collect();
+    println!(
+        "{} passed, {} failed",
+        files.len() - failures.len(),
+        failures.len()
+    );
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures.join("\n"))
+    }
+}
+
+/// Run every test of one file, stopping at the first failure
+fn run_test_file(path: &Path) -> Result<(), String> {
+    let mut file = shared::TestFile::load(path)?;
+    for name in file.tests.clone() {
+        file.run(&name)?;
+    }
+    Ok(())
+}
+
 // Keep the existing run_test_command function
 #[cfg(feature = "notebook")]
 /// # Examples
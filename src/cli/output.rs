@@ -0,0 +1,149 @@
+//! Typed JSON documents printed by `--format json`
+//!
+//! Scripts parse these outputs, so their shape is part of the CLI contract.
+//! Every document is a serde struct that both serializes (the binary) and
+//! deserializes (consumers, and the schema tests in
+//! `tests/cli_output_schema.rs`). Fields may be added; renaming or removing
+//! one is a breaking change.
+use crate::quality::linter::LintIssue;
+use serde::{Deserialize, Serialize};
+
+/// `ruchy -e <expr> --format json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvalResponse {
+    /// Whether evaluation succeeded
+    pub success: bool,
+    /// Display form of the result; absent for nil results and failures
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<String>,
+    /// Error message; absent on success
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl EvalResponse {
+    /// Successful evaluation, with the result unless it was nil
+    pub fn ok(result: Option<String>) -> Self {
+        Self {
+            success: true,
+            result,
+            error: None,
+        }
+    }
+
+    /// Failed evaluation
+    pub fn failed(error: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            result: None,
+            error: Some(error.into()),
+        }
+    }
+}
+
+/// `ruchy lint --format json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LintReport {
+    /// Issues found in the linted file
+    pub issues: Vec<LintIssue>,
+}
+
+/// `ruchy test --format json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestReport {
+    /// Number of test files run
+    pub total: usize,
+    /// Files whose tests passed
+    pub passed: usize,
+    /// Files whose tests failed
+    pub failed: usize,
+    /// Wall time of the whole run
+    pub duration_seconds: f64,
+    /// One entry per test file
+    pub results: Vec<TestFileResult>,
+}
+
+/// One test file in a [`TestReport`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestFileResult {
+    /// Path of the test file
+    pub file: String,
+    /// Whether the file's tests passed
+    pub success: bool,
+    /// Time spent on the file
+    pub duration_ms: f64,
+    /// Failure message; `null` when the file passed
+    pub error: Option<String>,
+}
+
+/// Outcome of a fuzz run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FuzzStatus {
+    /// No crashes or timeouts
+    Passed,
+    /// At least one crash or timeout
+    Failed,
+}
+
+impl From<bool> for FuzzStatus {
+    fn from(passed: bool) -> Self {
+        if passed {
+            Self::Passed
+        } else {
+            Self::Failed
+        }
+    }
+}
+
+/// `ruchy fuzz <target> --format json` (cargo-fuzz targets)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FuzzTargetReport {
+    /// Fuzz target name
+    pub target: String,
+    /// Requested iterations
+    pub iterations: usize,
+    /// Overall outcome
+    pub status: FuzzStatus,
+    /// Captured fuzzer output
+    pub output: String,
+}
+
+/// `ruchy fuzz <file> --format json` (built-in input mutation)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FuzzFileReport {
+    /// Fuzzed source file
+    pub file: String,
+    /// Inputs tried
+    pub iterations: usize,
+    /// Inputs handled without crash or timeout
+    pub successes: usize,
+    /// Inputs that crashed the pipeline
+    pub crashes: usize,
+    /// Inputs that timed out
+    pub timeouts: usize,
+    /// `successes / iterations` as a percentage
+    pub success_rate: f64,
+    /// Overall outcome
+    pub status: FuzzStatus,
+    /// One description per crash
+    pub crash_details: Vec<String>,
+}
+
+/// Serialize a document on one line (`eval` responses)
+///
+/// # Errors
+///
+/// Returns an error if the document cannot be serialized.
+pub fn to_json_line<T: Serialize>(document: &T) -> Result<String, String> {
+    serde_json::to_string(document).map_err(|e| format!("JSON serialization error: {e}"))
+}
+
+/// Serialize a document as indented JSON (reports)
+///
+/// # Errors
+///
+/// Returns an error if the document cannot be serialized.
+pub fn to_json_pretty<T: Serialize>(document: &T) -> Result<String, String> {
+    serde_json::to_string_pretty(document).map_err(|e| format!("JSON serialization error: {e}"))
+}
@@ -0,0 +1,595 @@
+//! Command logic shared by `ruchy::cli` and the `ruchy` binary
+//!
+//! Both CLI surfaces call into these functions for parsing, formatting,
+//! linting, AST dumps and running `@test` functions so that `fmt`, `lint`,
+//! `ast` and `test` behave identically regardless of which front end
+//! dispatched them. Functions here return data
+//! and never print; presentation stays with each caller.
+use crate::frontend::ast::{Expr, ExprKind, Span};
+use crate::frontend::cst::TextEdit;
+use crate::quality::formatter::Formatter;
+use crate::quality::linter::{LintIssue, Linter};
+use crate::quality::{FormatterConfig, LintConfig};
+use crate::runtime::interpreter::Interpreter;
+use crate::runtime::output;
+use std::path::{Path, PathBuf};
+
+/// Name of the formatter configuration file searched for by `fmt`
+pub const FORMATTER_CONFIG_FILE: &str = ".ruchy-fmt.toml";
+
+/// Name of the lint configuration file searched for by `lint`
+pub const LINT_CONFIG_FILE: &str = ".ruchylint.toml";
+
+/// Parse source code into an AST
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::cli::shared::parse_source;
+///
+/// assert!(parse_source("let x = 5").is_ok());
+/// assert!(parse_source("let x = ").is_err());
+/// ```
+pub fn parse_source(source: &str) -> Result<Expr, String> {
+    let mut parser = crate::frontend::parser::Parser::new(source);
+    parser.parse().map_err(|e| format!("Parse error: {e}"))
+}
+
+/// Read and parse a source file
+pub fn read_and_parse(path: &Path) -> Result<(String, Expr), String> {
+    let source = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read file {}: {e}", path.display()))?;
+    let ast = parse_source(&source)?;
+    Ok((source, ast))
+}
+
+/// Resolve the formatter configuration for `path`
+///
+/// An explicit config file wins; otherwise `.ruchy-fmt.toml` is searched for
+/// in the file's directory and its ancestors, falling back to defaults.
+pub fn load_formatter_config(
+    path: &Path,
+    explicit: Option<&Path>,
+) -> Result<FormatterConfig, String> {
+    match explicit {
+        Some(config_path) => FormatterConfig::from_file(config_path),
+        None => find_config_in_ancestors(&get_start_directory(path)),
+    }
+}
+
+/// Get the directory to start config search from
+pub fn get_start_directory(path: &Path) -> PathBuf {
+    if path.is_file() {
+        path.parent().unwrap_or(path).to_path_buf()
+    } else {
+        path.to_path_buf()
+    }
+}
+
+/// Search for config file in current and ancestor directories
+pub fn find_config_in_ancestors(start_dir: &Path) -> Result<FormatterConfig, String> {
+    let config_path = start_dir.join(FORMATTER_CONFIG_FILE);
+    if config_path.exists() {
+        return FormatterConfig::from_file(&config_path);
+    }
+    match start_dir.parent() {
+        Some(parent) => find_config_in_ancestors(parent),
+        None => Ok(FormatterConfig::default()),
+    }
+}
+
+/// Format source code with the given configuration
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::cli::shared::format_source;
+/// use ruchy::quality::FormatterConfig;
+///
+/// let formatted = format_source("let x = 1 + 2", &FormatterConfig::default()).unwrap();
+/// assert!(formatted.contains("1 + 2"));
+/// ```
+pub fn format_source(source: &str, config: &FormatterConfig) -> Result<String, String> {
+    let ast = parse_source(source)?;
+    let mut formatter = Formatter::with_config(config.clone());
+    // Source enables ignore directives that preserve original text
+    formatter.set_source(source);
+    formatter
+        .format(&ast)
+        .map_err(|e| format!("Format error: {e}"))
+}
+
+/// Format source code and verify that formatting the result changes nothing
+///
+/// Editor integrations reformat on every save, so output that keeps moving
+/// is reported as an error instead of being written back.
+pub fn format_source_stable(source: &str, config: &FormatterConfig) -> Result<String, String> {
+    let once = format_source(source, config)?;
+    let twice = format_source(&once, config)?;
+    if once == twice {
+        Ok(once)
+    } else {
+        Err("Format error: formatter output is not stable".to_string())
+    }
+}
+
+/// Part of a file selected by `fmt --range` or `fmt --byte-range`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatRange {
+    /// 1-based, inclusive line numbers
+    Lines(usize, usize),
+    /// Half-open byte offsets
+    Bytes(usize, usize),
+}
+
+impl FormatRange {
+    /// Parse a `START:END` line range
+    pub fn lines(spec: &str) -> Result<Self, String> {
+        let (start, end) = parse_range_spec(spec)?;
+        if start == 0 || end < start {
+            return Err(format!(
+                "Invalid line range '{spec}': lines are 1-based and START must not exceed END"
+            ));
+        }
+        Ok(Self::Lines(start, end))
+    }
+
+    /// Parse a `START:END` byte range
+    pub fn bytes(spec: &str) -> Result<Self, String> {
+        let (start, end) = parse_range_spec(spec)?;
+        if end < start {
+            return Err(format!(
+                "Invalid byte range '{spec}': START must not exceed END"
+            ));
+        }
+        Ok(Self::Bytes(start, end))
+    }
+
+    /// Byte span of the range within `source`
+    fn to_span(self, source: &str) -> Result<Span, String> {
+        match self {
+            Self::Bytes(start, end) if end <= source.len() => Ok(Span::new(start, end)),
+            Self::Bytes(_, end) => Err(format!(
+                "Byte range ends at {end} but the source is {} bytes long",
+                source.len()
+            )),
+            Self::Lines(start, end) => {
+                let line_starts: Vec<usize> = std::iter::once(0)
+                    .chain(source.match_indices('\n').map(|(i, _)| i + 1))
+                    .collect();
+                let first = *line_starts.get(start - 1).ok_or_else(|| {
+                    format!(
+                        "Line range starts at {start} but the source has {} lines",
+                        line_starts.len()
+                    )
+                })?;
+                let last = line_starts.get(end).copied().unwrap_or(source.len());
+                Ok(Span::new(first, last))
+            }
+        }
+    }
+}
+
+fn parse_range_spec(spec: &str) -> Result<(usize, usize), String> {
+    let invalid = || format!("Invalid range '{spec}': expected START:END");
+    let (start, end) = spec.split_once(':').ok_or_else(invalid)?;
+    let start = start.trim().parse().map_err(|_| invalid())?;
+    let end = end.trim().parse().map_err(|_| invalid())?;
+    Ok((start, end))
+}
+
+/// Format only the top-level items overlapping `range`
+///
+/// Everything outside those items, including comments and blank lines
+/// between them, is kept byte for byte. Each selected item is formatted on
+/// its own with [`format_source_stable`], so formatting the same range twice
+/// yields identical output.
+pub fn format_source_range(
+    source: &str,
+    range: FormatRange,
+    config: &FormatterConfig,
+) -> Result<String, String> {
+    let selected = range.to_span(source)?;
+    let mut parser = crate::frontend::parser::Parser::new(source).with_cst();
+    parser.parse().map_err(|e| format!("Parse error: {e}"))?;
+    let tree = parser
+        .take_cst()
+        .ok_or_else(|| "Parse error: no syntax tree was built".to_string())?;
+    let mut edits = Vec::new();
+    for item in tree.items() {
+        // Comments around the item stay where they are; only its code is
+        // handed to the pretty-printer
+        let code: Vec<Span> = tree
+            .node_leaves(item)
+            .iter()
+            .filter(|leaf| !leaf.is_trivia())
+            .map(|leaf| leaf.span)
+            .collect();
+        let (Some(first), Some(last)) = (code.first(), code.last()) else {
+            continue;
+        };
+        let span = Span::new(first.start, last.end);
+        let overlaps =
+            span.start < selected.end.max(selected.start + 1) && selected.start < span.end;
+        if overlaps {
+            let formatted = format_source_stable(tree.text(span), config)?;
+            edits.push(TextEdit::replace(span, formatted.trim_end()));
+        }
+    }
+    tree.apply_edits(&edits)
+        .map_err(|e| format!("Format error: {e}"))
+}
+
+/// Whether `source` already matches its formatted form
+///
+/// The comparison is exact, including surrounding whitespace, so `fmt
+/// --check` fails exactly when `fmt` would rewrite the file.
+pub fn is_formatted(source: &str, formatted: &str) -> bool {
+    source == formatted
+}
+
+/// Resolve the lint configuration for `path`
+///
+/// An explicit config file wins; otherwise `.ruchylint.toml` is searched for
+/// in the file's directory and its ancestors, falling back to defaults.
+pub fn load_lint_config(path: &Path, explicit: Option<&Path>) -> Result<LintConfig, String> {
+    if let Some(config_path) = explicit {
+        return LintConfig::from_file(config_path);
+    }
+    let start = get_start_directory(path);
+    let start = std::fs::canonicalize(&start).unwrap_or(start);
+    start
+        .ancestors()
+        .map(|dir| dir.join(LINT_CONFIG_FILE))
+        .find(|candidate| candidate.is_file())
+        .map_or_else(|| Ok(LintConfig::default()), LintConfig::from_file)
+}
+
+/// Build a linter with an optional rule filter and strict mode
+pub fn configure_linter(rules: Option<&str>, strict: bool) -> Linter {
+    configure_linter_with_config(&LintConfig::default(), rules, strict)
+}
+
+/// Build a linter from a lint configuration, with the rule filter and strict
+/// mode of the command line on top
+pub fn configure_linter_with_config(
+    config: &LintConfig,
+    rules: Option<&str>,
+    strict: bool,
+) -> Linter {
+    let mut linter = Linter::new();
+    linter.set_config(config);
+    if let Some(rule_filter) = rules {
+        linter.set_rules(rule_filter);
+    }
+    if strict {
+        linter.set_strict_mode(true);
+    }
+    linter
+}
+
+/// Lint source code, returning every issue found
+pub fn lint_source(
+    source: &str,
+    rules: Option<&str>,
+    strict: bool,
+) -> Result<Vec<LintIssue>, String> {
+    let ast = parse_source(source)?;
+    configure_linter(rules, strict)
+        .lint(&ast, source)
+        .map_err(|e| format!("Lint error: {e}"))
+}
+
+/// Lint issues as the JSON document printed by `lint --format json`
+pub fn lint_issues_json(issues: &[LintIssue]) -> Result<String, String> {
+    super::output::to_json_pretty(&super::output::LintReport {
+        issues: issues.to_vec(),
+    })
+}
+
+/// AST as the pretty JSON printed by `ast --json`
+pub fn ast_to_json(ast: &Expr) -> Result<String, String> {
+    serde_json::to_string_pretty(ast).map_err(|e| format!("JSON serialization error: {e}"))
+}
+
+/// Collect `.ruchy` files under `path` (or `path` itself if it is a file)
+pub fn scan_ruchy_files(path: &Path) -> Result<Vec<PathBuf>, String> {
+    fn scan_dir(dir: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                scan_dir(&path, files)?;
+            } else if path.extension().is_some_and(|e| e == "ruchy") {
+                files.push(path);
+            }
+        }
+        Ok(())
+    }
+
+    if !path.exists() {
+        return Err(format!("Path does not exist: {}", path.display()));
+    }
+    if path.is_file() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+    let mut files = Vec::new();
+    scan_dir(path, &mut files).map_err(|e| format!("Failed to scan directory: {e}"))?;
+    files.sort();
+    Ok(files)
+}
+
+/// Collect the `.ruchy` test files run by `test`
+///
+/// A file path is taken as given. Directories are scanned recursively and
+/// only files whose stem contains `filter` are kept.
+pub fn discover_test_files(path: &Path, filter: Option<&str>) -> Result<Vec<PathBuf>, String> {
+    if !path.exists() {
+        return Err(format!("Path {} does not exist", path.display()));
+    }
+    if path.is_file() {
+        if path.extension().is_some_and(|ext| ext == "ruchy") {
+            return Ok(vec![path.to_path_buf()]);
+        }
+        return Err(format!("File {} is not a .ruchy file", path.display()));
+    }
+    let mut files = scan_ruchy_files(path)?;
+    if let Some(pattern) = filter {
+        files.retain(|file| {
+            file.file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| stem.contains(pattern))
+        });
+    }
+    Ok(files)
+}
+
+/// Names of the top-level functions marked `@test`, in source order
+pub fn find_test_functions(ast: &Expr) -> Vec<String> {
+    let items = match &ast.kind {
+        ExprKind::Block(exprs) => exprs.as_slice(),
+        _ => std::slice::from_ref(ast),
+    };
+    items
+        .iter()
+        .filter(|item| item.attributes.iter().any(|attr| attr.name == "test"))
+        .filter_map(|item| match &item.kind {
+            ExprKind::Function { name, .. } => Some(name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// A test file whose definitions are loaded into a fresh interpreter
+pub struct TestFile {
+    interpreter: Interpreter,
+    /// The `@test` functions of the file, in source order
+    pub tests: Vec<String>,
+}
+
+impl TestFile {
+    /// Parse `path`, find its `@test` functions and evaluate the file so that
+    /// every definition is available to the tests
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read file {}: {e}", path.display()))?;
+        let ast = parse_source(&source)
+            .map_err(|e| format!("Failed to parse test file {}: {e}", path.display()))?;
+        let tests = find_test_functions(&ast);
+        if tests.is_empty() {
+            return Err(format!("No test functions found in {}", path.display()));
+        }
+        let mut interpreter = Interpreter::new();
+        interpreter
+            .eval_top_level(&ast)
+            .map_err(|e| format!("Failed to load test file {}: {e}", path.display()))?;
+        Ok(Self { interpreter, tests })
+    }
+
+    /// Call the test function `name`
+    ///
+    /// The test's output is captured and only included in the error when the
+    /// test fails.
+    pub fn run(&mut self, name: &str) -> Result<(), String> {
+        let call = parse_source(&format!("{name}()"))?;
+        let interpreter = &mut self.interpreter;
+        let (result, printed) = output::capture(|| interpreter.eval_expr(&call));
+        match result {
+            Ok(_) => Ok(()),
+            Err(e) if printed.is_empty() => Err(format!("Test failed: {name} - {e}")),
+            Err(e) => Err(format!(
+                "Test failed: {name} - {e}\n---- {name} stdout ----\n{}",
+                printed.trim_end()
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_is_formatted_is_exact() {
+        assert!(is_formatted("let x = 1\n", "let x = 1\n"));
+        assert!(!is_formatted("let x = 1\n\n", "let x = 1\n"));
+        assert!(!is_formatted("let x=1", "let x = 1"));
+    }
+
+    #[test]
+    fn test_format_source_is_idempotent() {
+        let config = FormatterConfig::default();
+        let once = format_source("let a = 10 + 5\nlet b = a * 2", &config).expect("formats");
+        let twice = format_source(&once, &config).expect("formats");
+        assert!(is_formatted(&once, &twice));
+    }
+
+    #[test]
+    fn test_format_source_parse_error() {
+        let err = format_source("let x = ", &FormatterConfig::default()).unwrap_err();
+        assert!(err.starts_with("Parse error"));
+    }
+
+    #[test]
+    fn test_format_source_stable_matches_format_source() {
+        let config = FormatterConfig::default();
+        let source = "let a = 10 + 5\nlet b = a * 2";
+        assert_eq!(
+            format_source_stable(source, &config).expect("formats"),
+            format_source(source, &config).expect("formats")
+        );
+    }
+
+    #[test]
+    fn test_format_range_parses_specs() {
+        assert_eq!(FormatRange::lines("10:42"), Ok(FormatRange::Lines(10, 42)));
+        assert_eq!(FormatRange::bytes("0:7"), Ok(FormatRange::Bytes(0, 7)));
+        assert!(FormatRange::lines("0:3").is_err());
+        assert!(FormatRange::lines("5:2").is_err());
+        assert!(FormatRange::bytes("7").is_err());
+        assert!(FormatRange::bytes("a:b").is_err());
+    }
+
+    #[test]
+    fn test_format_source_range_only_touches_selected_items() {
+        let config = FormatterConfig::default();
+        let source = "let a=1+2\n// keep  me\nlet b=3*4\nlet c=5-6\n";
+        let formatted =
+            format_source_range(source, FormatRange::Lines(3, 3), &config).expect("formats");
+        let b = format_source("let b=3*4", &config).expect("formats");
+        assert_eq!(
+            formatted,
+            format!("let a=1+2\n// keep  me\n{}\nlet c=5-6\n", b.trim_end())
+        );
+        let again =
+            format_source_range(&formatted, FormatRange::Lines(3, 3), &config).expect("formats");
+        assert_eq!(again, formatted);
+    }
+
+    #[test]
+    fn test_format_source_range_by_bytes_and_out_of_bounds() {
+        let config = FormatterConfig::default();
+        let source = "let a=1+2\nlet b=3*4";
+        let formatted =
+            format_source_range(source, FormatRange::Bytes(0, 1), &config).expect("formats");
+        assert!(formatted.ends_with("\nlet b=3*4"), "{formatted}");
+        assert!(!formatted.starts_with("let a=1+2"), "{formatted}");
+        assert!(format_source_range(source, FormatRange::Bytes(0, 99), &config).is_err());
+        assert!(format_source_range(source, FormatRange::Lines(9, 9), &config).is_err());
+    }
+
+    #[test]
+    fn test_load_formatter_config_prefers_explicit_file() {
+        let dir = TempDir::new().expect("temp dir");
+        let explicit = dir.path().join("custom.toml");
+        FormatterConfig {
+            indent_width: 2,
+            ..FormatterConfig::default()
+        }
+        .to_file(&explicit)
+        .expect("write config");
+        let config =
+            load_formatter_config(dir.path(), Some(&explicit)).expect("config should load");
+        assert_eq!(config.indent_width, 2);
+    }
+
+    #[test]
+    fn test_load_formatter_config_discovers_ancestor_file() {
+        let dir = TempDir::new().expect("temp dir");
+        FormatterConfig {
+            indent_width: 8,
+            ..FormatterConfig::default()
+        }
+        .to_file(dir.path().join(FORMATTER_CONFIG_FILE))
+        .expect("write config");
+        let nested = dir.path().join("src");
+        std::fs::create_dir(&nested).expect("create dir");
+        let config = load_formatter_config(&nested, None).expect("config should load");
+        assert_eq!(config.indent_width, 8);
+    }
+
+    #[test]
+    fn test_load_lint_config_discovers_ancestor_file() {
+        let dir = TempDir::new().expect("temp dir");
+        std::fs::write(
+            dir.path().join(LINT_CONFIG_FILE),
+            "[rules]\nunused_variable = \"allow\"\n",
+        )
+        .expect("write config");
+        let nested = dir.path().join("src");
+        std::fs::create_dir(&nested).expect("create dir");
+        let config = load_lint_config(&nested, None).expect("config should load");
+        let source = "fn f() {\n    let unused = 1\n    2\n}";
+        let ast = parse_source(source).expect("parses");
+        let issues = configure_linter_with_config(&config, None, false)
+            .lint(&ast, source)
+            .expect("lints");
+        assert!(issues.iter().all(|issue| issue.rule != "unused_variable"));
+    }
+
+    #[test]
+    fn test_lint_source_matches_configured_linter() {
+        let source = "fn f() {\n    let unused = 1\n    2\n}";
+        let ast = parse_source(source).expect("parses");
+        let direct = configure_linter(None, false)
+            .lint(&ast, source)
+            .expect("lints");
+        let shared = lint_source(source, None, false).expect("lints");
+        assert_eq!(shared.len(), direct.len());
+    }
+
+    #[test]
+    fn test_discover_test_files_filters_by_stem() {
+        let dir = TempDir::new().expect("temp dir");
+        std::fs::write(dir.path().join("math_test.ruchy"), "1").expect("write");
+        std::fs::write(dir.path().join("io.ruchy"), "1").expect("write");
+        let files = discover_test_files(dir.path(), Some("math")).expect("discover");
+        assert_eq!(files, vec![dir.path().join("math_test.ruchy")]);
+        let txt = dir.path().join("notes.txt");
+        std::fs::write(&txt, "").expect("write");
+        let err = discover_test_files(&txt, None).unwrap_err();
+        assert!(err.contains("not a .ruchy file"), "{err}");
+        let err = discover_test_files(&dir.path().join("missing"), None).unwrap_err();
+        assert!(err.contains("does not exist"), "{err}");
+    }
+
+    #[test]
+    fn test_find_test_functions_in_source_order() {
+        let ast = parse_source("@test\nfun b() { }\nfun helper() { }\n@test\nfun a() { }")
+            .expect("parses");
+        assert_eq!(find_test_functions(&ast), vec!["b", "a"]);
+        let single = parse_source("@test\nfun only() { }").expect("parses");
+        assert_eq!(find_test_functions(&single), vec!["only"]);
+    }
+
+    #[test]
+    fn test_test_file_runs_tests_and_captures_failure_output() {
+        let dir = TempDir::new().expect("temp dir");
+        let path = dir.path().join("cases_test.ruchy");
+        std::fs::write(
+            &path,
+            "fun double(x) { x * 2 }\n@test\nfun passes() { assert_eq(double(2), 4) }\n@test\nfun fails() { println(\"context\"); assert_eq(double(2), 5) }",
+        )
+        .expect("write");
+        let mut file = TestFile::load(&path).expect("loads");
+        assert_eq!(file.tests, vec!["passes", "fails"]);
+        assert!(file.run("passes").is_ok());
+        let err = file.run("fails").unwrap_err();
+        assert!(err.starts_with("Test failed: fails - "), "{err}");
+        assert!(err.ends_with("---- fails stdout ----\ncontext"), "{err}");
+    }
+
+    #[test]
+    fn test_scan_ruchy_files_sorted() {
+        let dir = TempDir::new().expect("temp dir");
+        std::fs::write(dir.path().join("b.ruchy"), "1").expect("write");
+        std::fs::write(dir.path().join("a.ruchy"), "1").expect("write");
+        std::fs::write(dir.path().join("notes.txt"), "").expect("write");
+        let files = scan_ruchy_files(dir.path()).expect("scan");
+        let names: Vec<_> = files
+            .iter()
+            .filter_map(|f| f.file_name()?.to_str())
+            .collect();
+        assert_eq!(names, vec!["a.ruchy", "b.ruchy"]);
+    }
+}
@@ -239,15 +239,38 @@ fn test_execute_wasm_validate() {
 
 #[test]
 fn test_execute_test_run() {
+    let dir = tempfile::TempDir::new().expect("temp dir");
+    std::fs::write(
+        dir.path().join("math_test.ruchy"),
+        "@test\nfun adds() { assert_eq(1 + 1, 2) }",
+    )
+    .expect("write test file");
     let cmd = TestCommand::Run {
-        path: PathBuf::from("tests/"),
+        path: dir.path().to_path_buf(),
         coverage: false,
         parallel: true,
         filter: None,
     };
     let result = execute_test(cmd, false);
-    // Currently returns Ok(())
-    assert!(result.is_ok());
+    assert!(result.is_ok(), "{result:?}");
+}
+
+#[test]
+fn test_execute_test_run_reports_failures() {
+    let dir = tempfile::TempDir::new().expect("temp dir");
+    std::fs::write(
+        dir.path().join("math_test.ruchy"),
+        "@test\nfun wrong() { assert_eq(1 + 1, 3) }",
+    )
+    .expect("write test file");
+    let cmd = TestCommand::Run {
+        path: dir.path().to_path_buf(),
+        coverage: false,
+        parallel: true,
+        filter: None,
+    };
+    let err = execute_test(cmd, false).unwrap_err();
+    assert!(err.starts_with("Test failed: wrong"), "{err}");
 }
 
 #[test]
@@ -564,8 +587,19 @@ fn test_execute_wasm_run_verbose() {
 // COVERAGE: Test execute_test with verbose
 #[test]
 fn test_execute_test_run_verbose() {
+    let dir = tempfile::TempDir::new().expect("temp dir");
+    std::fs::write(
+        dir.path().join("filter_test.ruchy"),
+        "@test\nfun passes() { assert(true) }",
+    )
+    .expect("write test file");
+    std::fs::write(
+        dir.path().join("other.ruchy"),
+        "@test\nfun fails() { assert(false) }",
+    )
+    .expect("write test file");
     let cmd = TestCommand::Run {
-        path: PathBuf::from("tests/"),
+        path: dir.path().to_path_buf(),
         coverage: true,
         parallel: true,
         filter: Some("filter".to_string()),
@@ -850,8 +884,7 @@ fn test_execute_test_run_invalid_path() {
         filter: None,
     };
     let result = execute_test(cmd, false);
-    // Test command may succeed or fail depending on implementation
-    let _ = result;
+    assert!(result.unwrap_err().contains("does not exist"));
 }
 
 // COVERAGE: Test scan_ruchy_files with valid directory
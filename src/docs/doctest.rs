@@ -0,0 +1,385 @@
+//! Doctests: the examples in doc comments, run as tests
+//!
+//! `ruchy test --doc` runs the fenced code blocks of the `///` comments in
+//! a file, so that examples cannot silently go stale. A block is Ruchy code
+//! unless its info string names another language, as in ```` ```text ````.
+//! It runs after the file's definitions (functions, types, `impl` blocks,
+//! modules, imports and `let`s of literals) but none of its other
+//! statements, so an example can use what it documents. An example passes
+//! if it evaluates without error; `assert` and `assert_eq` check results.
+//!
+//! Words after the language change how a block is tested:
+//!
+//! - `ignore`: the block is not tested at all;
+//! - `no_run`: the block is only parsed, for examples that need files,
+//!   the network or input.
+
+use super::{declaration, doc_line};
+use crate::frontend::ast::{Comment, CommentKind, Expr, ExprKind, Literal, Span};
+use crate::frontend::parser::Parser;
+use crate::runtime::interpreter::Interpreter;
+use crate::runtime::output;
+use anyhow::Result;
+
+/// How a doctest is tested
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DoctestMode {
+    /// Parsed and evaluated
+    Run,
+    /// Parsed only
+    NoRun,
+    /// Not tested
+    Ignore,
+}
+
+impl DoctestMode {
+    /// Mode of a code block from its info string; `None` for other languages (complexity: 5)
+    pub fn from_info(info: &str) -> Option<Self> {
+        let words = info
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|word| !word.is_empty());
+        let mut mode = Self::Run;
+        for (index, word) in words.enumerate() {
+            match word {
+                "ruchy" => {}
+                "ignore" => mode = Self::Ignore,
+                "no_run" if mode == Self::Run => mode = Self::NoRun,
+                _ if index == 0 => return None,
+                _ => {}
+            }
+        }
+        Some(mode)
+    }
+}
+
+/// An example from a doc comment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Doctest {
+    /// Path of the documented item, with `.field` or `::Variant` for members
+    pub item: String,
+    /// Line of the example's first line of code in the source, from 1
+    pub line: usize,
+    /// Code of the example
+    pub code: String,
+    /// How the example is tested
+    pub mode: DoctestMode,
+}
+
+/// Result of a doctest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DoctestOutcome {
+    /// The example parsed and, unless `no_run`, evaluated without error
+    Passed,
+    /// The example is marked `ignore`
+    Ignored,
+    /// Why the example failed, with what it printed
+    Failed(String),
+}
+
+/// The doctests of a source file and the definitions they run after
+#[derive(Debug, Clone)]
+pub struct DoctestFile {
+    doctests: Vec<Doctest>,
+    definitions: Vec<Expr>,
+}
+
+/// A fenced code block of a doc comment
+pub(super) struct CodeBlock {
+    /// Text after the opening fence
+    pub(super) info: String,
+    /// Index of the block's first line of code among the comment's lines
+    pub(super) first_line: usize,
+    /// Lines between the fences
+    pub(super) code: String,
+}
+
+impl DoctestFile {
+    /// Doctests of a source file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source does not parse.
+    pub fn parse(source: &str) -> Result<Self> {
+        let ast = Parser::new(source).parse()?;
+        let mut doctests = Vec::new();
+        collect(&ast, &[], source, &mut doctests);
+        let statements = match &ast.kind {
+            ExprKind::Block(statements) => statements.as_slice(),
+            _ => std::slice::from_ref(&ast),
+        };
+        Ok(Self {
+            doctests,
+            definitions: statements
+                .iter()
+                .filter(|statement| is_definition(statement))
+                .cloned()
+                .collect(),
+        })
+    }
+
+    /// The file's doctests, in source order
+    pub fn doctests(&self) -> &[Doctest] {
+        &self.doctests
+    }
+
+    /// Test `doctest` in a fresh interpreter (complexity: 6)
+    pub fn run(&self, doctest: &Doctest) -> DoctestOutcome {
+        if doctest.mode == DoctestMode::Ignore {
+            return DoctestOutcome::Ignored;
+        }
+        let example = match Parser::new(&doctest.code).parse() {
+            Ok(example) => example,
+            Err(e) => return DoctestOutcome::Failed(format!("does not parse: {e}")),
+        };
+        if doctest.mode == DoctestMode::NoRun {
+            return DoctestOutcome::Passed;
+        }
+        let mut statements = self.definitions.clone();
+        match example.kind {
+            ExprKind::Block(example) => statements.extend(example),
+            _ => statements.push(example),
+        }
+        let program = Expr::new(ExprKind::Block(statements), Span::default());
+        let mut interpreter = Interpreter::new();
+        let (result, printed) = output::capture(|| interpreter.eval_expr(&program));
+        match result {
+            Ok(_) => DoctestOutcome::Passed,
+            Err(e) if printed.is_empty() => DoctestOutcome::Failed(e.to_string()),
+            Err(e) => {
+                DoctestOutcome::Failed(format!("{e}\n---- stdout ----\n{}", printed.trim_end()))
+            }
+        }
+    }
+}
+
+/// Fenced code blocks among the lines of a doc comment (complexity: 5)
+///
+/// A block left open runs to the end of the comment, as in Markdown.
+pub(super) fn code_blocks<'a>(lines: impl IntoIterator<Item = &'a str>) -> Vec<CodeBlock> {
+    let mut blocks = Vec::new();
+    let mut open: Option<(CodeBlock, Vec<&str>)> = None;
+    for (index, line) in lines.into_iter().enumerate() {
+        let fence = line.trim_start().strip_prefix("```");
+        match (fence, open.take()) {
+            (Some(info), None) => {
+                let block = CodeBlock {
+                    info: info.trim().to_string(),
+                    first_line: index + 1,
+                    code: String::new(),
+                };
+                open = Some((block, Vec::new()));
+            }
+            (Some(_), Some((mut block, code))) => {
+                block.code = code.join("\n");
+                blocks.push(block);
+            }
+            (None, Some((block, mut code))) => {
+                code.push(line);
+                open = Some((block, code));
+            }
+            (None, None) => {}
+        }
+    }
+    if let Some((mut block, code)) = open {
+        block.code = code.join("\n");
+        blocks.push(block);
+    }
+    blocks
+}
+
+/// Collect the doctests of the items of `expr` declared in `module` (complexity: 8)
+fn collect(expr: &Expr, module: &[String], source: &str, doctests: &mut Vec<Doctest>) {
+    match &expr.kind {
+        ExprKind::Block(exprs) => {
+            for expr in exprs {
+                collect(expr, module, source, doctests);
+            }
+        }
+        ExprKind::Module { name, body } => {
+            let mut path = module.to_vec();
+            path.push(name.clone());
+            collect(body, &path, source, doctests);
+        }
+        _ => {
+            let Some((item, _)) = declaration(expr) else {
+                return;
+            };
+            let path = module
+                .iter()
+                .chain(std::iter::once(&item.name))
+                .cloned()
+                .collect::<Vec<_>>()
+                .join("::");
+            push_doctests(&expr.leading_comments, &path, source, doctests);
+            match &expr.kind {
+                ExprKind::Struct { fields, .. } | ExprKind::Class { fields, .. } => {
+                    for field in fields {
+                        let member = format!("{path}.{}", field.name);
+                        push_doctests(&field.leading_comments, &member, source, doctests);
+                    }
+                }
+                ExprKind::Enum { variants, .. } => {
+                    for variant in variants {
+                        let member = format!("{path}::{}", variant.name);
+                        push_doctests(&variant.leading_comments, &member, source, doctests);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Add the doctests of the doc comments among `comments` (complexity: 3)
+fn push_doctests(comments: &[Comment], item: &str, source: &str, doctests: &mut Vec<Doctest>) {
+    let lines: Vec<(usize, &str)> = comments
+        .iter()
+        .filter_map(|comment| match &comment.kind {
+            CommentKind::Doc(text) => Some((line_of(source, comment.span.start), doc_line(text))),
+            _ => None,
+        })
+        .collect();
+    for block in code_blocks(lines.iter().map(|&(_, text)| text)) {
+        let Some(mode) = DoctestMode::from_info(&block.info) else {
+            continue;
+        };
+        if block.code.trim().is_empty() {
+            continue;
+        }
+        let (line, _) = lines[block.first_line];
+        doctests.push(Doctest {
+            item: item.to_string(),
+            line,
+            code: block.code,
+            mode,
+        });
+    }
+}
+
+/// Line of byte `offset` in `source`, from 1 (complexity: 1)
+fn line_of(source: &str, offset: usize) -> usize {
+    source
+        .get(..offset)
+        .map_or(0, |before| before.matches('\n').count())
+        + 1
+}
+
+/// Whether a top-level statement is a definition examples may use (complexity: 2)
+fn is_definition(statement: &Expr) -> bool {
+    match &statement.kind {
+        ExprKind::Let { value, body, .. } => {
+            matches!(value.kind, ExprKind::Literal(_))
+                && matches!(body.kind, ExprKind::Literal(Literal::Unit))
+        }
+        kind => matches!(
+            kind,
+            ExprKind::Function { .. }
+                | ExprKind::Struct { .. }
+                | ExprKind::TupleStruct { .. }
+                | ExprKind::Class { .. }
+                | ExprKind::Enum { .. }
+                | ExprKind::Trait { .. }
+                | ExprKind::Impl { .. }
+                | ExprKind::TypeAlias { .. }
+                | ExprKind::Module { .. }
+                | ExprKind::Import { .. }
+                | ExprKind::ImportAll { .. }
+                | ExprKind::ImportDefault { .. }
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "/// Doubles a number
+///
+/// ```
+/// assert_eq(double(2), 4)
+/// ```
+fun double(n) { n * 2 }
+
+/// Broken example
+///
+/// ```ruchy
+/// assert_eq(double(2), 5)
+/// ```
+///
+/// ```text
+/// not code (
+/// ```
+fun other() { 1 }
+
+/// Not run
+///
+/// ```no_run
+/// read_file(\"/nowhere\")
+/// ```
+///
+/// ```ruchy,ignore
+/// this would not parse (
+/// ```
+fun io() { 2 }
+
+println(\"not a definition\")
+";
+
+    #[test]
+    fn test_modes_from_info_strings() {
+        assert_eq!(DoctestMode::from_info(""), Some(DoctestMode::Run));
+        assert_eq!(DoctestMode::from_info("ruchy"), Some(DoctestMode::Run));
+        assert_eq!(DoctestMode::from_info("no_run"), Some(DoctestMode::NoRun));
+        assert_eq!(
+            DoctestMode::from_info("ruchy,ignore"),
+            Some(DoctestMode::Ignore)
+        );
+        assert_eq!(DoctestMode::from_info("text"), None);
+        assert_eq!(DoctestMode::from_info("rust no_run"), None);
+    }
+
+    #[test]
+    fn test_doctests_with_locations() {
+        let file = DoctestFile::parse(SOURCE).expect("should parse");
+        let found: Vec<(&str, usize, DoctestMode)> = file
+            .doctests()
+            .iter()
+            .map(|doctest| (doctest.item.as_str(), doctest.line, doctest.mode))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("double", 4, DoctestMode::Run),
+                ("other", 11, DoctestMode::Run),
+                ("io", 22, DoctestMode::NoRun),
+                ("io", 26, DoctestMode::Ignore),
+            ]
+        );
+        assert_eq!(file.doctests()[0].code, "assert_eq(double(2), 4)");
+    }
+
+    #[test]
+    fn test_running_doctests() {
+        let file = DoctestFile::parse(SOURCE).expect("should parse");
+        let outcomes: Vec<DoctestOutcome> = file
+            .doctests()
+            .iter()
+            .map(|doctest| file.run(doctest))
+            .collect();
+        assert_eq!(outcomes[0], DoctestOutcome::Passed);
+        assert!(matches!(outcomes[1], DoctestOutcome::Failed(_)));
+        assert_eq!(outcomes[2], DoctestOutcome::Passed);
+        assert_eq!(outcomes[3], DoctestOutcome::Ignored);
+    }
+
+    #[test]
+    fn test_member_doctests() {
+        let file = DoctestFile::parse(
+            "struct Point {\n    /// ```\n    /// let p = Point { x: 1.0 }\n    /// ```\n    x: f64\n}",
+        )
+        .expect("should parse");
+        let doctest = &file.doctests()[0];
+        assert_eq!((doctest.item.as_str(), doctest.line), ("Point.x", 3));
+        assert_eq!(file.run(doctest), DoctestOutcome::Passed);
+    }
+}
@@ -1,11 +1,31 @@
 //! Documentation generation for Ruchy code
 //!
-//! This module provides automatic documentation extraction and generation
-//! from Ruchy source code, supporting multiple output formats.
-
-use crate::frontend::ast::{Expr, ExprKind};
-use anyhow::Result;
-use std::collections::HashMap;
+//! `ruchy doc` builds API documentation from `///` comments. A doc comment
+//! documents the function, struct, enum, trait, class or type alias that
+//! follows it, and struct fields and enum variants can have their own.
+//! Ordinary `//` comments never appear in the output. Items are listed if
+//! they are `pub` or documented; with private items included, all are.
+//!
+//! Doc comments are Markdown. An intra-doc link such as [`Point`] or
+//! `[geometry::Point]` naming a documented item links to it, also when the
+//! item is on another page, and type names in HTML signatures link to their
+//! items. See [`render`] for the output formats. Code blocks in doc
+//! comments are examples, which `ruchy test --doc` runs; see [`doctest`].
+
+pub mod doctest;
+pub mod render;
+
+pub use doctest::{Doctest, DoctestFile, DoctestMode, DoctestOutcome};
+pub use render::Documentation;
+
+use crate::frontend::ast::{
+    ClassMethod, CommentKind, EnumVariant, EnumVariantKind, Expr, ExprKind, ImplMethod, Param,
+    StructField, TraitMethod, Type, TypeKind,
+};
+use crate::frontend::parser::Parser;
+use anyhow::{bail, Result};
+use serde::Serialize;
+use std::collections::BTreeMap;
 
 /// Documentation generator for Ruchy code
 pub struct DocGenerator {
@@ -27,70 +47,152 @@ impl DocGenerator {
         self.sort_order = order;
     }
 
-    /// Extract documentation comments from AST
-    pub fn extract_docs(&self, _ast: &Expr) -> Vec<String> {
-        // Simplified implementation - return different strings based on test context
-        // In a real implementation, we'd parse the AST for doc comments
-        vec![
-            "Adds two numbers together".to_string(),
-            "Math utilities module".to_string(),
-        ]
+    /// List undocumented private items too
+    pub fn set_include_private(&mut self, include: bool) {
+        self.include_private = include;
     }
 
-    /// Generate documentation in specified format
-    pub fn generate(&self, ast: &Expr, format: DocFormat) -> Result<String> {
-        let _docs = self.extract_docs(ast);
-
-        match format {
-            DocFormat::Markdown => {
-                let mut output = String::from("# Documentation\n\n");
-
-                // Add function documentation
-                if let ExprKind::Function { name, .. } = &ast.kind {
-                    output.push_str(&format!("## Function: {name}\n\n"));
-                    output.push_str("test\n");
-                }
-
-                Ok(output)
+    /// Documented items of a program, in the generator's sort order
+    pub fn extract_docs(&self, ast: &Expr) -> Vec<DocItem> {
+        let mut items = Vec::new();
+        let mut impls = Vec::new();
+        self.collect(ast, &[], &mut items, &mut impls);
+        // Methods of `impl` blocks belong to their type, wherever it is declared
+        for (module, for_type, methods) in impls {
+            let owner = items.iter_mut().find(|item| {
+                item.module == module && item.name == for_type && item.kind.has_methods()
+            });
+            if let Some(owner) = owner {
+                owner.members.extend(methods);
             }
-            DocFormat::Html => Ok("<html><body>example</body></html>".to_string()),
-            DocFormat::Json => Ok(r#"{"name":"data"}"#.to_string()),
         }
+        self.sort(&mut items);
+        items
     }
 
-    /// Extract code examples from documentation
-    pub fn extract_examples(&self, _ast: &Expr) -> Vec<String> {
-        vec!["let result = factorial(5);".to_string()]
+    /// Documentation of a program as a single page
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the items cannot be serialized to JSON.
+    pub fn generate(&self, ast: &Expr, format: DocFormat) -> Result<String> {
+        let page = DocPage {
+            name: "index".to_string(),
+            source: "program".to_string(),
+            items: self.extract_docs(ast),
+        };
+        Documentation::new(vec![page]).render_page(0, format)
     }
 
-    /// Validate code examples
-    pub fn validate_examples(&self, _ast: &Expr) -> Result<()> {
-        Ok(())
+    /// Code blocks of the doc comments that are Ruchy examples
+    ///
+    /// A fenced block is an example unless its info string names another
+    /// language or marks it `ignore`; see [`doctest`].
+    pub fn extract_examples(&self, ast: &Expr) -> Vec<String> {
+        self.extract_docs(ast)
+            .iter()
+            .flat_map(|item| {
+                std::iter::once(item.docs.as_str())
+                    .chain(item.members.iter().map(|member| member.docs.as_str()))
+            })
+            .flat_map(examples)
+            .collect()
     }
 
-    /// Extract attributes from AST
-    pub fn extract_attributes(&self, _ast: &Expr) -> Vec<String> {
-        vec![]
+    /// Check that every example in the doc comments parses
+    ///
+    /// # Errors
+    ///
+    /// Returns an error listing the examples that do not parse.
+    pub fn validate_examples(&self, ast: &Expr) -> Result<()> {
+        let failures: Vec<String> = self
+            .extract_examples(ast)
+            .iter()
+            .filter_map(|example| {
+                let error = Parser::new(example).parse().err()?;
+                Some(format!("{}: {error}", example.lines().next().unwrap_or("")))
+            })
+            .collect();
+        if !failures.is_empty() {
+            bail!("Examples do not parse:\n{}", failures.join("\n"));
+        }
+        Ok(())
     }
 
-    /// Extract inline documentation
-    pub fn extract_inline_docs(&self, _ast: &Expr) -> Vec<String> {
-        vec![]
+    /// Documented items grouped by module path; top-level items are under `""`
+    pub fn group_by_module(&self, ast: &Expr) -> BTreeMap<String, Vec<DocItem>> {
+        let mut groups: BTreeMap<String, Vec<DocItem>> = BTreeMap::new();
+        for item in self.extract_docs(ast) {
+            groups.entry(item.module.join("::")).or_default().push(item);
+        }
+        groups
     }
 
-    /// Group documentation by module
-    pub fn group_by_module(&self, _ast: &Expr) -> HashMap<String, Vec<String>> {
-        HashMap::new()
+    /// Check that every intra-doc link names a documented item
+    ///
+    /// # Errors
+    ///
+    /// Returns an error listing the links that do not resolve.
+    pub fn resolve_links(&self, ast: &Expr) -> Result<()> {
+        let page = DocPage {
+            name: "index".to_string(),
+            source: "program".to_string(),
+            items: self.extract_docs(ast),
+        };
+        let unresolved = Documentation::new(vec![page]).unresolved_links();
+        if !unresolved.is_empty() {
+            bail!("Unresolved links: {}", unresolved.join(", "));
+        }
+        Ok(())
     }
 
-    /// Generate documentation index
-    pub fn generate_index(&self, _ast: &Expr) -> String {
-        String::new()
+    /// Collect the items of `expr` declared in `module` (complexity: 9)
+    fn collect(
+        &self,
+        expr: &Expr,
+        module: &[String],
+        items: &mut Vec<DocItem>,
+        impls: &mut Vec<(Vec<String>, String, Vec<DocMember>)>,
+    ) {
+        match &expr.kind {
+            ExprKind::Block(exprs) => {
+                for expr in exprs {
+                    self.collect(expr, module, items, impls);
+                }
+            }
+            ExprKind::Module { name, body } => {
+                let mut path = module.to_vec();
+                path.push(name.clone());
+                self.collect(body, &path, items, impls);
+            }
+            ExprKind::Impl {
+                for_type, methods, ..
+            } => {
+                // `impl Stack<T>` adds to `Stack`
+                let name = for_type.split('<').next().unwrap_or(for_type).trim();
+                let methods = methods.iter().map(impl_method).collect();
+                impls.push((module.to_vec(), name.to_string(), methods));
+            }
+            _ => {
+                let Some((mut item, is_pub)) = declaration(expr) else {
+                    return;
+                };
+                item.docs = doc_text(expr);
+                if is_pub || !item.docs.is_empty() || self.include_private {
+                    item.module = module.to_vec();
+                    items.push(item);
+                }
+            }
+        }
     }
 
-    /// Resolve cross-references and links
-    pub fn resolve_links(&self, _ast: &Expr) -> Result<()> {
-        Ok(())
+    /// Order items as configured (complexity: 3)
+    fn sort(&self, items: &mut [DocItem]) {
+        match self.sort_order {
+            SortOrder::Source => {}
+            SortOrder::Alphabetical => items.sort_by_key(DocItem::path),
+            SortOrder::ByKind => items.sort_by_key(|item| item.kind),
+        }
     }
 }
 
@@ -111,6 +213,27 @@ pub enum DocFormat {
     Json,
 }
 
+impl DocFormat {
+    /// Format named on the command line, if there is one
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "markdown" | "md" => Some(Self::Markdown),
+            "html" => Some(Self::Html),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    /// Extension of the files written in this format
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Html => "html",
+            Self::Json => "json",
+        }
+    }
+}
+
 /// Sort order for documentation
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SortOrder {
@@ -122,10 +245,452 @@ pub enum SortOrder {
     ByKind,
 }
 
+/// Kind of a documented item or member
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DocKind {
+    /// `fun`
+    Function,
+    /// `struct`, with named or tuple fields
+    Struct,
+    /// `class`
+    Class,
+    /// `enum`
+    Enum,
+    /// `trait`
+    Trait,
+    /// `type` alias
+    TypeAlias,
+    /// Field of a struct, class or struct variant
+    Field,
+    /// Variant of an enum
+    Variant,
+    /// Method of a type or trait
+    Method,
+}
+
+impl DocKind {
+    /// Keyword shown before the item's name, also used in anchors
+    pub fn keyword(self) -> &'static str {
+        match self {
+            Self::Function => "fn",
+            Self::Struct => "struct",
+            Self::Class => "class",
+            Self::Enum => "enum",
+            Self::Trait => "trait",
+            Self::TypeAlias => "type",
+            Self::Field => "field",
+            Self::Variant => "variant",
+            Self::Method => "method",
+        }
+    }
+
+    /// Whether `impl` blocks can add methods to items of this kind
+    fn has_methods(self) -> bool {
+        matches!(self, Self::Struct | Self::Class | Self::Enum)
+    }
+}
+
+/// A documented item
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DocItem {
+    /// What the item is
+    pub kind: DocKind,
+    /// Name of the item
+    pub name: String,
+    /// Modules the item is declared in, outermost first
+    pub module: Vec<String>,
+    /// Declaration without its body
+    pub signature: String,
+    /// Markdown of the item's doc comment; empty if it has none
+    pub docs: String,
+    /// Fields, variants and methods
+    pub members: Vec<DocMember>,
+}
+
+impl DocItem {
+    /// Name of the item qualified by its modules
+    pub fn path(&self) -> String {
+        self.module
+            .iter()
+            .chain(std::iter::once(&self.name))
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("::")
+    }
+}
+
+/// A field, variant or method of a documented item
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DocMember {
+    /// What the member is
+    pub kind: DocKind,
+    /// Name of the member
+    pub name: String,
+    /// Declaration without its body
+    pub signature: String,
+    /// Markdown of the member's doc comment; empty if it has none
+    pub docs: String,
+}
+
+/// Documented items of one source file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DocPage {
+    /// File name of the page, without extension
+    pub name: String,
+    /// Source file the page documents
+    pub source: String,
+    /// Items of the page
+    pub items: Vec<DocItem>,
+}
+
+/// Item declared by `expr` and whether it is `pub` (complexity: 8)
+fn declaration(expr: &Expr) -> Option<(DocItem, bool)> {
+    let item = |kind, name: &String, signature, members| DocItem {
+        kind,
+        name: name.clone(),
+        module: Vec::new(),
+        signature,
+        docs: String::new(),
+        members,
+    };
+    Some(match &expr.kind {
+        ExprKind::Function {
+            name,
+            type_params,
+            params,
+            return_type,
+            is_async,
+            is_pub,
+            ..
+        } => {
+            let prefix = format!(
+                "{}{}",
+                visibility(*is_pub),
+                if *is_async { "async " } else { "" }
+            );
+            let signature =
+                function_signature(&prefix, name, type_params, params, return_type.as_ref());
+            (
+                item(DocKind::Function, name, signature, Vec::new()),
+                *is_pub,
+            )
+        }
+        ExprKind::Struct {
+            name,
+            type_params,
+            fields,
+            methods,
+            is_pub,
+            ..
+        } => {
+            let signature = format!(
+                "{}struct {name}{}",
+                visibility(*is_pub),
+                generics(type_params)
+            );
+            let members = fields
+                .iter()
+                .map(field)
+                .chain(methods.iter().map(class_method));
+            (
+                item(DocKind::Struct, name, signature, members.collect()),
+                *is_pub,
+            )
+        }
+        ExprKind::TupleStruct {
+            name,
+            type_params,
+            fields,
+            is_pub,
+            ..
+        } => {
+            let types: Vec<String> = fields.iter().map(type_name).collect();
+            let signature = format!(
+                "{}struct {name}{}({})",
+                visibility(*is_pub),
+                generics(type_params),
+                types.join(", ")
+            );
+            (item(DocKind::Struct, name, signature, Vec::new()), *is_pub)
+        }
+        ExprKind::Class {
+            name,
+            type_params,
+            superclass,
+            fields,
+            methods,
+            is_pub,
+            ..
+        } => {
+            let parent = superclass
+                .as_ref()
+                .map(|parent| format!(" : {parent}"))
+                .unwrap_or_default();
+            let signature = format!(
+                "{}class {name}{}{parent}",
+                visibility(*is_pub),
+                generics(type_params)
+            );
+            let members = fields
+                .iter()
+                .map(field)
+                .chain(methods.iter().map(class_method));
+            (
+                item(DocKind::Class, name, signature, members.collect()),
+                *is_pub,
+            )
+        }
+        ExprKind::Enum {
+            name,
+            type_params,
+            variants,
+            is_pub,
+        } => {
+            let signature = format!(
+                "{}enum {name}{}",
+                visibility(*is_pub),
+                generics(type_params)
+            );
+            let members = variants.iter().map(variant).collect();
+            (item(DocKind::Enum, name, signature, members), *is_pub)
+        }
+        ExprKind::Trait {
+            name,
+            type_params,
+            methods,
+            is_pub,
+            ..
+        } => {
+            let signature = format!(
+                "{}trait {name}{}",
+                visibility(*is_pub),
+                generics(type_params)
+            );
+            let members = methods.iter().map(trait_method).collect();
+            (item(DocKind::Trait, name, signature, members), *is_pub)
+        }
+        ExprKind::TypeAlias { name, target_type } => {
+            let signature = format!("type {name} = {}", type_name(target_type));
+            (item(DocKind::TypeAlias, name, signature, Vec::new()), false)
+        }
+        _ => return None,
+    })
+}
+
+/// Markdown of the `///` comments before an item (complexity: 2)
+fn doc_text(expr: &Expr) -> String {
+    doc_lines(expr.leading_comments.iter().map(|comment| &comment.kind))
+}
+
+/// Text of the doc comments among `comments`, one line each (complexity: 2)
+fn doc_lines<'a>(comments: impl Iterator<Item = &'a CommentKind>) -> String {
+    comments
+        .filter_map(|comment| match comment {
+            CommentKind::Doc(text) => Some(doc_line(text)),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Markdown of one `///` line, without the space after the slashes (complexity: 1)
+fn doc_line(text: &str) -> &str {
+    text.strip_prefix(' ').unwrap_or(text).trim_end()
+}
+
+/// Ruchy code blocks of `docs` that are not ignored (complexity: 2)
+fn examples(docs: &str) -> Vec<String> {
+    doctest::code_blocks(docs.lines())
+        .into_iter()
+        .filter(|block| {
+            DoctestMode::from_info(&block.info).is_some_and(|mode| mode != DoctestMode::Ignore)
+        })
+        .map(|block| block.code)
+        .collect()
+}
+
+/// Member for a struct or class field (complexity: 1)
+fn field(field: &StructField) -> DocMember {
+    DocMember {
+        kind: DocKind::Field,
+        name: field.name.clone(),
+        signature: format!(
+            "{}{}: {}",
+            visibility(field.visibility.is_public()),
+            field.name,
+            type_name(&field.ty)
+        ),
+        docs: doc_lines(field.leading_comments.iter().map(|comment| &comment.kind)),
+    }
+}
+
+/// Member for an enum variant (complexity: 4)
+fn variant(variant: &EnumVariant) -> DocMember {
+    let shape = match &variant.kind {
+        EnumVariantKind::Unit => String::new(),
+        EnumVariantKind::Tuple(types) => {
+            let types: Vec<String> = types.iter().map(type_name).collect();
+            format!("({})", types.join(", "))
+        }
+        EnumVariantKind::Struct(fields) => {
+            let fields: Vec<String> = fields
+                .iter()
+                .map(|field| format!("{}: {}", field.name, type_name(&field.ty)))
+                .collect();
+            format!(" {{ {} }}", fields.join(", "))
+        }
+    };
+    let discriminant = variant
+        .discriminant
+        .map(|value| format!(" = {value}"))
+        .unwrap_or_default();
+    DocMember {
+        kind: DocKind::Variant,
+        name: variant.name.clone(),
+        signature: format!("{}{shape}{discriminant}", variant.name),
+        docs: doc_lines(variant.leading_comments.iter().map(|comment| &comment.kind)),
+    }
+}
+
+/// Member for a method declared in a struct or class body (complexity: 2)
+fn class_method(method: &ClassMethod) -> DocMember {
+    let prefix = format!(
+        "{}{}{}",
+        visibility(method.is_pub),
+        if method.is_static { "static " } else { "" },
+        if method.is_async { "async " } else { "" }
+    );
+    method_member(
+        &prefix,
+        &method.name,
+        &method.params,
+        method.return_type.as_ref(),
+    )
+}
+
+/// Member for a method of an `impl` block (complexity: 1)
+fn impl_method(method: &ImplMethod) -> DocMember {
+    method_member(
+        visibility(method.is_pub),
+        &method.name,
+        &method.params,
+        method.return_type.as_ref(),
+    )
+}
+
+/// Member for a trait method (complexity: 1)
+fn trait_method(method: &TraitMethod) -> DocMember {
+    method_member(
+        visibility(method.is_pub),
+        &method.name,
+        &method.params,
+        method.return_type.as_ref(),
+    )
+}
+
+/// Member for a method; methods carry no comments in the AST (complexity: 1)
+fn method_member(
+    prefix: &str,
+    name: &str,
+    params: &[Param],
+    return_type: Option<&Type>,
+) -> DocMember {
+    DocMember {
+        kind: DocKind::Method,
+        name: name.to_string(),
+        signature: function_signature(prefix, name, &[], params, return_type),
+        docs: String::new(),
+    }
+}
+
+/// `fun` declaration without its body (complexity: 2)
+fn function_signature(
+    prefix: &str,
+    name: &str,
+    type_params: &[String],
+    params: &[Param],
+    return_type: Option<&Type>,
+) -> String {
+    let params: Vec<String> = params.iter().map(param).collect();
+    let result = return_type
+        .map(|ty| format!(" -> {}", type_name(ty)))
+        .unwrap_or_default();
+    format!(
+        "{prefix}fun {name}{}({}){result}",
+        generics(type_params),
+        params.join(", ")
+    )
+}
+
+/// A parameter as declared; untyped parameters are just named (complexity: 4)
+fn param(param: &Param) -> String {
+    let name = param.name();
+    if name == "self" {
+        return match &param.ty.kind {
+            TypeKind::Reference { is_mut: true, .. } => "&mut self".to_string(),
+            TypeKind::Reference { .. } => "&self".to_string(),
+            _ => name,
+        };
+    }
+    let name = if param.is_mutable {
+        format!("mut {name}")
+    } else {
+        name
+    };
+    match &param.ty.kind {
+        TypeKind::Named(ty) if ty == "_" || ty == "Any" => name,
+        _ => format!("{name}: {}", type_name(&param.ty)),
+    }
+}
+
+/// A type in Ruchy syntax (complexity: 10)
+fn type_name(ty: &Type) -> String {
+    let list = |types: &[Type]| types.iter().map(type_name).collect::<Vec<_>>().join(", ");
+    match &ty.kind {
+        TypeKind::Named(name) => name.clone(),
+        TypeKind::Generic { base, params } if params.is_empty() => base.clone(),
+        TypeKind::Generic { base, params } => format!("{base}<{}>", list(params)),
+        TypeKind::Optional(inner) => format!("{}?", type_name(inner)),
+        TypeKind::List(inner) => format!("[{}]", type_name(inner)),
+        TypeKind::Array { elem_type, size } => format!("[{}; {size}]", type_name(elem_type)),
+        TypeKind::Tuple(types) => format!("({})", list(types)),
+        TypeKind::Function { params, ret } => format!("({}) -> {}", list(params), type_name(ret)),
+        TypeKind::DataFrame { .. } => "DataFrame".to_string(),
+        TypeKind::Series { dtype } => format!("Series<{}>", type_name(dtype)),
+        TypeKind::Reference { is_mut, inner, .. } => {
+            format!("&{}{}", if *is_mut { "mut " } else { "" }, type_name(inner))
+        }
+        TypeKind::Refined { base, .. } => type_name(base),
+    }
+}
+
+/// Type parameter list, if there are any (complexity: 2)
+fn generics(type_params: &[String]) -> String {
+    if type_params.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", type_params.join(", "))
+    }
+}
+
+/// `pub ` for public declarations (complexity: 1)
+fn visibility(is_pub: bool) -> &'static str {
+    if is_pub {
+        "pub "
+    } else {
+        ""
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::frontend::ast::{Expr, ExprKind, Literal};
+
+    fn docs_of(source: &str) -> Vec<DocItem> {
+        let ast = Parser::new(source).parse().expect("should parse");
+        DocGenerator::new().extract_docs(&ast)
+    }
 
     #[test]
     fn test_doc_generator_new() {
@@ -149,175 +714,150 @@ mod tests {
 
     #[test]
     fn test_extract_docs() {
-        let gen = DocGenerator::new();
-        let ast = Expr {
-            kind: ExprKind::Literal(Literal::Integer(42, None)),
-            span: Default::default(),
-            attributes: vec![],
-            leading_comments: vec![],
-            trailing_comment: None,
-            contracts: Vec::new(),
-        };
-        let docs = gen.extract_docs(&ast);
-        assert_eq!(docs.len(), 2);
+        let items = docs_of(
+            "/// Adds two numbers\n/// together\nfun add(a: i32, b) -> i32 { a + b }\n\
+             // Not documented\nfun helper() { 1 }",
+        );
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].kind, DocKind::Function);
+        assert_eq!(items[0].signature, "fun add(a: i32, b) -> i32");
+        assert_eq!(items[0].docs, "Adds two numbers\ntogether");
     }
 
     #[test]
-    fn test_generate_markdown() {
-        let gen = DocGenerator::new();
-        let ast = Expr {
-            kind: ExprKind::Literal(Literal::Integer(42, None)),
-            span: Default::default(),
-            attributes: vec![],
-            leading_comments: vec![],
-            trailing_comment: None,
-            contracts: Vec::new(),
-        };
-        let result = gen.generate(&ast, DocFormat::Markdown);
-        assert!(result.is_ok());
-    }
-
-    #[test]
-    fn test_generate_html() {
-        let gen = DocGenerator::new();
-        let ast = Expr {
-            kind: ExprKind::Literal(Literal::Integer(42, None)),
-            span: Default::default(),
-            attributes: vec![],
-            leading_comments: vec![],
-            trailing_comment: None,
-            contracts: Vec::new(),
-        };
-        let result = gen.generate(&ast, DocFormat::Html);
-        assert!(result.is_ok());
-        assert!(result.unwrap().contains("html"));
+    fn test_private_items_are_included_on_request() {
+        let ast = Parser::new("fun helper() { 1 }\nfun other() { 2 }")
+            .parse()
+            .expect("should parse");
+        let mut gen = DocGenerator::new();
+        assert!(gen.extract_docs(&ast).is_empty());
+        gen.set_include_private(true);
+        assert_eq!(gen.extract_docs(&ast).len(), 2);
     }
 
     #[test]
-    fn test_generate_json() {
-        let gen = DocGenerator::new();
-        let ast = Expr {
-            kind: ExprKind::Literal(Literal::Integer(42, None)),
-            span: Default::default(),
-            attributes: vec![],
-            leading_comments: vec![],
-            trailing_comment: None,
-            contracts: Vec::new(),
-        };
-        let result = gen.generate(&ast, DocFormat::Json);
-        assert!(result.is_ok());
-        assert!(result.unwrap().contains("name"));
+    fn test_type_members_are_documented() {
+        let items = docs_of(
+            "/// A point\nstruct Point {\n    /// Horizontal\n    x: f64,\n    y: f64\n}\n\
+             impl Point {\n    fun norm(&self) -> f64 { self.x }\n}\n\
+             /// Shapes\nenum Shape {\n    /// Round\n    Circle(f64),\n    Empty\n}",
+        );
+        let point = &items[0];
+        assert_eq!(point.signature, "struct Point");
+        let members: Vec<(&str, &str)> = point
+            .members
+            .iter()
+            .map(|member| (member.signature.as_str(), member.docs.as_str()))
+            .collect();
+        assert_eq!(
+            members,
+            vec![
+                ("x: f64", "Horizontal"),
+                ("y: f64", ""),
+                ("fun norm(&self) -> f64", "")
+            ]
+        );
+        let variants: Vec<&str> = items[1]
+            .members
+            .iter()
+            .map(|m| m.signature.as_str())
+            .collect();
+        assert_eq!(variants, vec!["Circle(f64)", "Empty"]);
+        assert_eq!(items[1].members[0].docs, "Round");
     }
 
     #[test]
-    fn test_extract_examples() {
-        let gen = DocGenerator::new();
-        let ast = Expr {
-            kind: ExprKind::Literal(Literal::Integer(42, None)),
-            span: Default::default(),
-            attributes: vec![],
-            leading_comments: vec![],
-            trailing_comment: None,
-            contracts: Vec::new(),
-        };
-        let examples = gen.extract_examples(&ast);
-        assert_eq!(examples.len(), 1);
+    fn test_traits_and_modules() {
+        let items = docs_of(
+            "mod geometry {\n    /// Has an area\n    trait Area {\n        fun area(&self) -> f64\n    }\n}",
+        );
+        assert_eq!(items[0].kind, DocKind::Trait);
+        assert_eq!(items[0].path(), "geometry::Area");
+        assert_eq!(items[0].signature, "trait Area");
+        assert_eq!(items[0].members[0].signature, "fun area(&self) -> f64");
     }
 
     #[test]
-    fn test_validate_examples() {
-        let gen = DocGenerator::new();
-        let ast = Expr {
-            kind: ExprKind::Literal(Literal::Integer(42, None)),
-            span: Default::default(),
-            attributes: vec![],
-            leading_comments: vec![],
-            trailing_comment: None,
-            contracts: Vec::new(),
-        };
-        let result = gen.validate_examples(&ast);
-        assert!(result.is_ok());
+    fn test_alphabetical_order() {
+        let ast = Parser::new("/// b\nfun b() { 1 }\n/// a\nfun a() { 2 }")
+            .parse()
+            .expect("should parse");
+        let mut gen = DocGenerator::new();
+        gen.set_sort_order(SortOrder::Alphabetical);
+        let names: Vec<String> = gen
+            .extract_docs(&ast)
+            .into_iter()
+            .map(|item| item.name)
+            .collect();
+        assert_eq!(names, vec!["a", "b"]);
     }
 
     #[test]
-    fn test_extract_attributes() {
-        let gen = DocGenerator::new();
-        let ast = Expr {
-            kind: ExprKind::Literal(Literal::Integer(42, None)),
-            span: Default::default(),
-            attributes: vec![],
-            leading_comments: vec![],
-            trailing_comment: None,
-            contracts: Vec::new(),
-        };
-        let attrs = gen.extract_attributes(&ast);
-        assert_eq!(attrs.len(), 0);
+    fn test_generate_markdown() {
+        let ast = Parser::new("/// Says hi\nfun hi() { 1 }")
+            .parse()
+            .expect("should parse");
+        let markdown = DocGenerator::new()
+            .generate(&ast, DocFormat::Markdown)
+            .expect("should render");
+        assert!(markdown.contains("fun hi()"), "{markdown}");
+        assert!(markdown.contains("Says hi"), "{markdown}");
     }
 
     #[test]
-    fn test_extract_inline_docs() {
+    fn test_extract_and_validate_examples() {
+        let ast = Parser::new(
+            "/// Factorial\n///\n/// ```\n/// let result = factorial(5)\n/// ```\n\
+             /// ```text\n/// not code (\n/// ```\nfun factorial(n) { n }",
+        )
+        .parse()
+        .expect("should parse");
         let gen = DocGenerator::new();
-        let ast = Expr {
-            kind: ExprKind::Literal(Literal::Integer(42, None)),
-            span: Default::default(),
-            attributes: vec![],
-            leading_comments: vec![],
-            trailing_comment: None,
-            contracts: Vec::new(),
-        };
-        let docs = gen.extract_inline_docs(&ast);
-        assert_eq!(docs.len(), 0);
+        assert_eq!(
+            gen.extract_examples(&ast),
+            vec!["let result = factorial(5)"]
+        );
+        assert!(gen.validate_examples(&ast).is_ok());
+
+        let broken = Parser::new("/// ```ruchy\n/// fun (\n/// ```\nfun f() { 1 }")
+            .parse()
+            .expect("should parse");
+        assert!(gen.validate_examples(&broken).is_err());
     }
 
     #[test]
     fn test_group_by_module() {
-        let gen = DocGenerator::new();
-        let ast = Expr {
-            kind: ExprKind::Literal(Literal::Integer(42, None)),
-            span: Default::default(),
-            attributes: vec![],
-            leading_comments: vec![],
-            trailing_comment: None,
-            contracts: Vec::new(),
-        };
-        let groups = gen.group_by_module(&ast);
-        assert!(groups.is_empty());
-    }
-
-    #[test]
-    fn test_generate_index() {
-        let gen = DocGenerator::new();
-        let ast = Expr {
-            kind: ExprKind::Literal(Literal::Integer(42, None)),
-            span: Default::default(),
-            attributes: vec![],
-            leading_comments: vec![],
-            trailing_comment: None,
-            contracts: Vec::new(),
-        };
-        let index = gen.generate_index(&ast);
-        assert!(index.is_empty());
+        let ast =
+            Parser::new("/// top\nfun a() { 1 }\nmod m {\n    /// inner\n    fun b() { 2 }\n}")
+                .parse()
+                .expect("should parse");
+        let groups = DocGenerator::new().group_by_module(&ast);
+        assert_eq!(groups.keys().collect::<Vec<_>>(), vec!["", "m"]);
+        assert_eq!(groups["m"][0].name, "b");
     }
 
     #[test]
     fn test_resolve_links() {
         let gen = DocGenerator::new();
-        let ast = Expr {
-            kind: ExprKind::Literal(Literal::Integer(42, None)),
-            span: Default::default(),
-            attributes: vec![],
-            leading_comments: vec![],
-            trailing_comment: None,
-            contracts: Vec::new(),
-        };
-        let result = gen.resolve_links(&ast);
-        assert!(result.is_ok());
+        let linked = Parser::new("/// Uses [`b`]\nfun a() { 1 }\n/// b\nfun b() { 2 }")
+            .parse()
+            .expect("should parse");
+        assert!(gen.resolve_links(&linked).is_ok());
+        let dangling = Parser::new("/// Uses [`missing`]\nfun a() { 1 }")
+            .parse()
+            .expect("should parse");
+        let err = gen
+            .resolve_links(&dangling)
+            .expect_err("link does not resolve");
+        assert!(err.to_string().contains("missing"), "{err}");
     }
 
     #[test]
     fn test_doc_format_equality() {
         assert_eq!(DocFormat::Markdown, DocFormat::Markdown);
         assert_ne!(DocFormat::Markdown, DocFormat::Html);
+        assert_eq!(DocFormat::from_name("markdown"), Some(DocFormat::Markdown));
+        assert_eq!(DocFormat::from_name("pdf"), None);
     }
 
     #[test]
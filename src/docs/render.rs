@@ -0,0 +1,618 @@
+//! Rendering documented items as Markdown, HTML or JSON
+//!
+//! Each page documents one source file, and the index lists the pages with
+//! their items. Every item has an anchor named after its kind and path,
+//! such as `fn.add` or `struct.geometry.Point`, which intra-doc links and
+//! the index point to. Intra-doc links inside code spans and code blocks
+//! are left alone.
+//!
+//! HTML pages render doc comments with the `markdown` feature; without it
+//! they are shown as written. Raw HTML in a doc comment is shown as text.
+
+use super::{DocFormat, DocItem, DocKind, DocMember, DocPage};
+use anyhow::Result;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::ops::Range;
+
+const STYLE: &str = "body { font-family: Arial, sans-serif; margin: 40px; max-width: 60em; }
+h1 { color: #333; }
+h2 { color: #666; border-bottom: 1px solid #ddd; padding-bottom: 5px; }
+h2 a { color: inherit; text-decoration: none; }
+.kind { color: #999; font-weight: normal; }
+code { background: #f4f4f4; padding: 2px 5px; border-radius: 3px; }
+pre { background: #f4f4f4; padding: 8px; border-radius: 3px; }
+pre code { padding: 0; }
+dd { margin-bottom: 1em; }
+";
+
+/// Where an item is documented
+#[derive(Debug, Clone)]
+struct Target {
+    page: usize,
+    anchor: String,
+}
+
+/// Pages of documentation and the symbols they link to
+#[derive(Debug)]
+pub struct Documentation {
+    pages: Vec<DocPage>,
+    /// Items by path
+    paths: HashMap<String, Target>,
+    /// The first item of each name, for links that leave out the modules
+    names: HashMap<String, Target>,
+}
+
+/// An intra-doc link candidate in a doc comment
+struct IntraDocLink<'a> {
+    /// Bytes of the whole `[...]`
+    range: Range<usize>,
+    /// Text between the brackets
+    text: &'a str,
+    /// Path the link names
+    target: &'a str,
+    /// Whether the link is clearly meant as one, so failing to resolve it is worth reporting
+    explicit: bool,
+}
+
+impl Documentation {
+    /// Documentation of `pages`; names link to the first item declaring them
+    pub fn new(pages: Vec<DocPage>) -> Self {
+        let mut paths = HashMap::new();
+        let mut names = HashMap::new();
+        for (page, doc) in pages.iter().enumerate() {
+            for item in &doc.items {
+                let target = Target {
+                    page,
+                    anchor: anchor(item),
+                };
+                names
+                    .entry(item.name.clone())
+                    .or_insert_with(|| target.clone());
+                paths.entry(item.path()).or_insert(target);
+            }
+        }
+        Self {
+            pages,
+            paths,
+            names,
+        }
+    }
+
+    /// The documented pages
+    pub fn pages(&self) -> &[DocPage] {
+        &self.pages
+    }
+
+    /// Name of the file page `page` is written to
+    pub fn file_name(&self, page: usize, format: DocFormat) -> String {
+        format!("{}.{}", self.pages[page].name, format.extension())
+    }
+
+    /// Page `page` in `format`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the page cannot be serialized to JSON.
+    pub fn render_page(&self, page: usize, format: DocFormat) -> Result<String> {
+        Ok(match format {
+            DocFormat::Markdown => self.markdown_page(page),
+            DocFormat::Html => self.html_page(page),
+            DocFormat::Json => serde_json::to_string_pretty(&self.pages[page])?,
+        })
+    }
+
+    /// Index of all pages in `format`; the JSON index holds every page
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pages cannot be serialized to JSON.
+    pub fn render_index(&self, format: DocFormat) -> Result<String> {
+        Ok(match format {
+            DocFormat::Markdown => self.markdown_index(),
+            DocFormat::Html => self.html_index(),
+            DocFormat::Json => serde_json::to_string_pretty(&self.pages)?,
+        })
+    }
+
+    /// Intra-doc links that name no documented item, with the items using them
+    pub fn unresolved_links(&self) -> Vec<String> {
+        let mut unresolved = Vec::new();
+        for item in self.pages.iter().flat_map(|page| &page.items) {
+            let docs = std::iter::once(item.docs.as_str())
+                .chain(item.members.iter().map(|member| member.docs.as_str()));
+            for link in docs.flat_map(intra_doc_links) {
+                if link.explicit && self.resolve(&item.module, link.target).is_none() {
+                    unresolved.push(format!("[{}] in {}", link.text, item.path()));
+                }
+            }
+        }
+        unresolved
+    }
+
+    /// Item a link from `module` names (complexity: 4)
+    ///
+    /// A path is looked up relative to `module` first, then from the top
+    /// level; a bare name falls back to the first item of that name.
+    fn resolve(&self, module: &[String], target: &str) -> Option<&Target> {
+        let relative = (!module.is_empty()).then(|| format!("{}::{target}", module.join("::")));
+        relative
+            .and_then(|path| self.paths.get(&path))
+            .or_else(|| self.paths.get(target))
+            .or_else(|| self.names.get(target))
+    }
+
+    /// Link from page `from` (or from the index) to `target` (complexity: 2)
+    fn href(&self, from: Option<usize>, target: &Target, format: DocFormat) -> String {
+        if from == Some(target.page) {
+            format!("#{}", target.anchor)
+        } else {
+            format!("{}#{}", self.file_name(target.page, format), target.anchor)
+        }
+    }
+
+    /// `docs` with the intra-doc links that resolve turned into Markdown links (complexity: 3)
+    fn link_docs(&self, docs: &str, page: usize, module: &[String], format: DocFormat) -> String {
+        let mut out = String::with_capacity(docs.len());
+        let mut last = 0;
+        for link in intra_doc_links(docs) {
+            if let Some(target) = self.resolve(module, link.target) {
+                out.push_str(&docs[last..link.range.start]);
+                let _ = write!(
+                    out,
+                    "[{}]({})",
+                    link.text,
+                    self.href(Some(page), target, format)
+                );
+                last = link.range.end;
+            }
+        }
+        out.push_str(&docs[last..]);
+        out
+    }
+
+    /// Markdown page (complexity: 5)
+    fn markdown_page(&self, page: usize) -> String {
+        let doc = &self.pages[page];
+        let mut out = format!("# Documentation for {}\n\n", doc.source);
+        for item in &doc.items {
+            let _ = write!(
+                out,
+                "<a id=\"{}\"></a>\n\n## {} `{}`\n\n```ruchy\n{}\n```\n\n",
+                anchor(item),
+                item.kind.keyword(),
+                item.path(),
+                item.signature
+            );
+            if item.docs.is_empty() {
+                out.push_str("*No documentation available*\n\n");
+            } else {
+                let docs = self.link_docs(&item.docs, page, &item.module, DocFormat::Markdown);
+                let _ = write!(out, "{docs}\n\n");
+            }
+            for (title, members) in member_groups(&item.members) {
+                let _ = write!(out, "### {title}\n\n");
+                for member in members {
+                    let _ = writeln!(out, "- `{}`", member.signature);
+                    if !member.docs.is_empty() {
+                        let docs =
+                            self.link_docs(&member.docs, page, &item.module, DocFormat::Markdown);
+                        // Indented so the paragraphs stay in the list item
+                        for line in docs.lines() {
+                            let _ = write!(out, "\n  {line}");
+                        }
+                        out.push('\n');
+                    }
+                }
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    /// HTML page (complexity: 5)
+    fn html_page(&self, page: usize) -> String {
+        let doc = &self.pages[page];
+        let title = format!("Documentation for {}", escape(&doc.source));
+        let mut out = html_header(&title);
+        for item in &doc.items {
+            let anchor = anchor(item);
+            let _ = write!(
+                out,
+                "<section id=\"{anchor}\">\n<h2><span class=\"kind\">{}</span> <a href=\"#{anchor}\">{}</a></h2>\n<pre><code>{}</code></pre>\n",
+                item.kind.keyword(),
+                escape(&item.path()),
+                self.link_signature(&item.signature, page, item)
+            );
+            if item.docs.is_empty() {
+                out.push_str("<p><em>No documentation available</em></p>\n");
+            } else {
+                let docs = self.link_docs(&item.docs, page, &item.module, DocFormat::Html);
+                let _ = write!(
+                    out,
+                    "<div class=\"docs\">\n{}</div>\n",
+                    markdown_to_html(&docs)
+                );
+            }
+            for (title, members) in member_groups(&item.members) {
+                let _ = write!(out, "<h3>{title}</h3>\n<dl>\n");
+                for member in members {
+                    let signature = self.link_signature(&member.signature, page, item);
+                    let _ = writeln!(out, "<dt><code>{signature}</code></dt>");
+                    if !member.docs.is_empty() {
+                        let docs =
+                            self.link_docs(&member.docs, page, &item.module, DocFormat::Html);
+                        let _ = writeln!(out, "<dd>{}</dd>", markdown_to_html(&docs));
+                    }
+                }
+                out.push_str("</dl>\n");
+            }
+            out.push_str("</section>\n");
+        }
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+
+    /// Escaped `signature` with its type names linked to their items (complexity: 5)
+    ///
+    /// Only capitalized names are linked, so that parameters named like a
+    /// function are not; `item`'s own name stays plain.
+    fn link_signature(&self, signature: &str, page: usize, item: &DocItem) -> String {
+        let mut out = String::with_capacity(signature.len());
+        let mut last = 0;
+        for range in identifiers(signature) {
+            let path = &signature[range.clone()];
+            let name = path.rsplit("::").next().unwrap_or(path);
+            if !name.starts_with(char::is_uppercase) || name == item.name {
+                continue;
+            }
+            if let Some(target) = self.resolve(&item.module, path) {
+                out.push_str(&escape(&signature[last..range.start]));
+                let href = self.href(Some(page), target, DocFormat::Html);
+                let _ = write!(out, "<a href=\"{href}\">{}</a>", escape(path));
+                last = range.end;
+            }
+        }
+        out.push_str(&escape(&signature[last..]));
+        out
+    }
+
+    /// Markdown index (complexity: 3)
+    fn markdown_index(&self) -> String {
+        let mut out = String::from("# Documentation\n\n");
+        for (page, doc) in self.pages.iter().enumerate() {
+            let file = self.file_name(page, DocFormat::Markdown);
+            let _ = write!(out, "## [{}]({file})\n\n", doc.source);
+            for item in &doc.items {
+                let _ = write!(
+                    out,
+                    "- {} [`{}`]({file}#{})",
+                    item.kind.keyword(),
+                    item.path(),
+                    anchor(item)
+                );
+                match summary(&item.docs) {
+                    Some(summary) => {
+                        let _ = writeln!(out, ": {summary}");
+                    }
+                    None => out.push('\n'),
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// HTML index (complexity: 3)
+    fn html_index(&self) -> String {
+        let mut out = html_header("Documentation");
+        for (page, doc) in self.pages.iter().enumerate() {
+            let file = self.file_name(page, DocFormat::Html);
+            let _ = write!(
+                out,
+                "<h2><a href=\"{file}\">{}</a></h2>\n<ul>\n",
+                escape(&doc.source)
+            );
+            for item in &doc.items {
+                let _ = write!(
+                    out,
+                    "<li><span class=\"kind\">{}</span> <a href=\"{file}#{}\"><code>{}</code></a>",
+                    item.kind.keyword(),
+                    anchor(item),
+                    escape(&item.path())
+                );
+                if let Some(summary) = summary(&item.docs) {
+                    let _ = write!(out, ": {}", escape(summary));
+                }
+                out.push_str("</li>\n");
+            }
+            out.push_str("</ul>\n");
+        }
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+}
+
+/// Anchor of an item, unique per page (complexity: 1)
+fn anchor(item: &DocItem) -> String {
+    format!("{}.{}", item.kind.keyword(), item.path().replace("::", "."))
+}
+
+/// Members grouped by kind, in the order the kinds first appear (complexity: 4)
+fn member_groups(members: &[DocMember]) -> Vec<(&'static str, Vec<&DocMember>)> {
+    let mut groups: Vec<(DocKind, Vec<&DocMember>)> = Vec::new();
+    for member in members {
+        match groups.iter_mut().find(|(kind, _)| *kind == member.kind) {
+            Some((_, group)) => group.push(member),
+            None => groups.push((member.kind, vec![member])),
+        }
+    }
+    groups
+        .into_iter()
+        .map(|(kind, group)| {
+            let title = match kind {
+                DocKind::Field => "Fields",
+                DocKind::Variant => "Variants",
+                _ => "Methods",
+            };
+            (title, group)
+        })
+        .collect()
+}
+
+/// First line of a doc comment, if it has one (complexity: 1)
+fn summary(docs: &str) -> Option<&str> {
+    docs.lines().next().filter(|line| !line.trim().is_empty())
+}
+
+/// Intra-doc link candidates of `docs`, outside code (complexity: 10)
+///
+/// `[name]` and ``[`name`]`` are candidates when `name` is a path and the
+/// brackets are not already part of a Markdown link.
+fn intra_doc_links(docs: &str) -> Vec<IntraDocLink<'_>> {
+    let mut links = Vec::new();
+    let mut in_code_block = false;
+    let mut offset = 0;
+    for line in docs.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+        let bytes = line.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'`' => i = skip_code_span(line, i),
+                b'[' if i == 0 || bytes[i - 1] != b']' => {
+                    let Some(close) = line[i + 1..].find(']').map(|at| i + 1 + at) else {
+                        break;
+                    };
+                    let text = &line[i + 1..close];
+                    let followed = bytes.get(close + 1).copied();
+                    let quoted = text.strip_prefix('`').and_then(|t| t.strip_suffix('`'));
+                    let target = quoted.unwrap_or(text);
+                    let target = target.strip_suffix("()").unwrap_or(target);
+                    if !matches!(followed, Some(b'(' | b'[' | b':')) && is_path(target) {
+                        links.push(IntraDocLink {
+                            range: start + i..start + close + 1,
+                            text,
+                            target,
+                            explicit: quoted.is_some() || target.contains("::"),
+                        });
+                    }
+                    i = close + 1;
+                }
+                _ => i += 1,
+            }
+        }
+    }
+    links
+}
+
+/// Offset after the code span starting at `start` (complexity: 2)
+fn skip_code_span(line: &str, start: usize) -> usize {
+    let ticks = line[start..].len() - line[start..].trim_start_matches('`').len();
+    let fence = &line[start..start + ticks];
+    match line[start + ticks..].find(fence) {
+        Some(at) => start + ticks + at + ticks,
+        None => start + ticks,
+    }
+}
+
+/// Whether `text` is a `::`-separated path of identifiers (complexity: 2)
+fn is_path(text: &str) -> bool {
+    !text.is_empty()
+        && text.split("::").all(|segment| {
+            segment.starts_with(|c: char| c.is_alphabetic() || c == '_')
+                && segment.chars().all(|c| c.is_alphanumeric() || c == '_')
+        })
+}
+
+/// Byte ranges of the identifiers and paths in `text` (complexity: 5)
+fn identifiers(text: &str) -> Vec<Range<usize>> {
+    let is_start = |c: char| c.is_alphabetic() || c == '_';
+    let mut ranges = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if !is_start(c) {
+            continue;
+        }
+        let mut end = start + c.len_utf8();
+        loop {
+            let rest = &text[end..];
+            if let Some(c) = rest
+                .chars()
+                .next()
+                .filter(|&c| c.is_alphanumeric() || c == '_')
+            {
+                end += c.len_utf8();
+            } else if rest.starts_with("::") && rest[2..].starts_with(is_start) {
+                end += 2;
+            } else {
+                break;
+            }
+        }
+        ranges.push(start..end);
+        while chars.peek().is_some_and(|&(at, _)| at < end) {
+            chars.next();
+        }
+    }
+    ranges
+}
+
+/// Start of an HTML page, up to its heading (complexity: 1)
+fn html_header(title: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\n{STYLE}</style>\n</head>\n<body>\n<h1>{title}</h1>\n"
+    )
+}
+
+/// `text` escaped for HTML (complexity: 1)
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// HTML of a doc comment's Markdown (complexity: 1)
+#[cfg(feature = "markdown")]
+fn markdown_to_html(markdown: &str) -> String {
+    use pulldown_cmark::{html, Event, Options, Parser};
+
+    let events = Parser::new_ext(
+        markdown,
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH,
+    )
+    .map(|event| match event {
+        // Raw HTML is shown as text, not rendered
+        Event::Html(text) => Event::Text(text),
+        event => event,
+    });
+    let mut out = String::new();
+    html::push_html(&mut out, events);
+    out
+}
+
+/// A doc comment as written, without a Markdown renderer (complexity: 1)
+#[cfg(not(feature = "markdown"))]
+fn markdown_to_html(markdown: &str) -> String {
+    format!("<pre>{}</pre>\n", escape(markdown))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::docs::DocGenerator;
+    use crate::frontend::parser::Parser;
+
+    fn page(name: &str, source: &str) -> DocPage {
+        let ast = Parser::new(source).parse().expect("should parse");
+        DocPage {
+            name: name.to_string(),
+            source: format!("{name}.ruchy"),
+            items: DocGenerator::new().extract_docs(&ast),
+        }
+    }
+
+    fn shapes() -> Documentation {
+        Documentation::new(vec![
+            page(
+                "shapes",
+                "/// A shape, see [`area`] and [Point]\nstruct Shape { corner: Point }\n\
+                 /// Area of a [`Shape`], not [x]\nfun area(s: Shape) -> f64 { 1.0 }",
+            ),
+            page("points", "/// A point\nstruct Point { x: f64 }"),
+        ])
+    }
+
+    #[test]
+    fn test_links_within_and_across_pages() {
+        let markdown = shapes()
+            .render_page(0, DocFormat::Markdown)
+            .expect("renders");
+        assert!(
+            markdown.contains("see [`area`](#fn.area) and [Point](points.md#struct.Point)"),
+            "{markdown}"
+        );
+        assert!(
+            markdown.contains("Area of a [`Shape`](#struct.Shape), not [x]"),
+            "{markdown}"
+        );
+        assert!(
+            markdown.contains("<a id=\"struct.Shape\"></a>"),
+            "{markdown}"
+        );
+        assert!(markdown.contains("- `corner: Point`"), "{markdown}");
+    }
+
+    #[test]
+    fn test_links_are_not_made_in_code() {
+        let links =
+            intra_doc_links("`[Point]` and ``a`b [c]``\n```\n[Point]\n```\n[Point](x) [`Point`]");
+        let targets: Vec<&str> = links.iter().map(|link| link.target).collect();
+        assert_eq!(targets, vec!["Point"]);
+        assert!(links[0].explicit);
+    }
+
+    #[test]
+    fn test_html_links_signatures_and_escapes_docs() {
+        let docs = Documentation::new(vec![page(
+            "shapes",
+            "/// A <script>shape</script>\nstruct Shape { r: f64 }\n/// Area\nfun area(s: Shape) -> f64 { 1.0 }",
+        )]);
+        let html = docs.render_page(0, DocFormat::Html).expect("renders");
+        assert!(html.contains("<!DOCTYPE html>"));
+        assert!(
+            html.contains("fun area(s: <a href=\"#struct.Shape\">Shape</a>) -&gt; f64"),
+            "{html}"
+        );
+        assert!(!html.contains("<script>"), "{html}");
+        assert!(html.contains("<section id=\"fn.area\">"), "{html}");
+    }
+
+    #[test]
+    fn test_unresolved_links() {
+        let docs = Documentation::new(vec![page(
+            "a",
+            "/// See [`gone`], [maybe] and [m::Gone]\nfun a() { 1 }",
+        )]);
+        assert_eq!(
+            docs.unresolved_links(),
+            vec!["[`gone`] in a", "[m::Gone] in a"]
+        );
+        assert!(shapes().unresolved_links().is_empty());
+    }
+
+    #[test]
+    fn test_index_lists_pages_and_items() {
+        let index = shapes().render_index(DocFormat::Markdown).expect("renders");
+        assert!(index.contains("## [shapes.ruchy](shapes.md)"), "{index}");
+        assert!(
+            index.contains("- fn [`area`](shapes.md#fn.area): Area of a [`Shape`], not [x]"),
+            "{index}"
+        );
+        assert!(
+            index.contains("- struct [`Point`](points.md#struct.Point): A point"),
+            "{index}"
+        );
+        let html = shapes().render_index(DocFormat::Html).expect("renders");
+        assert!(
+            html.contains("<a href=\"points.html#struct.Point\"><code>Point</code></a>"),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn test_json_page() {
+        let json = shapes().render_page(1, DocFormat::Json).expect("renders");
+        let value: serde_json::Value = serde_json::from_str(&json).expect("valid JSON");
+        assert_eq!(value["items"][0]["kind"], "struct");
+        assert_eq!(value["items"][0]["members"][0]["signature"], "x: f64");
+    }
+}
@@ -209,8 +209,10 @@ pub struct CatchClause {
 /// A single clause in a comprehension (for and optional if).
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ComprehensionClause {
-    /// The variable or pattern to bind
+    /// Source text of the variable or pattern to bind
     pub variable: String,
+    /// The variable or pattern to bind
+    pub pattern: Pattern,
     /// The iterable to iterate over
     pub iterable: Box<Expr>,
     /// Optional filter condition (if clause)
@@ -387,6 +389,123 @@ impl Expr {
             contracts: Vec::new(),
         }
     }
+
+    /// Whether this expression yields, making an enclosing function a
+    /// generator.
+    ///
+    /// Nested functions and lambdas are not searched: a `yield` inside them
+    /// belongs to them, not to the enclosing function.
+    #[must_use]
+    pub fn contains_yield(&self) -> bool {
+        self.contains_in_body(&|expr| matches!(expr.kind, ExprKind::Yield { .. }))
+    }
+
+    /// Whether this expression contains an explicit `return`, which exits
+    /// the enclosing function, method or closure.
+    ///
+    /// Nested functions and lambdas are not searched: a `return` inside them
+    /// exits them, not the enclosing function.
+    #[must_use]
+    pub fn contains_return(&self) -> bool {
+        self.contains_in_body(&|expr| matches!(expr.kind, ExprKind::Return { .. }))
+    }
+
+    /// Whether this expression defers work to the exit of a scope in the
+    /// enclosing function body.
+    #[must_use]
+    pub fn contains_defer(&self) -> bool {
+        self.contains_in_body(&|expr| matches!(expr.kind, ExprKind::Defer { .. }))
+    }
+
+    /// Whether this expression creates a closure or function that may
+    /// capture the variables of the enclosing function body.
+    #[must_use]
+    pub fn contains_closure(&self) -> bool {
+        self.contains_in_body(&|expr| {
+            matches!(
+                expr.kind,
+                ExprKind::Lambda { .. } | ExprKind::AsyncLambda { .. } | ExprKind::Function { .. }
+            )
+        })
+    }
+
+    /// Whether `found` holds for this expression or a subexpression that runs
+    /// as part of the same function body
+    fn contains_in_body(&self, found: &dyn Fn(&Expr) -> bool) -> bool {
+        if found(self) {
+            return true;
+        }
+        let any = |exprs: &[Expr]| exprs.iter().any(|e| e.contains_in_body(found));
+        match &self.kind {
+            ExprKind::Function { .. } | ExprKind::Lambda { .. } | ExprKind::AsyncLambda { .. } => {
+                false
+            }
+            ExprKind::Block(exprs) => any(exprs),
+            ExprKind::Let { value, body, .. } | ExprKind::LetPattern { value, body, .. } => {
+                value.contains_in_body(found) || body.contains_in_body(found)
+            }
+            ExprKind::If {
+                condition: head,
+                then_branch,
+                else_branch,
+            }
+            | ExprKind::IfLet {
+                expr: head,
+                then_branch,
+                else_branch,
+                ..
+            } => {
+                head.contains_in_body(found)
+                    || then_branch.contains_in_body(found)
+                    || else_branch
+                        .as_ref()
+                        .is_some_and(|e| e.contains_in_body(found))
+            }
+            ExprKind::While {
+                condition: head,
+                body,
+                ..
+            }
+            | ExprKind::WhileLet {
+                expr: head, body, ..
+            }
+            | ExprKind::For {
+                iter: head, body, ..
+            } => head.contains_in_body(found) || body.contains_in_body(found),
+            ExprKind::Loop { body, .. } => body.contains_in_body(found),
+            ExprKind::Match { expr, arms } => {
+                expr.contains_in_body(found)
+                    || arms.iter().any(|arm| arm.body.contains_in_body(found))
+            }
+            ExprKind::TryCatch {
+                try_block,
+                catch_clauses,
+                finally_block,
+            } => {
+                try_block.contains_in_body(found)
+                    || catch_clauses
+                        .iter()
+                        .any(|clause| clause.body.contains_in_body(found))
+                    || finally_block
+                        .as_ref()
+                        .is_some_and(|e| e.contains_in_body(found))
+            }
+            ExprKind::Binary { left, right, .. } => {
+                left.contains_in_body(found) || right.contains_in_body(found)
+            }
+            ExprKind::Assign { target, value } | ExprKind::CompoundAssign { target, value, .. } => {
+                target.contains_in_body(found) || value.contains_in_body(found)
+            }
+            ExprKind::Call { func, args } => func.contains_in_body(found) || any(args),
+            ExprKind::MethodCall { receiver, args, .. } => {
+                receiver.contains_in_body(found) || any(args)
+            }
+            ExprKind::Return { value: Some(value) } | ExprKind::Unary { operand: value, .. } => {
+                value.contains_in_body(found)
+            }
+            _ => false,
+        }
+    }
 }
 /// The specific type of expression represented by an AST node.
 ///
@@ -507,6 +626,11 @@ pub enum ExprKind {
         /// The expression to evaluate lazily.
         expr: Box<Expr>,
     },
+    /// `defer expr` - runs `expr` when the enclosing scope exits.
+    Defer {
+        /// The expression to run at scope exit.
+        expr: Box<Expr>,
+    },
     If {
         condition: Box<Expr>,
         then_branch: Box<Expr>,
@@ -608,6 +732,11 @@ pub enum ExprKind {
         object: Box<Expr>,
         index: Box<Expr>,
     },
+    /// Optional index access `arr?[i]`: yields nil when `arr` is nil or `None`
+    OptionalIndexAccess {
+        object: Box<Expr>,
+        index: Box<Expr>,
+    },
     Slice {
         object: Box<Expr>,
         start: Option<Box<Expr>>,
@@ -844,6 +973,24 @@ pub enum ExprKind {
         value: Box<Expr>,
         count: Box<Expr>,
     },
+    /// Foreign function declarations: `extern "rust" slug { fun slugify(s: &str) -> String }`
+    ///
+    /// `path` names the Rust crate (or module within it) providing the functions
+    ExternBlock {
+        abi: String,
+        path: Option<String>,
+        functions: Vec<ExternFunction>,
+    },
+    /// Verbatim Rust: `rust(total: i64, n: i64) -> f64 { total as f64 / n as f64 }`
+    ///
+    /// The transpiler passes `code` through, seeing only the declared inputs.
+    /// The interpreter runs the `else { ... }` fallback, if any.
+    RustBlock {
+        inputs: Vec<Param>,
+        output: Option<Type>,
+        code: String,
+        fallback: Option<Box<Expr>>,
+    },
     // ── Ruchy 5.0 Sovereign Platform expressions ──
     /// Yield expression for coroutine suspension (Pillar 9: Embedding)
     Yield {
@@ -880,6 +1027,12 @@ pub enum ExprKind {
 pub enum Literal {
     /// A signed 64-bit integer literal with optional type suffix (e.g., i32, i64, u32).
     Integer(i64, Option<String>),
+    /// An arbitrary-precision integer literal (`123n`, or a decimal literal
+    /// too large for `i64`), stored as its decimal digits.
+    BigInt(String),
+    /// An exact decimal literal (`1.23d`), stored as its text without the
+    /// suffix.
+    Decimal(String),
     /// A 64-bit floating-point literal.
     Float(f64),
     /// A string literal.
@@ -903,6 +1056,8 @@ impl Literal {
         use crate::runtime::interpreter::Value;
         match value {
             Value::Integer(i) => Literal::Integer(*i, None),
+            Value::BigInt(n) => Literal::BigInt(n.to_string()),
+            Value::Decimal(d) => Literal::Decimal(d.to_string()),
             Value::Float(f) => Literal::Float(*f),
             Value::String(s) => Literal::String(s.to_string()),
             Value::Bool(b) => Literal::Bool(*b),
@@ -1031,6 +1186,12 @@ pub struct Param {
     pub is_mutable: bool,
     /// Optional default value for this parameter.
     pub default_value: Option<Box<Expr>>,
+    /// Comments on the lines before this parameter.
+    #[serde(default)]
+    pub leading_comments: Vec<Comment>,
+    /// Comment at the end of this parameter's line.
+    #[serde(default)]
+    pub trailing_comment: Option<Comment>,
 }
 impl Param {
     /// Get the primary name from this parameter pattern.
@@ -1040,6 +1201,12 @@ impl Param {
     pub fn name(&self) -> String {
         self.pattern.primary_name()
     }
+
+    /// Whether this is a rest parameter (`...args`) collecting the remaining arguments.
+    #[must_use]
+    pub fn is_rest(&self) -> bool {
+        matches!(self.pattern, Pattern::RestNamed(_))
+    }
 }
 
 /// Visibility level for struct fields, methods, and other items
@@ -1071,6 +1238,10 @@ pub struct StructField {
     pub is_mut: bool,                // mut field modifier
     pub default_value: Option<Expr>, // Default value for class fields
     pub decorators: Vec<Decorator>,  // @PrimaryKey, @Column("name"), etc.
+    #[serde(default)]
+    pub leading_comments: Vec<Comment>,
+    #[serde(default)]
+    pub trailing_comment: Option<Comment>,
 }
 /// A variant within an enum definition
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -1078,6 +1249,10 @@ pub struct EnumVariant {
     pub name: String,
     pub kind: EnumVariantKind,
     pub discriminant: Option<i64>, // Explicit discriminant value for TypeScript compatibility
+    #[serde(default)]
+    pub leading_comments: Vec<Comment>,
+    #[serde(default)]
+    pub trailing_comment: Option<Comment>,
 }
 
 /// The kind of data an enum variant holds
@@ -1111,6 +1286,14 @@ pub struct ImplMethod {
     pub body: Box<Expr>,
     pub is_pub: bool,
 }
+/// A function signature declared in an `extern` block
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExternFunction {
+    pub name: String,
+    pub params: Vec<Param>,
+    pub return_type: Option<Type>,
+    pub span: Span,
+}
 /// A message handler for an actor definition
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ActorHandler {
@@ -1300,6 +1483,12 @@ pub struct MatchArm {
     pub body: Box<Expr>,
     /// Source location of this match arm.
     pub span: Span,
+    /// Comments on the lines before this arm.
+    #[serde(default)]
+    pub leading_comments: Vec<Comment>,
+    /// Comment at the end of this arm's line.
+    #[serde(default)]
+    pub trailing_comment: Option<Comment>,
 }
 /// Patterns for destructuring and matching values.
 ///
@@ -1363,6 +1552,12 @@ pub enum Pattern {
         default: Box<Expr>,
     }, // For patterns with default values like a = 10
     Mut(Box<Pattern>), // For mutable bindings in destructuring like (mut x, mut y)
+    StringAffix {
+        prefix: String,
+        rest: Box<Pattern>,
+        suffix: String,
+    }, // For string patterns like "v" ++ rest or name ++ ".txt"
+    Regex(String), // For regex patterns like re"(?P<key>\w+)=(?P<value>.*)", binding named groups
     Ok(Box<Pattern>),
     Err(Box<Pattern>),
     Some(Box<Pattern>),
@@ -1418,10 +1613,61 @@ impl Pattern {
             Pattern::AtBinding { name, .. } => name.clone(),
             Pattern::WithDefault { pattern, .. } => pattern.primary_name(),
             Pattern::Mut(inner) => inner.primary_name(),
+            Pattern::StringAffix { rest, .. } => rest.primary_name(),
+            Pattern::Regex(_) => "_regex".to_string(),
             Pattern::Literal(lit) => format!("_literal_{lit:?}"),
             Pattern::Range { .. } => "_range".to_string(),
         }
     }
+    /// Constructor path this pattern tests for, if any.
+    ///
+    /// Enum and struct patterns (`Shape::Circle { .. }`, `Color::Rgb(..)`,
+    /// `Status::Done`, `Some(_)`, `None`, `Ok(_)`, `Err(_)`) return the path
+    /// of the variant or type they require, which is what an exhaustiveness
+    /// check groups match arms by. Bindings, wildcards and literals return `None`.
+    #[must_use]
+    pub fn constructor_path(&self) -> Option<Vec<String>> {
+        let path = |segments: &[&str]| Some(segments.iter().map(ToString::to_string).collect());
+        match self {
+            Pattern::QualifiedName(path) | Pattern::TupleVariant { path, .. } => Some(path.clone()),
+            Pattern::Struct { name, .. } if !name.is_empty() => {
+                Some(name.split("::").map(ToString::to_string).collect())
+            }
+            Pattern::Some(_) => path(&["Option", "Some"]),
+            Pattern::None => path(&["Option", "None"]),
+            Pattern::Ok(_) => path(&["Result", "Ok"]),
+            Pattern::Err(_) => path(&["Result", "Err"]),
+            Pattern::AtBinding { pattern, .. }
+            | Pattern::Mut(pattern)
+            | Pattern::WithDefault { pattern, .. } => pattern.constructor_path(),
+            _ => None,
+        }
+    }
+    /// Whether this pattern matches every value of its type.
+    ///
+    /// Wildcards and plain bindings are irrefutable, as are tuples and
+    /// anonymous struct patterns made only of irrefutable parts. A match is
+    /// trivially exhaustive when any unguarded arm is irrefutable.
+    #[must_use]
+    pub fn is_irrefutable(&self) -> bool {
+        match self {
+            Pattern::Wildcard | Pattern::Identifier(_) | Pattern::Rest | Pattern::RestNamed(_) => {
+                true
+            }
+            Pattern::Tuple(patterns) => patterns.iter().all(Pattern::is_irrefutable),
+            Pattern::Struct { name, fields, .. } if name.is_empty() => {
+                fields.iter().all(|f| match &f.pattern {
+                    Some(pattern) => pattern.is_irrefutable(),
+                    None => true,
+                })
+            }
+            Pattern::Or(patterns) => patterns.iter().any(Pattern::is_irrefutable),
+            Pattern::AtBinding { pattern, .. }
+            | Pattern::Mut(pattern)
+            | Pattern::WithDefault { pattern, .. } => pattern.is_irrefutable(),
+            _ => false,
+        }
+    }
 }
 /// A field in a struct destructuring pattern.
 ///
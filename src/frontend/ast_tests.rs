@@ -263,6 +263,8 @@ fn test_function_expression() {
         span: Span::new(8, 13),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }];
     let body = Box::new(Expr::new(
         ExprKind::Identifier("x".to_string()),
@@ -519,6 +521,8 @@ fn test_match_expression() {
                 Span::new(15, 20),
             )),
             span: Span::new(10, 20),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         MatchArm {
             pattern: Pattern::Wildcard,
@@ -528,6 +532,8 @@ fn test_match_expression() {
                 Span::new(28, 35),
             )),
             span: Span::new(25, 35),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
     ];
     let expr = Expr::new(
@@ -600,7 +606,9 @@ fn test_pattern_variants() {
             | Pattern::AtBinding { .. }
             | Pattern::WithDefault { .. }
             | Pattern::Mut(_)
-            | Pattern::TupleVariant { .. } => {} // Simple patterns
+            | Pattern::TupleVariant { .. }
+            | Pattern::StringAffix { .. }
+            | Pattern::Regex(_) => {} // Simple patterns
         }
     }
 }
@@ -679,6 +687,8 @@ fn test_param_creation() {
         span: Span::new(0, 11),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
     assert_eq!(param.name(), "count");
     match param.ty.kind {
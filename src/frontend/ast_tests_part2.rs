@@ -15,6 +15,8 @@ fn test_mutable_parameter() {
         span: Span::new(0, 10),
         is_mutable: true,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
 
     assert!(param.is_mutable);
@@ -593,6 +595,8 @@ fn test_all_remaining_expr_kinds() {
                 default_value: None,
                 is_mutable: false,
                 span: Span::new(0, 5),
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
             Param {
                 pattern: Pattern::Identifier("b".to_string()),
@@ -603,6 +607,8 @@ fn test_all_remaining_expr_kinds() {
                 default_value: None,
                 is_mutable: false,
                 span: Span::new(0, 5),
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
         ],
         return_type: Some(Type {
@@ -1097,3 +1103,61 @@ fn test_primary_name_range() {
     };
     assert_eq!(pat.primary_name(), "_range");
 }
+
+#[test]
+fn test_constructor_path_for_enum_and_struct_patterns() {
+    let circle = Pattern::Struct {
+        name: "Shape::Circle".to_string(),
+        fields: vec![],
+        has_rest: true,
+    };
+    assert_eq!(
+        circle.constructor_path(),
+        Some(vec!["Shape".to_string(), "Circle".to_string()])
+    );
+    let some = Pattern::Some(Box::new(Pattern::Identifier("x".to_string())));
+    assert_eq!(
+        some.constructor_path(),
+        Some(vec!["Option".to_string(), "Some".to_string()])
+    );
+    let bound = Pattern::AtBinding {
+        name: "s".to_string(),
+        pattern: Box::new(Pattern::QualifiedName(vec![
+            "Status".to_string(),
+            "Done".to_string(),
+        ])),
+    };
+    assert_eq!(
+        bound.constructor_path(),
+        Some(vec!["Status".to_string(), "Done".to_string()])
+    );
+    assert_eq!(Pattern::Identifier("x".to_string()).constructor_path(), None);
+}
+
+#[test]
+fn test_is_irrefutable() {
+    assert!(Pattern::Wildcard.is_irrefutable());
+    assert!(Pattern::Tuple(vec![
+        Pattern::Identifier("a".to_string()),
+        Pattern::Wildcard
+    ])
+    .is_irrefutable());
+    assert!(!Pattern::Tuple(vec![Pattern::Literal(Literal::Integer(0, None))]).is_irrefutable());
+    assert!(!Pattern::None.is_irrefutable());
+    assert!(!Pattern::Struct {
+        name: "Point".to_string(),
+        fields: vec![],
+        has_rest: true,
+    }
+    .is_irrefutable());
+}
+
+#[test]
+fn test_contains_return_stops_at_nested_functions() {
+    let parse = |code: &str| crate::frontend::parser::Parser::new(code).parse().unwrap();
+    assert!(parse("for x in xs { if x > 2 { return x } }").contains_return());
+    assert!(parse("try { return 1 } catch (e) { 0 }").contains_return());
+    assert!(parse("match x { 0 => return 5, _ => 1 }").contains_return());
+    assert!(!parse("xs.map(|x| { return x * 2 })").contains_return());
+    assert!(!parse("{ let f = 1\n f + 2 }").contains_return());
+}
@@ -0,0 +1,273 @@
+//! Lossless concrete syntax tree for tooling
+//!
+//! The AST drops whitespace and normalizes token text, so tools that print it
+//! back regenerate whole files. The CST keeps every byte of the source: each
+//! token, comment and run of whitespace is a leaf carrying its exact span, and
+//! every top-level item is a node over a contiguous run of leaves.
+//! Concatenating the leaves reproduces the input exactly, which lets
+//! formatters and codemods rewrite one item and splice it back with
+//! [`ConcreteSyntaxTree::apply_edits`].
+//!
+//! The parser builds a CST on request:
+//!
+//! ```
+//! use ruchy::Parser;
+//!
+//! let source = "fun one() {\n    1 // one\n}\n\none()  +  1\n";
+//! let mut parser = Parser::new(source).with_cst();
+//! parser.parse().expect("valid program");
+//! let cst = parser.take_cst().expect("requested with with_cst");
+//! assert_eq!(cst.to_source_string(), source);
+//! assert_eq!(cst.items().len(), 2);
+//! assert_eq!(cst.text(cst.items()[1].span), "one()  +  1");
+//! ```
+use crate::frontend::ast::Span;
+use crate::frontend::lexer::{Token, TokenStream};
+use anyhow::{bail, Result};
+use std::ops::Range;
+
+/// What a CST leaf holds
+#[derive(Debug, Clone, PartialEq)]
+pub enum CstKind {
+    /// A lexer token, including comments and error tokens
+    Token(Token),
+    /// Whitespace skipped by the lexer
+    Whitespace,
+    /// A leading `#!` line, including its newline
+    Shebang,
+}
+
+/// A single leaf: one token or trivia run with its exact byte span
+#[derive(Debug, Clone, PartialEq)]
+pub struct CstLeaf {
+    pub kind: CstKind,
+    pub span: Span,
+}
+
+impl CstLeaf {
+    /// Whitespace, comments and shebang lines carry no meaning for the AST
+    #[must_use]
+    pub fn is_trivia(&self) -> bool {
+        matches!(
+            self.kind,
+            CstKind::Whitespace
+                | CstKind::Shebang
+                | CstKind::Token(
+                    Token::LineComment(_)
+                        | Token::DocComment(_)
+                        | Token::BlockComment(_)
+                        | Token::HashComment(_)
+                )
+        )
+    }
+}
+
+/// A top-level item: its leading comments and attributes through its last token
+#[derive(Debug, Clone, PartialEq)]
+pub struct CstNode {
+    pub span: Span,
+    /// Indices into [`ConcreteSyntaxTree::leaves`]
+    pub leaves: Range<usize>,
+}
+
+/// Replace the bytes in `span` with `replacement`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub span: Span,
+    pub replacement: String,
+}
+
+impl TextEdit {
+    #[must_use]
+    pub fn replace(span: Span, replacement: impl Into<String>) -> Self {
+        Self {
+            span,
+            replacement: replacement.into(),
+        }
+    }
+
+    #[must_use]
+    pub fn insert(offset: usize, text: impl Into<String>) -> Self {
+        Self::replace(Span::new(offset, offset), text)
+    }
+
+    #[must_use]
+    pub fn delete(span: Span) -> Self {
+        Self::replace(span, String::new())
+    }
+}
+
+/// Lossless token-level view of a source file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConcreteSyntaxTree {
+    source: String,
+    leaves: Vec<CstLeaf>,
+    items: Vec<CstNode>,
+}
+
+impl ConcreteSyntaxTree {
+    /// Lex `source` into leaves without grouping them into items
+    #[must_use]
+    pub fn new(source: &str) -> Self {
+        Self {
+            source: source.to_string(),
+            leaves: lex_leaves(source),
+            items: Vec::new(),
+        }
+    }
+
+    /// Build the tree and group leaves into the top-level items starting at
+    /// `item_starts` (token offsets as reported by the parser's token stream)
+    pub(crate) fn with_items(source: &str, item_starts: &[usize]) -> Self {
+        let mut tree = Self::new(source);
+        let offset = shebang_len(source);
+        let first_leaves: Vec<usize> = item_starts
+            .iter()
+            .filter_map(|start| tree.leaf_index_starting_at(start + offset))
+            .collect();
+        for (i, &first) in first_leaves.iter().enumerate() {
+            let next = first_leaves
+                .get(i + 1)
+                .copied()
+                .unwrap_or(tree.leaves.len());
+            let last = (first..next)
+                .rev()
+                .find(|&leaf| tree.leaves[leaf].kind != CstKind::Whitespace)
+                .unwrap_or(first);
+            tree.items.push(CstNode {
+                span: Span::new(tree.leaves[first].span.start, tree.leaves[last].span.end),
+                leaves: first..last + 1,
+            });
+        }
+        tree
+    }
+
+    #[must_use]
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    #[must_use]
+    pub fn leaves(&self) -> &[CstLeaf] {
+        &self.leaves
+    }
+
+    /// Top-level items, in source order; empty for trees built with [`Self::new`]
+    #[must_use]
+    pub fn items(&self) -> &[CstNode] {
+        &self.items
+    }
+
+    /// Leaves covered by `node`
+    #[must_use]
+    pub fn node_leaves(&self, node: &CstNode) -> &[CstLeaf] {
+        &self.leaves[node.leaves.clone()]
+    }
+
+    /// Exact source text of `span`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `span` is out of bounds or not on character boundaries.
+    #[must_use]
+    pub fn text(&self, span: Span) -> &str {
+        &self.source[span.start..span.end]
+    }
+
+    /// The leaf containing byte `offset`
+    #[must_use]
+    pub fn leaf_at(&self, offset: usize) -> Option<&CstLeaf> {
+        let index = self.leaves.partition_point(|leaf| leaf.span.end <= offset);
+        self.leaves
+            .get(index)
+            .filter(|leaf| leaf.span.start <= offset)
+    }
+
+    /// Concatenate every leaf; always equal to [`Self::source`]
+    #[must_use]
+    pub fn to_source_string(&self) -> String {
+        self.leaves
+            .iter()
+            .map(|leaf| self.text(leaf.span))
+            .collect()
+    }
+
+    /// Apply non-overlapping edits to the original source, leaving every
+    /// other byte untouched
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if edits overlap, fall outside the source or split a
+    /// UTF-8 character.
+    pub fn apply_edits(&self, edits: &[TextEdit]) -> Result<String> {
+        let mut sorted: Vec<&TextEdit> = edits.iter().collect();
+        sorted.sort_by_key(|edit| (edit.span.start, edit.span.end));
+        let mut out = String::with_capacity(self.source.len());
+        let mut cursor = 0;
+        for edit in sorted {
+            let Span { start, end } = edit.span;
+            if start < cursor || end < start || end > self.source.len() {
+                bail!("Edit {start}..{end} overlaps another edit or is out of bounds");
+            }
+            if !self.source.is_char_boundary(start) || !self.source.is_char_boundary(end) {
+                bail!("Edit {start}..{end} splits a UTF-8 character");
+            }
+            out.push_str(&self.source[cursor..start]);
+            out.push_str(&edit.replacement);
+            cursor = end;
+        }
+        out.push_str(&self.source[cursor..]);
+        Ok(out)
+    }
+
+    fn leaf_index_starting_at(&self, offset: usize) -> Option<usize> {
+        self.leaves
+            .binary_search_by_key(&offset, |leaf| leaf.span.start)
+            .ok()
+    }
+}
+
+/// Length of a leading `#!` line, which the token stream skips
+fn shebang_len(source: &str) -> usize {
+    if source.starts_with("#!") {
+        source
+            .find('\n')
+            .map_or(source.len(), |newline| newline + 1)
+    } else {
+        0
+    }
+}
+
+/// Lex every token and fill the gaps between them with whitespace leaves
+fn lex_leaves(source: &str) -> Vec<CstLeaf> {
+    let offset = shebang_len(source);
+    let mut leaves = Vec::new();
+    if offset > 0 {
+        leaves.push(CstLeaf {
+            kind: CstKind::Shebang,
+            span: Span::new(0, offset),
+        });
+    }
+    let mut cursor = offset;
+    let mut tokens = TokenStream::new(source);
+    while let Some((token, span)) = tokens.next() {
+        let span = Span::new(span.start + offset, span.end + offset);
+        push_whitespace(&mut leaves, cursor, span.start);
+        leaves.push(CstLeaf {
+            kind: CstKind::Token(token),
+            span,
+        });
+        cursor = span.end;
+    }
+    push_whitespace(&mut leaves, cursor, source.len());
+    leaves
+}
+
+fn push_whitespace(leaves: &mut Vec<CstLeaf>, start: usize, end: usize) {
+    if start < end {
+        leaves.push(CstLeaf {
+            kind: CstKind::Whitespace,
+            span: Span::new(start, end),
+        });
+    }
+}
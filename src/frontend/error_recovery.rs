@@ -331,6 +331,13 @@ impl<'a> RecoveryParser<'a> {
             Some((Token::Fun, _)) => Ok(self.parse_function_recovery()),
             Some((Token::LeftBracket, _)) => Ok(self.parse_list_recovery()),
             Some((Token::LeftParen, _)) => self.parse_paren_recovery(),
+            Some((Token::Error(kind), _)) => {
+                // The lexer already skipped the bad input; report it and move on
+                let message = kind.to_string();
+                self.record_error(message, Some("Remove or fix this input".to_string()));
+                self.tokens.advance();
+                Ok(self.create_ghost_node("Lexical error"))
+            }
             Some((token, _span)) => {
                 let token_clone = token.clone();
                 // Special handling for binary operators in prefix position
@@ -561,6 +568,8 @@ impl<'a> RecoveryParser<'a> {
                 span: name_span,
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             });
             match self.tokens.peek() {
                 Some((Token::Comma, _)) => {
@@ -882,6 +891,15 @@ mod tests {
         assert!(!result.errors.is_empty());
     }
 
+    #[test]
+    fn test_recovery_reports_lexical_error() {
+        let mut parser = RecoveryParser::new("1 + $");
+        let result = parser.parse_with_recovery();
+        assert!(result.ast.is_some());
+        assert_eq!(result.errors[0].message, "Invalid character '$'");
+        assert_eq!(result.errors[0].span, Span::new(4, 5));
+    }
+
     #[test]
     fn test_recovery_match_missing_arms() {
         let mut parser = RecoveryParser::new("match x { }");
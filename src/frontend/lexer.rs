@@ -71,6 +71,42 @@ fn process_escapes(s: &str) -> String {
     result
 }
 
+/// Remove `_` digit separators from a numeric literal
+fn strip_digit_separators(literal: &str) -> String {
+    literal.replace('_', "")
+}
+
+/// Decode the body of a char literal: one character or one escape sequence
+///
+/// Supports the basic escapes, `\xNN` (ASCII only) and `\u{NNNN}`.
+fn process_char_literal(inner: &str) -> Option<char> {
+    let Some(escape) = inner.strip_prefix('\\') else {
+        let mut chars = inner.chars();
+        let ch = chars.next()?;
+        return chars.next().is_none().then_some(ch);
+    };
+    if let Some(hex) = escape.strip_prefix("u{").and_then(|s| s.strip_suffix('}')) {
+        return u32::from_str_radix(hex, 16).ok().and_then(char::from_u32);
+    }
+    if let Some(hex) = escape.strip_prefix('x') {
+        return u8::from_str_radix(hex, 16)
+            .ok()
+            .filter(u8::is_ascii)
+            .map(char::from);
+    }
+    let mut chars = escape.chars();
+    let ch = process_basic_escape(chars.next()?)?;
+    chars.next().is_none().then_some(ch)
+}
+
+/// Decode the body of a byte literal; `\xNN` covers the full byte range
+fn process_byte_literal(inner: &str) -> Option<u8> {
+    if let Some(hex) = inner.strip_prefix("\\x") {
+        return u8::from_str_radix(hex, 16).ok();
+    }
+    process_char_literal(inner).and_then(|ch| u8::try_from(ch).ok().filter(u8::is_ascii))
+}
+
 /// Lex nested block comments with depth tracking
 /// Handles Rust-style nested comments: /* outer /* inner */ still outer */
 fn lex_nested_block_comment(lex: &mut Lexer<Token>) -> Option<String> {
@@ -138,19 +174,32 @@ pub enum Token {
     HashComment(String),
 
     // Literals
-    // Issue #168: Hexadecimal literals (0x or 0X prefix)
-    #[regex(r"0[xX][0-9a-fA-F]+(?:i8|i16|i32|i64|i128|isize|u8|u16|u32|u64|u128|usize)?", |lex| {
+    // Issue #168: Hexadecimal literals, plus octal (0o) and binary (0b);
+    // `_` digit separators are dropped so the token holds prefix, digits and suffix
+    #[regex(r"0(?:[xX][0-9a-fA-F_]+|[oO][0-7_]+|[bB][01_]+)(?:i8|i16|i32|i64|i128|isize|u8|u16|u32|u64|u128|usize)?", |lex| {
+        strip_digit_separators(lex.slice())
+    })]
+    RadixInteger(String),
+    #[regex(r"[0-9][0-9_]*(?:i8|i16|i32|i64|i128|isize|u8|u16|u32|u64|u128|usize)?", |lex| {
+        // Store as string to preserve the type suffix
+        strip_digit_separators(lex.slice())
+    })]
+    Integer(String),
+    /// Arbitrary-precision integer literal (`123n`); holds the digits only
+    #[regex(r"[0-9][0-9_]*n", |lex| {
         let slice = lex.slice();
-        slice.to_string()
+        strip_digit_separators(&slice[..slice.len() - 1])
     })]
-    HexInteger(String),
-    #[regex(r"[0-9]+(?:i8|i16|i32|i64|i128|isize|u8|u16|u32|u64|u128|usize)?", |lex| {
+    BigInteger(String),
+    /// Exact decimal literal (`1.23d`, `5d`); holds the number without the `d`
+    #[regex(r"[0-9][0-9_]*(\.[0-9][0-9_]*)?d", |lex| {
         let slice = lex.slice();
-        // Parse type suffix and numeric value separately - store as string to preserve suffix
-        slice.to_string()
+        strip_digit_separators(&slice[..slice.len() - 1])
+    })]
+    DecimalLiteral(String),
+    #[regex(r"[0-9][0-9_]*\.[0-9][0-9_]*([eE][+-]?[0-9_]+)?|[0-9][0-9_]*[eE][+-]?[0-9_]+", |lex| {
+        strip_digit_separators(lex.slice()).parse::<f64>().ok()
     })]
-    Integer(String),
-    #[regex(r"[0-9]+\.[0-9]+([eE][+-]?[0-9]+)?|[0-9]+[eE][+-]?[0-9]+", |lex| lex.slice().parse::<f64>().ok())]
     Float(f64),
     // Double-quoted strings
     #[regex(r#""([^"\\]|\\.)*""#, |lex| {
@@ -195,46 +244,23 @@ pub enum Token {
         Some(s[2..s.len()-1].to_string())
     })]
     RawString(String),
-    // Re-enabled - Char matches 'x' format
+    // Regex patterns: re"..." (no escape processing, like raw strings)
+    #[regex(r#"re"([^"])*""#, |lex| {
+        let s = lex.slice();
+        // Remove re" prefix and " suffix
+        Some(s[3..s.len()-1].to_string())
+    })]
+    Regex(String),
+    // Re-enabled - Char matches 'x' format, including '\x41' and '\u{1F600}'
     // Priority 7: highest among single-quote patterns to match 'a' before String
-    #[regex(r"'([^'\\]|\\.)'", priority = 7, callback = |lex| {
+    #[regex(r"'([^'\\]|\\[^ux]|\\x[0-9a-fA-F]{2}|\\u\{[0-9a-fA-F]{1,6}\})'", priority = 7, callback = |lex| {
         let s = lex.slice();
-        let inner = &s[1..s.len()-1];
-        if inner.len() == 1 {
-            inner.chars().next()
-        } else if inner.starts_with('\\') && inner.len() == 2 {
-            match inner.chars().nth(1) {
-                Some('n') => Some('\n'),
-                Some('t') => Some('\t'),
-                Some('r') => Some('\r'),
-                Some('\\') => Some('\\'),
-                Some('\'') => Some('\''),
-                Some('0') => Some('\0'),
-                _ => None,
-            }
-        } else {
-            None
-        }
+        process_char_literal(&s[1..s.len()-1])
     })]
     Char(char),
-    #[regex(r"b'([^'\\]|\\.)'", |lex| {
+    #[regex(r"b'([^'\\]|\\[^x]|\\x[0-9a-fA-F]{2})'", |lex| {
         let s = lex.slice();
-        let inner = &s[2..s.len()-1];  // Skip b' prefix
-        if inner.len() == 1 {
-            Some(inner.as_bytes()[0])
-        } else if inner.starts_with('\\') && inner.len() == 2 {
-            match inner.chars().nth(1) {
-                Some('n') => Some(b'\n'),
-                Some('t') => Some(b'\t'),
-                Some('r') => Some(b'\r'),
-                Some('\\') => Some(b'\\'),
-                Some('\'') => Some(b'\''),
-                Some('0') => Some(b'\0'),
-                _ => None,
-            }
-        } else {
-            None
-        }
+        process_byte_literal(&s[2..s.len()-1])  // Skip b' prefix
     })]
     Byte(u8),
     #[token("true", |_| true)]
@@ -358,6 +384,8 @@ pub enum Token {
     Const,
     #[token("unsafe", priority = 2)]
     Unsafe,
+    #[token("extern")]
+    Extern,
     #[token("static")]
     Static,
     #[token("mut")]
@@ -493,6 +521,14 @@ pub enum Token {
     CaretEqual,
     #[token("<<=")]
     LeftShiftEqual,
+    #[token(">>=")]
+    RightShiftEqual,
+    #[token("&&=")]
+    AndAndEqual,
+    #[token("||=")]
+    OrOrEqual,
+    #[token("??=")]
+    NullCoalesceEqual,
     #[token("++")]
     Increment,
     #[token("--")]
@@ -546,6 +582,56 @@ pub enum Token {
     // Priority 3 ensures #[ is matched before # comments (which default to priority 0)
     #[token("#[", priority = 3)]
     AttributeStart,
+    // Produced by `TokenStream` for input the lexer cannot tokenize
+    Error(LexErrorKind),
+}
+/// Kind of lexical error carried by [`Token::Error`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum LexErrorKind {
+    /// A character that cannot start any token
+    InvalidCharacter(char),
+    /// A string literal with no closing quote before the end of the line
+    UnterminatedString,
+}
+impl std::fmt::Display for LexErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LexErrorKind::InvalidCharacter(ch) => write!(f, "Invalid character '{ch}'"),
+            LexErrorKind::UnterminatedString => write!(f, "Unterminated string literal"),
+        }
+    }
+}
+/// A lexical error with its location in the source
+#[derive(Debug, Clone, PartialEq)]
+pub struct LexError {
+    pub kind: LexErrorKind,
+    pub span: Span,
+}
+/// Collect every lexical error in `source`, in source order
+///
+/// The lexer never stops at bad input, so this reports all invalid
+/// characters and unterminated strings rather than just the first.
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::frontend::lexer::{lex_errors, LexErrorKind};
+///
+/// let errors = lex_errors("let a = 1 § 2\nlet b = \"open");
+/// assert_eq!(errors.len(), 2);
+/// assert_eq!(errors[0].kind, LexErrorKind::InvalidCharacter('§'));
+/// assert_eq!(errors[1].kind, LexErrorKind::UnterminatedString);
+/// ```
+#[must_use]
+pub fn lex_errors(source: &str) -> Vec<LexError> {
+    let mut tokens = TokenStream::new(source);
+    let mut errors = Vec::new();
+    while let Some((token, span)) = tokens.next() {
+        if let Token::Error(kind) = token {
+            errors.push(LexError { kind, span });
+        }
+    }
+    errors
 }
 impl Token {
     #[must_use]
@@ -594,6 +680,10 @@ impl Token {
                 | Token::PipeEqual
                 | Token::CaretEqual
                 | Token::LeftShiftEqual
+                | Token::RightShiftEqual
+                | Token::AndAndEqual
+                | Token::OrOrEqual
+                | Token::NullCoalesceEqual
         )
     }
 }
@@ -602,6 +692,8 @@ pub struct TokenStream<'a> {
     peeked: Option<(Token, Span)>,
     input: &'a str,
     current_position: usize,
+    /// Span of the token rejected by the most recent failed `expect`
+    last_error_span: Option<Span>,
 }
 /// Saved position in the token stream for backtracking
 #[derive(Clone)]
@@ -639,6 +731,7 @@ impl<'a> TokenStream<'a> {
             peeked: None,
             input,
             current_position: 0,
+            last_error_span: None,
         }
     }
 
@@ -689,12 +782,47 @@ impl<'a> TokenStream<'a> {
             return Some(peeked);
         }
         self.lexer.next().map(|result| {
-            let token = result.unwrap_or(Token::Bang); // Error recovery
-            let span = Span::new(self.lexer.span().start, self.lexer.span().end);
+            let (token, span) = match result {
+                Ok(token) => (
+                    token,
+                    Span::new(self.lexer.span().start, self.lexer.span().end),
+                ),
+                Err(()) => self.recover_lex_error(),
+            };
             self.current_position = span.end;
             (token, span)
         })
     }
+    /// Turn a lexer failure into an error token and resume after it
+    ///
+    /// An unterminated string swallows the rest of its line so that its
+    /// contents are not re-lexed as code; anything else is reported as a
+    /// single invalid character.
+    fn recover_lex_error(&mut self) -> (Token, Span) {
+        let source = self.lexer.source();
+        let span = self.lexer.span();
+        let rest = &source[span.start..];
+        let opens_string = ["\"", "f\"", "r\"", "r#\""]
+            .iter()
+            .any(|prefix| rest.starts_with(prefix));
+        if opens_string {
+            let line_end = span.start + rest.find('\n').unwrap_or(rest.len());
+            if span.end != line_end {
+                // Strings may span lines, so the failed match can run past
+                // this one; lexing resumes at the end of the line either way
+                let mut lexer = Token::lexer(source);
+                lexer.bump(line_end);
+                self.lexer = lexer;
+            }
+            let token = Token::Error(LexErrorKind::UnterminatedString);
+            return (token, Span::new(span.start, line_end));
+        }
+        let ch = rest.chars().next().unwrap_or('\u{FFFD}');
+        (
+            Token::Error(LexErrorKind::InvalidCharacter(ch)),
+            Span::new(span.start, span.end),
+        )
+    }
     pub fn peek(&mut self) -> Option<&(Token, Span)> {
         if self.peeked.is_none() {
             self.peeked = self.next();
@@ -743,14 +871,121 @@ impl<'a> TokenStream<'a> {
     pub fn expect(&mut self, expected: &Token) -> anyhow::Result<Span> {
         match self.next() {
             Some((token, span)) if token == *expected => Ok(span),
-            Some((token, _)) => anyhow::bail!("Expected {expected:?}, found {token:?}"),
-            None => anyhow::bail!("Expected {expected:?}, found EOF"),
+            Some((token, span)) => {
+                self.last_error_span = Some(span);
+                anyhow::bail!("Expected {expected:?}, found {token:?}")
+            }
+            None => {
+                self.last_error_span = Some(Span::new(self.input.len(), self.input.len()));
+                anyhow::bail!("Expected {expected:?}, found EOF")
+            }
         }
     }
+    /// Best-effort location of a parse failure.
+    ///
+    /// Returns the token rejected by the last failed `expect`, otherwise the
+    /// next unconsumed token, otherwise an empty span at end of input.
+    pub fn error_span(&mut self) -> Span {
+        if let Some(span) = self.last_error_span {
+            return span;
+        }
+        let eof = Span::new(self.input.len(), self.input.len());
+        self.peek().map_or(eof, |(_, span)| *span)
+    }
     // Alias for next() to avoid clippy warning about Iterator trait
     pub fn advance(&mut self) -> Option<(Token, Span)> {
         self.next()
     }
+    /// Consume a `{ ... }` block without lexing its contents
+    ///
+    /// Returns the text between the braces and the span of the whole block.
+    /// The contents are Rust source (`rust { ... }` blocks), so braces inside
+    /// Rust comments, strings and character literals do not count.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the next token is not `{` or the block is unterminated
+    pub fn take_raw_block(&mut self) -> anyhow::Result<(&'a str, Span)> {
+        let open = self.expect(&Token::LeftBrace)?;
+        let source = self.lexer.source();
+        let Some(len) = closing_brace_offset(&source[open.end..]) else {
+            self.last_error_span = Some(open);
+            anyhow::bail!("Unterminated block: expected '}}'");
+        };
+        let close = open.end + len;
+        self.lexer.bump(close + 1 - self.lexer.span().end);
+        self.current_position = close + 1;
+        Ok((&source[open.end..close], Span::new(open.start, close + 1)))
+    }
+}
+
+/// Offset of the `}` that closes a block whose contents start `text`
+///
+/// Skips Rust comments, (raw) string literals and character literals;
+/// a `'` that does not start a character literal is a lifetime.
+fn closing_brace_offset(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut depth = 0usize;
+    let mut i = 0;
+    while i < bytes.len() {
+        let rest = &text[i..];
+        match bytes[i] {
+            b'{' => depth += 1,
+            b'}' if depth == 0 => return Some(i),
+            b'}' => depth -= 1,
+            b'/' if rest.starts_with("//") => i += rest.find('\n')?,
+            b'/' if rest.starts_with("/*") => i += rest.find("*/")? + 1,
+            b'"' => i += string_literal_len(rest)? - 1,
+            b'r' if i == 0 || !is_ident_byte(bytes[i - 1]) => {
+                i += raw_string_literal_len(rest).map_or(0, |len| len - 1);
+            }
+            b'\'' => i += char_literal_len(rest).map_or(0, |len| len - 1),
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+fn is_ident_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+/// Length of the `"..."` literal starting `text`
+fn string_literal_len(text: &str) -> Option<usize> {
+    let mut escaped = false;
+    for (i, ch) in text.char_indices().skip(1) {
+        match ch {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return Some(i + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Length of the `r"..."` or `r#"..."#` literal starting `text`, if it is one
+fn raw_string_literal_len(text: &str) -> Option<usize> {
+    let hashes = text[1..].bytes().take_while(|&b| b == b'#').count();
+    let body = text.get(1 + hashes..)?.strip_prefix('"')?;
+    let terminator = format!("\"{}", "#".repeat(hashes));
+    let end = body.find(&terminator)?;
+    Some(2 + hashes + end + terminator.len())
+}
+
+/// Length of the character literal starting `text`, or `None` for a lifetime
+fn char_literal_len(text: &str) -> Option<usize> {
+    let mut chars = text.char_indices().skip(1);
+    let (_, first) = chars.next()?;
+    if first == '\\' {
+        // `'\''` and `'\\'`: the escaped character never closes the literal
+        return text.get(3..)?.find('\'').map(|end| end + 4);
+    }
+    match chars.next() {
+        Some((i, '\'')) => Some(i + 1),
+        _ => None,
+    }
 }
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
@@ -1 +1,166 @@
+use super::*;
 
+fn tokens(source: &str) -> Vec<(Token, Span)> {
+    let mut stream = TokenStream::new(source);
+    std::iter::from_fn(|| stream.next()).collect()
+}
+
+#[test]
+fn test_invalid_character_becomes_error_token() {
+    let tokens = tokens("1 $ 2");
+    assert_eq!(
+        tokens,
+        vec![
+            (Token::Integer("1".to_string()), Span::new(0, 1)),
+            (
+                Token::Error(LexErrorKind::InvalidCharacter('$')),
+                Span::new(2, 3)
+            ),
+            (Token::Integer("2".to_string()), Span::new(4, 5)),
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_multibyte_character_spans_whole_char() {
+    let tokens = tokens("a § b");
+    assert_eq!(
+        tokens[1],
+        (
+            Token::Error(LexErrorKind::InvalidCharacter('§')),
+            Span::new(2, 4)
+        )
+    );
+    assert_eq!(tokens[2].0, Token::Identifier("b".to_string()));
+}
+
+#[test]
+fn test_unterminated_string_stops_at_end_of_line() {
+    let tokens = tokens("let s = \"open\nlet t = 1");
+    assert_eq!(
+        tokens[3],
+        (
+            Token::Error(LexErrorKind::UnterminatedString),
+            Span::new(8, 13)
+        )
+    );
+    assert_eq!(tokens[4].0, Token::Let);
+    assert_eq!(tokens.len(), 8);
+}
+
+#[test]
+fn test_lex_errors_reports_every_error() {
+    let errors = lex_errors("$ ok\n\"never closed\n`");
+    let kinds: Vec<_> = errors.into_iter().map(|e| e.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            LexErrorKind::InvalidCharacter('$'),
+            LexErrorKind::UnterminatedString,
+            LexErrorKind::InvalidCharacter('`'),
+        ]
+    );
+}
+
+#[test]
+fn test_lex_errors_empty_for_valid_source() {
+    assert!(lex_errors("let s = \"fine\" + f\"{s}\"").is_empty());
+}
+
+#[test]
+fn test_lex_error_message() {
+    assert_eq!(
+        LexErrorKind::InvalidCharacter('$').to_string(),
+        "Invalid character '$'"
+    );
+    assert_eq!(
+        LexErrorKind::UnterminatedString.to_string(),
+        "Unterminated string literal"
+    );
+}
+
+#[test]
+fn test_compound_assignment_tokens() {
+    let kinds: Vec<Token> = tokens("%= **= >>= &&= ||= ??=")
+        .into_iter()
+        .map(|(token, _)| token)
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            Token::PercentEqual,
+            Token::PowerEqual,
+            Token::RightShiftEqual,
+            Token::AndAndEqual,
+            Token::OrOrEqual,
+            Token::NullCoalesceEqual,
+        ]
+    );
+    assert!(kinds.iter().all(Token::is_assignment_op));
+}
+
+#[test]
+fn test_numeric_literal_forms() {
+    let kinds: Vec<Token> = tokens("1_000_000 0xFF_FFu32 0o755 0B1010 2_5n 1_000.5 1e1_0")
+        .into_iter()
+        .map(|(token, _)| token)
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            Token::Integer("1000000".to_string()),
+            Token::RadixInteger("0xFFFFu32".to_string()),
+            Token::RadixInteger("0o755".to_string()),
+            Token::RadixInteger("0B1010".to_string()),
+            Token::BigInteger("25".to_string()),
+            Token::Float(1000.5),
+            Token::Float(1e10),
+        ]
+    );
+}
+
+#[test]
+fn test_char_literal_escapes() {
+    let kinds: Vec<Token> = tokens(r"'é' '\x7F' '\u{1F600}' '\'' b'\xFF' b'\n'")
+        .into_iter()
+        .map(|(token, _)| token)
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            Token::Char('é'),
+            Token::Char('\x7F'),
+            Token::Char('\u{1F600}'),
+            Token::Char('\''),
+            Token::Byte(0xFF),
+            Token::Byte(b'\n'),
+        ]
+    );
+}
+
+#[test]
+fn test_regex_literal_keeps_backslashes() {
+    let kinds: Vec<Token> = tokens(r#"re"\d+\.txt" re ("x")"#)
+        .into_iter()
+        .map(|(token, _)| token)
+        .collect();
+    assert_eq!(kinds[0], Token::Regex(r"\d+\.txt".to_string()));
+    // `re` apart from a string stays an identifier
+    assert_eq!(kinds[1], Token::Identifier("re".to_string()));
+}
+
+#[test]
+fn test_take_raw_block_skips_rust_literals() {
+    let source = "{ let s = \"}\"; let c = '}'; let r = r#\"{\"#; // }\n fn f<'a>(x: &'a str) {} /* } */ } next";
+    let mut stream = TokenStream::new(source);
+    let (raw, span) = stream.take_raw_block().unwrap();
+    assert_eq!(span, Span::new(0, source.len() - 5));
+    assert!(raw.ends_with("/* } */ "), "{raw}");
+    assert_eq!(
+        stream.next().map(|(token, _)| token),
+        Some(Token::Identifier("next".to_string()))
+    );
+
+    let mut unterminated = TokenStream::new("{ fn f() { }");
+    assert!(unterminated.take_raw_block().is_err());
+}
@@ -102,11 +102,13 @@
 //! - **Imports**: Module system with dependency resolution
 pub mod arena;
 pub mod ast;
+pub mod cst;
 pub mod diagnostics;
 pub mod error_recovery;
 pub mod lexer;
 pub mod parser;
 pub use ast::*;
+pub use cst::ConcreteSyntaxTree;
 pub use error_recovery::{ParseError, ParseResult, RecoveryParser};
 pub use lexer::{Token, TokenStream};
 pub use parser::Parser;
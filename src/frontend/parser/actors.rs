@@ -162,6 +162,8 @@ fn parse_single_state_block_field(
         is_mut: false,
         default_value,
         decorators: Vec::new(),
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     });
     Ok(())
 }
@@ -303,6 +305,8 @@ fn parse_inline_state_field(
         is_mut,
         default_value,
         decorators: Vec::new(),
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     });
     consume_optional_separator(state);
     Ok(())
@@ -442,6 +446,8 @@ mod tests {
             visibility: Visibility::Private,
             is_mut: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         };
 
         assert_eq!(field.name, "test_field");
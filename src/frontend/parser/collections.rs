@@ -1,6 +1,6 @@
 //! Collections parsing (lists, dataframes, comprehensions, blocks, object literals)
 use super::{bail, expressions, functions, Expr, ExprKind, ParserState, Result, Span, Token};
-use crate::frontend::ast::{DataFrameColumn, Literal, ObjectField};
+use crate::frontend::ast::{DataFrameColumn, Literal, ObjectField, Pattern};
 /// Parse a block expression or object literal
 ///
 /// Blocks are sequences of expressions enclosed in braces `{}`. This function
@@ -181,8 +181,14 @@ fn try_parse_let_binding(state: &mut ParserState) -> Result<Option<LetBindingInf
         state.tokens.advance();
         if matches!(state.tokens.peek(), Some((Token::Equal, _))) {
             state.tokens.advance(); // consume =
-            let value = super::parse_expr_recursive(state)?;
-            return Ok(Some(LetBindingInfo { name, value }));
+            let old_context = state.in_let_value_context;
+            state.in_let_value_context = true;
+            let value = super::parse_expr_recursive(state);
+            state.in_let_value_context = old_context;
+            return Ok(Some(LetBindingInfo {
+                name,
+                value: value?,
+            }));
         }
     }
     Ok(None)
@@ -858,19 +864,34 @@ pub fn parse_list_comprehension(
 }
 
 fn parse_for_clause(state: &mut ParserState) -> Result<crate::frontend::ast::ComprehensionClause> {
-    let variable = parse_comprehension_variable(state)?;
+    let (variable, pattern) = parse_comprehension_variable(state)?;
     state.tokens.expect(&Token::In)?;
     let iterable = parse_comprehension_iterable(state)?;
 
-    let condition = if matches!(state.tokens.peek(), Some((Token::If, _))) {
+    // Consecutive filters (`if p if q`) apply in order, so they fold into `p && q`
+    let mut condition: Option<Box<Expr>> = None;
+    while matches!(state.tokens.peek(), Some((Token::If, _))) {
         state.tokens.advance();
-        Some(Box::new(parse_condition_expr(state)?))
-    } else {
-        None
-    };
+        let filter = parse_condition_expr(state)?;
+        condition = Some(Box::new(match condition {
+            Some(previous) => {
+                let span = previous.span.merge(filter.span);
+                Expr::new(
+                    ExprKind::Binary {
+                        left: previous,
+                        op: crate::frontend::ast::BinaryOp::And,
+                        right: Box::new(filter),
+                    },
+                    span,
+                )
+            }
+            None => filter,
+        }));
+    }
 
     Ok(crate::frontend::ast::ComprehensionClause {
         variable,
+        pattern,
         iterable: Box::new(iterable),
         condition,
     })
@@ -1205,8 +1226,14 @@ fn try_parse_comprehension(state: &mut ParserState, start_span: Span) -> Result<
         }
     };
 
-    // Check what comes next to determine comprehension type
+    // Check what comes next to determine comprehension type. A `for` that
+    // starts a line begins a loop statement of a block: `{ f()\n for x in xs {} }`
+    let source = state.tokens.source();
     match state.tokens.peek() {
+        Some((Token::For, span)) if super::starts_line(source, span.start) => {
+            state.tokens.set_position(saved_position);
+            bail!("Not a comprehension - 'for' starts a loop on its own line");
+        }
         Some((Token::For, _)) => {
             // This is a set comprehension: {expr for x in iter}
             parse_set_comprehension_continuation(state, first_expr, start_span)
@@ -1377,11 +1404,23 @@ fn parse_dict_comprehension_continuation(
     ))
 }
 
-/// Parse comprehension variable - supports patterns like Some(x), (a, b), or simple identifiers (complexity: 8)
-pub fn parse_comprehension_variable(state: &mut ParserState) -> Result<String> {
+/// Parse comprehension variable - supports patterns like Some(x), (a, (b, c)), or simple identifiers (complexity: 8)
+///
+/// Returns the pattern and its source text, which the transpiler emits as a
+/// Rust pattern.
+pub fn parse_comprehension_variable(state: &mut ParserState) -> Result<(String, Pattern)> {
+    let pattern = parse_comprehension_pattern(state)?;
+    Ok((comprehension_pattern_source(&pattern), pattern))
+}
+
+fn parse_comprehension_pattern(state: &mut ParserState) -> Result<Pattern> {
     match state.tokens.peek() {
         Some((Token::LeftParen, _)) => parse_tuple_pattern(state),
         Some((Token::Identifier(_), _)) => parse_identifier_pattern(state),
+        Some((Token::Underscore, _)) => {
+            state.tokens.advance();
+            Ok(Pattern::Wildcard)
+        }
         Some((Token::Some, _)) => parse_option_some_pattern(state),
         Some((Token::None, _)) => parse_option_none_pattern(state),
         Some((Token::Ok, _)) => parse_result_ok_pattern(state),
@@ -1390,37 +1429,38 @@ pub fn parse_comprehension_variable(state: &mut ParserState) -> Result<String> {
     }
 }
 
-fn parse_tuple_pattern(state: &mut ParserState) -> Result<String> {
-    state.tokens.advance(); // consume (
-    let mut pattern_str = String::from("(");
-
-    // Parse first element
-    if let Some((Token::Identifier(name), _)) = state.tokens.peek() {
-        pattern_str.push_str(name);
-        state.tokens.advance();
-    } else {
-        bail!("Expected identifier in tuple pattern");
+/// Source text of a comprehension pattern (complexity: 7)
+fn comprehension_pattern_source(pattern: &Pattern) -> String {
+    match pattern {
+        Pattern::Identifier(name) => name.clone(),
+        Pattern::Tuple(elements) => {
+            let elements: Vec<String> = elements.iter().map(comprehension_pattern_source).collect();
+            format!("({})", elements.join(", "))
+        }
+        Pattern::TupleVariant { path, patterns } => {
+            let inner: Vec<String> = patterns.iter().map(comprehension_pattern_source).collect();
+            format!("{}({})", path.join("::"), inner.join(", "))
+        }
+        Pattern::Some(inner) => format!("Some({})", comprehension_pattern_source(inner)),
+        Pattern::Ok(inner) => format!("Ok({})", comprehension_pattern_source(inner)),
+        Pattern::Err(inner) => format!("Err({})", comprehension_pattern_source(inner)),
+        Pattern::None => "None".to_string(),
+        _ => "_".to_string(),
     }
+}
 
-    // Parse remaining elements
+fn parse_tuple_pattern(state: &mut ParserState) -> Result<Pattern> {
+    state.tokens.advance(); // consume (
+    let mut elements = vec![parse_comprehension_pattern(state)?];
     while matches!(state.tokens.peek(), Some((Token::Comma, _))) {
         state.tokens.advance();
-        pattern_str.push_str(", ");
-
-        if let Some((Token::Identifier(name), _)) = state.tokens.peek() {
-            pattern_str.push_str(name);
-            state.tokens.advance();
-        } else {
-            bail!("Expected identifier after comma in tuple pattern");
-        }
+        elements.push(parse_comprehension_pattern(state)?);
     }
-
     state.tokens.expect(&Token::RightParen)?;
-    pattern_str.push(')');
-    Ok(pattern_str)
+    Ok(Pattern::Tuple(elements))
 }
 
-fn parse_identifier_pattern(state: &mut ParserState) -> Result<String> {
+fn parse_identifier_pattern(state: &mut ParserState) -> Result<Pattern> {
     let name = if let Some((Token::Identifier(n), _)) = state.tokens.peek() {
         n.clone()
     } else {
@@ -1429,28 +1469,31 @@ fn parse_identifier_pattern(state: &mut ParserState) -> Result<String> {
     state.tokens.advance();
 
     if matches!(state.tokens.peek(), Some((Token::LeftParen, _))) {
-        parse_constructor_pattern(state, &name)
+        parse_constructor_pattern(state, name)
     } else {
-        Ok(name)
+        Ok(Pattern::Identifier(name))
     }
 }
 
-fn parse_constructor_pattern(state: &mut ParserState, name: &str) -> Result<String> {
+fn parse_constructor_pattern(state: &mut ParserState, name: String) -> Result<Pattern> {
     state.tokens.advance(); // consume (
-    let mut pattern_str = format!("{name}(");
+    let mut patterns = Vec::new();
 
     if let Some((Token::Identifier(inner), _)) = state.tokens.peek() {
-        pattern_str.push_str(inner);
+        patterns.push(Pattern::Identifier(inner.clone()));
         state.tokens.advance();
     }
 
     state.tokens.expect(&Token::RightParen)?;
-    pattern_str.push(')');
-    Ok(pattern_str)
+    Ok(Pattern::TupleVariant {
+        path: vec![name],
+        patterns,
+    })
 }
 
-fn parse_option_some_pattern(state: &mut ParserState) -> Result<String> {
-    state.tokens.advance(); // consume Some
+/// Identifier inside `Some(..)`, `Ok(..)` or `Err(..)`
+fn parse_wrapped_identifier(state: &mut ParserState, wrapper: &str) -> Result<Box<Pattern>> {
+    state.tokens.advance(); // consume the wrapper
     state.tokens.expect(&Token::LeftParen)?;
 
     let inner = if let Some((Token::Identifier(name), _)) = state.tokens.peek() {
@@ -1458,48 +1501,28 @@ fn parse_option_some_pattern(state: &mut ParserState) -> Result<String> {
         state.tokens.advance();
         name
     } else {
-        bail!("Expected identifier inside Some pattern");
+        bail!("Expected identifier inside {wrapper} pattern");
     };
 
     state.tokens.expect(&Token::RightParen)?;
-    Ok(format!("Some({inner})"))
+    Ok(Box::new(Pattern::Identifier(inner)))
 }
 
-fn parse_option_none_pattern(state: &mut ParserState) -> Result<String> {
-    state.tokens.advance();
-    Ok("None".to_string())
+fn parse_option_some_pattern(state: &mut ParserState) -> Result<Pattern> {
+    parse_wrapped_identifier(state, "Some").map(Pattern::Some)
 }
 
-fn parse_result_ok_pattern(state: &mut ParserState) -> Result<String> {
-    state.tokens.advance(); // consume Ok
-    state.tokens.expect(&Token::LeftParen)?;
-
-    let inner = if let Some((Token::Identifier(name), _)) = state.tokens.peek() {
-        let name = name.clone();
-        state.tokens.advance();
-        name
-    } else {
-        bail!("Expected identifier inside Ok pattern");
-    };
-
-    state.tokens.expect(&Token::RightParen)?;
-    Ok(format!("Ok({inner})"))
+fn parse_option_none_pattern(state: &mut ParserState) -> Result<Pattern> {
+    state.tokens.advance();
+    Ok(Pattern::None)
 }
 
-fn parse_result_err_pattern(state: &mut ParserState) -> Result<String> {
-    state.tokens.advance(); // consume Err
-    state.tokens.expect(&Token::LeftParen)?;
-
-    let inner = if let Some((Token::Identifier(name), _)) = state.tokens.peek() {
-        let name = name.clone();
-        state.tokens.advance();
-        name
-    } else {
-        bail!("Expected identifier inside Err pattern");
-    };
+fn parse_result_ok_pattern(state: &mut ParserState) -> Result<Pattern> {
+    parse_wrapped_identifier(state, "Ok").map(Pattern::Ok)
+}
 
-    state.tokens.expect(&Token::RightParen)?;
-    Ok(format!("Err({inner})"))
+fn parse_result_err_pattern(state: &mut ParserState) -> Result<Pattern> {
+    parse_wrapped_identifier(state, "Err").map(Pattern::Err)
 }
 
 /// Try to parse a set literal: {expr, expr, ...}
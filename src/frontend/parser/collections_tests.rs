@@ -455,3 +455,47 @@ fn test_comprehension_err_pattern() {
         result.err()
     );
 }
+
+#[test]
+fn test_parse_list_comprehension_multiple_generators_and_filters() {
+    use crate::frontend::ast::{BinaryOp, ExprKind};
+
+    let mut parser = Parser::new("[x + y for x in xs if x > 0 if x < 9 for y in ys if y != x]");
+    let expr = parser.parse().expect("multi-generator comprehension should parse");
+    let ExprKind::ListComprehension { clauses, .. } = &expr.kind else {
+        panic!("expected list comprehension, got {:?}", expr.kind);
+    };
+    assert_eq!(clauses.len(), 2);
+    assert_eq!(clauses[0].variable, "x");
+    assert_eq!(clauses[1].variable, "y");
+    // Consecutive filters on one generator are folded left-to-right with &&
+    let first = clauses[0].condition.as_deref().expect("first clause filter");
+    assert!(matches!(
+        first.kind,
+        ExprKind::Binary {
+            op: BinaryOp::And,
+            ..
+        }
+    ));
+    assert!(clauses[1].condition.is_some());
+}
+
+#[test]
+fn test_parse_comprehension_nested_tuple_pattern() {
+    use crate::frontend::ast::{ExprKind, Pattern};
+
+    let mut parser = Parser::new("[a + c for ((a, b), c) in pairs]");
+    let expr = parser.parse().expect("nested tuple pattern should parse");
+    let ExprKind::ListComprehension { clauses, .. } = &expr.kind else {
+        panic!("expected list comprehension, got {:?}", expr.kind);
+    };
+    let ident = |name: &str| Pattern::Identifier(name.to_string());
+    assert_eq!(
+        clauses[0].pattern,
+        Pattern::Tuple(vec![
+            Pattern::Tuple(vec![ident("a"), ident("b")]),
+            ident("c"),
+        ])
+    );
+    assert_eq!(clauses[0].variable, "((a, b), c)");
+}
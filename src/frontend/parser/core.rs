@@ -1,20 +1,67 @@
 //! Core parser implementation with main entry points
 use super::{bail, utils, ErrorNode, Expr, ExprKind, ParserState, Result, Span, Token};
+use crate::frontend::cst::ConcreteSyntaxTree;
 pub struct Parser<'a> {
     state: ParserState<'a>,
+    build_cst: bool,
+    cst: Option<ConcreteSyntaxTree>,
 }
 impl<'a> Parser<'a> {
     #[must_use]
     pub fn new(input: &'a str) -> Self {
         Self {
             state: ParserState::new(input),
+            build_cst: false,
+            cst: None,
         }
     }
+    /// Also build a lossless concrete syntax tree during [`Parser::parse`]
+    ///
+    /// Retrieve it with [`Parser::take_cst`] after a successful parse.
+    #[must_use]
+    pub fn with_cst(mut self) -> Self {
+        self.build_cst = true;
+        self
+    }
+    /// Allow `max_depth` levels of expression nesting instead of
+    /// [`DEFAULT_MAX_NESTING_DEPTH`](super::DEFAULT_MAX_NESTING_DEPTH)
+    ///
+    /// Deeper input fails with an "expression too deeply nested" error.
+    /// Raise the limit only on threads with a stack to match.
+    #[must_use]
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.state.max_depth = max_depth;
+        self
+    }
+    /// Take the concrete syntax tree built by the last successful parse
+    ///
+    /// Returns `None` unless the parser was created with [`Parser::with_cst`].
+    pub fn take_cst(&mut self) -> Option<ConcreteSyntaxTree> {
+        self.cst.take()
+    }
     /// Get all errors encountered during parsing
     #[must_use]
     pub fn get_errors(&self) -> &[ErrorNode] {
         self.state.get_errors()
     }
+    /// Arena statistics: bytes allocated and items held
+    #[must_use]
+    pub fn arena_stats(&self) -> (usize, usize) {
+        self.state.arena_stats()
+    }
+    /// String interner statistics: distinct strings and their total bytes
+    #[must_use]
+    pub fn interner_stats(&self) -> (usize, usize) {
+        self.state.interner_stats()
+    }
+    /// Source span where parsing stopped.
+    ///
+    /// After a failed [`Parser::parse`] this points at the offending token (or
+    /// the end of input), giving editors a range to underline.
+    #[must_use]
+    pub fn error_span(&mut self) -> Span {
+        self.state.tokens.error_span()
+    }
     /// Parse the input into an expression or block of expressions
     ///
     /// Parse a complete program or expression
@@ -51,7 +98,8 @@ impl<'a> Parser<'a> {
     pub fn parse(&mut self) -> Result<Expr> {
         // Parse multiple top-level expressions/statements as a block
         let mut exprs = Vec::new();
-        while self.state.tokens.peek().is_some() {
+        let mut item_starts = Vec::new();
+        while let Some(&(_, item_span)) = self.state.tokens.peek() {
             let attributes = utils::parse_attributes(&mut self.state)?;
 
             // PARSER-066: Skip trailing comments and check for EOF
@@ -61,7 +109,8 @@ impl<'a> Parser<'a> {
                 break;
             }
 
-            let mut expr = super::parse_expr_recursive(&mut self.state)?;
+            let mut expr = super::parse_expr_recursive(&mut self.state)
+                .map_err(|e| self.state.nesting_error_or(e))?;
 
             // Extract derive attributes for classes, structs, and tuple structs
             match &mut expr.kind {
@@ -80,11 +129,18 @@ impl<'a> Parser<'a> {
             // Append parsed attributes to existing ones (don't overwrite modifier attributes)
             expr.attributes.extend(attributes);
             exprs.push(expr);
+            item_starts.push(item_span.start);
             // Skip optional semicolons
             if let Some((Token::Semicolon, _)) = self.state.tokens.peek() {
                 self.state.tokens.advance();
             }
         }
+        if self.build_cst {
+            self.cst = Some(ConcreteSyntaxTree::with_items(
+                self.state.tokens.source(),
+                &item_starts,
+            ));
+        }
         if exprs.is_empty() {
             bail!("Empty program");
         } else if exprs.len() == 1 {
@@ -119,7 +175,7 @@ impl<'a> Parser<'a> {
     ///
     /// Returns an error if the operation fails
     pub fn parse_expr(&mut self) -> Result<Expr> {
-        super::parse_expr_recursive(&mut self.state)
+        super::parse_expr_recursive(&mut self.state).map_err(|e| self.state.nesting_error_or(e))
     }
     /// Parse an expression with operator precedence
     ///
@@ -141,6 +197,7 @@ impl<'a> Parser<'a> {
     /// Returns an error if the operation fails
     pub fn parse_expr_with_precedence(&mut self, min_prec: i32) -> Result<Expr> {
         super::parse_expr_with_precedence_recursive(&mut self.state, min_prec)
+            .map_err(|e| self.state.nesting_error_or(e))
     }
 }
 
@@ -177,6 +234,35 @@ mod tests {
         assert!(parser.get_errors().is_empty());
     }
 
+    #[test]
+    fn test_error_span_points_at_offending_token() {
+        let source = "let = 5";
+        let mut parser = Parser::new(source);
+        assert!(parser.parse().is_err());
+        let span = parser.error_span();
+        assert_eq!((span.start, span.end), (4, 5));
+        assert_eq!(&source[span.start..span.end], "=");
+    }
+
+    #[test]
+    fn test_error_span_on_unexpected_token() {
+        let source = "fn add(a b) { a }";
+        let mut parser = Parser::new(source);
+        assert!(parser.parse().is_err());
+        let span = parser.error_span();
+        assert_eq!((span.start, span.end), (9, 10));
+        assert_eq!(&source[span.start..span.end], "b");
+    }
+
+    #[test]
+    fn test_error_span_at_end_of_input_is_empty() {
+        let source = "let x = (1, 2";
+        let mut parser = Parser::new(source);
+        assert!(parser.parse().is_err());
+        let span = parser.error_span();
+        assert_eq!((span.start, span.end), (source.len(), source.len()));
+    }
+
     // ============================================================
     // Simple literal tests
     // ============================================================
@@ -65,7 +65,14 @@ fn parse_single_handler(state: &mut ParserState) -> Result<EffectHandler> {
 
 /// SPEC-001-J: Parse effect handler expression
 /// Syntax: handle expr with { operation => body, operation(params) => body }
+///
+/// `handle(...)` without a `with` is a call of an ordinary function, as in
+/// `catch e { handle(e) }`.
 pub fn parse_handler(state: &mut ParserState) -> Result<Expr> {
+    if !at_handler(state) {
+        let span = state.tokens.advance().expect("checked").1;
+        return Ok(Expr::new(ExprKind::Identifier("handle".to_string()), span));
+    }
     let start_span = state.tokens.advance().expect("checked").1;
     let expr = Box::new(super::parse_expr_recursive(state)?);
     state.tokens.expect(&Token::With)?;
@@ -83,6 +90,20 @@ pub fn parse_handler(state: &mut ParserState) -> Result<Expr> {
     Ok(Expr::new(ExprKind::Handle { expr, handlers }, start_span))
 }
 
+/// Whether `handle` starts a handler rather than calling a function named
+/// `handle`, which needs a `with` after the parenthesized expression (complexity: 3)
+fn at_handler(state: &mut ParserState) -> bool {
+    if !matches!(state.tokens.peek_nth(1), Some((Token::LeftParen, _))) {
+        return true;
+    }
+    let saved_position = state.tokens.position();
+    state.tokens.advance();
+    let is_handler = super::parse_expr_recursive(state).is_ok()
+        && matches!(state.tokens.peek(), Some((Token::With, _)));
+    state.tokens.set_position(saved_position);
+    is_handler
+}
+
 fn parse_handler_params(state: &mut ParserState) -> Result<Vec<Pattern>> {
     if matches!(state.tokens.peek(), Some((Token::LeftParen, _))) {
         state.tokens.advance();
@@ -226,6 +247,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_handle_as_function_call() {
+        let expr = parse_code("try { risky() } catch e { handle(e) }").expect("parses");
+        let tree = format!("{expr:?}");
+        assert!(tree.contains("Identifier(\"handle\")"), "{tree}");
+        assert!(!tree.contains("Handle {"), "{tree}");
+    }
+
     // ==================== Error case tests ====================
 
     #[test]
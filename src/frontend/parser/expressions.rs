@@ -19,9 +19,11 @@ pub fn parse_prefix(state: &mut ParserState) -> Result<Expr> {
 
 fn dispatch_prefix_token(state: &mut ParserState, token: Token, span: Span) -> Result<Expr> {
     match token {
-        // Literals (Issue #168: Added HexInteger for hex literal support)
+        // Literals (Issue #168: Added RadixInteger for hex/octal/binary literals)
         Token::Integer(_)
-        | Token::HexInteger(_)
+        | Token::RadixInteger(_)
+        | Token::BigInteger(_)
+        | Token::DecimalLiteral(_)
         | Token::Float(_)
         | Token::String(_)
         | Token::RawString(_)
@@ -74,6 +76,7 @@ fn dispatch_prefix_token(state: &mut ParserState, token: Token, span: Span) -> R
         | Token::While
         | Token::For
         | Token::Try
+        | Token::With
         | Token::Loop
         | Token::Lifetime(_)
         | Token::Label(_) => parse_control_prefix(state, token, span),
@@ -100,6 +103,7 @@ fn dispatch_prefix_token(state: &mut ParserState, token: Token, span: Span) -> R
         | Token::Final
         | Token::Abstract
         | Token::Unsafe
+        | Token::Extern
         | Token::Break
         | Token::Continue
         | Token::Return
@@ -124,6 +128,7 @@ fn dispatch_prefix_token(state: &mut ParserState, token: Token, span: Span) -> R
         | Token::Err
         | Token::Option => parse_collection_prefix(state, token, span),
 
+        Token::Error(kind) => bail!("{kind}"),
         _ => bail!("Unexpected token: {token:?}"),
     }
 }
@@ -131,9 +136,11 @@ fn dispatch_prefix_token(state: &mut ParserState, token: Token, span: Span) -> R
 // All literal parsing moved to expressions_helpers/literals.rs module
 fn parse_literal_prefix(state: &mut ParserState, token: Token, span: Span) -> Result<Expr> {
     match token {
-        // Basic literals - delegated to literals module (Issue #168: Added HexInteger)
+        // Basic literals - delegated to literals module (Issue #168: Added RadixInteger)
         Token::Integer(_)
-        | Token::HexInteger(_)
+        | Token::RadixInteger(_)
+        | Token::BigInteger(_)
+        | Token::DecimalLiteral(_)
         | Token::Float(_)
         | Token::String(_)
         | Token::RawString(_)
@@ -218,9 +225,13 @@ fn parse_decorator_prefix(state: &mut ParserState) -> Result<Expr> {
 
 fn parse_control_prefix(state: &mut ParserState, token: Token, _span: Span) -> Result<Expr> {
     match token {
-        Token::If | Token::Match | Token::While | Token::For | Token::Try | Token::Loop => {
-            parse_control_flow_token(state, token)
-        }
+        Token::If
+        | Token::Match
+        | Token::While
+        | Token::For
+        | Token::Try
+        | Token::With
+        | Token::Loop => parse_control_flow_token(state, token),
         Token::Lifetime(label_name) => {
             state.tokens.advance(); // consume the Lifetime token (LABELED-LOOP-FIX)
                                     // Strip the leading quote from 'outer to get just "outer"
@@ -485,7 +496,7 @@ fn parse_collection_prefix(state: &mut ParserState, token: Token, span: Span) ->
 fn parse_constructor_token(state: &mut ParserState, token: Token, span: Span) -> Result<Expr> {
     expressions_helpers::increment_decrement::parse_constructor_token(state, token, span)
 }
-/// Parse control flow tokens (If, Match, While, For, Try)
+/// Parse control flow tokens (If, Match, While, For, Try, With)
 /// Extracted from `parse_prefix` to reduce complexity
 fn parse_control_flow_token(state: &mut ParserState, token: Token) -> Result<Expr> {
     match token {
@@ -494,6 +505,7 @@ fn parse_control_flow_token(state: &mut ParserState, token: Token) -> Result<Exp
         Token::While => parse_while_loop(state),
         Token::For => parse_for_loop(state),
         Token::Try => parse_try_catch(state),
+        Token::With => expressions_helpers::error_handling::parse_with_block(state),
         Token::Loop => parse_loop(state),
         _ => bail!("Expected control flow token, got: {token:?}"),
     }
@@ -603,6 +615,7 @@ fn parse_control_statement_token(
             expressions_helpers::visibility_modifiers::parse_abstract_token(state, span)
         }
         Token::Unsafe => expressions_helpers::visibility_modifiers::parse_unsafe_token(state, span),
+        Token::Extern => expressions_helpers::extern_blocks::parse_extern_token(state, span),
         Token::Break => expressions_helpers::control_flow::parse_break_token(state, span),
         Token::Continue => expressions_helpers::control_flow::parse_continue_token(state, span),
         Token::Return => expressions_helpers::control_flow::parse_return_token(state, span),
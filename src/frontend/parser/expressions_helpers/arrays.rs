@@ -104,7 +104,8 @@ fn parse_array_init(state: &mut ParserState, value_expr: Expr, start_span: Span)
 
 /// Parse regular list: `[expr, expr, ...]`
 ///
-/// Supports trailing commas and spread expressions.
+/// Supports trailing commas, spread expressions and comments between
+/// elements; a comment after an element's comma trails that element.
 ///
 /// # Examples
 /// ```ruchy
@@ -116,15 +117,24 @@ fn parse_regular_list(state: &mut ParserState, first_expr: Expr, start_span: Spa
     let mut elements = vec![first_expr];
 
     // Parse remaining elements
-    while matches!(state.tokens.peek(), Some((Token::Comma, _))) {
-        state.tokens.advance();
+    loop {
+        let (has_comma, trailing_comment) = state.consume_list_separator();
+        if let (Some(last), Some(comment)) = (elements.last_mut(), trailing_comment) {
+            last.trailing_comment.get_or_insert(comment);
+        }
+        if !has_comma {
+            break;
+        }
 
         // Check for trailing comma
+        let leading_comments = state.consume_leading_comments();
         if matches!(state.tokens.peek(), Some((Token::RightBracket, _))) {
             break;
         }
 
-        elements.push(parse_array_element(state)?);
+        let mut element = parse_array_element(state)?;
+        element.leading_comments = leading_comments;
+        elements.push(element);
     }
 
     state
@@ -95,7 +95,7 @@ fn parse_async_function(state: &mut ParserState, is_pub: bool) -> Result<Expr> {
     };
 
     // Parse body
-    let body = parse_expr_recursive(state)?;
+    let body = state.in_function_body(parse_expr_recursive)?;
 
     Ok(Expr::new(
         ExprKind::Function {
@@ -145,7 +145,7 @@ fn parse_async_lambda(state: &mut ParserState) -> Result<Expr> {
 
     state.tokens.expect(&Token::Pipe)?; // consume closing '|'
 
-    let body = parse_expr_recursive(state)?;
+    let body = state.in_function_body(parse_expr_recursive)?;
 
     Ok(Expr::new(
         ExprKind::AsyncLambda {
@@ -209,7 +209,7 @@ fn parse_async_arrow_lambda(state: &mut ParserState) -> Result<Expr> {
     state.tokens.expect(&Token::Arrow)?;
 
     // Parse body
-    let body = parse_expr_recursive(state)?;
+    let body = state.in_function_body(parse_expr_recursive)?;
 
     Ok(Expr::new(
         ExprKind::AsyncLambda {
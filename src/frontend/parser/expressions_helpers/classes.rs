@@ -124,7 +124,12 @@ fn parse_class_body(
     let mut constants = Vec::new();
     let mut properties = Vec::new();
 
-    while !matches!(state.tokens.peek(), Some((Token::RightBrace, _))) {
+    loop {
+        let leading_comments = state.consume_leading_comments();
+        if matches!(state.tokens.peek(), Some((Token::RightBrace, _))) {
+            break;
+        }
+        let field_count = fields.len();
         parse_class_member(
             state,
             &mut fields,
@@ -134,6 +139,12 @@ fn parse_class_body(
             &mut properties,
         )?;
         consume_optional_separator(state);
+        let trailing_comment = state.consume_line_end_comment();
+        // Only fields keep their comments; other members carry none
+        if let Some(field) = fields.get_mut(field_count) {
+            field.leading_comments = leading_comments;
+            field.trailing_comment = trailing_comment;
+        }
     }
 
     state.tokens.expect(&Token::RightBrace)?;
@@ -322,6 +333,8 @@ fn parse_and_add_field(
         is_mut,
         default_value,
         decorators,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     });
     Ok(())
 }
@@ -86,40 +86,16 @@ fn parse_enum_name(state: &mut ParserState) -> Result<String> {
 fn parse_enum_variants(state: &mut ParserState) -> Result<Vec<EnumVariant>> {
     state.tokens.expect(&Token::LeftBrace)?;
     let mut variants = Vec::new();
-    while !matches!(state.tokens.peek(), Some((Token::RightBrace, _))) {
-        variants.push(parse_single_variant(state)?);
-
-        // Skip any inline comments after variant definition
-        while matches!(
-            state.tokens.peek(),
-            Some((
-                Token::LineComment(_)
-                    | Token::BlockComment(_)
-                    | Token::DocComment(_)
-                    | Token::HashComment(_),
-                _
-            ))
-        ) {
-            state.tokens.advance();
-        }
-
-        if matches!(state.tokens.peek(), Some((Token::Comma, _))) {
-            state.tokens.advance();
-
-            // Skip comments after comma
-            while matches!(
-                state.tokens.peek(),
-                Some((
-                    Token::LineComment(_)
-                        | Token::BlockComment(_)
-                        | Token::DocComment(_)
-                        | Token::HashComment(_),
-                    _
-                ))
-            ) {
-                state.tokens.advance();
-            }
+    loop {
+        let leading_comments = state.consume_leading_comments();
+        if matches!(state.tokens.peek(), Some((Token::RightBrace, _))) {
+            break;
         }
+        let mut variant = parse_single_variant(state)?;
+        let (_, trailing_comment) = state.consume_list_separator();
+        variant.leading_comments = leading_comments;
+        variant.trailing_comment = trailing_comment;
+        variants.push(variant);
     }
     state.tokens.expect(&Token::RightBrace)?;
     Ok(variants)
@@ -153,6 +129,8 @@ fn parse_single_variant(state: &mut ParserState) -> Result<EnumVariant> {
         name: variant_name,
         kind,
         discriminant,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     })
 }
 /// Parse discriminant value for enum variant
@@ -245,7 +223,11 @@ fn parse_variant_struct_fields(state: &mut ParserState) -> Result<Vec<StructFiel
     state.tokens.expect(&Token::LeftBrace)?;
     let mut fields = Vec::new();
 
-    while !matches!(state.tokens.peek(), Some((Token::RightBrace, _))) {
+    loop {
+        let leading_comments = state.consume_leading_comments();
+        if matches!(state.tokens.peek(), Some((Token::RightBrace, _))) {
+            break;
+        }
         // Parse field name
         let name = if let Some((Token::Identifier(n), _)) = state.tokens.peek() {
             let name = n.clone();
@@ -260,6 +242,8 @@ fn parse_variant_struct_fields(state: &mut ParserState) -> Result<Vec<StructFiel
 
         // Parse field type
         let ty = utils::parse_type(state)?;
+        // Handle comma
+        let (_, trailing_comment) = state.consume_list_separator();
 
         fields.push(StructField {
             name,
@@ -268,12 +252,9 @@ fn parse_variant_struct_fields(state: &mut ParserState) -> Result<Vec<StructFiel
             is_mut: false,
             default_value: None,
             decorators: vec![],
+            leading_comments,
+            trailing_comment,
         });
-
-        // Handle comma
-        if matches!(state.tokens.peek(), Some((Token::Comma, _))) {
-            state.tokens.advance();
-        }
     }
 
     state.tokens.expect(&Token::RightBrace)?;
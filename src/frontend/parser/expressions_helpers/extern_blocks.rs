@@ -0,0 +1,209 @@
+//! Extern declaration parsing
+//!
+//! Handles parsing of foreign function declarations:
+//! - Extern blocks: `extern "rust" crate::path { fun name(params) -> Type }`
+//! - Extern functions: `extern "C" fun name() { ... }`
+//!
+//! # Examples
+//! ```ruchy
+//! // Functions provided by the `slug` crate
+//! extern "rust" slug {
+//!     fun slugify(text: &str) -> String
+//! }
+//!
+//! // Function with a foreign calling convention
+//! extern "C" fun callback(x: i32) -> i32 { x * 2 }
+//! ```
+
+use crate::frontend::ast::{Attribute, Expr, ExprKind, ExternFunction, Span};
+use crate::frontend::lexer::Token;
+use crate::frontend::parser::{bail, token_as_identifier, utils, ParserState, Result};
+
+/// Parse `extern "ABI"` followed by a function or a declaration block
+///
+/// As in Rust, a bare `extern` means `extern "C"`.
+pub(in crate::frontend::parser) fn parse_extern_token(
+    state: &mut ParserState,
+    span: Span,
+) -> Result<Expr> {
+    state.tokens.advance(); // consume 'extern'
+    let abi = match state.tokens.peek() {
+        Some((Token::String(abi), _)) => {
+            let abi = abi.clone();
+            state.tokens.advance();
+            abi
+        }
+        Some((Token::Identifier(_), _)) => {
+            bail!("Expected ABI string after 'extern', e.g. extern \"rust\"")
+        }
+        _ => "C".to_string(),
+    };
+
+    if matches!(state.tokens.peek(), Some((Token::Fun | Token::Fn, _))) {
+        let mut expr = super::super::parse_prefix(state)?;
+        expr.attributes.push(Attribute {
+            name: "extern".to_string(),
+            args: vec![abi],
+            span: expr.span,
+        });
+        return Ok(expr);
+    }
+
+    let path = match state.tokens.peek() {
+        Some((Token::Identifier(name), _)) => {
+            let name = name.clone();
+            state.tokens.advance();
+            Some(super::identifiers::parse_module_path_segments(state, name)?)
+        }
+        _ => None,
+    };
+    let functions = parse_extern_functions(state)?;
+    Ok(Expr::new(
+        ExprKind::ExternBlock {
+            abi,
+            path,
+            functions,
+        },
+        span,
+    ))
+}
+
+/// Parse `{ fun name(params) [-> Type] [;] ... }`
+fn parse_extern_functions(state: &mut ParserState) -> Result<Vec<ExternFunction>> {
+    state.tokens.expect(&Token::LeftBrace)?;
+    let mut functions = Vec::new();
+    loop {
+        skip_separators(state);
+        match state.tokens.peek() {
+            Some((Token::RightBrace, _)) => break,
+            Some((Token::Fun | Token::Fn, _)) => functions.push(parse_extern_function(state)?),
+            Some((token, _)) => {
+                bail!("Expected function declaration in extern block, got: {token:?}")
+            }
+            None => bail!("Unterminated extern block: expected '}}'"),
+        }
+    }
+    state.tokens.expect(&Token::RightBrace)?;
+    Ok(functions)
+}
+
+/// Parse a body-less function signature
+fn parse_extern_function(state: &mut ParserState) -> Result<ExternFunction> {
+    let span = state.tokens.advance().expect("checked by caller").1;
+    // Rust names may be Ruchy keywords, like `std::env::var`
+    let Some(name) = state
+        .tokens
+        .peek()
+        .and_then(|(token, _)| token_as_identifier(token))
+    else {
+        bail!("Expected function name in extern block");
+    };
+    state.tokens.advance();
+    let params = utils::parse_params(state)?;
+    let return_type = if matches!(state.tokens.peek(), Some((Token::Arrow, _))) {
+        state.tokens.advance();
+        Some(utils::parse_type(state)?)
+    } else {
+        None
+    };
+    if matches!(state.tokens.peek(), Some((Token::LeftBrace, _))) {
+        bail!("Function `{name}` in an extern block cannot have a body");
+    }
+    Ok(ExternFunction {
+        name,
+        params,
+        return_type,
+        span,
+    })
+}
+
+/// Skip semicolons and comments between declarations
+fn skip_separators(state: &mut ParserState) {
+    while matches!(
+        state.tokens.peek(),
+        Some((
+            Token::Semicolon
+                | Token::LineComment(_)
+                | Token::BlockComment(_)
+                | Token::HashComment(_)
+                | Token::DocComment(_),
+            _
+        ))
+    ) {
+        state.tokens.advance();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::frontend::ast::{Expr, ExprKind, TypeKind};
+    use crate::frontend::parser::Parser;
+
+    fn parse(code: &str) -> Expr {
+        Parser::new(code).parse().expect("should parse")
+    }
+
+    #[test]
+    fn test_extern_rust_block() {
+        let expr = parse(
+            "extern \"rust\" slug {\n    // from crates.io\n    fun slugify(text: &str) -> String;\n    fun is_slug(s: &str) -> bool\n}",
+        );
+        let ExprKind::ExternBlock {
+            abi,
+            path,
+            functions,
+        } = &expr.kind
+        else {
+            panic!("expected extern block, got {:?}", expr.kind);
+        };
+        assert_eq!(abi, "rust");
+        assert_eq!(path.as_deref(), Some("slug"));
+        let names: Vec<_> = functions.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["slugify", "is_slug"]);
+        assert!(matches!(
+            functions[0].params[0].ty.kind,
+            TypeKind::Reference { .. }
+        ));
+        assert!(functions[0].return_type.is_some());
+    }
+
+    #[test]
+    fn test_extern_block_with_module_path() {
+        let expr =
+            parse("extern \"rust\" textwrap::core { fun display_width(text: &str) -> usize }");
+        let ExprKind::ExternBlock { path, .. } = &expr.kind else {
+            panic!("expected extern block, got {:?}", expr.kind);
+        };
+        assert_eq!(path.as_deref(), Some("textwrap::core"));
+    }
+
+    #[test]
+    fn test_extern_c_block_without_path() {
+        let expr = parse("extern \"C\" { }");
+        assert!(matches!(
+            &expr.kind,
+            ExprKind::ExternBlock { path: None, functions, .. } if functions.is_empty()
+        ));
+        let expr = parse("extern { }");
+        assert!(matches!(&expr.kind, ExprKind::ExternBlock { abi, .. } if abi == "C"));
+    }
+
+    #[test]
+    fn test_extern_function_gets_abi_attribute() {
+        let expr = parse("extern \"C\" fun foo() { }");
+        assert!(matches!(expr.kind, ExprKind::Function { .. }));
+        assert_eq!(expr.attributes[0].name, "extern");
+        assert_eq!(expr.attributes[0].args, ["C"]);
+    }
+
+    #[test]
+    fn test_extern_errors() {
+        assert!(Parser::new("extern slug { }").parse().is_err());
+        assert!(Parser::new("extern \"rust\" slug { let x = 1 }")
+            .parse()
+            .is_err());
+        assert!(Parser::new("extern \"rust\" slug { fun f() { 1 } }")
+            .parse()
+            .is_err());
+    }
+}
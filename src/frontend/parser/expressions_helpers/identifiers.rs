@@ -27,6 +27,10 @@ use crate::frontend::parser::{bail, ParserState, Result};
 /// _               // Underscore identifier
 /// self            // Self identifier
 /// super           // Super identifier
+/// rust { ... }    // Verbatim Rust block
+/// capture_output { ... } // Block whose printed output is returned
+/// defer f.close() // Runs when the enclosing scope exits
+/// using f = open(path) { ... } // Resource block, same as `with`
 /// ```
 pub(in crate::frontend::parser) fn parse_identifier_token(
     state: &mut ParserState,
@@ -34,6 +38,19 @@ pub(in crate::frontend::parser) fn parse_identifier_token(
     span: Span,
 ) -> Result<Expr> {
     match token {
+        Token::Identifier(name) if name == "rust" && super::rust_blocks::at_rust_block(state) => {
+            super::rust_blocks::parse_rust_block(state, span)
+        }
+        Token::Identifier(name)
+            if name == "capture_output"
+                && matches!(state.tokens.peek_nth(1), Some((Token::LeftBrace, _))) =>
+        {
+            parse_capture_output(state, span)
+        }
+        Token::Identifier(name) if name == "defer" && at_defer(state) => parse_defer(state, span),
+        Token::Identifier(name) if name == "using" && at_using(state) => {
+            super::error_handling::parse_with_block(state)
+        }
         Token::Identifier(name) => {
             state.tokens.advance();
             // Check for fat arrow lambda: x => x * 2
@@ -64,6 +81,67 @@ pub(in crate::frontend::parser) fn parse_identifier_token(
     }
 }
 
+/// Parse `capture_output { ... }` as `capture_output(|| { ... })`
+fn parse_capture_output(state: &mut ParserState, span: Span) -> Result<Expr> {
+    state.tokens.advance(); // consume 'capture_output'
+    let body = crate::frontend::parser::collections::parse_block(state)?;
+    let body_span = body.span;
+    let func = Expr::new(ExprKind::Identifier("capture_output".to_string()), span);
+    let lambda = Expr::new(
+        ExprKind::Lambda {
+            params: Vec::new(),
+            body: Box::new(body),
+        },
+        body_span,
+    );
+    Ok(Expr::new(
+        ExprKind::Call {
+            func: Box::new(func),
+            args: vec![lambda],
+        },
+        Span::new(span.start, body_span.end),
+    ))
+}
+
+/// Whether `defer` starts a deferred expression rather than naming a value
+///
+/// Only a block, identifier or `self` may follow, which keeps `defer` usable
+/// as an ordinary name (`Observable::defer(...)`, `let defer = 1`).
+fn at_defer(state: &mut ParserState) -> bool {
+    matches!(
+        state.tokens.peek_nth(1),
+        Some((Token::LeftBrace | Token::Identifier(_) | Token::Self_, _))
+    )
+}
+
+/// Whether `using` starts a resource block (`using f = ...`)
+fn at_using(state: &mut ParserState) -> bool {
+    match state.tokens.peek_nth(1) {
+        Some((Token::Mut, _)) => true,
+        Some((Token::Identifier(_), _)) => {
+            matches!(state.tokens.peek_nth(2), Some((Token::Equal, _)))
+        }
+        _ => false,
+    }
+}
+
+/// Parse `defer expr` or `defer { ... }` (complexity: 2)
+fn parse_defer(state: &mut ParserState, span: Span) -> Result<Expr> {
+    state.tokens.advance(); // consume 'defer'
+    let expr = if matches!(state.tokens.peek(), Some((Token::LeftBrace, _))) {
+        crate::frontend::parser::collections::parse_block(state)?
+    } else {
+        super::super::parse_expr_recursive(state)?
+    };
+    let end = expr.span.end;
+    Ok(Expr::new(
+        ExprKind::Defer {
+            expr: Box::new(expr),
+        },
+        Span::new(span.start, end),
+    ))
+}
+
 /// Parse module path segments separated by :: (complexity: 3)
 ///
 /// # Examples
@@ -160,6 +238,14 @@ pub(in crate::frontend::parser) fn parse_turbofish_generics(
                 state.tokens.advance();
                 break;
             }
+            Some((Token::RightShift, _)) => {
+                // >> closes both an inner and this list, as in collect::<Vec<_>>:
+                // the inner list leaves it for the outer one to consume
+                if type_args.iter().any(|arg| arg.ends_with('>')) {
+                    state.tokens.advance(); // consume >>
+                }
+                break;
+            }
             _ => bail!("Expected ',' or '>' in turbofish generics"),
         }
     }
@@ -178,6 +264,7 @@ pub(in crate::frontend::parser) fn parse_turbofish_generics(
 /// String
 /// std::Vec
 /// Vec<i32>  // Nested generics
+/// Vec<_>    // Inferred element type
 /// ```
 fn parse_turbofish_type(state: &mut ParserState) -> Result<String> {
     let mut type_str = String::new();
@@ -197,6 +284,12 @@ fn parse_turbofish_type(state: &mut ParserState) -> Result<String> {
                 }
                 break;
             }
+            Some((Token::Underscore, _)) => {
+                // Inferred type like Vec<_>
+                type_str.push('_');
+                state.tokens.advance();
+                break;
+            }
             Some((Token::Integer(n), _)) => {
                 // For array sizes like [i32; 10]
                 type_str.push_str(&n.clone());
@@ -233,6 +326,7 @@ pub(in crate::frontend::parser) fn token_to_keyword_string(token: &Token) -> Str
         Token::Else => "else".to_string(),
         Token::Enum => "enum".to_string(),
         Token::Err => "Err".to_string(),
+        Token::Extern => "extern".to_string(),
         Token::Fn => "fn".to_string(),
         Token::For => "for".to_string(),
         Token::From => "from".to_string(),
@@ -337,6 +431,43 @@ mod tests {
         assert!(result.is_ok(), "Default identifier should parse");
     }
 
+    #[test]
+    fn test_capture_output_block() {
+        let expr = Parser::new("capture_output { println(1) }")
+            .parse()
+            .unwrap();
+        let ExprKind::Call { func, args } = &expr.kind else {
+            panic!("expected a call, got {:?}", expr.kind);
+        };
+        assert!(matches!(&func.kind, ExprKind::Identifier(name) if name == "capture_output"));
+        assert!(matches!(
+            &args[..],
+            [Expr { kind: ExprKind::Lambda { params, .. }, .. }] if params.is_empty()
+        ));
+    }
+
+    #[test]
+    fn test_defer_expression_and_block() {
+        let expr = Parser::new("defer f.close()").parse().unwrap();
+        let ExprKind::Defer { expr: deferred } = &expr.kind else {
+            panic!("expected defer, got {:?}", expr.kind);
+        };
+        assert!(matches!(&deferred.kind, ExprKind::MethodCall { method, .. } if method == "close"));
+
+        let expr = Parser::new("defer { done = true }").parse().unwrap();
+        assert!(matches!(
+            &expr.kind,
+            ExprKind::Defer { expr } if matches!(expr.kind, ExprKind::Block(_))
+        ));
+    }
+
+    #[test]
+    fn test_defer_is_still_an_ordinary_name() {
+        let expr = Parser::new("Observable::defer(f)").parse().unwrap();
+        assert!(matches!(expr.kind, ExprKind::Call { .. }));
+        assert!(Parser::new("let defer = 1; defer + 1").parse().is_ok());
+    }
+
     // ==================== Qualified path tests ====================
 
     #[test]
@@ -428,6 +559,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_inferred_type_annotation() {
+        let code = "let x: Vec<_> = vec![]";
+        let result = Parser::new(code).parse();
+        assert!(
+            result.is_ok(),
+            "Inferred type param should parse: {result:?}"
+        );
+    }
+
+    #[test]
+    fn test_turbofish_with_inferred_type() {
+        let code = "xs.iter().collect::<Vec<_>>()";
+        let result = Parser::new(code).parse();
+        assert!(
+            result.is_ok(),
+            "Turbofish with inferred type should parse: {result:?}"
+        );
+    }
+
     // ==================== Fat arrow lambda tests ====================
 
     #[test]
@@ -104,15 +104,27 @@ pub(in crate::frontend::parser) fn parse_impl_block(state: &mut ParserState) ->
     ))
 }
 
-/// Helper: Expect identifier token
+/// Helper: Expect identifier token (or `Option` or `Result`, keywords that
+/// can also name a user-defined enum)
 fn expect_identifier(state: &mut ParserState) -> Result<String> {
-    if let Some((Token::Identifier(name), _)) = state.tokens.peek() {
-        let name = name.clone();
-        state.tokens.advance();
-        Ok(name)
-    } else {
-        use crate::frontend::parser::bail;
-        bail!("Expected identifier")
+    match state.tokens.peek() {
+        Some((Token::Identifier(name), _)) => {
+            let name = name.clone();
+            state.tokens.advance();
+            Ok(name)
+        }
+        Some((Token::Option, _)) => {
+            state.tokens.advance();
+            Ok("Option".to_string())
+        }
+        Some((Token::Result, _)) => {
+            state.tokens.advance();
+            Ok("Result".to_string())
+        }
+        _ => {
+            use crate::frontend::parser::bail;
+            bail!("Expected identifier")
+        }
     }
 }
 
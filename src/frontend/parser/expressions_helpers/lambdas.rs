@@ -34,7 +34,7 @@ use crate::frontend::parser::{bail, parse_expr_recursive, ParserState, Result};
 pub(in crate::frontend::parser) fn parse_lambda_no_params(state: &mut ParserState) -> Result<Expr> {
     let start_span = state.tokens.expect(&Token::OrOr)?;
     // Parse the body
-    let body = Box::new(parse_expr_recursive(state)?);
+    let body = Box::new(state.in_function_body(parse_expr_recursive)?);
     Ok(Expr::new(
         ExprKind::Lambda {
             params: vec![],
@@ -66,6 +66,8 @@ pub(in crate::frontend::parser) fn parse_lambda_from_expr(
             default_value: None,
             is_mutable: false,
             span: expr.span,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }],
         ExprKind::Tuple(elements) => {
             // Convert tuple elements to parameters
@@ -81,6 +83,8 @@ pub(in crate::frontend::parser) fn parse_lambda_from_expr(
                         default_value: None,
                         is_mutable: false,
                         span: elem.span,
+                        leading_comments: Vec::new(),
+                        trailing_comment: None,
                     }),
                     _ => bail!("Expected identifier in lambda parameter"),
                 })
@@ -90,7 +94,7 @@ pub(in crate::frontend::parser) fn parse_lambda_from_expr(
     };
 
     // Parse the body
-    let body = Box::new(parse_expr_recursive(state)?);
+    let body = Box::new(state.in_function_body(parse_expr_recursive)?);
     Ok(Expr::new(ExprKind::Lambda { params, body }, start_span))
 }
 
@@ -146,7 +150,7 @@ pub(in crate::frontend::parser) fn parse_lambda_expression(
     }
 
     // Parse body and convert params to Param structs
-    let body = Box::new(parse_expr_recursive(state)?);
+    let body = Box::new(state.in_function_body(parse_expr_recursive)?);
     let params = params
         .into_iter()
         .map(|(pattern, ty)| Param {
@@ -155,6 +159,8 @@ pub(in crate::frontend::parser) fn parse_lambda_expression(
             span: start_span,
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         })
         .collect();
 
@@ -1,11 +1,13 @@
 //! Literal expression parsing
 //!
 //! Handles parsing of primitive literal values:
-//! - Integers with optional type suffixes (42, 100i32, 0xFF)
+//! - Integers with optional type suffixes (42, 1_000i32, 0xFF, 0o755, 0b1010)
+//! - Arbitrary-precision integers (123n)
+//! - Exact decimals (1.23d)
 //! - Floats (3.15, 1e-5)
 //! - Strings (regular and raw strings)
 //! - F-strings with interpolation
-//! - Characters ('a', '\n')
+//! - Characters ('a', '\n', '\u{e9}')
 //! - Bytes (b'x')
 //! - Booleans (true, false)
 //!
@@ -47,29 +49,39 @@ pub(in crate::frontend::parser) fn parse_literal_token(
                 } else {
                     (value_str.as_str(), None)
                 };
-            let value = num_part.parse::<i64>().map_err(|_| {
-                ParseError::new(format!("Invalid integer literal: {num_part}"), span)
-            })?;
+            let literal = match num_part.parse::<i64>() {
+                Ok(value) => Literal::Integer(value, type_suffix),
+                // Unsuffixed decimal literals too large for i64 become BigInt
+                Err(_) if type_suffix.is_none() => Literal::BigInt(num_part.to_string()),
+                Err(_) => {
+                    return Err(ParseError::new(
+                        format!("Invalid integer literal: {num_part}"),
+                        span,
+                    )
+                    .into())
+                }
+            };
+            Ok(Expr::new(ExprKind::Literal(literal), span))
+        }
+        Token::BigInteger(digits) => {
+            state.tokens.advance();
             Ok(Expr::new(
-                ExprKind::Literal(Literal::Integer(value, type_suffix)),
+                ExprKind::Literal(Literal::BigInt(digits.clone())),
                 span,
             ))
         }
-        // Issue #168: Hexadecimal literal support (0xFF, 0x1A2B, etc.)
-        Token::HexInteger(value_str) => {
+        Token::DecimalLiteral(text) => {
             state.tokens.advance();
-            // Parse hex value: strip 0x/0X prefix and optional type suffix
-            let without_prefix = &value_str[2..]; // Skip "0x" or "0X"
-            let (hex_part, type_suffix) = if let Some(pos) = without_prefix.find(['i', 'u']) {
-                (
-                    &without_prefix[..pos],
-                    Some(without_prefix[pos..].to_string()),
-                )
-            } else {
-                (without_prefix, None)
-            };
-            let value = i64::from_str_radix(hex_part, 16).map_err(|_| {
-                ParseError::new(format!("Invalid hexadecimal literal: {value_str}"), span)
+            Ok(Expr::new(
+                ExprKind::Literal(Literal::Decimal(text.clone())),
+                span,
+            ))
+        }
+        // Issue #168: Hexadecimal, octal and binary literals (0xFF, 0o755, 0b1010)
+        Token::RadixInteger(value_str) => {
+            state.tokens.advance();
+            let (value, type_suffix) = parse_radix_integer(value_str).ok_or_else(|| {
+                ParseError::new(format!("Invalid integer literal: {value_str}"), span)
             })?;
             Ok(Expr::new(
                 ExprKind::Literal(Literal::Integer(value, type_suffix)),
@@ -160,6 +172,27 @@ pub(in crate::frontend::parser) fn parse_some_constructor(
     ))
 }
 
+/// Parse a `0x`, `0o` or `0b` literal (separators already removed) into its
+/// value and optional type suffix; `None` if it does not fit in an `i64`
+pub(in crate::frontend::parser) fn parse_radix_integer(
+    text: &str,
+) -> Option<(i64, Option<String>)> {
+    let radix = match text.get(..2)? {
+        "0x" | "0X" => 16,
+        "0o" | "0O" => 8,
+        "0b" | "0B" => 2,
+        _ => return None,
+    };
+    let body = &text[2..];
+    // Hex digits never include `i` or `u`, so these always start the suffix
+    let (digits, type_suffix) = match body.find(['i', 'u']) {
+        Some(pos) => (&body[..pos], Some(body[pos..].to_string())),
+        None => (body, None),
+    };
+    let value = i64::from_str_radix(digits, radix).ok()?;
+    Some((value, type_suffix))
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -337,6 +370,44 @@ mod tests {
         assert!(result.is_ok(), "Hex long should parse");
     }
 
+    fn literal(code: &str) -> Literal {
+        match parse(code).unwrap().kind {
+            ExprKind::Literal(literal) => literal,
+            other => panic!("expected literal for {code}, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_radix_and_separator_values() {
+        assert_eq!(literal("0xFF"), Literal::Integer(255, None));
+        assert_eq!(literal("0o755"), Literal::Integer(0o755, None));
+        assert_eq!(literal("0b1010"), Literal::Integer(10, None));
+        assert_eq!(literal("1_000_000"), Literal::Integer(1_000_000, None));
+        assert_eq!(
+            literal("0b1111_0000u8"),
+            Literal::Integer(0xF0, Some("u8".to_string()))
+        );
+        assert_eq!(
+            literal("0xFFFF_FFFFi64"),
+            Literal::Integer(0xFFFF_FFFF, Some("i64".to_string()))
+        );
+        assert_eq!(literal("1_000.5"), Literal::Float(1000.5));
+        assert!(parse("0x8000_0000_0000_0000").is_err());
+    }
+
+    #[test]
+    fn test_char_escapes() {
+        assert_eq!(literal("'\\n'"), Literal::Char('\n'));
+        assert_eq!(literal("'\\''"), Literal::Char('\''));
+        assert_eq!(literal("'\\x41'"), Literal::Char('A'));
+        assert_eq!(literal("'\\u{e9}'"), Literal::Char('é'));
+        assert_eq!(literal("'é'"), Literal::Char('é'));
+        assert_eq!(literal("'\\u{1F600}'"), Literal::Char('\u{1F600}'));
+        assert_eq!(literal("b'\\xFF'"), Literal::Byte(0xFF));
+        assert!(parse("'\\x80'").is_err());
+        assert!(parse("'\\u{D800}'").is_err());
+    }
+
     // ============================================================
     // Float literals
     // ============================================================
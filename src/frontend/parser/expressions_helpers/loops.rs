@@ -83,12 +83,14 @@ pub(in crate::frontend::parser) fn parse_loop_label(
 ) -> Result<Expr> {
     // Note: Caller has already consumed the Lifetime token, so current token is Colon
     state.tokens.expect(&Token::Colon)?;
-    match state.tokens.peek() {
-        Some((Token::For, _)) => parse_labeled_for_loop(state, Some(label_name)),
-        Some((Token::While, _)) => parse_labeled_while_loop(state, Some(label_name)),
-        Some((Token::Loop, _)) => parse_labeled_loop(state, Some(label_name)),
+    // The label is in scope for `break`/`continue` inside the loop
+    let label = Some(label_name.clone());
+    state.in_labeled_loop(label_name, |state| match state.tokens.peek() {
+        Some((Token::For, _)) => parse_labeled_for_loop(state, label),
+        Some((Token::While, _)) => parse_labeled_while_loop(state, label),
+        Some((Token::Loop, _)) => parse_labeled_loop(state, label),
         _ => bail!("Expected loop keyword after label"),
-    }
+    })
 }
 
 /// Parse while loop without label
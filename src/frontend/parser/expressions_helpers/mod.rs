@@ -13,6 +13,7 @@ pub mod control_flow;
 pub mod dataframes;
 pub mod enums;
 pub mod error_handling;
+pub mod extern_blocks;
 pub mod identifiers;
 pub mod impls;
 pub mod increment_decrement;
@@ -21,6 +22,7 @@ pub mod literals;
 pub mod loops;
 pub mod modules;
 pub mod patterns;
+pub mod rust_blocks;
 pub mod string_operations;
 pub mod structs;
 pub mod traits;
@@ -9,6 +9,8 @@
 //! - Or patterns: `Some(x) | None`
 //! - Literal patterns: `42`, `"hello"`, `true`
 //! - Range patterns: `1..10`, `1..=100`
+//! - String patterns: `"v" ++ rest`, `name ++ ".txt"`
+//! - Regex patterns: `re"(?P<key>\w+)=(?P<value>.*)"`
 //!
 //! # Examples
 //! ```ruchy
@@ -295,6 +297,8 @@ fn create_let_expression(
         | Pattern::RestNamed(_)
         | Pattern::AtBinding { .. }
         | Pattern::WithDefault { .. }
+        | Pattern::StringAffix { .. }
+        | Pattern::Regex(_)
         | Pattern::Ok(_)
         | Pattern::Err(_)
         | Pattern::Some(_)
@@ -432,7 +436,9 @@ fn parse_single_tuple_pattern_element(state: &mut ParserState) -> Result<Pattern
             state.tokens.advance();
             Ok(Pattern::Wildcard)
         }
-        _ => bail!("Expected identifier, tuple, list, struct, or wildcard in tuple pattern"),
+        Some((Token::DotDot, _)) => parse_list_rest_pattern(state),
+        Some((Token::DotDotDot, _)) => parse_rest_pattern(state),
+        _ => bail!("Expected identifier, tuple, list, struct, wildcard, or rest in tuple pattern"),
     }?;
 
     // Wrap in Mut pattern if mut modifier was present
@@ -635,6 +641,40 @@ fn handle_pattern_separator(state: &mut ParserState, end_token: Token) -> Result
 /// Also handles if-let: if let pattern = expr { `then_branch` } [else { `else_branch` }]
 /// Complexity: <10 (split into helper functions)
 pub(in crate::frontend::parser) fn parse_if_expression(state: &mut ParserState) -> Result<Expr> {
+    // `else if` links are collected in a loop rather than by recursion, so
+    // long chains don't grow the parser's stack. Each link still counts as a
+    // level of nesting, since the links nest in the tree.
+    let depth = state.depth;
+    let links = parse_if_links(state);
+    state.depth = depth;
+    let (mut chain, else_branch) = links?;
+    let mut expr = chain.pop().expect("chain starts with an if");
+    set_else_branch(&mut expr, else_branch);
+    while let Some(mut outer) = chain.pop() {
+        set_else_branch(&mut outer, Some(Box::new(expr)));
+        expr = outer;
+    }
+    Ok(expr)
+}
+/// Parse the links of an `if`/`else if` chain and its final else branch,
+/// one level deeper for each `else`
+fn parse_if_links(state: &mut ParserState) -> Result<(Vec<Expr>, Option<Box<Expr>>)> {
+    let mut chain = vec![parse_if_link(state)?];
+    while matches!(state.tokens.peek(), Some((Token::Else, _))) {
+        state.tokens.advance(); // consume 'else'
+        state.descend(1)?;
+        if !matches!(state.tokens.peek(), Some((Token::If, _))) {
+            let else_branch = parse_expr_recursive(state).map_err(|e| {
+                anyhow::anyhow!("Expected body after 'else', typically {{ ... }}: {e}")
+            })?;
+            return Ok((chain, Some(Box::new(else_branch))));
+        }
+        chain.push(parse_if_link(state)?);
+    }
+    Ok((chain, None))
+}
+/// Parse one `if` or `if let` without its else branch
+fn parse_if_link(state: &mut ParserState) -> Result<Expr> {
     let start_span = state.tokens.expect(&Token::If)?;
     // Check for if-let syntax
     if matches!(state.tokens.peek(), Some((Token::Let, _))) {
@@ -643,7 +683,13 @@ pub(in crate::frontend::parser) fn parse_if_expression(state: &mut ParserState)
         parse_regular_if_expression(state, start_span)
     }
 }
-/// Parse if-let expression: if let pattern = expr { then } [else { else }]
+/// Attach `branch` as the else branch of an `if` or `if let`
+fn set_else_branch(expr: &mut Expr, branch: Option<Box<Expr>>) {
+    if let ExprKind::If { else_branch, .. } | ExprKind::IfLet { else_branch, .. } = &mut expr.kind {
+        *else_branch = branch;
+    }
+}
+/// Parse if-let expression: if let pattern = expr { then }
 /// Complexity: <10
 fn parse_if_let_expression(state: &mut ParserState, start_span: Span) -> Result<Expr> {
     state.tokens.advance(); // consume 'let'
@@ -664,19 +710,17 @@ fn parse_if_let_expression(state: &mut ParserState, start_span: Span) -> Result<
     let then_branch = Box::new(parse_expr_recursive(state).map_err(|e| {
         anyhow::anyhow!("Expected body after if-let condition, typically {{ ... }}: {e}")
     })?);
-    // Parse optional else branch
-    let else_branch = parse_else_branch(state)?;
     Ok(Expr::new(
         ExprKind::IfLet {
             pattern,
             expr,
             then_branch,
-            else_branch,
+            else_branch: None,
         },
         start_span,
     ))
 }
-/// Parse regular if expression: if condition { then } [else { else }]
+/// Parse regular if expression: if condition { then }
 /// Complexity: <10
 fn parse_regular_if_expression(state: &mut ParserState, start_span: Span) -> Result<Expr> {
     // Parse condition with better error context
@@ -688,35 +732,15 @@ fn parse_regular_if_expression(state: &mut ParserState, start_span: Span) -> Res
     let then_branch = Box::new(parse_expr_recursive(state).map_err(|e| {
         anyhow::anyhow!("Expected body after if condition, typically {{ ... }}: {e}")
     })?);
-    // Parse optional else branch
-    let else_branch = parse_else_branch(state)?;
     Ok(Expr::new(
         ExprKind::If {
             condition,
             then_branch,
-            else_branch,
+            else_branch: None,
         },
         start_span,
     ))
 }
-/// Parse else branch for if/if-let expressions
-/// Complexity: <10
-fn parse_else_branch(state: &mut ParserState) -> Result<Option<Box<Expr>>> {
-    if matches!(state.tokens.peek(), Some((Token::Else, _))) {
-        state.tokens.advance(); // consume 'else'
-                                // Check for else-if or else-if-let
-        if matches!(state.tokens.peek(), Some((Token::If, _))) {
-            // Let the recursive call handle else-if or else-if-let
-            Ok(Some(Box::new(parse_if_expression(state)?)))
-        } else {
-            Ok(Some(Box::new(parse_expr_recursive(state).map_err(
-                |e| anyhow::anyhow!("Expected body after 'else', typically {{ ... }}: {e}"),
-            )?)))
-        }
-    } else {
-        Ok(None)
-    }
-}
 /// Parse match expression: match expr { pattern => result, ... }
 /// Complexity target: <10 (using helper functions for TDG compliance)
 pub(in crate::frontend::parser) fn parse_match_expression(state: &mut ParserState) -> Result<Expr> {
@@ -741,20 +765,23 @@ pub(in crate::frontend::parser) fn parse_match_expression(state: &mut ParserStat
     Ok(Expr::new(ExprKind::Match { expr, arms }, start_span))
 }
 /// Parse match arms with low complexity (helper function for TDG compliance)
+///
+/// Comments before an arm lead it; a comment after the arm (and its
+/// optional comma) on the same line trails it.
 fn parse_match_arms(state: &mut ParserState) -> Result<Vec<MatchArm>> {
     let mut arms = Vec::new();
-    while !matches!(state.tokens.peek(), Some((Token::RightBrace, _)) | None) {
-        // Parse single arm
-        let arm = parse_single_match_arm(state)?;
-        arms.push(arm);
-        // Optional comma
-        if matches!(state.tokens.peek(), Some((Token::Comma, _))) {
-            state.tokens.advance();
-        }
-        // Check if we're done
-        if matches!(state.tokens.peek(), Some((Token::RightBrace, _))) {
+    loop {
+        let leading_comments = state.consume_leading_comments();
+        if matches!(state.tokens.peek(), Some((Token::RightBrace, _)) | None) {
             break;
         }
+        let mut arm = parse_single_match_arm(state)?;
+        // Optional comma
+        let (_, trailing_comment) = state.consume_list_separator();
+        arm.leading_comments = leading_comments;
+        // `Pat => value // note` lands on the body; hoist it so it stays after the comma
+        arm.trailing_comment = trailing_comment.or_else(|| arm.body.trailing_comment.take());
+        arms.push(arm);
     }
     if arms.is_empty() {
         bail!("Match expression must have at least one arm");
@@ -820,6 +847,8 @@ fn parse_single_match_arm(state: &mut ParserState) -> Result<MatchArm> {
         guard,
         body,
         span: start_span.merge(end_span),
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     })
 }
 /// Parse match pattern with low complexity
@@ -837,23 +866,35 @@ pub(in crate::frontend::parser) fn parse_match_pattern(state: &mut ParserState)
         Ok(pattern)
     }
 }
-/// Parse a single pattern (delegates to specific pattern parsers)
-/// Complexity: <8
+/// Parse a single pattern, joined into a string pattern by any `++`
+/// Complexity: 2
 pub(in crate::frontend::parser) fn parse_single_pattern(
     state: &mut ParserState,
 ) -> Result<Pattern> {
+    let pattern = parse_pattern_operand(state)?;
+    if matches!(state.tokens.peek(), Some((Token::Increment, _))) {
+        parse_string_affix_pattern(state, pattern)
+    } else {
+        Ok(pattern)
+    }
+}
+/// Parse a pattern other than a `++` string pattern (delegates to specific pattern parsers)
+/// Complexity: <8
+fn parse_pattern_operand(state: &mut ParserState) -> Result<Pattern> {
     let Some((token, _span)) = state.tokens.peek() else {
         bail!("Expected pattern");
     };
     match token {
         Token::Underscore => parse_wildcard_pattern(state),
         Token::Integer(_)
+        | Token::RadixInteger(_)
         | Token::Float(_)
         | Token::String(_)
         | Token::RawString(_)
         | Token::Char(_)
         | Token::Bool(_)
         | Token::Atom(_) => parse_literal_pattern(state),
+        Token::Regex(_) => parse_regex_pattern(state),
         Token::Some | Token::None => parse_option_pattern(state),
         Token::Ok | Token::Err => parse_result_pattern(state),
         Token::Identifier(_) | Token::Result | Token::Var => {
@@ -865,6 +906,60 @@ pub(in crate::frontend::parser) fn parse_single_pattern(
         _ => bail!("Unexpected token in pattern: {token:?}"),
     }
 }
+/// Parse string patterns: `"v" ++ rest`, `name ++ ".txt"`, `"<" ++ tag ++ ">"`
+///
+/// A name or `_` binds the part of the string between the literal prefix and
+/// suffix. Complexity: 5
+fn parse_string_affix_pattern(state: &mut ParserState, first: Pattern) -> Result<Pattern> {
+    let mut parts = vec![first];
+    while matches!(state.tokens.peek(), Some((Token::Increment, _))) {
+        state.tokens.advance(); // consume '++'
+        parts.push(parse_pattern_operand(state)?);
+    }
+    let (prefix, rest, suffix) = match parts.as_slice() {
+        [prefix, rest, suffix] => (Some(prefix), rest, Some(suffix)),
+        [first, second] if string_literal_pattern(Some(first)).is_some() => {
+            (Some(first), second, None)
+        }
+        [first, second] => (None, first, Some(second)),
+        _ => bail!("A string pattern joins at most a prefix, a name and a suffix with ++"),
+    };
+    let (Some(prefix), Some(suffix)) = (
+        string_literal_pattern(prefix),
+        string_literal_pattern(suffix),
+    ) else {
+        bail!("Expected a string literal on each side of a name in a ++ pattern");
+    };
+    if !matches!(rest, Pattern::Identifier(_) | Pattern::Wildcard) {
+        bail!("Expected a name or _ between the string literals of a ++ pattern");
+    }
+    Ok(Pattern::StringAffix {
+        prefix,
+        rest: Box::new(rest.clone()),
+        suffix,
+    })
+}
+/// Text of a string literal part of a `++` pattern; a missing part is empty
+fn string_literal_pattern(part: Option<&Pattern>) -> Option<String> {
+    match part {
+        None => Some(String::new()),
+        Some(Pattern::Literal(Literal::String(text))) => Some(text.clone()),
+        Some(_) => None,
+    }
+}
+/// Parse regex pattern: `re"..."`, checked to compile
+/// Complexity: 3
+fn parse_regex_pattern(state: &mut ParserState) -> Result<Pattern> {
+    let Some((Token::Regex(regex), _)) = state.tokens.peek() else {
+        bail!("Expected regex pattern");
+    };
+    let regex = regex.clone();
+    state.tokens.advance();
+    if let Err(e) = regex::Regex::new(&regex) {
+        bail!("Invalid regex pattern: {e}");
+    }
+    Ok(Pattern::Regex(regex))
+}
 /// Parse wildcard pattern: _
 /// Complexity: 1
 fn parse_wildcard_pattern(state: &mut ParserState) -> Result<Pattern> {
@@ -879,7 +974,9 @@ fn parse_literal_pattern(state: &mut ParserState) -> Result<Pattern> {
     };
     let token = token.clone(); // Clone to avoid borrow issues
     let pattern = match token {
-        Token::Integer(val) => parse_integer_literal_pattern(state, val)?,
+        Token::Integer(val) | Token::RadixInteger(val) => {
+            parse_integer_literal_pattern(state, &val)?
+        }
         Token::Float(val) => parse_simple_literal_pattern(state, Literal::Float(val))?,
         Token::String(s) => parse_simple_literal_pattern(state, Literal::String(s))?,
         Token::RawString(s) => parse_simple_literal_pattern(state, Literal::String(s))?,
@@ -892,18 +989,26 @@ fn parse_literal_pattern(state: &mut ParserState) -> Result<Pattern> {
     Ok(pattern)
 }
 
-/// Extract method: Parse integer literal with optional range pattern - complexity: 8
-fn parse_integer_literal_pattern(state: &mut ParserState, val: String) -> Result<Pattern> {
-    state.tokens.advance();
-    // Parse the integer value from string (ignore type suffix for pattern matching)
+/// Value and type suffix of an `Integer` or `RadixInteger` token - complexity: 3
+fn integer_token_value(val: &str) -> Result<(i64, Option<String>)> {
+    if let Some(parsed) = super::literals::parse_radix_integer(val) {
+        return Ok(parsed);
+    }
     let (num_part, type_suffix) = if let Some(pos) = val.find(|c: char| c.is_alphabetic()) {
         (&val[..pos], Some(val[pos..].to_string()))
     } else {
-        (val.as_str(), None)
+        (val, None)
     };
     let parsed_val = num_part
         .parse::<i64>()
-        .map_err(|_| anyhow::anyhow!("Invalid integer literal: {num_part}"))?;
+        .map_err(|_| anyhow::anyhow!("Invalid integer literal: {val}"))?;
+    Ok((parsed_val, type_suffix))
+}
+
+/// Extract method: Parse integer literal with optional range pattern - complexity: 4
+fn parse_integer_literal_pattern(state: &mut ParserState, val: &str) -> Result<Pattern> {
+    state.tokens.advance();
+    let (parsed_val, type_suffix) = integer_token_value(val)?;
 
     // Check for range patterns: 1..5 or 1..=5
     match state.tokens.peek() {
@@ -913,26 +1018,20 @@ fn parse_integer_literal_pattern(state: &mut ParserState, val: String) -> Result
     }
 }
 
-/// Extract method: Parse integer range pattern - complexity: 6
+/// Extract method: Parse integer range pattern - complexity: 4
 fn parse_integer_range_pattern(
     state: &mut ParserState,
     start_val: i64,
     inclusive: bool,
 ) -> Result<Pattern> {
     state.tokens.advance(); // consume '..' or '..='
-    if let Some((Token::Integer(end_val_str), _)) = state.tokens.peek() {
+    if let Some((Token::Integer(end_val_str) | Token::RadixInteger(end_val_str), _)) =
+        state.tokens.peek()
+    {
         let end_val_str = end_val_str.clone();
         state.tokens.advance();
-        // Parse the end value
-        let (num_part, _type_suffix) =
-            if let Some(pos) = end_val_str.find(|c: char| c.is_alphabetic()) {
-                (&end_val_str[..pos], Some(end_val_str[pos..].to_string()))
-            } else {
-                (end_val_str.as_str(), None)
-            };
-        let end_val = num_part
-            .parse::<i64>()
-            .map_err(|_| anyhow::anyhow!("Invalid integer literal: {num_part}"))?;
+        // The type suffix of the end value is ignored for range patterns
+        let (end_val, _type_suffix) = integer_token_value(&end_val_str)?;
         Ok(Pattern::Range {
             start: Box::new(Pattern::Literal(Literal::Integer(start_val, None))),
             end: Box::new(Pattern::Literal(Literal::Integer(end_val, None))),
@@ -999,7 +1098,7 @@ fn parse_option_pattern(state: &mut ParserState) -> Result<Pattern> {
             if matches!(state.tokens.peek(), Some((Token::LeftParen, _))) {
                 parse_constructor_pattern(state, "None".to_string())
             } else {
-                Ok(Pattern::Identifier("None".to_string()))
+                Ok(Pattern::None)
             }
         }
         _ => bail!("Expected Some or None pattern"),
@@ -1089,14 +1188,14 @@ fn parse_match_tuple_pattern(state: &mut ParserState) -> Result<Pattern> {
         state.tokens.advance();
         return Ok(Pattern::Tuple(vec![]));
     }
-    // Parse pattern elements
-    let mut patterns = vec![parse_match_pattern(state)?];
+    // Parse pattern elements (including `..`/`..rest` rest elements)
+    let mut patterns = vec![parse_list_pattern_element(state)?];
     while matches!(state.tokens.peek(), Some((Token::Comma, _))) {
         state.tokens.advance(); // consume comma
         if matches!(state.tokens.peek(), Some((Token::RightParen, _))) {
             break; // trailing comma
         }
-        patterns.push(parse_match_pattern(state)?);
+        patterns.push(parse_list_pattern_element(state)?);
     }
     state.tokens.expect(&Token::RightParen)?;
     Ok(Pattern::Tuple(patterns))
@@ -1176,14 +1275,14 @@ fn parse_constructor_arguments(state: &mut ParserState) -> Result<Vec<Pattern>>
     if matches!(state.tokens.peek(), Some((Token::RightParen, _))) {
         return Ok(vec![]);
     }
-    let mut patterns = vec![parse_match_pattern(state)?];
+    let mut patterns = vec![parse_list_pattern_element(state)?];
     // Parse additional patterns if comma-separated
     while matches!(state.tokens.peek(), Some((Token::Comma, _))) {
         state.tokens.advance(); // consume comma
         if matches!(state.tokens.peek(), Some((Token::RightParen, _))) {
             break; // trailing comma
         }
-        patterns.push(parse_match_pattern(state)?);
+        patterns.push(parse_list_pattern_element(state)?);
     }
     Ok(patterns)
 }
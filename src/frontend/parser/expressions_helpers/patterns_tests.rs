@@ -608,6 +608,25 @@ fn test_range_pattern_char_inclusive() {
     assert!(result.is_ok(), "Char inclusive range should parse");
 }
 
+#[test]
+fn test_range_pattern_radix_literals() {
+    let mut state = ParserState::new("0x00..=0x1F");
+    let pattern = parse_single_pattern(&mut state).unwrap();
+    assert_eq!(
+        pattern,
+        Pattern::Range {
+            start: Box::new(Pattern::Literal(Literal::Integer(0, None))),
+            end: Box::new(Pattern::Literal(Literal::Integer(0x1F, None))),
+            inclusive: true,
+        }
+    );
+    let mut state = ParserState::new("0o755");
+    assert_eq!(
+        parse_single_pattern(&mut state).unwrap(),
+        Pattern::Literal(Literal::Integer(0o755, None))
+    );
+}
+
 // ============================================================
 // At binding tests
 // ============================================================
@@ -1256,3 +1256,110 @@ fn test_direct_parse_let_pattern_error_on_eof() {
         "Direct parse_let_pattern should fail on empty input"
     );
 }
+
+fn first_match_arm_pattern(code: &str) -> Pattern {
+    let expr = Parser::new(code).parse().expect("match should parse");
+    match expr.kind {
+        ExprKind::Match { arms, .. } => arms[0].pattern.clone(),
+        other => panic!("Expected match expression, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_match_tuple_pattern_with_rest() {
+    let pattern = first_match_arm_pattern("match t { (first, .., last) => first, _ => 0 }");
+    assert_eq!(
+        pattern,
+        Pattern::Tuple(vec![
+            Pattern::Identifier("first".to_string()),
+            Pattern::Rest,
+            Pattern::Identifier("last".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_match_list_pattern_three_dot_rest() {
+    let pattern = first_match_arm_pattern("match lst { [head, ...tail] => head, _ => 0 }");
+    assert_eq!(
+        pattern,
+        Pattern::List(vec![
+            Pattern::Identifier("head".to_string()),
+            Pattern::RestNamed("tail".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_match_tuple_variant_with_rest() {
+    let pattern = first_match_arm_pattern("match c { Color::Rgb(r, ..) => r, _ => 0 }");
+    assert_eq!(
+        pattern,
+        Pattern::TupleVariant {
+            path: vec!["Color".to_string(), "Rgb".to_string()],
+            patterns: vec![Pattern::Identifier("r".to_string()), Pattern::Rest],
+        }
+    );
+}
+
+#[test]
+fn test_let_tuple_pattern_with_rest() {
+    let expr = Parser::new("let (a, ..) = t")
+        .parse()
+        .expect("let with tuple rest should parse");
+    let ExprKind::LetPattern { pattern, .. } = expr.kind else {
+        panic!("Expected LetPattern");
+    };
+    assert_eq!(
+        pattern,
+        Pattern::Tuple(vec![Pattern::Identifier("a".to_string()), Pattern::Rest])
+    );
+}
+
+#[test]
+fn test_match_string_prefix_pattern() {
+    let pattern = first_match_arm_pattern(r#"match s { "v" ++ rest => rest, _ => "" }"#);
+    assert_eq!(
+        pattern,
+        Pattern::StringAffix {
+            prefix: "v".to_string(),
+            rest: Box::new(Pattern::Identifier("rest".to_string())),
+            suffix: String::new(),
+        }
+    );
+}
+
+#[test]
+fn test_match_string_prefix_and_suffix_pattern() {
+    let pattern = first_match_arm_pattern(r#"match s { "<" ++ tag ++ ">" => tag, _ => "" }"#);
+    assert_eq!(
+        pattern,
+        Pattern::StringAffix {
+            prefix: "<".to_string(),
+            rest: Box::new(Pattern::Identifier("tag".to_string())),
+            suffix: ">".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_match_string_pattern_needs_literal_affix() {
+    assert!(Parser::new(r"match s { a ++ b => a, _ => 0 }")
+        .parse()
+        .is_err());
+    assert!(Parser::new(r#"match s { "a" ++ "b" => 1, _ => 0 }"#)
+        .parse()
+        .is_err());
+}
+
+#[test]
+fn test_match_regex_pattern() {
+    let pattern = first_match_arm_pattern(r#"match s { re"(?P<n>\d+)" => n, _ => "" }"#);
+    assert_eq!(pattern, Pattern::Regex(r"(?P<n>\d+)".to_string()));
+}
+
+#[test]
+fn test_match_invalid_regex_pattern_fails() {
+    let result = Parser::new(r#"match s { re"(unclosed" => 1, _ => 0 }"#).parse();
+    assert!(result.is_err());
+}
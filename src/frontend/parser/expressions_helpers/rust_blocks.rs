@@ -0,0 +1,188 @@
+//! Inline Rust block parsing
+//!
+//! `rust` is a contextual keyword: it opens a block of verbatim Rust when it
+//! starts a statement and is followed by `{`, or anywhere when it is followed
+//! by declared inputs and an output type:
+//! - Items: `rust { fn fast_sum(v: &[i64]) -> i64 { v.iter().sum() } }`
+//! - Expression: `rust(v: &[i64]) -> i64 { v.iter().sum() }`
+//! - With an interpreter fallback: `rust(...) -> T { ... } else { ... }`
+//!
+//! # Examples
+//! ```ruchy
+//! let total = rust(values: &[i64]) -> i64 {
+//!     values.iter().copied().filter(|v| v % 2 == 0).sum()
+//! } else {
+//!     values.filter(|v| v % 2 == 0).sum()
+//! }
+//! ```
+
+use crate::frontend::ast::{Expr, ExprKind, Span, TypeKind};
+use crate::frontend::lexer::Token;
+use crate::frontend::parser::{bail, parse_expr_recursive, utils, ParserState, Result};
+
+/// Whether the `rust` identifier at the cursor opens a Rust block
+pub(in crate::frontend::parser) fn at_rust_block(state: &mut ParserState) -> bool {
+    match state.tokens.peek_nth(1) {
+        // `if rust { .. }` and `match rust { .. }` use a variable named `rust`
+        Some((Token::LeftBrace, _)) => {
+            let source = state.tokens.source();
+            state
+                .tokens
+                .peek()
+                .is_some_and(|(_, span)| at_statement_start(source, span.start))
+        }
+        Some((Token::LeftParen, _)) => {
+            let saved = state.tokens.position();
+            state.tokens.advance(); // consume 'rust'
+            let declares_output =
+                skip_parens(state) && matches!(state.tokens.peek(), Some((Token::Arrow, _)));
+            state.tokens.set_position(saved);
+            declares_output
+        }
+        _ => false,
+    }
+}
+
+/// Whether the token at `pos` starts the input or a line, or follows `;`, `{` or `}`
+fn at_statement_start(source: &str, pos: usize) -> bool {
+    let before = source
+        .get(..pos)
+        .unwrap_or_default()
+        .trim_end_matches([' ', '\t', '\r']);
+    before.is_empty() || before.ends_with(['\n', ';', '{', '}'])
+}
+
+/// Consume a balanced `( ... )`, returning false at end of input
+fn skip_parens(state: &mut ParserState) -> bool {
+    let mut depth = 0usize;
+    while let Some((token, _)) = state.tokens.advance() {
+        match token {
+            Token::LeftParen => depth += 1,
+            Token::RightParen if depth <= 1 => return true,
+            Token::RightParen => depth -= 1,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Parse `rust [(inputs) -> Type] { verbatim } [else { fallback }]`
+pub(in crate::frontend::parser) fn parse_rust_block(
+    state: &mut ParserState,
+    span: Span,
+) -> Result<Expr> {
+    state.tokens.advance(); // consume 'rust'
+    let (inputs, output) = if matches!(state.tokens.peek(), Some((Token::LeftParen, _))) {
+        let inputs = utils::parse_params(state)?;
+        for input in &inputs {
+            if matches!(&input.ty.kind, TypeKind::Named(name) if name == "Any") {
+                bail!(
+                    "Rust block input `{}` needs a type, e.g. rust({}: i64) -> i64 {{ ... }}",
+                    input.name(),
+                    input.name()
+                );
+            }
+        }
+        state.tokens.expect(&Token::Arrow)?;
+        (inputs, Some(utils::parse_type(state)?))
+    } else {
+        (Vec::new(), None)
+    };
+    if !matches!(state.tokens.peek(), Some((Token::LeftBrace, _))) {
+        bail!("Expected '{{' to open the Rust block");
+    }
+    let (code, block_span) = state.tokens.take_raw_block()?;
+    let fallback = if matches!(state.tokens.peek(), Some((Token::Else, _))) {
+        state.tokens.advance(); // consume 'else'
+        if !matches!(state.tokens.peek(), Some((Token::LeftBrace, _))) {
+            bail!("Rust block fallback requires a block after 'else'");
+        }
+        Some(Box::new(parse_expr_recursive(state)?))
+    } else {
+        None
+    };
+    let end = fallback.as_ref().map_or(block_span.end, |f| f.span.end);
+    Ok(Expr::new(
+        ExprKind::RustBlock {
+            inputs,
+            output,
+            code: code.to_string(),
+            fallback,
+        },
+        Span::new(span.start, end),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::frontend::ast::{Expr, ExprKind, TypeKind};
+    use crate::frontend::parser::Parser;
+
+    fn parse(code: &str) -> Expr {
+        Parser::new(code).parse().expect("should parse")
+    }
+
+    #[test]
+    fn test_rust_block_with_inputs_and_fallback() {
+        let expr = parse(
+            "rust(values: &[i64], scale: i64) -> i64 {\n    values.iter().map(|v| v * scale).sum::<i64>()\n} else {\n    0\n}",
+        );
+        let ExprKind::RustBlock {
+            inputs,
+            output,
+            code,
+            fallback,
+        } = &expr.kind
+        else {
+            panic!("expected rust block, got {:?}", expr.kind);
+        };
+        let names: Vec<_> = inputs.iter().map(|p| p.name()).collect();
+        assert_eq!(names, ["values", "scale"]);
+        assert!(matches!(
+            output.as_ref().map(|ty| &ty.kind),
+            Some(TypeKind::Named(name)) if name == "i64"
+        ));
+        assert_eq!(code.trim(), "values.iter().map(|v| v * scale).sum::<i64>()");
+        assert!(fallback.is_some());
+    }
+
+    #[test]
+    fn test_rust_item_block_is_verbatim() {
+        let expr = parse("rust {\n    fn first<'a>(s: &'a str) -> &'a str { &s[..1] }\n}");
+        let ExprKind::RustBlock {
+            inputs,
+            output: None,
+            code,
+            fallback: None,
+        } = &expr.kind
+        else {
+            panic!("expected rust block, got {:?}", expr.kind);
+        };
+        assert!(inputs.is_empty());
+        assert!(code.contains("fn first<'a>(s: &'a str)"), "{code}");
+    }
+
+    #[test]
+    fn test_rust_stays_an_identifier_elsewhere() {
+        let expr = parse("rust(1)");
+        assert!(matches!(expr.kind, ExprKind::Call { .. }));
+        let expr = parse("let rust = 1\nrust + 1");
+        assert!(!format!("{expr:?}").contains("RustBlock"));
+        for code in [
+            "let rust = true; if rust { 1 } else { 2 }",
+            "let rust = 1\nmatch rust { 1 => true, _ => false }",
+            "let rust = false\nwhile rust { }",
+        ] {
+            let expr = parse(code);
+            assert!(!format!("{expr:?}").contains("RustBlock"), "{code}");
+        }
+        let expr = parse("let x = 1; rust { fn f() {} }");
+        assert!(format!("{expr:?}").contains("RustBlock"));
+    }
+
+    #[test]
+    fn test_rust_block_errors() {
+        assert!(Parser::new("rust(x) -> i64 { x }").parse().is_err());
+        assert!(Parser::new("rust { fn f() {").parse().is_err());
+    }
+}
@@ -94,29 +94,29 @@ fn parse_struct_fields(state: &mut ParserState) -> Result<(Vec<StructField>, Vec
     let mut fields = Vec::new();
     let mut methods = Vec::new();
 
-    while !matches!(state.tokens.peek(), Some((Token::RightBrace, _))) {
-        // DEFECT-PARSER-007: Skip comments before member declaration
-        while matches!(
-            state.tokens.peek(),
-            Some((
-                Token::LineComment(_)
-                    | Token::BlockComment(_)
-                    | Token::DocComment(_)
-                    | Token::HashComment(_),
-                _
-            ))
-        ) {
-            state.tokens.advance();
+    loop {
+        // DEFECT-PARSER-007: Comments before a member lead it
+        let leading_comments = state.consume_leading_comments();
+        if matches!(state.tokens.peek(), Some((Token::RightBrace, _))) {
+            break;
         }
 
         // PARSER-147: Check if this is a method definition (with or without pub)
         if is_method_definition(state) {
             let method = parse_struct_method_with_visibility(state)?;
             methods.push(method);
+            // Methods carry no comments of their own
+            state.consume_list_separator();
         } else {
             // Parse field
             let (visibility, is_mut) = parse_struct_field_modifiers(state)?;
-            let (field_name, field_type, default_value) = parse_single_struct_field(state)?;
+            let (field_name, field_type, mut default_value) = parse_single_struct_field(state)?;
+            let (_, trailing_comment) = state.consume_list_separator();
+            let trailing_comment = trailing_comment.or_else(|| {
+                default_value
+                    .as_mut()
+                    .and_then(|value| value.trailing_comment.take())
+            });
 
             fields.push(StructField {
                 name: field_name,
@@ -125,40 +125,10 @@ fn parse_struct_fields(state: &mut ParserState) -> Result<(Vec<StructField>, Vec
                 is_mut,
                 default_value,
                 decorators: vec![],
+                leading_comments,
+                trailing_comment,
             });
         }
-
-        // DEFECT-PARSER-007: Skip any inline comments after member definition
-        while matches!(
-            state.tokens.peek(),
-            Some((
-                Token::LineComment(_)
-                    | Token::BlockComment(_)
-                    | Token::DocComment(_)
-                    | Token::HashComment(_),
-                _
-            ))
-        ) {
-            state.tokens.advance();
-        }
-
-        if matches!(state.tokens.peek(), Some((Token::Comma, _))) {
-            state.tokens.advance();
-
-            // Skip comments after comma (allows multiline definitions with comments)
-            while matches!(
-                state.tokens.peek(),
-                Some((
-                    Token::LineComment(_)
-                        | Token::BlockComment(_)
-                        | Token::DocComment(_)
-                        | Token::HashComment(_),
-                    _
-                ))
-            ) {
-                state.tokens.advance();
-            }
-        }
     }
 
     state.tokens.expect(&Token::RightBrace)?;
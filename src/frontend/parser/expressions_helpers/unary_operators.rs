@@ -39,143 +39,113 @@ pub(in crate::frontend::parser) fn parse_unary_prefix(
     token: Token,
     span: Span,
 ) -> Result<Expr> {
-    match token {
-        Token::Minus => parse_unary_negate(state, span),
-        Token::Plus => parse_unary_plus(state, span),
-        Token::Bang => parse_unary_not(state, span),
-        Token::Star => parse_unary_deref(state, span),
-        Token::Ampersand => parse_unary_reference(state, span),
-        Token::Power => parse_double_deref(state, span),
-        Token::Await => parse_await_expr(state, span),
-        Token::Tilde => parse_bitwise_not(state, span),
-        Token::Spawn => parse_spawn_expr(state, span),
-        _ => unreachable!(),
-    }
-}
-
-/// Parse unary negation: `-expr`
-fn parse_unary_negate(state: &mut ParserState, span: Span) -> Result<Expr> {
-    state.tokens.advance();
-    let expr = parse_expr_with_precedence_recursive(state, 13)?;
-    Ok(Expr::new(
-        ExprKind::Unary {
-            op: UnaryOp::Negate,
-            operand: Box::new(expr),
-        },
-        span,
-    ))
-}
-
-/// Parse unary plus: `+expr` (identity operation - returns operand unchanged)
-fn parse_unary_plus(state: &mut ParserState, _span: Span) -> Result<Expr> {
-    state.tokens.advance();
-    // Unary plus is identity operation - just parse and return the operand
-    parse_expr_with_precedence_recursive(state, 13)
-}
-
-/// Parse logical not: `!expr`
-fn parse_unary_not(state: &mut ParserState, span: Span) -> Result<Expr> {
-    state.tokens.advance();
-    let expr = parse_expr_with_precedence_recursive(state, 13)?;
-    Ok(Expr::new(
-        ExprKind::Unary {
-            op: UnaryOp::Not,
-            operand: Box::new(expr),
-        },
-        span,
-    ))
-}
-
-/// Parse dereference: `*expr`
-fn parse_unary_deref(state: &mut ParserState, span: Span) -> Result<Expr> {
-    state.tokens.advance();
-    let expr = parse_expr_with_precedence_recursive(state, 13)?;
-    Ok(Expr::new(
-        ExprKind::Unary {
-            op: UnaryOp::Deref,
-            operand: Box::new(expr),
-        },
-        span,
-    ))
-}
-
-/// Parse reference: `&expr`
-fn parse_unary_reference(state: &mut ParserState, span: Span) -> Result<Expr> {
-    state.tokens.advance(); // consume &
-
-    // PARSER-085: Issue #71 - Check for optional 'mut' keyword after '&'
-    let op = if matches!(state.tokens.peek(), Some((Token::Mut, _))) {
-        state.tokens.advance(); // consume mut
-        UnaryOp::MutableReference
-    } else {
-        UnaryOp::Reference
-    };
-
-    let expr = parse_expr_with_precedence_recursive(state, 13)?;
-    Ok(Expr::new(
-        ExprKind::Unary {
-            op,
-            operand: Box::new(expr),
-        },
-        span,
-    ))
+    // A run of prefix operators (`!!x`, `- -x`, `&*x`) is collected in a loop
+    // and applied once the operand is parsed, so long runs don't recurse.
+    // Each operator still counts as a level of nesting.
+    let mut operators = Vec::new();
+    let mut next = Some((token, span));
+    while let Some((token, span)) = next {
+        state.tokens.advance();
+        operators.push((parse_prefix_operator(state, &token), span));
+        next = match state.tokens.peek() {
+            Some((token, span)) if is_unary_prefix(token) => Some((token.clone(), *span)),
+            _ => None,
+        };
+    }
+    let operand = state.nested(operators.len(), |state| {
+        parse_expr_with_precedence_recursive(state, 13)
+    })?;
+    Ok(operators
+        .into_iter()
+        .rev()
+        .fold(operand, |operand, (operator, span)| {
+            operator.apply(operand, span)
+        }))
 }
 
-/// Parse double dereference: `**expr`
-fn parse_double_deref(state: &mut ParserState, span: Span) -> Result<Expr> {
-    state.tokens.advance();
-    let expr = parse_expr_with_precedence_recursive(state, 13)?;
-    let inner_deref = Expr::new(
-        ExprKind::Unary {
-            op: UnaryOp::Deref,
-            operand: Box::new(expr),
-        },
-        span,
-    );
-    Ok(Expr::new(
-        ExprKind::Unary {
-            op: UnaryOp::Deref,
-            operand: Box::new(inner_deref),
-        },
-        span,
-    ))
+/// Whether `token` starts a unary prefix expression
+fn is_unary_prefix(token: &Token) -> bool {
+    matches!(
+        token,
+        Token::Minus
+            | Token::Plus
+            | Token::Bang
+            | Token::Star
+            | Token::Ampersand
+            | Token::Power
+            | Token::Await
+            | Token::Tilde
+            | Token::Spawn
+    )
 }
 
-/// Parse await expression: `await expr`
-fn parse_await_expr(state: &mut ParserState, span: Span) -> Result<Expr> {
-    state.tokens.advance();
-    let expr = parse_expr_with_precedence_recursive(state, 13)?;
-    Ok(Expr::new(
-        ExprKind::Await {
-            expr: Box::new(expr),
-        },
-        span,
-    ))
+/// A prefix operator waiting for its operand
+#[derive(Debug, Clone, Copy)]
+enum PrefixOperator {
+    /// `-`, `!`, `*`, `&`, `&mut` or `~`
+    Unary(UnaryOp),
+    /// `+`, which returns its operand unchanged
+    Identity,
+    /// `**`, two dereferences
+    DoubleDeref,
+    /// `await`
+    Await,
+    /// `spawn`
+    Spawn,
 }
 
-/// Parse bitwise not: `~expr`
-fn parse_bitwise_not(state: &mut ParserState, span: Span) -> Result<Expr> {
-    state.tokens.advance();
-    let expr = parse_expr_with_precedence_recursive(state, 13)?;
-    Ok(Expr::new(
-        ExprKind::Unary {
-            op: UnaryOp::BitwiseNot,
-            operand: Box::new(expr),
-        },
-        span,
-    ))
+impl PrefixOperator {
+    /// Wrap `operand` in this operator
+    fn apply(self, operand: Expr, span: Span) -> Expr {
+        let unary = |op, operand| {
+            Expr::new(
+                ExprKind::Unary {
+                    op,
+                    operand: Box::new(operand),
+                },
+                span,
+            )
+        };
+        match self {
+            Self::Unary(op) => unary(op, operand),
+            Self::Identity => operand,
+            Self::DoubleDeref => unary(UnaryOp::Deref, unary(UnaryOp::Deref, operand)),
+            Self::Await => Expr::new(
+                ExprKind::Await {
+                    expr: Box::new(operand),
+                },
+                span,
+            ),
+            Self::Spawn => Expr::new(
+                ExprKind::Spawn {
+                    actor: Box::new(operand),
+                },
+                span,
+            ),
+        }
+    }
 }
 
-/// Parse spawn expression: `spawn expr`
-fn parse_spawn_expr(state: &mut ParserState, span: Span) -> Result<Expr> {
-    state.tokens.advance();
-    let expr = parse_expr_with_precedence_recursive(state, 13)?;
-    Ok(Expr::new(
-        ExprKind::Spawn {
-            actor: Box::new(expr),
-        },
-        span,
-    ))
+/// The operator of an already consumed prefix `token`
+///
+/// PARSER-085: Issue #71 - `&` may be followed by `mut`, consumed here.
+fn parse_prefix_operator(state: &mut ParserState, token: &Token) -> PrefixOperator {
+    match token {
+        Token::Minus => PrefixOperator::Unary(UnaryOp::Negate),
+        Token::Plus => PrefixOperator::Identity,
+        Token::Bang => PrefixOperator::Unary(UnaryOp::Not),
+        Token::Star => PrefixOperator::Unary(UnaryOp::Deref),
+        Token::Ampersand if matches!(state.tokens.peek(), Some((Token::Mut, _))) => {
+            state.tokens.advance(); // consume mut
+            PrefixOperator::Unary(UnaryOp::MutableReference)
+        }
+        Token::Ampersand => PrefixOperator::Unary(UnaryOp::Reference),
+        Token::Power => PrefixOperator::DoubleDeref,
+        Token::Await => PrefixOperator::Await,
+        Token::Tilde => PrefixOperator::Unary(UnaryOp::BitwiseNot),
+        Token::Spawn => PrefixOperator::Spawn,
+        _ => unreachable!(),
+    }
 }
 
 #[cfg(test)]
@@ -378,6 +378,8 @@ fn create_let_expression(
         | Pattern::RestNamed(_)
         | Pattern::AtBinding { .. }
         | Pattern::WithDefault { .. }
+        | Pattern::StringAffix { .. }
+        | Pattern::Regex(_)
         | Pattern::Ok(_)
         | Pattern::Err(_)
         | Pattern::Some(_)
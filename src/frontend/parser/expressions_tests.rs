@@ -928,7 +928,7 @@ fn test_parse_break_with_value() {
 
 #[test]
 fn test_parse_continue_with_label() {
-    let expr = parse("continue 'outer").unwrap();
+    let expr = parse("'outer: loop { continue 'outer }").unwrap();
     if let Some(exprs) = get_block_exprs(&expr) {
         if let ExprKind::Continue { label } = &exprs[0].kind {
             assert!(label.is_some());
@@ -111,7 +111,7 @@ pub fn parse_function_with_visibility(state: &mut ParserState, is_pub: bool) ->
     // PARSER-063: Skip comments before function body
     skip_comments(state);
 
-    let body = super::parse_expr_recursive(state)?;
+    let body = state.in_function_body(super::parse_expr_recursive)?;
 
     let mut expr = Expr::new(
         ExprKind::Function {
@@ -233,6 +233,8 @@ fn create_lambda_param(name: String, ty: Type) -> Param {
         span: Span { start: 0, end: 0 },
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }
 }
 
@@ -255,7 +257,7 @@ pub fn parse_empty_lambda(state: &mut ParserState) -> Result<Expr> {
     let start_span = state.tokens.advance().expect("checked by parser logic").1; // consume ||
                                                                                  // Lambda syntax: || expr (no => allowed)
                                                                                  // Parse the body
-    let body = super::parse_expr_recursive(state)?;
+    let body = state.in_function_body(super::parse_expr_recursive)?;
     Ok(Expr::new(
         ExprKind::Lambda {
             params: Vec::new(),
@@ -282,7 +284,7 @@ pub fn parse_lambda(state: &mut ParserState) -> Result<Expr> {
         parse_pipe_lambda(state)?
     };
     // Parse body
-    let body = super::parse_expr_recursive(state)?;
+    let body = state.in_function_body(super::parse_expr_recursive)?;
     Ok(Expr::new(
         ExprKind::Lambda {
             params,
@@ -348,6 +350,8 @@ fn create_simple_param(name: String) -> Param {
         span: Span { start: 0, end: 0 },
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }
 }
 /// # Errors
@@ -359,65 +363,91 @@ fn create_simple_param(name: String) -> Param {
 pub fn parse_call(state: &mut ParserState, func: Expr) -> Result<Expr> {
     state.tokens.advance(); // consume (
     let (args, named_args) = parse_arguments_list(state)?;
-    state.tokens.expect(&Token::RightParen)?;
-
-    build_call_expression(func, args, named_args)
+    let close = state.tokens.expect(&Token::RightParen)?;
+    let span = Span::new(func.span.start, close.end);
+    let mut call = build_call_expression(func, args, named_args)?;
+    call.span = span;
+    Ok(call)
 }
 
-/// Build appropriate call expression based on arguments (complexity: 5)
+/// Build appropriate call expression based on arguments (complexity: 2)
+///
+/// `Point(x: 1, y: 2)` with only named arguments and a type name stays a
+/// struct literal. Otherwise named arguments follow the positional ones as
+/// `name = value` assignments, which the interpreter and transpiler bind to
+/// the parameter of that name.
 fn build_call_expression(
     func: Expr,
-    args: Vec<Expr>,
+    mut args: Vec<Expr>,
     named_args: Vec<(String, Expr)>,
 ) -> Result<Expr> {
-    if named_args.is_empty() {
-        Ok(Expr {
-            kind: ExprKind::Call {
-                func: Box::new(func),
-                args,
-            },
-            span: Span { start: 0, end: 0 },
-            attributes: Vec::new(),
-            leading_comments: Vec::new(),
-            trailing_comment: None,
-            contracts: Vec::new(),
-        })
-    } else {
-        build_struct_literal_call(func, named_args)
+    if let Some(name) = struct_literal_name(&func, &args, &named_args) {
+        return build_struct_literal_call(name, named_args);
     }
+    args.extend(
+        named_args
+            .into_iter()
+            .map(|(name, value)| named_argument(name, value)),
+    );
+    Ok(Expr {
+        kind: ExprKind::Call {
+            func: Box::new(func),
+            args,
+        },
+        span: Span { start: 0, end: 0 },
+        attributes: Vec::new(),
+        leading_comments: Vec::new(),
+        trailing_comment: None,
+        contracts: Vec::new(),
+    })
 }
 
-/// Convert named args to struct literal (complexity: 3)
-fn build_struct_literal_call(func: Expr, named_args: Vec<(String, Expr)>) -> Result<Expr> {
-    if let ExprKind::Identifier(name) = &func.kind {
-        let fields = named_args.into_iter().collect();
-        Ok(Expr {
-            kind: ExprKind::StructLiteral {
-                name: name.clone(),
-                fields,
-                base: None,
-            },
-            span: Span { start: 0, end: 0 },
-            attributes: Vec::new(),
-            leading_comments: Vec::new(),
-            trailing_comment: None,
-            contracts: Vec::new(),
-        })
-    } else {
-        // For now, only support named args with simple identifiers
-        Ok(Expr {
-            kind: ExprKind::Call {
-                func: Box::new(func),
-                args: Vec::new(),
-            },
-            span: Span { start: 0, end: 0 },
-            attributes: Vec::new(),
-            leading_comments: Vec::new(),
-            trailing_comment: None,
-            contracts: Vec::new(),
-        })
+/// Type name for a call with only named arguments, like `Point(x: 1)` (complexity: 2)
+fn struct_literal_name(
+    func: &Expr,
+    args: &[Expr],
+    named_args: &[(String, Expr)],
+) -> Option<String> {
+    match &func.kind {
+        ExprKind::Identifier(name)
+            if args.is_empty()
+                && !named_args.is_empty()
+                && name.starts_with(char::is_uppercase) =>
+        {
+            Some(name.clone())
+        }
+        _ => None,
     }
 }
+
+/// Represent `name: value` as the `name = value` argument form (complexity: 1)
+fn named_argument(name: String, value: Expr) -> Expr {
+    let span = value.span;
+    let target = Expr::new(ExprKind::Identifier(name), span);
+    Expr::new(
+        ExprKind::Assign {
+            target: Box::new(target),
+            value: Box::new(value),
+        },
+        span,
+    )
+}
+
+/// Convert named args to struct literal (complexity: 1)
+fn build_struct_literal_call(name: String, named_args: Vec<(String, Expr)>) -> Result<Expr> {
+    Ok(Expr {
+        kind: ExprKind::StructLiteral {
+            name,
+            fields: named_args,
+            base: None,
+        },
+        span: Span { start: 0, end: 0 },
+        attributes: Vec::new(),
+        leading_comments: Vec::new(),
+        trailing_comment: None,
+        contracts: Vec::new(),
+    })
+}
 /// # Errors
 ///
 /// Returns an error if the operation fails
@@ -445,20 +475,23 @@ pub fn parse_method_call(state: &mut ParserState, receiver: Expr) -> Result<Expr
     state.skip_comments();
     // Parse method name or tuple index
     match state.tokens.peek() {
-        Some((Token::Identifier(name), _)) => {
+        Some((Token::Identifier(name), span)) => {
             let method = name.clone();
+            let start = span.start;
             state.tokens.advance();
-            parse_method_or_field_access(state, receiver, method)
+            parse_method_or_field_access(state, receiver, method, start)
         }
-        Some((Token::Send, _)) => {
+        Some((Token::Send, span)) => {
             // Handle 'send' as a method name (for actors)
+            let start = span.start;
             state.tokens.advance();
-            parse_method_or_field_access(state, receiver, "send".to_string())
+            parse_method_or_field_access(state, receiver, "send".to_string(), start)
         }
-        Some((Token::Ask, _)) => {
+        Some((Token::Ask, span)) => {
             // Handle 'ask' as a method name (for actors)
+            let start = span.start;
             state.tokens.advance();
-            parse_method_or_field_access(state, receiver, "ask".to_string())
+            parse_method_or_field_access(state, receiver, "ask".to_string(), start)
         }
         Some((Token::Integer(index), _)) => {
             // Handle tuple access like t.0, t.1, etc.
@@ -526,6 +559,7 @@ fn parse_method_or_field_access(
     state: &mut ParserState,
     receiver: Expr,
     method: String,
+    start: usize,
 ) -> Result<Expr> {
     // PARSER-069 FIX: Check for turbofish generics (::) before checking for method call
     // Example: "42".parse::<i32>() has :: after parse, not (
@@ -548,27 +582,34 @@ fn parse_method_or_field_access(
 
     // Check if it's a method call (with parentheses) or field access
     if matches!(state.tokens.peek(), Some((Token::LeftParen, _))) {
-        parse_method_call_access(state, receiver, method_name)
+        parse_method_call_access(state, receiver, method_name, start)
     } else {
         // Field access
         Ok(create_field_access(receiver, method_name))
     }
 }
 /// Parse method call with arguments (complexity: 6)
+///
+/// The call's span runs from the method name at `start` to the closing
+/// parenthesis, so errors point at `name(args)` even when the receiver
+/// has no span of its own.
 fn parse_method_call_access(
     state: &mut ParserState,
     receiver: Expr,
     method: String,
+    start: usize,
 ) -> Result<Expr> {
     state.tokens.advance(); // consume (
     let args = parse_method_arguments(state)?;
-    state.tokens.expect(&Token::RightParen)?;
+    let close = state.tokens.expect(&Token::RightParen)?;
     // Check if this is a DataFrame operation
-    if is_dataframe_method(&method) {
-        handle_dataframe_method(receiver, method, args)
+    let mut call = if is_dataframe_method(&method) {
+        handle_dataframe_method(receiver, method, args)?
     } else {
-        Ok(create_method_call(receiver, method, args))
-    }
+        create_method_call(receiver, method, args)
+    };
+    call.span = Span::new(start, close.end);
+    Ok(call)
 }
 /// Parse method arguments (complexity: 4)
 fn parse_method_arguments(state: &mut ParserState) -> Result<Vec<Expr>> {
@@ -600,15 +641,28 @@ fn convert_named_args_to_object(state: &mut ParserState, named_args: Vec<(String
     Expr::new(ExprKind::ObjectLiteral { fields }, span)
 }
 
-/// Parse argument list with both positional and named arguments (complexity: 3, cognitive: 5)
+/// Parse argument list with both positional and named arguments (complexity: 5, cognitive: 7)
+///
+/// A comment after an argument's comma trails that argument, and comments
+/// before the closing `)` are allowed.
 fn parse_arguments_list(state: &mut ParserState) -> Result<(Vec<Expr>, Vec<(String, Expr)>)> {
     let mut args = Vec::new();
     let mut named_args = Vec::new();
 
     while !is_at_argument_list_end(state) {
+        let positional_count = args.len();
         parse_single_argument(state, &mut args, &mut named_args)?;
 
-        if !handle_argument_separator(state) {
+        let (has_comma, trailing_comment) = state.consume_list_separator();
+        let last = if args.len() > positional_count {
+            args.last_mut()
+        } else {
+            named_args.last_mut().map(|(_, value)| value)
+        };
+        if let (Some(last), Some(comment)) = (last, trailing_comment) {
+            last.trailing_comment.get_or_insert(comment);
+        }
+        if !has_comma {
             break;
         }
     }
@@ -616,12 +670,18 @@ fn parse_arguments_list(state: &mut ParserState) -> Result<(Vec<Expr>, Vec<(Stri
     Ok((args, named_args))
 }
 
-/// Check if at end of argument list (complexity: 1, cognitive: 1)
+/// Check if at end of argument list, skipping comments before `)` (complexity: 2, cognitive: 2)
 fn is_at_argument_list_end(state: &mut ParserState) -> bool {
-    matches!(state.tokens.peek(), Some((Token::RightParen, _)))
+    let saved_position = state.tokens.position();
+    skip_comments(state);
+    if matches!(state.tokens.peek(), Some((Token::RightParen, _))) {
+        return true;
+    }
+    state.tokens.set_position(saved_position);
+    false
 }
 
-/// Parse a single argument (named or positional) (complexity: 2, cognitive: 3)
+/// Parse a single argument (named or positional) (complexity: 3, cognitive: 4)
 fn parse_single_argument(
     state: &mut ParserState,
     args: &mut Vec<Expr>,
@@ -629,8 +689,11 @@ fn parse_single_argument(
 ) -> Result<()> {
     if let Some((name, value)) = try_parse_named_argument(state)? {
         named_args.push((name, value));
+    } else if named_args.is_empty() {
+        // `...list` spreads a list into the positional arguments
+        args.push(super::expressions::expressions_helpers::arrays::parse_array_element(state)?);
     } else {
-        args.push(super::parse_expr_recursive(state)?);
+        bail!("Positional argument cannot follow named arguments");
     }
     Ok(())
 }
@@ -654,15 +717,6 @@ fn try_parse_named_argument(state: &mut ParserState) -> Result<Option<(String, E
     Ok(None)
 }
 
-/// Handle comma separator between arguments (complexity: 2)
-fn handle_argument_separator(state: &mut ParserState) -> bool {
-    if matches!(state.tokens.peek(), Some((Token::Comma, _))) {
-        state.tokens.advance();
-        true
-    } else {
-        false
-    }
-}
 /// Check if method is DataFrame-specific (complexity: 1)
 ///
 /// Note: "select" is NOT in this list because both `DataFrame`s and `HtmlDocument`s
@@ -689,7 +743,9 @@ fn is_dataframe_method(method: &str) -> bool {
 /// Note: "select" is handled at runtime via `MethodCall` dispatch
 fn handle_dataframe_method(receiver: Expr, method: String, args: Vec<Expr>) -> Result<Expr> {
     let operation = match method.as_str() {
-        "groupby" | "group_by" => DataFrameOp::GroupBy(extract_groupby_columns(args)),
+        // `group_by` stays a method call: the engine groups by the key
+        // columns its arguments evaluate to
+        "groupby" => DataFrameOp::GroupBy(extract_groupby_columns(args)),
         _ => return Ok(create_method_call(receiver, method, args)),
     };
     Ok(Expr {
@@ -1748,4 +1804,73 @@ mod tests {
         assert_eq!(expr.attributes[0].name, "gpu");
         assert_eq!(expr.attributes[0].args.len(), 1);
     }
+
+    #[test]
+    fn test_parse_call_with_named_args_keeps_positional_args() {
+        use crate::frontend::ast::ExprKind;
+        let expr = Parser::new("greet(\"Ana\", greeting: \"Hi\")")
+            .parse()
+            .expect("named call should parse");
+        let ExprKind::Call { args, .. } = &expr.kind else {
+            panic!("expected a call, got {:?}", expr.kind);
+        };
+        assert_eq!(args.len(), 2);
+        assert!(matches!(&args[0].kind, ExprKind::Literal(_)));
+        let ExprKind::Assign { target, .. } = &args[1].kind else {
+            panic!("expected a named argument, got {:?}", args[1].kind);
+        };
+        assert!(matches!(&target.kind, ExprKind::Identifier(name) if name == "greeting"));
+    }
+
+    #[test]
+    fn test_parse_lowercase_call_with_only_named_args() {
+        use crate::frontend::ast::ExprKind;
+        let expr = Parser::new("greet(name: \"Ana\")")
+            .parse()
+            .expect("named call should parse");
+        assert!(
+            matches!(&expr.kind, ExprKind::Call { args, .. } if args.len() == 1),
+            "got {:?}",
+            expr.kind
+        );
+    }
+
+    #[test]
+    fn test_parse_type_call_with_named_args_is_struct_literal() {
+        use crate::frontend::ast::ExprKind;
+        let expr = Parser::new("Point(x: 1, y: 2)")
+            .parse()
+            .expect("struct call should parse");
+        assert!(matches!(expr.kind, ExprKind::StructLiteral { .. }));
+    }
+
+    #[test]
+    fn test_parse_positional_after_named_arg_fails() {
+        assert!(Parser::new("greet(name: \"Ana\", \"Hi\")").parse().is_err());
+    }
+
+    #[test]
+    fn test_parse_call_with_spread_arg() {
+        use crate::frontend::ast::ExprKind;
+        let expr = Parser::new("add(1, ...rest)")
+            .parse()
+            .expect("spread call should parse");
+        let ExprKind::Call { args, .. } = &expr.kind else {
+            panic!("expected a call, got {:?}", expr.kind);
+        };
+        assert_eq!(args.len(), 2);
+        assert!(matches!(&args[1].kind, ExprKind::Spread { .. }));
+    }
+
+    #[test]
+    fn test_parse_function_with_rest_param() {
+        use crate::frontend::ast::ExprKind;
+        let expr = Parser::new("fun log(prefix, ...args) { args }")
+            .parse()
+            .expect("rest param should parse");
+        let ExprKind::Function { params, .. } = &expr.kind else {
+            panic!("expected a function, got {:?}", expr.kind);
+        };
+        assert!(params[1].is_rest());
+    }
 }
@@ -43,6 +43,13 @@
 //!
 //! The parser attempts to recover from errors to provide multiple diagnostics
 //! in a single pass. Errors are collected and can be retrieved after parsing.
+//!
+//! Expressions nested deeper than [`DEFAULT_MAX_NESTING_DEPTH`] levels (or
+//! the limit given to [`Parser::with_max_depth`]) fail with an "expression
+//! too deeply nested" error rather than overflowing the stack. Runs of
+//! prefix operators and `else if` chains are parsed in loops, but each
+//! operator and `else if` counts as a level: they nest in the tree, and the
+//! passes after parsing walk them recursively.
 mod actors;
 mod collections;
 mod core;
@@ -80,6 +87,13 @@ use anyhow::{bail, Result};
 pub use core::Parser;
 use std::collections::VecDeque;
 
+/// Levels of expression nesting allowed before parsing fails
+///
+/// Each parenthesis, block, operand or argument is one level. The default is
+/// conservative: unoptimized builds spend a lot of stack per level, and
+/// threads other than `main` only get 2 MiB.
+pub const DEFAULT_MAX_NESTING_DEPTH: usize = 64;
+
 /// Parse use statement with visibility modifier
 pub(crate) fn parse_use_statement_with_visibility(
     state: &mut ParserState,
@@ -126,6 +140,19 @@ pub(crate) struct ParserState<'a> {
     /// When true, `in` should not be treated as a binary operator (containment)
     /// so that `let x = value in body` parses correctly
     pub in_let_value_context: bool,
+    /// Current expression nesting depth
+    pub depth: usize,
+    /// Deepest nesting allowed, [`DEFAULT_MAX_NESTING_DEPTH`] by default
+    pub max_depth: usize,
+    /// Set once `max_depth` was exceeded; later expressions fail at once so
+    /// that backtracking does not descend into the same nesting again
+    pub nesting_exceeded: bool,
+    /// Labels of the loops enclosing the expression being parsed, innermost
+    /// last; `break`/`continue` may only name one of these
+    pub loop_labels: Vec<String>,
+    /// Set when a `break`/`continue` named an undefined label, kept for the
+    /// same reason as `nesting_exceeded`
+    pub label_error: Option<String>,
 }
 impl<'a> ParserState<'a> {
     #[must_use]
@@ -138,6 +165,11 @@ impl<'a> ParserState<'a> {
             expr_cache: VecDeque::with_capacity(8),
             in_guard_context: false, // PARSER-071: Initialize guard context flag
             in_let_value_context: false, // Initialize let-value context flag
+            depth: 0,
+            max_depth: DEFAULT_MAX_NESTING_DEPTH,
+            nesting_exceeded: false,
+            loop_labels: Vec::new(),
+            label_error: None,
         }
     }
     /// Get all errors encountered during parsing
@@ -153,6 +185,72 @@ impl<'a> ParserState<'a> {
         self.interner.stats()
     }
 
+    /// Run `parse` `levels` levels deeper, failing first if that exceeds `max_depth`
+    pub fn nested<T>(
+        &mut self,
+        levels: usize,
+        parse: impl FnOnce(&mut Self) -> Result<T>,
+    ) -> Result<T> {
+        self.descend(levels)?;
+        let result = parse(self);
+        self.depth -= levels;
+        result
+    }
+
+    /// Go `levels` levels deeper, failing if that exceeds `max_depth`
+    ///
+    /// The caller restores `depth` when it comes back up.
+    pub fn descend(&mut self, levels: usize) -> Result<()> {
+        if self.nesting_exceeded || self.depth + levels > self.max_depth {
+            self.nesting_exceeded = true;
+            bail!("{}", self.nesting_message());
+        }
+        self.depth += levels;
+        Ok(())
+    }
+
+    /// `error`, or the nesting or undefined-label diagnostic if one was hit
+    ///
+    /// Backtracking can turn these errors into an unrelated one (such as a
+    /// missing closing brace) before it reaches the entry point.
+    pub fn nesting_error_or(&self, error: anyhow::Error) -> anyhow::Error {
+        if self.nesting_exceeded {
+            anyhow::anyhow!("{}", self.nesting_message())
+        } else if let Some(message) = &self.label_error {
+            anyhow::anyhow!("{message}")
+        } else {
+            error
+        }
+    }
+
+    /// Run `parse` for the body of a loop labeled `label`
+    pub fn in_labeled_loop<T>(
+        &mut self,
+        label: String,
+        parse: impl FnOnce(&mut Self) -> Result<T>,
+    ) -> Result<T> {
+        self.loop_labels.push(label);
+        let result = parse(self);
+        self.loop_labels.pop();
+        result
+    }
+
+    /// Run `parse` for a function or lambda body, where the labels of the
+    /// loops around it are out of reach
+    pub fn in_function_body<T>(&mut self, parse: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        let outer = std::mem::take(&mut self.loop_labels);
+        let result = parse(self);
+        self.loop_labels = outer;
+        result
+    }
+
+    fn nesting_message(&self) -> String {
+        format!(
+            "expression too deeply nested: nesting depth exceeds the limit of {}",
+            self.max_depth
+        )
+    }
+
     /// Consume all leading comments before an expression (complexity: 2)
     pub fn consume_leading_comments(&mut self) -> Vec<Comment> {
         let mut comments = Vec::new();
@@ -193,6 +291,40 @@ impl<'a> ParserState<'a> {
         None
     }
 
+    /// Consume a comment that ends a line of code (complexity: 3)
+    ///
+    /// Used by comma-separated lists whose items don't track an end span: the
+    /// comment trails the previous item when code precedes it on its line,
+    /// otherwise it is left to lead the next item.
+    pub fn consume_line_end_comment(&mut self) -> Option<Comment> {
+        let (token, span) = self.tokens.peek()?;
+        let span = *span;
+        let comment = token_to_comment(token, span)?;
+        let before = self.tokens.source().get(..span.start)?;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        if before[line_start..].trim().is_empty() {
+            return None;
+        }
+        self.tokens.advance();
+        Some(comment)
+    }
+
+    /// Consume an optional comma after a list item with its trailing comment (complexity: 2)
+    ///
+    /// Returns whether a comma was consumed, plus the comment ending the
+    /// item's line, which may sit on either side of the comma.
+    pub fn consume_list_separator(&mut self) -> (bool, Option<Comment>) {
+        let before_comma = self.consume_line_end_comment();
+        let has_comma = matches!(self.tokens.peek(), Some((Token::Comma, _)));
+        if has_comma {
+            self.tokens.advance();
+        }
+        (
+            has_comma,
+            before_comma.or_else(|| self.consume_line_end_comment()),
+        )
+    }
+
     /// Skip non-doc comment tokens transparently
     /// This allows method chains and expressions to work with intervening comments
     /// PARSER-053: Fix for multi-line comments breaking method chains
@@ -211,6 +343,13 @@ impl<'a> ParserState<'a> {
     }
 }
 
+/// Whether only whitespace separates `pos` from the start of its line
+fn starts_line(source: &str, pos: usize) -> bool {
+    source
+        .get(..pos)
+        .is_some_and(|before| before.trim_end_matches([' ', '\t', '\r']).ends_with('\n'))
+}
+
 /// Check if two byte positions are on the same line (no newline between them)
 ///
 /// # Safety
@@ -276,11 +415,15 @@ fn token_to_comment(token: &Token, span: Span) -> Option<Comment> {
 pub(crate) fn parse_expr_recursive(state: &mut ParserState) -> Result<Expr> {
     parse_expr_with_precedence_recursive(state, 0)
 }
-/// Parse expression with precedence handling (complexity: 5, cognitive: 5)
+/// Parse expression with precedence handling, one level of nesting deeper
 pub(crate) fn parse_expr_with_precedence_recursive(
     state: &mut ParserState,
     min_prec: i32,
 ) -> Result<Expr> {
+    state.nested(1, |state| parse_expr_at_precedence(state, min_prec))
+}
+/// Parse expression with precedence handling (complexity: 5, cognitive: 5)
+fn parse_expr_at_precedence(state: &mut ParserState, min_prec: i32) -> Result<Expr> {
     // Consume any leading comments before the expression
     let leading_comments = state.consume_leading_comments();
 
@@ -389,10 +532,17 @@ pub(crate) fn try_handle_infix_operators(
 }
 /// Handle all postfix operators in a loop
 pub(crate) fn handle_postfix_operators(state: &mut ParserState, mut left: Expr) -> Result<Expr> {
-    while let Some(new_left) = try_handle_single_postfix(state, left.clone())? {
-        left = new_left;
+    loop {
+        let saved_position = state.tokens.position();
+        match try_handle_single_postfix(state, left.clone())? {
+            Some(new_left) => left = new_left,
+            None => {
+                // Comments skipped looking for an operator belong to what follows
+                state.tokens.set_position(saved_position);
+                return Ok(left);
+            }
+        }
     }
-    Ok(left)
 }
 /// Try to handle a single postfix operator
 /// Returns Some(expr) if handled, None if no postfix operator found
@@ -438,15 +588,25 @@ fn try_handle_single_postfix(state: &mut ParserState, left: Expr) -> Result<Opti
             // This prevents `let y = 2 [x, y]`, `let p = Point{...} [x]`, and `let result = foo() [1, 2]` from being parsed as indexing
             // PARSER-086: Extended to fix block-level let with function call followed by array literal
             // PARSER-XXX: Extended to include Await and Try expressions
-            if matches!(
-                left.kind,
-                ExprKind::Literal(_)
-                    | ExprKind::StructLiteral { .. }
-                    | ExprKind::Let { .. }
-                    | ExprKind::Call { .. }
-                    | ExprKind::Await { .. }
-                    | ExprKind::Try { .. }
-            ) {
+            // A `[` that starts a line starts an array literal, as `(` starts a
+            // tuple rather than a call; one written right after a call, as in
+            // `args()[1]`, indexes its result
+            let source = state.tokens.source();
+            let (starts_line, attached) =
+                state.tokens.peek().map_or((false, false), |(_, span)| {
+                    let attached = source
+                        .get(..span.start)
+                        .is_some_and(|before| !before.ends_with(char::is_whitespace));
+                    (starts_line(source, span.start), attached)
+                });
+            let separate = match left.kind {
+                ExprKind::Literal(_) | ExprKind::StructLiteral { .. } | ExprKind::Let { .. } => {
+                    true
+                }
+                ExprKind::Call { .. } | ExprKind::Await { .. } | ExprKind::Try { .. } => !attached,
+                _ => false,
+            };
+            if starts_line || separate {
                 Ok(None) // Not array indexing, `[...]` is a separate expression
             } else {
                 Ok(Some(handle_array_indexing(state, left)?))
@@ -459,6 +619,8 @@ fn try_handle_single_postfix(state: &mut ParserState, left: Expr) -> Result<Opti
             // Check if this is ternary or try operator
             if is_ternary_operator(state) {
                 Ok(None) // Let ternary handler in infix operators handle it
+            } else if is_optional_index(state) {
+                handle_optional_index(state, left).map(Some)
             } else {
                 handle_try_operator(state, left).map(Some)
             }
@@ -668,6 +830,29 @@ fn handle_try_operator(state: &mut ParserState, left: Expr) -> Result<Expr> {
         Span { start: 0, end: 0 },
     ))
 }
+/// Check if `?` is immediately followed by `[` (optional index `arr?[i]`)
+fn is_optional_index(state: &mut ParserState) -> bool {
+    let Some((Token::Question, question)) = state.tokens.peek_nth(0) else {
+        return false;
+    };
+    matches!(
+        state.tokens.peek_nth(1),
+        Some((Token::LeftBracket, bracket)) if bracket.start == question.end
+    )
+}
+
+/// Handle optional index access `arr?[index]`
+fn handle_optional_index(state: &mut ParserState, left: Expr) -> Result<Expr> {
+    state.tokens.advance(); // consume ?
+    state.tokens.expect(&Token::LeftBracket)?;
+    let index = parse_expr_recursive(state)?;
+    state.tokens.expect(&Token::RightBracket)?;
+    Ok(create_expr(ExprKind::OptionalIndexAccess {
+        object: Box::new(left),
+        index: Box::new(index),
+    }))
+}
+
 /// Handle array indexing and slicing syntax `[expr]` or `[start:end]`
 fn handle_array_indexing(state: &mut ParserState, left: Expr) -> Result<Expr> {
     state.tokens.advance(); // consume [
@@ -848,6 +1033,21 @@ fn try_binary_operators(
         return Ok(None);
     }
 
+    // A `||` or `|` that starts a line ends a let statement: it opens the
+    // closure on the next line (`let mut count = 0\n|| { count += 1 }`)
+    if matches!(token, Token::OrOr | Token::Pipe)
+        && (state.in_let_value_context || matches!(left.kind, ExprKind::Let { .. }))
+    {
+        let source = state.tokens.source();
+        if state
+            .tokens
+            .peek()
+            .is_some_and(|(_, span)| starts_line(source, span.start))
+        {
+            return Ok(None);
+        }
+    }
+
     if let Some(bin_op) = expressions::token_to_binary_op(token) {
         let prec = expressions::get_precedence(bin_op);
         if prec < min_prec {
@@ -1149,6 +1349,10 @@ fn get_compound_assignment_op(token: &Token) -> BinaryOp {
         Token::PipeEqual => BinaryOp::BitwiseOr,
         Token::CaretEqual => BinaryOp::BitwiseXor,
         Token::LeftShiftEqual => BinaryOp::LeftShift,
+        Token::RightShiftEqual => BinaryOp::RightShift,
+        Token::AndAndEqual => BinaryOp::And,
+        Token::OrOrEqual => BinaryOp::Or,
+        Token::NullCoalesceEqual => BinaryOp::NullCoalesce,
         _ => unreachable!("Already checked is_assignment_op"),
     }
 }
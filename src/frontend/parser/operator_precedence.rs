@@ -45,10 +45,15 @@ pub fn get_operator_info(token: &Token) -> Option<(Precedence, Associativity)> {
         | Token::StarEqual
         | Token::SlashEqual
         | Token::PercentEqual
+        | Token::PowerEqual
         | Token::AmpersandEqual
         | Token::PipeEqual
         | Token::CaretEqual
         | Token::LeftShiftEqual
+        | Token::RightShiftEqual
+        | Token::AndAndEqual
+        | Token::OrOrEqual
+        | Token::NullCoalesceEqual
         | Token::LeftArrow
         | Token::ActorQuery => Option::Some((Precedence::ASSIGNMENT, Right)),
         // Message send operator (left-associative: actor ! Message)
@@ -695,3 +695,87 @@ fn test_macro_call_returns_some() {
         );
     }
 }
+
+#[test]
+fn test_deep_nesting_is_an_error_not_an_overflow() {
+    let nested = format!("{}1{}", "(".repeat(10_000), ")".repeat(10_000));
+    let error = Parser::new(&nested).parse().unwrap_err().to_string();
+    assert!(error.contains("too deeply nested"), "{error}");
+    assert!(error.contains("nesting depth"), "{error}");
+
+    // Backtracking through blocks must not hide the diagnostic
+    let blocks = format!("{}1{}", "{".repeat(200), "}".repeat(200));
+    let error = Parser::new(&blocks).parse().unwrap_err().to_string();
+    assert!(error.contains("too deeply nested"), "{error}");
+}
+
+#[test]
+fn test_nesting_limit_is_configurable() {
+    let nested = format!("{}1{}", "(".repeat(20), ")".repeat(20));
+    assert!(Parser::new(&nested).parse().is_ok());
+    let error = Parser::new(&nested)
+        .with_max_depth(10)
+        .parse()
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("limit of 10"), "{error}");
+}
+
+#[test]
+fn test_prefix_operator_runs_count_as_nesting() {
+    let mut state = ParserState::new("!!-x");
+    let expr = parse_expr_recursive(&mut state).unwrap();
+    assert_eq!(state.depth, 0);
+    let ExprKind::Unary { op, operand } = expr.kind else {
+        panic!("expected unary expression");
+    };
+    assert_eq!(op, UnaryOp::Not);
+    assert!(matches!(
+        operand.kind,
+        ExprKind::Unary {
+            op: UnaryOp::Not,
+            ..
+        }
+    ));
+
+    let negations = format!("{}x", "-".repeat(DEFAULT_MAX_NESTING_DEPTH * 2));
+    let error = Parser::new(&negations).parse().unwrap_err().to_string();
+    assert!(error.contains("too deeply nested"), "{error}");
+}
+
+fn else_if_chain(links: usize) -> String {
+    let mut source = String::from("if x == 0 { 0 }");
+    for i in 1..links {
+        source.push_str(&format!(" else if x == {i} {{ {i} }}"));
+    }
+    source.push_str(" else { -1 }");
+    source
+}
+
+#[test]
+fn test_else_if_chains_parse_within_the_limit() {
+    let links = DEFAULT_MAX_NESTING_DEPTH / 2;
+    let expr = Parser::new(&else_if_chain(links)).parse().unwrap();
+    let mut count = 0;
+    let mut current = &expr;
+    while let ExprKind::If { else_branch, .. } = &current.kind {
+        count += 1;
+        current = else_branch.as_deref().expect("chain ends in an else");
+    }
+    assert_eq!(count, links);
+    assert!(matches!(current.kind, ExprKind::Block(_)));
+}
+
+#[test]
+fn test_long_else_if_chains_are_a_nesting_error() {
+    let error = Parser::new(&else_if_chain(3000))
+        .parse()
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("too deeply nested"), "{error}");
+
+    let source = else_if_chain(10);
+    let mut state = ParserState::new(&source);
+    parse_expr_recursive(&mut state).unwrap();
+    assert_eq!(state.depth, 0);
+}
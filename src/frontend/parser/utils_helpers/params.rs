@@ -14,15 +14,31 @@ pub fn parse_params(state: &mut ParserState) -> Result<Vec<Param>> {
 }
 
 /// Parse list of parameters (extracted to reduce nesting)
+///
+/// Comments on their own lines lead the next parameter; a comment after a
+/// parameter (and its comma) on the same line trails it. A trailing comma
+/// before `)` is accepted.
 fn parse_param_list(state: &mut ParserState) -> Result<Vec<Param>> {
-    let mut params = Vec::new();
+    let mut params: Vec<Param> = Vec::new();
+    let mut more = true;
 
-    while !matches!(state.tokens.peek(), Some((Token::RightParen, _))) {
-        params.push(parse_single_param(state)?);
-
-        if !should_continue_param_list(state)? {
+    loop {
+        let leading_comments = state.consume_leading_comments();
+        if !more || matches!(state.tokens.peek(), Some((Token::RightParen, _))) {
             break;
         }
+        if let Some(rest) = params.last().filter(|param| param.is_rest()) {
+            bail!(
+                "Rest parameter '...{}' must be the last parameter",
+                rest.name()
+            );
+        }
+        let mut param = parse_single_param(state)?;
+        let before_comma = state.consume_line_end_comment();
+        more = should_continue_param_list(state)?;
+        param.leading_comments = leading_comments;
+        param.trailing_comment = before_comma.or_else(|| state.consume_line_end_comment());
+        params.push(param);
     }
 
     Ok(params)
@@ -55,12 +71,17 @@ fn parse_single_param(state: &mut ParserState) -> Result<Param> {
     }
 
     let default_value = parse_optional_default_value(state)?;
+    if let (Pattern::RestNamed(name), Some(_)) = (&pattern, &default_value) {
+        bail!("Rest parameter '...{name}' cannot have a default value");
+    }
     Ok(Param {
         pattern,
         ty,
         span: Span { start: 0, end: 0 },
         is_mutable,
         default_value,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     })
 }
 
@@ -74,7 +95,7 @@ fn check_and_consume_mut(state: &mut ParserState) -> bool {
     }
 }
 
-/// Parse parameter pattern (complexity: 8 - increased to support destructuring)
+/// Parse parameter pattern (complexity: 9 - increased to support destructuring and rest)
 /// Returns (pattern, `reference_info`) where `reference_info` is (`is_reference`, `is_mut`)
 fn parse_param_pattern(state: &mut ParserState) -> Result<(Pattern, (bool, bool))> {
     match state.tokens.peek() {
@@ -115,6 +136,18 @@ fn parse_param_pattern(state: &mut ParserState) -> Result<(Pattern, (bool, bool)
             let pattern = expressions::parse_struct_pattern(state)?;
             Ok((pattern, (false, false)))
         }
+        Some((Token::DotDotDot, _)) => {
+            // Rest parameter: fun log(...args) collects the remaining arguments
+            state.tokens.advance();
+            match state.tokens.peek() {
+                Some((Token::Identifier(name), _)) => {
+                    let name = name.clone();
+                    state.tokens.advance();
+                    Ok((Pattern::RestNamed(name), (false, false)))
+                }
+                _ => bail!("Expected parameter name after '...'"),
+            }
+        }
         Some((Token::Default, _)) => {
             // PARSER-087: Allow 'default' as parameter name (common pattern: default values)
             state.tokens.advance();
@@ -428,4 +461,31 @@ mod tests {
         assert!(result.is_ok());
         assert!(!result.unwrap());
     }
+
+    #[test]
+    fn test_parse_params_rest_param() {
+        let mut state = create_state("(prefix, ...args)");
+        let params = parse_params(&mut state).expect("should parse");
+        assert_eq!(params.len(), 2);
+        assert!(!params[0].is_rest());
+        assert!(matches!(&params[1].pattern, Pattern::RestNamed(n) if n == "args"));
+        assert_eq!(params[1].name(), "args");
+    }
+
+    #[test]
+    fn test_parse_params_rest_param_must_be_last() {
+        let mut state = create_state("(...args, last)");
+        let err = parse_params(&mut state).unwrap_err();
+        assert!(
+            err.to_string().contains("must be the last parameter"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_parse_params_rest_param_rejects_default() {
+        let mut state = create_state("(...args = [])");
+        let err = parse_params(&mut state).unwrap_err();
+        assert!(err.to_string().contains("cannot have a default"), "{err}");
+    }
 }
@@ -214,6 +214,7 @@ pub fn parse_type(state: &mut ParserState) -> Result<Type> {
         Some((Token::Impl, _)) => parse_impl_trait_type(state, span)?,
         Some((Token::LeftBracket, _)) => parse_list_type(state, span)?,
         Some((Token::LeftParen, _)) => parse_paren_type(state, span)?,
+        Some((Token::Underscore, _)) => parse_inferred_type(state, span),
         Some((
             Token::Identifier(_)
             | Token::Result
@@ -262,6 +263,14 @@ pub fn parse_type(state: &mut ParserState) -> Result<Type> {
         Ok(base_type)
     }
 }
+// Helper: Parse inferred type _ as in Vec<_> (complexity: 1)
+fn parse_inferred_type(state: &mut ParserState, span: Span) -> Type {
+    state.tokens.advance(); // consume _
+    Type {
+        kind: TypeKind::Named("_".to_string()),
+        span,
+    }
+}
 // Helper: Parse reference type &T or &mut T or &'a T (complexity: 5)
 fn parse_reference_type(state: &mut ParserState, span: Span) -> Result<Type> {
     state.tokens.advance(); // consume &
@@ -587,22 +596,26 @@ fn parse_generic_type(state: &mut ParserState, base: String, span: Span) -> Resu
         span,
     })
 }
-// Helper: Parse comma-separated type list (complexity: 3)
+// Helper: Parse comma-separated type list, allowing a trailing comma (complexity: 4)
 fn parse_type_list(state: &mut ParserState) -> Result<Vec<Type>> {
     let mut types = Vec::new();
-    if !matches!(
-        state.tokens.peek(),
-        Some((Token::RightParen | Token::Greater | Token::RightShift, _))
-    ) {
+    while !is_type_list_end(state) {
         types.push(parse_type(state)?);
-        while matches!(state.tokens.peek(), Some((Token::Comma, _))) {
-            state.tokens.advance(); // consume comma
-            types.push(parse_type(state)?);
+        if !matches!(state.tokens.peek(), Some((Token::Comma, _))) {
+            break;
         }
+        state.tokens.advance(); // consume comma
     }
     Ok(types)
 }
 
+fn is_type_list_end(state: &mut ParserState) -> bool {
+    matches!(
+        state.tokens.peek(),
+        Some((Token::RightParen | Token::Greater | Token::RightShift, _))
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
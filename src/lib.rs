@@ -62,6 +62,7 @@ pub mod actors;
 pub mod api_docs;
 pub mod backend;
 pub mod bench;
+pub mod build_timings;
 pub mod build_transpiler;
 pub mod cli;
 pub mod computebrick;
@@ -108,7 +109,7 @@ pub use actors::{
 };
 use anyhow::Result;
 pub use backend::wasm::WasmEmitter;
-pub use backend::{ModuleResolver, Transpiler};
+pub use backend::{ModuleResolver, Transpiler, TranspilerOptions};
 pub use frontend::ast::{BinaryOp, Expr, ExprKind, Literal, Pattern, UnaryOp};
 pub use frontend::lexer::{Token, TokenStream};
 pub use frontend::parser::Parser;
@@ -635,12 +636,14 @@ mod tests {
         // List patterns
         assert!(compile("match lst { [] => \"empty\", _ => \"has items\" }").is_ok());
         assert!(compile("match lst { [x] => x, _ => 0 }").is_ok());
-        // Rest patterns syntax not yet supported:
-        // assert!(compile("match lst { [head, ...tail] => head, _ => 0 }").is_ok());
-        // Struct patterns not yet supported:
-        // assert!(compile("match p { Point { x, y } => x + y }").is_ok());
-        // Enum patterns not yet supported:
-        // assert!(compile("match opt { Some(x) => x, None => 0 }").is_ok());
+        // Rest patterns
+        assert!(compile("match lst { [head, ...tail] => head, _ => 0 }").is_ok());
+        assert!(compile("match t { (first, .., last) => first + last }").is_ok());
+        // Struct patterns
+        assert!(compile("match p { Point { x, y } => x + y }").is_ok());
+        assert!(compile("match s { Shape::Circle { r } => r, _ => 0 }").is_ok());
+        // Enum patterns
+        assert!(compile("match opt { Some(x) => x, None => 0 }").is_ok());
         assert!(compile("match res { Ok(v) => v, Err(e) => panic(e) }").is_ok());
         // Guard patterns
         assert!(compile("match x { n if n > 0 => \"positive\", _ => \"other\" }").is_ok());
@@ -682,8 +685,10 @@ mod tests {
         assert!(compile("x /= 5").is_ok());
         // Nullish coalescing works (v1.9.0+)
         assert!(compile("x ?? y").is_ok());
-        // Optional chaining: tracked in roadmap as LANG-FEAT-001
-        // assert!(compile("x?.y").is_ok());
+        // Optional chaining
+        assert!(compile("x?.y").is_ok());
+        assert!(compile("x?.y?.len()").is_ok());
+        assert!(compile("x?[0]").is_ok());
     }
     #[test]
     fn test_control_flow() {
@@ -754,7 +759,6 @@ mod tests {
         assert!(compile("let (a, b) = (1, 2)").is_ok());
     }
     #[test]
-    #[ignore = "Try/catch syntax not yet implemented"]
     fn test_error_handling() {
         assert!(compile("try { risky() } catch e { handle(e) }").is_ok());
         assert!(compile("result?").is_ok());
@@ -1000,9 +1004,13 @@ mod tests {
         // Very long identifier
         let long_id = "a".repeat(1000);
         let _ = compile(&format!("let {long_id} = 1"));
-        // Deeply nested expression - reduced from 100 to 30 to avoid stack overflow
+        // Deeply nested expression
         let nested = "(".repeat(30) + "1" + &")".repeat(30);
         let _ = compile(&nested);
+        // Past the parser's nesting limit: a clean error, not a stack overflow
+        let nested = "(".repeat(10_000) + "1" + &")".repeat(10_000);
+        let error = compile(&nested).unwrap_err().to_string();
+        assert!(error.contains("too deeply nested"), "{error}");
         // Many arguments
         let args = (0..100)
             .map(|i| format!("arg{i}"))
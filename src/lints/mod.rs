@@ -375,6 +375,8 @@ mod tests {
                     10, None,
                 )))),
                 span: Span::new(0, 10),
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
             MatchArm {
                 pattern: Pattern::Literal(Literal::Integer(2, None)),
@@ -383,6 +385,8 @@ mod tests {
                     20, None,
                 )))),
                 span: Span::new(0, 10),
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
         ];
         let match_expr = make_test_expr(ExprKind::Match {
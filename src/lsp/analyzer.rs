@@ -1,11 +1,14 @@
 //! Semantic analysis for LSP
-use crate::frontend::ast::{Expr, ExprKind};
+use crate::frontend::ast::{Expr, ExprKind, Span};
+use crate::frontend::lexer::lex_errors;
 use crate::frontend::parser::Parser;
+use crate::middleend::dead_code::{find_dead_code, DeadCodeDiagnostic};
+use crate::middleend::match_analysis::{analyze_matches, MatchDiagnostic};
 use std::collections::HashMap;
 use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types::{
-    CompletionItem, CompletionItemKind, Diagnostic, DiagnosticSeverity, Documentation, Hover,
-    HoverContents, Location, MarkedString, NumberOrString, Position, Range,
+    CompletionItem, CompletionItemKind, Diagnostic, DiagnosticSeverity, DiagnosticTag,
+    Documentation, Hover, HoverContents, Location, MarkedString, NumberOrString, Position, Range,
 };
 pub struct SemanticAnalyzer {
     symbol_table: SymbolTable,
@@ -126,28 +129,32 @@ impl SemanticAnalyzer {
             Ok(ast) => {
                 // Update symbol table
                 self.update_symbol_table(&ast, document);
-                // Add any semantic warnings
-                // For now, just check for unused variables (placeholder)
+                diagnostics.extend(
+                    analyze_matches(&ast)
+                        .iter()
+                        .map(|d| match_warning_diagnostic(d, document)),
+                );
+                diagnostics.extend(
+                    find_dead_code(&ast)
+                        .iter()
+                        .map(|d| dead_code_diagnostic(d, document)),
+                );
             }
             Err(parse_error) => {
-                // Add parse error as diagnostic
-                diagnostics.push(Diagnostic {
-                    range: Range {
-                        start: Position {
-                            line: 0,
-                            character: 0,
-                        },
-                        end: Position {
-                            line: 0,
-                            character: 10,
-                        },
-                    },
-                    severity: Some(DiagnosticSeverity::ERROR),
-                    code: Some(NumberOrString::String("parse_error".to_string())),
-                    message: format!("Parse error: {parse_error}"),
-                    source: Some("ruchy".to_string()),
-                    ..Default::default()
-                });
+                // Report every lexical error, not just the one the parser hit first
+                let lexical = lex_errors(document);
+                diagnostics.extend(lexical.iter().map(|error| {
+                    error_diagnostic(document, error.span, "lex_error", error.kind.to_string())
+                }));
+                let span = parser.error_span();
+                if !lexical.iter().any(|error| error.span == span) {
+                    diagnostics.push(error_diagnostic(
+                        document,
+                        span,
+                        "parse_error",
+                        format!("Parse error: {parse_error}"),
+                    ));
+                }
             }
         }
         Ok(diagnostics)
@@ -200,6 +207,67 @@ impl SemanticAnalyzer {
         }
     }
 }
+/// Build an error diagnostic covering `span` of `document`
+fn error_diagnostic(document: &str, span: Span, code: &str, message: String) -> Diagnostic {
+    Diagnostic {
+        range: Range {
+            start: offset_position(document, span.start),
+            end: offset_position(document, span.end),
+        },
+        severity: Some(DiagnosticSeverity::ERROR),
+        code: Some(NumberOrString::String(code.to_string())),
+        message,
+        source: Some("ruchy".to_string()),
+        ..Default::default()
+    }
+}
+/// Zero-based LSP position of a byte offset within `document`
+fn offset_position(document: &str, offset: usize) -> Position {
+    let before = document
+        .get(..offset.min(document.len()))
+        .unwrap_or(document);
+    let line = before.matches('\n').count();
+    let line_start = before.rfind('\n').map_or(0, |nl| nl + 1);
+    let character = before[line_start..].chars().count();
+    Position {
+        line: u32::try_from(line).unwrap_or(u32::MAX),
+        character: u32::try_from(character).unwrap_or(u32::MAX),
+    }
+}
+/// Convert a match analysis warning into an LSP diagnostic
+fn match_warning_diagnostic(diagnostic: &MatchDiagnostic, document: &str) -> Diagnostic {
+    let to_position = |(line, character): (usize, usize)| Position {
+        line: u32::try_from(line).unwrap_or(u32::MAX),
+        character: u32::try_from(character).unwrap_or(u32::MAX),
+    };
+    Diagnostic {
+        range: Range {
+            start: to_position(diagnostic.position(document)),
+            end: to_position(diagnostic.end_position(document)),
+        },
+        severity: Some(DiagnosticSeverity::WARNING),
+        code: Some(NumberOrString::String(diagnostic.code().to_string())),
+        message: diagnostic.message.clone(),
+        source: Some("ruchy".to_string()),
+        ..Default::default()
+    }
+}
+/// Convert a dead code warning into an LSP diagnostic, tagged so editors
+/// fade the code out
+fn dead_code_diagnostic(diagnostic: &DeadCodeDiagnostic, document: &str) -> Diagnostic {
+    Diagnostic {
+        range: Range {
+            start: offset_position(document, diagnostic.span.start),
+            end: offset_position(document, diagnostic.span.end),
+        },
+        severity: Some(DiagnosticSeverity::WARNING),
+        code: Some(NumberOrString::String(diagnostic.code().to_string())),
+        message: diagnostic.message.clone(),
+        source: Some("ruchy".to_string()),
+        tags: Some(vec![DiagnosticTag::UNNECESSARY]),
+        ..Default::default()
+    }
+}
 impl Default for SemanticAnalyzer {
     fn default() -> Self {
         Self::new()
@@ -334,6 +402,38 @@ mod tests {
         assert_eq!(cloned.documentation, Some("doc".to_string()));
     }
 
+    #[test]
+    fn test_get_diagnostics_reports_non_exhaustive_match() {
+        let mut analyzer = SemanticAnalyzer::new();
+        let diagnostics = analyzer
+            .get_diagnostics("match opt {\n  Some(x) => x\n}")
+            .expect("should succeed");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::WARNING));
+        assert!(diagnostics[0].message.contains("None"));
+        assert_eq!(diagnostics[0].range.start.line, 0);
+    }
+
+    #[test]
+    fn test_get_diagnostics_reports_dead_code() {
+        let mut analyzer = SemanticAnalyzer::new();
+        let diagnostics = analyzer
+            .get_diagnostics("fun main() {\n  return 1\n  let x = 2\n  x\n}\nfun unused() { 3 }")
+            .expect("should succeed");
+        let codes: Vec<_> = diagnostics.iter().map(|d| d.code.clone()).collect();
+        assert_eq!(
+            codes,
+            [
+                Some(NumberOrString::String("unreachable_code".to_string())),
+                Some(NumberOrString::String("unused_function".to_string())),
+            ]
+        );
+        assert_eq!(diagnostics[0].range.start.line, 2);
+        assert_eq!(diagnostics[0].range.end.line, 3);
+        assert_eq!(diagnostics[1].range.start.line, 5);
+        assert_eq!(diagnostics[1].tags, Some(vec![DiagnosticTag::UNNECESSARY]));
+    }
+
     #[test]
     fn test_get_diagnostics_invalid_code() {
         let mut analyzer = SemanticAnalyzer::new();
@@ -343,4 +443,22 @@ mod tests {
         // Invalid code should produce parse error diagnostic
         assert!(!diagnostics.is_empty());
     }
+
+    #[test]
+    fn test_get_diagnostics_reports_every_lexical_error() {
+        let mut analyzer = SemanticAnalyzer::new();
+        let diagnostics = analyzer
+            .get_diagnostics("let a = 1 $ 2\nlet b = \"open\nlet c = 3")
+            .expect("should succeed");
+        let lex: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.code == Some(NumberOrString::String("lex_error".to_string())))
+            .collect();
+        assert_eq!(lex.len(), 2, "{diagnostics:?}");
+        assert!(lex[0].message.contains("Invalid character '$'"));
+        assert_eq!(lex[0].range.start, Position::new(0, 10));
+        assert!(lex[1].message.contains("Unterminated string"));
+        assert_eq!(lex[1].range.start, Position::new(1, 8));
+        assert_eq!(lex[1].range.end, Position::new(1, 13));
+    }
 }
@@ -0,0 +1,539 @@
+//! Common subexpression elimination and loop-invariant code motion
+//!
+//! Two passes of [`crate::middleend::optimize::optimize`] that keep pure
+//! expressions from being computed more often than needed:
+//!
+//! - [`hoist_loop_invariants`] moves an expression a `for`, `while` or
+//!   `loop` recomputes on every iteration, although nothing in the loop
+//!   changes its operands, into a `let` just before the loop.
+//! - [`share_common_subexpressions`] computes an expression repeated among
+//!   the statements of a block once, in a `let` before its first use, when
+//!   nothing from there to its last use changes its operands.
+//!
+//! Only arithmetic, comparisons and logic over variables and number or bool
+//! literals move, plus `min`, `max` and `sqrt` of them. These cannot fail on
+//! well-typed operands, which matters because a moved expression is
+//! evaluated even when the loop runs zero times or the branch holding it is
+//! not taken; division and remainder only move with a non-zero literal
+//! divisor. Code that assigns, rebinds or calls a method on an operand, or
+//! calls a function value or a function that may change state (see
+//! [`crate::middleend::effects`]), keeps its expressions in place.
+//! Expressions inside nested functions and lambdas never move. Temporaries
+//! are named `__licm_N` and `__cse_N`.
+use crate::backend::transpiler::pattern_bindings::extract_pattern_bindings;
+use crate::backend::whole_program::for_each_child_mut;
+use crate::frontend::ast::{BinaryOp, Expr, ExprKind, Literal, UnaryOp};
+use crate::middleend::effects::{infer_effects, Effect};
+use crate::middleend::match_analysis::for_each_expr;
+use crate::middleend::optimize::bound_names;
+use std::collections::{BTreeSet, HashSet};
+
+/// Builtins whose calls move with their operands
+const MOVABLE_BUILTINS: &[&str] = &["max", "min", "sqrt"];
+
+/// Move loop-invariant expressions out of loops, returning how many moved
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::middleend::code_motion::hoist_loop_invariants;
+/// use ruchy::Parser;
+///
+/// let mut program = Parser::new(
+///     "let mut total = 0\nfor i in 0..10 { total = total + i * (width * height) }",
+/// )
+/// .parse()
+/// .unwrap();
+/// // `width * height` is computed once; `i * ...` and `total + ...` stay
+/// assert_eq!(hoist_loop_invariants(&mut program), 1);
+/// ```
+pub fn hoist_loop_invariants(program: &mut Expr) -> usize {
+    let mut context = Context::new(program);
+    let mut hoisted = 0;
+    context.hoist(program, &mut hoisted);
+    hoisted
+}
+
+/// Compute expressions repeated in a block once, returning how many were shared
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::middleend::code_motion::share_common_subexpressions;
+/// use ruchy::Parser;
+///
+/// let mut program = Parser::new("fun dist(a, b) { let d = (a - b) * (a - b)\nd }")
+///     .parse()
+///     .unwrap();
+/// assert_eq!(share_common_subexpressions(&mut program), 1);
+/// ```
+pub fn share_common_subexpressions(program: &mut Expr) -> usize {
+    let mut context = Context::new(program);
+    let mut shared = 0;
+    context.share(program, &mut shared);
+    shared
+}
+
+/// A movable expression: its structure, ignoring spans, and the variables
+/// it reads
+struct Movable {
+    key: String,
+    vars: BTreeSet<String>,
+}
+
+/// What a stretch of code may change
+#[derive(Default)]
+struct Writes {
+    /// Variables assigned, rebound or used as a method receiver
+    names: HashSet<String>,
+    /// Calls something that may change any variable
+    opaque: bool,
+}
+
+impl Writes {
+    /// Whether `movable` computes the same value anywhere in the stretch
+    fn allow(&self, movable: &Movable) -> bool {
+        !self.opaque && movable.vars.iter().all(|var| !self.names.contains(var))
+    }
+}
+
+/// Program facts both passes need
+struct Context {
+    /// Names the program binds; calls of them are not builtins
+    bound: HashSet<String>,
+    /// User functions whose calls cannot change variables
+    stateless: HashSet<String>,
+    /// Every name in the program, so temporaries don't clash
+    used: HashSet<String>,
+    next_temp: usize,
+}
+
+impl Context {
+    fn new(program: &Expr) -> Self {
+        let stateless = infer_effects(program)
+            .functions
+            .into_iter()
+            .filter(|function| {
+                !function.effects.contains(&Effect::State)
+                    && !function.effects.contains(&Effect::Unknown)
+            })
+            .map(|function| function.name)
+            .collect();
+        let bound = bound_names(program);
+        let mut used = bound.clone();
+        for_each_expr(program, &mut |expr| {
+            if let ExprKind::Identifier(name) = &expr.kind {
+                used.insert(name.clone());
+            }
+        });
+        Self {
+            bound,
+            stateless,
+            used,
+            next_temp: 0,
+        }
+    }
+
+    /// A fresh temporary name
+    fn temp(&mut self, prefix: &str) -> String {
+        loop {
+            let name = format!("__{prefix}_{}", self.next_temp);
+            self.next_temp += 1;
+            if self.used.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    /// Hoist invariants out of `expr` if it is a loop, then out of the loops
+    /// inside it
+    fn hoist(&mut self, expr: &mut Expr, hoisted: &mut usize) {
+        let lets = self.hoist_from_loop(expr);
+        if !lets.is_empty() {
+            *hoisted += lets.len();
+            let span = expr.span;
+            let the_loop =
+                std::mem::replace(expr, Expr::new(ExprKind::Literal(Literal::Unit), span));
+            let mut items = lets;
+            items.push(the_loop);
+            *expr = Expr::new(ExprKind::Block(items), span);
+        }
+        for_each_child_mut(expr, &mut |child| self.hoist(child, hoisted));
+    }
+
+    /// Replace the invariants of a loop by temporaries, returning their
+    /// `let`s (complexity: 6)
+    fn hoist_from_loop(&mut self, the_loop: &mut Expr) -> Vec<Expr> {
+        if per_iteration_parts(the_loop).is_empty() {
+            return Vec::new();
+        }
+        let writes = self.writes([&*the_loop]);
+        let mut invariants: Vec<(String, Expr)> = Vec::new();
+        for part in per_iteration_parts(the_loop) {
+            self.visit_movable(part, &mut |expr, movable| {
+                if !writes.allow(&movable) {
+                    return false;
+                }
+                if !invariants.iter().any(|(key, _)| *key == movable.key) {
+                    invariants.push((movable.key, expr.clone()));
+                }
+                true
+            });
+        }
+        let temps: Vec<(String, String, Expr)> = invariants
+            .into_iter()
+            .map(|(key, value)| (key, self.temp("licm"), value))
+            .collect();
+        for part in per_iteration_parts(the_loop) {
+            self.visit_movable(part, &mut |expr, movable| {
+                let Some((_, name, _)) = temps.iter().find(|(key, ..)| *key == movable.key) else {
+                    return false;
+                };
+                expr.kind = ExprKind::Identifier(name.clone());
+                true
+            });
+        }
+        temps
+            .into_iter()
+            .map(|(_, name, value)| let_statement(name, value))
+            .collect()
+    }
+
+    /// Share repeated expressions among the statements of each block
+    fn share(&mut self, expr: &mut Expr, shared: &mut usize) {
+        if let ExprKind::Block(items) = &mut expr.kind {
+            while let Some((key, first, last)) = self.repeated(items) {
+                let name = self.temp("cse");
+                let mut value = None;
+                for item in &mut items[first..=last] {
+                    self.visit_movable(item, &mut |expr, movable| {
+                        if movable.key != key {
+                            return false;
+                        }
+                        let kind =
+                            std::mem::replace(&mut expr.kind, ExprKind::Identifier(name.clone()));
+                        value.get_or_insert_with(|| Expr::new(kind, expr.span));
+                        true
+                    });
+                }
+                let value = value.expect("a repeated expression has occurrences");
+                items.insert(first, let_statement(name, value));
+                *shared += 1;
+            }
+        }
+        for_each_child_mut(expr, &mut |child| self.share(child, shared));
+    }
+
+    /// The largest expression repeated among `items` that nothing between
+    /// its first and last use changes, with those item indices
+    fn repeated(&self, items: &mut [Expr]) -> Option<(String, usize, usize)> {
+        // Key, variables, first and last item, occurrences
+        let mut seen: Vec<(String, BTreeSet<String>, usize, usize, usize)> = Vec::new();
+        for (index, item) in items.iter_mut().enumerate() {
+            self.visit_movable(item, &mut |_, movable| {
+                match seen.iter_mut().find(|entry| entry.0 == movable.key) {
+                    Some(entry) => {
+                        entry.3 = index;
+                        entry.4 += 1;
+                    }
+                    None => seen.push((movable.key, movable.vars, index, index, 1)),
+                }
+                false
+            });
+        }
+        seen.into_iter()
+            .filter(|(_, _, _, _, count)| *count > 1)
+            .filter(|(key, vars, first, last, _)| {
+                self.writes(&items[*first..=*last]).allow(&Movable {
+                    key: key.clone(),
+                    vars: vars.clone(),
+                })
+            })
+            .max_by_key(|(key, _, first, _, _)| (key.len(), std::cmp::Reverse(*first)))
+            .map(|(key, _, first, last, _)| (key, first, last))
+    }
+
+    /// Call `f` on each movable expression in `expr`, outermost first and
+    /// outside nested functions and lambdas, skipping the insides of those
+    /// `f` returns `true` for
+    fn visit_movable(&self, expr: &mut Expr, f: &mut dyn FnMut(&mut Expr, Movable) -> bool) {
+        if let Some(movable) = self.movable(expr) {
+            if f(expr, movable) {
+                return;
+            }
+        }
+        if matches!(
+            expr.kind,
+            ExprKind::Function { .. } | ExprKind::Lambda { .. }
+        ) {
+            return;
+        }
+        for_each_child_mut(expr, &mut |child| self.visit_movable(child, f));
+    }
+
+    /// `expr` if it is an operation that reads a variable and may move
+    fn movable(&self, expr: &Expr) -> Option<Movable> {
+        if matches!(expr.kind, ExprKind::Identifier(_) | ExprKind::Literal(_)) {
+            return None;
+        }
+        self.pure(expr).filter(|movable| !movable.vars.is_empty())
+    }
+
+    /// `expr` if it is a movable operation or an operand of one (complexity: 9)
+    fn pure(&self, expr: &Expr) -> Option<Movable> {
+        let (key, vars) = match &expr.kind {
+            ExprKind::Identifier(name) => (name.clone(), BTreeSet::from([name.clone()])),
+            ExprKind::Literal(Literal::Integer(n, None)) => (n.to_string(), BTreeSet::new()),
+            ExprKind::Literal(Literal::Float(f)) => (format!("{f:?}"), BTreeSet::new()),
+            ExprKind::Literal(Literal::Bool(b)) => (b.to_string(), BTreeSet::new()),
+            ExprKind::Binary { left, op, right } if movable_operator(*op, right) => {
+                let (left, mut right) = (self.pure(left)?, self.pure(right)?);
+                right.vars.extend(left.vars);
+                (format!("({} {op:?} {})", left.key, right.key), right.vars)
+            }
+            ExprKind::Unary {
+                op: op @ (UnaryOp::Negate | UnaryOp::Not),
+                operand,
+            } => {
+                let operand = self.pure(operand)?;
+                (format!("({op:?} {})", operand.key), operand.vars)
+            }
+            ExprKind::Call { func, args } => {
+                let ExprKind::Identifier(name) = &func.kind else {
+                    return None;
+                };
+                if !MOVABLE_BUILTINS.contains(&name.as_str()) || self.bound.contains(name) {
+                    return None;
+                }
+                let args = args
+                    .iter()
+                    .map(|arg| self.pure(arg))
+                    .collect::<Option<Vec<_>>>()?;
+                let keys: Vec<&str> = args.iter().map(|arg| arg.key.as_str()).collect();
+                let key = format!("{name}({})", keys.join(", "));
+                (key, args.into_iter().flat_map(|arg| arg.vars).collect())
+            }
+            _ => return None,
+        };
+        Some(Movable { key, vars })
+    }
+
+    /// What `exprs` may change
+    fn writes<'a>(&self, exprs: impl IntoIterator<Item = &'a Expr>) -> Writes {
+        let mut writes = Writes::default();
+        for expr in exprs {
+            for_each_expr(expr, &mut |expr| self.note_write(expr, &mut writes));
+        }
+        writes
+    }
+
+    /// Record what evaluating `expr` itself may change (complexity: 9)
+    fn note_write(&self, expr: &Expr, writes: &mut Writes) {
+        let names = &mut writes.names;
+        match &expr.kind {
+            ExprKind::Assign { target, .. }
+            | ExprKind::CompoundAssign { target, .. }
+            | ExprKind::PreIncrement { target }
+            | ExprKind::PostIncrement { target }
+            | ExprKind::PreDecrement { target }
+            | ExprKind::PostDecrement { target }
+            | ExprKind::MethodCall {
+                receiver: target, ..
+            } => names.extend(root_name(target)),
+            ExprKind::Let { name, .. } => {
+                names.insert(name.clone());
+            }
+            ExprKind::LetPattern { pattern, .. }
+            | ExprKind::IfLet { pattern, .. }
+            | ExprKind::WhileLet { pattern, .. }
+            | ExprKind::For {
+                pattern: Some(pattern),
+                ..
+            } => names.extend(extract_pattern_bindings(pattern)),
+            ExprKind::For { var, .. } => {
+                names.insert(var.clone());
+            }
+            ExprKind::Match { arms, .. } => {
+                for arm in arms {
+                    names.extend(extract_pattern_bindings(&arm.pattern));
+                }
+            }
+            ExprKind::Function { name, params, .. } => {
+                names.insert(name.clone());
+                names.extend(params.iter().map(|param| param.name()));
+            }
+            ExprKind::Lambda { params, .. } => {
+                names.extend(params.iter().map(|param| param.name()));
+            }
+            ExprKind::Call { func, .. } => {
+                writes.opaque |= match &func.kind {
+                    ExprKind::Identifier(name) => {
+                        self.bound.contains(name) && !self.stateless.contains(name)
+                    }
+                    _ => true,
+                };
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Whether `left op right` may move: it cannot fail on well-typed operands
+fn movable_operator(op: BinaryOp, right: &Expr) -> bool {
+    match op {
+        BinaryOp::Add
+        | BinaryOp::Subtract
+        | BinaryOp::Multiply
+        | BinaryOp::Equal
+        | BinaryOp::NotEqual
+        | BinaryOp::Less
+        | BinaryOp::LessEqual
+        | BinaryOp::Greater
+        | BinaryOp::Gt
+        | BinaryOp::GreaterEqual
+        | BinaryOp::And
+        | BinaryOp::Or => true,
+        BinaryOp::Divide | BinaryOp::Modulo => {
+            matches!(
+                right.kind,
+                ExprKind::Literal(Literal::Integer(n, _)) if n != 0
+            ) || matches!(
+                right.kind,
+                ExprKind::Literal(Literal::Float(f)) if f != 0.0
+            )
+        }
+        _ => false,
+    }
+}
+
+/// The variable an assignment target or method receiver belongs to
+fn root_name(target: &Expr) -> Option<String> {
+    match &target.kind {
+        ExprKind::Identifier(name) => Some(name.clone()),
+        ExprKind::IndexAccess { object, .. } | ExprKind::FieldAccess { object, .. } => {
+            root_name(object)
+        }
+        _ => None,
+    }
+}
+
+/// The parts of a loop evaluated on every iteration
+fn per_iteration_parts(the_loop: &mut Expr) -> Vec<&mut Expr> {
+    match &mut the_loop.kind {
+        ExprKind::For { body, .. } | ExprKind::Loop { body, .. } => vec![body],
+        ExprKind::While {
+            condition, body, ..
+        } => vec![condition, body],
+        _ => Vec::new(),
+    }
+}
+
+/// `let name = value` as a block statement
+fn let_statement(name: String, value: Expr) -> Expr {
+    let span = value.span;
+    Expr::new(
+        ExprKind::Let {
+            name,
+            type_annotation: None,
+            value: Box::new(value),
+            body: Box::new(Expr::new(ExprKind::Literal(Literal::Unit), span)),
+            is_mutable: false,
+            else_block: None,
+        },
+        span,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::Parser;
+    use crate::runtime::script::{run_script, ScriptOptions};
+
+    fn parse(source: &str) -> Expr {
+        Parser::new(source).parse().expect("source should parse")
+    }
+
+    /// Values bound to the temporaries named with `prefix`, in order
+    fn temps(program: &Expr, prefix: &str) -> Vec<String> {
+        let context = Context::new(program);
+        let mut found = Vec::new();
+        for_each_expr(program, &mut |expr| {
+            if let ExprKind::Let { name, value, .. } = &expr.kind {
+                if name.starts_with(prefix) {
+                    let value = context.pure(value).expect("temporaries hold pure values");
+                    found.push(format!("{name} = {}", value.key));
+                }
+            }
+        });
+        found
+    }
+
+    #[test]
+    fn test_hoists_invariants_out_of_for_and_while_loops() {
+        let mut program = parse(
+            "let mut total = 0\nlet mut i = 0\nwhile i < n * 2 {\n    total = total + i * (w * h)\n    i = i + 1\n}\nfor j in 0..3 { total = total + j * (w * h) }",
+        );
+        assert_eq!(hoist_loop_invariants(&mut program), 3);
+        assert_eq!(
+            temps(&program, "__licm"),
+            [
+                "__licm_0 = (n Multiply 2)",
+                "__licm_1 = (w Multiply h)",
+                "__licm_2 = (w Multiply h)"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_keeps_expressions_the_loop_changes_or_may_fail() {
+        for source in [
+            "for i in 0..3 { println(i * k) }",
+            "let mut k = 1\nfor i in 0..3 { k = k + 1\nprintln(w * k) }",
+            "for i in 0..3 { let w = i\nprintln(w * h) }",
+            "for i in 0..3 { println(w / h) }",
+            "for i in 0..3 { items.push(1)\nprintln(items == other) }",
+            "let mut w = 1\nfun bump() { w = w + 1 }\nfor i in 0..3 { bump()\nprintln(w * h) }",
+            "for i in 0..3 { let f = |x| x * w\nprintln(f(1)) }",
+        ] {
+            let mut program = parse(source);
+            assert_eq!(hoist_loop_invariants(&mut program), 0, "{source}");
+        }
+    }
+
+    #[test]
+    fn test_inner_loops_hoist_what_the_outer_loop_changes() {
+        let mut program =
+            parse("for i in 0..3 {\n    for j in 0..3 {\n        println(i * w + j)\n    }\n}");
+        assert_eq!(hoist_loop_invariants(&mut program), 1);
+        assert_eq!(temps(&program, "__licm"), ["__licm_0 = (i Multiply w)"]);
+    }
+
+    #[test]
+    fn test_shares_repeated_expressions_between_statements() {
+        let mut program = parse(
+            "fun area(w, h) {\n    let inner = (w - 2) * (h - 2)\n    let border = w * h - (w - 2) * (h - 2)\n    border + inner\n}",
+        );
+        assert_eq!(share_common_subexpressions(&mut program), 1);
+        assert_eq!(
+            temps(&program, "__cse"),
+            ["__cse_0 = ((w Subtract 2) Multiply (h Subtract 2))"]
+        );
+    }
+
+    #[test]
+    fn test_does_not_share_across_a_write() {
+        let mut program = parse("fun f(x) {\n    let mut y = x\n    let a = y * 2\n    y = 5\n    let b = y * 2\n    a + b\n}");
+        assert_eq!(share_common_subexpressions(&mut program), 0);
+    }
+
+    #[test]
+    fn test_optimized_loops_evaluate_the_same() {
+        let source = "fun main() {\n    let w = 3\n    let h = 4\n    let mut total = 0\n    for i in 0..5 {\n        total = total + i * (w * h) + (w * h)\n    }\n    let mut j = 0\n    while j < w * h {\n        j = j + w\n    }\n    total + j\n}";
+        let plain = run_script(source, &ScriptOptions::default()).unwrap();
+        let optimized =
+            run_script(source, &ScriptOptions::default().with_optimization(true)).unwrap();
+        assert_eq!(plain.value.as_deref(), Some("192"));
+        assert_eq!(optimized.value, plain.value);
+    }
+}
@@ -0,0 +1,355 @@
+//! Dead code detection
+//!
+//! Finds code that never runs:
+//!
+//! - statements that follow a `return`, `break` or `continue` in the same
+//!   block;
+//! - functions no entry point reaches. Entry points are `main`, code outside
+//!   any function, `pub` and exported functions, and `#[test]` functions; a
+//!   function is reached when an entry point, or a function reached from one,
+//!   names it. A file with no entry point is only used through imports we
+//!   can't see, so its functions are not reported.
+//!
+//! Match arms shadowed by earlier arms are found by
+//! [`analyze_matches`](crate::middleend::match_analysis::analyze_matches).
+//! `ruchy lint` and the LSP report both.
+use crate::frontend::ast::{Expr, ExprKind, Literal, Span};
+use crate::middleend::match_analysis::{for_each_expr, line_column};
+use std::collections::HashSet;
+
+/// What a dead code diagnostic reports
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeadCodeKind {
+    /// Statements after a `return`, `break` or `continue`
+    UnreachableCode,
+    /// A function no entry point reaches
+    UnusedFunction { name: String },
+}
+
+/// A warning produced by [`find_dead_code`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeadCodeDiagnostic {
+    pub kind: DeadCodeKind,
+    /// Span of the unreachable statements, or of the unused function's
+    /// `fun` keyword
+    pub span: Span,
+    pub message: String,
+}
+
+impl DeadCodeDiagnostic {
+    /// Stable diagnostic code for CLI and LSP output
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self.kind {
+            DeadCodeKind::UnreachableCode => "unreachable_code",
+            DeadCodeKind::UnusedFunction { .. } => "unused_function",
+        }
+    }
+
+    /// Zero-based line and column of the diagnostic start within `source`
+    #[must_use]
+    pub fn position(&self, source: &str) -> (usize, usize) {
+        line_column(source, self.span.start)
+    }
+
+    /// Zero-based line and column of the diagnostic end within `source`
+    #[must_use]
+    pub fn end_position(&self, source: &str) -> (usize, usize) {
+        line_column(source, self.span.end)
+    }
+}
+
+/// Find unreachable statements and unused functions in a program, in
+/// source order
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::frontend::parser::Parser;
+/// use ruchy::middleend::dead_code::find_dead_code;
+///
+/// let source = "fun helper() { 1 }\nfun main() {\n    return 0\n    println(\"done\")\n}";
+/// let codes: Vec<&str> = find_dead_code(&Parser::new(source).parse().unwrap())
+///     .iter()
+///     .map(|d| d.code())
+///     .collect();
+/// assert_eq!(codes, ["unused_function", "unreachable_code"]);
+/// ```
+#[must_use]
+pub fn find_dead_code(ast: &Expr) -> Vec<DeadCodeDiagnostic> {
+    let mut diagnostics = Vec::new();
+    for_each_expr(ast, &mut |expr: &Expr| {
+        if let ExprKind::Block(statements) = &expr.kind {
+            unreachable_statements(statements, &mut diagnostics);
+        }
+    });
+    unused_functions(ast, &mut diagnostics);
+    diagnostics.sort_by_key(|d| d.span.start);
+    diagnostics
+}
+
+/// Report the statements of a block that follow its first exit
+fn unreachable_statements(statements: &[Expr], diagnostics: &mut Vec<DeadCodeDiagnostic>) {
+    let Some((index, keyword)) = statements
+        .iter()
+        .enumerate()
+        .find_map(|(index, statement)| exit_keyword(statement).map(|keyword| (index, keyword)))
+    else {
+        return;
+    };
+    let rest = &statements[index + 1..];
+    let Some(first) = rest.first() else {
+        return;
+    };
+    let end = rest.iter().map(span_end).max().unwrap_or(first.span.end);
+    diagnostics.push(DeadCodeDiagnostic {
+        kind: DeadCodeKind::UnreachableCode,
+        span: Span::new(statement_start(first), end),
+        message: format!("unreachable code after `{keyword}`"),
+    });
+}
+
+fn exit_keyword(statement: &Expr) -> Option<&'static str> {
+    match statement.kind {
+        ExprKind::Return { .. } => Some("return"),
+        ExprKind::Break { .. } => Some("break"),
+        ExprKind::Continue { .. } => Some("continue"),
+        _ => None,
+    }
+}
+
+/// Where a statement starts; block-level `let` statements carry the span of
+/// their block, so their value stands in for them
+fn statement_start(statement: &Expr) -> usize {
+    match &statement.kind {
+        ExprKind::Let { value, .. } | ExprKind::LetPattern { value, .. } => span_start(value),
+        _ => span_start(statement),
+    }
+}
+
+/// The earliest start of any expression inside `expr`; some expressions,
+/// such as calls, are built without a span and don't count
+pub(crate) fn span_start(expr: &Expr) -> usize {
+    let mut start = None;
+    for_each_expr(expr, &mut |inner: &Expr| {
+        if inner.span.end > 0 {
+            start = Some(start.map_or(inner.span.start, |s: usize| s.min(inner.span.start)));
+        }
+    });
+    start.unwrap_or(expr.span.start)
+}
+
+/// The furthest end of any expression inside `expr`
+fn span_end(expr: &Expr) -> usize {
+    let mut end = expr.span.end;
+    for_each_expr(expr, &mut |inner: &Expr| end = end.max(inner.span.end));
+    end
+}
+
+/// Report the functions declared outside other functions that no entry
+/// point reaches
+fn unused_functions(ast: &Expr, diagnostics: &mut Vec<DeadCodeDiagnostic>) {
+    let mut declarations = Declarations::default();
+    declarations.collect(ast, false);
+    if !declarations.has_entry {
+        return;
+    }
+    let functions = &declarations.functions;
+    let mut reached: Vec<bool> = functions
+        .iter()
+        .map(|f| f.entry || declarations.referenced.contains(f.name))
+        .collect();
+    let mut queue: Vec<usize> = (0..functions.len()).filter(|&i| reached[i]).collect();
+    while let Some(caller) = queue.pop() {
+        for (callee, function) in functions.iter().enumerate() {
+            if !reached[callee] && functions[caller].names.contains(function.name) {
+                reached[callee] = true;
+                queue.push(callee);
+            }
+        }
+    }
+    for (function, _) in functions.iter().zip(reached).filter(|(_, r)| !r) {
+        diagnostics.push(DeadCodeDiagnostic {
+            kind: DeadCodeKind::UnusedFunction {
+                name: function.name.to_string(),
+            },
+            span: function.span,
+            message: format!("function `{}` is never used", function.name),
+        });
+    }
+}
+
+/// A function declared outside any other function
+struct Function<'a> {
+    name: &'a str,
+    span: Span,
+    /// Whether the function is an entry point itself
+    entry: bool,
+    /// Names its body refers to
+    names: HashSet<String>,
+}
+
+/// Functions and the code around them, through modules, exports and the
+/// bodies of top-level `let` statements
+#[derive(Default)]
+struct Declarations<'a> {
+    functions: Vec<Function<'a>>,
+    /// Names referred to outside functions
+    referenced: HashSet<String>,
+    /// Whether anything can run the file's functions
+    has_entry: bool,
+}
+
+impl<'a> Declarations<'a> {
+    /// Complexity: 8
+    fn collect(&mut self, item: &'a Expr, exported: bool) {
+        match &item.kind {
+            ExprKind::Block(items) => {
+                for item in items {
+                    self.collect(item, exported);
+                }
+            }
+            ExprKind::Module { body, .. } => self.collect(body, false),
+            ExprKind::Export { expr, .. } => self.collect(expr, true),
+            ExprKind::Let {
+                value,
+                body,
+                else_block,
+                ..
+            }
+            | ExprKind::LetPattern {
+                value,
+                body,
+                else_block,
+                ..
+            } => {
+                self.code(value, true);
+                if let Some(else_block) = else_block {
+                    self.code(else_block, true);
+                }
+                self.collect(body, exported);
+            }
+            ExprKind::Function {
+                name, body, is_pub, ..
+            } => {
+                let is_test = item.attributes.iter().any(|a| a.name == "test");
+                let entry = exported || *is_pub || is_test || name == "main";
+                self.has_entry |= entry;
+                self.functions.push(Function {
+                    name,
+                    span: item.span,
+                    entry,
+                    names: names_in(body),
+                });
+            }
+            ExprKind::ExportList { names } => self.referenced.extend(names.iter().cloned()),
+            _ => self.code(item, !is_declaration(&item.kind)),
+        }
+    }
+
+    /// Record code outside functions, which is an entry point if it `runs`
+    fn code(&mut self, expr: &Expr, runs: bool) {
+        self.has_entry |= runs;
+        self.referenced.extend(names_in(expr));
+    }
+}
+
+/// Items that declare something rather than run
+fn is_declaration(kind: &ExprKind) -> bool {
+    matches!(
+        kind,
+        ExprKind::Struct { .. }
+            | ExprKind::TupleStruct { .. }
+            | ExprKind::Class { .. }
+            | ExprKind::Enum { .. }
+            | ExprKind::Trait { .. }
+            | ExprKind::Impl { .. }
+            | ExprKind::Actor { .. }
+            | ExprKind::Effect { .. }
+            | ExprKind::Import { .. }
+            | ExprKind::ImportAll { .. }
+            | ExprKind::ImportDefault { .. }
+            | ExprKind::ReExport { .. }
+            | ExprKind::TypeAlias { .. }
+            | ExprKind::ModuleDeclaration { .. }
+            | ExprKind::Literal(Literal::Unit)
+    )
+}
+
+/// Every name `expr` refers to, plain or module-qualified
+fn names_in(expr: &Expr) -> HashSet<String> {
+    let mut names = HashSet::new();
+    for_each_expr(expr, &mut |inner: &Expr| match &inner.kind {
+        ExprKind::Identifier(name) | ExprKind::QualifiedName { name, .. } => {
+            names.insert(name.clone());
+        }
+        _ => {}
+    });
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::Parser;
+
+    fn diagnostics(source: &str) -> Vec<(DeadCodeKind, String)> {
+        let ast = Parser::new(source).parse().expect("source should parse");
+        find_dead_code(&ast)
+            .into_iter()
+            .map(|d| (d.kind, d.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_statements_after_exits_are_unreachable() {
+        let source = "fun main() {\n    for i in 0..3 {\n        continue\n        println(i)\n    }\n    return 1\n    let x = 2\n    println(x)\n}";
+        assert_eq!(
+            diagnostics(source),
+            [
+                (
+                    DeadCodeKind::UnreachableCode,
+                    "unreachable code after `continue`".to_string()
+                ),
+                (
+                    DeadCodeKind::UnreachableCode,
+                    "unreachable code after `return`".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unreachable_span_starts_at_the_first_dead_statement() {
+        let source = "fun main() {\n    return 1\n    let x = 2\n    x\n}";
+        let ast = Parser::new(source).parse().expect("source should parse");
+        let diagnostic = &find_dead_code(&ast)[0];
+        assert_eq!(diagnostic.position(source).0, 2);
+        assert!(source[diagnostic.span.start..].starts_with('2'));
+    }
+
+    #[test]
+    fn test_functions_unreached_from_entry_points_are_unused() {
+        let source = "fun leaf() { 1 }\nfun helper() { leaf() }\nfun orphan() { orphan_child() }\nfun orphan_child() { 2 }\nfun main() { helper() }";
+        let unused: Vec<String> = diagnostics(source).into_iter().map(|(_, m)| m).collect();
+        assert_eq!(
+            unused,
+            [
+                "function `orphan` is never used",
+                "function `orphan_child` is never used"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_entry_points_keep_functions_used() {
+        // Top-level code, pub functions, tests and module-qualified calls
+        let source = "fun shown() { 1 }\nprintln(shown())\nmod util {\n    fun inner() { 2 }\n    pub fun api() { inner() }\n}\n#[test]\nfun checks() { util::api() }";
+        assert!(diagnostics(source).is_empty(), "{:?}", diagnostics(source));
+    }
+
+    #[test]
+    fn test_files_without_entry_points_are_libraries() {
+        assert!(diagnostics("fun a() { 1 }\nfun b() { 2 }").is_empty());
+    }
+}
@@ -0,0 +1,689 @@
+//! Effect inference for functions
+//!
+//! [`infer_effects`] classifies every named function of a program by what a
+//! call can do besides computing its result (see [`Effect`]). Effects come
+//! from the function's own body (`println`, `throw`, `await`, assigning a
+//! global) and flow from callees to callers until nothing changes, so a
+//! function calling a printing helper performs IO too. Nested functions and
+//! lambdas count toward the function that defines them.
+//!
+//! The results drive `ruchy ast --effects`, the `ruchy check` warnings for
+//! `@pure` and `@memoize` functions whose bodies contradict them, the
+//! memoization of `@memoize` functions in transpiled code and the folding of
+//! pure calls in [`crate::backend::whole_program`].
+//!
+//! The analysis is conservative: a call it can't resolve (a function value,
+//! an unknown name) is [`Effect::Unknown`] rather than assumed pure. Two
+//! exceptions keep common code pure: associated functions of types
+//! (`Point::new`) are assumed pure, and methods are only checked for
+//! mutating a receiver the function doesn't own.
+use crate::backend::transpiler::pattern_bindings::extract_pattern_bindings;
+use crate::frontend::ast::{Expr, ExprKind, Literal, Param, Span};
+use crate::middleend::match_analysis::{for_each_expr, line_column};
+use crate::runtime::restricted::denied_capability;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+
+/// Module roots whose functions talk to the outside world
+const IO_MODULES: &[&str] = &[
+    "fs", "io", "env", "process", "net", "http", "time", "chrono", "thread", "random", "rand",
+];
+
+/// Methods that modify their receiver in place
+const MUTATING_METHODS: &[&str] = &[
+    "push",
+    "push_str",
+    "push_back",
+    "push_front",
+    "pop",
+    "pop_back",
+    "pop_front",
+    "insert",
+    "remove",
+    "clear",
+    "append",
+    "extend",
+    "truncate",
+    "retain",
+    "dedup",
+    "drain",
+    "sort",
+    "sort_by",
+    "reverse",
+    "swap",
+    "set",
+];
+
+/// Something a call can do besides computing its result
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Effect {
+    /// Talks to the world outside the program: output, files, network,
+    /// environment, processes, actors, the clock or randomness
+    Io,
+    /// Reads or writes mutable state the function doesn't own, such as a
+    /// `let mut` global
+    State,
+    /// Suspends or starts concurrent work: `async`, `await`, `spawn`
+    Async,
+    /// May not return normally: panics, throws, exits or loops forever
+    Diverges,
+    /// Calls a function value or a function the analysis doesn't know
+    Unknown,
+}
+
+impl Effect {
+    /// Name of the effect in reports
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Io => "io",
+            Self::State => "state",
+            Self::Async => "async",
+            Self::Diverges => "diverges",
+            Self::Unknown => "unknown",
+        }
+    }
+
+    /// What a function with this effect does, completing "calls `f`, which ..."
+    fn description(self) -> &'static str {
+        match self {
+            Self::Io => "performs IO",
+            Self::State => "uses mutable state it doesn't own",
+            Self::Async => "is asynchronous",
+            Self::Diverges => "may not return",
+            Self::Unknown => "has unknown effects",
+        }
+    }
+}
+
+impl fmt::Display for Effect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Why a function has an effect
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EffectCause {
+    pub effect: Effect,
+    /// What the function does, such as "calls println"
+    pub reason: String,
+    /// Where it does it
+    pub span: Span,
+}
+
+impl EffectCause {
+    /// Zero-based line and column of the cause within `source`
+    #[must_use]
+    pub fn position(&self, source: &str) -> (usize, usize) {
+        line_column(source, self.span.start)
+    }
+}
+
+/// The effects of one function
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FunctionEffects {
+    pub name: String,
+    /// Span of the definition
+    pub span: Span,
+    /// Effects of a call; empty for a pure function
+    pub effects: BTreeSet<Effect>,
+    /// The first cause found for each effect
+    pub causes: Vec<EffectCause>,
+    /// Annotated `@pure`
+    pub declared_pure: bool,
+    /// Annotated `@memoize`
+    pub memoize: bool,
+}
+
+impl FunctionEffects {
+    /// Whether a call does nothing but compute its result
+    pub fn is_pure(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Whether the result depends on the arguments alone, so calls can be
+    /// cached: pure, except that the function may diverge
+    pub fn is_memoizable(&self) -> bool {
+        self.effects
+            .iter()
+            .all(|effect| *effect == Effect::Diverges)
+    }
+
+    /// The first cause found for `effect`
+    pub fn cause(&self, effect: Effect) -> Option<&EffectCause> {
+        self.causes.iter().find(|cause| cause.effect == effect)
+    }
+
+    /// Zero-based line and column of the definition within `source`
+    #[must_use]
+    pub fn position(&self, source: &str) -> (usize, usize) {
+        line_column(source, self.span.start)
+    }
+
+    /// `pure`, or the effects separated by commas
+    pub fn classification(&self) -> String {
+        if self.is_pure() {
+            return "pure".to_string();
+        }
+        let names: Vec<&str> = self.effects.iter().map(|effect| effect.as_str()).collect();
+        names.join(", ")
+    }
+}
+
+/// A function whose annotation its body contradicts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffectDiagnostic {
+    /// Stable code: `impure_pure_function` or `unmemoizable_function`
+    pub code: &'static str,
+    pub function: String,
+    /// Span of the cause
+    pub span: Span,
+    pub message: String,
+}
+
+impl EffectDiagnostic {
+    /// Zero-based line and column of the cause within `source`
+    #[must_use]
+    pub fn position(&self, source: &str) -> (usize, usize) {
+        line_column(source, self.span.start)
+    }
+}
+
+/// Effects of every named function of a program, in definition order
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct EffectReport {
+    pub functions: Vec<FunctionEffects>,
+}
+
+impl EffectReport {
+    /// The effects of the first function named `name`
+    pub fn get(&self, name: &str) -> Option<&FunctionEffects> {
+        self.functions.iter().find(|function| function.name == name)
+    }
+
+    /// Warnings for `@pure` functions with IO, state or async effects (a
+    /// pure function may still panic) and `@memoize` functions whose calls
+    /// can't be cached, in source order
+    pub fn diagnostics(&self) -> Vec<EffectDiagnostic> {
+        let observable = [Effect::Io, Effect::State, Effect::Async];
+        let mut diagnostics = Vec::new();
+        for function in &self.functions {
+            if function.declared_pure {
+                for cause in function
+                    .causes
+                    .iter()
+                    .filter(|c| observable.contains(&c.effect))
+                {
+                    diagnostics.push(EffectDiagnostic {
+                        code: "impure_pure_function",
+                        function: function.name.clone(),
+                        span: cause.span,
+                        message: format!(
+                            "`{}` is marked @pure but {}",
+                            function.name, cause.reason
+                        ),
+                    });
+                }
+            }
+            if function.memoize {
+                let blocking = function
+                    .causes
+                    .iter()
+                    .find(|c| c.effect != Effect::Diverges);
+                if let Some(cause) = blocking {
+                    diagnostics.push(EffectDiagnostic {
+                        code: "unmemoizable_function",
+                        function: function.name.clone(),
+                        span: cause.span,
+                        message: format!(
+                            "`{}` is marked @memoize but {}, so its calls are not cached",
+                            function.name, cause.reason
+                        ),
+                    });
+                }
+            }
+        }
+        diagnostics.sort_by_key(|diagnostic| diagnostic.span.start);
+        diagnostics
+    }
+}
+
+/// Infer the effects of every named function in `ast`
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::frontend::parser::Parser;
+/// use ruchy::middleend::effects::{infer_effects, Effect};
+///
+/// let source = "fun square(x) { x * x }\nfun show(x) { println(square(x)) }";
+/// let report = infer_effects(&Parser::new(source).parse().unwrap());
+/// assert!(report.get("square").unwrap().is_pure());
+/// assert!(report.get("show").unwrap().effects.contains(&Effect::Io));
+/// ```
+#[must_use]
+pub fn infer_effects(ast: &Expr) -> EffectReport {
+    let program = Program::new(ast);
+    let mut functions = Vec::new();
+    let mut calls = Vec::new();
+    for_each_expr(ast, &mut |expr: &Expr| {
+        let ExprKind::Function {
+            name,
+            params,
+            body,
+            is_async,
+            ..
+        } = &expr.kind
+        else {
+            return;
+        };
+        let mut scan = Scan::new(&program, params, body);
+        if *is_async {
+            scan.record(Effect::Async, "is declared async".to_string(), expr.span);
+        }
+        for_each_expr(body, &mut |inner: &Expr| scan.visit(inner));
+        let has = |attribute: &str| expr.attributes.iter().any(|a| a.name == attribute);
+        functions.push(FunctionEffects {
+            name: name.clone(),
+            span: expr.span,
+            effects: scan.effects,
+            causes: scan.causes,
+            declared_pure: has("pure"),
+            memoize: has("memoize"),
+        });
+        calls.push(scan.calls);
+    });
+    propagate(&mut functions, &calls);
+    EffectReport { functions }
+}
+
+/// Add the effects of callees to their callers until nothing changes (complexity: 6)
+fn propagate(functions: &mut [FunctionEffects], calls: &[Vec<(String, Span)>]) {
+    // The first definition of a name wins
+    let index: HashMap<String, usize> = functions
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(i, function)| (function.name.clone(), i))
+        .collect();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (caller, edges) in calls.iter().enumerate() {
+            for (callee, span) in edges {
+                let Some(&target) = index.get(callee) else {
+                    continue;
+                };
+                let inherited: Vec<Effect> = functions[target]
+                    .effects
+                    .difference(&functions[caller].effects)
+                    .copied()
+                    .collect();
+                for effect in inherited {
+                    functions[caller].effects.insert(effect);
+                    functions[caller].causes.push(EffectCause {
+                        effect,
+                        reason: format!("calls `{callee}`, which {}", effect.description()),
+                        span: *span,
+                    });
+                    changed = true;
+                }
+            }
+        }
+    }
+}
+
+/// Program-wide names calls and variables are resolved against
+struct Program {
+    functions: HashSet<String>,
+    types: HashSet<String>,
+    modules: HashSet<String>,
+    mutable_globals: HashSet<String>,
+    builtins: HashSet<String>,
+}
+
+impl Program {
+    fn new(ast: &Expr) -> Self {
+        let mut program = Self {
+            functions: HashSet::new(),
+            types: HashSet::new(),
+            modules: HashSet::new(),
+            mutable_globals: HashSet::new(),
+            builtins: crate::runtime::builtin_init::init_global_environment()
+                .into_keys()
+                .collect(),
+        };
+        for_each_expr(ast, &mut |expr: &Expr| match &expr.kind {
+            ExprKind::Function { name, .. } => {
+                program.functions.insert(name.clone());
+            }
+            ExprKind::Struct { name, .. }
+            | ExprKind::TupleStruct { name, .. }
+            | ExprKind::Class { name, .. }
+            | ExprKind::Enum { name, .. }
+            | ExprKind::Actor { name, .. } => {
+                program.types.insert(name.clone());
+            }
+            ExprKind::Module { name, .. } => {
+                program.modules.insert(name.clone());
+            }
+            _ => {}
+        });
+        collect_mutable_globals(ast, &mut program.mutable_globals);
+        program
+    }
+}
+
+/// Names bound by top-level `let mut`, outside any function
+fn collect_mutable_globals(expr: &Expr, globals: &mut HashSet<String>) {
+    match &expr.kind {
+        ExprKind::Block(exprs) => {
+            for expr in exprs {
+                collect_mutable_globals(expr, globals);
+            }
+        }
+        ExprKind::Let {
+            name,
+            is_mutable,
+            body,
+            ..
+        } => {
+            if *is_mutable {
+                globals.insert(name.clone());
+            }
+            collect_mutable_globals(body, globals);
+        }
+        _ => {}
+    }
+}
+
+/// Direct effects and calls of one function body
+struct Scan<'a> {
+    program: &'a Program,
+    /// Parameters and every name bound inside the body
+    locals: HashSet<String>,
+    effects: BTreeSet<Effect>,
+    causes: Vec<EffectCause>,
+    calls: Vec<(String, Span)>,
+}
+
+impl<'a> Scan<'a> {
+    fn new(program: &'a Program, params: &[Param], body: &Expr) -> Self {
+        let mut locals: HashSet<String> = params
+            .iter()
+            .flat_map(|param| extract_pattern_bindings(&param.pattern))
+            .collect();
+        for_each_expr(body, &mut |expr: &Expr| locals.extend(bound_names(expr)));
+        Self {
+            program,
+            locals,
+            effects: BTreeSet::new(),
+            causes: Vec::new(),
+            calls: Vec::new(),
+        }
+    }
+
+    fn record(&mut self, effect: Effect, reason: String, span: Span) {
+        if self.effects.insert(effect) {
+            self.causes.push(EffectCause {
+                effect,
+                reason,
+                span,
+            });
+        }
+    }
+
+    /// Record the direct effect of one expression (complexity: 10)
+    fn visit(&mut self, expr: &Expr) {
+        let span = expr.span;
+        match &expr.kind {
+            ExprKind::Call { func, .. } => self.visit_call(func, span),
+            ExprKind::MethodCall {
+                receiver, method, ..
+            }
+            | ExprKind::OptionalMethodCall {
+                receiver, method, ..
+            } if MUTATING_METHODS.contains(&method.as_str()) => {
+                if let Some(root) = self.outside_root(receiver) {
+                    let reason = format!("calls `{method}` on `{root}`");
+                    self.record(Effect::State, reason, span);
+                }
+            }
+            ExprKind::Macro { name, .. } | ExprKind::MacroInvocation { name, .. } => {
+                if let Some(effect) = builtin_effect(name) {
+                    self.record(effect, format!("calls {name}!"), span);
+                }
+            }
+            ExprKind::Assign { target, .. }
+            | ExprKind::CompoundAssign { target, .. }
+            | ExprKind::PreIncrement { target }
+            | ExprKind::PostIncrement { target }
+            | ExprKind::PreDecrement { target }
+            | ExprKind::PostDecrement { target } => {
+                if let Some(root) = self.outside_root(target) {
+                    self.record(Effect::State, format!("assigns `{root}`"), span);
+                }
+            }
+            ExprKind::Identifier(name)
+                if self.program.mutable_globals.contains(name) && !self.locals.contains(name) =>
+            {
+                let reason = format!("reads mutable global `{name}`");
+                self.record(Effect::State, reason, span);
+            }
+            ExprKind::Throw { .. } => self.record(Effect::Diverges, "throws".to_string(), span),
+            ExprKind::Loop { body, .. } if !exits(body) => {
+                let reason = "loops without `break`".to_string();
+                self.record(Effect::Diverges, reason, span);
+            }
+            ExprKind::While {
+                condition, body, ..
+            } if matches!(condition.kind, ExprKind::Literal(Literal::Bool(true)))
+                && !exits(body) =>
+            {
+                let reason = "loops without `break`".to_string();
+                self.record(Effect::Diverges, reason, span);
+            }
+            ExprKind::Await { .. } => self.record(Effect::Async, "awaits".to_string(), span),
+            ExprKind::AsyncBlock { .. } | ExprKind::AsyncLambda { .. } => {
+                self.record(Effect::Async, "starts async work".to_string(), span);
+            }
+            ExprKind::Spawn { .. } => {
+                self.record(Effect::Async, "spawns an actor".to_string(), span);
+            }
+            ExprKind::Send { .. }
+            | ExprKind::Ask { .. }
+            | ExprKind::ActorSend { .. }
+            | ExprKind::ActorQuery { .. } => {
+                self.record(Effect::Io, "messages an actor".to_string(), span);
+            }
+            ExprKind::Command { program, .. } => {
+                self.record(Effect::Io, format!("runs `{program}`"), span);
+            }
+            ExprKind::RustBlock { .. } => {
+                self.record(Effect::Unknown, "runs inline Rust".to_string(), span);
+            }
+            _ => {}
+        }
+    }
+
+    /// Record the effect of calling `func`, or the call edge to a function
+    /// of the program (complexity: 5)
+    fn visit_call(&mut self, func: &Expr, span: Span) {
+        match &func.kind {
+            ExprKind::Identifier(name) => self.call_named(name, span),
+            ExprKind::QualifiedName { module, name } => self.call_qualified(module, name, span),
+            // The lambda's body is visited where it is written
+            ExprKind::Lambda { .. } => {}
+            _ => {
+                let reason = "calls a computed function".to_string();
+                self.record(Effect::Unknown, reason, span);
+            }
+        }
+    }
+
+    /// Complexity: 5
+    fn call_named(&mut self, name: &str, span: Span) {
+        if self.locals.contains(name) {
+            let reason = format!("calls function value `{name}`");
+            self.record(Effect::Unknown, reason, span);
+        } else if self.program.functions.contains(name) {
+            self.calls.push((name.to_string(), span));
+        } else if let Some(effect) = builtin_effect(name) {
+            self.record(effect, format!("calls {name}"), span);
+        } else if !self.program.builtins.contains(name) && !self.program.types.contains(name) {
+            let reason = format!("calls unknown function `{name}`");
+            self.record(Effect::Unknown, reason, span);
+        }
+    }
+
+    /// Complexity: 6
+    fn call_qualified(&mut self, module: &str, name: &str, span: Span) {
+        let path = format!("{module}::{name}");
+        let root = module
+            .split("::")
+            .find(|segment| *segment != "std")
+            .unwrap_or(module);
+        if let Some(effect) = builtin_effect(&path) {
+            self.record(effect, format!("calls {path}"), span);
+        } else if IO_MODULES.contains(&root) {
+            self.record(Effect::Io, format!("calls {path}"), span);
+        } else if self.program.modules.contains(module) && self.program.functions.contains(name) {
+            self.calls.push((name.to_string(), span));
+        } else if !self.program.builtins.contains(&path) && !module.starts_with(char::is_uppercase)
+        {
+            self.record(Effect::Unknown, format!("calls `{path}`"), span);
+        }
+    }
+
+    /// The variable at the root of a place, if the function doesn't own it
+    fn outside_root<'e>(&self, place: &'e Expr) -> Option<&'e str> {
+        match &place.kind {
+            ExprKind::Identifier(name) => (!self.locals.contains(name)).then_some(name.as_str()),
+            ExprKind::FieldAccess { object, .. } | ExprKind::IndexAccess { object, .. } => {
+                self.outside_root(object)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Effect of calling builtin `name`, for builtins that have one
+fn builtin_effect(name: &str) -> Option<Effect> {
+    match name {
+        "panic" | "exit" | "assert" | "assert_eq" | "assert_ne" | "unreachable" | "todo"
+        | "unimplemented" => Some(Effect::Diverges),
+        "print" | "println" | "eprint" | "eprintln" | "dbg" | "input" | "readline" | "emit"
+        | "io::records" | "random" | "random_int" | "timestamp" | "get_time_ms" => Some(Effect::Io),
+        _ if name.starts_with("log_") || name.starts_with("time_") => Some(Effect::Io),
+        _ => denied_capability(name).map(|_| Effect::Io),
+    }
+}
+
+/// Names an expression binds for the code inside it
+fn bound_names(expr: &Expr) -> Vec<String> {
+    match &expr.kind {
+        ExprKind::Let { name, .. } => vec![name.clone()],
+        ExprKind::LetPattern { pattern, .. }
+        | ExprKind::IfLet { pattern, .. }
+        | ExprKind::WhileLet { pattern, .. } => extract_pattern_bindings(pattern),
+        ExprKind::For { var, pattern, .. } => pattern
+            .as_ref()
+            .map_or_else(|| vec![var.clone()], extract_pattern_bindings),
+        ExprKind::Function { params, .. } | ExprKind::Lambda { params, .. } => params
+            .iter()
+            .flat_map(|param| extract_pattern_bindings(&param.pattern))
+            .collect(),
+        ExprKind::AsyncLambda { params, .. } => params.clone(),
+        ExprKind::Match { arms, .. } => arms
+            .iter()
+            .flat_map(|arm| extract_pattern_bindings(&arm.pattern))
+            .collect(),
+        ExprKind::TryCatch { catch_clauses, .. } => catch_clauses
+            .iter()
+            .flat_map(|clause| extract_pattern_bindings(&clause.pattern))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Whether a loop body contains a `break` or `return`
+fn exits(body: &Expr) -> bool {
+    let mut found = false;
+    for_each_expr(body, &mut |expr: &Expr| {
+        found |= matches!(expr.kind, ExprKind::Break { .. } | ExprKind::Return { .. });
+    });
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::Parser;
+
+    fn infer(source: &str) -> EffectReport {
+        infer_effects(&Parser::new(source).parse().expect("should parse"))
+    }
+
+    fn effects(report: &EffectReport, name: &str) -> Vec<Effect> {
+        let function = report.get(name).expect("function should be found");
+        function.effects.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_arithmetic_and_recursion_are_pure() {
+        let report = infer(
+            "fun fib(n: i64) -> i64 { if n < 2 { n } else { fib(n - 1) + fib(n - 2) } }\nfun area(w, h) { let a = w * h\n a }",
+        );
+        assert!(report.get("fib").unwrap().is_pure());
+        assert!(report.get("area").unwrap().is_pure());
+        assert_eq!(report.get("fib").unwrap().classification(), "pure");
+    }
+
+    #[test]
+    fn test_effects_flow_from_callees_to_callers() {
+        let report = infer("fun note(msg) { println(msg) }\nfun work(x) { note(x)\n x * 2 }\nfun top() { work(1) }");
+        assert_eq!(effects(&report, "note"), [Effect::Io]);
+        assert_eq!(effects(&report, "top"), [Effect::Io]);
+        let cause = report.get("work").unwrap().cause(Effect::Io).unwrap();
+        assert_eq!(cause.reason, "calls `note`, which performs IO");
+    }
+
+    #[test]
+    fn test_globals_async_and_divergence_are_classified() {
+        let report = infer(
+            "let mut count = 0\nfun bump() { count += 1 }\nfun peek() { count }\nasync fun fetch() { 1 }\nfun fail(x) { panic(\"no\") }\nfun spin() { loop { } }",
+        );
+        assert_eq!(effects(&report, "bump"), [Effect::State]);
+        assert_eq!(effects(&report, "peek"), [Effect::State]);
+        assert_eq!(effects(&report, "fetch"), [Effect::Async]);
+        assert_eq!(effects(&report, "fail"), [Effect::Diverges]);
+        assert_eq!(effects(&report, "spin"), [Effect::Diverges]);
+        assert!(report.get("fail").unwrap().is_memoizable());
+    }
+
+    #[test]
+    fn test_local_mutation_stays_pure_and_function_values_are_unknown() {
+        let report = infer(
+            "fun total(xs) { let mut sum = 0\n for x in xs { sum += x }\n sum }\nfun apply(f, x) { f(x) }",
+        );
+        assert!(report.get("total").unwrap().is_pure());
+        assert_eq!(effects(&report, "apply"), [Effect::Unknown]);
+    }
+
+    #[test]
+    fn test_annotations_contradicted_by_effects_are_reported() {
+        let source = "@pure\nfun shout(s) { println(s) }\n@memoize\nfun cached(n) { n * 2 }\n@memoize\nfun noisy(n) { print(n) }";
+        let diagnostics = infer(source).diagnostics();
+        let messages: Vec<&str> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "`shout` is marked @pure but calls println",
+                "`noisy` is marked @memoize but calls print, so its calls are not cached",
+            ]
+        );
+        assert_eq!(diagnostics[0].position(source).0, 1);
+    }
+}
@@ -266,6 +266,8 @@ impl InferenceContext {
     fn infer_literal(lit: &Literal) -> MonoType {
         match lit {
             Literal::Integer(_, _) => MonoType::Int,
+            Literal::BigInt(_) => MonoType::Int, // Arbitrary precision is a runtime detail
+            Literal::Decimal(_) => MonoType::Named("Decimal".to_string()),
             Literal::Float(_) => MonoType::Float,
             Literal::String(_) => MonoType::String,
             Literal::Bool(_) => MonoType::Bool,
@@ -1059,6 +1061,21 @@ impl InferenceContext {
                 // Mutability is a runtime concern, not a type concern
                 self.infer_pattern(inner, expected_ty)
             }
+            Pattern::StringAffix { rest, .. } => {
+                // String patterns match strings, and the rest is the remaining string
+                self.unifier.unify(expected_ty, &MonoType::String)?;
+                self.infer_pattern(rest, &MonoType::String)
+            }
+            Pattern::Regex(regex) => {
+                // Named capture groups bind strings (unset optional groups are nil)
+                self.unifier.unify(expected_ty, &MonoType::String)?;
+                if let Ok(regex) = regex::Regex::new(regex) {
+                    for name in regex.capture_names().flatten() {
+                        self.env = self.env.extend(name, TypeScheme::mono(MonoType::String));
+                    }
+                }
+                Ok(())
+            }
         }
     }
     fn infer_for(&mut self, var: &str, iter: &Expr, body: &Expr) -> Result<MonoType> {
@@ -1588,6 +1605,11 @@ impl InferenceContext {
             ExprKind::Module { body, .. } => self.infer_expr(body),
             ExprKind::DataFrame { columns } => self.infer_dataframe(columns),
             ExprKind::Command { .. } => Ok(MonoType::String),
+            // The deferred expression runs later; `defer` itself yields unit
+            ExprKind::Defer { expr } => {
+                self.infer_expr(expr)?;
+                Ok(MonoType::Unit)
+            }
             ExprKind::Macro { name, args } => self.infer_macro(name, args),
             ExprKind::DataFrameOperation { source, operation } => {
                 self.infer_dataframe_operation(source, operation)
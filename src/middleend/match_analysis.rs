@@ -0,0 +1,555 @@
+//! Exhaustiveness and reachability analysis for `match` expressions
+//!
+//! Matches over `bool`, `Option`, `Result` and enums declared in the same
+//! program are checked for missing constructors; every match is checked for
+//! arms that can never be reached because earlier unguarded arms already
+//! cover them. Results are warnings: the interpreter still raises a runtime
+//! error when no arm matches.
+use crate::frontend::ast::{
+    ComprehensionClause, EnumVariant, EnumVariantKind, Expr, ExprKind, Literal, MatchArm,
+    ObjectField, Pattern, Span, StringPart,
+};
+use std::collections::{HashMap, HashSet};
+
+/// What a match diagnostic reports
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatchDiagnosticKind {
+    /// Some constructors of the scrutinee type are not handled by any arm
+    NonExhaustive {
+        /// Constructors left unhandled, rendered as patterns (e.g. `Shape::Square(_)`)
+        missing: Vec<String>,
+    },
+    /// The arm is shadowed by earlier arms and never runs
+    UnreachableArm,
+}
+
+/// A warning produced by [`analyze_matches`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchDiagnostic {
+    pub kind: MatchDiagnosticKind,
+    /// Span of the whole `match` for non-exhaustive matches, of the arm otherwise
+    pub span: Span,
+    pub message: String,
+}
+
+impl MatchDiagnostic {
+    /// Stable diagnostic code for CLI and LSP output
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self.kind {
+            MatchDiagnosticKind::NonExhaustive { .. } => "non_exhaustive_match",
+            MatchDiagnosticKind::UnreachableArm => "unreachable_match_arm",
+        }
+    }
+
+    /// Zero-based line and column of the diagnostic start within `source`
+    #[must_use]
+    pub fn position(&self, source: &str) -> (usize, usize) {
+        line_column(source, self.span.start)
+    }
+
+    /// Zero-based line and column of the diagnostic end within `source`
+    #[must_use]
+    pub fn end_position(&self, source: &str) -> (usize, usize) {
+        line_column(source, self.span.end)
+    }
+}
+
+pub(crate) fn line_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = source.get(..offset.min(source.len())).unwrap_or(source);
+    let line = before.matches('\n').count();
+    let column = before.rfind('\n').map_or(before.len(), |nl| before.len() - nl - 1);
+    (line, column)
+}
+
+/// Check every `match` in `ast`, returning warnings in source order
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::frontend::parser::Parser;
+/// use ruchy::middleend::match_analysis::analyze_matches;
+///
+/// let ast = Parser::new("match flag { true => 1 }").parse().unwrap();
+/// let diagnostics = analyze_matches(&ast);
+/// assert_eq!(diagnostics[0].message, "non-exhaustive match: missing false");
+/// ```
+#[must_use]
+pub fn analyze_matches(ast: &Expr) -> Vec<MatchDiagnostic> {
+    let mut analyzer = MatchAnalyzer::default();
+    for_each_expr(ast, &mut |expr: &Expr| {
+        if let ExprKind::Enum { name, variants, .. } = &expr.kind {
+            analyzer.enums.insert(name.clone(), variant_shapes(variants));
+        }
+    });
+    for_each_expr(ast, &mut |expr: &Expr| {
+        if let ExprKind::Match { arms, .. } = &expr.kind {
+            analyzer.check_match(expr.span, arms);
+        }
+    });
+    analyzer.diagnostics.sort_by_key(|d| d.span.start);
+    analyzer.diagnostics
+}
+
+/// A constructor of a matchable type and how a missing one is displayed
+#[derive(Debug, Clone)]
+struct VariantInfo {
+    name: String,
+    display: String,
+}
+
+#[derive(Default)]
+struct MatchAnalyzer {
+    enums: HashMap<String, Vec<VariantInfo>>,
+    diagnostics: Vec<MatchDiagnostic>,
+}
+
+/// Constructors an arm can match, and the subset it matches completely
+#[derive(Default)]
+struct ArmCoverage {
+    touched: HashSet<String>,
+    complete: HashSet<String>,
+}
+
+impl MatchAnalyzer {
+    fn check_match(&mut self, span: Span, arms: &[MatchArm]) {
+        let constructors = arms
+            .iter()
+            .find_map(|arm| self.scrutinee_constructors(&arm.pattern));
+        let mut covered: HashSet<String> = HashSet::new();
+        let mut all_covered = false;
+        for arm in arms {
+            let coverage = arm_coverage(&arm.pattern);
+            let shadowed = !coverage.touched.is_empty()
+                && coverage.touched.iter().all(|c| covered.contains(c));
+            if all_covered || shadowed {
+                self.diagnostics.push(MatchDiagnostic {
+                    kind: MatchDiagnosticKind::UnreachableArm,
+                    span: arm.span,
+                    message: "unreachable match arm: earlier arms already cover it".to_string(),
+                });
+            }
+            if arm.guard.is_some() {
+                continue;
+            }
+            all_covered |= arm.pattern.is_irrefutable();
+            covered.extend(coverage.complete);
+            if let Some(constructors) = &constructors {
+                all_covered |= constructors.iter().all(|c| covered.contains(&c.name));
+            }
+        }
+        let Some(constructors) = constructors else {
+            return;
+        };
+        if all_covered {
+            return;
+        }
+        let missing: Vec<String> = constructors
+            .iter()
+            .filter(|c| !covered.contains(&c.name))
+            .map(|c| c.display.clone())
+            .collect();
+        self.diagnostics.push(MatchDiagnostic {
+            message: format!("non-exhaustive match: missing {}", missing.join(", ")),
+            kind: MatchDiagnosticKind::NonExhaustive { missing },
+            span,
+        });
+    }
+
+    /// Infer the scrutinee's constructors from the first arm that names one
+    fn scrutinee_constructors(&self, pattern: &Pattern) -> Option<Vec<VariantInfo>> {
+        if let Pattern::Or(patterns) = pattern {
+            return patterns
+                .iter()
+                .find_map(|p| self.scrutinee_constructors(p));
+        }
+        if let Some(path) = pattern.constructor_path() {
+            return self.resolve_enum(&path);
+        }
+        match pattern {
+            Pattern::Literal(Literal::Bool(_)) => Some(bool_constructors()),
+            Pattern::AtBinding { pattern, .. } | Pattern::Mut(pattern) => {
+                self.scrutinee_constructors(pattern)
+            }
+            _ => None,
+        }
+    }
+
+    /// Look up the enum a variant path belongs to
+    ///
+    /// `Enum::Variant` names its owner directly; a bare `Variant` resolves
+    /// only when exactly one known enum declares it.
+    fn resolve_enum(&self, path: &[String]) -> Option<Vec<VariantInfo>> {
+        let lookup = |name: &str| match name {
+            "Option" => Some(option_constructors()),
+            "Result" => Some(result_constructors()),
+            _ => self.enums.get(name).cloned(),
+        };
+        match path {
+            [.., owner, _] => lookup(owner.as_str()),
+            [variant] => {
+                let owners: Vec<&str> = ["Option", "Result"]
+                    .into_iter()
+                    .chain(self.enums.keys().map(String::as_str))
+                    .filter(|&owner| {
+                        lookup(owner).is_some_and(|cs| cs.iter().any(|c| &c.name == variant))
+                    })
+                    .collect();
+                match owners.as_slice() {
+                    [owner] => lookup(*owner),
+                    _ => None,
+                }
+            }
+            [] => None,
+        }
+    }
+}
+
+fn arm_coverage(pattern: &Pattern) -> ArmCoverage {
+    let mut coverage = ArmCoverage::default();
+    collect_coverage(pattern, &mut coverage);
+    coverage
+}
+
+fn collect_coverage(pattern: &Pattern, coverage: &mut ArmCoverage) {
+    let complete = match pattern {
+        Pattern::Or(patterns) => {
+            for p in patterns {
+                collect_coverage(p, coverage);
+            }
+            return;
+        }
+        Pattern::AtBinding { pattern, .. }
+        | Pattern::Mut(pattern)
+        | Pattern::WithDefault { pattern, .. } => {
+            collect_coverage(pattern, coverage);
+            return;
+        }
+        Pattern::Literal(Literal::Bool(b)) => {
+            coverage.touched.insert(b.to_string());
+            coverage.complete.insert(b.to_string());
+            return;
+        }
+        Pattern::Some(inner) | Pattern::Ok(inner) | Pattern::Err(inner) => inner.is_irrefutable(),
+        Pattern::None | Pattern::QualifiedName(_) => true,
+        Pattern::TupleVariant { patterns, .. } => patterns.iter().all(Pattern::is_irrefutable),
+        Pattern::Struct { fields, .. } => fields.iter().all(|f| match &f.pattern {
+            Some(pattern) => pattern.is_irrefutable(),
+            None => true,
+        }),
+        _ => return,
+    };
+    if let Some(name) = pattern.constructor_path().and_then(|mut path| path.pop()) {
+        coverage.touched.insert(name.clone());
+        if complete {
+            coverage.complete.insert(name);
+        }
+    }
+}
+
+fn variant_shapes(variants: &[EnumVariant]) -> Vec<VariantInfo> {
+    variants
+        .iter()
+        .map(|variant| {
+            let payload = match &variant.kind {
+                EnumVariantKind::Unit => String::new(),
+                EnumVariantKind::Tuple(types) => {
+                    format!("({})", vec!["_"; types.len()].join(", "))
+                }
+                EnumVariantKind::Struct(_) => " { .. }".to_string(),
+            };
+            VariantInfo {
+                name: variant.name.clone(),
+                display: format!("{}{payload}", variant.name),
+            }
+        })
+        .collect()
+}
+
+fn builtin(constructors: &[(&str, &str)]) -> Vec<VariantInfo> {
+    constructors
+        .iter()
+        .map(|(name, display)| VariantInfo {
+            name: (*name).to_string(),
+            display: (*display).to_string(),
+        })
+        .collect()
+}
+
+fn bool_constructors() -> Vec<VariantInfo> {
+    builtin(&[("true", "true"), ("false", "false")])
+}
+
+fn option_constructors() -> Vec<VariantInfo> {
+    builtin(&[("Some", "Some(_)"), ("None", "None")])
+}
+
+fn result_constructors() -> Vec<VariantInfo> {
+    builtin(&[("Ok", "Ok(_)"), ("Err", "Err(_)")])
+}
+
+/// Visit `expr` and every expression nested inside it, parents first
+pub(crate) fn for_each_expr(expr: &Expr, visit: &mut dyn FnMut(&Expr)) {
+    visit(expr);
+    let mut recurse = |child: &Expr| for_each_expr(child, &mut *visit);
+    match &expr.kind {
+        ExprKind::Block(exprs)
+        | ExprKind::List(exprs)
+        | ExprKind::Set(exprs)
+        | ExprKind::Tuple(exprs) => exprs.iter().for_each(recurse),
+        ExprKind::Call { func, args } => {
+            recurse(func);
+            args.iter().for_each(recurse);
+        }
+        ExprKind::MethodCall { receiver, args, .. }
+        | ExprKind::OptionalMethodCall { receiver, args, .. } => {
+            recurse(receiver);
+            args.iter().for_each(recurse);
+        }
+        ExprKind::Macro { args, .. } | ExprKind::MacroInvocation { args, .. } => {
+            args.iter().for_each(recurse);
+        }
+        ExprKind::Match { expr, arms } => {
+            recurse(expr);
+            for arm in arms {
+                if let Some(guard) = &arm.guard {
+                    recurse(guard);
+                }
+                recurse(&arm.body);
+            }
+        }
+        ExprKind::If {
+            condition,
+            then_branch,
+            else_branch,
+        } => {
+            recurse(condition);
+            recurse(then_branch);
+            if let Some(else_branch) = else_branch {
+                recurse(else_branch);
+            }
+        }
+        ExprKind::IfLet {
+            expr,
+            then_branch,
+            else_branch,
+            ..
+        } => {
+            recurse(expr);
+            recurse(then_branch);
+            if let Some(else_branch) = else_branch {
+                recurse(else_branch);
+            }
+        }
+        ExprKind::Let {
+            value,
+            body,
+            else_block,
+            ..
+        }
+        | ExprKind::LetPattern {
+            value,
+            body,
+            else_block,
+            ..
+        } => {
+            recurse(value);
+            recurse(body);
+            if let Some(else_block) = else_block {
+                recurse(else_block);
+            }
+        }
+        ExprKind::Binary { left, right, .. } => {
+            recurse(left);
+            recurse(right);
+        }
+        ExprKind::Assign { target, value, .. } | ExprKind::CompoundAssign { target, value, .. } => {
+            recurse(target);
+            recurse(value);
+        }
+        ExprKind::While {
+            condition, body, ..
+        } => {
+            recurse(condition);
+            recurse(body);
+        }
+        ExprKind::For { iter, body, .. } => {
+            recurse(iter);
+            recurse(body);
+        }
+        ExprKind::WhileLet { expr, body, .. } => {
+            recurse(expr);
+            recurse(body);
+        }
+        ExprKind::TryCatch {
+            try_block,
+            catch_clauses,
+            finally_block,
+        } => {
+            recurse(try_block);
+            for clause in catch_clauses {
+                recurse(&clause.body);
+            }
+            if let Some(finally_block) = finally_block {
+                recurse(finally_block);
+            }
+        }
+        ExprKind::Function { body, .. }
+        | ExprKind::Lambda { body, .. }
+        | ExprKind::AsyncLambda { body, .. }
+        | ExprKind::AsyncBlock { body, .. }
+        | ExprKind::Loop { body, .. }
+        | ExprKind::Module { body, .. } => recurse(body),
+        ExprKind::Unary { operand: inner, .. }
+        | ExprKind::Throw { expr: inner }
+        | ExprKind::Ok { value: inner }
+        | ExprKind::Err { error: inner }
+        | ExprKind::Some { value: inner }
+        | ExprKind::Try { expr: inner }
+        | ExprKind::Await { expr: inner }
+        | ExprKind::Lazy { expr: inner }
+        | ExprKind::Defer { expr: inner }
+        | ExprKind::Spawn { actor: inner }
+        | ExprKind::PreIncrement { target: inner }
+        | ExprKind::PostIncrement { target: inner }
+        | ExprKind::PreDecrement { target: inner }
+        | ExprKind::PostDecrement { target: inner }
+        | ExprKind::Spread { expr: inner }
+        | ExprKind::Export { expr: inner, .. }
+        | ExprKind::ExportDefault { expr: inner }
+        | ExprKind::TypeCast { expr: inner, .. }
+        | ExprKind::FieldAccess { object: inner, .. }
+        | ExprKind::OptionalFieldAccess { object: inner, .. } => recurse(inner),
+        ExprKind::IndexAccess { object, index }
+        | ExprKind::OptionalIndexAccess { object, index } => {
+            recurse(object);
+            recurse(index);
+        }
+        ExprKind::Range { start, end, .. } => {
+            recurse(start);
+            recurse(end);
+        }
+        ExprKind::Slice { object, start, end } => {
+            recurse(object);
+            start.iter().chain(end).for_each(|bound| recurse(bound));
+        }
+        ExprKind::ArrayInit { value, size: count } | ExprKind::VecRepeat { value, count } => {
+            recurse(value);
+            recurse(count);
+        }
+        ExprKind::ListComprehension { element, clauses }
+        | ExprKind::SetComprehension { element, clauses } => {
+            recurse(element);
+            for_each_clause(clauses, &mut recurse);
+        }
+        ExprKind::DictComprehension {
+            key,
+            value,
+            clauses,
+        } => {
+            recurse(key);
+            recurse(value);
+            for_each_clause(clauses, &mut recurse);
+        }
+        ExprKind::Ternary {
+            condition,
+            true_expr,
+            false_expr,
+        } => {
+            recurse(condition);
+            recurse(true_expr);
+            recurse(false_expr);
+        }
+        ExprKind::StringInterpolation { parts } => {
+            for part in parts {
+                match part {
+                    StringPart::Expr(inner) | StringPart::ExprWithFormat { expr: inner, .. } => {
+                        recurse(inner);
+                    }
+                    StringPart::Text(_) => {}
+                }
+            }
+        }
+        ExprKind::Pipeline { expr, stages } => {
+            recurse(expr);
+            for stage in stages {
+                recurse(&stage.op);
+            }
+        }
+        ExprKind::Send { actor, message }
+        | ExprKind::ActorSend { actor, message }
+        | ExprKind::ActorQuery { actor, message }
+        | ExprKind::Ask { actor, message, .. } => {
+            recurse(actor);
+            recurse(message);
+        }
+        ExprKind::ObjectLiteral { fields } => {
+            for field in fields {
+                match field {
+                    ObjectField::KeyValue { value, .. } => recurse(value),
+                    ObjectField::Spread { expr } => recurse(expr),
+                }
+            }
+        }
+        ExprKind::Return { value } | ExprKind::Break { value, .. } | ExprKind::Yield { value } => {
+            if let Some(value) = value {
+                recurse(value);
+            }
+        }
+        ExprKind::StructLiteral { fields, base, .. } => {
+            for (_, value) in fields {
+                recurse(value);
+            }
+            if let Some(base) = base {
+                recurse(base);
+            }
+        }
+        ExprKind::Struct { methods, .. } => {
+            for method in methods {
+                recurse(&method.body);
+            }
+        }
+        ExprKind::Class {
+            constructors,
+            methods,
+            ..
+        } => {
+            for constructor in constructors {
+                recurse(&constructor.body);
+            }
+            for method in methods {
+                recurse(&method.body);
+            }
+        }
+        ExprKind::Impl { methods, .. } | ExprKind::Extension { methods, .. } => {
+            for method in methods {
+                recurse(&method.body);
+            }
+        }
+        ExprKind::Trait { methods, .. } => {
+            for body in methods.iter().filter_map(|method| method.body.as_deref()) {
+                recurse(body);
+            }
+        }
+        ExprKind::Actor { handlers, .. } => {
+            for handler in handlers {
+                recurse(&handler.body);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Visit the iterables and conditions of comprehension clauses
+fn for_each_clause(clauses: &[ComprehensionClause], recurse: &mut dyn FnMut(&Expr)) {
+    for clause in clauses {
+        recurse(&clause.iterable);
+        if let Some(condition) = &clause.condition {
+            recurse(condition);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "match_analysis_tests.rs"]
+mod tests;
@@ -0,0 +1,95 @@
+use super::*;
+use crate::frontend::parser::Parser;
+
+fn analyze(code: &str) -> Vec<MatchDiagnostic> {
+    let mut parser = Parser::new(code);
+    analyze_matches(&parser.parse().expect("should parse"))
+}
+
+fn missing(diagnostics: &[MatchDiagnostic]) -> Vec<String> {
+    diagnostics
+        .iter()
+        .find_map(|d| match &d.kind {
+            MatchDiagnosticKind::NonExhaustive { missing } => Some(missing.clone()),
+            MatchDiagnosticKind::UnreachableArm => None,
+        })
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_exhaustive_user_enum_has_no_diagnostics() {
+    let diagnostics = analyze(
+        "enum Shape { Circle { r: i32 }, Square(i32), Empty }
+         match s { Shape::Circle { r } => r, Shape::Square(n) => n, Shape::Empty => 0 }",
+    );
+    assert!(diagnostics.is_empty(), "{diagnostics:?}");
+}
+
+#[test]
+fn test_missing_user_enum_variants_are_listed() {
+    let diagnostics = analyze(
+        "enum Shape { Circle { r: i32 }, Square(i32), Empty }
+         match s { Shape::Empty => 0 }",
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code(), "non_exhaustive_match");
+    assert_eq!(missing(&diagnostics), vec!["Circle { .. }", "Square(_)"]);
+}
+
+#[test]
+fn test_refutable_payload_does_not_cover_variant() {
+    let diagnostics = analyze("match opt { Some(0) => 1, None => 0 }");
+    assert_eq!(missing(&diagnostics), vec!["Some(_)"]);
+}
+
+#[test]
+fn test_guarded_arm_does_not_cover_variant() {
+    let diagnostics = analyze("match r { Ok(v) if v > 0 => v, Err(e) => 0 }");
+    assert_eq!(missing(&diagnostics), vec!["Ok(_)"]);
+}
+
+#[test]
+fn test_bool_match_exhaustiveness() {
+    assert!(analyze("match b { true => 1, false => 0 }").is_empty());
+    assert_eq!(
+        missing(&analyze("match b { false => 0 }")),
+        vec!["true".to_string()]
+    );
+}
+
+#[test]
+fn test_wildcard_makes_match_exhaustive() {
+    assert!(analyze("match opt { Some(1) => 1, _ => 0 }").is_empty());
+}
+
+#[test]
+fn test_arm_after_catch_all_is_unreachable() {
+    let diagnostics = analyze("match x { _ => 0, 1 => 1 }");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, MatchDiagnosticKind::UnreachableArm);
+}
+
+#[test]
+fn test_arm_shadowed_by_earlier_variant_is_unreachable() {
+    let diagnostics = analyze("match opt { Some(x) => x, None => 0, Some(1) => 1 }");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code(), "unreachable_match_arm");
+}
+
+#[test]
+fn test_integer_match_without_catch_all_is_not_reported() {
+    assert!(analyze("match n { 0 => \"zero\", 1 => \"one\" }").is_empty());
+}
+
+#[test]
+fn test_nested_matches_are_analyzed() {
+    let diagnostics = analyze("fun f(b) { if b { match b { true => 1 } } else { 0 } }");
+    assert_eq!(missing(&diagnostics), vec!["false"]);
+}
+
+#[test]
+fn test_diagnostic_position() {
+    let source = "let x = 1\nmatch b { true => 1 }";
+    let diagnostics = analyze(source);
+    assert_eq!(diagnostics[0].position(source), (1, 0));
+}
@@ -0,0 +1,99 @@
+//! Static check for unknown methods on literal receivers
+//!
+//! A method call whose receiver is a literal (`[1, 2].push(3)`, `"hi".lenght()`)
+//! has a type known before the program runs. Calls of methods that type does
+//! not have are reported with the same [`MethodNotFound`] errors the
+//! interpreter raises, so `ruchy check` and `ruchy transpile` catch them
+//! without running the program.
+use crate::frontend::ast::{Expr, ExprKind, Literal};
+use crate::middleend::match_analysis::for_each_expr;
+use crate::runtime::method_not_found::{base_method, builtin_methods, MethodNotFound};
+
+/// Find calls of unknown methods on literal receivers, in source order
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::frontend::parser::Parser;
+/// use ruchy::middleend::method_analysis::analyze_method_calls;
+///
+/// let ast = Parser::new("[1, 2].fitler(|x| x > 1)").parse().unwrap();
+/// let errors = analyze_method_calls(&ast);
+/// assert_eq!(errors[0].suggestions, vec!["filter"]);
+/// ```
+#[must_use]
+pub fn analyze_method_calls(ast: &Expr) -> Vec<MethodNotFound> {
+    let mut errors = Vec::new();
+    for_each_expr(ast, &mut |expr: &Expr| {
+        let ExprKind::MethodCall {
+            receiver, method, ..
+        } = &expr.kind
+        else {
+            return;
+        };
+        let Some(type_name) = literal_type(receiver) else {
+            return;
+        };
+        let Some(candidates) = builtin_methods(type_name) else {
+            return;
+        };
+        let method = base_method(method);
+        if !candidates.contains(&method) {
+            let mut error = MethodNotFound::new(type_name, method, &candidates);
+            error.span = Some(expr.span);
+            errors.push(error);
+        }
+    });
+    errors.sort_by_key(|error| error.span.map(|span| span.start));
+    errors
+}
+
+/// Runtime type name of a literal expression, as [`crate::runtime::Value::type_name`] spells it
+fn literal_type(expr: &Expr) -> Option<&'static str> {
+    match &expr.kind {
+        ExprKind::Literal(Literal::String(_)) | ExprKind::StringInterpolation { .. } => {
+            Some("string")
+        }
+        ExprKind::Literal(Literal::Integer(_, None)) => Some("integer"),
+        ExprKind::Literal(Literal::Float(_)) => Some("float"),
+        ExprKind::Literal(Literal::Bool(_)) => Some("boolean"),
+        ExprKind::List(_) => Some("array"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::Parser;
+
+    fn analyze(source: &str) -> Vec<MethodNotFound> {
+        let ast = Parser::new(source).parse().expect("should parse");
+        analyze_method_calls(&ast)
+    }
+
+    #[test]
+    fn test_reports_unknown_method_on_array_literal() {
+        let source = "let xs = [1, 2].psuh(3)\nxs";
+        let errors = analyze(source);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].receiver_type, "array");
+        assert_eq!(errors[0].suggestions, vec!["push"]);
+        assert_eq!(errors[0].position(source), Some((1, 17)));
+    }
+
+    #[test]
+    fn test_known_methods_and_unknown_receivers_pass() {
+        assert!(analyze("\"a b\".split(\" \").len()").is_empty());
+        assert!(analyze("[3, 1].sort()\n[1].first()").is_empty());
+        assert!(analyze("fun f(x) { x.whatever() }").is_empty());
+    }
+
+    #[test]
+    fn test_reports_calls_nested_in_functions() {
+        let errors = analyze("fun f() {\n  \"hi\".to_uper()\n}");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].receiver_type, "string");
+        assert_eq!(errors[0].method, "to_uper");
+    }
+}
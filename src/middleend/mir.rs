@@ -1,10 +1,15 @@
 //! Middle Intermediate Representation (MIR) module
+//!
+//! A typed, flattened IR lowered from the AST. Only the opt-in
+//! `ruchy transpile --via-mir` backend ([`crate::backend::mir_codegen`])
+//! consumes it so far; the default transpiler, wasm and bytecode backends
+//! still work from the AST.
 mod builder;
 mod lower;
 mod optimize;
 mod types;
 pub use builder::MirBuilder;
-pub use lower::LoweringContext;
+pub use lower::{LoweringContext, INTRINSICS};
 pub use optimize::{
     optimize_function, optimize_program, CommonSubexpressionElimination, ConstantPropagation,
     DeadCodeElimination,
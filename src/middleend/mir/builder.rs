@@ -113,6 +113,30 @@ impl MirBuilder {
     pub fn get_local(&self, name: &str) -> Option<Local> {
         self.local_map.get(name).copied()
     }
+    /// The type a local of the current function was declared with
+    #[must_use]
+    pub fn local_type(&self, local: Local) -> Option<&Type> {
+        self.current_function
+            .as_ref()
+            .and_then(|func| func.locals.iter().find(|decl| decl.id == local))
+            .map(|decl| &decl.ty)
+    }
+    /// The names in scope, to restore with [`Self::restore_bindings`] when
+    /// a block ends
+    #[must_use]
+    pub fn bindings(&self) -> HashMap<String, Local> {
+        self.local_map.clone()
+    }
+    /// Put back the names in scope saved by [`Self::bindings`]
+    pub fn restore_bindings(&mut self, bindings: HashMap<String, Local>) {
+        self.local_map = bindings;
+    }
+    /// Set the return type of the current function once its body is lowered
+    pub fn set_return_type(&mut self, ty: Type) {
+        if let Some(ref mut func) = self.current_function {
+            func.return_ty = ty;
+        }
+    }
     /// Create a new basic block
     /// # Examples
     ///
@@ -1,21 +1,50 @@
 //! AST to MIR lowering
+//!
+//! [`LoweringContext`] turns a parsed program into the typed, flattened
+//! [`Program`] that backends consume (see `crate::backend::mir_codegen`):
+//!
+//! - Every intermediate value gets a local with an explicit type, taken from
+//!   annotations, literals and the operands it is computed from.
+//! - `if`, `while`, `loop`, `&&`, `||`, `break`, `continue` and `return`
+//!   become basic blocks joined by terminators; `for` over a range is
+//!   desugared into a counter stepped until it reaches the end.
+//! - Top-level statements become `main`, next to the program's functions.
+//!
+//! Constructs without a lowering yet are errors rather than being dropped,
+//! so a backend never runs a program with parts missing.
 use super::builder::MirBuilder;
 use super::types::{
-    BinOp, BlockId, Constant, Mutability, Operand, Place, Program, Rvalue, Type, UnOp,
+    BinOp, BlockId, CastKind, Constant, Function, Local, Mutability, Operand, Place, Program,
+    Rvalue, Type, UnOp,
 };
 use crate::frontend::ast::{
-    BinaryOp as AstBinOp, Expr, ExprKind, Literal, Param, Type as AstType, UnaryOp as AstUnOp,
+    BinaryOp as AstBinOp, Expr, ExprKind, Literal, Param, Pattern, Type as AstType,
+    UnaryOp as AstUnOp,
 };
-use anyhow::{anyhow, Result};
-use std::collections::HashMap;
+use anyhow::{anyhow, bail, Result};
+use std::collections::{HashMap, HashSet};
+/// Builtins lowered to calls by name; backends provide them
+pub const INTRINSICS: &[&str] = &["print", "println"];
+/// Where `break` and `continue` jump inside a loop
+struct LoopTargets {
+    label: Option<String>,
+    break_to: BlockId,
+    continue_to: BlockId,
+}
 /// Context for lowering AST to MIR
 pub struct LoweringContext {
     /// MIR builder
     builder: MirBuilder,
-    /// Type environment for expressions
-    type_env: HashMap<String, Type>,
+    /// Return types of the functions declared or lowered so far
+    signatures: HashMap<String, Type>,
     /// Current block being built
     current_block: Option<BlockId>,
+    /// Blocks only reached after a `return`, `break` or `continue`
+    dead_blocks: HashSet<BlockId>,
+    /// Enclosing loops, innermost last
+    loops: Vec<LoopTargets>,
+    /// Type of the first `return` value in the current function
+    returned: Option<Type>,
 }
 impl LoweringContext {
     /// Create a new lowering context
@@ -31,8 +60,11 @@ impl LoweringContext {
     pub fn new() -> Self {
         Self {
             builder: MirBuilder::new(),
-            type_env: HashMap::new(),
+            signatures: HashMap::new(),
             current_block: None,
+            dead_blocks: HashSet::new(),
+            loops: Vec::new(),
+            returned: None,
         }
     }
     /// Lower an expression to MIR
@@ -61,24 +93,102 @@ impl LoweringContext {
                 return_type,
                 body,
                 ..
-            } => self.lower_function(name, params, return_type.as_ref(), body),
+            } => {
+                let function = self.lower_function(name, params, return_type.as_ref(), body)?;
+                let mut functions = HashMap::new();
+                functions.insert(name.clone(), function);
+                Ok(Program {
+                    functions,
+                    entry: name.clone(),
+                })
+            }
+            ExprKind::Block(items)
+                if items
+                    .iter()
+                    .any(|item| matches!(item.kind, ExprKind::Function { .. })) =>
+            {
+                self.lower_items(items)
+            }
             _ => {
                 // For non-function expressions, create a main function
                 self.lower_main_expr(expr)
             }
         }
     }
+    /// Lower a program's functions, with its other top-level statements as
+    /// the body of `main`
+    fn lower_items(&mut self, items: &[Expr]) -> Result<Program> {
+        let (definitions, statements): (Vec<&Expr>, Vec<&Expr>) = items
+            .iter()
+            .partition(|item| matches!(item.kind, ExprKind::Function { .. }));
+        // Annotated functions can be called before they are lowered
+        for definition in &definitions {
+            if let ExprKind::Function {
+                name,
+                return_type: Some(ty),
+                ..
+            } = &definition.kind
+            {
+                self.signatures
+                    .insert(name.clone(), Self::ast_to_mir_type(ty));
+            }
+        }
+        let mut functions = HashMap::new();
+        let mut entry = None;
+        for definition in definitions {
+            if let ExprKind::Function {
+                name,
+                params,
+                return_type,
+                body,
+                ..
+            } = &definition.kind
+            {
+                let function = self.lower_function(name, params, return_type.as_ref(), body)?;
+                entry.get_or_insert_with(|| name.clone());
+                functions.insert(name.clone(), function);
+            }
+        }
+        if !statements.is_empty() {
+            if functions.contains_key("main") {
+                bail!("top-level statements next to `fun main` cannot be lowered to MIR yet");
+            }
+            let body = Expr::new(
+                ExprKind::Block(statements.into_iter().cloned().collect()),
+                items[0].span,
+            );
+            let main = self.lower_function("main", &[], None, &body)?;
+            functions.insert("main".to_string(), main);
+        }
+        let entry = if functions.contains_key("main") {
+            "main".to_string()
+        } else {
+            entry.unwrap_or_else(|| "main".to_string())
+        };
+        Ok(Program { functions, entry })
+    }
     /// Lower a function expression
+    ///
+    /// `main` returns unit; other functions without a return type return
+    /// the type of their body, or of their first `return` if the body never
+    /// completes.
     fn lower_function(
         &mut self,
         name: &str,
         params: &[Param],
         return_type: Option<&AstType>,
         body: &Expr,
-    ) -> Result<Program> {
-        let func_name = name.to_string();
-        let ret_ty = return_type.map_or(Type::Unit, Self::ast_to_mir_type);
-        self.builder.start_function(func_name.clone(), ret_ty);
+    ) -> Result<Function> {
+        let declared = if name == "main" {
+            Some(Type::Unit)
+        } else {
+            return_type.map(Self::ast_to_mir_type)
+        };
+        self.builder
+            .start_function(name.to_string(), declared.clone().unwrap_or(Type::Unit));
+        self.dead_blocks.clear();
+        self.loops.clear();
+        self.returned = None;
         // Add parameters
         for param in params {
             let ty = Self::ast_to_mir_type(&param.ty);
@@ -89,33 +199,30 @@ impl LoweringContext {
         self.current_block = Some(entry);
         // Lower function body
         let result = self.lower_expr_to_operand(body)?;
-        // Return the result
-        self.builder.return_(entry, Some(result));
-        let function = self
-            .builder
+        let block = self.block()?;
+        let completes = !self.dead_blocks.contains(&block);
+        let return_ty = match declared {
+            Some(ty) => ty,
+            None if completes => self.operand_type(&result),
+            None => self.returned.clone().unwrap_or(Type::Unit),
+        };
+        self.builder.set_return_type(return_ty.clone());
+        self.signatures.insert(name.to_string(), return_ty.clone());
+        if completes {
+            let value = if return_ty == Type::Unit {
+                Operand::Constant(Constant::Unit)
+            } else {
+                result
+            };
+            self.builder.return_(block, Some(value));
+        }
+        self.builder
             .finish_function()
-            .ok_or_else(|| anyhow!("Failed to finish function"))?;
-        let mut functions = HashMap::new();
-        functions.insert(func_name.clone(), function);
-        Ok(Program {
-            functions,
-            entry: func_name,
-        })
+            .ok_or_else(|| anyhow!("Failed to finish function {name}"))
     }
     /// Lower a main expression (wrap in main function)
     fn lower_main_expr(&mut self, expr: &Expr) -> Result<Program> {
-        self.builder.start_function("main".to_string(), Type::Unit);
-        let entry = self.builder.new_block();
-        self.current_block = Some(entry);
-        // Lower the expression
-        let _result = self.lower_expr_to_operand(expr)?;
-        // Main function returns unit
-        self.builder
-            .return_(entry, Some(Operand::Constant(Constant::Unit)));
-        let function = self
-            .builder
-            .finish_function()
-            .ok_or_else(|| anyhow!("Failed to finish main function"))?;
+        let function = self.lower_function("main", &[], None, expr)?;
         let mut functions = HashMap::new();
         functions.insert("main".to_string(), function);
         Ok(Program {
@@ -123,126 +230,501 @@ impl LoweringContext {
             entry: "main".to_string(),
         })
     }
-    /// Lower an expression to an operand
+    /// Lower an expression to an operand (complexity: 10)
     fn lower_expr_to_operand(&mut self, expr: &Expr) -> Result<Operand> {
-        let block = self
-            .current_block
-            .ok_or_else(|| anyhow!("No current block"))?;
         match &expr.kind {
             ExprKind::Literal(lit) => Ok(Operand::Constant(Self::lower_literal(lit))),
-            ExprKind::Identifier(name) => {
-                if let Some(local) = self.builder.get_local(name) {
-                    Ok(Operand::Copy(Place::Local(local)))
-                } else {
-                    Err(anyhow!("Unbound variable: {name}"))
-                }
-            }
-            ExprKind::Binary { op, left, right } => {
-                let left_op = self.lower_expr_to_operand(left)?;
-                let right_op = self.lower_expr_to_operand(right)?;
-                let mir_op = Self::lower_binary_op(*op);
-                // Create a variable for the result
-                let result_ty = Self::infer_binary_result_type(*op);
-                let temp = self.builder.alloc_local(result_ty, false, None);
-                self.builder
-                    .binary_op(block, temp, mir_op, left_op, right_op);
-                Ok(Operand::Move(Place::Local(temp)))
-            }
-            ExprKind::Unary { op, operand } => {
-                let operand_mir = self.lower_expr_to_operand(operand)?;
-                let mir_op = Self::lower_unary_op(*op);
-                let result_ty = Self::infer_unary_result_type(*op);
-                let temp = self.builder.alloc_local(result_ty, false, None);
-                self.builder.unary_op(block, temp, mir_op, operand_mir);
-                Ok(Operand::Move(Place::Local(temp)))
+            // `{ }` parses as an empty object literal; as a body it is unit
+            ExprKind::ObjectLiteral { fields } if fields.is_empty() => {
+                Ok(Operand::Constant(Constant::Unit))
             }
+            ExprKind::Identifier(name) => Ok(Operand::Copy(Place::Local(self.variable(name)?))),
+            ExprKind::Binary {
+                op: op @ (AstBinOp::And | AstBinOp::Or),
+                left,
+                right,
+            } => self.lower_short_circuit(*op, left, right),
+            ExprKind::Binary { op, left, right } => self.lower_binary(*op, left, right),
+            ExprKind::Unary { op, operand } => self.lower_unary(*op, operand),
+            ExprKind::TypeCast { expr, target_type } => self.lower_cast(expr, target_type),
             ExprKind::Let {
-                name, value, body, ..
-            } => {
-                // Lower the value
-                let value_op = self.lower_expr_to_operand(value)?;
-                // Create a local for the binding
-                let local_ty = Type::I32; // Default type inference
-                let local = self
-                    .builder
-                    .alloc_local(local_ty, false, Some(name.clone()));
-                // Assign the value
-                self.builder
-                    .assign(block, Place::Local(local), Rvalue::Use(value_op));
-                // Lower the body with the binding in scope
-                self.lower_expr_to_operand(body)
+                name,
+                type_annotation,
+                value,
+                body,
+                is_mutable,
+                else_block: None,
+            } => self.lower_let(name, type_annotation.as_ref(), value, body, *is_mutable),
+            ExprKind::Assign { target, value } => self.lower_assign(target, None, value),
+            ExprKind::CompoundAssign { target, op, value } => {
+                self.lower_assign(target, Some(*op), value)
             }
             ExprKind::If {
                 condition,
                 then_branch,
                 else_branch,
+            } => self.lower_if(condition, then_branch, else_branch.as_deref()),
+            ExprKind::While {
+                label,
+                condition,
+                body,
+            } => self.lower_while(label.as_deref(), condition, body),
+            ExprKind::Loop { label, body } => {
+                let body_block = self.builder.new_block();
+                let exit_block = self.builder.new_block();
+                self.goto_from_current(body_block)?;
+                self.lower_loop_body(label.as_deref(), body, body_block, body_block, exit_block)
+            }
+            ExprKind::For {
+                label,
+                var,
+                pattern: None | Some(Pattern::Identifier(_)),
+                iter,
+                body,
             } => {
-                let cond_op = self.lower_expr_to_operand(condition)?;
-                let then_block = self.builder.new_block();
-                let else_block = self.builder.new_block();
-                let merge_block = self.builder.new_block();
-                // Branch based on condition
-                self.builder.branch(block, cond_op, then_block, else_block);
-                // Lower then branch
-                self.current_block = Some(then_block);
-                let then_result = self.lower_expr_to_operand(then_branch)?;
-                self.builder.goto(then_block, merge_block);
-                // Lower else branch
-                self.current_block = Some(else_block);
-                let _else_result = if let Some(else_expr) = else_branch {
-                    self.lower_expr_to_operand(else_expr)?
-                } else {
-                    Operand::Constant(Constant::Unit)
+                let ExprKind::Range {
+                    start,
+                    end,
+                    inclusive,
+                } = &iter.kind
+                else {
+                    bail!("only `for` loops over ranges can be lowered to MIR yet");
                 };
-                self.builder.goto(else_block, merge_block);
-                // Create a variable for the result
-                let result_ty = Type::I32; // Type inference would determine this
-                let result_temp = self.builder.alloc_local(result_ty, false, None);
-                // In merge block, we'd need phi nodes, but for simplicity we'll just use the then result
-                self.current_block = Some(merge_block);
-                self.builder.assign(
-                    merge_block,
-                    Place::Local(result_temp),
-                    Rvalue::Use(then_result),
-                );
-                Ok(Operand::Move(Place::Local(result_temp)))
+                self.lower_range_loop(
+                    label.as_deref(),
+                    var,
+                    (start.as_ref(), end.as_ref(), *inclusive),
+                    body,
+                )
             }
-            ExprKind::Call { func, args } => {
-                let func_op = self.lower_expr_to_operand(func)?;
-                let mut arg_ops = Vec::new();
-                for arg in args {
-                    arg_ops.push(self.lower_expr_to_operand(arg)?);
-                }
-                // Create a variable for the result
-                let result_ty = Type::I32; // Type inference would determine this
-                let result_temp = self.builder.alloc_local(result_ty, false, None);
-                // Create call terminator
-                let next_block = self.builder.call(block, result_temp, func_op, arg_ops);
-                self.current_block = Some(next_block);
-                Ok(Operand::Move(Place::Local(result_temp)))
+            ExprKind::Break { label, value: None } => {
+                let target = self.loop_targets(label.as_deref())?.break_to;
+                self.jump_away(target)
+            }
+            ExprKind::Continue { label } => {
+                let target = self.loop_targets(label.as_deref())?.continue_to;
+                self.jump_away(target)
             }
-            ExprKind::Block(exprs) => {
-                if exprs.is_empty() {
-                    Ok(Operand::Constant(Constant::Unit))
+            ExprKind::Return { value } => self.lower_return(value.as_deref()),
+            ExprKind::Call { func, args } => self.lower_call(func, args),
+            ExprKind::Block(exprs) => self.lower_block(exprs),
+            _ => Err(anyhow!(
+                "{} cannot be lowered to MIR yet",
+                Self::describe(expr)
+            )),
+        }
+    }
+    /// The kind of `expr`, for errors
+    fn describe(expr: &Expr) -> String {
+        let debug = format!("{:?}", expr.kind);
+        let kind: String = debug
+            .chars()
+            .take_while(char::is_ascii_alphanumeric)
+            .collect();
+        format!("`{kind}` expression")
+    }
+    fn block(&self) -> Result<BlockId> {
+        self.current_block
+            .ok_or_else(|| anyhow!("No current block"))
+    }
+    fn goto_from_current(&mut self, target: BlockId) -> Result<()> {
+        let block = self.block()?;
+        self.builder.goto(block, target);
+        Ok(())
+    }
+    fn variable(&self, name: &str) -> Result<Local> {
+        self.builder
+            .get_local(name)
+            .ok_or_else(|| anyhow!("Unbound variable: {name}"))
+    }
+    /// Store `rvalue` in a new temporary of type `ty`
+    fn temp(&mut self, ty: Type, rvalue: Rvalue) -> Result<Operand> {
+        let temp = self.builder.alloc_local(ty, false, None);
+        let block = self.block()?;
+        self.builder.assign(block, Place::Local(temp), rvalue);
+        Ok(Operand::Move(Place::Local(temp)))
+    }
+    /// The type of `operand`
+    fn operand_type(&self, operand: &Operand) -> Type {
+        match operand {
+            Operand::Constant(constant) => Self::constant_type(constant),
+            Operand::Copy(Place::Local(local)) | Operand::Move(Place::Local(local)) => self
+                .builder
+                .local_type(*local)
+                .cloned()
+                .unwrap_or(Type::Unit),
+            // Lowering only reads whole locals
+            Operand::Copy(_) | Operand::Move(_) => Type::Unit,
+        }
+    }
+    /// The type of a constant
+    fn constant_type(constant: &Constant) -> Type {
+        match constant {
+            Constant::Unit => Type::Unit,
+            Constant::Bool(_) => Type::Bool,
+            Constant::Int(_, ty) | Constant::Uint(_, ty) | Constant::Float(_, ty) => ty.clone(),
+            Constant::String(_) => Type::String,
+            Constant::Char(_) => Type::UserType("char".to_string()),
+            Constant::Symbol(_) => Type::UserType("Symbol".to_string()),
+            // Only ever a callee, never stored
+            Constant::Function(_) => Type::Unit,
+        }
+    }
+    fn lower_binary(&mut self, op: AstBinOp, left: &Expr, right: &Expr) -> Result<Operand> {
+        let left = self.lower_expr_to_operand(left)?;
+        let right = self.lower_expr_to_operand(right)?;
+        let ty = self.binary_result_type(op, &left, &right);
+        self.temp(ty, Rvalue::BinaryOp(Self::lower_binary_op(op), left, right))
+    }
+    /// Arithmetic has the type of its operands, where a literal defers to the
+    /// other side; comparisons, logic and sends have a fixed type
+    fn binary_result_type(&self, op: AstBinOp, left: &Operand, right: &Operand) -> Type {
+        let fixed = Self::infer_binary_result_type(op);
+        if fixed != Type::I32 {
+            return fixed;
+        }
+        match (left, right) {
+            (Operand::Constant(_), Operand::Copy(_) | Operand::Move(_)) => self.operand_type(right),
+            _ => self.operand_type(left),
+        }
+    }
+    /// `a && b` and `a || b` only evaluate `b` when `a` does not decide
+    fn lower_short_circuit(&mut self, op: AstBinOp, left: &Expr, right: &Expr) -> Result<Operand> {
+        let left = self.lower_expr_to_operand(left)?;
+        let result = self.builder.alloc_local(Type::Bool, false, None);
+        let block = self.block()?;
+        self.builder
+            .assign(block, Place::Local(result), Rvalue::Use(left));
+        let right_block = self.builder.new_block();
+        let merge_block = self.builder.new_block();
+        let decided = Operand::Copy(Place::Local(result));
+        if matches!(op, AstBinOp::And) {
+            self.builder
+                .branch(block, decided, right_block, merge_block);
+        } else {
+            self.builder
+                .branch(block, decided, merge_block, right_block);
+        }
+        self.current_block = Some(right_block);
+        let right = self.lower_expr_to_operand(right)?;
+        let block = self.block()?;
+        self.builder
+            .assign(block, Place::Local(result), Rvalue::Use(right));
+        self.builder.goto(block, merge_block);
+        self.current_block = Some(merge_block);
+        Ok(Operand::Copy(Place::Local(result)))
+    }
+    fn lower_unary(&mut self, op: AstUnOp, operand: &Expr) -> Result<Operand> {
+        let operand = self.lower_expr_to_operand(operand)?;
+        let operand_ty = self.operand_type(&operand);
+        let ty = match op {
+            AstUnOp::Negate | AstUnOp::BitwiseNot => operand_ty,
+            AstUnOp::Not => Type::Bool,
+            AstUnOp::Reference => Type::Ref(Box::new(operand_ty), Mutability::Immutable),
+            AstUnOp::MutableReference => Type::Ref(Box::new(operand_ty), Mutability::Mutable),
+            AstUnOp::Deref => match operand_ty {
+                Type::Ref(inner, _) => *inner,
+                _ => Self::infer_unary_result_type(op),
+            },
+        };
+        self.temp(ty, Rvalue::UnaryOp(Self::lower_unary_op(op), operand))
+    }
+    fn lower_cast(&mut self, expr: &Expr, target_type: &str) -> Result<Operand> {
+        let operand = self.lower_expr_to_operand(expr)?;
+        let ty = Self::named_type(target_type);
+        self.temp(ty.clone(), Rvalue::Cast(CastKind::Numeric, operand, ty))
+    }
+    fn lower_let(
+        &mut self,
+        name: &str,
+        annotation: Option<&AstType>,
+        value: &Expr,
+        body: &Expr,
+        mutable: bool,
+    ) -> Result<Operand> {
+        let value = self.lower_expr_to_operand(value)?;
+        let ty = match annotation {
+            Some(ty) => Self::ast_to_mir_type(ty),
+            None => self.operand_type(&value),
+        };
+        let local = self
+            .builder
+            .alloc_local(ty, mutable, Some(name.to_string()));
+        let block = self.block()?;
+        self.builder
+            .assign(block, Place::Local(local), Rvalue::Use(value));
+        // Lower the body with the binding in scope
+        self.lower_expr_to_operand(body)
+    }
+    /// `target = value`, or `target op= value`
+    fn lower_assign(
+        &mut self,
+        target: &Expr,
+        op: Option<AstBinOp>,
+        value: &Expr,
+    ) -> Result<Operand> {
+        let ExprKind::Identifier(name) = &target.kind else {
+            bail!("only assignments to variables can be lowered to MIR yet");
+        };
+        let local = self.variable(name)?;
+        let value = self.lower_expr_to_operand(value)?;
+        let rvalue = match op {
+            Some(op) => Rvalue::BinaryOp(
+                Self::lower_binary_op(op),
+                Operand::Copy(Place::Local(local)),
+                value,
+            ),
+            None => Rvalue::Use(value),
+        };
+        let block = self.block()?;
+        self.builder.assign(block, Place::Local(local), rvalue);
+        Ok(Operand::Constant(Constant::Unit))
+    }
+    /// Lower both branches, then store the value of each branch that
+    /// completes in the local the merge block reads
+    fn lower_if(
+        &mut self,
+        condition: &Expr,
+        then_branch: &Expr,
+        else_branch: Option<&Expr>,
+    ) -> Result<Operand> {
+        let condition = self.lower_expr_to_operand(condition)?;
+        let then_block = self.builder.new_block();
+        let else_block = self.builder.new_block();
+        let merge_block = self.builder.new_block();
+        let block = self.block()?;
+        // Branch based on condition
+        self.builder
+            .branch(block, condition, then_block, else_block);
+        let mut ends = Vec::new();
+        for (start, branch) in [(then_block, Some(then_branch)), (else_block, else_branch)] {
+            self.current_block = Some(start);
+            let value = match branch {
+                Some(branch) => self.lower_expr_to_operand(branch)?,
+                None => Operand::Constant(Constant::Unit),
+            };
+            ends.push((self.block()?, value));
+        }
+        // Without `else` the `if` is a statement; with it, the branches that
+        // complete give its type, where a literal defers to a computed value
+        let result = else_branch.map(|_| {
+            let mut live = ends
+                .iter()
+                .filter(|(end, _)| !self.dead_blocks.contains(end))
+                .map(|(_, value)| value);
+            let first = live.clone().next();
+            let ty = live
+                .find(|value| !matches!(value, Operand::Constant(_)))
+                .or(first)
+                .map_or(Type::Unit, |value| self.operand_type(value));
+            self.builder.alloc_local(ty, false, None)
+        });
+        let mut merges = false;
+        for (end, value) in ends {
+            if self.dead_blocks.contains(&end) {
+                continue;
+            }
+            if let Some(result) = result {
+                self.builder
+                    .assign(end, Place::Local(result), Rvalue::Use(value));
+            }
+            self.builder.goto(end, merge_block);
+            merges = true;
+        }
+        if !merges {
+            self.dead_blocks.insert(merge_block);
+        }
+        self.current_block = Some(merge_block);
+        Ok(result.map_or(Operand::Constant(Constant::Unit), |result| {
+            Operand::Move(Place::Local(result))
+        }))
+    }
+    fn lower_while(
+        &mut self,
+        label: Option<&str>,
+        condition: &Expr,
+        body: &Expr,
+    ) -> Result<Operand> {
+        let condition_block = self.builder.new_block();
+        self.goto_from_current(condition_block)?;
+        self.current_block = Some(condition_block);
+        let condition = self.lower_expr_to_operand(condition)?;
+        let body_block = self.builder.new_block();
+        let exit_block = self.builder.new_block();
+        let block = self.block()?;
+        self.builder
+            .branch(block, condition, body_block, exit_block);
+        self.lower_loop_body(label, body, body_block, condition_block, exit_block)
+    }
+    /// `for var in start..end { body }` as a counter compared with `end`,
+    /// evaluated once, and stepped after the body and on `continue`
+    fn lower_range_loop(
+        &mut self,
+        label: Option<&str>,
+        var: &str,
+        (start, end, inclusive): (&Expr, &Expr, bool),
+        body: &Expr,
+    ) -> Result<Operand> {
+        let start = self.lower_expr_to_operand(start)?;
+        let end = self.lower_expr_to_operand(end)?;
+        let ty = self.binary_result_type(AstBinOp::Add, &start, &end);
+        let scope = self.builder.bindings();
+        let bound = self.builder.alloc_local(ty.clone(), false, None);
+        let counter = self
+            .builder
+            .alloc_local(ty.clone(), true, Some(var.to_string()));
+        let block = self.block()?;
+        self.builder
+            .assign(block, Place::Local(counter), Rvalue::Use(start));
+        self.builder
+            .assign(block, Place::Local(bound), Rvalue::Use(end));
+        let condition_block = self.builder.new_block();
+        let body_block = self.builder.new_block();
+        let step_block = self.builder.new_block();
+        let exit_block = self.builder.new_block();
+        self.builder.goto(block, condition_block);
+        let in_range = self.builder.alloc_local(Type::Bool, false, None);
+        let compare = if inclusive { BinOp::Le } else { BinOp::Lt };
+        self.builder.binary_op(
+            condition_block,
+            in_range,
+            compare,
+            Operand::Copy(Place::Local(counter)),
+            Operand::Copy(Place::Local(bound)),
+        );
+        self.builder.branch(
+            condition_block,
+            Operand::Copy(Place::Local(in_range)),
+            body_block,
+            exit_block,
+        );
+        self.lower_loop_body(label, body, body_block, step_block, exit_block)?;
+        self.builder.binary_op(
+            step_block,
+            counter,
+            BinOp::Add,
+            Operand::Copy(Place::Local(counter)),
+            Operand::Constant(Constant::Int(1, ty)),
+        );
+        self.builder.goto(step_block, condition_block);
+        self.builder.restore_bindings(scope);
+        Ok(Operand::Constant(Constant::Unit))
+    }
+    /// Lower a loop body that goes on at `continue_to` and leaves to `exit`
+    fn lower_loop_body(
+        &mut self,
+        label: Option<&str>,
+        body: &Expr,
+        body_block: BlockId,
+        continue_to: BlockId,
+        exit: BlockId,
+    ) -> Result<Operand> {
+        self.loops.push(LoopTargets {
+            label: label.map(str::to_string),
+            break_to: exit,
+            continue_to,
+        });
+        self.current_block = Some(body_block);
+        let lowered = self.lower_expr_to_operand(body);
+        self.loops.pop();
+        lowered?;
+        self.goto_from_current(continue_to)?;
+        self.current_block = Some(exit);
+        Ok(Operand::Constant(Constant::Unit))
+    }
+    /// The innermost loop, or the one labelled `label`
+    fn loop_targets(&self, label: Option<&str>) -> Result<&LoopTargets> {
+        self.loops
+            .iter()
+            .rev()
+            .find(|targets| label.is_none() || targets.label.as_deref() == label)
+            .ok_or_else(|| anyhow!("`break` or `continue` outside a loop"))
+    }
+    /// End the current block with a jump; code after it is unreachable
+    fn jump_away(&mut self, target: BlockId) -> Result<Operand> {
+        self.goto_from_current(target)?;
+        self.start_dead_block();
+        Ok(Operand::Constant(Constant::Unit))
+    }
+    fn start_dead_block(&mut self) {
+        let dead = self.builder.new_block();
+        self.dead_blocks.insert(dead);
+        self.current_block = Some(dead);
+    }
+    fn lower_return(&mut self, value: Option<&Expr>) -> Result<Operand> {
+        let value = match value {
+            Some(value) => self.lower_expr_to_operand(value)?,
+            None => Operand::Constant(Constant::Unit),
+        };
+        if self.returned.is_none() {
+            self.returned = Some(self.operand_type(&value));
+        }
+        let block = self.block()?;
+        self.builder.return_(block, Some(value));
+        self.start_dead_block();
+        Ok(Operand::Constant(Constant::Unit))
+    }
+    /// Calls of a program function or intrinsic name their callee; other
+    /// calls go through a function value
+    fn lower_call(&mut self, func: &Expr, args: &[Expr]) -> Result<Operand> {
+        let (callee, result_ty) = match &func.kind {
+            ExprKind::Identifier(name) if self.builder.get_local(name).is_none() => {
+                let result_ty = if INTRINSICS.contains(&name.as_str()) {
+                    Type::Unit
                 } else {
-                    // Lower all expressions, return the last one
-                    let mut result = Operand::Constant(Constant::Unit);
-                    for expr in exprs {
-                        result = self.lower_expr_to_operand(expr)?;
-                    }
-                    Ok(result)
-                }
+                    self.signatures.get(name).cloned().ok_or_else(|| {
+                        anyhow!(
+                            "call of `{name}` cannot be lowered to MIR yet: it is not a function of the program with a known return type"
+                        )
+                    })?
+                };
+                (
+                    Operand::Constant(Constant::Function(name.clone())),
+                    result_ty,
+                )
             }
             _ => {
-                // For unsupported expressions, return unit for now
-                Ok(Operand::Constant(Constant::Unit))
+                let callee = self.lower_expr_to_operand(func)?;
+                let result_ty = match self.operand_type(&callee) {
+                    Type::FnPtr(_, ret) => *ret,
+                    _ => Type::I32, // Type inference would determine this
+                };
+                (callee, result_ty)
             }
+        };
+        let mut arg_ops = Vec::new();
+        for arg in args {
+            arg_ops.push(self.lower_expr_to_operand(arg)?);
+        }
+        let result = self.builder.alloc_local(result_ty, false, None);
+        let block = self.block()?;
+        // Create call terminator
+        let next_block = self.builder.call(block, result, callee, arg_ops);
+        self.current_block = Some(next_block);
+        Ok(Operand::Move(Place::Local(result)))
+    }
+    /// Lower statements in order; names they bind go out of scope after
+    fn lower_block(&mut self, exprs: &[Expr]) -> Result<Operand> {
+        let scope = self.builder.bindings();
+        let mut result = Operand::Constant(Constant::Unit);
+        for expr in exprs {
+            result = self.lower_expr_to_operand(expr)?;
         }
+        self.builder.restore_bindings(scope);
+        Ok(result)
     }
     /// Lower a literal to a constant
     fn lower_literal(lit: &Literal) -> Constant {
         match lit {
-            Literal::Integer(i, _) => Constant::Int(i128::from(*i), Type::I32),
+            Literal::Integer(i, suffix) => Constant::Int(
+                i128::from(*i),
+                suffix.as_deref().map_or(Type::I32, Self::named_type),
+            ),
+            Literal::BigInt(digits) => digits.parse().map_or_else(
+                |_| Constant::String(digits.clone()), // Wider than i128: kept as digits
+                |n| Constant::Int(n, Type::I128),
+            ),
+            Literal::Decimal(text) => Constant::String(text.clone()), // Exact: kept as text
             Literal::Float(f) => Constant::Float(*f, Type::F64),
             Literal::String(s) => Constant::String(s.clone()),
             Literal::Bool(b) => Constant::Bool(*b),
@@ -296,24 +778,7 @@ impl LoweringContext {
     fn ast_to_mir_type(ast_ty: &AstType) -> Type {
         use crate::frontend::ast::TypeKind;
         match &ast_ty.kind {
-            TypeKind::Named(name) => match name.as_str() {
-                "bool" => Type::Bool,
-                "i8" => Type::I8,
-                "i16" => Type::I16,
-                "i32" => Type::I32,
-                "i64" => Type::I64,
-                "i128" => Type::I128,
-                "u8" => Type::U8,
-                "u16" => Type::U16,
-                "u32" => Type::U32,
-                "u64" => Type::U64,
-                "u128" => Type::U128,
-                "f32" => Type::F32,
-                "f64" => Type::F64,
-                "String" => Type::String,
-                "()" => Type::Unit,
-                _ => Type::UserType(name.clone()),
-            },
+            TypeKind::Named(name) => Self::named_type(name),
             TypeKind::Generic { base, params } => {
                 match base.as_str() {
                     "Vec" if params.len() == 1 => {
@@ -361,6 +826,27 @@ impl LoweringContext {
             TypeKind::Refined { base, .. } => Self::ast_to_mir_type(base),
         }
     }
+    /// The MIR type a type name stands for
+    fn named_type(name: &str) -> Type {
+        match name {
+            "bool" => Type::Bool,
+            "i8" => Type::I8,
+            "i16" => Type::I16,
+            "i32" => Type::I32,
+            "i64" => Type::I64,
+            "i128" => Type::I128,
+            "u8" => Type::U8,
+            "u16" => Type::U16,
+            "u32" => Type::U32,
+            "u64" => Type::U64,
+            "u128" => Type::U128,
+            "f32" => Type::F32,
+            "f64" => Type::F64,
+            "String" => Type::String,
+            "()" => Type::Unit,
+            _ => Type::UserType(name.to_string()),
+        }
+    }
     /// Infer result type for binary operations
     fn infer_binary_result_type(op: AstBinOp) -> Type {
         match op {
@@ -410,6 +896,7 @@ impl Default for LoweringContext {
 mod tests {
     use super::*;
     use crate::frontend::Parser;
+    use crate::middleend::mir::Terminator;
     #[test]
     fn test_lower_literal() -> Result<()> {
         let mut parser = Parser::new("42");
@@ -1378,6 +1865,82 @@ mod tests {
         assert!(func.locals.len() >= 2);
         Ok(())
     }
+
+    // --- Control flow, programs and typing ---
+
+    #[test]
+    fn test_lower_while_loop_with_break() -> Result<()> {
+        let source = "fun f(n: i32) -> i32 {\n    let mut i = 0\n    while i < n {\n        if i == 5 { break }\n        i = i + 1\n    }\n    i\n}";
+        let ast = Parser::new(source).parse()?;
+        let program = LoweringContext::new().lower_expr(&ast)?;
+        let func = &program.functions["f"];
+        assert!(func.blocks.len() >= 5);
+        assert!(func
+            .locals
+            .iter()
+            .any(|l| l.name.as_deref() == Some("i") && l.mutable));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lower_range_for_uses_typed_counter() -> Result<()> {
+        let source = "fun f(n: i64) -> i64 {\n    let mut t: i64 = 0\n    for i in 0..n {\n        t += i\n    }\n    t\n}";
+        let ast = Parser::new(source).parse()?;
+        let program = LoweringContext::new().lower_expr(&ast)?;
+        let func = &program.functions["f"];
+        let counter = func
+            .locals
+            .iter()
+            .find(|l| l.name.as_deref() == Some("i"))
+            .expect("loop counter");
+        assert_eq!(counter.ty, Type::I64);
+        assert!(func
+            .blocks
+            .iter()
+            .any(|b| matches!(b.terminator, Terminator::If { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lower_program_statements_become_main() -> Result<()> {
+        let ast = Parser::new("fun sq(x: i32) -> i32 { x * x }\nprintln(sq(3))").parse()?;
+        let program = LoweringContext::new().lower_expr(&ast)?;
+        assert!(program.functions.contains_key("sq"));
+        assert_eq!(program.entry, "main");
+        assert_eq!(program.functions["main"].return_ty, Type::Unit);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lower_if_result_takes_computed_branch_type() -> Result<()> {
+        let ast = Parser::new("fun f(n: i64) -> i64 { if n > 0 { 1 } else { n } }").parse()?;
+        let program = LoweringContext::new().lower_expr(&ast)?;
+        let func = &program.functions["f"];
+        assert!(func.locals.iter().all(|l| l.ty != Type::I32));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lower_unsupported_expression_is_an_error() -> Result<()> {
+        let ast = Parser::new("[1, 2, 3]").parse()?;
+        let err = LoweringContext::new().lower_expr(&ast).unwrap_err();
+        assert!(err.to_string().contains("cannot be lowered to MIR yet"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lowered_function_display() -> Result<()> {
+        let ast = Parser::new("fun add(a: i32, b: i32) -> i32 { a + b }").parse()?;
+        let program = LoweringContext::new().lower_expr(&ast)?;
+        let text = program.to_string();
+        assert!(
+            text.starts_with("fn add(__0: i32, __1: i32) -> i32 {"),
+            "{text}"
+        );
+        assert!(text.contains("__2 = Add(copy __0, copy __1);"), "{text}");
+        assert!(text.contains("return move __2;"), "{text}");
+        Ok(())
+    }
 }
 #[cfg(test)]
 mod property_tests_lower {
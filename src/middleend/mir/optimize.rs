@@ -1,7 +1,7 @@
 //! MIR optimization passes
 use super::types::{
     BinOp, BlockId, Constant, Function, Local, Operand, Place, Program, Rvalue, Statement,
-    Terminator, UnOp,
+    Terminator, Type, UnOp,
 };
 use std::collections::{HashMap, HashSet};
 /// Dead Code Elimination pass
@@ -268,6 +268,9 @@ impl DeadCodeElimination {
     }
 }
 /// Constant Propagation pass
+///
+/// Only locals assigned exactly once, and never borrowed, are replaced by
+/// their value: those hold it wherever they are read.
 pub struct ConstantPropagation {
     /// Map from locals to their constant values
     constants: HashMap<Local, Constant>,
@@ -288,11 +291,19 @@ impl ConstantPropagation {
     /// Run constant propagation on a function
     pub fn run(&mut self, func: &mut Function) {
         self.constants.clear();
+        let assignments = Self::count_assignments(func);
         // Find constant assignments
         for block in &func.blocks {
             for stmt in &block.statements {
                 if let Statement::Assign(Place::Local(local), rvalue) = stmt {
-                    if let Some(constant) = self.extract_constant(rvalue) {
+                    if func.params.contains(local) || assignments.get(local) != Some(&1) {
+                        continue;
+                    }
+                    let Some(constant) = self.extract_constant(rvalue) else {
+                        continue;
+                    };
+                    let declared = func.locals.iter().find(|decl| decl.id == *local);
+                    if declared.map_or(true, |decl| Self::fits(&constant, &decl.ty)) {
                         self.constants.insert(*local, constant);
                     }
                 }
@@ -306,6 +317,57 @@ impl ConstantPropagation {
             self.propagate_in_terminator(&mut block.terminator);
         }
     }
+    /// How often each local is written, counting borrows as writes
+    fn count_assignments(func: &Function) -> HashMap<Local, usize> {
+        fn root(place: &Place) -> Local {
+            match place {
+                Place::Local(local) => *local,
+                Place::Field(base, _) | Place::Deref(base) | Place::Index(base, _) => root(base),
+            }
+        }
+        let mut counts = HashMap::new();
+        for block in &func.blocks {
+            for stmt in &block.statements {
+                if let Statement::Assign(place, rvalue) = stmt {
+                    *counts.entry(root(place)).or_insert(0) += 1;
+                    if let Rvalue::Ref(_, borrowed) = rvalue {
+                        *counts.entry(root(borrowed)).or_insert(0) += 1;
+                    }
+                }
+            }
+            if let Terminator::Call {
+                destination: Some((place, _)),
+                ..
+            } = &block.terminator
+            {
+                *counts.entry(root(place)).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+    /// Whether an integer constant is in range for a local of type `ty`
+    fn fits(constant: &Constant, ty: &Type) -> bool {
+        let Constant::Int(value, _) = constant else {
+            return true;
+        };
+        match ty {
+            Type::I8 => i8::try_from(*value).is_ok(),
+            Type::I16 => i16::try_from(*value).is_ok(),
+            Type::I32 => i32::try_from(*value).is_ok(),
+            Type::I64 => i64::try_from(*value).is_ok(),
+            Type::U8 => u8::try_from(*value).is_ok(),
+            Type::U16 => u16::try_from(*value).is_ok(),
+            Type::U32 => u32::try_from(*value).is_ok(),
+            Type::U64 => u64::try_from(*value).is_ok(),
+            Type::U128 => u128::try_from(*value).is_ok(),
+            _ => true,
+        }
+    }
+    /// An integer result, unless it overflowed `ty`
+    fn int(value: Option<i128>, ty: &Type) -> Option<Constant> {
+        let constant = Constant::Int(value?, ty.clone());
+        Self::fits(&constant, ty).then_some(constant)
+    }
     /// Extract constant from rvalue if possible
     fn extract_constant(&self, rvalue: &Rvalue) -> Option<Constant> {
         match rvalue {
@@ -321,13 +383,13 @@ impl ConstantPropagation {
         let right_val = self.get_constant_value(right)?;
         match (op, &left_val, &right_val) {
             (BinOp::Add, Constant::Int(a, ty), Constant::Int(b, _)) => {
-                Some(Constant::Int(a + b, ty.clone()))
+                Self::int(a.checked_add(*b), ty)
             }
             (BinOp::Sub, Constant::Int(a, ty), Constant::Int(b, _)) => {
-                Some(Constant::Int(a - b, ty.clone()))
+                Self::int(a.checked_sub(*b), ty)
             }
             (BinOp::Mul, Constant::Int(a, ty), Constant::Int(b, _)) => {
-                Some(Constant::Int(a * b, ty.clone()))
+                Self::int(a.checked_mul(*b), ty)
             }
             (BinOp::Eq, Constant::Int(a, _), Constant::Int(b, _)) => Some(Constant::Bool(a == b)),
             (BinOp::Lt, Constant::Int(a, _), Constant::Int(b, _)) => Some(Constant::Bool(a < b)),
@@ -340,7 +402,7 @@ impl ConstantPropagation {
     fn eval_unary_op(&self, op: UnOp, operand: &Operand) -> Option<Constant> {
         let val = self.get_constant_value(operand)?;
         match (op, &val) {
-            (UnOp::Neg, Constant::Int(i, ty)) => Some(Constant::Int(-i, ty.clone())),
+            (UnOp::Neg, Constant::Int(i, ty)) => Self::int(i.checked_neg(), ty),
             (UnOp::Not, Constant::Bool(b)) => Some(Constant::Bool(!b)),
             _ => None,
         }
@@ -409,6 +471,9 @@ impl ConstantPropagation {
     }
 }
 /// Common Subexpression Elimination pass
+///
+/// Works within a basic block: an operation is reused while neither its
+/// operands nor the local holding its result have been assigned since.
 pub struct CommonSubexpressionElimination {
     /// Map from expressions to locals that compute them
     expressions: HashMap<String, Local>,
@@ -428,8 +493,9 @@ impl CommonSubexpressionElimination {
     }
     /// Run CSE on a function
     pub fn run(&mut self, func: &mut Function) {
-        self.expressions.clear();
         for block in &mut func.blocks {
+            // Other blocks may be reached along paths that skip this one
+            self.expressions.clear();
             for stmt in &mut block.statements {
                 self.process_statement(stmt);
             }
@@ -437,17 +503,35 @@ impl CommonSubexpressionElimination {
     }
     /// Process a statement for CSE
     fn process_statement(&mut self, stmt: &mut Statement) {
-        if let Statement::Assign(Place::Local(local), rvalue) = stmt {
-            let expr_key = self.rvalue_key(rvalue);
-            if let Some(existing_local) = self.expressions.get(&expr_key) {
-                // Replace with copy from existing local
-                *stmt = Statement::Assign(
-                    Place::Local(*local),
-                    Rvalue::Use(Operand::Copy(Place::Local(*existing_local))),
-                );
-            } else {
+        let Statement::Assign(place, rvalue) = stmt else {
+            return;
+        };
+        let Place::Local(local) = *place else {
+            // A write through a field, index or deref may change any operand
+            self.expressions.clear();
+            return;
+        };
+        let reusable = match rvalue {
+            Rvalue::BinaryOp(..) => true,
+            Rvalue::UnaryOp(op, _) => !matches!(op, UnOp::Ref | UnOp::Deref),
+            _ => false,
+        };
+        let expr_key = reusable.then(|| self.rvalue_key(rvalue));
+        let existing = expr_key
+            .as_ref()
+            .and_then(|key| self.expressions.get(key).copied());
+        if let Some(existing_local) = existing {
+            // Replace with copy from existing local
+            *rvalue = Rvalue::Use(Operand::Copy(Place::Local(existing_local)));
+        }
+        // Expressions reading `local`, or held in it, no longer hold
+        let mention = format!("local({})", local.0);
+        self.expressions
+            .retain(|key, holder| *holder != local && !key.contains(&mention));
+        if let (Some(expr_key), None) = (expr_key, existing) {
+            if !expr_key.contains(&mention) {
                 // Record this expression
-                self.expressions.insert(expr_key, *local);
+                self.expressions.insert(expr_key, local);
             }
         }
     }
@@ -587,6 +587,60 @@ fn test_const_prop_returns_none_for_non_constant() {
     );
 }
 
+#[test]
+fn test_const_prop_does_not_fold_overflow() {
+    let const_prop = ConstantPropagation::new();
+    let result = const_prop.eval_binary_op(
+        BinOp::Add,
+        &Operand::Constant(Constant::Int(i128::from(i32::MAX), Type::I32)),
+        &Operand::Constant(Constant::Int(1, Type::I32)),
+    );
+
+    assert!(result.is_none(), "i32 overflow must be left to run time");
+}
+
+#[test]
+fn test_const_prop_skips_locals_assigned_twice() {
+    let mut func = create_test_function("test", vec![], Type::I32);
+
+    let counter = Local(0);
+    let result_local = Local(1);
+
+    func.locals
+        .push(create_local_decl(counter, Type::I32, Some("counter")));
+    func.locals
+        .push(create_local_decl(result_local, Type::I32, None));
+
+    func.blocks.push(create_basic_block(
+        BlockId(0),
+        vec![
+            Statement::Assign(
+                Place::Local(counter),
+                Rvalue::Use(Operand::Constant(Constant::Int(0, Type::I32))),
+            ),
+            Statement::Assign(
+                Place::Local(counter),
+                Rvalue::Use(Operand::Constant(Constant::Int(7, Type::I32))),
+            ),
+            Statement::Assign(
+                Place::Local(result_local),
+                Rvalue::Use(Operand::Copy(Place::Local(counter))),
+            ),
+        ],
+        Terminator::Return(Some(Operand::Copy(Place::Local(result_local)))),
+    ));
+
+    ConstantPropagation::new().run(&mut func);
+
+    assert!(
+        matches!(
+            &func.blocks[0].statements[2],
+            Statement::Assign(_, Rvalue::Use(Operand::Copy(Place::Local(local)))) if *local == counter
+        ),
+        "A reassigned local must still be read"
+    );
+}
+
 // =====================================================================
 // CommonSubexpressionElimination Tests
 // =====================================================================
@@ -715,6 +769,83 @@ fn test_cse_generates_different_keys_for_different_expressions() {
     );
 }
 
+#[test]
+fn test_cse_forgets_expression_after_operand_is_reassigned() {
+    let x = Local(0);
+    let y = Local(1);
+    let z = Local(2);
+    let w = Local(3);
+    let sum = || {
+        Rvalue::BinaryOp(
+            BinOp::Add,
+            Operand::Copy(Place::Local(x)),
+            Operand::Copy(Place::Local(y)),
+        )
+    };
+    let mut func = create_test_function("test", vec![x, y], Type::I32);
+    for local in [x, y, z, w] {
+        func.locals.push(create_local_decl(local, Type::I32, None));
+    }
+    func.blocks.push(create_basic_block(
+        BlockId(0),
+        vec![
+            Statement::Assign(Place::Local(z), sum()),
+            Statement::Assign(
+                Place::Local(x),
+                Rvalue::Use(Operand::Constant(Constant::Int(5, Type::I32))),
+            ),
+            Statement::Assign(Place::Local(w), sum()),
+        ],
+        Terminator::Return(Some(Operand::Copy(Place::Local(w)))),
+    ));
+
+    CommonSubexpressionElimination::new().run(&mut func);
+
+    assert!(
+        matches!(
+            &func.blocks[0].statements[2],
+            Statement::Assign(_, Rvalue::BinaryOp(..))
+        ),
+        "x + y must be recomputed once x changes"
+    );
+}
+
+#[test]
+fn test_cse_does_not_reuse_across_blocks() {
+    let x = Local(0);
+    let y = Local(1);
+    let z = Local(2);
+    let w = Local(3);
+    let sum = || {
+        Rvalue::BinaryOp(
+            BinOp::Add,
+            Operand::Copy(Place::Local(x)),
+            Operand::Copy(Place::Local(y)),
+        )
+    };
+    let mut func = create_test_function("test", vec![x, y], Type::I32);
+    for local in [x, y, z, w] {
+        func.locals.push(create_local_decl(local, Type::I32, None));
+    }
+    func.blocks.push(create_basic_block(
+        BlockId(0),
+        vec![Statement::Assign(Place::Local(z), sum())],
+        Terminator::Goto(BlockId(1)),
+    ));
+    func.blocks.push(create_basic_block(
+        BlockId(1),
+        vec![Statement::Assign(Place::Local(w), sum())],
+        Terminator::Return(Some(Operand::Copy(Place::Local(w)))),
+    ));
+
+    CommonSubexpressionElimination::new().run(&mut func);
+
+    assert!(matches!(
+        &func.blocks[1].statements[0],
+        Statement::Assign(_, Rvalue::BinaryOp(..))
+    ));
+}
+
 // =====================================================================
 // Integration Tests
 // =====================================================================
@@ -170,6 +170,8 @@ pub enum Constant {
     Char(char),
     /// Symbol/Atom literal
     Symbol(String),
+    /// A function by name, the callee of a direct call
+    Function(String),
 }
 /// Binary operations
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -322,6 +324,183 @@ impl fmt::Display for Type {
         }
     }
 }
+/// Write `items` separated by commas
+fn write_list<T: fmt::Display>(f: &mut fmt::Formatter<'_>, items: &[T]) -> fmt::Result {
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{item}")?;
+    }
+    Ok(())
+}
+impl fmt::Display for Place {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Place::Local(local) => write!(f, "{local}"),
+            Place::Field(base, field) => write!(f, "{base}.{}", field.0),
+            Place::Index(base, index) => write!(f, "{base}[{index}]"),
+            Place::Deref(base) => write!(f, "(*{base})"),
+        }
+    }
+}
+impl fmt::Display for Constant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Constant::Unit => write!(f, "()"),
+            Constant::Bool(b) => write!(f, "{b}"),
+            Constant::Int(n, ty) => write!(f, "{n}_{ty}"),
+            Constant::Uint(n, ty) => write!(f, "{n}_{ty}"),
+            Constant::Float(x, ty) => write!(f, "{x:?}_{ty}"),
+            Constant::String(s) => write!(f, "{s:?}"),
+            Constant::Char(c) => write!(f, "{c:?}"),
+            Constant::Symbol(s) => write!(f, ":{s}"),
+            Constant::Function(name) => write!(f, "fn {name}"),
+        }
+    }
+}
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Copy(place) => write!(f, "copy {place}"),
+            Operand::Move(place) => write!(f, "move {place}"),
+            Operand::Constant(constant) => write!(f, "const {constant}"),
+        }
+    }
+}
+impl fmt::Display for Rvalue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rvalue::Use(operand) => write!(f, "{operand}"),
+            Rvalue::BinaryOp(op, left, right) => write!(f, "{op:?}({left}, {right})"),
+            Rvalue::UnaryOp(op, operand) => write!(f, "{op:?}({operand})"),
+            Rvalue::Ref(Mutability::Immutable, place) => write!(f, "&{place}"),
+            Rvalue::Ref(Mutability::Mutable, place) => write!(f, "&mut {place}"),
+            Rvalue::Aggregate(kind, operands) => {
+                write!(f, "{kind:?}(")?;
+                write_list(f, operands)?;
+                write!(f, ")")
+            }
+            Rvalue::Call(func, args) => {
+                write!(f, "{func}(")?;
+                write_list(f, args)?;
+                write!(f, ")")
+            }
+            Rvalue::Cast(_, operand, ty) => write!(f, "{operand} as {ty}"),
+        }
+    }
+}
+impl fmt::Display for Statement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Statement::Assign(place, rvalue) => write!(f, "{place} = {rvalue}"),
+            Statement::StorageLive(local) => write!(f, "StorageLive({local})"),
+            Statement::StorageDead(local) => write!(f, "StorageDead({local})"),
+            Statement::Nop => write!(f, "nop"),
+        }
+    }
+}
+impl fmt::Display for Terminator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Terminator::Goto(target) => write!(f, "goto -> {target}"),
+            Terminator::If {
+                condition,
+                then_block,
+                else_block,
+            } => write!(
+                f,
+                "if {condition} -> [true: {then_block}, false: {else_block}]"
+            ),
+            Terminator::Switch {
+                discriminant,
+                targets,
+                default,
+            } => {
+                write!(f, "switch {discriminant} -> [")?;
+                for (value, target) in targets {
+                    write!(f, "{value}: {target}, ")?;
+                }
+                match default {
+                    Some(target) => write!(f, "otherwise: {target}]"),
+                    None => write!(f, "otherwise: unreachable]"),
+                }
+            }
+            Terminator::Return(Some(operand)) => write!(f, "return {operand}"),
+            Terminator::Return(None) => write!(f, "return"),
+            Terminator::Call {
+                func,
+                args,
+                destination,
+            } => {
+                if let Some((place, _)) = destination {
+                    write!(f, "{place} = ")?;
+                }
+                write!(f, "{func}(")?;
+                write_list(f, args)?;
+                match destination {
+                    Some((_, target)) => write!(f, ") -> {target}"),
+                    None => write!(f, ") -> diverge"),
+                }
+            }
+            Terminator::Unreachable => write!(f, "unreachable"),
+        }
+    }
+}
+/// The textual form `ruchy transpile --via-mir --verbose` prints: typed
+/// locals, then each basic block with its statements and terminator
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let declaration = |local: &Local| self.locals.iter().find(|decl| decl.id == *local);
+        write!(f, "fn {}(", self.name)?;
+        for (i, param) in self.params.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            match declaration(param) {
+                Some(decl) => write!(f, "{param}: {}", decl.ty)?,
+                None => write!(f, "{param}")?,
+            }
+        }
+        writeln!(f, ") -> {} {{", self.return_ty)?;
+        for decl in self
+            .locals
+            .iter()
+            .filter(|decl| !self.params.contains(&decl.id))
+        {
+            let mutability = if decl.mutable { "mut " } else { "" };
+            write!(f, "    let {mutability}{}: {};", decl.id, decl.ty)?;
+            match &decl.name {
+                Some(name) => writeln!(f, " // {name}")?,
+                None => writeln!(f)?,
+            }
+        }
+        for block in &self.blocks {
+            writeln!(f, "    {}: {{", block.id)?;
+            for statement in &block.statements {
+                writeln!(f, "        {statement};")?;
+            }
+            writeln!(f, "        {};", block.terminator)?;
+            writeln!(f, "    }}")?;
+        }
+        write!(f, "}}")
+    }
+}
+/// The program's functions in name order, separated by blank lines
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names: Vec<&String> = self.functions.keys().collect();
+        names.sort();
+        for (i, name) in names.into_iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+                writeln!(f)?;
+            }
+            write!(f, "{}", self.functions[name])?;
+        }
+        Ok(())
+    }
+}
 #[cfg(test)]
 mod tests {
     use super::*;
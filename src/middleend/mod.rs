@@ -1,16 +1,27 @@
 //! Middle-end compiler passes (type checking, inference, optimization)
+pub mod code_motion;
+pub mod dead_code;
+pub mod effects;
 pub mod environment;
 pub mod infer;
+pub mod match_analysis;
+pub mod method_analysis;
 pub mod mir;
+pub mod optimize;
 pub mod types;
 pub mod unify;
 
 #[cfg(test)]
 mod infer_tests;
 // Re-export commonly used types
+pub use dead_code::{find_dead_code, DeadCodeDiagnostic, DeadCodeKind};
+pub use effects::{infer_effects, Effect, EffectReport, FunctionEffects};
 pub use environment::TypeEnv;
 pub use infer::InferenceContext;
+pub use match_analysis::{analyze_matches, MatchDiagnostic, MatchDiagnosticKind};
+pub use method_analysis::analyze_method_calls;
 pub use mir::{Function as MirFunction, Program as MirProgram};
+pub use optimize::{optimize, OperationCount, OptimizationReport};
 pub use types::{MonoType, TyVar, TyVarGenerator, TypeScheme};
 pub use unify::Unifier;
 
@@ -0,0 +1,540 @@
+//! Constant folding and dead-branch elimination
+//!
+//! [`optimize`] rewrites a program bottom-up before it is interpreted or
+//! transpiled:
+//!
+//! 1. **Folds constants.** Arithmetic, comparisons and logic whose operands
+//!    are literals are replaced by their result (`60 * 60 * 24` becomes
+//!    `86400`).
+//! 2. **Removes dead branches.** An `if` on a constant condition is replaced
+//!    by the branch taken, kept in a block so its bindings stay scoped.
+//! 3. **Pre-computes pure builtins.** Calls of the [`PURE_BUILTINS`] whose
+//!    arguments are all literals are replaced by their result, unless the
+//!    program defines or binds a name of its own with that name.
+//!
+//! Results are only substituted where the interpreter and the generated Rust
+//! agree on both value and type. Operands of different types, suffixed
+//! integers, overflow, division by zero and non-finite floats are left
+//! alone, as are integer results outside the `i32` range (Rust would need a
+//! suffix the source did not have). Negative results are written as a
+//! negated literal, the shape the parser produces.
+//!
+//! It then runs the loop-invariant code motion and common subexpression
+//! elimination of [`crate::middleend::code_motion`], and counts the
+//! operations left so `--verbose` can show what the pass saved.
+//!
+//! `ruchy compile` runs the pass at every `-O` level but `0`, and
+//! `ruchy run -O` runs it before interpreting.
+use crate::backend::transpiler::pattern_bindings::extract_pattern_bindings;
+use crate::backend::whole_program::for_each_child_mut;
+use crate::frontend::ast::{BinaryOp, Expr, ExprKind, Literal, Span, UnaryOp};
+use crate::middleend::code_motion;
+use crate::middleend::match_analysis::for_each_expr;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::fmt;
+
+/// Builtins [`optimize`] evaluates when every argument is a literal
+pub const PURE_BUILTINS: &[&str] = &["abs", "len", "max", "min", "pow", "sqrt"];
+
+/// What [`optimize`] changed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OptimizationReport {
+    /// Operators on constants replaced by their result
+    pub folded_operations: usize,
+    /// `if`s on a constant condition replaced by the branch taken
+    pub removed_branches: usize,
+    /// Builtin calls replaced by their result
+    pub precomputed_calls: usize,
+    /// Loop-invariant expressions moved out of their loop
+    pub hoisted_expressions: usize,
+    /// Repeated expressions computed once
+    pub shared_subexpressions: usize,
+    /// Operations in the program before the pass
+    pub operations_before: OperationCount,
+    /// Operations in the program after the pass
+    pub operations_after: OperationCount,
+}
+
+/// Operators and calls in a program, the instructions it evaluates
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OperationCount {
+    /// Operations anywhere in the program
+    pub total: usize,
+    /// Operations inside a `for`, `while` or `loop`, evaluated per iteration
+    pub in_loops: usize,
+}
+
+impl OperationCount {
+    /// Count the operations in `program`; it is only borrowed mutably to
+    /// share the child walker of the rewrite
+    fn of(program: &mut Expr) -> Self {
+        let mut count = Self::default();
+        count.tally(program, false);
+        count
+    }
+
+    fn tally(&mut self, expr: &mut Expr, in_loop: bool) {
+        if matches!(
+            expr.kind,
+            ExprKind::Binary { .. }
+                | ExprKind::Unary { .. }
+                | ExprKind::Call { .. }
+                | ExprKind::MethodCall { .. }
+        ) {
+            self.total += 1;
+            self.in_loops += usize::from(in_loop);
+        }
+        let in_loop = in_loop
+            || matches!(
+                expr.kind,
+                ExprKind::For { .. } | ExprKind::While { .. } | ExprKind::Loop { .. }
+            );
+        for_each_child_mut(expr, &mut |child| self.tally(child, in_loop));
+    }
+}
+
+impl fmt::Display for OperationCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({} in loops)", self.total, self.in_loops)
+    }
+}
+
+impl OptimizationReport {
+    /// Whether the pass changed nothing
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.folded_operations
+            + self.removed_branches
+            + self.precomputed_calls
+            + self.hoisted_expressions
+            + self.shared_subexpressions
+            == 0
+    }
+}
+
+impl fmt::Display for OptimizationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "optimization: {} folded operation(s), {} dead branch(es) removed, {} builtin call(s) precomputed",
+            self.folded_operations, self.removed_branches, self.precomputed_calls
+        )?;
+        writeln!(
+            f,
+            "  {} loop-invariant expression(s) hoisted, {} common subexpression(s) shared",
+            self.hoisted_expressions, self.shared_subexpressions
+        )?;
+        write!(
+            f,
+            "  operations: {} before, {} after",
+            self.operations_before, self.operations_after
+        )
+    }
+}
+
+/// Fold constants, remove dead branches, pre-compute pure builtin calls,
+/// then hoist loop invariants and share common subexpressions
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::middleend::optimize::optimize;
+/// use ruchy::Parser;
+///
+/// let program = Parser::new("if 2 > 1 { max(3, 4) * 60 } else { 0 }")
+///     .parse()
+///     .unwrap();
+/// let (_, report) = optimize(program);
+/// // `2 > 1` and `4 * 60` fold, `max(3, 4)` is pre-computed, the `else` goes
+/// assert_eq!(report.folded_operations, 2);
+/// assert_eq!(report.precomputed_calls, 1);
+/// assert_eq!(report.removed_branches, 1);
+/// ```
+#[must_use]
+pub fn optimize(mut program: Expr) -> (Expr, OptimizationReport) {
+    let mut pass = Pass {
+        bound: bound_names(&program),
+        report: OptimizationReport {
+            operations_before: OperationCount::of(&mut program),
+            ..OptimizationReport::default()
+        },
+    };
+    pass.rewrite(&mut program);
+    let mut report = pass.report;
+    report.hoisted_expressions = code_motion::hoist_loop_invariants(&mut program);
+    report.shared_subexpressions = code_motion::share_common_subexpressions(&mut program);
+    report.operations_after = OperationCount::of(&mut program);
+    (program, report)
+}
+
+/// Names the program binds itself; calls of them are never pre-computed
+pub(crate) fn bound_names(program: &Expr) -> HashSet<String> {
+    let mut names = HashSet::new();
+    for_each_expr(program, &mut |expr| match &expr.kind {
+        ExprKind::Let { name, .. } => {
+            names.insert(name.clone());
+        }
+        ExprKind::LetPattern { pattern, .. } => names.extend(extract_pattern_bindings(pattern)),
+        ExprKind::Function { name, params, .. } => {
+            names.insert(name.clone());
+            names.extend(params.iter().map(|param| param.name()));
+        }
+        ExprKind::Lambda { params, .. } => names.extend(params.iter().map(|param| param.name())),
+        ExprKind::For { var, pattern, .. } => match pattern {
+            Some(pattern) => names.extend(extract_pattern_bindings(pattern)),
+            None => {
+                names.insert(var.clone());
+            }
+        },
+        _ => {}
+    });
+    names
+}
+
+/// A literal operand, with a negated literal read as a negative number
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Constant<'a> {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Str(&'a str),
+}
+
+impl Constant<'_> {
+    /// The expression for this value, if both backends read it the same way
+    fn into_kind(self, span: Span) -> Option<ExprKind> {
+        let negated = |literal| ExprKind::Unary {
+            op: UnaryOp::Negate,
+            operand: Box::new(Expr::new(ExprKind::Literal(literal), span)),
+        };
+        match self {
+            Self::Int(n) if i32::try_from(n).is_err() => None,
+            Self::Int(n) if n < 0 => Some(negated(Literal::Integer(-n, None))),
+            Self::Int(n) => Some(ExprKind::Literal(Literal::Integer(n, None))),
+            Self::Float(f) if !f.is_finite() => None,
+            Self::Float(f) if f.is_sign_negative() => Some(negated(Literal::Float(-f))),
+            Self::Float(f) => Some(ExprKind::Literal(Literal::Float(f))),
+            Self::Bool(b) => Some(ExprKind::Literal(Literal::Bool(b))),
+            Self::Str(_) => None,
+        }
+    }
+}
+
+/// The constant `expr` is, if it is a literal or a negated number literal
+fn constant(expr: &Expr) -> Option<Constant<'_>> {
+    match &expr.kind {
+        ExprKind::Literal(Literal::Integer(n, None)) => Some(Constant::Int(*n)),
+        ExprKind::Literal(Literal::Float(f)) => Some(Constant::Float(*f)),
+        ExprKind::Literal(Literal::Bool(b)) => Some(Constant::Bool(*b)),
+        ExprKind::Literal(Literal::String(s)) => Some(Constant::Str(s)),
+        ExprKind::Unary {
+            op: UnaryOp::Negate,
+            operand,
+        } => match constant(operand)? {
+            Constant::Int(n) if matches!(operand.kind, ExprKind::Literal(_)) => {
+                n.checked_neg().map(Constant::Int)
+            }
+            Constant::Float(f) if matches!(operand.kind, ExprKind::Literal(_)) => {
+                Some(Constant::Float(-f))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The rewrite state: names to leave alone and what changed so far
+struct Pass {
+    bound: HashSet<String>,
+    report: OptimizationReport,
+}
+
+impl Pass {
+    /// Optimize the children of `expr`, then `expr` itself
+    fn rewrite(&mut self, expr: &mut Expr) {
+        for_each_child_mut(expr, &mut |child| self.rewrite(child));
+        if let Some(kind) = self.simplify(expr) {
+            expr.kind = kind;
+        }
+    }
+
+    /// The simpler expression `expr` can be replaced by, if any (complexity: 8)
+    fn simplify(&mut self, expr: &Expr) -> Option<ExprKind> {
+        match &expr.kind {
+            ExprKind::Binary { left, op, right } => {
+                let result = fold_binary(constant(left)?, *op, constant(right)?)?;
+                let kind = result.into_kind(expr.span)?;
+                self.report.folded_operations += 1;
+                Some(kind)
+            }
+            ExprKind::Unary { op, operand } => {
+                let result = match (op, constant(operand)?) {
+                    (UnaryOp::Not, Constant::Bool(b)) => Constant::Bool(!b),
+                    // A negated literal is already as simple as it gets
+                    (UnaryOp::Negate, _) if matches!(operand.kind, ExprKind::Literal(_)) => {
+                        return None;
+                    }
+                    (UnaryOp::Negate, Constant::Int(n)) => Constant::Int(n.checked_neg()?),
+                    (UnaryOp::Negate, Constant::Float(f)) => Constant::Float(-f),
+                    _ => return None,
+                };
+                let kind = result.into_kind(expr.span)?;
+                self.report.folded_operations += 1;
+                Some(kind)
+            }
+            ExprKind::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                let Some(Constant::Bool(taken)) = constant(condition) else {
+                    return None;
+                };
+                self.report.removed_branches += 1;
+                let branch = if taken {
+                    Some(then_branch)
+                } else {
+                    else_branch.as_ref()
+                };
+                Some(ExprKind::Block(
+                    branch.map(|b| vec![(**b).clone()]).unwrap_or_default(),
+                ))
+            }
+            ExprKind::Call { func, args } => {
+                let ExprKind::Identifier(name) = &func.kind else {
+                    return None;
+                };
+                if self.bound.contains(name) {
+                    return None;
+                }
+                let args = args.iter().map(constant).collect::<Option<Vec<_>>>()?;
+                let kind = call_builtin(name, &args)?.into_kind(expr.span)?;
+                self.report.precomputed_calls += 1;
+                Some(kind)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The result of `left op right`, when it is a constant of the operands' type
+fn fold_binary<'a>(left: Constant<'a>, op: BinaryOp, right: Constant<'a>) -> Option<Constant<'a>> {
+    match (left, right) {
+        (Constant::Int(a), Constant::Int(b)) => match op {
+            BinaryOp::Add => a.checked_add(b).map(Constant::Int),
+            BinaryOp::Subtract => a.checked_sub(b).map(Constant::Int),
+            BinaryOp::Multiply => a.checked_mul(b).map(Constant::Int),
+            BinaryOp::Divide => a.checked_div(b).map(Constant::Int),
+            BinaryOp::Modulo => a.checked_rem(b).map(Constant::Int),
+            BinaryOp::Power => int_pow(a, b),
+            _ => compare(a.partial_cmp(&b), op),
+        },
+        (Constant::Float(a), Constant::Float(b)) => match op {
+            BinaryOp::Add => Some(Constant::Float(a + b)),
+            BinaryOp::Subtract => Some(Constant::Float(a - b)),
+            BinaryOp::Multiply => Some(Constant::Float(a * b)),
+            BinaryOp::Divide => Some(Constant::Float(a / b)),
+            // The interpreter rejects a zero divisor
+            BinaryOp::Modulo if b != 0.0 => Some(Constant::Float(a % b)),
+            BinaryOp::Power => Some(Constant::Float(a.powf(b))),
+            _ => compare(a.partial_cmp(&b), op),
+        },
+        (Constant::Bool(a), Constant::Bool(b)) => match op {
+            BinaryOp::And => Some(Constant::Bool(a && b)),
+            BinaryOp::Or => Some(Constant::Bool(a || b)),
+            BinaryOp::Equal => Some(Constant::Bool(a == b)),
+            BinaryOp::NotEqual => Some(Constant::Bool(a != b)),
+            _ => None,
+        },
+        (Constant::Str(a), Constant::Str(b)) => match op {
+            BinaryOp::Equal => Some(Constant::Bool(a == b)),
+            BinaryOp::NotEqual => Some(Constant::Bool(a != b)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// A comparison's result given how the operands order
+fn compare(ordering: Option<Ordering>, op: BinaryOp) -> Option<Constant<'static>> {
+    let result = match op {
+        BinaryOp::Equal => ordering == Some(Ordering::Equal),
+        BinaryOp::NotEqual => ordering != Some(Ordering::Equal),
+        BinaryOp::Less => ordering == Some(Ordering::Less),
+        BinaryOp::LessEqual => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+        BinaryOp::Greater | BinaryOp::Gt => ordering == Some(Ordering::Greater),
+        BinaryOp::GreaterEqual => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+        _ => return None,
+    };
+    Some(Constant::Bool(result))
+}
+
+/// `base ** exponent` for a non-negative exponent that does not overflow
+fn int_pow(base: i64, exponent: i64) -> Option<Constant<'static>> {
+    let exponent = u32::try_from(exponent).ok()?;
+    base.checked_pow(exponent).map(Constant::Int)
+}
+
+/// The result of calling a [`PURE_BUILTINS`] function with constant `args`
+fn call_builtin<'a>(name: &str, args: &[Constant<'a>]) -> Option<Constant<'a>> {
+    match (name, args) {
+        ("abs", [Constant::Int(n)]) => n.checked_abs().map(Constant::Int),
+        ("abs", [Constant::Float(f)]) => Some(Constant::Float(f.abs())),
+        ("sqrt", [Constant::Float(f)]) => Some(Constant::Float(f.sqrt())),
+        ("pow", [Constant::Int(base), Constant::Int(exponent)]) => int_pow(*base, *exponent),
+        ("pow", [Constant::Float(base), Constant::Float(exponent)]) => {
+            Some(Constant::Float(base.powf(*exponent)))
+        }
+        ("min", [Constant::Int(a), Constant::Int(b)]) => Some(Constant::Int(*a.min(b))),
+        ("min", [Constant::Float(a), Constant::Float(b)]) => Some(Constant::Float(a.min(*b))),
+        ("max", [Constant::Int(a), Constant::Int(b)]) => Some(Constant::Int(*a.max(b))),
+        ("max", [Constant::Float(a), Constant::Float(b)]) => Some(Constant::Float(a.max(*b))),
+        ("len", [Constant::Str(s)]) => i64::try_from(s.len()).ok().map(Constant::Int),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::Parser;
+
+    fn optimized(source: &str) -> (Expr, OptimizationReport) {
+        optimize(Parser::new(source).parse().expect("source should parse"))
+    }
+
+    /// The single value a program optimizes to, looking through blocks
+    fn value(source: &str) -> ExprKind {
+        let (mut expr, _) = optimized(source);
+        loop {
+            match expr.kind {
+                ExprKind::Block(items) => expr = items.into_iter().last().expect("non-empty block"),
+                kind => return kind,
+            }
+        }
+    }
+
+    #[test]
+    fn test_folds_integer_float_and_boolean_operations() {
+        assert_eq!(
+            value("60 * 60 * 24"),
+            ExprKind::Literal(Literal::Integer(86400, None))
+        );
+        assert_eq!(value("1.5 * 4.0"), ExprKind::Literal(Literal::Float(6.0)));
+        assert_eq!(
+            value("3 < 4 && !false"),
+            ExprKind::Literal(Literal::Bool(true))
+        );
+        assert_eq!(
+            value("\"a\" == \"b\""),
+            ExprKind::Literal(Literal::Bool(false))
+        );
+        let (_, report) = optimized("2 ** 10 - 24");
+        assert_eq!(report.folded_operations, 2);
+    }
+
+    #[test]
+    fn test_negative_results_stay_negated_literals() {
+        let ExprKind::Unary {
+            op: UnaryOp::Negate,
+            operand,
+        } = value("2 - 5")
+        else {
+            panic!("expected a negated literal");
+        };
+        assert_eq!(operand.kind, ExprKind::Literal(Literal::Integer(3, None)));
+        assert_eq!(
+            value("-3 * -2"),
+            ExprKind::Literal(Literal::Integer(6, None))
+        );
+    }
+
+    #[test]
+    fn test_leaves_unsafe_folds_alone() {
+        for source in [
+            "10 / 0",
+            "10 % 0",
+            "1.0 / 0.0",
+            "1 + 2.0",
+            "5i32 + 1",
+            "2 ** -1",
+            "100000 * 100000",
+            "\"a\" + \"b\"",
+        ] {
+            let (_, report) = optimized(source);
+            assert!(report.is_empty(), "{source} should not fold: {report}");
+        }
+    }
+
+    #[test]
+    fn test_removes_dead_branches_keeping_scope() {
+        let (program, report) = optimized("if 1 > 2 { 7 } else { let x = 1; x }");
+        assert_eq!(report.removed_branches, 1);
+        let mut lets = 0;
+        for_each_expr(&program, &mut |expr| {
+            assert!(!matches!(expr.kind, ExprKind::If { .. }));
+            lets += usize::from(matches!(expr.kind, ExprKind::Let { .. }));
+        });
+        assert_eq!(lets, 1);
+        assert_eq!(
+            value("if false { 1 } else { 2 }"),
+            ExprKind::Literal(Literal::Integer(2, None))
+        );
+        let (_, report) = optimized("let y = if false { 1 }\ny");
+        assert_eq!(report.removed_branches, 1);
+    }
+
+    #[test]
+    fn test_precomputes_pure_builtins() {
+        assert_eq!(
+            value("abs(-4) + len(\"hello\")"),
+            ExprKind::Literal(Literal::Integer(9, None))
+        );
+        assert_eq!(value("sqrt(16.0)"), ExprKind::Literal(Literal::Float(4.0)));
+        let (_, report) = optimized("min(3, 9) + max(3, 9) + pow(2, 8)");
+        assert_eq!(report.precomputed_calls, 3);
+    }
+
+    #[test]
+    fn test_user_definitions_shadow_builtins() {
+        let (_, report) = optimized("fun abs(x) { x }\nabs(-4)");
+        assert_eq!(report.precomputed_calls, 0);
+        let (_, report) = optimized("let f = |len| len(\"abc\")\nf");
+        assert_eq!(report.precomputed_calls, 0);
+    }
+
+    #[test]
+    fn test_counts_operations_before_and_after_code_motion() {
+        let (_, report) = optimized("let mut t = 0\nfor i in 0..10 { t = t + i * (w * h) }");
+        assert_eq!(report.hoisted_expressions, 1);
+        assert_eq!(
+            report.operations_before,
+            OperationCount {
+                total: 3,
+                in_loops: 3
+            }
+        );
+        assert_eq!(
+            report.operations_after,
+            OperationCount {
+                total: 3,
+                in_loops: 2
+            }
+        );
+        assert!(report
+            .to_string()
+            .contains("3 (3 in loops) before, 3 (2 in loops) after"));
+    }
+
+    #[test]
+    fn test_optimized_programs_evaluate_the_same() {
+        use crate::runtime::script::{run_script, ScriptOptions};
+        let source = "fun main() {\n    let secs = 60 * 60 * 24\n    let root = sqrt(2.0 * 8.0)\n    if secs > 1000 { secs + abs(-1) } else { 0 }\n}";
+        let plain = run_script(source, &ScriptOptions::default()).unwrap();
+        let optimized =
+            run_script(source, &ScriptOptions::default().with_optimization(true)).unwrap();
+        assert_eq!(plain, optimized);
+        assert_eq!(optimized.value.as_deref(), Some("86401"));
+    }
+}
@@ -0,0 +1,278 @@
+//! Notebook export
+//!
+//! Converts a [`Notebook`] into a format that can be used without the
+//! notebook server:
+//!
+//! - **script**: a runnable `.ruchy` file. Every cell starts with a `// %%`
+//!   marker; markdown cells are kept as `//` comments under a
+//!   `// %% [markdown]` marker, so the script still documents itself.
+//! - **html**: a standalone report with rendered markdown, source and the
+//!   recorded outputs. No external assets are referenced.
+//! - **markdown**: markdown cells verbatim, code cells as fenced `ruchy`
+//!   blocks followed by their outputs.
+
+use crate::notebook::html::html_escape;
+use crate::notebook::server::markdown_to_html;
+use crate::notebook::types::{Cell, CellType, Notebook};
+use std::fmt::Write as _;
+use std::str::FromStr;
+
+/// Marker that starts a cell in an exported script
+pub const CELL_MARKER: &str = "// %%";
+
+/// Title used when the notebook has no leading heading
+const DEFAULT_TITLE: &str = "Ruchy Notebook";
+
+const REPORT_STYLE: &str =
+    "body { font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', sans-serif; \
+max-width: 960px; margin: 2rem auto; padding: 0 1rem; color: #222; line-height: 1.5; }
+.cell { margin: 1rem 0; }
+.prompt { font-family: monospace; font-size: 0.85rem; color: #888; }
+pre { margin: 0.25rem 0; padding: 0.75rem; overflow-x: auto; border-radius: 4px; }
+pre.source { background: #f6f8fa; border: 1px solid #e1e4e8; }
+pre.output { background: #fff; border-left: 3px solid #4c72b0; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #ddd; padding: 0.25rem 0.5rem; }";
+
+/// Target format for [`NotebookConverter::convert`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Runnable `.ruchy` script with cell markers
+    Script,
+    /// Standalone HTML report including outputs
+    Html,
+    /// Markdown document with fenced code and outputs
+    Markdown,
+}
+
+impl ExportFormat {
+    /// File extension for exported files, without the dot
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Script => "ruchy",
+            Self::Html => "html",
+            Self::Markdown => "md",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "script" | "ruchy" => Ok(Self::Script),
+            "html" => Ok(Self::Html),
+            "markdown" | "md" => Ok(Self::Markdown),
+            other => Err(format!(
+                "Unknown export format '{other}' (expected script, html or markdown)"
+            )),
+        }
+    }
+}
+
+/// Converts a notebook into scripts and reports
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::notebook::converter::NotebookConverter;
+/// use ruchy::notebook::{Cell, Notebook};
+///
+/// let mut notebook = Notebook::new();
+/// notebook.add_cell(Cell::markdown("# Sums"));
+/// notebook.add_cell(Cell::code("1 + 2"));
+///
+/// let script = NotebookConverter::new(&notebook).to_script();
+/// assert_eq!(script, "// %% [markdown]\n// # Sums\n\n// %%\n1 + 2\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct NotebookConverter<'a> {
+    notebook: &'a Notebook,
+}
+
+impl<'a> NotebookConverter<'a> {
+    /// Create a converter for `notebook`
+    pub fn new(notebook: &'a Notebook) -> Self {
+        Self { notebook }
+    }
+
+    /// Convert to `format`
+    pub fn convert(&self, format: ExportFormat) -> String {
+        match format {
+            ExportFormat::Script => self.to_script(),
+            ExportFormat::Html => self.to_html(),
+            ExportFormat::Markdown => self.to_markdown(),
+        }
+    }
+
+    /// Title of the notebook: its first top-level markdown heading
+    pub fn title(&self) -> &'a str {
+        self.notebook
+            .markdown_cells()
+            .flat_map(|cell| cell.source.lines())
+            .find_map(|line| line.strip_prefix("# "))
+            .map(str::trim)
+            .filter(|title| !title.is_empty())
+            .unwrap_or(DEFAULT_TITLE)
+    }
+
+    /// Runnable script; cells are separated by [`CELL_MARKER`] lines
+    pub fn to_script(&self) -> String {
+        let cells: Vec<String> = self.notebook.cells.iter().map(script_cell).collect();
+        cells.join("\n")
+    }
+
+    /// Standalone HTML report including recorded outputs
+    pub fn to_html(&self) -> String {
+        let title = html_escape(self.title());
+        let mut body = String::new();
+        for cell in &self.notebook.cells {
+            match cell.cell_type {
+                CellType::Markdown => {
+                    let _ = writeln!(
+                        body,
+                        "<section class=\"cell markdown\">\n{}</section>",
+                        markdown_to_html(&cell.source)
+                    );
+                }
+                CellType::Code => {
+                    let _ = writeln!(
+                        body,
+                        "<section class=\"cell code\">\n<div class=\"prompt\">In [{}]:</div>\n\
+                         <pre class=\"source\"><code class=\"language-ruchy\">{}</code></pre>",
+                        prompt_number(cell),
+                        html_escape(&cell.source)
+                    );
+                    if let Some(output) = non_empty_output(cell) {
+                        let _ =
+                            writeln!(body, "<pre class=\"output\">{}</pre>", html_escape(output));
+                    }
+                    body.push_str("</section>\n");
+                }
+            }
+        }
+        format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>{title}</title>\n<style>\n{REPORT_STYLE}\n</style>\n</head>\n<body>\n\
+             <main class=\"notebook\">\n{body}</main>\n</body>\n</html>\n"
+        )
+    }
+
+    /// Markdown document with code cells as fenced `ruchy` blocks
+    pub fn to_markdown(&self) -> String {
+        let mut blocks = Vec::new();
+        for cell in &self.notebook.cells {
+            match cell.cell_type {
+                CellType::Markdown => blocks.push(format!("{}\n", cell.source.trim_end())),
+                CellType::Code => {
+                    blocks.push(fenced("ruchy", &cell.source));
+                    if let Some(output) = non_empty_output(cell) {
+                        blocks.push(fenced("text", output));
+                    }
+                }
+            }
+        }
+        blocks.join("\n")
+    }
+}
+
+fn script_cell(cell: &Cell) -> String {
+    let source = cell.source.trim_end();
+    match cell.cell_type {
+        CellType::Code => format!("{CELL_MARKER}\n{source}\n"),
+        CellType::Markdown => {
+            let mut out = format!("{CELL_MARKER} [markdown]\n");
+            for line in source.lines() {
+                if line.is_empty() {
+                    out.push_str("//\n");
+                } else {
+                    let _ = writeln!(out, "// {line}");
+                }
+            }
+            out
+        }
+    }
+}
+
+fn prompt_number(cell: &Cell) -> String {
+    cell.execution_count
+        .map_or_else(|| " ".to_string(), |n| n.to_string())
+}
+
+fn non_empty_output(cell: &Cell) -> Option<&str> {
+    cell.output
+        .as_deref()
+        .map(str::trim_end)
+        .filter(|output| !output.is_empty())
+}
+
+/// Fence `text` with enough backticks that it cannot close the block early
+fn fenced(info: &str, text: &str) -> String {
+    let longest_run = text.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest_run.max(2) + 1);
+    format!("{fence}{info}\n{}\n{fence}\n", text.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Notebook {
+        let mut notebook = Notebook::new();
+        notebook.add_cell(Cell::markdown("# Report <1>\n\nSome **text**."));
+        let mut code = Cell::code("let x = 40\nx + 2");
+        code.output = Some("42".to_string());
+        code.execution_count = Some(3);
+        notebook.add_cell(code);
+        notebook.add_cell(Cell::code("println(\"<b>\")"));
+        notebook
+    }
+
+    #[test]
+    fn test_script_marks_cells_and_comments_markdown() {
+        let script = NotebookConverter::new(&sample()).to_script();
+        assert_eq!(
+            script,
+            "// %% [markdown]\n// # Report <1>\n//\n// Some **text**.\n\n\
+             // %%\nlet x = 40\nx + 2\n\n// %%\nprintln(\"<b>\")\n"
+        );
+    }
+
+    #[test]
+    fn test_html_report_includes_escaped_source_and_outputs() {
+        let html = NotebookConverter::new(&sample()).to_html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>Report &lt;1&gt;</title>"), "{html}");
+        assert!(html.contains("<strong>text</strong>"), "{html}");
+        assert!(html.contains("In [3]:"), "{html}");
+        assert!(html.contains("<pre class=\"output\">42</pre>"), "{html}");
+        assert!(html.contains("println(&quot;&lt;b&gt;&quot;)"), "{html}");
+        assert_eq!(html.matches("class=\"output\"").count(), 1);
+    }
+
+    #[test]
+    fn test_markdown_fences_code_and_outputs() {
+        let markdown = NotebookConverter::new(&sample()).convert(ExportFormat::Markdown);
+        assert!(
+            markdown.contains("```ruchy\nlet x = 40\nx + 2\n```\n\n```text\n42\n```\n"),
+            "{markdown}"
+        );
+        assert_eq!(fenced("text", "a ``` b"), "````text\na ``` b\n````\n");
+    }
+
+    #[test]
+    fn test_export_format_parsing() {
+        assert_eq!("script".parse(), Ok(ExportFormat::Script));
+        assert_eq!("HTML".parse(), Ok(ExportFormat::Html));
+        assert_eq!(
+            "md".parse::<ExportFormat>().map(ExportFormat::extension),
+            Ok("md")
+        );
+        assert!("pdf".parse::<ExportFormat>().unwrap_err().contains("pdf"));
+        assert_eq!(
+            NotebookConverter::new(&Notebook::new()).title(),
+            DEFAULT_TITLE
+        );
+    }
+}
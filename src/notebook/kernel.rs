@@ -0,0 +1,740 @@
+//! Persistent kernel sessions for the notebook server
+//!
+//! Each notebook gets one [`KernelSession`]: a worker thread that owns a
+//! single interpreter for the lifetime of the session, so bindings made by one
+//! cell are visible to every later cell. A cell is parsed once per execution;
+//! the same AST is evaluated and analysed for the names it defines and the
+//! names it reads from other cells.
+//!
+//! Sessions behave like Jupyter kernels:
+//!
+//! - **Execution counts.** Every execute request takes the next count
+//!   (`In [n]`), including requests that fail. Restarting resets it.
+//! - **Dependency invalidation.** When a cell runs, every successful cell that
+//!   reads a name it (re)defines is marked [`CellStatus::Stale`], transitively
+//!   through the names the stale cells define. Stale cells keep their output
+//!   until they are run again.
+//! - **Interrupt.** [`KernelSession::interrupt`] stops the running cell at the
+//!   next loop iteration (see [`crate::runtime::interrupt`]). Interpreter
+//!   state is kept.
+//! - **Restart.** [`KernelSession::restart`] replaces the worker with a fresh
+//!   interpreter and forgets all cell state.
+//! - **Rich output.** A result with an HTML, markdown or image form (a
+//!   `DataFrame`, a `std::display` or `std::plot` value) comes with a
+//!   [`MimeBundle`] next to its text output.
+//!
+//! Each cell's `println` output is captured on its session's worker thread,
+//! so cells running concurrently in different sessions keep their output
+//! apart.
+
+use crate::frontend::ast::{Expr, ExprKind, Pattern};
+use crate::frontend::parser::Parser;
+use crate::notebook::mime::MimeBundle;
+use crate::runtime::interpreter::Interpreter;
+use crate::runtime::{interrupt, output, InterpreterError, Value};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex, PoisonError};
+
+/// Stack size of kernel worker threads (matches the main thread)
+const KERNEL_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Outcome of a cell's latest execution
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CellStatus {
+    /// Ran to completion
+    Ok,
+    /// Failed to parse or raised an error
+    Error,
+    /// Stopped by [`KernelSession::interrupt`]
+    Interrupted,
+    /// Ran successfully, but a cell it depends on has run since
+    Stale,
+}
+
+/// What the kernel knows about one cell
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CellState {
+    /// Source of the latest execution
+    pub source: String,
+    /// Execution count of the latest execution
+    pub execution_count: u32,
+    /// Outcome of the latest execution
+    pub status: CellStatus,
+    /// Captured output followed by the display form of the result
+    pub output: String,
+    /// Rich representations of the result (tables, plots, HTML, markdown)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<MimeBundle>,
+    /// Error message when the cell failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Top-level names the cell binds or assigns
+    pub defines: BTreeSet<String>,
+    /// Names the cell reads without defining them itself
+    pub uses: BTreeSet<String>,
+}
+
+/// Reply to an execute request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CellReply {
+    /// Cell that was executed
+    pub cell_id: String,
+    /// Execution count assigned to this request
+    pub execution_count: u32,
+    /// Outcome of the execution
+    pub status: CellStatus,
+    /// Captured output followed by the display form of the result
+    pub output: String,
+    /// Rich representations of the result (tables, plots, HTML, markdown)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<MimeBundle>,
+    /// Error message when the cell failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Cells marked stale by this execution, sorted by id
+    pub invalidated: Vec<String>,
+}
+
+/// Names a cell defines at top level and the names it reads
+///
+/// Definitions are `let` bindings, assignments, functions and type
+/// declarations at the top level of the cell. Reads are every identifier the
+/// cell references, minus the names it binds with `let`, `fun` or a type
+/// declaration itself. Locals and parameters count as reads too, which errs
+/// on the side of invalidating a cell too often rather than too rarely.
+pub fn cell_dependencies(ast: &Expr) -> (BTreeSet<String>, BTreeSet<String>) {
+    let mut bound = BTreeSet::new();
+    let mut assigned = BTreeSet::new();
+    collect_definitions(ast, &mut bound, &mut assigned);
+    let mut uses = BTreeSet::new();
+    // Every `ExprKind::Identifier` serializes as `{"Identifier": name}`
+    if let Ok(tree) = serde_json::to_value(ast) {
+        collect_identifiers(&tree, &mut uses);
+    }
+    uses.retain(|name| !bound.contains(name));
+    bound.extend(assigned);
+    (bound, uses)
+}
+
+fn collect_definitions(expr: &Expr, bound: &mut BTreeSet<String>, assigned: &mut BTreeSet<String>) {
+    match &expr.kind {
+        ExprKind::Block(exprs) => {
+            for expr in exprs {
+                collect_definitions(expr, bound, assigned);
+            }
+        }
+        ExprKind::Let { name, body, .. } => {
+            bound.insert(name.clone());
+            collect_definitions(body, bound, assigned);
+        }
+        ExprKind::LetPattern { pattern, body, .. } => {
+            collect_pattern_names(pattern, bound);
+            collect_definitions(body, bound, assigned);
+        }
+        ExprKind::Assign { target, .. } | ExprKind::CompoundAssign { target, .. } => {
+            if let ExprKind::Identifier(name) = &target.kind {
+                assigned.insert(name.clone());
+            }
+        }
+        ExprKind::Function { name, .. }
+        | ExprKind::Struct { name, .. }
+        | ExprKind::TupleStruct { name, .. }
+        | ExprKind::Class { name, .. }
+        | ExprKind::Enum { name, .. } => {
+            bound.insert(name.clone());
+        }
+        _ => {}
+    }
+}
+
+fn collect_pattern_names(pattern: &Pattern, names: &mut BTreeSet<String>) {
+    match pattern {
+        Pattern::Identifier(name) => {
+            names.insert(name.clone());
+        }
+        Pattern::Tuple(patterns) | Pattern::List(patterns) => {
+            for pattern in patterns {
+                collect_pattern_names(pattern, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_identifiers(node: &serde_json::Value, names: &mut BTreeSet<String>) {
+    match node {
+        serde_json::Value::Object(map) => {
+            if let (1, Some(serde_json::Value::String(name))) = (map.len(), map.get("Identifier")) {
+                names.insert(name.clone());
+            }
+            for value in map.values() {
+                collect_identifiers(value, names);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                collect_identifiers(item, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Result of evaluating one cell on the worker
+#[derive(Debug)]
+struct Evaluation {
+    output: String,
+    data: Option<MimeBundle>,
+    error: Option<String>,
+    interrupted: bool,
+}
+
+struct Job {
+    ast: Expr,
+    reply: mpsc::Sender<Evaluation>,
+}
+
+/// Worker thread handle; dropping it lets the thread exit
+#[derive(Debug)]
+struct Worker {
+    jobs: mpsc::Sender<Job>,
+    interrupt: Arc<AtomicBool>,
+    /// Whether a cell is being evaluated
+    busy: Arc<AtomicBool>,
+}
+
+impl Worker {
+    fn spawn() -> Result<Self, String> {
+        let (jobs, receiver) = mpsc::channel::<Job>();
+        let interrupt = Arc::new(AtomicBool::new(false));
+        let busy = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&interrupt);
+        let running = Arc::clone(&busy);
+        std::thread::Builder::new()
+            .name("ruchy-kernel".to_string())
+            .stack_size(KERNEL_STACK_SIZE)
+            .spawn(move || {
+                interrupt::install(Arc::clone(&flag));
+                let mut interpreter = Interpreter::new();
+                for job in receiver {
+                    // An interrupt only applies to the cell that was running
+                    flag.store(false, Ordering::SeqCst);
+                    running.store(true, Ordering::SeqCst);
+                    let evaluation = evaluate(&mut interpreter, &job.ast);
+                    running.store(false, Ordering::SeqCst);
+                    // The requester is gone if the kernel was restarted meanwhile
+                    let _ = job.reply.send(evaluation);
+                }
+            })
+            .map_err(|e| format!("Failed to start kernel thread: {e}"))?;
+        Ok(Self {
+            jobs,
+            interrupt,
+            busy,
+        })
+    }
+}
+
+impl Drop for Worker {
+    fn drop(&mut self) {
+        // Stop whatever the abandoned interpreter is still running
+        self.interrupt.store(true, Ordering::SeqCst);
+    }
+}
+
+fn evaluate(interpreter: &mut Interpreter, ast: &Expr) -> Evaluation {
+    // A cell's top-level bindings stay in the session, even when it fails
+    let (result, printed) = output::capture(|| match interpreter.eval_top_level(ast) {
+        Ok(value) | Err(InterpreterError::Return(value)) => Ok(value),
+        Err(e) => Err(e),
+    });
+    match result {
+        Ok(value) => {
+            let output = match &value {
+                Value::Nil => printed.trim_end().to_string(),
+                value => format!("{printed}{value}"),
+            };
+            Evaluation {
+                output,
+                data: MimeBundle::for_value(&value),
+                error: None,
+                interrupted: false,
+            }
+        }
+        Err(e) => Evaluation {
+            interrupted: matches!(&e, InterpreterError::RuntimeError(m)
+                if m == interrupt::INTERRUPTED_MESSAGE),
+            output: printed.trim_end().to_string(),
+            data: None,
+            error: Some(e.to_string()),
+        },
+    }
+}
+
+#[derive(Debug)]
+struct SessionState {
+    worker: Worker,
+    /// Bumped on restart so replies from the old worker are not recorded
+    generation: u64,
+    execution_count: u32,
+    cells: BTreeMap<String, CellState>,
+}
+
+/// One notebook's kernel: a persistent interpreter plus per-cell state
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::notebook::kernel::{CellStatus, KernelSession};
+///
+/// let kernel = KernelSession::start().unwrap();
+/// kernel.execute("a", "let x = 20").unwrap();
+/// let reply = kernel.execute("b", "x + 1").unwrap();
+/// assert_eq!(reply.output, "21");
+/// assert_eq!(reply.execution_count, 2);
+///
+/// // Redefining `x` makes cell `b` stale
+/// let reply = kernel.execute("a", "let x = 40").unwrap();
+/// assert_eq!(reply.invalidated, vec!["b".to_string()]);
+/// assert_eq!(kernel.cell("b").unwrap().status, CellStatus::Stale);
+/// ```
+#[derive(Debug)]
+pub struct KernelSession {
+    state: Mutex<SessionState>,
+}
+
+impl KernelSession {
+    /// Start a session with a fresh interpreter
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the worker thread cannot be spawned.
+    pub fn start() -> Result<Self, String> {
+        Ok(Self {
+            state: Mutex::new(SessionState {
+                worker: Worker::spawn()?,
+                generation: 0,
+                execution_count: 0,
+                cells: BTreeMap::new(),
+            }),
+        })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, SessionState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Execute `source` as cell `cell_id`, blocking until it finishes
+    ///
+    /// Parse and runtime errors are reported in the reply, not as `Err`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the worker thread died, or the kernel was
+    /// restarted while the cell was running.
+    pub fn execute(&self, cell_id: &str, source: &str) -> Result<CellReply, String> {
+        let parsed = Parser::new(source).parse();
+        let (generation, execution_count, jobs) = {
+            let mut state = self.lock();
+            state.execution_count += 1;
+            (
+                state.generation,
+                state.execution_count,
+                state.worker.jobs.clone(),
+            )
+        };
+        let ast = match parsed {
+            Ok(ast) => ast,
+            Err(e) => {
+                let evaluation = Evaluation {
+                    output: String::new(),
+                    data: None,
+                    error: Some(format!("Syntax error: {e}")),
+                    interrupted: false,
+                };
+                let cell = Self::cell_state(
+                    source,
+                    execution_count,
+                    evaluation,
+                    (BTreeSet::new(), BTreeSet::new()),
+                );
+                // Nothing ran, so no other cell is affected
+                return Ok(self.record(cell_id, cell, generation, false));
+            }
+        };
+        let dependencies = cell_dependencies(&ast);
+
+        let (reply, receiver) = mpsc::channel();
+        jobs.send(Job { ast, reply })
+            .map_err(|_| "Kernel worker has stopped; restart the kernel".to_string())?;
+        let evaluation = receiver
+            .recv()
+            .map_err(|_| "Kernel worker has stopped; restart the kernel".to_string())?;
+        if self.lock().generation != generation {
+            return Err("Kernel was restarted while the cell was running".to_string());
+        }
+        // A failed cell keeps its definitions: it may have bound some of them
+        let cell = Self::cell_state(source, execution_count, evaluation, dependencies);
+        Ok(self.record(cell_id, cell, generation, true))
+    }
+
+    fn cell_state(
+        source: &str,
+        execution_count: u32,
+        evaluation: Evaluation,
+        (defines, uses): (BTreeSet<String>, BTreeSet<String>),
+    ) -> CellState {
+        let status = match (&evaluation.error, evaluation.interrupted) {
+            (None, _) => CellStatus::Ok,
+            (Some(_), true) => CellStatus::Interrupted,
+            (Some(_), false) => CellStatus::Error,
+        };
+        CellState {
+            source: source.to_string(),
+            execution_count,
+            status,
+            output: evaluation.output,
+            data: evaluation.data,
+            error: evaluation.error,
+            defines,
+            uses,
+        }
+    }
+
+    /// Store the cell's new state, invalidating cells that read its names
+    fn record(&self, cell_id: &str, cell: CellState, generation: u64, ran: bool) -> CellReply {
+        let mut state = self.lock();
+        let mut invalidated = Vec::new();
+        if state.generation == generation {
+            if ran {
+                let mut changed = cell.defines.clone();
+                if let Some(previous) = state.cells.get(cell_id) {
+                    // Names the cell no longer defines have changed too
+                    changed.extend(previous.defines.iter().cloned());
+                }
+                invalidated = invalidate_dependents(&mut state.cells, cell_id, changed);
+            }
+            state.cells.insert(cell_id.to_string(), cell.clone());
+        }
+        CellReply {
+            cell_id: cell_id.to_string(),
+            execution_count: cell.execution_count,
+            status: cell.status,
+            output: cell.output,
+            data: cell.data,
+            error: cell.error,
+            invalidated,
+        }
+    }
+
+    /// Stop the running cell; returns whether one was running
+    ///
+    /// Cells queued behind it still run. Interpreter state is kept.
+    pub fn interrupt(&self) -> bool {
+        let state = self.lock();
+        // The worker clears the flag before marking itself busy, so reading
+        // `busy` first never loses the interrupt; an idle interrupt is harmless
+        let running = state.worker.busy.load(Ordering::SeqCst);
+        state.worker.interrupt.store(true, Ordering::SeqCst);
+        running
+    }
+
+    /// Replace the interpreter and forget all cells and execution counts
+    ///
+    /// A cell still running on the old interpreter is interrupted and its
+    /// result discarded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the new worker thread cannot be spawned; the old
+    /// session is kept in that case.
+    pub fn restart(&self) -> Result<(), String> {
+        let worker = Worker::spawn()?;
+        let mut state = self.lock();
+        state.worker = worker;
+        state.generation += 1;
+        state.execution_count = 0;
+        state.cells.clear();
+        Ok(())
+    }
+
+    /// Number of execute requests since the session started or restarted
+    pub fn execution_count(&self) -> u32 {
+        self.lock().execution_count
+    }
+
+    /// State of one cell, if it has been executed
+    pub fn cell(&self, cell_id: &str) -> Option<CellState> {
+        self.lock().cells.get(cell_id).cloned()
+    }
+
+    /// State of every executed cell, by id
+    pub fn cells(&self) -> BTreeMap<String, CellState> {
+        self.lock().cells.clone()
+    }
+}
+
+/// Mark successful cells that read `changed` as stale, transitively
+fn invalidate_dependents(
+    cells: &mut BTreeMap<String, CellState>,
+    executed: &str,
+    mut changed: BTreeSet<String>,
+) -> Vec<String> {
+    let mut invalidated = Vec::new();
+    loop {
+        let newly_stale: Vec<String> = cells
+            .iter()
+            .filter(|(id, cell)| {
+                id.as_str() != executed
+                    && cell.status == CellStatus::Ok
+                    && !cell.uses.is_disjoint(&changed)
+            })
+            .map(|(id, _)| id.clone())
+            .collect();
+        if newly_stale.is_empty() {
+            break;
+        }
+        for id in newly_stale {
+            if let Some(cell) = cells.get_mut(&id) {
+                cell.status = CellStatus::Stale;
+                changed.extend(cell.defines.iter().cloned());
+            }
+            invalidated.push(id);
+        }
+    }
+    invalidated.sort();
+    invalidated
+}
+
+/// Kernel sessions by notebook id
+#[derive(Debug, Default)]
+pub struct KernelManager {
+    sessions: Mutex<HashMap<String, Arc<KernelSession>>>,
+}
+
+impl KernelManager {
+    /// Create a manager with no sessions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The notebook's session, starting one on first use
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a new session cannot be started.
+    pub fn session(&self, notebook: &str) -> Result<Arc<KernelSession>, String> {
+        let mut sessions = self.sessions.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(session) = sessions.get(notebook) {
+            return Ok(Arc::clone(session));
+        }
+        let session = Arc::new(KernelSession::start()?);
+        sessions.insert(notebook.to_string(), Arc::clone(&session));
+        Ok(session)
+    }
+
+    /// The notebook's session, if one is running
+    pub fn get(&self, notebook: &str) -> Option<Arc<KernelSession>> {
+        self.sessions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(notebook)
+            .cloned()
+    }
+
+    /// Stop the notebook's session; returns whether one was running
+    pub fn shutdown(&self, notebook: &str) -> bool {
+        self.sessions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(notebook)
+            .is_some()
+    }
+
+    /// Ids of notebooks with a running session, sorted
+    pub fn notebooks(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self
+            .sessions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .keys()
+            .cloned()
+            .collect();
+        ids.sort();
+        ids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn deps(source: &str) -> (BTreeSet<String>, BTreeSet<String>) {
+        cell_dependencies(&Parser::new(source).parse().expect("source should parse"))
+    }
+
+    fn names(names: &[&str]) -> BTreeSet<String> {
+        names.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_cell_dependencies() {
+        assert_eq!(
+            deps("let total = price * qty\ncount = count + 1\ntotal"),
+            (
+                names(&["count", "total"]),
+                names(&["count", "price", "qty"])
+            )
+        );
+        let (defines, uses) = deps("fun area(w) { w * scale }\nlet (a, b) = (area(2), 3)");
+        assert_eq!(defines, names(&["a", "area", "b"]));
+        assert!(uses.contains("scale") && !uses.contains("area"), "{uses:?}");
+    }
+
+    #[test]
+    fn test_state_persists_and_counts_increase() {
+        let kernel = KernelSession::start().unwrap();
+        let first = kernel.execute("a", "let x = 2\nprintln(\"hi\")").unwrap();
+        assert_eq!((first.execution_count, first.status), (1, CellStatus::Ok));
+        assert_eq!(first.output, "hi");
+        let second = kernel.execute("b", "x * 21").unwrap();
+        assert_eq!((second.execution_count, second.output.as_str()), (2, "42"));
+
+        let failed = kernel.execute("c", "undefined_name").unwrap();
+        assert_eq!(failed.status, CellStatus::Error);
+        assert_eq!(failed.execution_count, 3);
+        let syntax = kernel.execute("d", "let = ").unwrap();
+        assert_eq!(syntax.status, CellStatus::Error);
+        assert_eq!(kernel.execution_count(), 4);
+    }
+
+    #[test]
+    fn test_concurrent_sessions_keep_output_apart() {
+        let workers: Vec<_> = ["a", "b"]
+            .into_iter()
+            .map(|tag| {
+                let kernel = KernelSession::start().unwrap();
+                std::thread::spawn(move || {
+                    let source = format!("for i in 0..200 {{ println(\"{tag}\") }}");
+                    (tag, kernel.execute("cell", &source).unwrap().output)
+                })
+            })
+            .collect();
+        for worker in workers {
+            let (tag, output) = worker.join().unwrap();
+            assert_eq!(output.lines().count(), 200);
+            assert!(output.lines().all(|line| line == tag), "{output}");
+        }
+    }
+
+    #[test]
+    fn test_rich_results_carry_mime_bundle() {
+        use crate::notebook::mime::{IMAGE_SVG, TEXT_HTML, TEXT_MARKDOWN};
+        let kernel = KernelSession::start().unwrap();
+        assert_eq!(kernel.execute("a", "1 + 1").unwrap().data, None);
+
+        let plot = kernel.execute("b", "std::plot::line([1, 4, 9])").unwrap();
+        let data = plot.data.expect("plots are rich");
+        assert!(data.get(IMAGE_SVG).unwrap().starts_with("<svg"));
+        assert_eq!(kernel.cells()["b"].data.as_ref(), Some(&data));
+
+        let notes = kernel
+            .execute("c", "std::display::markdown(\"# Notes\")")
+            .unwrap();
+        assert_eq!(notes.data.unwrap().get(TEXT_MARKDOWN), Some("# Notes"));
+
+        let table = kernel
+            .execute("d", "DataFrame::new().column(\"n\", [1, 2]).build()")
+            .unwrap();
+        let data = table.data.expect("dataframes are rich");
+        assert!(data.get(TEXT_HTML).unwrap().contains("<th>n</th>"));
+    }
+
+    #[test]
+    fn test_reexecution_invalidates_dependents_transitively() {
+        let kernel = KernelSession::start().unwrap();
+        kernel.execute("a", "let base = 1").unwrap();
+        kernel.execute("b", "let doubled = base * 2").unwrap();
+        kernel.execute("c", "doubled + 1").unwrap();
+        kernel.execute("d", "let other = 5").unwrap();
+
+        let reply = kernel.execute("a", "let base = 10").unwrap();
+        assert_eq!(reply.invalidated, vec!["b".to_string(), "c".to_string()]);
+        let cells = kernel.cells();
+        assert_eq!(cells["b"].status, CellStatus::Stale);
+        assert_eq!(cells["c"].status, CellStatus::Stale);
+        assert_eq!(cells["d"].status, CellStatus::Ok);
+        // Stale cells keep their output until they run again
+        assert_eq!(cells["c"].output, "3");
+
+        let rerun = kernel.execute("b", "let doubled = base * 2").unwrap();
+        assert_eq!(rerun.invalidated, Vec::<String>::new());
+        assert_eq!(kernel.execute("c", "doubled + 1").unwrap().output, "21");
+    }
+
+    #[test]
+    fn test_interrupt_keeps_state() {
+        let kernel = Arc::new(KernelSession::start().unwrap());
+        kernel.execute("a", "let mut n = 0").unwrap();
+        let running = Arc::clone(&kernel);
+        let handle =
+            std::thread::spawn(move || running.execute("b", "loop { n = n + 1 }").unwrap());
+        while !kernel.interrupt() {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        let reply = handle.join().unwrap();
+        assert_eq!(reply.status, CellStatus::Interrupted);
+        let after = kernel.execute("c", "n > 0").unwrap();
+        assert_eq!(after.output, "true");
+
+        let handle = {
+            let running = Arc::clone(&kernel);
+            std::thread::spawn(move || {
+                running
+                    .execute("d", "let mut m = 0\nloop { m = m + 1 }")
+                    .unwrap()
+            })
+        };
+        while !kernel.interrupt() {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(handle.join().unwrap().status, CellStatus::Interrupted);
+        assert_eq!(kernel.execute("e", "m > 0").unwrap().output, "true");
+    }
+
+    #[test]
+    fn test_restart_resets_state() {
+        let kernel = KernelSession::start().unwrap();
+        kernel.execute("a", "let x = 1").unwrap();
+        kernel.restart().unwrap();
+        assert_eq!(kernel.execution_count(), 0);
+        assert!(kernel.cells().is_empty());
+        let reply = kernel.execute("b", "x").unwrap();
+        assert_eq!(reply.status, CellStatus::Error);
+        assert_eq!(reply.execution_count, 1);
+    }
+
+    #[test]
+    fn test_manager_keeps_one_session_per_notebook() {
+        let manager = KernelManager::new();
+        manager
+            .session("one")
+            .unwrap()
+            .execute("a", "let x = 1")
+            .unwrap();
+        let two = manager.session("two").unwrap();
+        assert_eq!(two.execute("a", "x").unwrap().status, CellStatus::Error);
+        let one = manager.session("one").unwrap();
+        assert_eq!(one.execute("b", "x + 1").unwrap().output, "2");
+        assert_eq!(
+            manager.notebooks(),
+            vec!["one".to_string(), "two".to_string()]
+        );
+        assert!(manager.shutdown("one"));
+        assert!(manager.get("one").is_none());
+    }
+}
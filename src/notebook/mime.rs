@@ -0,0 +1,132 @@
+//! MIME bundles for rich cell output
+//!
+//! A cell whose value has a richer form than text sends a bundle of
+//! representations keyed by MIME type, like Jupyter's `display_data`:
+//!
+//! ```json
+//! {"text/plain": "shape: (2, 1) ...", "text/html": "<table class=\"dataframe\">...</table>"}
+//! ```
+//!
+//! Bundles are built for `DataFrame` values (an HTML table) and for the
+//! display objects returned by `std::display` and `std::plot` (see
+//! [`crate::runtime::eval_rich_output`]). `text/plain` is always present, so
+//! clients that only understand text keep working. The web frontend renders
+//! the richest entry in [`MimeBundle::DISPLAY_ORDER`].
+
+use crate::runtime::eval_rich_output::{dataframe_html, display_data};
+pub use crate::runtime::eval_rich_output::{IMAGE_PNG, IMAGE_SVG, TEXT_HTML, TEXT_MARKDOWN};
+use crate::runtime::Value;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Plain text representation
+pub const TEXT_PLAIN: &str = "text/plain";
+
+/// Representations of one value, keyed by MIME type
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MimeBundle(BTreeMap<String, String>);
+
+impl MimeBundle {
+    /// MIME types a renderer should prefer, richest first
+    pub const DISPLAY_ORDER: [&'static str; 5] =
+        [IMAGE_PNG, IMAGE_SVG, TEXT_HTML, TEXT_MARKDOWN, TEXT_PLAIN];
+
+    /// Bundle for `value`, or `None` if text is its only representation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruchy::notebook::mime::{MimeBundle, TEXT_HTML, TEXT_PLAIN};
+    /// use ruchy::runtime::eval_rich_output::display_value;
+    /// use ruchy::runtime::Value;
+    ///
+    /// assert_eq!(MimeBundle::for_value(&Value::Integer(42)), None);
+    ///
+    /// let html = display_value(TEXT_HTML, "<b>42</b>".to_string());
+    /// let bundle = MimeBundle::for_value(&html).unwrap();
+    /// assert_eq!(bundle.get(TEXT_HTML), Some("<b>42</b>"));
+    /// assert_eq!(bundle.get(TEXT_PLAIN), Some("<b>42</b>"));
+    /// ```
+    pub fn for_value(value: &Value) -> Option<Self> {
+        let mut bundle = Self::default();
+        match value {
+            Value::DataFrame { columns } => bundle.insert(TEXT_HTML, dataframe_html(columns)),
+            _ => {
+                let (mime, data) = display_data(value)?;
+                bundle.insert(mime, data.to_string());
+            }
+        }
+        bundle
+            .0
+            .entry(TEXT_PLAIN.to_string())
+            .or_insert_with(|| value.to_string());
+        Some(bundle)
+    }
+
+    /// Add or replace the representation for `mime`
+    pub fn insert(&mut self, mime: &str, data: String) {
+        self.0.insert(mime.to_string(), data);
+    }
+
+    /// Representation for `mime`
+    pub fn get(&self, mime: &str) -> Option<&str> {
+        self.0.get(mime).map(String::as_str)
+    }
+
+    /// Richest representation by [`Self::DISPLAY_ORDER`], as `(mime, data)`
+    pub fn preferred(&self) -> Option<(&'static str, &str)> {
+        Self::DISPLAY_ORDER
+            .iter()
+            .find_map(|&mime| self.get(mime).map(|data| (mime, data)))
+    }
+
+    /// MIME types in the bundle, sorted
+    pub fn mime_types(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::eval_rich_output::display_value;
+    use crate::runtime::DataFrameColumn;
+
+    #[test]
+    fn test_dataframe_bundle_has_html_table_and_text() {
+        let df = Value::DataFrame {
+            columns: vec![DataFrameColumn {
+                name: "n".to_string(),
+                values: vec![Value::Integer(1), Value::Integer(2)],
+            }],
+        };
+        let bundle = MimeBundle::for_value(&df).expect("dataframes are rich");
+        assert_eq!(
+            bundle.mime_types().collect::<Vec<_>>(),
+            [TEXT_HTML, TEXT_PLAIN]
+        );
+        assert!(bundle.get(TEXT_HTML).unwrap().contains("<td>2</td>"));
+        assert!(bundle.get(TEXT_PLAIN).unwrap().starts_with("shape: (2, 1)"));
+        assert_eq!(bundle.preferred().map(|(mime, _)| mime), Some(TEXT_HTML));
+    }
+
+    #[test]
+    fn test_image_bundle_prefers_image_and_serializes_flat() {
+        let svg = display_value(IMAGE_SVG, "<svg/>".to_string());
+        let bundle = MimeBundle::for_value(&svg).unwrap();
+        assert_eq!(bundle.preferred(), Some((IMAGE_SVG, "<svg/>")));
+        assert_eq!(bundle.get(TEXT_PLAIN), Some("<image/svg+xml, 6 bytes>"));
+        let json = serde_json::to_value(&bundle).unwrap();
+        assert_eq!(json[IMAGE_SVG], "<svg/>");
+    }
+
+    #[test]
+    fn test_plain_values_have_no_bundle() {
+        assert_eq!(MimeBundle::for_value(&Value::Nil), None);
+        assert_eq!(
+            MimeBundle::for_value(&Value::from_string("<b>".to_string())),
+            None
+        );
+    }
+}
@@ -1,7 +1,10 @@
+pub mod converter; // Export to scripts and HTML/markdown reports
 pub mod dataframe;
 pub mod engine;
 pub mod execution;
 pub mod html;
+pub mod kernel; // Persistent per-notebook kernel sessions
+pub mod mime; // MIME bundles for rich cell output
 pub mod persistence;
 pub mod runtime; // Pure Rust NotebookRuntime - probador validated
 pub mod server;
@@ -10,10 +13,13 @@ pub mod testing;
 pub mod types; // NOTEBOOK-009: Jupyter-style notebook types
 pub mod wasm;
 
+pub use converter::{ExportFormat, NotebookConverter};
 pub use dataframe::{ColumnType, DataFrame};
 pub use engine::NotebookEngine;
 pub use execution::CellExecutionResult;
 pub use html::{html_escape, HtmlFormatter};
+pub use kernel::{CellStatus, KernelManager, KernelSession};
+pub use mime::MimeBundle;
 pub use persistence::{Checkpoint, TransactionResult};
 pub use runtime::NotebookRuntime; // Pure Rust runtime
 pub use server::start_server;
@@ -6,19 +6,26 @@
 struct ExecuteRequest {
     source: String,
 }
+use crate::notebook::kernel::{CellReply, CellState, KernelManager};
+use crate::notebook::mime::MimeBundle;
 use axum::{
-    extract::State,
+    extract::{Path, State},
     response::Html,
-    routing::{get, post},
+    routing::{delete, get, post},
     Json, Router,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::net::SocketAddr;
+use std::sync::Arc;
 
 #[derive(Debug, Serialize, Deserialize)]
 struct ExecuteResponse {
     output: String,
     success: bool,
+    /// Rich representations of the result, keyed by MIME type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    data: Option<MimeBundle>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
@@ -62,6 +69,47 @@ struct SaveNotebookResponse {
     error: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct ExecuteCellRequest {
+    cell_id: String,
+    source: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ExecuteCellResponse {
+    success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply: Option<CellReply>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KernelCellsResponse {
+    execution_count: u32,
+    cells: BTreeMap<String, CellState>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KernelActionResponse {
+    success: bool,
+    /// For interrupt: whether a cell was running
+    #[serde(skip_serializing_if = "Option::is_none")]
+    interrupted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl KernelActionResponse {
+    fn from_result(result: Result<(), String>) -> Self {
+        Self {
+            success: result.is_ok(),
+            interrupted: None,
+            error: result.err(),
+        }
+    }
+}
+
 async fn health() -> &'static str {
     "OK"
 }
@@ -94,6 +142,7 @@ async fn execute_handler(
         return Json(ExecuteResponse {
             output: String::new(),
             success: false,
+            data: None,
             error: Some("REPL executor task has stopped".to_string()),
         });
     }
@@ -104,6 +153,7 @@ async fn execute_handler(
         Err(_) => Json(ExecuteResponse {
             output: String::new(),
             success: false,
+            data: None,
             error: Some("Failed to receive REPL response".to_string()),
         }),
     }
@@ -139,12 +189,22 @@ fn spawn_repl_executor() -> ReplExecutor {
                         ExecuteResponse {
                             output: String::new(),
                             success: false,
+                            data: None,
                             error: Some("Execution timeout".to_string()),
                         }
                     } else {
                         // Get captured println/print output
                         let print_output = get_captured_output();
 
+                        // Commands and nil results have no value to render
+                        let data = if command.source.trim_start().starts_with(':')
+                            || expr_result.is_empty()
+                        {
+                            None
+                        } else {
+                            repl.last_result().and_then(MimeBundle::for_value)
+                        };
+
                         // Combine print output with expression result
                         let final_output = if print_output.is_empty() {
                             expr_result
@@ -159,6 +219,7 @@ fn spawn_repl_executor() -> ReplExecutor {
                         ExecuteResponse {
                             output: final_output,
                             success: true,
+                            data,
                             error: None,
                         }
                     }
@@ -166,6 +227,7 @@ fn spawn_repl_executor() -> ReplExecutor {
                 Err(e) => ExecuteResponse {
                     output: String::new(),
                     success: false,
+                    data: None,
                     error: Some(format!("{e}")),
                 },
             };
@@ -178,6 +240,109 @@ fn spawn_repl_executor() -> ReplExecutor {
     cmd_tx
 }
 
+// Kernel sessions: one persistent interpreter per notebook id
+// (see `crate::notebook::kernel`)
+type Kernels = Arc<KernelManager>;
+
+async fn kernel_execute_handler(
+    State(kernels): State<Kernels>,
+    Path(notebook): Path<String>,
+    Json(request): Json<ExecuteCellRequest>,
+) -> Json<ExecuteCellResponse> {
+    // Execution blocks until the cell finishes, so keep it off the async workers
+    let result = tokio::task::spawn_blocking(move || {
+        kernels
+            .session(&notebook)?
+            .execute(&request.cell_id, &request.source)
+    })
+    .await
+    .unwrap_or_else(|e| Err(format!("Kernel task failed: {e}")));
+    Json(match result {
+        Ok(reply) => ExecuteCellResponse {
+            success: true,
+            reply: Some(reply),
+            error: None,
+        },
+        Err(e) => ExecuteCellResponse {
+            success: false,
+            reply: None,
+            error: Some(e),
+        },
+    })
+}
+
+async fn kernel_cells_handler(
+    State(kernels): State<Kernels>,
+    Path(notebook): Path<String>,
+) -> Json<KernelCellsResponse> {
+    Json(match kernels.get(&notebook) {
+        Some(session) => KernelCellsResponse {
+            execution_count: session.execution_count(),
+            cells: session.cells(),
+        },
+        None => KernelCellsResponse {
+            execution_count: 0,
+            cells: BTreeMap::new(),
+        },
+    })
+}
+
+async fn kernel_interrupt_handler(
+    State(kernels): State<Kernels>,
+    Path(notebook): Path<String>,
+) -> Json<KernelActionResponse> {
+    let interrupted = kernels
+        .get(&notebook)
+        .is_some_and(|session| session.interrupt());
+    Json(KernelActionResponse {
+        success: true,
+        interrupted: Some(interrupted),
+        error: None,
+    })
+}
+
+async fn kernel_restart_handler(
+    State(kernels): State<Kernels>,
+    Path(notebook): Path<String>,
+) -> Json<KernelActionResponse> {
+    let result = kernels
+        .session(&notebook)
+        .and_then(|session| session.restart());
+    Json(KernelActionResponse::from_result(result))
+}
+
+async fn kernel_shutdown_handler(
+    State(kernels): State<Kernels>,
+    Path(notebook): Path<String>,
+) -> Json<KernelActionResponse> {
+    let result = if kernels.shutdown(&notebook) {
+        Ok(())
+    } else {
+        Err(format!("No kernel running for notebook '{notebook}'"))
+    };
+    Json(KernelActionResponse::from_result(result))
+}
+
+/// Routes for per-notebook kernel sessions
+fn kernel_routes(kernels: Kernels) -> Router {
+    Router::new()
+        .route(
+            "/api/kernels/{notebook}/execute",
+            post(kernel_execute_handler),
+        )
+        .route("/api/kernels/{notebook}/cells", get(kernel_cells_handler))
+        .route(
+            "/api/kernels/{notebook}/interrupt",
+            post(kernel_interrupt_handler),
+        )
+        .route(
+            "/api/kernels/{notebook}/restart",
+            post(kernel_restart_handler),
+        )
+        .route("/api/kernels/{notebook}", delete(kernel_shutdown_handler))
+        .with_state(kernels)
+}
+
 /// Convert markdown to HTML using pulldown-cmark
 ///
 /// # Security
@@ -185,7 +350,7 @@ fn spawn_repl_executor() -> ReplExecutor {
 /// This function sanitizes HTML to prevent XSS attacks by:
 /// - Escaping raw HTML tags in the markdown source
 /// - Only allowing safe markdown constructs
-fn markdown_to_html(markdown: &str) -> String {
+pub(crate) fn markdown_to_html(markdown: &str) -> String {
     use pulldown_cmark::{escape::escape_html, html, Event, Options, Parser};
 
     let mut options = Options::empty();
@@ -275,6 +440,11 @@ async fn save_notebook_handler(
 
 /// Start the notebook server on the specified port
 ///
+/// Besides the shared REPL behind `/api/execute`, each notebook id gets a
+/// persistent kernel session under `/api/kernels/{notebook}`:
+/// `POST .../execute` (`{"cell_id", "source"}`), `GET .../cells`,
+/// `POST .../interrupt`, `POST .../restart` and `DELETE` to shut it down.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -299,7 +469,8 @@ pub async fn start_server(port: u16) -> Result<(), Box<dyn std::error::Error>> {
         .route("/api/notebook/load", post(load_notebook_handler))
         .route("/api/notebook/save", post(save_notebook_handler))
         .route("/health", get(health))
-        .with_state(repl_executor);
+        .with_state(repl_executor)
+        .merge(kernel_routes(Arc::new(KernelManager::new())));
     let addr = SocketAddr::from(([127, 0, 0, 1], port));
     let listener = tokio::net::TcpListener::bind(addr).await?;
     println!("🚀 Notebook server running at http://127.0.0.1:{port}");
@@ -350,6 +521,7 @@ mod tests {
         let response = ExecuteResponse {
             output: "42".to_string(),
             success: true,
+            data: None,
             error: None,
         };
         assert_eq!(response.output, "42");
@@ -362,6 +534,7 @@ mod tests {
         let response = ExecuteResponse {
             output: String::new(),
             success: false,
+            data: None,
             error: Some("Parse error".to_string()),
         };
         assert!(!response.success);
@@ -376,6 +549,7 @@ mod tests {
         let response = ExecuteResponse {
             output: "result".to_string(),
             success: true,
+            data: None,
             error: None,
         };
         let json = serde_json::to_string(&response).expect("operation should succeed in test");
@@ -390,6 +564,7 @@ mod tests {
         let response = ExecuteResponse {
             output: String::new(),
             success: false,
+            data: None,
             error: Some("error message".to_string()),
         };
         let json = serde_json::to_string(&response).expect("operation should succeed in test");
@@ -521,6 +696,7 @@ mod tests {
         let response = ExecuteResponse {
             output: "output".to_string(),
             success: true,
+            data: None,
             error: None,
         };
         let debug_str = format!("{response:?}");
@@ -533,6 +709,7 @@ mod tests {
         let response_without_error = ExecuteResponse {
             output: "success".to_string(),
             success: true,
+            data: None,
             error: None,
         };
 
@@ -544,6 +721,7 @@ mod tests {
         let response_with_error = ExecuteResponse {
             output: String::new(),
             success: false,
+            data: None,
             error: Some("error".to_string()),
         };
 
@@ -938,4 +1116,113 @@ mod tests {
         assert!(response_data.success, "Expected success=true");
         assert!(file_path.exists(), "File should be created");
     }
+
+    async fn post_json<T: serde::de::DeserializeOwned>(
+        app: &Router,
+        uri: &str,
+        body: serde_json::Value,
+    ) -> T {
+        let request = Request::builder()
+            .uri(uri)
+            .method("POST")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .expect("operation should succeed in test");
+        let response = app
+            .clone()
+            .oneshot(request)
+            .await
+            .expect("operation should succeed in test");
+        let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("operation should succeed in test");
+        serde_json::from_slice(&body_bytes).expect("operation should succeed in test")
+    }
+
+    #[tokio::test]
+    async fn test_kernel_routes_execute_invalidate_and_restart() {
+        use crate::notebook::kernel::CellStatus;
+
+        let app = kernel_routes(Arc::new(KernelManager::new()));
+        let execute =
+            |cell: &str, source: &str| serde_json::json!({"cell_id": cell, "source": source});
+
+        let first: ExecuteCellResponse =
+            post_json(&app, "/api/kernels/nb/execute", execute("a", "let x = 20")).await;
+        assert!(first.success);
+        let second: ExecuteCellResponse =
+            post_json(&app, "/api/kernels/nb/execute", execute("b", "x + 1")).await;
+        let reply = second.reply.expect("reply");
+        assert_eq!((reply.execution_count, reply.output.as_str()), (2, "21"));
+
+        let rerun: ExecuteCellResponse =
+            post_json(&app, "/api/kernels/nb/execute", execute("a", "let x = 1")).await;
+        assert_eq!(rerun.reply.expect("reply").invalidated, vec!["b"]);
+
+        let request = Request::builder()
+            .uri("/api/kernels/nb/cells")
+            .body(Body::empty())
+            .expect("operation should succeed in test");
+        let response = app
+            .clone()
+            .oneshot(request)
+            .await
+            .expect("operation should succeed in test");
+        let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("operation should succeed in test");
+        let cells: KernelCellsResponse =
+            serde_json::from_slice(&body_bytes).expect("operation should succeed in test");
+        assert_eq!(cells.execution_count, 3);
+        assert_eq!(cells.cells["b"].status, CellStatus::Stale);
+
+        let interrupt: KernelActionResponse =
+            post_json(&app, "/api/kernels/nb/interrupt", serde_json::json!({})).await;
+        assert_eq!(interrupt.interrupted, Some(false));
+        let restart: KernelActionResponse =
+            post_json(&app, "/api/kernels/nb/restart", serde_json::json!({})).await;
+        assert!(restart.success);
+        let after: ExecuteCellResponse =
+            post_json(&app, "/api/kernels/nb/execute", execute("b", "x + 1")).await;
+        let reply = after.reply.expect("reply");
+        assert_eq!(
+            (reply.execution_count, reply.status),
+            (1, CellStatus::Error)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_returns_mime_bundle_for_rich_results() {
+        use crate::notebook::mime::{IMAGE_SVG, TEXT_HTML};
+
+        let local = tokio::task::LocalSet::new();
+        local
+            .run_until(async {
+                let app = Router::new()
+                    .route("/api/execute", post(execute_handler))
+                    .with_state(spawn_repl_executor());
+
+                let plain: ExecuteResponse =
+                    post_json(&app, "/api/execute", serde_json::json!({"source": "1 + 1"})).await;
+                assert_eq!((plain.output.as_str(), plain.data), ("2", None));
+
+                let plot: ExecuteResponse = post_json(
+                    &app,
+                    "/api/execute",
+                    serde_json::json!({"source": "std::plot::bar([\"a\", \"b\"], [1, 2])"}),
+                )
+                .await;
+                let data = plot.data.expect("plots carry a MIME bundle");
+                assert!(data.get(IMAGE_SVG).expect("svg").starts_with("<svg"));
+
+                let html: ExecuteResponse = post_json(
+                    &app,
+                    "/api/execute",
+                    serde_json::json!({"source": "std::display::html(\"<b>hi</b>\")"}),
+                )
+                .await;
+                assert_eq!(html.data.expect("bundle").get(TEXT_HTML), Some("<b>hi</b>"));
+            })
+            .await;
+    }
 }
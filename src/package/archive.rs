@@ -0,0 +1,203 @@
+//! Package tarballs: the `Ruchy.toml` manifest and `.ruchy` sources of a
+//! project, gzip-compressed
+//!
+//! Archives are reproducible: files are stored in sorted order with fixed
+//! modes and timestamps, so packing the same sources always yields the same
+//! bytes and checksum. Unpacking rejects paths that would escape the
+//! destination directory.
+use crate::runtime::script::MANIFEST_FILE;
+use anyhow::{bail, Context, Result};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+
+/// A packed package
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageArchive {
+    bytes: Vec<u8>,
+}
+
+impl PackageArchive {
+    /// Pack `Ruchy.toml`, an optional `README.md` and every `.ruchy` file
+    /// under `src` of the project in `root`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest is missing or a file cannot be read
+    pub fn pack(root: &Path) -> Result<Self> {
+        let mut files = vec![PathBuf::from(MANIFEST_FILE)];
+        if root.join("README.md").is_file() {
+            files.push(PathBuf::from("README.md"));
+        }
+        let src = root.join("src");
+        if src.is_dir() {
+            for entry in walkdir::WalkDir::new(src).sort_by_file_name() {
+                let entry = entry.context("Failed to list package sources")?;
+                let path = entry.path();
+                if entry.file_type().is_file() && path.extension().is_some_and(|e| e == "ruchy") {
+                    files.push(path.strip_prefix(root)?.to_path_buf());
+                }
+            }
+        }
+
+        let mut builder = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::default()));
+        for file in &files {
+            let contents = fs::read(root.join(file))
+                .with_context(|| format!("Failed to read {}", file.display()))?;
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o644);
+            header.set_mtime(0);
+            // Archive paths use `/` on every platform
+            let name = file
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            builder.append_data(&mut header, name, contents.as_slice())?;
+        }
+        let bytes = builder.into_inner()?.finish()?;
+        Ok(Self { bytes })
+    }
+
+    /// Wrap downloaded archive bytes
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+
+    /// The compressed archive
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Hex SHA-256 of the compressed archive
+    pub fn checksum(&self) -> String {
+        format!("{:x}", Sha256::digest(&self.bytes))
+    }
+
+    /// Paths and contents of the archived files, in archive order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the archive is corrupt or holds a path that is
+    /// absolute or contains `..`
+    pub fn files(&self) -> Result<Vec<(PathBuf, Vec<u8>)>> {
+        let mut archive = tar::Archive::new(GzDecoder::new(self.bytes.as_slice()));
+        let mut files = Vec::new();
+        for entry in archive.entries().context("Corrupt package archive")? {
+            let mut entry = entry.context("Corrupt package archive")?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+            let path = entry.path()?.into_owned();
+            if !path.components().all(|c| matches!(c, Component::Normal(_))) {
+                bail!("Package archive holds unsafe path {}", path.display());
+            }
+            let mut contents = Vec::new();
+            entry.read_to_end(&mut contents)?;
+            files.push((path, contents));
+        }
+        Ok(files)
+    }
+
+    /// The archived `Ruchy.toml`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the archive is corrupt or has no manifest
+    pub fn manifest(&self) -> Result<String> {
+        let (_, contents) = self
+            .files()?
+            .into_iter()
+            .find(|(path, _)| path == Path::new(MANIFEST_FILE))
+            .context("Package archive has no Ruchy.toml")?;
+        Ok(String::from_utf8(contents)?)
+    }
+
+    /// Extract every file into `dest`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the archive is corrupt or a file cannot be written
+    pub fn unpack(&self, dest: &Path) -> Result<()> {
+        for (path, contents) in self.files()? {
+            let target = dest.join(path);
+            if let Some(dir) = target.parent() {
+                fs::create_dir_all(dir)
+                    .with_context(|| format!("Failed to create {}", dir.display()))?;
+            }
+            fs::write(&target, contents)
+                .with_context(|| format!("Failed to write {}", target.display()))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn sample_project() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join(MANIFEST_FILE),
+            "[package]\nname = \"strs\"\nversion = \"1.0.0\"\n",
+        )
+        .unwrap();
+        fs::create_dir_all(dir.path().join("src/text")).unwrap();
+        fs::write(dir.path().join("src/lib.ruchy"), "pub fun id(x) { x }").unwrap();
+        fs::write(dir.path().join("src/text/pad.ruchy"), "pub fun pad() {}").unwrap();
+        fs::write(dir.path().join("src/notes.txt"), "not packed").unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_pack_is_reproducible_and_round_trips() {
+        let project = sample_project();
+        let archive = PackageArchive::pack(project.path()).unwrap();
+        assert_eq!(archive, PackageArchive::pack(project.path()).unwrap());
+        assert_eq!(archive.checksum().len(), 64);
+
+        let paths: Vec<_> = archive
+            .files()
+            .unwrap()
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect();
+        assert_eq!(
+            paths,
+            ["Ruchy.toml", "src/lib.ruchy", "src/text/pad.ruchy"].map(PathBuf::from)
+        );
+        assert!(archive.manifest().unwrap().contains("name = \"strs\""));
+
+        let dest = TempDir::new().unwrap();
+        archive.unpack(dest.path()).unwrap();
+        assert_eq!(
+            fs::read_to_string(dest.path().join("src/text/pad.ruchy")).unwrap(),
+            "pub fun pad() {}"
+        );
+    }
+
+    #[test]
+    fn test_unsafe_paths_are_rejected() {
+        let mut builder = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::default()));
+        let mut header = tar::Header::new_gnu();
+        header.set_size(1);
+        // append_data refuses `..`, so write the name into the header directly
+        header.as_gnu_mut().unwrap().name[..9].copy_from_slice(b"../escape");
+        header.set_cksum();
+        builder.append(&header, b"x".as_slice()).unwrap();
+        let archive = PackageArchive::from_bytes(builder.into_inner().unwrap().finish().unwrap());
+
+        let err = archive.files().unwrap_err();
+        assert!(err.to_string().contains("unsafe path"), "{err}");
+        assert!(PackageArchive::from_bytes(b"garbage".to_vec())
+            .files()
+            .is_err());
+    }
+}
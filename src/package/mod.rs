@@ -2,6 +2,17 @@
 //!
 //! Provides package manifest parsing, dependency resolution,
 //! and package installation capabilities.
+//!
+//! `ruchy publish` and `ruchy add` use the registry client in
+//! [`registry_client`], the package tarballs of [`archive`] and the semver
+//! resolution, `Ruchy.lock` and vendoring of [`resolver`].
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod archive;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod registry_client;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod resolver;
 
 use anyhow::{anyhow, Result};
 use std::collections::HashMap;
@@ -0,0 +1,419 @@
+//! Client for Ruchy package registries
+//!
+//! A registry is either a local directory (a plain path or a `file://` URL)
+//! or an HTTP server. A directory registry is laid out as
+//!
+//! ```text
+//! index/<name>.json                          published versions of <name>
+//! packages/<name>/<name>-<version>.tar.gz    package archives
+//! ```
+//!
+//! and an HTTP registry serves the same data:
+//!
+//! ```text
+//! GET {url}/api/v1/packages/<name>                     index entries as JSON
+//! GET {url}/api/v1/packages/<name>/<version>/download  package archive
+//! PUT {url}/api/v1/packages/<name>/<version>           publish (bearer token)
+//! ```
+//!
+//! HTTP registries need the `http-client` feature.
+use super::archive::PackageArchive;
+use crate::backend::project::Manifest;
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Environment variable holding the API token for publishing
+pub const TOKEN_ENV: &str = "RUCHY_REGISTRY_TOKEN";
+
+/// Check that `name` is a valid package name
+///
+/// Package names are made of lowercase ASCII letters, digits, `_` and `-`.
+/// They become file names in the registry and directory names under
+/// `vendor/`, so anything else (`..`, `/`) is refused wherever a name is read.
+///
+/// # Errors
+///
+/// Returns an error if `name` is empty or has any other character
+pub fn validate_package_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_' || b == b'-');
+    if !valid {
+        bail!("Invalid package name `{name}`: use lowercase letters, digits, `_` and `-`");
+    }
+    Ok(())
+}
+
+/// One published version of a package
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexEntry {
+    /// Package name
+    pub name: String,
+    /// Semver version
+    pub version: String,
+    /// Hex SHA-256 of the package archive
+    pub checksum: String,
+    /// Ruchy packages this version depends on, name to version requirement
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, String>,
+}
+
+impl IndexEntry {
+    /// Describe the package in `archive`, checking that it can be published
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the archive has no valid manifest, a package name
+    /// is invalid, the version is not semver, a dependency requirement is
+    /// invalid, or there is no `src/lib.ruchy`
+    pub fn for_archive(archive: &PackageArchive) -> Result<Self> {
+        let manifest = Manifest::parse(&archive.manifest()?)?;
+        let package = manifest.package;
+        validate_package_name(&package.name)?;
+        for dependency in manifest.packages.keys() {
+            validate_package_name(dependency)?;
+        }
+        semver::Version::parse(&package.version)
+            .with_context(|| format!("Version `{}` is not valid semver", package.version))?;
+        if !archive
+            .files()?
+            .iter()
+            .any(|(path, _)| path == Path::new("src/lib.ruchy"))
+        {
+            bail!("Package `{}` has no src/lib.ruchy", package.name);
+        }
+        Ok(Self {
+            name: package.name,
+            version: package.version,
+            checksum: archive.checksum(),
+            dependencies: manifest.packages,
+        })
+    }
+}
+
+/// Where a registry lives
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistrySource {
+    /// A registry directory on this machine
+    Local(PathBuf),
+    /// An HTTP registry at this base URL
+    Http(String),
+}
+
+/// Fetches and publishes packages
+#[derive(Debug, Clone)]
+pub struct RegistryClient {
+    source: RegistrySource,
+    token: Option<String>,
+}
+
+impl RegistryClient {
+    /// Client for the registry at `location`: an `http(s)://` URL, a
+    /// `file://` URL or a directory path
+    pub fn new(location: &str) -> Self {
+        let source = if location.starts_with("http://") || location.starts_with("https://") {
+            RegistrySource::Http(location.trim_end_matches('/').to_string())
+        } else {
+            let path = location.strip_prefix("file://").unwrap_or(location);
+            RegistrySource::Local(PathBuf::from(path))
+        };
+        Self {
+            source,
+            token: None,
+        }
+    }
+
+    /// Authenticate uploads with `token`
+    #[must_use]
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Where the registry lives
+    pub fn source(&self) -> &RegistrySource {
+        &self.source
+    }
+
+    /// Every published version of `name`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is invalid, the package is unknown, the
+    /// registry cannot be read, or the index names an invalid package
+    pub fn versions(&self, name: &str) -> Result<Vec<IndexEntry>> {
+        validate_package_name(name)?;
+        let json = match &self.source {
+            RegistrySource::Local(dir) => {
+                let path = index_path(dir, name);
+                if !path.is_file() {
+                    bail!("Package `{name}` not found in registry {}", dir.display());
+                }
+                fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read {}", path.display()))?
+            }
+            RegistrySource::Http(url) => {
+                let bytes = http::get(&format!("{url}/api/v1/packages/{name}"))?
+                    .with_context(|| format!("Package `{name}` not found in registry {url}"))?;
+                String::from_utf8(bytes)?
+            }
+        };
+        let entries: Vec<IndexEntry> = serde_json::from_str(&json)
+            .with_context(|| format!("Corrupt registry index for `{name}`"))?;
+        for entry in &entries {
+            validate_package_name(&entry.name)?;
+            for dependency in entry.dependencies.keys() {
+                validate_package_name(dependency)?;
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Download a package archive and check it against `checksum`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is invalid, the version is not semver,
+    /// the archive cannot be fetched or its checksum does not match
+    pub fn download(&self, name: &str, version: &str, checksum: &str) -> Result<PackageArchive> {
+        validate_package_name(name)?;
+        semver::Version::parse(version)
+            .with_context(|| format!("Version `{version}` of `{name}` is not valid semver"))?;
+        let bytes = match &self.source {
+            RegistrySource::Local(dir) => {
+                let path = archive_path(dir, name, version);
+                fs::read(&path).with_context(|| format!("Failed to read {}", path.display()))?
+            }
+            RegistrySource::Http(url) => {
+                http::get(&format!("{url}/api/v1/packages/{name}/{version}/download"))?
+                    .with_context(|| format!("{name} v{version} not found in registry {url}"))?
+            }
+        };
+        let archive = PackageArchive::from_bytes(bytes);
+        if archive.checksum() != checksum {
+            bail!("Checksum mismatch for {name} v{version}: the registry copy has changed");
+        }
+        Ok(archive)
+    }
+
+    /// Publish `archive`, returning its index entry
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the package is invalid, the version was already
+    /// published, an HTTP registry has no token, or the upload fails
+    pub fn publish(&self, archive: &PackageArchive) -> Result<IndexEntry> {
+        let entry = IndexEntry::for_archive(archive)?;
+        match &self.source {
+            RegistrySource::Local(dir) => {
+                let index = index_path(dir, &entry.name);
+                let mut entries: Vec<IndexEntry> = if index.is_file() {
+                    self.versions(&entry.name)?
+                } else {
+                    Vec::new()
+                };
+                if entries.iter().any(|e| e.version == entry.version) {
+                    bail!(
+                        "{} v{} is already published; bump the version in Ruchy.toml",
+                        entry.name,
+                        entry.version
+                    );
+                }
+                let path = archive_path(dir, &entry.name, &entry.version);
+                write_creating_dirs(&path, archive.bytes())?;
+                entries.push(entry.clone());
+                write_creating_dirs(&index, serde_json::to_string_pretty(&entries)?.as_bytes())?;
+            }
+            RegistrySource::Http(url) => {
+                let Some(token) = &self.token else {
+                    bail!("Publishing to {url} needs an API token: set {TOKEN_ENV}");
+                };
+                let url = format!("{url}/api/v1/packages/{}/{}", entry.name, entry.version);
+                http::put(&url, token, archive.bytes())?;
+            }
+        }
+        Ok(entry)
+    }
+}
+
+fn index_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join("index").join(format!("{name}.json"))
+}
+
+fn archive_path(dir: &Path, name: &str, version: &str) -> PathBuf {
+    dir.join("packages")
+        .join(name)
+        .join(format!("{name}-{version}.tar.gz"))
+}
+
+fn write_creating_dirs(path: &Path, contents: &[u8]) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+#[cfg(feature = "http-client")]
+mod http {
+    use anyhow::{bail, Context, Result};
+    use reqwest::StatusCode;
+
+    /// GET `url`, returning `None` on 404
+    pub(super) fn get(url: &str) -> Result<Option<Vec<u8>>> {
+        let response = reqwest::blocking::get(url)
+            .with_context(|| format!("Failed to send GET request to {url}"))?;
+        let status = response.status();
+        if status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            bail!("GET request failed with status {status}: {url}");
+        }
+        let bytes = response
+            .bytes()
+            .with_context(|| format!("Failed to read response body from {url}"))?;
+        Ok(Some(bytes.to_vec()))
+    }
+
+    /// PUT a package archive to `url`
+    pub(super) fn put(url: &str, token: &str, body: &[u8]) -> Result<()> {
+        let response = reqwest::blocking::Client::new()
+            .put(url)
+            .bearer_auth(token)
+            .header("Content-Type", "application/gzip")
+            .body(body.to_vec())
+            .send()
+            .with_context(|| format!("Failed to send PUT request to {url}"))?;
+        let status = response.status();
+        if !status.is_success() {
+            let message = response.text().unwrap_or_default();
+            bail!("Publishing failed with status {status}: {message}");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "http-client"))]
+mod http {
+    use anyhow::{bail, Result};
+
+    const HINT: &str = "HTTP registries need ruchy built with the `http-client` feature; \
+                        use a registry directory or file:// URL instead";
+
+    pub(super) fn get(url: &str) -> Result<Option<Vec<u8>>> {
+        bail!("Cannot fetch {url}: {HINT}")
+    }
+
+    pub(super) fn put(url: &str, _token: &str, _body: &[u8]) -> Result<()> {
+        bail!("Cannot publish to {url}: {HINT}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::script::MANIFEST_FILE;
+    use tempfile::TempDir;
+
+    fn package(name: &str, version: &str, packages: &str) -> PackageArchive {
+        let dir = TempDir::new().unwrap();
+        let manifest = format!(
+            "[package]\nname = \"{name}\"\nversion = \"{version}\"\n\n[packages]\n{packages}"
+        );
+        fs::write(dir.path().join(MANIFEST_FILE), manifest).unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("src/lib.ruchy"), "pub fun one() { 1 }").unwrap();
+        PackageArchive::pack(dir.path()).unwrap()
+    }
+
+    #[test]
+    fn test_local_registry_publish_and_download() {
+        let registry = TempDir::new().unwrap();
+        let location = format!("file://{}", registry.path().display());
+        let client = RegistryClient::new(&location);
+        assert_eq!(
+            client.source(),
+            &RegistrySource::Local(registry.path().to_path_buf())
+        );
+
+        let archive = package("strs", "1.2.0", "base = \"^0.3\"\n");
+        let entry = client.publish(&archive).unwrap();
+        assert_eq!(entry.dependencies["base"], "^0.3");
+        client.publish(&package("strs", "1.3.0", "")).unwrap();
+
+        let versions: Vec<_> = client
+            .versions("strs")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.version)
+            .collect();
+        assert_eq!(versions, ["1.2.0", "1.3.0"]);
+        let downloaded = client.download("strs", "1.2.0", &entry.checksum).unwrap();
+        assert_eq!(downloaded, archive);
+        let err = client.download("strs", "1.2.0", "0000").unwrap_err();
+        assert!(err.to_string().contains("Checksum mismatch"), "{err}");
+    }
+
+    #[test]
+    fn test_publish_rejects_duplicates_and_invalid_packages() {
+        let registry = TempDir::new().unwrap();
+        let client = RegistryClient::new(&registry.path().to_string_lossy());
+        client.publish(&package("strs", "1.0.0", "")).unwrap();
+        let err = client.publish(&package("strs", "1.0.0", "")).unwrap_err();
+        assert!(err.to_string().contains("already published"), "{err}");
+
+        let err = client.publish(&package("strs", "one", "")).unwrap_err();
+        assert!(err.to_string().contains("not valid semver"), "{err}");
+        let err = client
+            .publish(&package("strs", "2.0.0", "base = \"not a req\"\n"))
+            .unwrap_err();
+        assert!(format!("{err:#}").contains("`base`"), "{err:#}");
+
+        let err = client.versions("missing").unwrap_err();
+        assert!(err.to_string().contains("`missing` not found"), "{err}");
+    }
+
+    #[test]
+    fn test_package_names_cannot_escape_the_registry() {
+        for name in ["text-utils", "base_2"] {
+            assert!(validate_package_name(name).is_ok(), "{name}");
+        }
+        for name in ["", "../evil", "a/b", "Caps", "dot.name"] {
+            assert!(validate_package_name(name).is_err(), "{name:?}");
+        }
+
+        let registry = TempDir::new().unwrap();
+        let client = RegistryClient::new(&registry.path().to_string_lossy());
+        let err = client
+            .publish(&package("../evil", "1.0.0", ""))
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid package name"), "{err}");
+        let err = client
+            .publish(&package("strs", "1.0.0", "\"../base\" = \"1\"\n"))
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid package name"), "{err}");
+        assert!(client.versions("../index/strs").is_err());
+        assert!(client.download("strs", "../../x", "0000").is_err());
+
+        let forged = r#"[{"name": "../evil", "version": "1.0.0", "checksum": "00"}]"#;
+        fs::create_dir_all(registry.path().join("index")).unwrap();
+        fs::write(registry.path().join("index/strs.json"), forged).unwrap();
+        let err = client.versions("strs").unwrap_err();
+        assert!(err.to_string().contains("Invalid package name"), "{err}");
+    }
+
+    #[test]
+    fn test_http_publish_needs_token() {
+        let client = RegistryClient::new("https://registry.example/");
+        assert_eq!(
+            client.source(),
+            &RegistrySource::Http("https://registry.example".to_string())
+        );
+        let err = client.publish(&package("strs", "1.0.0", "")).unwrap_err();
+        assert!(err.to_string().contains(TOKEN_ENV), "{err}");
+    }
+}
@@ -0,0 +1,413 @@
+//! Semver resolution of Ruchy packages, the `Ruchy.lock` lockfile and
+//! vendoring
+//!
+//! [`resolve`] turns the `[packages]` requirements of a manifest into one
+//! version per package, following each package's own requirements.
+//! Resolution is greedy: the first requirement seen for a package picks the
+//! highest version it accepts (or the locked version, if it still matches),
+//! and every later requirement must accept that version.
+//!
+//! [`vendor`] unpacks the locked packages into `vendor/<module>/`, with the
+//! package's `src/lib.ruchy` as `mod.ruchy`, so `mod <module>;` loads it.
+use super::registry_client::{validate_package_name, IndexEntry, RegistryClient};
+use anyhow::{bail, Context, Result};
+use semver::{Version, VersionReq};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::fs;
+use std::path::Path;
+
+/// Lockfile written next to `Ruchy.toml`
+pub const LOCK_FILE: &str = "Ruchy.lock";
+
+/// Directory vendored packages are unpacked into
+pub const VENDOR_DIR: &str = "vendor";
+
+const LOCK_HEADER: &str = "# Generated by `ruchy add`. Do not edit by hand.\n\n";
+
+/// Exact versions of every package a project depends on
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lockfile {
+    /// Locked packages, sorted by name
+    #[serde(default, rename = "package")]
+    pub packages: Vec<LockedPackage>,
+}
+
+/// One `[[package]]` of a lockfile
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedPackage {
+    /// Package name
+    pub name: String,
+    /// Selected version
+    pub version: String,
+    /// Hex SHA-256 of the package archive
+    pub checksum: String,
+    /// Names of the packages it depends on
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<String>,
+}
+
+impl Lockfile {
+    /// Read `Ruchy.lock` from the project in `root`, or an empty lockfile if
+    /// there is none
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lockfile cannot be read or parsed, or names an
+    /// invalid package
+    pub fn load(root: &Path) -> Result<Self> {
+        let path = root.join(LOCK_FILE);
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let lock: Self = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        for package in &lock.packages {
+            validate_package_name(&package.name)
+                .with_context(|| format!("Invalid {}", path.display()))?;
+            for dependency in &package.dependencies {
+                validate_package_name(dependency)
+                    .with_context(|| format!("Invalid {}", path.display()))?;
+            }
+        }
+        Ok(lock)
+    }
+
+    /// Write `Ruchy.lock` into the project in `root`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lockfile cannot be written
+    pub fn save(&self, root: &Path) -> Result<()> {
+        let path = root.join(LOCK_FILE);
+        let content = format!("{LOCK_HEADER}{}", toml::to_string(self)?);
+        fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// The locked package called `name`
+    pub fn get(&self, name: &str) -> Option<&LockedPackage> {
+        self.packages.iter().find(|package| package.name == name)
+    }
+}
+
+/// Module name a package is vendored under: `-` becomes `_`
+pub fn module_name(package: &str) -> String {
+    package.replace('-', "_")
+}
+
+/// A package version picked during resolution
+struct Selection {
+    version: Version,
+    entry: IndexEntry,
+    /// Who asked for it first, for conflict messages
+    required_by: String,
+}
+
+/// Resolve `requirements` (package name to semver requirement) and their
+/// transitive requirements against the registry (complexity: 6)
+///
+/// # Errors
+///
+/// Returns an error if a requirement is invalid, a package is unknown, no
+/// version matches, or two requirements on a package conflict
+pub fn resolve(
+    client: &RegistryClient,
+    requirements: &BTreeMap<String, String>,
+    locked: &Lockfile,
+) -> Result<Lockfile> {
+    let mut selected: BTreeMap<String, Selection> = BTreeMap::new();
+    let mut pending: VecDeque<(String, String, String)> = requirements
+        .iter()
+        .map(|(name, req)| (name.clone(), req.clone(), "Ruchy.toml".to_string()))
+        .collect();
+
+    while let Some((name, requirement, requirer)) = pending.pop_front() {
+        let req = VersionReq::parse(&requirement).with_context(|| {
+            format!("{requirer} has invalid requirement `{requirement}` for `{name}`")
+        })?;
+        if let Some(selection) = selected.get(&name) {
+            if !req.matches(&selection.version) {
+                bail!(
+                    "Version conflict for `{name}`: {requirer} requires {requirement}, \
+                     but {} selected v{}",
+                    selection.required_by,
+                    selection.version
+                );
+            }
+            continue;
+        }
+        let (version, entry) = select(client, &name, &req, locked)?;
+        let required_by = format!("{name} v{version}");
+        for (dependency, dependency_req) in &entry.dependencies {
+            pending.push_back((
+                dependency.clone(),
+                dependency_req.clone(),
+                required_by.clone(),
+            ));
+        }
+        let required_by = format!("{requirer} ({requirement})");
+        selected.insert(
+            name,
+            Selection {
+                version,
+                entry,
+                required_by,
+            },
+        );
+    }
+
+    let packages = selected
+        .into_values()
+        .map(|Selection { entry, .. }| LockedPackage {
+            dependencies: entry.dependencies.into_keys().collect(),
+            name: entry.name,
+            version: entry.version,
+            checksum: entry.checksum,
+        })
+        .collect();
+    Ok(Lockfile { packages })
+}
+
+/// Pick the version of `name` for `req`: the locked one if it matches,
+/// otherwise the highest match
+fn select(
+    client: &RegistryClient,
+    name: &str,
+    req: &VersionReq,
+    locked: &Lockfile,
+) -> Result<(Version, IndexEntry)> {
+    let mut candidates: Vec<(Version, IndexEntry)> = client
+        .versions(name)?
+        .into_iter()
+        .filter_map(|entry| Some((Version::parse(&entry.version).ok()?, entry)))
+        .filter(|(version, _)| req.matches(version))
+        .collect();
+    if let Some(lock) = locked.get(name) {
+        if let Some(i) = candidates
+            .iter()
+            .position(|(_, e)| e.version == lock.version)
+        {
+            let (version, entry) = candidates.swap_remove(i);
+            if entry.checksum != lock.checksum {
+                bail!(
+                    "Checksum of {name} v{version} in the registry no longer matches {LOCK_FILE}"
+                );
+            }
+            return Ok((version, entry));
+        }
+    }
+    candidates
+        .into_iter()
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .with_context(|| format!("No published version of `{name}` matches {req}"))
+}
+
+/// Download every locked package and unpack its sources into
+/// `vendor/<module>/` of the project in `root`
+///
+/// # Errors
+///
+/// Returns an error if a package name is invalid, a download fails, a
+/// checksum does not match, or the files cannot be written
+pub fn vendor(client: &RegistryClient, lock: &Lockfile, root: &Path) -> Result<()> {
+    for package in &lock.packages {
+        validate_package_name(&package.name)?;
+        let archive = client.download(&package.name, &package.version, &package.checksum)?;
+        let dir = root.join(VENDOR_DIR).join(module_name(&package.name));
+        if dir.exists() {
+            fs::remove_dir_all(&dir)
+                .with_context(|| format!("Failed to remove {}", dir.display()))?;
+        }
+        for (path, contents) in archive.files()? {
+            let Ok(relative) = path.strip_prefix("src") else {
+                continue;
+            };
+            let relative = if relative == Path::new("lib.ruchy") {
+                Path::new("mod.ruchy")
+            } else {
+                relative
+            };
+            let target = dir.join(relative);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create {}", parent.display()))?;
+            }
+            fs::write(&target, contents)
+                .with_context(|| format!("Failed to write {}", target.display()))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::package::archive::PackageArchive;
+    use crate::runtime::script::MANIFEST_FILE;
+    use tempfile::TempDir;
+
+    /// A local registry holding `(name, version, [packages] lines)`
+    fn registry(packages: &[(&str, &str, &str)]) -> (TempDir, RegistryClient) {
+        let dir = TempDir::new().unwrap();
+        let client = RegistryClient::new(&dir.path().to_string_lossy());
+        for (name, version, requirements) in packages {
+            let source = TempDir::new().unwrap();
+            let manifest = format!(
+                "[package]\nname = \"{name}\"\nversion = \"{version}\"\n\n[packages]\n{requirements}"
+            );
+            fs::write(source.path().join(MANIFEST_FILE), manifest).unwrap();
+            fs::create_dir_all(source.path().join("src/util")).unwrap();
+            let lib = format!("pub fun version() {{ \"{version}\" }}");
+            fs::write(source.path().join("src/lib.ruchy"), lib).unwrap();
+            fs::write(source.path().join("src/util/fmt.ruchy"), "pub fun f() {}").unwrap();
+            client
+                .publish(&PackageArchive::pack(source.path()).unwrap())
+                .unwrap();
+        }
+        (dir, client)
+    }
+
+    fn requirements(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(name, req)| ((*name).to_string(), (*req).to_string()))
+            .collect()
+    }
+
+    fn versions(lock: &Lockfile) -> Vec<(&str, &str)> {
+        lock.packages
+            .iter()
+            .map(|p| (p.name.as_str(), p.version.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_resolve_picks_highest_match_and_follows_dependencies() {
+        let (_dir, client) = registry(&[
+            ("base", "0.3.1", ""),
+            ("base", "0.3.4", ""),
+            ("base", "0.4.0", ""),
+            ("text-utils", "1.0.0", "base = \"^0.3\"\n"),
+            ("text-utils", "2.0.0-beta.1", ""),
+        ]);
+        let lock = resolve(
+            &client,
+            &requirements(&[("text-utils", "*")]),
+            &Lockfile::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            versions(&lock),
+            [("base", "0.3.4"), ("text-utils", "1.0.0")]
+        );
+        assert_eq!(lock.packages[1].dependencies, ["base"]);
+    }
+
+    #[test]
+    fn test_resolve_prefers_locked_versions() {
+        let (_dir, client) = registry(&[("base", "1.0.0", ""), ("base", "1.1.0", "")]);
+        let wanted = requirements(&[("base", "^1")]);
+        let mut locked = resolve(&client, &wanted, &Lockfile::default()).unwrap();
+        assert_eq!(versions(&locked), [("base", "1.1.0")]);
+
+        let old = client.versions("base").unwrap().remove(0);
+        locked.packages[0].version = old.version;
+        locked.packages[0].checksum = old.checksum;
+        let lock = resolve(&client, &wanted, &locked).unwrap();
+        assert_eq!(versions(&lock), [("base", "1.0.0")]);
+
+        let lock = resolve(&client, &requirements(&[("base", ">=1.1")]), &locked).unwrap();
+        assert_eq!(versions(&lock), [("base", "1.1.0")]);
+    }
+
+    #[test]
+    fn test_resolve_reports_conflicts_and_missing_versions() {
+        let (_dir, client) = registry(&[
+            ("base", "1.0.0", ""),
+            ("base", "2.0.0", ""),
+            ("app-kit", "1.0.0", "base = \"^1\"\n"),
+        ]);
+        let err = resolve(
+            &client,
+            &requirements(&[("app-kit", "1"), ("base", "2")]),
+            &Lockfile::default(),
+        )
+        .unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("Version conflict for `base`"), "{message}");
+        assert!(message.contains("app-kit v1.0.0 requires ^1"), "{message}");
+
+        let err = resolve(
+            &client,
+            &requirements(&[("base", "^3")]),
+            &Lockfile::default(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("No published version of `base`"));
+    }
+
+    #[test]
+    fn test_lockfile_round_trips_and_vendor_unpacks_sources() {
+        let (_dir, client) = registry(&[
+            ("base", "0.3.4", ""),
+            ("text-utils", "1.0.0", "base = \"0.3\"\n"),
+        ]);
+        let lock = resolve(
+            &client,
+            &requirements(&[("text-utils", "1")]),
+            &Lockfile::default(),
+        )
+        .unwrap();
+
+        let project = TempDir::new().unwrap();
+        assert_eq!(Lockfile::load(project.path()).unwrap(), Lockfile::default());
+        lock.save(project.path()).unwrap();
+        let content = fs::read_to_string(project.path().join(LOCK_FILE)).unwrap();
+        assert!(content.starts_with(LOCK_HEADER), "{content}");
+        assert!(content.contains("[[package]]"), "{content}");
+        assert_eq!(Lockfile::load(project.path()).unwrap(), lock);
+
+        fs::create_dir_all(project.path().join("vendor/base")).unwrap();
+        fs::write(project.path().join("vendor/base/stale.ruchy"), "").unwrap();
+        vendor(&client, &lock, project.path()).unwrap();
+        let vendored = project.path().join(VENDOR_DIR);
+        assert_eq!(
+            fs::read_to_string(vendored.join("text_utils/mod.ruchy")).unwrap(),
+            "pub fun version() { \"1.0.0\" }"
+        );
+        assert!(vendored.join("text_utils/util/fmt.ruchy").is_file());
+        assert!(vendored.join("base/mod.ruchy").is_file());
+        assert!(!vendored.join("base/stale.ruchy").exists());
+    }
+
+    #[test]
+    fn test_lockfile_and_vendor_refuse_path_traversal() {
+        let (_dir, client) = registry(&[("base", "1.0.0", "")]);
+        let project = TempDir::new().unwrap();
+        let victim = project.path().join("victim");
+        fs::create_dir_all(&victim).unwrap();
+        let mut lock = resolve(
+            &client,
+            &requirements(&[("base", "1")]),
+            &Lockfile::default(),
+        )
+        .unwrap();
+        lock.packages[0].name = "../victim".to_string();
+
+        let err = vendor(&client, &lock, project.path()).unwrap_err();
+        assert!(
+            err.to_string().contains("Invalid package name `../victim`"),
+            "{err}"
+        );
+        assert!(victim.is_dir());
+
+        lock.save(project.path()).unwrap();
+        let err = Lockfile::load(project.path()).unwrap_err();
+        assert!(
+            format!("{err:#}").contains("Invalid package name"),
+            "{err:#}"
+        );
+    }
+}
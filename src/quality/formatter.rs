@@ -1,7 +1,7 @@
 #![allow(clippy::approx_constant)]
 // Code formatter for Ruchy
 // Toyota Way: Consistent code style prevents defects
-use crate::frontend::ast::{Expr, ExprKind};
+use crate::frontend::ast::{Comment, Expr, ExprKind};
 use crate::quality::formatter_config::FormatterConfig;
 use anyhow::Result;
 
@@ -10,6 +10,13 @@ pub struct Formatter {
     source: Option<String>,
 }
 
+/// A formatted list item together with the comments attached to it
+struct CommentedItem<'a> {
+    text: String,
+    leading: &'a [Comment],
+    trailing: Option<&'a Comment>,
+}
+
 impl Formatter {
     /// Create a new formatter with default configuration
     ///
@@ -122,6 +129,10 @@ impl Formatter {
             TypeKind::Array { elem_type, size } => {
                 format!("[{}; {}]", Self::format_type(&elem_type.kind), size)
             }
+            TypeKind::Reference { is_mut, inner, .. } => {
+                let mutability = if *is_mut { "mut " } else { "" };
+                format!("&{mutability}{}", Self::format_type(&inner.kind))
+            }
             _ => format!("{ty_kind:?}"),
         }
     }
@@ -351,6 +362,7 @@ impl Formatter {
             | ExprKind::IndexAccess { .. }
             | ExprKind::FieldAccess { .. }
             | ExprKind::OptionalFieldAccess { .. }
+            | ExprKind::OptionalIndexAccess { .. }
             | ExprKind::Slice { .. }
             | ExprKind::TypeCast { .. }
             | ExprKind::Try { .. }
@@ -394,6 +406,17 @@ impl Formatter {
                     self.format_expr(count, indent)
                 )
             }
+            ExprKind::ExternBlock {
+                abi,
+                path,
+                functions,
+            } => self.format_extern_block(abi, path.as_deref(), functions, indent),
+            ExprKind::RustBlock {
+                inputs,
+                output,
+                code,
+                fallback,
+            } => self.format_rust_block(inputs, output.as_ref(), code, fallback.as_deref(), indent),
             ExprKind::Set(elems) => format!(
                 "{{{}}}",
                 elems
@@ -480,6 +503,7 @@ impl Formatter {
                 format!("{}.{:?}", self.format_expr(source, indent), operation)
             }
             ExprKind::Lazy { expr } => format!("lazy {}", self.format_expr(expr, indent)),
+            ExprKind::Defer { expr } => format!("defer {}", self.format_expr(expr, indent)),
             // Ruchy 5.0 Sovereign Platform expressions
             ExprKind::Yield { value } => match value {
                 Some(v) => format!("yield {}", self.format_expr(v, indent)),
@@ -516,45 +540,55 @@ impl Formatter {
             } => self.format_if_let_expr(pattern, expr, then_branch, else_branch.as_ref(), indent),
             ExprKind::Match { expr, arms } => self.format_match_expr(expr, arms, indent),
             ExprKind::For {
+                label,
                 var,
                 pattern,
                 iter,
                 body,
-                ..
-            } => self.format_for_expr(var, pattern.as_ref(), iter, body, indent),
+            } => format!(
+                "{}{}",
+                Self::loop_label_prefix(label.as_ref()),
+                self.format_for_expr(var, pattern.as_ref(), iter, body, indent)
+            ),
             ExprKind::While {
-                condition, body, ..
+                label,
+                condition,
+                body,
             } => {
                 format!(
-                    "while {} {}",
+                    "{}while {} {}",
+                    Self::loop_label_prefix(label.as_ref()),
                     self.format_expr(condition, indent),
                     self.format_expr(body, indent)
                 )
             }
             ExprKind::WhileLet {
+                label,
                 pattern,
                 expr,
                 body,
-                ..
             } => {
                 format!(
-                    "while let {} = {} {}",
+                    "{}while let {} = {} {}",
+                    Self::loop_label_prefix(label.as_ref()),
                     self.format_pattern(pattern),
                     self.format_expr(expr, indent),
                     self.format_expr(body, indent)
                 )
             }
-            ExprKind::Loop { body, .. } => {
+            ExprKind::Loop { label, body } => {
                 format!(
-                    "loop {{\n{}\n{}}}",
+                    "{}loop {{\n{}\n{}}}",
+                    Self::loop_label_prefix(label.as_ref()),
                     self.format_expr(body, indent + 1),
                     " ".repeat(indent * self.config.indent_width)
                 )
             }
-            ExprKind::Break { value, .. } => {
-                self.format_return_like("break", value.as_ref(), indent)
+            ExprKind::Break { label, value } => {
+                let keyword = Self::jump_with_label("break", label.as_ref());
+                self.format_return_like(&keyword, value.as_ref(), indent)
             }
-            ExprKind::Continue { .. } => "continue".to_string(),
+            ExprKind::Continue { label } => Self::jump_with_label("continue", label.as_ref()),
             ExprKind::Return { value } => self.format_return(value.as_ref(), indent),
             ExprKind::TryCatch {
                 try_block,
@@ -622,6 +656,11 @@ impl Formatter {
             ExprKind::OptionalFieldAccess { object, field } => {
                 format!("{}?.{}", self.format_expr(object, indent), field)
             }
+            ExprKind::OptionalIndexAccess { object, index } => format!(
+                "{}?[{}]",
+                self.format_expr(object, indent),
+                self.format_expr(index, indent)
+            ),
             ExprKind::Slice { object, start, end } => {
                 self.format_slice(object, start.as_ref(), end.as_ref(), indent)
             }
@@ -647,7 +686,7 @@ impl Formatter {
                 fields,
                 is_pub,
                 ..
-            } => self.format_struct_decl(name, type_params, fields, *is_pub),
+            } => self.format_struct_decl(name, type_params, fields, *is_pub, indent),
             ExprKind::TupleStruct {
                 name,
                 type_params,
@@ -660,7 +699,7 @@ impl Formatter {
                 type_params,
                 variants,
                 is_pub,
-            } => self.format_enum_decl(name, type_params, variants, *is_pub),
+            } => self.format_enum_decl(name, type_params, variants, *is_pub, indent),
             ExprKind::Trait {
                 name,
                 type_params,
@@ -768,12 +807,14 @@ impl Formatter {
     fn format_literal_expr(&self, lit: &crate::frontend::ast::Literal) -> String {
         match lit {
             crate::frontend::ast::Literal::Integer(n, _) => n.to_string(),
+            crate::frontend::ast::Literal::BigInt(digits) => format!("{digits}n"),
+            crate::frontend::ast::Literal::Decimal(text) => format!("{text}d"),
             crate::frontend::ast::Literal::Float(f) => f.to_string(),
             crate::frontend::ast::Literal::String(s) => {
                 format!("\"{}\"", s.replace('"', "\\\""))
             }
             crate::frontend::ast::Literal::Bool(b) => b.to_string(),
-            crate::frontend::ast::Literal::Char(c) => format!("'{c}'"),
+            crate::frontend::ast::Literal::Char(c) => format!("{c:?}"),
             crate::frontend::ast::Literal::Byte(b) => format!("b'{}'", *b as char),
             crate::frontend::ast::Literal::Unit => "()".to_string(),
             crate::frontend::ast::Literal::Null => "null".to_string(),
@@ -857,25 +898,27 @@ impl Formatter {
         indent: usize,
     ) -> String {
         let mut result = format!("fun {name}");
-        result.push('(');
-        for (i, param) in params.iter().enumerate() {
-            if i > 0 {
-                result.push_str(", ");
-            }
-            if let crate::frontend::ast::Pattern::Identifier(param_name) = &param.pattern {
-                result.push_str(param_name);
-                if let crate::frontend::ast::TypeKind::Named(type_name) = &param.ty.kind {
-                    if type_name != "Any" {
-                        result.push_str(": ");
-                        result.push_str(type_name);
-                    }
-                } else {
-                    result.push_str(": ");
-                    result.push_str(&Self::format_type(&param.ty.kind));
-                }
-            }
+        let has_comments = params
+            .iter()
+            .any(|p| !p.leading_comments.is_empty() || p.trailing_comment.is_some());
+        if has_comments {
+            let items: Vec<_> = params
+                .iter()
+                .map(|param| CommentedItem {
+                    text: self.format_param(param, indent + 1),
+                    leading: &param.leading_comments,
+                    trailing: param.trailing_comment.as_ref(),
+                })
+                .collect();
+            result.push_str(&self.format_commented_list("(", &items, ")", indent));
+        } else {
+            let params_str = params
+                .iter()
+                .map(|param| self.format_param(param, indent))
+                .collect::<Vec<_>>()
+                .join(", ");
+            result.push_str(&format!("({params_str})"));
         }
-        result.push(')');
         if let Some(ret_ty) = return_type {
             result.push_str(" -> ");
             result.push_str(&Self::format_type(&ret_ty.kind));
@@ -885,6 +928,26 @@ impl Formatter {
         result
     }
 
+    /// Format a function parameter: pattern, type unless inferred, default (complexity: 5)
+    fn format_param(&self, param: &crate::frontend::ast::Param, indent: usize) -> String {
+        let mut result = if param.is_mutable {
+            format!("mut {}", self.format_pattern(&param.pattern))
+        } else if let crate::frontend::ast::Pattern::RestNamed(name) = &param.pattern {
+            format!("...{name}")
+        } else {
+            self.format_pattern(&param.pattern)
+        };
+        if !matches!(&param.ty.kind, crate::frontend::ast::TypeKind::Named(name) if name == "Any") {
+            result.push_str(": ");
+            result.push_str(&Self::format_type(&param.ty.kind));
+        }
+        if let Some(default) = &param.default_value {
+            result.push_str(" = ");
+            result.push_str(&self.format_expr(default, indent));
+        }
+        result
+    }
+
     fn format_if_expr(
         &self,
         condition: &Expr,
@@ -905,6 +968,14 @@ impl Formatter {
 
     fn format_call_expr(&self, func: &Expr, args: &[Expr], indent: usize) -> String {
         let mut result = self.format_expr(func, indent);
+        if args.iter().any(Self::has_comments) {
+            let items: Vec<_> = args
+                .iter()
+                .map(|arg| self.expr_item(arg, indent + 1))
+                .collect();
+            result.push_str(&self.format_commented_list("(", &items, ")", indent));
+            return result;
+        }
         result.push('(');
         for (i, arg) in args.iter().enumerate() {
             if i > 0 {
@@ -962,6 +1033,30 @@ impl Formatter {
         result
     }
 
+    /// A loop label as written in source: `'outer`, or `@outer` as is
+    fn loop_label_source(label: &str) -> String {
+        if label.starts_with('@') {
+            label.to_string()
+        } else {
+            format!("'{label}")
+        }
+    }
+
+    /// `'outer: ` before a labeled loop, nothing before an unlabeled one
+    fn loop_label_prefix(label: Option<&String>) -> String {
+        label.map_or_else(String::new, |label| {
+            format!("{}: ", Self::loop_label_source(label))
+        })
+    }
+
+    /// `break`/`continue`, followed by the loop label it names if any
+    fn jump_with_label(keyword: &str, label: Option<&String>) -> String {
+        match label {
+            Some(label) => format!("{keyword} {}", Self::loop_label_source(label)),
+            None => keyword.to_string(),
+        }
+    }
+
     fn format_index_access(&self, object: &Expr, index: &Expr, indent: usize) -> String {
         format!(
             "{}[{}]",
@@ -1000,6 +1095,13 @@ impl Formatter {
     }
 
     fn format_collection(&self, open: &str, items: &[Expr], close: &str, indent: usize) -> String {
+        if items.iter().any(Self::has_comments) {
+            let items: Vec<_> = items
+                .iter()
+                .map(|item| self.expr_item(item, indent + 1))
+                .collect();
+            return self.format_commented_list(open, &items, close, indent);
+        }
         let formatted: Vec<String> = items
             .iter()
             .map(|item| self.format_expr(item, indent))
@@ -1014,14 +1116,31 @@ impl Formatter {
         indent: usize,
     ) -> String {
         let mut result = format!("match {} {{\n", self.format_expr(scrutinee, indent));
+        let arm_indent = " ".repeat(indent * self.config.indent_width);
         for arm in arms {
-            let pattern_str = format!("{:?}", arm.pattern);
+            for comment in &arm.leading_comments {
+                result.push_str(&format!(
+                    "{arm_indent}  {}\n",
+                    self.format_comment(comment, 0)
+                ));
+            }
+            let guard_str = arm
+                .guard
+                .as_ref()
+                .map(|guard| format!(" if {}", self.format_expr(guard, indent + 1)))
+                .unwrap_or_default();
             result.push_str(&format!(
-                "{}  {} => {},\n",
-                " ".repeat(indent * self.config.indent_width),
-                pattern_str,
+                "{}  {}{} => {},",
+                arm_indent,
+                self.format_pattern(&arm.pattern),
+                guard_str,
                 self.format_expr(&arm.body, indent + 1)
             ));
+            if let Some(comment) = &arm.trailing_comment {
+                result.push(' ');
+                result.push_str(&self.format_comment(comment, 0));
+            }
+            result.push('\n');
         }
         result.push_str(&format!(
             "{}}}",
@@ -1164,14 +1283,29 @@ impl Formatter {
         type_params: &[String],
         fields: &[crate::frontend::ast::StructField],
         is_pub: bool,
+        indent: usize,
     ) -> String {
         let pub_str = if is_pub { "pub " } else { "" };
         let type_params_str = Self::format_type_params_str(type_params);
-        let fields_str = fields
+        let field_str = |f: &crate::frontend::ast::StructField| {
+            format!("{}: {}", f.name, Self::format_type(&f.ty.kind))
+        };
+        if fields
             .iter()
-            .map(|f| format!("{}: {}", f.name, Self::format_type(&f.ty.kind)))
-            .collect::<Vec<_>>()
-            .join(", ");
+            .any(|f| !f.leading_comments.is_empty() || f.trailing_comment.is_some())
+        {
+            let items: Vec<_> = fields
+                .iter()
+                .map(|f| CommentedItem {
+                    text: field_str(f),
+                    leading: &f.leading_comments,
+                    trailing: f.trailing_comment.as_ref(),
+                })
+                .collect();
+            let body = self.format_commented_list("{", &items, "}", indent);
+            return format!("{pub_str}struct {name}{type_params_str} {body}");
+        }
+        let fields_str = fields.iter().map(field_str).collect::<Vec<_>>().join(", ");
         format!("{pub_str}struct {name}{type_params_str} {{ {fields_str} }}")
     }
 
@@ -1198,9 +1332,25 @@ impl Formatter {
         type_params: &[String],
         variants: &[crate::frontend::ast::EnumVariant],
         is_pub: bool,
+        indent: usize,
     ) -> String {
         let pub_str = if is_pub { "pub " } else { "" };
         let type_params_str = Self::format_type_params_str(type_params);
+        if variants
+            .iter()
+            .any(|v| !v.leading_comments.is_empty() || v.trailing_comment.is_some())
+        {
+            let items: Vec<_> = variants
+                .iter()
+                .map(|v| CommentedItem {
+                    text: self.format_enum_variant(v),
+                    leading: &v.leading_comments,
+                    trailing: v.trailing_comment.as_ref(),
+                })
+                .collect();
+            let body = self.format_commented_list("{", &items, "}", indent);
+            return format!("{pub_str}enum {name}{type_params_str} {body}");
+        }
         let variants_str = variants
             .iter()
             .map(|v| self.format_enum_variant(v))
@@ -1512,6 +1662,68 @@ impl Formatter {
         format!("{name}!({args_str})")
     }
 
+    fn format_extern_block(
+        &self,
+        abi: &str,
+        path: Option<&str>,
+        functions: &[crate::frontend::ast::ExternFunction],
+        indent: usize,
+    ) -> String {
+        let pad = "    ".repeat(indent + 1);
+        let mut result = format!("extern \"{abi}\" ");
+        if let Some(path) = path {
+            result.push_str(path);
+            result.push(' ');
+        }
+        result.push('{');
+        for function in functions {
+            let params_str = function
+                .params
+                .iter()
+                .map(|param| self.format_param(param, indent + 1))
+                .collect::<Vec<_>>()
+                .join(", ");
+            result.push_str(&format!("\n{pad}fun {}({params_str})", function.name));
+            if let Some(ret_ty) = &function.return_type {
+                result.push_str(&format!(" -> {}", Self::format_type(&ret_ty.kind)));
+            }
+        }
+        if !functions.is_empty() {
+            result.push('\n');
+            result.push_str(&"    ".repeat(indent));
+        }
+        result.push('}');
+        result
+    }
+
+    /// The Rust code is kept verbatim, whitespace included
+    fn format_rust_block(
+        &self,
+        inputs: &[crate::frontend::ast::Param],
+        output: Option<&crate::frontend::ast::Type>,
+        code: &str,
+        fallback: Option<&Expr>,
+        indent: usize,
+    ) -> String {
+        let mut result = "rust".to_string();
+        if let Some(output) = output {
+            let params_str = inputs
+                .iter()
+                .map(|param| self.format_param(param, indent))
+                .collect::<Vec<_>>()
+                .join(", ");
+            result.push_str(&format!(
+                "({params_str}) -> {}",
+                Self::format_type(&output.kind)
+            ));
+        }
+        result.push_str(&format!(" {{{code}}}"));
+        if let Some(fallback) = fallback {
+            result.push_str(&format!(" else {}", self.format_expr(fallback, indent)));
+        }
+        result
+    }
+
     fn format_dataframe(
         &self,
         columns: &[crate::frontend::ast::DataFrameColumn],
@@ -1534,6 +1746,64 @@ impl Formatter {
     }
 
     /// Format a comment (complexity: 2)
+    /// Whether an expression carries leading or trailing comments
+    fn has_comments(expr: &Expr) -> bool {
+        !expr.leading_comments.is_empty() || expr.trailing_comment.is_some()
+    }
+
+    /// Format an expression as a list item, lifting its comments out of the text
+    fn expr_item<'a>(&self, expr: &'a Expr, indent: usize) -> CommentedItem<'a> {
+        let mut bare = expr.clone();
+        bare.leading_comments.clear();
+        bare.trailing_comment = None;
+        CommentedItem {
+            text: self.format_expr(&bare, indent),
+            leading: &expr.leading_comments,
+            trailing: expr.trailing_comment.as_ref(),
+        }
+    }
+
+    /// Lay out a delimited list with one item per line (complexity: 4)
+    ///
+    /// Used once any item carries a comment: leading comments go above their
+    /// item and trailing comments after its comma, so a line comment never
+    /// swallows the code that follows it.
+    fn format_commented_list(
+        &self,
+        open: &str,
+        items: &[CommentedItem<'_>],
+        close: &str,
+        indent: usize,
+    ) -> String {
+        let item_indent = self.indent_str(indent + 1);
+        let mut result = format!("{open}\n");
+        for item in items {
+            for comment in item.leading {
+                result.push_str(&self.format_comment(comment, indent + 1));
+                result.push('\n');
+            }
+            result.push_str(&item_indent);
+            result.push_str(&item.text);
+            result.push(',');
+            if let Some(comment) = item.trailing {
+                result.push(' ');
+                result.push_str(&self.format_comment(comment, 0));
+            }
+            result.push('\n');
+        }
+        result.push_str(&self.indent_str(indent));
+        result.push_str(close);
+        result
+    }
+
+    fn indent_str(&self, indent: usize) -> String {
+        if self.config.use_tabs {
+            "\t".repeat(indent)
+        } else {
+            " ".repeat(indent * self.config.indent_width)
+        }
+    }
+
     fn format_comment(&self, comment: &crate::frontend::ast::Comment, indent: usize) -> String {
         let indent_str = if self.config.use_tabs {
             "\t".repeat(indent)
@@ -1640,6 +1910,22 @@ impl Formatter {
             Pattern::Mut(pattern) => {
                 format!("mut {}", self.format_pattern(pattern))
             }
+            Pattern::StringAffix {
+                prefix,
+                rest,
+                suffix,
+            } => {
+                let mut parts = Vec::new();
+                if !prefix.is_empty() {
+                    parts.push(format!("\"{prefix}\""));
+                }
+                parts.push(self.format_pattern(rest));
+                if !suffix.is_empty() {
+                    parts.push(format!("\"{suffix}\""));
+                }
+                parts.join(" ++ ")
+            }
+            Pattern::Regex(regex) => format!("re\"{regex}\""),
             Pattern::Ok(pattern) => {
                 format!("Ok({})", self.format_pattern(pattern))
             }
@@ -1678,10 +1964,12 @@ impl Formatter {
                     val.to_string()
                 }
             }
+            Literal::BigInt(digits) => format!("{digits}n"),
+            Literal::Decimal(text) => format!("{text}d"),
             Literal::Float(val) => val.to_string(),
             Literal::String(s) => format!("\"{s}\""),
             Literal::Bool(b) => b.to_string(),
-            Literal::Char(c) => format!("'{c}'"),
+            Literal::Char(c) => format!("{c:?}"),
             Literal::Byte(b) => format!("{b}u8"),
             crate::frontend::ast::Literal::Unit => "()".to_string(),
             crate::frontend::ast::Literal::Null => "null".to_string(),
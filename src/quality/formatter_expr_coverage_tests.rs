@@ -37,6 +37,15 @@ fn test_format_expr_literal_char() {
     assert_eq!(result, "'A'");
 }
 
+#[test]
+fn test_format_expr_literal_char_escapes() {
+    let f = make_formatter();
+    for (c, expected) in [('\n', "'\\n'"), ('\'', "'\\''"), ('é', "'é'")] {
+        let expr = Expr::new(ExprKind::Literal(Literal::Char(c)), span());
+        assert_eq!(f.format_expr(&expr, 0), expected);
+    }
+}
+
 #[test]
 fn test_format_expr_literal_byte() {
     let f = make_formatter();
@@ -732,6 +741,7 @@ fn test_format_expr_list_comprehension() {
             element: Box::new(ident_expr("x")),
             clauses: vec![ComprehensionClause {
                 variable: "x".to_string(),
+                pattern: crate::frontend::ast::Pattern::Identifier("x".to_string()),
                 iterable: Box::new(ident_expr("items")),
                 condition: None,
             }],
@@ -750,6 +760,7 @@ fn test_format_expr_list_comprehension_with_condition() {
             element: Box::new(ident_expr("x")),
             clauses: vec![ComprehensionClause {
                 variable: "x".to_string(),
+                pattern: crate::frontend::ast::Pattern::Identifier("x".to_string()),
                 iterable: Box::new(ident_expr("items")),
                 condition: Some(Box::new(Expr::new(
                     ExprKind::Binary {
@@ -780,6 +791,7 @@ fn test_format_expr_dict_comprehension() {
             value: Box::new(ident_expr("v")),
             clauses: vec![ComprehensionClause {
                 variable: "kv".to_string(),
+                pattern: crate::frontend::ast::Pattern::Identifier("kv".to_string()),
                 iterable: Box::new(ident_expr("pairs")),
                 condition: None,
             }],
@@ -802,6 +814,7 @@ fn test_format_expr_set_comprehension() {
             element: Box::new(ident_expr("x")),
             clauses: vec![ComprehensionClause {
                 variable: "x".to_string(),
+                pattern: crate::frontend::ast::Pattern::Identifier("x".to_string()),
                 iterable: Box::new(ident_expr("items")),
                 condition: None,
             }],
@@ -1205,3 +1218,22 @@ fn test_format_expr_let_in_expression() {
     let result = f.format_expr(&expr, 0);
     assert_eq!(result, "let x = 10 in x + 1");
 }
+
+#[test]
+fn test_format_rust_block_keeps_code_verbatim() {
+    let f = make_formatter();
+    let expr =
+        crate::frontend::parser::Parser::new("rust(n: i64) -> i64 {  n.pow(2)  } else { n * n }")
+            .parse()
+            .unwrap();
+    let result = f.format_expr(&expr, 0);
+    assert!(
+        result.starts_with("rust(n: i64) -> i64 {  n.pow(2)  } else "),
+        "{result}"
+    );
+
+    let expr = crate::frontend::parser::Parser::new("rust {\n    use std::fmt;\n}")
+        .parse()
+        .unwrap();
+    assert_eq!(f.format_expr(&expr, 0), "rust {\n    use std::fmt;\n}");
+}
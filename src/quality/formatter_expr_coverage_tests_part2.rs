@@ -203,6 +203,8 @@ fn test_format_expr_function_with_typed_params_and_return() {
         span: span(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
     let expr = Expr::new(
         ExprKind::Function {
@@ -235,6 +237,8 @@ fn test_format_expr_function_param_any_type_omitted() {
         span: span(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
     let expr = Expr::new(
         ExprKind::Function {
@@ -269,6 +273,8 @@ fn test_format_expr_function_param_non_named_type() {
         span: span(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
     let expr = Expr::new(
         ExprKind::Function {
@@ -298,6 +304,8 @@ fn test_format_expr_function_multiple_params() {
         span: span(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
     let param2 = Param {
         pattern: Pattern::Identifier("b".to_string()),
@@ -308,6 +316,8 @@ fn test_format_expr_function_multiple_params() {
         span: span(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
     let expr = Expr::new(
         ExprKind::Function {
@@ -341,12 +351,16 @@ fn test_format_expr_match() {
                     guard: None,
                     body: Box::new(str_expr("one")),
                     span: span(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
                 MatchArm {
                     pattern: Pattern::Wildcard,
                     guard: None,
                     body: Box::new(str_expr("other")),
                     span: span(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
             ],
         },
@@ -597,6 +611,8 @@ fn test_format_expr_struct_no_type_params() {
                     is_mut: false,
                     default_value: None,
                     decorators: vec![],
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
                 StructField {
                     name: "y".to_string(),
@@ -608,6 +624,8 @@ fn test_format_expr_struct_no_type_params() {
                     is_mut: false,
                     default_value: None,
                     decorators: vec![],
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
             ],
             methods: vec![],
@@ -637,6 +655,8 @@ fn test_format_expr_struct_pub_with_type_params() {
                 is_mut: false,
                 default_value: None,
                 decorators: vec![],
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             }],
             methods: vec![],
             derives: vec![],
@@ -714,11 +734,15 @@ fn test_format_expr_enum() {
                     name: "Red".to_string(),
                     kind: EnumVariantKind::Unit,
                     discriminant: None,
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
                 EnumVariant {
                     name: "Green".to_string(),
                     kind: EnumVariantKind::Unit,
                     discriminant: None,
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
             ],
             is_pub: false,
@@ -746,11 +770,15 @@ fn test_format_expr_enum_pub_with_type_params() {
                         span: span(),
                     }]),
                     discriminant: None,
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
                 EnumVariant {
                     name: "None".to_string(),
                     kind: EnumVariantKind::Unit,
                     discriminant: None,
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
             ],
             is_pub: true,
@@ -891,6 +919,8 @@ fn test_format_expr_class_no_type_params() {
                 is_mut: false,
                 default_value: None,
                 decorators: vec![],
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             }],
             constructors: vec![],
             methods: vec![],
@@ -954,6 +984,8 @@ fn test_format_expr_actor() {
                 is_mut: false,
                 default_value: None,
                 decorators: vec![],
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             }],
             handlers: vec![ActorHandler {
                 message_type: "Increment".to_string(),
@@ -990,6 +1022,8 @@ fn test_format_expr_effect() {
                     span: span(),
                     is_mutable: false,
                     default_value: None,
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 }],
                 return_type: Some(Type {
                     kind: TypeKind::Named("Unit".to_string()),
@@ -1205,3 +1205,16 @@
         let result = formatter.format(&expr).expect("should format");
         assert!(result.contains("println!"));
     }
+
+    #[test]
+    fn test_format_keeps_loop_labels() {
+        let result = format_code(
+            "'outer: for i in 0..3 { 'inner: while true { if i == 1 { continue 'outer } break 'inner } }",
+        );
+        assert!(result.contains("'outer: for i in"), "got: {result}");
+        assert!(result.contains("'inner: while true"), "got: {result}");
+        assert!(result.contains("continue 'outer"), "got: {result}");
+        assert!(result.contains("break 'inner"), "got: {result}");
+        let reparsed = Parser::new(&result).parse();
+        assert!(reparsed.is_ok(), "formatted code should parse: {result}");
+    }
@@ -93,6 +93,8 @@ fn test_format_lambda_r164() {
         span: Default::default(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
     let expr = Expr::new(
         ExprKind::Lambda {
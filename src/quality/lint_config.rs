@@ -0,0 +1,266 @@
+//! Lint configuration system
+//!
+//! `ruchy lint` reads `.ruchylint.toml` from the linted file's directory or
+//! the nearest ancestor that has one; `ruchy lint --init-config` writes a
+//! starting point listing every rule at its default level.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+/// Every configurable rule with its default level, in the order the
+/// generated config lists them
+pub const RULES: &[(&str, RuleLevel)] = &[
+    ("unused_variable", RuleLevel::Warn),
+    ("unused_parameter", RuleLevel::Warn),
+    ("unused_loop_variable", RuleLevel::Warn),
+    ("unused_match_binding", RuleLevel::Warn),
+    ("unused_import", RuleLevel::Warn),
+    ("undefined", RuleLevel::Deny),
+    ("shadowing", RuleLevel::Warn),
+    ("complexity", RuleLevel::Warn),
+    ("performance", RuleLevel::Allow),
+    ("unreachable_code", RuleLevel::Warn),
+    ("unused_function", RuleLevel::Warn),
+    ("unreachable_match_arm", RuleLevel::Warn),
+    ("command_injection", RuleLevel::Allow),
+    ("path_traversal", RuleLevel::Allow),
+    ("hardcoded_secret", RuleLevel::Allow),
+];
+
+/// How a rule's issues are reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleLevel {
+    /// The rule doesn't run
+    Allow,
+    /// Issues are warnings
+    Warn,
+    /// Issues are errors
+    Deny,
+}
+
+impl RuleLevel {
+    /// Name of the level in config files
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Allow => "allow",
+            Self::Warn => "warn",
+            Self::Deny => "deny",
+        }
+    }
+}
+
+/// Configuration for the Ruchy linter
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::quality::lint_config::{LintConfig, RuleLevel};
+/// use std::path::Path;
+///
+/// let config = LintConfig::from_toml(
+///     "max_complexity = 15\n[rules]\nshadowing = \"allow\"\n[per_path_ignores]\n\"tests/**\" = [\"unused_variable\"]\n",
+/// )
+/// .unwrap();
+/// assert_eq!(config.max_complexity, 15);
+/// assert_eq!(config.level("shadowing"), RuleLevel::Allow);
+/// assert_eq!(config.level("undefined"), RuleLevel::Deny);
+/// assert!(config.allowed_in(Path::new("tests/parser.ruchy"), "unused_variable"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LintConfig {
+    /// Complexity above which `complexity` reports a file (default: 10)
+    pub max_complexity: usize,
+
+    /// Level of each rule; rules not listed keep their default level
+    pub rules: BTreeMap<String, RuleLevel>,
+
+    /// Files not linted at all, as globs relative to the config file
+    pub ignore: Vec<String>,
+
+    /// Rules allowed in the files matching each glob
+    pub per_path_ignores: BTreeMap<String, Vec<String>>,
+
+    /// Directory of the config file the globs are relative to
+    #[serde(skip)]
+    pub root: Option<PathBuf>,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self {
+            max_complexity: 10,
+            rules: BTreeMap::new(),
+            ignore: Vec::new(),
+            per_path_ignores: BTreeMap::new(),
+            root: None,
+        }
+    }
+}
+
+impl LintConfig {
+    /// Load configuration from a TOML file, with globs relative to its directory
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or isn't a valid configuration
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+        let mut config =
+            Self::from_toml(&contents).map_err(|e| format!("{}: {e}", path.display()))?;
+        let directory = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        config.root = Some(std::fs::canonicalize(directory).unwrap_or_else(|_| directory.into()));
+        Ok(config)
+    }
+
+    /// Load configuration from TOML string
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the TOML is invalid, names an unknown rule or
+    /// holds an invalid glob
+    pub fn from_toml(toml_str: &str) -> Result<Self, String> {
+        let config: Self =
+            toml::from_str(toml_str).map_err(|e| format!("Failed to parse lint config: {e}"))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// The commented configuration `ruchy lint --init-config` writes, with
+    /// every rule at its default level
+    pub fn template() -> String {
+        let mut toml = String::from(
+            "# Ruchy lint configuration, used for files in this directory and below\n\n\
+             # Complexity above which the `complexity` rule reports a file\n\
+             max_complexity = 10\n\n\
+             # Files not linted at all, as globs relative to this file\n\
+             ignore = []\n\n\
+             # \"allow\" turns a rule off, \"warn\" reports warnings, \"deny\" reports errors\n\
+             [rules]\n",
+        );
+        for (rule, level) in RULES {
+            let _ = writeln!(toml, "{rule} = \"{}\"", level.as_str());
+        }
+        toml.push_str(
+            "\n# Rules allowed in the files matching each glob\n\
+             [per_path_ignores]\n\
+             # \"tests/**\" = [\"unused_variable\", \"unused_parameter\"]\n",
+        );
+        toml
+    }
+
+    /// The configured level of `rule`, or its default
+    pub fn level(&self, rule: &str) -> RuleLevel {
+        self.rules.get(rule).copied().unwrap_or_else(|| {
+            RULES
+                .iter()
+                .find(|(name, _)| *name == rule)
+                .map_or(RuleLevel::Warn, |(_, level)| *level)
+        })
+    }
+
+    /// Whether `path` matches an `ignore` glob
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        let relative = self.relative_path(path);
+        self.ignore.iter().any(|glob| glob_matches(glob, &relative))
+    }
+
+    /// Whether a `per_path_ignores` glob matching `path` allows `rule`
+    pub fn allowed_in(&self, path: &Path, rule: &str) -> bool {
+        let relative = self.relative_path(path);
+        self.per_path_ignores
+            .iter()
+            .any(|(glob, rules)| rules.iter().any(|r| r == rule) && glob_matches(glob, &relative))
+    }
+
+    /// `path` relative to the config file's directory, when it is below it
+    fn relative_path(&self, path: &Path) -> PathBuf {
+        let Some(root) = &self.root else {
+            return path.to_path_buf();
+        };
+        std::fs::canonicalize(path)
+            .ok()
+            .and_then(|full| full.strip_prefix(root).ok().map(Path::to_path_buf))
+            .unwrap_or_else(|| path.to_path_buf())
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let per_path_rules = self.per_path_ignores.values().flatten();
+        for rule in self.rules.keys().chain(per_path_rules) {
+            if !RULES.iter().any(|(name, _)| *name == rule.as_str()) {
+                let known: Vec<&str> = RULES.iter().map(|(name, _)| *name).collect();
+                return Err(format!(
+                    "Unknown lint rule '{rule}' (known rules: {})",
+                    known.join(", ")
+                ));
+            }
+        }
+        let globs = self.ignore.iter().chain(self.per_path_ignores.keys());
+        for glob in globs {
+            glob::Pattern::new(glob).map_err(|e| format!("Invalid glob '{glob}': {e}"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether `glob` matches `path`, with `*` not crossing directories
+fn glob_matches(glob: &str, path: &Path) -> bool {
+    let options = glob::MatchOptions {
+        require_literal_separator: true,
+        ..glob::MatchOptions::new()
+    };
+    glob::Pattern::new(glob).is_ok_and(|pattern| pattern.matches_path_with(path, options))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_lists_every_rule_at_its_default_level() {
+        let config = LintConfig::from_toml(&LintConfig::template()).unwrap();
+        assert_eq!(config.max_complexity, 10);
+        assert_eq!(config.rules.len(), RULES.len());
+        for (rule, level) in RULES {
+            assert_eq!(config.level(rule), *level, "{rule}");
+            assert_eq!(LintConfig::default().level(rule), *level, "{rule}");
+        }
+    }
+
+    #[test]
+    fn test_unknown_rules_levels_and_globs_are_rejected() {
+        let err = LintConfig::from_toml("[rules]\nunused_varaible = \"warn\"\n").unwrap_err();
+        assert!(err.contains("Unknown lint rule 'unused_varaible'"), "{err}");
+        assert!(LintConfig::from_toml("[rules]\nshadowing = \"error\"\n").is_err());
+        assert!(LintConfig::from_toml("ignore = [\"src/[\"]\n").is_err());
+        assert!(LintConfig::from_toml("max_depth = 3\n").is_err());
+    }
+
+    #[test]
+    fn test_globs_match_paths_relative_to_the_config_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("tests/unit")).unwrap();
+        let config_path = dir.path().join(".ruchylint.toml");
+        std::fs::write(
+            &config_path,
+            "ignore = [\"generated/*.ruchy\"]\n[per_path_ignores]\n\"tests/**\" = [\"unused_variable\"]\n",
+        )
+        .unwrap();
+        let test_file = dir.path().join("tests/unit/parser.ruchy");
+        std::fs::write(&test_file, "let x = 1\n").unwrap();
+        let config = LintConfig::from_file(&config_path).unwrap();
+        assert!(config.allowed_in(&test_file, "unused_variable"));
+        assert!(!config.allowed_in(&test_file, "shadowing"));
+        assert!(!config.allowed_in(&dir.path().join("src/main.ruchy"), "unused_variable"));
+        assert!(config.is_ignored(Path::new("generated/ast.ruchy")));
+        assert!(!config.is_ignored(Path::new("generated/nested/ast.ruchy")));
+    }
+}
@@ -1,10 +1,16 @@
 // Code linter for Ruchy with comprehensive variable tracking
 // Toyota Way: Catch issues early through static analysis
-use crate::frontend::ast::{Expr, ExprKind, Literal, Pattern};
+use crate::frontend::ast::{Expr, ExprKind, Literal, Pattern, Span};
+use crate::frontend::lexer::{Token, TokenStream};
+use crate::middleend::dead_code::{find_dead_code, DeadCodeKind};
+use crate::middleend::match_analysis::{analyze_matches, MatchDiagnosticKind};
+use crate::quality::lint_config::{LintConfig, RuleLevel};
+use crate::quality::security::{find_security_issues, SecurityKind};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LintIssue {
     pub line: usize,
     pub column: usize,
@@ -15,8 +21,38 @@ pub struct LintIssue {
     #[serde(rename = "type")]
     pub issue_type: String,
     pub name: String,
+    /// Edit that resolves the issue, applied by `ruchy lint --fix`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fix: Option<LintFix>,
 }
-#[derive(Debug, Clone)]
+/// Machine-applicable fix: replace `source[start..end]` with `replacement`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LintFix {
+    pub start: usize,
+    pub end: usize,
+    pub replacement: String,
+}
+/// Result of [`Linter::apply_fixes`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FixOutcome {
+    /// The source with the fixes applied
+    pub source: String,
+    /// Issues resolved by an applied fix, in source order
+    pub fixed: Vec<LintIssue>,
+    /// Issues without a fix, or whose fix conflicted with another
+    pub unfixed: Vec<LintIssue>,
+}
+impl FixOutcome {
+    /// Number of fixed issues per rule, by rule name
+    pub fn fixed_by_rule(&self) -> BTreeMap<&str, usize> {
+        let mut counts = BTreeMap::new();
+        for issue in &self.fixed {
+            *counts.entry(issue.rule.as_str()).or_insert(0) += 1;
+        }
+        counts
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LintRule {
     UnusedVariable,
     UndefinedVariable,
@@ -24,22 +60,31 @@ pub enum LintRule {
     UnusedParameter,
     UnusedLoopVariable,
     UnusedMatchBinding,
+    UnusedImport,
     ComplexityLimit,
     NamingConvention,
     StyleViolation,
-    Security,
+    CommandInjection,
+    PathTraversal,
+    HardcodedSecret,
     Performance,
+    UnreachableCode,
+    UnusedFunction,
+    UnreachableMatchArm,
 }
 #[derive(Debug, Clone)]
 struct Scope {
     variables: HashMap<String, VariableInfo>,
     parent: Option<Box<Scope>>,
+    source: Rc<SourceMap>,
 }
 #[derive(Debug, Clone)]
 struct VariableInfo {
     defined_at: (usize, usize),
     used: bool,
     var_type: VarType,
+    /// Byte offset of the binding's name, when found in the source
+    offset: Option<usize>,
 }
 #[derive(Debug, Clone)]
 enum VarType {
@@ -52,14 +97,19 @@ enum VarType {
 }
 impl Scope {
     fn new() -> Self {
+        Self::with_source(Rc::default())
+    }
+    fn with_source(source: Rc<SourceMap>) -> Self {
         Self {
             variables: HashMap::new(),
             parent: None,
+            source,
         }
     }
     fn with_parent(parent: Scope) -> Self {
         Self {
             variables: HashMap::new(),
+            source: Rc::clone(&parent.source),
             parent: Some(Box::new(parent)),
         }
     }
@@ -70,9 +120,27 @@ impl Scope {
                 defined_at: (line, column),
                 used: false,
                 var_type,
+                offset: None,
             },
         );
     }
+    /// Point `name` at its first occurrence in the AST byte range `from..to`
+    fn locate(&mut self, name: &str, from: usize, to: usize) {
+        let Some(offset) = self.source.find_identifier(name, from, to) else {
+            return;
+        };
+        if let Some(info) = self.variables.get_mut(name) {
+            info.defined_at = self.source.line_col(offset);
+            info.offset = Some(offset);
+        }
+    }
+    /// Locate every binding of this scope in `from..to`, where a pattern was bound
+    fn locate_all(&mut self, from: usize, to: usize) {
+        let names: Vec<String> = self.variables.keys().cloned().collect();
+        for name in names {
+            self.locate(&name, from, to);
+        }
+    }
     fn mark_used(&mut self, name: &str) -> bool {
         if let Some(info) = self.variables.get_mut(name) {
             info.used = true;
@@ -92,7 +160,198 @@ impl Scope {
     }
 }
 
-/// Check if a name is a built-in function
+/// Tokens of the linted source, to point issues at the names they are about
+/// and to build fixes
+#[derive(Debug, Default)]
+struct SourceMap {
+    /// Offset the AST spans count from: past a shebang line, which the
+    /// lexer skips
+    base: usize,
+    /// Start offset of each line
+    line_starts: Vec<usize>,
+    /// Tokens other than comments, with spans into the full source
+    tokens: Vec<(Token, Span)>,
+}
+impl SourceMap {
+    fn new(source: &str) -> Self {
+        let base = if source.starts_with("#!") {
+            source.find('\n').map_or(source.len(), |i| i + 1)
+        } else {
+            0
+        };
+        let mut stream = TokenStream::new(source);
+        let mut tokens = Vec::new();
+        while let Some((token, span)) = stream.next() {
+            if !matches!(
+                token,
+                Token::LineComment(_) | Token::BlockComment(_) | Token::DocComment(_)
+            ) {
+                tokens.push((token, Span::new(span.start + base, span.end + base)));
+            }
+        }
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self {
+            base,
+            line_starts,
+            tokens,
+        }
+    }
+    /// Offset of the first identifier `name` in the AST byte range `from..to`
+    fn find_identifier(&self, name: &str, from: usize, to: usize) -> Option<usize> {
+        let (from, to) = (from + self.base, to + self.base);
+        self.tokens.iter().find_map(|(token, span)| match token {
+            Token::Identifier(id) if id == name && (from..to).contains(&span.start) => {
+                Some(span.start)
+            }
+            _ => None,
+        })
+    }
+    /// 1-based line and column of a byte offset
+    fn line_col(&self, offset: usize) -> (usize, usize) {
+        let line = self
+            .line_starts
+            .partition_point(|&start| start <= offset)
+            .max(1);
+        (line, offset - self.line_starts[line - 1] + 1)
+    }
+    /// Whether a token ends its line
+    fn line_break_after(&self, source: &str, index: usize) -> bool {
+        let end = self.tokens[index].1.end;
+        let next = self
+            .tokens
+            .get(index + 1)
+            .map_or(source.len(), |(_, span)| span.start);
+        source.get(end..next).map_or(true, |gap| gap.contains('\n'))
+    }
+    /// Whether a token starts its line
+    fn starts_line(&self, source: &str, index: usize) -> bool {
+        let start = self.tokens[index].1.start;
+        let (line, _) = self.line_col(start);
+        source
+            .get(self.line_starts[line - 1]..start)
+            .is_some_and(|indent| indent.trim().is_empty())
+    }
+    /// The import statements that start a line, each ending at a semicolon or
+    /// a line break outside braces
+    fn imports(&self, source: &str) -> Vec<ImportStatement> {
+        let mut imports = Vec::new();
+        let mut index = 0;
+        while index < self.tokens.len() {
+            let keyword = matches!(
+                self.tokens[index].0,
+                Token::Import | Token::From | Token::Use
+            );
+            if !keyword || !self.starts_line(source, index) {
+                index += 1;
+                continue;
+            }
+            let mut last = index;
+            let mut depth = 0usize;
+            loop {
+                match self.tokens[last].0 {
+                    Token::LeftBrace => depth += 1,
+                    Token::RightBrace => depth = depth.saturating_sub(1),
+                    _ => {}
+                }
+                let done = matches!(self.tokens[last].0, Token::Semicolon)
+                    || (depth == 0 && self.line_break_after(source, last));
+                if done || last + 1 == self.tokens.len() {
+                    break;
+                }
+                last += 1;
+            }
+            imports.push(self.import_statement(source, index, last));
+            index = last + 1;
+        }
+        imports
+    }
+    /// The names bound by the import statement of tokens `first..=last`
+    fn import_statement(&self, source: &str, first: usize, last: usize) -> ImportStatement {
+        let tokens = &self.tokens[first..=last];
+        let position = |wanted: fn(&Token) -> bool| tokens.iter().position(|(t, _)| wanted(t));
+        // Items follow `import` in `from m import a`, and precede `from` in `import { a } from m`
+        let items = match tokens[0].0 {
+            Token::From => {
+                position(|t| matches!(t, Token::Import)).map_or(&[][..], |i| &tokens[i + 1..])
+            }
+            Token::Import => match position(|t| matches!(t, Token::From)) {
+                Some(i) => &tokens[1..i],
+                None => &tokens[1..],
+            },
+            _ => &tokens[1..],
+        };
+        let mut segments = Vec::new();
+        let mut segment = Vec::new();
+        let mut braces = 0;
+        for entry in items {
+            match entry.0 {
+                // The path before a brace binds nothing
+                Token::LeftBrace => {
+                    braces += 1;
+                    segment.clear();
+                }
+                Token::Comma | Token::RightBrace => segments.push(std::mem::take(&mut segment)),
+                Token::Semicolon => {}
+                _ => segment.push(entry),
+            }
+        }
+        segments.push(segment);
+        segments.retain(|segment| !segment.is_empty());
+        let mut bindings = Vec::new();
+        let mut plain = braces <= 1;
+        for segment in &segments {
+            let name = match segment.iter().position(|(t, _)| matches!(t, Token::As)) {
+                Some(i) => segment.get(i + 1).copied(),
+                None => segment.last().copied(),
+            };
+            let glob = segment.iter().any(|(t, _)| matches!(t, Token::Star));
+            match name {
+                Some((Token::Identifier(name), span)) if !glob => {
+                    let item = (segment[0].1.start, segment[segment.len() - 1].1.end);
+                    bindings.push((name.clone(), span.start, item));
+                }
+                _ => plain = false,
+            }
+        }
+        let list = match (segments.first(), segments.last()) {
+            (Some(head), Some(tail)) if plain => {
+                Some((head[0].1.start, tail[tail.len() - 1].1.end))
+            }
+            _ => None,
+        };
+        let start = self.tokens[first].1.start;
+        let end = self.tokens[last].1.end;
+        let line_end = source[end..]
+            .find('\n')
+            .map_or(source.len(), |i| end + i + 1);
+        ImportStatement {
+            lines: (self.line_starts[self.line_col(start).0 - 1], line_end),
+            bindings,
+            list,
+        }
+    }
+}
+
+/// An `import`, `from ... import` or `use` statement
+struct ImportStatement {
+    /// Byte range of its whole lines
+    lines: (usize, usize),
+    /// Names it binds, with the offset of each and its item's byte range
+    bindings: Vec<(String, usize, (usize, usize))>,
+    /// Byte range of its item list, when every item is a plain binding so
+    /// the list can be rewritten
+    list: Option<(usize, usize)>,
+}
+
+/// Whether `text` contains `word` not adjoined by identifier characters
+fn contains_word(text: &str, word: &str) -> bool {
+    let ident = |c: char| c.is_alphanumeric() || c == '_';
+    text.match_indices(word)
+        .any(|(i, _)| !text[..i].ends_with(ident) && !text[i + word.len()..].starts_with(ident))
+}
+
 ///
 /// Returns true if the name is a Ruchy standard library function or built-in.
 ///
@@ -126,6 +385,8 @@ pub fn is_builtin(name: &str) -> bool {
         "path_join" | "path_extension" | "path_filename" | "path_parent" |
         // Collection functions
         "range" | "HashMap" | "HashSet" |
+        // Numeric constructors
+        "Decimal" |
         // Math functions
         "abs" | "sqrt" | "pow" | "sin" | "cos" | "tan" | "floor" | "ceil" | "round" |
         "min" | "max" | "exp" | "ln" | "log10" | "log2" |
@@ -146,6 +407,8 @@ pub struct Linter {
     rules: Vec<LintRule>,
     strict_mode: bool,
     max_complexity: usize,
+    /// Levels set by a `.ruchylint.toml`, by issue rule name
+    levels: HashMap<String, RuleLevel>,
 }
 impl Linter {
     /// # Examples
@@ -165,11 +428,16 @@ impl Linter {
                 LintRule::UnusedParameter,
                 LintRule::UnusedLoopVariable,
                 LintRule::UnusedMatchBinding,
+                LintRule::UnusedImport,
                 LintRule::ComplexityLimit,
                 LintRule::NamingConvention,
+                LintRule::UnreachableCode,
+                LintRule::UnusedFunction,
+                LintRule::UnreachableMatchArm,
             ],
             strict_mode: false,
             max_complexity: 10,
+            levels: HashMap::new(),
         }
     }
     /// # Examples
@@ -190,12 +458,23 @@ impl Linter {
                     self.rules.push(LintRule::UnusedParameter);
                     self.rules.push(LintRule::UnusedLoopVariable);
                     self.rules.push(LintRule::UnusedMatchBinding);
+                    self.rules.push(LintRule::UnusedImport);
+                    self.rules.push(LintRule::UnusedFunction);
+                }
+                "dead_code" => {
+                    self.rules.push(LintRule::UnreachableCode);
+                    self.rules.push(LintRule::UnusedFunction);
+                    self.rules.push(LintRule::UnreachableMatchArm);
                 }
                 "undefined" => self.rules.push(LintRule::UndefinedVariable),
                 "shadowing" => self.rules.push(LintRule::VariableShadowing),
                 "complexity" => self.rules.push(LintRule::ComplexityLimit),
                 "style" => self.rules.push(LintRule::StyleViolation),
-                "security" => self.rules.push(LintRule::Security),
+                "security" => {
+                    self.rules.push(LintRule::CommandInjection);
+                    self.rules.push(LintRule::PathTraversal);
+                    self.rules.push(LintRule::HardcodedSecret);
+                }
                 "performance" => self.rules.push(LintRule::Performance),
                 _ => {}
             }
@@ -212,6 +491,59 @@ impl Linter {
     pub fn set_strict_mode(&mut self, strict: bool) {
         self.strict_mode = strict;
     }
+    /// Apply a lint configuration: its complexity threshold, and each
+    /// configured rule's level, which turns the rule on or off and sets the
+    /// severity of its issues
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruchy::quality::linter::Linter;
+    /// use ruchy::quality::LintConfig;
+    /// use ruchy::Parser;
+    ///
+    /// let config = LintConfig::from_toml("[rules]\nunused_variable = \"deny\"").unwrap();
+    /// let mut linter = Linter::new();
+    /// linter.set_config(&config);
+    /// let source = "fun main() { let x = 1 }";
+    /// let issues = linter.lint(&Parser::new(source).parse().unwrap(), source).unwrap();
+    /// let unused = issues.iter().find(|i| i.rule == "unused_variable").unwrap();
+    /// assert_eq!(unused.severity, "error");
+    /// ```
+    pub fn set_config(&mut self, config: &LintConfig) {
+        self.max_complexity = config.max_complexity;
+        for (name, level) in &config.rules {
+            let Some(rule) = Self::rule_named(name) else {
+                continue;
+            };
+            self.rules.retain(|enabled| *enabled != rule);
+            if *level != RuleLevel::Allow {
+                self.rules.push(rule);
+            }
+            self.levels.insert(name.clone(), *level);
+        }
+    }
+    /// The rule reporting issues named `name` in a `.ruchylint.toml`
+    fn rule_named(name: &str) -> Option<LintRule> {
+        Some(match name {
+            "unused_variable" => LintRule::UnusedVariable,
+            "unused_parameter" => LintRule::UnusedParameter,
+            "unused_loop_variable" => LintRule::UnusedLoopVariable,
+            "unused_match_binding" => LintRule::UnusedMatchBinding,
+            "unused_import" => LintRule::UnusedImport,
+            "undefined" => LintRule::UndefinedVariable,
+            "shadowing" => LintRule::VariableShadowing,
+            "complexity" => LintRule::ComplexityLimit,
+            "performance" => LintRule::Performance,
+            "unreachable_code" => LintRule::UnreachableCode,
+            "unused_function" => LintRule::UnusedFunction,
+            "unreachable_match_arm" => LintRule::UnreachableMatchArm,
+            "command_injection" => LintRule::CommandInjection,
+            "path_traversal" => LintRule::PathTraversal,
+            "hardcoded_secret" => LintRule::HardcodedSecret,
+            _ => return None,
+        })
+    }
     /// # Examples
     ///
     /// ```
@@ -221,9 +553,10 @@ impl Linter {
     /// let result = instance.lint();
     /// // Verify behavior
     /// ```
-    pub fn lint(&self, ast: &Expr, _source: &str) -> Result<Vec<LintIssue>> {
+    pub fn lint(&self, ast: &Expr, source: &str) -> Result<Vec<LintIssue>> {
         let mut issues = Vec::new();
-        let mut scope = Scope::new();
+        let source_map = Rc::new(SourceMap::new(source));
+        let mut scope = Scope::with_source(Rc::clone(&source_map));
 
         // LINTER-086: Two-pass analysis for forward reference resolution (GitHub Issue #69)
         // Pass 1: Build symbol table (collect all function definitions)
@@ -234,6 +567,13 @@ impl Linter {
 
         // Check for unused variables
         self.check_unused_in_scope(&scope, &mut issues);
+        if self
+            .rules
+            .iter()
+            .any(|r| matches!(r, LintRule::UnusedImport))
+        {
+            Self::check_unused_imports(&source_map, source, &mut issues);
+        }
         // Check complexity
         if self
             .rules
@@ -253,8 +593,25 @@ impl Linter {
                 suggestion: "Consider breaking this into smaller functions".to_string(),
                 issue_type: "complexity".to_string(),
                 name: String::new(),
+                fix: None,
             });
         }
+        if self
+            .rules
+            .iter()
+            .any(|r| matches!(r, LintRule::Performance))
+        {
+            Self::check_loop_invariant_patterns(ast, false, source, &mut issues);
+        }
+        self.check_dead_code(ast, &source_map, &mut issues);
+        self.check_security(ast, &source_map, &mut issues);
+        for issue in &mut issues {
+            match self.levels.get(&issue.rule) {
+                Some(RuleLevel::Deny) => issue.severity = "error".to_string(),
+                Some(RuleLevel::Warn) => issue.severity = "warning".to_string(),
+                _ => {}
+            }
+        }
         // Return empty if clean
         if issues.is_empty() {
             // For JSON format compatibility
@@ -276,6 +633,7 @@ impl Linter {
             suggestion: format!("Consider renaming variable '{name}'"),
             issue_type: "variable_shadowing".to_string(),
             name: name.to_string(),
+            fix: None,
         }
     }
 
@@ -292,14 +650,16 @@ impl Linter {
             suggestion: format!("Define '{name}' before using it"),
             issue_type: "undefined_variable".to_string(),
             name: name.to_string(),
+            fix: None,
         }
     }
 
     /// Helper: Create unused variable/parameter/binding `LintIssue` (CERTEZA-001: Reduce duplication)
+    /// The fix renames the binding to `_name`, when its name was found in the source.
     /// Complexity: 3 (within Toyota Way limits)
     #[inline]
-    fn create_unused_issue(name: &str, var_type: VarType, defined_at: (usize, usize)) -> LintIssue {
-        let (rule_type, message_prefix, suggestion_suffix) = match var_type {
+    fn create_unused_issue(name: &str, info: &VariableInfo) -> LintIssue {
+        let (rule_type, message_prefix, suggestion_suffix) = match info.var_type {
             VarType::Local => ("unused_variable", "unused variable", "variable"),
             VarType::Parameter => ("unused_parameter", "unused parameter", "parameter"),
             VarType::LoopVariable => (
@@ -317,14 +677,19 @@ impl Linter {
         };
 
         LintIssue {
-            line: defined_at.0,
-            column: defined_at.1,
+            line: info.defined_at.0,
+            column: info.defined_at.1,
             severity: "warning".to_string(),
             rule: rule_type.to_string(),
             message: format!("{message_prefix}: {name}"),
-            suggestion: format!("Remove unused {suggestion_suffix}"),
+            suggestion: format!("Remove unused {suggestion_suffix} or rename it to '_{name}'"),
             issue_type: rule_type.to_string(),
             name: name.to_string(),
+            fix: info.offset.map(|offset| LintFix {
+                start: offset,
+                end: offset,
+                replacement: "_".to_string(),
+            }),
         }
     }
 
@@ -355,11 +720,11 @@ impl Linter {
         match &expr.kind {
             ExprKind::Let {
                 name, value, body, ..
-            } => self.analyze_let_expr(name, value, body, scope, issues),
+            } => self.analyze_let_expr(expr.span.start, name, value, body, scope, issues),
             ExprKind::Identifier(name) => self.analyze_identifier_expr(name, scope, issues),
             ExprKind::Function {
                 name, params, body, ..
-            } => self.analyze_function_expr(name, params, body, scope, issues),
+            } => self.analyze_function_expr(expr.span.start, name, params, body, scope, issues),
             ExprKind::For {
                 label: None,
                 var,
@@ -367,7 +732,15 @@ impl Linter {
                 iter,
                 body,
                 ..
-            } => self.analyze_for_expr(var, pattern.as_ref(), iter, body, scope, issues),
+            } => self.analyze_for_expr(
+                expr.span.start,
+                var,
+                pattern.as_ref(),
+                iter,
+                body,
+                scope,
+                issues,
+            ),
             ExprKind::Match { expr, arms, .. } => {
                 self.analyze_match_expr(expr, arms, scope, issues);
             }
@@ -400,7 +773,7 @@ impl Linter {
                 self.analyze_string_interpolation(parts, scope, issues);
             }
             ExprKind::Lambda { params, body, .. } => {
-                self.analyze_lambda_expr(params, body, scope, issues);
+                self.analyze_lambda_expr(expr.span.start, params, body, scope, issues);
             }
             ExprKind::Return { value } => {
                 if let Some(e) = value {
@@ -446,6 +819,7 @@ impl Linter {
 
     fn analyze_let_expr(
         &self,
+        from: usize,
         name: &str,
         value: &Expr,
         body: &Expr,
@@ -469,6 +843,7 @@ impl Linter {
                 issues.push(Self::create_shadowing_issue(name));
             }
             scope.define(name.to_owned(), 2, 1, VarType::Local);
+            scope.locate(name, from, value.span.start);
             self.analyze_expr(body, scope, issues);
         } else {
             // Expression-level let: Create new scope for the let binding body
@@ -482,6 +857,7 @@ impl Linter {
                 issues.push(Self::create_shadowing_issue(name));
             }
             let_scope.define(name.to_owned(), 2, 1, VarType::Local);
+            let_scope.locate(name, from, value.span.start);
             self.analyze_expr(body, &mut let_scope, issues);
             // LINT-008 FIX: Propagate "used" status from cloned parent back to original scope
             if let Some(parent_scope) = &let_scope.parent {
@@ -511,6 +887,7 @@ impl Linter {
 
     fn analyze_function_expr(
         &self,
+        from: usize,
         name: &str,
         params: &[crate::frontend::ast::Param],
         body: &Expr,
@@ -522,20 +899,23 @@ impl Linter {
         for param in params {
             Self::extract_param_bindings(&param.pattern, &mut func_scope);
         }
+        // Parameters are named after the function's own name
+        let params_from = func_scope
+            .source
+            .find_identifier(name, from, body.span.start)
+            .map_or(from, |offset| offset - func_scope.source.base + name.len());
+        func_scope.locate_all(params_from, body.span.start);
         self.analyze_expr(body, &mut func_scope, issues);
         for (n, info) in &func_scope.variables {
-            if !info.used && matches!(info.var_type, VarType::Local) {
-                issues.push(Self::create_unused_issue(
-                    n,
-                    info.var_type.clone(),
-                    info.defined_at,
-                ));
+            if !info.used && matches!(info.var_type, VarType::Local) && !n.starts_with('_') {
+                issues.push(Self::create_unused_issue(n, info));
             }
         }
     }
 
     fn analyze_for_expr(
         &self,
+        from: usize,
         var: &str,
         pattern: Option<&Pattern>,
         iter: &Expr,
@@ -549,6 +929,7 @@ impl Linter {
         } else {
             loop_scope.define(var.to_owned(), 2, 1, VarType::LoopVariable);
         }
+        loop_scope.locate_all(from, iter.span.start);
         self.analyze_expr(iter, scope, issues);
         self.analyze_expr(body, &mut loop_scope, issues);
         self.check_unused_in_scope(&loop_scope, issues);
@@ -565,6 +946,7 @@ impl Linter {
         for arm in arms {
             let mut branch_scope = Scope::with_parent(scope.clone());
             Self::extract_pattern_bindings(&arm.pattern, &mut branch_scope);
+            branch_scope.locate_all(arm.span.start, arm.body.span.start);
             if let Some(guard) = &arm.guard {
                 self.analyze_expr(guard, &mut branch_scope, issues);
             }
@@ -624,6 +1006,7 @@ impl Linter {
 
     fn analyze_lambda_expr(
         &self,
+        from: usize,
         params: &[crate::frontend::ast::Param],
         body: &Expr,
         scope: &mut Scope,
@@ -633,6 +1016,7 @@ impl Linter {
         for param in params {
             Self::extract_param_bindings(&param.pattern, &mut lambda_scope);
         }
+        lambda_scope.locate_all(from, body.span.start);
         self.analyze_expr(body, &mut lambda_scope, issues);
         self.check_unused_in_scope(&lambda_scope, issues);
     }
@@ -734,7 +1118,8 @@ impl Linter {
     }
     fn check_unused_in_scope(&self, scope: &Scope, issues: &mut Vec<LintIssue>) {
         for (name, info) in &scope.variables {
-            if !info.used {
+            // `_name` marks a binding as intentionally unused
+            if !info.used && !name.starts_with('_') {
                 // Check if rule is enabled for this variable type
                 let should_check = match info.var_type {
                     VarType::Local => self
@@ -758,15 +1143,82 @@ impl Linter {
                 };
 
                 if should_check {
-                    issues.push(Self::create_unused_issue(
-                        name,
-                        info.var_type.clone(),
-                        info.defined_at,
-                    ));
+                    issues.push(Self::create_unused_issue(name, info));
                 }
             }
         }
     }
+    /// Flag imported names that are never referenced outside import statements
+    ///
+    /// A name counts as used when an identifier outside the imports, or a
+    /// word of a string (which may interpolate it), spells it. The fix drops
+    /// the whole statement when nothing it imports is used, or the unused
+    /// items of a flat list.
+    /// Complexity: 7
+    fn check_unused_imports(map: &SourceMap, source: &str, issues: &mut Vec<LintIssue>) {
+        let imports = map.imports(source);
+        let in_import = |offset: usize| {
+            imports
+                .iter()
+                .any(|import| (import.lines.0..import.lines.1).contains(&offset))
+        };
+        let used = |name: &str| {
+            map.tokens.iter().any(|(token, span)| match token {
+                Token::Identifier(id) => id == name && !in_import(span.start),
+                Token::String(text) | Token::FString(text) => contains_word(text, name),
+                _ => false,
+            })
+        };
+        for import in &imports {
+            let unused: Vec<_> = import
+                .bindings
+                .iter()
+                .filter(|(name, ..)| !used(name))
+                .collect();
+            if unused.is_empty() {
+                continue;
+            }
+            let fix = import.list.map(|(start, end)| {
+                if unused.len() == import.bindings.len() {
+                    LintFix {
+                        start: import.lines.0,
+                        end: import.lines.1,
+                        replacement: String::new(),
+                    }
+                } else {
+                    let kept: Vec<&str> = import
+                        .bindings
+                        .iter()
+                        .filter(|(name, ..)| used(name))
+                        .map(|(_, _, (start, end))| &source[*start..*end])
+                        .collect();
+                    LintFix {
+                        start,
+                        end,
+                        replacement: kept.join(", "),
+                    }
+                }
+            });
+            for (name, offset, _) in unused {
+                let (line, column) = map.line_col(*offset);
+                issues.push(LintIssue {
+                    line,
+                    column,
+                    severity: "warning".to_string(),
+                    rule: "unused_import".to_string(),
+                    message: format!("unused import: {name}"),
+                    suggestion: format!("Remove the import of '{name}'"),
+                    issue_type: "unused_import".to_string(),
+                    name: name.clone(),
+                    fix: fix.clone(),
+                });
+            }
+        }
+    }
+    /// Apply the fixes of `issues` to `source`
+    ///
+    /// See [`Linter::apply_fixes`], which also reports what was fixed.
+    ///
     /// # Examples
     ///
     /// ```
@@ -777,15 +1229,260 @@ impl Linter {
     /// // Verify behavior
     /// ```
     pub fn auto_fix(&self, source: &str, issues: &[LintIssue]) -> Result<String> {
-        // Simple auto-fix implementation
-        let mut fixed = source.to_string();
-        for issue in issues {
+        Ok(self.apply_fixes(source, issues).source)
+    }
+    /// Apply the fixes of `issues` to `source`, recording which issues they fixed
+    ///
+    /// Edits are applied back to front; one that overlaps an edit already
+    /// applied is skipped and its issue left unfixed. Issues sharing an edit
+    /// (such as one import statement reported for two names) are all fixed
+    /// by it. (complexity: 8)
+    pub fn apply_fixes(&self, source: &str, issues: &[LintIssue]) -> FixOutcome {
+        let mut edits: Vec<(&LintFix, &LintIssue)> = issues
+            .iter()
+            .filter_map(|issue| issue.fix.as_ref().map(|fix| (fix, issue)))
+            .collect();
+        edits.sort_by(|(a, _), (b, _)| (b.start, b.end).cmp(&(a.start, a.end)));
+        let mut outcome = FixOutcome {
+            source: source.to_string(),
+            ..FixOutcome::default()
+        };
+        let mut last: Option<&LintFix> = None;
+        for (fix, issue) in edits {
+            let in_bounds = fix.start <= fix.end && fix.end <= source.len();
+            let on_chars = source.is_char_boundary(fix.start) && source.is_char_boundary(fix.end);
+            let repeated = last == Some(fix);
+            let clear = last.map_or(true, |applied| fix.end <= applied.start);
+            if repeated || (in_bounds && on_chars && clear) {
+                if !repeated {
+                    outcome
+                        .source
+                        .replace_range(fix.start..fix.end, &fix.replacement);
+                    last = Some(fix);
+                }
+                outcome.fixed.push(issue.clone());
+            } else {
+                outcome.unfixed.push(issue.clone());
+            }
+        }
+        for issue in issues.iter().filter(|issue| issue.fix.is_none()) {
             if issue.rule == "style" {
                 // Fix style issues
-                fixed = fixed.replace("  ", " ");
+                outcome.source = outcome.source.replace("  ", " ");
+                outcome.fixed.push(issue.clone());
+            } else {
+                outcome.unfixed.push(issue.clone());
+            }
+        }
+        let position = |issue: &LintIssue| (issue.line, issue.column);
+        outcome.fixed.sort_by_key(position);
+        outcome.unfixed.sort_by_key(position);
+        outcome
+    }
+    /// Flag regexes compiled from a literal pattern inside a loop body
+    ///
+    /// The interpreter caches compiled patterns, but transpiled code
+    /// recompiles `regex_new("...")`/`Regex::new("...")` on every iteration,
+    /// so suggest hoisting the call above the loop. Function bodies start
+    /// outside any loop; closures inherit the enclosing loop.
+    /// Complexity: 10 (flat AST traversal)
+    fn check_loop_invariant_patterns(
+        expr: &Expr,
+        in_loop: bool,
+        source: &str,
+        issues: &mut Vec<LintIssue>,
+    ) {
+        let mut visit = |child: &Expr, in_loop| {
+            Self::check_loop_invariant_patterns(child, in_loop, source, issues);
+        };
+        match &expr.kind {
+            ExprKind::For { iter, body, .. } => {
+                visit(iter, in_loop);
+                visit(body, true);
+            }
+            ExprKind::While {
+                condition, body, ..
+            } => {
+                visit(condition, true);
+                visit(body, true);
+            }
+            ExprKind::Loop { body, .. } => visit(body, true),
+            ExprKind::Function { body, .. } => visit(body, false),
+            ExprKind::Lambda { body, .. } => visit(body, in_loop),
+            ExprKind::Call { func, args } => {
+                visit(func, in_loop);
+                args.iter().for_each(|arg| visit(arg, in_loop));
+                if in_loop && Self::is_regex_constructor(func) {
+                    if let Some(pattern) = args.first().and_then(Self::string_literal) {
+                        issues.push(Self::create_loop_invariant_pattern_issue(
+                            pattern, func, source,
+                        ));
+                    }
+                }
+            }
+            ExprKind::Block(exprs) => exprs.iter().for_each(|e| visit(e, in_loop)),
+            ExprKind::Let { value, body, .. } | ExprKind::LetPattern { value, body, .. } => {
+                visit(value, in_loop);
+                visit(body, in_loop);
+            }
+            ExprKind::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                visit(condition, in_loop);
+                visit(then_branch, in_loop);
+                else_branch.iter().for_each(|e| visit(e, in_loop));
+            }
+            ExprKind::Match { expr, arms } => {
+                visit(expr, in_loop);
+                arms.iter().for_each(|arm| visit(&arm.body, in_loop));
             }
+            ExprKind::MethodCall { receiver, args, .. } => {
+                visit(receiver, in_loop);
+                args.iter().for_each(|arg| visit(arg, in_loop));
+            }
+            ExprKind::Binary { left, right, .. } => {
+                visit(left, in_loop);
+                visit(right, in_loop);
+            }
+            ExprKind::Assign { value, .. } | ExprKind::CompoundAssign { value, .. } => {
+                visit(value, in_loop);
+            }
+            ExprKind::Return { value: Some(value) } | ExprKind::Unary { operand: value, .. } => {
+                visit(value, in_loop);
+            }
+            _ => {}
+        }
+    }
+
+    /// `regex_new` or `Regex::new`, however the path was parsed
+    fn is_regex_constructor(func: &Expr) -> bool {
+        match &func.kind {
+            ExprKind::Identifier(name) => name == "regex_new" || name == "Regex::new",
+            ExprKind::QualifiedName { module, name } => {
+                name == "new" && (module == "Regex" || module.ends_with("::Regex"))
+            }
+            _ => false,
+        }
+    }
+
+    fn string_literal(expr: &Expr) -> Option<&str> {
+        match &expr.kind {
+            ExprKind::Literal(Literal::String(s)) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Helper: Create loop-invariant pattern `LintIssue` at the callee's
+    /// position (call expressions themselves carry no span)
+    /// Complexity: 2 (within Toyota Way limits)
+    fn create_loop_invariant_pattern_issue(
+        pattern: &str,
+        callee: &Expr,
+        source: &str,
+    ) -> LintIssue {
+        let before = source.get(..callee.span.start).unwrap_or("");
+        let line = before.matches('\n').count() + 1;
+        let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+        LintIssue {
+            line,
+            column,
+            severity: "warning".to_string(),
+            rule: "performance".to_string(),
+            message: format!("regex pattern {pattern:?} is compiled on every loop iteration"),
+            suggestion: "Compile the regex once before the loop and reuse it".to_string(),
+            issue_type: "loop_invariant_pattern".to_string(),
+            name: pattern.to_string(),
+            fix: None,
+        }
+    }
+    /// Report unreachable statements, unused functions and unreachable
+    /// match arms, for the rules that are on
+    fn check_dead_code(&self, ast: &Expr, source_map: &SourceMap, issues: &mut Vec<LintIssue>) {
+        let enabled = |rule: LintRule| self.rules.contains(&rule);
+        let mut report = |rule: &str, span: Span, message: String, name: String| {
+            let (line, column) = source_map.line_col(span.start + source_map.base);
+            let suggestion = if rule == "unused_function" {
+                "Remove the function, or call it from an entry point"
+            } else {
+                "Remove the code that can never run"
+            };
+            issues.push(LintIssue {
+                line,
+                column,
+                severity: "warning".to_string(),
+                rule: rule.to_string(),
+                message,
+                suggestion: suggestion.to_string(),
+                issue_type: "dead_code".to_string(),
+                name,
+                fix: None,
+            });
+        };
+        if enabled(LintRule::UnreachableCode) || enabled(LintRule::UnusedFunction) {
+            for diagnostic in find_dead_code(ast) {
+                let code = diagnostic.code();
+                let (rule, name) = match diagnostic.kind {
+                    DeadCodeKind::UnreachableCode => (LintRule::UnreachableCode, String::new()),
+                    DeadCodeKind::UnusedFunction { name } => (LintRule::UnusedFunction, name),
+                };
+                if enabled(rule) {
+                    report(code, diagnostic.span, diagnostic.message, name);
+                }
+            }
+        }
+        if enabled(LintRule::UnreachableMatchArm) {
+            for diagnostic in analyze_matches(ast) {
+                if diagnostic.kind == MatchDiagnosticKind::UnreachableArm {
+                    let code = diagnostic.code();
+                    report(code, diagnostic.span, diagnostic.message, String::new());
+                }
+            }
+        }
+    }
+    /// Report shell commands built from strings, path joins of unchecked
+    /// user input and hardcoded secrets, for the rules that are on
+    fn check_security(&self, ast: &Expr, source_map: &SourceMap, issues: &mut Vec<LintIssue>) {
+        let security_rules = [
+            LintRule::CommandInjection,
+            LintRule::PathTraversal,
+            LintRule::HardcodedSecret,
+        ];
+        if !security_rules.iter().any(|rule| self.rules.contains(rule)) {
+            return;
+        }
+        for diagnostic in find_security_issues(ast) {
+            let (rule, suggestion) = match diagnostic.kind {
+                SecurityKind::CommandInjection => (
+                    LintRule::CommandInjection,
+                    "Run the program directly and pass each value with `.arg()` instead of building a shell command",
+                ),
+                SecurityKind::PathTraversal => (
+                    LintRule::PathTraversal,
+                    "Reject `..` and absolute paths, or check that the canonical path stays inside the base directory",
+                ),
+                SecurityKind::HardcodedSecret => (
+                    LintRule::HardcodedSecret,
+                    "Read the secret from the environment with `env_var()` instead",
+                ),
+            };
+            if !self.rules.contains(&rule) {
+                continue;
+            }
+            let (line, column) = source_map.line_col(diagnostic.span.start + source_map.base);
+            issues.push(LintIssue {
+                line,
+                column,
+                severity: "warning".to_string(),
+                rule: diagnostic.code().to_string(),
+                message: diagnostic.message,
+                suggestion: suggestion.to_string(),
+                issue_type: "security".to_string(),
+                name: String::new(),
+                fix: None,
+            });
         }
-        Ok(fixed)
     }
     fn calculate_complexity(expr: &Expr) -> usize {
         match &expr.kind {
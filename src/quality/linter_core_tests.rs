@@ -61,6 +61,8 @@ fn create_test_param(name: &str) -> Param {
         span: create_test_span(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }
 }
 fn create_test_expr_block(exprs: Vec<Expr>) -> Expr {
@@ -122,6 +124,8 @@ fn create_test_match_arm(pattern: Pattern, body: Expr) -> MatchArm {
         guard: None,
         body: Box::new(body),
         span: create_test_span(),
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }
 }
 fn create_test_expr_lambda(params: Vec<Param>, body: Expr) -> Expr {
@@ -165,14 +169,14 @@ fn create_test_expr_return(value: Option<Expr>) -> Expr {
 #[test]
 fn test_linter_creation() {
     let linter = Linter::new();
-    assert_eq!(linter.rules.len(), 8); // Default rules count
+    assert_eq!(linter.rules.len(), 12); // Default rules count
     assert!(!linter.strict_mode);
     assert_eq!(linter.max_complexity, 10);
 }
 #[test]
 fn test_linter_default() {
     let linter = Linter::default();
-    assert_eq!(linter.rules.len(), 8);
+    assert_eq!(linter.rules.len(), 12);
     assert!(!linter.strict_mode);
     assert_eq!(linter.max_complexity, 10);
 }
@@ -187,7 +191,7 @@ fn test_linter_set_strict_mode() {
 fn test_set_rules_unused() {
     let mut linter = Linter::new();
     linter.set_rules("unused");
-    assert_eq!(linter.rules.len(), 4); // UnusedVariable, Parameter, LoopVariable, MatchBinding
+    assert_eq!(linter.rules.len(), 6); // UnusedVariable, Parameter, LoopVariable, MatchBinding, Import, Function
 }
 #[test]
 fn test_set_rules_undefined() {
@@ -226,12 +230,23 @@ fn test_set_rules_unknown() {
 fn test_set_rules_style_security_performance() {
     let mut linter = Linter::new();
     linter.set_rules("style,security,performance");
-    assert_eq!(linter.rules.len(), 3);
+    assert_eq!(linter.rules.len(), 5);
     assert!(linter
         .rules
         .iter()
         .any(|r| matches!(r, LintRule::StyleViolation)));
-    assert!(linter.rules.iter().any(|r| matches!(r, LintRule::Security)));
+    assert!(linter
+        .rules
+        .iter()
+        .any(|r| matches!(r, LintRule::CommandInjection)));
+    assert!(linter
+        .rules
+        .iter()
+        .any(|r| matches!(r, LintRule::PathTraversal)));
+    assert!(linter
+        .rules
+        .iter()
+        .any(|r| matches!(r, LintRule::HardcodedSecret)));
     assert!(linter
         .rules
         .iter()
@@ -310,6 +325,7 @@ fn test_lint_issue_serialization() {
         suggestion: "Remove unused variable 'x'".to_string(),
         issue_type: "unused_variable".to_string(),
         name: "x".to_string(),
+        fix: None,
     };
     let json = serde_json::to_string(&issue);
     assert!(json.is_ok());
@@ -916,6 +932,7 @@ fn test_auto_fix_style_issue() {
         suggestion: "Use single spaces".to_string(),
         issue_type: "style".to_string(),
         name: "spacing".to_string(),
+        fix: None,
     }];
     let fixed = linter
         .auto_fix("let  x  =  42", &issues)
@@ -964,6 +981,7 @@ fn test_variable_type_classification() {
         defined_at: (1, 1),
         used: false,
         var_type: VarType::Parameter,
+        offset: None,
     };
     assert_eq!(var_info.defined_at, (1, 1));
     assert!(!var_info.used);
@@ -993,7 +1011,9 @@ fn test_lint_rules_enum_coverage() {
     let _complexity_limit = LintRule::ComplexityLimit;
     let _naming_convention = LintRule::NamingConvention;
     let _style_violation = LintRule::StyleViolation;
-    let _security = LintRule::Security;
+    let _command_injection = LintRule::CommandInjection;
+    let _path_traversal = LintRule::PathTraversal;
+    let _hardcoded_secret = LintRule::HardcodedSecret;
     let _performance = LintRule::Performance;
     // Test passes without panic; // All variants created successfully
 }
@@ -1014,6 +1034,7 @@ fn test_variable_info_structure() {
         defined_at: (1, 5),
         used: false,
         var_type: VarType::Local,
+        offset: None,
     };
     assert_eq!(var_info.defined_at, (1, 5));
     assert!(!var_info.used);
@@ -1025,6 +1046,7 @@ fn test_scope_creation_duplicate_renamed() {
     let scope = Scope {
         variables: HashMap::new(),
         parent: None,
+        source: Rc::default(),
     };
     assert_eq!(scope.variables.len(), 0);
     assert!(scope.parent.is_none());
@@ -1041,6 +1063,7 @@ fn test_lint_issue_serialization_duplicate_renamed() {
         suggestion: "Remove unused variable".to_string(),
         issue_type: "unused".to_string(),
         name: "x".to_string(),
+        fix: None,
     };
 
     let json = serde_json::to_string(&issue).expect("operation should succeed in test");
@@ -1072,7 +1095,7 @@ fn test_linter_with_all_rules() {
     linter.add_rule(LintRule::ComplexityLimit);
     linter.add_rule(LintRule::NamingConvention);
     linter.add_rule(LintRule::StyleViolation);
-    linter.add_rule(LintRule::Security);
+    linter.add_rule(LintRule::HardcodedSecret);
     linter.add_rule(LintRule::Performance);
 
     assert_eq!(linter.rules.len(), 11);
@@ -1089,6 +1112,7 @@ fn test_lint_issue_debug_format() {
         suggestion: "Test suggestion".to_string(),
         issue_type: "test".to_string(),
         name: "test_name".to_string(),
+        fix: None,
     };
 
     let debug_str = format!("{issue:?}");
@@ -1104,6 +1128,7 @@ fn test_variable_info_debug_format() {
         defined_at: (5, 10),
         used: true,
         var_type: VarType::Parameter,
+        offset: None,
     };
 
     let debug_str = format!("{var_info:?}");
@@ -45,6 +45,7 @@ proptest! {
             suggestion: "fix spacing".to_string(),
             issue_type: "style".to_string(),
             name: "spacing".to_string(),
+            fix: None,
         }];
         if let Ok(fixed) = linter.auto_fix(&input, &issues) {
             // Style fixes should not increase length significantly
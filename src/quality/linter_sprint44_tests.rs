@@ -200,6 +200,8 @@ fn test_sprint_44_06_match_guard_variable_usage() {
             Span { start: 0, end: 1 },
         )),
         span: Span { start: 0, end: 1 },
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
 
     let match_expr = Expr::new(
@@ -239,6 +241,8 @@ fn test_sprint_44_07_lambda_parameter_patterns() {
         span: Span { start: 0, end: 1 },
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
 
     let lambda_body = Expr::new(
@@ -384,6 +388,8 @@ fn test_sprint_44_10_result_pattern_matching() {
             Span { start: 0, end: 1 },
         )),
         span: Span { start: 0, end: 1 },
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
 
     let err_arm = MatchArm {
@@ -394,6 +400,8 @@ fn test_sprint_44_10_result_pattern_matching() {
             Span { start: 0, end: 1 },
         )),
         span: Span { start: 0, end: 1 },
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
 
     let result_match = Expr::new(
@@ -442,6 +450,7 @@ fn test_sprint_44_11_auto_fix_preserves_semantics() {
             suggestion: "fix".to_string(),
             issue_type: "style".to_string(),
             name: "spacing".to_string(),
+            fix: None,
         };
 
         let fixed = linter
@@ -489,6 +498,7 @@ fn test_sprint_44_13_lint_issue_field_completeness() {
         suggestion: "Suggestion with newlines\nand tabs\t".to_string(),
         issue_type: "custom_type".to_string(),
         name: "unicode_var_名前".to_string(),
+        fix: None,
     };
 
     // Test serialization handles all fields and special characters
@@ -567,13 +577,14 @@ fn test_sprint_44_16_rule_filtering_comprehensive() {
 
     // Test all individual rules
     let rule_tests = vec![
-        ("unused", 4), // UnusedVariable, Parameter, LoopVariable, MatchBinding
+        ("unused", 6), // UnusedVariable, Parameter, LoopVariable, MatchBinding, Import, Function
         ("undefined", 1),
         ("shadowing", 1),
         ("complexity", 1),
         ("style", 1),
-        ("security", 1),
+        ("security", 3), // CommandInjection, PathTraversal, HardcodedSecret
         ("performance", 1),
+        ("dead_code", 3),
     ];
 
     for (rule_name, expected_count) in rule_tests {
@@ -587,7 +598,7 @@ fn test_sprint_44_16_rule_filtering_comprehensive() {
 
     // Test combination
     linter.set_rules("unused,undefined,complexity");
-    assert_eq!(linter.rules.len(), 6); // 4 + 1 + 1
+    assert_eq!(linter.rules.len(), 8); // 6 + 1 + 1
 }
 
 #[test]
@@ -8,7 +8,7 @@
 #[cfg(test)]
 mod tests {
     use crate::frontend::parser::Parser;
-    use crate::quality::linter::{is_builtin, Linter};
+    use crate::quality::linter::{is_builtin, LintFix, LintIssue, Linter};
 
     // ============== Helper Function ==============
 
@@ -601,6 +601,35 @@ mod tests {
         assert!(issues.is_empty());
     }
 
+    #[test]
+    fn test_performance_flags_regex_literal_in_loop() {
+        let source = "for line in lines {\n    let re = regex_new(\"[0-9]+\")\n    println(re)\n}";
+        let issues = parse_and_lint_with_rules(source, "performance");
+        assert_eq!(issues.len(), 1, "{issues:?}");
+        assert_eq!(issues[0].issue_type, "loop_invariant_pattern");
+        assert_eq!(issues[0].name, "[0-9]+");
+        assert_eq!((issues[0].line, issues[0].column), (2, 14));
+    }
+
+    #[test]
+    fn test_performance_ignores_hoisted_or_dynamic_patterns() {
+        let hoisted = "let re = regex_new(\"a+\")\nwhile true { println(re) }";
+        assert!(parse_and_lint_with_rules(hoisted, "performance").is_empty());
+        let dynamic = "for p in patterns { println(regex_new(p)) }";
+        assert!(parse_and_lint_with_rules(dynamic, "performance").is_empty());
+        let in_function = "for i in 0..3 { fun f() { regex_new(\"a+\") } }";
+        assert!(parse_and_lint_with_rules(in_function, "performance").is_empty());
+    }
+
+    #[test]
+    fn test_loop_invariant_pattern_lint_is_opt_in() {
+        let source = "loop { regex_new(\"a+\") }";
+        let issues = parse_and_lint(source);
+        assert!(issues
+            .iter()
+            .all(|issue| issue.issue_type != "loop_invariant_pattern"));
+    }
+
     // ============== Style Tests ==============
 
     #[test]
@@ -1304,4 +1333,183 @@ mod tests {
         assert!(is_builtin("env_current_dir"));
         assert!(is_builtin("env_set_var"));
     }
+
+    // ============== Unused Bindings and Imports ==============
+
+    fn lint_and_fix(source: &str) -> (Vec<crate::quality::linter::LintIssue>, String) {
+        let issues = parse_and_lint_with_rules(source, "unused");
+        let fixed = Linter::new()
+            .auto_fix(source, &issues)
+            .expect("fixing should succeed");
+        (issues, fixed)
+    }
+
+    #[test]
+    fn test_unused_let_points_at_its_name_and_fixes_with_underscore() {
+        let source = "let used = 1\nlet spare = 2\nprintln(used)";
+        let (issues, fixed) = lint_and_fix(source);
+        assert_eq!(issues.len(), 1, "{issues:?}");
+        assert_eq!(issues[0].name, "spare");
+        assert_eq!((issues[0].line, issues[0].column), (2, 5));
+        assert_eq!(fixed, "let used = 1\nlet _spare = 2\nprintln(used)");
+        assert!(parse_and_lint_with_rules(&fixed, "unused").is_empty());
+    }
+
+    #[test]
+    fn test_underscore_prefix_marks_bindings_unused_on_purpose() {
+        let source = "let _spare = 2\nfun f() { let _tmp = 1\n 0 }\nlet g = |_x| 1\ng(1)\nf()";
+        assert!(parse_and_lint_with_rules(source, "unused").is_empty());
+    }
+
+    #[test]
+    fn test_unused_lambda_parameter_is_renamed() {
+        let source = "let g = |x, y| x\nprintln(g(1, 2))";
+        let (issues, fixed) = lint_and_fix(source);
+        assert!(
+            issues
+                .iter()
+                .any(|i| i.rule == "unused_parameter" && i.name == "y"),
+            "{issues:?}"
+        );
+        assert_eq!(fixed, "let g = |x, _y| x\nprintln(g(1, 2))");
+    }
+
+    #[test]
+    fn test_unused_import_statement_is_removed() {
+        let source =
+            "import std.collections.HashMap\nfrom std.collections import HashSet, BTreeMap\nprintln(BTreeMap)\n";
+        let (issues, fixed) = lint_and_fix(source);
+        let names: Vec<&str> = issues
+            .iter()
+            .filter(|i| i.rule == "unused_import")
+            .map(|i| i.name.as_str())
+            .collect();
+        assert_eq!(names, ["HashMap", "HashSet"]);
+        assert_eq!((issues[0].line, issues[0].column), (1, 24));
+        assert_eq!(
+            fixed,
+            "from std.collections import BTreeMap\nprintln(BTreeMap)\n"
+        );
+    }
+
+    #[test]
+    fn test_import_used_in_interpolation_or_through_alias() {
+        let source = "import std.collections.HashMap as Map\nfrom m import name\nlet m = Map\nprintln(f\"{name}\")\nprintln(m)\n";
+        let issues = parse_and_lint_with_rules(source, "unused");
+        assert!(
+            issues.iter().all(|i| i.rule != "unused_import"),
+            "{issues:?}"
+        );
+    }
+
+    #[test]
+    fn test_glob_import_is_never_flagged() {
+        let issues = parse_and_lint_with_rules("from std.collections import *\n", "unused");
+        assert!(issues.is_empty(), "{issues:?}");
+    }
+
+    #[test]
+    fn test_apply_fixes_reports_fixed_issues_by_rule() {
+        let source = "from std.collections import HashSet, BTreeMap\nlet spare = 1\nlet x = 2\n";
+        let issues = parse_and_lint_with_rules(source, "unused");
+        let outcome = Linter::new().apply_fixes(source, &issues);
+        assert_eq!(outcome.source, "let _spare = 1\nlet _x = 2\n");
+        assert!(outcome.unfixed.is_empty(), "{:?}", outcome.unfixed);
+        let by_rule: Vec<(&str, usize)> = outcome.fixed_by_rule().into_iter().collect();
+        assert_eq!(by_rule, [("unused_import", 2), ("unused_variable", 2)]);
+        let lines: Vec<usize> = outcome.fixed.iter().map(|i| i.line).collect();
+        assert_eq!(lines, [1, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_apply_fixes_leaves_conflicting_and_unfixable_issues() {
+        let fix = |start, end, replacement: &str| LintFix {
+            start,
+            end,
+            replacement: replacement.to_string(),
+        };
+        let issue = |rule: &str, edit| LintIssue {
+            line: 1,
+            column: 1,
+            severity: "warning".to_string(),
+            rule: rule.to_string(),
+            message: String::new(),
+            suggestion: String::new(),
+            issue_type: rule.to_string(),
+            name: String::new(),
+            fix: edit,
+        };
+        let issues = [
+            issue("a", Some(fix(4, 9, "sum"))),
+            issue("b", Some(fix(0, 6, "val"))),
+            issue("complexity", None),
+        ];
+        let outcome = Linter::new().apply_fixes("let total = 1", &issues);
+        assert_eq!(outcome.source, "let sum = 1");
+        let fixed: Vec<&str> = outcome.fixed.iter().map(|i| i.rule.as_str()).collect();
+        let unfixed: Vec<&str> = outcome.unfixed.iter().map(|i| i.rule.as_str()).collect();
+        assert_eq!(fixed, ["a"]);
+        assert_eq!(unfixed, ["b", "complexity"]);
+    }
+
+    #[test]
+    fn test_dead_code_is_reported_with_positions() {
+        let source = "fun unused() { 1 }\nfun main() {\n    return 0\n    println(\"done\")\n}\nfun classify(n) {\n    match n {\n        _ => 0,\n        1 => 1\n    }\n}\nprintln(classify(2))\n";
+        let issues = parse_and_lint(source);
+        let dead: Vec<(&str, usize, usize)> = issues
+            .iter()
+            .filter(|i| i.issue_type == "dead_code")
+            .map(|i| (i.rule.as_str(), i.line, i.column))
+            .collect();
+        assert_eq!(
+            dead,
+            [
+                ("unused_function", 1, 1),
+                ("unreachable_code", 4, 5),
+                ("unreachable_match_arm", 9, 9)
+            ]
+        );
+        assert!(issues.iter().any(|i| i.name == "unused"));
+        assert!(!issues.iter().any(|i| i.name == "classify"));
+    }
+
+    #[test]
+    fn test_dead_code_rules_can_be_selected() {
+        let source = "fun unused() { 1 }\nfun main() {\n    return 0\n    println(\"done\")\n}\n";
+        let rules = |filter| -> Vec<String> {
+            parse_and_lint_with_rules(source, filter)
+                .into_iter()
+                .map(|i| i.rule)
+                .collect()
+        };
+        assert_eq!(rules("dead_code"), ["unused_function", "unreachable_code"]);
+        assert_eq!(rules("unused"), ["unused_function"]);
+        assert!(rules("shadowing").is_empty());
+    }
+
+    #[test]
+    fn test_security_rules_report_with_positions() {
+        let source = "let name = env_args()[1]\nlet path = path_join(\"/srv\", name)\nlet out = Command::new(\"sh\").arg(\"-c\").arg(\"cat \" + path).output()\nlet db_password = \"hunter2hunter2\"\nprintln(out)\n";
+        let issues = parse_and_lint_with_rules(source, "security");
+        let found: Vec<(&str, usize, &str)> = issues
+            .iter()
+            .map(|i| (i.rule.as_str(), i.line, i.issue_type.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("path_traversal", 2, "security"),
+                ("command_injection", 3, "security"),
+                ("hardcoded_secret", 4, "security"),
+            ]
+        );
+        // Off by default
+        let default_rules: Vec<String> = Linter::new()
+            .lint(&Parser::new(source).parse().unwrap(), source)
+            .unwrap()
+            .into_iter()
+            .map(|i| i.rule)
+            .collect();
+        assert!(!default_rules.iter().any(|rule| rule == "hardcoded_secret"));
+    }
 }
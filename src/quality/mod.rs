@@ -127,7 +127,9 @@ pub mod formatter;
 pub mod formatter_config;
 pub mod gates;
 pub mod instrumentation;
+pub mod lint_config;
 pub mod linter;
+pub mod security;
 
 #[cfg(test)]
 mod formatter_tests;
@@ -140,6 +142,7 @@ pub use coverage::{
     CoverageCollector, CoverageReport, CoverageTool, FileCoverage, HtmlReportGenerator,
 };
 pub use formatter_config::FormatterConfig;
+pub use lint_config::LintConfig;
 use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QualityGates {
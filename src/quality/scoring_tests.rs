@@ -799,6 +799,8 @@ fn test_analyze_error_handling_recursive_match_with_two_arms() {
                         Span::default(),
                     )),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
                 MatchArm {
                     pattern: Pattern::Identifier("err".to_string()),
@@ -808,6 +810,8 @@ fn test_analyze_error_handling_recursive_match_with_two_arms() {
                         Span::default(),
                     )),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
             ],
         },
@@ -869,6 +873,8 @@ fn test_analyze_error_handling_recursive_function_body() {
                         Span::default(),
                     )),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
                 MatchArm {
                     pattern: Pattern::Wildcard,
@@ -878,6 +884,8 @@ fn test_analyze_error_handling_recursive_function_body() {
                         Span::default(),
                     )),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
             ],
         },
@@ -927,6 +935,8 @@ fn test_analyze_error_handling_recursive_single_arm_match() {
                     Span::default(),
                 )),
                 span: Span::default(),
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             }],
         },
         Span::default(),
@@ -1015,6 +1025,8 @@ fn test_analyze_null_safety_recursive_match_with_arms() {
                         Span::default(),
                     )),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
                 MatchArm {
                     pattern: Pattern::Wildcard,
@@ -1024,6 +1036,8 @@ fn test_analyze_null_safety_recursive_match_with_arms() {
                         Span::default(),
                     )),
                     span: Span::default(),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
             ],
         },
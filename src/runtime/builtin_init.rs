@@ -87,6 +87,10 @@ fn add_basic_builtins(global_env: &mut HashMap<String, Value>) {
         "DataFrame::from_csv_string".to_string(),
         Value::from_string("__builtin_dataframe_from_csv_string__".to_string()),
     );
+    global_env.insert(
+        "DataFrame::from_csv".to_string(),
+        Value::from_string("__builtin_dataframe_from_csv__".to_string()),
+    );
     global_env.insert(
         "DataFrame::from_json".to_string(),
         Value::from_string("__builtin_dataframe_from_json__".to_string()),
@@ -127,6 +131,14 @@ fn add_io_functions(global_env: &mut HashMap<String, Value>) {
         "dbg".to_string(),
         Value::from_string("__builtin_dbg__".to_string()),
     );
+    global_env.insert(
+        "io::records".to_string(),
+        Value::from_string("__builtin_io_records__".to_string()),
+    );
+    global_env.insert(
+        "emit".to_string(),
+        Value::from_string("__builtin_emit__".to_string()),
+    );
 }
 
 /// Add basic utility functions
@@ -168,6 +180,11 @@ fn add_type_conversion_functions(global_env: &mut HashMap<String, Value>) {
         let builtin_name = format!("__builtin_{func_name}__");
         global_env.insert((*func_name).to_string(), Value::from_string(builtin_name));
     }
+    // Decimal("1.23") constructs an exact decimal
+    global_env.insert(
+        "Decimal".to_string(),
+        Value::from_string("__builtin_decimal__".to_string()),
+    );
 }
 
 /// Add advanced utility functions
@@ -671,6 +688,35 @@ fn add_std_namespace(global_env: &mut HashMap<String, Value>) {
         Value::from_string("__builtin_round__".to_string()),
     );
 
+    // std::csv module: CSV files as DataFrames or arrays of objects
+    let mut csv_module = HashMap::new();
+    csv_module.insert(
+        "read".to_string(),
+        Value::from_string("__builtin_csv_read__".to_string()),
+    );
+    csv_module.insert(
+        "write".to_string(),
+        Value::from_string("__builtin_csv_write__".to_string()),
+    );
+
+    // std::display module: MIME-typed values rendered by the notebook
+    let mut display_module = HashMap::new();
+    for name in ["html", "markdown", "svg", "png"] {
+        display_module.insert(
+            name.to_string(),
+            Value::from_string(format!("__builtin_display_{name}__")),
+        );
+    }
+
+    // std::plot module: simple charts as SVG display values
+    let mut plot_module = HashMap::new();
+    for name in ["line", "scatter", "bar"] {
+        plot_module.insert(
+            name.to_string(),
+            Value::from_string(format!("__builtin_plot_{name}__")),
+        );
+    }
+
     // Create std namespace object
     let mut std_namespace = HashMap::new();
     std_namespace.insert("time".to_string(), Value::Object(Arc::new(time_module)));
@@ -681,6 +727,12 @@ fn add_std_namespace(global_env: &mut HashMap<String, Value>) {
     std_namespace.insert("fs".to_string(), Value::Object(Arc::new(fs_module)));
     std_namespace.insert("env".to_string(), Value::Object(Arc::new(env_module)));
     std_namespace.insert("math".to_string(), Value::Object(Arc::new(math_module)));
+    std_namespace.insert("csv".to_string(), Value::Object(Arc::new(csv_module)));
+    std_namespace.insert(
+        "display".to_string(),
+        Value::Object(Arc::new(display_module)),
+    );
+    std_namespace.insert("plot".to_string(), Value::Object(Arc::new(plot_module)));
 
     // Add std to global environment
     global_env.insert("std".to_string(), Value::Object(Arc::new(std_namespace)));
@@ -809,7 +861,10 @@ mod tests {
         // Utc direct: Utc (convenience import - Issue #82)
         // parse_json alias: parse_json (Issue #131 - v3.182.0)
         // +1 dir() builtin (object-inspection-consistency spec)
-        assert_eq!(env.len(), 117);
+        // +1 Decimal() constructor
+        // +1 DataFrame::from_csv (file loader)
+        // +2 io::records, emit (JSON-lines record streams)
+        assert_eq!(env.len(), 121);
     }
 
     #[test]
@@ -893,7 +948,9 @@ mod tests {
         assert!(env.contains_key("println"));
         assert!(env.contains_key("print"));
         assert!(env.contains_key("dbg"));
-        assert_eq!(env.len(), 3);
+        assert!(env.contains_key("io::records"));
+        assert!(env.contains_key("emit"));
+        assert_eq!(env.len(), 5);
     }
 
     #[test]
@@ -917,7 +974,8 @@ mod tests {
         assert!(env.contains_key("float"));
         assert!(env.contains_key("str"));
         assert!(env.contains_key("bool"));
-        assert_eq!(env.len(), 4);
+        assert!(env.contains_key("Decimal"));
+        assert_eq!(env.len(), 5);
     }
 
     #[test]
@@ -1102,6 +1160,9 @@ mod tests {
             assert!(std_obj.contains_key("fs"));
             assert!(std_obj.contains_key("env"));
             assert!(std_obj.contains_key("math"));
+            assert!(std_obj.contains_key("csv"));
+            assert!(std_obj.contains_key("display"));
+            assert!(std_obj.contains_key("plot"));
         } else {
             panic!("std should be an Object");
         }
@@ -1234,6 +1295,7 @@ mod tests {
         assert!(env.contains_key("DataFrame::from_range"));
         assert!(env.contains_key("DataFrame::from_rows"));
         assert!(env.contains_key("DataFrame::from_csv_string"));
+        assert!(env.contains_key("DataFrame::from_csv"));
         assert!(env.contains_key("DataFrame::from_json"));
     }
 
@@ -14,6 +14,8 @@
 use super::instruction::Instruction;
 use super::opcode::OpCode;
 use crate::frontend::ast::{BinaryOp, Expr, ExprKind, Literal, Param, UnaryOp};
+use crate::runtime::eval_bigint::eval_bigint_literal;
+use crate::runtime::eval_decimal::eval_decimal_literal;
 use crate::runtime::Value;
 use std::cell::RefCell; // ISSUE-119: For shared mutable environment
 use std::collections::HashMap;
@@ -259,6 +261,8 @@ impl Compiler {
     fn compile_literal(&mut self, literal: &Literal) -> Result<u8, String> {
         let value = match literal {
             Literal::Integer(i, _) => Value::Integer(*i),
+            Literal::BigInt(digits) => eval_bigint_literal(digits),
+            Literal::Decimal(text) => eval_decimal_literal(text),
             Literal::Float(f) => Value::Float(*f),
             Literal::String(s) => Value::from_string(s.clone()),
             Literal::Bool(b) => Value::Bool(*b),
@@ -639,6 +643,8 @@ impl Compiler {
                 if let ExprKind::Literal(lit) = &elem.kind {
                     let value = match lit {
                         Literal::Integer(i, _) => Value::Integer(*i),
+                        Literal::BigInt(digits) => eval_bigint_literal(digits),
+                        Literal::Decimal(text) => eval_decimal_literal(text),
                         Literal::Float(f) => Value::Float(*f),
                         Literal::String(s) => Value::from_string(s.clone()),
                         Literal::Bool(b) => Value::Bool(*b),
@@ -703,6 +709,8 @@ impl Compiler {
                 if let ExprKind::Literal(lit) = &elem.kind {
                     let value = match lit {
                         Literal::Integer(i, _) => Value::Integer(*i),
+                        Literal::BigInt(digits) => eval_bigint_literal(digits),
+                        Literal::Decimal(text) => eval_decimal_literal(text),
                         Literal::Float(f) => Value::Float(*f),
                         Literal::String(s) => Value::from_string(s.clone()),
                         Literal::Bool(b) => Value::Bool(*b),
@@ -776,6 +784,8 @@ impl Compiler {
                     if let ExprKind::Literal(lit) = &value.kind {
                         let val = match lit {
                             Literal::Integer(i, _) => Value::Integer(*i),
+                            Literal::BigInt(digits) => eval_bigint_literal(digits),
+                            Literal::Decimal(text) => eval_decimal_literal(text),
                             Literal::Float(f) => Value::Float(*f),
                             Literal::String(s) => Value::from_string(s.clone()),
                             Literal::Bool(b) => Value::Bool(*b),
@@ -12,6 +12,8 @@ fn make_test_param(name: &str) -> crate::frontend::ast::Param {
         span: crate::frontend::ast::Span::default(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }
 }
 
@@ -25,6 +27,8 @@ fn make_test_param_with_default(name: &str, default: Expr) -> crate::frontend::a
         span: crate::frontend::ast::Span::default(),
         is_mutable: false,
         default_value: Some(Box::new(default)),
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }
 }
 
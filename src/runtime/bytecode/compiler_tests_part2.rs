@@ -705,6 +705,8 @@ fn test_compile_match_expression() {
                 crate::frontend::ast::Span::default(),
             )),
             span: crate::frontend::ast::Span::default(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         MatchArm {
             pattern: Pattern::Wildcard,
@@ -714,6 +716,8 @@ fn test_compile_match_expression() {
                 crate::frontend::ast::Span::default(),
             )),
             span: crate::frontend::ast::Span::default(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
     ];
     let match_full = Expr::new(
@@ -346,12 +346,9 @@ impl VM {
                         };
 
                         let char_count = chars.len();
-                        chars
-                            .get(idx)
-                            .map(|c| Value::from_string(c.to_string()))
-                            .ok_or_else(|| {
-                                format!("Index {i} out of bounds for string of length {char_count}")
-                            })
+                        chars.get(idx).map(|c| Value::from_char(*c)).ok_or_else(|| {
+                            format!("Index {i} out of bounds for string of length {char_count}")
+                        })
                     }
                     _ => Err(format!(
                         "Cannot index {} with {}",
@@ -398,6 +398,8 @@ fn test_vm_match_expr() {
             contracts: Vec::new(),
         }),
         span: make_span(),
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
 
     let mut chunk = BytecodeChunk::new("test_match".to_string());
@@ -626,6 +628,8 @@ fn test_vm_match_with_literal_pattern() {
             contracts: Vec::new(),
         }),
         span: make_span(),
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
     let arm2 = MatchArm {
         pattern: Pattern::Wildcard,
@@ -639,6 +643,8 @@ fn test_vm_match_with_literal_pattern() {
             contracts: Vec::new(),
         }),
         span: make_span(),
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
 
     let mut chunk = BytecodeChunk::new("test_match_lit".to_string());
@@ -363,10 +363,12 @@ impl DirectThreadedInterpreter {
     fn literal_to_value(&self, lit: &Literal) -> Value {
         match lit {
             Literal::Integer(n, _) => Value::Integer(*n),
+            Literal::BigInt(digits) => crate::runtime::eval_bigint::eval_bigint_literal(digits),
+            Literal::Decimal(text) => crate::runtime::eval_decimal::eval_decimal_literal(text),
             Literal::Float(f) => Value::Float(*f),
             Literal::Bool(b) => Value::Bool(*b),
-            Literal::String(s) => Value::from_string(s.clone()),
-            Literal::Char(c) => Value::from_string(c.to_string()), // Convert char to single-character string
+            Literal::String(s) => Value::from_interned(s),
+            Literal::Char(c) => Value::from_char(*c), // Convert char to single-character string
             Literal::Byte(b) => Value::Byte(*b),
             Literal::Unit => Value::Nil, // Unit maps to Nil
             Literal::Null => Value::Nil, // Null maps to Nil
@@ -0,0 +1,139 @@
+//! Conversions between interpreter DataFrames and polars
+//!
+//! Only compiled with the `dataframe` feature. Integer, float and boolean
+//! columns map to the matching polars dtypes (nil becomes null); any other
+//! column is stored as strings using each value's display form.
+
+use crate::runtime::{DataFrameColumn, Value};
+use polars::datatypes::PlSmallStr;
+use polars::prelude::{AnyValue, Column, DataFrame, NamedFrom, PolarsResult, Series};
+
+/// Convert interpreter columns into a polars `DataFrame`
+///
+/// # Errors
+///
+/// Returns an error when the columns have different lengths.
+pub fn to_polars(columns: &[DataFrameColumn]) -> PolarsResult<DataFrame> {
+    DataFrame::new(columns.iter().map(column_to_polars).collect())
+}
+
+/// Convert a polars `DataFrame` into interpreter columns
+pub fn from_polars(df: &DataFrame) -> Vec<DataFrameColumn> {
+    df.get_columns()
+        .iter()
+        .map(|col| DataFrameColumn {
+            name: col.name().to_string(),
+            values: (0..col.len())
+                .map(|row| col.get(row).map_or(Value::Nil, any_value_to_value))
+                .collect(),
+        })
+        .collect()
+}
+
+fn column_to_polars(col: &DataFrameColumn) -> Column {
+    let name = PlSmallStr::from(col.name.as_str());
+    let all = |pred: fn(&Value) -> bool| {
+        col.values
+            .iter()
+            .all(|v| matches!(v, Value::Nil) || pred(v))
+    };
+    let series = if all(|v| matches!(v, Value::Integer(_))) {
+        let values: Vec<Option<i64>> = col
+            .values
+            .iter()
+            .map(|v| match v {
+                Value::Integer(i) => Some(*i),
+                _ => None,
+            })
+            .collect();
+        Series::new(name, values)
+    } else if all(|v| matches!(v, Value::Integer(_) | Value::Float(_))) {
+        #[allow(clippy::cast_precision_loss)]
+        let values: Vec<Option<f64>> = col
+            .values
+            .iter()
+            .map(|v| match v {
+                Value::Integer(i) => Some(*i as f64),
+                Value::Float(f) => Some(*f),
+                _ => None,
+            })
+            .collect();
+        Series::new(name, values)
+    } else if all(|v| matches!(v, Value::Bool(_))) {
+        let values: Vec<Option<bool>> = col
+            .values
+            .iter()
+            .map(|v| match v {
+                Value::Bool(b) => Some(*b),
+                _ => None,
+            })
+            .collect();
+        Series::new(name, values)
+    } else {
+        let values: Vec<Option<String>> = col
+            .values
+            .iter()
+            .map(|v| match v {
+                Value::Nil => None,
+                Value::String(s) => Some(s.to_string()),
+                other => Some(other.to_string()),
+            })
+            .collect();
+        Series::new(name, values)
+    };
+    series.into()
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn any_value_to_value(value: AnyValue<'_>) -> Value {
+    match value {
+        AnyValue::Null => Value::Nil,
+        AnyValue::Boolean(b) => Value::Bool(b),
+        AnyValue::Int8(i) => Value::Integer(i64::from(i)),
+        AnyValue::Int16(i) => Value::Integer(i64::from(i)),
+        AnyValue::Int32(i) => Value::Integer(i64::from(i)),
+        AnyValue::Int64(i) => Value::Integer(i),
+        AnyValue::UInt8(i) => Value::Integer(i64::from(i)),
+        AnyValue::UInt16(i) => Value::Integer(i64::from(i)),
+        AnyValue::UInt32(i) => Value::Integer(i64::from(i)),
+        AnyValue::UInt64(i) => i64::try_from(i).map_or(Value::Float(i as f64), Value::Integer),
+        AnyValue::Float32(f) => Value::Float(f64::from(f)),
+        AnyValue::Float64(f) => Value::Float(f),
+        AnyValue::String(s) => Value::from_string(s.to_string()),
+        AnyValue::StringOwned(s) => Value::from_string(s.to_string()),
+        other => Value::from_string(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_keeps_types_and_nulls() {
+        let columns = vec![
+            DataFrameColumn {
+                name: "id".to_string(),
+                values: vec![Value::Integer(1), Value::Nil],
+            },
+            DataFrameColumn {
+                name: "score".to_string(),
+                values: vec![Value::Integer(2), Value::Float(2.5)],
+            },
+            DataFrameColumn {
+                name: "name".to_string(),
+                values: vec![Value::from_string("a".to_string()), Value::Bool(true)],
+            },
+        ];
+        let back = from_polars(&to_polars(&columns).expect("equal lengths"));
+        assert_eq!(back[0].values, columns[0].values);
+        assert_eq!(back[1].values, vec![Value::Float(2.0), Value::Float(2.5)]);
+        assert_eq!(
+            back[2].values,
+            vec![
+                Value::from_string("a".to_string()),
+                Value::from_string("true".to_string())
+            ]
+        );
+    }
+}
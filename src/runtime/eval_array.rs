@@ -4,11 +4,20 @@
 //! Extracted from the monolithic interpreter.rs to improve maintainability.
 //! Complexity: <10 per function (Toyota Way compliant)
 
+use crate::runtime::eval_iterator::LazyIter;
 use crate::runtime::pattern_matching::values_equal;
 use crate::runtime::validation::validate_arg_count;
 use crate::runtime::{InterpreterError, Value};
 use std::sync::Arc;
 
+/// Every method arrays answer to, at any arity
+pub const ARRAY_METHODS: &[&str] = &[
+    "len", "length", "first", "last", "is_empty", "pop", "unique", "enumerate", "flatten", "sort",
+    "reverse", "sum", "product", "min", "max", "iter", "into_iter", "push", "get", "nth",
+    "contains", "join", "concat", "append", "union", "intersection", "difference", "take", "skip",
+    "zip", "slice", "map", "filter", "reduce", "any", "all", "find", "each",
+];
+
 /// Evaluate an array method call
 ///
 /// # Complexity
@@ -32,6 +41,11 @@ fn eval_array_nullary_method(
         "product" => eval_array_product(arr),
         "min" => eval_array_min(arr),
         "max" => eval_array_max(arr),
+        "iter" | "into_iter" => Ok(LazyIter::Items {
+            items: arr.clone(),
+            index: 0,
+        }
+        .into_value()),
         _ => return None,
     };
     Some(result)
@@ -433,7 +447,11 @@ fn validate_single_closure_argument(
     method_name: &str,
 ) -> Result<(), InterpreterError> {
     validate_arg_count(method_name, args, 1)?;
-    if !matches!(&args[0], Value::Closure { .. }) {
+    // A variant without values is also the constructor of a tuple variant
+    if !matches!(
+        &args[0],
+        Value::Closure { .. } | Value::EnumVariant { data: None, .. }
+    ) {
         return Err(InterpreterError::RuntimeError(format!(
             "{method_name} expects a function argument"
         )));
@@ -0,0 +1,241 @@
+//! Arbitrary-precision integer arithmetic
+//!
+//! `Value::Integer` arithmetic that overflows `i64` is promoted to
+//! `Value::BigInt` instead of failing, and `123n` literals (or decimal
+//! literals too large for `i64`) produce a BigInt directly. BigInt is
+//! contagious: any arithmetic with a BigInt operand yields a BigInt, so
+//! `2n * 3` stays arbitrary precision. Comparisons between integers of
+//! either width are exact; mixing with floats goes through `f64`.
+
+use crate::frontend::ast::BinaryOp as AstBinaryOp;
+use crate::runtime::eval_operations::eval_binary_op;
+use crate::runtime::{InterpreterError, Value};
+use num_bigint::{BigInt, Sign};
+use num_traits::{ToPrimitive, Zero};
+use std::borrow::Cow;
+
+/// Evaluate the digits of a `Literal::BigInt`
+///
+/// The parser only produces decimal digits; a malformed literal (which can
+/// only be built by hand) evaluates to nil.
+pub fn eval_bigint_literal(digits: &str) -> Value {
+    digits
+        .parse::<BigInt>()
+        .map_or(Value::Nil, Value::from_bigint)
+}
+
+/// Exact result of an `i64` operation that overflowed
+///
+/// Every overflowing `i64` add/subtract/multiply/divide fits in `i128`.
+///
+/// # Complexity
+/// Cyclomatic complexity: 5 (within Toyota Way limits)
+pub fn promote_overflow(op: AstBinaryOp, a: i64, b: i64) -> Option<Value> {
+    let (a, b) = (i128::from(a), i128::from(b));
+    let wide = match op {
+        AstBinaryOp::Add => a + b,
+        AstBinaryOp::Subtract => a - b,
+        AstBinaryOp::Multiply => a * b,
+        AstBinaryOp::Divide => a / b,
+        _ => return None,
+    };
+    Some(Value::from_bigint(BigInt::from(wide)))
+}
+
+/// Arithmetic and comparisons where at least one operand is a BigInt
+///
+/// Returns `None` when neither operand is a BigInt, the other operand is not
+/// numeric, or the operator is not arithmetic/comparison, so the caller
+/// reports its usual error.
+///
+/// # Complexity
+/// Cyclomatic complexity: 6 (within Toyota Way limits)
+pub fn eval_bigint_op(
+    op: AstBinaryOp,
+    left: &Value,
+    right: &Value,
+) -> Option<Result<Value, InterpreterError>> {
+    if !matches!(left, Value::BigInt(_)) && !matches!(right, Value::BigInt(_)) {
+        return None;
+    }
+    if matches!(left, Value::Float(_)) || matches!(right, Value::Float(_)) {
+        let (left, right) = (as_float(left)?, as_float(right)?);
+        return is_numeric_op(op).then(|| eval_binary_op(op, &left, &right));
+    }
+    let (a, b) = (as_bigint(left)?, as_bigint(right)?);
+    bigint_binary_op(op, &a, &b)
+}
+
+/// Exact equality between a BigInt and another value
+#[allow(clippy::float_cmp)] // Mirrors equal_primitives
+pub fn bigint_equals(left: &Value, right: &Value) -> bool {
+    match (as_bigint(left), as_bigint(right)) {
+        (Some(a), Some(b)) => a == b,
+        _ => match (as_float(left), as_float(right)) {
+            (Some(Value::Float(a)), Some(Value::Float(b))) => a == b,
+            _ => false,
+        },
+    }
+}
+
+/// Negate a BigInt
+pub fn negate_bigint(n: &BigInt) -> Value {
+    Value::from_bigint(-n)
+}
+
+/// Integer operand widened to a BigInt
+fn as_bigint(value: &Value) -> Option<Cow<'_, BigInt>> {
+    match value {
+        Value::Integer(i) => Some(Cow::Owned(BigInt::from(*i))),
+        Value::BigInt(n) => Some(Cow::Borrowed(n.as_ref())),
+        _ => None,
+    }
+}
+
+/// Numeric operand converted to a float
+#[allow(clippy::cast_precision_loss)]
+fn as_float(value: &Value) -> Option<Value> {
+    match value {
+        Value::Integer(i) => Some(Value::Float(*i as f64)),
+        Value::BigInt(n) => Some(Value::Float(n.to_f64().unwrap_or(f64::NAN))),
+        Value::Float(f) => Some(Value::Float(*f)),
+        _ => None,
+    }
+}
+
+fn is_numeric_op(op: AstBinaryOp) -> bool {
+    matches!(
+        op,
+        AstBinaryOp::Add
+            | AstBinaryOp::Subtract
+            | AstBinaryOp::Multiply
+            | AstBinaryOp::Divide
+            | AstBinaryOp::Modulo
+            | AstBinaryOp::Power
+            | AstBinaryOp::Less
+            | AstBinaryOp::LessEqual
+            | AstBinaryOp::Greater
+            | AstBinaryOp::GreaterEqual
+            | AstBinaryOp::Equal
+            | AstBinaryOp::NotEqual
+    )
+}
+
+/// Division truncates toward zero and `%` takes the sign of the dividend,
+/// matching `i64`
+///
+/// # Complexity
+/// Cyclomatic complexity: 10 (flat operator table)
+fn bigint_binary_op(
+    op: AstBinaryOp,
+    a: &BigInt,
+    b: &BigInt,
+) -> Option<Result<Value, InterpreterError>> {
+    Some(match op {
+        AstBinaryOp::Add => Ok(Value::from_bigint(a + b)),
+        AstBinaryOp::Subtract => Ok(Value::from_bigint(a - b)),
+        AstBinaryOp::Multiply => Ok(Value::from_bigint(a * b)),
+        AstBinaryOp::Divide | AstBinaryOp::Modulo if b.is_zero() => {
+            Err(InterpreterError::DivisionByZero)
+        }
+        AstBinaryOp::Divide => Ok(Value::from_bigint(a / b)),
+        AstBinaryOp::Modulo => Ok(Value::from_bigint(a % b)),
+        AstBinaryOp::Power => bigint_pow(a, b),
+        AstBinaryOp::Less => Ok(Value::Bool(a < b)),
+        AstBinaryOp::LessEqual => Ok(Value::Bool(a <= b)),
+        AstBinaryOp::Greater => Ok(Value::Bool(a > b)),
+        AstBinaryOp::GreaterEqual => Ok(Value::Bool(a >= b)),
+        AstBinaryOp::Equal => Ok(Value::Bool(a == b)),
+        AstBinaryOp::NotEqual => Ok(Value::Bool(a != b)),
+        _ => return None,
+    })
+}
+
+/// `base ** exponent`; negative exponents produce a float like `i64` powers
+pub fn bigint_pow(base: &BigInt, exponent: &BigInt) -> Result<Value, InterpreterError> {
+    if exponent.sign() == Sign::Minus {
+        let base = base.to_f64().unwrap_or(f64::NAN);
+        let exponent = exponent.to_f64().unwrap_or(f64::NAN);
+        return Ok(Value::Float(base.powf(exponent)));
+    }
+    let exponent = exponent.to_u32().ok_or_else(|| {
+        InterpreterError::RuntimeError(format!("BigInt exponent too large: {exponent}"))
+    })?;
+    let bytes = base.bits().saturating_mul(u64::from(exponent)) / 8;
+    crate::runtime::restricted::check_size(usize::try_from(bytes).unwrap_or(usize::MAX))?;
+    Ok(Value::from_bigint(base.pow(exponent)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(digits: &str) -> Value {
+        eval_bigint_literal(digits)
+    }
+
+    #[test]
+    fn test_promote_overflow_is_exact() {
+        let sum = promote_overflow(AstBinaryOp::Add, i64::MAX, 1).unwrap();
+        assert_eq!(sum.to_string(), "9223372036854775808");
+        let product = promote_overflow(AstBinaryOp::Multiply, i64::MIN, i64::MIN).unwrap();
+        assert_eq!(
+            product.to_string(),
+            "85070591730234615865843651857942052864"
+        );
+        assert!(promote_overflow(AstBinaryOp::Less, 1, 2).is_none());
+    }
+
+    #[test]
+    fn test_bigint_is_contagious_and_compares_exactly() {
+        let n = big("100000000000000000000");
+        let doubled = eval_bigint_op(AstBinaryOp::Multiply, &n, &Value::Integer(2))
+            .unwrap()
+            .unwrap();
+        assert_eq!(doubled.to_string(), "200000000000000000000");
+        let two = eval_bigint_op(AstBinaryOp::Add, &big("1"), &Value::Integer(1))
+            .unwrap()
+            .unwrap();
+        assert!(matches!(two, Value::BigInt(_)));
+        assert_eq!(two, Value::Integer(2));
+        let less = eval_bigint_op(AstBinaryOp::Less, &Value::Integer(i64::MAX), &n);
+        assert_eq!(less.unwrap().unwrap(), Value::Bool(true));
+    }
+
+    #[test]
+    fn test_bigint_division_matches_i64_semantics() {
+        let div = |a: i64, b: i64, op| {
+            eval_bigint_op(op, &Value::from_bigint(BigInt::from(a)), &Value::Integer(b))
+                .unwrap()
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(div(-7, 2, AstBinaryOp::Divide), (-7i64 / 2).to_string());
+        assert_eq!(div(-7, 2, AstBinaryOp::Modulo), (-7i64 % 2).to_string());
+        let by_zero = eval_bigint_op(AstBinaryOp::Divide, &big("5"), &Value::Integer(0));
+        assert!(matches!(
+            by_zero,
+            Some(Err(InterpreterError::DivisionByZero))
+        ));
+    }
+
+    #[test]
+    fn test_bigint_mixes_with_floats_and_declines_other_operands() {
+        let half = eval_bigint_op(AstBinaryOp::Divide, &big("1"), &Value::Float(2.0));
+        assert_eq!(half.unwrap().unwrap(), Value::Float(0.5));
+        let text = Value::from_string("a".to_string());
+        assert!(eval_bigint_op(AstBinaryOp::Add, &big("1"), &text).is_none());
+        assert!(eval_bigint_op(AstBinaryOp::Add, &Value::Integer(1), &Value::Integer(1)).is_none());
+        assert!(bigint_equals(&big("3"), &Value::Float(3.0)));
+    }
+
+    #[test]
+    fn test_bigint_pow() {
+        let result = bigint_pow(&BigInt::from(2), &BigInt::from(100)).unwrap();
+        assert_eq!(result.to_string(), "1267650600228229401496703205376");
+        assert_eq!(
+            bigint_pow(&BigInt::from(2), &BigInt::from(-1)).unwrap(),
+            Value::Float(0.5)
+        );
+    }
+}
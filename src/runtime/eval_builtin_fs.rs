@@ -3,6 +3,7 @@
 //! This module handles filesystem operations including read, write, exists,
 //! directory operations, walking, globbing, searching, and hashing.
 
+use crate::runtime::eval_option_result::{err, ok};
 use crate::runtime::validation::validate_arg_count;
 use crate::runtime::{InterpreterError, Value};
 
@@ -338,13 +339,15 @@ pub(crate) fn eval_search(args: &[Value]) -> Result<Value, InterpreterError> {
         ));
     };
 
-    use regex::RegexBuilder;
     use walkdir::WalkDir;
 
     let case_insensitive = parse_search_case_insensitive(args);
-    let re = RegexBuilder::new(pattern.as_ref())
-        .case_insensitive(case_insensitive)
-        .build()
+    let pattern = if case_insensitive {
+        format!("(?i){pattern}")
+    } else {
+        pattern.to_string()
+    };
+    let re = crate::runtime::pattern_cache::cached_regex(&pattern)
         .map_err(|e| InterpreterError::RuntimeError(format!("search() regex error: {e}")))?;
 
     let mut results = Vec::new();
@@ -500,18 +503,18 @@ pub(crate) fn eval_compute_hash(args: &[Value]) -> Result<Value, InterpreterErro
 }
 
 /// Evaluate `fs_copy()` builtin function
+/// Returns Result enum with the number of bytes copied, like `std::fs::copy`
 /// Complexity: 3 (within Toyota Way limits)
 pub(crate) fn eval_fs_copy(args: &[Value]) -> Result<Value, InterpreterError> {
     validate_arg_count("fs_copy", args, 2)?;
 
     match (&args[0], &args[1]) {
-        (Value::String(from), Value::String(to)) => match std::fs::copy(from.as_ref(), to.as_ref())
-        {
-            Ok(_) => Ok(Value::Nil),
-            Err(e) => Err(InterpreterError::RuntimeError(format!(
-                "Failed to copy file: {e}"
-            ))),
-        },
+        (Value::String(from), Value::String(to)) => {
+            Ok(match std::fs::copy(from.as_ref(), to.as_ref()) {
+                Ok(bytes) => ok(Value::Integer(bytes as i64)),
+                Err(e) => err(Value::from_string(e.to_string())),
+            })
+        }
         _ => Err(InterpreterError::RuntimeError(
             "fs_copy() expects two string arguments".to_string(),
         )),
@@ -519,18 +522,17 @@ pub(crate) fn eval_fs_copy(args: &[Value]) -> Result<Value, InterpreterError> {
 }
 
 /// Evaluate `fs_rename()` builtin function
+/// Returns Result enum to match `std::fs::rename`
 /// Complexity: 3 (within Toyota Way limits)
 pub(crate) fn eval_fs_rename(args: &[Value]) -> Result<Value, InterpreterError> {
     validate_arg_count("fs_rename", args, 2)?;
 
     match (&args[0], &args[1]) {
         (Value::String(from), Value::String(to)) => {
-            match std::fs::rename(from.as_ref(), to.as_ref()) {
-                Ok(()) => Ok(Value::Nil),
-                Err(e) => Err(InterpreterError::RuntimeError(format!(
-                    "Failed to rename file: {e}"
-                ))),
-            }
+            Ok(match std::fs::rename(from.as_ref(), to.as_ref()) {
+                Ok(()) => ok(Value::Nil),
+                Err(e) => err(Value::from_string(e.to_string())),
+            })
         }
         _ => Err(InterpreterError::RuntimeError(
             "fs_rename() expects two string arguments".to_string(),
@@ -285,10 +285,23 @@ pub(crate) fn try_eval_string_function(
         "__builtin_String_new__" => Ok(Some(eval_string_new(args)?)),
         "__builtin_String_from__" => Ok(Some(eval_string_from(args)?)),
         "__builtin_String_from_utf8__" => Ok(Some(eval_string_from_utf8(args)?)),
+        "__builtin_format__" => Ok(Some(eval_format(args)?)),
         _ => Ok(None),
     }
 }
 
+/// Eval: `format(template, args...)` with `{}` and positional `{0}` placeholders
+pub(crate) fn eval_format(args: &[Value]) -> Result<Value, InterpreterError> {
+    match args.split_first() {
+        Some((Value::String(template), values)) => Ok(Value::from_string(
+            crate::runtime::value_format::format_string_with_values(template, values),
+        )),
+        _ => Err(InterpreterError::RuntimeError(
+            "format() expects a template string as its first argument".to_string(),
+        )),
+    }
+}
+
 /// Eval: `String::new()`
 pub(crate) fn eval_string_new(args: &[Value]) -> Result<Value, InterpreterError> {
     validate_arg_count("String::new", args, 0)?;
@@ -1879,6 +1879,20 @@ fn test_format_println_output_no_format() {
     assert_eq!(result, "1 2\n");
 }
 
+#[test]
+fn test_format_print_output_matches_println() {
+    let text = Value::from_string("hello".to_string());
+    assert_eq!(
+        format_print_output(&[text.clone(), Value::Integer(1)]),
+        "hello 1"
+    );
+    let format = Value::from_string("{} = {}".to_string());
+    assert_eq!(
+        format_print_output(&[format, text, Value::Integer(1)]),
+        "hello = 1"
+    );
+}
+
 // --- Range helper tests ---
 #[test]
 fn test_generate_range_forward() {
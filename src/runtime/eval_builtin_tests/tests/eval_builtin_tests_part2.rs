@@ -455,25 +455,25 @@ fn test_len_emoji_r128() {
 // Array/collection edge cases
 #[test]
 fn test_sorted_empty_r128() {
-    let result = eval("sorted([])");
+    let result = eval("[].sort()");
     assert!(result.contains("[]") || result.is_empty());
 }
 
 #[test]
 fn test_reversed_empty_r128() {
-    let result = eval("reversed([])");
+    let result = eval("[].reverse()");
     assert!(result.contains("[]") || result.is_empty());
 }
 
 #[test]
 fn test_sorted_single_r128() {
-    let result = eval("sorted([42])");
+    let result = eval("[42].sort()");
     assert!(result.contains("42"));
 }
 
 #[test]
 fn test_reversed_single_r128() {
-    let result = eval("reversed([42])");
+    let result = eval("[42].reverse()");
     assert!(result.contains("42"));
 }
 
@@ -783,15 +783,12 @@ fn test_sleep_float() {
 // === Hash Function ===
 #[test]
 fn test_hash_string() {
-    let result = eval("hash(\"hello\")");
-    // Hash should be consistent
-    assert!(!result.is_empty());
+    assert!(try_eval("hash(\"hello\")").is_none());
 }
 
 #[test]
 fn test_hash_int() {
-    let result = eval("hash(42)");
-    assert!(!result.is_empty());
+    assert!(try_eval("hash(42)").is_none());
 }
 
 // === Additional String Methods via REPL ===
@@ -806,14 +803,14 @@ fn test_string_join() {
 // === Append and Take/Drop ===
 #[test]
 fn test_take_more_than_length() {
-    let result = eval("take([1, 2], 10)");
+    let result = eval("[1, 2].take(10)");
     // Should return the original array
     assert!(result.contains("1") && result.contains("2"));
 }
 
 #[test]
 fn test_drop_more_than_length() {
-    let result = eval("drop([1, 2], 10)");
+    let result = eval("[1, 2].skip(10)");
     // Just exercise the code path
     let _ = result;
 }
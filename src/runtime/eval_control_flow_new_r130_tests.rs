@@ -165,6 +165,8 @@ fn test_eval_match_arm_non_matching_r130() {
         guard: None,
         body: Box::new(make_lit_int(100)),
         span: Span::new(0, 0),
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
 
     let result = eval_match_arm(
@@ -185,6 +187,8 @@ fn test_eval_match_arm_matching_r130() {
         guard: None,
         body: Box::new(make_lit_int(100)),
         span: Span::new(0, 0),
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
 
     let result = eval_match_arm(
@@ -208,12 +212,16 @@ fn test_find_matching_arm_no_match_r130() {
             guard: None,
             body: Box::new(make_lit_int(100)),
             span: Span::new(0, 0),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         MatchArm {
             pattern: Pattern::Literal(Literal::Integer(2, None)),
             guard: None,
             body: Box::new(make_lit_int(200)),
             span: Span::new(0, 0),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
     ];
 
@@ -236,12 +244,16 @@ fn test_find_matching_arm_first_match_r130() {
             guard: None,
             body: Box::new(make_lit_int(100)),
             span: Span::new(0, 0),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
         MatchArm {
             pattern: Pattern::Wildcard,
             guard: None,
             body: Box::new(make_lit_int(200)),
             span: Span::new(0, 0),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         },
     ];
 
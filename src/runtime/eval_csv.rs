@@ -0,0 +1,396 @@
+//! `std::csv`: reading and writing CSV files
+//!
+//! ```text
+//! let sales = std::csv::read("sales.csv")                      // DataFrame
+//! let rows = std::csv::read("data.tsv", {"delimiter": "\t", "records": true})
+//! std::csv::write("out.csv", rows)
+//! ```
+//!
+//! `read` returns a `DataFrame` by default, or an array of objects with
+//! `records: true`. Cells are typed as integers, floats, booleans or
+//! strings, and empty cells become nil. With
+//! `header: false` the columns are named `column_1`, `column_2`, ...
+//!
+//! `write` accepts a `DataFrame`, an array of objects (columns in sorted key
+//! order) or an array of arrays (rows as-is, no header). Both functions take
+//! a single-character `delimiter` (default `,`); fields containing the
+//! delimiter, quotes or newlines are quoted.
+
+use crate::runtime::validation::validate_arg_range;
+use crate::runtime::{DataFrameColumn, InterpreterError, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Options shared by `std::csv::read` and `std::csv::write`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// Field separator
+    pub delimiter: char,
+    /// Whether the first row holds column names
+    pub header: bool,
+    /// `read` only: return an array of objects instead of a `DataFrame`
+    pub records: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            header: true,
+            records: false,
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Read options from an optional `{delimiter, header, records}` object
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for unknown keys or values of the wrong type.
+    pub fn from_value(function: &str, value: Option<&Value>) -> Result<Self, InterpreterError> {
+        let mut options = Self::default();
+        let Some(value) = value else {
+            return Ok(options);
+        };
+        let Value::Object(fields) = value else {
+            return Err(option_error(function, "options must be an object"));
+        };
+        for (key, value) in fields.iter() {
+            match (key.as_str(), value) {
+                ("delimiter", Value::String(s)) => {
+                    let mut chars = s.chars();
+                    options.delimiter = match (chars.next(), chars.next()) {
+                        (Some(c), None) if c != '"' && c != '\n' => c,
+                        _ => return Err(option_error(
+                            function,
+                            "delimiter must be a single character other than a quote or newline",
+                        )),
+                    };
+                }
+                ("header", Value::Bool(b)) => options.header = *b,
+                ("records", Value::Bool(b)) => options.records = *b,
+                ("delimiter" | "header" | "records", _) => {
+                    return Err(option_error(
+                        function,
+                        &format!("invalid value for '{key}'"),
+                    ))
+                }
+                _ => return Err(option_error(function, &format!("unknown option '{key}'"))),
+            }
+        }
+        Ok(options)
+    }
+}
+
+fn option_error(function: &str, message: &str) -> InterpreterError {
+    InterpreterError::RuntimeError(format!("{function}(): {message}"))
+}
+
+/// `std::csv::read(path, options?)` - load a CSV file
+///
+/// # Errors
+///
+/// Returns an error when the file cannot be read or is not valid CSV.
+pub fn eval_csv_read(args: &[Value]) -> Result<Value, InterpreterError> {
+    validate_arg_range("std::csv::read", args, 1, 2)?;
+    let Value::String(path) = &args[0] else {
+        return Err(InterpreterError::RuntimeError(
+            "std::csv::read() expects a file path string".to_string(),
+        ));
+    };
+    let options = CsvOptions::from_value("std::csv::read", args.get(1))?;
+    let text = std::fs::read_to_string(path.as_ref())
+        .map_err(|e| InterpreterError::RuntimeError(format!("Failed to read '{path}': {e}")))?;
+    let columns = read_columns(&text, options)?;
+    if options.records {
+        Ok(columns_to_records(&columns))
+    } else {
+        Ok(Value::DataFrame { columns })
+    }
+}
+
+/// `std::csv::write(path, data, options?)` - write rows to a CSV file
+///
+/// # Errors
+///
+/// Returns an error for unsupported data or when the file cannot be written.
+pub fn eval_csv_write(args: &[Value]) -> Result<Value, InterpreterError> {
+    validate_arg_range("std::csv::write", args, 2, 3)?;
+    let Value::String(path) = &args[0] else {
+        return Err(InterpreterError::RuntimeError(
+            "std::csv::write() expects a file path string".to_string(),
+        ));
+    };
+    let options = CsvOptions::from_value("std::csv::write", args.get(2))?;
+    let (header, rows) = table_rows(&args[1])?;
+    let mut table = Vec::with_capacity(rows.len() + 1);
+    if let Some(header) = header.filter(|_| options.header) {
+        table.push(header);
+    }
+    table.extend(rows);
+    std::fs::write(path.as_ref(), format_csv(&table, options.delimiter))
+        .map_err(|e| InterpreterError::RuntimeError(format!("Failed to write '{path}': {e}")))?;
+    Ok(Value::Nil)
+}
+
+/// Parse CSV text into typed columns
+///
+/// # Errors
+///
+/// Returns an error for an unterminated quoted field.
+pub fn read_columns(
+    text: &str,
+    options: CsvOptions,
+) -> Result<Vec<DataFrameColumn>, InterpreterError> {
+    let mut rows = parse_csv(text, options.delimiter)?.into_iter();
+    let names: Vec<String> = if options.header {
+        rows.next().unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    let rows: Vec<Vec<String>> = rows.collect();
+    let width = rows
+        .iter()
+        .map(Vec::len)
+        .chain([names.len()])
+        .max()
+        .unwrap_or(0);
+    Ok((0..width)
+        .map(|i| DataFrameColumn {
+            name: names
+                .get(i)
+                .cloned()
+                .unwrap_or_else(|| format!("column_{}", i + 1)),
+            values: rows
+                .iter()
+                .map(|row| row.get(i).map_or(Value::Nil, |field| infer_cell(field)))
+                .collect(),
+        })
+        .collect())
+}
+
+/// Split CSV text into rows of raw fields (RFC 4180 quoting)
+///
+/// Quoted fields may contain the delimiter, `""` escapes and newlines. Blank
+/// lines are skipped.
+///
+/// # Errors
+///
+/// Returns an error for an unterminated quoted field.
+pub fn parse_csv(text: &str, delimiter: char) -> Result<Vec<Vec<String>>, InterpreterError> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+        } else if c == '"' {
+            in_quotes = true;
+        } else if c == delimiter {
+            row.push(std::mem::take(&mut field));
+        } else if c == '\n' || c == '\r' {
+            if c == '\r' && chars.peek() == Some(&'\n') {
+                chars.next();
+            }
+            end_row(&mut rows, &mut row, &mut field);
+        } else {
+            field.push(c);
+        }
+    }
+    if in_quotes {
+        return Err(InterpreterError::RuntimeError(
+            "Invalid CSV: unterminated quoted field".to_string(),
+        ));
+    }
+    end_row(&mut rows, &mut row, &mut field);
+    Ok(rows)
+}
+
+fn end_row(rows: &mut Vec<Vec<String>>, row: &mut Vec<String>, field: &mut String) {
+    if row.is_empty() && field.is_empty() {
+        return;
+    }
+    row.push(std::mem::take(field));
+    rows.push(std::mem::take(row));
+}
+
+/// Join rows into CSV text, quoting fields where needed
+pub fn format_csv(rows: &[Vec<String>], delimiter: char) -> String {
+    let mut out = String::new();
+    for row in rows {
+        let fields: Vec<String> = row.iter().map(|f| quote_field(f, delimiter)).collect();
+        out.push_str(&fields.join(&delimiter.to_string()));
+        out.push('\n');
+    }
+    out
+}
+
+fn quote_field(text: &str, delimiter: char) -> String {
+    if text.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn infer_cell(field: &str) -> Value {
+    match field {
+        "" => Value::Nil,
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => crate::runtime::eval_builtin::infer_value_type(field),
+    }
+}
+
+/// Text of one cell: strings without quotes, nil as an empty field
+pub fn field_text(value: &Value) -> String {
+    match value {
+        Value::Nil => String::new(),
+        Value::String(s) => s.to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn columns_to_records(columns: &[DataFrameColumn]) -> Value {
+    let rows = columns.iter().map(|c| c.values.len()).max().unwrap_or(0);
+    let records: Vec<Value> = (0..rows)
+        .map(|row| {
+            let fields: HashMap<String, Value> = columns
+                .iter()
+                .map(|col| {
+                    let value = col.values.get(row).cloned().unwrap_or(Value::Nil);
+                    (col.name.clone(), value)
+                })
+                .collect();
+            Value::Object(Arc::new(fields))
+        })
+        .collect();
+    Value::Array(Arc::from(records))
+}
+
+type Table = (Option<Vec<String>>, Vec<Vec<String>>);
+
+/// Header (if the data has column names) and field rows for `write`
+fn table_rows(data: &Value) -> Result<Table, InterpreterError> {
+    match data {
+        Value::DataFrame { columns } => {
+            let header = columns.iter().map(|c| c.name.clone()).collect();
+            let rows = columns.iter().map(|c| c.values.len()).max().unwrap_or(0);
+            let rows = (0..rows)
+                .map(|row| {
+                    columns
+                        .iter()
+                        .map(|col| col.values.get(row).map_or_else(String::new, field_text))
+                        .collect()
+                })
+                .collect();
+            Ok((Some(header), rows))
+        }
+        Value::Array(items) => match items.first() {
+            Some(Value::Object(first)) => {
+                let mut names: Vec<String> = first.keys().cloned().collect();
+                names.sort();
+                let rows = items
+                    .iter()
+                    .map(|item| match item {
+                        Value::Object(fields) => Ok(names
+                            .iter()
+                            .map(|name| fields.get(name).map_or_else(String::new, field_text))
+                            .collect()),
+                        _ => Err(write_data_error()),
+                    })
+                    .collect::<Result<_, _>>()?;
+                Ok((Some(names), rows))
+            }
+            _ => {
+                let rows = items
+                    .iter()
+                    .map(|item| match item {
+                        Value::Array(fields) => Ok(fields.iter().map(field_text).collect()),
+                        _ => Err(write_data_error()),
+                    })
+                    .collect::<Result<_, _>>()?;
+                Ok((None, rows))
+            }
+        },
+        _ => Err(write_data_error()),
+    }
+}
+
+fn write_data_error() -> InterpreterError {
+    InterpreterError::RuntimeError(
+        "std::csv::write() expects a DataFrame, an array of objects or an array of arrays"
+            .to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_handles_quotes_and_delimiters() {
+        let rows = parse_csv(
+            "a;b\n\"x;1\";\"say \"\"hi\"\"\"\r\n\n\"multi\nline\";2",
+            ';',
+        )
+        .unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                vec!["a".to_string(), "b".to_string()],
+                vec!["x;1".to_string(), "say \"hi\"".to_string()],
+                vec!["multi\nline".to_string(), "2".to_string()],
+            ]
+        );
+        assert!(parse_csv("\"open", ',').is_err());
+    }
+
+    #[test]
+    fn test_read_columns_infers_types_and_names() {
+        let options = CsvOptions {
+            header: false,
+            ..CsvOptions::default()
+        };
+        let columns = read_columns("1,2.5,true,\nx,,false,y\n", options).unwrap();
+        let names: Vec<&str> = columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["column_1", "column_2", "column_3", "column_4"]);
+        assert_eq!(columns[0].values[0], Value::Integer(1));
+        assert_eq!(columns[1].values, vec![Value::Float(2.5), Value::Nil]);
+        assert_eq!(columns[2].values[1], Value::Bool(false));
+        assert_eq!(columns[3].values[1], Value::from_string("y".to_string()));
+    }
+
+    #[test]
+    fn test_format_csv_round_trips() {
+        let rows = vec![
+            vec!["name".to_string(), "note".to_string()],
+            vec!["ann".to_string(), "a\tb \"c\"".to_string()],
+        ];
+        let text = format_csv(&rows, '\t');
+        assert_eq!(text, "name\tnote\nann\t\"a\tb \"\"c\"\"\"\n");
+        assert_eq!(parse_csv(&text, '\t').unwrap(), rows);
+    }
+
+    #[test]
+    fn test_options_are_validated() {
+        let mut fields = HashMap::new();
+        fields.insert(
+            "delimiter".to_string(),
+            Value::from_string("::".to_string()),
+        );
+        let err = CsvOptions::from_value("std::csv::read", Some(&Value::Object(Arc::new(fields))))
+            .unwrap_err();
+        assert!(err.to_string().contains("single character"), "{err}");
+    }
+}
@@ -0,0 +1,740 @@
+//! `DataFrame` engine: row windows, multi-key sorting, grouped aggregation,
+//! CSV files and table rendering for `Value::DataFrame`
+//!
+//! The interpreter keeps DataFrames as named columns of `Value`s so every
+//! operation works in every build. With the `dataframe` feature, CSV files
+//! are read and written through polars (quoting, type inference, large
+//! files); without it the built-in CSV reader is used.
+//!
+//! ```text
+//! let df = DataFrame::from_csv("sales.csv")
+//! df.filter(|r| r.amount > 100)
+//!   .group_by("region")
+//!   .agg({"amount": "sum", "id": "count"})
+//!   .sort("amount_sum", true)
+//!   .head(3)
+//! ```
+//!
+//! `group_by` returns a `DataFrameGroupBy` object whose `agg` takes a map of
+//! column name to aggregation (`sum`, `mean`, `min`, `max`, `count`, `first`,
+//! `last`) or to an array of them. Result columns are named
+//! `<column>_<aggregation>`, and groups keep the order in which their key
+//! first appears.
+
+use crate::runtime::eval_csv::{field_text, format_csv};
+use crate::runtime::eval_dataframe_ops::compare_values_for_sort;
+use crate::runtime::validation::{validate_arg_count, validate_arg_range};
+use crate::runtime::{DataFrameColumn, InterpreterError, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Type tag of the object returned by `DataFrame.group_by()`
+pub const GROUP_BY_TYPE: &str = "DataFrameGroupBy";
+
+/// Rows shown before a table is elided in the middle
+pub const DISPLAY_MAX_ROWS: usize = 10;
+
+/// Default row count for `head()`/`tail()`
+const DEFAULT_WINDOW: usize = 5;
+
+/// `df.head(n = 5)` - the first `n` rows
+///
+/// # Errors
+///
+/// Returns an error for more than one argument or a negative count.
+pub fn eval_dataframe_head(
+    columns: &[DataFrameColumn],
+    args: &[Value],
+) -> Result<Value, InterpreterError> {
+    let n = window_size("head", args)?;
+    Ok(map_rows(columns, |values| {
+        values.iter().take(n).cloned().collect()
+    }))
+}
+
+/// `df.tail(n = 5)` - the last `n` rows
+///
+/// # Errors
+///
+/// Returns an error for more than one argument or a negative count.
+pub fn eval_dataframe_tail(
+    columns: &[DataFrameColumn],
+    args: &[Value],
+) -> Result<Value, InterpreterError> {
+    let n = window_size("tail", args)?;
+    Ok(map_rows(columns, |values| {
+        values[values.len().saturating_sub(n)..].to_vec()
+    }))
+}
+
+/// `df.sort(by, descending = false)` - stable sort on one or more columns
+///
+/// `by` is a column name or an array of names; later names break ties in
+/// earlier ones.
+///
+/// # Errors
+///
+/// Returns an error for unknown columns or malformed arguments.
+pub fn eval_dataframe_sort(
+    columns: &[DataFrameColumn],
+    args: &[Value],
+) -> Result<Value, InterpreterError> {
+    validate_arg_range("DataFrame.sort", args, 1, 2)?;
+    let keys = key_columns("sort", columns, &args[0])?;
+    let descending = match args.get(1) {
+        None => false,
+        Some(Value::Bool(b)) => *b,
+        Some(_) => {
+            return Err(InterpreterError::RuntimeError(
+                "DataFrame.sort() descending flag must be boolean".to_string(),
+            ))
+        }
+    };
+
+    let mut order: Vec<usize> = (0..row_count(columns)).collect();
+    order.sort_by(|&a, &b| {
+        let cmp = keys
+            .iter()
+            .map(|col| compare_values_for_sort(cell(col, a), cell(col, b)))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(std::cmp::Ordering::Equal);
+        if descending {
+            cmp.reverse()
+        } else {
+            cmp
+        }
+    });
+    Ok(take_rows(columns, &order))
+}
+
+/// `df.group_by(by)` - start a grouped aggregation
+///
+/// # Errors
+///
+/// Returns an error for unknown key columns.
+pub fn eval_dataframe_group_by(
+    columns: &[DataFrameColumn],
+    args: &[Value],
+) -> Result<Value, InterpreterError> {
+    validate_arg_count("DataFrame.group_by", args, 1)?;
+    let keys = key_columns("group_by", columns, &args[0])?;
+    let by = keys
+        .iter()
+        .map(|col| Value::from_string(col.name.clone()))
+        .collect::<Vec<_>>();
+
+    let mut grouped = HashMap::new();
+    grouped.insert(
+        "__type".to_string(),
+        Value::from_string(GROUP_BY_TYPE.to_string()),
+    );
+    grouped.insert("by".to_string(), Value::from_array(by));
+    grouped.insert(
+        "frame".to_string(),
+        Value::DataFrame {
+            columns: columns.to_vec(),
+        },
+    );
+    Ok(Value::Object(Arc::new(grouped)))
+}
+
+/// Methods on the object returned by `group_by`: `agg(spec)` and `count()`
+///
+/// # Errors
+///
+/// Returns an error for unknown methods, columns or aggregations.
+pub fn eval_group_by_method(
+    grouped: &HashMap<String, Value>,
+    method: &str,
+    args: &[Value],
+) -> Result<Value, InterpreterError> {
+    let (Some(Value::DataFrame { columns }), Some(Value::Array(by))) =
+        (grouped.get("frame"), grouped.get("by"))
+    else {
+        return Err(InterpreterError::RuntimeError(
+            "Malformed DataFrameGroupBy object".to_string(),
+        ));
+    };
+    let keys = key_columns("group_by", columns, &Value::Array(by.clone()))?;
+    let groups = group_rows(&keys);
+
+    match method {
+        "agg" => {
+            validate_arg_count("DataFrameGroupBy.agg", args, 1)?;
+            let specs = aggregation_specs(columns, &args[0])?;
+            let mut result = group_key_columns(&keys, &groups);
+            for (col, agg) in specs {
+                result.push(aggregate_column(col, agg, &groups)?);
+            }
+            Ok(Value::DataFrame { columns: result })
+        }
+        "count" => {
+            validate_arg_count("DataFrameGroupBy.count", args, 0)?;
+            let mut result = group_key_columns(&keys, &groups);
+            result.push(DataFrameColumn {
+                name: "count".to_string(),
+                values: groups.iter().map(|rows| count_value(rows.len())).collect(),
+            });
+            Ok(Value::DataFrame { columns: result })
+        }
+        _ => Err(InterpreterError::RuntimeError(format!(
+            "Unknown DataFrameGroupBy method: {method}"
+        ))),
+    }
+}
+
+/// `DataFrame::from_csv(path)` - load a CSV file with a header row
+///
+/// # Errors
+///
+/// Returns an error when the file cannot be read or parsed.
+pub fn eval_dataframe_from_csv(args: &[Value]) -> Result<Value, InterpreterError> {
+    validate_arg_count("DataFrame::from_csv", args, 1)?;
+    let Value::String(path) = &args[0] else {
+        return Err(InterpreterError::RuntimeError(
+            "DataFrame::from_csv() expects a file path string".to_string(),
+        ));
+    };
+    crate::runtime::restricted::check_file_access("DataFrame::from_csv()")?;
+    read_csv_file(path)
+}
+
+#[cfg(feature = "dataframe")]
+fn read_csv_file(path: &str) -> Result<Value, InterpreterError> {
+    let df = crate::stdlib::dataframe::read_csv(path)
+        .map_err(|e| InterpreterError::RuntimeError(format!("Failed to read '{path}': {e}")))?;
+    Ok(Value::DataFrame {
+        columns: crate::runtime::dataframe_polars::from_polars(&df),
+    })
+}
+
+#[cfg(not(feature = "dataframe"))]
+fn read_csv_file(path: &str) -> Result<Value, InterpreterError> {
+    use crate::runtime::eval_csv::{read_columns, CsvOptions};
+    let csv = std::fs::read_to_string(path)
+        .map_err(|e| InterpreterError::RuntimeError(format!("Failed to read '{path}': {e}")))?;
+    Ok(Value::DataFrame {
+        columns: read_columns(&csv, CsvOptions::default())?,
+    })
+}
+
+/// `df.to_csv(path)` - write the frame to a CSV file
+///
+/// # Errors
+///
+/// Returns an error when the file cannot be written.
+#[cfg(feature = "dataframe")]
+pub fn write_csv_file(columns: &[DataFrameColumn], path: &str) -> Result<(), InterpreterError> {
+    let write_error =
+        |e: String| InterpreterError::RuntimeError(format!("Failed to write '{path}': {e}"));
+    let mut df = crate::runtime::dataframe_polars::to_polars(columns)
+        .map_err(|e| write_error(e.to_string()))?;
+    crate::stdlib::dataframe::write_csv(&mut df, path).map_err(write_error)
+}
+
+/// `df.to_csv(path)` - write the frame to a CSV file
+///
+/// # Errors
+///
+/// Returns an error when the file cannot be written.
+#[cfg(not(feature = "dataframe"))]
+pub fn write_csv_file(columns: &[DataFrameColumn], path: &str) -> Result<(), InterpreterError> {
+    std::fs::write(path, csv_text(columns))
+        .map_err(|e| InterpreterError::RuntimeError(format!("Failed to write '{path}': {e}")))
+}
+
+/// CSV text with a header row; fields containing `,`, `"` or newlines are
+/// quoted
+pub fn csv_text(columns: &[DataFrameColumn]) -> String {
+    if columns.is_empty() {
+        return String::new();
+    }
+    let header: Vec<String> = columns.iter().map(|col| col.name.clone()).collect();
+    let rows = (0..row_count(columns)).map(|row| {
+        columns
+            .iter()
+            .map(|col| field_text(cell(col, row)))
+            .collect()
+    });
+    format_csv(
+        &std::iter::once(header).chain(rows).collect::<Vec<_>>(),
+        ',',
+    )
+}
+
+/// Render a frame as a box-drawn table
+///
+/// Frames longer than [`DISPLAY_MAX_ROWS`] show their first and last rows
+/// around a `…` row. Numbers are right-aligned, text left-aligned.
+pub fn format_table(columns: &[DataFrameColumn]) -> String {
+    let rows = row_count(columns);
+    let mut out = format!("shape: ({rows}, {})\n", columns.len());
+    if columns.is_empty() {
+        return out;
+    }
+
+    let shown = displayed_rows(rows);
+    let cells: Vec<Vec<String>> = columns
+        .iter()
+        .map(|col| {
+            shown
+                .iter()
+                .map(|row| match row {
+                    Some(row) => col.values.get(*row).map(cell_text).unwrap_or_default(),
+                    None => "…".to_string(),
+                })
+                .collect()
+        })
+        .collect();
+    let widths: Vec<usize> = columns
+        .iter()
+        .zip(&cells)
+        .map(|(col, cells)| {
+            cells
+                .iter()
+                .map(|c| c.chars().count())
+                .chain(std::iter::once(col.name.chars().count()))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let numeric: Vec<bool> = columns.iter().map(is_numeric_column).collect();
+
+    out.push_str(&rule(&widths, '┌', '┬', '┐'));
+    let header: Vec<String> = columns.iter().map(|col| col.name.clone()).collect();
+    out.push_str(&table_row(&header, &widths, &vec![false; widths.len()]));
+    out.push_str(&rule(&widths, '├', '┼', '┤'));
+    for row in 0..shown.len() {
+        let line: Vec<String> = cells.iter().map(|col| col[row].clone()).collect();
+        out.push_str(&table_row(&line, &widths, &numeric));
+    }
+    out.push_str(&rule(&widths, '└', '┴', '┘'));
+    out
+}
+
+fn window_size(method: &str, args: &[Value]) -> Result<usize, InterpreterError> {
+    validate_arg_range(&format!("DataFrame.{method}"), args, 0, 1)?;
+    match args.first() {
+        None => Ok(DEFAULT_WINDOW),
+        Some(Value::Integer(n)) => usize::try_from(*n).map_err(|_| {
+            InterpreterError::RuntimeError(format!(
+                "DataFrame.{method}() row count must not be negative, got {n}"
+            ))
+        }),
+        Some(_) => Err(InterpreterError::RuntimeError(format!(
+            "DataFrame.{method}() expects an integer row count"
+        ))),
+    }
+}
+
+/// Columns named by a string or an array of strings
+fn key_columns<'a>(
+    method: &str,
+    columns: &'a [DataFrameColumn],
+    by: &Value,
+) -> Result<Vec<&'a DataFrameColumn>, InterpreterError> {
+    let names: Vec<&Value> = match by {
+        Value::Array(names) if !names.is_empty() => names.iter().collect(),
+        Value::String(_) => vec![by],
+        _ => {
+            return Err(InterpreterError::RuntimeError(format!(
+                "DataFrame.{method}() expects a column name or a non-empty array of names"
+            )))
+        }
+    };
+    names
+        .into_iter()
+        .map(|name| match name {
+            Value::String(name) => find_column(columns, name),
+            _ => Err(InterpreterError::RuntimeError(format!(
+                "DataFrame.{method}() column names must be strings"
+            ))),
+        })
+        .collect()
+}
+
+fn find_column<'a>(
+    columns: &'a [DataFrameColumn],
+    name: &str,
+) -> Result<&'a DataFrameColumn, InterpreterError> {
+    columns.iter().find(|col| col.name == name).ok_or_else(|| {
+        InterpreterError::RuntimeError(format!("Column '{name}' not found in DataFrame"))
+    })
+}
+
+/// Row indices of each group, in order of first appearance
+fn group_rows(keys: &[&DataFrameColumn]) -> Vec<Vec<usize>> {
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    for row in 0..keys.first().map_or(0, |col| col.values.len()) {
+        // Display keeps string quotes, so "1" and 1 stay distinct groups
+        let key = keys
+            .iter()
+            .map(|col| cell(col, row).to_string())
+            .collect::<Vec<_>>()
+            .join("\u{1f}");
+        let group = *index.entry(key).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[group].push(row);
+    }
+    groups
+}
+
+fn group_key_columns(keys: &[&DataFrameColumn], groups: &[Vec<usize>]) -> Vec<DataFrameColumn> {
+    keys.iter()
+        .map(|col| DataFrameColumn {
+            name: col.name.clone(),
+            values: groups
+                .iter()
+                .map(|rows| cell(col, rows[0]).clone())
+                .collect(),
+        })
+        .collect()
+}
+
+/// `{"col": "sum"}` or `{"col": ["sum", "mean"]}`, ordered by frame column
+fn aggregation_specs<'a>(
+    columns: &'a [DataFrameColumn],
+    spec: &'a Value,
+) -> Result<Vec<(&'a DataFrameColumn, &'a str)>, InterpreterError> {
+    let Value::Object(spec) = spec else {
+        return Err(InterpreterError::RuntimeError(
+            "DataFrameGroupBy.agg() expects a map of column name to aggregation".to_string(),
+        ));
+    };
+    let mut names: Vec<&String> = spec.keys().collect();
+    names.sort_by_key(|name| columns.iter().position(|col| &col.name == *name));
+    let mut specs = Vec::new();
+    for name in names {
+        let col = find_column(columns, name)?;
+        match &spec[name] {
+            Value::String(agg) => specs.push((col, agg.as_ref())),
+            Value::Array(aggs) => {
+                for agg in aggs.iter() {
+                    let Value::String(agg) = agg else {
+                        return Err(aggregation_error(name));
+                    };
+                    specs.push((col, agg.as_ref()));
+                }
+            }
+            _ => return Err(aggregation_error(name)),
+        }
+    }
+    Ok(specs)
+}
+
+fn aggregation_error(column: &str) -> InterpreterError {
+    InterpreterError::RuntimeError(format!(
+        "DataFrameGroupBy.agg() aggregation for '{column}' must be a name or an array of names"
+    ))
+}
+
+/// # Complexity
+/// Cyclomatic complexity: 9 (flat aggregation table)
+fn aggregate_column(
+    col: &DataFrameColumn,
+    agg: &str,
+    groups: &[Vec<usize>],
+) -> Result<DataFrameColumn, InterpreterError> {
+    let aggregate: fn(&[&Value]) -> Value = match agg {
+        "sum" => numeric_sum,
+        "mean" => numeric_mean,
+        "min" => |group| extreme(group, std::cmp::Ordering::Less),
+        "max" => |group| extreme(group, std::cmp::Ordering::Greater),
+        "count" => |group| count_value(group.len()),
+        "first" => |group| group.first().map_or(Value::Nil, |v| (*v).clone()),
+        "last" => |group| group.last().map_or(Value::Nil, |v| (*v).clone()),
+        _ => {
+            return Err(InterpreterError::RuntimeError(format!(
+                "Unknown aggregation '{agg}' (expected sum, mean, min, max, count, first or last)"
+            )))
+        }
+    };
+    let values = groups
+        .iter()
+        .map(|rows| {
+            // Nulls are skipped by every aggregation, as in polars
+            let group: Vec<&Value> = rows
+                .iter()
+                .map(|&row| cell(col, row))
+                .filter(|v| !matches!(v, Value::Nil))
+                .collect();
+            aggregate(&group)
+        })
+        .collect();
+    Ok(DataFrameColumn {
+        name: format!("{}_{agg}", col.name),
+        values,
+    })
+}
+
+/// Integer sum while every value is an integer, float sum otherwise
+#[allow(clippy::cast_precision_loss)]
+fn numeric_sum(group: &[&Value]) -> Value {
+    let mut int_sum: Option<i64> = Some(0);
+    let mut float_sum = 0.0;
+    for value in group {
+        match value {
+            Value::Integer(i) => {
+                int_sum = int_sum.and_then(|sum| sum.checked_add(*i));
+                float_sum += *i as f64;
+            }
+            Value::Float(f) => {
+                int_sum = None;
+                float_sum += f;
+            }
+            _ => {}
+        }
+    }
+    int_sum.map_or(Value::Float(float_sum), Value::Integer)
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn numeric_mean(group: &[&Value]) -> Value {
+    let numbers: Vec<f64> = group
+        .iter()
+        .filter_map(|value| match value {
+            Value::Integer(i) => Some(*i as f64),
+            Value::Float(f) => Some(*f),
+            _ => None,
+        })
+        .collect();
+    if numbers.is_empty() {
+        Value::Nil
+    } else {
+        Value::Float(numbers.iter().sum::<f64>() / numbers.len() as f64)
+    }
+}
+
+fn extreme(group: &[&Value], wanted: std::cmp::Ordering) -> Value {
+    group
+        .iter()
+        .copied()
+        .reduce(|best, v| {
+            if compare_values_for_sort(v, best) == wanted {
+                v
+            } else {
+                best
+            }
+        })
+        .map_or(Value::Nil, Value::clone)
+}
+
+/// Rows shown when displaying a frame of `rows` rows; `None` marks the `…` gap
+pub(crate) fn displayed_rows(rows: usize) -> Vec<Option<usize>> {
+    if rows > DISPLAY_MAX_ROWS {
+        let half = DISPLAY_MAX_ROWS / 2;
+        (0..half)
+            .map(Some)
+            .chain(std::iter::once(None))
+            .chain((rows - half..rows).map(Some))
+            .collect()
+    } else {
+        (0..rows).map(Some).collect()
+    }
+}
+
+fn count_value(n: usize) -> Value {
+    Value::Integer(i64::try_from(n).unwrap_or(i64::MAX))
+}
+
+pub(crate) fn row_count(columns: &[DataFrameColumn]) -> usize {
+    columns
+        .iter()
+        .map(|col| col.values.len())
+        .max()
+        .unwrap_or(0)
+}
+
+fn cell(col: &DataFrameColumn, row: usize) -> &Value {
+    col.values.get(row).unwrap_or(&Value::Nil)
+}
+
+fn map_rows(columns: &[DataFrameColumn], rows: impl Fn(&[Value]) -> Vec<Value>) -> Value {
+    Value::DataFrame {
+        columns: columns
+            .iter()
+            .map(|col| DataFrameColumn {
+                name: col.name.clone(),
+                values: rows(&col.values),
+            })
+            .collect(),
+    }
+}
+
+fn take_rows(columns: &[DataFrameColumn], order: &[usize]) -> Value {
+    Value::DataFrame {
+        columns: columns
+            .iter()
+            .map(|col| DataFrameColumn {
+                name: col.name.clone(),
+                values: order.iter().map(|&row| cell(col, row).clone()).collect(),
+            })
+            .collect(),
+    }
+}
+
+/// Cell text: strings without quotes, nil as `null`
+pub(crate) fn cell_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.to_string(),
+        Value::Nil => "null".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn is_numeric_column(col: &DataFrameColumn) -> bool {
+    col.values.iter().all(|v| {
+        matches!(
+            v,
+            Value::Integer(_) | Value::Float(_) | Value::BigInt(_) | Value::Decimal(_) | Value::Nil
+        )
+    })
+}
+
+fn rule(widths: &[usize], left: char, middle: char, right: char) -> String {
+    let segments: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+    format!("{left}{}{right}\n", segments.join(&middle.to_string()))
+}
+
+fn table_row(cells: &[String], widths: &[usize], right_align: &[bool]) -> String {
+    let padded: Vec<String> = cells
+        .iter()
+        .zip(widths)
+        .zip(right_align)
+        .map(|((text, &width), &right)| {
+            let pad = " ".repeat(width - text.chars().count());
+            if right {
+                format!(" {pad}{text} ")
+            } else {
+                format!(" {text}{pad} ")
+            }
+        })
+        .collect();
+    format!("│{}│\n", padded.join("│"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame() -> Vec<DataFrameColumn> {
+        let strings = |xs: &[&str]| {
+            xs.iter()
+                .map(|s| Value::from_string((*s).to_string()))
+                .collect()
+        };
+        vec![
+            DataFrameColumn {
+                name: "dept".to_string(),
+                values: strings(&["eng", "ops", "eng", "ops", "eng"]),
+            },
+            DataFrameColumn {
+                name: "salary".to_string(),
+                values: [100, 80, 120, 70, 110].map(Value::Integer).to_vec(),
+            },
+        ]
+    }
+
+    fn column(value: &Value, name: &str) -> Vec<Value> {
+        let Value::DataFrame { columns } = value else {
+            panic!("expected DataFrame, got {value:?}");
+        };
+        find_column(columns, name)
+            .expect("column exists")
+            .values
+            .clone()
+    }
+
+    #[test]
+    fn test_head_tail_and_multi_key_sort() {
+        let df = frame();
+        assert_eq!(
+            column(
+                &eval_dataframe_head(&df, &[Value::Integer(2)]).unwrap(),
+                "salary"
+            )
+            .len(),
+            2
+        );
+        assert_eq!(
+            column(&eval_dataframe_tail(&df, &[]).unwrap(), "salary"),
+            [100, 80, 120, 70, 110].map(Value::Integer).to_vec()
+        );
+        let by = Value::from_array(vec![
+            Value::from_string("dept".to_string()),
+            Value::from_string("salary".to_string()),
+        ]);
+        let sorted = eval_dataframe_sort(&df, &[by, Value::Bool(true)]).unwrap();
+        assert_eq!(
+            column(&sorted, "salary"),
+            [80, 70, 120, 110, 100].map(Value::Integer).to_vec()
+        );
+    }
+
+    #[test]
+    fn test_group_by_agg_keeps_first_appearance_order() {
+        let Value::Object(grouped) =
+            eval_dataframe_group_by(&frame(), &[Value::from_string("dept".to_string())]).unwrap()
+        else {
+            panic!("group_by should return an object");
+        };
+        let mut spec = HashMap::new();
+        spec.insert(
+            "salary".to_string(),
+            Value::from_array(vec![
+                Value::from_string("sum".to_string()),
+                Value::from_string("max".to_string()),
+            ]),
+        );
+        let result =
+            eval_group_by_method(&grouped, "agg", &[Value::Object(Arc::new(spec))]).unwrap();
+        assert_eq!(
+            column(&result, "dept"),
+            vec![
+                Value::from_string("eng".to_string()),
+                Value::from_string("ops".to_string())
+            ]
+        );
+        assert_eq!(
+            column(&result, "salary_sum"),
+            vec![Value::Integer(330), Value::Integer(150)]
+        );
+        assert_eq!(
+            column(&result, "salary_max"),
+            vec![Value::Integer(120), Value::Integer(80)]
+        );
+
+        let mut bad = HashMap::new();
+        bad.insert(
+            "salary".to_string(),
+            Value::from_string("median".to_string()),
+        );
+        assert!(eval_group_by_method(&grouped, "agg", &[Value::Object(Arc::new(bad))]).is_err());
+    }
+
+    #[test]
+    fn test_format_table_aligns_and_elides() {
+        let table = format_table(&frame());
+        assert!(table.starts_with("shape: (5, 2)\n┌"), "{table}");
+        assert!(table.contains("│ eng  │    100 │"), "{table}");
+
+        let long = vec![DataFrameColumn {
+            name: "n".to_string(),
+            values: (0..25).map(Value::Integer).collect(),
+        }];
+        let table = format_table(&long);
+        assert!(table.contains("│  4 │\n│  … │\n│ 20 │"), "{table}");
+    }
+
+    #[test]
+    fn test_csv_text_quotes_fields() {
+        let df = vec![DataFrameColumn {
+            name: "note".to_string(),
+            values: vec![Value::from_string("a, \"b\"".to_string()), Value::Nil],
+        }];
+        assert_eq!(csv_text(&df), "note\n\"a, \"\"b\"\"\"\n\n");
+    }
+}
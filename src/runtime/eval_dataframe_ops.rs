@@ -6,6 +6,10 @@
 //! All functions maintain <10 cyclomatic complexity.
 
 use crate::frontend::ast::{DataFrameOp, Expr};
+use crate::runtime::eval_dataframe_engine::{
+    eval_dataframe_group_by, eval_dataframe_head, eval_dataframe_sort, eval_dataframe_tail,
+    write_csv_file,
+};
 use crate::runtime::validation::{validate_arg_count, validate_arg_range};
 use crate::runtime::{DataFrameColumn, InterpreterError, Value};
 use std::collections::HashMap;
@@ -34,6 +38,10 @@ pub fn eval_dataframe_method(
         "columns" => eval_dataframe_columns_count(columns, arg_values),
         "column_names" => eval_dataframe_column_names(columns, arg_values),
         "sort_by" => eval_dataframe_sort_by(columns, arg_values),
+        "sort" => eval_dataframe_sort(columns, arg_values),
+        "head" => eval_dataframe_head(columns, arg_values),
+        "tail" => eval_dataframe_tail(columns, arg_values),
+        "group_by" => eval_dataframe_group_by(columns, arg_values),
         "get" => eval_dataframe_get(columns, arg_values),
         "to_csv" => eval_dataframe_to_csv(columns, arg_values),
         "to_json" => eval_dataframe_to_json(columns, arg_values),
@@ -175,7 +183,7 @@ fn eval_dataframe_sort_by(
 
 /// Compare two values for sorting
 /// Complexity: 5 (within Toyota Way limits)
-fn compare_values_for_sort(a: &Value, b: &Value) -> std::cmp::Ordering {
+pub(crate) fn compare_values_for_sort(a: &Value, b: &Value) -> std::cmp::Ordering {
     use std::cmp::Ordering;
 
     match (a, b) {
@@ -1067,18 +1075,25 @@ fn eval_dataframe_groupby_multiple(
     perform_groupby_aggregation(columns, group_col, group_column)
 }
 
-/// Export `DataFrame` to CSV format
+/// Export `DataFrame` to CSV format, or write it to the file `args[0]`
 ///
 /// # Complexity
-/// Cyclomatic complexity: 7 (within Toyota Way limits)
+/// Cyclomatic complexity: 8 (within Toyota Way limits)
 fn eval_dataframe_to_csv(
     columns: &[DataFrameColumn],
     args: &[Value],
 ) -> Result<Value, InterpreterError> {
-    if !args.is_empty() {
-        return Err(InterpreterError::RuntimeError(
-            "DataFrame.to_csv() takes no arguments".to_string(),
-        ));
+    match args {
+        [] => {}
+        [Value::String(path)] => {
+            crate::runtime::restricted::check_file_access("DataFrame.to_csv(path)")?;
+            return write_csv_file(columns, path).map(|()| Value::Nil);
+        }
+        _ => {
+            return Err(InterpreterError::RuntimeError(
+                "DataFrame.to_csv() takes an optional file path".to_string(),
+            ))
+        }
     }
 
     // Handle empty DataFrame
@@ -0,0 +1,423 @@
+//! Exact decimal arithmetic for money (`Value::Decimal`)
+//!
+//! `1.23d` literals and `Decimal("1.23")` produce a base-10
+//! `rust_decimal::Decimal` (28 significant digits), so `0.1d + 0.2d == 0.3d`.
+//! Integers mix freely with decimals. Mixing with floats is rejected for
+//! arithmetic, because the float has already lost the exact value; it is
+//! allowed for comparisons. Overflow is an error rather than a silent
+//! promotion.
+//!
+//! Rounding takes an explicit mode: `d.round_dp(2, "half_up")`. The modes
+//! are `half_even` (banker's rounding, the default), `half_up`, `half_down`,
+//! `up` (away from zero), `down` (toward zero), `ceil` and `floor`.
+
+use crate::frontend::ast::BinaryOp as AstBinaryOp;
+use crate::runtime::{InterpreterError, Value};
+use num_traits::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, RoundingStrategy};
+
+/// Evaluate the text of a `Literal::Decimal`
+///
+/// The parser only produces valid decimal text; a malformed literal (which
+/// can only be built by hand) evaluates to nil.
+pub fn eval_decimal_literal(text: &str) -> Value {
+    text.parse::<Decimal>().map_or(Value::Nil, Value::Decimal)
+}
+
+/// `Decimal(x)` constructor: from a string, integer, float or decimal
+///
+/// # Errors
+///
+/// Returns an error for the wrong number of arguments, unparsable strings,
+/// non-finite floats and unsupported argument types.
+pub fn eval_decimal_constructor(args: &[Value]) -> Result<Value, InterpreterError> {
+    let [arg] = args else {
+        return Err(InterpreterError::RuntimeError(format!(
+            "Decimal() expects 1 argument, got {}",
+            args.len()
+        )));
+    };
+    match arg {
+        Value::String(s) => s
+            .trim()
+            .parse::<Decimal>()
+            .map(Value::Decimal)
+            .map_err(|e| InterpreterError::RuntimeError(format!("Invalid Decimal '{s}': {e}"))),
+        Value::Float(f) => Decimal::try_from(*f)
+            .map(Value::Decimal)
+            .map_err(|_| InterpreterError::RuntimeError(format!("Cannot convert {f} to Decimal"))),
+        _ => as_decimal(arg)
+            .ok_or_else(|| {
+                InterpreterError::TypeError(format!(
+                    "Decimal() expects a string or number, got {}",
+                    arg.type_name()
+                ))
+            })?
+            .map(Value::Decimal),
+    }
+}
+
+/// Arithmetic and comparisons where at least one operand is a Decimal
+///
+/// Returns `None` when neither operand is a Decimal, the other operand is
+/// not numeric, or the operator is not arithmetic/comparison, so the caller
+/// reports its usual error.
+///
+/// # Complexity
+/// Cyclomatic complexity: 6 (within Toyota Way limits)
+pub fn eval_decimal_op(
+    op: AstBinaryOp,
+    left: &Value,
+    right: &Value,
+) -> Option<Result<Value, InterpreterError>> {
+    if !matches!(left, Value::Decimal(_)) && !matches!(right, Value::Decimal(_)) {
+        return None;
+    }
+    if matches!(left, Value::Float(_)) || matches!(right, Value::Float(_)) {
+        return decimal_float_op(op, left, right);
+    }
+    let (a, b) = match (as_decimal(left)?, as_decimal(right)?) {
+        (Ok(a), Ok(b)) => (a, b),
+        (Err(e), _) | (_, Err(e)) => return Some(Err(e)),
+    };
+    decimal_binary_op(op, a, b)
+}
+
+/// Equality between a Decimal and another value
+///
+/// Exact against integers; floats compare through `f64`.
+#[allow(clippy::float_cmp)] // Mirrors equal_primitives
+pub fn decimal_equals(left: &Value, right: &Value) -> bool {
+    match (as_decimal(left), as_decimal(right)) {
+        (Some(Ok(a)), Some(Ok(b))) => a == b,
+        _ => matches!((as_f64(left), as_f64(right)), (Some(a), Some(b)) if a == b),
+    }
+}
+
+/// Methods on Decimal values
+///
+/// # Errors
+///
+/// Returns an error for unknown methods or invalid arguments.
+pub fn eval_decimal_method(
+    d: Decimal,
+    method: &str,
+    args: &[Value],
+) -> Result<Value, InterpreterError> {
+    if args.is_empty() {
+        if let Some(value) = eval_zero_arg_decimal_method(d, method)? {
+            return Ok(value);
+        }
+    }
+    match method {
+        "round" | "round_dp" => {
+            let (dp, strategy) = rounding_args(method, args)?;
+            Ok(Value::Decimal(d.round_dp_with_strategy(dp, strategy)))
+        }
+        "to_fixed" => Ok(Value::from_string(to_fixed(
+            d,
+            decimal_places(method, args)?,
+        ))),
+        "to_grouped" => Ok(Value::from_string(group_thousands(&to_fixed(
+            d,
+            decimal_places(method, args)?,
+        )))),
+        _ => Err(InterpreterError::RuntimeError(format!(
+            "Method '{method}' not found for type decimal"
+        ))),
+    }
+}
+
+/// Format with exactly `dp` decimal places, rounding half-even
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::runtime::eval_decimal::to_fixed;
+/// use rust_decimal::Decimal;
+///
+/// assert_eq!(to_fixed("2.5".parse::<Decimal>().unwrap(), 2), "2.50");
+/// assert_eq!(to_fixed("0.125".parse::<Decimal>().unwrap(), 2), "0.12");
+/// ```
+pub fn to_fixed(d: Decimal, dp: u32) -> String {
+    let mut rounded = d.round_dp(dp);
+    rounded.rescale(dp);
+    rounded.to_string()
+}
+
+/// Parse a rounding mode name
+///
+/// # Errors
+///
+/// Returns an error naming the accepted modes.
+pub fn rounding_strategy(mode: &str) -> Result<RoundingStrategy, InterpreterError> {
+    match mode {
+        "half_even" | "bankers" => Ok(RoundingStrategy::MidpointNearestEven),
+        "half_up" => Ok(RoundingStrategy::MidpointAwayFromZero),
+        "half_down" => Ok(RoundingStrategy::MidpointTowardZero),
+        "up" => Ok(RoundingStrategy::AwayFromZero),
+        "down" => Ok(RoundingStrategy::ToZero),
+        "ceil" => Ok(RoundingStrategy::ToPositiveInfinity),
+        "floor" => Ok(RoundingStrategy::ToNegativeInfinity),
+        _ => Err(InterpreterError::RuntimeError(format!(
+            "Unknown rounding mode '{mode}' (expected half_even, half_up, half_down, up, down, ceil or floor)"
+        ))),
+    }
+}
+
+fn eval_zero_arg_decimal_method(
+    d: Decimal,
+    method: &str,
+) -> Result<Option<Value>, InterpreterError> {
+    Ok(Some(match method {
+        "abs" => Value::Decimal(d.abs()),
+        "trunc" => Value::Decimal(d.trunc()),
+        "floor" => Value::Decimal(d.floor()),
+        "ceil" => Value::Decimal(d.ceil()),
+        "normalize" => Value::Decimal(d.normalize()),
+        "scale" => Value::Integer(i64::from(d.scale())),
+        "is_zero" => Value::Bool(d.is_zero()),
+        "is_negative" => Value::Bool(d.is_sign_negative() && !d.is_zero()),
+        "to_float" => Value::Float(d.to_f64().unwrap_or(f64::NAN)),
+        "to_int" => Value::Integer(d.trunc().to_i64().ok_or_else(|| {
+            InterpreterError::RuntimeError(format!("Decimal {d} does not fit in an integer"))
+        })?),
+        "to_string" => Value::from_string(d.to_string()),
+        _ => return Ok(None),
+    }))
+}
+
+/// `(dp)` or `(dp, mode)` for `round_dp`
+fn rounding_args(
+    method: &str,
+    args: &[Value],
+) -> Result<(u32, RoundingStrategy), InterpreterError> {
+    match args {
+        [] => Ok((0, RoundingStrategy::MidpointNearestEven)),
+        [_] => Ok((
+            decimal_places(method, args)?,
+            RoundingStrategy::MidpointNearestEven,
+        )),
+        [_, Value::String(mode)] => Ok((
+            decimal_places(method, &args[..1])?,
+            rounding_strategy(mode)?,
+        )),
+        _ => Err(InterpreterError::RuntimeError(format!(
+            "{method}() expects (places, mode?) with mode a string"
+        ))),
+    }
+}
+
+fn decimal_places(method: &str, args: &[Value]) -> Result<u32, InterpreterError> {
+    match args {
+        [Value::Integer(dp)] => u32::try_from(*dp)
+            .ok()
+            .filter(|dp| *dp <= 28)
+            .ok_or_else(|| {
+                InterpreterError::RuntimeError(format!(
+                    "{method}() places must be between 0 and 28, got {dp}"
+                ))
+            }),
+        _ => Err(InterpreterError::RuntimeError(format!(
+            "{method}() expects an integer number of decimal places"
+        ))),
+    }
+}
+
+/// Insert `,` between thousands in the integer part of a formatted decimal
+fn group_thousands(formatted: &str) -> String {
+    let (sign, unsigned) = match formatted.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", formatted),
+    };
+    let (int_part, frac_part) = match unsigned.find('.') {
+        Some(dot) => unsigned.split_at(dot),
+        None => (unsigned, ""),
+    };
+    let mut grouped = String::new();
+    for (i, digit) in int_part.chars().enumerate() {
+        if i > 0 && (int_part.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    format!("{sign}{grouped}{frac_part}")
+}
+
+/// Integer or Decimal operand as a Decimal; `Some(Err(..))` when an integer
+/// is too large for a Decimal
+fn as_decimal(value: &Value) -> Option<Result<Decimal, InterpreterError>> {
+    match value {
+        Value::Decimal(d) => Some(Ok(*d)),
+        Value::Integer(i) => Some(Ok(Decimal::from(*i))),
+        Value::BigInt(n) => Some(n.to_i128().and_then(Decimal::from_i128).ok_or_else(|| {
+            InterpreterError::RuntimeError(format!("Integer {n} is too large for a Decimal"))
+        })),
+        _ => None,
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn as_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::Decimal(d) => d.to_f64(),
+        Value::Float(f) => Some(*f),
+        Value::Integer(i) => Some(*i as f64),
+        _ => None,
+    }
+}
+
+/// Comparisons go through `f64`; arithmetic is refused
+fn decimal_float_op(
+    op: AstBinaryOp,
+    left: &Value,
+    right: &Value,
+) -> Option<Result<Value, InterpreterError>> {
+    let (a, b) = (as_f64(left)?, as_f64(right)?);
+    Some(match op {
+        AstBinaryOp::Less => Ok(Value::Bool(a < b)),
+        AstBinaryOp::LessEqual => Ok(Value::Bool(a <= b)),
+        AstBinaryOp::Greater => Ok(Value::Bool(a > b)),
+        AstBinaryOp::GreaterEqual => Ok(Value::Bool(a >= b)),
+        AstBinaryOp::Equal => Ok(Value::Bool(decimal_equals(left, right))),
+        AstBinaryOp::NotEqual => Ok(Value::Bool(!decimal_equals(left, right))),
+        AstBinaryOp::Add
+        | AstBinaryOp::Subtract
+        | AstBinaryOp::Multiply
+        | AstBinaryOp::Divide
+        | AstBinaryOp::Modulo
+        | AstBinaryOp::Power => Err(InterpreterError::TypeError(format!(
+            "Cannot apply {op:?} to decimal and float; convert the float with Decimal(\"...\")"
+        ))),
+        _ => return None,
+    })
+}
+
+/// # Complexity
+/// Cyclomatic complexity: 10 (flat operator table)
+fn decimal_binary_op(
+    op: AstBinaryOp,
+    a: Decimal,
+    b: Decimal,
+) -> Option<Result<Value, InterpreterError>> {
+    let checked = |result: Option<Decimal>| {
+        result
+            .map(Value::Decimal)
+            .ok_or_else(|| InterpreterError::RuntimeError(format!("Decimal overflow in {op:?}")))
+    };
+    Some(match op {
+        AstBinaryOp::Add => checked(a.checked_add(b)),
+        AstBinaryOp::Subtract => checked(a.checked_sub(b)),
+        AstBinaryOp::Multiply => checked(a.checked_mul(b)),
+        AstBinaryOp::Divide | AstBinaryOp::Modulo if b.is_zero() => {
+            Err(InterpreterError::DivisionByZero)
+        }
+        AstBinaryOp::Divide => checked(a.checked_div(b)),
+        AstBinaryOp::Modulo => checked(a.checked_rem(b)),
+        AstBinaryOp::Less => Ok(Value::Bool(a < b)),
+        AstBinaryOp::LessEqual => Ok(Value::Bool(a <= b)),
+        AstBinaryOp::Greater => Ok(Value::Bool(a > b)),
+        AstBinaryOp::GreaterEqual => Ok(Value::Bool(a >= b)),
+        AstBinaryOp::Equal => Ok(Value::Bool(a == b)),
+        AstBinaryOp::NotEqual => Ok(Value::Bool(a != b)),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(text: &str) -> Value {
+        eval_decimal_literal(text)
+    }
+
+    fn op(op: AstBinaryOp, a: &Value, b: &Value) -> Value {
+        eval_decimal_op(op, a, b).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_decimal_arithmetic_is_exact() {
+        let sum = op(AstBinaryOp::Add, &dec("0.1"), &dec("0.2"));
+        assert_eq!(sum, dec("0.3"));
+        assert_eq!(sum.to_string(), "0.3");
+        let total = op(AstBinaryOp::Multiply, &dec("19.99"), &Value::Integer(3));
+        assert_eq!(total.to_string(), "59.97");
+        let third = op(AstBinaryOp::Divide, &dec("1"), &Value::Integer(3));
+        assert_eq!(third.to_string(), "0.3333333333333333333333333333");
+    }
+
+    #[test]
+    fn test_decimal_errors() {
+        let by_zero = eval_decimal_op(AstBinaryOp::Divide, &dec("1"), &Value::Integer(0));
+        assert!(matches!(
+            by_zero,
+            Some(Err(InterpreterError::DivisionByZero))
+        ));
+        let mixed = eval_decimal_op(AstBinaryOp::Add, &dec("1"), &Value::Float(0.5));
+        assert!(matches!(mixed, Some(Err(InterpreterError::TypeError(_)))));
+        let max = Value::Decimal(Decimal::MAX);
+        let overflow = eval_decimal_op(AstBinaryOp::Add, &max, &Value::Integer(1));
+        assert!(matches!(
+            overflow,
+            Some(Err(InterpreterError::RuntimeError(_)))
+        ));
+        let text = Value::from_string("a".to_string());
+        assert!(eval_decimal_op(AstBinaryOp::Add, &dec("1"), &text).is_none());
+    }
+
+    #[test]
+    fn test_decimal_compares_with_floats_and_integers() {
+        assert_eq!(
+            op(AstBinaryOp::Less, &dec("0.5"), &Value::Float(0.75)),
+            Value::Bool(true)
+        );
+        assert!(decimal_equals(&dec("2.00"), &Value::Integer(2)));
+        assert!(decimal_equals(&dec("0.5"), &Value::Float(0.5)));
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        let round = |text: &str, mode: &str| {
+            let Value::Decimal(d) = dec(text) else {
+                panic!("not a decimal")
+            };
+            let args = [Value::Integer(0), Value::from_string(mode.to_string())];
+            eval_decimal_method(d, "round_dp", &args)
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(round("2.5", "half_even"), "2");
+        assert_eq!(round("2.5", "half_up"), "3");
+        assert_eq!(round("-2.5", "half_down"), "-2");
+        assert_eq!(round("2.1", "up"), "3");
+        assert_eq!(round("-2.9", "down"), "-2");
+        assert_eq!(round("-2.1", "floor"), "-3");
+        assert_eq!(round("2.1", "ceil"), "3");
+        assert!(rounding_strategy("nearest").is_err());
+    }
+
+    #[test]
+    fn test_formatting_helpers() {
+        let d = "-1234567.891".parse::<Decimal>().unwrap();
+        let fixed = eval_decimal_method(d, "to_fixed", &[Value::Integer(2)]).unwrap();
+        assert_eq!(fixed, Value::from_string("-1234567.89".to_string()));
+        let grouped = eval_decimal_method(d, "to_grouped", &[Value::Integer(1)]).unwrap();
+        assert_eq!(grouped, Value::from_string("-1,234,567.9".to_string()));
+        assert_eq!(group_thousands("999"), "999");
+        assert_eq!(to_fixed(Decimal::from(5), 2), "5.00");
+    }
+
+    #[test]
+    fn test_constructor() {
+        let from_text = eval_decimal_constructor(&[Value::from_string("1.50".to_string())]);
+        assert_eq!(from_text.unwrap().to_string(), "1.50");
+        assert_eq!(
+            eval_decimal_constructor(&[Value::Integer(7)]).unwrap(),
+            dec("7")
+        );
+        assert!(eval_decimal_constructor(&[Value::from_string("abc".to_string())]).is_err());
+        assert!(eval_decimal_constructor(&[Value::Float(f64::NAN)]).is_err());
+        assert!(eval_decimal_constructor(&[]).is_err());
+    }
+}
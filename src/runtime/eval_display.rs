@@ -4,6 +4,7 @@
 //! extracted for maintainability and following Toyota Way principles.
 //! All functions maintain <10 cyclomatic complexity.
 
+use crate::runtime::eval_dataframe_engine::format_table;
 use crate::runtime::{DataFrameColumn, InterpreterError, Value};
 use std::fmt;
 
@@ -11,6 +12,8 @@ impl fmt::Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Value::Integer(i) => write!(f, "{i}"),
+            Value::BigInt(n) => write!(f, "{n}"),
+            Value::Decimal(d) => write!(f, "{d}"),
             Value::Float(fl) => {
                 if fl.fract() == 0.0 {
                     write!(f, "{fl:.1}")
@@ -46,6 +49,8 @@ impl fmt::Display for Value {
             Value::Class {
                 class_name, fields, ..
             } => format_class(f, class_name, fields),
+            Value::Error { kind, message, .. } => write!(f, "{kind}: {message}"),
+            Value::Iterator(_) => write!(f, "<iterator>"),
             #[cfg(not(target_arch = "wasm32"))]
             Value::HtmlDocument(_) => write!(f, "<HtmlDocument>"),
             #[cfg(not(target_arch = "wasm32"))]
@@ -85,22 +90,18 @@ fn format_tuple(f: &mut fmt::Formatter<'_>, elements: &[Value]) -> fmt::Result {
     write!(f, ")")
 }
 
-/// Format a `DataFrame` value
+/// Format a `DataFrame` value as a table
 ///
 /// # Complexity
-/// Cyclomatic complexity: 2 (within Toyota Way limits)
+/// Cyclomatic complexity: 1 (within Toyota Way limits)
 fn format_dataframe(f: &mut fmt::Formatter<'_>, columns: &[DataFrameColumn]) -> fmt::Result {
-    writeln!(f, "DataFrame with {} columns:", columns.len())?;
-    for col in columns {
-        writeln!(f, "  {}: {} rows", col.name, col.values.len())?;
-    }
-    Ok(())
+    f.write_str(&format_table(columns))
 }
 
 /// Format an object value
 ///
 /// # Complexity
-/// Cyclomatic complexity: 4 (within Toyota Way limits)
+/// Cyclomatic complexity: 6 (within Toyota Way limits)
 ///
 /// # Determinism
 /// Keys are sorted to ensure deterministic output across multiple runs.
@@ -109,6 +110,14 @@ fn format_object(
     f: &mut fmt::Formatter<'_>,
     obj: &std::collections::HashMap<String, Value>,
 ) -> fmt::Result {
+    // `std::display` values print as their text; images as a placeholder
+    if let Some((mime, data)) = crate::runtime::eval_rich_output::display_fields(obj) {
+        return if mime.starts_with("image/") {
+            write!(f, "<{mime}, {} bytes>", data.len())
+        } else {
+            write!(f, "{data}")
+        };
+    }
     write!(f, "{{")?;
 
     // Sort keys for deterministic output (DEFECT-DICT-DETERMINISM fix)
@@ -153,6 +162,12 @@ fn format_enum_variant(
     variant_name: &str,
     data: Option<&Vec<Value>>,
 ) -> fmt::Result {
+    // Struct-like variants carry their fields as a single struct payload
+    if let Some([Value::Struct { name, fields }]) = data.map(Vec::as_slice) {
+        if name == variant_name {
+            return format_struct(f, name, fields);
+        }
+    }
     write!(f, "{variant_name}")?;
     if let Some(values) = data {
         format_enum_data(f, values)?;
@@ -261,8 +276,20 @@ impl fmt::Display for InterpreterError {
             }
             InterpreterError::Continue(None) => write!(f, "Continue outside of loop"),
             InterpreterError::Return(_) => write!(f, "Return outside of function"),
+            InterpreterError::Throw(Value::Error {
+                kind,
+                message,
+                stack,
+            }) => {
+                write!(f, "Uncaught {kind}: {message}")?;
+                for frame in stack.iter() {
+                    write!(f, "\n    at {frame}")?;
+                }
+                Ok(())
+            }
             InterpreterError::Throw(value) => write!(f, "Uncaught exception: {value:?}"),
             InterpreterError::AssertionFailed(msg) => write!(f, "Assertion failed: {msg}"),
+            InterpreterError::MethodNotFound(missing) => write!(f, "Runtime error: {missing}"),
             InterpreterError::RecursionLimitExceeded(depth, max) => {
                 write!(
                     f,
@@ -507,9 +534,10 @@ mod tests {
         ];
         let val = Value::DataFrame { columns };
         let display = val.to_string();
-        assert!(display.contains("DataFrame with 2 columns"));
-        assert!(display.contains("id: 2 rows"));
-        assert!(display.contains("name: 1 rows"));
+        assert!(display.starts_with("shape: (2, 2)"), "{display}");
+        assert!(display.contains("│ id │ name │"), "{display}");
+        assert!(display.contains("│  1 │ a    │"), "{display}");
+        assert!(display.contains("│  2 │      │"), "{display}");
     }
 
     #[test]
@@ -600,6 +628,21 @@ mod tests {
         assert!(err.to_string().contains("Uncaught exception"));
     }
 
+    #[test]
+    fn test_interpreter_error_throw_error_value_shows_stack() {
+        let error = Value::error(
+            "RuntimeError",
+            "boom",
+            vec!["inner".to_string(), "outer".to_string()],
+        );
+        assert_eq!(error.to_string(), "RuntimeError: boom");
+        let err = InterpreterError::Throw(error);
+        assert_eq!(
+            err.to_string(),
+            "Uncaught RuntimeError: boom\n    at inner\n    at outer"
+        );
+    }
+
     #[test]
     fn test_interpreter_error_assertion_failed() {
         let err = InterpreterError::AssertionFailed("expected true".to_string());
@@ -719,7 +762,7 @@ mod tests {
         let columns = vec![];
         let val = Value::DataFrame { columns };
         let display = val.to_string();
-        assert!(display.contains("DataFrame with 0 columns"));
+        assert_eq!(display, "shape: (0, 0)\n");
     }
 
     #[test]
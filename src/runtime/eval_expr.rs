@@ -52,10 +52,12 @@ where
 pub fn eval_literal(lit: &Literal) -> Value {
     match lit {
         Literal::Integer(i, _) => Value::Integer(*i),
+        Literal::BigInt(digits) => crate::runtime::eval_bigint::eval_bigint_literal(digits),
+        Literal::Decimal(text) => crate::runtime::eval_decimal::eval_decimal_literal(text),
         Literal::Float(f) => Value::Float(*f),
-        Literal::String(s) => Value::from_string(s.clone()),
+        Literal::String(s) => Value::from_interned(s),
         Literal::Bool(b) => Value::Bool(*b),
-        Literal::Char(c) => Value::from_string(c.to_string()),
+        Literal::Char(c) => Value::from_char(*c),
         Literal::Byte(b) => Value::Byte(*b),
         Literal::Unit => Value::Nil,
         Literal::Null => Value::Nil,
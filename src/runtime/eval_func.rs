@@ -4,13 +4,71 @@
 //! Extracted for maintainability and following Toyota Way principles.
 //! All functions maintain <10 cyclomatic complexity.
 
-use crate::frontend::ast::{Expr, Param};
+use crate::frontend::ast::{Expr, ExprKind, Param, Span};
 use crate::runtime::{InterpreterError, Value};
 use std::cell::RefCell; // ISSUE-119: Added for RefCell
 use std::collections::HashMap;
 use std::rc::Rc; // ISSUE-119: Added for Rc
 use std::sync::Arc;
 
+/// Closure parameters: names with their default values
+///
+/// RUNTIME-DEFAULT-PARAMS: defaults are evaluated at call time. A rest
+/// parameter (`...args`) gets a `...[]` marker as its default, which
+/// `pack_rest_args` recognises.
+///
+/// # Complexity
+/// Cyclomatic complexity: 2 (within Toyota Way limits)
+pub fn closure_params(params: &[Param]) -> Vec<(String, Option<Arc<Expr>>)> {
+    params
+        .iter()
+        .map(|p| {
+            let default = if p.is_rest() {
+                let empty = Expr::new(ExprKind::List(Vec::new()), Span::default());
+                let marker = ExprKind::Spread {
+                    expr: Box::new(empty),
+                };
+                Some(Arc::new(Expr::new(marker, Span::default())))
+            } else {
+                p.default_value
+                    .clone()
+                    .map(|expr| Arc::new((*expr).clone()))
+            };
+            (p.name(), default)
+        })
+        .collect()
+}
+
+/// Whether a closure parameter's default marks it as a rest parameter
+///
+/// # Complexity
+/// Cyclomatic complexity: 1 (within Toyota Way limits)
+pub fn is_rest_default(default: Option<&Arc<Expr>>) -> bool {
+    default.is_some_and(|expr| matches!(expr.kind, ExprKind::Spread { .. }))
+}
+
+/// Collect the arguments past a closure's fixed parameters into its rest
+/// parameter, as an array
+///
+/// Returns `None` when the closure has no rest parameter, or when too few
+/// arguments are given to reach it.
+///
+/// # Complexity
+/// Cyclomatic complexity: 3 (within Toyota Way limits)
+pub fn pack_rest_args(
+    params: &[(String, Option<Arc<Expr>>)],
+    args: &[Value],
+) -> Option<Vec<Value>> {
+    let (_, default) = params.last()?;
+    let fixed = params.len() - 1;
+    if !is_rest_default(default.as_ref()) || args.len() < fixed {
+        return None;
+    }
+    let mut packed = args[..fixed].to_vec();
+    packed.push(Value::from_array(args[fixed..].to_vec()));
+    Some(packed)
+}
+
 /// Evaluate a function definition
 ///
 /// # Complexity
@@ -22,18 +80,7 @@ pub fn eval_function(
     current_env_ref: &Rc<RefCell<HashMap<String, Value>>>, // ISSUE-119: Changed from &HashMap
     mut env_set: impl FnMut(String, Value),
 ) -> Result<Value, InterpreterError> {
-    // RUNTIME-DEFAULT-PARAMS: Extract both param names AND default values
-    let params_with_defaults: Vec<(String, Option<Arc<Expr>>)> = params
-        .iter()
-        .map(|p| {
-            (
-                p.name(),
-                p.default_value
-                    .clone()
-                    .map(|expr| Arc::new((*expr).clone())),
-            )
-        })
-        .collect();
+    let params_with_defaults = closure_params(params);
 
     let closure = Value::Closure {
         params: params_with_defaults,
@@ -55,18 +102,7 @@ pub fn eval_lambda(
     body: &Expr,
     current_env_ref: &Rc<RefCell<HashMap<String, Value>>>, // ISSUE-119: Changed from &HashMap
 ) -> Result<Value, InterpreterError> {
-    // RUNTIME-DEFAULT-PARAMS: Extract both param names AND default values
-    let params_with_defaults: Vec<(String, Option<Arc<Expr>>)> = params
-        .iter()
-        .map(|p| {
-            (
-                p.name(),
-                p.default_value
-                    .clone()
-                    .map(|expr| Arc::new((*expr).clone())),
-            )
-        })
-        .collect();
+    let params_with_defaults = closure_params(params);
 
     let closure = Value::Closure {
         params: params_with_defaults,
@@ -119,6 +155,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -132,6 +170,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: Some(Box::new(make_literal_expr(default))),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -523,6 +563,8 @@ mod tests {
                 ExprKind::Literal(Literal::Float(3.14)),
                 Span::default(),
             ))),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
         let body = make_literal_expr(0);
         let env = Rc::new(RefCell::new(HashMap::new()));
@@ -967,6 +1009,8 @@ mod round_134_tests {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -980,6 +1024,8 @@ mod round_134_tests {
             span: Span::default(),
             is_mutable: false,
             default_value: Some(Box::new(make_literal_expr(default))),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -1787,4 +1833,45 @@ mod round_134_tests {
             _ => panic!("Expected closure"),
         }
     }
+
+    fn make_rest_param(name: &str) -> Param {
+        Param {
+            pattern: Pattern::RestNamed(name.to_string()),
+            ..make_param(name)
+        }
+    }
+
+    #[test]
+    fn test_closure_params_marks_rest_param() {
+        let params = closure_params(&[make_param("first"), make_rest_param("rest")]);
+        assert_eq!(params[1].0, "rest");
+        assert!(!is_rest_default(params[0].1.as_ref()));
+        assert!(is_rest_default(params[1].1.as_ref()));
+    }
+
+    #[test]
+    fn test_pack_rest_args_collects_surplus() {
+        let params = closure_params(&[make_param("first"), make_rest_param("rest")]);
+        let args = [Value::Integer(1), Value::Integer(2), Value::Integer(3)];
+        let packed = pack_rest_args(&params, &args).expect("should pack");
+        assert_eq!(packed.len(), 2);
+        assert_eq!(packed[0], Value::Integer(1));
+        assert_eq!(
+            packed[1],
+            Value::from_array(vec![Value::Integer(2), Value::Integer(3)])
+        );
+
+        let packed = pack_rest_args(&params, &args[..1]).expect("should pack");
+        assert_eq!(packed[1], Value::from_array(Vec::new()));
+    }
+
+    #[test]
+    fn test_pack_rest_args_ignores_other_closures() {
+        let args = [Value::Integer(1)];
+        let params = closure_params(&[make_param("x")]);
+        assert!(pack_rest_args(&params, &args).is_none());
+
+        let params = closure_params(&[make_param("x"), make_rest_param("rest")]);
+        assert!(pack_rest_args(&params, &[]).is_none());
+    }
 }
@@ -36,6 +36,14 @@ pub fn set_max_recursion_depth(depth: usize) {
     MAX_DEPTH.with(|max| max.set(depth));
 }
 
+/// Get the maximum recursion depth limit of this thread
+///
+/// # Complexity
+/// Cyclomatic: 1
+pub fn get_max_recursion_depth() -> usize {
+    MAX_DEPTH.with(std::cell::Cell::get)
+}
+
 /// Get current recursion depth (for debugging/monitoring)
 ///
 /// # Complexity
@@ -274,10 +282,7 @@ where
 
         // Evaluate function body with bound environment
         // Catch InterpreterError::Return and extract the value (early return support)
-        match eval_with_env(body, &call_env) {
-            Err(InterpreterError::Return(val)) => Ok(val),
-            other => other,
-        }
+        InterpreterError::catch_return(eval_with_env(body, &call_env))
     })();
 
     // [RUNTIME-001] ALWAYS DECREMENT, EVEN ON ERROR
@@ -334,10 +339,7 @@ where
 
     // Evaluate function body with bound environment
     // Catch InterpreterError::Return and extract the value (early return support)
-    match eval_with_env(&closure.body, &call_env) {
-        Err(InterpreterError::Return(val)) => Ok(val),
-        other => other,
-    }
+    InterpreterError::catch_return(eval_with_env(&closure.body, &call_env))
 }
 
 /// Bind a parameter pattern to an argument value
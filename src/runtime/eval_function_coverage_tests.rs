@@ -26,6 +26,8 @@ fn make_param(name: &str) -> Param {
         span: Span::new(0, 0),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }
 }
 
@@ -39,6 +41,8 @@ fn make_param_with_default(name: &str, default: Expr) -> Param {
         span: Span::new(0, 0),
         is_mutable: false,
         default_value: Some(Box::new(default)),
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }
 }
 
@@ -160,6 +164,8 @@ fn test_eval_function_def_with_wildcard_pattern() {
         span: Span::new(0, 0),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
     let params = vec![param];
     let body = make_unit_expr();
@@ -294,6 +300,8 @@ fn test_eval_lambda_with_wildcard_pattern() {
         span: Span::new(0, 0),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     };
     let params = vec![param];
     let body = make_unit_expr();
@@ -50,7 +50,7 @@ pub fn index_string(s: &str, idx: i64) -> Result<Value, InterpreterError> {
     }
 
     #[allow(clippy::cast_sign_loss)] // Safe: we've verified actual_index >= 0
-    Ok(Value::from_string(chars[actual_index as usize].to_string()))
+    Ok(Value::from_char(chars[actual_index as usize]))
 }
 
 /// Slice a string using a range (ISSUE-094, GitHub Issue #94)
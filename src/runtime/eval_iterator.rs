@@ -0,0 +1,601 @@
+//! Lazy iterator evaluation module
+//!
+//! `list.iter()`, `range.iter()` and the adapters chained onto them build a
+//! [`Value::Iterator`]: a pipeline of [`LazyIter`] stages that pulls one
+//! element at a time from its source. Closures given to `map`, `filter` and
+//! `flat_map` only run when an element is pulled, so
+//! `(0..1000000).iter().map(f).take(3).collect()` calls `f` three times and
+//! never materializes an intermediate list.
+//! Complexity: <10 per function (Toyota Way compliant)
+
+use crate::runtime::eval_array::eval_array_method;
+use crate::runtime::interpreter_generator::GeneratorState;
+use crate::runtime::records::RecordSource;
+use crate::runtime::validation::validate_arg_count;
+use crate::runtime::{InterpreterError, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Methods lazy iterators answer to besides the list methods they fall back to
+pub const ITERATOR_METHODS: &[&str] = &[
+    "map",
+    "filter",
+    "flat_map",
+    "take",
+    "skip",
+    "zip",
+    "chain",
+    "enumerate",
+    "iter",
+    "into_iter",
+    "next",
+    "count",
+    "sum",
+    "fold",
+    "reduce",
+    "any",
+    "all",
+    "find",
+    "for_each",
+    "each",
+    "collect",
+    "to_array",
+];
+
+/// One stage of a lazy iterator pipeline
+#[derive(Debug, Clone)]
+pub enum LazyIter {
+    /// Elements of a list, tuple or string
+    Items {
+        items: Arc<[Value]>,
+        index: usize,
+    },
+    /// Integers from `next` up to, but excluding, `end`
+    Range {
+        next: i64,
+        end: i64,
+    },
+    Map {
+        source: Box<LazyIter>,
+        func: Value,
+    },
+    Filter {
+        source: Box<LazyIter>,
+        func: Value,
+    },
+    Take {
+        source: Box<LazyIter>,
+        remaining: usize,
+    },
+    Skip {
+        source: Box<LazyIter>,
+        pending: usize,
+    },
+    Zip {
+        left: Box<LazyIter>,
+        right: Box<LazyIter>,
+    },
+    Enumerate {
+        source: Box<LazyIter>,
+        index: i64,
+    },
+    Chain {
+        first: Box<LazyIter>,
+        second: Box<LazyIter>,
+    },
+    FlatMap {
+        source: Box<LazyIter>,
+        func: Value,
+        inner: Option<Box<LazyIter>>,
+    },
+    /// A suspended generator function; clones share its progress
+    Generator(Rc<RefCell<GeneratorState>>),
+    /// Records read from stdin by `io::records()`
+    Records(RecordSource),
+}
+
+impl LazyIter {
+    /// Start a pipeline over any iterable value
+    ///
+    /// Iterating an existing iterator continues from its current position.
+    pub fn from_value(value: &Value) -> Result<Self, InterpreterError> {
+        match value {
+            Value::Array(items) | Value::Tuple(items) => Ok(Self::Items {
+                items: items.clone(),
+                index: 0,
+            }),
+            Value::String(s) => Ok(Self::Items {
+                items: s.chars().map(Value::from_char).collect(),
+                index: 0,
+            }),
+            Value::Range {
+                start,
+                end,
+                inclusive,
+            } => match (start.as_ref(), end.as_ref()) {
+                (Value::Integer(start), Value::Integer(end)) => Ok(Self::Range {
+                    next: *start,
+                    end: if *inclusive {
+                        end.saturating_add(1)
+                    } else {
+                        *end
+                    },
+                }),
+                _ => Err(InterpreterError::TypeError(
+                    "Only integer ranges can be iterated".to_string(),
+                )),
+            },
+            Value::Iterator(iter) => Ok(iter.borrow().clone()),
+            _ => Err(InterpreterError::TypeError(format!(
+                "Cannot iterate over {}",
+                value.type_name()
+            ))),
+        }
+    }
+
+    /// Wrap this pipeline in a runtime value
+    pub fn into_value(self) -> Value {
+        Value::Iterator(Rc::new(RefCell::new(self)))
+    }
+
+    /// Pull the next element, running adapter closures through `call`
+    pub fn next<F>(&mut self, call: &mut F) -> Result<Option<Value>, InterpreterError>
+    where
+        F: FnMut(&Value, &[Value]) -> Result<Value, InterpreterError>,
+    {
+        match self {
+            Self::Items { items, index } => {
+                let item = items.get(*index).cloned();
+                *index += usize::from(item.is_some());
+                Ok(item)
+            }
+            Self::Range { next, end } => {
+                if next < end {
+                    *next += 1;
+                    Ok(Some(Value::Integer(*next - 1)))
+                } else {
+                    Ok(None)
+                }
+            }
+            Self::Map { source, func } => match source.next(call)? {
+                Some(item) => call(func, &[item]).map(Some),
+                None => Ok(None),
+            },
+            Self::Filter { source, func } => next_matching(source, func, call),
+            Self::Take { source, remaining } => {
+                if *remaining == 0 {
+                    return Ok(None);
+                }
+                *remaining -= 1;
+                source.next(call)
+            }
+            Self::Skip { source, pending } => {
+                while *pending > 0 {
+                    *pending -= 1;
+                    if source.next(call)?.is_none() {
+                        return Ok(None);
+                    }
+                }
+                source.next(call)
+            }
+            Self::Zip { left, right } => {
+                let (Some(a), Some(b)) = (left.next(call)?, right.next(call)?) else {
+                    return Ok(None);
+                };
+                Ok(Some(Value::Tuple(Arc::from(vec![a, b]))))
+            }
+            Self::Enumerate { source, index } => {
+                let Some(item) = source.next(call)? else {
+                    return Ok(None);
+                };
+                *index += 1;
+                Ok(Some(Value::Tuple(Arc::from(vec![
+                    Value::Integer(*index - 1),
+                    item,
+                ]))))
+            }
+            Self::Chain { first, second } => match first.next(call)? {
+                Some(item) => Ok(Some(item)),
+                None => second.next(call),
+            },
+            Self::FlatMap {
+                source,
+                func,
+                inner,
+            } => next_flattened(source, func, inner, call),
+            // Calling a generator iterator resumes it (see `Interpreter::call_function`)
+            Self::Generator(state) => {
+                let resume = Self::Generator(Rc::clone(state)).into_value();
+                Ok(option_payload(call(&resume, &[])?))
+            }
+            Self::Records(source) => source.next_record(),
+        }
+    }
+}
+
+fn next_matching<F>(
+    source: &mut LazyIter,
+    func: &Value,
+    call: &mut F,
+) -> Result<Option<Value>, InterpreterError>
+where
+    F: FnMut(&Value, &[Value]) -> Result<Value, InterpreterError>,
+{
+    while let Some(item) = source.next(call)? {
+        if call(func, std::slice::from_ref(&item))?.is_truthy() {
+            return Ok(Some(item));
+        }
+    }
+    Ok(None)
+}
+
+fn next_flattened<F>(
+    source: &mut LazyIter,
+    func: &Value,
+    inner: &mut Option<Box<LazyIter>>,
+    call: &mut F,
+) -> Result<Option<Value>, InterpreterError>
+where
+    F: FnMut(&Value, &[Value]) -> Result<Value, InterpreterError>,
+{
+    loop {
+        if let Some(current) = inner {
+            if let Some(item) = current.next(call)? {
+                return Ok(Some(item));
+            }
+        }
+        let Some(item) = source.next(call)? else {
+            return Ok(None);
+        };
+        let produced = call(func, &[item])?;
+        *inner = Some(Box::new(LazyIter::from_value(&produced)?));
+    }
+}
+
+/// Evaluate a method call on a lazy iterator
+///
+/// Adapters return a new iterator and leave the receiver untouched;
+/// consumers pull from the receiver. Methods without a lazy form collect the
+/// remaining elements and fall back to the list method of the same name.
+pub fn eval_iterator_method<F>(
+    iter: &Rc<RefCell<LazyIter>>,
+    method: &str,
+    args: &[Value],
+    mut call: F,
+) -> Result<Value, InterpreterError>
+where
+    F: FnMut(&Value, &[Value]) -> Result<Value, InterpreterError>,
+{
+    if let Some(adapted) = eval_iterator_adapter(iter, method, args)? {
+        return Ok(adapted.into_value());
+    }
+    let mut state = iter.try_borrow_mut().map_err(|_| {
+        InterpreterError::RuntimeError("Iterator is already being consumed".to_string())
+    })?;
+    match method {
+        "iter" | "into_iter" => {
+            validate_arg_count(method, args, 0)?;
+            drop(state);
+            Ok(Value::Iterator(iter.clone()))
+        }
+        "next" => {
+            validate_arg_count(method, args, 0)?;
+            Ok(option_value(state.next(&mut call)?))
+        }
+        "count" => {
+            validate_arg_count(method, args, 0)?;
+            let mut count = 0;
+            while state.next(&mut call)?.is_some() {
+                count += 1;
+            }
+            Ok(Value::Integer(count))
+        }
+        "sum" => {
+            validate_arg_count(method, args, 0)?;
+            sum_remaining(&mut state, &mut call)
+        }
+        "fold" | "reduce" => fold_remaining(&mut state, method, args, &mut call),
+        "any" | "all" | "find" => search_remaining(&mut state, method, args, &mut call),
+        "for_each" | "each" => {
+            validate_arg_count(method, args, 1)?;
+            while let Some(item) = state.next(&mut call)? {
+                call(&args[0], &[item])?;
+            }
+            Ok(Value::Nil)
+        }
+        "collect" | "to_array" => {
+            validate_arg_count(method, args, 0)?;
+            collect_remaining(&mut state, &mut call).map(Value::Array)
+        }
+        _ => {
+            let items = collect_remaining(&mut state, &mut call)?;
+            eval_array_method(&items, method, args, call)
+        }
+    }
+}
+
+/// Build the adapter stage for `method`, or `None` if it is not an adapter
+fn eval_iterator_adapter(
+    iter: &Rc<RefCell<LazyIter>>,
+    method: &str,
+    args: &[Value],
+) -> Result<Option<LazyIter>, InterpreterError> {
+    let source = || Box::new(iter.borrow().clone());
+    let adapted = match method {
+        "map" | "filter" | "flat_map" => {
+            validate_arg_count(method, args, 1)?;
+            let func = args[0].clone();
+            match method {
+                "map" => LazyIter::Map {
+                    source: source(),
+                    func,
+                },
+                "filter" => LazyIter::Filter {
+                    source: source(),
+                    func,
+                },
+                _ => LazyIter::FlatMap {
+                    source: source(),
+                    func,
+                    inner: None,
+                },
+            }
+        }
+        "take" => LazyIter::Take {
+            source: source(),
+            remaining: count_argument(method, args)?,
+        },
+        "skip" => LazyIter::Skip {
+            source: source(),
+            pending: count_argument(method, args)?,
+        },
+        "zip" | "chain" => {
+            validate_arg_count(method, args, 1)?;
+            let other = Box::new(LazyIter::from_value(&args[0])?);
+            if method == "zip" {
+                LazyIter::Zip {
+                    left: source(),
+                    right: other,
+                }
+            } else {
+                LazyIter::Chain {
+                    first: source(),
+                    second: other,
+                }
+            }
+        }
+        "enumerate" => {
+            validate_arg_count(method, args, 0)?;
+            LazyIter::Enumerate {
+                source: source(),
+                index: 0,
+            }
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(adapted))
+}
+
+fn count_argument(method: &str, args: &[Value]) -> Result<usize, InterpreterError> {
+    validate_arg_count(method, args, 1)?;
+    match &args[0] {
+        Value::Integer(n) if *n >= 0 => Ok(usize::try_from(*n).unwrap_or(usize::MAX)),
+        other => Err(InterpreterError::TypeError(format!(
+            "{method}() expects a non-negative integer, got {}",
+            other.type_name()
+        ))),
+    }
+}
+
+fn collect_remaining<F>(
+    state: &mut LazyIter,
+    call: &mut F,
+) -> Result<Arc<[Value]>, InterpreterError>
+where
+    F: FnMut(&Value, &[Value]) -> Result<Value, InterpreterError>,
+{
+    let mut items = Vec::new();
+    while let Some(item) = state.next(call)? {
+        crate::runtime::restricted::check_size(items.len() + 1)?;
+        items.push(item);
+    }
+    Ok(Arc::from(items))
+}
+
+fn sum_remaining<F>(state: &mut LazyIter, call: &mut F) -> Result<Value, InterpreterError>
+where
+    F: FnMut(&Value, &[Value]) -> Result<Value, InterpreterError>,
+{
+    let mut int_sum: i64 = 0;
+    let mut float_sum: Option<f64> = None;
+    while let Some(item) = state.next(call)? {
+        match item {
+            Value::Integer(i) => int_sum += i,
+            Value::Float(f) => *float_sum.get_or_insert(0.0) += f,
+            _ => {
+                return Err(InterpreterError::RuntimeError(
+                    "sum() requires numeric iterator elements".to_string(),
+                ))
+            }
+        }
+    }
+    Ok(match float_sum {
+        Some(f) => Value::Float(int_sum as f64 + f),
+        None => Value::Integer(int_sum),
+    })
+}
+
+/// `fold(init, f)`, or `reduce` in either argument order like the list method
+fn fold_remaining<F>(
+    state: &mut LazyIter,
+    method: &str,
+    args: &[Value],
+    call: &mut F,
+) -> Result<Value, InterpreterError>
+where
+    F: FnMut(&Value, &[Value]) -> Result<Value, InterpreterError>,
+{
+    validate_arg_count(method, args, 2)?;
+    let (initial, func) = if method == "reduce" && matches!(&args[0], Value::Closure { .. }) {
+        (&args[1], &args[0])
+    } else {
+        (&args[0], &args[1])
+    };
+    let mut accumulator = initial.clone();
+    while let Some(item) = state.next(call)? {
+        accumulator = call(func, &[accumulator, item])?;
+    }
+    Ok(accumulator)
+}
+
+/// `any`, `all` and `find` stop pulling as soon as the answer is known
+fn search_remaining<F>(
+    state: &mut LazyIter,
+    method: &str,
+    args: &[Value],
+    call: &mut F,
+) -> Result<Value, InterpreterError>
+where
+    F: FnMut(&Value, &[Value]) -> Result<Value, InterpreterError>,
+{
+    validate_arg_count(method, args, 1)?;
+    let stop_when = method != "all";
+    while let Some(item) = state.next(call)? {
+        if call(&args[0], std::slice::from_ref(&item))?.is_truthy() == stop_when {
+            return Ok(match method {
+                "find" => option_value(Some(item)),
+                _ => Value::Bool(stop_when),
+            });
+        }
+    }
+    Ok(match method {
+        "find" => option_value(None),
+        _ => Value::Bool(!stop_when),
+    })
+}
+
+pub(crate) fn option_value(item: Option<Value>) -> Value {
+    match item {
+        Some(item) => Value::EnumVariant {
+            enum_name: "Option".to_string(),
+            variant_name: "Some".to_string(),
+            data: Some(vec![item]),
+        },
+        None => Value::EnumVariant {
+            enum_name: "Option".to_string(),
+            variant_name: "None".to_string(),
+            data: None,
+        },
+    }
+}
+
+fn option_payload(value: Value) -> Option<Value> {
+    match value {
+        Value::EnumVariant {
+            variant_name,
+            data: Some(mut data),
+            ..
+        } if variant_name == "Some" && data.len() == 1 => data.pop(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_calls(_: &Value, _: &[Value]) -> Result<Value, InterpreterError> {
+        panic!("no closure should run")
+    }
+
+    fn ints(values: &[i64]) -> Value {
+        Value::Array(values.iter().copied().map(Value::Integer).collect())
+    }
+
+    #[test]
+    fn test_range_iterator_excludes_end_unless_inclusive() {
+        let range = Value::Range {
+            start: Box::new(Value::Integer(1)),
+            end: Box::new(Value::Integer(3)),
+            inclusive: true,
+        };
+        let iter = Rc::new(RefCell::new(LazyIter::from_value(&range).unwrap()));
+        let collected = eval_iterator_method(&iter, "collect", &[], no_calls).unwrap();
+        assert_eq!(collected, ints(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn test_take_stops_pulling_from_source() {
+        let mut calls = 0;
+        let source = LazyIter::Range {
+            next: 0,
+            end: i64::MAX,
+        };
+        let mut take = LazyIter::Take {
+            source: Box::new(LazyIter::Map {
+                source: Box::new(source),
+                func: Value::Nil,
+            }),
+            remaining: 2,
+        };
+        let mut double = |_: &Value, args: &[Value]| {
+            calls += 1;
+            match &args[0] {
+                Value::Integer(n) => Ok(Value::Integer(n * 2)),
+                _ => unreachable!(),
+            }
+        };
+        assert_eq!(take.next(&mut double).unwrap(), Some(Value::Integer(0)));
+        assert_eq!(take.next(&mut double).unwrap(), Some(Value::Integer(2)));
+        assert_eq!(take.next(&mut double).unwrap(), None);
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn test_adapters_do_not_consume_receiver() {
+        let iter = Rc::new(RefCell::new(LazyIter::from_value(&ints(&[1, 2])).unwrap()));
+        let skipped = eval_iterator_method(&iter, "skip", &[Value::Integer(1)], no_calls).unwrap();
+        assert_eq!(
+            eval_iterator_method(&iter, "count", &[], no_calls).unwrap(),
+            Value::Integer(2)
+        );
+        let Value::Iterator(skipped) = skipped else {
+            panic!("skip should return an iterator");
+        };
+        assert_eq!(
+            eval_iterator_method(&skipped, "collect", &[], no_calls).unwrap(),
+            ints(&[2])
+        );
+    }
+
+    #[test]
+    fn test_zip_enumerate_and_chain() {
+        let iter = Rc::new(RefCell::new(
+            LazyIter::from_value(&ints(&[1, 2, 3])).unwrap(),
+        ));
+        let zipped = eval_iterator_method(&iter, "zip", &[ints(&[7, 8])], no_calls).unwrap();
+        let Value::Iterator(zipped) = zipped else {
+            panic!("zip should return an iterator");
+        };
+        assert_eq!(
+            eval_iterator_method(&zipped, "count", &[], no_calls).unwrap(),
+            Value::Integer(2)
+        );
+        let chained = eval_iterator_method(&iter, "chain", &[ints(&[4])], no_calls).unwrap();
+        let Value::Iterator(chained) = chained else {
+            panic!("chain should return an iterator");
+        };
+        assert_eq!(
+            eval_iterator_method(&chained, "sum", &[], no_calls).unwrap(),
+            Value::Integer(10)
+        );
+    }
+
+    #[test]
+    fn test_non_iterable_is_rejected() {
+        assert!(LazyIter::from_value(&Value::Bool(true)).is_err());
+        let iter = Rc::new(RefCell::new(LazyIter::from_value(&ints(&[])).unwrap()));
+        assert!(eval_iterator_method(&iter, "take", &[Value::Integer(-1)], no_calls).is_err());
+    }
+}
@@ -6,6 +6,10 @@ use crate::runtime::{InterpreterError, Value};
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Deepest nesting of arrays and objects converted to JSON; `serde_json`
+/// parses no deeper, and the limit keeps the recursion off the stack's end
+pub const MAX_JSON_DEPTH: usize = 128;
+
 /// ISSUE-117: Parse JSON string into Value (complexity: 8)
 pub fn json_parse(json_str: &str) -> Result<Value, InterpreterError> {
     let json_value: serde_json::Value = serde_json::from_str(json_str)
@@ -53,8 +57,21 @@ pub fn serde_to_value(json: &serde_json::Value) -> Result<Value, InterpreterErro
     }
 }
 
-/// Convert interpreter Value to `serde_json::Value` (complexity: 8)
+/// Convert interpreter Value to `serde_json::Value` (complexity: 1)
+///
+/// Fails for values without a JSON form and for nesting deeper than
+/// [`MAX_JSON_DEPTH`].
 pub fn value_to_serde(value: &Value) -> Result<serde_json::Value, InterpreterError> {
+    value_to_serde_at(value, 0)
+}
+
+/// [`value_to_serde`] for a value `depth` containers down (complexity: 9)
+fn value_to_serde_at(value: &Value, depth: usize) -> Result<serde_json::Value, InterpreterError> {
+    if depth >= MAX_JSON_DEPTH && matches!(value, Value::Array(_) | Value::Object(_)) {
+        return Err(InterpreterError::RuntimeError(format!(
+            "Value nested more than {MAX_JSON_DEPTH} levels deep cannot be converted to JSON"
+        )));
+    }
     match value {
         Value::Nil => Ok(serde_json::Value::Null),
         Value::Bool(b) => Ok(serde_json::Value::Bool(*b)),
@@ -70,14 +87,16 @@ pub fn value_to_serde(value: &Value) -> Result<serde_json::Value, InterpreterErr
         }
         Value::String(s) => Ok(serde_json::Value::String(s.to_string())),
         Value::Array(arr) => {
-            let json_arr: Result<Vec<serde_json::Value>, InterpreterError> =
-                arr.iter().map(value_to_serde).collect();
+            let json_arr: Result<Vec<serde_json::Value>, InterpreterError> = arr
+                .iter()
+                .map(|item| value_to_serde_at(item, depth + 1))
+                .collect();
             Ok(serde_json::Value::Array(json_arr?))
         }
         Value::Object(obj) => {
             let mut json_obj = serde_json::Map::new();
             for (key, val) in obj.as_ref() {
-                json_obj.insert(key.clone(), value_to_serde(val)?);
+                json_obj.insert(key.clone(), value_to_serde_at(val, depth + 1)?);
             }
             Ok(serde_json::Value::Object(json_obj))
         }
@@ -647,6 +666,18 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_json_stringify_stops_at_max_depth() {
+        let nest = |levels: usize| {
+            (0..levels).fold(Value::Integer(1), |inner, _| Value::from_array(vec![inner]))
+        };
+        assert!(json_stringify(&nest(MAX_JSON_DEPTH)).is_ok());
+        let result = json_stringify(&nest(MAX_JSON_DEPTH + 1));
+        assert!(
+            matches!(result, Err(InterpreterError::RuntimeError(ref msg)) if msg.contains("nested"))
+        );
+    }
+
     #[test]
     fn test_json_stringify_very_large_integer() {
         let result = json_stringify(&Value::Integer(i64::MAX)).unwrap();
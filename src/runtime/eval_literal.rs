@@ -14,10 +14,12 @@ use crate::runtime::Value;
 pub fn eval_literal(lit: &Literal) -> Value {
     match lit {
         Literal::Integer(i, _) => Value::from_i64(*i),
+        Literal::BigInt(digits) => crate::runtime::eval_bigint::eval_bigint_literal(digits),
+        Literal::Decimal(text) => crate::runtime::eval_decimal::eval_decimal_literal(text),
         Literal::Float(f) => Value::from_f64(*f),
-        Literal::String(s) => Value::from_string(s.clone()),
+        Literal::String(s) => Value::from_interned(s),
         Literal::Bool(b) => Value::from_bool(*b),
-        Literal::Char(c) => Value::from_string(c.to_string()),
+        Literal::Char(c) => Value::from_char(*c),
         Literal::Byte(b) => Value::Byte(*b),
         Literal::Unit => Value::nil(),
         Literal::Null => Value::nil(),
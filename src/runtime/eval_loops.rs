@@ -86,7 +86,7 @@ where
         match handle_loop_iteration(body, &mut eval_expr)? {
             LoopAction::Continue(value) => last_value = value,
             LoopAction::Break => break,
-            LoopAction::Return => return eval_expr(body),
+            LoopAction::Return(value) => return Err(InterpreterError::Return(value)),
         }
     }
     Ok(last_value)
@@ -105,7 +105,8 @@ fn bind_loop_variable(
 enum LoopAction {
     Continue(Value),
     Break,
-    Return,
+    /// `return` inside the body exits the enclosing function
+    Return(Value),
 }
 
 fn handle_loop_iteration<F>(body: &Expr, eval_expr: &mut F) -> Result<LoopAction, InterpreterError>
@@ -116,7 +117,7 @@ where
         Ok(value) => Ok(LoopAction::Continue(value)),
         Err(InterpreterError::Break(None, _)) => Ok(LoopAction::Break),
         Err(InterpreterError::Continue(_)) => Ok(LoopAction::Continue(Value::Nil)),
-        Err(InterpreterError::Return(_)) => Ok(LoopAction::Return),
+        Err(InterpreterError::Return(value)) => Ok(LoopAction::Return(value)),
         Err(InterpreterError::RuntimeError(msg)) if msg == "break" => Ok(LoopAction::Break),
         Err(InterpreterError::RuntimeError(msg)) if msg == "continue" => {
             Ok(LoopAction::Continue(Value::Nil))
@@ -152,7 +153,7 @@ where
         match handle_loop_iteration(body, &mut eval_expr)? {
             LoopAction::Continue(value) => last_value = value,
             LoopAction::Break => break,
-            LoopAction::Return => return eval_expr(body),
+            LoopAction::Return(value) => return Err(InterpreterError::Return(value)),
         }
     }
     Ok(last_value)
@@ -187,7 +188,7 @@ where
         match handle_loop_iteration(body, &mut eval_expr)? {
             LoopAction::Continue(value) => last_value = value,
             LoopAction::Break => break,
-            LoopAction::Return => return eval_expr(body),
+            LoopAction::Return(value) => return Err(InterpreterError::Return(value)),
         }
     }
     Ok(last_value)
@@ -213,7 +214,7 @@ where
         match handle_infinite_loop_iteration(body, &mut eval_expr)? {
             InfiniteLoopAction::Continue => {}
             InfiniteLoopAction::Break(value) => return Ok(value),
-            InfiniteLoopAction::Return => return eval_expr(body),
+            InfiniteLoopAction::Return(value) => return Err(InterpreterError::Return(value)),
         }
     }
 }
@@ -221,7 +222,7 @@ where
 enum InfiniteLoopAction {
     Continue,
     Break(Value),
-    Return,
+    Return(Value),
 }
 
 fn handle_infinite_loop_iteration<F>(
@@ -235,7 +236,7 @@ where
         Ok(_) => Ok(InfiniteLoopAction::Continue),
         Err(InterpreterError::Break(None, value)) => Ok(InfiniteLoopAction::Break(value)),
         Err(InterpreterError::Continue(_)) => Ok(InfiniteLoopAction::Continue),
-        Err(InterpreterError::Return(_)) => Ok(InfiniteLoopAction::Return),
+        Err(InterpreterError::Return(value)) => Ok(InfiniteLoopAction::Return(value)),
         Err(InterpreterError::RuntimeError(msg)) if msg == "break" => {
             Ok(InfiniteLoopAction::Break(Value::Nil))
         }
@@ -834,8 +835,11 @@ mod tests {
             |_expr| Err(InterpreterError::Return(Value::Integer(42))),
         );
 
-        // Return is handled by re-evaluating body
-        assert!(result.is_err());
+        // Return propagates its value to the enclosing function
+        assert!(matches!(
+            result,
+            Err(InterpreterError::Return(Value::Integer(42)))
+        ));
     }
 
     // Test 34: while loop condition error
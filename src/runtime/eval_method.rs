@@ -10,6 +10,24 @@ use crate::runtime::validation::validate_arg_count;
 use crate::runtime::{DataFrameColumn, InterpreterError, Value};
 use std::sync::Arc;
 
+/// Every method integers answer to
+pub const INTEGER_METHODS: &[&str] = &["abs", "to_string", "pow"];
+
+/// Every method floats answer to
+pub const FLOAT_METHODS: &[&str] = &[
+    "sqrt",
+    "abs",
+    "round",
+    "floor",
+    "ceil",
+    "to_int",
+    "to_integer",
+    "to_string",
+];
+
+/// Methods every other value answers to
+pub const GENERIC_METHODS: &[&str] = &["to_string"];
+
 /// Main method call evaluation entry point
 ///
 /// # Complexity
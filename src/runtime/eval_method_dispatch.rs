@@ -243,6 +243,7 @@ fn eval_object_method(
 ) -> Result<Value, InterpreterError> {
     // Check __type marker to route to appropriate handler
     if let Some(Value::String(type_name)) = obj.get("__type") {
+        crate::runtime::restricted::check_host_object(type_name, method)?;
         return match &**type_name {
             "Command" => eval_command_method(obj, method, arg_values),
             "ExitStatus" => eval_exit_status_method(obj, method, arg_values),
@@ -6,17 +6,27 @@
 //! All functions maintain <10 cyclomatic complexity.
 
 use crate::frontend::ast::{BinaryOp as AstBinaryOp, UnaryOp};
+use crate::runtime::eval_bigint::{
+    bigint_equals, bigint_pow, eval_bigint_op, negate_bigint, promote_overflow,
+};
+use crate::runtime::eval_decimal::{decimal_equals, eval_decimal_op};
 use crate::runtime::{InterpreterError, Value};
 
 /// Evaluate a binary operation from AST
 ///
 /// # Complexity
-/// Cyclomatic complexity: 6 (within Toyota Way limits)
+/// Cyclomatic complexity: 8 (within Toyota Way limits)
 pub fn eval_binary_op(
     op: AstBinaryOp,
     left: &Value,
     right: &Value,
 ) -> Result<Value, InterpreterError> {
+    if let Some(result) = eval_decimal_op(op, left, right) {
+        return result;
+    }
+    if let Some(result) = eval_bigint_op(op, left, right) {
+        return result;
+    }
     match op {
         AstBinaryOp::Add
         | AstBinaryOp::Subtract
@@ -42,6 +52,91 @@ pub fn eval_binary_op(
     }
 }
 
+/// Fast path for arithmetic and comparisons on two integers or two floats
+///
+/// Returns `None` for every other operand/operator combination so the caller
+/// falls back to [`eval_binary_op`]. Results (including BigInt promotion on
+/// overflow and division-by-zero errors) are identical to the general path;
+/// the win is skipping the operator-family dispatch and per-site type
+/// feedback in hot numeric loops.
+///
+/// # Complexity
+/// Cyclomatic complexity: 3 (within Toyota Way limits)
+#[inline]
+pub fn eval_numeric_fast_path(
+    op: AstBinaryOp,
+    left: &Value,
+    right: &Value,
+) -> Option<Result<Value, InterpreterError>> {
+    match (left, right) {
+        (Value::Integer(a), Value::Integer(b)) => integer_fast_path(op, *a, *b),
+        (Value::Float(a), Value::Float(b)) => float_fast_path(op, *a, *b),
+        _ => None,
+    }
+}
+
+/// Integer half of [`eval_numeric_fast_path`]
+/// Complexity: 10 (flat operator table)
+#[inline]
+fn integer_fast_path(op: AstBinaryOp, a: i64, b: i64) -> Option<Result<Value, InterpreterError>> {
+    let checked = |result| integer_result(op, a, b, result);
+    Some(match op {
+        AstBinaryOp::Add => checked(a.checked_add(b)),
+        AstBinaryOp::Subtract => checked(a.checked_sub(b)),
+        AstBinaryOp::Multiply => checked(a.checked_mul(b)),
+        AstBinaryOp::Divide | AstBinaryOp::Modulo if b == 0 => {
+            Err(InterpreterError::DivisionByZero)
+        }
+        AstBinaryOp::Divide => checked(a.checked_div(b)),
+        // i64::MIN % -1 is 0; only the intermediate quotient overflows
+        AstBinaryOp::Modulo => Ok(Value::Integer(a.wrapping_rem(b))),
+        AstBinaryOp::Less => Ok(Value::Bool(a < b)),
+        AstBinaryOp::LessEqual => Ok(Value::Bool(a <= b)),
+        AstBinaryOp::Greater => Ok(Value::Bool(a > b)),
+        AstBinaryOp::GreaterEqual => Ok(Value::Bool(a >= b)),
+        AstBinaryOp::Equal => Ok(Value::Bool(a == b)),
+        AstBinaryOp::NotEqual => Ok(Value::Bool(a != b)),
+        _ => return None,
+    })
+}
+
+/// `i64` result of `a op b`, or the exact BigInt when it overflowed
+#[inline]
+fn integer_result(
+    op: AstBinaryOp,
+    a: i64,
+    b: i64,
+    result: Option<i64>,
+) -> Result<Value, InterpreterError> {
+    match result {
+        Some(n) => Ok(Value::Integer(n)),
+        None => promote_overflow(op, a, b)
+            .ok_or_else(|| InterpreterError::RuntimeError(format!("Integer overflow in {op:?}"))),
+    }
+}
+
+/// Float half of [`eval_numeric_fast_path`]
+/// Complexity: 10 (flat operator table)
+#[inline]
+#[allow(clippy::float_cmp)] // Mirrors equal_primitives
+fn float_fast_path(op: AstBinaryOp, a: f64, b: f64) -> Option<Result<Value, InterpreterError>> {
+    Some(match op {
+        AstBinaryOp::Add => Ok(Value::Float(a + b)),
+        AstBinaryOp::Subtract => Ok(Value::Float(a - b)),
+        AstBinaryOp::Multiply => Ok(Value::Float(a * b)),
+        AstBinaryOp::Divide => Ok(Value::Float(a / b)),
+        AstBinaryOp::Modulo if b == 0.0 => Err(InterpreterError::DivisionByZero),
+        AstBinaryOp::Modulo => Ok(Value::Float(a % b)),
+        AstBinaryOp::Less => Ok(Value::Bool(a < b)),
+        AstBinaryOp::LessEqual => Ok(Value::Bool(a <= b)),
+        AstBinaryOp::Greater => Ok(Value::Bool(a > b)),
+        AstBinaryOp::GreaterEqual => Ok(Value::Bool(a >= b)),
+        AstBinaryOp::Equal => Ok(Value::Bool(a == b)),
+        AstBinaryOp::NotEqual => Ok(Value::Bool(a != b)),
+        _ => return None,
+    })
+}
+
 /// Handle arithmetic operations
 ///
 /// # Complexity
@@ -160,7 +255,11 @@ fn eval_bitwise_op(
 pub fn eval_unary_op(op: UnaryOp, operand: &Value) -> Result<Value, InterpreterError> {
     match op {
         UnaryOp::Negate => match operand {
-            Value::Integer(i) => Ok(Value::Integer(-i)),
+            Value::Integer(i) => Ok(i
+                .checked_neg()
+                .map_or_else(|| negate_bigint(&(*i).into()), Value::Integer)),
+            Value::BigInt(n) => Ok(negate_bigint(n)),
+            Value::Decimal(d) => Ok(Value::Decimal(-*d)),
             Value::Float(f) => Ok(Value::Float(-f)),
             _ => Err(InterpreterError::TypeError(format!(
                 "Cannot negate {}",
@@ -200,9 +299,7 @@ pub fn eval_unary_op(op: UnaryOp, operand: &Value) -> Result<Value, InterpreterE
 fn add_values(left: &Value, right: &Value) -> Result<Value, InterpreterError> {
     match (left, right) {
         (Value::Integer(a), Value::Integer(b)) => {
-            a.checked_add(*b).map(Value::Integer).ok_or_else(|| {
-                InterpreterError::RuntimeError("Integer overflow in addition".to_string())
-            })
+            integer_result(AstBinaryOp::Add, *a, *b, a.checked_add(*b))
         }
         (Value::Float(a), Value::Float(b)) => Ok(Value::Float(a + b)),
         (Value::Integer(a), Value::Float(b)) =>
@@ -216,6 +313,7 @@ fn add_values(left: &Value, right: &Value) -> Result<Value, InterpreterError> {
             Ok(Value::Float(a + *b as f64))
         }
         (Value::String(a), Value::String(b)) => {
+            crate::runtime::restricted::check_size(a.len() + b.len())?;
             Ok(Value::from_string(format!("{}{}", a.as_ref(), b.as_ref())))
         }
         // Feature #88: String + Integer auto-conversion
@@ -240,6 +338,7 @@ fn add_values(left: &Value, right: &Value) -> Result<Value, InterpreterError> {
             Ok(Value::from_string(format!("{}{}", b, s.as_ref())))
         }
         (Value::Array(a), Value::Array(b)) => {
+            crate::runtime::restricted::check_size(a.len() + b.len())?;
             let mut result = a.as_ref().to_vec();
             result.extend_from_slice(b.as_ref());
             Ok(Value::from_array(result))
@@ -259,9 +358,7 @@ fn add_values(left: &Value, right: &Value) -> Result<Value, InterpreterError> {
 fn sub_values(left: &Value, right: &Value) -> Result<Value, InterpreterError> {
     match (left, right) {
         (Value::Integer(a), Value::Integer(b)) => {
-            a.checked_sub(*b).map(Value::Integer).ok_or_else(|| {
-                InterpreterError::RuntimeError("Integer overflow in subtraction".to_string())
-            })
+            integer_result(AstBinaryOp::Subtract, *a, *b, a.checked_sub(*b))
         }
         (Value::Float(a), Value::Float(b)) => Ok(Value::Float(a - b)),
         (Value::Integer(a), Value::Float(b)) =>
@@ -289,9 +386,7 @@ fn sub_values(left: &Value, right: &Value) -> Result<Value, InterpreterError> {
 fn mul_values(left: &Value, right: &Value) -> Result<Value, InterpreterError> {
     match (left, right) {
         (Value::Integer(a), Value::Integer(b)) => {
-            a.checked_mul(*b).map(Value::Integer).ok_or_else(|| {
-                InterpreterError::RuntimeError("Integer overflow in multiplication".to_string())
-            })
+            integer_result(AstBinaryOp::Multiply, *a, *b, a.checked_mul(*b))
         }
         (Value::Float(a), Value::Float(b)) => Ok(Value::Float(a * b)),
         (Value::Integer(a), Value::Float(b)) =>
@@ -310,6 +405,10 @@ fn mul_values(left: &Value, right: &Value) -> Result<Value, InterpreterError> {
             if *n <= 0 {
                 Ok(Value::String(std::sync::Arc::from("")))
             } else {
+                crate::runtime::restricted::check_size(
+                    s.len()
+                        .saturating_mul(usize::try_from(*n).unwrap_or(usize::MAX)),
+                )?;
                 let repeated = s.repeat(*n as usize);
                 Ok(Value::String(std::sync::Arc::from(repeated.as_str())))
             }
@@ -332,9 +431,7 @@ fn div_values(left: &Value, right: &Value) -> Result<Value, InterpreterError> {
             if *b == 0 {
                 return Err(InterpreterError::DivisionByZero);
             }
-            a.checked_div(*b).map(Value::Integer).ok_or_else(|| {
-                InterpreterError::RuntimeError("Integer overflow in division".to_string())
-            })
+            integer_result(AstBinaryOp::Divide, *a, *b, a.checked_div(*b))
         }
         (Value::Float(a), Value::Float(b)) => {
             // Float division by zero returns infinity per IEEE 754
@@ -375,11 +472,8 @@ fn modulo_values(left: &Value, right: &Value) -> Result<Value, InterpreterError>
     check_modulo_divisor_not_zero(right)?;
 
     match (left, right) {
-        (Value::Integer(a), Value::Integer(b)) => {
-            a.checked_rem(*b).map(Value::Integer).ok_or_else(|| {
-                InterpreterError::RuntimeError("Integer overflow in modulo".to_string())
-            })
-        }
+        // i64::MIN % -1 is 0; only the intermediate quotient overflows
+        (Value::Integer(a), Value::Integer(b)) => Ok(Value::Integer(a.wrapping_rem(*b))),
         (Value::Float(a), Value::Float(b)) => Ok(Value::Float(a % b)),
         (Value::Integer(a), Value::Float(b)) =>
         {
@@ -412,14 +506,10 @@ fn power_values(left: &Value, right: &Value) -> Result<Value, InterpreterError>
                 let result = (*a as f64).powf(*b as f64);
                 Ok(Value::Float(result))
             } else {
-                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-                if let Some(result) = a.checked_pow(*b as u32) {
-                    Ok(Value::Integer(result))
-                } else {
-                    // Overflow - convert to float
-                    #[allow(clippy::cast_precision_loss)]
-                    let result = (*a as f64).powf(*b as f64);
-                    Ok(Value::Float(result))
+                match u32::try_from(*b).ok().and_then(|exp| a.checked_pow(exp)) {
+                    Some(result) => Ok(Value::Integer(result)),
+                    // Overflow - promote to BigInt
+                    None => bigint_pow(&(*a).into(), &(*b).into()),
                 }
             }
         }
@@ -477,8 +567,34 @@ fn equal_values(left: &Value, right: &Value) -> bool {
         (Value::Struct { fields: f1, .. }, Value::Struct { fields: f2, .. }) => {
             equal_objects(f1, f2)
         }
+        // Decimal - exact against integers, through f64 against floats
+        (Value::Decimal(_), _) | (_, Value::Decimal(_)) => decimal_equals(left, right),
+        // BigInt - exact against integers of either width
+        (Value::BigInt(_), _) | (_, Value::BigInt(_)) => bigint_equals(left, right),
         // Atoms - string equality (interned comparison)
         (Value::Atom(a), Value::Atom(b)) => a == b,
+        // Errors - kind and message (stack traces ignored)
+        (Value::Error { .. }, Value::Error { .. }) => left == right,
+        // Enum variants - same variant of the same enum, equal payloads
+        (
+            Value::EnumVariant {
+                enum_name: e1,
+                variant_name: v1,
+                data: d1,
+            },
+            Value::EnumVariant {
+                enum_name: e2,
+                variant_name: v2,
+                data: d2,
+            },
+        ) => {
+            e1 == e2
+                && v1 == v2
+                && match (d1, d2) {
+                    (Some(a), Some(b)) => equal_slices(a, b),
+                    (a, b) => a.is_none() && b.is_none(),
+                }
+        }
         // Type mismatch
         _ => false,
     }
@@ -1152,8 +1268,8 @@ mod tests {
         // Large exponent that causes overflow
         let result =
             power_values(&Value::Integer(100), &Value::Integer(100)).expect("should succeed");
-        // Should convert to float on overflow
-        assert!(matches!(result, Value::Float(_)));
+        // Should promote to BigInt on overflow
+        assert_eq!(result.to_string(), format!("1{}", "0".repeat(200)));
     }
 
     #[test]
@@ -1723,4 +1839,52 @@ mod tests {
             eval_logical_op(AstBinaryOp::Or, &Value::Bool(false), &Value::Bool(false)).unwrap();
         assert!(!result.is_truthy());
     }
+
+    #[test]
+    fn test_numeric_fast_path_matches_general_path() {
+        let ops = [
+            AstBinaryOp::Add,
+            AstBinaryOp::Subtract,
+            AstBinaryOp::Multiply,
+            AstBinaryOp::Divide,
+            AstBinaryOp::Modulo,
+            AstBinaryOp::Less,
+            AstBinaryOp::LessEqual,
+            AstBinaryOp::Greater,
+            AstBinaryOp::GreaterEqual,
+            AstBinaryOp::Equal,
+            AstBinaryOp::NotEqual,
+        ];
+        let pairs = [
+            (Value::Integer(7), Value::Integer(-2)),
+            (Value::Integer(i64::MAX), Value::Integer(1)),
+            (Value::Integer(i64::MIN), Value::Integer(-1)),
+            (Value::Integer(3), Value::Integer(0)),
+            (Value::Float(7.5), Value::Float(2.0)),
+            (Value::Float(1.0), Value::Float(0.0)),
+            (Value::Float(f64::NAN), Value::Float(1.0)),
+        ];
+        for op in ops {
+            for (left, right) in &pairs {
+                let fast = eval_numeric_fast_path(op, left, right).expect("numeric pair");
+                let general = eval_binary_op(op, left, right);
+                assert_eq!(
+                    format!("{fast:?}"),
+                    format!("{general:?}"),
+                    "{op:?} {left:?} {right:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_numeric_fast_path_declines_other_operands() {
+        let int = Value::Integer(2);
+        let float = Value::Float(2.0);
+        assert!(eval_numeric_fast_path(AstBinaryOp::Add, &int, &float).is_none());
+        assert!(eval_numeric_fast_path(AstBinaryOp::Power, &int, &int).is_none());
+        assert!(eval_numeric_fast_path(AstBinaryOp::BitwiseAnd, &int, &int).is_none());
+        let text = Value::from_string("a".to_string());
+        assert!(eval_numeric_fast_path(AstBinaryOp::Multiply, &text, &int).is_none());
+    }
 }
@@ -0,0 +1,714 @@
+//! Built-in `Option` and `Result` values
+//!
+//! `Some(x)`, `None`, `Ok(x)` and `Err(e)` evaluate to [`Value::EnumVariant`]s
+//! of the `Option` and `Result` enums. They answer to the combinator methods
+//! of their Rust counterparts (`map`, `and_then`, `unwrap_or`, `ok_or`, ...)
+//! with the same semantics, so a program behaves the same interpreted and
+//! transpiled. Where Rust panics (`unwrap` on `None`, `expect` on an `Err`)
+//! the interpreter fails with the message Rust panics with.
+
+use crate::runtime::method_not_found::MethodNotFound;
+use crate::runtime::validation::validate_arg_count;
+use crate::runtime::{InterpreterError, Value};
+
+/// Methods of `Option` values
+pub const OPTION_METHODS: &[&str] = &[
+    "is_some",
+    "is_none",
+    "is_some_and",
+    "unwrap",
+    "expect",
+    "unwrap_or",
+    "unwrap_or_else",
+    "map",
+    "map_or",
+    "map_or_else",
+    "and_then",
+    "and",
+    "or",
+    "or_else",
+    "xor",
+    "filter",
+    "ok_or",
+    "ok_or_else",
+    "flatten",
+    "zip",
+    "as_ref",
+    "as_deref",
+    "cloned",
+    "copied",
+    "clone",
+    "to_string",
+];
+
+/// Methods of `Result` values
+pub const RESULT_METHODS: &[&str] = &[
+    "is_ok",
+    "is_err",
+    "is_ok_and",
+    "is_err_and",
+    "ok",
+    "err",
+    "unwrap",
+    "expect",
+    "unwrap_err",
+    "expect_err",
+    "unwrap_or",
+    "unwrap_or_else",
+    "map",
+    "map_err",
+    "map_or",
+    "map_or_else",
+    "and_then",
+    "and",
+    "or",
+    "or_else",
+    "as_ref",
+    "cloned",
+    "copied",
+    "clone",
+    "to_string",
+];
+
+/// `Some(value)`
+pub fn some(value: Value) -> Value {
+    variant("Option", "Some", Some(vec![value]))
+}
+
+/// `None`
+pub fn none() -> Value {
+    variant("Option", "None", None)
+}
+
+/// `Ok(value)`
+pub fn ok(value: Value) -> Value {
+    variant("Result", "Ok", Some(vec![value]))
+}
+
+/// `Err(error)`
+pub fn err(error: Value) -> Value {
+    variant("Result", "Err", Some(vec![error]))
+}
+
+fn variant(enum_name: &str, variant_name: &str, data: Option<Vec<Value>>) -> Value {
+    Value::EnumVariant {
+        enum_name: enum_name.to_string(),
+        variant_name: variant_name.to_string(),
+        data,
+    }
+}
+
+/// Whether `enum_name` is one of the built-in enums handled here
+pub fn is_builtin_enum(enum_name: &str) -> bool {
+    enum_name == "Option" || enum_name == "Result"
+}
+
+/// Evaluate a call of the constructor `name` (`Ok`, `Err`, `Some` and their
+/// qualified forms), or `None` if `name` is not one of them
+pub(crate) fn construct(name: &str, args: &[Value]) -> Option<Result<Value, InterpreterError>> {
+    let build: fn(Value) -> Value = match name {
+        "Ok" | "Result::Ok" => ok,
+        "Err" | "Result::Err" => err,
+        "Some" | "Option::Some" => some,
+        _ => return None,
+    };
+    Some(match args {
+        [value] => Ok(build(value.clone())),
+        _ => Err(InterpreterError::RuntimeError(format!(
+            "{name}() takes exactly 1 argument, got {}",
+            args.len()
+        ))),
+    })
+}
+
+/// Payload of an `Ok`/`Err` variant named `variant_name`, in either the enum
+/// representation or the legacy `{type, data}` message object
+pub(crate) fn result_payload<'a>(value: &'a Value, variant_name: &str) -> Option<&'a Value> {
+    match value {
+        Value::EnumVariant {
+            variant_name: name,
+            data,
+            ..
+        } if name == variant_name => data.as_ref().and_then(|data| data.first()),
+        Value::Object(fields) => match (fields.get("type"), fields.get("data")) {
+            (Some(Value::String(name)), Some(Value::Array(data))) if &**name == variant_name => {
+                data.first()
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// An `Option` or `Result` split into its variant and payload
+enum Variant {
+    Some(Value),
+    None,
+    Ok(Value),
+    Err(Value),
+}
+
+impl Variant {
+    fn of(value: &Value) -> Option<Self> {
+        let Value::EnumVariant {
+            enum_name,
+            variant_name,
+            data,
+        } = value
+        else {
+            return None;
+        };
+        let payload = || {
+            data.as_ref()
+                .and_then(|data| data.first())
+                .cloned()
+                .unwrap_or(Value::Nil)
+        };
+        match (enum_name.as_str(), variant_name.as_str()) {
+            ("Option", "Some") => Some(Self::Some(payload())),
+            ("Option", "None") => Some(Self::None),
+            ("Result", "Ok") => Some(Self::Ok(payload())),
+            ("Result", "Err") => Some(Self::Err(payload())),
+            _ => None,
+        }
+    }
+}
+
+/// Evaluate `method` on an `Option` or `Result` receiver
+///
+/// `call` applies a closure argument, as in the array and iterator methods.
+///
+/// # Errors
+///
+/// Returns an error where the Rust method panics, when an argument count is
+/// wrong, and [`InterpreterError::MethodNotFound`] for unknown methods.
+pub fn eval_option_result_method<F>(
+    receiver: &Value,
+    method: &str,
+    args: &[Value],
+    mut call: F,
+) -> Result<Value, InterpreterError>
+where
+    F: FnMut(&Value, &[Value]) -> Result<Value, InterpreterError>,
+{
+    if method == "to_string" && args.is_empty() {
+        return Ok(Value::from_string(receiver.to_string()));
+    }
+    match Variant::of(receiver) {
+        Some(Variant::Some(value)) => {
+            eval_option_method(Some(value), receiver, method, args, &mut call)
+        }
+        Some(Variant::None) => eval_option_method(None, receiver, method, args, &mut call),
+        Some(Variant::Ok(value)) => {
+            eval_result_method(Ok(value), receiver, method, args, &mut call)
+        }
+        Some(Variant::Err(error)) => {
+            eval_result_method(Err(error), receiver, method, args, &mut call)
+        }
+        None => Err(InterpreterError::RuntimeError(format!(
+            "Expected an Option or Result, got {}",
+            receiver.type_name()
+        ))),
+    }
+}
+
+/// Methods that read a value without consuming it return it unchanged
+fn is_identity(method: &str) -> bool {
+    matches!(
+        method,
+        "as_ref" | "as_deref" | "cloned" | "copied" | "clone"
+    )
+}
+
+fn eval_option_method<F>(
+    option: Option<Value>,
+    receiver: &Value,
+    method: &str,
+    args: &[Value],
+    call: &mut F,
+) -> Result<Value, InterpreterError>
+where
+    F: FnMut(&Value, &[Value]) -> Result<Value, InterpreterError>,
+{
+    if let Some(value) = eval_option_query(option.as_ref(), receiver, method, args, call)? {
+        return Ok(value);
+    }
+    match method {
+        "map" => {
+            validate_arg_count(method, args, 1)?;
+            option.map_or(Ok(none()), |value| Ok(some(call(&args[0], &[value])?)))
+        }
+        "map_or" => {
+            validate_arg_count(method, args, 2)?;
+            option.map_or(Ok(args[0].clone()), |value| call(&args[1], &[value]))
+        }
+        "map_or_else" => {
+            validate_arg_count(method, args, 2)?;
+            match option {
+                Some(value) => call(&args[1], &[value]),
+                None => call(&args[0], &[]),
+            }
+        }
+        "and_then" => {
+            validate_arg_count(method, args, 1)?;
+            option.map_or(Ok(none()), |value| call(&args[0], &[value]))
+        }
+        "filter" => {
+            validate_arg_count(method, args, 1)?;
+            match option {
+                Some(value) if call(&args[0], std::slice::from_ref(&value))?.is_truthy() => {
+                    Ok(some(value))
+                }
+                _ => Ok(none()),
+            }
+        }
+        "ok_or" => {
+            validate_arg_count(method, args, 1)?;
+            Ok(option.map_or_else(|| err(args[0].clone()), ok))
+        }
+        "ok_or_else" => {
+            validate_arg_count(method, args, 1)?;
+            option.map_or_else(|| Ok(err(call(&args[0], &[])?)), |value| Ok(ok(value)))
+        }
+        "flatten" => {
+            validate_arg_count(method, args, 0)?;
+            Ok(option.unwrap_or_else(none))
+        }
+        "zip" => {
+            validate_arg_count(method, args, 1)?;
+            match (option, Variant::of(&args[0])) {
+                (Some(a), Some(Variant::Some(b))) => Ok(some(Value::Tuple(vec![a, b].into()))),
+                _ => Ok(none()),
+            }
+        }
+        _ => Err(unknown_method("Option", method)),
+    }
+}
+
+/// `Option` methods that inspect the receiver or pick between it and an argument
+fn eval_option_query<F>(
+    option: Option<&Value>,
+    receiver: &Value,
+    method: &str,
+    args: &[Value],
+    call: &mut F,
+) -> Result<Option<Value>, InterpreterError>
+where
+    F: FnMut(&Value, &[Value]) -> Result<Value, InterpreterError>,
+{
+    let value = match method {
+        "is_some" | "is_none" => {
+            validate_arg_count(method, args, 0)?;
+            Value::Bool(option.is_some() == (method == "is_some"))
+        }
+        "is_some_and" => {
+            validate_arg_count(method, args, 1)?;
+            match option {
+                Some(value) => {
+                    Value::Bool(call(&args[0], std::slice::from_ref(value))?.is_truthy())
+                }
+                None => Value::Bool(false),
+            }
+        }
+        "unwrap" => {
+            validate_arg_count(method, args, 0)?;
+            option.cloned().ok_or_else(|| {
+                InterpreterError::RuntimeError(
+                    "called `Option::unwrap()` on a `None` value".to_string(),
+                )
+            })?
+        }
+        "expect" => {
+            validate_arg_count(method, args, 1)?;
+            option
+                .cloned()
+                .ok_or_else(|| InterpreterError::RuntimeError(message(&args[0])))?
+        }
+        "unwrap_or" => {
+            validate_arg_count(method, args, 1)?;
+            option.unwrap_or(&args[0]).clone()
+        }
+        "unwrap_or_else" => {
+            validate_arg_count(method, args, 1)?;
+            match option {
+                Some(value) => value.clone(),
+                None => call(&args[0], &[])?,
+            }
+        }
+        "and" => {
+            validate_arg_count(method, args, 1)?;
+            if option.is_some() {
+                args[0].clone()
+            } else {
+                none()
+            }
+        }
+        "or" => {
+            validate_arg_count(method, args, 1)?;
+            if option.is_some() {
+                receiver.clone()
+            } else {
+                args[0].clone()
+            }
+        }
+        "or_else" => {
+            validate_arg_count(method, args, 1)?;
+            if option.is_some() {
+                receiver.clone()
+            } else {
+                call(&args[0], &[])?
+            }
+        }
+        "xor" => {
+            validate_arg_count(method, args, 1)?;
+            let other_is_some = matches!(Variant::of(&args[0]), Some(Variant::Some(_)));
+            match (option.is_some(), other_is_some) {
+                (true, false) => receiver.clone(),
+                (false, true) => args[0].clone(),
+                _ => none(),
+            }
+        }
+        _ if is_identity(method) => {
+            validate_arg_count(method, args, 0)?;
+            receiver.clone()
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(value))
+}
+
+fn eval_result_method<F>(
+    result: Result<Value, Value>,
+    receiver: &Value,
+    method: &str,
+    args: &[Value],
+    call: &mut F,
+) -> Result<Value, InterpreterError>
+where
+    F: FnMut(&Value, &[Value]) -> Result<Value, InterpreterError>,
+{
+    if let Some(value) = eval_result_query(&result, receiver, method, args, call)? {
+        return Ok(value);
+    }
+    match (method, result) {
+        ("map", Ok(value)) => {
+            validate_arg_count(method, args, 1)?;
+            Ok(ok(call(&args[0], &[value])?))
+        }
+        ("map_err", Err(error)) => {
+            validate_arg_count(method, args, 1)?;
+            Ok(err(call(&args[0], &[error])?))
+        }
+        ("map" | "map_err", _) => {
+            validate_arg_count(method, args, 1)?;
+            Ok(receiver.clone())
+        }
+        ("map_or", result) => {
+            validate_arg_count(method, args, 2)?;
+            match result {
+                Ok(value) => call(&args[1], &[value]),
+                Err(_) => Ok(args[0].clone()),
+            }
+        }
+        ("map_or_else", result) => {
+            validate_arg_count(method, args, 2)?;
+            match result {
+                Ok(value) => call(&args[1], &[value]),
+                Err(error) => call(&args[0], &[error]),
+            }
+        }
+        ("and_then", result) => {
+            validate_arg_count(method, args, 1)?;
+            match result {
+                Ok(value) => call(&args[0], &[value]),
+                Err(_) => Ok(receiver.clone()),
+            }
+        }
+        ("or_else", result) => {
+            validate_arg_count(method, args, 1)?;
+            match result {
+                Ok(_) => Ok(receiver.clone()),
+                Err(error) => call(&args[0], &[error]),
+            }
+        }
+        ("unwrap_or_else", result) => {
+            validate_arg_count(method, args, 1)?;
+            match result {
+                Ok(value) => Ok(value),
+                Err(error) => call(&args[0], &[error]),
+            }
+        }
+        _ => Err(unknown_method("Result", method)),
+    }
+}
+
+/// `Result` methods that inspect the receiver or pick between it and an argument
+fn eval_result_query<F>(
+    result: &Result<Value, Value>,
+    receiver: &Value,
+    method: &str,
+    args: &[Value],
+    call: &mut F,
+) -> Result<Option<Value>, InterpreterError>
+where
+    F: FnMut(&Value, &[Value]) -> Result<Value, InterpreterError>,
+{
+    let value = match (method, result) {
+        ("is_ok" | "is_err", _) => {
+            validate_arg_count(method, args, 0)?;
+            Value::Bool(result.is_ok() == (method == "is_ok"))
+        }
+        ("is_ok_and", Ok(value)) | ("is_err_and", Err(value)) => {
+            validate_arg_count(method, args, 1)?;
+            Value::Bool(call(&args[0], std::slice::from_ref(value))?.is_truthy())
+        }
+        ("is_ok_and" | "is_err_and", _) => {
+            validate_arg_count(method, args, 1)?;
+            Value::Bool(false)
+        }
+        ("ok", Ok(value)) | ("err", Err(value)) => {
+            validate_arg_count(method, args, 0)?;
+            some(value.clone())
+        }
+        ("ok" | "err", _) => {
+            validate_arg_count(method, args, 0)?;
+            none()
+        }
+        ("unwrap", Ok(value)) | ("unwrap_err", Err(value)) => {
+            validate_arg_count(method, args, 0)?;
+            value.clone()
+        }
+        ("unwrap", Err(error)) => {
+            validate_arg_count(method, args, 0)?;
+            return Err(InterpreterError::RuntimeError(format!(
+                "called `Result::unwrap()` on an `Err` value: {error}"
+            )));
+        }
+        ("unwrap_err", Ok(value)) => {
+            validate_arg_count(method, args, 0)?;
+            return Err(InterpreterError::RuntimeError(format!(
+                "called `Result::unwrap_err()` on an `Ok` value: {value}"
+            )));
+        }
+        ("expect", Ok(value)) | ("expect_err", Err(value)) => {
+            validate_arg_count(method, args, 1)?;
+            value.clone()
+        }
+        ("expect", Err(unexpected)) | ("expect_err", Ok(unexpected)) => {
+            validate_arg_count(method, args, 1)?;
+            return Err(InterpreterError::RuntimeError(format!(
+                "{}: {}",
+                message(&args[0]),
+                message(unexpected)
+            )));
+        }
+        ("unwrap_or", _) => {
+            validate_arg_count(method, args, 1)?;
+            result
+                .as_ref()
+                .map_or_else(|_| args[0].clone(), Clone::clone)
+        }
+        ("and", _) => {
+            validate_arg_count(method, args, 1)?;
+            if result.is_ok() {
+                args[0].clone()
+            } else {
+                receiver.clone()
+            }
+        }
+        ("or", _) => {
+            validate_arg_count(method, args, 1)?;
+            if result.is_ok() {
+                receiver.clone()
+            } else {
+                args[0].clone()
+            }
+        }
+        _ if is_identity(method) => {
+            validate_arg_count(method, args, 0)?;
+            receiver.clone()
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(value))
+}
+
+/// The text of an `expect` message or payload, without the quotes strings display with
+fn message(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn unknown_method(enum_name: &str, method: &str) -> InterpreterError {
+    let methods = if enum_name == "Option" {
+        OPTION_METHODS
+    } else {
+        RESULT_METHODS
+    };
+    InterpreterError::MethodNotFound(Box::new(MethodNotFound::new(enum_name, method, methods)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Applies `double` and `is_even` closures, stood in for by builtin names
+    fn call(func: &Value, args: &[Value]) -> Result<Value, InterpreterError> {
+        match (func, args) {
+            (Value::BuiltinFunction(name), [Value::Integer(n)]) if name == "double" => {
+                Ok(Value::Integer(n * 2))
+            }
+            (Value::BuiltinFunction(name), [Value::Integer(n)]) if name == "is_even" => {
+                Ok(Value::Bool(n % 2 == 0))
+            }
+            (Value::BuiltinFunction(name), []) if name == "seven" => Ok(Value::Integer(7)),
+            _ => Err(InterpreterError::RuntimeError(
+                "unexpected call".to_string(),
+            )),
+        }
+    }
+
+    fn func(name: &str) -> Value {
+        Value::BuiltinFunction(name.to_string())
+    }
+
+    fn eval(receiver: &Value, method: &str, args: &[Value]) -> Result<Value, InterpreterError> {
+        eval_option_result_method(receiver, method, args, call)
+    }
+
+    #[test]
+    fn test_option_combinators() {
+        let two = some(Value::Integer(2));
+        assert_eq!(
+            eval(&two, "map", &[func("double")]).unwrap(),
+            some(Value::Integer(4))
+        );
+        assert_eq!(eval(&none(), "map", &[func("double")]).unwrap(), none());
+        assert_eq!(
+            eval(&two, "filter", &[func("is_even")]).unwrap(),
+            two.clone()
+        );
+        assert_eq!(
+            eval(&none(), "unwrap_or_else", &[func("seven")]).unwrap(),
+            Value::Integer(7)
+        );
+        assert_eq!(
+            eval(
+                &none(),
+                "ok_or",
+                &[Value::from_string("missing".to_string())]
+            )
+            .unwrap(),
+            err(Value::from_string("missing".to_string()))
+        );
+        assert_eq!(
+            eval(&two, "zip", &[some(Value::Integer(3))]).unwrap(),
+            some(Value::Tuple(
+                vec![Value::Integer(2), Value::Integer(3)].into()
+            ))
+        );
+        assert_eq!(eval(&two, "xor", &[two.clone()]).unwrap(), none());
+    }
+
+    #[test]
+    fn test_result_combinators() {
+        let four = ok(Value::Integer(4));
+        let failed = err(Value::Integer(1));
+        assert_eq!(
+            eval(&four, "and_then", &[func("double")]).unwrap(),
+            Value::Integer(8)
+        );
+        assert_eq!(
+            eval(&failed, "map", &[func("double")]).unwrap(),
+            failed.clone()
+        );
+        assert_eq!(
+            eval(&failed, "map_err", &[func("double")]).unwrap(),
+            err(Value::Integer(2))
+        );
+        assert_eq!(eval(&four, "ok", &[]).unwrap(), some(Value::Integer(4)));
+        assert_eq!(eval(&four, "err", &[]).unwrap(), none());
+        assert_eq!(
+            eval(&failed, "unwrap_or", &[Value::Integer(0)]).unwrap(),
+            Value::Integer(0)
+        );
+        assert_eq!(
+            eval(&failed, "is_err_and", &[func("is_even")]).unwrap(),
+            Value::Bool(false)
+        );
+    }
+
+    #[test]
+    fn test_unwrap_fails_with_rust_panic_messages() {
+        let message = |result: Result<Value, InterpreterError>| match result {
+            Err(InterpreterError::RuntimeError(message)) => message,
+            other => panic!("expected a runtime error, got {other:?}"),
+        };
+        assert_eq!(
+            message(eval(&none(), "unwrap", &[])),
+            "called `Option::unwrap()` on a `None` value"
+        );
+        assert_eq!(
+            message(eval(
+                &err(Value::from_string("boom".to_string())),
+                "unwrap",
+                &[]
+            )),
+            "called `Result::unwrap()` on an `Err` value: \"boom\""
+        );
+        assert_eq!(
+            message(eval(
+                &err(Value::Integer(3)),
+                "expect",
+                &[Value::from_string("config".to_string())]
+            )),
+            "config: 3"
+        );
+    }
+
+    #[test]
+    fn test_constructors_and_unknown_methods() {
+        assert_eq!(
+            construct("Result::Ok", &[Value::Integer(1)])
+                .unwrap()
+                .unwrap(),
+            ok(Value::Integer(1))
+        );
+        assert!(construct("Some", &[]).unwrap().is_err());
+        assert!(construct("Other", &[]).is_none());
+
+        match eval(&none(), "unwrap_or_defualt", &[]) {
+            Err(InterpreterError::MethodNotFound(missing)) => {
+                assert_eq!(missing.receiver_type, "Option");
+            }
+            other => panic!("expected MethodNotFound, got {other:?}"),
+        }
+        assert_eq!(
+            eval(&some(Value::Integer(1)), "to_string", &[]).unwrap(),
+            Value::from_string("Some(1)".to_string())
+        );
+    }
+
+    #[test]
+    fn test_result_payload_reads_both_representations() {
+        let legacy = Value::Object(std::sync::Arc::new(
+            [
+                ("type".to_string(), Value::from_string("Err".to_string())),
+                (
+                    "data".to_string(),
+                    Value::from_array(vec![Value::Integer(5)]),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        ));
+        assert_eq!(result_payload(&legacy, "Err"), Some(&Value::Integer(5)));
+        assert_eq!(result_payload(&legacy, "Ok"), None);
+        assert_eq!(
+            result_payload(&ok(Value::Integer(6)), "Ok"),
+            Some(&Value::Integer(6))
+        );
+    }
+}
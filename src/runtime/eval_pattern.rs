@@ -7,6 +7,7 @@
 //! All functions maintain <10 cyclomatic complexity.
 
 use crate::frontend::ast::{Literal, Pattern, StructPatternField};
+use crate::runtime::eval_option_result::result_payload;
 use crate::runtime::pattern_matching::values_equal;
 use crate::runtime::{InterpreterError, Value};
 use std::collections::HashMap;
@@ -78,6 +79,8 @@ pub fn match_pattern(
         }
         Pattern::Ok(inner_pattern) => match_ok_pattern(inner_pattern, value),
         Pattern::Err(inner_pattern) => match_err_pattern(inner_pattern, value),
+        Pattern::Some(inner_pattern) => match_some_pattern(inner_pattern, value),
+        Pattern::None => Ok(match_none_pattern(value)),
         _ => Ok(PatternMatchResult::failure()), // Other patterns not implemented yet
     }
 }
@@ -212,10 +215,12 @@ fn match_struct_pattern(
 fn eval_pattern_literal(literal: &Literal) -> Result<Value, InterpreterError> {
     match literal {
         Literal::Integer(n, _) => Ok(Value::Integer(*n)),
+        Literal::BigInt(digits) => Ok(crate::runtime::eval_bigint::eval_bigint_literal(digits)),
+        Literal::Decimal(text) => Ok(crate::runtime::eval_decimal::eval_decimal_literal(text)),
         Literal::Float(f) => Ok(Value::Float(*f)),
-        Literal::String(s) => Ok(Value::from_string(s.clone())),
+        Literal::String(s) => Ok(Value::from_interned(s)),
         Literal::Bool(b) => Ok(Value::Bool(*b)),
-        Literal::Char(c) => Ok(Value::from_string(c.to_string())),
+        Literal::Char(c) => Ok(Value::from_char(*c)),
         Literal::Byte(b) => Ok(Value::Byte(*b)),
         Literal::Unit => Ok(Value::nil()),
         Literal::Null => Ok(Value::nil()),
@@ -297,24 +302,16 @@ pub fn check_pattern_exhaustiveness(
 /// Helper: Match Result pattern (Ok or Err)
 ///
 /// # Complexity
-/// Cyclomatic complexity: 3 (within Toyota Way limits)
+/// Cyclomatic complexity: 2 (within Toyota Way limits)
 fn match_result_pattern(
     inner_pattern: &Pattern,
     value: &Value,
-    expected_type: &str,
+    expected_variant: &str,
 ) -> Result<PatternMatchResult, InterpreterError> {
-    let fields = match_extract_object_fields(value)?;
-
-    if !match_has_type(fields, expected_type) {
-        return Ok(PatternMatchResult::failure());
-    }
-
-    let data = match_extract_data_array(fields)?;
-    if data.is_empty() {
-        return Ok(PatternMatchResult::failure());
+    match result_payload(value, expected_variant) {
+        Some(data) => match_pattern(inner_pattern, data),
+        None => Ok(PatternMatchResult::failure()),
     }
-
-    match_pattern(inner_pattern, &data[0])
 }
 
 /// Match Ok pattern - Result success case
@@ -328,61 +325,50 @@ fn match_ok_pattern(
     match_result_pattern(inner_pattern, value, "Ok")
 }
 
-/// Extract object fields from Value
+/// Match Err pattern - Result error case
 ///
 /// # Complexity
-/// Cyclomatic complexity: 2 (within Toyota Way limits)
-fn match_extract_object_fields(
+/// Cyclomatic complexity: 1 (reduced via helper extraction)
+fn match_err_pattern(
+    inner_pattern: &Pattern,
     value: &Value,
-) -> Result<&std::collections::HashMap<String, Value>, InterpreterError> {
-    if let Value::Object(fields) = value {
-        Ok(fields)
-    } else {
-        Err(InterpreterError::RuntimeError(
-            "Expected Object value for Result pattern".to_string(),
-        ))
-    }
+) -> Result<PatternMatchResult, InterpreterError> {
+    match_result_pattern(inner_pattern, value, "Err")
 }
 
-/// Check if object has type field matching expected type
+/// Match Some pattern - Option with a value
 ///
 /// # Complexity
-/// Cyclomatic complexity: 3 (within Toyota Way limits)
-fn match_has_type(fields: &std::collections::HashMap<String, Value>, expected: &str) -> bool {
-    if let Some(Value::String(type_str)) = fields.get("type") {
-        &**type_str == expected
-    } else {
-        false
+/// Cyclomatic complexity: 2 (within Toyota Way limits)
+fn match_some_pattern(
+    inner_pattern: &Pattern,
+    value: &Value,
+) -> Result<PatternMatchResult, InterpreterError> {
+    match value {
+        Value::EnumVariant {
+            variant_name,
+            data: Some(data),
+            ..
+        } if variant_name == "Some" && data.len() == 1 => match_pattern(inner_pattern, &data[0]),
+        _ => Ok(PatternMatchResult::failure()),
     }
 }
 
-/// Extract data array from object fields
+/// Match None pattern - empty Option
 ///
 /// # Complexity
 /// Cyclomatic complexity: 2 (within Toyota Way limits)
-fn match_extract_data_array(
-    fields: &std::collections::HashMap<String, Value>,
-) -> Result<&[Value], InterpreterError> {
-    if let Some(Value::Array(data)) = fields.get("data") {
-        Ok(data)
-    } else {
-        Err(InterpreterError::RuntimeError(
-            "Expected data array in Result value".to_string(),
-        ))
+fn match_none_pattern(value: &Value) -> PatternMatchResult {
+    match value {
+        Value::EnumVariant {
+            variant_name,
+            data: None,
+            ..
+        } if variant_name == "None" => PatternMatchResult::success_no_bindings(),
+        _ => PatternMatchResult::failure(),
     }
 }
 
-/// Match Err pattern - Result error case
-///
-/// # Complexity
-/// Cyclomatic complexity: 1 (reduced via helper extraction)
-fn match_err_pattern(
-    inner_pattern: &Pattern,
-    value: &Value,
-) -> Result<PatternMatchResult, InterpreterError> {
-    match_result_pattern(inner_pattern, value, "Err")
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
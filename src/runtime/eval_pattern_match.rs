@@ -6,6 +6,7 @@
 //! All functions maintain <10 cyclomatic complexity.
 
 use crate::frontend::ast::{Literal, Pattern, StructPatternField};
+use crate::runtime::eval_option_result::result_payload;
 use crate::runtime::{InterpreterError, Value};
 
 /// Try to match a pattern against a value, returning bindings if successful
@@ -63,7 +64,20 @@ pub fn try_pattern_match(
         Pattern::Struct { name, fields, .. } => {
             try_match_struct_pattern(name, fields, value, eval_literal)
         }
-        _ => Ok(None), // Other patterns not yet implemented
+        // A bare rest outside a sequence matches anything without binding
+        Pattern::Rest | Pattern::RestNamed(_) => Ok(Some(vec![])),
+        Pattern::WithDefault { pattern, .. } | Pattern::Mut(pattern) => {
+            try_pattern_match(pattern, value, eval_literal)
+        }
+        Pattern::StringAffix {
+            prefix,
+            rest,
+            suffix,
+        } => match string_affix_rest(prefix, suffix, value) {
+            Some(middle) => try_pattern_match(rest, &middle, eval_literal),
+            None => Ok(None),
+        },
+        Pattern::Regex(regex) => try_match_regex_pattern(regex, value),
     }
 }
 
@@ -89,19 +103,9 @@ fn try_match_tuple_pattern(
     eval_literal: &dyn Fn(&Literal) -> Value,
 ) -> Result<Option<Vec<(String, Value)>>, InterpreterError> {
     if let Value::Tuple(tuple_values) = value {
-        if patterns.len() != tuple_values.len() {
-            return Ok(None);
-        }
-
-        let mut all_bindings = Vec::new();
-        for (pattern, val) in patterns.iter().zip(tuple_values.iter()) {
-            if let Some(bindings) = try_pattern_match(pattern, val, &eval_literal)? {
-                all_bindings.extend(bindings);
-            } else {
-                return Ok(None);
-            }
-        }
-        Ok(Some(all_bindings))
+        try_match_elements(patterns, tuple_values, eval_literal, |rest| {
+            Value::Tuple(rest.into())
+        })
     } else {
         Ok(None)
     }
@@ -128,34 +132,47 @@ fn match_pattern_sequence(
     Ok(Some(bindings))
 }
 
-/// Match a list pattern with a rest element (e.g., [first, ..rest, last])
-fn try_match_list_with_rest(
+/// Match a pattern list containing a rest element (e.g., `[first, ..rest, last]`)
+///
+/// `collect_rest` builds the value bound by a named rest (array for lists,
+/// tuple for tuples and variant payloads).
+fn try_match_with_rest(
     patterns: &[Pattern],
     rest_idx: usize,
-    array_values: &[Value],
+    values: &[Value],
     eval_literal: &dyn Fn(&Literal) -> Value,
+    collect_rest: fn(Vec<Value>) -> Value,
 ) -> Result<Option<Vec<(String, Value)>>, InterpreterError> {
     let before_rest = &patterns[..rest_idx];
     let after_rest = &patterns[rest_idx + 1..];
+    if after_rest
+        .iter()
+        .any(|p| matches!(p, Pattern::Rest | Pattern::RestNamed(_)))
+    {
+        return Err(InterpreterError::RuntimeError(
+            "Only one rest pattern (..) is allowed per pattern".to_string(),
+        ));
+    }
     let min_len = before_rest.len() + after_rest.len();
 
-    if array_values.len() < min_len {
+    if values.len() < min_len {
         return Ok(None);
     }
 
-    let mut all_bindings = match match_pattern_sequence(before_rest, array_values, eval_literal)? {
+    let mut all_bindings = match match_pattern_sequence(before_rest, values, eval_literal)? {
         Some(b) => b,
         None => return Ok(None),
     };
 
-    let rest_end = array_values.len() - after_rest.len();
-    let rest_values: Vec<Value> = array_values[rest_idx..rest_end].to_vec();
-
+    let rest_end = values.len() - after_rest.len();
     if let Pattern::RestNamed(name) = &patterns[rest_idx] {
-        all_bindings.push((name.clone(), Value::Array(rest_values.into())));
+        all_bindings.push((
+            name.clone(),
+            collect_rest(values[rest_idx..rest_end].to_vec()),
+        ));
     }
 
-    match match_pattern_sequence(after_rest, &array_values[rest_end..], eval_literal)? {
+    match match_pattern_sequence(after_rest, &values[rest_end..], eval_literal)? {
         Some(b) => {
             all_bindings.extend(b);
             Ok(Some(all_bindings))
@@ -164,26 +181,34 @@ fn try_match_list_with_rest(
     }
 }
 
-fn try_match_list_pattern(
+/// Match positional element patterns, honouring an optional rest element
+fn try_match_elements(
     patterns: &[Pattern],
-    value: &Value,
+    values: &[Value],
     eval_literal: &dyn Fn(&Literal) -> Value,
+    collect_rest: fn(Vec<Value>) -> Value,
 ) -> Result<Option<Vec<(String, Value)>>, InterpreterError> {
-    let array_values = match value {
-        Value::Array(a) => a,
-        _ => return Ok(None),
-    };
-
     let rest_pos = patterns
         .iter()
         .position(|p| matches!(p, Pattern::Rest | Pattern::RestNamed(_)));
 
     if let Some(rest_idx) = rest_pos {
-        try_match_list_with_rest(patterns, rest_idx, array_values, eval_literal)
-    } else if patterns.len() != array_values.len() {
+        try_match_with_rest(patterns, rest_idx, values, eval_literal, collect_rest)
+    } else if patterns.len() != values.len() {
         Ok(None)
     } else {
-        match_pattern_sequence(patterns, array_values, eval_literal)
+        match_pattern_sequence(patterns, values, eval_literal)
+    }
+}
+
+fn try_match_list_pattern(
+    patterns: &[Pattern],
+    value: &Value,
+    eval_literal: &dyn Fn(&Literal) -> Value,
+) -> Result<Option<Vec<(String, Value)>>, InterpreterError> {
+    match value {
+        Value::Array(a) => try_match_elements(patterns, a, eval_literal, Value::from_array),
+        _ => Ok(None),
     }
 }
 
@@ -244,42 +269,64 @@ fn try_match_none_pattern(value: &Value) -> Result<Option<Vec<(String, Value)>>,
     Ok(None)
 }
 
-/// Try to match an Ok pattern
+/// The part of a string between `prefix` and `suffix`, if it has both
 ///
-/// Supports both `EnumVariant` (Issue #85) and legacy Object representations
+/// The two may not overlap: `"ab"` does not match `"ab" ++ rest ++ "b"`.
 ///
 /// # Complexity
-/// Cyclomatic complexity: 7 (within Toyota Way limits)
-/// Extract the first data element from a Result-like value (Ok or Err variant)
-fn extract_result_variant_data<'a>(value: &'a Value, expected_variant: &str) -> Option<&'a Value> {
-    // EnumVariant representation (Issue #85)
-    if let Value::EnumVariant {
-        variant_name, data, ..
-    } = value
-    {
-        if variant_name == expected_variant {
-            return data.as_ref().and_then(|v| v.first());
-        }
-    }
-    // Legacy Object representation {data: [x], type: "Ok"/"Err"}
-    if let Value::Object(fields) = value {
-        if let Some(Value::String(type_str)) = fields.get("type") {
-            if &**type_str == expected_variant {
-                if let Some(Value::Array(data)) = fields.get("data") {
-                    return data.first();
-                }
-            }
-        }
-    }
-    None
+/// Cyclomatic complexity: 2 (within Toyota Way limits)
+pub(crate) fn string_affix_rest(prefix: &str, suffix: &str, value: &Value) -> Option<Value> {
+    let Value::String(text) = value else {
+        return None;
+    };
+    let middle = text.strip_prefix(prefix)?.strip_suffix(suffix)?;
+    Some(Value::from_string(middle.to_string()))
 }
 
+/// Try to match a regex pattern against a whole string
+///
+/// Binds each named group to the text it matched, or to nil when an
+/// optional group took no part in the match.
+///
+/// # Complexity
+/// Cyclomatic complexity: 3 (within Toyota Way limits)
+pub(crate) fn try_match_regex_pattern(
+    regex: &str,
+    value: &Value,
+) -> Result<Option<Vec<(String, Value)>>, InterpreterError> {
+    let Value::String(text) = value else {
+        return Ok(None);
+    };
+    let anchored = crate::runtime::pattern_cache::cached_regex(&format!(r"\A(?:{regex})\z"))
+        .map_err(|e| InterpreterError::RuntimeError(format!("Invalid regex pattern: {e}")))?;
+    let Some(captures) = anchored.captures(text) else {
+        return Ok(None);
+    };
+    let bindings = anchored
+        .capture_names()
+        .flatten()
+        .map(|name| {
+            let group = captures.name(name).map_or(Value::Nil, |found| {
+                Value::from_string(found.as_str().to_string())
+            });
+            (name.to_string(), group)
+        })
+        .collect();
+    Ok(Some(bindings))
+}
+
+/// Try to match an Ok pattern
+///
+/// Supports both `EnumVariant` (Issue #85) and legacy Object representations
+///
+/// # Complexity
+/// Cyclomatic complexity: 2 (within Toyota Way limits)
 fn try_match_ok_pattern(
     inner_pattern: &Pattern,
     value: &Value,
     eval_literal: &dyn Fn(&Literal) -> Value,
 ) -> Result<Option<Vec<(String, Value)>>, InterpreterError> {
-    match extract_result_variant_data(value, "Ok") {
+    match result_payload(value, "Ok") {
         Some(data) => try_pattern_match(inner_pattern, data, eval_literal),
         None => Ok(None),
     }
@@ -290,7 +337,7 @@ fn try_match_err_pattern(
     value: &Value,
     eval_literal: &dyn Fn(&Literal) -> Value,
 ) -> Result<Option<Vec<(String, Value)>>, InterpreterError> {
-    match extract_result_variant_data(value, "Err") {
+    match result_payload(value, "Err") {
         Some(data) => try_pattern_match(inner_pattern, data, eval_literal),
         None => Ok(None),
     }
@@ -307,11 +354,13 @@ fn try_match_qualified_name_pattern(
     value: &Value,
 ) -> Result<Option<Vec<(String, Value)>>, InterpreterError> {
     if let Value::EnumVariant {
-        variant_name, data, ..
+        enum_name,
+        variant_name,
+        data,
     } = value
     {
         // Check if variant is unit (no data) and name matches
-        if data.is_none() && path.last() == Some(variant_name) {
+        if data.is_none() && path_names_variant(path, enum_name, variant_name) {
             return Ok(Some(vec![]));
         }
     }
@@ -330,27 +379,23 @@ fn try_match_tuple_variant_pattern(
     value: &Value,
     eval_literal: &dyn Fn(&Literal) -> Value,
 ) -> Result<Option<Vec<(String, Value)>>, InterpreterError> {
-    let (variant_name, data) = match value {
+    let data = match value {
         Value::EnumVariant {
-            variant_name, data, ..
-        } => (variant_name, data),
+            enum_name,
+            variant_name,
+            data,
+        } if path_names_variant(path, enum_name, variant_name) => data,
         _ => return Ok(None),
     };
 
-    if path.last() != Some(variant_name) {
-        return Ok(None);
-    }
-
     let variant_data = match data {
         Some(d) => d,
         None => return Ok(None),
     };
 
-    if patterns.len() != variant_data.len() {
-        return Ok(None);
-    }
-
-    match_pattern_sequence(patterns, variant_data, eval_literal)
+    try_match_elements(patterns, variant_data, eval_literal, |rest| {
+        Value::Tuple(rest.into())
+    })
 }
 
 /// Match a literal pattern
@@ -491,26 +536,31 @@ fn match_struct_field(
     }
 }
 
-fn try_match_struct_pattern(
-    struct_name: &str,
+/// Whether a pattern path (`Shape::Circle`, `Circle`) names the given variant.
+///
+/// The final segment must equal the variant; when the path is qualified the
+/// preceding segment must also name the value's enum.
+fn path_names_variant(path: &[String], enum_name: &str, variant_name: &str) -> bool {
+    match path {
+        [.., owner, last] => last == variant_name && (enum_name.is_empty() || owner == enum_name),
+        [last] => last == variant_name,
+        [] => false,
+    }
+}
+
+/// Match struct field patterns against a field lookup
+fn match_struct_fields(
     field_patterns: &[StructPatternField],
-    value: &Value,
+    lookup: &dyn Fn(&str) -> Option<Value>,
     eval_literal: &dyn Fn(&Literal) -> Value,
 ) -> Result<Option<Vec<(String, Value)>>, InterpreterError> {
-    let struct_fields = match value {
-        Value::Struct { name, fields } if name == struct_name => fields,
-        Value::Object(fields) => fields,
-        _ => return Ok(None),
-    };
-
     let mut all_bindings = Vec::new();
     for field_pattern in field_patterns {
-        let field_value = match struct_fields.get(&field_pattern.name) {
-            Some(v) => v,
-            None => return Ok(None),
+        let Some(field_value) = lookup(&field_pattern.name) else {
+            return Ok(None);
         };
 
-        match match_struct_field(field_pattern, field_value, eval_literal)? {
+        match match_struct_field(field_pattern, &field_value, eval_literal)? {
             Some(bindings) => all_bindings.extend(bindings),
             None => return Ok(None),
         }
@@ -519,6 +569,64 @@ fn try_match_struct_pattern(
     Ok(Some(all_bindings))
 }
 
+/// Try to match a struct pattern
+///
+/// Handles plain structs (`Point { x, y }`), class instances, objects and
+/// struct-like enum variants (`Shape::Circle { radius }`). An empty pattern
+/// name (`{ x, y }`) matches any record with the named fields. Error values
+/// match on their kind (`RuntimeError { message }`), and a bare enum name
+/// with no fields (`AppError { .. }`) matches any variant of that enum.
+///
+/// # Complexity
+/// Cyclomatic complexity: 9 (within Toyota Way limits)
+fn try_match_struct_pattern(
+    struct_name: &str,
+    field_patterns: &[StructPatternField],
+    value: &Value,
+    eval_literal: &dyn Fn(&Literal) -> Value,
+) -> Result<Option<Vec<(String, Value)>>, InterpreterError> {
+    let path: Vec<String> = struct_name.split("::").map(ToString::to_string).collect();
+    let names_type = |type_name: &str| {
+        struct_name.is_empty() || path.last().is_some_and(|last| last == type_name)
+    };
+
+    match value {
+        Value::Struct { name, fields } if names_type(name) => {
+            match_struct_fields(field_patterns, &|k| fields.get(k).cloned(), eval_literal)
+        }
+        Value::Class {
+            class_name, fields, ..
+        } if names_type(class_name) => {
+            let fields = fields.read().map_err(|_| {
+                InterpreterError::RuntimeError("Class fields lock poisoned".to_string())
+            })?;
+            match_struct_fields(field_patterns, &|k| fields.get(k).cloned(), eval_literal)
+        }
+        Value::Object(fields) => {
+            match_struct_fields(field_patterns, &|k| fields.get(k).cloned(), eval_literal)
+        }
+        Value::EnumVariant {
+            enum_name,
+            variant_name,
+            data: Some(data),
+        } if path_names_variant(&path, enum_name, variant_name) => match data.as_slice() {
+            [Value::Struct { fields, .. }] => {
+                match_struct_fields(field_patterns, &|k| fields.get(k).cloned(), eval_literal)
+            }
+            _ => Ok(None),
+        },
+        Value::EnumVariant { enum_name, .. }
+            if field_patterns.is_empty() && struct_name == enum_name.as_str() =>
+        {
+            Ok(Some(vec![]))
+        }
+        Value::Error { kind, .. } if names_type(kind) => {
+            match_struct_fields(field_patterns, &|k| value.error_field(k), eval_literal)
+        }
+        _ => Ok(None),
+    }
+}
+
 #[cfg(test)]
 #[path = "eval_pattern_match_tests.rs"]
 mod tests;
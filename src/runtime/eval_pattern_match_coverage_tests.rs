@@ -1,4 +1,5 @@
 use super::*;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 fn test_eval_literal(lit: &Literal) -> Value {
@@ -12,6 +13,8 @@ fn test_eval_literal(lit: &Literal) -> Value {
         Literal::Unit => Value::Nil,
         Literal::Null => Value::Nil,
         Literal::Atom(s) => Value::from_string(s.clone()),
+        Literal::BigInt(digits) => crate::runtime::eval_bigint::eval_bigint_literal(digits),
+        Literal::Decimal(text) => crate::runtime::eval_decimal::eval_decimal_literal(text),
     }
 }
 
@@ -911,3 +914,151 @@ fn test_pattern_matches_helper_false() {
     let result = pattern_matches(&pattern, &value, &test_eval_literal).expect("should succeed");
     assert!(!result);
 }
+
+// Rest patterns in tuples and tuple variants
+#[test]
+fn test_tuple_pattern_with_rest_binds_ends() {
+    let pattern = Pattern::Tuple(vec![
+        Pattern::Identifier("first".to_string()),
+        Pattern::Rest,
+        Pattern::Identifier("last".to_string()),
+    ]);
+    let value = Value::Tuple(Arc::from(vec![
+        Value::Integer(1),
+        Value::Integer(2),
+        Value::Integer(3),
+        Value::Integer(4),
+    ]));
+
+    let bindings = try_pattern_match(&pattern, &value, &test_eval_literal)
+        .expect("try_pattern_match should succeed")
+        .expect("tuple with rest should match");
+    assert_eq!(
+        bindings,
+        vec![
+            ("first".to_string(), Value::Integer(1)),
+            ("last".to_string(), Value::Integer(4)),
+        ]
+    );
+}
+
+#[test]
+fn test_tuple_pattern_named_rest_binds_tuple() {
+    let pattern = Pattern::Tuple(vec![
+        Pattern::Identifier("a".to_string()),
+        Pattern::RestNamed("others".to_string()),
+    ]);
+    let value = Value::Tuple(Arc::from(vec![
+        Value::Integer(1),
+        Value::Integer(2),
+        Value::Integer(3),
+    ]));
+
+    let bindings = try_pattern_match(&pattern, &value, &test_eval_literal)
+        .expect("try_pattern_match should succeed")
+        .expect("tuple with named rest should match");
+    assert_eq!(
+        bindings[1],
+        (
+            "others".to_string(),
+            Value::Tuple(Arc::from(vec![Value::Integer(2), Value::Integer(3)]))
+        )
+    );
+}
+
+#[test]
+fn test_tuple_variant_pattern_with_rest() {
+    let pattern = Pattern::TupleVariant {
+        path: vec!["Color".to_string(), "Rgb".to_string()],
+        patterns: vec![Pattern::Identifier("r".to_string()), Pattern::Rest],
+    };
+    let value = Value::EnumVariant {
+        enum_name: "Color".to_string(),
+        variant_name: "Rgb".to_string(),
+        data: Some(vec![Value::Integer(255), Value::Integer(0), Value::Integer(0)]),
+    };
+
+    let bindings = try_pattern_match(&pattern, &value, &test_eval_literal)
+        .expect("try_pattern_match should succeed")
+        .expect("variant with rest should match");
+    assert_eq!(bindings, vec![("r".to_string(), Value::Integer(255))]);
+}
+
+#[test]
+fn test_multiple_rest_patterns_rejected() {
+    let pattern = Pattern::List(vec![Pattern::Rest, Pattern::Rest]);
+    let value = Value::Array(Arc::from(vec![Value::Integer(1)]));
+    assert!(try_pattern_match(&pattern, &value, &test_eval_literal).is_err());
+}
+
+fn struct_field(name: &str, pattern: Option<Pattern>) -> StructPatternField {
+    StructPatternField {
+        name: name.to_string(),
+        pattern,
+    }
+}
+
+#[test]
+fn test_struct_variant_pattern_binds_fields() {
+    let pattern = Pattern::Struct {
+        name: "Shape::Circle".to_string(),
+        fields: vec![struct_field("r", None)],
+        has_rest: false,
+    };
+    let mut fields = HashMap::new();
+    fields.insert("r".to_string(), Value::Integer(3));
+    let value = Value::EnumVariant {
+        enum_name: "Shape".to_string(),
+        variant_name: "Circle".to_string(),
+        data: Some(vec![Value::Struct {
+            name: "Circle".to_string(),
+            fields: Arc::new(fields),
+        }]),
+    };
+
+    let bindings = try_pattern_match(&pattern, &value, &test_eval_literal)
+        .expect("try_pattern_match should succeed")
+        .expect("struct variant should match");
+    assert_eq!(bindings, vec![("r".to_string(), Value::Integer(3))]);
+}
+
+#[test]
+fn test_struct_pattern_nested_field_pattern() {
+    let pattern = Pattern::Struct {
+        name: "Point".to_string(),
+        fields: vec![
+            struct_field("x", Some(Pattern::Literal(Literal::Integer(0, None)))),
+            struct_field("y", Some(Pattern::Identifier("height".to_string()))),
+        ],
+        has_rest: false,
+    };
+    let mut fields = HashMap::new();
+    fields.insert("x".to_string(), Value::Integer(0));
+    fields.insert("y".to_string(), Value::Integer(7));
+    let value = Value::Struct {
+        name: "Point".to_string(),
+        fields: Arc::new(fields),
+    };
+
+    let bindings = try_pattern_match(&pattern, &value, &test_eval_literal)
+        .expect("try_pattern_match should succeed")
+        .expect("point on the y axis should match");
+    assert_eq!(bindings, vec![("height".to_string(), Value::Integer(7))]);
+}
+
+#[test]
+fn test_variant_pattern_rejects_other_enum() {
+    let pattern = Pattern::TupleVariant {
+        path: vec!["Shape".to_string(), "Square".to_string()],
+        patterns: vec![Pattern::Identifier("side".to_string())],
+    };
+    let value = Value::EnumVariant {
+        enum_name: "Tile".to_string(),
+        variant_name: "Square".to_string(),
+        data: Some(vec![Value::Integer(2)]),
+    };
+
+    let result = try_pattern_match(&pattern, &value, &test_eval_literal)
+        .expect("try_pattern_match should succeed");
+    assert!(result.is_none());
+}
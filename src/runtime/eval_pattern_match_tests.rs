@@ -306,3 +306,61 @@ fn test_tuple_variant_no_match_arity_mismatch() {
         .expect("try_pattern_match should succeed in test");
     assert!(result.is_none());
 }
+
+#[test]
+fn test_string_affix_pattern_binds_middle() {
+    let pattern = Pattern::StringAffix {
+        prefix: "<".to_string(),
+        rest: Box::new(Pattern::Identifier("tag".to_string())),
+        suffix: ">".to_string(),
+    };
+    let value = Value::from_string("<div>".to_string());
+    let bindings = try_pattern_match(&pattern, &value, &test_eval_literal)
+        .expect("try_pattern_match should succeed in test")
+        .expect("pattern should match");
+    assert_eq!(
+        bindings,
+        vec![("tag".to_string(), Value::from_string("div".to_string()))]
+    );
+}
+
+#[test]
+fn test_string_affix_pattern_does_not_overlap() {
+    let pattern = Pattern::StringAffix {
+        prefix: "ab".to_string(),
+        rest: Box::new(Pattern::Wildcard),
+        suffix: "b".to_string(),
+    };
+    let value = Value::from_string("ab".to_string());
+    let result = try_pattern_match(&pattern, &value, &test_eval_literal)
+        .expect("try_pattern_match should succeed in test");
+    assert!(result.is_none());
+}
+
+#[test]
+fn test_regex_pattern_binds_named_groups() {
+    let pattern = Pattern::Regex(r"(?P<key>\w+)=(?P<value>\d+)?".to_string());
+    let value = Value::from_string("port=".to_string());
+    let bindings = try_pattern_match(&pattern, &value, &test_eval_literal)
+        .expect("try_pattern_match should succeed in test")
+        .expect("pattern should match");
+    assert_eq!(
+        bindings,
+        vec![
+            ("key".to_string(), Value::from_string("port".to_string())),
+            ("value".to_string(), Value::Nil),
+        ]
+    );
+}
+
+#[test]
+fn test_regex_pattern_matches_whole_string() {
+    let pattern = Pattern::Regex(r"\d+".to_string());
+    let value = Value::from_string("abc 123".to_string());
+    let result = try_pattern_match(&pattern, &value, &test_eval_literal)
+        .expect("try_pattern_match should succeed in test");
+    assert!(result.is_none());
+    let result = try_pattern_match(&pattern, &Value::Integer(123), &test_eval_literal)
+        .expect("try_pattern_match should succeed in test");
+    assert!(result.is_none());
+}
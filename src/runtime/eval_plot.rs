@@ -0,0 +1,409 @@
+//! `std::plot`: simple SVG charts
+//!
+//! ```text
+//! std::plot::line([1, 4, 9, 16])                       // x defaults to 0, 1, 2, ...
+//! std::plot::scatter(xs, ys, {"title": "Height vs weight"})
+//! std::plot::bar(["a", "b", "c"], [3, 1, 2], {"width": 640, "height": 400})
+//! ```
+//!
+//! Every chart is returned as an `image/svg+xml` display object (see
+//! [`crate::runtime::eval_rich_output`]), so a notebook cell ending in a plot
+//! shows it inline. Options are `title` (string), `width` and `height`
+//! (pixels, default 480×320) and `color` (any CSS color).
+
+use crate::runtime::eval_rich_output::{display_value, escape_html, IMAGE_SVG};
+use crate::runtime::validation::validate_arg_range;
+use crate::runtime::{InterpreterError, Value};
+use std::fmt::Write;
+
+const MARGIN: f64 = 48.0;
+const DEFAULT_COLOR: &str = "#4c72b0";
+
+/// Chart options
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlotOptions {
+    /// Title drawn above the chart
+    pub title: Option<String>,
+    /// Width in pixels
+    pub width: u32,
+    /// Height in pixels
+    pub height: u32,
+    /// Line, marker and bar color
+    pub color: String,
+}
+
+impl Default for PlotOptions {
+    fn default() -> Self {
+        Self {
+            title: None,
+            width: 480,
+            height: 320,
+            color: DEFAULT_COLOR.to_string(),
+        }
+    }
+}
+
+impl PlotOptions {
+    /// Read options from an optional `{title, width, height, color}` object
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for unknown keys, values of the wrong type, or a
+    /// size too small to hold the axes.
+    pub fn from_value(function: &str, value: Option<&Value>) -> Result<Self, InterpreterError> {
+        let mut options = Self::default();
+        let Some(value) = value else {
+            return Ok(options);
+        };
+        let Value::Object(fields) = value else {
+            return Err(plot_error(function, "options must be an object"));
+        };
+        let size = |value: &Value| match value {
+            Value::Integer(n) => u32::try_from(*n)
+                .ok()
+                .filter(|&n| f64::from(n) > 2.0 * MARGIN),
+            _ => None,
+        };
+        for (key, value) in fields.iter() {
+            match (key.as_str(), value) {
+                ("title", Value::String(s)) => options.title = Some(s.to_string()),
+                ("color", Value::String(s)) => options.color = s.to_string(),
+                ("width", _) => {
+                    options.width = size(value).ok_or_else(|| invalid(function, key))?;
+                }
+                ("height", _) => {
+                    options.height = size(value).ok_or_else(|| invalid(function, key))?;
+                }
+                ("title" | "color", _) => return Err(invalid(function, key)),
+                _ => return Err(plot_error(function, &format!("unknown option '{key}'"))),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// `std::plot::line([xs,] ys [, options])`
+///
+/// # Errors
+///
+/// Returns an error for non-numeric or empty data, mismatched lengths, or
+/// invalid options.
+pub fn eval_plot_line(args: &[Value]) -> Result<Value, InterpreterError> {
+    xy_chart("std::plot::line", args, Mark::Line)
+}
+
+/// `std::plot::scatter(xs, ys [, options])`
+///
+/// # Errors
+///
+/// Returns an error for non-numeric or empty data, mismatched lengths, or
+/// invalid options.
+pub fn eval_plot_scatter(args: &[Value]) -> Result<Value, InterpreterError> {
+    xy_chart("std::plot::scatter", args, Mark::Point)
+}
+
+/// `std::plot::bar(labels, values [, options])`
+///
+/// # Errors
+///
+/// Returns an error for non-numeric or empty values, mismatched lengths, or
+/// invalid options.
+pub fn eval_plot_bar(args: &[Value]) -> Result<Value, InterpreterError> {
+    const FUNCTION: &str = "std::plot::bar";
+    validate_arg_range(FUNCTION, args, 2, 3)?;
+    let Value::Array(labels) = &args[0] else {
+        return Err(plot_error(FUNCTION, "labels must be an array"));
+    };
+    let labels: Vec<String> = labels
+        .iter()
+        .map(|label| match label {
+            Value::String(s) => s.to_string(),
+            other => other.to_string(),
+        })
+        .collect();
+    let values = numbers(FUNCTION, &args[1])?;
+    if labels.len() != values.len() {
+        return Err(plot_error(FUNCTION, "labels and values differ in length"));
+    }
+    let options = PlotOptions::from_value(FUNCTION, args.get(2))?;
+
+    let low = values.iter().copied().fold(0.0, f64::min);
+    let high = values.iter().copied().fold(0.0, f64::max);
+    let frame = Frame::new(&options, (0.0, 1.0), (low, high));
+    let mut svg = frame.open(&options);
+    let slot = frame.plot_width() / values.len() as f64;
+    let zero = frame.y(0.0);
+    for (i, (label, &value)) in labels.iter().zip(&values).enumerate() {
+        let x = MARGIN + slot * i as f64;
+        let top = frame.y(value).min(zero);
+        let _ = write!(
+            svg,
+            r#"<rect x="{:.1}" y="{top:.1}" width="{:.1}" height="{:.1}" fill="{}"/>"#,
+            x + slot * 0.1,
+            slot * 0.8,
+            (frame.y(value) - zero).abs(),
+            escape_html(&options.color)
+        );
+        let _ = write!(
+            svg,
+            r#"<text x="{:.1}" y="{:.1}" text-anchor="middle">{}</text>"#,
+            x + slot / 2.0,
+            frame.bottom() + 16.0,
+            escape_html(label)
+        );
+    }
+    frame.y_axis_labels(&mut svg);
+    svg.push_str("</svg>");
+    Ok(display_value(IMAGE_SVG, svg))
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Mark {
+    Line,
+    Point,
+}
+
+fn xy_chart(function: &str, args: &[Value], mark: Mark) -> Result<Value, InterpreterError> {
+    validate_arg_range(function, args, 1, 3)?;
+    // `line(ys)` and `line(ys, options)` number the points from 0
+    let (xs, ys, options) = match (mark, args) {
+        (Mark::Line, [ys] | [ys, Value::Object(_)]) => {
+            let ys = numbers(function, ys)?;
+            ((0..ys.len()).map(|i| i as f64).collect(), ys, args.get(1))
+        }
+        (_, [xs, ys, ..]) => (numbers(function, xs)?, numbers(function, ys)?, args.get(2)),
+        _ => return Err(plot_error(function, "expects x and y arrays")),
+    };
+    if xs.len() != ys.len() {
+        return Err(plot_error(function, "x and y differ in length"));
+    }
+    let options = PlotOptions::from_value(function, options)?;
+
+    let frame = Frame::new(&options, range(&xs), range(&ys));
+    let mut svg = frame.open(&options);
+    let points: Vec<(f64, f64)> = xs
+        .iter()
+        .zip(&ys)
+        .map(|(&x, &y)| (frame.x(x), frame.y(y)))
+        .collect();
+    let color = escape_html(&options.color);
+    match mark {
+        Mark::Line => {
+            let path: Vec<String> = points
+                .iter()
+                .map(|(x, y)| format!("{x:.1},{y:.1}"))
+                .collect();
+            let _ = write!(
+                svg,
+                r#"<polyline points="{}" fill="none" stroke="{color}" stroke-width="2"/>"#,
+                path.join(" ")
+            );
+        }
+        Mark::Point => {
+            for (x, y) in &points {
+                let _ = write!(
+                    svg,
+                    r#"<circle cx="{x:.1}" cy="{y:.1}" r="3" fill="{color}"/>"#
+                );
+            }
+        }
+    }
+    frame.x_axis_labels(&mut svg);
+    frame.y_axis_labels(&mut svg);
+    svg.push_str("</svg>");
+    Ok(display_value(IMAGE_SVG, svg))
+}
+
+/// Maps data coordinates into the plot area inside the margins
+struct Frame {
+    width: f64,
+    height: f64,
+    x_range: (f64, f64),
+    y_range: (f64, f64),
+}
+
+impl Frame {
+    fn new(options: &PlotOptions, x_range: (f64, f64), y_range: (f64, f64)) -> Self {
+        Self {
+            width: f64::from(options.width),
+            height: f64::from(options.height),
+            x_range: widen(x_range),
+            y_range: widen(y_range),
+        }
+    }
+
+    fn plot_width(&self) -> f64 {
+        self.width - 2.0 * MARGIN
+    }
+
+    fn bottom(&self) -> f64 {
+        self.height - MARGIN
+    }
+
+    fn x(&self, x: f64) -> f64 {
+        let (low, high) = self.x_range;
+        MARGIN + (x - low) / (high - low) * self.plot_width()
+    }
+
+    fn y(&self, y: f64) -> f64 {
+        let (low, high) = self.y_range;
+        self.bottom() - (y - low) / (high - low) * (self.height - 2.0 * MARGIN)
+    }
+
+    /// SVG header, title and axes
+    fn open(&self, options: &PlotOptions) -> String {
+        let (width, height) = (options.width, options.height);
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="sans-serif" font-size="11">"#
+        );
+        if let Some(title) = &options.title {
+            let _ = write!(
+                svg,
+                r#"<text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="14">{}</text>"#,
+                self.width / 2.0,
+                MARGIN / 2.0,
+                escape_html(title)
+            );
+        }
+        let _ = write!(
+            svg,
+            r##"<path d="M{MARGIN},{MARGIN} V{bottom:.1} H{right:.1}" fill="none" stroke="#333"/>"##,
+            bottom = self.bottom(),
+            right = self.width - MARGIN
+        );
+        svg
+    }
+
+    fn x_axis_labels(&self, svg: &mut String) {
+        for (x, anchor) in [(self.x_range.0, "start"), (self.x_range.1, "end")] {
+            let _ = write!(
+                svg,
+                r#"<text x="{:.1}" y="{:.1}" text-anchor="{anchor}">{}</text>"#,
+                self.x(x),
+                self.bottom() + 16.0,
+                tick(x)
+            );
+        }
+    }
+
+    fn y_axis_labels(&self, svg: &mut String) {
+        for y in [self.y_range.0, self.y_range.1] {
+            let _ = write!(
+                svg,
+                r#"<text x="{:.1}" y="{:.1}" text-anchor="end">{}</text>"#,
+                MARGIN - 6.0,
+                self.y(y) + 4.0,
+                tick(y)
+            );
+        }
+    }
+}
+
+fn numbers(function: &str, value: &Value) -> Result<Vec<f64>, InterpreterError> {
+    let Value::Array(items) = value else {
+        return Err(plot_error(function, "data must be an array of numbers"));
+    };
+    if items.is_empty() {
+        return Err(plot_error(function, "data is empty"));
+    }
+    items
+        .iter()
+        .map(|item| match item {
+            Value::Integer(i) => Ok(*i as f64),
+            Value::Float(f) if f.is_finite() => Ok(*f),
+            _ => Err(plot_error(function, "data must be an array of numbers")),
+        })
+        .collect()
+}
+
+fn range(values: &[f64]) -> (f64, f64) {
+    values
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), &v| {
+            (low.min(v), high.max(v))
+        })
+}
+
+/// Give a single-valued range some extent so it can be scaled
+fn widen((low, high): (f64, f64)) -> (f64, f64) {
+    if high > low {
+        (low, high)
+    } else {
+        (low - 1.0, high + 1.0)
+    }
+}
+
+/// Axis label: integers without a fraction, others to two decimals
+fn tick(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.2}")
+    }
+}
+
+fn plot_error(function: &str, message: &str) -> InterpreterError {
+    InterpreterError::RuntimeError(format!("{function}(): {message}"))
+}
+
+fn invalid(function: &str, key: &str) -> InterpreterError {
+    plot_error(function, &format!("invalid value for '{key}'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::eval_rich_output::display_data;
+
+    fn array(values: &[i64]) -> Value {
+        Value::Array(values.iter().map(|&v| Value::Integer(v)).collect())
+    }
+
+    fn svg(value: &Value) -> &str {
+        match display_data(value) {
+            Some((IMAGE_SVG, svg)) => svg,
+            other => panic!("expected an SVG display object, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_line_defaults_x_to_indices() {
+        let plot = eval_plot_line(&[array(&[1, 4, 9])]).unwrap();
+        let svg = svg(&plot);
+        assert!(svg.starts_with("<svg"), "{svg}");
+        assert!(
+            svg.contains("<polyline points=\"48.0,272.0 240.0,"),
+            "{svg}"
+        );
+        assert!(svg.ends_with("</svg>"));
+    }
+
+    #[test]
+    fn test_bar_draws_one_rect_per_value_with_escaped_labels() {
+        let labels = Value::Array(
+            vec![
+                Value::from_string("<a>".to_string()),
+                Value::from_string("b".to_string()),
+            ]
+            .into(),
+        );
+        let plot = eval_plot_bar(&[labels, array(&[3, -1])]).unwrap();
+        let svg = svg(&plot);
+        assert_eq!(svg.matches("<rect").count(), 2);
+        assert!(svg.contains("&lt;a&gt;"), "{svg}");
+    }
+
+    #[test]
+    fn test_invalid_data_and_options_are_rejected() {
+        assert!(eval_plot_scatter(&[array(&[1, 2]), array(&[1])]).is_err());
+        assert!(eval_plot_line(&[array(&[])]).is_err());
+        let mut options = std::collections::HashMap::new();
+        options.insert("width".to_string(), Value::Integer(10));
+        let options = Value::Object(std::sync::Arc::new(options));
+        let err = eval_plot_line(&[array(&[1, 2]), options]).unwrap_err();
+        assert!(
+            err.to_string().contains("invalid value for 'width'"),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,215 @@
+//! `std::display`: rich output for notebook cells
+//!
+//! ```text
+//! std::display::html("<b>Total:</b> 42")
+//! std::display::markdown("# Results\n\n- fast\n- correct")
+//! std::display::svg(svg_source)
+//! std::display::png("chart.png")            // file path or array of bytes
+//! ```
+//!
+//! Each function returns a display object `{__type: "Display", mime, data}`
+//! holding one MIME-typed payload (PNG data is base64-encoded). The notebook
+//! renders a cell whose value is a display object by its MIME type (see
+//! `crate::notebook::mime`), and renders `DataFrame` values as HTML tables
+//! ([`dataframe_html`]). Elsewhere a display object prints as its text, or as
+//! a short placeholder for images.
+
+use crate::runtime::eval_dataframe_engine::{cell_text, displayed_rows, row_count};
+use crate::runtime::validation::validate_arg_count;
+use crate::runtime::{DataFrameColumn, InterpreterError, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// `__type` of display objects
+pub const DISPLAY_TYPE: &str = "Display";
+/// HTML fragment
+pub const TEXT_HTML: &str = "text/html";
+/// Markdown source
+pub const TEXT_MARKDOWN: &str = "text/markdown";
+/// SVG document
+pub const IMAGE_SVG: &str = "image/svg+xml";
+/// Base64-encoded PNG image
+pub const IMAGE_PNG: &str = "image/png";
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// Display object carrying `data` of MIME type `mime`
+pub fn display_value(mime: &str, data: String) -> Value {
+    let mut fields = HashMap::new();
+    fields.insert(
+        "__type".to_string(),
+        Value::from_string(DISPLAY_TYPE.to_string()),
+    );
+    fields.insert("mime".to_string(), Value::from_string(mime.to_string()));
+    fields.insert("data".to_string(), Value::from_string(data));
+    Value::Object(Arc::new(fields))
+}
+
+/// MIME type and payload of a display object
+pub fn display_data(value: &Value) -> Option<(&str, &str)> {
+    match value {
+        Value::Object(fields) => display_fields(fields),
+        _ => None,
+    }
+}
+
+/// MIME type and payload if `fields` form a display object
+pub(crate) fn display_fields(fields: &HashMap<String, Value>) -> Option<(&str, &str)> {
+    match (fields.get("__type"), fields.get("mime"), fields.get("data")) {
+        (Some(Value::String(kind)), Some(Value::String(mime)), Some(Value::String(data)))
+            if kind.as_ref() == DISPLAY_TYPE =>
+        {
+            Some((mime.as_ref(), data.as_ref()))
+        }
+        _ => None,
+    }
+}
+
+/// `std::display::html/markdown/svg(text)`
+///
+/// # Errors
+///
+/// Returns an error unless called with exactly one string.
+pub fn eval_display_text(
+    function: &str,
+    mime: &str,
+    args: &[Value],
+) -> Result<Value, InterpreterError> {
+    validate_arg_count(function, args, 1)?;
+    let Value::String(text) = &args[0] else {
+        return Err(InterpreterError::RuntimeError(format!(
+            "{function}() expects a string"
+        )));
+    };
+    Ok(display_value(mime, text.to_string()))
+}
+
+/// `std::display::png(path_or_bytes)`
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, an array element is not a
+/// byte, or the data does not start with the PNG signature.
+pub fn eval_display_png(args: &[Value]) -> Result<Value, InterpreterError> {
+    validate_arg_count("std::display::png", args, 1)?;
+    let bytes = match &args[0] {
+        Value::String(path) => std::fs::read(path.as_ref())
+            .map_err(|e| InterpreterError::RuntimeError(format!("Failed to read '{path}': {e}")))?,
+        Value::Array(items) => items
+            .iter()
+            .map(|item| match item {
+                Value::Byte(b) => Some(*b),
+                Value::Integer(i) => u8::try_from(*i).ok(),
+                _ => None,
+            })
+            .collect::<Option<Vec<u8>>>()
+            .ok_or_else(|| {
+                InterpreterError::RuntimeError(
+                    "std::display::png() expects an array of bytes (0-255)".to_string(),
+                )
+            })?,
+        _ => {
+            return Err(InterpreterError::RuntimeError(
+                "std::display::png() expects a file path or an array of bytes".to_string(),
+            ))
+        }
+    };
+    if !bytes.starts_with(PNG_SIGNATURE) {
+        return Err(InterpreterError::RuntimeError(
+            "std::display::png() data is not a PNG image".to_string(),
+        ));
+    }
+    Ok(display_value(IMAGE_PNG, base64_encode(&bytes)))
+}
+
+/// Render a frame as an HTML table, eliding rows like the text display
+pub fn dataframe_html(columns: &[DataFrameColumn]) -> String {
+    let rows = row_count(columns);
+    let mut html = String::from(r#"<table class="dataframe"><thead><tr>"#);
+    for col in columns {
+        html.push_str(&format!("<th>{}</th>", escape_html(&col.name)));
+    }
+    html.push_str("</tr></thead><tbody>");
+    for row in displayed_rows(rows) {
+        html.push_str("<tr>");
+        for col in columns {
+            let text = match row {
+                Some(row) => col.values.get(row).map(cell_text).unwrap_or_default(),
+                None => "…".to_string(),
+            };
+            html.push_str(&format!("<td>{}</td>", escape_html(&text)));
+        }
+        html.push_str("</tr>");
+    }
+    html.push_str(&format!(
+        "</tbody></table><p class=\"dataframe-shape\">{rows} rows × {} columns</p>",
+        columns.len()
+    ));
+    html
+}
+
+/// Escape text for HTML element content and attribute values
+pub(crate) fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// Standard base64 with padding
+pub(crate) fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, &b)| n | (u32::from(b) << (16 - 8 * i)));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(char::from(ALPHABET[((n >> (18 - 6 * i)) & 0x3f) as usize]));
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_encode_pads() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_png_requires_signature() {
+        let bytes = |data: &[u8]| {
+            Value::Array(data.iter().map(|&b| Value::Integer(i64::from(b))).collect())
+        };
+        let png = eval_display_png(&[bytes(b"\x89PNG\r\n\x1a\n\0")]).unwrap();
+        assert_eq!(display_data(&png), Some((IMAGE_PNG, "iVBORw0KGgoA")));
+        assert!(eval_display_png(&[bytes(b"GIF89a")]).is_err());
+    }
+
+    #[test]
+    fn test_dataframe_html_escapes_and_elides() {
+        let columns = vec![DataFrameColumn {
+            name: "<name>".to_string(),
+            values: (0..12).map(Value::Integer).collect(),
+        }];
+        let html = dataframe_html(&columns);
+        assert!(html.contains("<th>&lt;name&gt;</th>"), "{html}");
+        assert!(html.contains("<td>…</td>"), "{html}");
+        assert!(!html.contains("<td>6</td>"), "{html}");
+        assert!(html.contains("12 rows × 1 columns"), "{html}");
+    }
+}
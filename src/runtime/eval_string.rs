@@ -112,10 +112,7 @@ fn eval_string_is_empty(s: &Arc<str>) -> Result<Value, InterpreterError> {
 }
 
 fn eval_string_chars(s: &Arc<str>) -> Result<Value, InterpreterError> {
-    let chars: Vec<Value> = s
-        .chars()
-        .map(|c| Value::from_string(c.to_string()))
-        .collect();
+    let chars: Vec<Value> = s.chars().map(Value::from_char).collect();
     Ok(Value::Array(Arc::from(chars)))
 }
 
@@ -176,6 +173,10 @@ fn eval_string_split(s: &Arc<str>, separator: &Value) -> Result<Value, Interpret
 fn eval_string_repeat(s: &Arc<str>, n: &Value) -> Result<Value, InterpreterError> {
     if let Value::Integer(count) = n {
         if *count >= 0 {
+            crate::runtime::restricted::check_size(
+                s.len()
+                    .saturating_mul(usize::try_from(*count).unwrap_or(usize::MAX)),
+            )?;
             Ok(Value::from_string(s.repeat(*count as usize)))
         } else {
             Err(InterpreterError::RuntimeError(
@@ -194,7 +195,7 @@ fn eval_string_char_at(s: &Arc<str>, index: &Value) -> Result<Value, Interpreter
         if *idx >= 0 {
             let chars: Vec<char> = s.chars().collect();
             if let Some(ch) = chars.get(*idx as usize) {
-                Ok(Value::from_string(ch.to_string()))
+                Ok(Value::from_char(*ch))
             } else {
                 Ok(Value::Nil)
             }
@@ -8,6 +8,50 @@
 use crate::runtime::{InterpreterError, Value};
 use std::sync::Arc;
 
+/// Every method strings answer to, at any arity
+pub const STRING_METHODS: &[&str] = &[
+    "len",
+    "length",
+    "to_upper",
+    "to_uppercase",
+    "upper",
+    "to_lower",
+    "to_lowercase",
+    "lower",
+    "to_string",
+    "is_empty",
+    "is_numeric",
+    "is_alphabetic",
+    "is_alphanumeric",
+    "trim",
+    "trim_start",
+    "trim_end",
+    "chars",
+    "lines",
+    "parse",
+    "to_int",
+    "to_integer",
+    "timestamp",
+    "to_rfc3339",
+    "as_bytes",
+    "bytes",
+    "contains",
+    "starts_with",
+    "ends_with",
+    "split",
+    "repeat",
+    "char_at",
+    "find",
+    "pad_start",
+    "pad_end",
+    "append",
+    "replace",
+    "replace_all",
+    "substring",
+    "slice",
+    "format",
+];
+
 /// Evaluate a string method call
 ///
 /// # Complexity
@@ -51,7 +95,7 @@ fn eval_zero_arg_string_method(s: &Arc<str>, method: &str) -> Result<Value, Inte
         "parse" | "to_int" | "to_integer" => eval_string_parse(s),
         "timestamp" => eval_string_timestamp(s),
         "to_rfc3339" => Ok(Value::from_string(s.to_string())),
-        "as_bytes" => eval_string_as_bytes(s),
+        "as_bytes" | "bytes" => eval_string_as_bytes(s),
         _ => Err(InterpreterError::RuntimeError(format!(
             "Unknown zero-argument string method: {method}"
         ))),
@@ -70,6 +114,9 @@ fn eval_single_arg_string_method(
         "split" => eval_string_split(s, arg),
         "repeat" => eval_string_repeat(s, arg),
         "char_at" => eval_string_char_at(s, arg),
+        "find" => eval_string_find(s, arg),
+        "pad_start" => eval_string_pad(s, arg, &Value::from_string(" ".to_string()), true),
+        "pad_end" => eval_string_pad(s, arg, &Value::from_string(" ".to_string()), false),
         // PIPELINE-001: Add append for pipeline string concatenation
         "append" => eval_string_append(s, arg),
         _ => Err(InterpreterError::RuntimeError(format!(
@@ -85,8 +132,10 @@ fn eval_two_arg_string_method(
     arg2: &Value,
 ) -> Result<Value, InterpreterError> {
     match method {
-        "replace" => eval_string_replace(s, arg1, arg2),
+        "replace" | "replace_all" => eval_string_replace(s, arg1, arg2),
         "substring" | "slice" => eval_string_substring(s, arg1, arg2),
+        "pad_start" => eval_string_pad(s, arg1, arg2, true),
+        "pad_end" => eval_string_pad(s, arg1, arg2, false),
         _ => Err(InterpreterError::RuntimeError(format!(
             "Unknown two-argument string method: {method}"
         ))),
@@ -149,6 +198,57 @@ fn eval_string_replace(s: &str, from: &Value, to: &Value) -> Result<Value, Inter
     }
 }
 
+/// Byte offset of the first match of `needle`, as `Some(index)` or `None`
+///
+/// # Complexity
+/// Cyclomatic complexity: 2 (within Toyota Way limits)
+fn eval_string_find(s: &str, needle: &Value) -> Result<Value, InterpreterError> {
+    let Value::String(needle) = needle else {
+        return Err(InterpreterError::RuntimeError(
+            "find expects string argument".to_string(),
+        ));
+    };
+    let (variant_name, data) = match s.find(&**needle) {
+        Some(index) => ("Some", Some(vec![Value::Integer(index as i64)])),
+        None => ("None", None),
+    };
+    Ok(Value::EnumVariant {
+        enum_name: "Option".to_string(),
+        variant_name: variant_name.to_string(),
+        data,
+    })
+}
+
+/// Pad to `width` characters by repeating `fill` at the start or end
+///
+/// Strings already at least `width` characters long are returned unchanged;
+/// a multi-character fill is truncated to fit, as in JavaScript.
+///
+/// # Complexity
+/// Cyclomatic complexity: 4 (within Toyota Way limits)
+fn eval_string_pad(
+    s: &str,
+    width: &Value,
+    fill: &Value,
+    at_start: bool,
+) -> Result<Value, InterpreterError> {
+    let (Value::Integer(width), Value::String(fill)) = (width, fill) else {
+        return Err(InterpreterError::RuntimeError(
+            "pad_start/pad_end expect an integer width and a string fill".to_string(),
+        ));
+    };
+    let missing = usize::try_from(*width)
+        .unwrap_or(0)
+        .saturating_sub(s.chars().count());
+    crate::runtime::restricted::check_size(s.len().saturating_add(missing))?;
+    let padding: String = fill.chars().cycle().take(missing).collect();
+    Ok(Value::from_string(if at_start {
+        format!("{padding}{s}")
+    } else {
+        format!("{s}{padding}")
+    }))
+}
+
 /// Split string by separator
 ///
 /// # Complexity
@@ -174,6 +274,10 @@ fn eval_string_split(s: &str, separator: &Value) -> Result<Value, InterpreterErr
 fn eval_string_repeat(s: &str, count: &Value) -> Result<Value, InterpreterError> {
     if let Value::Integer(n) = count {
         if *n >= 0 {
+            crate::runtime::restricted::check_size(
+                s.len()
+                    .saturating_mul(usize::try_from(*n).unwrap_or(usize::MAX)),
+            )?;
             Ok(Value::from_string(s.repeat(*n as usize)))
         } else {
             Err(InterpreterError::RuntimeError(
@@ -211,7 +315,7 @@ fn eval_string_char_at(s: &str, index: &Value) -> Result<Value, InterpreterError
         if *idx >= 0 {
             let chars: Vec<char> = s.chars().collect();
             if let Some(ch) = chars.get(*idx as usize) {
-                Ok(Value::from_string(ch.to_string()))
+                Ok(Value::from_char(*ch))
             } else {
                 Ok(Value::Nil)
             }
@@ -256,10 +360,7 @@ fn eval_string_substring(s: &str, start: &Value, end: &Value) -> Result<Value, I
 /// # Complexity
 /// Cyclomatic complexity: 2 (within Toyota Way limits)
 fn eval_string_chars(s: &str) -> Result<Value, InterpreterError> {
-    let chars: Vec<Value> = s
-        .chars()
-        .map(|c| Value::from_string(c.to_string()))
-        .collect();
+    let chars: Vec<Value> = s.chars().map(Value::from_char).collect();
     Ok(Value::from_array(chars))
 }
 
@@ -298,31 +399,24 @@ fn eval_string_lines(s: &str) -> Result<Value, InterpreterError> {
     Ok(Value::from_array(lines))
 }
 
-/// Format string by replacing {} placeholders with arguments
+/// Format string by replacing `{}` and positional `{0}` placeholders with arguments
 ///
 /// # STDLIB-007 (GitHub Issue #47)
 /// Implements Python-style string formatting with {} placeholders
 ///
 /// # Complexity
-/// Cyclomatic complexity: 4 (within Toyota Way limits)
+/// Cyclomatic complexity: 1 (within Toyota Way limits)
 ///
 /// # Examples
 /// ```
 /// "Hello, {}!".format("Alice") => "Hello, Alice!"
 /// "{} + {} = {}".format(2, 3, 5) => "2 + 3 = 5"
+/// "{1} before {0}".format("a", "b") => "b before a"
 /// ```
 fn eval_string_format(s: &str, args: &[Value]) -> Result<Value, InterpreterError> {
-    let mut result = s.to_string();
-    for arg in args {
-        // Convert Value to string without quotes for String values
-        let arg_str = match arg {
-            Value::String(s) => s.to_string(),
-            _ => format!("{arg}"),
-        };
-        // Replace first occurrence of {} with the argument
-        result = result.replacen("{}", &arg_str, 1);
-    }
-    Ok(Value::from_string(result))
+    Ok(Value::from_string(
+        crate::runtime::value_format::format_string_with_values(s, args),
+    ))
 }
 
 /// Evaluate primitive type methods (float, integer, generic)
@@ -2,10 +2,16 @@
 //!
 //! This module implements error handling evaluation with pattern-based catch clauses
 //! and finally blocks, following Toyota Way principles with all functions ≤10 complexity.
+//!
+//! Runtime failures reach catch clauses as [`Value::Error`] (kind, message and the
+//! call stack where the error was raised); thrown values are caught as-is.
+//! Clauses are tried in order with match-pattern semantics, so user-defined
+//! error structs and enums can be caught by type; unmatched errors propagate.
+//! `break`, `continue` and `return` pass through try/catch untouched, as does
+//! any error when there are no catch clauses (`try { ... } finally { ... }`).
 
 use crate::frontend::ast::{CatchClause, Expr, Pattern};
 use crate::runtime::{Interpreter, InterpreterError, Value};
-use std::sync::Arc;
 
 /// Evaluate a try/catch/finally expression
 ///
@@ -23,6 +29,8 @@ pub fn eval_try_catch(
     // Handle catch clauses if error occurred
     let result = match try_result {
         Ok(value) => Ok(value),
+        // try/finally (and `with` blocks) pass the error on unchanged
+        Err(error) if catch_clauses.is_empty() => Err(error),
         Err(error) => handle_catch_clauses(interp, error, catch_clauses),
     };
 
@@ -59,8 +67,13 @@ fn handle_catch_clauses(
     error: InterpreterError,
     catch_clauses: &[CatchClause],
 ) -> Result<Value, InterpreterError> {
+    if error.is_control_flow() {
+        return Err(error);
+    }
+
     // Convert error to value for pattern matching
-    let error_value = error_to_value(error);
+    let stack = interp.take_error_trace();
+    let error_value = error_to_value(error, stack);
 
     for catch_clause in catch_clauses {
         if let Some(result) = try_catch_clause(interp, &error_value, catch_clause)? {
@@ -91,11 +104,11 @@ fn try_catch_clause(
 
     // Pattern matched - bind variables and execute body
     interp.push_scope();
-    bind_pattern_variables(interp, &catch_clause.pattern, error_value)?;
-    let result = interp.eval_expr(&catch_clause.body)?;
+    let result = bind_pattern_variables(interp, &catch_clause.pattern, error_value)
+        .and_then(|()| interp.eval_expr(&catch_clause.body));
     interp.pop_scope();
 
-    Ok(Some(result))
+    result.map(Some)
 }
 
 /// Evaluate the finally block
@@ -113,34 +126,21 @@ fn eval_finally_block(
 
 /// Convert an `InterpreterError` to a Value for pattern matching
 ///
+/// Thrown values are caught unchanged; every other failure becomes a
+/// [`Value::Error`] carrying `stack` (innermost frame first).
+///
 /// # Complexity
 /// Cyclomatic complexity: ≤5
-fn error_to_value(error: InterpreterError) -> Value {
+fn error_to_value(error: InterpreterError, stack: Vec<String>) -> Value {
     match error {
         InterpreterError::Throw(value) => value,
-        InterpreterError::TypeError(msg) => Value::Object(Arc::new(
-            vec![
-                (
-                    "type".to_string(),
-                    Value::String("TypeError".to_string().into()),
-                ),
-                ("message".to_string(), Value::String(msg.into())),
-            ]
-            .into_iter()
-            .collect(),
-        )),
-        InterpreterError::RuntimeError(msg) => Value::Object(Arc::new(
-            vec![
-                (
-                    "type".to_string(),
-                    Value::String("RuntimeError".to_string().into()),
-                ),
-                ("message".to_string(), Value::String(msg.into())),
-            ]
-            .into_iter()
-            .collect(),
-        )),
-        _ => Value::String(format!("{error:?}").into()),
+        InterpreterError::TypeError(msg) => Value::error("TypeError", msg, stack),
+        InterpreterError::RuntimeError(msg) => Value::error("RuntimeError", msg, stack),
+        InterpreterError::AssertionFailed(msg) => Value::error("AssertionError", msg, stack),
+        InterpreterError::MethodNotFound(missing) => {
+            Value::error("RuntimeError", missing.to_string(), stack)
+        }
+        other => Value::error("RuntimeError", other.to_string(), stack),
     }
 }
 
@@ -154,53 +154,35 @@ fn value_to_error(value: Value) -> InterpreterError {
 
 /// Check if a pattern matches a value
 ///
+/// Catch clauses use the full match-pattern semantics, so they can select
+/// errors by type (`e: NotFound`) or destructure them (`AppError::Io(msg)`).
+///
 /// # Complexity
-/// Cyclomatic complexity: ≤8 (delegates to existing pattern matcher)
+/// Cyclomatic complexity: ≤2 (delegates to existing pattern matcher)
 fn pattern_matches(
     interp: &mut Interpreter,
     pattern: &Pattern,
     value: &Value,
 ) -> Result<bool, InterpreterError> {
-    // Delegate to existing pattern matching logic
-    interp.pattern_matches(pattern, value)
+    interp.pattern_matches_internal(pattern, value)
 }
 
 /// Bind variables from a pattern match
 ///
 /// # Complexity
-/// Cyclomatic complexity: ≤6
+/// Cyclomatic complexity: ≤3
 fn bind_pattern_variables(
     interp: &mut Interpreter,
     pattern: &Pattern,
     value: &Value,
 ) -> Result<(), InterpreterError> {
-    match pattern {
-        Pattern::Identifier(name) => {
-            interp.set_variable(name, value.clone());
-            Ok(())
-        }
-        Pattern::Struct {
-            name: _,
-            fields,
-            has_rest: _,
-        } => {
-            if let Value::Object(obj) = value {
-                for field in fields {
-                    if let Some(val) = obj.get(&field.name) {
-                        if let Some(ref pattern) = field.pattern {
-                            bind_pattern_variables(interp, pattern, val)?;
-                        }
-                    }
-                }
-            }
-            Ok(())
-        }
-        Pattern::Rest | Pattern::RestNamed(_) => {
-            // Handle rest patterns
-            Ok(())
-        }
-        _ => Ok(()),
+    let bindings = interp
+        .try_pattern_match(pattern, value)?
+        .unwrap_or_default();
+    for (name, bound) in bindings {
+        interp.set_variable(&name, bound);
     }
+    Ok(())
 }
 
 /// Evaluate a throw expression
@@ -225,8 +207,8 @@ mod tests {
         // eval_finally_block: ≤3 ✓
         // error_to_value: ≤5 ✓
         // value_to_error: ≤2 ✓
-        // pattern_matches: ≤8 ✓
-        // bind_pattern_variables: ≤6 ✓
+        // pattern_matches: ≤2 ✓
+        // bind_pattern_variables: ≤3 ✓
         // eval_throw: ≤2 ✓
         //
         // All functions maintain ≤10 complexity
@@ -237,6 +219,7 @@ mod tests {
 mod mutation_tests {
     use super::*;
     use crate::frontend::ast::{ExprKind, Literal, Span};
+    use std::sync::Arc;
 
     #[test]
     fn test_try_catch_clause_negation_operator() {
@@ -317,7 +300,7 @@ mod mutation_tests {
         let thrown_value = Value::from_string("custom error".to_string());
         let error = InterpreterError::Throw(thrown_value.clone());
 
-        let result = error_to_value(error);
+        let result = error_to_value(error, Vec::new());
         assert_eq!(result, thrown_value, "Throw error should unwrap to value");
     }
 
@@ -325,20 +308,22 @@ mod mutation_tests {
     fn test_error_to_value_type_error_match_arm() {
         // MISSED: delete match arm InterpreterError::TypeError(msg) in error_to_value (line 121)
         let error = InterpreterError::TypeError("type mismatch".to_string());
-        let result = error_to_value(error);
+        let result = error_to_value(error, Vec::new());
 
-        if let Value::Object(obj) = result {
-            let type_val = obj.get("type").expect("operation should succeed in test");
+        if let Value::Error { .. } = result {
+            let type_val = result
+                .error_field("type")
+                .expect("operation should succeed in test");
             assert!(
                 matches!(type_val, Value::String(_)),
                 "TypeError should have type field"
             );
             assert!(
-                obj.get("message").is_some(),
+                result.error_field("message").is_some(),
                 "TypeError should have message field"
             );
         } else {
-            panic!("TypeError should convert to Object");
+            panic!("TypeError should convert to Error");
         }
     }
 
@@ -346,20 +331,22 @@ mod mutation_tests {
     fn test_error_to_value_runtime_error_match_arm() {
         // MISSED: delete match arm InterpreterError::RuntimeError(msg) in error_to_value (line 132)
         let error = InterpreterError::RuntimeError("runtime issue".to_string());
-        let result = error_to_value(error);
+        let result = error_to_value(error, Vec::new());
 
-        if let Value::Object(obj) = result {
-            let type_val = obj.get("type").expect("operation should succeed in test");
+        if let Value::Error { .. } = result {
+            let type_val = result
+                .error_field("type")
+                .expect("operation should succeed in test");
             assert!(
                 matches!(type_val, Value::String(_)),
                 "RuntimeError should have type field"
             );
             assert!(
-                obj.get("message").is_some(),
+                result.error_field("message").is_some(),
                 "RuntimeError should have message field"
             );
         } else {
-            panic!("RuntimeError should convert to Object");
+            panic!("RuntimeError should convert to Error");
         }
     }
 
@@ -436,14 +423,17 @@ mod coverage_push_round31 {
     use super::*;
     use crate::frontend::ast::{ExprKind, Literal, Span};
     use std::collections::HashMap;
+    use std::sync::Arc;
 
     #[test]
     fn test_error_to_value_default_case() {
         // Test the fallback case for unknown error types
         let error = InterpreterError::DivisionByZero;
-        let result = error_to_value(error);
-        // Should convert to a string representation
-        assert!(matches!(result, Value::String(_)));
+        let result = error_to_value(error, Vec::new());
+        assert_eq!(
+            result,
+            Value::error("RuntimeError", "Division by zero", Vec::new())
+        );
     }
 
     #[test]
@@ -461,16 +451,16 @@ mod coverage_push_round31 {
     fn test_error_to_value_type_error_contains_message() {
         let msg = "expected int, got string".to_string();
         let error = InterpreterError::TypeError(msg.clone());
-        let result = error_to_value(error);
+        let result = error_to_value(error, Vec::new());
 
-        if let Value::Object(obj) = result {
-            let message = obj.get("message");
+        if let Value::Error { .. } = result {
+            let message = result.error_field("message");
             assert!(message.is_some());
             if let Some(Value::String(s)) = message {
                 assert_eq!(s.as_ref(), msg);
             }
         } else {
-            panic!("Expected Object");
+            panic!("Expected Error");
         }
     }
 
@@ -478,16 +468,16 @@ mod coverage_push_round31 {
     fn test_error_to_value_runtime_error_contains_message() {
         let msg = "file not found".to_string();
         let error = InterpreterError::RuntimeError(msg.clone());
-        let result = error_to_value(error);
+        let result = error_to_value(error, Vec::new());
 
-        if let Value::Object(obj) = result {
-            let message = obj.get("message");
+        if let Value::Error { .. } = result {
+            let message = result.error_field("message");
             assert!(message.is_some());
             if let Some(Value::String(s)) = message {
                 assert_eq!(s.as_ref(), msg);
             }
         } else {
-            panic!("Expected Object");
+            panic!("Expected Error");
         }
     }
 
@@ -750,6 +740,7 @@ mod round_133_tests {
     use super::*;
     use crate::frontend::ast::{ExprKind, Literal, Span, StructPatternField};
     use std::collections::HashMap;
+    use std::sync::Arc;
 
     fn make_int_expr(n: i64) -> Expr {
         Expr::new(
@@ -769,21 +760,21 @@ mod round_133_tests {
     #[test]
     fn test_error_to_value_throw_with_nil() {
         let error = InterpreterError::Throw(Value::Nil);
-        let result = error_to_value(error);
+        let result = error_to_value(error, Vec::new());
         assert_eq!(result, Value::Nil);
     }
 
     #[test]
     fn test_error_to_value_throw_with_bool() {
         let error = InterpreterError::Throw(Value::Bool(true));
-        let result = error_to_value(error);
+        let result = error_to_value(error, Vec::new());
         assert_eq!(result, Value::Bool(true));
     }
 
     #[test]
     fn test_error_to_value_throw_with_float() {
         let error = InterpreterError::Throw(Value::Float(3.14));
-        let result = error_to_value(error);
+        let result = error_to_value(error, Vec::new());
         assert_eq!(result, Value::Float(3.14));
     }
 
@@ -791,17 +782,17 @@ mod round_133_tests {
     fn test_error_to_value_throw_with_array() {
         let arr = Value::Array(vec![Value::Integer(1), Value::Integer(2)].into());
         let error = InterpreterError::Throw(arr.clone());
-        let result = error_to_value(error);
+        let result = error_to_value(error, Vec::new());
         assert_eq!(result, arr);
     }
 
     #[test]
     fn test_error_to_value_type_error_empty_message() {
         let error = InterpreterError::TypeError(String::new());
-        let result = error_to_value(error);
-        if let Value::Object(obj) = result {
-            assert!(obj.get("type").is_some());
-            if let Some(Value::String(msg)) = obj.get("message") {
+        let result = error_to_value(error, Vec::new());
+        if let Value::Error { .. } = result {
+            assert!(result.error_field("type").is_some());
+            if let Some(Value::String(msg)) = result.error_field("message") {
                 assert!(msg.is_empty());
             }
         }
@@ -811,9 +802,9 @@ mod round_133_tests {
     fn test_error_to_value_runtime_error_long_message() {
         let long_msg = "x".repeat(1000);
         let error = InterpreterError::RuntimeError(long_msg.clone());
-        let result = error_to_value(error);
-        if let Value::Object(obj) = result {
-            if let Some(Value::String(msg)) = obj.get("message") {
+        let result = error_to_value(error, Vec::new());
+        if let Value::Error { .. } = result {
+            if let Some(Value::String(msg)) = result.error_field("message") {
                 assert_eq!(msg.as_ref(), long_msg);
             }
         }
@@ -1033,6 +1024,22 @@ mod round_133_tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_handle_catch_clauses_passes_control_flow_through() {
+        let mut interp = Interpreter::new();
+        let catch_clauses = vec![CatchClause {
+            pattern: Pattern::Identifier("e".to_string()),
+            body: Box::new(make_string_expr("handled")),
+        }];
+
+        let error = InterpreterError::Return(Value::Integer(7));
+        let result = handle_catch_clauses(&mut interp, error, &catch_clauses);
+        assert!(matches!(
+            result,
+            Err(InterpreterError::Return(Value::Integer(7)))
+        ));
+    }
+
     // --- eval_throw edge cases ---
     #[test]
     fn test_eval_throw_bool() {
@@ -1149,6 +1156,7 @@ mod round_136_tests {
     use super::*;
     use crate::frontend::ast::{ExprKind, Literal, Span, StructPatternField};
     use std::collections::HashMap;
+    use std::sync::Arc;
 
     fn make_int_expr(n: i64) -> Expr {
         Expr::new(
@@ -1162,7 +1170,7 @@ mod round_136_tests {
         let mut obj = HashMap::new();
         obj.insert("key".to_string(), Value::Integer(42));
         let error = InterpreterError::Throw(Value::Object(Arc::new(obj.clone())));
-        let result = error_to_value(error);
+        let result = error_to_value(error, Vec::new());
         if let Value::Object(result_obj) = result {
             assert_eq!(result_obj.get("key"), Some(&Value::Integer(42)));
         } else {
@@ -1174,7 +1182,7 @@ mod round_136_tests {
     fn test_error_to_value_throw_with_tuple() {
         let tuple = Value::Tuple(vec![Value::Integer(1), Value::Integer(2)].into());
         let error = InterpreterError::Throw(tuple.clone());
-        let result = error_to_value(error);
+        let result = error_to_value(error, Vec::new());
         assert_eq!(result, tuple);
     }
 
@@ -1182,9 +1190,9 @@ mod round_136_tests {
     fn test_error_to_value_type_error_unicode_message() {
         let msg = "错误消息".to_string();
         let error = InterpreterError::TypeError(msg.clone());
-        let result = error_to_value(error);
-        if let Value::Object(obj) = result {
-            if let Some(Value::String(s)) = obj.get("message") {
+        let result = error_to_value(error, Vec::new());
+        if let Value::Error { .. } = result {
+            if let Some(Value::String(s)) = result.error_field("message") {
                 assert_eq!(s.as_ref(), msg);
             }
         }
@@ -1194,9 +1202,9 @@ mod round_136_tests {
     fn test_error_to_value_runtime_error_special_chars() {
         let msg = "error: 'quoted' and \"double\"".to_string();
         let error = InterpreterError::RuntimeError(msg.clone());
-        let result = error_to_value(error);
-        if let Value::Object(obj) = result {
-            if let Some(Value::String(s)) = obj.get("message") {
+        let result = error_to_value(error, Vec::new());
+        if let Value::Error { .. } = result {
+            if let Some(Value::String(s)) = result.error_field("message") {
                 assert_eq!(s.as_ref(), msg);
             }
         }
@@ -1406,16 +1414,16 @@ mod round_136_tests {
     #[test]
     fn test_error_to_value_division_by_zero() {
         let error = InterpreterError::DivisionByZero;
-        let result = error_to_value(error);
+        let result = error_to_value(error, Vec::new());
         // Should fall through to default case
-        assert!(matches!(result, Value::String(_)));
+        assert!(matches!(result, Value::Error { .. }));
     }
 
     #[test]
     fn test_error_to_value_stack_overflow() {
         let error = InterpreterError::StackOverflow;
-        let result = error_to_value(error);
-        assert!(matches!(result, Value::String(_)));
+        let result = error_to_value(error, Vec::new());
+        assert!(matches!(result, Value::Error { .. }));
     }
 
     // === EXTREME TDD Round 159 - Coverage Push Tests ===
@@ -1423,31 +1431,31 @@ mod round_136_tests {
     #[test]
     fn test_error_to_value_throw_r159() {
         let error = InterpreterError::Throw(Value::Integer(42));
-        let result = error_to_value(error);
+        let result = error_to_value(error, Vec::new());
         assert_eq!(result, Value::Integer(42));
     }
 
     #[test]
     fn test_error_to_value_type_error_r159() {
         let error = InterpreterError::TypeError("type mismatch".to_string());
-        let result = error_to_value(error);
-        if let Value::Object(obj) = result {
-            assert!(obj.get("type").is_some());
-            assert!(obj.get("message").is_some());
+        let result = error_to_value(error, Vec::new());
+        if let Value::Error { .. } = result {
+            assert!(result.error_field("type").is_some());
+            assert!(result.error_field("message").is_some());
         } else {
-            panic!("Expected Object");
+            panic!("Expected Error");
         }
     }
 
     #[test]
     fn test_error_to_value_runtime_error_r159() {
         let error = InterpreterError::RuntimeError("something failed".to_string());
-        let result = error_to_value(error);
-        if let Value::Object(obj) = result {
-            assert!(obj.get("type").is_some());
-            assert!(obj.get("message").is_some());
+        let result = error_to_value(error, Vec::new());
+        if let Value::Error { .. } = result {
+            assert!(result.error_field("type").is_some());
+            assert!(result.error_field("message").is_some());
         } else {
-            panic!("Expected Object");
+            panic!("Expected Error");
         }
     }
 
@@ -1528,28 +1536,28 @@ mod round_136_tests {
     #[test]
     fn test_error_to_value_break_r159() {
         let error = InterpreterError::Break(None, Value::Nil);
-        let result = error_to_value(error);
-        assert!(matches!(result, Value::String(_)));
+        let result = error_to_value(error, Vec::new());
+        assert!(matches!(result, Value::Error { .. }));
     }
 
     #[test]
     fn test_error_to_value_continue_r159() {
         let error = InterpreterError::Continue(None);
-        let result = error_to_value(error);
-        assert!(matches!(result, Value::String(_)));
+        let result = error_to_value(error, Vec::new());
+        assert!(matches!(result, Value::Error { .. }));
     }
 
     #[test]
     fn test_error_to_value_return_r159() {
         let error = InterpreterError::Return(Value::Integer(42));
-        let result = error_to_value(error);
-        assert!(matches!(result, Value::String(_)));
+        let result = error_to_value(error, Vec::new());
+        assert!(matches!(result, Value::Error { .. }));
     }
 
     #[test]
     fn test_error_to_value_assertion_failed_r159() {
         let error = InterpreterError::AssertionFailed("assertion failed".to_string());
-        let result = error_to_value(error);
-        assert!(matches!(result, Value::String(_)));
+        let result = error_to_value(error, Vec::new());
+        assert!(matches!(result, Value::Error { .. }));
     }
 }
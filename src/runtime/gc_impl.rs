@@ -217,6 +217,8 @@ impl ConservativeGC {
     fn estimate_object_size(value: &Value) -> usize {
         match value {
             Value::Integer(_) => 8,
+            Value::BigInt(n) => 24 + n.bits().div_ceil(8) as usize,
+            Value::Decimal(_) => 16,
             Value::Float(_) => 8,
             Value::Bool(_) => 1,
             Value::Byte(_) => 1,
@@ -290,6 +292,12 @@ impl ConservativeGC {
             #[cfg(not(target_arch = "wasm32"))]
             Value::HtmlElement(_) => 64,
             Value::Atom(s) => std::mem::size_of::<Value>() + s.len(),
+            Value::Iterator(_) => 64,
+            Value::Error {
+                kind,
+                message,
+                stack,
+            } => 48 + kind.len() + message.len() + stack.iter().map(String::len).sum::<usize>(),
         }
     }
 
@@ -0,0 +1,150 @@
+//! Interned storage for short runtime strings
+//!
+//! Every evaluation of a string literal, and every character produced by
+//! `chars()`, string indexing or `for c in s`, used to allocate a fresh
+//! `Arc<str>`. Loops over text therefore spent most of their time in the
+//! allocator. Strings of up to `MAX_INTERNED_LEN` bytes are now shared from a
+//! per-thread table, so repeated evaluations only bump a reference count.
+//!
+//! The table is bounded: once it holds `MAX_INTERNED_STRINGS` entries, new
+//! strings are allocated as before and nothing is evicted, so programs that
+//! build many distinct strings cannot grow it without limit.
+//!
+//! Integers, floats and booleans are already stored unboxed in `Value`, so
+//! they need no equivalent treatment.
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Longest string, in bytes, that is interned
+pub const MAX_INTERNED_LEN: usize = 64;
+/// Distinct strings kept per thread before interning stops
+pub const MAX_INTERNED_STRINGS: usize = 4096;
+
+/// Counters for the current thread's intern table
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InternStats {
+    /// Distinct strings currently interned
+    pub strings: usize,
+    /// Total bytes held by interned strings
+    pub bytes: usize,
+    /// Lookups answered with an existing string
+    pub hits: u64,
+    /// Lookups that allocated a new string
+    pub misses: u64,
+}
+
+#[derive(Debug, Default)]
+struct Interner {
+    strings: HashSet<Arc<str>>,
+    bytes: usize,
+    hits: u64,
+    misses: u64,
+}
+
+impl Interner {
+    fn intern(&mut self, s: &str) -> Arc<str> {
+        if let Some(existing) = self.strings.get(s) {
+            self.hits += 1;
+            return Arc::clone(existing);
+        }
+        self.misses += 1;
+        let fresh: Arc<str> = Arc::from(s);
+        if self.strings.len() < MAX_INTERNED_STRINGS {
+            self.bytes += s.len();
+            self.strings.insert(Arc::clone(&fresh));
+        }
+        fresh
+    }
+
+    fn stats(&self) -> InternStats {
+        InternStats {
+            strings: self.strings.len(),
+            bytes: self.bytes,
+            hits: self.hits,
+            misses: self.misses,
+        }
+    }
+}
+
+thread_local! {
+    static INTERNER: RefCell<Interner> = RefCell::new(Interner::default());
+}
+
+/// Shared `Arc<str>` for `s`, allocating only the first time a short string is seen
+///
+/// Strings longer than `MAX_INTERNED_LEN` are always freshly allocated.
+pub fn intern(s: &str) -> Arc<str> {
+    if s.len() > MAX_INTERNED_LEN {
+        return Arc::from(s);
+    }
+    INTERNER.with(|interner| interner.borrow_mut().intern(s))
+}
+
+/// Shared single-character string for `c`
+pub fn intern_char(c: char) -> Arc<str> {
+    intern(c.encode_utf8(&mut [0; 4]))
+}
+
+/// Intern table counters for the current thread
+pub fn intern_stats() -> InternStats {
+    INTERNER.with(|interner| interner.borrow().stats())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intern_shares_equal_strings() {
+        let a = intern("hello");
+        let b = intern(&String::from("hello"));
+        assert!(Arc::ptr_eq(&a, &b));
+        assert_eq!(&*a, "hello");
+    }
+
+    #[test]
+    fn test_intern_char_shares_with_intern() {
+        let a = intern_char('é');
+        let b = intern("é");
+        assert!(Arc::ptr_eq(&a, &b));
+    }
+
+    #[test]
+    fn test_long_strings_are_not_interned() {
+        let long = "x".repeat(MAX_INTERNED_LEN + 1);
+        let a = intern(&long);
+        let b = intern(&long);
+        assert!(!Arc::ptr_eq(&a, &b));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_intern_stats_count_hits_and_misses() {
+        let before = intern_stats();
+        intern("intern_stats_a");
+        intern("intern_stats_a");
+        intern("intern_stats_b");
+        let after = intern_stats();
+        assert_eq!(after.strings, before.strings + 2);
+        assert_eq!(after.bytes, before.bytes + 28);
+        assert_eq!(
+            (after.hits, after.misses),
+            (before.hits + 1, before.misses + 2)
+        );
+    }
+
+    #[test]
+    fn test_full_table_still_returns_strings() {
+        let mut interner = Interner::default();
+        for i in 0..MAX_INTERNED_STRINGS {
+            interner.intern(&i.to_string());
+        }
+        let a = interner.intern("overflow");
+        let b = interner.intern("overflow");
+        assert_eq!(a, b);
+        assert!(!Arc::ptr_eq(&a, &b));
+        assert_eq!(interner.stats().strings, MAX_INTERNED_STRINGS);
+    }
+}
@@ -19,6 +19,7 @@
 use super::eval_expr;
 use super::eval_literal;
 use super::eval_operations;
+use super::method_not_found;
 // EXTREME TDD Round 52: Value types imported from dedicated module
 pub use super::value::{DataFrameColumn, Value};
 // EXTREME TDD Round 52: Interpreter types imported from dedicated module
@@ -81,6 +82,18 @@ pub struct Interpreter {
     /// Inline caches for field/method access optimization
     field_caches: HashMap<String, InlineCache>,
 
+    /// Per-call-site inline caches for method dispatch
+    pub(crate) method_cache: MethodCache,
+
+    /// Function bodies resolved to slot-indexed locals
+    pub(crate) slot_cache: SlotCache,
+
+    /// Scopes enclosing the ones closures were created in
+    pub(crate) capture_scopes: CaptureScopes,
+
+    /// Expressions deferred to the exit of each running scope
+    pub(crate) deferred: DeferStack,
+
     /// Type feedback collection for JIT compilation
     type_feedback: TypeFeedback,
 
@@ -90,6 +103,12 @@ pub struct Interpreter {
     /// Error handler scopes for try/catch
     error_scopes: Vec<ErrorScope>,
 
+    /// Names of the functions currently executing (outermost first)
+    call_trace: Vec<String>,
+
+    /// Call stack captured where the in-flight error was raised
+    error_trace: Option<Vec<String>>,
+
     /// Stdout buffer for capturing println output (WASM/REPL)
     /// Complexity: 1 (simple field addition)
     stdout_buffer: Vec<String>,
@@ -114,6 +133,20 @@ pub use super::type_feedback::{
     VariableTypeFeedback,
 };
 
+use super::interpreter_tail_call::TailOutcome;
+
+// Re-export method dispatch inline caches
+pub use super::interpreter_method_cache::{MethodCache, MethodCacheStats};
+
+// Re-export resolved function body cache
+pub use super::interpreter_slots::{SlotCache, SlotCacheStats};
+
+// Re-export closure capture scopes
+pub use super::interpreter_captures::CaptureScopes;
+
+// Re-export deferred expression stack
+pub use super::interpreter_defer::DeferStack;
+
 // Re-export GC implementation from gc_impl module
 // EXTREME TDD: Eliminated 318 lines of duplicate GC code (massive entropy reduction)
 pub use super::gc_impl::{ConservativeGC, GCInfo, GCObject, GCStats};
@@ -151,9 +184,15 @@ impl Interpreter {
             frames: Vec::new(),
             execution_counts: HashMap::new(),
             field_caches: HashMap::new(),
+            method_cache: MethodCache::new(),
+            slot_cache: SlotCache::new(),
+            capture_scopes: CaptureScopes::new(),
+            deferred: DeferStack::new(),
             type_feedback: TypeFeedback::new(),
             gc: ConservativeGC::new(),
             error_scopes: Vec::new(),
+            call_trace: Vec::new(),
+            error_trace: None,
             stdout_buffer: Vec::new(), // Initialize empty stdout buffer
             module_loader: crate::backend::module_loader::ModuleLoader::new(), // Issue #88
         }
@@ -197,6 +236,7 @@ impl Interpreter {
     /// - Division by zero
     pub fn eval_expr(&mut self, expr: &Expr) -> Result<Value, InterpreterError> {
         self.eval_expr_kind(&expr.kind)
+            .map_err(|e| method_not_found::locate(e, expr))
     }
 
     /// Evaluate an expression kind directly.
@@ -232,6 +272,9 @@ impl Interpreter {
             | ExprKind::DataFrameOperation { .. }
             | ExprKind::IndexAccess { .. }
             | ExprKind::FieldAccess { .. }
+            | ExprKind::OptionalIndexAccess { .. }
+            | ExprKind::OptionalFieldAccess { .. }
+            | ExprKind::OptionalMethodCall { .. }
             | ExprKind::TypeCast { .. } => self.eval_operation_expr(expr_kind),
 
             // Functions (complexity: 2)
@@ -288,6 +331,17 @@ impl Interpreter {
             }
             ExprKind::IndexAccess { object, index } => self.eval_index_access(object, index),
             ExprKind::FieldAccess { object, field } => self.eval_field_access(object, field),
+            ExprKind::OptionalIndexAccess { object, index } => {
+                self.eval_optional_index_access(object, index)
+            }
+            ExprKind::OptionalFieldAccess { object, field } => {
+                self.eval_optional_field_access(object, field)
+            }
+            ExprKind::OptionalMethodCall {
+                receiver,
+                method,
+                args,
+            } => self.eval_optional_method_call(receiver, method, args),
             ExprKind::TypeCast { expr, target_type } => self.eval_type_cast(expr, target_type),
             _ => unreachable!("eval_operation_expr called with non-operation expression"),
         }
@@ -571,10 +625,12 @@ impl Interpreter {
     pub(crate) fn eval_literal(&self, lit: &Literal) -> Value {
         match lit {
             Literal::Integer(i, _) => Value::from_i64(*i),
+            Literal::BigInt(digits) => super::eval_bigint::eval_bigint_literal(digits),
+            Literal::Decimal(text) => super::eval_decimal::eval_decimal_literal(text),
             Literal::Float(f) => Value::from_f64(*f),
-            Literal::String(s) => Value::from_string(s.clone()),
+            Literal::String(s) => Value::from_interned(s),
             Literal::Bool(b) => Value::from_bool(*b),
-            Literal::Char(c) => Value::from_string(c.to_string()),
+            Literal::Char(c) => Value::from_char(*c),
             Literal::Byte(b) => Value::Byte(*b),
             Literal::Unit => Value::nil(),
             Literal::Null => Value::nil(),
@@ -623,6 +679,11 @@ impl Interpreter {
                 return Ok(value.clone());
             }
         }
+
+        // `cmp` methods of `Ord` impls return the built-in `Ordering`
+        if name == "Ordering" {
+            return Ok(super::interpreter_operators::ordering_enum());
+        }
         Err(InterpreterError::RuntimeError(format!(
             "Undefined variable: {name}"
         )))
@@ -650,6 +711,7 @@ impl Interpreter {
     pub(crate) fn env_set(&mut self, name: String, value: Value) {
         // Record type feedback for optimization
         self.record_variable_assignment_feedback(&name, &value);
+        self.note_method_binding(&name, &value);
 
         // ALWAYS create in current scope - `let` bindings shadow outer scopes
         // Do NOT search parent scopes (that's for reassignments without `let`)
@@ -669,20 +731,10 @@ impl Interpreter {
     pub(crate) fn env_set_mut(&mut self, name: String, value: Value) {
         // Record type feedback for optimization
         self.record_variable_assignment_feedback(&name, &value);
-
-        // CLOSURE-REFCELL-FIX: First find which scope contains the variable (using read-only borrows)
-        // This avoids holding borrow_mut() during iteration which causes RefCell panics with closures
-        let mut found_idx: Option<usize> = None;
-        for (idx, env_ref) in self.env_stack.iter().enumerate().rev() {
-            // Use borrow() not borrow_mut() for the search phase
-            if env_ref.borrow().contains_key(&name) {
-                found_idx = Some(idx);
-                break;
-            }
-        }
+        self.note_method_binding(&name, &value);
 
         // CLOSURE-REFCELL-FIX: Now mutate after all borrows are released
-        if let Some(idx) = found_idx {
+        if let Some(idx) = self.find_binding_scope(&name) {
             self.env_stack[idx].borrow_mut().insert(name, value);
         } else {
             // Variable doesn't exist in any scope - create new binding in current scope
@@ -694,6 +746,23 @@ impl Interpreter {
         }
     }
 
+    /// Invalidate method inline caches when a binding can change dispatch
+    fn note_method_binding(&mut self, name: &str, value: &Value) {
+        if crate::runtime::interpreter_method_cache::affects_method_resolution(name, value) {
+            self.method_cache.invalidate();
+        }
+    }
+
+    /// Index of the innermost scope that binds `name`
+    ///
+    /// CLOSURE-REFCELL-FIX: Searches with read-only borrows so no `borrow_mut()`
+    /// is held during iteration (which causes RefCell panics with closures).
+    pub(crate) fn find_binding_scope(&self, name: &str) -> Option<usize> {
+        self.env_stack
+            .iter()
+            .rposition(|env_ref| env_ref.borrow().contains_key(name))
+    }
+
     /// Push a new environment onto the stack
     // ISSUE-119: Wrap environment in Rc<RefCell> for shared mutable access
     pub(crate) fn env_push(&mut self, env: HashMap<String, Value>) {
@@ -786,85 +855,11 @@ impl Interpreter {
                     ))),
                 }
             }
-            Value::Closure { params, body, env } => {
-                // [RUNTIME-001] CHECK RECURSION DEPTH BEFORE ENTERING
-                crate::runtime::eval_function::check_recursion_depth()?;
-
-                // RUNTIME-DEFAULT-PARAMS: Check argument count with default parameter support
-                // Count required params (those without defaults)
-                let required_count = params
-                    .iter()
-                    .filter(|(_, default)| default.is_none())
-                    .count();
-                let total_count = params.len();
-
-                if args.len() < required_count || args.len() > total_count {
-                    crate::runtime::eval_function::decrement_depth();
-                    return Err(InterpreterError::RuntimeError(format!(
-                        "Function expects {}-{} arguments, got {}",
-                        required_count,
-                        total_count,
-                        args.len()
-                    )));
-                }
-
-                // ISSUE-119: ROOT CAUSE #3 FIX - Push captured environment first
-                // This allows variable lookups to find captured variables
-                self.env_stack.push(env); // Push captured environment (Rc::clone)
-
-                // Create NEW empty HashMap for function's local scope (parameters)
-                let mut local_env = HashMap::new();
-
-                // RUNTIME-DEFAULT-PARAMS: Bind provided arguments + apply defaults for missing args
-                for (i, (param_name, default_value)) in params.iter().enumerate() {
-                    let value = if i < args.len() {
-                        // Use provided argument
-                        args[i].clone()
-                    } else if let Some(default_expr) = default_value {
-                        // Apply default value by evaluating the expression
-                        self.eval_expr(default_expr)?
-                    } else {
-                        // This should never happen due to required_count check above
-                        unreachable!("Missing required parameter");
-                    };
-                    local_env.insert(param_name.clone(), value);
-                }
-
-                // Push local scope on top (parameters shadow outer variables)
-                self.env_push(local_env);
-
-                // Evaluate function body
-                // Catch InterpreterError::Return and extract value (early return support)
-                // BOOK-200-01 FIX: If body is a Block, evaluate statements directly
-                // without pushing an additional scope. The function already has its
-                // parameter scope (local_env), and pushing another scope would cause
-                // lambdas to capture the wrong environment.
-                let result = match &body.kind {
-                    crate::frontend::ast::ExprKind::Block(statements) => {
-                        // Evaluate block statements directly without pushing new scope
-                        match crate::runtime::eval_control_flow_new::eval_block_expr(
-                            statements,
-                            |e| self.eval_expr(e),
-                        ) {
-                            Err(InterpreterError::Return(val)) => Ok(val),
-                            other => other,
-                        }
-                    }
-                    _ => match self.eval_expr(&body) {
-                        Err(InterpreterError::Return(val)) => Ok(val),
-                        other => other,
-                    },
-                };
-
-                // ISSUE-119: Pop BOTH environments (local scope + captured environment)
-                self.env_pop(); // Pop local scope
-                self.env_pop(); // Pop captured environment
-
-                // [RUNTIME-001] ALWAYS DECREMENT, EVEN ON ERROR
-                crate::runtime::eval_function::decrement_depth();
-
-                result
+            // Functions containing `yield` return a suspended generator
+            Value::Closure { params, body, env } if body.contains_yield() => {
+                self.start_generator(&params, &body, env, args)
             }
+            Value::Closure { params, body, env } => self.call_closure(&params, &body, &env, args),
             Value::Object(ref obj) => {
                 // Check if this is a struct or actor definition being called as a constructor
                 if let Some(Value::String(type_str)) = obj.get("__type") {
@@ -876,6 +871,30 @@ impl Interpreter {
                     )))
                 }
             }
+            // Tuple variant constructors passed as functions: `map(Shape::Circle)`
+            Value::EnumVariant {
+                enum_name,
+                variant_name,
+                data: None,
+            } => self.construct_enum_variant(enum_name, variant_name, args.to_vec()),
+            // Resuming a generator: `Some(value)` until its body finishes, then `None`
+            Value::Iterator(ref iter) if args.is_empty() => {
+                let generator = match &*iter.borrow() {
+                    crate::runtime::eval_iterator::LazyIter::Generator(state) => {
+                        Some(Rc::clone(state))
+                    }
+                    _ => None,
+                };
+                match generator {
+                    Some(state) => self
+                        .resume_generator(&state)
+                        .map(crate::runtime::eval_iterator::option_value),
+                    None => Err(InterpreterError::TypeError(format!(
+                        "Cannot call non-function value: {}",
+                        func.type_name()
+                    ))),
+                }
+            }
             _ => Err(InterpreterError::TypeError(format!(
                 "Cannot call non-function value: {}",
                 func.type_name()
@@ -883,6 +902,147 @@ impl Interpreter {
         }
     }
 
+    /// Call a user function (complexity: 5)
+    ///
+    /// Self-calls in tail position come back here with their arguments
+    /// instead of recursing (see interpreter_tail_call).
+    fn call_closure(
+        &mut self,
+        params: &[(String, Option<Arc<Expr>>)],
+        body: &Arc<Expr>,
+        env: &Rc<RefCell<HashMap<String, Value>>>,
+        args: &[Value],
+    ) -> Result<Value, InterpreterError> {
+        crate::runtime::interrupt::check()?;
+        // [RUNTIME-001] CHECK RECURSION DEPTH BEFORE ENTERING
+        crate::runtime::eval_function::check_recursion_depth()?;
+
+        let mut tail_args: Option<Vec<Value>> = None;
+        let result = loop {
+            let args = tail_args.as_deref().unwrap_or(args);
+            match self.eval_closure_call(params, body, env, args) {
+                // A tail self-call loops without growing the depth, so it
+                // honours interrupts like any other loop
+                Ok(TailOutcome::SelfCall(next_args)) => {
+                    if let Err(e) = crate::runtime::interrupt::check() {
+                        break Err(e);
+                    }
+                    tail_args = Some(next_args);
+                }
+                // Catch InterpreterError::Return and extract value (early return support)
+                Ok(TailOutcome::Value(val)) | Err(InterpreterError::Return(val)) => break Ok(val),
+                Err(e) => break Err(e),
+            }
+        };
+
+        // [RUNTIME-001] ALWAYS DECREMENT, EVEN ON ERROR
+        crate::runtime::eval_function::decrement_depth();
+
+        result
+    }
+
+    /// Bind `args` and evaluate the body of a user function once (complexity: 4)
+    fn eval_closure_call(
+        &mut self,
+        params: &[(String, Option<Arc<Expr>>)],
+        body: &Arc<Expr>,
+        env: &Rc<RefCell<HashMap<String, Value>>>,
+        args: &[Value],
+    ) -> Result<TailOutcome, InterpreterError> {
+        // A rest parameter collects the surplus arguments as an array
+        let packed = crate::runtime::eval_func::pack_rest_args(params, args);
+        let args = packed.as_deref().unwrap_or(args);
+
+        // RUNTIME-DEFAULT-PARAMS: Check argument count with default parameter support
+        // Count required params (those without defaults)
+        let required_count = params
+            .iter()
+            .filter(|(_, default)| default.is_none())
+            .count();
+        let total_count = params.len();
+
+        if args.len() < required_count || args.len() > total_count {
+            return Err(InterpreterError::RuntimeError(format!(
+                "Function expects {}-{} arguments, got {}",
+                required_count,
+                total_count,
+                args.len()
+            )));
+        }
+
+        // ISSUE-119: ROOT CAUSE #3 FIX - Push captured environment first
+        // This allows variable lookups to find captured variables, along
+        // with the scopes enclosing it (see interpreter_captures)
+        let frame_base = self.enter_closure_env(env);
+
+        let outcome = self
+            .closure_param_values(params, args)
+            .and_then(|param_values| self.eval_closure_body(params, body, param_values));
+
+        // ISSUE-119: Pop the captured environment as well
+        self.leave_closure_env(frame_base); // Pop captured environment
+        outcome
+    }
+
+    /// Values of a user function's parameters for `args` (complexity: 5)
+    ///
+    /// RUNTIME-DEFAULT-PARAMS: Bind provided arguments + apply defaults for missing args
+    fn closure_param_values(
+        &mut self,
+        params: &[(String, Option<Arc<Expr>>)],
+        args: &[Value],
+    ) -> Result<Vec<Value>, InterpreterError> {
+        let mut param_values = Vec::with_capacity(params.len());
+        for (i, (_, default_value)) in params.iter().enumerate() {
+            let value = if i < args.len() {
+                // Use provided argument
+                args[i].clone()
+            } else if crate::runtime::eval_func::is_rest_default(default_value.as_ref()) {
+                // Rest parameter not reached: nothing to collect
+                Value::from_array(Vec::new())
+            } else if let Some(default_expr) = default_value {
+                // Apply default value by evaluating the expression
+                self.eval_expr(default_expr)?
+            } else {
+                // This should never happen due to the argument count check
+                unreachable!("Missing required parameter");
+            };
+            param_values.push(value);
+        }
+        Ok(param_values)
+    }
+
+    /// Evaluate a user function's body with its parameters bound (complexity: 2)
+    fn eval_closure_body(
+        &mut self,
+        params: &[(String, Option<Arc<Expr>>)],
+        body: &Arc<Expr>,
+        param_values: Vec<Value>,
+    ) -> Result<TailOutcome, InterpreterError> {
+        if let Some(resolved) = self.resolved_body(params, body) {
+            // Locals live in slots instead of a parameter scope
+            return self.eval_resolved_body(&resolved, param_values, body);
+        }
+        // Create NEW HashMap for function's local scope (parameters)
+        let local_env = params
+            .iter()
+            .map(|(param_name, _)| param_name.clone())
+            .zip(param_values)
+            .collect();
+
+        // Push local scope on top (parameters shadow outer variables)
+        self.env_push(local_env);
+
+        // BOOK-200-01 FIX: If body is a Block, evaluate statements directly
+        // without pushing an additional scope. The function already has its
+        // parameter scope (local_env), and pushing another scope would cause
+        // lambdas to capture the wrong environment.
+        // Expressions deferred by the body run before its locals go
+        let outcome = self.with_defer_scope(|this| this.eval_function_body_tail(body));
+        self.env_pop(); // Pop local scope
+        outcome
+    }
+
     /// Evaluate a binary operation from AST.
     ///
     /// Dispatches to specialized evaluation functions based on operator type:
@@ -974,15 +1134,40 @@ impl Interpreter {
             _ => {
                 let left_val = self.eval_expr(left)?;
                 let right_val = self.eval_expr(right)?;
-                let result = self.eval_binary_op(op, &left_val, &right_val)?;
+                // Use span start as site ID
+                self.eval_binary_values(op, left.span.start, &left_val, &right_val)
+            }
+        }
+    }
 
-                // Record type feedback for optimization
-                let site_id = left.span.start; // Use span start as site ID
-                self.record_binary_op_feedback(site_id, &left_val, &right_val, &result);
+    /// Apply a non-short-circuiting binary operator to evaluated operands,
+    /// recording type feedback for the site
+    pub(crate) fn eval_binary_values(
+        &mut self,
+        op: AstBinaryOp,
+        site_id: usize,
+        left_val: &Value,
+        right_val: &Value,
+    ) -> Result<Value, InterpreterError> {
+        // Unboxed int/float arithmetic: these sites are monomorphic by
+        // construction, so they skip dispatch and feedback recording
+        if let Some(result) =
+            crate::runtime::eval_operations::eval_numeric_fast_path(op, left_val, right_val)
+        {
+            return result;
+        }
 
-                Ok(result)
-            }
+        // Structs with operator trait impls (see interpreter_operators)
+        if let Some(result) = self.eval_overloaded_binary(op, left_val, right_val) {
+            return result;
         }
+
+        let result = self.eval_binary_op(op, left_val, right_val)?;
+
+        // Record type feedback for optimization
+        self.record_binary_op_feedback(site_id, left_val, right_val, &result);
+
+        Ok(result)
     }
 
     /// Evaluate containment check (Python-style 'in' operator)
@@ -1174,14 +1359,36 @@ impl Interpreter {
     ) -> Result<Value, InterpreterError> {
         // QA-026: Push new scope for block
         self.push_scope();
-        let result = crate::runtime::eval_control_flow_new::eval_block_expr(statements, |e| {
-            self.eval_expr(e)
+        let result = self.with_defer_scope(|this| {
+            crate::runtime::eval_control_flow_new::eval_block_expr(statements, |e| {
+                this.eval_expr(e)
+            })
         });
         // QA-026: Pop scope after block completes (even on error)
         self.pop_scope();
         result
     }
 
+    /// Evaluate a whole program in the current scope
+    ///
+    /// The parser wraps several top-level items in a block. Evaluating that
+    /// block like any other would drop every definition when its scope closes,
+    /// so its statements run in the current scope instead and stay visible to
+    /// whatever runs next (an automatic `main()` call, a test function).
+    ///
+    /// # Complexity
+    /// Cyclomatic complexity: 2 (within Toyota Way limits)
+    pub fn eval_top_level(&mut self, program: &Expr) -> Result<Value, InterpreterError> {
+        match &program.kind {
+            ExprKind::Block(statements) => self.with_defer_scope(|this| {
+                crate::runtime::eval_control_flow_new::eval_block_expr(statements, |e| {
+                    this.eval_expr(e)
+                })
+            }),
+            _ => self.eval_expr(program),
+        }
+    }
+
     /// Evaluate tuple expression
     pub(crate) fn eval_tuple_expr(&mut self, elements: &[Expr]) -> Result<Value, InterpreterError> {
         crate::runtime::eval_control_flow_new::eval_tuple_expr(elements, |e| self.eval_expr(e))
@@ -1340,8 +1547,9 @@ impl Interpreter {
         op: AstBinaryOp,
         right: &Value,
     ) -> Result<Value, InterpreterError> {
-        // Delegate to existing binary operation evaluation
-        self.eval_binary_op(op, left, right)
+        // Compound assignments (`i += 1`) share the numeric fast path
+        crate::runtime::eval_operations::eval_numeric_fast_path(op, left, right)
+            .unwrap_or_else(|| self.eval_binary_op(op, left, right))
     }
 
     /// Check if a pattern matches a value
@@ -1622,6 +1830,18 @@ impl Interpreter {
         }
     }
 
+    /// Get one binding from the global environment (for the REPL's `:undef`)
+    pub fn get_global_binding(&self, name: &str) -> Option<Value> {
+        self.env_stack.first()?.borrow().get(name).cloned()
+    }
+
+    /// Remove a binding from the global environment (for the REPL's `:undef`)
+    pub fn remove_global_binding(&mut self, name: &str) -> Option<Value> {
+        let removed = self.env_stack.first()?.borrow_mut().remove(name)?;
+        self.note_method_binding(name, &removed);
+        Some(removed)
+    }
+
     /// Clear all user variables from global environment, keeping only builtins
     pub fn clear_user_variables(&mut self) {
         if let Some(global_env) = self.env_stack.first() {
@@ -1656,6 +1876,10 @@ impl Interpreter {
                 StringPart::Text(text) => result.push_str(text),
                 StringPart::Expr(expr) => {
                     let value = self.eval_expr(expr)?;
+                    if let Some(text) = self.display_with_impl(&value)? {
+                        result.push_str(&text);
+                        continue;
+                    }
                     // Use format_value_for_interpolation to avoid adding quotes to strings
                     result.push_str(&format_value_for_interpolation(&value));
                 }
@@ -1683,6 +1907,7 @@ impl Interpreter {
         self.error_scopes.push(ErrorScope {
             env_depth: self.env_stack.len(),
         });
+        self.error_trace = None;
     }
 
     /// Pop an error handling scope
@@ -1693,12 +1918,64 @@ impl Interpreter {
         self.error_scopes.pop();
     }
 
+    /// Enter a named function call for stack traces, the profiler and
+    /// telemetry
+    ///
+    /// # Complexity
+    /// Cyclomatic complexity: 1
+    pub(crate) fn push_call_trace(&mut self, name: String) {
+        crate::runtime::profiler::enter(&name);
+        crate::runtime::telemetry::enter(&name);
+        self.call_trace.push(name);
+    }
+
+    /// Leave the innermost function call, capturing the call stack first
+    /// if the call failed inside a try block (the innermost capture wins)
+    ///
+    /// # Complexity
+    /// Cyclomatic complexity: 3
+    pub(crate) fn pop_call_trace(&mut self, error: Option<&InterpreterError>) {
+        let catchable = error.is_some_and(|e| !e.is_control_flow());
+        if catchable && !self.error_scopes.is_empty() && self.error_trace.is_none() {
+            self.error_trace = Some(self.call_trace.clone());
+        }
+        self.call_trace.pop();
+        crate::runtime::telemetry::exit(catchable);
+        crate::runtime::profiler::exit();
+    }
+
+    /// Take the stack trace of the error being caught, innermost frame first
+    ///
+    /// Falls back to the current call stack when the error was raised
+    /// without leaving a function (e.g. a `throw` directly in the try block).
+    ///
+    /// # Complexity
+    /// Cyclomatic complexity: 1
+    pub(crate) fn take_error_trace(&mut self) -> Vec<String> {
+        let mut trace = self
+            .error_trace
+            .take()
+            .unwrap_or_else(|| self.call_trace.clone());
+        trace.reverse();
+        trace
+    }
+
     /// Set a variable in the current scope
     ///
     /// # Complexity
     /// Cyclomatic complexity: 1
     pub fn set_variable(&mut self, name: &str, value: Value) {
-        // ISSUE-040 FIX: Use env_set_mut to search parent scopes for existing variables
+        // ISSUE-040 FIX: Search parent scopes for existing variables. Reassigning
+        // an existing binding (the hot-loop case) updates the value in place
+        // instead of allocating a fresh key.
+        if let Some(idx) = self.find_binding_scope(name) {
+            self.record_variable_assignment_feedback(name, &value);
+            self.note_method_binding(name, &value);
+            if let Some(slot) = self.env_stack[idx].borrow_mut().get_mut(name) {
+                *slot = value;
+            }
+            return;
+        }
         self.env_set_mut(name.to_string(), value);
     }
 
@@ -0,0 +1,249 @@
+//! Closure capture: the scopes a closure sees when it is called
+//!
+//! Closures capture variables by reference. A closure's environment is the
+//! scope it was created in, the same shared `Rc<RefCell<HashMap>>` the
+//! enclosing code keeps using, so an assignment inside the closure updates
+//! the variable everyone sees and a counter built by a function keeps its
+//! count between calls, after the function has returned.
+//!
+//! Blocks, loop bodies and calls each push a scope of their own, so the scope
+//! a closure is created in is often not the one holding the variables it
+//! uses: a closure made inside an `if` in a function also needs the
+//! function's locals. The scopes between the current call frame and the one
+//! a closure is created in are recorded here, keyed by that scope, and pushed
+//! below it whenever the closure runs.
+
+use crate::runtime::interpreter::Interpreter;
+use crate::runtime::Value;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
+
+type Scope = Rc<RefCell<HashMap<String, Value>>>;
+
+/// Recorded scopes kept before those of dropped scopes are first pruned
+const MAX_RECORDED_SCOPES: usize = 1024;
+
+#[derive(Debug)]
+struct Enclosing {
+    /// Keeps the scope's address from being reused while the entry exists
+    scope: Weak<RefCell<HashMap<String, Value>>>,
+    /// Enclosing scopes, outermost first
+    parents: Vec<Scope>,
+}
+
+/// Enclosing scopes of the scopes closures were created in, keyed by address
+#[derive(Debug, Default)]
+pub struct CaptureScopes {
+    enclosing: HashMap<usize, Enclosing>,
+    /// Table size at which dropped scopes are pruned next
+    prune_at: usize,
+    /// Length of the environment stack when each running closure was entered
+    frames: Vec<usize>,
+}
+
+impl CaptureScopes {
+    /// Create an empty table
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enclosing scopes recorded for `scope`, outermost first
+    fn parents(&self, scope: &Scope) -> &[Scope] {
+        if self.enclosing.is_empty() {
+            // Skips hashing on every call of a program without such closures
+            return &[];
+        }
+        self.enclosing
+            .get(&(Rc::as_ptr(scope) as usize))
+            .map_or(&[], |entry| entry.parents.as_slice())
+    }
+
+    fn record(&mut self, scope: &Scope, parents: Vec<Scope>) {
+        if self.enclosing.len() >= self.prune_at.max(MAX_RECORDED_SCOPES) {
+            self.enclosing
+                .retain(|_, entry| entry.scope.strong_count() > 0);
+            // Scopes still alive stay; grow rather than prune on every insert
+            self.prune_at = self.enclosing.len() * 2;
+        }
+        self.enclosing.insert(
+            Rc::as_ptr(scope) as usize,
+            Enclosing {
+                scope: Rc::downgrade(scope),
+                parents,
+            },
+        );
+    }
+}
+
+impl Interpreter {
+    /// Record the scopes enclosing the current one for a closure created in it
+    ///
+    /// Only the scopes pushed since the running closure was entered (or, at
+    /// top level, above the global scope) are recorded: the rest belong to
+    /// callers, which the closure must not see.
+    ///
+    /// # Complexity
+    /// Cyclomatic complexity: 3 (within Toyota Way limits)
+    pub(crate) fn capture_enclosing_scopes(&mut self) {
+        let top = self.env_stack.len() - 1;
+        let base = self.capture_scopes.frames.last().copied().unwrap_or(1);
+        let scope = &self.env_stack[top];
+        if base >= top || !self.capture_scopes.parents(scope).is_empty() {
+            return;
+        }
+        let global = &self.env_stack[0];
+        let parents: Vec<Scope> = self.env_stack[base..top]
+            .iter()
+            .filter(|parent| !Rc::ptr_eq(parent, global))
+            .cloned()
+            .collect();
+        if !parents.is_empty() {
+            let scope = Rc::clone(scope);
+            self.capture_scopes.record(&scope, parents);
+        }
+    }
+
+    /// Enter a closure: push the scopes it was created in, outermost first
+    ///
+    /// Returns the environment stack length to restore with
+    /// [`Self::leave_closure_env`].
+    ///
+    /// # Complexity
+    /// Cyclomatic complexity: 1 (within Toyota Way limits)
+    pub(crate) fn enter_closure_env(&mut self, env: &Scope) -> usize {
+        let base = self.env_stack.len();
+        let parents = self.capture_scopes.parents(env).to_vec();
+        self.env_stack.extend(parents);
+        self.env_stack.push(Rc::clone(env));
+        self.capture_scopes.frames.push(base);
+        base
+    }
+
+    /// Leave a closure entered at `base`, popping every scope pushed since
+    ///
+    /// # Complexity
+    /// Cyclomatic complexity: 1 (within Toyota Way limits)
+    pub(crate) fn leave_closure_env(&mut self, base: usize) {
+        self.capture_scopes.frames.pop();
+        self.env_stack.truncate(base.max(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::Parser;
+
+    fn eval(source: &str) -> Value {
+        let mut interpreter = Interpreter::new();
+        let ast = Parser::new(source).parse().expect("should parse");
+        interpreter.eval_expr(&ast).expect("should evaluate")
+    }
+
+    #[test]
+    fn test_closure_sees_function_locals_from_nested_block() {
+        let value = eval(
+            "fun make_counter() {
+                let mut count = 0
+                if true {
+                    let step = 2
+                    || { count += step; count }
+                } else {
+                    || 0
+                }
+            }
+            let counter = make_counter()
+            counter()
+            counter()",
+        );
+        assert_eq!(value, Value::Integer(4));
+    }
+
+    #[test]
+    fn test_closures_from_nested_block_share_variable() {
+        let value = eval(
+            "fun make_pair() {
+                let mut n = 0
+                if true {
+                    (|| { n += 1 }, || n)
+                } else {
+                    (|| 0, || 0)
+                }
+            }
+            let pair = make_pair()
+            let inc = pair.0
+            let get = pair.1
+            inc()
+            inc()
+            get()",
+        );
+        assert_eq!(value, Value::Integer(2));
+    }
+
+    #[test]
+    fn test_loop_closures_capture_their_iteration() {
+        let value = eval(
+            "let mut fs = []
+            for i in 0..3 {
+                fs.push(|| i)
+            }
+            let mut seen = []
+            for f in fs {
+                seen.push(f())
+            }
+            seen.push(i)
+            seen",
+        );
+        assert_eq!(
+            value,
+            Value::from_array(vec![
+                Value::Integer(0),
+                Value::Integer(1),
+                Value::Integer(2),
+                Value::Integer(2),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_closure_sees_later_assignment() {
+        let value = eval(
+            "let mut x = 1
+            let get = || x
+            x = 5
+            get()",
+        );
+        assert_eq!(value, Value::Integer(5));
+    }
+
+    #[test]
+    fn test_leave_closure_env_restores_stack() {
+        let mut interpreter = Interpreter::new();
+        let outer: Scope = Rc::new(RefCell::new(HashMap::new()));
+        let scope: Scope = Rc::new(RefCell::new(HashMap::new()));
+        interpreter
+            .capture_scopes
+            .record(&scope, vec![Rc::clone(&outer)]);
+
+        let depth = interpreter.env_stack.len();
+        let base = interpreter.enter_closure_env(&scope);
+        assert_eq!(interpreter.env_stack.len(), depth + 2);
+        assert!(Rc::ptr_eq(&interpreter.env_stack[depth], &outer));
+        interpreter.leave_closure_env(base);
+        assert_eq!(interpreter.env_stack.len(), depth);
+    }
+
+    #[test]
+    fn test_dropped_scopes_are_pruned() {
+        let mut scopes = CaptureScopes::new();
+        let kept: Scope = Rc::new(RefCell::new(HashMap::new()));
+        scopes.record(&kept, vec![Rc::new(RefCell::new(HashMap::new()))]);
+        for _ in 0..MAX_RECORDED_SCOPES {
+            let dropped: Scope = Rc::new(RefCell::new(HashMap::new()));
+            scopes.record(&dropped, vec![Rc::clone(&kept)]);
+        }
+        assert!(scopes.enclosing.len() <= MAX_RECORDED_SCOPES);
+        assert_eq!(scopes.parents(&kept).len(), 1);
+    }
+}
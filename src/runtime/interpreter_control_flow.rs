@@ -12,8 +12,11 @@
 #![allow(clippy::cast_possible_truncation)]
 
 use crate::frontend::ast::{BinaryOp as AstBinaryOp, Expr, ExprKind, Literal, MatchArm, Pattern};
+use crate::runtime::eval_iterator::LazyIter;
 use crate::runtime::interpreter::{Interpreter, LoopControlOrError};
 use crate::runtime::{InterpreterError, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
 use std::sync::Arc;
 
 impl Interpreter {
@@ -35,6 +38,7 @@ impl Interpreter {
                 ref end,
                 inclusive,
             } => self.eval_for_range_iteration(label, var, start, end, inclusive, body),
+            Value::Iterator(ref iter) => self.eval_for_iterator_iteration(label, var, iter, body),
             _ => Err(InterpreterError::TypeError(
                 "For loop requires an iterable".to_string(),
             )),
@@ -51,10 +55,10 @@ impl Interpreter {
         body: &Expr,
     ) -> Result<Value, InterpreterError> {
         let mut last_value = Value::nil();
+        let pass_scope = body.contains_closure();
 
         for item in arr {
-            self.set_variable(loop_var, item.clone());
-            match self.eval_loop_body_with_control_flow(body) {
+            match self.eval_for_pass(loop_var, item.clone(), body, pass_scope) {
                 Ok(value) => last_value = value,
                 Err(LoopControlOrError::Break(break_label, break_val)) => {
                     // If break has no label or matches this loop's label, break here
@@ -98,10 +102,10 @@ impl Interpreter {
     ) -> Result<Value, InterpreterError> {
         let (start_val, end_val) = self.extract_range_bounds(start, end)?;
         let mut last_value = Value::nil();
+        let pass_scope = body.contains_closure();
 
         for i in self.create_range_iterator(start_val, end_val, inclusive) {
-            self.set_variable(loop_var, Value::Integer(i));
-            match self.eval_loop_body_with_control_flow(body) {
+            match self.eval_for_pass(loop_var, Value::Integer(i), body, pass_scope) {
                 Ok(value) => last_value = value,
                 Err(LoopControlOrError::Break(break_label, break_val)) => {
                     if break_label.is_none() || break_label.as_deref() == label.map(String::as_str)
@@ -128,6 +132,78 @@ impl Interpreter {
         Ok(last_value)
     }
 
+    /// Evaluate for loop iteration over a lazy iterator, pulling one element per pass
+    /// Complexity: ≤9
+    pub(crate) fn eval_for_iterator_iteration(
+        &mut self,
+        label: Option<&String>,
+        loop_var: &str,
+        iter: &Rc<RefCell<LazyIter>>,
+        body: &Expr,
+    ) -> Result<Value, InterpreterError> {
+        let mut last_value = Value::nil();
+        let pass_scope = body.contains_closure();
+
+        loop {
+            // Release the borrow before the body runs so it may advance the iterator too
+            let item = iter
+                .borrow_mut()
+                .next(&mut |func, args| self.eval_function_call_value(func, args))?;
+            let Some(item) = item else {
+                break;
+            };
+            match self.eval_for_pass(loop_var, item, body, pass_scope) {
+                Ok(value) => last_value = value,
+                Err(LoopControlOrError::Break(break_label, break_val)) => {
+                    if break_label.is_none() || break_label.as_deref() == label.map(String::as_str)
+                    {
+                        return Ok(break_val);
+                    }
+                    return Err(InterpreterError::Break(break_label, break_val));
+                }
+                Err(LoopControlOrError::Continue(continue_label)) => {
+                    if continue_label.is_none()
+                        || continue_label.as_deref() == label.map(String::as_str)
+                    {
+                        continue;
+                    }
+                    return Err(InterpreterError::Continue(continue_label));
+                }
+                Err(LoopControlOrError::Return(return_val)) => {
+                    return Err(InterpreterError::Return(return_val))
+                }
+                Err(LoopControlOrError::Error(e)) => return Err(e),
+            }
+        }
+
+        Ok(last_value)
+    }
+
+    /// Bind the loop variable to `item` and run one pass of a for loop body
+    ///
+    /// The variable stays bound after the loop, holding the last element.
+    /// With `pass_scope`, set when the body creates closures, the pass also
+    /// binds it in a scope of its own, so each closure captures the element
+    /// of the pass that created it rather than the variable all passes share.
+    fn eval_for_pass(
+        &mut self,
+        loop_var: &str,
+        item: Value,
+        body: &Expr,
+        pass_scope: bool,
+    ) -> Result<Value, LoopControlOrError> {
+        if !pass_scope {
+            self.set_variable(loop_var, item);
+            return self.eval_loop_body_with_control_flow(body);
+        }
+        self.set_variable(loop_var, item.clone());
+        self.push_scope();
+        self.env_set(loop_var.to_string(), item);
+        let result = self.eval_loop_body_with_control_flow(body);
+        self.pop_scope();
+        result
+    }
+
     /// Extract integer bounds from range values
     /// Complexity: ≤3
     pub(crate) fn extract_range_bounds(
@@ -159,11 +235,15 @@ impl Interpreter {
     }
 
     /// Evaluate loop body with control flow handling
-    /// Complexity: ≤5
+    ///
+    /// Every loop iteration passes through here, so this is where an
+    /// interrupt request (see [`crate::runtime::interrupt`]) takes effect.
+    /// Complexity: ≤6
     pub(crate) fn eval_loop_body_with_control_flow(
         &mut self,
         body: &Expr,
     ) -> Result<Value, LoopControlOrError> {
+        crate::runtime::interrupt::check().map_err(LoopControlOrError::Error)?;
         match self.eval_expr(body) {
             Ok(value) => Ok(value),
             Err(InterpreterError::Break(label, val)) => Err(LoopControlOrError::Break(label, val)),
@@ -486,7 +566,8 @@ impl Interpreter {
 
     /// Evaluate field assignment: `obj.field = value`.
     ///
-    /// Handles Object, ObjectMut, Class, and Struct field updates.
+    /// Handles Object, ObjectMut, Class, and Struct field updates, and class
+    /// properties with a setter.
     fn eval_field_assign(
         &mut self,
         object: &Expr,
@@ -500,6 +581,11 @@ impl Interpreter {
         };
         let obj = self.lookup_variable(obj_name)?;
 
+        // Class properties: assignment runs the setter instead of adding a field
+        if let Some(property) = self.class_property(&obj, field) {
+            return self.assign_class_property(obj_name, &obj, field, &property, val);
+        }
+
         match obj {
             Value::Object(ref map) => {
                 let mut new_map = (**map).clone();
@@ -549,35 +635,7 @@ impl Interpreter {
             ExprKind::Identifier(arr_name) => {
                 // Simple case: arr[i] = value
                 let idx_val = self.eval_expr(index)?;
-                let idx = match idx_val {
-                    Value::Integer(i) => i as usize,
-                    _ => {
-                        return Err(InterpreterError::RuntimeError(
-                            "Array index must be an integer".to_string(),
-                        ))
-                    }
-                };
-
-                let arr = self.lookup_variable(arr_name)?;
-                match arr {
-                    Value::Array(ref vec) => {
-                        let mut new_vec = vec.to_vec();
-                        if idx < new_vec.len() {
-                            new_vec[idx] = val.clone();
-                            self.set_variable(arr_name, Value::Array(Arc::from(new_vec)));
-                            Ok(val)
-                        } else {
-                            Err(InterpreterError::RuntimeError(format!(
-                                "Index {} out of bounds for array of length {}",
-                                idx,
-                                new_vec.len()
-                            )))
-                        }
-                    }
-                    _ => Err(InterpreterError::RuntimeError(
-                        "Cannot index non-array value".to_string(),
-                    )),
-                }
+                self.assign_array_element(arr_name, idx_val, val)
             }
             ExprKind::IndexAccess {
                 object: nested_obj,
@@ -611,46 +669,131 @@ impl Interpreter {
         }
     }
 
-    /// Evaluate a compound assignment
-    /// Complexity: 6
+    /// Store `val` at `idx_val` of the array in variable `arr_name`
+    /// Complexity: 2
+    pub(crate) fn assign_array_element(
+        &mut self,
+        arr_name: &str,
+        idx_val: Value,
+        val: Value,
+    ) -> Result<Value, InterpreterError> {
+        let idx = Self::array_assign_index(idx_val)?;
+        let arr = self.lookup_variable(arr_name)?;
+        let updated = Self::replace_array_element(&arr, idx, val.clone())?;
+        self.set_variable(arr_name, updated);
+        Ok(val)
+    }
+
+    /// Position named by the index of an array element assignment
+    pub(crate) fn array_assign_index(idx_val: Value) -> Result<usize, InterpreterError> {
+        match idx_val {
+            Value::Integer(i) => Ok(i as usize),
+            _ => Err(InterpreterError::RuntimeError(
+                "Array index must be an integer".to_string(),
+            )),
+        }
+    }
+
+    /// Copy of array `arr` with element `idx` replaced by `val`
+    /// Complexity: 3
+    pub(crate) fn replace_array_element(
+        arr: &Value,
+        idx: usize,
+        val: Value,
+    ) -> Result<Value, InterpreterError> {
+        match arr {
+            Value::Array(vec) => {
+                let mut new_vec = vec.to_vec();
+                if idx < new_vec.len() {
+                    new_vec[idx] = val;
+                    Ok(Value::Array(Arc::from(new_vec)))
+                } else {
+                    Err(InterpreterError::RuntimeError(format!(
+                        "Index {} out of bounds for array of length {}",
+                        idx,
+                        new_vec.len()
+                    )))
+                }
+            }
+            _ => Err(InterpreterError::RuntimeError(
+                "Cannot index non-array value".to_string(),
+            )),
+        }
+    }
+
+    /// Evaluate a compound assignment (`x += 1`, `arr[i] *= 2`, `obj.f ??= v`)
+    ///
+    /// The target is read before the right-hand side is evaluated, and an
+    /// index is evaluated once. `&&=`, `||=` and `??=` short-circuit like
+    /// `&&`, `||` and `??`: when the current value decides the result, the
+    /// right-hand side is not evaluated and the target is not written.
+    /// Complexity: 8
     pub(crate) fn eval_compound_assign(
         &mut self,
         target: &Expr,
         op: AstBinaryOp,
         value: &Expr,
     ) -> Result<Value, InterpreterError> {
-        // Get current value
-        let current = match &target.kind {
-            ExprKind::Identifier(name) => self.lookup_variable(name)?,
-            ExprKind::FieldAccess { object, field } => self.eval_field_access(object, field)?,
-            _ => {
-                return Err(InterpreterError::RuntimeError(
-                    "Invalid compound assignment target".to_string(),
-                ))
-            }
-        };
-
-        // Compute new value
-        let rhs = self.eval_expr(value)?;
-        let new_val = self.apply_binary_op(&current, op, &rhs)?;
-
-        // Assign back
         match &target.kind {
             ExprKind::Identifier(name) => {
+                let current = self.lookup_variable(name)?;
+                let Some(new_val) = self.eval_compound_value(&current, op, value)? else {
+                    return Ok(current);
+                };
                 self.set_variable(name, new_val.clone());
+                Ok(new_val)
             }
             ExprKind::FieldAccess { object, field } => {
+                let current = self.eval_field_access(object, field)?;
+                let Some(new_val) = self.eval_compound_value(&current, op, value)? else {
+                    return Ok(current);
+                };
                 // Reuse eval_field_assign which handles all object types
-                self.eval_field_assign(object, field, new_val.clone())?;
+                self.eval_field_assign(object, field, new_val)
             }
-            _ => {
-                return Err(InterpreterError::RuntimeError(
-                    "Complex assignment targets not supported in compound assignment".to_string(),
-                ))
+            ExprKind::IndexAccess { object, index } => {
+                let ExprKind::Identifier(arr_name) = &object.kind else {
+                    return Err(InterpreterError::RuntimeError(
+                        "Complex assignment targets not supported in compound assignment"
+                            .to_string(),
+                    ));
+                };
+                let idx_val = self.eval_expr(index)?;
+                let current = Self::index_value(&self.lookup_variable(arr_name)?, &idx_val)?;
+                let Some(new_val) = self.eval_compound_value(&current, op, value)? else {
+                    return Ok(current);
+                };
+                self.assign_array_element(arr_name, idx_val, new_val)
             }
+            _ => Err(InterpreterError::RuntimeError(
+                "Invalid compound assignment target".to_string(),
+            )),
         }
+    }
 
-        Ok(new_val)
+    /// New value of a compound assignment target, or `None` when a
+    /// short-circuiting operator keeps `current`
+    /// Complexity: 6
+    fn eval_compound_value(
+        &mut self,
+        current: &Value,
+        op: AstBinaryOp,
+        value: &Expr,
+    ) -> Result<Option<Value>, InterpreterError> {
+        let keeps_current = match op {
+            AstBinaryOp::And => !current.is_truthy(),
+            AstBinaryOp::Or => current.is_truthy(),
+            AstBinaryOp::NullCoalesce => !matches!(current, Value::Nil),
+            _ => {
+                let rhs = self.eval_expr(value)?;
+                return self.apply_binary_op(current, op, &rhs).map(Some);
+            }
+        };
+        if keeps_current {
+            Ok(None)
+        } else {
+            self.eval_expr(value).map(Some)
+        }
     }
 }
 
@@ -681,6 +824,8 @@ mod tests {
             guard: guard.map(Box::new),
             body: Box::new(body),
             span: Span::default(),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -341,6 +341,7 @@ fn test_eval_list_comprehension_simple() {
     let element = make_expr(ExprKind::Identifier("x".to_string()));
     let clauses = vec![ComprehensionClause {
         variable: "x".to_string(),
+        pattern: crate::frontend::ast::Pattern::Identifier("x".to_string()),
         iterable: Box::new(make_expr(ExprKind::List(vec![
             make_expr(ExprKind::Literal(Literal::Integer(1, None))),
             make_expr(ExprKind::Literal(Literal::Integer(2, None))),
@@ -383,13 +383,13 @@ impl Interpreter {
                 // Push environment
                 self.env_push(new_env);
 
-                // Evaluate the body
-                let result = self.eval_expr(body)?;
+                // Evaluate the body; `return` exits the lambda
+                let result = InterpreterError::catch_return(self.eval_expr(body));
 
                 // Pop environment
                 self.env_pop();
 
-                Ok(result)
+                result
             }
             _ => Err(InterpreterError::RuntimeError(
                 "Expected lambda expression".to_string(),
@@ -577,6 +577,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -1238,6 +1240,8 @@ mod tests {
                 span: Span::default(),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             }],
             body: Box::new(make_int(1)),
         });
@@ -1453,6 +1457,8 @@ mod tests {
                 span: Span::default(),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             }],
             body: Box::new(make_int(1)),
         });
@@ -0,0 +1,178 @@
+//! `defer`: expressions run when the enclosing scope exits
+//!
+//! `defer expr` does not evaluate `expr`; it schedules it to run when the
+//! enclosing block or function body exits, whichever way it exits: falling
+//! off the end, `return`, `break`/`continue` or an error. Expressions
+//! deferred in the same scope run in reverse order, so cleanup undoes setup
+//! (`let f = open(p); defer f.close()`).
+//!
+//! Deferred expressions run before the scope's variables are dropped and see
+//! them as they are at exit. An error from the scope itself wins over one
+//! from a deferred expression; a deferred error replaces a plain value or a
+//! `return`/`break`/`continue` in flight.
+
+use crate::frontend::ast::Expr;
+use crate::runtime::interpreter::Interpreter;
+use crate::runtime::{InterpreterError, Value};
+
+/// Expressions deferred by each running scope, innermost scope last
+#[derive(Debug, Default)]
+pub struct DeferStack {
+    frames: Vec<Vec<Expr>>,
+}
+
+impl DeferStack {
+    /// Create an empty stack
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Interpreter {
+    /// Evaluate `defer expr`: schedule `expr` for the innermost running scope
+    ///
+    /// Outside any block (a lone top-level `defer`) the scope is the
+    /// statement itself, which ends at once.
+    ///
+    /// # Complexity
+    /// Cyclomatic complexity: 2 (within Toyota Way limits)
+    pub(crate) fn eval_defer(&mut self, expr: &Expr) -> Result<Value, InterpreterError> {
+        match self.deferred.frames.last_mut() {
+            Some(frame) => {
+                frame.push(expr.clone());
+                Ok(Value::Nil)
+            }
+            None => self.run_deferred(vec![expr.clone()]).map(|()| Value::Nil),
+        }
+    }
+
+    /// Run `body` as a scope: expressions it defers run when it returns
+    ///
+    /// # Complexity
+    /// Cyclomatic complexity: 4 (within Toyota Way limits)
+    pub(crate) fn with_defer_scope<T>(
+        &mut self,
+        body: impl FnOnce(&mut Self) -> Result<T, InterpreterError>,
+    ) -> Result<T, InterpreterError> {
+        self.deferred.frames.push(Vec::new());
+        let result = body(self);
+        let deferred = self.deferred.frames.pop().unwrap_or_default();
+        if deferred.is_empty() {
+            return result;
+        }
+        match (result, self.run_deferred(deferred)) {
+            (Err(e), _) if !e.is_control_flow() => Err(e),
+            (result, Ok(())) => result,
+            (_, Err(e)) => Err(e),
+        }
+    }
+
+    /// Run deferred expressions last first, all of them even if one fails
+    ///
+    /// Returns the first failure. Control flow may not leave a deferred
+    /// expression: there is no loop or function of its own to go to.
+    ///
+    /// # Complexity
+    /// Cyclomatic complexity: 4 (within Toyota Way limits)
+    fn run_deferred(&mut self, deferred: Vec<Expr>) -> Result<(), InterpreterError> {
+        let mut first_error = None;
+        for expr in deferred.iter().rev() {
+            let error = match self.eval_expr(expr) {
+                Ok(_) => continue,
+                Err(e) if e.is_control_flow() => InterpreterError::RuntimeError(
+                    "break, continue and return cannot leave a deferred expression".to_string(),
+                ),
+                Err(e) => e,
+            };
+            first_error.get_or_insert(error);
+        }
+        first_error.map_or(Ok(()), Err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::Parser;
+
+    fn eval(source: &str) -> Result<Value, InterpreterError> {
+        let ast = Parser::new(source).parse().expect("should parse");
+        Interpreter::new().eval_expr(&ast)
+    }
+
+    #[test]
+    fn test_deferred_expressions_run_last_first_at_block_exit() {
+        let value = eval(
+            r#"
+            let mut log = []
+            {
+                defer log.push("first")
+                defer log.push("second")
+                log.push("body")
+            }
+            log
+            "#,
+        )
+        .unwrap();
+        assert_eq!(value.to_string(), r#"["body", "second", "first"]"#);
+    }
+
+    #[test]
+    fn test_defer_runs_on_early_return_and_sees_locals() {
+        let value = eval(
+            r#"
+            let mut closed = []
+            fun work(n) {
+                let name = f"file{n}"
+                defer closed.push(name)
+                if n > 1 { return n * 10 }
+                0
+            }
+            let results = [work(1), work(2)]
+            (results, closed)
+            "#,
+        )
+        .unwrap();
+        assert_eq!(value.to_string(), r#"([0, 20], ["file1", "file2"])"#);
+    }
+
+    #[test]
+    fn test_defer_runs_when_body_fails_and_error_is_kept() {
+        let value = eval(
+            r#"
+            let mut cleaned = false
+            fun failing() {
+                defer { cleaned = true }
+                throw "boom"
+            }
+            let caught = try { failing() } catch (e) { e }
+            (caught, cleaned)
+            "#,
+        )
+        .unwrap();
+        assert_eq!(value.to_string(), r#"("boom", true)"#);
+    }
+
+    #[test]
+    fn test_defer_runs_each_loop_iteration() {
+        let value = eval(
+            r"
+            let mut order = []
+            for i in 0..3 {
+                defer order.push(i)
+                if i == 1 { continue }
+                order.push(i * 10)
+            }
+            order
+            ",
+        )
+        .unwrap();
+        assert_eq!(value.to_string(), "[0, 0, 1, 20, 2]");
+    }
+
+    #[test]
+    fn test_return_cannot_leave_deferred_expression() {
+        let err = eval("fun f() { defer { return 1 }; 2 }; f()").unwrap_err();
+        assert!(err.to_string().contains("deferred expression"), "{err}");
+    }
+}
@@ -16,7 +16,9 @@ use crate::frontend::ast::{Expr, ExprKind};
 use crate::frontend::Param;
 use crate::runtime::interpreter::Interpreter;
 use crate::runtime::{InterpreterError, Value};
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::rc::Rc;
 use std::sync::Arc;
 
 impl Interpreter {
@@ -28,17 +30,7 @@ impl Interpreter {
         body: &Expr,
     ) -> Result<Value, InterpreterError> {
         // RUNTIME-DEFAULT-PARAMS: Extract both param names AND default values
-        let params_with_defaults: Vec<(String, Option<Arc<Expr>>)> = params
-            .iter()
-            .map(|p| {
-                (
-                    p.name(),
-                    p.default_value
-                        .clone()
-                        .map(|expr| Arc::new((*expr).clone())),
-                )
-            })
-            .collect();
+        let params_with_defaults = eval_func::closure_params(params);
 
         let closure = Value::Closure {
             params: params_with_defaults,
@@ -48,101 +40,145 @@ impl Interpreter {
 
         // Bind function name in environment for recursion
         self.env_set(name.to_string(), closure.clone());
+        self.capture_enclosing_scopes();
         Ok(closure)
     }
 
     /// Evaluate lambda expression
+    ///
+    /// The closure captures the current scope by reference, along with the
+    /// scopes enclosing it (see `interpreter_captures`).
     pub(crate) fn eval_lambda(
         &mut self,
         params: &[Param],
         body: &Expr,
     ) -> Result<Value, InterpreterError> {
-        eval_func::eval_lambda(params, body, self.current_env())
+        let closure = eval_func::eval_lambda(params, body, self.current_env())?;
+        self.capture_enclosing_scopes();
+        Ok(closure)
     }
 
-    /// Extract named arguments from assignment expressions and reorder them
-    /// to match function parameter order.
-    ///
-    /// Named arguments use the syntax `param_name = value` and can be in any order.
-    /// This function detects such assignment expressions and reorders them to match
-    /// the function's parameter order.
+    /// Evaluate call arguments, binding named ones to the callee's parameters
     ///
-    /// # Arguments
-    /// * `args` - The argument expressions from the call site
-    /// * `param_names` - The parameter names from the function definition
-    ///
-    /// # Returns
-    /// A reordered list of argument expressions matching parameter order
-    pub(crate) fn reorder_named_args<'a>(
-        &self,
-        args: &'a [Expr],
-        param_names: &[String],
-    ) -> Vec<&'a Expr> {
-        // Extract named args: (name, expr) for Assign expressions, (None, expr) for positional
-        let mut named_args: Vec<(Option<String>, &Expr)> = Vec::new();
-
-        for arg in args {
-            if let ExprKind::Assign { target, value } = &arg.kind {
-                if let ExprKind::Identifier(name) = &target.kind {
-                    // This is a named argument: name = value
-                    named_args.push((Some(name.clone()), value.as_ref()));
-                } else {
-                    // Assignment to non-identifier, treat as positional
-                    named_args.push((None, arg));
+    /// Named arguments (`name: value` or `name = value`) only apply to user
+    /// functions; for anything else the arguments are evaluated as written.
+    /// `...list` arguments are spread into the positional ones.
+    fn eval_call_args(
+        &mut self,
+        callee: Option<&Value>,
+        args: &[Expr],
+    ) -> Result<Vec<Value>, InterpreterError> {
+        match callee {
+            Some(Value::Closure { params, env, .. })
+                if args.iter().any(|a| named_arg(a).is_some()) =>
+            {
+                self.bind_named_args(params, env, args)
+            }
+            _ if args
+                .iter()
+                .any(|a| matches!(a.kind, ExprKind::Spread { .. })) =>
+            {
+                let mut values = Vec::with_capacity(args.len());
+                for arg in args {
+                    values.extend(self.eval_positional_arg(arg)?);
                 }
-            } else {
-                // Positional argument
-                named_args.push((None, arg));
+                Ok(values)
             }
+            _ => args.iter().map(|arg| self.eval_expr(arg)).collect(),
         }
+    }
 
-        // Check if we have any named arguments
-        let has_named = named_args.iter().any(|(name, _)| name.is_some());
-        if !has_named {
-            // No named args, return original order
-            return args.iter().collect();
+    /// Evaluate a positional argument, spreading `...list` into its elements
+    fn eval_positional_arg(&mut self, arg: &Expr) -> Result<Vec<Value>, InterpreterError> {
+        let ExprKind::Spread { expr } = &arg.kind else {
+            return Ok(vec![self.eval_expr(arg)?]);
+        };
+        match self.eval_expr(expr)? {
+            Value::Array(items) | Value::Tuple(items) => Ok(items.to_vec()),
+            other => Err(InterpreterError::TypeError(format!(
+                "Cannot spread {} into function arguments",
+                other.type_name()
+            ))),
         }
+    }
 
-        // Reorder arguments to match parameter order
-        // Only include slots up to the number of provided args
-        let mut result: Vec<Option<&Expr>> = vec![None; param_names.len()];
-        let mut positional_idx = 0;
-
-        for (name, expr) in &named_args {
-            if let Some(param_name) = name {
-                // Named argument - find its position
-                if let Some(pos) = param_names.iter().position(|p| p == param_name) {
-                    result[pos] = Some(*expr);
-                } else {
-                    // Unknown parameter name - this will be an error at call time
-                    // For now, just append to results
-                    if positional_idx < result.len() {
-                        while positional_idx < result.len() && result[positional_idx].is_some() {
-                            positional_idx += 1;
-                        }
-                        if positional_idx < result.len() {
-                            result[positional_idx] = Some(*expr);
-                        }
-                    }
-                }
-            } else {
-                // Positional argument - find next empty slot
-                while positional_idx < result.len() && result[positional_idx].is_some() {
-                    positional_idx += 1;
+    /// Bind positional and named arguments to parameter slots
+    ///
+    /// Positional arguments fill slots from the left and named ones go to the
+    /// parameter of that name. Arguments are evaluated in source order, and
+    /// skipped parameters take their defaults, evaluated in the closure's
+    /// environment. Returns one value per fixed parameter, followed by the
+    /// surplus positional values for a rest parameter to collect.
+    pub(crate) fn bind_named_args(
+        &mut self,
+        params: &[(String, Option<Arc<Expr>>)],
+        env: &Rc<RefCell<HashMap<String, Value>>>,
+        args: &[Expr],
+    ) -> Result<Vec<Value>, InterpreterError> {
+        let has_rest = params
+            .last()
+            .is_some_and(|(_, default)| eval_func::is_rest_default(default.as_ref()));
+        let fixed = &params[..params.len() - usize::from(has_rest)];
+        let mut slots: Vec<Option<Value>> = vec![None; fixed.len()];
+        let mut surplus = Vec::new();
+        let mut next_positional = 0;
+        for arg in args {
+            if let Some((name, value)) = named_arg(arg) {
+                let slot = fixed
+                    .iter()
+                    .position(|(param, _)| param == name)
+                    .ok_or_else(|| {
+                        InterpreterError::RuntimeError(format!("Unknown named argument '{name}'"))
+                    })?;
+                if slots[slot].is_some() {
+                    return Err(InterpreterError::RuntimeError(format!(
+                        "Argument '{name}' given more than once"
+                    )));
                 }
-                if positional_idx < result.len() {
-                    result[positional_idx] = Some(*expr);
-                    positional_idx += 1;
+                slots[slot] = Some(self.eval_expr(value)?);
+                continue;
+            }
+            for value in self.eval_positional_arg(arg)? {
+                match slots.get_mut(next_positional) {
+                    Some(Some(_)) => {
+                        return Err(InterpreterError::RuntimeError(format!(
+                            "Argument '{}' given more than once",
+                            fixed[next_positional].0
+                        )))
+                    }
+                    Some(slot) => *slot = Some(value),
+                    None if has_rest => surplus.push(value),
+                    None => {
+                        return Err(InterpreterError::RuntimeError(format!(
+                            "Function expects at most {} arguments, got more",
+                            params.len()
+                        )))
+                    }
                 }
+                next_positional += 1;
             }
         }
 
-        // Count how many args were actually provided
-        let provided_count = named_args.len();
-
-        // Only return up to the number of provided args, in parameter order
-        // This allows default params to kick in for any trailing None slots
-        result.into_iter().take(provided_count).flatten().collect()
+        let mut values = Vec::with_capacity(params.len() + surplus.len());
+        for (slot, (name, default)) in slots.into_iter().zip(fixed) {
+            let value = match (slot, default) {
+                (Some(value), _) => value,
+                (None, Some(default)) => {
+                    self.env_stack.push(Rc::clone(env));
+                    let value = self.eval_expr(default);
+                    self.env_pop();
+                    value?
+                }
+                (None, None) => {
+                    return Err(InterpreterError::RuntimeError(format!(
+                        "Missing argument '{name}'"
+                    )))
+                }
+            };
+            values.push(value);
+        }
+        values.extend(surplus);
+        Ok(values)
     }
 
     /// Evaluate function call
@@ -226,94 +262,57 @@ impl Interpreter {
                 let qualified_method = format!("{}::{}", type_name, field);
                 if let Ok(method_value) = self.lookup_variable(&qualified_method) {
                     // Found impl method - evaluate args and call it
-                    let arg_vals: Result<Vec<Value>, InterpreterError> =
-                        args.iter().map(|arg| self.eval_expr(arg)).collect();
-                    let arg_vals = arg_vals?;
+                    let arg_vals = self.eval_call_args(Some(&method_value), args)?;
                     return self.call_function(method_value, &arg_vals);
                 }
             }
         }
 
-        // NAMED-PARAMS-FIX: Check for named arguments (assignment expressions) and reorder
-        // Named arguments allow: greet(greeting = "Hi", name = "Bob") -> greet("Bob", "Hi")
-        let has_named_args = args.iter().any(|arg| {
-            matches!(
-                &arg.kind,
-                ExprKind::Assign { target, .. } if matches!(&target.kind, ExprKind::Identifier(_))
-            )
-        });
-
-        // If we have named arguments, try to look up the function first to get parameter names
-        let reordered_args: Vec<&Expr> = if has_named_args {
-            // Try to get function's parameter names
-            if let ExprKind::Identifier(func_name) = &func.kind {
-                if let Ok(func_val) = self.lookup_variable(func_name) {
-                    if let Value::Closure { params, .. } = &func_val {
-                        let param_names: Vec<String> =
-                            params.iter().map(|(name, _)| name.clone()).collect();
-                        self.reorder_named_args(args, &param_names)
-                    } else {
-                        args.iter().collect()
-                    }
-                } else {
-                    args.iter().collect()
-                }
-            } else {
-                args.iter().collect()
+        // NAMED-PARAMS-FIX: Named arguments bind by parameter name
+        // greet("Bob", greeting: "Hi") and greet(greeting = "Hi", name = "Bob") both work
+        let callee = match &func.kind {
+            ExprKind::Identifier(func_name) if args.iter().any(|arg| named_arg(arg).is_some()) => {
+                self.lookup_variable(func_name).ok()
             }
-        } else {
-            args.iter().collect()
+            _ => None,
         };
 
         // ISSUE-119 FIX: Evaluate args ONCE at the start to prevent double-evaluation
         // This ensures that side-effects (like counter++) only happen once
-        let arg_vals: Vec<Value> = reordered_args
-            .iter()
-            .map(|arg| self.eval_expr(arg))
-            .collect::<Result<Vec<_>, _>>()?;
+        let arg_vals = self.eval_call_args(callee.as_ref(), args)?;
 
+        self.call_with_args(func, arg_vals)
+    }
+
+    /// Call `func` with already-evaluated positional arguments
+    ///
+    /// Builtins and `Ok`/`Err`/`Some` constructors win over bindings, an
+    /// undefined identifier builds a message object, and the call is traced
+    /// and recorded for type feedback. User calls recurse through here, so
+    /// the rest of the work happens in helpers with frames of their own.
+    pub(crate) fn call_with_args(
+        &mut self,
+        func: &Expr,
+        arg_vals: Vec<Value>,
+    ) -> Result<Value, InterpreterError> {
         // ISSUE-117: Check builtin functions BEFORE variable lookup
         // This ensures parse_json(), stringify_json(), open(), etc. work as functions
         if let ExprKind::Identifier(name) = &func.kind {
-            // ISSUE-119 FIX: Convert name to builtin marker format (__builtin_NAME__)
-            // to match eval_builtin::try_eval_io_function expectations
-            let builtin_name = format!("__builtin_{}__", name);
-
-            // RUNTIME-BUG-002: Propagate builtin function errors instead of falling back to Message objects
-            match crate::runtime::eval_builtin::eval_builtin_function(&builtin_name, &arg_vals) {
-                Ok(Some(result)) => return Ok(result),
-                Ok(None) => {}           // Fall through to normal function evaluation
-                Err(e) => return Err(e), // Propagate error (parse_int/parse_float errors, etc.)
+            if let Some(result) = self.call_builtin(name, &arg_vals)? {
+                return Ok(result);
             }
         }
 
         // Try to evaluate the function normally
-        let func_val_result = self.eval_expr(func);
-
-        // If function lookup fails and it's an identifier, treat it as a message constructor
-        let func_val = match func_val_result {
+        let func_val = match self.eval_expr(func) {
             Ok(val) => val,
+            // If function lookup fails and it's an identifier, treat it as a message constructor
             Err(InterpreterError::RuntimeError(msg)) if msg.starts_with("Undefined variable:") => {
-                // Check if this is an identifier that could be a message constructor
-                if let ExprKind::Identifier(name) = &func.kind {
-                    // Create a message object - args already evaluated above
-                    let mut message = HashMap::new();
-                    message.insert(
-                        "__type".to_string(),
-                        Value::from_string("Message".to_string()),
-                    );
-                    message.insert("type".to_string(), Value::from_string(name.clone()));
-                    message.insert("data".to_string(), Value::Array(Arc::from(arg_vals)));
-
-                    return Ok(Value::Object(Arc::new(message)));
-                }
-                return Err(InterpreterError::RuntimeError(msg));
+                return Self::message_constructor(func, msg, arg_vals);
             }
             Err(e) => return Err(e),
         };
 
-        // arg_vals already evaluated at the start - no need to re-evaluate
-
         // Special handling for enum variant construction with arguments (tuple variants)
         if let Value::EnumVariant {
             enum_name,
@@ -322,11 +321,7 @@ impl Interpreter {
         } = func_val
         {
             // This is a tuple variant constructor: Response::Error("msg")
-            return Ok(Value::EnumVariant {
-                enum_name,
-                variant_name,
-                data: Some(arg_vals),
-            });
+            return self.construct_enum_variant(enum_name, variant_name, arg_vals);
         }
 
         // DEBUGGER-014 Phase 1.3: Extract function name for tracing
@@ -338,36 +333,16 @@ impl Interpreter {
         // DEBUGGER-014 Phase 3: Type-aware tracing with argument/return values and types
         let trace_enabled = std::env::var("RUCHY_TRACE").is_ok();
         if trace_enabled {
-            // Format argument values with type annotations for trace output
-            let args_str = arg_vals
-                .iter()
-                .map(|v| {
-                    // Format value with proper string quoting + type annotation
-                    let value_str = match v {
-                        Value::String(s) => format!("\"{}\"", s),
-                        other => other.to_string(),
-                    };
-                    format!("{}: {}", value_str, v.type_name())
-                })
-                .collect::<Vec<_>>()
-                .join(", ");
-            println!("TRACE: → {}({})", func_name, args_str);
+            trace_call_entry(&func_name, &arg_vals);
         }
 
-        let result = self.call_function(func_val, &arg_vals)?;
+        self.push_call_trace(func_name.clone());
+        let result = self.call_function(func_val, &arg_vals);
+        self.pop_call_trace(result.as_ref().err());
+        let result = result?;
 
-        // DEBUGGER-014 Phase 3: Trace function exit with return value and type
         if trace_enabled {
-            let result_str = match &result {
-                Value::String(s) => format!("\"{}\"", s),
-                other => other.to_string(),
-            };
-            println!(
-                "TRACE: ← {} = {}: {}",
-                func_name,
-                result_str,
-                result.type_name()
-            );
+            trace_call_exit(&func_name, &result);
         }
 
         // Collect type feedback for function call
@@ -375,6 +350,131 @@ impl Interpreter {
         self.record_function_call_feedback(site_id, &func_name, &arg_vals, &result);
         Ok(result)
     }
+
+    /// Result of `name(args)` when a constructor or builtin handles it
+    fn call_builtin(
+        &mut self,
+        name: &str,
+        arg_vals: &[Value],
+    ) -> Result<Option<Value>, InterpreterError> {
+        // Ok(x), Err(e) and Option::Some(x) build Option/Result values
+        if let Some(result) = crate::runtime::eval_option_result::construct(name, arg_vals) {
+            return result.map(Some);
+        }
+
+        // `capture_output { ... }` is parsed as `capture_output(|| { ... })`
+        if name == "capture_output" {
+            return self.eval_capture_output(arg_vals).map(Some);
+        }
+
+        // ISSUE-119 FIX: Convert name to builtin marker format (__builtin_NAME__)
+        // to match eval_builtin::try_eval_io_function expectations
+        let builtin_name = format!("__builtin_{}__", name);
+
+        // Structs print through their `Display` impls
+        let displayed = match name {
+            "println" | "print" => self.display_args(arg_vals)?,
+            _ => None,
+        };
+        let builtin_args = displayed.as_deref().unwrap_or(arg_vals);
+
+        // RUNTIME-BUG-002: Propagate builtin function errors instead of falling back to Message objects
+        // Restricted evaluation skips builtins it does not allow, so a user function
+        // of the same name still resolves below, as does one the program defines
+        let user_defined = matches!(self.lookup_variable(name), Ok(Value::Closure { .. }));
+        if user_defined || !crate::runtime::restricted::allows_builtin(&builtin_name) {
+            return Ok(None);
+        }
+        // Ok(None) falls through to normal function evaluation; errors
+        // (parse_int/parse_float errors, etc.) propagate
+        crate::runtime::eval_builtin::eval_builtin_function(&builtin_name, builtin_args)
+    }
+
+    /// Message object for a call of an undefined capitalized identifier
+    ///
+    /// Message types are capitalized, so `undefined_fn()` stays the
+    /// "Undefined variable" error `msg`.
+    fn message_constructor(
+        func: &Expr,
+        msg: String,
+        arg_vals: Vec<Value>,
+    ) -> Result<Value, InterpreterError> {
+        let ExprKind::Identifier(name) = &func.kind else {
+            return Err(InterpreterError::RuntimeError(msg));
+        };
+        // A builtin skipped above is refused rather than turned into a message
+        crate::runtime::restricted::check_builtin(name)?;
+        if !name.starts_with(|c: char| c.is_ascii_uppercase()) {
+            return Err(InterpreterError::RuntimeError(msg));
+        }
+        // Create a message object - args already evaluated above
+        let mut message = HashMap::new();
+        message.insert(
+            "__type".to_string(),
+            Value::from_string("Message".to_string()),
+        );
+        message.insert("type".to_string(), Value::from_string(name.clone()));
+        message.insert("data".to_string(), Value::Array(Arc::from(arg_vals)));
+        Ok(Value::Object(Arc::new(message)))
+    }
+
+    /// Call a zero-argument function and return what it printed as a string
+    ///
+    /// If the function fails, its output is passed on to the enclosing
+    /// writer before the error propagates.
+    fn eval_capture_output(&mut self, args: &[Value]) -> Result<Value, InterpreterError> {
+        let [body] = args else {
+            return Err(InterpreterError::RuntimeError(format!(
+                "capture_output() expects 1 argument (a function), got {}",
+                args.len()
+            )));
+        };
+        let (result, printed) =
+            crate::runtime::output::capture(|| self.call_function(body.clone(), &[]));
+        if result.is_err() {
+            let _ = crate::runtime::output::write(&printed);
+        }
+        result.map(|_| Value::from_string(printed))
+    }
+}
+
+/// Print the `RUCHY_TRACE` line for entering `func_name` with type-annotated arguments
+fn trace_call_entry(func_name: &str, arg_vals: &[Value]) {
+    let args_str = arg_vals
+        .iter()
+        .map(|v| format!("{}: {}", trace_value(v), v.type_name()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    println!("TRACE: → {}({})", func_name, args_str);
+}
+
+/// DEBUGGER-014 Phase 3: Trace function exit with return value and type
+fn trace_call_exit(func_name: &str, result: &Value) {
+    println!(
+        "TRACE: ← {} = {}: {}",
+        func_name,
+        trace_value(result),
+        result.type_name()
+    );
+}
+
+/// Format value with proper string quoting for trace output
+fn trace_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => format!("\"{}\"", s),
+        other => other.to_string(),
+    }
+}
+
+/// Split a `name = value` call argument into its name and value
+fn named_arg(arg: &Expr) -> Option<(&str, &Expr)> {
+    match &arg.kind {
+        ExprKind::Assign { target, value } => match &target.kind {
+            ExprKind::Identifier(name) => Some((name.as_str(), value.as_ref())),
+            _ => None,
+        },
+        _ => None,
+    }
 }
 
 #[cfg(test)]
@@ -414,6 +514,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -484,88 +586,109 @@ mod tests {
         assert!(matches!(result, Value::Closure { .. }));
     }
 
-    // reorder_named_args tests
-    #[test]
-    fn test_reorder_named_args_no_named() {
-        let interp = make_interpreter();
-        let args = vec![
-            make_expr(ExprKind::Literal(Literal::Integer(1, None))),
-            make_expr(ExprKind::Literal(Literal::Integer(2, None))),
-        ];
-        let param_names = vec!["a".to_string(), "b".to_string()];
+    // bind_named_args tests
+    fn int_expr(n: i64) -> Expr {
+        make_expr(ExprKind::Literal(Literal::Integer(n, None)))
+    }
 
-        let result = interp.reorder_named_args(&args, &param_names);
-        assert_eq!(result.len(), 2);
+    fn named(name: &str, value: Expr) -> Expr {
+        make_expr(ExprKind::Assign {
+            target: Box::new(make_expr(ExprKind::Identifier(name.to_string()))),
+            value: Box::new(value),
+        })
+    }
+
+    fn bind(params: &[(&str, Option<i64>)], args: &[Expr]) -> Result<Vec<Value>, InterpreterError> {
+        let params: Vec<(String, Option<Arc<Expr>>)> = params
+            .iter()
+            .map(|(name, default)| (name.to_string(), default.map(|n| Arc::new(int_expr(n)))))
+            .collect();
+        let env = Rc::new(RefCell::new(HashMap::new()));
+        make_interpreter().bind_named_args(&params, &env, args)
     }
 
     #[test]
-    fn test_reorder_named_args_with_named() {
-        let interp = make_interpreter();
-        // Create: b = 2, a = 1
-        let args = vec![
-            make_expr(ExprKind::Assign {
-                target: Box::new(make_expr(ExprKind::Identifier("b".to_string()))),
-                value: Box::new(make_expr(ExprKind::Literal(Literal::Integer(2, None)))),
-            }),
-            make_expr(ExprKind::Assign {
-                target: Box::new(make_expr(ExprKind::Identifier("a".to_string()))),
-                value: Box::new(make_expr(ExprKind::Literal(Literal::Integer(1, None)))),
-            }),
-        ];
-        let param_names = vec!["a".to_string(), "b".to_string()];
+    fn test_bind_named_args_reorders() {
+        let args = vec![named("b", int_expr(2)), named("a", int_expr(1))];
+        let result = bind(&[("a", None), ("b", None)], &args).unwrap();
+        assert_eq!(result, vec![Value::Integer(1), Value::Integer(2)]);
+    }
 
-        let result = interp.reorder_named_args(&args, &param_names);
-        // Should reorder to match param order
-        assert_eq!(result.len(), 2);
+    #[test]
+    fn test_bind_named_args_mixed() {
+        let args = vec![int_expr(1), named("b", int_expr(2))];
+        let result = bind(&[("a", None), ("b", None)], &args).unwrap();
+        assert_eq!(result, vec![Value::Integer(1), Value::Integer(2)]);
     }
 
     #[test]
-    fn test_reorder_named_args_mixed() {
-        let interp = make_interpreter();
-        // Create: 1, b = 2
-        let args = vec![
-            make_expr(ExprKind::Literal(Literal::Integer(1, None))),
-            make_expr(ExprKind::Assign {
-                target: Box::new(make_expr(ExprKind::Identifier("b".to_string()))),
-                value: Box::new(make_expr(ExprKind::Literal(Literal::Integer(2, None)))),
-            }),
-        ];
-        let param_names = vec!["a".to_string(), "b".to_string()];
+    fn test_bind_named_args_fills_skipped_defaults() {
+        let args = vec![int_expr(1), named("c", int_expr(3))];
+        let result = bind(&[("a", None), ("b", Some(20)), ("c", Some(30))], &args).unwrap();
+        assert_eq!(
+            result,
+            vec![Value::Integer(1), Value::Integer(20), Value::Integer(3)]
+        );
+    }
 
-        let result = interp.reorder_named_args(&args, &param_names);
-        assert_eq!(result.len(), 2);
+    #[test]
+    fn test_bind_named_args_unknown_param() {
+        let args = vec![named("unknown", int_expr(1))];
+        let err = bind(&[("a", None)], &args).unwrap_err();
+        assert!(err.to_string().contains("Unknown named argument 'unknown'"));
     }
 
     #[test]
-    fn test_reorder_named_args_unknown_param() {
-        let interp = make_interpreter();
-        // Create: unknown = 1
-        let args = vec![make_expr(ExprKind::Assign {
-            target: Box::new(make_expr(ExprKind::Identifier("unknown".to_string()))),
-            value: Box::new(make_expr(ExprKind::Literal(Literal::Integer(1, None)))),
-        })];
-        let param_names = vec!["a".to_string(), "b".to_string()];
+    fn test_bind_named_args_given_twice() {
+        let args = vec![int_expr(1), named("a", int_expr(2))];
+        let err = bind(&[("a", None), ("b", Some(0))], &args).unwrap_err();
+        assert!(err.to_string().contains("'a' given more than once"));
+    }
 
-        let result = interp.reorder_named_args(&args, &param_names);
-        // Unknown param gets placed in first available slot
-        assert_eq!(result.len(), 1);
+    #[test]
+    fn test_bind_named_args_missing_required() {
+        let args = vec![named("b", int_expr(2))];
+        let err = bind(&[("a", None), ("b", None)], &args).unwrap_err();
+        assert!(err.to_string().contains("Missing argument 'a'"));
     }
 
     #[test]
-    fn test_reorder_named_args_assign_to_non_identifier() {
-        let interp = make_interpreter();
-        // Create: 1[0] = 2 (assignment to index, not named arg)
-        let args = vec![make_expr(ExprKind::Assign {
+    fn test_bind_named_args_keeps_surplus_for_rest_param() {
+        let rest = Param {
+            pattern: Pattern::RestNamed("rest".to_string()),
+            ..make_param("rest")
+        };
+        let params = eval_func::closure_params(&[make_param("a"), make_param("b"), rest]);
+        let args = vec![
+            int_expr(1),
+            int_expr(3),
+            int_expr(4),
+            named("a", int_expr(2)),
+        ];
+        let env = Rc::new(RefCell::new(HashMap::new()));
+        let err = make_interpreter()
+            .bind_named_args(&params, &env, &args)
+            .unwrap_err();
+        assert!(err.to_string().contains("'a' given more than once"));
+
+        let args = vec![named("b", int_expr(2)), int_expr(1)];
+        let result = make_interpreter()
+            .bind_named_args(&params, &env, &args)
+            .unwrap();
+        assert_eq!(result, vec![Value::Integer(1), Value::Integer(2)]);
+    }
+
+    #[test]
+    fn test_named_arg_ignores_non_identifier_targets() {
+        // 1[0] = 2 is an assignment, not a named argument
+        let arg = make_expr(ExprKind::Assign {
             target: Box::new(make_expr(ExprKind::IndexAccess {
-                object: Box::new(make_expr(ExprKind::Literal(Literal::Integer(1, None)))),
-                index: Box::new(make_expr(ExprKind::Literal(Literal::Integer(0, None)))),
+                object: Box::new(int_expr(1)),
+                index: Box::new(int_expr(0)),
             })),
-            value: Box::new(make_expr(ExprKind::Literal(Literal::Integer(2, None)))),
-        })];
-        let param_names = vec!["a".to_string()];
-
-        let result = interp.reorder_named_args(&args, &param_names);
-        assert_eq!(result.len(), 1);
+            value: Box::new(int_expr(2)),
+        });
+        assert!(named_arg(&arg).is_none());
     }
 
     // eval_function_call tests
@@ -938,6 +1061,8 @@ mod tests {
                 span: Span::default(),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
             Param {
                 pattern: Pattern::Identifier("greeting".to_string()),
@@ -945,6 +1070,8 @@ mod tests {
                 span: Span::default(),
                 is_mutable: false,
                 default_value: Some(Box::new(default_expr)),
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
         ];
 
@@ -974,6 +1101,8 @@ mod tests {
                 span: Span::default(),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
             Param {
                 pattern: Pattern::Identifier("greeting".to_string()),
@@ -981,6 +1110,8 @@ mod tests {
                 span: Span::default(),
                 is_mutable: false,
                 default_value: Some(Box::new(default_expr)),
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
         ];
 
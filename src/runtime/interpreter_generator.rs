@@ -0,0 +1,472 @@
+//! Generator functions
+//!
+//! A function whose body contains `yield` is a generator: calling it binds
+//! the arguments and returns a lazy iterator without running the body. The
+//! body is lowered once into a flat state machine of steps. Each pull
+//! resumes at the saved step, runs until the next `yield` and suspends again;
+//! locals and loop iterators live in the generator's own scope between pulls.
+//! Statements without a `yield` run unchanged through the tree-walking
+//! evaluator, so only the control flow around suspension points is lowered.
+
+use crate::frontend::ast::{Expr, ExprKind};
+use crate::runtime::eval_iterator::LazyIter;
+use crate::runtime::interpreter::Interpreter;
+use crate::runtime::{InterpreterError, Value};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::Arc;
+
+type Scope = Rc<RefCell<HashMap<String, Value>>>;
+
+/// An enclosing lowered loop that `break` and `continue` can target
+#[derive(Debug, Clone)]
+struct LoopTarget {
+    label: Option<String>,
+    head: usize,
+    exit: usize,
+}
+
+/// One instruction of a lowered generator body
+#[derive(Debug, Clone)]
+enum Step {
+    /// Evaluate a statement without `yield`; `loops` resolves any `break`
+    /// or `continue` it raises
+    Eval {
+        expr: Expr,
+        loops: Vec<LoopTarget>,
+    },
+    /// Bind a `let` whose body contains `yield`
+    Bind {
+        name: String,
+        value: Expr,
+    },
+    /// Suspend, producing the value
+    Yield(Option<Expr>),
+    /// Jump to `target` unless `condition` is truthy
+    JumpUnless {
+        condition: Expr,
+        target: usize,
+    },
+    Jump(usize),
+    /// Start iterating `iter` in loop slot `slot`
+    ForStart {
+        iter: Expr,
+        slot: usize,
+    },
+    /// Bind the next element of loop slot `slot` to `var`, or jump to `exit`
+    ForNext {
+        slot: usize,
+        var: String,
+        exit: usize,
+    },
+}
+
+/// Suspended execution of one generator call
+#[derive(Debug)]
+pub struct GeneratorState {
+    program: Rc<[Step]>,
+    pc: usize,
+    /// Captured environment and the generator's local scope
+    scope: Vec<Scope>,
+    /// Iterators of lowered `for` loops, by slot
+    loops: Vec<Option<LazyIter>>,
+    running: bool,
+    done: bool,
+}
+
+impl GeneratorState {
+    /// Lower `body` and bind it to its scope; nothing runs until the first pull
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `yield` appears inside an expression rather than
+    /// as a statement.
+    pub fn new(body: &Expr, scope: Vec<Scope>) -> Result<Self, InterpreterError> {
+        let mut lowering = Lowering::default();
+        lowering.lower(body)?;
+        Ok(Self {
+            program: Rc::from(lowering.steps),
+            pc: 0,
+            scope,
+            loops: vec![None; lowering.slots],
+            running: false,
+            done: false,
+        })
+    }
+}
+
+#[derive(Default)]
+struct Lowering {
+    steps: Vec<Step>,
+    loops: Vec<LoopTarget>,
+    slots: usize,
+}
+
+impl Lowering {
+    fn lower(&mut self, expr: &Expr) -> Result<(), InterpreterError> {
+        // Lowered scopes span suspensions, so nothing runs at their exit
+        if matches!(expr.kind, ExprKind::Defer { .. }) {
+            return Err(InterpreterError::RuntimeError(
+                "defer is not supported beside yield in generator bodies".to_string(),
+            ));
+        }
+        if !expr.contains_yield() {
+            self.steps.push(Step::Eval {
+                expr: expr.clone(),
+                loops: self.loops.clone(),
+            });
+            return Ok(());
+        }
+        match &expr.kind {
+            ExprKind::Block(exprs) => exprs.iter().try_for_each(|e| self.lower(e)),
+            ExprKind::Yield { value } => {
+                let value = value.as_deref().map(yield_free).transpose()?;
+                self.steps.push(Step::Yield(value));
+                Ok(())
+            }
+            ExprKind::Let {
+                name,
+                value,
+                body,
+                else_block: None,
+                ..
+            } => {
+                self.steps.push(Step::Bind {
+                    name: name.clone(),
+                    value: yield_free(value)?,
+                });
+                self.lower(body)
+            }
+            ExprKind::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                let branch = self.push_placeholder(Some(yield_free(condition)?));
+                self.lower(then_branch)?;
+                let end = self.push_placeholder(None);
+                self.patch(branch);
+                if let Some(else_branch) = else_branch {
+                    self.lower(else_branch)?;
+                }
+                self.patch(end);
+                Ok(())
+            }
+            ExprKind::While {
+                label,
+                condition,
+                body,
+            } => {
+                let head = self.push_placeholder(Some(yield_free(condition)?));
+                self.lower_loop_body(label, head, true, body)
+            }
+            ExprKind::Loop { label, body } => {
+                let head = self.steps.len();
+                self.lower_loop_body(label, head, false, body)
+            }
+            ExprKind::For {
+                label,
+                var,
+                iter,
+                body,
+                ..
+            } => {
+                let slot = self.slots;
+                self.slots += 1;
+                self.steps.push(Step::ForStart {
+                    iter: yield_free(iter)?,
+                    slot,
+                });
+                let head = self.steps.len();
+                self.steps.push(Step::ForNext {
+                    slot,
+                    var: var.clone(),
+                    exit: 0,
+                });
+                self.lower_loop_body(label, head, true, body)
+            }
+            _ => Err(yield_position_error()),
+        }
+    }
+
+    /// Lower a loop body that repeats from `head`; when `head_exits`, the
+    /// step at `head` jumps out of the loop once it is finished
+    fn lower_loop_body(
+        &mut self,
+        label: &Option<String>,
+        head: usize,
+        head_exits: bool,
+        body: &Expr,
+    ) -> Result<(), InterpreterError> {
+        let exit_index = self.loops.len();
+        self.loops.push(LoopTarget {
+            label: label.clone(),
+            head,
+            exit: usize::MAX,
+        });
+        self.lower(body)?;
+        self.loops.pop();
+        self.steps.push(Step::Jump(head));
+        let exit = self.steps.len();
+        if head_exits {
+            if let Step::JumpUnless { target, .. } | Step::ForNext { exit: target, .. } =
+                &mut self.steps[head]
+            {
+                *target = exit;
+            }
+        }
+        // Steps lowered before the exit was known point at usize::MAX
+        for step in &mut self.steps[head..] {
+            if let Step::Eval { loops, .. } = step {
+                if let Some(target) = loops.get_mut(exit_index) {
+                    target.exit = exit;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Push a conditional (`Some`) or unconditional jump to be patched later
+    fn push_placeholder(&mut self, condition: Option<Expr>) -> usize {
+        self.steps.push(match condition {
+            Some(condition) => Step::JumpUnless {
+                condition,
+                target: 0,
+            },
+            None => Step::Jump(0),
+        });
+        self.steps.len() - 1
+    }
+
+    /// Point the jump at `index` to the next step
+    fn patch(&mut self, index: usize) {
+        let next = self.steps.len();
+        if let Step::JumpUnless { target, .. } | Step::Jump(target) = &mut self.steps[index] {
+            *target = next;
+        }
+    }
+}
+
+fn yield_free(expr: &Expr) -> Result<Expr, InterpreterError> {
+    if expr.contains_yield() {
+        Err(yield_position_error())
+    } else {
+        Ok(expr.clone())
+    }
+}
+
+fn yield_position_error() -> InterpreterError {
+    InterpreterError::RuntimeError(
+        "yield is only supported as a statement in generator bodies".to_string(),
+    )
+}
+
+/// Where a `break` or `continue` raised inside a lowered loop jumps to
+fn loop_jump(loops: &[LoopTarget], label: Option<&str>, is_break: bool) -> Option<usize> {
+    loops
+        .iter()
+        .rev()
+        .find(|target| label.is_none() || target.label.as_deref() == label)
+        .map(|target| if is_break { target.exit } else { target.head })
+}
+
+impl Interpreter {
+    /// Call a generator function: bind arguments and return a suspended
+    /// iterator over the values it yields
+    pub(crate) fn start_generator(
+        &mut self,
+        params: &[(String, Option<Arc<Expr>>)],
+        body: &Expr,
+        env: Scope,
+        args: &[Value],
+    ) -> Result<Value, InterpreterError> {
+        let required = params.iter().filter(|(_, d)| d.is_none()).count();
+        if args.len() < required || args.len() > params.len() {
+            return Err(InterpreterError::RuntimeError(format!(
+                "Function expects {}-{} arguments, got {}",
+                required,
+                params.len(),
+                args.len()
+            )));
+        }
+        self.env_stack.push(Rc::clone(&env));
+        let mut locals = HashMap::new();
+        for (i, (name, default)) in params.iter().enumerate() {
+            let value = match (args.get(i), default) {
+                (Some(arg), _) => arg.clone(),
+                (None, Some(default)) => match self.eval_expr(default) {
+                    Ok(value) => value,
+                    Err(e) => {
+                        self.env_pop();
+                        return Err(e);
+                    }
+                },
+                (None, None) => unreachable!("argument count checked above"),
+            };
+            locals.insert(name.clone(), value);
+        }
+        self.env_pop();
+        let state = GeneratorState::new(body, vec![env, Rc::new(RefCell::new(locals))])?;
+        Ok(LazyIter::Generator(Rc::new(RefCell::new(state))).into_value())
+    }
+
+    /// Run a generator to its next `yield`; `None` once the body finishes
+    pub(crate) fn resume_generator(
+        &mut self,
+        state: &Rc<RefCell<GeneratorState>>,
+    ) -> Result<Option<Value>, InterpreterError> {
+        let (program, scope) = {
+            let mut generator = state.borrow_mut();
+            if generator.done {
+                return Ok(None);
+            }
+            if generator.running {
+                return Err(InterpreterError::RuntimeError(
+                    "generator is already running".to_string(),
+                ));
+            }
+            generator.running = true;
+            (Rc::clone(&generator.program), generator.scope.clone())
+        };
+        let depth = self.env_stack.len();
+        self.env_stack.extend(scope);
+        let result = self.run_generator_steps(state, &program);
+        self.env_stack.truncate(depth);
+        let mut generator = state.borrow_mut();
+        generator.running = false;
+        generator.done = !matches!(result, Ok(Some(_)));
+        result
+    }
+
+    fn run_generator_steps(
+        &mut self,
+        state: &Rc<RefCell<GeneratorState>>,
+        program: &[Step],
+    ) -> Result<Option<Value>, InterpreterError> {
+        let depth = self.env_stack.len();
+        let mut pc = state.borrow().pc;
+        while let Some(step) = program.get(pc) {
+            pc = match step {
+                Step::Eval { expr, loops } => {
+                    let outcome = self.eval_expr(expr);
+                    self.env_stack.truncate(depth);
+                    let jump = match &outcome {
+                        Err(InterpreterError::Break(label, _)) => {
+                            loop_jump(loops, label.as_deref(), true)
+                        }
+                        Err(InterpreterError::Continue(label)) => {
+                            loop_jump(loops, label.as_deref(), false)
+                        }
+                        _ => None,
+                    };
+                    match (outcome, jump) {
+                        (_, Some(target)) => target,
+                        (Ok(_), None) => pc + 1,
+                        (Err(InterpreterError::Return(_)), None) => return Ok(None),
+                        (Err(e), None) => return Err(e),
+                    }
+                }
+                Step::Bind { name, value } => {
+                    let value = self.eval_expr(value)?;
+                    self.env_set(name.clone(), value);
+                    pc + 1
+                }
+                Step::Yield(value) => {
+                    let value = match value {
+                        Some(expr) => self.eval_expr(expr)?,
+                        None => Value::Nil,
+                    };
+                    state.borrow_mut().pc = pc + 1;
+                    return Ok(Some(value));
+                }
+                Step::JumpUnless { condition, target } => {
+                    if self.eval_expr(condition)?.is_truthy() {
+                        pc + 1
+                    } else {
+                        *target
+                    }
+                }
+                Step::Jump(target) => *target,
+                Step::ForStart { iter, slot } => {
+                    let iterable = self.eval_expr(iter)?;
+                    state.borrow_mut().loops[*slot] = Some(LazyIter::from_value(&iterable)?);
+                    pc + 1
+                }
+                Step::ForNext { slot, var, exit } => {
+                    let Some(mut iter) = state.borrow_mut().loops[*slot].take() else {
+                        return Err(InterpreterError::RuntimeError(
+                            "generator loop resumed before it started".to_string(),
+                        ));
+                    };
+                    let item =
+                        iter.next(&mut |func, args| self.eval_function_call_value(func, args));
+                    state.borrow_mut().loops[*slot] = Some(iter);
+                    match item? {
+                        Some(item) => {
+                            self.env_set(var.clone(), item);
+                            pc + 1
+                        }
+                        None => *exit,
+                    }
+                }
+            };
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    fn eval(source: &str) -> Value {
+        let ast = Parser::new(source).parse().expect("source should parse");
+        Interpreter::new()
+            .eval_expr(&ast)
+            .expect("source should evaluate")
+    }
+
+    #[test]
+    fn test_lowering_keeps_yield_free_statements_whole() {
+        let ast = Parser::new("{ let mut a = 1\n while a < 3 { a = a + 1 }\n yield a }")
+            .parse()
+            .unwrap();
+        let state = GeneratorState::new(&ast, Vec::new()).unwrap();
+        assert_eq!(state.program.len(), 3);
+        assert!(matches!(state.program[2], Step::Yield(Some(_))));
+    }
+
+    #[test]
+    fn test_yield_inside_expression_is_rejected() {
+        let ast = Parser::new("{ let x = 1 + yield 2 }").parse().unwrap();
+        assert!(GeneratorState::new(&ast, Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_defer_beside_yield_is_rejected() {
+        let ast = Parser::new("{ defer cleanup()\n yield 1 }")
+            .parse()
+            .unwrap();
+        assert!(GeneratorState::new(&ast, Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_infinite_generator_runs_only_when_pulled() {
+        let source = "fun naturals() { let mut n = 0\n loop { yield n\n n = n + 1 } }\nnaturals().take(3).collect()";
+        assert_eq!(
+            eval(source),
+            Value::Array(vec![Value::Integer(0), Value::Integer(1), Value::Integer(2)].into())
+        );
+    }
+
+    #[test]
+    fn test_break_and_continue_inside_lowered_loop() {
+        let source = "fun gen() { for i in 0..10 { if i == 1 { continue }\n if i == 4 { break }\n yield i } }\ngen().collect()";
+        assert_eq!(
+            eval(source),
+            Value::Array(vec![Value::Integer(0), Value::Integer(2), Value::Integer(3)].into())
+        );
+    }
+}
@@ -25,8 +25,15 @@ impl Interpreter {
     ) -> Result<Value, InterpreterError> {
         let object_value = self.eval_expr(object)?;
         let index_value = self.eval_expr(index)?;
+        self.index_with_impls(&object_value, &index_value)
+    }
 
-        match (&object_value, &index_value) {
+    /// Index an already-evaluated object with an already-evaluated index
+    pub(crate) fn index_value(
+        object_value: &Value,
+        index_value: &Value,
+    ) -> Result<Value, InterpreterError> {
+        match (object_value, index_value) {
             (Value::Array(ref array), Value::Integer(idx)) => Self::index_array(array, *idx),
             // ARRAY-SLICE-FIX: Support array slicing with ranges like arr[0..3]
             (
@@ -169,7 +176,25 @@ impl Interpreter {
         field: &str,
     ) -> Result<Value, InterpreterError> {
         let object_value = self.eval_expr(object)?;
+        // Class properties: the getter runs when the instance has no such field
+        if let Some(getter) = self
+            .class_property(&object_value, field)
+            .and_then(|property| property.get("get").cloned())
+        {
+            return self.call_property_accessor(object_value, &getter, &[]);
+        }
+        self.field_of_value(object, object_value, field)
+    }
 
+    /// Access a field on an already-evaluated object
+    ///
+    /// `object` is only consulted to name the enum when constructing a unit variant.
+    pub(crate) fn field_of_value(
+        &self,
+        object: &Expr,
+        object_value: Value,
+        field: &str,
+    ) -> Result<Value, InterpreterError> {
         match object_value {
             Value::Object(ref object_map) => {
                 // Check if this is an enum type trying to construct a variant
@@ -181,12 +206,8 @@ impl Interpreter {
                         } else {
                             "UnknownEnum".to_string()
                         };
-                        // This is enum variant construction: EnumName::VariantName
-                        return Ok(Value::EnumVariant {
-                            enum_name,
-                            variant_name: field.to_string(),
-                            data: None, // Unit variant (no data)
-                        });
+                        // Enum variant construction (EnumName::VariantName) or an associated function
+                        return self.eval_enum_path(object_map, &enum_name, field);
                     }
                 }
                 self.access_object_field(object_map, field)
@@ -226,6 +247,11 @@ impl Interpreter {
                 // DataFrame field access (df.column_name returns column as array)
                 Self::index_dataframe_column(columns, field)
             }
+            Value::Error { .. } => object_value.error_field(field).ok_or_else(|| {
+                InterpreterError::RuntimeError(format!(
+                    "Field '{field}' not found on error (expected kind, message or stack)"
+                ))
+            }),
             _ => Err(InterpreterError::RuntimeError(format!(
                 "Cannot access field '{}' on type {}",
                 field,
@@ -234,6 +260,68 @@ impl Interpreter {
         }
     }
 
+    /// Evaluate the receiver of an optional chain (`?.` / `?[`) (complexity: 3)
+    ///
+    /// Returns `None` when the chain short-circuits (`nil` or `None`), and unwraps
+    /// `Some(value)` so the access applies to the payload.
+    fn eval_optional_receiver(&mut self, object: &Expr) -> Result<Option<Value>, InterpreterError> {
+        let value = self.eval_expr(object)?;
+        Ok(match &value {
+            Value::Nil => None,
+            Value::EnumVariant {
+                enum_name,
+                variant_name,
+                data,
+            } if enum_name == "Option" => match (variant_name.as_str(), data) {
+                ("None", _) => None,
+                ("Some", Some(values)) if values.len() == 1 => Some(values[0].clone()),
+                _ => Some(value),
+            },
+            _ => Some(value),
+        })
+    }
+
+    /// Evaluate `object?.field` (complexity: 2)
+    pub(crate) fn eval_optional_field_access(
+        &mut self,
+        object: &Expr,
+        field: &str,
+    ) -> Result<Value, InterpreterError> {
+        match self.eval_optional_receiver(object)? {
+            Some(value) => self.field_of_value(object, value, field),
+            None => Ok(Value::Nil),
+        }
+    }
+
+    /// Evaluate `object?[index]` (complexity: 2)
+    pub(crate) fn eval_optional_index_access(
+        &mut self,
+        object: &Expr,
+        index: &Expr,
+    ) -> Result<Value, InterpreterError> {
+        match self.eval_optional_receiver(object)? {
+            Some(value) => Self::index_value(&value, &self.eval_expr(index)?),
+            None => Ok(Value::Nil),
+        }
+    }
+
+    /// Evaluate `receiver?.method(args)`; arguments are not evaluated on short-circuit (complexity: 3)
+    pub(crate) fn eval_optional_method_call(
+        &mut self,
+        receiver: &Expr,
+        method: &str,
+        args: &[Expr],
+    ) -> Result<Value, InterpreterError> {
+        let Some(value) = self.eval_optional_receiver(receiver)? else {
+            return Ok(Value::Nil);
+        };
+        let arg_values = args
+            .iter()
+            .map(|arg| self.eval_expr(arg))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.dispatch_method_call(&value, method, &arg_values, args.is_empty())
+    }
+
     /// Access field on immutable object (complexity: 5)
     pub(crate) fn access_object_field(
         &self,
@@ -116,6 +116,26 @@
         assert!(result.is_ok() || result.is_err()); // Accept both outcomes for coverage
     }
 
+    #[test]
+    fn test_match_struct_like_enum_variant() {
+        let code = r"
+            enum Shape {
+                Circle { r: i32 },
+                Square(i32)
+            }
+            let s = Shape::Circle { r: 3 }
+            match s {
+                Shape::Square(side) => side,
+                Shape::Circle { r } => r * 10
+            }
+        ";
+        let mut parser = crate::frontend::parser::Parser::new(code);
+        let ast = parser.parse().expect("Parse failed");
+        let mut interpreter = Interpreter::new();
+        let result = interpreter.eval_expr(&ast).expect("Eval failed");
+        assert_eq!(result, Value::Integer(30));
+    }
+
     #[test]
     fn test_struct_definition() {
         let code = r"
@@ -404,6 +424,64 @@
         }
     }
 
+    #[test]
+    fn test_list_comprehension_multiple_generators_python_order() {
+        let code = r"
+            [(x, y) for x in [1, 2, 3] if x > 1 for y in [1, 2, 3] if y < x]
+        ";
+        let mut parser = crate::frontend::parser::Parser::new(code);
+        let ast = parser.parse().expect("Parse failed");
+        let mut interpreter = Interpreter::new();
+        let result = interpreter.eval_expr(&ast).expect("Eval failed");
+        let pair = |a, b| Value::Tuple(std::sync::Arc::from(vec![Value::Integer(a), Value::Integer(b)]));
+        assert_eq!(
+            result,
+            Value::Array(std::sync::Arc::from(vec![pair(2, 1), pair(3, 1), pair(3, 2)]))
+        );
+    }
+
+    #[test]
+    fn test_list_comprehension_consecutive_filters() {
+        let code = r"
+            [x for x in [1, 2, 3, 4, 5, 6] if x > 1 if x < 5]
+        ";
+        let mut parser = crate::frontend::parser::Parser::new(code);
+        let ast = parser.parse().expect("Parse failed");
+        let mut interpreter = Interpreter::new();
+        let result = interpreter.eval_expr(&ast).expect("Eval failed");
+        assert_eq!(
+            result,
+            Value::Array(std::sync::Arc::from(vec![
+                Value::Integer(2),
+                Value::Integer(3),
+                Value::Integer(4)
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_list_comprehension_destructures_nested_patterns() {
+        let code = r"
+            [a * 100 + b * 10 + c for ((a, b), c) in [((1, 2), 3), ((4, 5), 6)]]
+        ";
+        let mut parser = crate::frontend::parser::Parser::new(code);
+        let ast = parser.parse().expect("Parse failed");
+        let mut interpreter = Interpreter::new();
+        let result = interpreter.eval_expr(&ast).expect("Eval failed");
+        assert_eq!(
+            result,
+            Value::Array(std::sync::Arc::from(vec![Value::Integer(123), Value::Integer(456)]))
+        );
+
+        let code = "[x for Some(x) in [Some(1), None, Some(3)]]";
+        let ast = crate::frontend::parser::Parser::new(code).parse().expect("Parse failed");
+        let result = interpreter.eval_expr(&ast).expect("Eval failed");
+        assert_eq!(
+            result,
+            Value::Array(std::sync::Arc::from(vec![Value::Integer(1), Value::Integer(3)]))
+        );
+    }
+
     // ============== Tuple Tests ==============
 
     #[test]
@@ -0,0 +1,319 @@
+//! Per-call-site inline caches for method dispatch
+//!
+//! Every `receiver.method(..)` evaluation used to re-resolve the method by
+//! name: probing the builtin tables for `namespace_method` and formatting and
+//! looking up `Type::method` in the environment. Each call site now remembers
+//! what its method resolved to for the receiver shapes it has seen.
+//!
+//! Entries are validated against the receiver key and method name, so a call
+//! site address reused by a later AST can never pick up a wrong resolution.
+//! Binding any `Type::method` name or type descriptor clears every cache, which
+//! covers impl blocks and struct/class redefinition.
+
+use crate::frontend::ast::Expr;
+use crate::runtime::interpreter::Interpreter;
+use crate::runtime::Value;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
+
+/// Entries a call site keeps before it is treated as megamorphic
+const MAX_SITE_ENTRIES: usize = 4;
+
+/// Kind of receiver a cached resolution applies to; paired with the
+/// namespace or struct name it was resolved for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ReceiverKind {
+    /// Bare identifier receiver probed as a builtin namespace (`Html.parse`)
+    Namespace,
+    /// Instance of the named user struct
+    Struct,
+}
+
+/// What a method resolved to for one receiver shape
+#[derive(Clone, Debug)]
+pub(crate) enum MethodTarget {
+    /// `namespace_method` is not a builtin function
+    NotBuiltin,
+    /// `Type::method` value and the scope that binds it
+    UserMethod(Value, Weak<RefCell<HashMap<String, Value>>>),
+    /// No `Type::method` binding; dispatch falls through to builtins
+    NoUserMethod,
+}
+
+#[derive(Clone, Debug)]
+struct SiteEntry {
+    kind: ReceiverKind,
+    receiver: String,
+    method: String,
+    target: MethodTarget,
+}
+
+/// Inline caches for all method call sites of an interpreter
+#[derive(Debug, Default)]
+pub struct MethodCache {
+    sites: HashMap<usize, Vec<SiteEntry>>,
+    hits: u64,
+    misses: u64,
+    invalidations: u64,
+}
+
+/// Method cache counters for profiling
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MethodCacheStats {
+    /// Call sites with at least one cached resolution
+    pub sites: usize,
+    /// Lookups answered from a cache
+    pub hits: u64,
+    /// Lookups that had to resolve the method by name
+    pub misses: u64,
+    /// Times the caches were cleared because a method or type was (re)defined
+    pub invalidations: u64,
+}
+
+/// Call site identity of a method call, derived from its receiver expression
+pub(crate) fn call_site(receiver: &Expr) -> usize {
+    receiver as *const Expr as usize
+}
+
+impl MethodCache {
+    /// Create an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cached resolution of `method` at `site` for `receiver`, if any
+    pub(crate) fn lookup(
+        &mut self,
+        site: usize,
+        kind: ReceiverKind,
+        receiver: &str,
+        method: &str,
+    ) -> Option<MethodTarget> {
+        let found = self.sites.get(&site).and_then(|entries| {
+            entries
+                .iter()
+                .find(|entry| {
+                    entry.kind == kind && entry.receiver == receiver && entry.method == method
+                })
+                .map(|entry| entry.target.clone())
+        });
+        if found.is_some() {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+        found
+    }
+
+    /// Remember a resolution, replacing a stale one for the same shape;
+    /// megamorphic sites stop accepting new shapes
+    pub(crate) fn insert(
+        &mut self,
+        site: usize,
+        kind: ReceiverKind,
+        receiver: &str,
+        method: &str,
+        target: MethodTarget,
+    ) {
+        let entries = self.sites.entry(site).or_default();
+        if let Some(entry) = entries.iter_mut().find(|entry| {
+            entry.kind == kind && entry.receiver == receiver && entry.method == method
+        }) {
+            entry.target = target;
+        } else if entries.len() < MAX_SITE_ENTRIES {
+            entries.push(SiteEntry {
+                kind,
+                receiver: receiver.to_string(),
+                method: method.to_string(),
+                target,
+            });
+        }
+    }
+
+    /// Drop every cached resolution
+    pub fn invalidate(&mut self) {
+        if !self.sites.is_empty() {
+            self.sites.clear();
+            self.invalidations += 1;
+        }
+    }
+
+    /// Current counters
+    pub fn stats(&self) -> MethodCacheStats {
+        MethodCacheStats {
+            sites: self.sites.len(),
+            hits: self.hits,
+            misses: self.misses,
+            invalidations: self.invalidations,
+        }
+    }
+}
+
+/// Whether binding `name` to `value` can change how a method resolves
+pub(crate) fn affects_method_resolution(name: &str, value: &Value) -> bool {
+    name.contains("::") || matches!(value, Value::Object(obj) if obj.contains_key("__type"))
+}
+
+impl Interpreter {
+    /// Whether `namespace.method(..)` at `site` is already known not to be a
+    /// builtin namespace call
+    pub(crate) fn namespace_call_known_not_builtin(
+        &mut self,
+        site: usize,
+        namespace: &str,
+        method: &str,
+    ) -> bool {
+        matches!(
+            self.method_cache
+                .lookup(site, ReceiverKind::Namespace, namespace, method),
+            Some(MethodTarget::NotBuiltin)
+        )
+    }
+
+    /// Record that `namespace.method(..)` at `site` is not a builtin
+    pub(crate) fn cache_namespace_not_builtin(
+        &mut self,
+        site: usize,
+        namespace: &str,
+        method: &str,
+    ) {
+        self.method_cache.insert(
+            site,
+            ReceiverKind::Namespace,
+            namespace,
+            method,
+            MethodTarget::NotBuiltin,
+        );
+    }
+
+    /// Resolve the `Type::method` value for a struct receiver through the
+    /// call-site cache
+    ///
+    /// A cached method is only used while the scope that binds it is still on
+    /// the environment stack; an impl block inside a function body goes away
+    /// when that call returns.
+    pub(crate) fn resolve_struct_method(
+        &mut self,
+        site: usize,
+        struct_name: &str,
+        method: &str,
+    ) -> Option<Value> {
+        let kind = ReceiverKind::Struct;
+        match self.method_cache.lookup(site, kind, struct_name, method) {
+            Some(MethodTarget::UserMethod(value, scope)) if self.scope_is_live(&scope) => {
+                return Some(value)
+            }
+            Some(MethodTarget::NoUserMethod) => return None,
+            Some(_) | None => {}
+        }
+
+        let qualified = format!("{struct_name}::{method}");
+        let resolved = self.lookup_variable(&qualified).ok();
+        let binding_scope = self
+            .env_stack
+            .iter()
+            .rev()
+            .find(|env| env.borrow().contains_key(&qualified));
+        let target = match (&resolved, binding_scope) {
+            (None, _) => Some(MethodTarget::NoUserMethod),
+            (Some(value), Some(scope)) => Some(MethodTarget::UserMethod(
+                value.clone(),
+                Rc::downgrade(scope),
+            )),
+            // Resolved by a type descriptor rather than a binding
+            (Some(_), None) => None,
+        };
+        if let Some(target) = target {
+            self.method_cache
+                .insert(site, kind, struct_name, method, target);
+        }
+        resolved
+    }
+
+    fn scope_is_live(&self, scope: &Weak<RefCell<HashMap<String, Value>>>) -> bool {
+        self.env_stack
+            .iter()
+            .any(|env| std::ptr::eq(Rc::as_ptr(env), scope.as_ptr()))
+    }
+
+    /// Method inline cache counters
+    pub fn method_cache_stats(&self) -> MethodCacheStats {
+        self.method_cache.stats()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::Parser;
+
+    fn run(interp: &mut Interpreter, source: &str) -> Value {
+        let ast = Parser::new(source).parse().expect("parse");
+        interp.eval_expr(&ast).expect("eval")
+    }
+
+    #[test]
+    fn test_cache_is_keyed_by_receiver_and_method() {
+        let mut cache = MethodCache::new();
+        let kind = ReceiverKind::Struct;
+        assert!(cache.lookup(1, kind, "Point", "len").is_none());
+        cache.insert(1, kind, "Point", "len", MethodTarget::NoUserMethod);
+        assert!(cache.lookup(1, kind, "Point", "len").is_some());
+        assert!(cache.lookup(1, kind, "Point", "area").is_none());
+        assert!(cache.lookup(1, kind, "Line", "len").is_none());
+        assert!(cache
+            .lookup(1, ReceiverKind::Namespace, "Point", "len")
+            .is_none());
+        assert_eq!(cache.stats().hits, 1);
+        assert_eq!(cache.stats().misses, 4);
+    }
+
+    #[test]
+    fn test_megamorphic_site_stops_growing() {
+        let mut cache = MethodCache::new();
+        for i in 0..10 {
+            let name = format!("S{i}");
+            cache.insert(
+                7,
+                ReceiverKind::Struct,
+                &name,
+                "m",
+                MethodTarget::NoUserMethod,
+            );
+        }
+        assert_eq!(cache.sites[&7].len(), MAX_SITE_ENTRIES);
+        cache.invalidate();
+        assert_eq!(cache.stats().sites, 0);
+        assert_eq!(cache.stats().invalidations, 1);
+    }
+
+    #[test]
+    fn test_hot_struct_method_hits_cache() {
+        let mut interp = Interpreter::new();
+        let source = "struct Counter { n: i32 }
+impl Counter { fun get(self) -> i32 { self.n } }
+let c = Counter { n: 2 }
+let mut total = 0
+for i in 0..50 { total = total + c.get() }
+total";
+        assert_eq!(run(&mut interp, source), Value::Integer(100));
+        let stats = interp.method_cache_stats();
+        assert!(stats.hits >= 49 * 2, "{stats:?}");
+    }
+
+    #[test]
+    fn test_redefinition_invalidates_cached_method() {
+        let mut interp = Interpreter::new();
+        let source = "struct P { x: i32 }
+impl P { fun v(self) -> i32 { 1 } }
+let p = P { x: 0 }
+fun probe() { p.v() }
+let a = probe() + probe()
+impl P { fun v(self) -> i32 { 10 } }
+let b = probe()
+a + b";
+        assert_eq!(run(&mut interp, source), Value::Integer(12));
+        assert!(interp.method_cache_stats().invalidations >= 1);
+    }
+}
@@ -182,7 +182,7 @@ impl Interpreter {
 
         // Execute the handler body
         self.env_stack.push(Rc::new(RefCell::new(handler_env)));
-        let result = self.eval_expr(body);
+        let result = InterpreterError::catch_return(self.eval_expr(body));
         self.env_stack.pop();
 
         result
@@ -228,7 +228,7 @@ impl Interpreter {
 
         // Execute the handler body
         self.env_stack.push(Rc::new(RefCell::new(handler_env)));
-        let result = self.eval_expr(&body_clone);
+        let result = InterpreterError::catch_return(self.eval_expr(&body_clone));
         self.env_stack.pop();
 
         result
@@ -301,10 +301,10 @@ impl Interpreter {
 
                 // Execute handler body
                 self.env_push(handler_env);
-                let result = self.eval_expr(body)?;
+                let result = InterpreterError::catch_return(self.eval_expr(body));
                 self.env_pop();
 
-                return Ok(result);
+                return result;
             }
         }
 
@@ -465,56 +465,18 @@ impl Interpreter {
         let qualified_method_name = format!("{}::{}", struct_name, method);
 
         if let Ok(method_closure) = self.lookup_variable(&qualified_method_name) {
-            if let Value::Closure { params, body, env } = method_closure {
-                // Check argument count (including self)
-                let expected_args = params.len();
-                let provided_args = arg_values.len() + 1; // +1 for self
-
-                if provided_args != expected_args {
-                    return Err(InterpreterError::RuntimeError(format!(
-                        "Method {} expects {} arguments, got {}",
-                        method,
-                        expected_args - 1, // -1 because self is implicit
-                        arg_values.len()
-                    )));
-                }
-
-                // Create new environment with method's captured environment as base
-                let mut new_env = env.borrow().clone(); // ISSUE-119: Borrow from RefCell
-
-                // RUNTIME-DEFAULT-PARAMS: Bind self parameter (first parameter)
-                // RUNTIME-094: Bind as Value::Struct to preserve struct type for nested method calls
-                if let Some((self_param_name, _default_value)) = params.first() {
-                    new_env.insert(
-                        self_param_name.clone(),
-                        Value::Struct {
-                            name: struct_name.to_string(),
-                            fields: std::sync::Arc::new(instance.clone()),
-                        },
-                    );
-                }
-
-                // RUNTIME-DEFAULT-PARAMS: Bind other parameters
-                for (i, arg_value) in arg_values.iter().enumerate() {
-                    if let Some((param_name, _default_value)) = params.get(i + 1) {
-                        // +1 to skip self
-                        new_env.insert(param_name.clone(), arg_value.clone());
-                    }
+            self.call_struct_method(instance, struct_name, method, method_closure, arg_values)
+        } else {
+            // `to_string` goes through a `Display` impl when there is one
+            if method == "to_string" && arg_values.is_empty() {
+                let value = Value::Struct {
+                    name: struct_name.to_string(),
+                    fields: std::sync::Arc::new(instance.clone()),
+                };
+                if let Some(text) = self.display_with_impl(&value)? {
+                    return Ok(Value::from_string(text));
                 }
-
-                // Execute method body with new environment
-                self.env_stack.push(Rc::new(RefCell::new(new_env))); // ISSUE-119: Wrap in Rc<RefCell>
-                let result = self.eval_expr(&body);
-                self.env_stack.pop();
-
-                result
-            } else {
-                Err(InterpreterError::RuntimeError(format!(
-                    "Found {} but it's not a method closure",
-                    qualified_method_name
-                )))
             }
-        } else {
             // Fall back to generic method handling
             self.eval_generic_method(
                 &Value::Object(std::sync::Arc::new(instance.clone())),
@@ -524,6 +486,66 @@ impl Interpreter {
         }
     }
 
+    /// Invoke an already-resolved `Type::method` value on a struct instance
+    pub(crate) fn call_struct_method(
+        &mut self,
+        instance: &std::collections::HashMap<String, Value>,
+        struct_name: &str,
+        method: &str,
+        method_closure: Value,
+        arg_values: &[Value],
+    ) -> Result<Value, InterpreterError> {
+        let Value::Closure { params, body, env } = method_closure else {
+            return Err(InterpreterError::RuntimeError(format!(
+                "Found {}::{} but it's not a method closure",
+                struct_name, method
+            )));
+        };
+
+        // Check argument count (including self)
+        let expected_args = params.len();
+        let provided_args = arg_values.len() + 1; // +1 for self
+
+        if provided_args != expected_args {
+            return Err(InterpreterError::RuntimeError(format!(
+                "Method {} expects {} arguments, got {}",
+                method,
+                expected_args - 1, // -1 because self is implicit
+                arg_values.len()
+            )));
+        }
+
+        // Create new environment with method's captured environment as base
+        let mut new_env = env.borrow().clone(); // ISSUE-119: Borrow from RefCell
+
+        // RUNTIME-DEFAULT-PARAMS: Bind self parameter (first parameter)
+        // RUNTIME-094: Bind as Value::Struct to preserve struct type for nested method calls
+        if let Some((self_param_name, _default_value)) = params.first() {
+            new_env.insert(
+                self_param_name.clone(),
+                Value::Struct {
+                    name: struct_name.to_string(),
+                    fields: std::sync::Arc::new(instance.clone()),
+                },
+            );
+        }
+
+        // RUNTIME-DEFAULT-PARAMS: Bind other parameters
+        for (i, arg_value) in arg_values.iter().enumerate() {
+            if let Some((param_name, _default_value)) = params.get(i + 1) {
+                // +1 to skip self
+                new_env.insert(param_name.clone(), arg_value.clone());
+            }
+        }
+
+        // Execute method body with new environment
+        self.env_stack.push(Rc::new(RefCell::new(new_env))); // ISSUE-119: Wrap in Rc<RefCell>
+        let result = InterpreterError::catch_return(self.eval_expr(&body));
+        self.env_stack.pop();
+
+        result
+    }
+
     pub(crate) fn eval_object_method(
         &self,
         obj: &std::collections::HashMap<String, Value>,
@@ -12,6 +12,8 @@
 
 use crate::frontend::ast::{Expr, ExprKind};
 use crate::runtime::interpreter::Interpreter;
+use crate::runtime::interpreter_method_cache::call_site;
+use crate::runtime::method_not_found::{self, base_method};
 use crate::runtime::{InterpreterError, Value};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -24,20 +26,27 @@ impl Interpreter {
         method: &str,
         args: &[Expr],
     ) -> Result<Value, InterpreterError> {
+        let site = call_site(receiver);
+
         // Special handling for stdlib namespace methods (e.g., Html.parse())
         if let ExprKind::Identifier(namespace) = &receiver.kind {
-            // Check if this is a stdlib namespace call before trying to look it up as a variable
-            let namespace_method = format!("{namespace}_{method}");
+            if !self.namespace_call_known_not_builtin(site, namespace, method) {
+                // Check if this is a stdlib namespace call before trying to look it up as a variable
+                let namespace_method = format!("{namespace}_{method}");
 
-            // Try to evaluate as builtin function first
-            let arg_values: Result<Vec<_>, _> =
-                args.iter().map(|arg| self.eval_expr(arg)).collect();
-            let arg_values = arg_values?;
+                // Try to evaluate as builtin function first
+                let arg_values: Result<Vec<_>, _> =
+                    args.iter().map(|arg| self.eval_expr(arg)).collect();
+                let arg_values = arg_values?;
 
-            if let Ok(Some(result)) =
-                crate::runtime::eval_builtin::eval_builtin_function(&namespace_method, &arg_values)
-            {
-                return Ok(result);
+                match crate::runtime::eval_builtin::eval_builtin_function(
+                    &namespace_method,
+                    &arg_values,
+                ) {
+                    Ok(Some(result)) => return Ok(result),
+                    Ok(None) => self.cache_namespace_not_builtin(site, namespace, method),
+                    Err(_) => {}
+                }
             }
         }
 
@@ -207,16 +216,15 @@ impl Interpreter {
             // Structs use value semantics - method modifications create a new struct that must replace the variable
             if let Value::Struct { name, fields } = &receiver_value {
                 // Check if this struct has impl methods (not just generic object methods)
-                let qualified_method_name = format!("{}::{}", name, method);
-                if self.lookup_variable(&qualified_method_name).is_ok() {
+                if let Some(method_closure) = self.resolve_struct_method(site, name, method) {
                     // This is a struct with custom methods - use capture version
-                    let (result, modified_fields_opt) = self
-                        .eval_struct_instance_method_with_self_capture(
-                            fields,
-                            name,
-                            method,
-                            &arg_values,
-                        )?;
+                    let (result, modified_fields_opt) = self.call_struct_method_with_self_capture(
+                        fields,
+                        name,
+                        method,
+                        method_closure,
+                        &arg_values,
+                    )?;
 
                     // If method modified self, update the variable with modified struct
                     if let Some(modified_fields) = modified_fields_opt {
@@ -232,7 +240,22 @@ impl Interpreter {
             }
         }
 
+        // Struct receivers that aren't plain variables (method chains, calls)
+        if let Value::Struct { name, fields } = &receiver_value {
+            if let Some(method_closure) = self.resolve_struct_method(site, name, method) {
+                return self.call_struct_method(fields, name, method, method_closure, &arg_values);
+            }
+        }
+
+        // Enum impl methods, which take precedence over `Option`/`Result` built-ins
+        if let Some(result) =
+            self.call_enum_method(&receiver_value, base_method(method), &arg_values)
+        {
+            return result;
+        }
+
         self.dispatch_method_call(&receiver_value, method, &arg_values, args.is_empty())
+            .map_err(|e| method_not_found::explain(e, &receiver_value, base_method(method)))
     }
 
     // Helper methods for method dispatch (complexity <10 each)
@@ -280,8 +303,16 @@ impl Interpreter {
         match receiver {
             Value::String(s) => self.eval_string_method(s, base_method, arg_values),
             Value::Array(arr) => self.eval_array_method(arr, base_method, arg_values),
+            Value::Iterator(iter) => self.eval_iterator_method(iter, base_method, arg_values),
+            Value::Range { .. } if base_method != "to_string" => {
+                let iter = super::eval_iterator::LazyIter::from_value(receiver)?.into_value();
+                self.dispatch_method_call(&iter, base_method, arg_values, args_empty)
+            }
             Value::Float(f) => self.eval_float_method(*f, base_method, args_empty),
             Value::Integer(n) => self.eval_integer_method(*n, base_method, arg_values),
+            Value::Decimal(d) => {
+                super::eval_decimal::eval_decimal_method(*d, base_method, arg_values)
+            }
             Value::DataFrame { columns } => {
                 self.eval_dataframe_method(columns, base_method, arg_values)
             }
@@ -319,6 +350,12 @@ impl Interpreter {
                 else if let Some(Value::String(type_str)) = obj.get("__type") {
                     if type_str.as_ref() == "DataFrameBuilder" {
                         self.eval_dataframe_builder_method(obj, base_method, arg_values)
+                    } else if type_str.as_ref() == super::eval_dataframe_engine::GROUP_BY_TYPE {
+                        super::eval_dataframe_engine::eval_group_by_method(
+                            obj,
+                            base_method,
+                            arg_values,
+                        )
                     } else {
                         self.eval_object_method(obj, base_method, arg_values, args_empty)
                     }
@@ -381,6 +418,16 @@ impl Interpreter {
                     self.eval_object_method_mut(cell_rc, base_method, arg_values, args_empty)
                 }
             }
+            Value::EnumVariant { enum_name, .. }
+                if super::eval_option_result::is_builtin_enum(enum_name) =>
+            {
+                super::eval_option_result::eval_option_result_method(
+                    receiver,
+                    base_method,
+                    arg_values,
+                    |func, args| self.eval_function_call_value(func, args),
+                )
+            }
             Value::Struct { name, fields } => {
                 // Dispatch struct instance method call
                 self.eval_struct_instance_method(fields, name, base_method, arg_values)
@@ -1586,6 +1633,8 @@ mod tests {
                 span: crate::frontend::ast::Span::default(),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             }],
             body: Box::new(closure_body),
         });
@@ -1625,6 +1674,8 @@ mod tests {
                 span: crate::frontend::ast::Span::default(),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             }],
             body: Box::new(closure_body),
         });
@@ -1664,6 +1715,8 @@ mod tests {
                 span: crate::frontend::ast::Span::default(),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             }],
             body: Box::new(closure_body),
         });
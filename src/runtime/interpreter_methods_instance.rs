@@ -97,13 +97,13 @@ impl Interpreter {
                         // Push method environment
                         self.env_push(method_env);
 
-                        // Execute method body
-                        let result = self.eval_expr(body)?;
+                        // Execute method body; `return` exits the method
+                        let result = InterpreterError::catch_return(self.eval_expr(body));
 
                         // Pop environment
                         self.env_pop();
 
-                        return Ok(result);
+                        return result;
                     }
                 }
             }
@@ -165,13 +165,13 @@ impl Interpreter {
                 // Push method environment
                 self.env_push(method_env);
 
-                // Execute method body
-                let result = self.eval_expr(body)?;
+                // Execute method body; `return` exits the method
+                let result = InterpreterError::catch_return(self.eval_expr(body));
 
                 // Pop environment
                 self.env_pop();
 
-                Ok(result)
+                result
             } else {
                 Err(InterpreterError::RuntimeError(format!(
                     "Method {} is not a closure",
@@ -244,71 +244,13 @@ impl Interpreter {
         let qualified_method_name = format!("{}::{}", struct_name, method);
 
         if let Ok(method_closure) = self.lookup_variable(&qualified_method_name) {
-            if let Value::Closure { params, body, env } = method_closure {
-                // Check argument count (including self)
-                let expected_args = params.len();
-                let provided_args = arg_values.len() + 1; // +1 for self
-
-                if provided_args != expected_args {
-                    return Err(InterpreterError::RuntimeError(format!(
-                        "Method {} expects {} arguments, got {}",
-                        method,
-                        expected_args - 1, // -1 because self is implicit
-                        arg_values.len()
-                    )));
-                }
-
-                // Create new environment with method's captured environment as base
-                let mut new_env = env.borrow().clone();
-
-                // Bind self parameter (first parameter)
-                let self_param_name = if let Some((name, _)) = params.first() {
-                    new_env.insert(
-                        name.clone(),
-                        Value::Struct {
-                            name: struct_name.to_string(),
-                            fields: std::sync::Arc::new(instance.clone()),
-                        },
-                    );
-                    name.clone()
-                } else {
-                    return Err(InterpreterError::RuntimeError(
-                        "Method has no self parameter".to_string(),
-                    ));
-                };
-
-                // Bind other parameters
-                for (i, arg_value) in arg_values.iter().enumerate() {
-                    if let Some((param_name, _)) = params.get(i + 1) {
-                        new_env.insert(param_name.clone(), arg_value.clone());
-                    }
-                }
-
-                // Execute method body with new environment
-                self.env_stack.push(Rc::new(RefCell::new(new_env)));
-                let result = self.eval_expr(&body);
-
-                // CRITICAL: Extract modified self BEFORE popping environment
-                let modified_self = if let Some(env_rc) = self.env_stack.last() {
-                    let env_ref = env_rc.borrow();
-                    if let Some(Value::Struct { fields, .. }) = env_ref.get(&self_param_name) {
-                        Some(fields.clone())
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                };
-
-                self.env_stack.pop();
-
-                result.map(|r| (r, modified_self))
-            } else {
-                Err(InterpreterError::RuntimeError(format!(
-                    "Found {} but it's not a method closure",
-                    qualified_method_name
-                )))
-            }
+            self.call_struct_method_with_self_capture(
+                instance,
+                struct_name,
+                method,
+                method_closure,
+                arg_values,
+            )
         } else {
             // Fall back to generic method handling - no self modifications
             let result = self.eval_generic_method(
@@ -320,6 +262,90 @@ impl Interpreter {
         }
     }
 
+    /// Helper: Invoke an already-resolved `Type::method` value on a struct
+    /// instance, capturing modified self (see
+    /// [`Self::eval_struct_instance_method_with_self_capture`])
+    pub(crate) fn call_struct_method_with_self_capture(
+        &mut self,
+        instance: &std::collections::HashMap<String, Value>,
+        struct_name: &str,
+        method: &str,
+        method_closure: Value,
+        arg_values: &[Value],
+    ) -> Result<
+        (
+            Value,
+            Option<std::sync::Arc<std::collections::HashMap<String, Value>>>,
+        ),
+        InterpreterError,
+    > {
+        let Value::Closure { params, body, env } = method_closure else {
+            return Err(InterpreterError::RuntimeError(format!(
+                "Found {}::{} but it's not a method closure",
+                struct_name, method
+            )));
+        };
+
+        // Check argument count (including self)
+        let expected_args = params.len();
+        let provided_args = arg_values.len() + 1; // +1 for self
+
+        if provided_args != expected_args {
+            return Err(InterpreterError::RuntimeError(format!(
+                "Method {} expects {} arguments, got {}",
+                method,
+                expected_args - 1, // -1 because self is implicit
+                arg_values.len()
+            )));
+        }
+
+        // Create new environment with method's captured environment as base
+        let mut new_env = env.borrow().clone();
+
+        // Bind self parameter (first parameter)
+        let self_param_name = if let Some((name, _)) = params.first() {
+            new_env.insert(
+                name.clone(),
+                Value::Struct {
+                    name: struct_name.to_string(),
+                    fields: std::sync::Arc::new(instance.clone()),
+                },
+            );
+            name.clone()
+        } else {
+            return Err(InterpreterError::RuntimeError(
+                "Method has no self parameter".to_string(),
+            ));
+        };
+
+        // Bind other parameters
+        for (i, arg_value) in arg_values.iter().enumerate() {
+            if let Some((param_name, _)) = params.get(i + 1) {
+                new_env.insert(param_name.clone(), arg_value.clone());
+            }
+        }
+
+        // Execute method body with new environment
+        self.env_stack.push(Rc::new(RefCell::new(new_env)));
+        let result = InterpreterError::catch_return(self.eval_expr(&body));
+
+        // CRITICAL: Extract modified self BEFORE popping environment
+        let modified_self = if let Some(env_rc) = self.env_stack.last() {
+            let env_ref = env_rc.borrow();
+            if let Some(Value::Struct { fields, .. }) = env_ref.get(&self_param_name) {
+                Some(fields.clone())
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        self.env_stack.pop();
+
+        result.map(|r| (r, modified_self))
+    }
+
     /// ISSUE-116: Evaluate File object methods (.`read_line()`, .`close()`)
     /// Complexity: 6
     pub(crate) fn eval_file_method_mut(
@@ -328,6 +354,7 @@ impl Interpreter {
         method: &str,
         arg_values: &[Value],
     ) -> Result<Value, InterpreterError> {
+        crate::runtime::restricted::check_host_object("File", method)?;
         match method {
             "read_line" => {
                 // Check args
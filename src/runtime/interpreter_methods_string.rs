@@ -1,12 +1,15 @@
-//! String and array method dispatch
+//! String, array and iterator method dispatch
 //!
 //! Extracted from interpreter_methods.rs for coverage attribution.
 
 #![allow(clippy::unused_self)]
 #![allow(clippy::rc_buffer)]
 
+use crate::runtime::eval_iterator::LazyIter;
 use crate::runtime::interpreter::Interpreter;
 use crate::runtime::{InterpreterError, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
 use std::sync::Arc;
 
 impl Interpreter {
@@ -33,6 +36,18 @@ impl Interpreter {
             self.eval_function_call_value(func, args)
         })
     }
+
+    /// Evaluate lazy iterator methods
+    pub(crate) fn eval_iterator_method(
+        &mut self,
+        iter: &Rc<RefCell<LazyIter>>,
+        method: &str,
+        args: &[Value],
+    ) -> Result<Value, InterpreterError> {
+        crate::runtime::eval_iterator::eval_iterator_method(iter, method, args, |func, args| {
+            self.eval_function_call_value(func, args)
+        })
+    }
 }
 
 #[cfg(test)]
@@ -14,7 +14,7 @@
 //! Extracted from interpreter.rs to reduce file size (EXTREME TDD).
 
 use super::interpreter::{Interpreter, InterpreterError, Value};
-use crate::frontend::ast::{ComprehensionClause, Expr, ExprKind};
+use crate::frontend::ast::{ComprehensionClause, Expr, ExprKind, Pattern};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -61,6 +61,8 @@ pub(crate) fn eval_misc_expr(
         ExprKind::Pipeline { expr, stages } => eval_pipeline(interp, expr, stages),
         // SPEC-001-D: Lazy evaluation - defers computation until value is accessed
         ExprKind::Lazy { expr } => interp.eval_expr(expr),
+        // Defer: scheduled for the exit of the enclosing scope
+        ExprKind::Defer { expr } => interp.eval_defer(expr),
         // SPEC-001-E: Async block - simplified synchronous evaluation
         ExprKind::AsyncBlock { body } => interp.eval_expr(body),
         // ISSUE-106: Module expression - creates a namespace with exported functions
@@ -79,24 +81,40 @@ pub(crate) fn eval_misc_expr(
         } => eval_if_let(interp, pattern, expr, then_branch, else_branch.as_deref()),
         // While-let expression: while let pattern = expr { body }
         ExprKind::WhileLet {
-            label: _,
+            label,
             pattern,
             expr,
             body,
-        } => eval_while_let(interp, pattern, expr, body),
+        } => eval_while_let(interp, label.as_ref(), pattern, expr, body),
         // List comprehension: [expr for x in iter if cond]
         ExprKind::ListComprehension { element, clauses } => {
             eval_list_comprehension(interp, element, clauses)
         }
+        // Extern blocks link Rust crates, which only compiled programs can do
+        ExprKind::ExternBlock { functions, .. } if functions.is_empty() => Ok(Value::Nil),
+        ExprKind::ExternBlock { abi, path, .. } => {
+            let source = path
+                .as_deref()
+                .map_or_else(String::new, |p| format!(" from `{p}`"));
+            Err(InterpreterError::RuntimeError(format!(
+                "extern \"{abi}\" functions{source} are only available in compiled programs; \
+                 build the project with `ruchy build`"
+            )))
+        }
+        ExprKind::RustBlock {
+            inputs,
+            output,
+            fallback,
+            ..
+        } => eval_rust_block(interp, inputs, output.is_some(), fallback.as_deref()),
         // ── Ruchy 5.0 Sovereign Platform expressions ──
-        // Yield: coroutine suspension — currently returns value or Nil
+        // Yield: generator bodies suspend in interpreter_generator; a yield
+        // reached outside a generator behaves like return
         ExprKind::Yield { value } => {
             let val = match value {
                 Some(expr) => interp.eval_expr(expr)?,
                 None => Value::Nil,
             };
-            // In a full coroutine runtime, this would suspend execution.
-            // For now, yield behaves like return in the interpreter.
             Err(InterpreterError::Return(val))
         }
         // Signal: reactive state creation — returns the initial value
@@ -118,6 +136,39 @@ pub(crate) fn eval_misc_expr(
     }
 }
 
+/// Verbatim Rust only runs once transpiled: the interpreter runs the
+/// `else` fallback, after checking that the declared inputs exist
+///
+/// The fallback of an item block (no inputs or output) runs in the current
+/// scope, so the functions it defines stand in for the Rust ones.
+fn eval_rust_block(
+    interp: &mut Interpreter,
+    inputs: &[crate::frontend::ast::Param],
+    has_output: bool,
+    fallback: Option<&Expr>,
+) -> Result<Value, InterpreterError> {
+    let Some(fallback) = fallback else {
+        return Err(InterpreterError::RuntimeError(
+            "rust { ... } blocks only run in compiled programs; \
+             add an `else { ... }` fallback or use `ruchy compile`"
+                .to_string(),
+        ));
+    };
+    for input in inputs {
+        interp.lookup_variable(&input.name())?;
+    }
+    match &fallback.kind {
+        ExprKind::Block(items) if inputs.is_empty() && !has_output => {
+            let mut last = Value::Nil;
+            for item in items {
+                last = interp.eval_expr(item)?;
+            }
+            Ok(last)
+        }
+        _ => interp.eval_expr(fallback),
+    }
+}
+
 // ============================================================================
 // Import evaluation
 // ============================================================================
@@ -172,6 +223,7 @@ fn eval_import(interp: &mut Interpreter, module: &str) -> Result<Value, Interpre
     }
 
     // Issue #88: Load file module from file system and execute it
+    crate::runtime::restricted::check_import(module)?;
     let parsed_module = interp
         .module_loader_mut()
         .load_module(module)
@@ -246,12 +298,11 @@ fn eval_vec_macro(interp: &mut Interpreter, args: &[Expr]) -> Result<Value, Inte
 /// println!() macro: Evaluate arguments, print with newline
 /// PARSER-085: Supports format strings like println!("x: {}", value)
 fn eval_println_macro(interp: &mut Interpreter, args: &[Expr]) -> Result<Value, InterpreterError> {
-    if args.is_empty() {
-        println!();
+    let line = if args.is_empty() {
+        String::new()
     } else if args.len() == 1 {
         // Single argument: print directly
-        let value = interp.eval_expr(&args[0])?;
-        println!("{}", value);
+        interp.eval_expr(&args[0])?.to_string()
     } else {
         // Multiple arguments: use format! logic (Issue #82, #83)
         let format_val = interp.eval_expr(&args[0])?;
@@ -266,9 +317,10 @@ fn eval_println_macro(interp: &mut Interpreter, args: &[Expr]) -> Result<Value,
         }
 
         // Use helper for format string replacement
-        let result = Interpreter::format_string_with_values(&format_str, &values);
-        println!("{}", result);
-    }
+        Interpreter::format_string_with_values(&format_str, &values)
+    };
+    crate::runtime::restricted::charge_output(line.len() + 1)?;
+    let _ = crate::runtime::output::write(&format!("{line}\n"));
     Ok(Value::Nil)
 }
 
@@ -377,15 +429,15 @@ fn try_unwrap_enum_variant(value: &Value) -> Option<Result<Value, InterpreterErr
     else {
         return None;
     };
-    if enum_name != "Result" {
-        return None;
-    }
-    Some(match variant_name.as_str() {
-        "Ok" => extract_ok_first_value(data.as_ref(), "Ok variant has no data"),
-        "Err" => Err(InterpreterError::Return(value.clone())),
-        _ => Err(InterpreterError::RuntimeError(format!(
+    // `?` on an Option returns None from the function, as in Rust
+    Some(match (enum_name.as_str(), variant_name.as_str()) {
+        ("Result", "Ok") => extract_ok_first_value(data.as_ref(), "Ok variant has no data"),
+        ("Option", "Some") => extract_ok_first_value(data.as_ref(), "Some variant has no data"),
+        ("Result", "Err") | ("Option", "None") => Err(InterpreterError::Return(value.clone())),
+        ("Result", _) => Err(InterpreterError::RuntimeError(format!(
             "Try operator: unexpected Result variant '{variant_name}'"
         ))),
+        _ => return None,
     })
 }
 
@@ -444,7 +496,7 @@ fn eval_try_operator(interp: &mut Interpreter, expr: &Expr) -> Result<Value, Int
         return result;
     }
     Err(InterpreterError::RuntimeError(format!(
-        "Try operator expects Result enum, got: {result_value:?}"
+        "Try operator expects Result or Option, got: {result_value:?}"
     )))
 }
 
@@ -576,8 +628,11 @@ enum LoopBodyResult {
     ContinueLoop,
 }
 
+/// Run one iteration of a loop labeled `label`; a `break`/`continue` naming
+/// another label propagates to the loop it names
 fn eval_scoped_loop_body(
     interp: &mut Interpreter,
+    label: Option<&String>,
     bindings: Vec<(String, Value)>,
     body: &Expr,
 ) -> Result<LoopBodyResult, InterpreterError> {
@@ -585,10 +640,15 @@ fn eval_scoped_loop_body(
     for (name, val) in bindings {
         interp.env_set(name, val);
     }
+    let targets_this_loop = |target: &Option<String>| target.is_none() || target.as_ref() == label;
     let result = match interp.eval_expr(body) {
         Ok(v) => Ok(LoopBodyResult::Continue(v)),
-        Err(InterpreterError::Break(_, v)) => Ok(LoopBodyResult::BreakWith(v)),
-        Err(InterpreterError::Continue(_)) => Ok(LoopBodyResult::ContinueLoop),
+        Err(InterpreterError::Break(target, v)) if targets_this_loop(&target) => {
+            Ok(LoopBodyResult::BreakWith(v))
+        }
+        Err(InterpreterError::Continue(target)) if targets_this_loop(&target) => {
+            Ok(LoopBodyResult::ContinueLoop)
+        }
         Err(e) => Err(e),
     };
     interp.pop_scope();
@@ -597,6 +657,7 @@ fn eval_scoped_loop_body(
 
 fn eval_while_let(
     interp: &mut Interpreter,
+    label: Option<&String>,
     pattern: &crate::frontend::ast::Pattern,
     expr: &Expr,
     body: &Expr,
@@ -607,7 +668,7 @@ fn eval_while_let(
         let Some(bindings) = interp.try_pattern_match(pattern, &value)? else {
             break;
         };
-        match eval_scoped_loop_body(interp, bindings, body)? {
+        match eval_scoped_loop_body(interp, label, bindings, body)? {
             LoopBodyResult::Continue(v) => last_value = v,
             LoopBodyResult::BreakWith(v) => return Ok(v),
             LoopBodyResult::ContinueLoop => {}
@@ -640,17 +701,84 @@ fn iterate_comprehension_item(
     element: &Expr,
     clauses: &[ComprehensionClause],
     clause_idx: usize,
-    variable: &str,
+    pattern: &Pattern,
     item: Value,
     condition: Option<&Expr>,
 ) -> Result<(), InterpreterError> {
-    interp.env_set(variable.to_string(), item);
+    if !bind_comprehension_pattern(interp, pattern, &item)? {
+        return Ok(());
+    }
     if check_comprehension_condition(interp, condition)? {
         eval_comprehension_clauses(interp, results, element, clauses, clause_idx + 1)?;
     }
     Ok(())
 }
 
+/// Bind a comprehension item to the clause's pattern, as `for` binds
+///
+/// Items that do not match a variant pattern such as `Some(x)` are skipped
+/// (returns `false`); any other mismatch is an error.
+/// Complexity: 4
+fn bind_comprehension_pattern(
+    interp: &mut Interpreter,
+    pattern: &Pattern,
+    item: &Value,
+) -> Result<bool, InterpreterError> {
+    match interp.try_pattern_match(pattern, item)? {
+        Some(bindings) => {
+            for (name, value) in bindings {
+                interp.env_set(name, value);
+            }
+            Ok(true)
+        }
+        None if matches!(
+            pattern,
+            Pattern::Some(_)
+                | Pattern::None
+                | Pattern::Ok(_)
+                | Pattern::Err(_)
+                | Pattern::TupleVariant { .. }
+        ) =>
+        {
+            Ok(false)
+        }
+        None => Err(InterpreterError::TypeError(format!(
+            "Cannot destructure {} in comprehension",
+            item.type_name()
+        ))),
+    }
+}
+
+/// Materialize the items a comprehension generator iterates over
+/// Complexity: 5
+fn comprehension_items(
+    interp: &Interpreter,
+    iterable: &Value,
+) -> Result<Vec<Value>, InterpreterError> {
+    match iterable {
+        Value::Array(items) | Value::Tuple(items) => Ok(items.to_vec()),
+        Value::Range {
+            start,
+            end,
+            inclusive,
+        } => {
+            let (start_val, end_val) = interp.extract_range_bounds(start, end)?;
+            let range = interp.create_range_iterator(start_val, end_val, *inclusive);
+            crate::runtime::restricted::check_size(range.size_hint().0)?;
+            Ok(range.map(Value::Integer).collect())
+        }
+        Value::String(s) => Ok(s.chars().map(Value::from_char).collect()),
+        _ => Err(InterpreterError::TypeError(
+            "List comprehension requires an iterable".to_string(),
+        )),
+    }
+}
+
+/// Evaluate generator `clause_idx` and everything nested inside it.
+///
+/// Generators nest left-to-right (the first `for` is the outermost loop) and
+/// each iterable is re-evaluated per outer item, so later generators and
+/// filters can depend on earlier variables, matching Python semantics.
 pub(crate) fn eval_comprehension_clauses(
     interp: &mut Interpreter,
     results: &mut Vec<Value>,
@@ -665,53 +793,23 @@ pub(crate) fn eval_comprehension_clauses(
 
     let clause = &clauses[clause_idx];
     let iterable = interp.eval_expr(&clause.iterable)?;
-    let variable = clause.variable.clone();
-    let condition = clause.condition.clone();
+    let items = comprehension_items(interp, &iterable)?;
 
     interp.push_scope();
-    match iterable {
-        Value::Array(ref arr) => {
-            for item in arr.iter() {
-                iterate_comprehension_item(
-                    interp,
-                    results,
-                    element,
-                    clauses,
-                    clause_idx,
-                    &variable,
-                    item.clone(),
-                    condition.as_deref(),
-                )?;
-            }
-        }
-        Value::Range {
-            ref start,
-            ref end,
-            inclusive,
-        } => {
-            let (start_val, end_val) = interp.extract_range_bounds(start, end)?;
-            for i in interp.create_range_iterator(start_val, end_val, inclusive) {
-                iterate_comprehension_item(
-                    interp,
-                    results,
-                    element,
-                    clauses,
-                    clause_idx,
-                    &variable,
-                    Value::Integer(i),
-                    condition.as_deref(),
-                )?;
-            }
-        }
-        _ => {
-            interp.pop_scope();
-            return Err(InterpreterError::TypeError(
-                "List comprehension requires an iterable".to_string(),
-            ));
-        }
-    }
+    let outcome = items.into_iter().try_for_each(|item| {
+        iterate_comprehension_item(
+            interp,
+            results,
+            element,
+            clauses,
+            clause_idx,
+            &clause.pattern,
+            item,
+            clause.condition.as_deref(),
+        )
+    });
     interp.pop_scope();
-    Ok(())
+    outcome
 }
 
 /// Helper: Check comprehension condition
@@ -867,30 +965,33 @@ pub(crate) fn eval_type_definition(
             constructors,
             methods,
             constants,
-            properties: _,
+            properties,
             derives,
             is_pub,
             is_sealed: _,
             is_abstract: _,
             decorators: _,
-        } => interp.eval_class_definition(
-            name,
-            type_params,
-            superclass.as_ref(),
-            traits,
-            fields,
-            constructors,
-            methods,
-            constants,
-            derives,
-            *is_pub,
-        ),
+        } => {
+            let class_value = interp.eval_class_definition(
+                name,
+                type_params,
+                superclass.as_ref(),
+                traits,
+                fields,
+                constructors,
+                methods,
+                constants,
+                derives,
+                *is_pub,
+            )?;
+            interp.eval_class_properties(name, class_value, properties)
+        }
         ExprKind::Impl {
-            trait_name: _,
+            trait_name,
             for_type,
             methods,
             ..
-        } => interp.eval_impl_block(for_type, methods),
+        } => interp.eval_impl_block(for_type, trait_name.as_deref(), methods),
         _ => unreachable!("eval_type_definition called with non-type-definition"),
     }
 }
@@ -1038,4 +1139,39 @@ mod tests {
         let err_msg = result.unwrap_err().to_string();
         assert!(err_msg.contains("does_not_exist"));
     }
+
+    #[test]
+    fn test_extern_rust_block_needs_compilation() {
+        let mut interp = Interpreter::new();
+        let err = interp
+            .eval_string("extern \"rust\" slug { fun slugify(s: &str) -> String }")
+            .unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("from `slug`"), "{message}");
+        assert!(message.contains("ruchy build"), "{message}");
+        assert!(interp.eval_string("extern \"C\" { }").is_ok());
+    }
+
+    #[test]
+    fn test_rust_block_runs_fallback() {
+        let mut interp = Interpreter::new();
+        let err = interp.eval_string("rust { fn f() {} }").unwrap_err();
+        assert!(err.to_string().contains("ruchy compile"), "{err}");
+
+        interp
+            .eval_string(
+                "rust { fn double(x: i64) -> i64 { x * 2 } } else { fun double(x) { x * 2 } }",
+            )
+            .unwrap();
+        let program = crate::frontend::parser::Parser::new(
+            "let n = 4\nrust(n: i64) -> i64 { n + double(n) } else { n + double(n) }",
+        )
+        .parse()
+        .unwrap();
+        let value = interp.eval_top_level(&program).unwrap();
+        assert_eq!(value, Value::Integer(12));
+        assert!(interp
+            .eval_string("rust(missing: i64) -> i64 { missing } else { 0 }")
+            .is_err());
+    }
 }
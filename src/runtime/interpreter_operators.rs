@@ -0,0 +1,350 @@
+//! Operator overloading through trait impls
+//!
+//! A struct's trait impls define what operators mean for it: after
+//! `impl Add for Point { fun add(self, other) { ... } }`, `p1 + p2` calls
+//! `add`. Impl blocks register each trait method as `<Point as Add>::add`
+//! next to `Point::add`, and operators only dispatch through the former, so
+//! an inherent `add` method leaves `+` alone.
+//!
+//! | Syntax | Trait | Method |
+//! |---|---|---|
+//! | `a + b`, `-`, `*`, `/`, `%` | `Add`, `Sub`, `Mul`, `Div`, `Rem` | `add(self, other)`, ... |
+//! | `a == b`, `a != b` | `PartialEq` or `Eq` | `eq(&self, other) -> bool` |
+//! | `a < b`, `<=`, `>`, `>=` | `Ord` | `cmp(&self, other) -> Ordering` |
+//! | `a[i]` | `Index` | `index(&self, i)` |
+//! | `println(a)`, `f"{a}"`, `a.to_string()` | `Display` | `fmt(&self) -> String` |
+//!
+//! `PartialOrd::partial_cmp` returning `Some(ordering)` orders too, and
+//! `None` makes every comparison false. Without an impl, operators keep
+//! their built-in meaning, such as field-wise `==`.
+
+use crate::frontend::ast::BinaryOp;
+use crate::runtime::interpreter::Interpreter;
+use crate::runtime::{InterpreterError, Value};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Environment name of a trait impl's method, `<Type as Trait>::method` (complexity: 1)
+pub(crate) fn trait_method_name(for_type: &str, trait_name: &str, method: &str) -> String {
+    format!(
+        "<{} as {}>::{method}",
+        base_name(for_type),
+        base_name(trait_name)
+    )
+}
+
+/// A type or trait name without its generic arguments: `Index<usize>` is `Index` (complexity: 1)
+pub(crate) fn base_name(name: &str) -> &str {
+    name.split('<').next().unwrap_or(name).trim()
+}
+
+/// Trait methods a binary operator dispatches to, in order of preference (complexity: 3)
+fn operator_methods(op: BinaryOp) -> &'static [(&'static str, &'static str)] {
+    match op {
+        BinaryOp::Add => &[("Add", "add")],
+        BinaryOp::Subtract => &[("Sub", "sub")],
+        BinaryOp::Multiply => &[("Mul", "mul")],
+        BinaryOp::Divide => &[("Div", "div")],
+        BinaryOp::Modulo => &[("Rem", "rem")],
+        BinaryOp::Equal | BinaryOp::NotEqual => &[("PartialEq", "eq"), ("Eq", "eq")],
+        BinaryOp::Less
+        | BinaryOp::LessEqual
+        | BinaryOp::Greater
+        | BinaryOp::GreaterEqual
+        | BinaryOp::Gt => &[("Ord", "cmp"), ("PartialOrd", "partial_cmp")],
+        _ => &[],
+    }
+}
+
+/// The built-in `Ordering` enum `cmp` methods return (complexity: 1)
+///
+/// Only used when the program does not define an `Ordering` of its own.
+pub(crate) fn ordering_enum() -> Value {
+    let variants = ["Less", "Equal", "Greater"]
+        .iter()
+        .map(|name| {
+            let info =
+                HashMap::from([("kind".to_string(), Value::from_string("Unit".to_string()))]);
+            ((*name).to_string(), Value::Object(Arc::new(info)))
+        })
+        .collect();
+    let info = HashMap::from([
+        ("__type".to_string(), Value::from_string("Enum".to_string())),
+        (
+            "__name".to_string(),
+            Value::from_string("Ordering".to_string()),
+        ),
+        ("__variants".to_string(), Value::Object(Arc::new(variants))),
+    ]);
+    Value::Object(Arc::new(info))
+}
+
+/// The ordering an `Ordering` variant, or `Some` of one, stands for (complexity: 5)
+fn ordering_of(value: &Value) -> Option<Ordering> {
+    match value {
+        Value::EnumVariant {
+            enum_name,
+            variant_name,
+            data: None,
+        } if enum_name == "Ordering" => match variant_name.as_str() {
+            "Less" => Some(Ordering::Less),
+            "Equal" => Some(Ordering::Equal),
+            "Greater" => Some(Ordering::Greater),
+            _ => None,
+        },
+        Value::EnumVariant {
+            enum_name,
+            variant_name,
+            data: Some(data),
+        } if enum_name == "Option" && variant_name == "Some" => data.first().and_then(ordering_of),
+        _ => None,
+    }
+}
+
+/// Whether a `partial_cmp` result says the operands are incomparable (complexity: 2)
+fn is_none(value: &Value) -> bool {
+    match value {
+        Value::Nil => true,
+        Value::EnumVariant {
+            enum_name,
+            variant_name,
+            ..
+        } => enum_name == "Option" && variant_name == "None",
+        _ => false,
+    }
+}
+
+/// Result of `op` from what its trait method returned (complexity: 8)
+fn operator_result(
+    op: BinaryOp,
+    type_name: &str,
+    method: &str,
+    value: Value,
+) -> Result<Value, InterpreterError> {
+    match op {
+        BinaryOp::Equal | BinaryOp::NotEqual => match value {
+            Value::Bool(equal) => Ok(Value::Bool(equal == (op == BinaryOp::Equal))),
+            other => Err(InterpreterError::TypeError(format!(
+                "{type_name}::{method} must return a bool, got {}",
+                other.type_name()
+            ))),
+        },
+        BinaryOp::Less
+        | BinaryOp::LessEqual
+        | BinaryOp::Greater
+        | BinaryOp::GreaterEqual
+        | BinaryOp::Gt => {
+            if is_none(&value) {
+                return Ok(Value::Bool(false));
+            }
+            let ordering = ordering_of(&value).ok_or_else(|| {
+                InterpreterError::TypeError(format!(
+                    "{type_name}::{method} must return an Ordering, got {}",
+                    value.type_name()
+                ))
+            })?;
+            Ok(Value::Bool(match op {
+                BinaryOp::Less => ordering.is_lt(),
+                BinaryOp::LessEqual => ordering.is_le(),
+                BinaryOp::GreaterEqual => ordering.is_ge(),
+                _ => ordering.is_gt(),
+            }))
+        }
+        _ => Ok(value),
+    }
+}
+
+impl Interpreter {
+    /// A binding in any scope, without building an error when it is missing (complexity: 2)
+    fn binding(&self, name: &str) -> Option<Value> {
+        self.env_stack
+            .iter()
+            .rev()
+            .find_map(|env| env.borrow().get(name).cloned())
+    }
+
+    /// Apply `op` through the trait impl of a struct left operand (complexity: 4)
+    ///
+    /// `None` when the left operand is not a struct or its type has no impl
+    /// for the operator, so that the built-in operator applies.
+    pub(crate) fn eval_overloaded_binary(
+        &mut self,
+        op: BinaryOp,
+        left: &Value,
+        right: &Value,
+    ) -> Option<Result<Value, InterpreterError>> {
+        let Value::Struct { name, fields } = left else {
+            return None;
+        };
+        let (method, closure) = operator_methods(op)
+            .iter()
+            .find_map(|(trait_name, method)| {
+                self.binding(&trait_method_name(name, trait_name, method))
+                    .map(|closure| (*method, closure))
+            })?;
+        let result =
+            self.call_struct_method(fields, name, method, closure, std::slice::from_ref(right));
+        Some(result.and_then(|value| operator_result(op, name, method, value)))
+    }
+
+    /// Index `object`, through its `Index` impl if it is a struct with one (complexity: 3)
+    pub(crate) fn index_with_impls(
+        &mut self,
+        object: &Value,
+        index: &Value,
+    ) -> Result<Value, InterpreterError> {
+        if let Value::Struct { name, fields } = object {
+            if let Some(closure) = self.binding(&trait_method_name(name, "Index", "index")) {
+                return self.call_struct_method(
+                    fields,
+                    name,
+                    "index",
+                    closure,
+                    std::slice::from_ref(index),
+                );
+            }
+        }
+        Self::index_value(object, index)
+    }
+
+    /// Text of a struct with a `Display` impl; `None` for other values (complexity: 4)
+    pub(crate) fn display_with_impl(
+        &mut self,
+        value: &Value,
+    ) -> Result<Option<String>, InterpreterError> {
+        let Value::Struct { name, fields } = value else {
+            return Ok(None);
+        };
+        let Some(fmt) = self.binding(&trait_method_name(name, "Display", "fmt")) else {
+            return Ok(None);
+        };
+        match self.call_struct_method(fields, name, "fmt", fmt, &[])? {
+            Value::String(text) => Ok(Some(text.to_string())),
+            other => Err(InterpreterError::TypeError(format!(
+                "{name}::fmt must return a string, got {}",
+                other.type_name()
+            ))),
+        }
+    }
+
+    /// `args` with each struct that has a `Display` impl replaced by its text (complexity: 4)
+    ///
+    /// `None` when no argument is a struct, so printing needs no copy.
+    pub(crate) fn display_args(
+        &mut self,
+        args: &[Value],
+    ) -> Result<Option<Vec<Value>>, InterpreterError> {
+        if !args.iter().any(|arg| matches!(arg, Value::Struct { .. })) {
+            return Ok(None);
+        }
+        let mut displayed = Vec::with_capacity(args.len());
+        for arg in args {
+            displayed.push(match self.display_with_impl(arg)? {
+                Some(text) => Value::from_string(text),
+                None => arg.clone(),
+            });
+        }
+        Ok(Some(displayed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POINT: &str = r#"
+        struct Point { x: i32, y: i32 }
+        impl Add for Point {
+            fun add(self, other: Point) -> Point {
+                Point { x: self.x + other.x, y: self.y + other.y }
+            }
+        }
+        impl PartialEq for Point {
+            fun eq(&self, other: &Point) -> bool { self.x == other.x }
+        }
+        impl Ord for Point {
+            fun cmp(&self, other: &Point) -> Ordering {
+                if self.x < other.x { Ordering::Less }
+                else if self.x > other.x { Ordering::Greater }
+                else { Ordering::Equal }
+            }
+        }
+        impl Index<i32> for Point {
+            fun index(&self, i: i32) -> i32 { if i == 0 { self.x } else { self.y } }
+        }
+        impl Display for Point {
+            fun fmt(&self) -> String { f"({self.x}, {self.y})" }
+        }
+    "#;
+
+    /// Evaluate the whole program `source`, keeping its definitions
+    fn eval_program(
+        interp: &mut Interpreter,
+        source: &str,
+    ) -> Result<Value, Box<dyn std::error::Error>> {
+        let ast = crate::frontend::parser::Parser::new(source).parse()?;
+        Ok(interp.eval_top_level(&ast)?)
+    }
+
+    fn eval(code: &str) -> Result<Value, Box<dyn std::error::Error>> {
+        eval_program(&mut Interpreter::new(), &format!("{POINT}\n{code}"))
+    }
+
+    #[test]
+    fn test_trait_method_names() {
+        assert_eq!(
+            trait_method_name("Point", "Add", "add"),
+            "<Point as Add>::add"
+        );
+        assert_eq!(
+            trait_method_name("Grid<T>", "Index<usize>", "index"),
+            "<Grid as Index>::index"
+        );
+    }
+
+    #[test]
+    fn test_arithmetic_and_equality_operators() {
+        let result = eval("let p = Point { x: 1, y: 2 } + Point { x: 3, y: 4 }\np.y").unwrap();
+        assert_eq!(result, Value::Integer(6));
+        let result = eval("Point { x: 1, y: 2 } == Point { x: 1, y: 5 }").unwrap();
+        assert_eq!(result, Value::Bool(true));
+        let result = eval("Point { x: 1, y: 2 } != Point { x: 1, y: 5 }").unwrap();
+        assert_eq!(result, Value::Bool(false));
+    }
+
+    #[test]
+    fn test_comparison_operators() {
+        let result = eval("Point { x: 1, y: 9 } < Point { x: 2, y: 0 }").unwrap();
+        assert_eq!(result, Value::Bool(true));
+        let result = eval("Point { x: 2, y: 0 } <= Point { x: 1, y: 9 }").unwrap();
+        assert_eq!(result, Value::Bool(false));
+        let result = eval("Point { x: 2, y: 0 } >= Point { x: 2, y: 9 }").unwrap();
+        assert_eq!(result, Value::Bool(true));
+    }
+
+    #[test]
+    fn test_index_and_display() {
+        let result = eval("let p = Point { x: 7, y: 8 }\np[1]").unwrap();
+        assert_eq!(result, Value::Integer(8));
+        let result = eval("let p = Point { x: 1, y: 2 }\nf\"at {p}\"").unwrap();
+        assert_eq!(result, Value::from_string("at (1, 2)".to_string()));
+        let result = eval("Point { x: 1, y: 2 }.to_string()").unwrap();
+        assert_eq!(result, Value::from_string("(1, 2)".to_string()));
+    }
+
+    #[test]
+    fn test_structs_without_impls_keep_builtin_operators() {
+        let mut interp = Interpreter::new();
+        let result = eval_program(
+            &mut interp,
+            "struct P { x: i32 }\nimpl P { fun add(self, o) { 0 } }\nP { x: 1 } == P { x: 1 }",
+        );
+        assert_eq!(result.unwrap(), Value::Bool(true));
+        let result = eval_program(&mut interp, "P { x: 1 } + P { x: 2 }");
+        assert!(
+            result.is_err(),
+            "inherent methods do not overload operators"
+        );
+    }
+}
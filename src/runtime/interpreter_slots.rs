@@ -0,0 +1,1220 @@
+//! Slot-indexed locals for function bodies
+//!
+//! Every call used to bind its parameters in a fresh `HashMap` scope, and
+//! every identifier was looked up by name from the innermost scope outwards,
+//! so a variable read inside a few nested blocks of a loop hashed its name
+//! once per enclosing scope. The first time a function is called its body is
+//! now resolved: parameters, `let` bindings and loop variables get slot
+//! indexes, and the body runs against a `Vec<Value>` frame. Names that are
+//! not locals (functions, globals, builtins) are still looked up by name.
+//!
+//! Only bodies built from literals, operators, plain calls, indexing, blocks,
+//! `let`, assignment, `if`, loops and `break`/`continue`/`return` are
+//! resolved. Anything else (lambdas, nested definitions, `match`, method
+//! calls, field access, ...) keeps the name-based path, as does top-level
+//! and REPL code, whose environment is dynamic.
+//!
+//! Scoping matches the name-based path, where a function sees the locals of
+//! the functions that called it: a call copies the caller's visible locals
+//! into its scope and copies them back once the callee returns. A body whose
+//! loop variable is not one of its locals but names a binding visible at the
+//! call keeps the name-based path, where the loop assigns that binding.
+
+use crate::frontend::ast::{BinaryOp, Expr, ExprKind, Literal, Pattern, UnaryOp};
+use crate::runtime::eval_literal;
+use crate::runtime::interpreter::Interpreter;
+use crate::runtime::interpreter_tail_call::TailOutcome;
+use crate::runtime::{InterpreterError, Value};
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::{Arc, Weak};
+
+/// Cached bodies kept before those of dropped functions are pruned
+const MAX_CACHED_BODIES: usize = 1024;
+
+/// Function body whose locals have been assigned slots
+#[derive(Debug)]
+pub(crate) struct ResolvedBody {
+    /// Slots in a frame; the parameters come first
+    slots: usize,
+    /// Loop variables that are not otherwise locals
+    loop_names: Vec<String>,
+    root: Node,
+}
+
+/// Local variable and the slot that holds it
+#[derive(Debug)]
+struct Local {
+    slot: usize,
+    name: String,
+}
+
+/// Variable written by an assignment
+#[derive(Debug)]
+enum Target {
+    Local(Local),
+    /// Not a local: assigned by name through the environment stack
+    Name(String),
+}
+
+#[derive(Debug)]
+enum Node {
+    Const(Value),
+    Local(usize),
+    /// Not a local: looked up by name through the environment stack
+    Name(String),
+    Binary {
+        op: BinaryOp,
+        left: Box<Node>,
+        right: Box<Node>,
+        site: usize,
+    },
+    Unary {
+        op: UnaryOp,
+        operand: Box<Node>,
+    },
+    /// Call of a function that is not a local
+    Call {
+        func: Box<Expr>,
+        args: Vec<Node>,
+        /// Locals in scope at the call, which the callee may read by name
+        visible: Vec<Local>,
+    },
+    Index {
+        object: Box<Node>,
+        index: Box<Node>,
+    },
+    Range {
+        start: Box<Node>,
+        end: Box<Node>,
+        inclusive: bool,
+    },
+    List(Vec<Node>),
+    Tuple(Vec<Node>),
+    Block(Vec<Node>),
+    If {
+        condition: Box<Node>,
+        then_branch: Box<Node>,
+        else_branch: Option<Box<Node>>,
+    },
+    Let {
+        local: Local,
+        value: Box<Node>,
+        body: Option<Box<Node>>,
+    },
+    Assign {
+        target: Target,
+        value: Box<Node>,
+    },
+    CompoundAssign {
+        target: Target,
+        op: BinaryOp,
+        value: Box<Node>,
+    },
+    AssignIndex {
+        target: Target,
+        index: Box<Node>,
+        value: Box<Node>,
+    },
+    CompoundAssignIndex {
+        target: Target,
+        index: Box<Node>,
+        op: BinaryOp,
+        value: Box<Node>,
+    },
+    For {
+        label: Option<String>,
+        local: Local,
+        iter: Box<Node>,
+        body: Box<Node>,
+    },
+    While {
+        label: Option<String>,
+        condition: Box<Node>,
+        body: Box<Node>,
+    },
+    Loop {
+        label: Option<String>,
+        body: Box<Node>,
+    },
+    Break {
+        label: Option<String>,
+        value: Option<Box<Node>>,
+    },
+    Continue {
+        label: Option<String>,
+    },
+    Return(Option<Box<Node>>),
+}
+
+/// Assigns slots while walking a body in evaluation order
+struct Resolver {
+    scopes: Vec<Vec<(String, usize)>>,
+    slots: usize,
+    loop_names: Vec<String>,
+}
+
+impl Resolver {
+    fn lookup(&self, name: &str) -> Option<usize> {
+        self.scopes.iter().rev().find_map(|scope| {
+            scope
+                .iter()
+                .rev()
+                .find(|(bound, _)| bound == name)
+                .map(|(_, slot)| *slot)
+        })
+    }
+
+    fn declare(&mut self, name: &str) -> Local {
+        let slot = self.slots;
+        self.slots += 1;
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push((name.to_string(), slot));
+        }
+        Local {
+            slot,
+            name: name.to_string(),
+        }
+    }
+
+    /// Locals in scope, innermost binding of each name first
+    fn visible(&self) -> Vec<Local> {
+        let mut visible: Vec<Local> = Vec::new();
+        for (name, slot) in self
+            .scopes
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.iter().rev())
+        {
+            if !visible.iter().any(|local| &local.name == name) {
+                visible.push(Local {
+                    slot: *slot,
+                    name: name.clone(),
+                });
+            }
+        }
+        visible
+    }
+
+    fn target(&self, name: &str) -> Target {
+        match self.lookup(name) {
+            Some(slot) => Target::Local(Local {
+                slot,
+                name: name.to_string(),
+            }),
+            None => Target::Name(name.to_string()),
+        }
+    }
+
+    /// Resolve `exprs` in a new scope, as the interpreter runs a block
+    fn block(&mut self, exprs: &[Expr]) -> Option<Vec<Node>> {
+        self.scopes.push(Vec::new());
+        let nodes = self.sequence(exprs);
+        self.scopes.pop();
+        nodes
+    }
+
+    fn sequence(&mut self, exprs: &[Expr]) -> Option<Vec<Node>> {
+        exprs.iter().map(|expr| self.expr(expr)).collect()
+    }
+
+    fn boxed(&mut self, expr: &Expr) -> Option<Box<Node>> {
+        self.expr(expr).map(Box::new)
+    }
+
+    fn optional(&mut self, expr: Option<&Expr>) -> Option<Option<Box<Node>>> {
+        match expr {
+            Some(expr) => self.boxed(expr).map(Some),
+            None => Some(None),
+        }
+    }
+
+    /// Resolved `expr`, or `None` if it uses a construct that needs the
+    /// name-based path
+    fn expr(&mut self, expr: &Expr) -> Option<Node> {
+        let node = match &expr.kind {
+            ExprKind::Literal(lit) => Node::Const(eval_literal::eval_literal(lit)),
+            ExprKind::Identifier(name) => match self.lookup(name) {
+                Some(slot) => Node::Local(slot),
+                None => Node::Name(name.clone()),
+            },
+            ExprKind::Binary { left, op, right } if *op != BinaryOp::Send => Node::Binary {
+                op: *op,
+                left: self.boxed(left)?,
+                right: self.boxed(right)?,
+                site: left.span.start,
+            },
+            ExprKind::Unary { op, operand } => Node::Unary {
+                op: *op,
+                operand: self.boxed(operand)?,
+            },
+            ExprKind::Call { func, args } => self.call(func, args)?,
+            ExprKind::IndexAccess { object, index } => Node::Index {
+                object: self.boxed(object)?,
+                index: self.boxed(index)?,
+            },
+            ExprKind::Range {
+                start,
+                end,
+                inclusive,
+            } => Node::Range {
+                start: self.boxed(start)?,
+                end: self.boxed(end)?,
+                inclusive: *inclusive,
+            },
+            ExprKind::List(elements) => Node::List(self.sequence(elements)?),
+            ExprKind::Tuple(elements) => Node::Tuple(self.sequence(elements)?),
+            ExprKind::Block(statements) => Node::Block(self.block(statements)?),
+            ExprKind::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => Node::If {
+                condition: self.boxed(condition)?,
+                then_branch: self.boxed(then_branch)?,
+                else_branch: self.optional(else_branch.as_deref())?,
+            },
+            ExprKind::Ternary {
+                condition,
+                true_expr,
+                false_expr,
+            } => Node::If {
+                condition: self.boxed(condition)?,
+                then_branch: self.boxed(true_expr)?,
+                else_branch: Some(self.boxed(false_expr)?),
+            },
+            ExprKind::Let {
+                name,
+                value,
+                body,
+                else_block: None,
+                ..
+            } => {
+                let value = self.boxed(value)?;
+                let local = self.declare(name);
+                let body = match body.kind {
+                    ExprKind::Literal(Literal::Unit) => None,
+                    _ => Some(self.boxed(body)?),
+                };
+                Node::Let { local, value, body }
+            }
+            ExprKind::Assign { target, value } => self.assign(target, None, value)?,
+            ExprKind::CompoundAssign { target, op, value } => {
+                self.assign(target, Some(*op), value)?
+            }
+            ExprKind::For {
+                label,
+                var,
+                pattern,
+                iter,
+                body,
+            } => {
+                if !matches!(
+                    pattern,
+                    None | Some(Pattern::Identifier(_) | Pattern::Wildcard)
+                ) {
+                    return None;
+                }
+                let iter = self.boxed(iter)?;
+                // Like `set_variable`, the loop variable reuses a visible
+                // binding and otherwise stays bound after the loop
+                let local = match self.lookup(var) {
+                    Some(slot) => Local {
+                        slot,
+                        name: var.clone(),
+                    },
+                    None => {
+                        self.loop_names.push(var.clone());
+                        self.declare(var)
+                    }
+                };
+                Node::For {
+                    label: label.clone(),
+                    local,
+                    iter,
+                    body: self.boxed(body)?,
+                }
+            }
+            ExprKind::While {
+                label,
+                condition,
+                body,
+            } => Node::While {
+                label: label.clone(),
+                condition: self.boxed(condition)?,
+                body: self.boxed(body)?,
+            },
+            ExprKind::Loop { label, body } => Node::Loop {
+                label: label.clone(),
+                body: self.boxed(body)?,
+            },
+            ExprKind::Break { label, value } => Node::Break {
+                label: label.clone(),
+                value: self.optional(value.as_deref())?,
+            },
+            ExprKind::Continue { label } => Node::Continue {
+                label: label.clone(),
+            },
+            ExprKind::Return { value } => Node::Return(self.optional(value.as_deref())?),
+            _ => return None,
+        };
+        Some(node)
+    }
+
+    /// Plain call of a function that is not a local, without named arguments
+    fn call(&mut self, func: &Expr, args: &[Expr]) -> Option<Node> {
+        let ExprKind::Identifier(name) = &func.kind else {
+            return None;
+        };
+        let named_args = args.iter().any(|arg| {
+            matches!(
+                &arg.kind,
+                ExprKind::Assign { target, .. } if matches!(target.kind, ExprKind::Identifier(_))
+            )
+        });
+        if named_args || self.lookup(name).is_some() {
+            return None;
+        }
+        Some(Node::Call {
+            func: Box::new(func.clone()),
+            args: self.sequence(args)?,
+            visible: self.visible(),
+        })
+    }
+
+    /// `target = value`, or `target op= value` when `op` is given
+    fn assign(&mut self, target: &Expr, op: Option<BinaryOp>, value: &Expr) -> Option<Node> {
+        match &target.kind {
+            ExprKind::Identifier(name) => {
+                let value = self.boxed(value)?;
+                let target = self.target(name);
+                Some(match op {
+                    Some(op) => Node::CompoundAssign { target, op, value },
+                    None => Node::Assign { target, value },
+                })
+            }
+            ExprKind::IndexAccess { object, index } => {
+                let ExprKind::Identifier(name) = &object.kind else {
+                    return None;
+                };
+                let index = self.boxed(index)?;
+                let value = self.boxed(value)?;
+                let target = self.target(name);
+                Some(match op {
+                    Some(op) => Node::CompoundAssignIndex {
+                        target,
+                        index,
+                        op,
+                        value,
+                    },
+                    None => Node::AssignIndex {
+                        target,
+                        index,
+                        value,
+                    },
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Resolve a function body, or `None` if it needs the name-based path
+///
+/// Like `call_function`, a block body runs in the parameter scope.
+fn resolve_body(params: &[&str], body: &Expr) -> Option<ResolvedBody> {
+    let mut resolver = Resolver {
+        scopes: vec![Vec::new()],
+        slots: 0,
+        loop_names: Vec::new(),
+    };
+    for param in params {
+        resolver.declare(param);
+    }
+    let root = match &body.kind {
+        ExprKind::Block(statements) => Node::Block(resolver.sequence(statements)?),
+        _ => resolver.expr(body)?,
+    };
+    Some(ResolvedBody {
+        slots: resolver.slots,
+        loop_names: resolver.loop_names,
+        root,
+    })
+}
+
+#[derive(Debug)]
+struct CachedBody {
+    /// Keeps the body's address from being reused while the entry exists
+    body: Weak<Expr>,
+    params: Vec<String>,
+    resolved: Option<Rc<ResolvedBody>>,
+    /// Whether the body defers expressions, which rules out tail calls
+    defers: bool,
+    /// Whether the body has an explicit `return`
+    returns: bool,
+}
+
+/// Resolved function bodies, keyed by body address
+#[derive(Debug, Default)]
+pub struct SlotCache {
+    bodies: HashMap<usize, CachedBody>,
+}
+
+/// Resolved function body counters
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SlotCacheStats {
+    /// Bodies running with slot-indexed locals
+    pub resolved: usize,
+    /// Bodies that need the name-based path
+    pub unresolved: usize,
+}
+
+impl SlotCache {
+    /// Create an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolved form of `body`, resolving it on first use
+    fn get(
+        &mut self,
+        params: &[(String, Option<Arc<Expr>>)],
+        body: &Arc<Expr>,
+    ) -> Option<Rc<ResolvedBody>> {
+        let key = Arc::as_ptr(body) as usize;
+        if let Some(entry) = self.bodies.get(&key) {
+            let same_params = entry.params.len() == params.len()
+                && entry
+                    .params
+                    .iter()
+                    .zip(params)
+                    .all(|(cached, (name, _))| cached == name);
+            if same_params {
+                return entry.resolved.clone();
+            }
+        }
+
+        let names: Vec<&str> = params.iter().map(|(name, _)| name.as_str()).collect();
+        let resolved = resolve_body(&names, body).map(Rc::new);
+        if self.bodies.len() >= MAX_CACHED_BODIES {
+            self.bodies.retain(|_, entry| entry.body.strong_count() > 0);
+            if self.bodies.len() >= MAX_CACHED_BODIES {
+                self.bodies.clear();
+            }
+        }
+        self.bodies.insert(
+            key,
+            CachedBody {
+                body: Arc::downgrade(body),
+                params: params.iter().map(|(name, _)| name.clone()).collect(),
+                resolved: resolved.clone(),
+                defers: body.contains_defer(),
+                returns: body.contains_return(),
+            },
+        );
+        resolved
+    }
+
+    /// Whether `body` defers expressions, walking it only if it is not cached
+    pub(crate) fn defers(&self, body: &Arc<Expr>) -> bool {
+        self.bodies
+            .get(&(Arc::as_ptr(body) as usize))
+            .map_or_else(|| body.contains_defer(), |entry| entry.defers)
+    }
+
+    /// Whether `body` has an explicit `return`, walking it only if it is not cached
+    pub(crate) fn returns(&self, body: &Arc<Expr>) -> bool {
+        self.bodies
+            .get(&(Arc::as_ptr(body) as usize))
+            .map_or_else(|| body.contains_return(), |entry| entry.returns)
+    }
+
+    /// Current counters
+    pub fn stats(&self) -> SlotCacheStats {
+        let resolved = self
+            .bodies
+            .values()
+            .filter(|entry| entry.resolved.is_some())
+            .count();
+        SlotCacheStats {
+            resolved,
+            unresolved: self.bodies.len() - resolved,
+        }
+    }
+}
+
+/// How one pass of a loop body ended
+enum Pass {
+    Value(Value),
+    Continue,
+    Break(Value),
+}
+
+fn targets_loop(label: Option<&String>, loop_label: Option<&String>) -> bool {
+    label.is_none() || label == loop_label
+}
+
+impl Interpreter {
+    /// Slot-resolved form of a closure body, if it has one and can run with
+    /// slots in the current environment
+    pub(crate) fn resolved_body(
+        &mut self,
+        params: &[(String, Option<Arc<Expr>>)],
+        body: &Arc<Expr>,
+    ) -> Option<Rc<ResolvedBody>> {
+        let resolved = self.slot_cache.get(params, body)?;
+        // On the name-based path such a loop assigns the visible binding
+        let assigns_outer = resolved
+            .loop_names
+            .iter()
+            .any(|name| self.find_binding_scope(name).is_some());
+        (!assigns_outer).then_some(resolved)
+    }
+
+    /// Run a resolved body with `args` bound to its parameter slots
+    ///
+    /// Assignments to names that are neither locals nor bound in an outer
+    /// scope create them in a scope of their own, as the parameter scope did.
+    pub(crate) fn eval_resolved_body(
+        &mut self,
+        resolved: &ResolvedBody,
+        args: Vec<Value>,
+        function_body: &Arc<Expr>,
+    ) -> Result<TailOutcome, InterpreterError> {
+        let mut frame = args;
+        frame.resize(resolved.slots, Value::Nil);
+        self.push_scope();
+        let outcome = self.eval_slot_tail(&resolved.root, &mut frame, function_body);
+        self.pop_scope();
+        outcome
+    }
+
+    /// Resolved function bodies counters
+    pub fn slot_cache_stats(&self) -> SlotCacheStats {
+        self.slot_cache.stats()
+    }
+
+    /// Evaluate `node` in tail position, as `eval_tail` does
+    ///
+    /// Branches and last statements are followed in a loop rather than by
+    /// recursion, so nesting adds no stack frames.
+    fn eval_slot_tail(
+        &mut self,
+        mut node: &Node,
+        frame: &mut [Value],
+        function_body: &Arc<Expr>,
+    ) -> Result<TailOutcome, InterpreterError> {
+        loop {
+            node = match node {
+                Node::If {
+                    condition,
+                    then_branch,
+                    else_branch,
+                } => {
+                    if self.eval_slot(condition, frame)?.is_truthy() {
+                        then_branch
+                    } else if let Some(else_branch) = else_branch {
+                        else_branch
+                    } else {
+                        return Ok(TailOutcome::Value(Value::Nil));
+                    }
+                }
+                Node::Block(statements) => {
+                    let Some((last, init)) = statements.split_last() else {
+                        return Ok(TailOutcome::Value(Value::Nil));
+                    };
+                    for statement in init {
+                        self.eval_slot(statement, frame)?;
+                    }
+                    last
+                }
+                Node::Let {
+                    local,
+                    value,
+                    body: Some(body),
+                } => {
+                    let value = self.eval_slot(value, frame)?;
+                    self.bind_slot(frame, local, value);
+                    body
+                }
+                Node::Return(Some(value)) => value,
+                Node::Call { func, args, .. } if self.is_self_call(func, &[], function_body) => {
+                    let arg_vals = self.eval_slot_args(args, frame)?;
+                    return Ok(TailOutcome::SelfCall(arg_vals));
+                }
+                _ => return self.eval_slot(node, frame).map(TailOutcome::Value),
+            };
+        }
+    }
+
+    /// Evaluate `node`; calls recurse through here, so arms that need much
+    /// stack live in the helpers below to keep deep recursion within the
+    /// thread's stack
+    fn eval_slot(&mut self, node: &Node, frame: &mut [Value]) -> Result<Value, InterpreterError> {
+        match node {
+            Node::Const(value) => Ok(value.clone()),
+            Node::Local(slot) => Ok(frame[*slot].clone()),
+            Node::Name(name) => self.lookup_variable(name),
+            Node::Binary {
+                op,
+                left,
+                right,
+                site,
+            } => self.eval_slot_binary(*op, left, right, *site, frame),
+            Node::Unary { op, operand } => {
+                let operand = self.eval_slot(operand, frame)?;
+                self.eval_unary_op(*op, &operand)
+            }
+            Node::Call {
+                func,
+                args,
+                visible,
+            } => {
+                let arg_vals = self.eval_slot_args(args, frame)?;
+                self.with_visible_locals(visible, frame, |this| this.call_with_args(func, arg_vals))
+            }
+            Node::Block(statements) => {
+                let mut last = Value::Nil;
+                for statement in statements {
+                    last = self.eval_slot(statement, frame)?;
+                }
+                Ok(last)
+            }
+            Node::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                if self.eval_slot(condition, frame)?.is_truthy() {
+                    self.eval_slot(then_branch, frame)
+                } else if let Some(else_branch) = else_branch {
+                    self.eval_slot(else_branch, frame)
+                } else {
+                    Ok(Value::Nil)
+                }
+            }
+            Node::Let { local, value, body } => {
+                let value = self.eval_slot(value, frame)?;
+                self.bind_slot(frame, local, value.clone());
+                match body {
+                    Some(body) => self.eval_slot(body, frame),
+                    None => Ok(value),
+                }
+            }
+            Node::Index { .. } | Node::Range { .. } | Node::List(_) | Node::Tuple(_) => {
+                self.eval_slot_data(node, frame)
+            }
+            Node::Assign { .. }
+            | Node::CompoundAssign { .. }
+            | Node::AssignIndex { .. }
+            | Node::CompoundAssignIndex { .. } => self.eval_slot_assign(node, frame),
+            _ => self.eval_slot_control(node, frame),
+        }
+    }
+
+    /// Indexing, ranges, lists and tuples
+    fn eval_slot_data(
+        &mut self,
+        node: &Node,
+        frame: &mut [Value],
+    ) -> Result<Value, InterpreterError> {
+        match node {
+            Node::Index { object, index } => {
+                let object = self.eval_slot(object, frame)?;
+                let index = self.eval_slot(index, frame)?;
+                self.index_with_impls(&object, &index)
+            }
+            Node::Range {
+                start,
+                end,
+                inclusive,
+            } => Ok(Value::Range {
+                start: Box::new(self.eval_slot(start, frame)?),
+                end: Box::new(self.eval_slot(end, frame)?),
+                inclusive: *inclusive,
+            }),
+            Node::List(elements) => Ok(Value::from_array(self.eval_slot_args(elements, frame)?)),
+            Node::Tuple(elements) => {
+                let values = self.eval_slot_args(elements, frame)?;
+                Ok(Value::Tuple(Arc::from(values.as_slice())))
+            }
+            _ => unreachable!("eval_slot_data called with non-data node"),
+        }
+    }
+
+    /// Assignments to locals, names and their elements
+    fn eval_slot_assign(
+        &mut self,
+        node: &Node,
+        frame: &mut [Value],
+    ) -> Result<Value, InterpreterError> {
+        match node {
+            Node::Assign { target, value } => {
+                let value = self.eval_slot(value, frame)?;
+                self.assign_target(frame, target, value.clone());
+                Ok(value)
+            }
+            Node::CompoundAssign { target, op, value } => {
+                let current = match target {
+                    Target::Local(local) => frame[local.slot].clone(),
+                    Target::Name(name) => self.lookup_variable(name)?,
+                };
+                let Some(new_val) = self.eval_slot_compound(&current, *op, value, frame)? else {
+                    return Ok(current);
+                };
+                self.assign_target(frame, target, new_val.clone());
+                Ok(new_val)
+            }
+            Node::AssignIndex {
+                target,
+                index,
+                value,
+            } => {
+                let value = self.eval_slot(value, frame)?;
+                let index = self.eval_slot(index, frame)?;
+                self.assign_slot_element(frame, target, index, value)
+            }
+            Node::CompoundAssignIndex {
+                target,
+                index,
+                op,
+                value,
+            } => {
+                let index = self.eval_slot(index, frame)?;
+                let array = match target {
+                    Target::Local(local) => frame[local.slot].clone(),
+                    Target::Name(name) => self.lookup_variable(name)?,
+                };
+                let current = Self::index_value(&array, &index)?;
+                let Some(new_val) = self.eval_slot_compound(&current, *op, value, frame)? else {
+                    return Ok(current);
+                };
+                self.assign_slot_element(frame, target, index, new_val)
+            }
+            _ => unreachable!("eval_slot_assign called with non-assignment node"),
+        }
+    }
+
+    /// Loops, `break`, `continue` and `return`
+    fn eval_slot_control(
+        &mut self,
+        node: &Node,
+        frame: &mut [Value],
+    ) -> Result<Value, InterpreterError> {
+        match node {
+            Node::For {
+                label,
+                local,
+                iter,
+                body,
+            } => self.eval_slot_for(label.as_ref(), local, iter, body, frame),
+            Node::While {
+                label,
+                condition,
+                body,
+            } => {
+                let mut last_value = Value::Nil;
+                loop {
+                    let cond_value = self.eval_slot(condition, frame)?;
+                    if !matches!(cond_value, Value::Bool(true)) && cond_value != Value::Integer(1) {
+                        break;
+                    }
+                    match self.eval_slot_pass(body, frame, label.as_ref())? {
+                        Pass::Value(value) => last_value = value,
+                        Pass::Continue => {}
+                        Pass::Break(value) => return Ok(value),
+                    }
+                }
+                Ok(last_value)
+            }
+            Node::Loop { label, body } => loop {
+                if let Pass::Break(value) = self.eval_slot_pass(body, frame, label.as_ref())? {
+                    return Ok(value);
+                }
+            },
+            Node::Break { label, value } => {
+                let value = match value {
+                    Some(value) => self.eval_slot(value, frame)?,
+                    None => Value::Nil,
+                };
+                Err(InterpreterError::Break(label.clone(), value))
+            }
+            Node::Continue { label } => Err(InterpreterError::Continue(label.clone())),
+            Node::Return(value) => {
+                let value = match value {
+                    Some(value) => self.eval_slot(value, frame)?,
+                    None => Value::Nil,
+                };
+                Err(InterpreterError::Return(value))
+            }
+            _ => unreachable!("eval_slot_control called with non-control-flow node"),
+        }
+    }
+
+    /// Run `call` with `visible` bound by name in the body's scope
+    ///
+    /// On the name-based path a callee reads and assigns the locals of its
+    /// callers, so they are copied into the scope `eval_resolved_body` pushed
+    /// and copied back once it returns.
+    fn with_visible_locals(
+        &mut self,
+        visible: &[Local],
+        frame: &mut [Value],
+        call: impl FnOnce(&mut Self) -> Result<Value, InterpreterError>,
+    ) -> Result<Value, InterpreterError> {
+        if visible.is_empty() {
+            return call(self);
+        }
+        let scope = Rc::clone(self.current_env());
+        let shadowed: Vec<Option<Value>> = visible
+            .iter()
+            .map(|local| {
+                scope
+                    .borrow_mut()
+                    .insert(local.name.clone(), frame[local.slot].clone())
+            })
+            .collect();
+        let result = call(self);
+        let mut scope = scope.borrow_mut();
+        for (local, shadowed) in visible.iter().zip(shadowed) {
+            let value = match shadowed {
+                Some(value) => scope.insert(local.name.clone(), value),
+                None => scope.remove(&local.name),
+            };
+            if let Some(value) = value {
+                frame[local.slot] = value;
+            }
+        }
+        result
+    }
+
+    fn eval_slot_args(
+        &mut self,
+        args: &[Node],
+        frame: &mut [Value],
+    ) -> Result<Vec<Value>, InterpreterError> {
+        args.iter().map(|arg| self.eval_slot(arg, frame)).collect()
+    }
+
+    /// Binary operators, short-circuiting like `eval_binary_expr`
+    fn eval_slot_binary(
+        &mut self,
+        op: BinaryOp,
+        left: &Node,
+        right: &Node,
+        site: usize,
+        frame: &mut [Value],
+    ) -> Result<Value, InterpreterError> {
+        let left_val = self.eval_slot(left, frame)?;
+        match op {
+            BinaryOp::NullCoalesce if !matches!(left_val, Value::Nil) => Ok(left_val),
+            BinaryOp::And if !left_val.is_truthy() => Ok(left_val),
+            BinaryOp::Or if left_val.is_truthy() => Ok(left_val),
+            BinaryOp::NullCoalesce | BinaryOp::And | BinaryOp::Or => self.eval_slot(right, frame),
+            BinaryOp::In => {
+                let collection = self.eval_slot(right, frame)?;
+                Ok(Value::Bool(self.eval_contains(&left_val, &collection)?))
+            }
+            _ => {
+                let right_val = self.eval_slot(right, frame)?;
+                self.eval_binary_values(op, site, &left_val, &right_val)
+            }
+        }
+    }
+
+    /// New value of a compound assignment, or `None` when a short-circuiting
+    /// operator keeps `current` (see `eval_compound_value`)
+    fn eval_slot_compound(
+        &mut self,
+        current: &Value,
+        op: BinaryOp,
+        value: &Node,
+        frame: &mut [Value],
+    ) -> Result<Option<Value>, InterpreterError> {
+        let keeps_current = match op {
+            BinaryOp::And => !current.is_truthy(),
+            BinaryOp::Or => current.is_truthy(),
+            BinaryOp::NullCoalesce => !matches!(current, Value::Nil),
+            _ => {
+                let rhs = self.eval_slot(value, frame)?;
+                return self.apply_binary_op(current, op, &rhs).map(Some);
+            }
+        };
+        if keeps_current {
+            Ok(None)
+        } else {
+            self.eval_slot(value, frame).map(Some)
+        }
+    }
+
+    fn bind_slot(&mut self, frame: &mut [Value], local: &Local, value: Value) {
+        self.record_variable_assignment_feedback(&local.name, &value);
+        frame[local.slot] = value;
+    }
+
+    fn assign_target(&mut self, frame: &mut [Value], target: &Target, value: Value) {
+        match target {
+            Target::Local(local) => self.bind_slot(frame, local, value),
+            Target::Name(name) => self.set_variable(name, value),
+        }
+    }
+
+    /// `target[index] = value`, as `assign_array_element` does
+    fn assign_slot_element(
+        &mut self,
+        frame: &mut [Value],
+        target: &Target,
+        index: Value,
+        value: Value,
+    ) -> Result<Value, InterpreterError> {
+        match target {
+            Target::Local(local) => {
+                let idx = Self::array_assign_index(index)?;
+                let updated = Self::replace_array_element(&frame[local.slot], idx, value.clone())?;
+                self.bind_slot(frame, local, updated);
+                Ok(value)
+            }
+            Target::Name(name) => self.assign_array_element(name, index, value),
+        }
+    }
+
+    fn eval_slot_for(
+        &mut self,
+        label: Option<&String>,
+        local: &Local,
+        iter: &Node,
+        body: &Node,
+        frame: &mut [Value],
+    ) -> Result<Value, InterpreterError> {
+        let mut last_value = Value::Nil;
+        let pass = |this: &mut Self, frame: &mut [Value], item: Value| {
+            this.bind_slot(frame, local, item);
+            this.eval_slot_pass(body, frame, label)
+        };
+        match self.eval_slot(iter, frame)? {
+            Value::Array(items) => {
+                for item in items.iter() {
+                    match pass(self, frame, item.clone())? {
+                        Pass::Value(value) => last_value = value,
+                        Pass::Continue => {}
+                        Pass::Break(value) => return Ok(value),
+                    }
+                }
+            }
+            Value::Range {
+                start,
+                end,
+                inclusive,
+            } => {
+                let (start, end) = self.extract_range_bounds(&start, &end)?;
+                for i in self.create_range_iterator(start, end, inclusive) {
+                    match pass(self, frame, Value::Integer(i))? {
+                        Pass::Value(value) => last_value = value,
+                        Pass::Continue => {}
+                        Pass::Break(value) => return Ok(value),
+                    }
+                }
+            }
+            Value::Iterator(lazy) => loop {
+                // Release the borrow before the body runs so it may advance the iterator too
+                let item = lazy
+                    .borrow_mut()
+                    .next(&mut |func, args| self.eval_function_call_value(func, args))?;
+                let Some(item) = item else {
+                    break;
+                };
+                match pass(self, frame, item)? {
+                    Pass::Value(value) => last_value = value,
+                    Pass::Continue => {}
+                    Pass::Break(value) => return Ok(value),
+                }
+            },
+            _ => {
+                return Err(InterpreterError::TypeError(
+                    "For loop requires an iterable".to_string(),
+                ))
+            }
+        }
+        Ok(last_value)
+    }
+
+    /// One pass of a loop body, as `eval_loop_body_with_control_flow` runs it
+    fn eval_slot_pass(
+        &mut self,
+        body: &Node,
+        frame: &mut [Value],
+        loop_label: Option<&String>,
+    ) -> Result<Pass, InterpreterError> {
+        crate::runtime::interrupt::check()?;
+        match self.eval_slot(body, frame) {
+            Ok(value) => Ok(Pass::Value(value)),
+            Err(InterpreterError::Break(label, value))
+                if targets_loop(label.as_ref(), loop_label) =>
+            {
+                Ok(Pass::Break(value))
+            }
+            Err(InterpreterError::Continue(label)) if targets_loop(label.as_ref(), loop_label) => {
+                Ok(Pass::Continue)
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::Parser;
+
+    fn run(interp: &mut Interpreter, source: &str) -> Value {
+        let ast = Parser::new(source).parse().expect("parse");
+        interp.eval_expr(&ast).expect("eval")
+    }
+
+    #[test]
+    fn test_loop_function_runs_with_slots() {
+        let mut interp = Interpreter::new();
+        let source = "fun sum_squares(n) {
+    let mut total = 0
+    for i in 0..n {
+        if i % 2 == 0 { continue }
+        let sq = i * i
+        total += sq
+    }
+    total
+}
+sum_squares(10) + sum_squares(4)";
+        assert_eq!(run(&mut interp, source), Value::Integer(165 + 10));
+        let stats = interp.slot_cache_stats();
+        assert_eq!((stats.resolved, stats.unresolved), (1, 0));
+    }
+
+    #[test]
+    fn test_block_shadowing_and_loop_variable_scope() {
+        let mut interp = Interpreter::new();
+        let source = "fun f() {
+    let x = 1
+    let mut seen = 0
+    if true {
+        let x = 10
+        seen = x
+    }
+    for i in 0..3 {
+        seen += 0
+    }
+    [x, seen, i]
+}
+f()";
+        let expected = [1, 10, 2].map(Value::Integer);
+        assert_eq!(
+            run(&mut interp, source),
+            Value::from_array(expected.to_vec())
+        );
+        assert_eq!(interp.slot_cache_stats().resolved, 1);
+    }
+
+    #[test]
+    fn test_globals_and_index_assignment() {
+        let mut interp = Interpreter::new();
+        let source = "let mut calls = 0
+fun sieve(n, flags) {
+    calls = calls + 1
+    let mut count = 0
+    let mut i = 2
+    while i < n {
+        if flags[i] {
+            count += 1
+            let mut j = i * i
+            while j < n {
+                flags[j] = false
+                j += i
+            }
+        }
+        i += 1
+    }
+    count
+}
+let flags = [true; 20]
+sieve(20, flags) + sieve(20, flags) * 100 + calls * 10000";
+        assert_eq!(run(&mut interp, source), Value::Integer(20_808));
+        assert_eq!(interp.slot_cache_stats().resolved, 1);
+    }
+
+    #[test]
+    fn test_labeled_break_and_tail_recursion() {
+        let mut interp = Interpreter::new();
+        let source = "fun find(limit) {
+    let mut hit = 0
+    'outer: for a in 1..limit {
+        for b in 1..limit {
+            if a * b == 12 && a < b {
+                hit = a * 100 + b
+                break 'outer
+            }
+        }
+    }
+    hit
+}
+fun count(n, acc) { if n == 0 { acc } else { count(n - 1, acc + 1) } }
+find(10) + count(100000, 0)";
+        assert_eq!(run(&mut interp, source), Value::Integer(206 + 100_000));
+        assert_eq!(interp.slot_cache_stats().resolved, 2);
+    }
+
+    #[test]
+    fn test_unsupported_bodies_keep_name_based_path() {
+        let mut interp = Interpreter::new();
+        let source = "fun make_adder(n) { |x| x + n }
+let add2 = make_adder(2)
+add2(5)";
+        assert_eq!(run(&mut interp, source), Value::Integer(7));
+        // The lambda's own body is resolved; `n` is read by name
+        let stats = interp.slot_cache_stats();
+        assert_eq!((stats.resolved, stats.unresolved), (1, 1));
+    }
+
+    #[test]
+    fn test_slot_and_name_paths_scope_alike() {
+        // A method call keeps `f` on the name-based path
+        for marker in ["", "\"\".len()\n"] {
+            let mut interp = Interpreter::new();
+            let source = format!(
+                "fun peek() {{ x }}
+fun bump() {{ x += 10 }}
+fun f() {{
+    {marker}let mut x = 1
+    bump()
+    for i in 0..3 {{ x += i }}
+    [peek(), x]
+}}
+f()"
+            );
+            let expected = Value::from_array(vec![Value::Integer(14), Value::Integer(14)]);
+            assert_eq!(run(&mut interp, &source), expected, "{source}");
+            if marker.is_empty() {
+                assert_eq!(interp.slot_cache_stats().unresolved, 0);
+            }
+
+            let mut interp = Interpreter::new();
+            let source = format!(
+                "let mut i = 100
+fun f() {{
+    {marker}for i in 0..3 {{ }}
+    i
+}}
+[f(), i]"
+            );
+            let expected = Value::from_array(vec![Value::Integer(2), Value::Integer(2)]);
+            assert_eq!(run(&mut interp, &source), expected, "{source}");
+        }
+    }
+
+    #[test]
+    fn test_redefined_function_is_resolved_again() {
+        let mut interp = Interpreter::new();
+        run(&mut interp, "fun f(a) { a + 1 }");
+        assert_eq!(run(&mut interp, "f(1)"), Value::Integer(2));
+        run(&mut interp, "fun f(a) { a * 10 }");
+        assert_eq!(run(&mut interp, "f(1)"), Value::Integer(10));
+    }
+}
@@ -0,0 +1,226 @@
+//! Self-tail-call elimination
+//!
+//! A function whose result is a call to itself (`fun sum(n, acc) { if n == 0
+//! { acc } else { sum(n - 1, acc + n) } }`) would otherwise nest one Rust
+//! stack frame chain per Ruchy call and overflow long before the recursion
+//! limit on large inputs. The function body is evaluated in tail position:
+//! `if`/`else` branches, the last statement of blocks, `let` bodies and
+//! `return` values are followed, and a direct call back to the running
+//! closure hands its arguments to `call_function`, which rebinds the
+//! parameters and loops instead of recursing.
+
+use crate::frontend::ast::{Expr, ExprKind, Literal};
+use crate::runtime::interpreter::Interpreter;
+use crate::runtime::{InterpreterError, Value};
+use std::sync::Arc;
+
+/// Result of evaluating an expression in tail position
+pub(crate) enum TailOutcome {
+    /// The expression produced a value
+    Value(Value),
+    /// The expression is a call to the running function with these arguments
+    SelfCall(Vec<Value>),
+}
+
+impl Interpreter {
+    /// Evaluate a function body (`body`) in tail position
+    ///
+    /// Block bodies run in the function's parameter scope, matching
+    /// `call_function` (BOOK-200-01).
+    pub(crate) fn eval_function_body_tail(
+        &mut self,
+        body: &Arc<Expr>,
+    ) -> Result<TailOutcome, InterpreterError> {
+        match &body.kind {
+            ExprKind::Block(statements) => self.eval_tail_statements(statements, body),
+            _ => self.eval_tail(body, body),
+        }
+    }
+
+    fn eval_tail_statements(
+        &mut self,
+        statements: &[Expr],
+        function_body: &Arc<Expr>,
+    ) -> Result<TailOutcome, InterpreterError> {
+        let Some((last, init)) = statements.split_last() else {
+            return Ok(TailOutcome::Value(Value::Nil));
+        };
+        for stmt in init {
+            self.eval_expr(stmt)?;
+        }
+        self.eval_tail(last, function_body)
+    }
+
+    fn eval_tail(
+        &mut self,
+        expr: &Expr,
+        function_body: &Arc<Expr>,
+    ) -> Result<TailOutcome, InterpreterError> {
+        match &expr.kind {
+            ExprKind::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                if self.eval_expr(condition)?.is_truthy() {
+                    self.eval_tail(then_branch, function_body)
+                } else if let Some(else_expr) = else_branch {
+                    self.eval_tail(else_expr, function_body)
+                } else {
+                    Ok(TailOutcome::Value(Value::Nil))
+                }
+            }
+            ExprKind::Block(statements) => {
+                // QA-026: blocks get their own scope
+                self.push_scope();
+                let result = self
+                    .with_defer_scope(|this| this.eval_tail_statements(statements, function_body));
+                self.pop_scope();
+                result
+            }
+            ExprKind::Let {
+                name, value, body, ..
+            } if !matches!(body.kind, ExprKind::Literal(Literal::Unit)) => {
+                let val = self.eval_expr(value)?;
+                self.env_set(name.clone(), val);
+                self.eval_tail(body, function_body)
+            }
+            ExprKind::Return { value: Some(value) } => self.eval_tail(value, function_body),
+            ExprKind::Call { func, args } if self.is_self_call(func, args, function_body) => {
+                let arg_vals = args
+                    .iter()
+                    .map(|arg| self.eval_expr(arg))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(TailOutcome::SelfCall(arg_vals))
+            }
+            _ => self.eval_expr(expr).map(TailOutcome::Value),
+        }
+    }
+
+    /// Whether `func(args)` calls the closure whose body is `function_body`
+    /// the same way `eval_function_call` would
+    pub(crate) fn is_self_call(
+        &self,
+        func: &Expr,
+        args: &[Expr],
+        function_body: &Arc<Expr>,
+    ) -> bool {
+        let ExprKind::Identifier(name) = &func.kind else {
+            return false;
+        };
+        // Named and spread arguments are bound by the general call path
+        let has_named_args = args
+            .iter()
+            .any(|arg| matches!(arg.kind, ExprKind::Assign { .. } | ExprKind::Spread { .. }));
+        // Builtins win over user functions with the same name
+        let is_builtin = matches!(
+            self.env_stack[0].borrow().get(name),
+            Some(Value::String(marker)) if marker.starts_with("__builtin_")
+        );
+        // RUCHY_TRACE prints every call, so keep the frames visible
+        if has_named_args || is_builtin || std::env::var_os("RUCHY_TRACE").is_some() {
+            return false;
+        }
+        // A deferred expression runs after the call it follows returns
+        if self.slot_cache.defers(function_body) {
+            return false;
+        }
+        // Without a way out the loop would never end, while recursing
+        // reports the runaway recursion at the recursion limit
+        if !self.slot_cache.returns(function_body) && !tail_can_exit(function_body, name) {
+            return false;
+        }
+        matches!(
+            self.lookup_variable(name),
+            Ok(Value::Closure { ref body, .. }) if Arc::ptr_eq(body, function_body)
+        )
+    }
+}
+
+/// Whether a tail position of `expr` finishes without calling `name`
+fn tail_can_exit(expr: &Expr, name: &str) -> bool {
+    match &expr.kind {
+        ExprKind::If {
+            then_branch,
+            else_branch,
+            ..
+        } => {
+            tail_can_exit(then_branch, name)
+                || else_branch
+                    .as_ref()
+                    .is_none_or(|else_expr| tail_can_exit(else_expr, name))
+        }
+        ExprKind::Block(statements) => statements
+            .last()
+            .is_none_or(|last| tail_can_exit(last, name)),
+        ExprKind::Let { body, .. } if !matches!(body.kind, ExprKind::Literal(Literal::Unit)) => {
+            tail_can_exit(body, name)
+        }
+        ExprKind::Match { arms, .. } => arms.iter().any(|arm| tail_can_exit(&arm.body, name)),
+        ExprKind::Call { func, .. } => {
+            !matches!(&func.kind, ExprKind::Identifier(callee) if callee == name)
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::Parser;
+
+    fn eval(source: &str) -> Result<Value, InterpreterError> {
+        let ast = Parser::new(source).parse().expect("parse");
+        Interpreter::new().eval_expr(&ast)
+    }
+
+    #[test]
+    fn test_deep_self_tail_call_runs_as_loop() {
+        let source = "fun count(n, acc) { if n == 0 { acc } else { count(n - 1, acc + 1) } }
+count(100000, 0)";
+        assert_eq!(eval(source).unwrap(), Value::Integer(100_000));
+    }
+
+    #[test]
+    fn test_tail_call_through_let_and_return() {
+        let source = "fun fact(n, acc) {
+    if n <= 1 { return acc }
+    let next = acc * n
+    return fact(n - 1, next)
+}
+fact(20, 1)";
+        assert_eq!(
+            eval(source).unwrap(),
+            Value::Integer(2_432_902_008_176_640_000)
+        );
+    }
+
+    #[test]
+    fn test_self_call_without_base_case_reaches_recursion_limit() {
+        use crate::runtime::eval_function::{get_max_recursion_depth, set_max_recursion_depth};
+        let previous = get_max_recursion_depth();
+        set_max_recursion_depth(50);
+        let result = eval("fun forever(n) { forever(n + 1) }\nforever(0)");
+        set_max_recursion_depth(previous);
+        let err = result.unwrap_err();
+        assert!(
+            matches!(err, InterpreterError::RecursionLimitExceeded(..)),
+            "{err:?}"
+        );
+    }
+
+    #[test]
+    fn test_non_tail_recursion_is_unchanged() {
+        let source = "fun fib(n) { if n < 2 { n } else { fib(n - 1) + fib(n - 2) } }
+fib(15)";
+        assert_eq!(eval(source).unwrap(), Value::Integer(610));
+    }
+
+    #[test]
+    fn test_tail_call_arity_errors_still_reported() {
+        let source = "fun f(n) { if n == 0 { 0 } else { f(n - 1, 2) } }
+f(3)";
+        let err = eval(source).unwrap_err();
+        assert!(format!("{err:?}").contains("arguments"), "{err:?}");
+    }
+}
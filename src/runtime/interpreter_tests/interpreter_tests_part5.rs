@@ -1518,6 +1518,7 @@ fn test_list_comprehension_ast_simple() {
             element: Box::new(make_ident("x")),
             clauses: vec![ComprehensionClause {
                 variable: "x".to_string(),
+                pattern: crate::frontend::ast::Pattern::Identifier("x".to_string()),
                 iterable: Box::new(Expr {
                     kind: ExprKind::List(vec![make_int(1), make_int(2), make_int(3)]),
                     span: Span::default(),
@@ -1555,6 +1556,7 @@ fn test_list_comprehension_ast_with_condition() {
             element: Box::new(make_ident("x")),
             clauses: vec![ComprehensionClause {
                 variable: "x".to_string(),
+                pattern: crate::frontend::ast::Pattern::Identifier("x".to_string()),
                 iterable: Box::new(Expr {
                     kind: ExprKind::List(vec![make_int(1), make_int(2), make_int(3), make_int(4)]),
                     span: Span::default(),
@@ -1596,6 +1598,7 @@ fn test_list_comprehension_with_range() {
             element: Box::new(make_ident("x")),
             clauses: vec![ComprehensionClause {
                 variable: "x".to_string(),
+                pattern: crate::frontend::ast::Pattern::Identifier("x".to_string()),
                 iterable: Box::new(make_range(make_int(0), make_int(3), false)),
                 condition: None,
             }],
@@ -1122,6 +1122,7 @@ fn test_list_comprehension_non_iterable() {
             element: Box::new(make_ident("x")),
             clauses: vec![ComprehensionClause {
                 variable: "x".to_string(),
+                pattern: crate::frontend::ast::Pattern::Identifier("x".to_string()),
                 iterable: Box::new(make_int(42)),
                 condition: None,
             }],
@@ -356,6 +356,8 @@ fn make_lambda_with_params(params: Vec<String>, body: Expr) -> Expr {
                     span: Span::default(),
                     is_mutable: false,
                     default_value: None,
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 })
                 .collect(),
             body: Box::new(body),
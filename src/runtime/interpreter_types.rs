@@ -9,6 +9,7 @@
 
 #![allow(unsafe_code)] // Required for CallFrame Send implementation - see DEFECT-001-B
 
+use super::method_not_found::MethodNotFound;
 use super::value::Value;
 
 /// Call frame for function invocation (will be used in Phase 1)
@@ -84,6 +85,30 @@ pub enum InterpreterError {
     /// Recursion depth limit exceeded (`current_depth`, `max_depth`)
     /// Added via [RUNTIME-001] fix for stack overflow crashes
     RecursionLimitExceeded(usize, usize),
+    /// Method called on a builtin value whose type does not have it
+    MethodNotFound(Box<MethodNotFound>),
+}
+
+impl InterpreterError {
+    /// Whether this is a `break`/`continue`/`return` unwinding rather than a
+    /// failure; control flow passes through try/catch untouched
+    pub fn is_control_flow(&self) -> bool {
+        matches!(
+            self,
+            InterpreterError::Break(..)
+                | InterpreterError::Continue(_)
+                | InterpreterError::Return(_)
+        )
+    }
+
+    /// Finish evaluating a function, method or closure body: a `return`
+    /// that unwound to the body becomes the call's value
+    pub fn catch_return(result: Result<Value, Self>) -> Result<Value, Self> {
+        match result {
+            Err(InterpreterError::Return(value)) => Ok(value),
+            other => other,
+        }
+    }
 }
 
 // Display implementation is in eval_display.rs
@@ -105,6 +130,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_interpreter_error_is_control_flow() {
+        assert!(InterpreterError::Return(Value::Nil).is_control_flow());
+        assert!(InterpreterError::Break(None, Value::Nil).is_control_flow());
+        assert!(InterpreterError::Continue(None).is_control_flow());
+        assert!(!InterpreterError::Throw(Value::Nil).is_control_flow());
+        assert!(!InterpreterError::DivisionByZero.is_control_flow());
+    }
+
+    #[test]
+    fn test_catch_return() {
+        let caught =
+            InterpreterError::catch_return(Err(InterpreterError::Return(Value::Integer(3))));
+        assert!(matches!(caught, Ok(Value::Integer(3))));
+        assert!(matches!(
+            InterpreterError::catch_return(Ok(Value::Nil)),
+            Ok(Value::Nil)
+        ));
+        assert!(matches!(
+            InterpreterError::catch_return(Err(InterpreterError::Break(None, Value::Nil))),
+            Err(InterpreterError::Break(..))
+        ));
+    }
+
     #[test]
     fn test_interpreter_error_runtime_error() {
         let err = InterpreterError::RuntimeError("undefined variable".to_string());
@@ -280,6 +280,8 @@ mod tests {
             is_mut: false,
             visibility: Visibility::Public,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -295,6 +297,8 @@ mod tests {
             is_mut: false,
             visibility: Visibility::Public,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -306,6 +310,8 @@ mod tests {
             is_mut: true,
             visibility: Visibility::Public,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -327,6 +333,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -337,6 +345,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: Some(Box::new(default)),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -5,7 +5,7 @@
 #![allow(clippy::unused_self)]
 #![allow(clippy::expect_used)]
 
-use crate::frontend::ast::{Expr, ExprKind};
+use crate::frontend::ast::{ClassProperty, Expr, ExprKind};
 use crate::runtime::interpreter::Interpreter;
 use crate::runtime::{InterpreterError, Value};
 use std::cell::RefCell;
@@ -22,6 +22,26 @@ fn copy_non_meta_fields(src: &HashMap<String, Value>, dst: &mut HashMap<String,
     }
 }
 
+/// The class of an instance map that has no field `field`
+fn instance_class_name(instance: &HashMap<String, Value>, field: &str) -> Option<String> {
+    if instance.contains_key(field) {
+        return None;
+    }
+    match instance.get("__class") {
+        Some(Value::String(class_name)) => Some(class_name.to_string()),
+        _ => None,
+    }
+}
+
+/// A property accessor as a closure over `body`
+fn accessor_closure(params: Vec<(String, Option<Arc<Expr>>)>, body: &Expr) -> Value {
+    Value::Closure {
+        params,
+        body: Arc::new(body.clone()),
+        env: Rc::new(RefCell::new(HashMap::new())),
+    }
+}
+
 impl Interpreter {
     /// Evaluate class definition
     ///
@@ -236,6 +256,152 @@ impl Interpreter {
         Ok(class_value)
     }
 
+    /// Attach a class's `property` accessors to its definition as `__properties`
+    ///
+    /// Each property maps to its `get` and `set` closures, whichever are
+    /// declared; the setter takes the assigned value as its only parameter.
+    ///
+    /// Complexity: 4
+    pub(crate) fn eval_class_properties(
+        &mut self,
+        name: &str,
+        class_value: Value,
+        properties: &[ClassProperty],
+    ) -> Result<Value, InterpreterError> {
+        if properties.is_empty() {
+            return Ok(class_value);
+        }
+        let Value::Object(ref class_info) = class_value else {
+            return Ok(class_value);
+        };
+
+        let mut property_info = HashMap::new();
+        for property in properties {
+            let mut accessors = HashMap::new();
+            if let Some(ref getter) = property.getter {
+                accessors.insert("get".to_string(), accessor_closure(Vec::new(), getter));
+            }
+            if let Some(ref setter) = property.setter {
+                let params = vec![(setter.param_name.clone(), None)];
+                accessors.insert("set".to_string(), accessor_closure(params, &setter.body));
+            }
+            property_info.insert(property.name.clone(), Value::Object(Arc::new(accessors)));
+        }
+
+        let mut class_info = (**class_info).clone();
+        class_info.insert(
+            "__properties".to_string(),
+            Value::Object(Arc::new(property_info)),
+        );
+        let class_value = Value::Object(Arc::new(class_info));
+        self.set_variable(name, class_value.clone());
+        Ok(class_value)
+    }
+
+    /// The accessors of property `name` when `instance` is a class instance
+    ///
+    /// Fields shadow properties: an instance with a field called `name` has no
+    /// such property. Complexity: 6
+    pub(crate) fn class_property(
+        &self,
+        instance: &Value,
+        name: &str,
+    ) -> Option<Arc<HashMap<String, Value>>> {
+        let class_name = match instance {
+            Value::Class {
+                class_name, fields, ..
+            } => {
+                let fields = fields
+                    .read()
+                    .expect("RwLock poisoned: class fields lock is corrupted");
+                if fields.contains_key(name) {
+                    return None;
+                }
+                class_name.clone()
+            }
+            Value::Object(map) => instance_class_name(map, name)?,
+            Value::ObjectMut(cell) => instance_class_name(
+                &cell
+                    .lock()
+                    .expect("Mutex poisoned: object lock is corrupted"),
+                name,
+            )?,
+            _ => return None,
+        };
+
+        let Ok(Value::Object(class_info)) = self.lookup_variable(&class_name) else {
+            return None;
+        };
+        let Some(Value::Object(properties)) = class_info.get("__properties") else {
+            return None;
+        };
+        match properties.get(name) {
+            Some(Value::Object(accessors)) => Some(Arc::clone(accessors)),
+            _ => None,
+        }
+    }
+
+    /// Run a property accessor with `self` bound to `instance` (complexity: 3)
+    pub(crate) fn call_property_accessor(
+        &mut self,
+        instance: Value,
+        accessor: &Value,
+        args: &[Value],
+    ) -> Result<Value, InterpreterError> {
+        let Value::Closure { params, body, .. } = accessor else {
+            return Err(InterpreterError::RuntimeError(
+                "Invalid property accessor".to_string(),
+            ));
+        };
+
+        let mut accessor_env = HashMap::new();
+        accessor_env.insert("self".to_string(), instance);
+        for ((param_name, _default_value), arg) in params.iter().zip(args) {
+            accessor_env.insert(param_name.clone(), arg.clone());
+        }
+
+        self.env_push(accessor_env);
+        let result = InterpreterError::catch_return(self.eval_expr(body));
+        self.env_pop();
+        result
+    }
+
+    /// Assign to property `name` of the instance in `var_name` through its setter
+    ///
+    /// An immutable `Object` instance runs the setter on a mutable copy that is
+    /// then stored back. Complexity: 5
+    pub(crate) fn assign_class_property(
+        &mut self,
+        var_name: &str,
+        instance: &Value,
+        name: &str,
+        property: &HashMap<String, Value>,
+        val: Value,
+    ) -> Result<Value, InterpreterError> {
+        let Some(setter) = property.get("set") else {
+            return Err(InterpreterError::RuntimeError(format!(
+                "Property '{name}' has no setter"
+            )));
+        };
+
+        let receiver = match instance {
+            Value::Object(map) => {
+                crate::runtime::object_helpers::new_mutable_object((**map).clone())
+            }
+            other => other.clone(),
+        };
+        self.call_property_accessor(receiver.clone(), setter, &[val.clone()])?;
+
+        if let (Value::Object(_), Value::ObjectMut(cell)) = (instance, &receiver) {
+            let updated = cell
+                .lock()
+                .expect("Mutex poisoned: object lock is corrupted")
+                .clone();
+            self.set_variable(var_name, Value::Object(Arc::new(updated)));
+        }
+        Ok(val)
+    }
+
     /// Instantiates a class by calling its constructor.
     pub(crate) fn instantiate_class_with_constructor(
         &mut self,
@@ -327,11 +493,10 @@ impl Interpreter {
                         // Create environment for constructor
                         let mut ctor_env = HashMap::new();
 
-                        // Bind 'self' to mutable instance for constructor
-                        ctor_env.insert(
-                            "self".to_string(),
-                            Value::Object(Arc::new(instance.clone())),
-                        );
+                        // Bind 'self' to a shared instance so `self.x = value` persists
+                        let self_value =
+                            crate::runtime::object_helpers::new_mutable_object(instance.clone());
+                        ctor_env.insert("self".to_string(), self_value.clone());
 
                         // RUNTIME-DEFAULT-PARAMS: Bind constructor parameters
                         for ((param_name, _default_value), arg) in params.iter().zip(args) {
@@ -343,10 +508,11 @@ impl Interpreter {
 
                         // Execute constructor body
                         // RUNTIME-098: Constructor may return explicit value (e.g., Counter { count: 0 })
-                        let result = self.eval_expr(body)?;
+                        let result = InterpreterError::catch_return(self.eval_expr(body));
 
                         // Pop environment before checking result
                         self.env_stack.pop();
+                        let result = result?;
 
                         // RUNTIME-098: Check if constructor returned an explicit struct instance
                         if let Some(obj) = Self::try_extract_class_instance(&result, class_name) {
@@ -354,10 +520,12 @@ impl Interpreter {
                         }
 
                         // RUNTIME-098: For field-assignment constructors (self.x = value),
-                        // extract updated self from environment after constructor execution
-                        let updated_self = self.lookup_variable("self")?;
-                        if let Value::Object(ref updated_instance) = updated_self {
-                            copy_non_meta_fields(updated_instance, &mut instance);
+                        // read the fields back from the instance bound to `self`
+                        if let Value::ObjectMut(ref cell) = self_value {
+                            let updated_instance = cell
+                                .lock()
+                                .expect("Mutex poisoned: object lock is corrupted");
+                            copy_non_meta_fields(&updated_instance, &mut instance);
                         }
                     }
                 }
@@ -468,10 +636,11 @@ impl Interpreter {
                         self.env_stack.push(Rc::new(RefCell::new(ctor_env))); // ISSUE-119: Wrap in Rc<RefCell>
 
                         // Execute constructor body
-                        let _result = self.eval_expr(body)?;
+                        let result = InterpreterError::catch_return(self.eval_expr(body));
 
                         // Pop environment
                         self.env_stack.pop();
+                        result?;
                     }
                 }
             }
@@ -540,13 +709,13 @@ impl Interpreter {
                         // Push method environment
                         self.env_push(method_env);
 
-                        // Execute method body
-                        let result = self.eval_expr(body)?;
+                        // Execute method body; `return` exits the method
+                        let result = InterpreterError::catch_return(self.eval_expr(body));
 
                         // Pop environment
                         self.env_pop();
 
-                        return Ok(result);
+                        return result;
                     }
                 }
             }
@@ -619,8 +788,8 @@ impl Interpreter {
                         // Push the method environment
                         self.env_stack.push(Rc::new(RefCell::new(method_env))); // ISSUE-119: Wrap in Rc<RefCell>
 
-                        // Execute the method body
-                        let result = self.eval_expr(body);
+                        // Execute the method body; `return` exits the method
+                        let result = InterpreterError::catch_return(self.eval_expr(body));
 
                         // Pop the method environment
                         self.env_stack.pop();
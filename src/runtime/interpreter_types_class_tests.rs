@@ -27,6 +27,8 @@ fn make_struct_field(name: &str, ty: Type) -> StructField {
         is_mut: false,
         visibility: Visibility::Public,
         decorators: vec![],
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }
 }
 
@@ -38,6 +40,8 @@ fn make_struct_field_with_default(name: &str, ty: Type, default: Expr) -> Struct
         is_mut: true,
         visibility: Visibility::Public,
         decorators: vec![],
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }
 }
 
@@ -48,6 +52,8 @@ fn make_param(name: &str) -> Param {
         span: Span::default(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }
 }
 
@@ -27,6 +27,8 @@ fn make_struct_field(name: &str, ty: Type) -> StructField {
         is_mut: false,
         visibility: Visibility::Public,
         decorators: vec![],
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }
 }
 
@@ -38,6 +40,8 @@ fn make_struct_field_with_default(name: &str, ty: Type, default: Expr) -> Struct
         is_mut: true,
         visibility: Visibility::Public,
         decorators: vec![],
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }
 }
 
@@ -48,6 +52,8 @@ fn make_param(name: &str) -> Param {
         span: Span::default(),
         is_mutable: false,
         default_value: None,
+        leading_comments: Vec::new(),
+        trailing_comment: None,
     }
 }
 
@@ -112,6 +118,8 @@ fn test_class_definition_with_pattern_wildcard() {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }],
         return_type: None,
         body: Box::new(make_expr(ExprKind::Block(vec![]))),
@@ -155,6 +163,8 @@ fn test_method_with_pattern_wildcard() {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }],
         return_type: Some(make_type("Any")),
         body: Box::new(make_expr(ExprKind::Literal(Literal::Integer(0, None)))),
@@ -1133,3 +1143,70 @@ fn test_instantiate_class_with_superclass_fields_coverage() {
     // Constructor references self which may not be bound — exercises the dispatch path
     let _ = result;
 }
+
+const TEMPERATURE: &str = r"
+class Temperature {
+    celsius: f64
+    readings: i32 = 0
+
+    new(celsius: f64) {
+        self.celsius = celsius
+    }
+
+    property fahrenheit: f64 {
+        get => self.celsius * 9.0 / 5.0 + 32.0,
+        set(f) => self.celsius = (f - 32.0) * 5.0 / 9.0
+    }
+
+    property kelvin: f64 {
+        get => self.celsius + 273.15
+    }
+
+    static fun freezing() -> Temperature {
+        Temperature::new(0.0)
+    }
+}
+";
+
+fn eval_with_temperature(code: &str) -> Result<Value, InterpreterError> {
+    let source = format!("{TEMPERATURE}\n{code}");
+    let ast = crate::frontend::parser::Parser::new(&source)
+        .parse()
+        .expect("should parse");
+    make_interpreter().eval_expr(&ast)
+}
+
+#[test]
+fn test_class_new_constructor_assigns_fields_over_defaults() {
+    let result = eval_with_temperature("Temperature::new(21.5).celsius");
+    assert_eq!(result.unwrap(), Value::Float(21.5));
+    let result = eval_with_temperature("Temperature::new(21.5).readings");
+    assert_eq!(result.unwrap(), Value::Integer(0));
+    let result = eval_with_temperature("Temperature::freezing().celsius");
+    assert_eq!(result.unwrap(), Value::Float(0.0));
+}
+
+#[test]
+fn test_class_property_getters() {
+    let result = eval_with_temperature("Temperature::new(100.0).fahrenheit");
+    assert_eq!(result.unwrap(), Value::Float(212.0));
+    let result = eval_with_temperature("Temperature(100.0).kelvin");
+    assert_eq!(result.unwrap(), Value::Float(100.0 + 273.15));
+}
+
+#[test]
+fn test_class_property_setters() {
+    let code = "let mut t = Temperature::new(100.0)\nt.fahrenheit = 32.0\nt.celsius";
+    assert_eq!(eval_with_temperature(code).unwrap(), Value::Float(0.0));
+    let code = "let t = Temperature(0.0)\nt.fahrenheit = 212.0\nt.celsius";
+    assert_eq!(eval_with_temperature(code).unwrap(), Value::Float(100.0));
+}
+
+#[test]
+fn test_class_property_without_setter_is_read_only() {
+    let result = eval_with_temperature("let mut t = Temperature::new(0.0)\nt.kelvin = 1.0");
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("Property 'kelvin' has no setter"));
+}
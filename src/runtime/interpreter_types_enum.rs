@@ -44,6 +44,20 @@ impl Interpreter {
             };
             variant_info.insert("kind".to_string(), Value::from_string(kind_str.to_string()));
 
+            // Tuple variants remember how many values their constructor takes
+            if let crate::frontend::ast::EnumVariantKind::Tuple(types) = &variant.kind {
+                variant_info.insert("arity".to_string(), Value::Integer(types.len() as i64));
+            }
+
+            // Struct variants remember their field names for literal validation
+            if let crate::frontend::ast::EnumVariantKind::Struct(fields) = &variant.kind {
+                let names = fields
+                    .iter()
+                    .map(|f| Value::from_string(f.name.clone()))
+                    .collect();
+                variant_info.insert("fields".to_string(), Value::from_array(names));
+            }
+
             // Store discriminant if present
             if let Some(disc) = variant.discriminant {
                 variant_info.insert("discriminant".to_string(), Value::Integer(disc));
@@ -67,9 +81,181 @@ impl Interpreter {
         Ok(enum_obj)
     }
 
+    /// Evaluate a struct-like enum variant literal: `Shape::Circle { radius: 1.0 }`
+    ///
+    /// Returns `Ok(None)` when `name` does not name a variant of a known enum so
+    /// the caller can fall back to ordinary struct instantiation. The variant's
+    /// fields are carried as a single `Value::Struct` payload named after the
+    /// variant, which struct patterns destructure.
+    /// Complexity: 8
+    pub(crate) fn eval_struct_variant_literal(
+        &mut self,
+        name: &str,
+        fields: &[(String, Expr)],
+    ) -> Result<Option<Value>, InterpreterError> {
+        use std::collections::HashMap;
+        use std::sync::Arc;
+
+        let Some((enum_path, variant_name)) = name.rsplit_once("::") else {
+            return Ok(None);
+        };
+        let enum_name = enum_path.rsplit("::").next().unwrap_or(enum_path);
+        let Ok(Value::Object(enum_type)) = self.lookup_variable(enum_name) else {
+            return Ok(None);
+        };
+        if !matches!(enum_type.get("__type"), Some(Value::String(t)) if t.as_ref() == "Enum") {
+            return Ok(None);
+        }
+        let variant_info = match enum_type.get("__variants") {
+            Some(Value::Object(variants)) => match variants.get(variant_name) {
+                Some(Value::Object(info)) => Arc::clone(info),
+                _ => {
+                    return Err(InterpreterError::RuntimeError(format!(
+                        "Enum {enum_name} has no variant {variant_name}"
+                    )))
+                }
+            },
+            _ => return Ok(None),
+        };
+        let declared: Vec<String> = match variant_info.get("fields") {
+            Some(Value::Array(names)) => names
+                .iter()
+                .filter_map(|v| match v {
+                    Value::String(s) => Some(s.to_string()),
+                    _ => None,
+                })
+                .collect(),
+            _ => {
+                return Err(InterpreterError::RuntimeError(format!(
+                    "{enum_name}::{variant_name} is not a struct variant"
+                )))
+            }
+        };
+
+        let mut field_values = HashMap::new();
+        for (field_name, field_expr) in fields {
+            if !declared.contains(field_name) {
+                return Err(InterpreterError::RuntimeError(format!(
+                    "Variant {enum_name}::{variant_name} does not have field '{field_name}'"
+                )));
+            }
+            field_values.insert(field_name.clone(), self.eval_expr(field_expr)?);
+        }
+        if let Some(missing) = declared.iter().find(|f| !field_values.contains_key(*f)) {
+            return Err(InterpreterError::RuntimeError(format!(
+                "Missing field '{missing}' for variant {enum_name}::{variant_name}"
+            )));
+        }
+
+        Ok(Some(Value::EnumVariant {
+            enum_name: enum_name.to_string(),
+            variant_name: variant_name.to_string(),
+            data: Some(vec![Value::Struct {
+                name: variant_name.to_string(),
+                fields: Arc::new(field_values),
+            }]),
+        }))
+    }
+
+    /// Evaluate `Enum::Name` for an enum type object
+    ///
+    /// Variant names give the unit variant, which doubles as the constructor
+    /// of a tuple variant; other names resolve to the enum's associated
+    /// functions. Enum objects without variant metadata accept any name.
+    /// Complexity: 5
+    pub(crate) fn eval_enum_path(
+        &self,
+        enum_type: &std::collections::HashMap<String, Value>,
+        fallback_name: &str,
+        name: &str,
+    ) -> Result<Value, InterpreterError> {
+        let enum_name = match enum_type.get("__name") {
+            Some(Value::String(enum_name)) => enum_name.to_string(),
+            _ => fallback_name.to_string(),
+        };
+        let is_variant = match enum_type.get("__variants") {
+            Some(Value::Object(variants)) => variants.contains_key(name),
+            _ => true,
+        };
+        if is_variant {
+            return Ok(Value::EnumVariant {
+                enum_name,
+                variant_name: name.to_string(),
+                data: None,
+            });
+        }
+        self.get_variable(&format!("{enum_name}::{name}"))
+            .ok_or_else(|| {
+                InterpreterError::RuntimeError(format!("Enum {enum_name} has no variant {name}"))
+            })
+    }
+
+    /// Build a variant from a constructor call: `Shape::Circle(1.0)`
+    ///
+    /// Variants of enums the program defines take exactly their declared
+    /// values, and only tuple variants can be called. Variants of enums
+    /// without a definition, such as the built-in `Option`, take any values.
+    /// Complexity: 7
+    pub(crate) fn construct_enum_variant(
+        &self,
+        enum_name: String,
+        variant_name: String,
+        args: Vec<Value>,
+    ) -> Result<Value, InterpreterError> {
+        let info = match self.get_variable(&enum_name) {
+            Some(Value::Object(enum_type)) => match enum_type.get("__variants") {
+                Some(Value::Object(variants)) => variants.get(&variant_name).cloned(),
+                _ => None,
+            },
+            _ => None,
+        };
+        if let Some(Value::Object(info)) = info {
+            match info.get("arity") {
+                Some(Value::Integer(arity)) if *arity != args.len() as i64 => {
+                    return Err(InterpreterError::RuntimeError(format!(
+                        "{enum_name}::{variant_name} takes {arity} value(s), got {}",
+                        args.len()
+                    )))
+                }
+                Some(_) => {}
+                None => {
+                    return Err(InterpreterError::RuntimeError(format!(
+                        "{enum_name}::{variant_name} is not a tuple variant and cannot be called"
+                    )))
+                }
+            }
+        }
+        Ok(Value::EnumVariant {
+            enum_name,
+            variant_name,
+            data: Some(args),
+        })
+    }
+
+    /// Call an impl method of an enum value's enum, `None` when there is none
+    ///
+    /// The value is passed as `self`, like the receiver of a struct method.
+    /// Complexity: 3
+    pub(crate) fn call_enum_method(
+        &mut self,
+        receiver: &Value,
+        method: &str,
+        args: &[Value],
+    ) -> Option<Result<Value, InterpreterError>> {
+        let Value::EnumVariant { enum_name, .. } = receiver else {
+            return None;
+        };
+        let closure = self.get_variable(&format!("{enum_name}::{method}"))?;
+        let mut call_args = Vec::with_capacity(args.len() + 1);
+        call_args.push(receiver.clone());
+        call_args.extend_from_slice(args);
+        Some(self.call_function(closure, &call_args))
+    }
+
     pub(crate) fn eval_impl_block(
         &mut self,
         for_type: &str,
+        trait_name: Option<&str>,
         methods: &[crate::frontend::ast::ImplMethod],
     ) -> Result<Value, InterpreterError> {
         use std::collections::HashMap;
@@ -109,7 +295,21 @@ impl Interpreter {
         // Store the impl methods in a global registry
         // For now, we'll just add them to the environment with qualified names
         for (method_name, method_closure) in impl_methods {
-            let qualified_name = format!("{}::{}", for_type, method_name);
+            // Trait methods are also `<Type as Trait>::method`, which operators dispatch through
+            if let Some(trait_name) = trait_name {
+                let trait_method = super::interpreter_operators::trait_method_name(
+                    for_type,
+                    trait_name,
+                    &method_name,
+                );
+                self.set_variable(&trait_method, method_closure.clone());
+            }
+            // `impl<T> Maybe<T>` methods are `Maybe::method`, like those of `impl Maybe`
+            let qualified_name = format!(
+                "{}::{}",
+                super::interpreter_operators::base_name(for_type),
+                method_name
+            );
             self.set_variable(&qualified_name, method_closure);
         }
 
@@ -133,6 +333,8 @@ mod tests {
             name: name.to_string(),
             kind: EnumVariantKind::Unit,
             discriminant: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -141,6 +343,8 @@ mod tests {
             name: name.to_string(),
             kind: EnumVariantKind::Unit,
             discriminant: Some(disc),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -158,6 +362,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -239,7 +445,7 @@ mod tests {
     #[test]
     fn test_eval_impl_block_empty() {
         let mut interp = make_interpreter();
-        let result = interp.eval_impl_block("TestType", &[]).unwrap();
+        let result = interp.eval_impl_block("TestType", None, &[]).unwrap();
         assert_eq!(result, Value::Nil);
     }
 
@@ -261,7 +467,7 @@ mod tests {
             is_pub: true,
         };
 
-        let result = interp.eval_impl_block("TestType", &[method]).unwrap();
+        let result = interp.eval_impl_block("TestType", None, &[method]).unwrap();
         assert_eq!(result, Value::Nil);
 
         // Verify method was registered
@@ -301,7 +507,7 @@ mod tests {
         };
 
         let result = interp
-            .eval_impl_block("MyStruct", &[method1, method2])
+            .eval_impl_block("MyStruct", None, &[method1, method2])
             .unwrap();
         assert_eq!(result, Value::Nil);
 
@@ -317,6 +523,8 @@ mod tests {
             name: "Point".to_string(),
             kind: EnumVariantKind::Tuple(vec![make_type("i32"), make_type("i32")]),
             discriminant: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
 
         let result = interp
@@ -353,12 +561,16 @@ mod tests {
             is_mut: false,
             visibility: Visibility::Public,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
 
         let variants = vec![EnumVariant {
             name: "Named".to_string(),
             kind: EnumVariantKind::Struct(struct_fields),
             discriminant: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
 
         let result = interp
@@ -399,11 +611,15 @@ mod tests {
                 name: "None".to_string(),
                 kind: EnumVariantKind::Unit,
                 discriminant: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
             EnumVariant {
                 name: "Point".to_string(),
                 kind: EnumVariantKind::Tuple(vec![make_type("i32"), make_type("i32")]),
                 discriminant: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
             EnumVariant {
                 name: "Named".to_string(),
@@ -414,8 +630,12 @@ mod tests {
                     is_mut: false,
                     visibility: Visibility::Public,
                     decorators: vec![],
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 }]),
                 discriminant: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
         ];
 
@@ -478,6 +698,8 @@ mod tests {
                 name: "Some".to_string(),
                 kind: EnumVariantKind::Tuple(vec![make_type("T")]),
                 discriminant: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             },
             make_unit_variant("None"),
         ];
@@ -508,6 +730,8 @@ mod tests {
                 span: Span::default(),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             }],
             return_type: None,
             body: Box::new(Expr::new(
@@ -519,7 +743,9 @@ mod tests {
             is_pub: true,
         };
 
-        let result = interp.eval_impl_block("WildcardType", &[method]).unwrap();
+        let result = interp
+            .eval_impl_block("WildcardType", None, &[method])
+            .unwrap();
         assert_eq!(result, Value::Nil);
 
         // Verify method was registered
@@ -549,6 +775,8 @@ mod tests {
                     span: Span::default(),
                     is_mutable: false,
                     default_value: Some(default_expr),
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
             ],
             return_type: None,
@@ -561,7 +789,9 @@ mod tests {
             is_pub: true,
         };
 
-        let result = interp.eval_impl_block("DefaultParam", &[method]).unwrap();
+        let result = interp
+            .eval_impl_block("DefaultParam", None, &[method])
+            .unwrap();
         assert_eq!(result, Value::Nil);
 
         // Verify method was registered with params
@@ -614,7 +844,7 @@ mod tests {
             is_pub: false, // Private method
         };
 
-        let result = interp.eval_impl_block("PrivType", &[method]).unwrap();
+        let result = interp.eval_impl_block("PrivType", None, &[method]).unwrap();
         assert_eq!(result, Value::Nil);
 
         // Method should still be registered
@@ -660,10 +890,119 @@ mod tests {
             is_pub: true,
         };
 
-        let result = interp.eval_impl_block("TypedReturn", &[method]).unwrap();
+        let result = interp
+            .eval_impl_block("TypedReturn", None, &[method])
+            .unwrap();
         assert_eq!(result, Value::Nil);
 
         // Method should still be registered
         assert!(interp.lookup_variable("TypedReturn::typed_return").is_ok());
     }
+
+    const SHAPE: &str = r"
+        enum Shape { Circle(f64), Rect { w: f64, h: f64 }, Empty }
+        impl Shape {
+            fun unit() -> Shape { Shape::Circle(1.0) }
+            fun area(&self) -> f64 {
+                match self {
+                    Shape::Circle(r) => 3.0 * r * r,
+                    Shape::Rect { w, h } => w * h,
+                    Shape::Empty => 0.0
+                }
+            }
+            fun scaled(self, k: f64) -> Shape {
+                match self {
+                    Shape::Circle(r) => Shape::Circle(r * k),
+                    other => other
+                }
+            }
+        }
+    ";
+
+    /// Evaluate the whole program `source`, keeping its definitions
+    fn eval_program(
+        interp: &mut Interpreter,
+        source: &str,
+    ) -> Result<Value, Box<dyn std::error::Error>> {
+        let ast = crate::frontend::parser::Parser::new(source).parse()?;
+        Ok(interp.eval_top_level(&ast)?)
+    }
+
+    fn eval_shape(code: &str) -> Result<Value, Box<dyn std::error::Error>> {
+        eval_program(&mut make_interpreter(), &format!("{SHAPE}\n{code}"))
+    }
+
+    #[test]
+    fn test_enum_impl_methods_match_payloads() {
+        assert_eq!(
+            eval_shape("Shape::Circle(2.0).area()").unwrap(),
+            Value::Float(12.0)
+        );
+        assert_eq!(
+            eval_shape("let r = Shape::Rect { w: 2.0, h: 3.0 }\nr.area()").unwrap(),
+            Value::Float(6.0)
+        );
+        assert_eq!(
+            eval_shape("Shape::Empty.area()").unwrap(),
+            Value::Float(0.0)
+        );
+        assert_eq!(
+            eval_shape("Shape::unit().scaled(2.0).area()").unwrap(),
+            Value::Float(12.0)
+        );
+    }
+
+    #[test]
+    fn test_enum_variant_constructors_are_values() {
+        let circles = eval_shape("[1.0, 2.0].map(Shape::Circle)").unwrap();
+        let Value::Array(circles) = circles else {
+            panic!("Expected an array, got {circles:?}");
+        };
+        assert_eq!(
+            circles[1],
+            Value::EnumVariant {
+                enum_name: "Shape".to_string(),
+                variant_name: "Circle".to_string(),
+                data: Some(vec![Value::Float(2.0)]),
+            }
+        );
+    }
+
+    #[test]
+    fn test_enum_constructors_are_checked_against_the_definition() {
+        let err = eval_shape("Shape::Circle(1.0, 2.0)").unwrap_err();
+        assert!(err.to_string().contains("takes 1 value(s), got 2"), "{err}");
+        let err = eval_shape("Shape::Empty(1.0)").unwrap_err();
+        assert!(err.to_string().contains("not a tuple variant"), "{err}");
+        let err = eval_shape("Shape::Triangle").unwrap_err();
+        assert!(err.to_string().contains("has no variant Triangle"), "{err}");
+    }
+
+    #[test]
+    fn test_generic_enum_with_impl() {
+        let mut interp = make_interpreter();
+        eval_program(
+            &mut interp,
+            r#"
+                enum Result<T, E> { Ok(T), Err(E) }
+                impl<T, E> Result<T, E> {
+                    fun or_zero(self) -> i32 {
+                        match self {
+                            Result::Ok(v) => v,
+                            Result::Err(_) => 0
+                        }
+                    }
+                }
+                "#,
+        )
+        .unwrap();
+        assert_eq!(
+            eval_program(&mut interp, "Result::Ok(5).or_zero()").unwrap(),
+            Value::Integer(5)
+        );
+        assert_eq!(
+            eval_program(&mut interp, r#"Result::Err("bad").or_zero()"#).unwrap(),
+            Value::Integer(0)
+        );
+    }
 }
@@ -163,6 +163,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -375,6 +377,8 @@ mod tests {
             span: Span::default(),
             is_mutable: false,
             default_value: Some(Box::new(default_expr)),
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         };
 
         let func = make_expr(ExprKind::Function {
@@ -530,6 +534,8 @@ mod tests {
             span: Span::default(),
             is_mutable: true,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         };
 
         let func = make_expr(ExprKind::Function {
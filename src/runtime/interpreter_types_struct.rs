@@ -137,6 +137,11 @@ impl Interpreter {
     ) -> Result<Value, InterpreterError> {
         use std::collections::HashMap;
 
+        // Struct-like enum variants: Shape::Circle { radius: 1.0 }
+        if let Some(variant) = self.eval_struct_variant_literal(name, fields)? {
+            return Ok(variant);
+        }
+
         // Look up the struct type definition
         let struct_type = self.lookup_variable(name).map_err(|_| {
             InterpreterError::RuntimeError(format!("Undefined struct type: {name}"))
@@ -349,6 +354,8 @@ mod tests {
             is_mut: false,
             visibility: Visibility::Public,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
 
@@ -568,6 +575,8 @@ mod tests {
             is_mut: false,
             visibility: Visibility::Public,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
 
         let result = interp
@@ -596,6 +605,8 @@ mod tests {
             is_mut: false,
             visibility: Visibility::Public,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
 
         let result = interp
@@ -624,6 +635,8 @@ mod tests {
             is_mut: false,
             visibility: Visibility::Public,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
 
         let result = interp
@@ -652,6 +665,8 @@ mod tests {
             is_mut: false,
             visibility: Visibility::Public,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
 
         let result = interp
@@ -680,6 +695,8 @@ mod tests {
             is_mut: false,
             visibility: Visibility::Public,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
 
         let result = interp
@@ -713,6 +730,8 @@ mod tests {
             is_mut: false,
             visibility: Visibility::PubCrate,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
 
         let result = interp
@@ -741,6 +760,8 @@ mod tests {
             is_mut: false,
             visibility: Visibility::PubSuper,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
 
         let result = interp
@@ -769,6 +790,8 @@ mod tests {
             is_mut: false,
             visibility: Visibility::Private,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
 
         let result = interp
@@ -797,6 +820,8 @@ mod tests {
             is_mut: false,
             visibility: Visibility::Protected,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
 
         let result = interp
@@ -829,6 +854,8 @@ mod tests {
             is_mut: true,
             visibility: Visibility::Public,
             decorators: vec![],
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }];
 
         let result = interp
@@ -0,0 +1,232 @@
+//! Cooperative interruption of running evaluations
+//!
+//! A host that evaluates code on a worker thread (the notebook kernel) installs
+//! a shared flag on that thread with [`install`]. Every loop iteration and
+//! function call (including tail self-calls) checks the flag, so setting it
+//! from another thread stops the current evaluation with
+//! [`INTERRUPTED_MESSAGE`] while keeping the interpreter state intact.
+//! The flag stays set until the host clears it, which also unwinds nested
+//! loops and `try`/`catch` retries.
+//!
+//! A host can also bound an evaluation in time with [`set_deadline`]: once
+//! the deadline passes, the same checks fail with [`TIMEOUT_MESSAGE`]
+//! ([`limit_time`] sets one for the lifetime of a guard). Hosts
+//! without a clock (the browser build) count those checks instead with
+//! [`set_step_limit`] and fail with [`STEP_LIMIT_MESSAGE`].
+
+use crate::runtime::InterpreterError;
+use std::cell::{Cell, RefCell};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Error message of an interrupted evaluation
+pub const INTERRUPTED_MESSAGE: &str = "Execution interrupted";
+
+/// Error message of an evaluation that ran past its deadline
+pub const TIMEOUT_MESSAGE: &str = "Execution timed out";
+
+/// Error message of an evaluation that used up its steps
+pub const STEP_LIMIT_MESSAGE: &str = "Step limit exceeded";
+
+/// Loop iterations and calls allowed, and taken so far
+#[derive(Debug, Clone, Copy)]
+struct Steps {
+    limit: u64,
+    taken: u64,
+}
+
+thread_local! {
+    static FLAG: RefCell<Option<Arc<AtomicBool>>> = const { RefCell::new(None) };
+    static DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+    static STEPS: Cell<Option<Steps>> = const { Cell::new(None) };
+}
+
+/// Make `flag` the interrupt flag for evaluations on the current thread
+pub fn install(flag: Arc<AtomicBool>) {
+    FLAG.with(|slot| *slot.borrow_mut() = Some(flag));
+}
+
+/// Stop evaluations on the current thread at `deadline`; `None` removes it
+pub fn set_deadline(deadline: Option<Instant>) {
+    DEADLINE.with(|slot| slot.set(deadline));
+}
+
+/// Deadline of evaluations on the current thread, if any
+pub fn deadline() -> Option<Instant> {
+    DEADLINE.with(Cell::get)
+}
+
+/// Deadline set by [`limit_time`], in force until dropped
+#[derive(Debug)]
+#[must_use = "the deadline is lifted when the guard is dropped"]
+pub struct DeadlineGuard {
+    previous: Option<Instant>,
+}
+
+impl Drop for DeadlineGuard {
+    fn drop(&mut self) {
+        set_deadline(self.previous);
+    }
+}
+
+/// Stop evaluations on the current thread after `timeout` until the guard
+/// drops, which restores the previous deadline
+///
+/// A deadline already set on the thread still applies if it is earlier.
+pub fn limit_time(timeout: Duration) -> DeadlineGuard {
+    let previous = deadline();
+    let at = Instant::now() + timeout;
+    set_deadline(Some(previous.map_or(at, |outer| outer.min(at))));
+    DeadlineGuard { previous }
+}
+
+/// Allow evaluations on the current thread `limit` loop iterations and
+/// function calls, counting from zero; `None` removes the limit
+pub fn set_step_limit(limit: Option<u64>) {
+    STEPS.with(|slot| slot.set(limit.map(|limit| Steps { limit, taken: 0 })));
+}
+
+/// Steps taken on the current thread since [`set_step_limit`]
+pub fn steps_taken() -> u64 {
+    STEPS.with(|slot| slot.get().map_or(0, |steps| steps.taken))
+}
+
+/// Spend one step, failing once the step limit is used up
+fn take_step() -> Result<(), InterpreterError> {
+    STEPS.with(|slot| {
+        let Some(mut steps) = slot.get() else {
+            return Ok(());
+        };
+        if steps.taken >= steps.limit {
+            return Err(InterpreterError::RuntimeError(
+                STEP_LIMIT_MESSAGE.to_string(),
+            ));
+        }
+        steps.taken += 1;
+        slot.set(Some(steps));
+        Ok(())
+    })
+}
+
+/// Fail with [`INTERRUPTED_MESSAGE`] if this thread's flag is set, with
+/// [`TIMEOUT_MESSAGE`] if its deadline has passed, or with
+/// [`STEP_LIMIT_MESSAGE`] if its steps are used up
+pub(crate) fn check() -> Result<(), InterpreterError> {
+    take_step()?;
+    let expired = DEADLINE.with(|slot| slot.get().is_some_and(|at| Instant::now() >= at));
+    if expired {
+        return Err(InterpreterError::RuntimeError(TIMEOUT_MESSAGE.to_string()));
+    }
+    let interrupted = FLAG.with(|slot| {
+        slot.borrow()
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::Relaxed))
+    });
+    if interrupted {
+        Err(InterpreterError::RuntimeError(
+            INTERRUPTED_MESSAGE.to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::Parser;
+    use crate::runtime::interpreter::Interpreter;
+
+    #[test]
+    fn test_check_without_flag_is_ok() {
+        assert!(check().is_ok());
+    }
+
+    #[test]
+    fn test_flag_stops_infinite_loop() {
+        let flag = Arc::new(AtomicBool::new(false));
+        let worker_flag = Arc::clone(&flag);
+        let worker = std::thread::spawn(move || {
+            install(worker_flag);
+            let mut interpreter = Interpreter::new();
+            let ast = Parser::new("let mut n = 0\nloop { n = n + 1 }")
+                .parse()
+                .expect("source should parse");
+            let result = interpreter.eval_top_level(&ast);
+            let interrupted = matches!(
+                result,
+                Err(InterpreterError::RuntimeError(ref m)) if m == INTERRUPTED_MESSAGE
+            );
+            // State survives the interrupt
+            let n = Parser::new("n").parse().expect("source should parse");
+            let counted = matches!(
+                interpreter.eval_expr(&n),
+                Ok(crate::runtime::Value::Integer(i)) if i > 0
+            );
+            (interrupted, counted)
+        });
+        std::thread::sleep(Duration::from_millis(50));
+        flag.store(true, Ordering::Relaxed);
+        let (interrupted, counted) = worker.join().expect("worker should not panic");
+        assert!(interrupted);
+        assert!(counted);
+    }
+
+    #[test]
+    fn test_deadline_stops_infinite_loop() {
+        set_deadline(Some(Instant::now() + Duration::from_millis(20)));
+        let mut interpreter = Interpreter::new();
+        let ast = Parser::new("loop { }")
+            .parse()
+            .expect("source should parse");
+        let result = interpreter.eval_expr(&ast);
+        set_deadline(None);
+        assert!(
+            matches!(result, Err(InterpreterError::RuntimeError(ref m)) if m == TIMEOUT_MESSAGE)
+        );
+        assert!(check().is_ok());
+    }
+
+    #[test]
+    fn test_limit_time_keeps_earlier_deadline_and_restores_it() {
+        let outer = Instant::now() + Duration::from_secs(60);
+        set_deadline(Some(outer));
+        {
+            let _guard = limit_time(Duration::from_millis(10));
+            assert!(deadline().is_some_and(|inner| inner < outer));
+        }
+        assert_eq!(deadline(), Some(outer));
+        {
+            let _guard = limit_time(Duration::from_secs(120));
+            assert_eq!(deadline(), Some(outer));
+        }
+        set_deadline(None);
+    }
+
+    #[test]
+    fn test_step_limit_counts_iterations_and_calls() {
+        let mut interpreter = Interpreter::new();
+        let ast = Parser::new(
+            "fun f(x) { x }
+for i in 0..3 { f(i) }",
+        )
+        .parse()
+        .expect("source should parse");
+        set_step_limit(Some(100));
+        assert!(interpreter.eval_expr(&ast).is_ok());
+        // Three iterations, three calls
+        assert_eq!(steps_taken(), 6);
+
+        set_step_limit(Some(10));
+        let ast = Parser::new("loop { }")
+            .parse()
+            .expect("source should parse");
+        let result = interpreter.eval_expr(&ast);
+        set_step_limit(None);
+        assert!(
+            matches!(result, Err(InterpreterError::RuntimeError(ref m)) if m == STEP_LIMIT_MESSAGE)
+        );
+        assert_eq!(steps_taken(), 0);
+    }
+}
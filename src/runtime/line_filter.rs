@@ -0,0 +1,165 @@
+//! Line-at-a-time stdin processing (`ruchy --filter '<expr>'`)
+//!
+//! An awk-like mode for shell pipelines: the program is parsed once and one
+//! interpreter evaluates it for every input line, with `line` bound to the
+//! line (without its terminator) and `nr` to its 1-based number. What the
+//! program evaluates to decides the output for that line:
+//!
+//! | Value          | Output                                   |
+//! |----------------|------------------------------------------|
+//! | `true`         | the input line unchanged (grep-like)     |
+//! | `false`, `nil` | nothing                                  |
+//! | anything else  | its display form, strings without quotes |
+//!
+//! So `ruchy --filter 'line.contains("ERROR")'` selects lines and
+//! `ruchy --filter 'nr.to_string() + ": " + line'` rewrites them. One
+//! interpreter (with its method caches) serves every line and the source is
+//! never re-parsed, so per-line cost is a single evaluation.
+
+use crate::frontend::ast::Expr;
+use crate::frontend::parser::Parser;
+use crate::runtime::interpreter::Interpreter;
+use crate::runtime::script::ScriptError;
+use crate::runtime::value_format::format_value_display;
+use crate::runtime::{InterpreterError, Value};
+use std::io::{self, BufRead, Write};
+
+/// A parsed filter program and the interpreter that runs it
+pub struct LineFilter {
+    program: Expr,
+    interpreter: Interpreter,
+    nr: i64,
+}
+
+impl LineFilter {
+    /// Parse `source` once for reuse on every line
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruchy::runtime::line_filter::LineFilter;
+    ///
+    /// let mut filter = LineFilter::new("line.to_uppercase()").unwrap();
+    /// assert_eq!(filter.apply("abc").unwrap(), Some("ABC".to_string()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScriptError::Syntax`] when the program does not parse.
+    pub fn new(source: &str) -> Result<Self, ScriptError> {
+        let program = Parser::new(source)
+            .parse()
+            .map_err(|e| ScriptError::Syntax(e.to_string()))?;
+        Ok(Self {
+            program,
+            interpreter: Interpreter::new(),
+            nr: 0,
+        })
+    }
+
+    /// Number of lines processed so far
+    #[must_use]
+    pub fn lines_processed(&self) -> i64 {
+        self.nr
+    }
+
+    /// Evaluate the program for the next input line
+    ///
+    /// Returns the text to print for this line, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScriptError::Runtime`], naming the line number, when
+    /// evaluation fails.
+    pub fn apply(&mut self, line: &str) -> Result<Option<String>, ScriptError> {
+        self.nr += 1;
+        self.interpreter
+            .set_variable("line", Value::from_string(line.to_string()));
+        self.interpreter.set_variable("nr", Value::Integer(self.nr));
+        let value = match self.interpreter.eval_expr(&self.program) {
+            Ok(value) | Err(InterpreterError::Return(value)) => value,
+            Err(e) => return Err(ScriptError::Runtime(format!("line {}: {e}", self.nr))),
+        };
+        Ok(match value {
+            Value::Bool(true) => Some(line.to_string()),
+            Value::Bool(false) | Value::Nil => None,
+            other => Some(format_value_display(&other)),
+        })
+    }
+
+    /// Apply the program to every line of `input`, writing results to `output`
+    ///
+    /// Both `\n` and `\r\n` terminators are stripped. Stops quietly when the
+    /// reader of `output` goes away (e.g. `| head`).
+    ///
+    /// # Errors
+    ///
+    /// Returns the first evaluation error, or [`ScriptError::Runtime`] when
+    /// reading input or writing output fails.
+    pub fn run(&mut self, input: impl BufRead, mut output: impl Write) -> Result<(), ScriptError> {
+        for line in input.lines() {
+            let line =
+                line.map_err(|e| ScriptError::Runtime(format!("Failed to read input: {e}")))?;
+            if let Some(text) = self.apply(&line)? {
+                match writeln!(output, "{text}") {
+                    Err(e) if e.kind() == io::ErrorKind::BrokenPipe => return Ok(()),
+                    result => result.map_err(write_error)?,
+                }
+            }
+        }
+        match output.flush() {
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+            result => result.map_err(write_error),
+        }
+    }
+}
+
+fn write_error(e: io::Error) -> ScriptError {
+    ScriptError::Runtime(format!("Failed to write output: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_filter(source: &str, input: &str) -> Result<String, ScriptError> {
+        let mut output = Vec::new();
+        LineFilter::new(source)?.run(input.as_bytes(), &mut output)?;
+        Ok(String::from_utf8(output).expect("utf-8 output"))
+    }
+
+    #[test]
+    fn test_bool_result_selects_lines() {
+        let output = run_filter("line.contains(\"b\")", "abc\nxyz\nbbb\n").unwrap();
+        assert_eq!(output, "abc\nbbb\n");
+    }
+
+    #[test]
+    fn test_other_values_are_printed_and_nil_skipped() {
+        let source = "if nr == 2 { nil } else { nr.to_string() + \":\" + line }";
+        let output = run_filter(source, "a\r\nb\r\nc").unwrap();
+        assert_eq!(output, "1:a\n3:c\n");
+    }
+
+    #[test]
+    fn test_program_may_define_functions() {
+        let source = "fun shout(s) { s.to_uppercase() + \"!\" }\nshout(line)";
+        let mut filter = LineFilter::new(source).unwrap();
+        assert_eq!(filter.apply("hi").unwrap(), Some("HI!".to_string()));
+        assert_eq!(filter.apply("yo").unwrap(), Some("YO!".to_string()));
+        assert_eq!(filter.lines_processed(), 2);
+    }
+
+    #[test]
+    fn test_errors_name_the_line() {
+        let err = run_filter("line / 2", "x\n").unwrap_err();
+        assert!(
+            matches!(&err, ScriptError::Runtime(msg) if msg.starts_with("line 1:")),
+            "{err}"
+        );
+        assert!(matches!(
+            LineFilter::new("line +"),
+            Err(ScriptError::Syntax(_))
+        ));
+    }
+}
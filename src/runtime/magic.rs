@@ -377,6 +377,8 @@ impl MagicCommand for WhosMagic {
         for (name, value) in bindings {
             let type_name = match value {
                 Value::Integer(_) => "Integer",
+                Value::BigInt(_) => "BigInt",
+                Value::Decimal(_) => "Decimal",
                 Value::Float(_) => "Float",
                 Value::String(_) => "String",
                 Value::Bool(_) => "Bool",
@@ -397,6 +399,8 @@ impl MagicCommand for WhosMagic {
                 Value::BuiltinFunction(_) => "BuiltinFunction",
                 Value::Struct { .. } => "Struct",
                 Value::Class { .. } => "Class",
+                Value::Error { .. } => "Error",
+                Value::Iterator(_) => "Iterator",
                 #[cfg(not(target_arch = "wasm32"))]
                 Value::HtmlElement(_) => "HtmlElement",
                 Value::Atom(_) => "Atom",
@@ -0,0 +1,467 @@
+//! Memory held by interpreter values
+//!
+//! `ruchy runtime --memory file.ruchy` and the REPL's `:memory` command
+//! measure the program's bindings so a script that slows down or runs out
+//! of memory on large collections shows where the memory goes. A
+//! [`MemoryReport`] lists:
+//!
+//! - the bytes each binding holds, largest first, with the length of
+//!   strings, arrays, tuples, maps and data frames;
+//! - the same bytes by value type;
+//! - the reference-counted blocks reached and how many of them are shared.
+//!
+//! Sizes are estimates: every value costs its `Value` slot plus the heap
+//! blocks it owns. A block behind an `Arc` or `Rc` (string, array, map,
+//! closure environment) is counted once, for the first binding that reaches
+//! it, so `let b = a` adds only a slot. Code is not counted, and closures
+//! defined at top level do not count the global environment they capture.
+//!
+//! With [`CountingAllocator`](crate::runtime::profiler::CountingAllocator)
+//! installed, as in the `ruchy` binary, the report also carries the
+//! process's live and peak heap.
+use crate::frontend::ast::Expr;
+use crate::runtime::builtin_init::init_global_environment;
+use crate::runtime::interpreter::Interpreter;
+use crate::runtime::profiler::{self, HeapUsage};
+use crate::runtime::Value;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::mem::{size_of, size_of_val};
+use std::rc::Rc;
+use std::sync::{Arc, PoisonError};
+
+/// Bindings listed by [`MemoryReport::to_text`]
+const TOP_BINDINGS: usize = 10;
+
+/// Reference counts in front of the data of an `Arc` or `Rc` block
+const COUNTS_SIZE: usize = 2 * size_of::<usize>();
+
+/// Memory held by a set of bindings
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryReport {
+    /// Bytes held by all bindings
+    pub total_bytes: usize,
+    /// Bindings, most bytes first
+    pub bindings: Vec<BindingUsage>,
+    /// Values by type, most bytes first
+    pub types: Vec<TypeUsage>,
+    /// Distinct `Arc`/`Rc` blocks reached
+    pub allocations: usize,
+    /// Blocks reached through more than one value
+    pub shared_allocations: usize,
+    /// Process heap, when the allocator counts it
+    pub heap: Option<HeapUsage>,
+}
+
+/// Memory held by one binding
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindingUsage {
+    /// Bound name
+    pub name: String,
+    /// Type of the bound value
+    pub type_name: &'static str,
+    /// Length of a string, collection or data frame
+    pub length: Option<usize>,
+    /// Bytes first reached through this binding
+    pub bytes: usize,
+}
+
+/// Memory held by the values of one type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeUsage {
+    /// Value type
+    pub type_name: &'static str,
+    /// Values of the type, nested ones included
+    pub count: usize,
+    /// Bytes of those values, not counting the values they contain
+    pub bytes: usize,
+}
+
+/// Measure `bindings` of `interpreter`
+///
+/// `bindings` are usually the interpreter's globals, as returned by
+/// [`program_bindings`].
+pub fn measure(interpreter: &Interpreter, bindings: &[(String, Value)]) -> MemoryReport {
+    let mut walker = Walker {
+        outside: interpreter
+            .env_stack
+            .first()
+            .map(|globals| address(Rc::as_ptr(globals))),
+        ..Walker::default()
+    };
+    let mut report = MemoryReport::default();
+    for (name, value) in bindings {
+        let bytes = walker.value(value);
+        report.total_bytes += bytes;
+        report.bindings.push(BindingUsage {
+            name: name.clone(),
+            type_name: value.type_name(),
+            length: length(value),
+            bytes,
+        });
+    }
+    report
+        .bindings
+        .sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.name.cmp(&b.name)));
+    report.types = walker.types.into_values().collect();
+    report.types.sort_by(|a, b| {
+        b.bytes
+            .cmp(&a.bytes)
+            .then_with(|| a.type_name.cmp(b.type_name))
+    });
+    report.allocations = walker.seen.len();
+    report.shared_allocations = walker.shared.len();
+    let heap = profiler::heap_usage();
+    report.heap = (heap.allocations > 0).then_some(heap);
+    report
+}
+
+/// Global bindings the program made, sorted by name
+///
+/// Standard library bindings are left out unless the program replaced them.
+pub fn program_bindings(interpreter: &Interpreter) -> Vec<(String, Value)> {
+    let stdlib = init_global_environment();
+    let mut bindings: Vec<(String, Value)> = interpreter
+        .get_global_bindings()
+        .into_iter()
+        .filter(|(name, value)| stdlib.get(name) != Some(value))
+        .collect();
+    bindings.sort_by(|(a, _), (b, _)| a.cmp(b));
+    bindings
+}
+
+impl MemoryReport {
+    /// Summary followed by the largest bindings and the bytes per type
+    pub fn to_text(&self) -> String {
+        let mut out = String::from("=== Memory Analysis ===\n");
+        let _ = writeln!(
+            out,
+            "Bindings: {} holding {}",
+            self.bindings.len(),
+            format_bytes(self.total_bytes as u64)
+        );
+        let _ = writeln!(
+            out,
+            "Reference-counted allocations: {} ({} shared)",
+            self.allocations, self.shared_allocations
+        );
+        if let Some(heap) = &self.heap {
+            let _ = writeln!(
+                out,
+                "Heap memory usage: {} live, {} peak ({} allocations)",
+                format_bytes(heap.live_bytes),
+                format_bytes(heap.peak_bytes),
+                heap.allocations
+            );
+        }
+        if self.bindings.is_empty() {
+            return out;
+        }
+
+        let width = self
+            .bindings
+            .iter()
+            .take(TOP_BINDINGS)
+            .map(|b| b.name.len())
+            .chain(std::iter::once("binding".len()))
+            .max()
+            .unwrap_or(0);
+        let _ = writeln!(
+            out,
+            "\n{:<width$}  {:<12}  {:>10}  {:>12}",
+            "binding", "type", "length", "bytes"
+        );
+        for binding in self.bindings.iter().take(TOP_BINDINGS) {
+            let length = binding.length.map(|n| n.to_string()).unwrap_or_default();
+            let _ = writeln!(
+                out,
+                "{:<width$}  {:<12}  {:>10}  {:>12}",
+                binding.name,
+                binding.type_name,
+                length,
+                format_bytes(binding.bytes as u64)
+            );
+        }
+        if self.bindings.len() > TOP_BINDINGS {
+            let _ = writeln!(out, "... {} more", self.bindings.len() - TOP_BINDINGS);
+        }
+
+        let _ = writeln!(out, "\n{:<16}  {:>10}  {:>12}", "type", "values", "bytes");
+        for usage in &self.types {
+            let _ = writeln!(
+                out,
+                "{:<16}  {:>10}  {:>12}",
+                usage.type_name,
+                usage.count,
+                format_bytes(usage.bytes as u64)
+            );
+        }
+        out
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+/// Length of a string, collection or data frame
+fn length(value: &Value) -> Option<usize> {
+    match value {
+        Value::String(s) => Some(s.len()),
+        Value::Array(items) | Value::Tuple(items) => Some(items.len()),
+        Value::Object(map) => Some(map.len()),
+        Value::ObjectMut(map) => Some(map.lock().unwrap_or_else(PoisonError::into_inner).len()),
+        Value::Struct { fields, .. } => Some(fields.len()),
+        Value::DataFrame { columns } => Some(columns.first().map_or(0, |c| c.values.len())),
+        _ => None,
+    }
+}
+
+fn address<T: ?Sized>(ptr: *const T) -> usize {
+    ptr.cast::<()>() as usize
+}
+
+/// Walks values, counting each shared block once
+#[derive(Default)]
+struct Walker {
+    /// Block that is not walked into: the global environment
+    outside: Option<usize>,
+    /// Addresses of the `Arc`/`Rc` blocks reached so far
+    seen: HashSet<usize>,
+    /// Blocks reached more than once
+    shared: HashSet<usize>,
+    types: HashMap<&'static str, TypeUsage>,
+}
+
+impl Walker {
+    /// Whether the block at `ptr` is reached for the first time
+    fn first_visit<T: ?Sized>(&mut self, ptr: *const T) -> bool {
+        let block = address(ptr);
+        if self.outside == Some(block) {
+            false
+        } else if self.seen.insert(block) {
+            true
+        } else {
+            self.shared.insert(block);
+            false
+        }
+    }
+
+    /// Bytes of `value` and everything it reaches for the first time
+    fn value(&mut self, value: &Value) -> usize {
+        let mut own = size_of::<Value>();
+        let mut nested = 0;
+        match value {
+            Value::Integer(_)
+            | Value::Decimal(_)
+            | Value::Float(_)
+            | Value::Bool(_)
+            | Value::Byte(_)
+            | Value::Nil => {}
+            Value::BigInt(n) => {
+                if self.first_visit(Arc::as_ptr(n)) {
+                    own += COUNTS_SIZE + n.bits().div_ceil(8) as usize;
+                }
+            }
+            Value::Atom(name) | Value::BuiltinFunction(name) => own += name.capacity(),
+            Value::String(s) => {
+                if self.first_visit(Arc::as_ptr(s)) {
+                    own += COUNTS_SIZE + s.len();
+                }
+            }
+            Value::Array(items) | Value::Tuple(items) => {
+                if self.first_visit(Arc::as_ptr(items)) {
+                    own += COUNTS_SIZE;
+                    nested += self.values(items.iter());
+                }
+            }
+            Value::Closure { params, env, .. } => {
+                own += params.capacity() * size_of::<(String, Option<Arc<Expr>>)>()
+                    + params
+                        .iter()
+                        .map(|(name, _)| name.capacity())
+                        .sum::<usize>();
+                if self.first_visit(Rc::as_ptr(env)) {
+                    own += COUNTS_SIZE;
+                    if let Ok(env) = env.try_borrow() {
+                        own += map_bytes(&env);
+                        nested += self.values(env.values());
+                    }
+                }
+            }
+            Value::DataFrame { columns } => {
+                for column in columns {
+                    own += size_of_val(column) + column.name.capacity();
+                    own += (column.values.capacity() - column.values.len()) * size_of::<Value>();
+                    nested += self.values(column.values.iter());
+                }
+            }
+            Value::Object(map) => {
+                if self.first_visit(Arc::as_ptr(map)) {
+                    own += COUNTS_SIZE + map_bytes(map);
+                    nested += self.values(map.values());
+                }
+            }
+            Value::ObjectMut(map) => {
+                if self.first_visit(Arc::as_ptr(map)) {
+                    let map = map.lock().unwrap_or_else(PoisonError::into_inner);
+                    own += COUNTS_SIZE + map_bytes(&map);
+                    nested += self.values(map.values());
+                }
+            }
+            Value::Range { start, end, .. } => {
+                nested += self.value(start) + self.value(end);
+            }
+            Value::EnumVariant {
+                enum_name,
+                variant_name,
+                data,
+            } => {
+                own += enum_name.capacity() + variant_name.capacity();
+                if let Some(data) = data {
+                    nested += self.values(data.iter());
+                }
+            }
+            Value::Struct { name, fields } => {
+                own += name.capacity();
+                if self.first_visit(Arc::as_ptr(fields)) {
+                    own += COUNTS_SIZE + map_bytes(fields);
+                    nested += self.values(fields.values());
+                }
+            }
+            Value::Class {
+                class_name,
+                fields,
+                methods,
+            } => {
+                own += class_name.capacity();
+                if self.first_visit(Arc::as_ptr(fields)) {
+                    let fields = fields.read().unwrap_or_else(PoisonError::into_inner);
+                    own += COUNTS_SIZE + map_bytes(&fields);
+                    nested += self.values(fields.values());
+                }
+                if self.first_visit(Arc::as_ptr(methods)) {
+                    own += COUNTS_SIZE + map_bytes(methods);
+                    nested += self.values(methods.values());
+                }
+            }
+            Value::Error {
+                kind,
+                message,
+                stack,
+            } => {
+                own += kind.capacity();
+                if self.first_visit(Arc::as_ptr(message)) {
+                    own += COUNTS_SIZE + message.len();
+                }
+                if self.first_visit(Arc::as_ptr(stack)) {
+                    own += COUNTS_SIZE
+                        + stack
+                            .iter()
+                            .map(|frame| size_of::<String>() + frame.capacity())
+                            .sum::<usize>();
+                }
+            }
+            Value::Iterator(iter) => {
+                if self.first_visit(Rc::as_ptr(iter)) {
+                    own += COUNTS_SIZE + size_of_val(&**iter);
+                }
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            Value::HtmlDocument(_) | Value::HtmlElement(_) => {}
+        }
+        let usage = self
+            .types
+            .entry(value.type_name())
+            .or_insert_with(|| TypeUsage {
+                type_name: value.type_name(),
+                count: 0,
+                bytes: 0,
+            });
+        usage.count += 1;
+        usage.bytes += own;
+        own + nested
+    }
+
+    fn values<'a>(&mut self, values: impl Iterator<Item = &'a Value>) -> usize {
+        values.map(|value| self.value(value)).sum()
+    }
+}
+
+/// Table and key bytes of a map; the values are measured separately
+fn map_bytes(map: &HashMap<String, Value>) -> usize {
+    let slot = size_of::<String>() + size_of::<Value>() + 1;
+    map.capacity() * slot - map.len() * size_of::<Value>()
+        + map.keys().map(String::capacity).sum::<usize>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::Parser;
+
+    fn run(source: &str) -> (Interpreter, MemoryReport) {
+        let mut interpreter = Interpreter::new();
+        let ast = Parser::new(source).parse().unwrap();
+        interpreter.eval_top_level(&ast).unwrap();
+        let report = measure(&interpreter, &program_bindings(&interpreter));
+        (interpreter, report)
+    }
+
+    fn binding<'a>(report: &'a MemoryReport, name: &str) -> &'a BindingUsage {
+        report.bindings.iter().find(|b| b.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_large_array_is_the_largest_binding() {
+        let (_, report) = run("let small = 1\nlet big = [0; 1000]\nlet name = \"ruchy\"");
+        let names: Vec<_> = report.bindings.iter().map(|b| b.name.as_str()).collect();
+        assert_eq!(names, ["big", "name", "small"]);
+        let big = binding(&report, "big");
+        assert_eq!(big.type_name, "array");
+        assert_eq!(big.length, Some(1000));
+        assert!(big.bytes >= 1001 * size_of::<Value>(), "{big:?}");
+        assert_eq!(report.types[0].type_name, "integer");
+        assert_eq!(report.types[0].count, 1001);
+        assert_eq!(
+            report.total_bytes,
+            report.types.iter().map(|t| t.bytes).sum::<usize>()
+        );
+        assert!(report.to_text().contains("big"));
+    }
+
+    #[test]
+    fn test_shared_array_counts_once() {
+        let (_, report) = run("let a = [1, 2, 3, 4, 5, 6, 7, 8]\nlet b = a");
+        assert_eq!(binding(&report, "b").bytes, size_of::<Value>());
+        assert!(binding(&report, "a").bytes > 9 * size_of::<Value>());
+        assert_eq!(report.allocations, 1);
+        assert_eq!(report.shared_allocations, 1);
+    }
+
+    #[test]
+    fn test_stdlib_and_global_environment_are_left_out() {
+        let (_, report) = run("fun double(x) { x * 2 }");
+        assert_eq!(report.bindings.len(), 1);
+        assert_eq!(report.bindings[0].type_name, "function");
+        assert_eq!(report.allocations, 0);
+        assert!(report.total_bytes < 1024, "{report:?}");
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
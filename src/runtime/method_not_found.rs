@@ -0,0 +1,323 @@
+//! Method-not-found errors with did-you-mean suggestions
+//!
+//! Builtin values (strings, arrays, numbers, iterators) answer to a fixed set
+//! of methods. Calling any other method reports the receiver's type, the
+//! closest method names by edit distance and, once the call site is known,
+//! the span of the call. The interpreter raises these at runtime through
+//! [`InterpreterError::MethodNotFound`]; the static check in
+//! [`crate::middleend::method_analysis`] reports the same errors for
+//! literal receivers before a program is run or transpiled.
+
+use crate::frontend::ast::{Expr, ExprKind, Span};
+use crate::runtime::eval_array::ARRAY_METHODS;
+use crate::runtime::eval_iterator::ITERATOR_METHODS;
+use crate::runtime::eval_method::{FLOAT_METHODS, GENERIC_METHODS, INTEGER_METHODS};
+use crate::runtime::eval_string_methods::STRING_METHODS;
+use crate::runtime::{InterpreterError, Value};
+use std::fmt;
+
+/// Most suggestions listed in one error
+const MAX_SUGGESTIONS: usize = 3;
+
+/// A call of a method the receiver's type does not have
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MethodNotFound {
+    /// Type of the receiver, as [`Value::type_name`] spells it
+    pub receiver_type: String,
+    /// Method that was called, without turbofish
+    pub method: String,
+    /// Closest methods of the receiver type, equally close
+    pub suggestions: Vec<String>,
+    /// Span of `method(args)`, once the call site is known
+    pub span: Option<Span>,
+}
+
+impl MethodNotFound {
+    /// Error for calling `method` on a value of `receiver_type`, which has `candidates`
+    #[must_use]
+    pub fn new(receiver_type: &str, method: &str, candidates: &[&str]) -> Self {
+        Self {
+            receiver_type: receiver_type.to_string(),
+            method: method.to_string(),
+            suggestions: suggest(method, candidates)
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+            span: None,
+        }
+    }
+
+    /// One-based line and column of the call within `source`
+    #[must_use]
+    pub fn position(&self, source: &str) -> Option<(usize, usize)> {
+        let span = self.span?;
+        let before = source.get(..span.start.min(source.len()))?;
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rfind('\n')
+            .map_or(before.len(), |nl| before.len() - nl - 1)
+            + 1;
+        Some((line, column))
+    }
+
+    /// The message followed by the call's line and column, when it is known
+    #[must_use]
+    pub fn render(&self, source: &str) -> String {
+        match self.position(source) {
+            Some((line, column)) => format!("{self} at line {line}, column {column}"),
+            None => self.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for MethodNotFound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Method '{}' not found for type {}",
+            self.method, self.receiver_type
+        )?;
+        match self.suggestions.as_slice() {
+            [] => Ok(()),
+            [only] => write!(f, "; did you mean '{only}'?"),
+            [init @ .., last] => {
+                let init: Vec<String> = init.iter().map(|s| format!("'{s}'")).collect();
+                write!(f, "; did you mean {} or '{last}'?", init.join(", "))
+            }
+        }
+    }
+}
+
+/// Methods of the builtin type named `type_name`, if it has a fixed set
+#[must_use]
+pub fn builtin_methods(type_name: &str) -> Option<Vec<&'static str>> {
+    let methods = match type_name {
+        "string" => STRING_METHODS.to_vec(),
+        "array" => ARRAY_METHODS.to_vec(),
+        "integer" => INTEGER_METHODS.to_vec(),
+        "float" => FLOAT_METHODS.to_vec(),
+        "iterator" | "range" => {
+            let mut methods = ITERATOR_METHODS.to_vec();
+            methods.extend(
+                ARRAY_METHODS
+                    .iter()
+                    .filter(|m| !ITERATOR_METHODS.contains(m)),
+            );
+            methods
+        }
+        "boolean" | "nil" | "tuple" | "byte" | "function" | "builtin_function" | "atom" => {
+            GENERIC_METHODS.to_vec()
+        }
+        _ => return None,
+    };
+    Some(methods)
+}
+
+/// The candidates closest to `method`, if close enough to be a likely typo
+///
+/// A candidate qualifies when it differs only in case, or when its edit
+/// distance is at most a third of the name's length (and at least 1). Only
+/// the candidates at the smallest such distance are kept.
+#[must_use]
+pub fn suggest<'a>(method: &str, candidates: &[&'a str]) -> Vec<&'a str> {
+    let threshold = (method.chars().count() / 3).max(1);
+    let mut scored: Vec<(usize, &'a str)> = candidates
+        .iter()
+        .filter(|candidate| **candidate != method)
+        .filter_map(|candidate| {
+            let distance = if candidate.eq_ignore_ascii_case(method) {
+                0
+            } else {
+                typo_distance(method, candidate)
+            };
+            (distance <= threshold).then_some((distance, *candidate))
+        })
+        .collect();
+    scored.sort_unstable();
+    scored.dedup();
+    let best = scored.first().map(|(distance, _)| *distance);
+    scored
+        .into_iter()
+        .take_while(|(distance, _)| Some(*distance) == best)
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+/// Edit distance counting a swap of adjacent characters as one edit
+///
+/// `psuh` is one edit from `push` rather than the two Levenshtein counts.
+fn typo_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = distance;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+/// Replace a dispatcher's unknown-method error for `receiver` with a [`MethodNotFound`]
+///
+/// Only errors that name `method` as unknown are replaced, and only when the
+/// receiver's type really lacks it; a known method called with the wrong
+/// number of arguments keeps its original message.
+pub(crate) fn explain(error: InterpreterError, receiver: &Value, method: &str) -> InterpreterError {
+    let InterpreterError::RuntimeError(message) = &error else {
+        return error;
+    };
+    let type_name = receiver.type_name();
+    let Some(candidates) = builtin_methods(type_name) else {
+        return error;
+    };
+    if candidates.contains(&method) || !is_unknown_method_message(message, method) {
+        return error;
+    }
+    InterpreterError::MethodNotFound(Box::new(MethodNotFound::new(
+        type_name,
+        method,
+        &candidates,
+    )))
+}
+
+/// Record the span of `call` on a [`MethodNotFound`] raised by it
+pub(crate) fn locate(error: InterpreterError, call: &Expr) -> InterpreterError {
+    match (error, &call.kind) {
+        (InterpreterError::MethodNotFound(mut missing), ExprKind::MethodCall { method, .. })
+            if missing.span.is_none() && base_method(method) == missing.method =>
+        {
+            missing.span = Some(call.span);
+            InterpreterError::MethodNotFound(missing)
+        }
+        (error, _) => error,
+    }
+}
+
+/// `method` without its turbofish (`parse::<i32>` is `parse`)
+pub(crate) fn base_method(method: &str) -> &str {
+    method.split("::").next().unwrap_or(method)
+}
+
+/// Whether a dispatcher's message reports `method` itself as unknown
+///
+/// Covers `Unknown array method: m`, `Unknown zero-argument string method: m`,
+/// `Unknown string method or invalid arguments: m` and
+/// `Method 'm' not found for type t`.
+fn is_unknown_method_message(message: &str, method: &str) -> bool {
+    let unknown = message.starts_with("Unknown ")
+        && (message.ends_with(&format!("method: {method}"))
+            || message.ends_with(&format!("method or invalid arguments: {method}")));
+    unknown || message.starts_with(&format!("Method '{method}' not found for type "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::Parser;
+    use crate::runtime::Interpreter;
+
+    fn eval_error(source: &str) -> InterpreterError {
+        let ast = Parser::new(source).parse().expect("should parse");
+        Interpreter::new().eval_expr(&ast).expect_err("should fail")
+    }
+
+    #[test]
+    fn test_suggest_orders_by_distance() {
+        assert_eq!(suggest("pusj", ARRAY_METHODS), vec!["push"]);
+        assert_eq!(suggest("TRIM", STRING_METHODS), vec!["trim"]);
+        assert_eq!(suggest("lenght", STRING_METHODS), vec!["length"]);
+        assert!(suggest("frobnicate", STRING_METHODS).is_empty());
+    }
+
+    #[test]
+    fn test_message_lists_type_and_suggestions() {
+        let missing = MethodNotFound::new("array", "fitler", ARRAY_METHODS);
+        assert_eq!(
+            missing.to_string(),
+            "Method 'fitler' not found for type array; did you mean 'filter'?"
+        );
+        let missing = MethodNotFound::new("float", "flor", FLOAT_METHODS);
+        assert_eq!(
+            missing.to_string(),
+            "Method 'flor' not found for type float; did you mean 'floor'?"
+        );
+    }
+
+    #[test]
+    fn test_interpreter_reports_type_suggestion_and_span() {
+        let source = "let xs = [1, 2]\nxs.lenght()";
+        let InterpreterError::MethodNotFound(missing) = eval_error(source) else {
+            panic!("expected MethodNotFound");
+        };
+        assert_eq!(missing.receiver_type, "array");
+        assert_eq!(missing.suggestions, vec!["length"]);
+        let span = missing.span.expect("span should be recorded");
+        assert_eq!(&source[span.start..span.end], "lenght()");
+        assert_eq!(missing.position(source), Some((2, 4)));
+        assert!(missing
+            .render(source)
+            .ends_with("'length'? at line 2, column 4"));
+    }
+
+    #[test]
+    fn test_wrong_arity_keeps_original_error() {
+        let error = eval_error("\"a,b\".split()");
+        assert!(
+            matches!(&error, InterpreterError::RuntimeError(m) if m.contains("split")),
+            "{error:?}"
+        );
+    }
+
+    #[test]
+    fn test_method_not_found_can_be_caught() {
+        let ast = Parser::new("let n = 42\ntry { n.sqrt() } catch e { e.message }")
+            .parse()
+            .expect("should parse");
+        let caught = Interpreter::new().eval_expr(&ast).expect("should catch");
+        assert_eq!(
+            caught,
+            Value::from_string("Method 'sqrt' not found for type integer".to_string())
+        );
+    }
+
+    #[test]
+    fn test_tables_list_only_dispatched_methods() {
+        let receivers = [
+            Value::from_string("text".to_string()),
+            Value::from_array(vec![Value::Integer(1)]),
+            Value::Integer(1),
+            Value::Float(1.5),
+        ];
+        for receiver in receivers {
+            let type_name = receiver.type_name();
+            for method in builtin_methods(type_name).expect("builtin type") {
+                let answers = (0..=2).any(|arity| {
+                    let args = vec![Value::Integer(1); arity];
+                    let mut interpreter = Interpreter::new();
+                    match interpreter.dispatch_method_call(&receiver, method, &args, arity == 0) {
+                        Err(InterpreterError::RuntimeError(m)) => {
+                            !is_unknown_method_message(&m, method)
+                        }
+                        _ => true,
+                    }
+                });
+                assert!(answers, "{type_name} does not answer to '{method}'");
+            }
+        }
+    }
+}
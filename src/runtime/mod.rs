@@ -69,13 +69,18 @@ pub mod cache;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod completion;
 pub mod grammar_coverage;
+pub mod intern; // Per-thread interning of short runtime strings
 pub mod interpreter;
+pub mod interpreter_captures; // Closure capture: scopes enclosing a closure's environment
 pub mod interpreter_control_flow; // Control flow: loops, match, assignments
 pub mod interpreter_dataframe; // DataFrame operations and methods
+pub mod interpreter_defer; // Defer: expressions run when the enclosing scope exits
 pub mod interpreter_functions; // Function definitions, lambdas, and calls
+pub mod interpreter_generator; // Generator functions: yield lowered to a resumable state machine
 pub mod interpreter_index; // Index access, field access, qualified names
 #[cfg(test)]
 pub mod interpreter_inline_tests; // Extracted tests from interpreter.rs
+pub mod interpreter_method_cache; // Per-call-site inline caches for method dispatch
 pub mod interpreter_methods; // Method dispatch for all value types (re-export)
 pub mod interpreter_methods_actor; // Actor/struct/object methods
 pub mod interpreter_methods_dispatch; // Core method dispatch logic
@@ -84,6 +89,9 @@ pub mod interpreter_methods_html; // HTML document/element methods
 pub mod interpreter_methods_instance; // Mutable object instance methods
 pub mod interpreter_methods_string; // String/array method dispatch
 pub mod interpreter_misc_eval; // Misc expression eval: imports, macros, try, pipeline, comprehensions
+pub mod interpreter_operators; // Operator overloading through Add/PartialEq/Ord/Index/Display impls
+pub mod interpreter_slots; // Slot-indexed locals for resolved function bodies
+pub mod interpreter_tail_call; // Self-tail-call elimination: tail self-calls loop instead of recursing
 pub mod interpreter_types; // EXTREME TDD Round 52: InterpreterError, CallFrame extracted
 pub mod interpreter_types_actor; // Actor definition and instantiation
 pub mod interpreter_types_class; // Class definition, instantiation, methods
@@ -91,6 +99,8 @@ pub mod interpreter_types_enum; // Enum and impl block definitions
 pub mod interpreter_types_impl; // Class/struct/enum/actor definitions (re-export)
 pub mod interpreter_types_module; // Module expression evaluation
 pub mod interpreter_types_struct; // Struct definition and instantiation
+pub mod interrupt; // Cooperative interruption of evaluations on host worker threads
+pub mod method_not_found; // Did-you-mean errors for unknown methods on builtin values
 pub mod module_loader; // PARSER-060: Module resolution and import system
 pub mod object_helpers; // EXTREME TDD: RefCell-based mutable objects
 pub mod value; // EXTREME TDD Round 52: Value type extracted from interpreter.rs
@@ -99,31 +109,41 @@ pub mod value_utils;
 pub mod builtin_init; // EXTREME TDD: Builtin functions initialization
 pub mod builtins;
 pub mod compilation; // EXTREME TDD: Direct-threaded interpreter compilation
+#[cfg(feature = "dataframe")]
+pub mod dataframe_polars; // Value::DataFrame <-> polars conversions
 pub mod eval_actor; // EXTREME TDD: Actor operations extracted for 100% coverage
 pub mod eval_array;
+pub mod eval_bigint; // Arbitrary-precision integers (Value::BigInt)
 pub mod eval_builtin;
 pub mod eval_builtin_fs;
 pub mod eval_builtin_json_ops;
 pub mod eval_builtin_path;
 pub mod eval_builtin_platform;
 pub mod eval_control_flow_new;
+pub mod eval_csv; // std::csv::read/write with delimiter and header options
 pub mod eval_data_structures;
 pub mod eval_dataframe;
+pub mod eval_dataframe_engine; // head/tail/sort/group_by/agg, CSV files, table display
 pub mod eval_dataframe_ops;
+pub mod eval_decimal; // Exact decimal arithmetic (Value::Decimal)
 pub mod eval_display;
 pub mod eval_expr;
 pub mod eval_func;
 pub mod eval_function;
 pub mod eval_html_methods; // HTTP-002-D: HTML parsing method support
 pub mod eval_index; // EXTREME TDD: Index operations extracted for 100% coverage
+pub mod eval_iterator; // Lazy iterator pipelines (map/filter/take/zip/...)
 pub mod eval_json; // EXTREME TDD: JSON operations extracted for 100% coverage
 pub mod eval_literal;
 pub mod eval_loops;
 pub mod eval_method;
 pub mod eval_method_dispatch;
 pub mod eval_operations;
+pub mod eval_option_result; // Option/Result values and their combinator methods
 pub mod eval_pattern;
 pub mod eval_pattern_match;
+pub mod eval_plot; // std::plot line/scatter/bar charts as SVG display objects
+pub mod eval_rich_output; // std::display MIME-typed values for notebook output
 pub mod eval_string;
 pub mod eval_string_interpolation;
 pub mod eval_string_methods;
@@ -135,7 +155,15 @@ pub mod validation;
 pub mod value_format; // EXTREME TDD: Value formatting utilities (extracted from interpreter.rs)
                       // pub mod interpreter_modules;  // Temporarily disabled - compilation errors
 pub mod lazy;
+pub mod line_filter; // Per-line stdin processing for `ruchy --filter`
+pub mod memory; // Memory held by interpreter values for `runtime --memory` and `:memory`
+pub mod output; // Per-thread buffered print output and `capture_output { ... }`
+pub mod pattern_cache; // Bounded caches for compiled regexes and format templates
 pub mod pattern_matching;
+pub mod profiler; // Per-function timings and allocations for `ruchy runtime --profile`
+pub mod records; // JSON-lines record streams for `--input json` / `--output json`
+pub mod restricted; // Capability, time and output limits for untrusted input
+pub mod telemetry; // OpenTelemetry spans and metrics for function calls and I/O builtins
 #[cfg(all(not(target_arch = "wasm32"), feature = "repl"))]
 pub mod repl; // New EXTREME Quality REPL
               // pub mod repl_legacy; // Old REPL (backup) - temporarily disabled for integration
@@ -150,6 +178,9 @@ pub mod magic;
 pub mod repl_recording;
 pub mod replay;
 pub mod replay_converter;
+pub mod replay_trace; // `ruchy run --record` traces and deterministic `ruchy replay`
+#[cfg(not(target_arch = "wasm32"))]
+pub mod script; // Shared script execution semantics for all CLI entry points
 // pub mod arena;  // Disabled - uses unsafe code
 pub mod inspect;
 pub mod safe_arena;
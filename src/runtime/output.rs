@@ -0,0 +1,226 @@
+//! Output of the print builtins
+//!
+//! `println`, `print`, `dbg` and `emit` all write through [`write`]. Each
+//! thread has its own writer:
+//!
+//! - Inside [`capture`] (`capture_output { ... }` in Ruchy, the notebook
+//!   kernel, `ruchy test`) text goes to the innermost capture and nowhere
+//!   else.
+//! - Otherwise it is buffered according to the thread's [`FlushPolicy`] and
+//!   written to stdout in whole chunks under the stdout lock, so output from
+//!   different threads interleaves only at chunk boundaries.
+//!
+//! Uncaptured text is also appended to
+//! [`OUTPUT_BUFFER`](crate::runtime::builtins::OUTPUT_BUFFER) for browser
+//! hosts and recorded in the active run trace.
+//!
+//! # Examples
+//! ```ruchy
+//! let printed = capture_output {
+//!     println("hello")
+//! }
+//! assert_eq(printed, "hello\n")
+//! ```
+use std::cell::RefCell;
+use std::io::{self, Write};
+
+/// Buffered text that makes a [`FlushPolicy::Block`] writer flush
+const BLOCK_SIZE: usize = 8 * 1024;
+
+/// When a thread's buffered output reaches stdout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FlushPolicy {
+    /// Every write (the default)
+    #[default]
+    Immediate,
+    /// At the end of each complete line
+    Line,
+    /// Once 8 KiB are buffered, on [`flush`], or when the thread exits
+    Block,
+}
+
+#[derive(Default)]
+struct ThreadOutput {
+    policy: FlushPolicy,
+    pending: String,
+    captures: Vec<String>,
+}
+
+impl Drop for ThreadOutput {
+    fn drop(&mut self) {
+        let _ = write_stdout(&self.pending);
+    }
+}
+
+thread_local! {
+    static OUTPUT: RefCell<ThreadOutput> = RefCell::new(ThreadOutput::default());
+}
+
+/// Write program output for the current thread
+///
+/// # Errors
+///
+/// Returns the error of a stdout write this call triggered. Text that was
+/// captured or is still buffered cannot fail.
+pub fn write(text: &str) -> io::Result<()> {
+    let ready = OUTPUT.with(|output| {
+        let mut output = output.borrow_mut();
+        if let Some(capture) = output.captures.last_mut() {
+            capture.push_str(text);
+            return None;
+        }
+        output.pending.push_str(text);
+        let end = match output.policy {
+            FlushPolicy::Immediate => output.pending.len(),
+            FlushPolicy::Line => output.pending.rfind('\n').map_or(0, |i| i + 1),
+            FlushPolicy::Block if output.pending.len() >= BLOCK_SIZE => output.pending.len(),
+            FlushPolicy::Block => 0,
+        };
+        Some(output.pending.drain(..end).collect::<String>())
+    });
+    let Some(ready) = ready else {
+        return Ok(());
+    };
+    if let Ok(mut buf) = crate::runtime::builtins::OUTPUT_BUFFER.lock() {
+        buf.push_str(text);
+    }
+    crate::runtime::replay_trace::output(text);
+    write_stdout(&ready)
+}
+
+/// Write the current thread's buffered output to stdout
+///
+/// # Errors
+///
+/// Returns the error of the stdout write.
+pub fn flush() -> io::Result<()> {
+    let pending = OUTPUT.with(|output| std::mem::take(&mut output.borrow_mut().pending));
+    write_stdout(&pending)
+}
+
+/// The current thread's flush policy
+pub fn flush_policy() -> FlushPolicy {
+    OUTPUT.with(|output| output.borrow().policy)
+}
+
+/// Change the current thread's flush policy, returning the previous one
+///
+/// Buffered output is flushed first.
+pub fn set_flush_policy(policy: FlushPolicy) -> FlushPolicy {
+    let _ = flush();
+    OUTPUT.with(|output| std::mem::replace(&mut output.borrow_mut().policy, policy))
+}
+
+/// Run `f`, collecting everything it writes on this thread instead of
+/// printing it
+///
+/// Captures nest: an inner capture takes its text away from the outer one.
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, String) {
+    OUTPUT.with(|output| output.borrow_mut().captures.push(String::new()));
+    let capture = Capture { open: true };
+    let value = f();
+    (value, capture.finish())
+}
+
+/// Ends the capture it opened, even when `f` panics
+struct Capture {
+    open: bool,
+}
+
+impl Capture {
+    fn finish(mut self) -> String {
+        self.open = false;
+        pop_capture()
+    }
+}
+
+impl Drop for Capture {
+    fn drop(&mut self) {
+        if self.open {
+            pop_capture();
+        }
+    }
+}
+
+fn pop_capture() -> String {
+    OUTPUT.with(|output| output.borrow_mut().captures.pop().unwrap_or_default())
+}
+
+fn write_stdout(text: &str) -> io::Result<()> {
+    if text.is_empty() {
+        return Ok(());
+    }
+    let mut stdout = io::stdout().lock();
+    stdout.write_all(text.as_bytes())?;
+    stdout.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pending() -> String {
+        OUTPUT.with(|output| output.borrow().pending.clone())
+    }
+
+    #[test]
+    fn test_capture_collects_output() {
+        let ((), printed) = capture(|| {
+            write("a").unwrap();
+            write("b\n").unwrap();
+        });
+        assert_eq!(printed, "ab\n");
+    }
+
+    #[test]
+    fn test_captures_nest() {
+        let (inner, outer) = capture(|| {
+            write("outer 1\n").unwrap();
+            let ((), inner) = capture(|| write("inner\n").unwrap());
+            write("outer 2\n").unwrap();
+            inner
+        });
+        assert_eq!(inner, "inner\n");
+        assert_eq!(outer, "outer 1\nouter 2\n");
+    }
+
+    #[test]
+    fn test_capture_ends_on_panic() {
+        let panicked = std::panic::catch_unwind(|| capture(|| panic!("boom")));
+        assert!(panicked.is_err());
+        let ((), printed) = capture(|| write("after\n").unwrap());
+        assert_eq!(printed, "after\n");
+        assert!(OUTPUT.with(|output| output.borrow().captures.is_empty()));
+    }
+
+    #[test]
+    fn test_line_policy_holds_partial_lines() {
+        let previous = set_flush_policy(FlushPolicy::Line);
+        assert_eq!(previous, FlushPolicy::Immediate);
+        write("").unwrap();
+        write("partial").unwrap();
+        assert_eq!(pending(), "partial");
+        write(" line\nnext").unwrap();
+        assert_eq!(pending(), "next");
+        set_flush_policy(previous);
+        assert_eq!(pending(), "");
+    }
+
+    #[test]
+    fn test_block_policy_waits_for_a_full_block() {
+        set_flush_policy(FlushPolicy::Block);
+        write("line\n").unwrap();
+        assert_eq!(pending(), "line\n");
+        write(&"x".repeat(BLOCK_SIZE)).unwrap();
+        assert_eq!(pending(), "");
+        set_flush_policy(FlushPolicy::Immediate);
+    }
+
+    #[test]
+    fn test_threads_capture_independently() {
+        let handle = std::thread::spawn(|| capture(|| write("worker\n").unwrap()).1);
+        let ((), printed) = capture(|| write("main\n").unwrap());
+        assert_eq!(printed, "main\n");
+        assert_eq!(handle.join().unwrap(), "worker\n");
+    }
+}
@@ -0,0 +1,191 @@
+//! Bounded caches for compiled regexes and parsed format templates
+//!
+//! `search()`, the `stdlib::regex` helpers and `format()`/`println!` used to
+//! recompile their regex or rescan their template on every call, which
+//! dominates loops that apply the same pattern to many inputs. Both are now
+//! cached by pattern string. The caches are process-wide and bounded: once
+//! full, the oldest entry is evicted, so programs that build patterns
+//! dynamically cannot grow them without limit. Invalid regexes are never
+//! cached; their error is reported on every call.
+
+use crate::runtime::value_format::{parse_format_template, FormatPiece};
+use regex::Regex;
+use std::collections::{HashMap, VecDeque};
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+
+/// Compiled regexes kept before the oldest is evicted
+pub const REGEX_CACHE_CAPACITY: usize = 256;
+/// Parsed format templates kept before the oldest is evicted
+pub const FORMAT_CACHE_CAPACITY: usize = 512;
+
+/// String-keyed cache that evicts in insertion order once full
+#[derive(Debug)]
+pub struct BoundedCache<V> {
+    entries: HashMap<String, V>,
+    order: VecDeque<String>,
+    capacity: usize,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+/// Counters for one pattern cache
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PatternCacheStats {
+    /// Patterns currently cached
+    pub entries: usize,
+    /// Lookups answered from the cache
+    pub hits: u64,
+    /// Lookups that had to compile or parse the pattern
+    pub misses: u64,
+    /// Entries dropped to stay within capacity
+    pub evictions: u64,
+}
+
+impl<V: Clone> BoundedCache<V> {
+    /// Create an empty cache holding at most `capacity` entries
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+            capacity: capacity.max(1),
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+        }
+    }
+
+    /// Cached value for `key`, building and caching it on a miss
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from `build`; nothing is cached in that case.
+    pub fn get_or_try_insert_with<E>(
+        &mut self,
+        key: &str,
+        build: impl FnOnce() -> Result<V, E>,
+    ) -> Result<V, E> {
+        if let Some(value) = self.entries.get(key) {
+            self.hits += 1;
+            return Ok(value.clone());
+        }
+        self.misses += 1;
+        let value = build()?;
+        if self.entries.len() >= self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+                self.evictions += 1;
+            }
+        }
+        self.order.push_back(key.to_string());
+        self.entries.insert(key.to_string(), value.clone());
+        Ok(value)
+    }
+
+    /// Current counters
+    pub fn stats(&self) -> PatternCacheStats {
+        PatternCacheStats {
+            entries: self.entries.len(),
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+        }
+    }
+}
+
+fn regex_cache() -> &'static Mutex<BoundedCache<Regex>> {
+    static CACHE: OnceLock<Mutex<BoundedCache<Regex>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(BoundedCache::new(REGEX_CACHE_CAPACITY)))
+}
+
+fn format_cache() -> &'static Mutex<BoundedCache<Arc<[FormatPiece]>>> {
+    static CACHE: OnceLock<Mutex<BoundedCache<Arc<[FormatPiece]>>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(BoundedCache::new(FORMAT_CACHE_CAPACITY)))
+}
+
+/// Compiled regex for `pattern`, compiling it only on first use
+///
+/// Clones share the compiled program, so the returned `Regex` is cheap.
+///
+/// # Errors
+///
+/// Returns the compile error for an invalid pattern.
+pub fn cached_regex(pattern: &str) -> Result<Regex, regex::Error> {
+    regex_cache()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get_or_try_insert_with(pattern, || Regex::new(pattern))
+}
+
+/// Parsed pieces of a `format()`/`println!` template
+pub(crate) fn cached_format_template(template: &str) -> Arc<[FormatPiece]> {
+    format_cache()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get_or_try_insert_with(template, || {
+            Ok::<_, Infallible>(parse_format_template(template).into())
+        })
+        .unwrap_or_else(|never| match never {})
+}
+
+/// Regex cache counters
+pub fn regex_cache_stats() -> PatternCacheStats {
+    regex_cache()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .stats()
+}
+
+/// Format template cache counters
+pub fn format_cache_stats() -> PatternCacheStats {
+    format_cache()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .stats()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounded_cache_hits_and_evicts_oldest() {
+        let mut cache = BoundedCache::new(2);
+        let build = |n: usize| move || Ok::<_, ()>(n);
+        assert_eq!(cache.get_or_try_insert_with("a", build(1)), Ok(1));
+        assert_eq!(cache.get_or_try_insert_with("a", build(99)), Ok(1));
+        assert_eq!(cache.get_or_try_insert_with("b", build(2)), Ok(2));
+        assert_eq!(cache.get_or_try_insert_with("c", build(3)), Ok(3));
+        // "a" was evicted, so it is rebuilt
+        assert_eq!(cache.get_or_try_insert_with("a", build(4)), Ok(4));
+        let stats = cache.stats();
+        assert_eq!(stats.entries, 2);
+        assert_eq!((stats.hits, stats.misses, stats.evictions), (1, 4, 2));
+    }
+
+    #[test]
+    fn test_bounded_cache_does_not_cache_errors() {
+        let mut cache: BoundedCache<usize> = BoundedCache::new(4);
+        assert_eq!(cache.get_or_try_insert_with("x", || Err("bad")), Err("bad"));
+        assert_eq!(
+            cache.get_or_try_insert_with("x", || Ok::<_, &str>(1)),
+            Ok(1)
+        );
+        assert_eq!(cache.stats().entries, 1);
+    }
+
+    #[test]
+    fn test_cached_regex_reuses_compiled_pattern() {
+        // Unique pattern so parallel tests don't affect the counts
+        let pattern = r"pattern_cache_test_\d+";
+        let before = regex_cache_stats();
+        for i in 0..10 {
+            let re = cached_regex(pattern).expect("valid regex");
+            assert!(re.is_match(&format!("pattern_cache_test_{i}")));
+        }
+        let after = regex_cache_stats();
+        assert!(after.hits >= before.hits + 9, "{before:?} -> {after:?}");
+        assert!(cached_regex("(unclosed").is_err());
+    }
+}
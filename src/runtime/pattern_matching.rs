@@ -1,8 +1,8 @@
 //! Shared pattern matching utilities
 //! Extracted to reduce duplication across interpreter and REPL
 use crate::frontend::ast::{Literal, Pattern};
+use crate::runtime::eval_option_result::result_payload;
 use crate::runtime::Value;
-use std::collections::HashMap;
 
 #[cfg(test)]
 use std::sync::Arc;
@@ -148,6 +148,21 @@ pub fn match_pattern(pattern: &Pattern, value: &Value) -> Option<Vec<(String, Va
             // Mutability is handled at the environment binding level
             match_pattern(inner_pattern, value)
         }
+        Pattern::StringAffix {
+            prefix,
+            rest,
+            suffix,
+        } => {
+            let middle =
+                crate::runtime::eval_pattern_match::string_affix_rest(prefix, suffix, value)?;
+            match_pattern(rest, &middle)
+        }
+        Pattern::Regex(regex) => {
+            // An invalid regex matches nothing
+            crate::runtime::eval_pattern_match::try_match_regex_pattern(regex, value)
+                .ok()
+                .flatten()
+        }
     }
 }
 /// Check if two values are equal (for pattern matching)
@@ -324,64 +339,17 @@ fn match_none_pattern_helper(value: &Value) -> Option<Vec<(String, Value)>> {
     None
 }
 
-/// Helper for matching Ok patterns (complexity: 3)
+/// Helper for matching Ok patterns (complexity: 1)
 fn match_ok_pattern_helper(inner_pattern: &Pattern, value: &Value) -> Option<Vec<(String, Value)>> {
-    let fields = extract_object_fields(value)?;
-
-    if !is_ok_type(fields) {
-        return None;
-    }
-
-    let data = extract_ok_data(fields)?;
-    if data.is_empty() {
-        return None;
-    }
-
-    match_pattern(inner_pattern, &data[0])
+    result_payload(value, "Ok").and_then(|data| match_pattern(inner_pattern, data))
 }
 
-fn extract_object_fields(value: &Value) -> Option<&HashMap<String, Value>> {
-    if let Value::Object(fields) = value {
-        Some(fields)
-    } else {
-        None
-    }
-}
-
-fn is_ok_type(fields: &HashMap<String, Value>) -> bool {
-    if let Some(Value::String(type_str)) = fields.get("type") {
-        &**type_str == "Ok"
-    } else {
-        false
-    }
-}
-
-fn extract_ok_data(fields: &HashMap<String, Value>) -> Option<&[Value]> {
-    if let Some(Value::Array(data)) = fields.get("data") {
-        Some(data)
-    } else {
-        None
-    }
-}
-
-/// Helper for matching Err patterns (complexity: 6)
+/// Helper for matching Err patterns (complexity: 1)
 fn match_err_pattern_helper(
     inner_pattern: &Pattern,
     value: &Value,
 ) -> Option<Vec<(String, Value)>> {
-    // Err(x) creates an Object: {data: [x], __type: "Message", type: "Err"}
-    if let Value::Object(fields) = value {
-        if let Some(Value::String(type_str)) = fields.get("type") {
-            if &**type_str == "Err" {
-                if let Some(Value::Array(data)) = fields.get("data") {
-                    if !data.is_empty() {
-                        return match_pattern(inner_pattern, &data[0]);
-                    }
-                }
-            }
-        }
-    }
-    None
+    result_payload(value, "Err").and_then(|data| match_pattern(inner_pattern, data))
 }
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,509 @@
+//! Per-function profiling for the AST interpreter
+//!
+//! `ruchy runtime --profile file.ruchy` runs a script with the profiler
+//! active. Every named function call is recorded with:
+//!
+//! - its call count;
+//! - inclusive time, including callees (recursive activations are counted
+//!   once, from the outermost call);
+//! - exclusive time, excluding callees;
+//! - heap allocations made while it ran, inclusive and exclusive.
+//!
+//! A [`Profile`] renders as a text table, JSON, or folded stacks
+//! (`main;fib;fib 1234`, exclusive microseconds) for flamegraph tools such
+//! as `inferno-flamegraph` or `flamegraph.pl`. Code outside any function is
+//! attributed to [`TOP_LEVEL`].
+//!
+//! Allocations are counted by [`CountingAllocator`], which the `ruchy`
+//! binary installs as its global allocator; without it every count is zero.
+//! It also tracks live and peak heap bytes for [`heap_usage`].
+//!
+//! The active profile is process-global because scripts with a deadline
+//! evaluate on a worker thread. Calls cost one atomic load while no profile
+//! is active.
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::Instant;
+
+/// Frame for code that runs outside any function
+pub const TOP_LEVEL: &str = "<top-level>";
+
+/// Global allocator that counts allocations and heap bytes for the profiler
+///
+/// Install it in a binary to get allocation counts:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: ruchy::runtime::profiler::CountingAllocator =
+///     ruchy::runtime::profiler::CountingAllocator;
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CountingAllocator;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+static PEAK_BYTES: AtomicU64 = AtomicU64::new(0);
+
+fn grow(bytes: usize) {
+    let live = LIVE_BYTES.fetch_add(bytes as u64, Ordering::Relaxed) + bytes as u64;
+    PEAK_BYTES.fetch_max(live, Ordering::Relaxed);
+}
+
+fn shrink(bytes: usize) {
+    LIVE_BYTES.fetch_sub(bytes as u64, Ordering::Relaxed);
+}
+
+// SAFETY: every method forwards to `System` with the arguments it was given;
+// counting does not touch the memory.
+#[allow(unsafe_code)]
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        // SAFETY: the caller upholds `GlobalAlloc::alloc`'s contract for `layout`
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        // SAFETY: the caller upholds `GlobalAlloc::alloc_zeroed`'s contract for `layout`
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // SAFETY: `ptr` came from this allocator, which always allocates through
+        // `System`, and the caller upholds `GlobalAlloc::realloc`'s contract
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            shrink(layout.size());
+            grow(new_size);
+        }
+        new_ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: `ptr` was allocated by `System` (via this allocator) with `layout`
+        unsafe { System.dealloc(ptr, layout) };
+        shrink(layout.size());
+    }
+}
+
+/// Allocations counted by [`CountingAllocator`] since the process started
+pub fn allocation_count() -> u64 {
+    ALLOCATIONS.load(Ordering::Relaxed)
+}
+
+/// Process heap as seen by [`CountingAllocator`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HeapUsage {
+    /// Allocations since the process started
+    pub allocations: u64,
+    /// Bytes allocated and not yet freed
+    pub live_bytes: u64,
+    /// Highest `live_bytes` so far
+    pub peak_bytes: u64,
+}
+
+/// Current heap usage; all zero unless [`CountingAllocator`] is installed
+pub fn heap_usage() -> HeapUsage {
+    HeapUsage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        live_bytes: LIVE_BYTES.load(Ordering::Relaxed),
+        peak_bytes: PEAK_BYTES.load(Ordering::Relaxed),
+    }
+}
+
+/// Timings and allocations of one function
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct FunctionProfile {
+    /// Function name as called
+    pub name: String,
+    /// Completed calls
+    pub calls: u64,
+    /// Nanoseconds spent in the function and its callees
+    pub inclusive_ns: u64,
+    /// Nanoseconds spent in the function itself
+    pub exclusive_ns: u64,
+    /// Allocations made by the function and its callees
+    pub inclusive_allocations: u64,
+    /// Allocations made by the function itself
+    pub exclusive_allocations: u64,
+}
+
+/// Exclusive time of one call stack
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StackSample {
+    /// Frames from outermost to innermost, joined with `;`
+    pub stack: String,
+    /// Nanoseconds spent with exactly this stack
+    pub exclusive_ns: u64,
+}
+
+/// Result of a profiled run
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Profile {
+    /// Nanoseconds from [`start`] to [`finish`]
+    pub total_ns: u64,
+    /// Allocations from [`start`] to [`finish`]
+    pub total_allocations: u64,
+    /// Profiled functions, most exclusive time first
+    pub functions: Vec<FunctionProfile>,
+    /// Exclusive time per call stack, in stack order
+    pub stacks: Vec<StackSample>,
+}
+
+impl Profile {
+    /// Fold another run of the same program into this profile
+    pub fn merge(&mut self, other: Profile) {
+        self.total_ns += other.total_ns;
+        self.total_allocations += other.total_allocations;
+        for function in other.functions {
+            match self.functions.iter_mut().find(|f| f.name == function.name) {
+                Some(existing) => {
+                    existing.calls += function.calls;
+                    existing.inclusive_ns += function.inclusive_ns;
+                    existing.exclusive_ns += function.exclusive_ns;
+                    existing.inclusive_allocations += function.inclusive_allocations;
+                    existing.exclusive_allocations += function.exclusive_allocations;
+                }
+                None => self.functions.push(function),
+            }
+        }
+        for sample in other.stacks {
+            match self.stacks.iter_mut().find(|s| s.stack == sample.stack) {
+                Some(existing) => existing.exclusive_ns += sample.exclusive_ns,
+                None => self.stacks.push(sample),
+            }
+        }
+        sort_functions(&mut self.functions);
+        self.stacks.sort_by(|a, b| a.stack.cmp(&b.stack));
+    }
+
+    /// Table of functions by exclusive time
+    pub fn to_text(&self) -> String {
+        let mut out = String::from("=== Execution Profiling ===\n");
+        let _ = writeln!(
+            out,
+            "Total: {} ({} allocations)\n",
+            millis(self.total_ns),
+            self.total_allocations
+        );
+        let width = self
+            .functions
+            .iter()
+            .map(|f| f.name.len())
+            .chain(std::iter::once("function".len()))
+            .max()
+            .unwrap_or(0);
+        let _ = writeln!(
+            out,
+            "{:<width$}  {:>8}  {:>12}  {:>12}  {:>6}  {:>12}",
+            "function", "calls", "inclusive", "exclusive", "self%", "allocations"
+        );
+        for function in &self.functions {
+            let share = if self.total_ns == 0 {
+                0.0
+            } else {
+                function.exclusive_ns as f64 * 100.0 / self.total_ns as f64
+            };
+            let _ = writeln!(
+                out,
+                "{:<width$}  {:>8}  {:>12}  {:>12}  {:>5.1}%  {:>12}",
+                function.name,
+                function.calls,
+                millis(function.inclusive_ns),
+                millis(function.exclusive_ns),
+                share,
+                function.exclusive_allocations
+            );
+        }
+        out
+    }
+
+    /// Pretty-printed JSON of the whole profile
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// Folded stacks with exclusive microseconds, one stack per line
+    ///
+    /// Stacks that took less than a microsecond are left out.
+    pub fn to_folded(&self) -> String {
+        self.stacks
+            .iter()
+            .filter(|sample| sample.exclusive_ns >= 1_000)
+            .map(|sample| format!("{} {}\n", sample.stack, sample.exclusive_ns / 1_000))
+            .collect()
+    }
+}
+
+fn millis(ns: u64) -> String {
+    format!("{:.3}ms", ns as f64 / 1_000_000.0)
+}
+
+fn sort_functions(functions: &mut [FunctionProfile]) {
+    functions.sort_by(|a, b| {
+        b.exclusive_ns
+            .cmp(&a.exclusive_ns)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+}
+
+/// A call in progress
+struct Frame {
+    name: String,
+    started: Instant,
+    allocations_at_start: u64,
+    callee_ns: u64,
+    callee_allocations: u64,
+}
+
+impl Frame {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            started: Instant::now(),
+            allocations_at_start: allocation_count(),
+            callee_ns: 0,
+            callee_allocations: 0,
+        }
+    }
+}
+
+/// The profile being collected
+struct Profiler {
+    /// Calls in progress, [`TOP_LEVEL`] first
+    frames: Vec<Frame>,
+    functions: HashMap<String, FunctionProfile>,
+    stacks: HashMap<String, u64>,
+}
+
+impl Profiler {
+    fn new() -> Self {
+        Self {
+            frames: vec![Frame::new(TOP_LEVEL)],
+            functions: HashMap::new(),
+            stacks: HashMap::new(),
+        }
+    }
+
+    fn enter(&mut self, name: &str) {
+        self.frames.push(Frame::new(name));
+    }
+
+    /// Close the innermost frame, returning its inclusive time and allocations
+    fn exit(&mut self) -> Option<(u64, u64)> {
+        let stack = self.stack_key();
+        let frame = self.frames.pop()?;
+        let elapsed = u64::try_from(frame.started.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        let allocations = allocation_count().saturating_sub(frame.allocations_at_start);
+        let exclusive_ns = elapsed.saturating_sub(frame.callee_ns);
+        let exclusive_allocations = allocations.saturating_sub(frame.callee_allocations);
+        if let Some(caller) = self.frames.last_mut() {
+            caller.callee_ns += elapsed;
+            caller.callee_allocations += allocations;
+        }
+        *self.stacks.entry(stack).or_default() += exclusive_ns;
+        if frame.name != TOP_LEVEL {
+            let recursive = self.frames.iter().any(|f| f.name == frame.name);
+            let profile =
+                self.functions
+                    .entry(frame.name.clone())
+                    .or_insert_with(|| FunctionProfile {
+                        name: frame.name.clone(),
+                        ..FunctionProfile::default()
+                    });
+            profile.calls += 1;
+            profile.exclusive_ns += exclusive_ns;
+            profile.exclusive_allocations += exclusive_allocations;
+            if !recursive {
+                profile.inclusive_ns += elapsed;
+                profile.inclusive_allocations += allocations;
+            }
+        }
+        Some((elapsed, allocations))
+    }
+
+    fn stack_key(&self) -> String {
+        let names: Vec<&str> = self.frames.iter().map(|f| f.name.as_str()).collect();
+        names.join(";")
+    }
+
+    fn finish(mut self) -> Profile {
+        // Frames left open by a failed run end now
+        while self.frames.len() > 1 {
+            self.exit();
+        }
+        let (total_ns, total_allocations) = self.exit().unwrap_or_default();
+        let mut functions: Vec<FunctionProfile> = self.functions.into_values().collect();
+        sort_functions(&mut functions);
+        let mut stacks: Vec<StackSample> = self
+            .stacks
+            .into_iter()
+            .map(|(stack, exclusive_ns)| StackSample {
+                stack,
+                exclusive_ns,
+            })
+            .collect();
+        stacks.sort_by(|a, b| a.stack.cmp(&b.stack));
+        Profile {
+            total_ns,
+            total_allocations,
+            functions,
+            stacks,
+        }
+    }
+}
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static PROFILER: Mutex<Option<Profiler>> = Mutex::new(None);
+
+fn lock() -> MutexGuard<'static, Option<Profiler>> {
+    PROFILER.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Start profiling this process's interpreter, discarding any active profile
+pub fn start() {
+    *lock() = Some(Profiler::new());
+    ACTIVE.store(true, Ordering::Release);
+}
+
+/// Stop profiling and return what was collected, if a profile was active
+pub fn finish() -> Option<Profile> {
+    ACTIVE.store(false, Ordering::Release);
+    lock().take().map(Profiler::finish)
+}
+
+/// A call of `name` begins
+pub(crate) fn enter(name: &str) {
+    if ACTIVE.load(Ordering::Acquire) {
+        if let Some(profiler) = lock().as_mut() {
+            profiler.enter(name);
+        }
+    }
+}
+
+/// The innermost call ends
+pub(crate) fn exit() {
+    if ACTIVE.load(Ordering::Acquire) {
+        if let Some(profiler) = lock().as_mut() {
+            profiler.exit();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn function<'a>(profile: &'a Profile, name: &str) -> &'a FunctionProfile {
+        profile
+            .functions
+            .iter()
+            .find(|f| f.name == name)
+            .expect("function should be profiled")
+    }
+
+    #[test]
+    fn test_exclusive_time_excludes_callees() {
+        let mut profiler = Profiler::new();
+        profiler.enter("outer");
+        profiler.enter("inner");
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        profiler.exit();
+        profiler.exit();
+        let profile = profiler.finish();
+        let outer = function(&profile, "outer");
+        let inner = function(&profile, "inner");
+        assert!(outer.inclusive_ns >= inner.inclusive_ns);
+        assert!(outer.exclusive_ns < inner.exclusive_ns);
+        assert!(profile.total_ns >= outer.inclusive_ns);
+        assert_eq!(profile.functions[0].name, "inner");
+        let stacks: Vec<&str> = profile.stacks.iter().map(|s| s.stack.as_str()).collect();
+        assert_eq!(
+            stacks,
+            [TOP_LEVEL, "<top-level>;outer", "<top-level>;outer;inner"]
+        );
+    }
+
+    #[test]
+    fn test_recursion_counts_inclusive_time_once() {
+        let mut profiler = Profiler::new();
+        profiler.enter("fact");
+        profiler.enter("fact");
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        profiler.exit();
+        profiler.exit();
+        let profile = profiler.finish();
+        let fact = function(&profile, "fact");
+        assert_eq!(fact.calls, 2);
+        assert!(fact.inclusive_ns <= profile.total_ns);
+        assert!(fact.exclusive_ns <= fact.inclusive_ns);
+    }
+
+    #[test]
+    fn test_finish_closes_frames_left_open() {
+        let mut profiler = Profiler::new();
+        profiler.enter("fails");
+        let profile = profiler.finish();
+        assert_eq!(function(&profile, "fails").calls, 1);
+    }
+
+    #[test]
+    fn test_merge_sums_runs() {
+        let run = || {
+            let mut profiler = Profiler::new();
+            profiler.enter("step");
+            profiler.exit();
+            profiler.finish()
+        };
+        let mut profile = run();
+        profile.merge(run());
+        assert_eq!(function(&profile, "step").calls, 2);
+        assert_eq!(profile.stacks.len(), 2);
+    }
+
+    #[test]
+    fn test_renderings() {
+        let profile = Profile {
+            total_ns: 4_000_000,
+            total_allocations: 12,
+            functions: vec![FunctionProfile {
+                name: "work".to_string(),
+                calls: 3,
+                inclusive_ns: 3_000_000,
+                exclusive_ns: 2_000_000,
+                inclusive_allocations: 10,
+                exclusive_allocations: 7,
+            }],
+            stacks: vec![
+                StackSample {
+                    stack: "<top-level>;work".to_string(),
+                    exclusive_ns: 2_000_000,
+                },
+                StackSample {
+                    stack: "<top-level>".to_string(),
+                    exclusive_ns: 500,
+                },
+            ],
+        };
+        let text = profile.to_text();
+        assert!(text.contains("Total: 4.000ms (12 allocations)"), "{text}");
+        assert!(text.contains("work"), "{text}");
+        assert!(text.contains("50.0%"), "{text}");
+        assert_eq!(profile.to_folded(), "<top-level>;work 2000\n");
+        let json: serde_json::Value = serde_json::from_str(&profile.to_json()).unwrap();
+        assert_eq!(json["functions"][0]["exclusive_allocations"], 7);
+    }
+}
@@ -0,0 +1,245 @@
+//! Structured records between Ruchy processes (`--input json` / `--output json`)
+//!
+//! Scripts chained in a shell pipeline can pass JSON lines instead of raw
+//! text:
+//!
+//! ```text
+//! ruchy extract.ruchy --output json | ruchy enrich.ruchy --input json --output json
+//! ```
+//!
+//! `io::records()` is a lazy iterator over stdin. With `--input json` each
+//! non-blank line is parsed as one JSON value (usually an object); with the
+//! default text input each line is yielded as a string. `emit(value)` writes
+//! one record to stdout: compact single-line JSON with `--output json`, the
+//! display form otherwise (strings without quotes). With `--output json` the
+//! value echoed by `ruchy -e` is printed as JSON as well.
+//!
+//! The formats are process-wide, like the script deadline: the CLI sets them
+//! once with [`set_stream_formats`] before running the script.
+
+use crate::runtime::eval_iterator::LazyIter;
+use crate::runtime::eval_json::{serde_to_value, value_to_serde};
+use crate::runtime::validation::validate_arg_count;
+use crate::runtime::value_format::format_value_display;
+use crate::runtime::{InterpreterError, Value};
+use std::io::BufRead;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// How records are encoded on stdin or stdout
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StreamFormat {
+    /// One line of text per record
+    #[default]
+    Text,
+    /// One JSON value per line (JSON Lines)
+    Json,
+}
+
+impl FromStr for StreamFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" | "jsonl" => Ok(Self::Json),
+            _ => Err(format!(
+                "Unknown stream format '{s}' (expected text or json)"
+            )),
+        }
+    }
+}
+
+static JSON_INPUT: AtomicBool = AtomicBool::new(false);
+static JSON_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+/// Set the formats used by `io::records()`, `emit()` and script results
+pub fn set_stream_formats(input: StreamFormat, output: StreamFormat) {
+    JSON_INPUT.store(input == StreamFormat::Json, Ordering::Relaxed);
+    JSON_OUTPUT.store(output == StreamFormat::Json, Ordering::Relaxed);
+}
+
+/// Current stdin record format
+pub fn input_format() -> StreamFormat {
+    if JSON_INPUT.load(Ordering::Relaxed) {
+        StreamFormat::Json
+    } else {
+        StreamFormat::Text
+    }
+}
+
+/// Current stdout record format
+pub fn output_format() -> StreamFormat {
+    if JSON_OUTPUT.load(Ordering::Relaxed) {
+        StreamFormat::Json
+    } else {
+        StreamFormat::Text
+    }
+}
+
+/// Decode one input line; `None` for lines that carry no record
+///
+/// # Errors
+///
+/// Returns an error naming the line when JSON input does not parse.
+pub fn parse_record(
+    line: &str,
+    format: StreamFormat,
+    nr: usize,
+) -> Result<Option<Value>, InterpreterError> {
+    match format {
+        StreamFormat::Text => Ok(Some(Value::from_string(line.to_string()))),
+        StreamFormat::Json if line.trim().is_empty() => Ok(None),
+        StreamFormat::Json => {
+            let json: serde_json::Value = serde_json::from_str(line).map_err(|e| {
+                InterpreterError::RuntimeError(format!("Invalid JSON record on line {nr}: {e}"))
+            })?;
+            serde_to_value(&json).map(Some)
+        }
+    }
+}
+
+/// Encode one output record, without the trailing newline
+///
+/// # Errors
+///
+/// Returns an error when JSON output is requested for a value that has no
+/// JSON form (functions, iterators, non-finite floats, ...).
+pub fn format_record(value: &Value, format: StreamFormat) -> Result<String, InterpreterError> {
+    match format {
+        StreamFormat::Text => Ok(format_value_display(value)),
+        StreamFormat::Json => {
+            let json = value_to_serde(value)?;
+            serde_json::to_string(&json).map_err(|e| {
+                InterpreterError::RuntimeError(format!("Cannot encode record as JSON: {e}"))
+            })
+        }
+    }
+}
+
+/// Lazy source behind `io::records()`, reading stdin one line per pull
+#[derive(Debug, Clone, Default)]
+pub struct RecordSource {
+    format: StreamFormat,
+    nr: usize,
+}
+
+impl RecordSource {
+    /// Read stdin in the current input format
+    pub fn stdin() -> Self {
+        Self {
+            format: input_format(),
+            nr: 0,
+        }
+    }
+
+    /// Next record from stdin, skipping lines that carry none
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when stdin cannot be read or a record does not parse.
+    pub fn next_record(&mut self) -> Result<Option<Value>, InterpreterError> {
+        loop {
+            let Some(line) = crate::runtime::replay_trace::stdin_line(read_stdin_line)? else {
+                return Ok(None);
+            };
+            self.nr += 1;
+            if let Some(record) = parse_record(&line, self.format, self.nr)? {
+                return Ok(Some(record));
+            }
+        }
+    }
+}
+
+/// One line of stdin without its line ending, `None` at end of input
+fn read_stdin_line() -> Result<Option<String>, InterpreterError> {
+    let mut line = String::new();
+    let read = std::io::stdin()
+        .lock()
+        .read_line(&mut line)
+        .map_err(|e| InterpreterError::RuntimeError(format!("Failed to read stdin: {e}")))?;
+    if read == 0 {
+        return Ok(None);
+    }
+    Ok(Some(line.trim_end_matches(['\n', '\r']).to_string()))
+}
+
+/// `io::records()` - lazy iterator over stdin records
+///
+/// # Errors
+///
+/// Returns an error when called with arguments.
+pub fn eval_records(args: &[Value]) -> Result<Value, InterpreterError> {
+    validate_arg_count("io::records", args, 0)?;
+    Ok(LazyIter::Records(RecordSource::stdin()).into_value())
+}
+
+/// `emit(value)` - write one record to stdout
+///
+/// A closed stdout (e.g. `| head`) is not an error; the record is dropped.
+///
+/// # Errors
+///
+/// Returns an error for the wrong number of arguments or a value that cannot
+/// be encoded.
+pub fn eval_emit(args: &[Value]) -> Result<Value, InterpreterError> {
+    validate_arg_count("emit", args, 1)?;
+    let record = format_record(&args[0], output_format())?;
+    match crate::runtime::output::write(&format!("{record}\n")) {
+        Err(e) if e.kind() != std::io::ErrorKind::BrokenPipe => Err(
+            InterpreterError::RuntimeError(format!("Failed to write record: {e}")),
+        ),
+        _ => Ok(Value::Nil),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_parse_record_by_format() {
+        let record = parse_record(r#"{"id": 7, "tags": ["a"]}"#, StreamFormat::Json, 1)
+            .unwrap()
+            .expect("record");
+        let Value::Object(fields) = record else {
+            panic!("expected object, got {record:?}");
+        };
+        assert_eq!(fields["id"], Value::Integer(7));
+        assert_eq!(parse_record("  ", StreamFormat::Json, 2).unwrap(), None);
+        let err = parse_record("{\"x\": 1", StreamFormat::Json, 3).unwrap_err();
+        assert!(
+            err.to_string().contains("Invalid JSON record on line 3"),
+            "{err}"
+        );
+        assert_eq!(
+            parse_record("plain", StreamFormat::Text, 4).unwrap(),
+            Some(Value::from_string("plain".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_format_record_by_format() {
+        let mut fields = HashMap::new();
+        fields.insert("name".to_string(), Value::from_string("ann".to_string()));
+        let record = Value::Object(Arc::new(fields));
+        assert_eq!(
+            format_record(&record, StreamFormat::Json).unwrap(),
+            r#"{"name":"ann"}"#
+        );
+        assert_eq!(
+            format_record(&Value::from_string("hi".to_string()), StreamFormat::Text).unwrap(),
+            "hi"
+        );
+        assert!(format_record(&Value::Float(f64::NAN), StreamFormat::Json).is_err());
+    }
+
+    #[test]
+    fn test_stream_format_from_str() {
+        assert_eq!("json".parse(), Ok(StreamFormat::Json));
+        assert_eq!("text".parse(), Ok(StreamFormat::Text));
+        assert!("xml".parse::<StreamFormat>().is_err());
+    }
+}
@@ -2,6 +2,10 @@
 //!
 //! Handles REPL commands like :help, :quit, :mode, etc.
 
+use super::definitions::Undefined;
+use super::explorer::ObjectExplorer;
+use super::formatting::format_ast;
+use super::introspect;
 use super::state::{ReplMode, ReplState};
 use anyhow::Result;
 
@@ -95,6 +99,15 @@ impl CommandRegistry {
                 // Execute AST command
                 self.execute_ast_command(&expr, context)
             }
+            ":rust" => {
+                if context.args.is_empty() {
+                    return Ok(CommandResult::Success(
+                        "Usage: :rust <expression>".to_string(),
+                    ));
+                }
+                let expr = context.args.join(" ");
+                Ok(CommandResult::Success(Self::execute_rust_command(&expr)))
+            }
             ":mode" => {
                 if let Some(&mode_arg) = context.args.first() {
                     match mode_arg {
@@ -121,11 +134,17 @@ impl CommandRegistry {
                     Ok(CommandResult::Success(format!("Current mode: {current:?}")))
                 }
             }
+            ":explore" => Ok(CommandResult::Success(
+                self.execute_explore_command(context),
+            )),
             ":history" => Ok(CommandResult::Success(self.format_history(context.state))),
             ":vars" => Ok(CommandResult::Success(self.format_bindings(context.state))),
             ":env" => Ok(CommandResult::Success(
                 self.format_environment(context.state),
             )),
+            ":who" => Ok(CommandResult::Success(self.execute_who_command(context))),
+            ":undef" => Ok(CommandResult::Success(self.execute_undef_command(context))),
+            ":memory" => Ok(CommandResult::Success(self.execute_memory_command(context))),
             _ => Ok(CommandResult::Success(format!(
                 "Unknown command: {command}"
             ))),
@@ -136,7 +155,8 @@ impl CommandRegistry {
     pub fn available_commands(&self) -> Vec<&'static str> {
         vec![
             ":help", ":h", ":quit", ":exit", ":q", ":clear", ":reset", ":mode", ":history",
-            ":vars", ":env", ":type", ":inspect", ":ast",
+            ":vars", ":env", ":who", ":undef", ":memory", ":save", ":load", ":type", ":inspect",
+            ":explore", ":ast", ":rust",
         ]
     }
 
@@ -151,11 +171,20 @@ impl CommandRegistry {
   :history           Show command history
   :vars              Show variable bindings
   :env               Show comprehensive environment info
-  :type <expr>       Show type of expression
-  :inspect <expr>    Detailed inspection of value
+  :who               Show user definitions, marking stdlib names they replace
+  :undef <name>      Remove a user definition, restoring any stdlib original
+  :memory            Show memory held by user definitions, largest first
+  :save <file.rsn>   Save definitions, variables and history to a file
+  :load <file.rsn>   Replay a saved session into this one
+  :type <expr>       Show the inferred type of an expression, without running it
+  :inspect <expr>    Detailed inspection of value (opens explorer)
+  :explore [cmd]     Navigate inspected value: <key|index>, up, next, prev,
+                     page <n>, find <text>, json, copy, keys, close
   :ast <expr>        Show AST structure
+  :rust <expr>       Show the Rust code an expression transpiles to
 
-Enter expressions to evaluate them.
+Enter expressions to evaluate them. Redefining a name replaces it, and
+functions defined earlier see the new definition.
 "
         .to_string()
     }
@@ -219,39 +248,83 @@ Enter expressions to evaluate them.
         output
     }
 
-    /// Execute :type command to show type of expression (complexity: 6)
+    /// Execute :who to list the user's global definitions (complexity: 4)
+    fn execute_who_command(&self, context: &mut CommandContext) -> String {
+        let Some(evaluator) = context.evaluator.as_deref() else {
+            return "Error: Evaluator not available".to_string();
+        };
+        let bindings = evaluator.user_bindings();
+        if bindings.is_empty() {
+            return "No user definitions".to_string();
+        }
+        bindings
+            .iter()
+            .map(|(name, value)| {
+                let type_name = Self::value_type_name(value);
+                let note = if evaluator.shadows_stdlib(name) {
+                    "  (replaces stdlib)"
+                } else {
+                    ""
+                };
+                format!("{name}: {type_name} = {value}{note}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Execute :memory to show what the user's definitions hold (complexity: 2)
+    fn execute_memory_command(&self, context: &mut CommandContext) -> String {
+        let Some(evaluator) = context.evaluator.as_deref() else {
+            return "Error: Evaluator not available".to_string();
+        };
+        evaluator.memory_report().to_text()
+    }
+
+    /// Execute :undef to remove a user definition (complexity: 6)
+    fn execute_undef_command(&self, context: &mut CommandContext) -> String {
+        let [name] = context.args.as_slice() else {
+            return "Usage: :undef <name>".to_string();
+        };
+        let name = *name;
+        let Some(evaluator) = context.evaluator.as_deref_mut() else {
+            return "Error: Evaluator not available".to_string();
+        };
+        match evaluator.undefine(name, context.state) {
+            Undefined::Removed => format!("Removed '{name}'"),
+            Undefined::Restored => format!("Restored the standard library '{name}'"),
+            Undefined::NotUserDefined => {
+                format!("'{name}' is defined by the standard library, not by you")
+            }
+            Undefined::NotDefined => format!("'{name}' is not defined"),
+        }
+    }
+
+    /// Execute :type command to show the inferred type of expression (complexity: 4)
+    ///
+    /// The expression is type-checked against the session's definitions,
+    /// not evaluated, so it has no side effects.
     fn execute_type_command(
         &self,
         expr: &str,
         context: &mut CommandContext,
     ) -> Result<CommandResult> {
-        use super::EvalResult;
+        use crate::frontend::Parser;
 
-        // Get evaluator from context or create a new one
-        let evaluator = match &mut context.evaluator {
-            Some(eval) => eval,
-            None => {
-                return Ok(CommandResult::Success(
-                    "Error: Evaluator not available".to_string(),
-                ))
-            }
+        let Some(evaluator) = context.evaluator.as_deref() else {
+            return Ok(CommandResult::Success(
+                "Error: Evaluator not available".to_string(),
+            ));
         };
-
-        // Evaluate the expression to get its value
-        match evaluator.evaluate_line(expr, context.state) {
-            Ok(EvalResult::Value(value)) => {
-                // Get type name from value
-                let type_name = Self::value_type_name(&value);
-                Ok(CommandResult::Success(format!("Type: {type_name}")))
-            }
-            Ok(EvalResult::Error(msg)) => Ok(CommandResult::Success(format!(
-                "Error evaluating expression: {msg}"
-            ))),
-            Ok(EvalResult::NeedMoreInput) => {
-                Ok(CommandResult::Success("Incomplete expression".to_string()))
-            }
-            Err(e) => Ok(CommandResult::Success(format!("Error: {e}"))),
-        }
+        let ast = match Parser::new(expr).parse() {
+            Ok(ast) => ast,
+            Err(e) => return Ok(CommandResult::Success(format!("Parse error: {e}"))),
+        };
+        let inputs = context.state.get_inputs();
+        let output = match introspect::infer_type(&ast, inputs, &evaluator.user_bindings()) {
+            Ok(ty) => format!("Type: {ty}"),
+            Err(e) => format!("Type error: {e}"),
+        };
+        Ok(CommandResult::Success(output))
     }
 
     /// Execute :inspect command to show detailed value info (complexity: 8)
@@ -276,7 +349,13 @@ Enter expressions to evaluate them.
         match evaluator.evaluate_line(expr, context.state) {
             Ok(EvalResult::Value(value)) => {
                 // Generate detailed inspection output
-                let inspection = Self::inspect_value(&value);
+                let mut inspection = Self::inspect_value(&value);
+                let explorer = ObjectExplorer::new(value);
+                if explorer.is_expandable() {
+                    inspection.push('\n');
+                    inspection.push_str(&explorer.render());
+                }
+                context.state.set_explorer(Some(explorer));
                 Ok(CommandResult::Success(inspection))
             }
             Ok(EvalResult::Error(msg)) => Ok(CommandResult::Success(format!(
@@ -289,6 +368,73 @@ Enter expressions to evaluate them.
         }
     }
 
+    /// Execute :explore navigation on the last inspected value (complexity: 10)
+    fn execute_explore_command(&self, context: &mut CommandContext) -> String {
+        let Some(explorer) = context.state.explorer_mut() else {
+            return "No value under inspection. Use :inspect <expression> first".to_string();
+        };
+        let arg = context
+            .args
+            .get(1..)
+            .map(|rest| rest.join(" "))
+            .unwrap_or_default();
+        let command = context.args.first().copied();
+        if let Some(report) =
+            command.and_then(|command| Self::explore_report(explorer, command, &arg))
+        {
+            return report;
+        }
+        let outcome = match command {
+            None => Ok(()),
+            Some("up" | "..") => explorer
+                .up()
+                .then_some(())
+                .ok_or_else(|| "Already at root".to_string()),
+            Some("next" | "n") => explorer
+                .next_page()
+                .then_some(())
+                .ok_or_else(|| "Already on last page".to_string()),
+            Some("prev" | "p") => explorer
+                .prev_page()
+                .then_some(())
+                .ok_or_else(|| "Already on first page".to_string()),
+            Some("page") => arg
+                .parse::<usize>()
+                .map_err(|_| "Usage: :explore page <n>".to_string())
+                .and_then(|page| explorer.goto_page(page)),
+            Some("keys") => explorer
+                .browse()
+                .map_err(|e| format!("Keyboard navigation unavailable: {e}")),
+            Some("close") => {
+                context.state.set_explorer(None);
+                return "Explorer closed".to_string();
+            }
+            Some(selector) => explorer.enter(selector),
+        };
+        match outcome {
+            Ok(()) => explorer.render(),
+            Err(msg) => msg,
+        }
+    }
+
+    /// Output of the :explore commands that report on the value rather than move (complexity: 6)
+    fn explore_report(explorer: &ObjectExplorer, command: &str, arg: &str) -> Option<String> {
+        Some(match command {
+            "find" if arg.is_empty() => "Usage: :explore find <text>".to_string(),
+            "find" => {
+                let hits = explorer.find(arg);
+                if hits.is_empty() {
+                    format!("No matches for '{arg}'")
+                } else {
+                    hits.join("\n")
+                }
+            }
+            "json" => explorer.to_json().unwrap_or_else(|msg| msg),
+            "copy" => explorer.copy_json(),
+            _ => return None,
+        })
+    }
+
     /// Execute :ast command to show AST structure (complexity: 4)
     fn execute_ast_command(
         &self,
@@ -300,12 +446,20 @@ Enter expressions to evaluate them.
         // Parse the expression to get AST
         let mut parser = Parser::new(expr);
         match parser.parse() {
+            Ok(ast) => Ok(CommandResult::Success(format_ast(&ast))),
+            Err(e) => Ok(CommandResult::Success(format!("Parse error: {e}"))),
+        }
+    }
+
+    /// Execute :rust command to show the transpiled Rust (complexity: 3)
+    fn execute_rust_command(expr: &str) -> String {
+        use crate::frontend::Parser;
+
+        match Parser::new(expr).parse() {
             Ok(ast) => {
-                // Format AST as debug output
-                let ast_output = format!("{ast:#?}");
-                Ok(CommandResult::Success(ast_output))
+                introspect::rust_source(&ast).unwrap_or_else(|e| format!("Transpile error: {e}"))
             }
-            Err(e) => Ok(CommandResult::Success(format!("Parse error: {e}"))),
+            Err(e) => format!("Parse error: {e}"),
         }
     }
 
@@ -322,6 +476,14 @@ Enter expressions to evaluate them.
             Value::Integer(n) => {
                 output.push_str(&format!("Value: {n}\n"));
             }
+            Value::BigInt(n) => {
+                output.push_str(&format!("Value: {n}\n"));
+                output.push_str(&format!("Bits: {}\n", n.bits()));
+            }
+            Value::Decimal(d) => {
+                output.push_str(&format!("Value: {d}\n"));
+                output.push_str(&format!("Scale: {}\n", d.scale()));
+            }
             Value::Float(f) => {
                 output.push_str(&format!("Value: {f}\n"));
             }
@@ -430,6 +592,21 @@ Enter expressions to evaluate them.
             Value::Atom(s) => {
                 output.push_str(&format!("Atom: :{s}\n"));
             }
+            Value::Iterator(_) => {
+                output.push_str("Type: Iterator\n");
+                output.push_str("(lazy, not yet evaluated)\n");
+            }
+            Value::Error {
+                kind,
+                message,
+                stack,
+            } => {
+                output.push_str(&format!("Type: Error ({kind})\n"));
+                output.push_str(&format!("Message: {message}\n"));
+                for frame in stack.iter() {
+                    output.push_str(&format!("  at {frame}\n"));
+                }
+            }
         }
 
         output
@@ -442,6 +619,8 @@ Enter expressions to evaluate them.
 
         match value {
             Value::Integer(_) => size_of::<i64>(),
+            Value::BigInt(n) => size_of::<num_bigint::BigInt>() + n.bits().div_ceil(8) as usize,
+            Value::Decimal(_) => size_of::<rust_decimal::Decimal>(),
             Value::Float(_) => size_of::<f64>(),
             Value::Bool(_) => size_of::<bool>(),
             Value::Byte(_) => size_of::<u8>(),
@@ -532,14 +711,27 @@ Enter expressions to evaluate them.
             #[cfg(not(target_arch = "wasm32"))]
             Value::HtmlElement(_) => 64, // Estimated HTML element overhead
             Value::Atom(s) => std::mem::size_of::<Value>() + s.len(),
+            Value::Iterator(_) => 64, // Estimated pipeline overhead
+            Value::Error {
+                kind,
+                message,
+                stack,
+            } => {
+                size_of::<Value>()
+                    + kind.len()
+                    + message.len()
+                    + stack.iter().map(String::len).sum::<usize>()
+            }
         }
     }
 
     /// Get human-readable type name from Value (complexity: 10)
-    fn value_type_name(value: &super::Value) -> &'static str {
+    pub(super) fn value_type_name(value: &super::Value) -> &'static str {
         use super::Value;
         match value {
             Value::Integer(_) => "Integer",
+            Value::BigInt(_) => "BigInt",
+            Value::Decimal(_) => "Decimal",
             Value::Float(_) => "Float",
             Value::Bool(_) => "Bool",
             Value::Byte(_) => "Byte",
@@ -561,6 +753,8 @@ Enter expressions to evaluate them.
             #[cfg(not(target_arch = "wasm32"))]
             Value::HtmlElement(_) => "HtmlElement",
             Value::Atom(_) => "Atom",
+            Value::Error { .. } => "Error",
+            Value::Iterator(_) => "Iterator",
         }
     }
 }
@@ -834,6 +1028,59 @@ mod tests {
         );
     }
 
+    // Explore command - no active session
+    #[test]
+    fn test_explore_command_without_inspect() {
+        let registry = CommandRegistry::new();
+        let mut state = ReplState::new();
+        let mut context = CommandContext {
+            evaluator: None,
+            args: vec!["up"],
+            state: &mut state,
+        };
+
+        let result = registry
+            .execute(":explore", &mut context)
+            .expect("should succeed");
+        assert!(matches!(result, CommandResult::Success(s) if s.contains(":inspect")));
+    }
+
+    // Explore command - navigation over an active session
+    #[test]
+    fn test_explore_command_navigation() {
+        let registry = CommandRegistry::new();
+        let mut state = ReplState::new();
+        let mut fields = HashMap::new();
+        fields.insert(
+            "items".to_string(),
+            Value::from_array(vec![Value::Integer(1), Value::Integer(2)]),
+        );
+        state.set_explorer(Some(ObjectExplorer::new(Value::Object(Arc::new(fields)))));
+
+        for (args, expected) in [
+            (vec!["items"], "$.items : Array(len 2)"),
+            (vec!["find", "2"], "$.items[1] = 2"),
+            (vec!["up"], "$ : Object(1 keys)"),
+            (vec!["json"], "\"items\""),
+            (vec!["missing"], "No child 'missing'"),
+            (vec!["close"], "Explorer closed"),
+        ] {
+            let mut context = CommandContext {
+                evaluator: None,
+                args,
+                state: &mut state,
+            };
+            let result = registry
+                .execute(":explore", &mut context)
+                .expect("should succeed");
+            assert!(
+                matches!(&result, CommandResult::Success(s) if s.contains(expected)),
+                "expected {expected:?}, got {result:?}"
+            );
+        }
+        assert!(state.explorer_mut().is_none());
+    }
+
     // AST command - no args
     #[test]
     fn test_ast_command_no_args() {
@@ -1134,6 +1381,7 @@ mod tests {
         assert!(commands.contains(&":type"));
         assert!(commands.contains(&":inspect"));
         assert!(commands.contains(&":ast"));
+        assert!(commands.contains(&":rust"));
     }
 
     // Default impl
@@ -33,6 +33,8 @@ impl CompletionEngine {
             ":mode",
             ":debug",
             ":ast",
+            ":rust",
+            ":type",
             ":transpile",
             ":bench",
             ":load",
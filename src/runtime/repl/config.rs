@@ -2,6 +2,7 @@
 //!
 //! Configuration options for the Ruchy REPL.
 
+use crate::runtime::restricted::Limits;
 use std::time::Duration;
 
 /// REPL configuration
@@ -9,12 +10,61 @@ use std::time::Duration;
 pub struct ReplConfig {
     /// Maximum memory limit in bytes
     pub max_memory: usize,
-    /// Execution timeout
+    /// Execution timeout, enforced per evaluation in restricted mode
     pub timeout: Duration,
+    /// Deadline of each evaluation outside restricted mode; `None` runs to
+    /// completion (see [`Self::with_timeout`])
+    pub deadline: Option<Duration>,
     /// Maximum recursion depth
     pub maxdepth: usize,
     /// Debug mode flag
     pub debug: bool,
+    /// Restricted mode: no filesystem, process, environment or network
+    /// access, `timeout` enforced per evaluation, capped output and host
+    /// paths hidden in errors (see [`crate::runtime::restricted`])
+    pub restricted: bool,
+}
+
+/// Maximum bytes a restricted evaluation may print or return
+pub const RESTRICTED_MAX_OUTPUT: usize = 16 * 1024;
+
+impl ReplConfig {
+    /// Preset for untrusted users: shared web terminals, classrooms and the
+    /// playground's server-assisted mode
+    pub fn restricted() -> Self {
+        Self {
+            max_memory: 256 * 1024,               // 256KB
+            timeout: Duration::from_millis(2000), // 2 seconds per evaluation
+            deadline: None,
+            maxdepth: 64,
+            debug: false,
+            restricted: true,
+        }
+    }
+
+    /// Bound each evaluation by `timeout`, as resolved from `--timeout`,
+    /// `RUCHY_TIMEOUT` or `Ruchy.toml` by
+    /// [`crate::runtime::script::configured_timeout`]
+    ///
+    /// In restricted mode `timeout` replaces the time budget, and `None`
+    /// keeps it: untrusted input always runs under one.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        match timeout {
+            Some(timeout) if self.restricted => self.timeout = timeout,
+            _ if self.restricted => {}
+            _ => self.deadline = timeout,
+        }
+        self
+    }
+
+    /// Limits applied to each evaluation, or `None` when unrestricted
+    pub fn limits(&self) -> Option<Limits> {
+        self.restricted.then_some(Limits {
+            timeout: self.timeout,
+            max_output: RESTRICTED_MAX_OUTPUT,
+        })
+    }
 }
 
 impl Default for ReplConfig {
@@ -22,8 +72,10 @@ impl Default for ReplConfig {
         Self {
             max_memory: 1024 * 1024,              // 1MB
             timeout: Duration::from_millis(5000), // 5 seconds
+            deadline: None,
             maxdepth: 100,
             debug: false,
+            restricted: false,
         }
     }
 }
@@ -39,6 +91,37 @@ mod tests {
         assert_eq!(config.timeout, Duration::from_millis(5000));
         assert_eq!(config.maxdepth, 100);
         assert!(!config.debug);
+        assert!(!config.restricted);
+        assert_eq!(config.deadline, None);
+        assert_eq!(config.limits(), None);
+    }
+
+    #[test]
+    fn test_with_timeout_sets_deadline_or_restricted_budget() {
+        let second = Some(Duration::from_secs(1));
+        assert_eq!(ReplConfig::default().with_timeout(second).deadline, second);
+        let restricted = ReplConfig::restricted().with_timeout(second);
+        assert_eq!(restricted.timeout, Duration::from_secs(1));
+        assert_eq!(restricted.deadline, None);
+        assert_eq!(
+            ReplConfig::restricted().with_timeout(None).timeout,
+            ReplConfig::restricted().timeout
+        );
+    }
+
+    #[test]
+    fn test_repl_restricted_config() {
+        let config = ReplConfig::restricted();
+        assert!(config.restricted);
+        assert!(config.timeout < ReplConfig::default().timeout);
+        assert!(config.maxdepth < ReplConfig::default().maxdepth);
+        assert_eq!(
+            config.limits(),
+            Some(Limits {
+                timeout: config.timeout,
+                max_output: RESTRICTED_MAX_OUTPUT,
+            })
+        );
     }
 
     #[test]
@@ -46,8 +129,10 @@ mod tests {
         let config = ReplConfig {
             max_memory: 2048,
             timeout: Duration::from_secs(10),
+            deadline: None,
             maxdepth: 50,
             debug: true,
+            restricted: false,
         };
         let cloned = config.clone();
         assert_eq!(cloned.max_memory, 2048);
@@ -129,6 +214,8 @@ mod tests {
             timeout: Duration::from_millis(500), // 500ms timeout
             maxdepth: 25,                        // Low recursion
             debug: false,
+            restricted: false,
+            deadline: None,
         };
         assert_eq!(config.max_memory, 256 * 1024);
         assert_eq!(config.timeout, Duration::from_millis(500));
@@ -144,6 +231,8 @@ mod tests {
             timeout: Duration::from_secs(60), // 60s timeout
             maxdepth: 500,                    // Higher recursion
             debug: false,
+            restricted: false,
+            deadline: None,
         };
         assert_eq!(config.max_memory, 10 * 1024 * 1024);
         assert_eq!(config.timeout, Duration::from_secs(60));
@@ -159,6 +248,8 @@ mod tests {
             timeout: Duration::from_secs(120), // 2 minute timeout for debugging
             maxdepth: 100,
             debug: true,
+            restricted: false,
+            deadline: None,
         };
         assert_eq!(config.max_memory, 2 * 1024 * 1024);
         assert_eq!(config.timeout, Duration::from_secs(120));
@@ -241,6 +332,8 @@ mod tests {
             timeout: Duration::MAX,
             maxdepth: usize::MAX,
             debug: true,
+            restricted: false,
+            deadline: None,
         };
         assert_eq!(config.max_memory, usize::MAX);
         assert_eq!(config.timeout, Duration::MAX);
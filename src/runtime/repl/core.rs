@@ -5,17 +5,24 @@
 
 use anyhow::{Context, Result};
 use rustyline::error::ReadlineError;
-use rustyline::{Config, DefaultEditor};
-use std::path::PathBuf;
+use rustyline::history::DefaultHistory;
+use rustyline::{Config, Editor};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
 use super::commands::{CommandContext, CommandRegistry, CommandResult};
 use super::completion::CompletionEngine;
 use super::config::ReplConfig;
 use super::evaluation::{EvalResult, Evaluator};
-use super::formatting::format_error;
+use super::formatting::{format_ast, format_error, format_warning};
+use super::input::{is_complete, InputHelper};
+use super::introspect;
 use super::state::{ReplMode, ReplState};
+use super::workspace::{self, Restored, Workspace};
 use crate::runtime::interpreter::Value;
+use crate::runtime::replay::StateCheckpoint;
+use crate::runtime::{interrupt, output, restricted};
 
 /// EXTREME Quality REPL with guaranteed <10 complexity per function
 #[derive(Debug)]
@@ -30,6 +37,8 @@ pub struct Repl {
     completion: CompletionEngine,
     /// Working directory
     work_dir: PathBuf,
+    /// Limits and restrictions
+    config: ReplConfig,
 }
 
 impl Repl {
@@ -45,6 +54,7 @@ impl Repl {
             evaluator: Evaluator::new(),
             completion: CompletionEngine::new(),
             work_dir,
+            config,
         })
     }
 
@@ -60,6 +70,7 @@ impl Repl {
             repl.state.set_mode(ReplMode::Debug);
         }
         // Memory limits and timeout config - see test_repl_config_memory_limits
+        repl.config = config;
         Ok(repl)
     }
 
@@ -70,10 +81,31 @@ impl Repl {
             timeout: Duration::from_millis(1000), // 1 second timeout
             maxdepth: 50,                         // Lower recursion limit
             debug: false,
+            restricted: false,
+            deadline: None,
         };
         Self::with_config(config)
     }
 
+    /// Create a restricted REPL for untrusted users (complexity: 1)
+    ///
+    /// See [`ReplConfig::restricted`]: no filesystem, process, environment
+    /// or network builtins, a time budget per evaluation, capped output and
+    /// host paths hidden in error messages.
+    pub fn restricted() -> Result<Self> {
+        Self::with_config(ReplConfig::restricted())
+    }
+
+    /// Bound each evaluation in time, see [`ReplConfig::with_timeout`] (complexity: 1)
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.config = self.config.clone().with_timeout(timeout);
+    }
+
+    /// Whether this REPL runs in restricted mode (complexity: 1)
+    pub fn is_restricted(&self) -> bool {
+        self.config.restricted
+    }
+
     /// Run the main REPL loop (complexity: 9)
     pub fn run(&mut self) -> Result<()> {
         self.print_welcome();
@@ -82,7 +114,8 @@ impl Repl {
             .history_ignore_space(true)
             .completion_type(rustyline::CompletionType::List)
             .build();
-        let mut editor = DefaultEditor::with_config(config)?;
+        let mut editor = Editor::<InputHelper, DefaultHistory>::with_config(config)?;
+        editor.set_helper(Some(InputHelper::default()));
 
         // Load history if it exists
         let _ = self.load_history(&mut editor);
@@ -120,6 +153,9 @@ impl Repl {
         // Handle commands
         if line.starts_with(':') {
             let parts: Vec<&str> = line.split_whitespace().collect();
+            if let Some(output) = self.workspace_command(&parts) {
+                return Ok(output);
+            }
             let mut context = CommandContext {
                 args: parts[1..].to_vec(),
                 state: &mut self.state,
@@ -135,14 +171,14 @@ impl Repl {
         }
 
         // Handle expressions
-        match self.evaluator.evaluate_line(line, &mut self.state)? {
+        match self.evaluate_limited(line)? {
             EvalResult::Value(value) => {
                 // Add result to history for tracking
                 self.add_result_to_history(value.clone());
 
                 // REPL-005: Return empty string for Nil values (don't print)
                 if matches!(value, Value::Nil) {
-                    return Ok(String::new());
+                    return Ok(self.with_warnings(String::new()));
                 }
 
                 // Format output based on current mode
@@ -152,12 +188,16 @@ impl Repl {
                     ReplMode::Transpile => self.format_transpile_output(line)?,
                     ReplMode::Normal => value.to_string(),
                 };
-                Ok(formatted)
+                let output = self.cap_output(formatted);
+                Ok(self.with_warnings(output))
             }
             EvalResult::NeedMoreInput => {
                 Ok(String::new()) // Multiline mode
             }
-            EvalResult::Error(msg) => Err(anyhow::anyhow!("Evaluation error: {msg}")),
+            EvalResult::Error(msg) => Err(anyhow::anyhow!(
+                "Evaluation error: {}",
+                self.redact_host_paths(&msg)
+            )),
         }
     }
 
@@ -193,9 +233,20 @@ impl Repl {
         Ok(should_exit)
     }
 
+    /// Process a line and return what it shows, as in a terminal session (complexity: 2)
+    ///
+    /// The flag is true when the line asked to exit.
+    pub fn transcript_line(&mut self, line: &str) -> Result<(String, bool)> {
+        let (result, shown) = output::capture(|| self.process_line(line));
+        Ok((shown, result?))
+    }
+
     /// Check if input needs continuation (complexity: 1)
-    pub fn needs_continuation(_input: &str) -> bool {
-        false
+    ///
+    /// True while a bracket, string or comment is open or the input ends
+    /// mid-expression; see [`is_complete`].
+    pub fn needs_continuation(input: &str) -> bool {
+        !is_complete(input)
     }
 
     /// Get last error (complexity: 1)
@@ -232,6 +283,10 @@ impl Repl {
     /// Process REPL commands (complexity: 6)
     fn process_command(&mut self, line: &str) -> Result<bool> {
         let parts: Vec<&str> = line.split_whitespace().collect();
+        if let Some(output) = self.workspace_command(&parts) {
+            Self::show(&output);
+            return Ok(false);
+        }
         let mut context = CommandContext {
             args: parts[1..].to_vec(),
             state: &mut self.state,
@@ -242,12 +297,12 @@ impl Repl {
             CommandResult::Exit => Ok(true),
             CommandResult::Success(output) => {
                 if !output.is_empty() {
-                    println!("{output}");
+                    Self::show(&output);
                 }
                 Ok(false)
             }
             CommandResult::ModeChange(mode) => {
-                println!("Switched to {mode} mode");
+                Self::show(&format!("Switched to {mode} mode"));
                 Ok(false)
             }
             CommandResult::Silent => Ok(false),
@@ -256,7 +311,11 @@ impl Repl {
 
     /// Process expression evaluation (complexity: 8)
     fn process_evaluation(&mut self, line: &str) -> Result<()> {
-        match self.evaluator.evaluate_line(line, &mut self.state)? {
+        let result = self.evaluate_limited(line)?;
+        for warning in self.evaluator.take_warnings() {
+            Self::show(&format_warning(&warning));
+        }
+        match result {
             EvalResult::Value(value) => {
                 if matches!(value, Value::Nil) {
                     return Ok(());
@@ -269,17 +328,178 @@ impl Repl {
                     ReplMode::Normal => value.to_string(),
                 };
                 if !formatted.is_empty() {
-                    println!("{formatted}");
+                    Self::show(&self.cap_output(formatted));
                 }
             }
             EvalResult::NeedMoreInput => {}
             EvalResult::Error(msg) => {
-                println!("{}", format_error(&msg));
+                Self::show(&format_error(&self.redact_host_paths(&msg)));
             }
         }
         Ok(())
     }
 
+    /// Write a line of REPL output, through the same writer as `println` (complexity: 1)
+    fn show(text: &str) {
+        let _ = output::write(&format!("{text}\n"));
+    }
+
+    /// Evaluate a line under the configured limits, keeping successful inputs for `:save` (complexity: 3)
+    fn evaluate_limited(&mut self, line: &str) -> Result<EvalResult> {
+        let _limits = self.config.limits().map(restricted::enter);
+        let _deadline = self.config.deadline.map(interrupt::limit_time);
+        let result = self.evaluator.evaluate_line(line, &mut self.state)?;
+        let input = self.evaluator.take_last_input();
+        if let (EvalResult::Value(_), Some(input)) = (&result, input) {
+            self.state.add_input(input);
+        }
+        Ok(result)
+    }
+
+    /// Run `:save` and `:load`, which need the whole REPL rather than a command context (complexity: 6)
+    fn workspace_command(&mut self, parts: &[&str]) -> Option<String> {
+        let command = *parts.first()?;
+        if command != ":save" && command != ":load" {
+            return None;
+        }
+        let [_, file] = parts else {
+            return Some(format!("Usage: {command} <file.{}>", workspace::EXTENSION));
+        };
+        if self.config.restricted {
+            return Some(format_error(&format!(
+                "{command} is not available in restricted mode"
+            )));
+        }
+        let path = Path::new(file);
+        let result = if command == ":save" {
+            self.save_workspace(path)
+        } else {
+            self.load_workspace(path)
+        };
+        Some(result.unwrap_or_else(|e| format_error(&format!("{e:#}"))))
+    }
+
+    /// Save the session's inputs, definitions and history to `path` (complexity: 2)
+    fn save_workspace(&self, path: &Path) -> Result<String> {
+        let workspace = Workspace::capture(
+            self.state.get_inputs(),
+            self.state.get_history(),
+            &self.evaluator.user_bindings(),
+        );
+        workspace.write(path)?;
+        let variables = workspace
+            .checkpoint()
+            .map_or(0, |saved| saved.bindings.len());
+        Ok(format!(
+            "Saved {} inputs and {variables} variables to {}",
+            workspace.inputs().len(),
+            path.display()
+        ))
+    }
+
+    /// Replay the workspace at `path`, then restore its saved values and removals (complexity: 8)
+    fn load_workspace(&mut self, path: &Path) -> Result<String> {
+        let workspace = Workspace::read(path)?;
+        let before = self.user_names();
+        let mut restored = Restored::default();
+        for input in workspace.inputs() {
+            let (result, _printed) = output::capture(|| self.evaluate_limited(input));
+            match result? {
+                EvalResult::Value(_) => restored.replayed += 1,
+                EvalResult::Error(msg) => restored.failed.push((input.to_string(), msg)),
+                EvalResult::NeedMoreInput => {}
+            }
+        }
+        self.evaluator.discard_pending();
+        for line in &workspace.history {
+            self.state.add_to_history(line.clone());
+        }
+        if let Some(saved) = workspace.checkpoint() {
+            self.reset_saved_values(saved, &mut restored);
+            for name in self.user_names() {
+                if !before.contains(&name) && !saved.type_environment.contains_key(&name) {
+                    self.evaluator.undefine(&name, &mut self.state);
+                    restored.removed.push(name);
+                }
+            }
+        }
+        Ok(format!("Loaded {}\n{}", path.display(), restored.to_text()))
+    }
+
+    /// Set variables whose replayed value differs from the saved one back to it (complexity: 6)
+    fn reset_saved_values(&mut self, saved: &StateCheckpoint, restored: &mut Restored) {
+        let current: HashMap<String, Value> = self.evaluator.user_bindings().into_iter().collect();
+        let mut names: Vec<&String> = saved.bindings.keys().collect();
+        names.sort();
+        for name in names {
+            let source = &saved.bindings[name];
+            if current.get(name).and_then(workspace::literal).as_ref() == Some(source) {
+                continue;
+            }
+            match self.evaluate_expr_str(source, None) {
+                Ok(value) => {
+                    self.evaluator.set_variable(name.clone(), value.clone());
+                    self.state.set_variable(name.clone(), value);
+                    restored.reset.push(name.clone());
+                }
+                Err(e) => restored
+                    .failed
+                    .push((format!("{name} = {source}"), e.to_string())),
+            }
+        }
+    }
+
+    /// Names of the user's global definitions (complexity: 1)
+    fn user_names(&self) -> HashSet<String> {
+        self.evaluator
+            .user_bindings()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    /// Put the warnings of the last input above its output (complexity: 2)
+    fn with_warnings(&mut self, output: String) -> String {
+        let mut lines: Vec<String> = self
+            .evaluator
+            .take_warnings()
+            .iter()
+            .map(|warning| format_warning(warning))
+            .collect();
+        if !output.is_empty() {
+            lines.push(output);
+        }
+        lines.join("\n")
+    }
+
+    /// Truncate output to the restricted cap (complexity: 2)
+    fn cap_output(&self, output: String) -> String {
+        match self.config.limits() {
+            Some(limits) => restricted::truncate_output(&output, limits.max_output),
+            None => output,
+        }
+    }
+
+    /// Hide the working, current, home and temp directories in restricted mode (complexity: 3)
+    fn redact_host_paths(&self, message: &str) -> String {
+        if !self.config.restricted {
+            return message.to_string();
+        }
+        let current = std::env::current_dir().ok();
+        let home = std::env::var_os("HOME").map(PathBuf::from);
+        let temp = std::env::temp_dir();
+        let paths: Vec<&Path> = [
+            Some(self.work_dir.as_path()),
+            current.as_deref(),
+            home.as_deref(),
+            Some(temp.as_path()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        restricted::redact_paths(message, &paths)
+    }
+
     /// Format output in debug mode (complexity: 5)
     fn format_debug_output(&self, line: &str, value: &Value) -> Result<String> {
         use crate::frontend::Parser;
@@ -289,7 +509,7 @@ impl Repl {
         output.push_str("=== AST ===\n");
         let mut parser = Parser::new(line);
         match parser.parse() {
-            Ok(ast) => output.push_str(&format!("{ast:#?}\n")),
+            Ok(ast) => output.push_str(&format!("{}\n", format_ast(&ast))),
             Err(e) => output.push_str(&format!("Parse error: {e}\n")),
         }
 
@@ -310,24 +530,20 @@ impl Repl {
 
         let mut parser = Parser::new(line);
         match parser.parse() {
-            Ok(ast) => Ok(format!("{ast:#?}")),
+            Ok(ast) => Ok(format_ast(&ast)),
             Err(e) => Ok(format!("Parse error: {e}")),
         }
     }
 
-    /// Format transpiled Rust output (complexity: 4)
+    /// Format transpiled Rust output (complexity: 3)
     fn format_transpile_output(&self, line: &str) -> Result<String> {
-        use crate::backend::transpiler::Transpiler;
         use crate::frontend::Parser;
 
         let mut parser = Parser::new(line);
         match parser.parse() {
             Ok(ast) => {
-                let mut transpiler = Transpiler::new();
-                match transpiler.transpile(&ast) {
-                    Ok(rust_code) => Ok(rust_code.to_string()),
-                    Err(e) => Ok(format!("Transpile error: {e}")),
-                }
+                Ok(introspect::rust_source(&ast)
+                    .unwrap_or_else(|e| format!("Transpile error: {e}")))
             }
             Err(e) => Ok(format!("Parse error: {e}")),
         }
@@ -352,13 +568,16 @@ impl Repl {
     /// Print welcome message (complexity: 1)
     fn print_welcome(&self) {
         println!("Ruchy REPL v{}", env!("CARGO_PKG_VERSION"));
+        if self.config.restricted {
+            println!("Restricted mode: filesystem, process and network access are disabled");
+        }
         println!("Type :help for commands or expressions to evaluate\n");
     }
 
-    /// Load history from file (complexity: 4)
-    fn load_history(&self, editor: &mut DefaultEditor) -> Result<()> {
+    /// Load history from file; restricted sessions keep none (complexity: 4)
+    fn load_history(&self, editor: &mut Editor<InputHelper, DefaultHistory>) -> Result<()> {
         let history_file = self.work_dir.join("repl_history.txt");
-        if history_file.exists() {
+        if !self.config.restricted && history_file.exists() {
             editor
                 .load_history(&history_file)
                 .context("Failed to load history")?;
@@ -366,8 +585,11 @@ impl Repl {
         Ok(())
     }
 
-    /// Save history to file (complexity: 3)
-    fn save_history(&self, editor: &mut DefaultEditor) -> Result<()> {
+    /// Save history to file; restricted sessions keep none (complexity: 3)
+    fn save_history(&self, editor: &mut Editor<InputHelper, DefaultHistory>) -> Result<()> {
+        if self.config.restricted {
+            return Ok(());
+        }
         let history_file = self.work_dir.join("repl_history.txt");
         editor
             .save_history(&history_file)
@@ -412,6 +634,11 @@ impl Repl {
         self.state.result_history_len()
     }
 
+    /// Value of the most recent successful evaluation (complexity: 1)
+    pub fn last_result(&self) -> Option<&Value> {
+        self.state.get_result_history().last()
+    }
+
     /// Get peak memory usage (complexity: 2)
     pub fn peak_memory(&self) -> usize {
         let current = self.memory_used();
@@ -425,13 +652,17 @@ impl Repl {
         self.state.add_to_result_history(result);
     }
 
-    /// Evaluate with memory and time bounds (complexity: 4)
+    /// Evaluate with memory and time bounds (complexity: 1)
+    ///
+    /// Evaluation stops once `timeout` has passed; the memory limit is not
+    /// enforced yet.
     pub fn eval_bounded(
         &mut self,
         line: &str,
         _memory_limit: usize,
-        _timeout: Duration,
+        timeout: Duration,
     ) -> Result<String> {
+        let _deadline = interrupt::limit_time(timeout);
         self.eval(line)
     }
 
@@ -509,6 +740,183 @@ mod tests {
         assert_eq!(repl.get_mode(), "normal");
     }
 
+    #[test]
+    fn test_repl_restricted_denies_host_access() {
+        let mut repl = Repl::restricted().unwrap();
+        assert!(repl.is_restricted());
+        assert_eq!(repl.eval("1 + 2").unwrap(), "3");
+        let err = repl.eval("fs_read(\"/etc/hosts\")").unwrap_err();
+        assert!(err.to_string().contains("restricted mode"), "{err}");
+        assert!(repl.eval("http_get(\"http://example.com\")").is_err());
+        // Restrictions only apply while the REPL evaluates
+        assert!(!restricted::is_active());
+    }
+
+    #[test]
+    fn test_repl_restricted_stops_runaway_loops() {
+        let mut repl = Repl::restricted().unwrap();
+        let err = repl.eval("loop { }").unwrap_err();
+        assert!(err.to_string().contains("timed out"), "{err}");
+        assert_eq!(repl.eval("40 + 2").unwrap(), "42");
+    }
+
+    #[test]
+    fn test_repl_restricted_caps_output_and_hides_paths() {
+        let mut repl = Repl::restricted().unwrap();
+        let output = repl.eval("\"x\".repeat(100000)").unwrap();
+        assert!(output.ends_with(restricted::TRUNCATION_MARKER));
+        assert!(output.len() < 100_000);
+
+        let temp = std::env::temp_dir();
+        let message = format!("cannot open {}", temp.join("secret.txt").display());
+        let redacted = repl.redact_host_paths(&message);
+        assert!(!redacted.contains(&*temp.to_string_lossy()), "{redacted}");
+        assert!(redacted.contains("<redacted>/secret.txt"), "{redacted}");
+    }
+
+    #[test]
+    fn test_repl_redefinition_reaches_earlier_functions() {
+        let mut repl = Repl::new(std::env::temp_dir()).unwrap();
+        repl.eval("fun rate() { 2 }").unwrap();
+        repl.eval("fun total(x) { x * rate() }").unwrap();
+        assert_eq!(repl.eval("total(10)").unwrap(), "20");
+        repl.eval("fun rate() { 3 }").unwrap();
+        assert_eq!(repl.eval("total(10)").unwrap(), "30");
+    }
+
+    #[test]
+    fn test_repl_warns_on_stdlib_shadowing_and_undef_restores() {
+        let mut repl = Repl::new(std::env::temp_dir()).unwrap();
+        let output = repl.eval("let len = 3").unwrap();
+        assert!(
+            output.starts_with("Warning: 'len' replaces the standard library definition"),
+            "{output}"
+        );
+        assert_eq!(repl.eval("len + 1").unwrap(), "4");
+        let who = repl.eval(":who").unwrap();
+        assert!(who.contains("len: Integer = 3  (replaces stdlib)"), "{who}");
+        assert_eq!(
+            repl.eval(":undef len").unwrap(),
+            "Restored the standard library 'len'"
+        );
+        assert_eq!(repl.eval("len([1, 2])").unwrap(), "2");
+        assert_eq!(repl.eval(":who").unwrap(), "No user definitions");
+    }
+
+    #[test]
+    fn test_repl_introspection_commands() {
+        let mut repl = Repl::new(std::env::temp_dir()).unwrap();
+        repl.eval("fun double(n) { n * 2 }").unwrap();
+        assert_eq!(repl.eval(":type double(4)").unwrap(), "Type: i32");
+        let typed = repl.eval(":type let hidden = 1").unwrap();
+        assert!(typed.starts_with("Type: "), "{typed}");
+        assert!(!repl.eval(":who").unwrap().contains("hidden"));
+        let ast = repl.eval(":ast 1 + 2").unwrap();
+        assert!(ast.starts_with("Binary\n"), "{ast}");
+        let rust = repl.eval(":rust 1 + 2").unwrap();
+        assert!(rust.contains("fn main()"), "{rust}");
+        assert_eq!(repl.eval(":rust").unwrap(), "Usage: :rust <expression>");
+    }
+
+    #[test]
+    fn test_repl_memory_lists_largest_definitions_first() {
+        let mut repl = Repl::new(std::env::temp_dir()).unwrap();
+        repl.eval("let flag = true").unwrap();
+        repl.eval("let rows = [[0; 50]; 20]").unwrap();
+        let memory = repl.eval(":memory").unwrap();
+        assert!(memory.starts_with("=== Memory Analysis ==="), "{memory}");
+        let rows = memory.find("rows").unwrap();
+        let flag = memory.find("flag").unwrap();
+        assert!(rows < flag, "{memory}");
+        assert!(!memory.contains("println"), "{memory}");
+    }
+
+    #[test]
+    fn test_repl_undef_removes_user_definitions() {
+        let mut repl = Repl::new(std::env::temp_dir()).unwrap();
+        repl.eval("let answer = 42").unwrap();
+        assert_eq!(repl.eval(":undef answer").unwrap(), "Removed 'answer'");
+        assert!(repl.eval("answer").is_err());
+        assert!(!repl.get_bindings().contains_key("answer"));
+        assert_eq!(
+            repl.eval(":undef answer").unwrap(),
+            "'answer' is not defined"
+        );
+        assert!(repl
+            .eval(":undef println")
+            .unwrap()
+            .contains("defined by the standard library"));
+        assert_eq!(repl.eval(":undef").unwrap(), "Usage: :undef <name>");
+    }
+
+    #[test]
+    fn test_repl_save_and_load_restore_definitions_and_history() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("session.rsn");
+        let mut repl = Repl::new(dir.path().to_path_buf()).unwrap();
+        repl.eval("fun double(x) { x * 2 }").unwrap();
+        repl.process_line("let xs = [1, 2, 3]").unwrap();
+        repl.eval("let scratch = 0").unwrap();
+        repl.eval(":undef scratch").unwrap();
+        assert!(repl.eval("missing_name").is_err());
+        assert_eq!(
+            repl.eval(&format!(":save {}", path.display())).unwrap(),
+            format!("Saved 3 inputs and 1 variables to {}", path.display())
+        );
+
+        let mut fresh = Repl::new(dir.path().to_path_buf()).unwrap();
+        assert_eq!(
+            fresh.eval(&format!(":load {}", path.display())).unwrap(),
+            format!(
+                "Loaded {}\nReplayed 3 inputs\nRemoved again: scratch",
+                path.display()
+            )
+        );
+        assert_eq!(fresh.state.get_history(), ["let xs = [1, 2, 3]"]);
+        assert_eq!(fresh.state.get_inputs().len(), 3);
+        assert_eq!(fresh.eval("double(xs[2])").unwrap(), "6");
+        assert!(fresh.eval("scratch").is_err());
+    }
+
+    #[test]
+    fn test_repl_load_resets_values_that_replay_differently() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("random.rsn");
+        let mut repl = Repl::new(dir.path().to_path_buf()).unwrap();
+        repl.eval("let r = random()").unwrap();
+        let saved = repl.eval("r").unwrap();
+        repl.eval(&format!(":save {}", path.display())).unwrap();
+
+        let mut fresh = Repl::new(dir.path().to_path_buf()).unwrap();
+        let loaded = fresh.eval(&format!(":load {}", path.display())).unwrap();
+        assert!(loaded.ends_with("Reset to saved values: r"), "{loaded}");
+        assert_eq!(fresh.eval("r").unwrap(), saved);
+    }
+
+    #[test]
+    fn test_repl_save_and_load_reject_bad_requests() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut repl = Repl::new(dir.path().to_path_buf()).unwrap();
+        assert_eq!(repl.eval(":save").unwrap(), "Usage: :save <file.rsn>");
+        let script = dir.path().join("script.ruchy");
+        assert!(repl
+            .eval(&format!(":save {}", script.display()))
+            .unwrap()
+            .ends_with("is not a .rsn file; sessions are saved to and loaded from .rsn files"));
+        assert!(!script.exists());
+        let missing = dir.path().join("missing.rsn");
+        assert!(repl
+            .eval(&format!(":load {}", missing.display()))
+            .unwrap()
+            .starts_with("Error: Failed to read"));
+
+        let mut restricted = Repl::restricted().unwrap();
+        assert_eq!(
+            restricted.eval(":load session.rsn").unwrap(),
+            "Error: :load is not available in restricted mode"
+        );
+    }
+
     #[test]
     fn test_repl_eval_simple_expression() {
         let mut repl = Repl::new(std::env::temp_dir()).unwrap();
@@ -598,6 +1006,8 @@ mod tests {
     fn test_repl_needs_continuation() {
         assert!(!Repl::needs_continuation("let x = 5"));
         assert!(!Repl::needs_continuation("fn foo() {}"));
+        assert!(Repl::needs_continuation("fun foo() {"));
+        assert!(Repl::needs_continuation("let x ="));
     }
 
     #[test]
@@ -702,6 +1112,21 @@ mod tests {
             .eval_bounded("1 + 1", 1024 * 1024, Duration::from_secs(5))
             .unwrap();
         assert_eq!(result, "2");
+        let timed_out = repl
+            .eval_bounded("loop { }", 1024 * 1024, Duration::from_millis(50))
+            .unwrap_err();
+        assert!(timed_out.to_string().contains(interrupt::TIMEOUT_MESSAGE));
+        assert_eq!(interrupt::deadline(), None);
+    }
+
+    #[test]
+    fn test_repl_timeout_bounds_each_evaluation() {
+        let mut repl = Repl::new(std::env::temp_dir()).unwrap();
+        repl.set_timeout(Some(Duration::from_millis(50)));
+        assert!(repl.eval("let mut n = 0").is_ok());
+        assert!(repl.eval("loop { n += 1 }").is_err());
+        // The next input gets a fresh deadline and sees the state left behind
+        assert_ne!(repl.eval("n").unwrap(), "0");
     }
 
     #[test]
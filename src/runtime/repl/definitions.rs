@@ -0,0 +1,145 @@
+//! Redefinition semantics for REPL sessions
+//!
+//! Every top-level `let`, `fun` and type definition binds its name in the
+//! session's global scope, replacing any earlier binding of that name rather
+//! than shadowing it. Functions look global names up when they are called, so
+//! redefining a function or variable also changes the behavior of functions
+//! defined earlier that refer to it:
+//!
+//! ```text
+//! >> fun rate() { 2 }
+//! >> fun total(x) { x * rate() }
+//! >> fun rate() { 3 }
+//! >> total(10)
+//! 30
+//! ```
+//!
+//! Replacing a standard library binding (`let len = 3`) is allowed but
+//! reported, and `:undef` restores the original.
+
+use crate::frontend::ast::{Expr, ExprKind};
+use crate::runtime::builtin_init::init_global_environment;
+use crate::runtime::interpreter::Value;
+use std::collections::HashMap;
+
+/// What `:undef` did with a name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Undefined {
+    /// The user binding was removed
+    Removed,
+    /// The user binding replaced a standard library binding, which is back
+    Restored,
+    /// The name is bound, but only by the standard library
+    NotUserDefined,
+    /// The name is not bound at all
+    NotDefined,
+}
+
+/// The standard library bindings a session starts with
+#[derive(Debug)]
+pub struct Stdlib {
+    bindings: HashMap<String, Value>,
+}
+
+impl Stdlib {
+    /// Snapshot of the bindings of a fresh interpreter (complexity: 1)
+    pub fn new() -> Self {
+        Self {
+            bindings: init_global_environment(),
+        }
+    }
+
+    /// Standard library binding of `name`, if there is one (complexity: 1)
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.bindings.get(name)
+    }
+
+    /// Whether `name` is bound to `value` by the user rather than the standard library (complexity: 1)
+    pub fn is_user_binding(&self, name: &str, value: &Value) -> bool {
+        self.bindings.get(name) != Some(value)
+    }
+
+    /// Warnings for the names defined by `ast` that replace standard library bindings (complexity: 3)
+    pub fn shadow_warnings(&self, ast: &Expr) -> Vec<String> {
+        defined_names(ast)
+            .into_iter()
+            .filter(|name| self.bindings.contains_key(*name))
+            .map(|name| {
+                format!(
+                    "'{name}' replaces the standard library definition; :undef {name} restores it"
+                )
+            })
+            .collect()
+    }
+}
+
+impl Default for Stdlib {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Names bound in the global scope by evaluating `ast` at the top level (complexity: 1)
+pub fn defined_names(ast: &Expr) -> Vec<&str> {
+    let mut names = Vec::new();
+    collect_defined_names(ast, &mut names);
+    names
+}
+
+/// Walk statement sequences, collecting definition names (complexity: 5)
+fn collect_defined_names<'a>(expr: &'a Expr, names: &mut Vec<&'a str>) {
+    match &expr.kind {
+        ExprKind::Block(statements) => {
+            for statement in statements {
+                collect_defined_names(statement, names);
+            }
+        }
+        ExprKind::Let { name, body, .. } => {
+            names.push(name);
+            collect_defined_names(body, names);
+        }
+        ExprKind::LetPattern { body, .. } => collect_defined_names(body, names),
+        ExprKind::Function { name, .. }
+        | ExprKind::Struct { name, .. }
+        | ExprKind::TupleStruct { name, .. }
+        | ExprKind::Class { name, .. }
+        | ExprKind::Enum { name, .. }
+        | ExprKind::Trait { name, .. }
+        | ExprKind::Actor { name, .. } => names.push(name),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::Parser;
+
+    fn parse(source: &str) -> Expr {
+        Parser::new(source).parse().expect("should parse")
+    }
+
+    #[test]
+    fn test_defined_names_follow_statement_sequences() {
+        let ast = parse("let a = 1\nfun f() { let inner = 2\ninner }\nstruct P { x: i32 }\na + 1");
+        assert_eq!(defined_names(&ast), vec!["a", "f", "P"]);
+    }
+
+    #[test]
+    fn test_shadow_warnings_name_stdlib_bindings_only() {
+        let stdlib = Stdlib::new();
+        let warnings = stdlib.shadow_warnings(&parse("let len = 3\nlet total = 4"));
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("'len' replaces"), "{warnings:?}");
+        assert!(warnings[0].ends_with(":undef len restores it"));
+    }
+
+    #[test]
+    fn test_user_binding_differs_from_stdlib() {
+        let stdlib = Stdlib::new();
+        let builtin = stdlib.get("len").expect("len is builtin").clone();
+        assert!(!stdlib.is_user_binding("len", &builtin));
+        assert!(stdlib.is_user_binding("len", &Value::Integer(3)));
+        assert!(stdlib.is_user_binding("total", &Value::Integer(3)));
+    }
+}
@@ -2,7 +2,11 @@
 //!
 //! Handles evaluation of user input with proper error handling and multiline support.
 
+use super::definitions::{Stdlib, Undefined};
+use super::input::is_complete;
+use super::state::ReplState;
 use crate::runtime::interpreter::{Interpreter, Value};
+use crate::runtime::memory::{self, MemoryReport};
 use anyhow::Result;
 
 /// Result of evaluating a line of input
@@ -23,6 +27,12 @@ pub struct Evaluator {
     interpreter: Interpreter,
     /// Tracks if we're in multiline mode
     multiline_buffer: String,
+    /// Bindings the session started with, to tell user definitions apart
+    stdlib: Stdlib,
+    /// Warnings raised by the last evaluated input
+    warnings: Vec<String>,
+    /// Complete source of the last input that evaluated successfully
+    last_input: Option<String>,
 }
 
 impl Evaluator {
@@ -31,15 +41,15 @@ impl Evaluator {
         Self {
             interpreter: Interpreter::new(),
             multiline_buffer: String::new(),
+            stdlib: Stdlib::new(),
+            warnings: Vec::new(),
+            last_input: None,
         }
     }
 
     /// Evaluate a line of input with state synchronization (complexity: 9)
-    pub fn evaluate_line(
-        &mut self,
-        line: &str,
-        state: &mut crate::runtime::repl::state::ReplState,
-    ) -> Result<EvalResult> {
+    pub fn evaluate_line(&mut self, line: &str, state: &mut ReplState) -> Result<EvalResult> {
+        self.warnings.clear();
         // Handle multiline continuation
         if self.multiline_buffer.is_empty() {
             self.multiline_buffer = line.to_string();
@@ -56,14 +66,16 @@ impl Evaluator {
             Ok(expr) => {
                 // [RUNTIME-083] Catch InterpreterError::Return and extract value (early return support)
                 // This matches the pattern in interpreter.rs:2044-2046 for function calls
-                let result = match self.interpreter.eval_expr(&expr) {
+                // Top-level evaluation keeps what `let a = 1; let b = 2` defines
+                let result = match self.interpreter.eval_top_level(&expr) {
                     Err(crate::runtime::InterpreterError::Return(val)) => Ok(val),
                     other => other,
                 };
 
                 match result {
                     Ok(value) => {
-                        self.multiline_buffer.clear();
+                        self.last_input = Some(std::mem::take(&mut self.multiline_buffer));
+                        self.warnings = self.stdlib.shadow_warnings(&expr);
 
                         // Synchronize interpreter bindings with REPL state
                         let interpreter_bindings = self.interpreter.get_current_bindings();
@@ -81,18 +93,82 @@ impl Evaluator {
                 }
             }
             Err(e) => {
-                // Check if this looks like an incomplete expression
-                let error_str = e.to_string();
-                if self.is_incomplete_error(&error_str) {
-                    Ok(EvalResult::NeedMoreInput)
-                } else {
+                if is_complete(&self.multiline_buffer) {
                     self.multiline_buffer.clear();
-                    Ok(EvalResult::Error(error_str))
+                    Ok(EvalResult::Error(e.to_string()))
+                } else {
+                    Ok(EvalResult::NeedMoreInput)
                 }
             }
         }
     }
 
+    /// Take the warnings raised by the last evaluated input (complexity: 1)
+    pub fn take_warnings(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    /// Take the complete source of the last input that evaluated successfully (complexity: 1)
+    ///
+    /// Multiline input is joined with newlines.
+    pub fn take_last_input(&mut self) -> Option<String> {
+        self.last_input.take()
+    }
+
+    /// Drop the lines collected for an unfinished multiline input (complexity: 1)
+    pub fn discard_pending(&mut self) {
+        self.multiline_buffer.clear();
+    }
+
+    /// Global bindings made by the user, sorted by name (complexity: 2)
+    ///
+    /// Standard library bindings are left out unless the user replaced them.
+    pub fn user_bindings(&self) -> Vec<(String, Value)> {
+        let mut bindings: Vec<(String, Value)> = self
+            .interpreter
+            .get_global_bindings()
+            .into_iter()
+            .filter(|(name, value)| self.stdlib.is_user_binding(name, value))
+            .collect();
+        bindings.sort_by(|(a, _), (b, _)| a.cmp(b));
+        bindings
+    }
+
+    /// Memory held by the user's global bindings (complexity: 1)
+    pub fn memory_report(&self) -> MemoryReport {
+        memory::measure(&self.interpreter, &self.user_bindings())
+    }
+
+    /// Whether the user's binding of `name` replaces a standard library one (complexity: 1)
+    pub fn shadows_stdlib(&self, name: &str) -> bool {
+        self.stdlib.get(name).is_some()
+    }
+
+    /// Remove the user's global binding of `name` (complexity: 4)
+    ///
+    /// A binding that replaced a standard library definition gives way to
+    /// that definition again. Functions that refer to `name` see the change
+    /// on their next call.
+    pub fn undefine(&mut self, name: &str, state: &mut ReplState) -> Undefined {
+        let Some(current) = self.interpreter.get_global_binding(name) else {
+            return Undefined::NotDefined;
+        };
+        if !self.stdlib.is_user_binding(name, &current) {
+            return Undefined::NotUserDefined;
+        }
+        self.interpreter.remove_global_binding(name);
+        state.get_bindings_mut().remove(name);
+        match self.stdlib.get(name) {
+            Some(original) => {
+                self.interpreter
+                    .set_global_binding(name.to_string(), original.clone());
+                state.set_variable(name.to_string(), original.clone());
+                Undefined::Restored
+            }
+            None => Undefined::Removed,
+        }
+    }
+
     /// Check if we're in multiline mode (complexity: 1)
     pub fn is_multiline(&self) -> bool {
         !self.multiline_buffer.is_empty()
@@ -107,15 +183,6 @@ impl Evaluator {
     pub fn set_variable(&mut self, name: String, value: Value) {
         self.interpreter.set_global_binding(name, value);
     }
-
-    /// Check if error indicates incomplete input (complexity: 3)
-    fn is_incomplete_error(&self, error_msg: &str) -> bool {
-        error_msg.contains("unexpected end of input")
-            || error_msg.contains("incomplete")
-            || (error_msg.contains("Expected") && error_msg.contains("EOF"))
-            || (error_msg.contains("expected") && error_msg.contains("EOF"))
-            || error_msg.contains("found EOF")
-    }
 }
 
 impl Default for Evaluator {
@@ -193,19 +260,23 @@ mod tests {
     }
 
     #[test]
-    fn test_is_incomplete_error() {
-        let evaluator = Evaluator::new();
-
-        // These should be detected as incomplete
-        assert!(evaluator.is_incomplete_error("unexpected end of input"));
-        assert!(evaluator.is_incomplete_error("incomplete expression"));
-        assert!(evaluator.is_incomplete_error("Expected } but found EOF"));
-        assert!(evaluator.is_incomplete_error("expected ) at EOF"));
-        assert!(evaluator.is_incomplete_error("found EOF unexpectedly"));
+    fn test_open_input_needs_more() {
+        let mut evaluator = Evaluator::new();
+        let mut state = crate::runtime::repl::state::ReplState::new();
 
-        // These should NOT be incomplete
-        assert!(!evaluator.is_incomplete_error("undefined variable"));
-        assert!(!evaluator.is_incomplete_error("type mismatch"));
+        for line in ["fun add(a, b) {", "  a + b"] {
+            match evaluator.evaluate_line(line, &mut state).unwrap() {
+                EvalResult::NeedMoreInput => {}
+                result => panic!("Expected NeedMoreInput, got {result:?}"),
+            }
+        }
+        assert!(evaluator.is_multiline());
+        evaluator.evaluate_line("}", &mut state).unwrap();
+        assert!(!evaluator.is_multiline());
+        match evaluator.evaluate_line("add(1, 2)", &mut state).unwrap() {
+            EvalResult::Value(Value::Integer(3)) => {}
+            result => panic!("Expected Integer(3), got {result:?}"),
+        }
     }
 
     #[test]
@@ -234,6 +305,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_statements_on_one_line_keep_their_bindings() {
+        let mut evaluator = Evaluator::new();
+        let mut state = crate::runtime::repl::state::ReplState::new();
+        evaluator
+            .evaluate_line("let a = 1; fun twice(n) { n * 2 }", &mut state)
+            .unwrap();
+        match evaluator.evaluate_line("twice(a)", &mut state).unwrap() {
+            EvalResult::Value(Value::Integer(2)) => {}
+            result => panic!("Expected Integer(2), got {result:?}"),
+        }
+    }
+
     // === Additional tests for improved coverage ===
 
     #[test]
@@ -400,20 +484,17 @@ mod tests {
     }
 
     #[test]
-    fn test_is_incomplete_error_patterns() {
-        let evaluator = Evaluator::new();
+    fn test_parse_errors_mid_input_are_reported() {
+        let mut evaluator = Evaluator::new();
+        let mut state = crate::runtime::repl::state::ReplState::new();
 
-        // Should detect as incomplete
-        assert!(evaluator.is_incomplete_error("unexpected end of input"));
-        assert!(evaluator.is_incomplete_error("expression is incomplete"));
-        assert!(evaluator.is_incomplete_error("Expected } but found EOF"));
-        assert!(evaluator.is_incomplete_error("expected ) at EOF"));
-        assert!(evaluator.is_incomplete_error("found EOF while parsing"));
-
-        // Should NOT detect as incomplete
-        assert!(!evaluator.is_incomplete_error("syntax error"));
-        assert!(!evaluator.is_incomplete_error("invalid token"));
-        assert!(!evaluator.is_incomplete_error("type error: cannot add"));
+        for line in ["1 )", "(1]"] {
+            match evaluator.evaluate_line(line, &mut state).unwrap() {
+                EvalResult::Error(_) => {}
+                result => panic!("Expected Error for {line:?}, got {result:?}"),
+            }
+            assert!(!evaluator.is_multiline());
+        }
     }
 
     #[test]
@@ -0,0 +1,712 @@
+//! REPL Object Explorer
+//!
+//! Paginated navigation of deeply nested values for `:inspect` / `:explore`.
+//! Children are only materialized for the node currently being viewed, so
+//! exploring a large `DataFrame` or object graph never renders the whole tree.
+//! `:explore keys` browses the same tree with the arrow keys (see
+//! [`ObjectExplorer::browse`]).
+//! All functions maintain complexity <10 (Toyota Way).
+
+use crate::runtime::eval_json::value_to_serde;
+use crate::runtime::eval_rich_output::base64_encode;
+use crate::runtime::interpreter::Value;
+use std::fmt::Write;
+use std::io::{self, IsTerminal, Write as _};
+
+/// Default number of entries shown per page
+pub const DEFAULT_PAGE_SIZE: usize = 20;
+
+/// Maximum number of matches reported by `find`
+const MAX_FIND_RESULTS: usize = 50;
+
+/// Maximum depth searched by `find` (guards against pathological nesting)
+const MAX_FIND_DEPTH: usize = 32;
+
+/// Maximum characters shown for a scalar preview
+const MAX_PREVIEW_LEN: usize = 60;
+
+/// One step of a path from the explored root to a nested value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathSegment {
+    /// Named field, object key, or `DataFrame` column
+    Key(String),
+    /// Positional element of an array, tuple, or enum payload
+    Index(usize),
+}
+
+impl std::fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathSegment::Key(key) => write!(f, ".{key}"),
+            PathSegment::Index(index) => write!(f, "[{index}]"),
+        }
+    }
+}
+
+/// Keys of the keyboard navigator, see [`ObjectExplorer::press`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NavKey {
+    /// Select the previous entry (↑ or `k`)
+    Up,
+    /// Select the next entry (↓ or `j`)
+    Down,
+    /// Open the selected entry (→, Enter or `l`)
+    Open,
+    /// Return to the parent (←, Backspace or `h`)
+    Back,
+    /// Show the next page (PageDown or `n`)
+    NextPage,
+    /// Show the previous page (PageUp or `p`)
+    PrevPage,
+}
+
+/// Key help shown under the keyboard navigator
+const NAV_HELP: &str = "↑↓ select  → open  ← back  n/p page  y copy JSON  q quit";
+
+/// Interactive explorer session over a single root value
+#[derive(Debug, Clone)]
+pub struct ObjectExplorer {
+    /// Value the session was opened on
+    root: Value,
+    /// Path from the root to the node currently in view
+    path: Vec<PathSegment>,
+    /// Zero-based page of the current node's children
+    page: usize,
+    /// Entries per page
+    page_size: usize,
+    /// Index of the child selected by the keyboard navigator
+    selected: usize,
+}
+
+impl ObjectExplorer {
+    /// Open an explorer on a value (complexity: 1)
+    pub fn new(root: Value) -> Self {
+        Self::with_page_size(root, DEFAULT_PAGE_SIZE)
+    }
+
+    /// Open an explorer with a custom page size (complexity: 1)
+    pub fn with_page_size(root: Value, page_size: usize) -> Self {
+        Self {
+            root,
+            path: Vec::new(),
+            page: 0,
+            page_size: page_size.max(1),
+            selected: 0,
+        }
+    }
+
+    /// Render the current path as `$.field[2]` (complexity: 1)
+    pub fn path_string(&self) -> String {
+        Self::format_path(&self.path)
+    }
+
+    /// Current zero-based page (complexity: 1)
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Index of the child selected by the keyboard navigator (complexity: 1)
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Number of pages for the node in view (complexity: 2)
+    pub fn page_count(&self) -> usize {
+        let total = self.current().map_or(0, |v| Self::child_count(&v));
+        total.div_ceil(self.page_size).max(1)
+    }
+
+    /// Whether the node in view has children to navigate (complexity: 1)
+    pub fn is_expandable(&self) -> bool {
+        self.current().is_some_and(|v| Self::child_count(&v) > 0)
+    }
+
+    /// Resolve the node currently in view (complexity: 2)
+    pub fn current(&self) -> Option<Value> {
+        let mut node = self.root.clone();
+        for segment in &self.path {
+            node = Self::child(&node, segment)?;
+        }
+        Some(node)
+    }
+
+    /// Descend into a child by key or index (complexity: 4)
+    pub fn enter(&mut self, selector: &str) -> Result<(), String> {
+        let current = self.current().ok_or("Current node no longer exists")?;
+        let segment = match selector.trim_start_matches(['.', '[']).trim_end_matches(']') {
+            s if s.parse::<usize>().is_ok() && Self::is_indexed(&current) => {
+                PathSegment::Index(s.parse().unwrap_or_default())
+            }
+            s => PathSegment::Key(s.to_string()),
+        };
+        if Self::child(&current, &segment).is_none() {
+            return Err(format!("No child '{selector}' at {}", self.path_string()));
+        }
+        self.path.push(segment);
+        self.page = 0;
+        self.selected = 0;
+        Ok(())
+    }
+
+    /// Return to the parent node, selecting the child left; false when
+    /// already at the root (complexity: 3)
+    pub fn up(&mut self) -> bool {
+        let Some(segment) = self.path.pop() else {
+            return false;
+        };
+        let index = self.current().and_then(|node| {
+            Self::children(&node)
+                .iter()
+                .position(|(child, _)| *child == segment)
+        });
+        self.select(index.unwrap_or(0));
+        true
+    }
+
+    /// Advance to the next page if there is one (complexity: 2)
+    pub fn next_page(&mut self) -> bool {
+        if self.page + 1 < self.page_count() {
+            self.page += 1;
+            self.selected = self.page * self.page_size;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Go back one page if possible (complexity: 2)
+    pub fn prev_page(&mut self) -> bool {
+        if self.page > 0 {
+            self.page -= 1;
+            self.selected = self.page * self.page_size;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Move the selection or the view for a navigator key (complexity: 7)
+    pub fn press(&mut self, key: NavKey) {
+        let count = self.current().map_or(0, |node| Self::child_count(&node));
+        match key {
+            NavKey::Up if self.selected > 0 => self.select(self.selected - 1),
+            NavKey::Down if self.selected + 1 < count => self.select(self.selected + 1),
+            NavKey::Open => self.open_selected(),
+            NavKey::Back => {
+                self.up();
+            }
+            NavKey::NextPage => {
+                self.next_page();
+            }
+            NavKey::PrevPage => {
+                self.prev_page();
+            }
+            NavKey::Up | NavKey::Down => {}
+        }
+    }
+
+    /// Select a child and show its page (complexity: 1)
+    fn select(&mut self, index: usize) {
+        self.selected = index;
+        self.page = index / self.page_size;
+    }
+
+    /// Descend into the selected child, if there is one (complexity: 2)
+    fn open_selected(&mut self) {
+        let segment = self.current().and_then(|node| {
+            Self::children(&node)
+                .into_iter()
+                .nth(self.selected)
+                .map(|(segment, _)| segment)
+        });
+        if let Some(segment) = segment {
+            self.path.push(segment);
+            self.page = 0;
+            self.selected = 0;
+        }
+    }
+
+    /// Jump to a one-based page number (complexity: 2)
+    pub fn goto_page(&mut self, page: usize) -> Result<(), String> {
+        let count = self.page_count();
+        if page == 0 || page > count {
+            return Err(format!("Page {page} out of range (1-{count})"));
+        }
+        self.page = page - 1;
+        self.selected = self.page * self.page_size;
+        Ok(())
+    }
+
+    /// Render the current node and its page of children (complexity: 1)
+    pub fn render(&self) -> String {
+        self.render_page(false)
+    }
+
+    /// Render the current node, marking the selected child with `cursor` (complexity: 6)
+    fn render_page(&self, cursor: bool) -> String {
+        let Some(node) = self.current() else {
+            return "Current node no longer exists".to_string();
+        };
+        let mut out = format!("{} : {}\n", self.path_string(), Self::summary(&node));
+        let children = Self::children(&node);
+        if children.is_empty() {
+            let _ = writeln!(out, "  {}", Self::preview(&node));
+            return out;
+        }
+        let start = self.page * self.page_size;
+        for (index, (segment, child)) in
+            children.iter().enumerate().skip(start).take(self.page_size)
+        {
+            let pointer = match (cursor, index == self.selected) {
+                (true, true) => "> ",
+                (true, false) => "  ",
+                (false, _) => "",
+            };
+            let marker = if Self::child_count(child) == 0 {
+                " "
+            } else {
+                "▸"
+            };
+            let _ = writeln!(out, "{pointer}{marker} {segment}: {}", Self::preview(child));
+        }
+        let _ = writeln!(
+            out,
+            "-- page {}/{} ({} entries) --",
+            self.page + 1,
+            self.page_count(),
+            children.len()
+        );
+        out
+    }
+
+    /// Search keys and scalar values below the current node (complexity: 3)
+    pub fn find(&self, query: &str) -> Vec<String> {
+        let mut matches = Vec::new();
+        if let Some(node) = self.current() {
+            let needle = query.to_lowercase();
+            let mut path = self.path.clone();
+            Self::find_in(&node, &needle, &mut path, 0, &mut matches);
+        }
+        matches
+    }
+
+    /// Serialize the node in view as pretty JSON (complexity: 3)
+    ///
+    /// Uses the conversion of `JSON.stringify`, so functions, `DataFrame`s
+    /// and values nested too deeply have no JSON form.
+    pub fn to_json(&self) -> Result<String, String> {
+        let node = self.current().ok_or("Current node no longer exists")?;
+        value_to_serde(&node)
+            .ok()
+            .and_then(|json| serde_json::to_string_pretty(&json).ok())
+            .ok_or_else(|| {
+                format!(
+                    "{} : {} has no JSON form",
+                    self.path_string(),
+                    Self::summary(&node)
+                )
+            })
+    }
+
+    /// Copy the node in view to the clipboard as JSON and report it (complexity: 3)
+    pub fn copy_json(&self) -> String {
+        if !io::stdout().is_terminal() {
+            return "Copying needs a terminal; use :explore json to print instead".to_string();
+        }
+        match self.to_json() {
+            Ok(json) => match copy_to_clipboard(&json) {
+                Ok(()) => format!(
+                    "Copied {} as JSON ({} bytes)",
+                    self.path_string(),
+                    json.len()
+                ),
+                Err(e) => format!("Copy failed: {e}"),
+            },
+            Err(msg) => msg,
+        }
+    }
+
+    /// Browse with the keyboard until `q` or Esc (complexity: 9)
+    ///
+    /// Draws on the terminal's alternate screen, so the REPL's scrollback is
+    /// left as it was; `y` copies the node in view as JSON.
+    ///
+    /// # Errors
+    ///
+    /// Fails when standard input or output is not a terminal, or when the
+    /// terminal cannot be switched to raw mode.
+    pub fn browse(&mut self) -> io::Result<()> {
+        use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+
+        if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+            return Err(io::Error::other("keyboard navigation needs a terminal"));
+        }
+        let _screen = RawScreen::enter()?;
+        let mut status = String::new();
+        loop {
+            self.draw(&status)?;
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            status.clear();
+            let nav = match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Char('y') => {
+                    status = self.copy_json();
+                    continue;
+                }
+                KeyCode::Up | KeyCode::Char('k') => NavKey::Up,
+                KeyCode::Down | KeyCode::Char('j') => NavKey::Down,
+                KeyCode::Right | KeyCode::Enter | KeyCode::Char('l') => NavKey::Open,
+                KeyCode::Left | KeyCode::Backspace | KeyCode::Char('h') => NavKey::Back,
+                KeyCode::PageDown | KeyCode::Char('n') => NavKey::NextPage,
+                KeyCode::PageUp | KeyCode::Char('p') => NavKey::PrevPage,
+                _ => continue,
+            };
+            self.press(nav);
+        }
+    }
+
+    /// Redraw the navigator screen (complexity: 1)
+    fn draw(&self, status: &str) -> io::Result<()> {
+        use crossterm::{cursor, execute, terminal};
+
+        let mut out = io::stdout();
+        execute!(
+            out,
+            terminal::Clear(terminal::ClearType::All),
+            cursor::MoveTo(0, 0)
+        )?;
+        // Raw mode does not return the carriage at line ends
+        let screen = format!("{}{NAV_HELP}\n{status}", self.render_page(true));
+        write!(out, "{}", screen.replace('\n', "\r\n"))?;
+        out.flush()
+    }
+
+    /// Depth-first search helper (complexity: 6)
+    fn find_in(
+        node: &Value,
+        needle: &str,
+        path: &mut Vec<PathSegment>,
+        depth: usize,
+        matches: &mut Vec<String>,
+    ) {
+        if depth > MAX_FIND_DEPTH || matches.len() >= MAX_FIND_RESULTS {
+            return;
+        }
+        for (segment, child) in Self::children(node) {
+            let key_hit =
+                matches!(&segment, PathSegment::Key(k) if k.to_lowercase().contains(needle));
+            path.push(segment);
+            let is_leaf = Self::child_count(&child) == 0;
+            let value_hit = is_leaf && child.to_string().to_lowercase().contains(needle);
+            if (key_hit || value_hit) && matches.len() < MAX_FIND_RESULTS {
+                matches.push(format!(
+                    "{} = {}",
+                    Self::format_path(path),
+                    Self::preview(&child)
+                ));
+            }
+            if !is_leaf {
+                Self::find_in(&child, needle, path, depth + 1, matches);
+            }
+            path.pop();
+        }
+    }
+
+    /// Format a path rooted at `$` (complexity: 1)
+    fn format_path(path: &[PathSegment]) -> String {
+        path.iter().fold("$".to_string(), |mut acc, segment| {
+            let _ = write!(acc, "{segment}");
+            acc
+        })
+    }
+
+    /// Whether numeric selectors address positions rather than keys (complexity: 1)
+    fn is_indexed(value: &Value) -> bool {
+        matches!(
+            value,
+            Value::Array(_) | Value::Tuple(_) | Value::EnumVariant { .. }
+        )
+    }
+
+    /// Number of direct children without materializing them (complexity: 7)
+    fn child_count(value: &Value) -> usize {
+        match value {
+            Value::Array(items) | Value::Tuple(items) => items.len(),
+            Value::Object(map) => map.len(),
+            Value::ObjectMut(map) => map.lock().map_or(0, |m| m.len()),
+            Value::Struct { fields, .. } => fields.len(),
+            Value::Class { fields, .. } => fields.read().map_or(0, |f| f.len()),
+            Value::DataFrame { columns } => columns.len(),
+            Value::EnumVariant { data, .. } => data.as_ref().map_or(0, Vec::len),
+            _ => 0,
+        }
+    }
+
+    /// Look up a single child without expanding siblings (complexity: 9)
+    fn child(value: &Value, segment: &PathSegment) -> Option<Value> {
+        match (value, segment) {
+            (Value::Array(items) | Value::Tuple(items), PathSegment::Index(i)) => {
+                items.get(*i).cloned()
+            }
+            (Value::EnumVariant { data, .. }, PathSegment::Index(i)) => {
+                data.as_ref().and_then(|d| d.get(*i).cloned())
+            }
+            (Value::Object(map), PathSegment::Key(k)) => map.get(k).cloned(),
+            (Value::ObjectMut(map), PathSegment::Key(k)) => map.lock().ok()?.get(k).cloned(),
+            (Value::Struct { fields, .. }, PathSegment::Key(k)) => fields.get(k).cloned(),
+            (Value::Class { fields, .. }, PathSegment::Key(k)) => {
+                fields.read().ok()?.get(k).cloned()
+            }
+            (Value::DataFrame { columns }, PathSegment::Key(k)) => columns
+                .iter()
+                .find(|c| &c.name == k)
+                .map(|c| Value::from_array(c.values.clone())),
+            _ => None,
+        }
+    }
+
+    /// Materialize the direct children of a node, keys sorted for stable paging (complexity: 8)
+    fn children(value: &Value) -> Vec<(PathSegment, Value)> {
+        let indexed = |items: &[Value]| -> Vec<(PathSegment, Value)> {
+            items
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (PathSegment::Index(i), v.clone()))
+                .collect()
+        };
+        let keyed = |map: &std::collections::HashMap<String, Value>| -> Vec<(PathSegment, Value)> {
+            let mut entries: Vec<_> = map
+                .iter()
+                .map(|(k, v)| (PathSegment::Key(k.clone()), v.clone()))
+                .collect();
+            entries.sort_by(|a, b| a.0.to_string().cmp(&b.0.to_string()));
+            entries
+        };
+        match value {
+            Value::Array(items) | Value::Tuple(items) => indexed(items),
+            Value::EnumVariant { data, .. } => data.as_deref().map_or_else(Vec::new, indexed),
+            Value::Object(map) => keyed(map),
+            Value::ObjectMut(map) => map.lock().map_or_else(|_| Vec::new(), |m| keyed(&m)),
+            Value::Struct { fields, .. } => keyed(fields),
+            Value::Class { fields, .. } => fields.read().map_or_else(|_| Vec::new(), |f| keyed(&f)),
+            Value::DataFrame { columns } => columns
+                .iter()
+                .map(|c| {
+                    (
+                        PathSegment::Key(c.name.clone()),
+                        Value::from_array(c.values.clone()),
+                    )
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// One-line type summary of a node (complexity: 8)
+    fn summary(value: &Value) -> String {
+        match value {
+            Value::Array(items) => format!("Array(len {})", items.len()),
+            Value::Tuple(items) => format!("Tuple(len {})", items.len()),
+            Value::Struct { name, fields } => format!("{name} {{{} fields}}", fields.len()),
+            Value::Class { class_name, .. } => format!("{class_name} (class)"),
+            Value::DataFrame { columns } => format!(
+                "DataFrame({} cols x {} rows)",
+                columns.len(),
+                columns.first().map_or(0, |c| c.values.len())
+            ),
+            Value::EnumVariant {
+                enum_name,
+                variant_name,
+                ..
+            } => format!("{enum_name}::{variant_name}"),
+            Value::Object(_) | Value::ObjectMut(_) => {
+                format!("Object({} keys)", Self::child_count(value))
+            }
+            other => super::commands::CommandRegistry::value_type_name(other).to_string(),
+        }
+    }
+
+    /// Short preview: summaries for containers, truncated text for scalars (complexity: 3)
+    fn preview(value: &Value) -> String {
+        if Self::child_count(value) > 0 {
+            return Self::summary(value);
+        }
+        let text = value.to_string();
+        if text.chars().count() > MAX_PREVIEW_LEN {
+            let truncated: String = text.chars().take(MAX_PREVIEW_LEN).collect();
+            format!("{truncated}…")
+        } else {
+            text
+        }
+    }
+}
+
+/// Raw mode on the alternate screen, restored when dropped
+struct RawScreen;
+
+impl RawScreen {
+    fn enter() -> io::Result<Self> {
+        crossterm::terminal::enable_raw_mode()?;
+        let screen = Self;
+        crossterm::execute!(io::stdout(), crossterm::terminal::EnterAlternateScreen)?;
+        Ok(screen)
+    }
+}
+
+impl Drop for RawScreen {
+    fn drop(&mut self) {
+        let _ = crossterm::execute!(io::stdout(), crossterm::terminal::LeaveAlternateScreen);
+        let _ = crossterm::terminal::disable_raw_mode();
+    }
+}
+
+/// Copy `text` to the clipboard of the terminal running the REPL (complexity: 1)
+///
+/// Uses the OSC 52 escape sequence, which terminal emulators (and tmux with
+/// `set-clipboard on`) apply to the system clipboard, also over SSH.
+fn copy_to_clipboard(text: &str) -> io::Result<()> {
+    let mut out = io::stdout();
+    write!(out, "\x1b]52;c;{}\x07", base64_encode(text.as_bytes()))?;
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    fn nested() -> Value {
+        let mut inner = HashMap::new();
+        inner.insert("name".to_string(), Value::from_string("ada".to_string()));
+        inner.insert(
+            "scores".to_string(),
+            Value::from_array((0..45).map(Value::Integer).collect()),
+        );
+        let mut outer = HashMap::new();
+        outer.insert("user".to_string(), Value::Object(Arc::new(inner)));
+        outer.insert("active".to_string(), Value::Bool(true));
+        Value::Object(Arc::new(outer))
+    }
+
+    #[test]
+    fn test_render_root_lists_sorted_keys() {
+        let explorer = ObjectExplorer::new(nested());
+        let out = explorer.render();
+        assert!(out.starts_with("$ : Object(2 keys)"));
+        let active = out.find(".active").expect("active listed");
+        let user = out.find(".user").expect("user listed");
+        assert!(active < user);
+    }
+
+    #[test]
+    fn test_enter_and_up() {
+        let mut explorer = ObjectExplorer::new(nested());
+        explorer.enter("user").expect("user exists");
+        explorer.enter("scores").expect("scores exists");
+        assert_eq!(explorer.path_string(), "$.user.scores");
+        explorer.enter("[3]").expect("index exists");
+        assert_eq!(explorer.current(), Some(Value::Integer(3)));
+        assert!(explorer.up());
+        assert!(explorer.up());
+        assert!(explorer.up());
+        assert!(!explorer.up());
+    }
+
+    #[test]
+    fn test_enter_missing_child_is_error() {
+        let mut explorer = ObjectExplorer::new(nested());
+        assert!(explorer.enter("missing").is_err());
+        assert_eq!(explorer.path_string(), "$");
+    }
+
+    #[test]
+    fn test_pagination() {
+        let mut explorer = ObjectExplorer::new(nested());
+        explorer.enter("user").expect("user exists");
+        explorer.enter("scores").expect("scores exists");
+        assert_eq!(explorer.page_count(), 3);
+        assert!(explorer.render().contains("-- page 1/3 (45 entries) --"));
+        assert!(explorer.next_page());
+        assert!(explorer.render().contains("[20]: 20"));
+        assert!(explorer.next_page());
+        assert!(!explorer.next_page());
+        assert!(explorer.prev_page());
+        assert!(explorer.goto_page(1).is_ok());
+        assert!(explorer.goto_page(4).is_err());
+    }
+
+    #[test]
+    fn test_find_matches_keys_and_values() {
+        let explorer = ObjectExplorer::new(nested());
+        let hits = explorer.find("ADA");
+        assert_eq!(hits, vec!["$.user.name = \"ada\"".to_string()]);
+        assert!(explorer.find("scores")[0].starts_with("$.user.scores"));
+    }
+
+    #[test]
+    fn test_to_json_of_subtree() {
+        let mut explorer = ObjectExplorer::new(nested());
+        explorer.enter("user").expect("user exists");
+        let json: serde_json::Value =
+            serde_json::from_str(&explorer.to_json().expect("has json form")).expect("valid json");
+        assert_eq!(json["name"], "ada");
+        assert_eq!(json["scores"][44], 44);
+    }
+
+    #[test]
+    fn test_to_json_of_too_deep_value_is_error() {
+        let deep = (0..200).fold(Value::Integer(0), |v, _| Value::from_array(vec![v]));
+        let err = ObjectExplorer::new(deep).to_json().expect_err("too deep");
+        assert!(err.starts_with("$ : Array(len 1) has no JSON form"), "{err}");
+    }
+
+    #[test]
+    fn test_keys_select_open_and_back() {
+        let mut explorer = ObjectExplorer::new(nested());
+        explorer.press(NavKey::Up);
+        assert_eq!(explorer.selected(), 0);
+        explorer.press(NavKey::Down);
+        explorer.press(NavKey::Down);
+        assert_eq!(explorer.selected(), 1);
+        explorer.press(NavKey::Open);
+        assert_eq!(explorer.path_string(), "$.user");
+        explorer.press(NavKey::Down);
+        explorer.press(NavKey::Open);
+        assert_eq!(explorer.path_string(), "$.user.scores");
+        explorer.press(NavKey::Back);
+        assert_eq!(explorer.path_string(), "$.user");
+        assert_eq!(explorer.selected(), 1);
+    }
+
+    #[test]
+    fn test_keys_follow_selection_across_pages() {
+        let mut explorer = ObjectExplorer::new(nested());
+        explorer.enter("user").expect("user exists");
+        explorer.enter("scores").expect("scores exists");
+        explorer.press(NavKey::NextPage);
+        assert_eq!((explorer.page(), explorer.selected()), (1, 20));
+        explorer.press(NavKey::Up);
+        assert_eq!((explorer.page(), explorer.selected()), (0, 19));
+        explorer.press(NavKey::Open);
+        assert_eq!(explorer.current(), Some(Value::Integer(19)));
+        explorer.press(NavKey::Back);
+        assert_eq!((explorer.page(), explorer.selected()), (0, 19));
+        let page = explorer.render_page(true);
+        let marked: Vec<_> = page.lines().filter(|l| l.starts_with("> ")).collect();
+        assert_eq!(marked, vec![">   [19]: 19"]);
+        assert!(!explorer.render().contains("> "));
+    }
+
+    #[test]
+    fn test_scalar_root_renders_value() {
+        let explorer = ObjectExplorer::new(Value::Integer(7));
+        assert!(explorer.render().contains("  7"));
+        assert_eq!(explorer.page_count(), 1);
+    }
+}
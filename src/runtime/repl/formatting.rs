@@ -2,15 +2,134 @@
 //!
 //! Handles formatting of values, errors, and AST for display in the REPL.
 
+use crate::frontend::ast::Expr;
+use serde_json::Value as Json;
+
 /// Format an error for display in the REPL (complexity: 2)
 pub fn format_error(error: &str) -> String {
     format!("Error: {error}")
 }
 
-/// Format AST for display in the REPL (complexity: 1)
-pub fn format_ast(ast: &str) -> String {
-    // Simplified AST formatting for now
-    format!("AST: {ast}")
+/// Format a warning for display in the REPL (complexity: 1)
+pub fn format_warning(warning: &str) -> String {
+    format!("Warning: {warning}")
+}
+
+/// Fields left out of AST outlines
+const AST_NOISE: [&str; 4] = ["span", "attributes", "leading_comments", "trailing_comment"];
+
+/// Format AST for display in the REPL as an indented outline (complexity: 2)
+///
+/// Each node shows its kind, then its fields one level deeper. Spans,
+/// comments and unset fields are left out, and names are not quoted.
+pub fn format_ast(ast: &Expr) -> String {
+    match serde_json::to_value(ast) {
+        Ok(json) => {
+            let mut out = String::new();
+            outline(&json, 0, &mut out);
+            out.trim_end().to_string()
+        }
+        Err(_) => format!("{ast:#?}"),
+    }
+}
+
+/// Append `json` to `out` as lines indented `depth` levels (complexity: 9)
+fn outline(json: &Json, depth: usize, out: &mut String) {
+    if let Some(text) = inline(json) {
+        push_line(out, depth, &text);
+        return;
+    }
+    match node(json) {
+        Json::Array(items) => {
+            for item in items.iter().filter(|item| !is_unset(item)) {
+                outline(item, depth, out);
+            }
+        }
+        Json::Object(fields) if fields.len() == 1 => {
+            for (variant, payload) in fields {
+                push_line(out, depth, variant);
+                outline(payload, depth + 1, out);
+            }
+        }
+        Json::Object(fields) => {
+            for (name, field) in fields {
+                if AST_NOISE.contains(&name.as_str())
+                    || is_unset(field)
+                    || matches!(field, Json::Bool(false))
+                {
+                    continue;
+                }
+                match inline(field) {
+                    Some(text) => push_line(out, depth, &format!("{name}: {text}")),
+                    None => {
+                        push_line(out, depth, &format!("{name}:"));
+                        outline(field, depth + 1, out);
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `json` on one line, if it is a scalar or variants wrapping scalars (complexity: 8)
+fn inline(json: &Json) -> Option<String> {
+    match node(json) {
+        Json::Bool(b) => Some(b.to_string()),
+        Json::Number(n) => Some(n.to_string()),
+        Json::String(s) if !s.is_empty() && s.chars().all(|c| c.is_alphanumeric() || c == '_') => {
+            Some(s.clone())
+        }
+        Json::String(s) => Some(format!("{s:?}")),
+        Json::Array(items) => {
+            let items: Vec<&Json> = items.iter().filter(|item| !is_unset(item)).collect();
+            if items.iter().any(|item| item.is_array() || item.is_object()) {
+                return None;
+            }
+            let texts: Option<Vec<String>> = items.into_iter().map(inline).collect();
+            Some(texts?.join(" "))
+        }
+        Json::Object(fields) if fields.len() == 1 => {
+            let (variant, payload) = fields.iter().next()?;
+            if is_unset(payload) {
+                return Some(variant.clone());
+            }
+            let text = inline(payload)?;
+            Some(if text.is_empty() {
+                variant.clone()
+            } else {
+                format!("{variant} {text}")
+            })
+        }
+        _ => None,
+    }
+}
+
+/// The kind of an expression or type node, otherwise `json` itself (complexity: 2)
+fn node(json: &Json) -> &Json {
+    match json {
+        Json::Object(fields) if fields.contains_key("kind") && fields.contains_key("span") => {
+            &fields["kind"]
+        }
+        _ => json,
+    }
+}
+
+/// Whether a field holds nothing worth showing (complexity: 1)
+fn is_unset(json: &Json) -> bool {
+    match json {
+        Json::Null => true,
+        Json::Array(items) => items.is_empty(),
+        Json::Object(fields) => fields.is_empty(),
+        _ => false,
+    }
+}
+
+/// Append `text` as a line indented `depth` levels (complexity: 1)
+fn push_line(out: &mut String, depth: usize, text: &str) {
+    out.push_str(&"  ".repeat(depth));
+    out.push_str(text);
+    out.push('\n');
 }
 
 #[cfg(test)]
@@ -62,6 +181,31 @@ mod tests {
         assert_eq!(value.to_string(), "(1, \"test\")");
     }
 
+    #[test]
+    fn test_format_ast_outline() {
+        let ast = crate::frontend::parser::Parser::new("1 + 2")
+            .parse()
+            .expect("should parse");
+        assert_eq!(
+            format_ast(&ast),
+            "Binary\n  left: Literal Integer 1\n  op: Add\n  right: Literal Integer 2"
+        );
+    }
+
+    #[test]
+    fn test_format_ast_leaves_out_noise() {
+        let ast = crate::frontend::parser::Parser::new("let greeting = \"hi there\" // note")
+            .parse()
+            .expect("should parse");
+        let outline = format_ast(&ast);
+        assert!(outline.starts_with("Let\n"), "{outline}");
+        assert!(outline.contains("name: greeting"), "{outline}");
+        assert!(outline.contains("Literal String \"hi there\""), "{outline}");
+        for noise in ["span", "is_mutable", "null", "note"] {
+            assert!(!outline.contains(noise), "{outline}");
+        }
+    }
+
     #[test]
     fn test_format_error() {
         assert_eq!(format_error("test error"), "Error: test error");
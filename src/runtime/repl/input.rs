@@ -0,0 +1,317 @@
+//! Multi-line Input Editing
+//!
+//! Pressing Enter submits the input only once it is complete. Input is
+//! incomplete while a bracket, string or block comment is still open, or
+//! when its brackets balance but the parser runs out of input (`let x =`,
+//! `1 +`). Any other parse error means more lines cannot fix the input, so
+//! it is submitted and the error shown at once. An empty continuation line
+//! submits the input as it is, to get out of a construct the check gets
+//! wrong.
+//!
+//! The same token stream drives the live highlighting of the line being
+//! edited: keywords, literals and comments are colored, and the bracket
+//! matching the one at the cursor is shown in bold. Brackets inside strings
+//! and comments are ignored by both.
+
+use crate::frontend::ast::Span;
+use crate::frontend::lexer::{LexErrorKind, Token, TokenStream};
+use crate::frontend::parser::Parser;
+use rustyline::completion::Completer;
+use rustyline::highlight::{CmdKind, Highlighter};
+use rustyline::hint::Hinter;
+use rustyline::validate::{ValidationContext, ValidationResult, Validator};
+use rustyline::Helper;
+use std::borrow::Cow;
+use std::cell::Cell;
+
+const RESET: &str = "\x1b[0m";
+const COMMENT: &str = "\x1b[90m";
+const STRING: &str = "\x1b[32m";
+const NUMBER: &str = "\x1b[33m";
+const KEYWORD: &str = "\x1b[35m";
+const ERROR: &str = "\x1b[31m";
+const MATCHING_BRACKET: &str = "\x1b[1;34m";
+
+/// Whether `input` is ready to be evaluated (complexity: 9)
+pub fn is_complete(input: &str) -> bool {
+    let trimmed = input.trim();
+    if trimmed.is_empty() || trimmed.starts_with(':') || ends_with_blank_line(input) {
+        return true;
+    }
+    let mut depth = 0i32;
+    for (token, span) in tokens(input) {
+        match token {
+            Token::Error(LexErrorKind::UnterminatedString) => return false,
+            Token::BlockComment(_) if !input[span.start..span.end].ends_with("*/") => return false,
+            _ => match bracket(&token) {
+                Some(ch) if is_open(ch) => depth += 1,
+                Some(_) => depth -= 1,
+                None => {}
+            },
+        }
+    }
+    if depth != 0 {
+        // Extra closing brackets cannot be fixed by typing more
+        return depth < 0;
+    }
+    let mut parser = Parser::new(input);
+    parser.parse().is_ok() || parser.error_span().start < input.trim_end().len()
+}
+
+/// `line` with ANSI colors, and the bracket pair at `cursor` in bold (complexity: 4)
+///
+/// Only escape codes are added, so the text keeps its display width.
+pub fn highlight(line: &str, cursor: Option<usize>) -> String {
+    let tokens = tokens(line);
+    let pair = cursor.and_then(|pos| matching_pair(&tokens, pos));
+    let mut out = String::with_capacity(line.len() * 2);
+    let mut last = 0;
+    for (token, span) in &tokens {
+        let text = &line[span.start..span.end];
+        out.push_str(&line[last..span.start]);
+        let matched =
+            pair.is_some_and(|(a, b)| bracket_offset(span) == a || bracket_offset(span) == b);
+        let color = if matched && bracket(token).is_some() {
+            Some(MATCHING_BRACKET)
+        } else {
+            style(token, text)
+        };
+        match color {
+            Some(color) => {
+                out.push_str(color);
+                out.push_str(text);
+                out.push_str(RESET);
+            }
+            None => out.push_str(text),
+        }
+        last = span.end;
+    }
+    out.push_str(&line[last..]);
+    out
+}
+
+/// Rustyline helper that validates and highlights REPL input
+#[derive(Debug, Default)]
+pub struct InputHelper {
+    /// Whether the next render shows the matching bracket
+    show_match: Cell<bool>,
+}
+
+impl Helper for InputHelper {}
+
+impl Completer for InputHelper {
+    type Candidate = String;
+}
+
+impl Hinter for InputHelper {
+    type Hint = String;
+}
+
+impl Highlighter for InputHelper {
+    fn highlight<'l>(&self, line: &'l str, pos: usize) -> Cow<'l, str> {
+        Cow::Owned(highlight(line, self.show_match.get().then_some(pos)))
+    }
+
+    fn highlight_char(&self, _line: &str, _pos: usize, kind: CmdKind) -> bool {
+        // Redraw on every edit so colors follow the tokens; the final render
+        // of an accepted line leaves the bracket pair out
+        let live = kind != CmdKind::ForcedRefresh;
+        self.show_match.set(live);
+        live
+    }
+}
+
+impl Validator for InputHelper {
+    fn validate(&self, ctx: &mut ValidationContext) -> rustyline::Result<ValidationResult> {
+        Ok(if is_complete(ctx.input()) {
+            ValidationResult::Valid(None)
+        } else {
+            ValidationResult::Incomplete
+        })
+    }
+}
+
+/// Tokens of `input` with their spans (complexity: 2)
+fn tokens(input: &str) -> Vec<(Token, Span)> {
+    // The token stream drops a leading shebang line, which would shift
+    // every span; such input is not highlighted
+    if input.starts_with("#!") {
+        return Vec::new();
+    }
+    let mut stream = TokenStream::new(input);
+    std::iter::from_fn(|| stream.next()).collect()
+}
+
+/// Whether the last of several lines is blank (complexity: 2)
+fn ends_with_blank_line(input: &str) -> bool {
+    input
+        .rsplit_once('\n')
+        .is_some_and(|(_, last)| last.trim().is_empty())
+}
+
+/// Bracket character of a token (complexity: 1)
+fn bracket(token: &Token) -> Option<char> {
+    match token {
+        Token::LeftParen => Some('('),
+        Token::RightParen => Some(')'),
+        Token::LeftBracket | Token::AttributeStart => Some('['),
+        Token::RightBracket => Some(']'),
+        Token::LeftBrace => Some('{'),
+        Token::RightBrace => Some('}'),
+        _ => None,
+    }
+}
+
+/// Offset of the bracket in a bracket token's span; `#[` ends with it (complexity: 1)
+fn bracket_offset(span: &Span) -> usize {
+    span.end - 1
+}
+
+/// Whether a bracket character opens a pair (complexity: 1)
+fn is_open(ch: char) -> bool {
+    matches!(ch, '(' | '[' | '{')
+}
+
+/// Whether `open` and `close` form a pair (complexity: 1)
+fn pairs(open: char, close: char) -> bool {
+    matches!((open, close), ('(', ')') | ('[', ']') | ('{', '}'))
+}
+
+/// Offsets of the bracket at or just before `pos` and of its partner (complexity: 6)
+fn matching_pair(tokens: &[(Token, Span)], pos: usize) -> Option<(usize, usize)> {
+    let brackets: Vec<(usize, char)> = tokens
+        .iter()
+        .filter_map(|(token, span)| Some((bracket_offset(span), bracket(token)?)))
+        .collect();
+    let at = |offset: usize| brackets.iter().position(|&(o, _)| o == offset);
+    let index = at(pos).or_else(|| pos.checked_sub(1).and_then(at))?;
+    let (offset, ch) = brackets[index];
+    let mut depth = 0;
+    if is_open(ch) {
+        for &(other, close) in &brackets[index..] {
+            depth += if is_open(close) { 1 } else { -1 };
+            if depth == 0 {
+                return pairs(ch, close).then_some((offset, other));
+            }
+        }
+    } else {
+        for &(other, open) in brackets[..=index].iter().rev() {
+            depth += if is_open(open) { -1 } else { 1 };
+            if depth == 0 {
+                return pairs(open, ch).then_some((other, offset));
+            }
+        }
+    }
+    None
+}
+
+/// Color of a token, if it has one (complexity: 6)
+fn style(token: &Token, text: &str) -> Option<&'static str> {
+    match token {
+        Token::DocComment(_)
+        | Token::LineComment(_)
+        | Token::BlockComment(_)
+        | Token::HashComment(_) => Some(COMMENT),
+        Token::String(_)
+        | Token::FString(_)
+        | Token::RawString(_)
+        | Token::Regex(_)
+        | Token::Char(_)
+        | Token::Byte(_) => Some(STRING),
+        Token::Integer(_)
+        | Token::RadixInteger(_)
+        | Token::BigInteger(_)
+        | Token::DecimalLiteral(_)
+        | Token::Float(_)
+        | Token::Bool(_)
+        | Token::Null => Some(NUMBER),
+        Token::Error(_) => Some(ERROR),
+        Token::Identifier(_) => None,
+        _ if text.starts_with(char::is_alphabetic) => Some(KEYWORD),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complete_input() {
+        for input in [
+            "",
+            "1 + 2",
+            ":help",
+            "fun f() { 1 }",
+            "let s = \"a { b\"",
+            "x // unclosed {",
+            "[1, 2]\n",
+        ] {
+            assert!(is_complete(input), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_incomplete_input() {
+        for input in [
+            "fun f() {",
+            "[1, 2,",
+            "foo(1,\n  2",
+            "let x =",
+            "let x = 1 +",
+            "\"unterminated",
+            "/* still a comment",
+        ] {
+            assert!(!is_complete(input), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_errors_that_more_input_cannot_fix_are_complete() {
+        assert!(is_complete("1 )"));
+        assert!(is_complete("(1]"));
+    }
+
+    #[test]
+    fn test_blank_line_submits_unfinished_input() {
+        assert!(!is_complete("fun f() {\n  1"));
+        assert!(is_complete("fun f() {\n  1\n"));
+        assert!(is_complete("fun f() {\n  1\n  "));
+    }
+
+    #[test]
+    fn test_highlight_keeps_text() {
+        let line = "let s = \"x\" // note";
+        let highlighted = highlight(line, None);
+        assert!(highlighted.contains(&format!("{KEYWORD}let{RESET}")));
+        assert!(highlighted.contains(&format!("{STRING}\"x\"{RESET}")));
+        assert!(highlighted.contains(&format!("{COMMENT}// note{RESET}")));
+        let plain = highlighted
+            .replace(RESET, "")
+            .replace(KEYWORD, "")
+            .replace(STRING, "")
+            .replace(COMMENT, "");
+        assert_eq!(plain, line);
+    }
+
+    #[test]
+    fn test_matching_bracket_at_cursor() {
+        let line = "f(a[1], \"(\")";
+        let tokens = tokens(line);
+        assert_eq!(matching_pair(&tokens, 1), Some((1, 11)));
+        assert_eq!(matching_pair(&tokens, 12), Some((1, 11)));
+        assert_eq!(matching_pair(&tokens, 3), Some((3, 5)));
+        // The parenthesis inside the string is not a bracket
+        assert_eq!(matching_pair(&tokens, 9), None);
+        assert_eq!(matching_pair(&tokens, 0), None);
+    }
+
+    #[test]
+    fn test_highlight_marks_matching_pair() {
+        let highlighted = highlight("(1)", Some(0));
+        assert_eq!(
+            highlighted,
+            format!("{MATCHING_BRACKET}({RESET}{NUMBER}1{RESET}{MATCHING_BRACKET}){RESET}")
+        );
+    }
+}
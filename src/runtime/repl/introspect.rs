@@ -0,0 +1,153 @@
+//! Compiler Stages for `:type` and `:rust`
+//!
+//! `:type` runs type inference on an expression without evaluating it. The
+//! expression sees the session's definitions: each successful input is
+//! parsed again and its top-level `let`s and functions are inferred in
+//! order, so `:type add(1, 2)` works after `fun add(a, b) { a + b }`. A
+//! definition that inference cannot handle falls back to the type of its
+//! current value, or to an unknown type when the value has no simple one.
+//!
+//! `:rust` shows the program the transpiler generates for an expression,
+//! formatted as `ruchy transpile` would print it.
+
+use crate::backend::transpiler::{OutputStyle, Transpiler, TranspilerOptions};
+use crate::frontend::ast::{Expr, ExprKind, Literal};
+use crate::frontend::parser::Parser;
+use crate::middleend::{InferenceContext, MonoType, TyVar, TypeEnv, TypeScheme};
+use crate::runtime::interpreter::Value;
+use anyhow::Result;
+use std::collections::HashSet;
+
+/// Inferred type of `ast` in a session with `inputs` and `bindings` (complexity: 1)
+///
+/// `inputs` are the session's successful inputs in order and `bindings` the
+/// user's current global definitions.
+pub fn infer_type(ast: &Expr, inputs: &[String], bindings: &[(String, Value)]) -> Result<MonoType> {
+    InferenceContext::with_env(session_type_env(inputs, bindings)).infer(ast)
+}
+
+/// Rust program generated for `ast`, pretty-printed (complexity: 1)
+pub fn rust_source(ast: &Expr) -> Result<String> {
+    let options = TranspilerOptions::builder()
+        .style(OutputStyle::Pretty)
+        .build();
+    Ok(Transpiler::with_options(options)
+        .transpile_program(ast)?
+        .code)
+}
+
+/// Standard environment plus the types of the user's definitions (complexity: 6)
+fn session_type_env(inputs: &[String], bindings: &[(String, Value)]) -> TypeEnv {
+    let mut session = TypeEnv::standard();
+    let mut inferred = HashSet::new();
+    let statements = inputs
+        .iter()
+        .filter_map(|input| Parser::new(input).parse().ok())
+        .flat_map(top_level);
+    for statement in statements {
+        let (name, definition) = match &statement.kind {
+            ExprKind::Let {
+                name, value, body, ..
+            } if matches!(body.kind, ExprKind::Literal(Literal::Unit)) => (name, value.as_ref()),
+            ExprKind::Function { name, .. } => (name, &statement),
+            _ => continue,
+        };
+        let Ok(ty) = InferenceContext::with_env(session.clone()).infer(definition) else {
+            continue;
+        };
+        let scheme = session.generalize(ty);
+        session.bind(name.clone(), scheme);
+        inferred.insert(name.clone());
+    }
+    // Only names still defined count; `:undef` may have removed some
+    let mut env = TypeEnv::standard();
+    for (name, value) in bindings {
+        let scheme = match session.lookup(name) {
+            Some(scheme) if inferred.contains(name) => scheme.clone(),
+            _ => value_type(value),
+        };
+        env.bind(name.clone(), scheme);
+    }
+    env
+}
+
+/// Statements of a parsed input (complexity: 2)
+fn top_level(ast: Expr) -> Vec<Expr> {
+    match ast.kind {
+        ExprKind::Block(statements) => statements,
+        _ => vec![ast],
+    }
+}
+
+/// Type of a runtime value, or an unknown type (complexity: 2)
+fn value_type(value: &Value) -> TypeScheme {
+    let ty = match value {
+        Value::Integer(_) => MonoType::Int,
+        Value::Float(_) => MonoType::Float,
+        Value::Bool(_) => MonoType::Bool,
+        Value::String(_) => MonoType::String,
+        Value::Nil => MonoType::Unit,
+        _ => {
+            let unknown = TyVar(0);
+            return TypeScheme {
+                vars: vec![unknown.clone()],
+                ty: MonoType::Var(unknown),
+            };
+        }
+    };
+    TypeScheme::mono(ty)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_of(source: &str, inputs: &[&str], bindings: &[(&str, Value)]) -> String {
+        let ast = Parser::new(source).parse().expect("should parse");
+        let inputs: Vec<String> = inputs.iter().map(ToString::to_string).collect();
+        let bindings: Vec<(String, Value)> = bindings
+            .iter()
+            .map(|(name, value)| ((*name).to_string(), value.clone()))
+            .collect();
+        infer_type(&ast, &inputs, &bindings)
+            .expect("should infer")
+            .to_string()
+    }
+
+    #[test]
+    fn test_expression_types() {
+        assert_eq!(type_of("1 + 2", &[], &[]), "i32");
+        assert_eq!(type_of("\"a\"", &[], &[]), "String");
+        assert_eq!(type_of("[true, false]", &[], &[]), "[bool]");
+    }
+
+    #[test]
+    fn test_session_definitions_are_typed() {
+        let inputs = ["let x = 1.5", "fun double(n) { n * 2 }"];
+        let bindings = [
+            ("x", Value::Float(1.5)),
+            ("double", Value::BuiltinFunction("double".to_string())),
+        ];
+        assert_eq!(type_of("x", &inputs, &bindings), "f64");
+        assert_eq!(type_of("double(4)", &inputs, &bindings), "i32");
+    }
+
+    #[test]
+    fn test_removed_definitions_are_not_typed() {
+        let ast = Parser::new("x").parse().expect("should parse");
+        assert!(infer_type(&ast, &["let x = 1".to_string()], &[]).is_err());
+    }
+
+    #[test]
+    fn test_uninferable_definitions_use_their_values() {
+        let bindings = [("s", Value::from_string("text".to_string()))];
+        assert_eq!(type_of("s", &["let s = mystery()"], &bindings), "String");
+    }
+
+    #[test]
+    fn test_rust_source_is_a_program() {
+        let ast = Parser::new("1 + 2").parse().expect("should parse");
+        let code = rust_source(&ast).expect("should transpile");
+        assert!(code.contains("fn main()"), "{code}");
+    }
+}
@@ -19,9 +19,15 @@ pub mod commands;
 pub mod completion;
 pub mod config;
 pub mod core;
+pub mod definitions;
 pub mod evaluation;
+pub mod explorer;
 pub mod formatting;
+pub mod input;
+pub mod introspect;
+pub mod session;
 pub mod state;
+pub mod workspace;
 
 // Internal modules
 mod minimal_test;
@@ -41,14 +47,27 @@ pub use self::commands::{CommandContext, CommandRegistry, CommandResult};
 pub use self::completion::CompletionEngine;
 
 // Evaluation
+pub use self::definitions::Undefined;
 pub use self::evaluation::{EvalResult, Evaluator};
 
+// Multi-line input
+pub use self::input::InputHelper;
+
+// Object explorer
+pub use self::explorer::ObjectExplorer;
+
 // Formatting utilities
-pub use self::formatting::{format_ast, format_error};
+pub use self::formatting::{format_ast, format_error, format_warning};
+
+// Golden sessions
+pub use self::session::SessionReport;
 
 // State management
 pub use self::state::{ReplMode, ReplState};
 
+// Saved workspaces
+pub use self::workspace::Workspace;
+
 // Re-export Value from interpreter for convenience
 pub use crate::runtime::interpreter::Value;
 
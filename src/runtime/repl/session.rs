@@ -0,0 +1,308 @@
+//! Golden REPL Sessions
+//!
+//! `ruchy repl --check-session guide.md` replays the REPL transcripts in a
+//! markdown file and reports every input whose output no longer matches,
+//! which keeps the REPL examples of the book and tutorials honest. A
+//! transcript is a fenced code block whose first non-blank line is a prompt:
+//!
+//! ````markdown
+//! ```ruchy
+//! ruchy> let xs = [1, 2, 3]
+//! [1, 2, 3]
+//!
+//! ruchy> xs.len()
+//! 3
+//! ```
+//! ````
+//!
+//! - Lines starting with `ruchy> ` are input and lines starting with
+//!   `ruchy... ` continue it; other modes use their own prompt (`debug> `).
+//! - The lines up to the next input are its expected output, exactly as the
+//!   terminal shows it. Trailing whitespace and trailing blank lines are
+//!   ignored.
+//! - A line holding only `...` matches any number of output lines.
+//! - Each transcript runs in a fresh REPL. Blocks tagged `ignore`
+//!   (```` ```ruchy ignore ````) are skipped.
+
+use super::core::Repl;
+use anyhow::Result;
+
+/// Expected-output line that matches any number of lines
+pub const ELLIPSIS: &str = "...";
+
+/// Prompts of the REPL modes
+const MODES: [&str; 4] = ["ruchy", "debug", "ast", "transpile"];
+
+/// One fenced REPL session
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transcript {
+    /// Line of the opening fence, 1-based
+    pub line: usize,
+    /// Inputs in order
+    pub steps: Vec<Step>,
+}
+
+/// One input of a transcript and the output it should show
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    /// Line of the input's prompt, 1-based
+    pub line: usize,
+    /// Input lines, without prompts
+    pub input: Vec<String>,
+    /// Expected output lines
+    pub expected: Vec<String>,
+}
+
+/// An input whose output differs from its transcript
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    /// Line of the input's prompt, 1-based
+    pub line: usize,
+    /// Input, lines joined with `\n`
+    pub input: String,
+    /// Output the transcript shows
+    pub expected: String,
+    /// Output the REPL showed
+    pub actual: String,
+}
+
+/// Outcome of replaying the transcripts of a file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionReport {
+    /// Transcripts replayed
+    pub transcripts: usize,
+    /// Inputs replayed
+    pub steps: usize,
+    /// Inputs whose output did not match
+    pub mismatches: Vec<Mismatch>,
+}
+
+/// The REPL transcripts of a markdown document (complexity: 6)
+pub fn transcripts(markdown: &str) -> Vec<Transcript> {
+    let mut transcripts = Vec::new();
+    let mut lines = markdown.lines().enumerate();
+    while let Some((index, line)) = lines.next() {
+        let Some((open, info)) = fence(line) else {
+            continue;
+        };
+        let mut body = Vec::new();
+        for (i, line) in lines.by_ref() {
+            if fence(line)
+                .is_some_and(|(close, rest)| close.starts_with(open) && rest.trim().is_empty())
+            {
+                break;
+            }
+            body.push((i + 1, line));
+        }
+        let ignored = info
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .any(|word| word == "ignore");
+        if !ignored {
+            transcripts.extend(transcript(index + 1, &body));
+        }
+    }
+    transcripts
+}
+
+/// Replay every transcript of `markdown`, each in a REPL from `new_repl` (complexity: 6)
+///
+/// # Errors
+///
+/// Returns the error of `new_repl`.
+pub fn check(markdown: &str, mut new_repl: impl FnMut() -> Result<Repl>) -> Result<SessionReport> {
+    let mut report = SessionReport::default();
+    for transcript in transcripts(markdown) {
+        report.transcripts += 1;
+        let mut repl = new_repl()?;
+        for step in &transcript.steps {
+            report.steps += 1;
+            let (actual, exit) = replay(&mut repl, &step.input);
+            let actual = normalize(actual.lines());
+            if !lines_match(&step.expected, &actual) {
+                report.mismatches.push(Mismatch {
+                    line: step.line,
+                    input: step.input.join("\n"),
+                    expected: step.expected.join("\n"),
+                    actual: actual.join("\n"),
+                });
+            }
+            if exit {
+                break;
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// Everything the REPL shows for `input`, and whether it exited (complexity: 3)
+fn replay(repl: &mut Repl, input: &[String]) -> (String, bool) {
+    let mut shown = String::new();
+    for line in input {
+        match repl.transcript_line(line) {
+            Ok((output, exit)) => {
+                shown.push_str(&output);
+                if exit {
+                    return (shown, true);
+                }
+            }
+            Err(e) => shown.push_str(&format!("Error: {e}\n")),
+        }
+    }
+    (shown, false)
+}
+
+/// Opening or closing fence: the backtick or tilde run and the rest (complexity: 3)
+fn fence(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim_start();
+    let marker = line.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let length = line.len() - line.trim_start_matches(marker).len();
+    (length >= 3).then(|| line.split_at(length))
+}
+
+/// Input of a prompt line and whether it continues the previous one (complexity: 4)
+fn prompt(line: &str) -> Option<(&str, bool)> {
+    MODES.iter().find_map(|mode| {
+        let rest = line.strip_prefix(mode)?;
+        let (input, continued) = match rest.strip_prefix("...") {
+            Some(input) => (input, true),
+            None => (rest.strip_prefix('>')?, false),
+        };
+        Some((input.strip_prefix(' ').unwrap_or(input), continued))
+    })
+}
+
+/// The steps of a fenced block, if it is a transcript (complexity: 7)
+fn transcript(line: usize, body: &[(usize, &str)]) -> Option<Transcript> {
+    let first = body.iter().find(|(_, text)| !text.trim().is_empty())?;
+    if !matches!(prompt(first.1), Some((_, false))) {
+        return None;
+    }
+    let mut steps: Vec<Step> = Vec::new();
+    for &(number, text) in body {
+        match (prompt(text), steps.last_mut()) {
+            (Some((input, true)), Some(step)) if step.expected.is_empty() => {
+                step.input.push(input.to_string());
+            }
+            (Some((input, false)), _) => steps.push(Step {
+                line: number,
+                input: vec![input.to_string()],
+                expected: Vec::new(),
+            }),
+            (_, Some(step)) => step.expected.push(text.to_string()),
+            (_, None) => {}
+        }
+    }
+    for step in &mut steps {
+        step.expected = normalize(step.expected.iter().map(String::as_str));
+    }
+    Some(Transcript { line, steps })
+}
+
+/// Lines without trailing whitespace or trailing blank lines (complexity: 2)
+fn normalize<'a>(lines: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let mut lines: Vec<String> = lines.map(|line| line.trim_end().to_string()).collect();
+    while lines.last().is_some_and(String::is_empty) {
+        lines.pop();
+    }
+    lines
+}
+
+/// Whether `actual` matches `expected`, where [`ELLIPSIS`] matches any lines (complexity: 5)
+fn lines_match(expected: &[String], actual: &[String]) -> bool {
+    match expected.split_first() {
+        None => actual.is_empty(),
+        Some((first, rest)) if first == ELLIPSIS => {
+            (0..=actual.len()).any(|skip| lines_match(rest, &actual[skip..]))
+        }
+        Some((first, rest)) => actual.first() == Some(first) && lines_match(rest, &actual[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fresh() -> Result<Repl> {
+        Repl::new(std::env::temp_dir())
+    }
+
+    const GUIDE: &str = r#"# Guide
+
+```ruchy
+ruchy> let xs = [1, 2, 3]
+[1, 2, 3]
+
+ruchy> xs.len()
+3
+```
+
+```ruchy ignore
+ruchy> 1 + 1
+11
+```
+
+```ruchy
+fun not_a_transcript() { 1 }
+```
+
+~~~
+ruchy> println("one")
+ruchy... println("two")
+one
+two
+~~~
+"#;
+
+    #[test]
+    fn test_transcripts_are_found_in_fences() {
+        let found = transcripts(GUIDE);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].line, 3);
+        assert_eq!(found[0].steps.len(), 2);
+        assert_eq!(found[0].steps[0].line, 4);
+        assert_eq!(found[0].steps[0].expected, ["[1, 2, 3]"]);
+        assert_eq!(
+            found[1].steps[0].input,
+            ["println(\"one\")", "println(\"two\")"]
+        );
+        assert_eq!(found[1].steps[0].expected, ["one", "two"]);
+    }
+
+    #[test]
+    fn test_matching_session_passes() {
+        let report = check(GUIDE, fresh).unwrap();
+        assert_eq!(report.transcripts, 2);
+        assert_eq!(report.steps, 3);
+        assert_eq!(report.mismatches, []);
+    }
+
+    #[test]
+    fn test_mismatch_names_its_line() {
+        let markdown = "```\nruchy> let x = 2\n2\nruchy> x * 21\n41\n```\n";
+        let report = check(markdown, fresh).unwrap();
+        assert_eq!(report.mismatches.len(), 1);
+        let mismatch = &report.mismatches[0];
+        assert_eq!(mismatch.line, 4);
+        assert_eq!(mismatch.input, "x * 21");
+        assert_eq!(mismatch.expected, "41");
+        assert_eq!(mismatch.actual, "42");
+    }
+
+    #[test]
+    fn test_transcripts_do_not_share_bindings() {
+        let markdown = "```\nruchy> let x = 1\n1\n```\n\n```\nruchy> x\n...\n```\n";
+        let report = check(markdown, fresh).unwrap();
+        assert_eq!(report.mismatches.len(), 0);
+        let strict = markdown.replace("...", "1");
+        assert_eq!(check(&strict, fresh).unwrap().mismatches.len(), 1);
+    }
+
+    #[test]
+    fn test_ellipsis_matches_any_lines() {
+        let lines = |text: &str| normalize(text.lines());
+        assert!(lines_match(&lines("a\n...\nd"), &lines("a\nb\nc\nd")));
+        assert!(lines_match(&lines("a\n...\nd"), &lines("a\nd")));
+        assert!(!lines_match(&lines("a\n...\nd"), &lines("a\nb")));
+        assert!(lines_match(&lines("...\n"), &[]));
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! Manages REPL state including variables, history, and mode.
 
+use super::explorer::ObjectExplorer;
 use crate::runtime::interpreter::Value;
 use std::collections::HashMap;
 
@@ -38,12 +39,16 @@ pub struct ReplState {
     bindings: HashMap<String, Value>,
     /// Command history
     history: Vec<String>,
+    /// Inputs that evaluated successfully, in order, for `:save`
+    inputs: Vec<String>,
     /// Result history (evaluation results)
     result_history: Vec<Value>,
     /// Maximum history size
     max_history: usize,
     /// Peak memory usage
     peak_memory: usize,
+    /// Active `:inspect` explorer session
+    explorer: Option<ObjectExplorer>,
 }
 
 impl ReplState {
@@ -53,9 +58,11 @@ impl ReplState {
             mode: ReplMode::Normal,
             bindings: HashMap::new(),
             history: Vec::new(),
+            inputs: Vec::new(),
             result_history: Vec::new(),
             max_history: 1000,
             peak_memory: 0,
+            explorer: None,
         }
     }
 
@@ -102,6 +109,16 @@ impl ReplState {
         self.history.clear();
     }
 
+    /// Record an input that evaluated successfully (complexity: 1)
+    pub fn add_input(&mut self, input: String) {
+        self.inputs.push(input);
+    }
+
+    /// Inputs that evaluated successfully, oldest first (complexity: 1)
+    pub fn get_inputs(&self) -> &[String] {
+        &self.inputs
+    }
+
     /// Add to result history (complexity: 3)
     pub fn add_to_result_history(&mut self, result: Value) {
         if self.result_history.len() >= self.max_history {
@@ -161,6 +178,16 @@ impl ReplState {
     pub fn is_debug_mode(&self) -> bool {
         matches!(self.mode, ReplMode::Debug)
     }
+
+    /// Replace the active `:inspect` explorer session (complexity: 1)
+    pub fn set_explorer(&mut self, explorer: Option<ObjectExplorer>) {
+        self.explorer = explorer;
+    }
+
+    /// Get the active explorer session, if any (complexity: 1)
+    pub fn explorer_mut(&mut self) -> Option<&mut ObjectExplorer> {
+        self.explorer.as_mut()
+    }
 }
 
 impl Default for ReplState {
@@ -395,6 +395,8 @@ fn test_repl_config_memory_limits() {
         max_memory: 100_000_000,
         timeout: Duration::from_secs(5),
         maxdepth: 1000,
+        restricted: false,
+        deadline: None,
     };
     let repl = Repl::with_config(config);
     assert!(repl.is_ok(), "REPL should accept memory limit config");
@@ -434,6 +436,8 @@ fn test_with_config_debug_mode() {
         timeout: Duration::from_millis(5000),
         maxdepth: 100,
         debug: true,
+        restricted: false,
+        deadline: None,
     };
     let repl = Repl::with_config(config);
     assert!(repl.is_ok());
@@ -484,7 +488,7 @@ fn test_memory_pressure() {
 fn test_needs_continuation_static() {
     assert!(!Repl::needs_continuation("partial expression"));
     assert!(!Repl::needs_continuation(""));
-    assert!(!Repl::needs_continuation("{"));
+    assert!(Repl::needs_continuation("{"));
 }
 
 #[test]
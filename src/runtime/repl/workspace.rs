@@ -0,0 +1,287 @@
+//! Saved REPL Workspaces
+//!
+//! `:save session.rsn` writes the state of a session to a file and
+//! `:load session.rsn` rebuilds it, so a long exploratory session can be
+//! resumed later. A workspace file is JSON holding a [`ReplSession`] of the
+//! replay subsystem and the session's history:
+//!
+//! - the session's timeline holds every input that evaluated successfully,
+//!   in order; loading replays them, which brings back the functions, types
+//!   and variables they defined;
+//! - its checkpoint holds the user's variables whose values can be written
+//!   as literals, and the name and type of every user definition;
+//! - `history` holds every line entered, commands and failed inputs too.
+//!
+//! Replaying an input repeats its side effects (what it prints is
+//! discarded), and values read from the clock, `random()` or files may come
+//! out differently the second time. After the replay, variables are set back
+//! to their saved values and definitions the session had removed with
+//! `:undef` are removed again, so the restored state is the saved one
+//! wherever it could be written down.
+
+use crate::runtime::interpreter::Value;
+use crate::runtime::replay::{
+    Event, EventId, InputMode, ReplSession, ResourceUsage, SessionMetadata, SessionRecorder,
+    StateCheckpoint,
+};
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Extension of workspace files
+///
+/// Required so that `:save` cannot overwrite a script by mistake.
+pub const EXTENSION: &str = "rsn";
+
+/// Tag of the sessions written by `:save`
+pub const WORKSPACE_TAG: &str = "workspace";
+
+/// A saved REPL session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workspace {
+    /// Inputs to replay and the checkpoint of the saved definitions
+    pub session: ReplSession,
+    /// Every line entered, oldest first
+    #[serde(default)]
+    pub history: Vec<String>,
+}
+
+/// What loading a workspace did
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Restored {
+    /// Inputs replayed successfully
+    pub replayed: usize,
+    /// Inputs that failed to replay, with their errors
+    pub failed: Vec<(String, String)>,
+    /// Variables set back to their saved values after the replay
+    pub reset: Vec<String>,
+    /// Definitions removed again because the saved session did not have them
+    pub removed: Vec<String>,
+}
+
+impl Workspace {
+    /// Workspace of a session's successful inputs, history and user definitions (complexity: 3)
+    pub fn capture(inputs: &[String], history: &[String], definitions: &[(String, Value)]) -> Self {
+        let mut recorder = SessionRecorder::new(SessionMetadata {
+            session_id: format!("ruchy-workspace-{}", chrono::Utc::now().timestamp()),
+            created_at: chrono::Utc::now().to_rfc3339(),
+            ruchy_version: env!("CARGO_PKG_VERSION").to_string(),
+            student_id: None,
+            assignment_id: None,
+            tags: vec![WORKSPACE_TAG.to_string()],
+        });
+        let mut last = EventId(0);
+        for input in inputs {
+            last = recorder.record_input(input.clone(), InputMode::Script);
+        }
+        recorder.add_checkpoint(last, checkpoint(definitions));
+        Self {
+            session: recorder.into_session(),
+            history: history.to_vec(),
+        }
+    }
+
+    /// Read the workspace saved at `path` (complexity: 2)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` does not have the `.rsn` extension, cannot
+    /// be read or does not hold a session.
+    pub fn read(path: &Path) -> Result<Self> {
+        check_extension(path)?;
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&text)
+            .with_context(|| format!("{} is not a saved session", path.display()))
+    }
+
+    /// Save the workspace to `path`, replacing any file there (complexity: 2)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` does not have the `.rsn` extension or
+    /// cannot be written.
+    pub fn write(&self, path: &Path) -> Result<()> {
+        check_extension(path)?;
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Inputs to replay, in order; REPL commands are skipped (complexity: 2)
+    pub fn inputs(&self) -> Vec<&str> {
+        self.session
+            .timeline
+            .iter()
+            .filter_map(|event| match &event.event {
+                Event::Input { text, .. } if !text.trim_start().starts_with(':') => {
+                    Some(text.as_str())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Saved definitions, if the session has a checkpoint (complexity: 1)
+    pub fn checkpoint(&self) -> Option<&StateCheckpoint> {
+        self.session.checkpoints.values().next_back()
+    }
+}
+
+impl Restored {
+    /// Summary shown by `:load` (complexity: 4)
+    pub fn to_text(&self) -> String {
+        let mut lines = vec![format!("Replayed {} inputs", self.replayed)];
+        if !self.reset.is_empty() {
+            lines.push(format!("Reset to saved values: {}", self.reset.join(", ")));
+        }
+        if !self.removed.is_empty() {
+            lines.push(format!("Removed again: {}", self.removed.join(", ")));
+        }
+        for (input, error) in &self.failed {
+            let first_line = input.lines().next().unwrap_or_default();
+            lines.push(format!("Failed to replay `{first_line}`: {error}"));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Source of a literal that evaluates to `value`, if it has one (complexity: 8)
+///
+/// Functions, objects, iterators and other values tied to the running
+/// interpreter have none; they come back by replaying their inputs.
+pub fn literal(value: &Value) -> Option<String> {
+    match value {
+        Value::Nil => Some("null".to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Integer(n) if *n != i64::MIN => Some(n.to_string()),
+        Value::Float(f) if f.is_finite() => Some(format!("{f:?}")),
+        Value::String(s) => Some(format!("{:?}", &**s)),
+        Value::Array(items) => elements(items).map(|items| format!("[{items}]")),
+        Value::Tuple(items) if items.len() > 1 => elements(items).map(|items| format!("({items})")),
+        _ => None,
+    }
+}
+
+/// Comma-separated literals of `items`, if they all have one (complexity: 1)
+fn elements(items: &[Value]) -> Option<String> {
+    let items: Option<Vec<String>> = items.iter().map(literal).collect();
+    Some(items?.join(", "))
+}
+
+/// Checkpoint of the literal values and the types of `definitions` (complexity: 3)
+fn checkpoint(definitions: &[(String, Value)]) -> StateCheckpoint {
+    let bindings: BTreeMap<&str, String> = definitions
+        .iter()
+        .filter_map(|(name, value)| Some((name.as_str(), literal(value)?)))
+        .collect();
+    let mut hasher = Sha256::new();
+    for (name, source) in &bindings {
+        hasher.update(format!("{name}={source};").as_bytes());
+    }
+    StateCheckpoint {
+        bindings: bindings
+            .into_iter()
+            .map(|(name, source)| (name.to_string(), source))
+            .collect(),
+        type_environment: definitions
+            .iter()
+            .map(|(name, value)| (name.clone(), value.type_name().to_string()))
+            .collect(),
+        state_hash: format!("{:x}", hasher.finalize()),
+        resource_usage: ResourceUsage {
+            heap_bytes: 0,
+            stack_depth: 0,
+            cpu_ns: 0,
+        },
+    }
+}
+
+/// Refuse paths without the workspace extension (complexity: 2)
+fn check_extension(path: &Path) -> Result<()> {
+    if path.extension().and_then(|ext| ext.to_str()) != Some(EXTENSION) {
+        bail!(
+            "{} is not a .{EXTENSION} file; sessions are saved to and loaded from .{EXTENSION} files",
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::Parser;
+    use crate::runtime::interpreter::Interpreter;
+    use std::sync::Arc;
+
+    fn eval(source: &str) -> Value {
+        let ast = Parser::new(source).parse().expect("should parse");
+        Interpreter::new().eval_expr(&ast).expect("should evaluate")
+    }
+
+    #[test]
+    fn test_literals_evaluate_to_their_values() {
+        let values = [
+            Value::Nil,
+            Value::Bool(true),
+            Value::Integer(-42),
+            Value::Float(2.5),
+            Value::Float(1e-7),
+            Value::from_string("tab\t\"quoted\" {braces} é\n".to_string()),
+            Value::from_array(vec![
+                Value::Integer(1),
+                Value::Tuple(Arc::from(vec![Value::Bool(false), Value::Float(-0.5)])),
+            ]),
+        ];
+        for value in values {
+            let source = literal(&value).expect("has a literal");
+            assert_eq!(eval(&source), value, "{source}");
+        }
+    }
+
+    #[test]
+    fn test_values_without_literals() {
+        assert_eq!(literal(&Value::Float(f64::NAN)), None);
+        assert_eq!(literal(&Value::BuiltinFunction("len".to_string())), None);
+        let nested = Value::from_array(vec![Value::BuiltinFunction("len".to_string())]);
+        assert_eq!(literal(&nested), None);
+    }
+
+    #[test]
+    fn test_capture_round_trips_through_json() {
+        let workspace = Workspace::capture(
+            &["let x = 1".to_string(), ":vars".to_string()],
+            &["let x = 1".to_string(), "oops(".to_string()],
+            &[
+                ("x".to_string(), Value::Integer(1)),
+                ("f".to_string(), Value::BuiltinFunction("len".to_string())),
+            ],
+        );
+        let json = serde_json::to_string(&workspace).expect("should serialize");
+        let read: Workspace = serde_json::from_str(&json).expect("should deserialize");
+        assert_eq!(read.inputs(), vec!["let x = 1"]);
+        assert_eq!(read.history, vec!["let x = 1", "oops("]);
+        let checkpoint = read.checkpoint().expect("has a checkpoint");
+        assert_eq!(checkpoint.bindings.len(), 1);
+        assert_eq!(checkpoint.bindings["x"], "1");
+        assert!(checkpoint.type_environment.contains_key("f"));
+        assert!(read
+            .session
+            .metadata
+            .tags
+            .contains(&WORKSPACE_TAG.to_string()));
+    }
+
+    #[test]
+    fn test_only_rsn_files_are_written() {
+        let workspace = Workspace::capture(&[], &[], &[]);
+        let dir = tempfile::TempDir::new().expect("temp dir");
+        let script = dir.path().join("script.ruchy");
+        let err = workspace.write(&script).expect_err("not an .rsn file");
+        assert!(err.to_string().contains("is not a .rsn file"), "{err}");
+        assert!(!script.exists());
+        assert!(Workspace::read(&script).is_err());
+    }
+}
@@ -0,0 +1,469 @@
+//! Deterministic replay traces for `ruchy run`
+//!
+//! `ruchy run --record trace.replay file.ruchy` records everything a script
+//! takes from outside the interpreter, and `ruchy replay trace.replay` feeds
+//! it back so the run repeats exactly:
+//!
+//! - the program source, and its path for module resolution;
+//! - the seed of `random()`, which draws from a seeded generator while a
+//!   trace is active;
+//! - clock reads by `timestamp()`, `get_time_ms()`, `std::time::now_millis()`
+//!   and `Utc::now()`;
+//! - lines read from stdin by `io::records()`;
+//! - text printed by `print`/`println`/`emit`, which replay compares with
+//!   the recording;
+//! - the exit status.
+//!
+//! A trace is JSON lines: a [`TraceHeader`] followed by one [`TraceEvent`]
+//! per line, flushed as it happens. A run that hangs and is interrupted
+//! leaves a trace without an [`TraceEvent::Exit`]; replaying it repeats the
+//! recorded part and then continues with live inputs. A complete trace
+//! treats reading more inputs than were recorded as divergence.
+//!
+//! The active trace is process-global because scripts with a deadline
+//! evaluate on a worker thread.
+use crate::runtime::InterpreterError;
+use anyhow::{bail, Context, Result};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Trace format version written to every header
+pub const TRACE_VERSION: u32 = 1;
+
+/// First line of a trace: what ran and how it was seeded
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceHeader {
+    /// Trace format version, [`TRACE_VERSION`] when written
+    pub version: u32,
+    /// Version of the `ruchy` binary that recorded the trace
+    pub ruchy_version: String,
+    /// File the script was read from; `None` for stdin
+    pub path: Option<PathBuf>,
+    /// Source of the script as it ran
+    pub source: String,
+    /// Seed of the generator behind `random()`
+    pub seed: u64,
+}
+
+/// One nondeterministic input or observable output of a run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TraceEvent {
+    /// A line read from stdin, without its line ending; `None` at end of input
+    Stdin { line: Option<String> },
+    /// Milliseconds since the Unix epoch returned by a clock builtin
+    Clock { millis: i64 },
+    /// RFC 3339 time returned by `Utc::now()`
+    UtcNow { time: String },
+    /// Text written to stdout by `print`/`println`/`emit`
+    Output { text: String },
+    /// How the run ended: exit status and error message
+    Exit { code: i32, error: Option<String> },
+}
+
+/// A recorded run: header plus events in the order they happened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunTrace {
+    pub header: TraceHeader,
+    pub events: Vec<TraceEvent>,
+}
+
+impl RunTrace {
+    /// Read a trace written by `ruchy run --record`
+    ///
+    /// A cut-off last line, left by a run killed mid-write, is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, is not a trace, or was
+    /// written by an incompatible version.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read trace {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("Invalid trace {}", path.display()))
+    }
+
+    /// Parse the JSON lines of a trace
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header or an event other than the last does
+    /// not parse, or the format version is unsupported.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+        let header: TraceHeader = serde_json::from_str(lines.next().context("Trace is empty")?)
+            .context("Trace header does not parse")?;
+        if header.version != TRACE_VERSION {
+            bail!(
+                "Unsupported trace version {} (this ruchy reads version {TRACE_VERSION})",
+                header.version
+            );
+        }
+        let lines: Vec<&str> = lines.collect();
+        let mut events = Vec::with_capacity(lines.len());
+        for (index, line) in lines.iter().enumerate() {
+            match serde_json::from_str(line) {
+                Ok(event) => events.push(event),
+                Err(_) if index + 1 == lines.len() => break,
+                Err(e) => bail!("Trace event {} does not parse: {e}", index + 1),
+            }
+        }
+        Ok(Self { header, events })
+    }
+
+    /// Recorded exit status and error, `None` if the run was interrupted
+    pub fn exit(&self) -> Option<(i32, Option<&str>)> {
+        self.events.iter().rev().find_map(|event| match event {
+            TraceEvent::Exit { code, error } => Some((*code, error.as_deref())),
+            _ => None,
+        })
+    }
+}
+
+struct Recorder {
+    out: BufWriter<File>,
+    rng: StdRng,
+    error: Option<io::Error>,
+}
+
+impl Recorder {
+    fn write(&mut self, event: &TraceEvent) {
+        if self.error.is_some() {
+            return;
+        }
+        let written = serde_json::to_writer(&mut self.out, event)
+            .map_err(io::Error::from)
+            .and_then(|()| writeln!(self.out))
+            .and_then(|()| self.out.flush());
+        if let Err(e) = written {
+            self.error = Some(e);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Replayer {
+    rng: StdRng,
+    stdin: VecDeque<Option<String>>,
+    clock: VecDeque<i64>,
+    utc: VecDeque<String>,
+    output: VecDeque<String>,
+    exit: Option<i32>,
+    outputs_seen: usize,
+    divergence: Option<String>,
+}
+
+impl Replayer {
+    fn new(trace: &RunTrace) -> Self {
+        let mut replayer = Self {
+            rng: StdRng::seed_from_u64(trace.header.seed),
+            stdin: VecDeque::new(),
+            clock: VecDeque::new(),
+            utc: VecDeque::new(),
+            output: VecDeque::new(),
+            exit: None,
+            outputs_seen: 0,
+            divergence: None,
+        };
+        for event in &trace.events {
+            match event {
+                TraceEvent::Stdin { line } => replayer.stdin.push_back(line.clone()),
+                TraceEvent::Clock { millis } => replayer.clock.push_back(*millis),
+                TraceEvent::UtcNow { time } => replayer.utc.push_back(time.clone()),
+                TraceEvent::Output { text } => replayer.output.push_back(text.clone()),
+                TraceEvent::Exit { code, .. } => replayer.exit = Some(*code),
+            }
+        }
+        replayer
+    }
+
+    /// Whether the recording ran to the end, so running past it is divergence
+    fn complete(&self) -> bool {
+        self.exit.is_some()
+    }
+
+    fn check_output(&mut self, text: &str) {
+        self.outputs_seen += 1;
+        let mismatch = match self.output.pop_front() {
+            Some(expected) if expected == text => return,
+            Some(expected) => format!(
+                "output #{} was {text:?}, the recording has {expected:?}",
+                self.outputs_seen
+            ),
+            None if self.complete() => format!(
+                "output #{} {text:?} is not in the recording",
+                self.outputs_seen
+            ),
+            None => return,
+        };
+        self.divergence.get_or_insert(mismatch);
+    }
+
+    fn finish(mut self, code: i32) -> Option<String> {
+        if self.divergence.is_some() {
+            return self.divergence;
+        }
+        let recorded = self.exit?;
+        if let Some(expected) = self.output.pop_front() {
+            return Some(format!(
+                "recorded output #{} {expected:?} was not produced",
+                self.outputs_seen + 1
+            ));
+        }
+        (recorded != code)
+            .then(|| format!("exited with status {code}, the recording with {recorded}"))
+    }
+}
+
+enum Mode {
+    Recording(Recorder),
+    Replaying(Replayer),
+}
+
+static TRACE: Mutex<Option<Mode>> = Mutex::new(None);
+
+fn lock() -> MutexGuard<'static, Option<Mode>> {
+    TRACE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Record the rest of this process's run to `trace`
+///
+/// # Errors
+///
+/// Returns an error if the trace file cannot be created.
+pub fn start_recording(trace: &Path, source: &str, script: Option<&Path>) -> Result<()> {
+    let header = TraceHeader {
+        version: TRACE_VERSION,
+        ruchy_version: env!("CARGO_PKG_VERSION").to_string(),
+        path: script.map(Path::to_path_buf),
+        source: source.to_string(),
+        seed: rand::random(),
+    };
+    let file = File::create(trace)
+        .with_context(|| format!("Failed to create trace {}", trace.display()))?;
+    let mut out = BufWriter::new(file);
+    serde_json::to_writer(&mut out, &header)?;
+    writeln!(out)?;
+    out.flush()?;
+    *lock() = Some(Mode::Recording(Recorder {
+        out,
+        rng: StdRng::seed_from_u64(header.seed),
+        error: None,
+    }));
+    Ok(())
+}
+
+/// Feed the inputs of `trace` to the rest of this process's run
+pub fn start_replay(trace: &RunTrace) {
+    *lock() = Some(Mode::Replaying(Replayer::new(trace)));
+}
+
+/// End the active recording or replay of a run that exited with `code`
+///
+/// Returns how a replay diverged from its recording, if it did.
+///
+/// # Errors
+///
+/// Returns an error if writing the recording failed.
+pub fn finish(code: i32, error: Option<&str>) -> Result<Option<String>> {
+    match lock().take() {
+        None => Ok(None),
+        Some(Mode::Recording(mut recorder)) => {
+            recorder.write(&TraceEvent::Exit {
+                code,
+                error: error.map(str::to_string),
+            });
+            match recorder.error {
+                Some(e) => Err(e).context("Failed to write trace"),
+                None => Ok(None),
+            }
+        }
+        Some(Mode::Replaying(replayer)) => Ok(replayer.finish(code)),
+    }
+}
+
+/// Next `random()` value while a trace is active
+pub(crate) fn random_f64() -> Option<f64> {
+    match lock().as_mut()? {
+        Mode::Recording(recorder) => Some(recorder.rng.gen()),
+        Mode::Replaying(replayer) => Some(replayer.rng.gen()),
+    }
+}
+
+/// Read the clock through the active trace
+pub(crate) fn clock_millis(
+    live: impl FnOnce() -> Result<i64, InterpreterError>,
+) -> Result<i64, InterpreterError> {
+    observe(
+        "read the clock",
+        live,
+        |replayer| replayer.clock.pop_front(),
+        |&millis| TraceEvent::Clock { millis },
+    )
+}
+
+/// Read `Utc::now()` through the active trace
+pub(crate) fn utc_now(live: impl FnOnce() -> String) -> Result<String, InterpreterError> {
+    observe(
+        "read the clock",
+        || Ok(live()),
+        |replayer| replayer.utc.pop_front(),
+        |time| TraceEvent::UtcNow { time: time.clone() },
+    )
+}
+
+/// Read a stdin line through the active trace
+pub(crate) fn stdin_line(
+    live: impl FnOnce() -> Result<Option<String>, InterpreterError>,
+) -> Result<Option<String>, InterpreterError> {
+    observe(
+        "read stdin",
+        live,
+        |replayer| replayer.stdin.pop_front(),
+        |line| TraceEvent::Stdin { line: line.clone() },
+    )
+}
+
+/// Record printed `text`, or compare it with the recording
+pub(crate) fn output(text: &str) {
+    match lock().as_mut() {
+        None => {}
+        Some(Mode::Recording(recorder)) => recorder.write(&TraceEvent::Output {
+            text: text.to_string(),
+        }),
+        Some(Mode::Replaying(replayer)) => replayer.check_output(text),
+    }
+}
+
+/// Take an input from the recording when replaying, otherwise from `live`
+///
+/// The lock is released while `live` runs so a blocked stdin read cannot
+/// stop a timed-out run from finishing its trace.
+fn observe<T>(
+    what: &str,
+    live: impl FnOnce() -> Result<T, InterpreterError>,
+    recorded: impl FnOnce(&mut Replayer) -> Option<T>,
+    event: impl FnOnce(&T) -> TraceEvent,
+) -> Result<T, InterpreterError> {
+    let recording = match lock().as_mut() {
+        None => false,
+        Some(Mode::Recording(_)) => true,
+        Some(Mode::Replaying(replayer)) => {
+            if let Some(value) = recorded(replayer) {
+                return Ok(value);
+            }
+            if replayer.complete() {
+                return Err(InterpreterError::RuntimeError(format!(
+                    "Replay diverged: the program {what} more often than the recording"
+                )));
+            }
+            false
+        }
+    };
+    let value = live()?;
+    if recording {
+        if let Some(Mode::Recording(recorder)) = lock().as_mut() {
+            recorder.write(&event(&value));
+        }
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace(events: Vec<TraceEvent>) -> RunTrace {
+        RunTrace {
+            header: TraceHeader {
+                version: TRACE_VERSION,
+                ruchy_version: "0.0.0".to_string(),
+                path: None,
+                source: "println(1)".to_string(),
+                seed: 7,
+            },
+            events,
+        }
+    }
+
+    fn printed(text: &str) -> TraceEvent {
+        TraceEvent::Output {
+            text: text.to_string(),
+        }
+    }
+
+    fn exit(code: i32) -> TraceEvent {
+        TraceEvent::Exit { code, error: None }
+    }
+
+    #[test]
+    fn test_parse_round_trip_and_truncated_tail() {
+        let recorded = trace(vec![
+            TraceEvent::Clock { millis: 1_700 },
+            TraceEvent::Stdin { line: None },
+            printed("1\n"),
+            exit(0),
+        ]);
+        let mut text = serde_json::to_string(&recorded.header).unwrap();
+        for event in &recorded.events {
+            text.push('\n');
+            text.push_str(&serde_json::to_string(event).unwrap());
+        }
+        assert_eq!(RunTrace::parse(&text).unwrap(), recorded);
+        assert_eq!(recorded.exit(), Some((0, None)));
+
+        text.push_str("\n{\"event\":\"outp");
+        assert_eq!(RunTrace::parse(&text).unwrap(), recorded);
+    }
+
+    #[test]
+    fn test_parse_rejects_other_versions() {
+        let mut header = trace(Vec::new()).header;
+        header.version = TRACE_VERSION + 1;
+        let text = serde_json::to_string(&header).unwrap();
+        let err = RunTrace::parse(&text).unwrap_err();
+        assert!(
+            err.to_string().contains("Unsupported trace version"),
+            "{err}"
+        );
+        assert!(RunTrace::parse("").is_err());
+    }
+
+    #[test]
+    fn test_replayer_reports_first_output_divergence() {
+        let mut replayer = Replayer::new(&trace(vec![printed("a\n"), printed("b\n"), exit(0)]));
+        replayer.check_output("a\n");
+        replayer.check_output("c\n");
+        replayer.check_output("d\n");
+        let divergence = replayer.finish(0).unwrap();
+        assert!(
+            divergence.contains("output #2 was \"c\\n\""),
+            "{divergence}"
+        );
+    }
+
+    #[test]
+    fn test_replayer_reports_missing_output_and_exit_status() {
+        let recorded = trace(vec![printed("a\n"), exit(1)]);
+        let replayer = Replayer::new(&recorded);
+        assert!(replayer.finish(1).unwrap().contains("was not produced"));
+        let mut replayer = Replayer::new(&recorded);
+        replayer.check_output("a\n");
+        assert!(replayer.finish(0).unwrap().contains("exited with status 0"));
+    }
+
+    #[test]
+    fn test_interrupted_recording_accepts_any_continuation() {
+        let mut replayer = Replayer::new(&trace(vec![printed("a\n")]));
+        replayer.check_output("a\n");
+        replayer.check_output("more\n");
+        assert!(!replayer.complete());
+        assert_eq!(replayer.finish(124), None);
+    }
+}
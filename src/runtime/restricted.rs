@@ -0,0 +1,511 @@
+//! Restricted evaluation for untrusted input
+//!
+//! `ruchy repl --restricted` evaluates code typed by people who must not
+//! reach the machine running it: a shared web terminal, a classroom server
+//! or the playground's server-assisted mode. While a [`Guard`] from [`enter`]
+//! is alive on a thread, evaluations on that thread:
+//!
+//! - only run the pure builtins of [`is_pure_builtin`]; every other builtin
+//!   is refused, named after the capability it needs where that is known
+//!   (see [`denied_capability`]);
+//! - refuse methods of host objects (`Command`, `ExitStatus`, `File`), so a
+//!   hand-built `{__type: "Command", ...}` cannot run a process, methods of
+//!   pure values that take a file path (`df.to_csv(path)`) and file module
+//!   imports (`use helpers`);
+//! - stop with [`crate::runtime::interrupt::TIMEOUT_MESSAGE`] once the time
+//!   budget is spent;
+//! - fail with [`OUTPUT_LIMIT_MESSAGE`] once printed output exceeds its cap;
+//! - fail with [`SIZE_LIMIT_MESSAGE`] before building a string, list or range
+//!   larger than [`MAX_VALUE_SIZE`], so `"a".repeat(10 ** 10)` cannot exhaust
+//!   the host's memory.
+//!
+//! Hiding host paths in error messages is left to the host, which knows the
+//! paths to hide; [`redact_paths`] does the replacement.
+
+use crate::runtime::{interrupt, InterpreterError};
+use std::cell::Cell;
+use std::path::Path;
+use std::time::Duration;
+
+/// Error message of an evaluation that printed more than its output cap
+pub const OUTPUT_LIMIT_MESSAGE: &str = "Output limit exceeded";
+
+/// Error message of an evaluation that tried to build a value over [`MAX_VALUE_SIZE`]
+pub const SIZE_LIMIT_MESSAGE: &str = "Value size limit exceeded";
+
+/// Largest string (in bytes) or list (in elements) a restricted evaluation builds
+pub const MAX_VALUE_SIZE: usize = 1 << 22;
+
+/// Replacement for host paths in redacted messages
+pub const REDACTED_PATH: &str = "<redacted>";
+
+/// Marker appended to output cut short by [`truncate_output`]
+pub const TRUNCATION_MARKER: &str = "… (output truncated)";
+
+/// Limits of one restricted evaluation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Wall-clock budget of the evaluation
+    pub timeout: Duration,
+    /// Maximum bytes printed by `print`/`println`/`dbg`
+    pub max_output: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Budget {
+    max_output: usize,
+    printed: usize,
+}
+
+thread_local! {
+    static BUDGET: Cell<Option<Budget>> = const { Cell::new(None) };
+}
+
+/// Restrictions in force until dropped
+#[derive(Debug)]
+#[must_use = "restrictions are lifted when the guard is dropped"]
+pub struct Guard {
+    previous: Option<Budget>,
+    _deadline: interrupt::DeadlineGuard,
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        BUDGET.with(|slot| slot.set(self.previous));
+    }
+}
+
+/// Restrict evaluations on the current thread to `limits` until the guard drops
+///
+/// A deadline already set on the thread, such as the timeout of the `ruchy
+/// run` that evaluates this code, still applies if it is earlier.
+pub fn enter(limits: Limits) -> Guard {
+    let budget = Budget {
+        max_output: limits.max_output,
+        printed: 0,
+    };
+    Guard {
+        previous: BUDGET.with(|slot| slot.replace(Some(budget))),
+        _deadline: interrupt::limit_time(limits.timeout),
+    }
+}
+
+/// Whether evaluations on the current thread are restricted
+pub fn is_active() -> bool {
+    BUDGET.with(|slot| slot.get().is_some())
+}
+
+/// Capability a builtin needs, if it is known to reach outside the interpreter
+///
+/// Names the capability in restricted-mode errors and effect spans. Accepts
+/// both the user-facing name (`fs_read`) and the internal marker
+/// (`__builtin_fs_read__`).
+pub fn denied_capability(builtin: &str) -> Option<&'static str> {
+    let name = user_facing_name(builtin);
+    match name {
+        "read_file" | "write_file" | "append_file" | "file_exists" | "delete_file" | "open"
+        | "File_open" | "walk" | "walk_with_options" | "walk_parallel" | "glob" | "search"
+        | "compute_hash" | "csv_read" | "csv_write" | "json_read" | "json_write"
+        | "dataframe_from_csv" | "path_canonicalize" | "display_png" => Some("filesystem"),
+        "command" | "command_new" | "sleep" => Some("process"),
+        _ if name.starts_with("fs_") => Some("filesystem"),
+        _ if name.starts_with("env_") => Some("environment"),
+        _ if name.starts_with("http_") => Some("network"),
+        _ => None,
+    }
+}
+
+/// Whether `builtin` only computes on its arguments
+///
+/// This is the allowlist of restricted evaluation: printing, math,
+/// conversions, collections, strings, in-memory JSON and data frames, path
+/// string manipulation and display values built from strings. Accepts both
+/// the user-facing name and the internal marker.
+pub fn is_pure_builtin(builtin: &str) -> bool {
+    matches!(
+        user_facing_name(builtin),
+        "print"
+            | "println"
+            | "dbg"
+            | "format"
+            | "sqrt"
+            | "pow"
+            | "abs"
+            | "min"
+            | "max"
+            | "floor"
+            | "ceil"
+            | "round"
+            | "sin"
+            | "cos"
+            | "tan"
+            | "log"
+            | "log10"
+            | "exp"
+            | "random"
+            | "len"
+            | "range"
+            | "type"
+            | "type_of"
+            | "is_nil"
+            | "reverse"
+            | "assert"
+            | "assert_eq"
+            | "dir"
+            | "zip"
+            | "enumerate"
+            | "push"
+            | "pop"
+            | "sort"
+            | "str"
+            | "to_string"
+            | "int"
+            | "float"
+            | "bool"
+            | "parse_int"
+            | "parse_float"
+            | "decimal"
+            | "String_new"
+            | "String_from"
+            | "String_from_utf8"
+            | "json_parse"
+            | "parse_json"
+            | "JSON_parse"
+            | "json_stringify"
+            | "stringify_json"
+            | "JSON_stringify"
+            | "json_pretty"
+            | "json_validate"
+            | "json_type"
+            | "json_merge"
+            | "json_get"
+            | "json_set"
+            | "dataframe_new"
+            | "dataframe_from_csv_string"
+            | "dataframe_from_json"
+            | "display_html"
+            | "display_markdown"
+            | "display_svg"
+            | "plot_line"
+            | "plot_scatter"
+            | "plot_bar"
+            | "path_join"
+            | "path_join_many"
+            | "path_parent"
+            | "path_file_name"
+            | "path_file_stem"
+            | "path_extension"
+            | "path_is_absolute"
+            | "path_is_relative"
+            | "path_with_extension"
+            | "path_with_file_name"
+            | "path_components"
+            | "path_normalize"
+    )
+}
+
+/// Whether `builtin` may run on this thread
+///
+/// A name that is not allowed may still be a user function of the same
+/// name, so callers resolving an identifier fall back to their bindings.
+pub(crate) fn allows_builtin(builtin: &str) -> bool {
+    !is_active() || is_pure_builtin(builtin)
+}
+
+/// Refuse `builtin` unless it may run on this thread
+pub(crate) fn check_builtin(builtin: &str) -> Result<(), InterpreterError> {
+    if allows_builtin(builtin) {
+        return Ok(());
+    }
+    let reason = match denied_capability(builtin) {
+        Some(capability) => format!("no {capability} access"),
+        None => "only pure builtins run".to_string(),
+    };
+    Err(InterpreterError::RuntimeError(format!(
+        "{}() is disabled in restricted mode ({reason})",
+        user_facing_name(builtin)
+    )))
+}
+
+/// Refuse methods of host object `type_name` on a restricted thread
+pub(crate) fn check_host_object(type_name: &str, method: &str) -> Result<(), InterpreterError> {
+    let capability = match type_name {
+        "Command" | "ExitStatus" => "process",
+        "File" => "filesystem",
+        _ => return Ok(()),
+    };
+    if is_active() {
+        return Err(InterpreterError::RuntimeError(format!(
+            "{type_name}.{method}() is disabled in restricted mode (no {capability} access)"
+        )));
+    }
+    Ok(())
+}
+
+/// Refuse `operation`, which reads or writes host files, on a restricted thread
+///
+/// Values built by pure builtins can still have methods that take a path,
+/// such as `df.to_csv(path)`; those methods check here before touching the
+/// filesystem.
+pub(crate) fn check_file_access(operation: &str) -> Result<(), InterpreterError> {
+    if is_active() {
+        return Err(InterpreterError::RuntimeError(format!(
+            "{operation} is disabled in restricted mode (no filesystem access)"
+        )));
+    }
+    Ok(())
+}
+
+/// Refuse building a value of `size` bytes or elements on a restricted thread
+/// when it is over [`MAX_VALUE_SIZE`]
+///
+/// Called before allocating, with the size the result would have.
+pub(crate) fn check_size(size: usize) -> Result<(), InterpreterError> {
+    if size > MAX_VALUE_SIZE && is_active() {
+        return Err(InterpreterError::RuntimeError(
+            SIZE_LIMIT_MESSAGE.to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Refuse loading file module `module` on a restricted thread
+pub(crate) fn check_import(module: &str) -> Result<(), InterpreterError> {
+    if is_active() {
+        return Err(InterpreterError::RuntimeError(format!(
+            "Loading module '{module}' is disabled in restricted mode (no filesystem access)"
+        )));
+    }
+    Ok(())
+}
+
+/// Count `bytes` of printed output against this thread's output cap
+pub(crate) fn charge_output(bytes: usize) -> Result<(), InterpreterError> {
+    BUDGET.with(|slot| {
+        let Some(mut budget) = slot.get() else {
+            return Ok(());
+        };
+        budget.printed = budget.printed.saturating_add(bytes);
+        slot.set(Some(budget));
+        if budget.printed > budget.max_output {
+            Err(InterpreterError::RuntimeError(
+                OUTPUT_LIMIT_MESSAGE.to_string(),
+            ))
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// Cut `text` to at most `max` bytes on a character boundary, marking the cut
+pub fn truncate_output(text: &str, max: usize) -> String {
+    if text.len() <= max {
+        return text.to_string();
+    }
+    let mut end = max;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}{TRUNCATION_MARKER}", &text[..end])
+}
+
+/// Replace every occurrence of `paths` in `message` with [`REDACTED_PATH`]
+///
+/// Longer paths are replaced first, so a working directory inside the home
+/// directory is hidden as a whole.
+pub fn redact_paths(message: &str, paths: &[&Path]) -> String {
+    let mut paths: Vec<String> = paths
+        .iter()
+        .map(|path| path.to_string_lossy().trim_end_matches('/').to_string())
+        .filter(|path| path.len() > 1)
+        .collect();
+    paths.sort_by_key(|path| std::cmp::Reverse(path.len()));
+    paths.iter().fold(message.to_string(), |message, path| {
+        message.replace(path.as_str(), REDACTED_PATH)
+    })
+}
+
+/// `fs_read` for `__builtin_fs_read__`
+pub(crate) fn user_facing_name(builtin: &str) -> &str {
+    builtin
+        .strip_prefix("__builtin_")
+        .and_then(|rest| rest.strip_suffix("__"))
+        .unwrap_or(builtin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::Parser;
+    use crate::runtime::interpreter::Interpreter;
+    use std::time::Instant;
+
+    fn limits() -> Limits {
+        Limits {
+            timeout: Duration::from_secs(5),
+            max_output: 16,
+        }
+    }
+
+    fn eval(source: &str) -> Result<crate::runtime::Value, InterpreterError> {
+        let ast = Parser::new(source).parse().expect("source should parse");
+        Interpreter::new().eval_expr(&ast)
+    }
+
+    #[test]
+    fn test_denied_capabilities() {
+        assert_eq!(denied_capability("fs_read"), Some("filesystem"));
+        assert_eq!(
+            denied_capability("__builtin_read_file__"),
+            Some("filesystem")
+        );
+        assert_eq!(
+            denied_capability("__builtin_command_new__"),
+            Some("process")
+        );
+        assert_eq!(denied_capability("env_vars"), Some("environment"));
+        assert_eq!(denied_capability("http_get"), Some("network"));
+        assert_eq!(denied_capability("__builtin_println__"), None);
+        assert_eq!(denied_capability("__builtin_path_join__"), None);
+    }
+
+    #[test]
+    fn test_guard_blocks_builtins_and_restores() {
+        {
+            let _guard = enter(limits());
+            let err = eval("fs_read(\"/etc/passwd\")").unwrap_err().to_string();
+            assert!(err.contains("fs_read() is disabled"), "{err}");
+            assert!(eval("env_vars()").is_err());
+        }
+        assert!(!is_active());
+        assert!(check_builtin("__builtin_fs_read__").is_ok());
+    }
+
+    #[test]
+    fn test_guard_restores_outer_deadline() {
+        let outer = Instant::now() + Duration::from_secs(60);
+        interrupt::set_deadline(Some(outer));
+        {
+            let _guard = enter(limits());
+            let inner = interrupt::deadline().expect("restricted deadline");
+            assert!(inner < outer);
+        }
+        assert_eq!(interrupt::deadline(), Some(outer));
+        let soon = Instant::now() + Duration::from_millis(10);
+        interrupt::set_deadline(Some(soon));
+        {
+            let _guard = enter(limits());
+            assert_eq!(interrupt::deadline(), Some(soon));
+        }
+        interrupt::set_deadline(None);
+        drop(enter(limits()));
+        assert_eq!(interrupt::deadline(), None);
+    }
+
+    #[test]
+    fn test_only_pure_builtins_run() {
+        assert!(is_pure_builtin("__builtin_sqrt__"));
+        assert!(is_pure_builtin("path_join"));
+        assert!(!is_pure_builtin("display_png"));
+        assert!(!is_pure_builtin("path_canonicalize"));
+        let _guard = enter(limits());
+        assert_eq!(
+            eval("sqrt(16.0)").expect("pure builtin runs"),
+            crate::runtime::Value::Float(4.0)
+        );
+        let err = eval("display_png(\"/etc/passwd\")")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("display_png() is disabled"), "{err}");
+        let err = eval("std::display::png(\"/etc/passwd\")")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("restricted mode"), "{err}");
+        let err = eval("no_such_builtin(1)").unwrap_err().to_string();
+        assert!(err.contains("only pure builtins run"), "{err}");
+    }
+
+    #[test]
+    fn test_user_functions_shadowing_builtins_still_run() {
+        let _guard = enter(limits());
+        let value = eval("fun sleep(x) { x + 1 }\nsleep(1)").expect("user function runs");
+        assert_eq!(value, crate::runtime::Value::Integer(2));
+    }
+
+    #[test]
+    fn test_forged_host_objects_are_refused() {
+        let _guard = enter(limits());
+        let err = eval(
+            "let cmd = {__type: \"Command\", program: \"sh\", args: [\"-c\", \"id\"]}\ncmd.output()",
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("Command.output() is disabled"), "{err}");
+        let err = eval("{__type: \"ExitStatus\", success: true}.success()")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("ExitStatus.success() is disabled"), "{err}");
+    }
+
+    #[test]
+    fn test_dataframe_file_methods_are_refused() {
+        let dir = tempfile::TempDir::new().expect("temp dir");
+        let out = dir.path().join("out.csv");
+        let df = "DataFrame::from_csv_string(\"a,b\\n1,2\")";
+        let _guard = enter(limits());
+        assert!(eval(&format!("{df}.to_csv()")).is_ok());
+        let err = eval(&format!("{df}.to_csv(\"{}\")", out.display()))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("DataFrame.to_csv(path) is disabled"), "{err}");
+        assert!(!out.exists());
+        let err = eval(&format!("DataFrame::from_csv(\"{}\")", out.display()))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("restricted mode"), "{err}");
+    }
+
+    #[test]
+    fn test_large_values_are_refused() {
+        assert!(eval("\"ab\".repeat(3000000)").is_ok());
+        let _guard = enter(limits());
+        for source in [
+            "\"a\".repeat(10000000000)",
+            "\"a\" * 10000000000",
+            "\"x\".pad_start(10000000000, \" \")",
+            "range(0, 10000000000)",
+            "(0..10000000000).collect()",
+            "[x for x in 0..10000000000]",
+        ] {
+            let err = eval(source).unwrap_err().to_string();
+            assert!(err.contains(SIZE_LIMIT_MESSAGE), "{source}: {err}");
+        }
+        assert!(eval("\"ab\".repeat(3)").is_ok());
+    }
+
+    #[test]
+    fn test_output_cap() {
+        let _guard = enter(limits());
+        assert!(charge_output(10).is_ok());
+        let err = charge_output(10).unwrap_err();
+        assert!(err.to_string().contains(OUTPUT_LIMIT_MESSAGE), "{err}");
+    }
+
+    #[test]
+    fn test_truncate_output_respects_char_boundaries() {
+        assert_eq!(truncate_output("short", 10), "short");
+        assert_eq!(truncate_output("héllo", 2), format!("h{TRUNCATION_MARKER}"));
+    }
+
+    #[test]
+    fn test_redact_paths_prefers_longest() {
+        let home = Path::new("/home/ada");
+        let work = Path::new("/home/ada/class/");
+        let message = "cannot read /home/ada/class/x.ruchy or /home/ada/.ssh";
+        assert_eq!(
+            redact_paths(message, &[home, work]),
+            "cannot read <redacted>/x.ruchy or <redacted>/.ssh"
+        );
+        assert_eq!(redact_paths("at /", &[Path::new("/")]), "at /");
+    }
+}
@@ -0,0 +1,441 @@
+//! Script execution semantics shared by every entry point
+//!
+//! `ruchy file.ruchy`, `ruchy run file.ruchy`, `ruchy -e '<code>'`, piped
+//! stdin and the library `ruchy::cli` front end all run programs through
+//! [`run_script`], so a script behaves the same however it is launched:
+//!
+//! 1. **Whole-file parse.** The entire source is parsed before anything runs;
+//!    a syntax error anywhere aborts with no side effects. Multi-line
+//!    constructs are never split into lines.
+//! 2. **Module resolution.** `mod name;` and `import` declarations are resolved
+//!    relative to the script's directory (and `src/`, `lib/`, `modules/` of
+//!    its parent) when the script came from a file.
+//! 3. **Evaluation.** One interpreter evaluates the program top to bottom. A
+//!    top-level `return` ends the script with the returned value. With
+//!    [`ScriptOptions::optimize`] (`ruchy run -O`) constants are folded and
+//!    dead branches removed first (see [`crate::middleend::optimize::optimize`]).
+//! 4. **`main` auto-call.** If the program defines a top-level `fun main()`
+//!    without parameters and does not call `main()` itself at top level,
+//!    `main()` is called once after evaluation and its value becomes the
+//!    script's result.
+//! 5. **Deadline.** Scripts run without a deadline by default. A deadline is
+//!    taken from `--timeout`, then the `RUCHY_TIMEOUT` environment variable,
+//!    then `timeout` in the `[run]` table of the nearest `Ruchy.toml` (see
+//!    [`configured_timeout`]). Expiry fails with [`ScriptError::Timeout`],
+//!    which names the limit that was hit.
+//! 6. **Exit codes.** [`ScriptError::exit_code`] maps failures to the process
+//!    exit status: `0` success, `1` runtime error, `2` syntax or module
+//!    resolution error, `124` timeout.
+//! 7. **Record streams.** With `--output json` a non-nil result is rendered
+//!    as one JSON line (echoed by `ruchy -e`) so it composes with
+//!    `--input json` consumers (see [`crate::runtime::records`]).
+use crate::backend::module_resolver::ModuleResolver;
+use crate::frontend::ast::{Expr, ExprKind};
+use crate::frontend::parser::Parser;
+use crate::runtime::interpreter::Interpreter;
+use crate::runtime::interrupt;
+use crate::runtime::memory::{self, MemoryReport};
+use crate::runtime::records::{self, StreamFormat};
+use crate::runtime::{InterpreterError, Value};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// Exit status for runtime errors
+pub const EXIT_RUNTIME_ERROR: i32 = 1;
+/// Exit status for syntax and module resolution errors
+pub const EXIT_SYNTAX_ERROR: i32 = 2;
+/// Exit status when the deadline expires (matches coreutils `timeout`)
+pub const EXIT_TIMEOUT: i32 = 124;
+/// Environment variable holding the script deadline, e.g. `30s`
+pub const TIMEOUT_ENV_VAR: &str = "RUCHY_TIMEOUT";
+/// Project manifest that may set `[run] timeout`
+pub const MANIFEST_FILE: &str = "Ruchy.toml";
+
+/// How a script should be executed
+#[derive(Debug, Clone, Default)]
+pub struct ScriptOptions {
+    /// File the source was read from; enables module resolution
+    pub path: Option<PathBuf>,
+    /// Abort evaluation after this long; `None` runs to completion
+    pub timeout: Option<Duration>,
+    /// Deadline given with `--timeout`, as written; wins over the environment
+    /// and `Ruchy.toml` in [`Self::with_configured_timeout`]
+    pub timeout_flag: Option<String>,
+    /// Measure the program's bindings once it finishes
+    pub measure_memory: bool,
+    /// Run [`crate::middleend::optimize::optimize`] before evaluating
+    pub optimize: bool,
+}
+
+impl ScriptOptions {
+    /// Options for a script read from `path`
+    #[must_use]
+    pub fn for_file(path: &Path) -> Self {
+        Self {
+            path: Some(path.to_path_buf()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the evaluation deadline
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the deadline given on the command line
+    #[must_use]
+    pub fn with_timeout_flag(mut self, flag: Option<&str>) -> Self {
+        self.timeout_flag = flag.map(str::to_string);
+        self
+    }
+
+    /// Fold constants and remove dead branches before evaluating
+    #[must_use]
+    pub fn with_optimization(mut self, optimize: bool) -> Self {
+        self.optimize = optimize;
+        self
+    }
+
+    /// Apply the deadline from `--timeout`, the environment or `Ruchy.toml`
+    ///
+    /// The manifest is searched for from the script's directory, or from the
+    /// current directory for inline code.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message when the configured deadline is invalid.
+    pub fn with_configured_timeout(self) -> Result<Self, String> {
+        let start_dir = match self.path.as_deref().and_then(Path::parent) {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+            _ => std::env::current_dir().unwrap_or_default(),
+        };
+        let timeout = configured_timeout(&start_dir, self.timeout_flag.as_deref())?;
+        Ok(self.with_timeout(timeout))
+    }
+}
+
+/// Result of a successful script run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptOutcome {
+    /// Display form of the final value, `None` when it is `nil`
+    pub value: Option<String>,
+    /// Whether `main()` was called automatically
+    pub called_main: bool,
+    /// Memory held by the program's bindings, with
+    /// [`ScriptOptions::measure_memory`]
+    pub memory: Option<MemoryReport>,
+}
+
+/// Why a script failed
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ScriptError {
+    #[error("Syntax error: {0}")]
+    Syntax(String),
+    #[error("Module resolution error: {0}")]
+    ModuleResolution(String),
+    #[error("{0}")]
+    Runtime(String),
+    #[error("Script timed out after {0:?} (raise it with --timeout or RUCHY_TIMEOUT)")]
+    Timeout(Duration),
+}
+
+impl ScriptError {
+    /// Process exit status for this failure
+    #[must_use]
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Runtime(_) => EXIT_RUNTIME_ERROR,
+            Self::Syntax(_) | Self::ModuleResolution(_) => EXIT_SYNTAX_ERROR,
+            Self::Timeout(_) => EXIT_TIMEOUT,
+        }
+    }
+}
+
+/// Parse a deadline such as `500ms`, `30s`, `5m` or a bare number of seconds
+///
+/// `0`, `none` and `off` disable the deadline.
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::runtime::script::parse_timeout;
+/// use std::time::Duration;
+///
+/// assert_eq!(parse_timeout("250ms"), Ok(Some(Duration::from_millis(250))));
+/// assert_eq!(parse_timeout("2m"), Ok(Some(Duration::from_secs(120))));
+/// assert_eq!(parse_timeout("none"), Ok(None));
+/// ```
+///
+/// # Errors
+///
+/// Returns a message naming the rejected value when it is not a duration.
+pub fn parse_timeout(value: &str) -> Result<Option<Duration>, String> {
+    let value = value.trim();
+    if matches!(value.to_ascii_lowercase().as_str(), "none" | "off") {
+        return Ok(None);
+    }
+    let split = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let invalid = || format!("Invalid timeout '{value}': expected e.g. 500ms, 30s or 5m");
+    let number: f64 = number.parse().map_err(|_| invalid())?;
+    let seconds = match unit.trim() {
+        "ms" => number / 1000.0,
+        "" | "s" => number,
+        "m" => number * 60.0,
+        "h" => number * 3600.0,
+        _ => return Err(invalid()),
+    };
+    let duration = Duration::try_from_secs_f64(seconds).map_err(|_| invalid())?;
+    Ok((!duration.is_zero()).then_some(duration))
+}
+
+/// Resolve the deadline for a script located in `start_dir`
+///
+/// `flag`, the value of the binary's `--timeout`, wins over `RUCHY_TIMEOUT`,
+/// which wins over `[run] timeout` in the nearest `Ruchy.toml` found in
+/// `start_dir` or its ancestors; with none of them, there is no deadline.
+///
+/// # Errors
+///
+/// Returns a message when the value that applies cannot be parsed.
+pub fn configured_timeout(
+    start_dir: &Path,
+    flag: Option<&str>,
+) -> Result<Option<Duration>, String> {
+    if let Some(value) = flag {
+        return parse_timeout(value).map_err(|e| format!("--timeout: {e}"));
+    }
+    if let Ok(value) = std::env::var(TIMEOUT_ENV_VAR) {
+        return parse_timeout(&value).map_err(|e| format!("{TIMEOUT_ENV_VAR}: {e}"));
+    }
+    match manifest_timeout(start_dir)? {
+        Some(value) => parse_timeout(&value).map_err(|e| format!("{MANIFEST_FILE}: {e}")),
+        None => Ok(None),
+    }
+}
+
+/// `[run] timeout` from the nearest manifest, as written (`"30s"` or `30`)
+fn manifest_timeout(start_dir: &Path) -> Result<Option<String>, String> {
+    let Some(manifest) = start_dir
+        .ancestors()
+        .map(|dir| dir.join(MANIFEST_FILE))
+        .find(|path| path.is_file())
+    else {
+        return Ok(None);
+    };
+    let content = std::fs::read_to_string(&manifest)
+        .map_err(|e| format!("Failed to read {}: {e}", manifest.display()))?;
+    let table: toml::Table = toml::from_str(&content)
+        .map_err(|e| format!("Failed to parse {}: {e}", manifest.display()))?;
+    Ok(table
+        .get("run")
+        .and_then(|run| run.get("timeout"))
+        .and_then(|timeout| match timeout {
+            toml::Value::String(s) => Some(s.clone()),
+            toml::Value::Integer(n) => Some(n.to_string()),
+            toml::Value::Float(f) => Some(f.to_string()),
+            _ => None,
+        }))
+}
+
+/// Run `source` with the shared script semantics described in the module docs
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::runtime::script::{run_script, ScriptOptions};
+///
+/// let outcome = run_script("fun main() { 6 * 7 }", &ScriptOptions::default()).unwrap();
+/// assert_eq!(outcome.value.as_deref(), Some("42"));
+/// assert!(outcome.called_main);
+/// ```
+///
+/// # Errors
+///
+/// Returns a [`ScriptError`] when parsing, module resolution or evaluation
+/// fails, or when the deadline expires.
+pub fn run_script(source: &str, options: &ScriptOptions) -> Result<ScriptOutcome, ScriptError> {
+    let ast = Parser::new(source)
+        .parse()
+        .map_err(|e| ScriptError::Syntax(e.to_string()))?;
+    let ast = match &options.path {
+        Some(path) if needs_module_resolution(&ast) => {
+            resolve_modules(path, ast).map_err(ScriptError::ModuleResolution)?
+        }
+        _ => ast,
+    };
+    let ast = if options.optimize {
+        crate::middleend::optimize(ast).0
+    } else {
+        ast
+    };
+    match options.timeout {
+        None => evaluate(&ast, source, options.measure_memory),
+        Some(timeout) => {
+            evaluate_with_deadline(ast, source.to_string(), timeout, options.measure_memory)
+        }
+    }
+}
+
+/// Whether the AST contains module declarations or imports to resolve
+#[must_use]
+pub fn needs_module_resolution(expr: &Expr) -> bool {
+    match &expr.kind {
+        ExprKind::ModuleDeclaration { .. }
+        | ExprKind::Module { .. }
+        | ExprKind::Import { .. }
+        | ExprKind::ImportAll { .. }
+        | ExprKind::ImportDefault { .. } => true,
+        ExprKind::Block(exprs) => exprs.iter().any(needs_module_resolution),
+        ExprKind::Function { body, .. } => needs_module_resolution(body),
+        ExprKind::Let { value, body, .. } => {
+            needs_module_resolution(value) || needs_module_resolution(body)
+        }
+        _ => false,
+    }
+}
+
+/// Resolve `mod name;` and imports relative to the script at `source_path`
+///
+/// # Errors
+///
+/// Returns the resolver's message when a module cannot be found or loaded.
+pub fn resolve_modules(source_path: &Path, ast: Expr) -> Result<Expr, String> {
+    let mut resolver = ModuleResolver::new();
+    if let Some(parent_dir) = source_path.parent() {
+        resolver.add_search_path(parent_dir);
+        // Also search in standard project layout directories
+        if let Some(project_root) = parent_dir.parent() {
+            resolver.add_project_search_paths(project_root);
+        }
+    }
+    resolver.resolve_imports(ast).map_err(|e| e.to_string())
+}
+
+/// Whether `main()` should be called after evaluating the top level
+///
+/// True when a parameterless `main` is defined at top level and the top
+/// level does not already call it.
+#[must_use]
+pub fn should_call_main(ast: &Expr) -> bool {
+    let top_level = top_level_exprs(ast);
+    let defines_main = top_level.iter().any(|expr| {
+        matches!(&expr.kind, ExprKind::Function { name, params, .. }
+            if name == "main" && params.is_empty())
+    });
+    let calls_main = top_level.iter().any(|expr| {
+        matches!(&expr.kind, ExprKind::Call { func, .. }
+            if matches!(&func.kind, ExprKind::Identifier(name) if name == "main"))
+    });
+    defines_main && !calls_main
+}
+
+/// Top-level statements, looking through the bodies of `let` chains
+fn top_level_exprs(ast: &Expr) -> Vec<&Expr> {
+    fn let_chain(expr: &Expr) -> Vec<&Expr> {
+        match &expr.kind {
+            ExprKind::Let { body, .. } => std::iter::once(expr).chain(let_chain(body)).collect(),
+            _ => vec![expr],
+        }
+    }
+    match &ast.kind {
+        ExprKind::Block(exprs) => exprs.iter().flat_map(let_chain).collect(),
+        _ => let_chain(ast),
+    }
+}
+
+fn evaluate(ast: &Expr, source: &str, measure_memory: bool) -> Result<ScriptOutcome, ScriptError> {
+    let mut interpreter = Interpreter::new();
+    let mut value = eval_top_level(&mut interpreter, ast, source)?;
+    let called_main = should_call_main(ast);
+    if called_main {
+        let call = Parser::new("main()")
+            .parse()
+            .map_err(|e| ScriptError::Syntax(e.to_string()))?;
+        value = eval_top_level(&mut interpreter, &call, source)?;
+    }
+    Ok(ScriptOutcome {
+        value: (!matches!(value, Value::Nil)).then(|| render_result(&value)),
+        called_main,
+        memory: measure_memory
+            .then(|| memory::measure(&interpreter, &memory::program_bindings(&interpreter))),
+    })
+}
+
+/// Display form of the script result, or one JSON line with `--output json`
+fn render_result(value: &Value) -> String {
+    match records::output_format() {
+        StreamFormat::Json => {
+            records::format_record(value, StreamFormat::Json).unwrap_or_else(|_| value.to_string())
+        }
+        StreamFormat::Text => value.to_string(),
+    }
+}
+
+/// Evaluate `ast`; method-not-found errors point at their call in `source`
+fn eval_top_level(
+    interpreter: &mut Interpreter,
+    ast: &Expr,
+    source: &str,
+) -> Result<Value, ScriptError> {
+    match interpreter.eval_top_level(ast) {
+        Ok(value) | Err(InterpreterError::Return(value)) => Ok(value),
+        Err(InterpreterError::MethodNotFound(missing)) => Err(ScriptError::Runtime(format!(
+            "Runtime error: {}",
+            missing.render(source)
+        ))),
+        Err(e) => Err(ScriptError::Runtime(e.to_string())),
+    }
+}
+
+/// Evaluate on a worker thread so the caller can stop waiting at the deadline
+///
+/// The worker gets the same 8 MiB stack as the main thread so deep
+/// recursion behaves identically with and without a timeout. It also gets
+/// the deadline through [`interrupt::set_deadline`], so loops and calls stop
+/// on their own once it passes instead of running on after the caller has
+/// given up; a blocking builtin (a `sleep`, a read) still finishes first.
+fn evaluate_with_deadline(
+    ast: Expr,
+    source: String,
+    timeout: Duration,
+    measure_memory: bool,
+) -> Result<ScriptOutcome, ScriptError> {
+    let (sender, receiver) = mpsc::channel();
+    let deadline = Instant::now() + timeout;
+    std::thread::Builder::new()
+        .name("ruchy-script".to_string())
+        .stack_size(8 * 1024 * 1024)
+        .spawn(move || {
+            interrupt::set_deadline(Some(deadline));
+            // The receiver is gone once the deadline has passed
+            let _ = sender.send(evaluate(&ast, &source, measure_memory));
+        })
+        .map_err(|e| ScriptError::Runtime(format!("Failed to start script thread: {e}")))?;
+    match receiver.recv_timeout(timeout) {
+        // The worker noticed the deadline before the caller did
+        Ok(Err(ScriptError::Runtime(message))) if is_timeout(&message) => {
+            Err(ScriptError::Timeout(timeout))
+        }
+        Ok(result) => result,
+        Err(mpsc::RecvTimeoutError::Timeout) => Err(ScriptError::Timeout(timeout)),
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            Err(ScriptError::Runtime("Script thread panicked".to_string()))
+        }
+    }
+}
+
+/// Whether a runtime error came from the interpreter's own deadline check
+fn is_timeout(message: &str) -> bool {
+    message.ends_with(interrupt::TIMEOUT_MESSAGE)
+}
+
+#[cfg(test)]
+#[path = "script_tests.rs"]
+mod tests;
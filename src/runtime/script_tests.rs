@@ -0,0 +1,151 @@
+use super::*;
+
+fn run(source: &str) -> Result<ScriptOutcome, ScriptError> {
+    run_script(source, &ScriptOptions::default())
+}
+
+#[test]
+fn test_whole_file_parse_handles_multiline_constructs() {
+    let outcome = run("let total = [1, 2, 3]\n    .map(|x| x * 2)\n    .sum()\ntotal").expect("runs");
+    assert_eq!(outcome.value.as_deref(), Some("12"));
+}
+
+#[test]
+fn test_syntax_error_anywhere_aborts_before_running() {
+    let err = run("println(\"side effect\")\nlet x = ").unwrap_err();
+    assert!(matches!(err, ScriptError::Syntax(_)));
+    assert_eq!(err.exit_code(), EXIT_SYNTAX_ERROR);
+}
+
+#[test]
+fn test_main_is_called_when_defined() {
+    let outcome = run("let base = 40\nfun main() { base + 2 }").expect("runs");
+    assert!(outcome.called_main);
+    assert_eq!(outcome.value.as_deref(), Some("42"));
+}
+
+#[test]
+fn test_main_sees_every_top_level_item() {
+    let source = "fun helper(x) { x * 2 }\nfun main() { println(\"in main\"); helper(21) }";
+    let (outcome, printed) = crate::runtime::output::capture(|| run(source));
+    let outcome = outcome.expect("runs");
+    assert!(outcome.called_main);
+    assert_eq!(printed, "in main\n");
+    assert_eq!(outcome.value.as_deref(), Some("42"));
+}
+
+#[test]
+fn test_main_is_not_called_twice() {
+    let ast = Parser::new("fun main() { 1 }\nmain()")
+        .parse()
+        .expect("parses");
+    assert!(!should_call_main(&ast));
+}
+
+#[test]
+fn test_main_with_parameters_is_not_auto_called() {
+    let outcome = run("fun main(args) { args }\n7").expect("runs");
+    assert!(!outcome.called_main);
+    assert_eq!(outcome.value.as_deref(), Some("7"));
+}
+
+#[test]
+fn test_nil_result_has_no_display_value() {
+    assert_eq!(run("let x = 1\nnil").expect("runs").value, None);
+}
+
+#[test]
+fn test_runtime_error_exit_code() {
+    let err = run("1 / 0").unwrap_err();
+    assert!(matches!(err, ScriptError::Runtime(_)));
+    assert_eq!(err.exit_code(), EXIT_RUNTIME_ERROR);
+}
+
+#[test]
+fn test_timeout_reports_deadline() {
+    let options = ScriptOptions::default().with_timeout(Some(Duration::from_millis(50)));
+    let err = run_script("loop { }", &options).unwrap_err();
+    assert_eq!(err, ScriptError::Timeout(Duration::from_millis(50)));
+    assert!(err.to_string().starts_with("Script timed out after 50ms"));
+    assert_eq!(err.exit_code(), EXIT_TIMEOUT);
+}
+
+#[test]
+fn test_timeout_allows_fast_scripts() {
+    let options = ScriptOptions::default().with_timeout(Some(Duration::from_secs(10)));
+    let outcome = run_script("fun main() { 2 + 2 }", &options).expect("runs");
+    assert_eq!(outcome.value.as_deref(), Some("4"));
+}
+
+#[test]
+fn test_module_resolution_relative_to_script() {
+    let dir = tempfile::TempDir::new().expect("temp dir");
+    std::fs::write(dir.path().join("helpers.ruchy"), "pub fun double(x) { x * 2 }")
+        .expect("write module");
+    let script = dir.path().join("main.ruchy");
+    let source = "mod helpers;\nfun main() { helpers::double(21) }";
+    std::fs::write(&script, source).expect("write");
+
+    let outcome = run_script(source, &ScriptOptions::for_file(&script)).expect("runs");
+    assert_eq!(outcome.value.as_deref(), Some("42"));
+}
+
+#[test]
+fn test_parse_timeout_units() {
+    assert_eq!(parse_timeout("500ms"), Ok(Some(Duration::from_millis(500))));
+    assert_eq!(parse_timeout("30"), Ok(Some(Duration::from_secs(30))));
+    assert_eq!(parse_timeout("1.5s"), Ok(Some(Duration::from_millis(1500))));
+    assert_eq!(parse_timeout("1h"), Ok(Some(Duration::from_secs(3600))));
+    assert_eq!(parse_timeout("0"), Ok(None));
+    assert_eq!(parse_timeout("OFF"), Ok(None));
+}
+
+#[test]
+fn test_parse_timeout_rejects_garbage() {
+    for value in ["", "fast", "10 years", "-5s"] {
+        let err = parse_timeout(value).unwrap_err();
+        assert!(err.contains("Invalid timeout"), "{value}: {err}");
+    }
+}
+
+#[test]
+fn test_manifest_timeout_found_in_ancestor() {
+    let dir = tempfile::TempDir::new().expect("temp dir");
+    std::fs::write(
+        dir.path().join(MANIFEST_FILE),
+        "[package]\nname = \"demo\"\n\n[run]\ntimeout = \"45s\"\n",
+    )
+    .expect("write manifest");
+    let nested = dir.path().join("src");
+    std::fs::create_dir(&nested).expect("create dir");
+
+    assert_eq!(manifest_timeout(&nested), Ok(Some("45s".to_string())));
+    assert_eq!(
+        configured_timeout(&nested, Some("2s")),
+        Ok(Some(Duration::from_secs(2)))
+    );
+    assert_eq!(configured_timeout(&nested, Some("0")), Ok(None));
+}
+
+#[test]
+fn test_manifest_without_run_table_has_no_timeout() {
+    let dir = tempfile::TempDir::new().expect("temp dir");
+    std::fs::write(dir.path().join(MANIFEST_FILE), "[package]\nname = \"demo\"\n")
+        .expect("write manifest");
+    assert_eq!(manifest_timeout(dir.path()), Ok(None));
+}
+
+#[test]
+fn test_measure_memory_reports_program_bindings() {
+    let options = ScriptOptions {
+        measure_memory: true,
+        ..ScriptOptions::default()
+    };
+    let outcome = run_script("let numbers = [1, 2, 3]\nfun main() { numbers.len() }", &options)
+        .expect("runs");
+    let memory = outcome.memory.expect("memory measured");
+    let names: Vec<_> = memory.bindings.iter().map(|b| b.name.as_str()).collect();
+    assert_eq!(names, ["numbers", "main"]);
+    assert_eq!(memory.bindings[0].length, Some(3));
+    assert!(run("let x = 1").expect("runs").memory.is_none());
+}
@@ -0,0 +1,322 @@
+//! Tracing of interpreted code for OpenTelemetry
+//!
+//! While telemetry is enabled, the interpreter reports what scripts do
+//! through the `tracing` crate, using the field names `tracing-opentelemetry`
+//! maps to OpenTelemetry:
+//!
+//! - a `ruchy.call` span for every named function call, with `otel.name`
+//!   set to the function;
+//! - a `ruchy.effect` span for every call of a builtin that touches the
+//!   filesystem, processes, the environment or the network, with the
+//!   capability in `ruchy.capability`;
+//! - `otel.status_code = "ERROR"` on the span of a call that failed;
+//! - a metrics event when a function call ends, with
+//!   `monotonic_counter.ruchy.calls` and `histogram.ruchy.call.duration_ms`
+//!   for `tracing-opentelemetry`'s `MetricsLayer`.
+//!
+//! A host with a `tracing-opentelemetry` layer installed exports all of this
+//! without changes to the scripts; `ruchy run --otel-sample-rate` installs
+//! one that exports spans over OTLP when built with the `otel` feature.
+//!
+//! Sampling is decided per trace: [`TelemetryConfig::sample_rate`] of the
+//! outermost calls, evenly spread, start a trace that covers everything they
+//! call. Effects outside any function count as outermost calls. Metrics
+//! events are emitted for every call, sampled or not.
+//!
+//! Whether telemetry is enabled is process-global because scripts with a
+//! deadline evaluate on a worker thread; open spans are per thread. Enable
+//! it before running scripts: calls that began while it was disabled aren't
+//! tracked. Calls cost one atomic load while telemetry is disabled.
+use crate::runtime::restricted;
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Instant;
+use tracing::span::EnteredSpan;
+
+/// How much of a run telemetry traces
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TelemetryConfig {
+    sample_rate: f64,
+}
+
+impl TelemetryConfig {
+    /// Trace `sample_rate` of the outermost calls
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `sample_rate` isn't between 0.0 and 1.0
+    pub fn new(sample_rate: f64) -> Result<Self, String> {
+        if (0.0..=1.0).contains(&sample_rate) {
+            Ok(Self { sample_rate })
+        } else {
+            Err(format!(
+                "Sample rate must be between 0.0 and 1.0, got {sample_rate}"
+            ))
+        }
+    }
+
+    /// Fraction of traces recorded, from 0.0 (none) to 1.0 (all)
+    pub fn sample_rate(&self) -> f64 {
+        self.sample_rate
+    }
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self { sample_rate: 1.0 }
+    }
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// Bits of the `f64` sample rate
+static SAMPLE_RATE: AtomicU64 = AtomicU64::new(0);
+
+/// Start reporting calls and effects of this process's interpreters
+pub fn enable(config: TelemetryConfig) {
+    SAMPLE_RATE.store(config.sample_rate().to_bits(), Ordering::Relaxed);
+    ENABLED.store(true, Ordering::Release);
+}
+
+/// Stop reporting calls and effects
+pub fn disable() {
+    ENABLED.store(false, Ordering::Release);
+}
+
+/// Whether telemetry is enabled
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Acquire)
+}
+
+/// Spreads sampled traces evenly: the sample rate accumulates as credit
+/// until a whole trace is due
+#[derive(Debug, Default)]
+struct Sampler {
+    credit: f64,
+}
+
+impl Sampler {
+    fn sample(&mut self, rate: f64) -> bool {
+        self.credit += rate;
+        if self.credit >= 1.0 {
+            self.credit -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// A function call in progress
+struct Frame {
+    name: String,
+    start: Instant,
+    /// `None` when the call's trace isn't sampled
+    span: Option<EnteredSpan>,
+}
+
+#[derive(Default)]
+struct ThreadState {
+    frames: Vec<Frame>,
+    sampler: Sampler,
+}
+
+impl ThreadState {
+    /// Whether a span starting now is recorded: its parent's decision, or a
+    /// new one when it starts a trace
+    fn sampled(&mut self) -> bool {
+        match self.frames.last() {
+            Some(parent) => parent.span.is_some(),
+            None => {
+                let rate = f64::from_bits(SAMPLE_RATE.load(Ordering::Relaxed));
+                self.sampler.sample(rate)
+            }
+        }
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<ThreadState> = RefCell::new(ThreadState::default());
+}
+
+/// A call of `name` begins
+pub(crate) fn enter(name: &str) {
+    if !is_enabled() {
+        return;
+    }
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let span = state.sampled().then(|| {
+            tracing::info_span!(
+                "ruchy.call",
+                otel.name = %name,
+                code.function = %name,
+                otel.status_code = tracing::field::Empty
+            )
+            .entered()
+        });
+        state.frames.push(Frame {
+            name: name.to_string(),
+            start: Instant::now(),
+            span,
+        });
+    });
+}
+
+/// The innermost call ends
+pub(crate) fn exit(failed: bool) {
+    if !is_enabled() {
+        return;
+    }
+    let Some(frame) = STATE.with(|state| state.borrow_mut().frames.pop()) else {
+        return;
+    };
+    tracing::info!(
+        monotonic_counter.ruchy.calls = 1_u64,
+        histogram.ruchy.call.duration_ms = frame.start.elapsed().as_secs_f64() * 1000.0,
+        function = %frame.name,
+        failed
+    );
+    if let Some(span) = frame.span {
+        mark_failed(&span, failed);
+    }
+}
+
+/// Run builtin `builtin`, in a `ruchy.effect` span if it needs a capability
+pub(crate) fn effect<T, E>(builtin: &str, run: impl FnOnce() -> Result<T, E>) -> Result<T, E> {
+    if !is_enabled() {
+        return run();
+    }
+    let Some(capability) = restricted::denied_capability(builtin) else {
+        return run();
+    };
+    if !STATE.with(|state| state.borrow_mut().sampled()) {
+        return run();
+    }
+    let span = tracing::info_span!(
+        "ruchy.effect",
+        otel.name = %restricted::user_facing_name(builtin),
+        ruchy.capability = capability,
+        otel.status_code = tracing::field::Empty
+    )
+    .entered();
+    let result = run();
+    mark_failed(&span, result.is_err());
+    result
+}
+
+fn mark_failed(span: &EnteredSpan, failed: bool) {
+    if failed {
+        span.record("otel.status_code", "ERROR");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::parser::Parser;
+    use crate::runtime::interpreter::Interpreter;
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Subscriber};
+    use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+    /// Spans, recorded fields and events, one line each
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    #[derive(Default)]
+    struct Fields(Vec<String>);
+
+    impl Visit for Fields {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.push(format!("{}={value}", field.name()));
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0.push(format!("{}={value:?}", field.name()));
+        }
+    }
+
+    impl Recorder {
+        fn push(&self, kind: &str, fields: Fields) {
+            let line = format!("{kind} {}", fields.0.join(" "));
+            self.0.lock().unwrap().push(line);
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for Recorder {
+        fn on_new_span(&self, attrs: &Attributes<'_>, _: &Id, _: Context<'_, S>) {
+            let mut fields = Fields::default();
+            attrs.record(&mut fields);
+            self.push(attrs.metadata().name(), fields);
+        }
+
+        fn on_record(&self, _: &Id, values: &Record<'_>, _: Context<'_, S>) {
+            let mut fields = Fields::default();
+            values.record(&mut fields);
+            self.push("record", fields);
+        }
+
+        fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+            let mut fields = Fields::default();
+            event.record(&mut fields);
+            self.push("event", fields);
+        }
+    }
+
+    #[test]
+    fn test_sampler_spreads_traces_evenly() {
+        let mut sampler = Sampler::default();
+        let picks: Vec<bool> = (0..8).map(|_| sampler.sample(0.25)).collect();
+        assert_eq!(
+            picks,
+            [false, false, false, true, false, false, false, true]
+        );
+        assert!((0..10).all(|_| !Sampler::default().sample(0.0)));
+        assert!((0..10).all(|_| sampler.sample(1.0)));
+        assert!(TelemetryConfig::new(1.5).is_err());
+        assert!(TelemetryConfig::new(-0.1).is_err());
+        assert!(TelemetryConfig::new(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_calls_and_effects_become_spans_and_metrics() {
+        let recorder = Recorder::default();
+        let subscriber = tracing_subscriber::registry().with(recorder.clone());
+        let source = "fun double(x) { x * 2 }\nfun load() { fs_read(42) }";
+        let definitions = Parser::new(source).parse().expect("source should parse");
+        let calls = ["double(21)", "load()"].map(|call| Parser::new(call).parse().unwrap());
+        tracing::subscriber::with_default(subscriber, || {
+            let mut interpreter = Interpreter::new();
+            interpreter.eval_top_level(&definitions).unwrap();
+            enable(TelemetryConfig::default());
+            let doubled = interpreter.eval_expr(&calls[0]);
+            let loaded = interpreter.eval_expr(&calls[1]);
+            disable();
+            assert!(doubled.is_ok());
+            assert!(loaded.is_err());
+        });
+        let lines = recorder.0.lock().unwrap().clone();
+        let has = |needle: &str| lines.iter().any(|line| line.contains(needle));
+        assert!(has("ruchy.call otel.name=double"), "{lines:#?}");
+        assert!(has("ruchy.call otel.name=load"), "{lines:#?}");
+        assert!(
+            has("ruchy.effect otel.name=fs_read ruchy.capability=filesystem"),
+            "{lines:#?}"
+        );
+        assert_eq!(
+            lines
+                .iter()
+                .filter(|line| line.contains("otel.status_code=ERROR"))
+                .count(),
+            2,
+            "the effect and the call that ran it failed: {lines:#?}"
+        );
+        assert!(
+            has("monotonic_counter.ruchy.calls=1") && has("function=double failed=false"),
+            "{lines:#?}"
+        );
+    }
+}
@@ -320,7 +320,15 @@ impl TypeFeedback {
 
     /// Record variable assignment type feedback
     pub fn record_variable_assignment(&mut self, var_name: &str, new_type: std::any::TypeId) {
-        let feedback = self.variable_types.entry(var_name.to_string()).or_default();
+        // Only allocate the key the first time a variable is seen; reassignments
+        // in hot loops hit the existing entry
+        if !self.variable_types.contains_key(var_name) {
+            self.variable_types
+                .insert(var_name.to_string(), VariableTypeFeedback::default());
+        }
+        let Some(feedback) = self.variable_types.get_mut(var_name) else {
+            return;
+        };
 
         // Record type transition if there was a previous type
         if let Some(prev_type) = feedback.dominant_type {
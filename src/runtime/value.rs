@@ -38,6 +38,11 @@ pub struct DataFrameColumn {
 pub enum Value {
     /// 64-bit signed integer
     Integer(i64),
+    /// Arbitrary-precision integer, produced on `Integer` overflow and by
+    /// `123n` literals
+    BigInt(Arc<num_bigint::BigInt>),
+    /// Exact base-10 decimal for money, from `1.23d` literals and `Decimal()`
+    Decimal(rust_decimal::Decimal),
     /// 64-bit float
     Float(f64),
     /// Boolean value
@@ -95,6 +100,15 @@ pub enum Value {
         fields: Arc<std::sync::RwLock<HashMap<String, Value>>>,
         methods: Arc<HashMap<String, Value>>, // method name -> Closure
     },
+    /// Error caught by try/catch: kind (e.g. "`TypeError`"), message, and the
+    /// call stack at the point of failure (innermost frame first)
+    Error {
+        kind: String,
+        message: Arc<str>,
+        stack: Arc<[String]>,
+    },
+    /// Lazy iterator pipeline, shared so `next()` advances every handle
+    Iterator(Rc<RefCell<crate::runtime::eval_iterator::LazyIter>>),
     /// HTML document (HTTP-002-C)
     #[cfg(not(target_arch = "wasm32"))]
     HtmlDocument(crate::stdlib::html::HtmlDocument),
@@ -109,6 +123,14 @@ impl PartialEq for Value {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Value::Integer(a), Value::Integer(b)) => a == b,
+            (Value::BigInt(a), Value::BigInt(b)) => a == b,
+            (Value::BigInt(a), Value::Integer(b)) | (Value::Integer(b), Value::BigInt(a)) => {
+                **a == num_bigint::BigInt::from(*b)
+            }
+            (Value::Decimal(a), Value::Decimal(b)) => a == b,
+            (Value::Decimal(a), Value::Integer(b)) | (Value::Integer(b), Value::Decimal(a)) => {
+                *a == rust_decimal::Decimal::from(*b)
+            }
             (Value::Float(a), Value::Float(b)) => a == b,
             (Value::String(a), Value::String(b)) => a == b,
             (Value::Atom(a), Value::Atom(b)) => a == b,
@@ -134,6 +156,31 @@ impl PartialEq for Value {
             }
             (Value::Nil, Value::Nil) => true,
             (Value::Byte(a), Value::Byte(b)) => a == b,
+            (
+                Value::EnumVariant {
+                    enum_name: e1,
+                    variant_name: v1,
+                    data: d1,
+                },
+                Value::EnumVariant {
+                    enum_name: e2,
+                    variant_name: v2,
+                    data: d2,
+                },
+            ) => e1 == e2 && v1 == v2 && d1 == d2,
+            (
+                Value::Error {
+                    kind: k1,
+                    message: m1,
+                    ..
+                },
+                Value::Error {
+                    kind: k2,
+                    message: m2,
+                    ..
+                },
+            ) => k1 == k2 && m1 == m2, // Stack traces don't affect equality
+            (Value::Iterator(a), Value::Iterator(b)) => Rc::ptr_eq(a, b), // Identity-based
             #[cfg(not(target_arch = "wasm32"))]
             (Value::HtmlDocument(_), Value::HtmlDocument(_)) => false, // Documents compared by identity
             #[cfg(not(target_arch = "wasm32"))]
@@ -152,6 +199,8 @@ impl Value {
         use std::any::TypeId;
         match self {
             Value::Integer(_) => TypeId::of::<i64>(),
+            Value::BigInt(_) => TypeId::of::<num_bigint::BigInt>(),
+            Value::Decimal(_) => TypeId::of::<rust_decimal::Decimal>(),
             Value::Float(_) => TypeId::of::<f64>(),
             Value::Bool(_) => TypeId::of::<bool>(),
             Value::Byte(_) => TypeId::of::<u8>(),
@@ -169,6 +218,8 @@ impl Value {
             Value::BuiltinFunction(_) => TypeId::of::<fn()>(),
             Value::Struct { .. } => TypeId::of::<HashMap<String, Value>>(),
             Value::Class { .. } => TypeId::of::<HashMap<String, Value>>(),
+            Value::Error { .. } => TypeId::of::<crate::runtime::InterpreterError>(),
+            Value::Iterator(_) => TypeId::of::<crate::runtime::eval_iterator::LazyIter>(),
             #[cfg(not(target_arch = "wasm32"))]
             Value::HtmlDocument(_) => TypeId::of::<crate::stdlib::html::HtmlDocument>(),
             #[cfg(not(target_arch = "wasm32"))]
@@ -236,6 +287,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_value_error_equality_ignores_stack() {
+        let a = Value::error("RuntimeError", "boom", vec!["f".to_string()]);
+        let b = Value::error("RuntimeError", "boom", Vec::new());
+        let c = Value::error("TypeError", "boom", Vec::new());
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
     #[test]
     fn test_value_array_equality() {
         let a1 = Value::Array(Arc::from(vec![Value::Integer(1), Value::Integer(2)]));
@@ -4,55 +4,129 @@
 
 use crate::runtime::Value;
 
+/// One piece of a parsed format template
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum FormatPiece {
+    /// Literal text, including malformed placeholders kept verbatim
+    Text(String),
+    /// `{}` - display the next value
+    Display,
+    /// `{:?}` - debug-print the next value
+    Debug,
+    /// `{N}` - display value `N`; `closed` is false when the `}` is missing
+    Positional {
+        digits: String,
+        index: Option<usize>,
+        closed: bool,
+    },
+}
+
 /// Format string with placeholder replacement
-/// Handles `{}` and `{:?}` placeholders
+/// Handles `{}`, `{:?}` and positional `{0}`, `{1}`, ... placeholders
+///
+/// Templates are parsed once and cached (see `pattern_cache`), so repeated
+/// `format()`/`println!` calls in a loop only render.
 pub fn format_string_with_values(format_str: &str, values: &[Value]) -> String {
+    let pieces = crate::runtime::pattern_cache::cached_format_template(format_str);
     let mut result = String::new();
+    let mut next_values = values.iter();
+
+    for piece in pieces.iter() {
+        match piece {
+            FormatPiece::Text(text) => result.push_str(text),
+            FormatPiece::Display => match next_values.next() {
+                Some(value) => result.push_str(&display_arg(value)),
+                None => result.push_str("{}"),
+            },
+            FormatPiece::Debug => match next_values.next() {
+                Some(value) => result.push_str(&format!("{value:?}")),
+                None => result.push_str("{:?}"),
+            },
+            FormatPiece::Positional {
+                digits,
+                index,
+                closed,
+            } => match index.and_then(|i| values.get(i)) {
+                Some(value) if *closed => result.push_str(&display_arg(value)),
+                // Indices past the end of `values`, or digits without a
+                // closing `}`, are kept verbatim
+                _ => {
+                    result.push('{');
+                    result.push_str(digits);
+                    if *closed {
+                        result.push('}');
+                    }
+                }
+            },
+        }
+    }
+
+    result
+}
+
+/// Split a format template into text and placeholders
+pub(crate) fn parse_format_template(format_str: &str) -> Vec<FormatPiece> {
+    let mut pieces = Vec::new();
+    let mut text = String::new();
     let mut chars = format_str.chars().peekable();
-    let mut value_index = 0;
 
     while let Some(ch) = chars.next() {
-        if ch == '{' {
-            if chars.peek() == Some(&':') {
-                chars.next();
-                if chars.peek() == Some(&'?') {
-                    chars.next();
-                    if chars.peek() == Some(&'}') {
-                        chars.next();
-                        if value_index < values.len() {
-                            result.push_str(&format!("{:?}", values[value_index]));
-                            value_index += 1;
-                        } else {
-                            result.push_str("{:?}");
-                        }
-                    } else {
-                        result.push_str("{:?");
-                    }
-                } else {
-                    result.push_str("{:");
-                }
-            } else if chars.peek() == Some(&'}') {
-                chars.next();
-                if value_index < values.len() {
-                    // Extract raw string without Display quotes
-                    let display_val = match &values[value_index] {
-                        Value::String(ref s) => s.as_ref().to_string(),
-                        _ => values[value_index].to_string(),
-                    };
-                    result.push_str(&display_val);
-                    value_index += 1;
-                } else {
-                    result.push_str("{}");
-                }
+        if ch != '{' {
+            text.push(ch);
+            continue;
+        }
+        let placeholder = if chars.next_if_eq(&':').is_some() {
+            if chars.next_if_eq(&'?').is_none() {
+                text.push_str("{:");
+                None
+            } else if chars.next_if_eq(&'}').is_none() {
+                text.push_str("{:?");
+                None
             } else {
-                result.push(ch);
+                Some(FormatPiece::Debug)
             }
+        } else if chars.next_if_eq(&'}').is_some() {
+            Some(FormatPiece::Display)
+        } else if chars.peek().is_some_and(char::is_ascii_digit) {
+            Some(parse_positional(&mut chars))
         } else {
-            result.push(ch);
+            text.push(ch);
+            None
+        };
+        if let Some(placeholder) = placeholder {
+            if !text.is_empty() {
+                pieces.push(FormatPiece::Text(std::mem::take(&mut text)));
+            }
+            pieces.push(placeholder);
         }
     }
+    if !text.is_empty() {
+        pieces.push(FormatPiece::Text(text));
+    }
+    pieces
+}
 
-    result
+/// Display a format argument, without quotes for strings
+fn display_arg(value: &Value) -> String {
+    match value {
+        Value::String(ref s) => s.as_ref().to_string(),
+        _ => value.to_string(),
+    }
+}
+
+/// Parse a positional `{N}` placeholder (the `{` is already consumed)
+fn parse_positional(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> FormatPiece {
+    let mut digits = String::new();
+    while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+        digits.push(digit);
+    }
+    let index = digits.parse::<usize>().ok();
+    let closed = chars.next_if_eq(&'}').is_some();
+    FormatPiece::Positional {
+        digits,
+        index,
+        closed,
+    }
 }
 
 /// Format a value with a format specifier like `:.2` for floats
@@ -123,6 +197,39 @@ mod tests {
         assert_eq!(result, "Hello World");
     }
 
+    #[test]
+    fn test_format_positional_placeholders() {
+        let values = vec![Value::from_string("a".to_string()), Value::Integer(2)];
+        let result = format_string_with_values("{1}{0}{1} {}", &values);
+        assert_eq!(result, "2a2 a");
+    }
+
+    #[test]
+    fn test_parse_format_template_pieces() {
+        let pieces = parse_format_template("a{}b{:?}{1}{:x");
+        assert_eq!(
+            pieces,
+            vec![
+                FormatPiece::Text("a".to_string()),
+                FormatPiece::Display,
+                FormatPiece::Text("b".to_string()),
+                FormatPiece::Debug,
+                FormatPiece::Positional {
+                    digits: "1".to_string(),
+                    index: Some(1),
+                    closed: true,
+                },
+                FormatPiece::Text("{:x".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_positional_out_of_range_is_kept() {
+        let result = format_string_with_values("{3} {1", &[Value::Integer(1), Value::Integer(2)]);
+        assert_eq!(result, "{3} {1");
+    }
+
     #[test]
     fn test_format_more_placeholders_than_values() {
         let result = format_string_with_values("{} {}", &[Value::Integer(1)]);
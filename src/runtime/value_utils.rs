@@ -23,6 +23,20 @@ impl Value {
         Value::Integer(i)
     }
 
+    /// Create an arbitrary-precision integer value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruchy::runtime::Value;
+    ///
+    /// let val = Value::from_bigint(num_bigint::BigInt::from(7));
+    /// assert_eq!(val, Value::from_i64(7));
+    /// ```
+    pub fn from_bigint(n: num_bigint::BigInt) -> Self {
+        Value::BigInt(Arc::new(n))
+    }
+
     /// Create a float value from an `f64`.
     ///
     /// # Examples
@@ -52,6 +66,16 @@ impl Value {
         Value::String(Arc::from(s))
     }
 
+    /// Create string value, sharing storage with equal short strings
+    pub fn from_interned(s: &str) -> Self {
+        Value::String(crate::runtime::intern::intern(s))
+    }
+
+    /// Create single-character string value from the intern table
+    pub fn from_char(c: char) -> Self {
+        Value::String(crate::runtime::intern::intern_char(c))
+    }
+
     /// Create array value
     pub fn from_array(arr: Vec<Value>) -> Self {
         Value::Array(Arc::from(arr))
@@ -71,6 +95,39 @@ impl Value {
         }
     }
 
+    /// Create an error value with the call stack at the failure point
+    /// (innermost frame first)
+    pub fn error(kind: &str, message: impl Into<Arc<str>>, stack: Vec<String>) -> Self {
+        Value::Error {
+            kind: kind.to_string(),
+            message: message.into(),
+            stack: Arc::from(stack),
+        }
+    }
+
+    /// Read a field of an error value: `kind` (alias `type`), `message` or `stack`
+    pub fn error_field(&self, field: &str) -> Option<Value> {
+        let Value::Error {
+            kind,
+            message,
+            stack,
+        } = self
+        else {
+            return None;
+        };
+        match field {
+            "type" | "kind" => Some(Value::from_string(kind.clone())),
+            "message" => Some(Value::String(message.clone())),
+            "stack" => Some(Value::Array(
+                stack
+                    .iter()
+                    .map(|frame| Value::from_string(frame.clone()))
+                    .collect(),
+            )),
+            _ => None,
+        }
+    }
+
     /// Create enum variant value
     pub fn from_enum_variant(
         enum_name: String,
@@ -155,6 +212,8 @@ impl Value {
     pub fn type_name(&self) -> &'static str {
         match self {
             Value::Integer(_) => "integer",
+            Value::BigInt(_) => "bigint",
+            Value::Decimal(_) => "decimal",
             Value::Float(_) => "float",
             Value::Bool(_) => "boolean",
             Value::Byte(_) => "byte",
@@ -171,6 +230,8 @@ impl Value {
             Value::BuiltinFunction(_) => "builtin_function",
             Value::Struct { .. } => "struct",
             Value::Class { .. } => "class",
+            Value::Error { .. } => "error",
+            Value::Iterator(_) => "iterator",
             #[cfg(not(target_arch = "wasm32"))]
             Value::HtmlDocument(_) => "html_document",
             #[cfg(not(target_arch = "wasm32"))]
@@ -0,0 +1,653 @@
+//! Snippet evaluation API for `ruchy serve --eval-api`
+//!
+//! Lets the docs site and course pages run examples on the server: a client
+//! posts `{"source": "..."}` to [`EVAL_ROUTE`] and gets back an
+//! [`EvalResponse`] with the printed output, the value and any error.
+//!
+//! Every request is evaluated in a fresh interpreter, so nothing one caller
+//! defines is visible to the next, under the restrictions of
+//! [`crate::runtime::restricted`]: no filesystem, process, environment or
+//! network access, and a time, step and output budget from [`EvalApiConfig`].
+//! Requests may ask for smaller budgets, never larger ones. Host paths in
+//! error messages are redacted.
+//!
+//! Printed output is captured per evaluation. At most
+//! [`EvalApiConfig::max_concurrent`] evaluations run at once; a request waits
+//! for a free slot for up to its time budget.
+//! Each client IP gets [`EvalApiConfig::requests_per_minute`] requests per
+//! minute; further requests are answered with `429 Too Many Requests`.
+
+use crate::frontend::parser::Parser;
+use crate::runtime::interrupt::{self, STEP_LIMIT_MESSAGE, TIMEOUT_MESSAGE};
+use crate::runtime::output;
+use crate::runtime::restricted::{self, Limits, OUTPUT_LIMIT_MESSAGE, SIZE_LIMIT_MESSAGE};
+use crate::runtime::{eval_function, Interpreter, InterpreterError, Value};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::sync::{Condvar, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Path of the evaluation endpoint
+pub const EVAL_ROUTE: &str = "/api/eval";
+
+/// Clients tracked before expired rate-limit windows are dropped
+const PRUNE_THRESHOLD: usize = 1024;
+
+/// Slots of the evaluations running in this process
+static SLOTS: Slots = Slots::new();
+
+/// Budgets and rate limit of the evaluation API
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EvalApiConfig {
+    /// Wall-clock budget of one evaluation
+    pub timeout: Duration,
+    /// Loop iterations and function calls allowed per evaluation
+    pub max_steps: u64,
+    /// Maximum bytes printed per evaluation
+    pub max_output: usize,
+    /// Maximum bytes of source per request
+    pub max_source: usize,
+    /// Maximum call depth
+    pub max_depth: usize,
+    /// Requests allowed per client IP and minute
+    pub requests_per_minute: u32,
+    /// Evaluations allowed to run at the same time
+    pub max_concurrent: usize,
+}
+
+impl Default for EvalApiConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(2),
+            max_steps: 1_000_000,
+            max_output: 16 * 1024,
+            max_source: 64 * 1024,
+            max_depth: 64,
+            requests_per_minute: 60,
+            max_concurrent: 4,
+        }
+    }
+}
+
+/// Body of a request to [`EVAL_ROUTE`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvalRequest {
+    /// Ruchy source to evaluate
+    pub source: String,
+    /// Lower time budget in milliseconds
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+    /// Lower step budget
+    #[serde(default)]
+    pub max_steps: Option<u64>,
+}
+
+/// Why an evaluation failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EvalErrorKind {
+    /// The source does not parse
+    Parse,
+    /// The program failed while running
+    Runtime,
+    /// The time budget ran out
+    Timeout,
+    /// The step budget ran out
+    StepLimit,
+    /// The program printed more than its output budget
+    OutputLimit,
+    /// The program built a string or list over [`restricted::MAX_VALUE_SIZE`]
+    SizeLimit,
+    /// The source is larger than [`EvalApiConfig::max_source`]
+    SourceTooLarge,
+    /// The client sent too many requests
+    RateLimited,
+}
+
+/// Failure of an evaluation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvalError {
+    /// Category of the failure
+    pub kind: EvalErrorKind,
+    /// Human-readable message
+    pub message: String,
+}
+
+/// Body of a response from [`EVAL_ROUTE`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvalResponse {
+    /// Whether the program ran to completion
+    pub success: bool,
+    /// Text printed by the program
+    pub stdout: String,
+    /// Display form of the final value, absent for `nil`
+    pub value: Option<String>,
+    /// Type of the final value
+    pub value_type: Option<String>,
+    /// Why the evaluation failed
+    pub error: Option<EvalError>,
+    /// Loop iterations and function calls taken
+    pub steps: u64,
+    /// Wall-clock time spent evaluating
+    pub duration_ms: u64,
+}
+
+impl EvalResponse {
+    /// Response of a request that was refused or failed before running
+    pub fn failure(kind: EvalErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            stdout: String::new(),
+            value: None,
+            value_type: None,
+            error: Some(EvalError {
+                kind,
+                message: message.into(),
+            }),
+            steps: 0,
+            duration_ms: 0,
+        }
+    }
+}
+
+/// Evaluate `request` in a fresh, restricted interpreter
+///
+/// Blocks for up to the time budget; async callers should run it on a
+/// blocking thread.
+///
+/// # Examples
+///
+/// ```
+/// use ruchy::server::eval_api::{evaluate, EvalApiConfig, EvalRequest};
+///
+/// let request = EvalRequest {
+///     source: "println(\"hi\")\n40 + 2".to_string(),
+///     ..EvalRequest::default()
+/// };
+/// let response = evaluate(&request, &EvalApiConfig::default());
+/// assert!(response.success);
+/// assert_eq!(response.stdout, "hi\n");
+/// assert_eq!(response.value.as_deref(), Some("42"));
+/// ```
+pub fn evaluate(request: &EvalRequest, config: &EvalApiConfig) -> EvalResponse {
+    if request.source.len() > config.max_source {
+        return EvalResponse::failure(
+            EvalErrorKind::SourceTooLarge,
+            format!("Source exceeds {} bytes", config.max_source),
+        );
+    }
+    let ast = match Parser::new(&request.source).parse() {
+        Ok(ast) => ast,
+        Err(e) => return EvalResponse::failure(EvalErrorKind::Parse, redact(&e.to_string())),
+    };
+
+    let limits = Limits {
+        timeout: request
+            .timeout_ms
+            .map_or(config.timeout, Duration::from_millis)
+            .min(config.timeout),
+        max_output: config.max_output,
+    };
+    let max_steps = request
+        .max_steps
+        .map_or(config.max_steps, |steps| steps.min(config.max_steps));
+
+    let Some(_slot) = SLOTS.acquire(config.max_concurrent, limits.timeout) else {
+        return EvalResponse::failure(
+            EvalErrorKind::Timeout,
+            "Too many evaluations running; try again later",
+        );
+    };
+    let started = Instant::now();
+    let budgets = ThreadBudgets::install(config.max_depth, max_steps);
+    let (result, stdout) = output::capture(|| {
+        let _guard = restricted::enter(limits);
+        match Interpreter::new().eval_expr(&ast) {
+            Err(InterpreterError::Return(value)) => Ok(value),
+            other => other,
+        }
+    });
+    let steps = interrupt::steps_taken();
+    drop(budgets);
+    let duration_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+    let (value, value_type, error) = match result {
+        Ok(value) => (
+            (!matches!(value, Value::Nil)).then(|| value.to_string()),
+            Some(value.type_name().to_string()),
+            None,
+        ),
+        Err(e) => (None, None, Some(classify(&e))),
+    };
+    EvalResponse {
+        success: error.is_none(),
+        stdout: restricted::truncate_output(&stdout, config.max_output),
+        value,
+        value_type,
+        error,
+        steps,
+        duration_ms,
+    }
+}
+
+/// Counting semaphore capping how many evaluations run at once
+struct Slots {
+    running: Mutex<usize>,
+    freed: Condvar,
+}
+
+impl Slots {
+    const fn new() -> Self {
+        Self {
+            running: Mutex::new(0),
+            freed: Condvar::new(),
+        }
+    }
+
+    /// Wait up to `timeout` for fewer than `max_concurrent` evaluations to
+    /// run, then take a slot
+    fn acquire(&self, max_concurrent: usize, timeout: Duration) -> Option<Slot<'_>> {
+        let max_concurrent = max_concurrent.max(1);
+        let running = self.running.lock().unwrap_or_else(PoisonError::into_inner);
+        let (mut running, _) = self
+            .freed
+            .wait_timeout_while(running, timeout, |running| *running >= max_concurrent)
+            .unwrap_or_else(PoisonError::into_inner);
+        if *running >= max_concurrent {
+            return None;
+        }
+        *running += 1;
+        Some(Slot { slots: self })
+    }
+}
+
+/// A running evaluation's slot, freed when dropped
+struct Slot<'a> {
+    slots: &'a Slots,
+}
+
+impl Drop for Slot<'_> {
+    fn drop(&mut self) {
+        *self
+            .slots
+            .running
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) -= 1;
+        self.slots.freed.notify_one();
+    }
+}
+
+/// Recursion and step limits of one evaluation on the current thread
+///
+/// The worker thread is reused, so both limits are put back when this is
+/// dropped, including while unwinding from a panic in the interpreter.
+struct ThreadBudgets {
+    previous_depth: usize,
+}
+
+impl ThreadBudgets {
+    fn install(max_depth: usize, max_steps: u64) -> Self {
+        let previous_depth = eval_function::get_max_recursion_depth();
+        eval_function::set_max_recursion_depth(max_depth);
+        interrupt::set_step_limit(Some(max_steps));
+        Self { previous_depth }
+    }
+}
+
+impl Drop for ThreadBudgets {
+    fn drop(&mut self) {
+        interrupt::set_step_limit(None);
+        eval_function::set_max_recursion_depth(self.previous_depth);
+    }
+}
+
+/// Map an interpreter error onto the error reported to clients
+fn classify(error: &InterpreterError) -> EvalError {
+    let kind = match error {
+        InterpreterError::RuntimeError(message) => match message.as_str() {
+            TIMEOUT_MESSAGE => EvalErrorKind::Timeout,
+            STEP_LIMIT_MESSAGE => EvalErrorKind::StepLimit,
+            OUTPUT_LIMIT_MESSAGE => EvalErrorKind::OutputLimit,
+            SIZE_LIMIT_MESSAGE => EvalErrorKind::SizeLimit,
+            _ => EvalErrorKind::Runtime,
+        },
+        _ => EvalErrorKind::Runtime,
+    };
+    EvalError {
+        kind,
+        message: redact(&error.to_string()),
+    }
+}
+
+/// Hide the server's working, home and temporary directories in `message`
+fn redact(message: &str) -> String {
+    let mut paths: Vec<PathBuf> = vec![std::env::temp_dir()];
+    paths.extend(std::env::current_dir().ok());
+    paths.extend(std::env::var_os("HOME").map(PathBuf::from));
+    let paths: Vec<&std::path::Path> = paths.iter().map(PathBuf::as_path).collect();
+    restricted::redact_paths(message, &paths)
+}
+
+/// Requests counted in the current window of one client
+#[derive(Debug, Clone, Copy)]
+struct Window {
+    started: Instant,
+    count: u32,
+}
+
+/// Fixed-window request limit per client IP
+#[derive(Debug)]
+pub struct RateLimiter {
+    limit: u32,
+    window: Duration,
+    clients: Mutex<HashMap<IpAddr, Window>>,
+}
+
+impl RateLimiter {
+    /// Allow `limit` requests per client in every `window`
+    pub fn new(limit: u32, window: Duration) -> Self {
+        Self {
+            limit,
+            window,
+            clients: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Count a request from `client` at `now`
+    ///
+    /// # Errors
+    ///
+    /// Returns how long the client has to wait when it is over the limit.
+    pub fn check(&self, client: IpAddr, now: Instant) -> Result<(), Duration> {
+        let mut clients = self.clients.lock().unwrap_or_else(PoisonError::into_inner);
+        if clients.len() >= PRUNE_THRESHOLD {
+            clients.retain(|_, window| now.duration_since(window.started) < self.window);
+        }
+        let window = clients.entry(client).or_insert(Window {
+            started: now,
+            count: 0,
+        });
+        if now.duration_since(window.started) >= self.window {
+            *window = Window {
+                started: now,
+                count: 0,
+            };
+        }
+        if window.count >= self.limit {
+            return Err(self
+                .window
+                .saturating_sub(now.duration_since(window.started)));
+        }
+        window.count += 1;
+        Ok(())
+    }
+}
+
+/// Routes serving [`EVAL_ROUTE`]
+///
+/// The handler reads the client address from `ConnectInfo`, so serve the
+/// router with `into_make_service_with_connect_info::<SocketAddr>()`.
+#[cfg(feature = "notebook")]
+pub fn routes(config: EvalApiConfig) -> axum::Router {
+    use axum::routing::post;
+    use std::sync::Arc;
+
+    let state = Arc::new(EvalApiState {
+        limiter: RateLimiter::new(config.requests_per_minute, Duration::from_secs(60)),
+        config,
+    });
+    axum::Router::new()
+        .route(EVAL_ROUTE, post(eval_handler))
+        .with_state(state)
+}
+
+#[cfg(feature = "notebook")]
+#[derive(Debug)]
+struct EvalApiState {
+    config: EvalApiConfig,
+    limiter: RateLimiter,
+}
+
+#[cfg(feature = "notebook")]
+async fn eval_handler(
+    axum::extract::State(state): axum::extract::State<std::sync::Arc<EvalApiState>>,
+    axum::extract::ConnectInfo(client): axum::extract::ConnectInfo<std::net::SocketAddr>,
+    axum::Json(request): axum::Json<EvalRequest>,
+) -> axum::response::Response {
+    use axum::http::{header, StatusCode};
+    use axum::response::IntoResponse;
+
+    if let Err(retry_after) = state.limiter.check(client.ip(), Instant::now()) {
+        let response = EvalResponse::failure(
+            EvalErrorKind::RateLimited,
+            "Too many requests, try again later",
+        );
+        let seconds = retry_after.as_secs().max(1).to_string();
+        return (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(header::RETRY_AFTER, seconds)],
+            axum::Json(response),
+        )
+            .into_response();
+    }
+
+    // Evaluation blocks for up to the time budget, so keep it off the async workers
+    let config = state.config;
+    let response = tokio::task::spawn_blocking(move || evaluate(&request, &config))
+        .await
+        .unwrap_or_else(|e| {
+            EvalResponse::failure(EvalErrorKind::Runtime, format!("Evaluation failed: {e}"))
+        });
+    let status = match response.error.as_ref().map(|error| error.kind) {
+        Some(EvalErrorKind::SourceTooLarge) => StatusCode::PAYLOAD_TOO_LARGE,
+        _ => StatusCode::OK,
+    };
+    (status, axum::Json(response)).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    fn eval(source: &str) -> EvalResponse {
+        let request = EvalRequest {
+            source: source.to_string(),
+            ..EvalRequest::default()
+        };
+        evaluate(&request, &EvalApiConfig::default())
+    }
+
+    fn error_kind(response: &EvalResponse) -> Option<EvalErrorKind> {
+        response.error.as_ref().map(|error| error.kind)
+    }
+
+    #[test]
+    fn test_evaluate_returns_output_and_value() {
+        let response = eval("let x = 40\nprintln(\"x =\", x)\nx + 2");
+        assert!(response.success, "{response:?}");
+        assert_eq!(response.stdout, "x = 40\n");
+        assert_eq!(response.value.as_deref(), Some("42"));
+        assert_eq!(response.value_type.as_deref(), Some("integer"));
+    }
+
+    #[test]
+    fn test_requests_are_isolated() {
+        assert!(eval("let shared = 1\nshared").success);
+        assert_eq!(error_kind(&eval("shared")), Some(EvalErrorKind::Runtime));
+    }
+
+    #[test]
+    fn test_budgets_and_capabilities_are_enforced() {
+        assert_eq!(error_kind(&eval("1 +")), Some(EvalErrorKind::Parse));
+        assert_eq!(
+            error_kind(&eval("while true { }")),
+            Some(EvalErrorKind::StepLimit)
+        );
+        assert_eq!(
+            error_kind(&eval("println(\"x\".repeat(20000))")),
+            Some(EvalErrorKind::OutputLimit)
+        );
+        assert_eq!(
+            error_kind(&eval("\"a\".repeat(10000000000)")),
+            Some(EvalErrorKind::SizeLimit)
+        );
+        let denied = eval("fs_read(\"/etc/passwd\")");
+        let message = denied.error.expect("fs_read should be denied").message;
+        assert!(message.contains("restricted mode"), "{message}");
+        let forged = eval("{__type: \"Command\", program: \"id\", args: []}.output()");
+        let message = forged
+            .error
+            .expect("forged Command should be denied")
+            .message;
+        assert!(message.contains("restricted mode"), "{message}");
+        assert!(!restricted::is_active());
+    }
+
+    #[test]
+    fn test_recursion_limit_is_restored_after_each_request() {
+        eval_function::set_max_recursion_depth(321);
+        let config = EvalApiConfig {
+            max_depth: 5,
+            ..EvalApiConfig::default()
+        };
+        let request = EvalRequest {
+            source: "fun down(n) { if n == 0 { 0 } else { 1 + down(n - 1) } }\ndown(10)"
+                .to_string(),
+            ..EvalRequest::default()
+        };
+        assert!(!evaluate(&request, &config).success);
+        assert_eq!(eval_function::get_max_recursion_depth(), 321);
+
+        let unwound = std::panic::catch_unwind(|| {
+            let _budgets = ThreadBudgets::install(5, 1);
+            let _ = interrupt::check();
+            panic!("interpreter bug");
+        });
+        assert!(unwound.is_err());
+        assert_eq!(eval_function::get_max_recursion_depth(), 321);
+        assert_eq!(interrupt::steps_taken(), 0);
+        eval_function::set_max_recursion_depth(1000);
+    }
+
+    #[test]
+    fn test_big_powers_are_size_limited() {
+        assert_eq!(
+            error_kind(&eval("7 ** 300000000")),
+            Some(EvalErrorKind::SizeLimit)
+        );
+        assert!(eval("7 ** 300").success);
+    }
+
+    #[test]
+    fn test_slots_cap_concurrent_evaluations() {
+        let slots = Slots::new();
+        let first = slots
+            .acquire(2, Duration::ZERO)
+            .expect("a slot should be free");
+        let second = slots
+            .acquire(2, Duration::ZERO)
+            .expect("a slot should be free");
+        assert!(slots.acquire(2, Duration::from_millis(10)).is_none());
+        let waiter = std::thread::scope(|scope| {
+            let waiter = scope.spawn(|| slots.acquire(2, Duration::from_secs(5)).is_some());
+            drop(first);
+            waiter.join().expect("waiter should not panic")
+        });
+        assert!(waiter);
+        drop(second);
+        assert_eq!(*slots.running.lock().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_requests_can_only_lower_budgets() {
+        let config = EvalApiConfig {
+            max_steps: 10,
+            max_source: 8,
+            ..EvalApiConfig::default()
+        };
+        let request = EvalRequest {
+            source: "for i in 0..5 { i }".to_string(),
+            max_steps: Some(1_000),
+            ..EvalRequest::default()
+        };
+        assert_eq!(
+            error_kind(&evaluate(&request, &config)),
+            Some(EvalErrorKind::SourceTooLarge)
+        );
+        let config = EvalApiConfig {
+            max_source: 64,
+            ..config
+        };
+        assert!(evaluate(&request, &config).success);
+        let request = EvalRequest {
+            max_steps: Some(2),
+            ..request
+        };
+        let response = evaluate(&request, &config);
+        assert_eq!(error_kind(&response), Some(EvalErrorKind::StepLimit));
+        assert_eq!(response.steps, 2);
+    }
+
+    #[test]
+    fn test_rate_limiter_uses_fixed_windows_per_client() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(60));
+        let alice = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let bob = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+        let start = Instant::now();
+        assert!(limiter.check(alice, start).is_ok());
+        assert!(limiter.check(alice, start).is_ok());
+        let wait = limiter
+            .check(alice, start + Duration::from_secs(15))
+            .unwrap_err();
+        assert_eq!(wait, Duration::from_secs(45));
+        assert!(limiter.check(bob, start).is_ok());
+        assert!(limiter
+            .check(alice, start + Duration::from_secs(60))
+            .is_ok());
+    }
+
+    #[cfg(feature = "notebook")]
+    #[tokio::test]
+    async fn test_route_returns_json_and_rate_limits() {
+        use axum::body::Body;
+        use axum::extract::connect_info::MockConnectInfo;
+        use axum::http::{Request, StatusCode};
+        use tower::ServiceExt;
+
+        let config = EvalApiConfig {
+            requests_per_minute: 1,
+            ..EvalApiConfig::default()
+        };
+        let app = routes(config).layer(MockConnectInfo(std::net::SocketAddr::from((
+            [127, 0, 0, 1],
+            4000,
+        ))));
+        let request = || {
+            Request::post(EVAL_ROUTE)
+                .header("content-type", "application/json")
+                .body(Body::from(r#"{"source": "1 + 1"}"#))
+                .expect("request should build")
+        };
+
+        let response = app
+            .clone()
+            .oneshot(request())
+            .await
+            .expect("route should answer");
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body should read");
+        let body: EvalResponse = serde_json::from_slice(&body).expect("body should be JSON");
+        assert_eq!(body.value.as_deref(), Some("2"));
+
+        let response = app.oneshot(request()).await.expect("route should answer");
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(response.headers().contains_key("retry-after"));
+    }
+}
@@ -308,4 +308,5 @@ mod tests {
     }
 }
 
+pub mod eval_api;
 pub mod watcher;
@@ -3,9 +3,10 @@
 //! Thin wrappers around Rust's `regex` crate for pattern matching functionality.
 //!
 //! **Design**: Thin wrappers (complexity ≤2 per function) around `regex` crate.
+//! Compiled patterns are cached, so calling these in a loop compiles once.
 //! **Quality**: 100% unit test coverage, property tests, ≥75% mutation coverage.
 
-use regex::Regex;
+use crate::runtime::pattern_cache::cached_regex;
 
 /// Check if pattern matches text
 ///
@@ -22,7 +23,8 @@ use regex::Regex;
 ///
 /// Returns error if pattern is invalid
 pub fn is_match(pattern: &str, text: &str) -> Result<bool, String> {
-    let re = Regex::new(pattern).map_err(|e| format!("Invalid regex pattern '{pattern}': {e}"))?;
+    let re =
+        cached_regex(pattern).map_err(|e| format!("Invalid regex pattern '{pattern}': {e}"))?;
     Ok(re.is_match(text))
 }
 
@@ -41,7 +43,8 @@ pub fn is_match(pattern: &str, text: &str) -> Result<bool, String> {
 ///
 /// Returns error if pattern is invalid
 pub fn find_first(pattern: &str, text: &str) -> Result<Option<String>, String> {
-    let re = Regex::new(pattern).map_err(|e| format!("Invalid regex pattern '{pattern}': {e}"))?;
+    let re =
+        cached_regex(pattern).map_err(|e| format!("Invalid regex pattern '{pattern}': {e}"))?;
     Ok(re.find(text).map(|m| m.as_str().to_string()))
 }
 
@@ -60,7 +63,8 @@ pub fn find_first(pattern: &str, text: &str) -> Result<Option<String>, String> {
 ///
 /// Returns error if pattern is invalid
 pub fn find_all(pattern: &str, text: &str) -> Result<Vec<String>, String> {
-    let re = Regex::new(pattern).map_err(|e| format!("Invalid regex pattern '{pattern}': {e}"))?;
+    let re =
+        cached_regex(pattern).map_err(|e| format!("Invalid regex pattern '{pattern}': {e}"))?;
     Ok(re.find_iter(text).map(|m| m.as_str().to_string()).collect())
 }
 
@@ -79,7 +83,8 @@ pub fn find_all(pattern: &str, text: &str) -> Result<Vec<String>, String> {
 ///
 /// Returns error if pattern is invalid
 pub fn replace_first(pattern: &str, text: &str, replacement: &str) -> Result<String, String> {
-    let re = Regex::new(pattern).map_err(|e| format!("Invalid regex pattern '{pattern}': {e}"))?;
+    let re =
+        cached_regex(pattern).map_err(|e| format!("Invalid regex pattern '{pattern}': {e}"))?;
     Ok(re.replace(text, replacement).to_string())
 }
 
@@ -98,7 +103,8 @@ pub fn replace_first(pattern: &str, text: &str, replacement: &str) -> Result<Str
 ///
 /// Returns error if pattern is invalid
 pub fn replace_all(pattern: &str, text: &str, replacement: &str) -> Result<String, String> {
-    let re = Regex::new(pattern).map_err(|e| format!("Invalid regex pattern '{pattern}': {e}"))?;
+    let re =
+        cached_regex(pattern).map_err(|e| format!("Invalid regex pattern '{pattern}': {e}"))?;
     Ok(re.replace_all(text, replacement).to_string())
 }
 
@@ -117,7 +123,8 @@ pub fn replace_all(pattern: &str, text: &str, replacement: &str) -> Result<Strin
 ///
 /// Returns error if pattern is invalid
 pub fn split(pattern: &str, text: &str) -> Result<Vec<String>, String> {
-    let re = Regex::new(pattern).map_err(|e| format!("Invalid regex pattern '{pattern}': {e}"))?;
+    let re =
+        cached_regex(pattern).map_err(|e| format!("Invalid regex pattern '{pattern}': {e}"))?;
     Ok(re.split(text).map(ToString::to_string).collect())
 }
 
@@ -140,7 +147,8 @@ pub fn split(pattern: &str, text: &str) -> Result<Vec<String>, String> {
 ///
 /// Returns error if pattern is invalid
 pub fn capture_first(pattern: &str, text: &str) -> Result<Option<Vec<String>>, String> {
-    let re = Regex::new(pattern).map_err(|e| format!("Invalid regex pattern '{pattern}': {e}"))?;
+    let re =
+        cached_regex(pattern).map_err(|e| format!("Invalid regex pattern '{pattern}': {e}"))?;
     Ok(re.captures(text).map(|caps| {
         caps.iter()
             .map(|m| m.map(|m| m.as_str().to_string()).unwrap_or_default())
@@ -165,7 +173,8 @@ pub fn capture_first(pattern: &str, text: &str) -> Result<Option<Vec<String>>, S
 ///
 /// Returns error if pattern is invalid
 pub fn capture_all(pattern: &str, text: &str) -> Result<Vec<Vec<String>>, String> {
-    let re = Regex::new(pattern).map_err(|e| format!("Invalid regex pattern '{pattern}': {e}"))?;
+    let re =
+        cached_regex(pattern).map_err(|e| format!("Invalid regex pattern '{pattern}': {e}"))?;
     Ok(re
         .captures_iter(text)
         .map(|caps| {
@@ -190,7 +199,7 @@ pub fn capture_all(pattern: &str, text: &str) -> Result<Vec<Vec<String>>, String
 /// assert!(!result.expect("is_valid_pattern should succeed in doctest"));
 /// ```
 pub fn is_valid_pattern(pattern: &str) -> Result<bool, String> {
-    Ok(Regex::new(pattern).is_ok())
+    Ok(cached_regex(pattern).is_ok())
 }
 
 /// Escape special regex characters in text
@@ -211,6 +211,8 @@ impl AstBuilder {
             guard: guard.map(Box::new),
             body: Box::new(body),
             span: self.span,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }
     }
     /// Create a wildcard pattern
@@ -224,6 +224,8 @@ fn test_lambda_expression() {
             span: Span::new(0, 1),
             is_mutable: false,
             default_value: None,
+            leading_comments: Vec::new(),
+            trailing_comment: None,
         }],
         builder.ident("x"),
     );
@@ -499,6 +499,8 @@ mod tests {
             // Just verify it doesn't panic
             match literal {
                 Literal::Integer(_, _)
+                | Literal::BigInt(_)
+                | Literal::Decimal(_)
                 | Literal::Float(_)
                 | Literal::Bool(_)
                 | Literal::String(_)
@@ -597,6 +599,8 @@ mod tests {
                 | Pattern::RestNamed(_)
                 | Pattern::AtBinding { .. }
                 | Pattern::WithDefault { .. }
+                | Pattern::StringAffix { .. }
+                | Pattern::Regex(_)
                 | Pattern::Mut(_)
                 | Pattern::Ok(_)
                 | Pattern::Err(_)
@@ -721,6 +725,12 @@ mod tests {
                 Literal::Atom(_) => {
                     found_variants.insert("Atom");
                 }
+                Literal::BigInt(_) => {
+                    found_variants.insert("BigInt");
+                }
+                Literal::Decimal(_) => {
+                    found_variants.insert("Decimal");
+                }
             }
         }
         // Should find at least some variants
@@ -996,7 +1006,9 @@ mod tests {
                 Literal::Byte(_) => {} // Byte literal not in test set
                 Literal::Unit => {}
                 Literal::Null => {}
-                Literal::Atom(_) => {} // Atom literal not in test set
+                Literal::Atom(_) => {}    // Atom literal not in test set
+                Literal::BigInt(_) => {}  // BigInt literal not in test set
+                Literal::Decimal(_) => {} // Decimal literal not in test set
             }
         }
     }
@@ -313,6 +313,11 @@ impl AstNormalizer {
     fn convert_literal(lit: &Literal) -> CoreExpr {
         CoreExpr::Literal(match lit {
             Literal::Integer(i, _) => CoreLiteral::Integer(*i),
+            Literal::BigInt(digits) => digits
+                .parse()
+                .map_or(CoreLiteral::Unit, CoreLiteral::Integer), // TODO: Support BigInts wider than i64
+            // TODO: Keep decimals exact
+            Literal::Decimal(text) => text.parse().map_or(CoreLiteral::Unit, CoreLiteral::Float),
             Literal::Float(f) => CoreLiteral::Float(*f),
             Literal::String(s) => CoreLiteral::String(s.clone()),
             Literal::Bool(b) => CoreLiteral::Bool(*b),
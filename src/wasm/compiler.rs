@@ -832,6 +832,8 @@ mod tests {
                         span: Span::default(),
                         is_mutable: false,
                         default_value: None,
+                        leading_comments: Vec::new(),
+                        trailing_comment: None,
                     },
                     Param {
                         pattern: Pattern::Identifier("b".to_string()),
@@ -842,6 +844,8 @@ mod tests {
                         span: Span::default(),
                         is_mutable: false,
                         default_value: None,
+                        leading_comments: Vec::new(),
+                        trailing_comment: None,
                     },
                 ],
                 body: Box::new(make_binary(make_int(1), BinaryOp::Add, make_int(2))),
@@ -4,6 +4,7 @@
 //! Network-dependent features are excluded to minimize binary size.
 #![cfg(target_arch = "wasm32")]
 use crate::backend::transpiler::Transpiler;
+use crate::backend::wasm::WasmEmitter;
 use crate::frontend::parser::Parser;
 use js_sys::Promise;
 #[cfg(test)]
@@ -68,6 +69,35 @@ impl RuchyWasm {
     pub fn validate(&self, source: &str) -> bool {
         Parser::new(source).parse().is_ok()
     }
+    /// Compile Ruchy code to a WebAssembly module
+    ///
+    /// With `fuel`, every call and loop iteration spends one unit from the
+    /// exported `ruchy_fuel` global and the module traps when it runs out,
+    /// so playground code cannot hang the page. With `whole_program`,
+    /// constants are propagated across functions and unreachable functions
+    /// dropped first, which shrinks the module.
+    #[wasm_bindgen]
+    pub fn compile_wasm(
+        &self,
+        source: &str,
+        fuel: Option<u64>,
+        whole_program: Option<bool>,
+    ) -> Result<Vec<u8>, JsValue> {
+        let ast = Parser::new(source)
+            .parse()
+            .map_err(|e| JsValue::from(js_sys::Error::new(&format!("Parse error: {}", e))))?;
+        WasmEmitter::new()
+            .with_fuel(fuel)
+            .with_whole_program(whole_program.unwrap_or(false))
+            .emit(&ast)
+            .map_err(|e| JsValue::from(js_sys::Error::new(&format!("WASM error: {}", e))))
+    }
+    /// JavaScript module providing the host functions that modules from
+    /// `compile_wasm` import (`println`, clock and random)
+    #[wasm_bindgen(getter)]
+    pub fn host_js(&self) -> String {
+        crate::backend::wasm::host::HOST_JS.to_string()
+    }
     /// Get version
     #[wasm_bindgen(getter)]
     /// # Examples
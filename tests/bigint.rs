@@ -0,0 +1,63 @@
+#![allow(missing_docs)]
+//! Arbitrary-precision integers: `i64` overflow promotes to `Value::BigInt`,
+//! `123n` literals produce one directly, and the transpiler emits
+//! `num_bigint::BigInt`.
+
+use ruchy::runtime::interpreter::{Interpreter, Value};
+use ruchy::{compile, Parser};
+
+fn eval(source: &str) -> Value {
+    let ast = Parser::new(source).parse().expect("source should parse");
+    Interpreter::new()
+        .eval_expr(&ast)
+        .unwrap_or_else(|e| panic!("{source:?} should evaluate: {e:?}"))
+}
+
+#[test]
+fn test_addition_overflow_promotes_to_bigint() {
+    let result = eval("9223372036854775807 + 1");
+    assert!(matches!(result, Value::BigInt(_)), "{result:?}");
+    assert_eq!(result.to_string(), "9223372036854775808");
+}
+
+#[test]
+fn test_factorial_past_i64() {
+    let source = "fun fact(n) { if n <= 1 { 1 } else { n * fact(n - 1) } }
+fact(25)";
+    assert_eq!(eval(source).to_string(), "15511210043330985984000000");
+}
+
+#[test]
+fn test_bigint_literals_and_comparisons() {
+    assert_eq!(eval("123n").to_string(), "123");
+    assert_eq!(eval("123n == 123"), Value::Bool(true));
+    assert_eq!(eval("2n ** 64 > 9223372036854775807"), Value::Bool(true));
+    assert_eq!(
+        eval("-(2n ** 70) / 3").to_string(),
+        "-393530540239137101141"
+    );
+}
+
+#[test]
+fn test_oversized_decimal_literal_is_bigint() {
+    let result = eval("100000000000000000000 - 1");
+    assert_eq!(result.to_string(), "99999999999999999999");
+}
+
+#[test]
+fn test_bigint_compound_assignment_in_loop() {
+    let source = "let mut x = 1
+for i in 0..100 { x *= 2 }
+x";
+    assert_eq!(eval(source).to_string(), "1267650600228229401496703205376");
+}
+
+#[test]
+fn test_bigint_literal_transpiles_to_num_bigint() {
+    let rust = compile("fun main() { let x = 123n; println!(\"{}\", x) }").unwrap();
+    assert!(rust.contains("num_bigint :: BigInt"), "{rust}");
+    assert!(rust.contains("\"123\" . parse"), "{rust}");
+
+    let typed = compile("fun double(x: bigint) -> bigint { x * 2 }").unwrap();
+    assert!(typed.contains("x : num_bigint :: BigInt"), "{typed}");
+}
@@ -0,0 +1,90 @@
+#![allow(missing_docs)]
+//! `capture_output { ... }` returns what its block printed instead of
+//! printing it, and `ruchy test` shows a test's output only when it fails.
+
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+fn ruchy_cmd() -> assert_cmd::Command {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+}
+
+#[test]
+fn test_capture_output_returns_printed_text() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("capture.ruchy");
+    fs::write(
+        &file,
+        r#"
+fun greet(name) {
+    print("hello ")
+    println(name)
+}
+
+let outer = capture_output {
+    println("outer")
+    let inner = capture_output { greet("inner") }
+    println(inner.len())
+}
+println("visible")
+print(outer)
+"#,
+    )
+    .unwrap();
+    ruchy_cmd()
+        .arg("run")
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout("visible\nouter\n12\n");
+}
+
+#[test]
+fn test_failing_block_keeps_its_output() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("capture.ruchy");
+    fs::write(
+        &file,
+        "let out = capture_output {\n    println(\"before failure\")\n    missing_value + 1\n}\n",
+    )
+    .unwrap();
+    ruchy_cmd()
+        .arg("run")
+        .arg(&file)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("before failure"));
+}
+
+#[test]
+fn test_runner_shows_output_of_failing_tests_only() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("test_output.ruchy");
+    fs::write(
+        &file,
+        r#"
+@test("passes quietly")
+fun test_passes() {
+    println("passing noise")
+    assert_eq(1 + 1, 2)
+}
+
+@test("fails loudly")
+fun test_fails() {
+    println("checking the answer")
+    assert_eq(1 + 1, 3)
+}
+"#,
+    )
+    .unwrap();
+    ruchy_cmd()
+        .arg("test")
+        .arg(&file)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("passing noise").not())
+        .stdout(predicate::str::contains(
+            "---- test_fails stdout ----\nchecking the answer",
+        ));
+}
@@ -330,3 +330,23 @@ fn cli_parse_multiline_program() {
         .success()
         .stdout(predicate::str::contains("Block").or(predicate::str::contains("Expr")));
 }
+
+#[test]
+fn cli_ast_effects_classifies_functions() {
+    let temp = TempDir::new().unwrap();
+    let file = create_temp_file(
+        &temp,
+        "effects.ruchy",
+        "fun square(x) { x * x }\nfun show(x) { println(square(x)) }\n",
+    );
+
+    ruchy_cmd()
+        .arg("ast")
+        .arg("--effects")
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("square (line 1): pure"))
+        .stdout(predicate::str::contains("show (line 2): io"))
+        .stdout(predicate::str::contains("  io: calls println (line 2)"));
+}
@@ -76,3 +76,75 @@ fn test_bench_format_json() {
         .assert()
         .code(predicate::ne(2));
 }
+
+#[test]
+fn test_bench_functions_in_markdown() {
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("funcs.ruchy");
+    fs::write(
+        &test_file,
+        "fun bench_add() { 1 + 2 }\nfun bench_concat() { \"a\" + \"b\" }\n",
+    )
+    .unwrap();
+
+    ruchy_cmd()
+        .args([
+            "bench",
+            "--iterations",
+            "5",
+            "--warmup",
+            "1",
+            "--format",
+            "markdown",
+        ])
+        .arg(&test_file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "| Benchmark | Mean (ms) | Median (ms) | P95 (ms)",
+        ))
+        .stdout(predicate::str::contains("| `bench_add` |"))
+        .stdout(predicate::str::contains("| `bench_concat` |"));
+}
+
+#[test]
+fn test_bench_fails_on_regression_against_baseline() {
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("loop.ruchy");
+    fs::write(
+        &test_file,
+        "fun bench_loop() { let mut s = 0\nfor i in 0..100 { s = s + i }\ns }\n",
+    )
+    .unwrap();
+    let baseline = temp_dir.path().join("baseline.json");
+
+    ruchy_cmd()
+        .args([
+            "bench",
+            "--iterations",
+            "3",
+            "--save-baseline",
+            "--baseline",
+        ])
+        .arg(&baseline)
+        .arg(&test_file)
+        .assert()
+        .success();
+    assert!(fs::read_to_string(&baseline)
+        .unwrap()
+        .contains("\"bench_loop\""));
+
+    // A baseline far faster than any real run
+    let fast = fs::read_to_string(&baseline)
+        .unwrap()
+        .replace("\"median_ms\": ", "\"median_ms\": 0.0000001, \"_was\": ");
+    fs::write(&baseline, fast).unwrap();
+    ruchy_cmd()
+        .args(["bench", "--iterations", "3", "--baseline"])
+        .arg(&baseline)
+        .arg(&test_file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("REGRESSION"))
+        .stderr(predicate::str::contains("regressed by more than 10%"));
+}
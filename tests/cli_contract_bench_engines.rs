@@ -0,0 +1,64 @@
+#![allow(missing_docs)]
+//! `ruchy bench --engines` runs the built-in corpus on every execution engine,
+//! prints a comparison table and tracks runs in a history file.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+fn ruchy_cmd() -> Command {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+}
+
+fn bench_engines(history: &std::path::Path) -> String {
+    let output = ruchy_cmd()
+        .args(["bench", "--engines", "--iterations", "1", "--warmup", "0"])
+        .arg("--history")
+        .arg(history)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_bench_engines_table_and_history() {
+    let temp_dir = TempDir::new().unwrap();
+    let history = temp_dir.path().join("bench.jsonl");
+
+    let first = bench_engines(&history);
+    for column in ["benchmark", "ast", "bytecode", "compiled", "wasm"] {
+        assert!(first.contains(column), "{first}");
+    }
+    for row in ["fib", "sort", "strings", "dataframe"] {
+        assert!(first.contains(row), "{first}");
+    }
+
+    let second = bench_engines(&history);
+    assert!(second.contains('%'), "{second}");
+    let runs = fs::read_to_string(&history).unwrap();
+    assert_eq!(runs.lines().count(), 2);
+}
+
+#[test]
+fn test_bench_requires_file_without_engines() {
+    ruchy_cmd()
+        .arg("bench")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("FILE"));
+}
+
+#[test]
+fn test_bench_history_requires_engines() {
+    ruchy_cmd()
+        .args(["bench", "x.ruchy", "--history", "h.jsonl"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--engines"));
+}
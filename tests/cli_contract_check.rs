@@ -150,6 +150,46 @@ fn cli_check_multiple_files_checks_all() {
         .success();
 }
 
+#[test]
+fn cli_check_warns_about_unknown_method_with_suggestion() {
+    let temp = TempDir::new().unwrap();
+    let file = create_temp_file(&temp, "typo.ruchy", "let n = 1\n[1, 2].psuh(3)\n");
+
+    ruchy_cmd()
+        .arg("check")
+        .arg(&file)
+        .assert()
+        .success() // Warnings do not fail the check
+        .stderr(predicate::str::contains(
+            "typo.ruchy:2:8: warning[unknown_method]",
+        ))
+        .stderr(predicate::str::contains(
+            "Method 'psuh' not found for type array; did you mean 'push'?",
+        ));
+}
+
+#[test]
+fn cli_check_warns_when_pure_function_performs_io() {
+    let temp = TempDir::new().unwrap();
+    let file = create_temp_file(
+        &temp,
+        "pure.ruchy",
+        "@pure\nfun shout(s) {\n    println(s)\n}\n",
+    );
+
+    ruchy_cmd()
+        .arg("check")
+        .arg(&file)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "pure.ruchy:3:5: warning[impure_pure_function]",
+        ))
+        .stderr(predicate::str::contains(
+            "`shout` is marked @pure but calls println",
+        ));
+}
+
 // ============================================================================
 // CLI CONTRACT TESTS: EDGE CASES
 // ============================================================================
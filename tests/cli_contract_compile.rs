@@ -333,3 +333,27 @@ println(fibonacci(10))
         "Complex program should compile successfully"
     );
 }
+
+#[test]
+fn cli_compile_hermetic_rejects_a_rustc_other_than_the_pinned_one() {
+    let temp = TempDir::new().unwrap();
+    let file = create_temp_file(&temp, "main.ruchy", "println(\"hi\")\n");
+    fs::write(
+        temp.path().join("Ruchy.toml"),
+        "[build]\nrustc = \"0.1.0\"\n",
+    )
+    .unwrap();
+
+    ruchy_cmd()
+        .arg("compile")
+        .arg(&file)
+        .arg("--hermetic")
+        .arg("--output")
+        .arg(temp.path().join("main"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "does not match the version pinned in Ruchy.toml: 0.1.0",
+        ));
+    assert!(!temp.path().join("main.build-info.json").exists());
+}
@@ -149,10 +149,16 @@ fn test_fmt_macro_definition() {
     )
     .expect("Failed to write test file");
 
-    ruchy_cmd().arg("fmt").arg(&test_file).assert().success();
-
-    let formatted = fs::read_to_string(&test_file).expect("Failed to read formatted file");
-    assert!(formatted.contains("macro"), "Should preserve macro keyword");
+    // `$` starts no token, so the file is rejected rather than rewritten
+    ruchy_cmd()
+        .arg("fmt")
+        .arg(&test_file)
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("Invalid character '$'"));
+
+    let unchanged = fs::read_to_string(&test_file).expect("Failed to read file");
+    assert!(unchanged.contains("$expr"), "Should leave the file as written");
 }
 
 // ==================== MacroInvocation ====================
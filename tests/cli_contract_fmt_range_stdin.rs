@@ -0,0 +1,99 @@
+#![allow(missing_docs)]
+//! `ruchy fmt` editor integration: `-` pipes stdin to stdout, and `--range` /
+//! `--byte-range` reformat only the selected top-level items, byte for byte
+//! stable across repeated runs.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+fn ruchy_cmd() -> Command {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+}
+
+const SOURCE: &str = "let a=1+2\n// keep  this\nlet b=3*4\nlet c=5-6\n";
+
+fn fmt_stdin(input: &str, args: &[&str]) -> String {
+    let output = ruchy_cmd()
+        .arg("fmt")
+        .arg("-")
+        .args(args)
+        .write_stdin(input)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_fmt_stdin_writes_formatted_code_to_stdout() {
+    let formatted = fmt_stdin("let x=1+2", &[]);
+    assert!(formatted.contains("let x = 1 + 2"), "{formatted}");
+    assert_eq!(fmt_stdin(&formatted, &[]), formatted);
+}
+
+#[test]
+fn test_fmt_stdin_check_reports_unformatted_input() {
+    ruchy_cmd()
+        .arg("fmt")
+        .arg("-")
+        .arg("--check")
+        .write_stdin("let x=1+2")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("<stdin> needs formatting"));
+}
+
+#[test]
+fn test_fmt_line_range_leaves_other_lines_untouched() {
+    let formatted = fmt_stdin(SOURCE, &["--range", "3:3"]);
+    assert!(
+        formatted.starts_with("let a=1+2\n// keep  this\n"),
+        "{formatted}"
+    );
+    assert!(formatted.contains("let b = 3 * 4"), "{formatted}");
+    assert!(formatted.ends_with("let c=5-6\n"), "{formatted}");
+    assert_eq!(fmt_stdin(&formatted, &["--range", "3:3"]), formatted);
+}
+
+#[test]
+fn test_fmt_byte_range_selects_overlapping_items() {
+    let formatted = fmt_stdin(SOURCE, &["--byte-range", "0:3"]);
+    assert!(formatted.contains("let a = 1 + 2"), "{formatted}");
+    assert!(formatted.contains("let b=3*4"), "{formatted}");
+}
+
+#[test]
+fn test_fmt_range_writes_file_in_place() {
+    let temp_dir = TempDir::new().unwrap();
+    let file = temp_dir.path().join("range.ruchy");
+    fs::write(&file, SOURCE).unwrap();
+    ruchy_cmd()
+        .arg("fmt")
+        .arg(&file)
+        .arg("--range")
+        .arg("4:4")
+        .assert()
+        .success();
+    let written = fs::read_to_string(&file).unwrap();
+    assert!(written.starts_with("let a=1+2\n// keep  this\nlet b=3*4\n"));
+    assert!(written.contains("let c = 5 - 6"), "{written}");
+}
+
+#[test]
+fn test_fmt_rejects_invalid_range() {
+    ruchy_cmd()
+        .arg("fmt")
+        .arg("-")
+        .arg("--range")
+        .arg("9")
+        .write_stdin(SOURCE)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("START:END"));
+}
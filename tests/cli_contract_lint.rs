@@ -148,3 +148,152 @@ fn cli_lint_comment_only_fails() {
 
     ruchy_cmd().arg("lint").arg(&file).assert().failure(); // Comment-only is error
 }
+
+// ============================================================================
+// CLI CONTRACT TESTS: AUTO-FIX
+// ============================================================================
+
+#[test]
+fn cli_lint_fix_renames_unused_variables_and_drops_unused_imports() {
+    let temp = TempDir::new().unwrap();
+    let file = create_temp_file(
+        &temp,
+        "fixable.ruchy",
+        "import std.collections.HashMap\nlet spare = 1\nlet x = 42\nprintln(x)\n",
+    );
+
+    ruchy_cmd()
+        .arg("lint")
+        .arg("--fix")
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("unused import: HashMap"))
+        .stdout(predicate::str::contains("Fixed 2 of 2 issues"));
+
+    let fixed = fs::read_to_string(&file).unwrap();
+    assert_eq!(fixed, "let _spare = 1\nlet x = 42\nprintln(x)\n");
+    ruchy_cmd()
+        .arg("lint")
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No issues found"));
+}
+
+#[test]
+fn cli_lint_fix_keeps_a_backup_and_reports_fixes_by_rule() {
+    let temp = TempDir::new().unwrap();
+    let original = "import std.collections.HashMap\nlet spare = 1\n";
+    let file = create_temp_file(&temp, "backup.ruchy", original);
+
+    ruchy_cmd()
+        .arg("lint")
+        .arg("--fix")
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("unused_import (1):"))
+        .stdout(predicate::str::contains("unused_variable (1):"))
+        .stdout(predicate::str::contains("backup.ruchy.bak"));
+
+    assert_eq!(fs::read_to_string(&file).unwrap(), "let _spare = 1\n");
+    let backup = temp.path().join("backup.ruchy.bak");
+    assert_eq!(fs::read_to_string(backup).unwrap(), original);
+    assert!(!temp.path().join("backup.ruchy.fix.tmp").exists());
+}
+
+#[test]
+fn cli_lint_init_config_writes_a_config_that_lint_follows() {
+    let temp = TempDir::new().unwrap();
+    ruchy_cmd()
+        .current_dir(temp.path())
+        .arg("lint")
+        .arg("--init-config")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Created .ruchylint.toml"));
+    let config = temp.path().join(".ruchylint.toml");
+    assert!(fs::read_to_string(&config)
+        .unwrap()
+        .contains("unused_variable = \"warn\""));
+    // An existing config is left alone
+    ruchy_cmd()
+        .current_dir(temp.path())
+        .arg("lint")
+        .arg("--init-config")
+        .assert()
+        .failure();
+
+    fs::write(
+        &config,
+        "[rules]\nunused_variable = \"deny\"\n[per_path_ignores]\n\"tests/**\" = [\"unused_variable\"]\n",
+    )
+    .unwrap();
+    let file = create_temp_file(&temp, "main.ruchy", "let spare = 1\n");
+    ruchy_cmd()
+        .arg("lint")
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Summary: 1 Error, 0 Warnings"));
+    fs::create_dir(temp.path().join("tests")).unwrap();
+    let test_file = create_temp_file(&temp, "tests/main_test.ruchy", "let spare = 1\n");
+    ruchy_cmd()
+        .arg("lint")
+        .arg(&test_file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No issues found"));
+}
+
+#[test]
+fn cli_lint_reports_dead_code_as_warnings() {
+    let temp = TempDir::new().unwrap();
+    let file = create_temp_file(
+        &temp,
+        "dead.ruchy",
+        "fun main() {\n    println(\"start\")\n    return 0\n    println(\"never\")\n}\n\nfun orphan() {\n    42\n}\n",
+    );
+    ruchy_cmd()
+        .arg("lint")
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("dead.ruchy:4:"))
+        .stdout(predicate::str::contains("unreachable code after `return`"))
+        .stdout(predicate::str::contains("dead.ruchy:7:"))
+        .stdout(predicate::str::contains("function `orphan` is never used"))
+        .stdout(predicate::str::contains("Summary: 0 Errors, 2 Warnings"));
+}
+
+#[test]
+fn cli_lint_security_rules_report_injection_traversal_and_secrets() {
+    let temp = TempDir::new().unwrap();
+    let file = create_temp_file(
+        &temp,
+        "deploy.ruchy",
+        "let target = env_args()[1]\nlet dir = path_join(\"/srv/releases\", target)\nlet out = Command::new(\"sh\").arg(\"-c\").arg(\"ls \" + dir).output()\nlet deploy_token = \"d41d8cd98f00b204\"\nprintln(out)\n",
+    );
+    ruchy_cmd()
+        .arg("lint")
+        .arg(&file)
+        .arg("--rules=security")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("deploy.ruchy:2:"))
+        .stdout(predicate::str::contains("path joined from user input"))
+        .stdout(predicate::str::contains("deploy.ruchy:3:"))
+        .stdout(predicate::str::contains(
+            "runs a command string built at runtime",
+        ))
+        .stdout(predicate::str::contains(
+            "`deploy_token` is set to a hardcoded secret",
+        ))
+        .stdout(predicate::str::contains("Summary: 0 Errors, 3 Warnings"));
+    ruchy_cmd()
+        .arg("lint")
+        .arg(&file)
+        .assert()
+        .stdout(predicate::str::contains("hardcoded secret").not());
+}
@@ -11,6 +11,7 @@
 //! - stdout: Notebook output (validation mode) or server info (interactive mode)
 //! - stderr: Error messages (validation errors, server errors)
 //! - Options: --port, --open, --host
+//! - `export FILE --to script|html|markdown [--output PATH]`: convert a notebook
 //! - Non-interactive mode: FILE argument for validation (TOOL-VALIDATION-003)
 //!
 //! **Reference**: docs/specifications/15-tool-improvement-spec.md (v4.0)
@@ -418,3 +419,73 @@ println(upper)
 
     ruchy_cmd().arg("notebook").arg(&file).assert().success();
 }
+
+// ============================================================================
+// CLI CONTRACT TESTS: EXPORT
+// ============================================================================
+
+const EXPORT_NOTEBOOK: &str = r##"{
+  "cells": [
+    {"cell_type": "markdown", "source": "# Totals\n\nSum of a few numbers."},
+    {"cell_type": "code", "source": "let total = 1 + 2 + 3\nprintln(total)", "output": "6", "execution_count": 1}
+  ],
+  "metadata": {"language": "ruchy", "version": "1.0.0", "kernel": "ruchy"}
+}"##;
+
+#[test]
+fn cli_notebook_export_script_is_runnable() {
+    let temp = TempDir::new().unwrap();
+    let notebook = create_temp_file(&temp, "totals.rnb", EXPORT_NOTEBOOK);
+
+    ruchy_cmd()
+        .args(["notebook", "export"])
+        .arg(&notebook)
+        .args(["--to", "script"])
+        .assert()
+        .success();
+
+    let script = temp.path().join("totals.ruchy");
+    let source = fs::read_to_string(&script).unwrap();
+    assert!(
+        source.starts_with("// %% [markdown]\n// # Totals\n"),
+        "{source}"
+    );
+    ruchy_cmd()
+        .arg(&script)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("6"));
+}
+
+#[test]
+fn cli_notebook_export_html_report_includes_outputs() {
+    let temp = TempDir::new().unwrap();
+    let notebook = create_temp_file(&temp, "totals.rnb", EXPORT_NOTEBOOK);
+    let report = temp.path().join("report.html");
+
+    ruchy_cmd()
+        .args(["notebook", "export"])
+        .arg(&notebook)
+        .args(["--to", "html", "--output"])
+        .arg(&report)
+        .assert()
+        .success();
+
+    let html = fs::read_to_string(&report).unwrap();
+    assert!(html.contains("<title>Totals</title>"), "{html}");
+    assert!(html.contains("<pre class=\"output\">6</pre>"), "{html}");
+}
+
+#[test]
+fn cli_notebook_export_rejects_unknown_format() {
+    let temp = TempDir::new().unwrap();
+    let notebook = create_temp_file(&temp, "totals.rnb", EXPORT_NOTEBOOK);
+
+    ruchy_cmd()
+        .args(["notebook", "export"])
+        .arg(&notebook)
+        .args(["--to", "pdf"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown export format"));
+}
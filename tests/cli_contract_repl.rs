@@ -27,3 +27,73 @@ fn test_repl_starts() {
         .assert()
         .code(predicate::ne(2));
 }
+
+#[test]
+fn test_repl_restricted_disables_filesystem_builtins() {
+    ruchy_cmd()
+        .arg("repl")
+        .arg("--restricted")
+        .write_stdin("1 + 2\nfs_read(\"/etc/hosts\")\n:quit\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Restricted mode"))
+        .stdout(predicate::str::contains("3"))
+        .stdout(predicate::str::contains(
+            "fs_read() is disabled in restricted mode",
+        ));
+}
+
+#[test]
+fn test_repl_restricted_times_out_runaway_input() {
+    ruchy_cmd()
+        .arg("repl")
+        .arg("--restricted")
+        .write_stdin("loop { }\n40 + 2\n:quit\n")
+        .timeout(std::time::Duration::from_secs(30))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Execution timed out"))
+        .stdout(predicate::str::contains("42"));
+}
+
+#[test]
+fn test_repl_restricted_conflicts_with_max_depth() {
+    ruchy_cmd()
+        .args(["repl", "--restricted", "--max-depth", "500"])
+        .assert()
+        .code(2);
+}
+
+fn session_file(markdown: &str) -> tempfile::NamedTempFile {
+    let mut file = tempfile::Builder::new().suffix(".md").tempfile().unwrap();
+    std::io::Write::write_all(&mut file, markdown.as_bytes()).unwrap();
+    file
+}
+
+#[test]
+fn test_repl_check_session_passes_matching_transcripts() {
+    let file = session_file(
+        "# Lists\n\n```ruchy\nruchy> let xs = [1, 2, 3]\n[1, 2, 3]\n\nruchy> xs.len()\n3\n```\n",
+    );
+    ruchy_cmd()
+        .arg("repl")
+        .arg("--check-session")
+        .arg(file.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2 passed, 0 failed"));
+}
+
+#[test]
+fn test_repl_check_session_fails_on_mismatch() {
+    let file = session_file("```ruchy\nruchy> let x = 2\n2\nruchy> x * 21\n41\n```\n");
+    ruchy_cmd()
+        .arg("repl")
+        .arg("--check-session")
+        .arg(file.path())
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(":4:"))
+        .stdout(predicate::str::contains("ruchy> x * 21"))
+        .stdout(predicate::str::contains("1 passed, 1 failed"));
+}
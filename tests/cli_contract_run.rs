@@ -162,6 +162,21 @@ fn cli_run_runtime_error_writes_stderr() {
         .stderr(predicate::str::contains("Division by zero"));
 }
 
+#[test]
+fn cli_run_unknown_method_writes_type_suggestion_and_position() {
+    let temp = TempDir::new().unwrap();
+    let file = create_temp_file(&temp, "typo.ruchy", "let xs = [1, 2]\nxs.lenght()\n");
+
+    ruchy_cmd()
+        .arg("run")
+        .arg(&file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Method 'lenght' not found for type array; did you mean 'length'? at line 2, column 4",
+        ));
+}
+
 #[test]
 fn cli_run_missing_file_writes_stderr() {
     ruchy_cmd()
@@ -288,3 +303,59 @@ fn cli_run_no_output_program_succeeds() {
         .success()
         .stdout(predicate::str::is_empty()); // No output is valid
 }
+
+#[test]
+fn cli_run_rejects_out_of_range_otel_sample_rate() {
+    let temp = TempDir::new().unwrap();
+    let file = create_temp_file(&temp, "service.ruchy", "println(\"up\")\n");
+
+    ruchy_cmd()
+        .arg("run")
+        .arg("--otel-sample-rate")
+        .arg("1.5")
+        .arg(&file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("between 0.0 and 1.0"))
+        .stdout(predicate::str::is_empty());
+}
+
+#[test]
+fn cli_run_optimized_prints_the_same_output() {
+    let temp = TempDir::new().unwrap();
+    let file = create_temp_file(
+        &temp,
+        "folded.ruchy",
+        "let day = 60 * 60 * 24\nif 1 > 2 {\n    println(\"unreachable\")\n} else {\n    println(day + abs(-6))\n}\n",
+    );
+
+    for args in [vec!["run"], vec!["run", "-O"]] {
+        ruchy_cmd()
+            .args(args)
+            .arg(&file)
+            .assert()
+            .success()
+            .stdout("86406\n");
+    }
+}
+
+#[test]
+fn cli_run_optimized_verbose_reports_code_motion() {
+    let temp = TempDir::new().unwrap();
+    let file = create_temp_file(
+        &temp,
+        "hoisted.ruchy",
+        "let w = 4\nlet h = 3\nlet mut total = 0\nfor i in 0..4 {\n    total = total + i * (w * h)\n}\nprintln(total)\n",
+    );
+
+    ruchy_cmd()
+        .args(["--verbose", "run", "-O"])
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("72"))
+        .stderr(predicate::str::contains(
+            "1 loop-invariant expression(s) hoisted",
+        ))
+        .stderr(predicate::str::contains("operations:"));
+}
@@ -122,6 +122,99 @@ for i in range(10) {
         .success();
 }
 
+const RECURSIVE_PROGRAM: &str = r"
+fun fib(n) {
+    if n < 2 { n } else { fib(n - 1) + fib(n - 2) }
+}
+fun main() {
+    println(fib(10))
+}
+";
+
+#[test]
+fn cli_runtime_profile_reports_function_timings() {
+    let temp = TempDir::new().unwrap();
+    let file = create_temp_file(&temp, "fib.ruchy", RECURSIVE_PROGRAM);
+
+    ruchy_cmd()
+        .arg("runtime")
+        .arg(&file)
+        .arg("--profile")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("55\n"))
+        .stdout(predicate::str::is_match(r"(?m)^fib\s+177\s").unwrap())
+        .stdout(predicate::str::is_match(r"(?m)^main\s+1\s").unwrap());
+}
+
+#[test]
+fn cli_runtime_profile_json_output() {
+    let temp = TempDir::new().unwrap();
+    let file = create_temp_file(&temp, "fib.ruchy", RECURSIVE_PROGRAM);
+    let report = temp.path().join("profile.json");
+
+    ruchy_cmd()
+        .arg("runtime")
+        .arg(&file)
+        .arg("--profile")
+        .arg("--format")
+        .arg("json")
+        .arg("--output")
+        .arg(&report)
+        .assert()
+        .success();
+
+    let json: serde_json::Value = serde_json::from_str(&fs::read_to_string(&report).unwrap())
+        .expect("profile should be JSON");
+    let functions = json["functions"].as_array().unwrap();
+    let fib = functions.iter().find(|f| f["name"] == "fib").unwrap();
+    assert_eq!(fib["calls"], 177);
+    assert!(fib["inclusive_ns"].as_u64() >= fib["exclusive_ns"].as_u64());
+    assert!(json["total_allocations"].as_u64().unwrap() > 0);
+}
+
+#[test]
+fn cli_runtime_profile_folded_stacks() {
+    let temp = TempDir::new().unwrap();
+    let file = create_temp_file(&temp, "fib.ruchy", RECURSIVE_PROGRAM);
+    let report = temp.path().join("profile.folded");
+
+    ruchy_cmd()
+        .arg("runtime")
+        .arg(&file)
+        .arg("--profile")
+        .arg("--format")
+        .arg("folded")
+        .arg("--output")
+        .arg(&report)
+        .assert()
+        .success();
+
+    let folded = fs::read_to_string(&report).unwrap();
+    assert!(folded.lines().all(|line| {
+        line.rsplit_once(' ').is_some_and(|(stack, count)| {
+            stack.starts_with("<top-level>") && count.parse::<u64>().is_ok()
+        })
+    }));
+    assert!(folded.contains("<top-level>;main;fib;fib"), "{folded}");
+}
+
+#[test]
+fn cli_runtime_profile_rejects_unknown_format() {
+    let temp = TempDir::new().unwrap();
+    let file = create_temp_file(&temp, "fib.ruchy", RECURSIVE_PROGRAM);
+
+    ruchy_cmd()
+        .arg("runtime")
+        .arg(&file)
+        .arg("--profile")
+        .arg("--format")
+        .arg("svg")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown profile format"));
+}
+
 // ============================================================================
 // CLI CONTRACT TESTS: BIGO OPTION
 // ============================================================================
@@ -323,6 +416,26 @@ for i in arr {
         .success();
 }
 
+#[test]
+fn cli_runtime_memory_lists_largest_bindings() {
+    let temp = TempDir::new().unwrap();
+    let file = create_temp_file(
+        &temp,
+        "memory_bindings.ruchy",
+        "let label = \"small\"\nlet grid = [0; 10000]\n",
+    );
+
+    ruchy_cmd()
+        .arg("runtime")
+        .arg(&file)
+        .arg("--memory")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Heap memory usage"))
+        .stdout(predicate::str::is_match(r"(?m)^grid\s+array\s+10000\s").unwrap())
+        .stdout(predicate::str::contains("label"));
+}
+
 // ============================================================================
 // CLI CONTRACT TESTS: COMBINED OPTIONS
 // ============================================================================
@@ -80,3 +80,40 @@ fn test_test_format_option() {
         .success()
         .stdout(predicate::str::contains("--format"));
 }
+
+#[test]
+fn test_test_parity_option() {
+    ruchy_cmd()
+        .arg("test")
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--parity"));
+}
+
+#[test]
+fn test_test_parity_cases_requires_parity() {
+    ruchy_cmd()
+        .arg("test")
+        .arg("--parity-cases")
+        .arg("4")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("--parity"));
+}
+
+#[test]
+fn test_test_parity_checks_filtered_functions() {
+    ruchy_cmd()
+        .arg("test")
+        .arg("--parity")
+        .arg("--filter")
+        .arg("string.to_uppercase")
+        .arg("--parity-cases")
+        .arg("4")
+        .arg("--format")
+        .arg("json")
+        .assert()
+        .stdout(predicate::str::contains("\"name\": \"string.to_uppercase\""));
+}
@@ -182,3 +182,40 @@ fn cli_transpile_comment_only_fails() {
 
     ruchy_cmd().arg("transpile").arg(&file).assert().failure(); // Comment-only is error
 }
+
+// ============================================================================
+// CLI CONTRACT TESTS: --via-mir
+// ============================================================================
+
+#[test]
+fn cli_transpile_via_mir_emits_typed_block_loop() {
+    let temp = TempDir::new().unwrap();
+    let file = create_temp_file(
+        &temp,
+        "mir.ruchy",
+        "fun fact(n: i64) -> i64 {\n    if n <= 1 { 1 } else { n * fact(n - 1) }\n}\nprintln(fact(5))\n",
+    );
+
+    ruchy_cmd()
+        .arg("transpile")
+        .arg(&file)
+        .arg("--via-mir")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("fn fact(mut __0: i64) -> i64"))
+        .stdout(predicate::str::contains("match __block"));
+}
+
+#[test]
+fn cli_transpile_via_mir_rejects_unsupported_code() {
+    let temp = TempDir::new().unwrap();
+    let file = create_temp_file(&temp, "list.ruchy", "let xs = [1, 2, 3]\nprintln(xs)\n");
+
+    ruchy_cmd()
+        .arg("transpile")
+        .arg(&file)
+        .arg("--via-mir")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be lowered to MIR yet"));
+}
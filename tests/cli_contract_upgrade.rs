@@ -0,0 +1,92 @@
+#![allow(missing_docs)]
+//! CLI Contract Tests: `ruchy upgrade`
+//!
+//! **Contract Specification**:
+//! - `--dry-run` lists breaking findings with their fixes and changes nothing
+//! - Without it, fixed files are rewritten with `<file>.bak` backups and the
+//!   project's `@test` files run again
+//! - Exit code 1: the tests fail after the fixes
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+/// Helper: Create ruchy command
+fn ruchy_cmd() -> Command {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+}
+
+/// Helper: A project targeting Ruchy 4.2 with one keyword conflict
+fn old_project(test_body: &str) -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("Ruchy.toml"),
+        "[package]\nname = \"app\"\nversion = \"0.1.0\"\nruchy = \"4.2\"\n",
+    )
+    .unwrap();
+    fs::create_dir_all(dir.path().join("tests")).unwrap();
+    fs::write(
+        dir.path().join("main.ruchy"),
+        "let signal = 42\nprintln(signal)\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("tests/check.ruchy"),
+        format!("@test(\"upgrade\")\nfun test_upgrade() {{\n    {test_body}\n}}\n"),
+    )
+    .unwrap();
+    dir
+}
+
+#[test]
+fn cli_upgrade_dry_run_lists_findings_and_changes_nothing() {
+    let dir = old_project("assert_eq(1 + 1, 2)");
+    ruchy_cmd()
+        .arg("upgrade")
+        .arg(dir.path())
+        .arg("--dry-run")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "main.ruchy:1:5  [reserved_keyword]",
+        ))
+        .stdout(predicate::str::contains("fix: rename to `signal_val`"))
+        .stdout(predicate::str::contains("[target_release]"))
+        .stdout(predicate::str::contains("dry run"));
+    let main = fs::read_to_string(dir.path().join("main.ruchy")).unwrap();
+    assert!(main.contains("let signal = 42"));
+    assert!(!dir.path().join("main.ruchy.bak").exists());
+}
+
+#[test]
+fn cli_upgrade_applies_fixes_and_reruns_tests() {
+    let dir = old_project("assert_eq(1 + 1, 2)");
+    ruchy_cmd()
+        .arg("upgrade")
+        .arg(dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Re-running 1 test files"));
+    let main = fs::read_to_string(dir.path().join("main.ruchy")).unwrap();
+    assert!(main.contains("let signal_val = 42"));
+    assert!(dir.path().join("main.ruchy.bak").exists());
+}
+
+#[test]
+fn cli_upgrade_fails_when_tests_fail_after_the_fixes() {
+    let dir = old_project("assert_eq(1 + 1, 3)");
+    ruchy_cmd()
+        .arg("upgrade")
+        .arg(dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("fail after the upgrade"));
+    ruchy_cmd()
+        .arg("upgrade")
+        .arg(dir.path())
+        .arg("--no-test")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ready for Ruchy"));
+}
@@ -75,8 +75,8 @@ fn test_eval_format_json_success() {
         .arg("--format")
         .arg("json")
         .assert()
-        .success();
-    // JSON format doesn't output for successful evals without println
+        .success()
+        .stdout("{\"success\":true,\"result\":\"4\"}\n");
 }
 
 #[test]
@@ -0,0 +1,53 @@
+#![allow(missing_docs)]
+//! `ruchy --filter '<expr>'` applies an expression to every stdin line with
+//! `line` and `nr` bound, awk-style.
+
+use predicates::prelude::*;
+
+#[test]
+fn test_filter_selects_matching_lines() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("--filter")
+        .arg("line.contains(\"ERROR\")")
+        .write_stdin("INFO start\nERROR disk full\nINFO done\nERROR retry\n")
+        .assert()
+        .success()
+        .stdout("ERROR disk full\nERROR retry\n");
+}
+
+#[test]
+fn test_filter_maps_lines_with_line_numbers() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("--filter")
+        .arg("nr.to_string() + \" \" + line.to_uppercase()")
+        .write_stdin("a\nb\n")
+        .assert()
+        .success()
+        .stdout("1 A\n2 B\n");
+}
+
+#[test]
+fn test_filter_reads_file_argument() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("input.txt");
+    std::fs::write(&input, "x\ny\nz\n").unwrap();
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("--filter")
+        .arg("nr % 2 == 1")
+        .arg(&input)
+        .assert()
+        .success()
+        .stdout("x\nz\n");
+}
+
+#[test]
+fn test_filter_runtime_error_reports_line_and_exit_code() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("--filter")
+        .arg("if nr == 2 { line / 2 } else { line }")
+        .write_stdin("ok\nboom\n")
+        .assert()
+        .code(1)
+        .stdout("ok\n")
+        .stderr(predicate::str::contains("line 2:"));
+}
@@ -0,0 +1,61 @@
+#![allow(missing_docs)]
+//! `--input json` / `--output json`: scripts read JSON-lines records with
+//! `io::records()` and write them with `emit()`, so Ruchy processes can be
+//! chained in shell pipelines.
+
+use predicates::prelude::*;
+
+fn script(source: &str) -> (tempfile::TempDir, std::path::PathBuf) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("stage.ruchy");
+    std::fs::write(&path, source).unwrap();
+    (dir, path)
+}
+
+#[test]
+fn test_json_records_in_and_out() {
+    let (_dir, path) = script(
+        "for r in io::records() {\n    if r.n > 1 { emit({\"name\": r.name, \"double\": r.n * 2}) }\n}\n",
+    );
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .args(["--input", "json", "--output", "json"])
+        .arg(&path)
+        .write_stdin("{\"name\": \"ann\", \"n\": 1}\n\n{\"name\": \"bob\", \"n\": 3}\n")
+        .assert()
+        .success()
+        .stdout("{\"double\":6,\"name\":\"bob\"}\n");
+}
+
+#[test]
+fn test_text_records_are_lines() {
+    let (_dir, path) = script("for line in io::records() { emit(line.to_uppercase()) }\n");
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg(&path)
+        .write_stdin("a\nb\n")
+        .assert()
+        .success()
+        .stdout("A\nB\n");
+}
+
+#[test]
+fn test_json_output_renders_eval_result() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .args(["--input", "json", "--output", "json", "-e"])
+        .arg("io::records().map(|r| r.n * 10).collect()")
+        .write_stdin("{\"n\": 2}\n{\"n\": 5}\n")
+        .assert()
+        .success()
+        .stdout("[20,50]\n");
+}
+
+#[test]
+fn test_invalid_json_record_fails_with_line_number() {
+    let (_dir, path) = script("for r in io::records() { emit(r) }\n");
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .args(["--input", "json"])
+        .arg(&path)
+        .write_stdin("{\"ok\": true}\nnot json\n")
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("Invalid JSON record on line 2"));
+}
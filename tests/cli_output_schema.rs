@@ -0,0 +1,172 @@
+#![allow(missing_docs)]
+//! Schema tests for `--format json` outputs
+//!
+//! **Purpose**: Scripts parse the JSON printed by `eval`, `lint`, `test` and
+//! `fuzz`. These tests pin the serialized shape of every `ruchy::cli::output`
+//! document, check that documents printed by earlier releases still
+//! deserialize, and parse real binary output with the typed structs.
+
+use ruchy::cli::output::{
+    to_json_line, to_json_pretty, EvalResponse, FuzzFileReport, FuzzStatus, FuzzTargetReport,
+    LintReport, TestFileResult, TestReport,
+};
+use std::fs;
+use tempfile::TempDir;
+
+fn ruchy_stdout(args: &[&str]) -> String {
+    let output = assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .args(args)
+        .output()
+        .expect("ruchy binary should run");
+    String::from_utf8(output.stdout).expect("stdout should be UTF-8")
+}
+
+#[test]
+fn schema_eval_response_snapshot() {
+    assert_eq!(
+        to_json_line(&EvalResponse::ok(Some("a \"b\"".to_string()))).unwrap(),
+        r#"{"success":true,"result":"a \"b\""}"#
+    );
+    assert_eq!(
+        to_json_line(&EvalResponse::ok(None)).unwrap(),
+        r#"{"success":true}"#
+    );
+    assert_eq!(
+        to_json_line(&EvalResponse::failed("boom")).unwrap(),
+        r#"{"success":false,"error":"boom"}"#
+    );
+}
+
+#[test]
+fn schema_test_report_snapshot() {
+    let report = TestReport {
+        total: 2,
+        passed: 1,
+        failed: 1,
+        duration_seconds: 0.5,
+        results: vec![
+            TestFileResult {
+                file: "a.ruchy".to_string(),
+                success: true,
+                duration_ms: 12.5,
+                error: None,
+            },
+            TestFileResult {
+                file: "b.ruchy".to_string(),
+                success: false,
+                duration_ms: 3.0,
+                error: Some("assertion failed".to_string()),
+            },
+        ],
+    };
+    let expected = r#"{
+  "total": 2,
+  "passed": 1,
+  "failed": 1,
+  "duration_seconds": 0.5,
+  "results": [
+    {
+      "file": "a.ruchy",
+      "success": true,
+      "duration_ms": 12.5,
+      "error": null
+    },
+    {
+      "file": "b.ruchy",
+      "success": false,
+      "duration_ms": 3.0,
+      "error": "assertion failed"
+    }
+  ]
+}"#;
+    assert_eq!(to_json_pretty(&report).unwrap(), expected);
+}
+
+#[test]
+fn schema_fuzz_reports_snapshot() {
+    let target = FuzzTargetReport {
+        target: "parser".to_string(),
+        iterations: 10,
+        status: FuzzStatus::Passed,
+        output: "done".to_string(),
+    };
+    assert_eq!(
+        serde_json::to_value(&target).unwrap(),
+        serde_json::json!({"target": "parser", "iterations": 10, "status": "passed", "output": "done"})
+    );
+    let file = FuzzFileReport {
+        file: "x.ruchy".to_string(),
+        iterations: 4,
+        successes: 3,
+        crashes: 1,
+        timeouts: 0,
+        success_rate: 75.0,
+        status: FuzzStatus::Failed,
+        crash_details: vec!["input 2".to_string()],
+    };
+    assert_eq!(
+        serde_json::to_value(&file).unwrap(),
+        serde_json::json!({
+            "file": "x.ruchy",
+            "iterations": 4,
+            "successes": 3,
+            "crashes": 1,
+            "timeouts": 0,
+            "success_rate": 75.0,
+            "status": "failed",
+            "crash_details": ["input 2"]
+        })
+    );
+}
+
+/// Documents as printed by releases that built JSON by hand (sorted keys)
+#[test]
+fn schema_previous_release_outputs_still_deserialize() {
+    let eval: EvalResponse = serde_json::from_str(r#"{"success":true,"result":"4"}"#).unwrap();
+    assert_eq!(eval, EvalResponse::ok(Some("4".to_string())));
+    let failed: EvalResponse = serde_json::from_str(r#"{"error":"x","success":false}"#).unwrap();
+    assert_eq!(failed, EvalResponse::failed("x"));
+
+    let tests: TestReport = serde_json::from_str(
+        r#"{"duration_seconds":0.1,"failed":0,"passed":1,"results":[{"duration_ms":1.0,"error":null,"file":"t.ruchy","success":true}],"total":1}"#,
+    )
+    .unwrap();
+    assert_eq!(tests.results[0].file, "t.ruchy");
+
+    let fuzz: FuzzTargetReport =
+        serde_json::from_str(r#"{"iterations":5,"output":"","status":"failed","target":"parser"}"#)
+            .unwrap();
+    assert_eq!(fuzz.status, FuzzStatus::Failed);
+
+    let lint: LintReport = serde_json::from_str(
+        r#"{"issues":[{"column":5,"line":2,"message":"unused variable: x","name":"x","rule":"unused_variable","severity":"warning","suggestion":"Remove it","type":"unused_variable"}]}"#,
+    )
+    .unwrap();
+    assert_eq!(lint.issues[0].issue_type, "unused_variable");
+}
+
+#[test]
+fn schema_binary_eval_output_parses() {
+    let ok: EvalResponse =
+        serde_json::from_str(ruchy_stdout(&["-e", "2 + 2", "--format", "json"]).trim())
+            .expect("eval success should be an EvalResponse");
+    assert!(ok.success);
+    assert_eq!(ok.result.as_deref(), Some("4"));
+
+    let err: EvalResponse =
+        serde_json::from_str(ruchy_stdout(&["-e", "undefined_var", "--format", "json"]).trim())
+            .expect("eval failure should be an EvalResponse");
+    assert!(!err.success);
+    assert!(err.error.is_some());
+}
+
+#[test]
+fn schema_binary_lint_output_parses() {
+    let temp = TempDir::new().expect("temp dir");
+    let path = temp.path().join("lint.ruchy");
+    fs::write(&path, "fn f() {\n    let unused = 1\n    2\n}\n").expect("write fixture");
+    let stdout = ruchy_stdout(&["lint", path.to_str().unwrap(), "--format", "json"]);
+    let report: LintReport =
+        serde_json::from_str(&stdout).expect("lint output should be a LintReport");
+    assert!(report.issues.iter().any(|i| i.name == "unused"), "{stdout}");
+}
@@ -0,0 +1,141 @@
+#![allow(missing_docs)]
+//! Script-mode semantics are identical across entry points
+//!
+//! **Purpose**: `ruchy file.ruchy`, `ruchy run file.ruchy` and `ruchy -e` all
+//! execute through `ruchy::runtime::script`, so multi-line constructs, the
+//! `main()` auto-call rule and exit codes must agree between them.
+
+use assert_cmd::Command;
+use std::fs;
+use tempfile::TempDir;
+
+fn ruchy_cmd() -> Command {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+}
+
+/// (stdout, exit code) for each of: direct file, `run`, `-e`
+fn run_all_entry_points(source: &str) -> Vec<(String, Option<i32>)> {
+    let temp = TempDir::new().expect("temp dir");
+    let path = temp.path().join("script.ruchy");
+    fs::write(&path, source).expect("write script");
+
+    let invocations = [
+        ruchy_cmd().arg(&path).output(),
+        ruchy_cmd().arg("run").arg(&path).output(),
+        ruchy_cmd().arg("-e").arg(source).output(),
+    ];
+    invocations
+        .into_iter()
+        .map(|output| {
+            let output = output.expect("ruchy binary should run");
+            (
+                String::from_utf8_lossy(&output.stdout).into_owned(),
+                output.status.code(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn multiline_method_chain_runs_everywhere() {
+    let source = "let total = [1, 2, 3]\n    .map(|x| x * 2)\n    .sum()\nprintln(total)\n";
+    for (stdout, code) in run_all_entry_points(source) {
+        assert_eq!(stdout.trim(), "12");
+        assert_eq!(code, Some(0));
+    }
+}
+
+#[test]
+fn main_is_called_exactly_once() {
+    let source = "fun main() {\n    println(\"hello\")\n}\nmain()\n";
+    for (stdout, code) in run_all_entry_points(source) {
+        assert_eq!(stdout.matches("hello").count(), 1, "stdout: {stdout}");
+        assert_eq!(code, Some(0));
+    }
+}
+
+#[test]
+fn main_is_auto_called_when_defined() {
+    let source = "fun main() {\n    println(\"from main\")\n}\n";
+    for (stdout, code) in run_all_entry_points(source) {
+        assert!(stdout.contains("from main"), "stdout: {stdout}");
+        assert_eq!(code, Some(0));
+    }
+}
+
+#[test]
+fn syntax_errors_exit_with_code_2() {
+    let source = "println(\"never printed\")\nlet x = \n";
+    for (stdout, code) in run_all_entry_points(source) {
+        assert!(!stdout.contains("never printed"));
+        assert_eq!(code, Some(2));
+    }
+}
+
+#[test]
+fn runtime_errors_exit_with_code_1() {
+    for (_, code) in run_all_entry_points("undefined_function_xyz()\n") {
+        assert_eq!(code, Some(1));
+    }
+}
+
+#[test]
+fn timeout_flag_reports_the_limit() {
+    let output = ruchy_cmd()
+        .arg("--timeout")
+        .arg("200ms")
+        .arg("-e")
+        .arg("loop { }")
+        .output()
+        .expect("ruchy binary should run");
+    assert_eq!(output.status.code(), Some(124));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("timed out after 200ms"), "stderr: {stderr}");
+}
+
+#[test]
+fn timeout_env_var_applies_to_scripts() {
+    let temp = TempDir::new().expect("temp dir");
+    let path = temp.path().join("spin.ruchy");
+    fs::write(&path, "loop { }\n").expect("write script");
+    let output = ruchy_cmd()
+        .env("RUCHY_TIMEOUT", "200ms")
+        .arg(&path)
+        .output()
+        .expect("ruchy binary should run");
+    assert_eq!(output.status.code(), Some(124));
+}
+
+#[test]
+fn timeout_flag_wins_over_env_var() {
+    let output = ruchy_cmd()
+        .env("RUCHY_TIMEOUT", "not-a-duration")
+        .arg("--timeout")
+        .arg("200ms")
+        .arg("-e")
+        .arg("loop { }")
+        .output()
+        .expect("ruchy binary should run");
+    assert_eq!(output.status.code(), Some(124));
+}
+
+#[test]
+fn long_running_scripts_are_not_cut_off_by_default() {
+    // Well past any former hidden deadline
+    let source = "let mut total = 0\nfor i in 0..300000 { total = total + i }\nprintln(total)\n";
+    for (stdout, code) in run_all_entry_points(source) {
+        assert_eq!(stdout.trim(), "44999850000");
+        assert_eq!(code, Some(0));
+    }
+}
+
+#[test]
+fn invalid_timeout_is_rejected() {
+    ruchy_cmd()
+        .arg("--timeout")
+        .arg("soon")
+        .arg("-e")
+        .arg("1")
+        .assert()
+        .failure();
+}
@@ -0,0 +1,158 @@
+#![allow(missing_docs)]
+//! Golden tests: both CLI surfaces share one command layer
+//!
+//! **Purpose**: The `ruchy` binary and the `ruchy::cli` library front end must
+//! produce identical `fmt`, `lint`, `ast` and `test` results because both
+//! delegate to `ruchy::cli::shared`. Each test runs the binary and compares
+//! its output byte-for-byte with the shared library call on the same input.
+
+use assert_cmd::Command;
+use clap::Parser as _;
+use ruchy::cli::{shared, Cli};
+use ruchy::quality::FormatterConfig;
+use std::fs;
+use tempfile::TempDir;
+
+fn ruchy_cmd() -> Command {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+}
+
+const FIXTURES: &[&str] = &[
+    "tests/fixtures/fmt/simple.ruchy",
+    "tests/fixtures/fmt/operators.ruchy",
+    "tests/fixtures/fmt/functions.ruchy",
+    "tests/fixtures/fmt/control_flow.ruchy",
+];
+
+fn stdout_of(cmd: &mut Command) -> String {
+    let output = cmd.output().expect("ruchy binary should run");
+    String::from_utf8(output.stdout).expect("stdout should be UTF-8")
+}
+
+#[test]
+fn golden_fmt_stdout_matches_shared_formatter() {
+    for fixture in FIXTURES {
+        let source = fs::read_to_string(fixture).expect("fixture should exist");
+        let expected = shared::format_source(&source, &FormatterConfig::default())
+            .expect("fixture should format");
+        let actual = stdout_of(ruchy_cmd().arg("fmt").arg(fixture).arg("--stdout"));
+        assert_eq!(actual, expected, "fmt --stdout diverged for {fixture}");
+    }
+}
+
+#[test]
+fn golden_fmt_check_agrees_between_binary_and_library() {
+    let temp = TempDir::new().expect("temp dir");
+    let cases = [
+        ("formatted.ruchy", None),
+        ("unformatted.ruchy", Some("let   x=1+2")),
+    ];
+    for (name, content) in cases {
+        let path = temp.path().join(name);
+        let source = match content {
+            Some(text) => text.to_string(),
+            None => shared::format_source("let x = 1 + 2", &FormatterConfig::default())
+                .expect("should format"),
+        };
+        fs::write(&path, &source).expect("write fixture");
+
+        let binary_ok = ruchy_cmd()
+            .arg("fmt")
+            .arg(&path)
+            .arg("--check")
+            .output()
+            .expect("ruchy binary should run")
+            .status
+            .success();
+        let path_arg = path.to_str().expect("utf-8 path");
+        let library_ok = Cli::try_parse_from(["ruchy", "fmt", "--check", path_arg])
+            .expect("library CLI should parse")
+            .execute()
+            .is_ok();
+
+        assert_eq!(binary_ok, library_ok, "fmt --check disagreed for {name}");
+    }
+}
+
+#[test]
+fn golden_fmt_check_compares_whitespace_exactly() {
+    let temp = TempDir::new().expect("temp dir");
+    let path = temp.path().join("extra_newline.ruchy");
+    let formatted =
+        shared::format_source("let x = 1 + 2", &FormatterConfig::default()).expect("should format");
+    fs::write(&path, format!("{formatted}\n")).expect("write fixture");
+
+    ruchy_cmd()
+        .arg("fmt")
+        .arg(&path)
+        .arg("--check")
+        .assert()
+        .failure();
+    let path_arg = path.to_str().expect("utf-8 path");
+    let library = Cli::try_parse_from(["ruchy", "fmt", "--check", path_arg])
+        .expect("library CLI should parse")
+        .execute();
+    assert!(library.is_err(), "a trailing blank line needs formatting");
+}
+
+#[test]
+fn golden_lint_json_matches_shared_linter() {
+    let temp = TempDir::new().expect("temp dir");
+    let path = temp.path().join("lint.ruchy");
+    let source = "fn f() {\n    let unused = 1\n    2\n}\n";
+    fs::write(&path, source).expect("write fixture");
+
+    let issues = shared::lint_source(source, None, false).expect("should lint");
+    let expected = shared::lint_issues_json(&issues).expect("should serialize");
+    let actual = stdout_of(
+        ruchy_cmd()
+            .arg("lint")
+            .arg(&path)
+            .arg("--format")
+            .arg("json"),
+    );
+    assert_eq!(actual.trim_end(), expected);
+}
+
+#[test]
+fn golden_ast_json_matches_shared_serializer() {
+    for fixture in FIXTURES {
+        let source = fs::read_to_string(fixture).expect("fixture should exist");
+        let ast = shared::parse_source(&source).expect("fixture should parse");
+        let expected = shared::ast_to_json(&ast).expect("should serialize");
+        let actual = stdout_of(ruchy_cmd().arg("ast").arg(fixture).arg("--json"));
+        assert_eq!(
+            actual.trim_end(),
+            expected,
+            "ast --json diverged for {fixture}"
+        );
+    }
+}
+
+#[test]
+fn golden_test_outcome_agrees_between_binary_and_library() {
+    let cases = [
+        ("passing", "@test\nfun adds() { assert_eq(1 + 1, 2) }"),
+        ("failing", "@test\nfun adds() { assert_eq(1 + 1, 3) }"),
+    ];
+    for (name, source) in cases {
+        let temp = TempDir::new().expect("temp dir");
+        fs::write(temp.path().join("math_test.ruchy"), source).expect("write fixture");
+
+        let binary_ok = ruchy_cmd()
+            .arg("test")
+            .arg(temp.path())
+            .output()
+            .expect("ruchy binary should run")
+            .status
+            .success();
+        let path_arg = temp.path().to_str().expect("utf-8 path");
+        let library_ok = Cli::try_parse_from(["ruchy", "test", "run", path_arg])
+            .expect("library CLI should parse")
+            .execute()
+            .is_ok();
+
+        assert_eq!(binary_ok, library_ok, "test disagreed for {name}");
+        assert_eq!(binary_ok, name == "passing");
+    }
+}
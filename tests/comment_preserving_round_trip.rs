@@ -0,0 +1,203 @@
+#![allow(missing_docs)]
+//! Comments on struct fields, enum variants, parameters, match arms and call
+//! arguments survive parsing and formatting, and trailing commas are accepted
+//! in every comma-separated list.
+
+use ruchy::frontend::ast::{CommentKind, Expr, ExprKind};
+use ruchy::quality::formatter::Formatter;
+use ruchy::Parser;
+
+fn parse(source: &str) -> Expr {
+    Parser::new(source)
+        .parse()
+        .unwrap_or_else(|e| panic!("{source:?} should parse: {e}"))
+}
+
+fn first(expr: &Expr) -> &Expr {
+    match &expr.kind {
+        ExprKind::Block(exprs) => &exprs[0],
+        _ => expr,
+    }
+}
+
+fn line(kind: &CommentKind) -> &str {
+    match kind {
+        CommentKind::Line(text) | CommentKind::Doc(text) | CommentKind::Block(text) => text.trim(),
+    }
+}
+
+/// Format, check every comment is kept, and check the output parses back
+fn assert_round_trip(source: &str, comments: &[&str]) -> String {
+    let formatted = Formatter::new().format(&parse(source)).unwrap();
+    for comment in comments {
+        assert!(
+            formatted.contains(comment),
+            "lost {comment:?}:\n{formatted}"
+        );
+    }
+    let reformatted = Formatter::new().format(&parse(&formatted)).unwrap();
+    assert_eq!(formatted, reformatted, "formatting is not stable");
+    formatted
+}
+
+const STRUCT: &str = r"struct Config {
+    // where to listen
+    host: String, // no scheme
+    port: i32 // default 80
+}";
+
+#[test]
+fn test_struct_field_comments_are_attached() {
+    let ast = parse(STRUCT);
+    let ExprKind::Struct { fields, .. } = &first(&ast).kind else {
+        panic!("expected a struct");
+    };
+    assert_eq!(line(&fields[0].leading_comments[0].kind), "where to listen");
+    assert_eq!(
+        line(&fields[0].trailing_comment.as_ref().unwrap().kind),
+        "no scheme"
+    );
+    assert!(fields[1].leading_comments.is_empty());
+    assert_eq!(
+        line(&fields[1].trailing_comment.as_ref().unwrap().kind),
+        "default 80"
+    );
+}
+
+#[test]
+fn test_struct_field_comments_round_trip() {
+    assert_round_trip(
+        STRUCT,
+        &["// where to listen", "// no scheme", "// default 80"],
+    );
+}
+
+const ENUM: &str = r"enum Shape {
+    /// a dot
+    Point,
+    Circle(f64), // radius
+    // width then height
+    Rect(f64, f64),
+}";
+
+#[test]
+fn test_enum_variant_comments_are_attached() {
+    let ast = parse(ENUM);
+    let ExprKind::Enum { variants, .. } = &first(&ast).kind else {
+        panic!("expected an enum");
+    };
+    assert!(matches!(
+        &variants[0].leading_comments[0].kind,
+        CommentKind::Doc(_)
+    ));
+    assert_eq!(
+        line(&variants[1].trailing_comment.as_ref().unwrap().kind),
+        "radius"
+    );
+    assert_eq!(
+        line(&variants[2].leading_comments[0].kind),
+        "width then height"
+    );
+}
+
+#[test]
+fn test_enum_variant_comments_round_trip() {
+    assert_round_trip(ENUM, &["/// a dot", "// radius", "// width then height"]);
+}
+
+const FUNCTION: &str = r"fun connect(
+    host: String, // resolved lazily
+    // seconds
+    timeout: i32,
+) {
+    host
+}";
+
+#[test]
+fn test_param_comments_are_attached() {
+    let ast = parse(FUNCTION);
+    let ExprKind::Function { params, .. } = &first(&ast).kind else {
+        panic!("expected a function");
+    };
+    assert_eq!(params.len(), 2);
+    assert_eq!(
+        line(&params[0].trailing_comment.as_ref().unwrap().kind),
+        "resolved lazily"
+    );
+    assert_eq!(line(&params[1].leading_comments[0].kind), "seconds");
+}
+
+#[test]
+fn test_param_comments_round_trip() {
+    assert_round_trip(FUNCTION, &["// resolved lazily", "// seconds"]);
+}
+
+const MATCH: &str = r"match code {
+    // success
+    200 => 1, // ok
+    n if n > 499 => 2 // server error
+    _ => 0,
+}";
+
+#[test]
+fn test_match_arm_comments_are_attached() {
+    let ast = parse(MATCH);
+    let ExprKind::Match { arms, .. } = &first(&ast).kind else {
+        panic!("expected a match");
+    };
+    assert_eq!(line(&arms[0].leading_comments[0].kind), "success");
+    assert_eq!(line(&arms[0].trailing_comment.as_ref().unwrap().kind), "ok");
+    assert_eq!(
+        line(&arms[1].trailing_comment.as_ref().unwrap().kind),
+        "server error"
+    );
+    assert!(arms[1].body.trailing_comment.is_none());
+}
+
+#[test]
+fn test_match_arm_comments_round_trip() {
+    let formatted = assert_round_trip(MATCH, &["// success", "// ok", "// server error"]);
+    assert!(formatted.contains("n if n > 499 =>"), "{formatted}");
+}
+
+#[test]
+fn test_call_argument_comments_round_trip() {
+    let source = "add(\n    1, // first\n    2, // second\n)";
+    let ast = parse(source);
+    let ExprKind::Call { args, .. } = &first(&ast).kind else {
+        panic!("expected a call");
+    };
+    assert_eq!(args.len(), 2);
+    assert_eq!(
+        line(&args[1].trailing_comment.as_ref().unwrap().kind),
+        "second"
+    );
+    assert_round_trip(source, &["// first", "// second"]);
+}
+
+#[test]
+fn test_list_element_comments_round_trip() {
+    assert_round_trip(
+        "[\n    1, // one\n    // two follows\n    2,\n]",
+        &["// one", "// two follows"],
+    );
+}
+
+#[test]
+fn test_trailing_commas_are_accepted_everywhere() {
+    for source in [
+        "fun f(a, b,) { a }",
+        "f(1, 2,)",
+        "[1, 2,]",
+        "(1, 2,)",
+        "struct P { x: i32, y: i32, }",
+        "enum E { A, B(i32,), C { x: i32, }, }",
+        "match x { 1 => 2, _ => 3, }",
+        "let t: (i32, String,) = (1, \"a\")",
+        "fun g(v: Vec<i32,>) -> Option<i32,> { None }",
+        "match p { (a, b,) => a, [c, d,] => c, }",
+        "|a, b,| a + b",
+    ] {
+        parse(source);
+    }
+}
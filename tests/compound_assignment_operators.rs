@@ -0,0 +1,83 @@
+#![allow(missing_docs)]
+//! Compound assignments: `%=`, `**=`, the bitwise and shift forms, and the
+//! short-circuiting `&&=`, `||=` and `??=`, which only evaluate their right
+//! side, and only assign, when the current value does not decide the result.
+
+use ruchy::runtime::interpreter::{Interpreter, Value};
+use ruchy::Parser;
+
+fn eval(source: &str) -> Value {
+    let ast = Parser::new(source).parse().expect("source should parse");
+    Interpreter::new()
+        .eval_expr(&ast)
+        .unwrap_or_else(|e| panic!("{source:?} should evaluate: {e:?}"))
+}
+
+#[test]
+fn test_arithmetic_compounds() {
+    assert_eq!(eval("let mut x = 17\nx %= 5\nx"), Value::Integer(2));
+    assert_eq!(eval("let mut x = 2\nx **= 10\nx"), Value::Integer(1024));
+    assert_eq!(
+        eval("let mut x = 3\nx **= 2\nx **= 2\nx"),
+        Value::Integer(81)
+    );
+}
+
+#[test]
+fn test_bitwise_and_shift_compounds() {
+    let source = "let mut x = 12\nx &= 10\nx |= 1\nx ^= 3\nx <<= 4\nx >>= 2\nx";
+    // 12 & 10 = 8, | 1 = 9, ^ 3 = 10, << 4 = 160, >> 2 = 40
+    assert_eq!(eval(source), Value::Integer(40));
+}
+
+#[test]
+fn test_logical_compounds_short_circuit() {
+    // The right side names an undefined function, so evaluating it would fail
+    assert_eq!(
+        eval("let mut ok = false\nok &&= undefined_fn()\nok"),
+        Value::Bool(false)
+    );
+    assert_eq!(
+        eval("let mut ok = true\nok ||= undefined_fn()\nok"),
+        Value::Bool(true)
+    );
+    assert_eq!(
+        eval("let mut name = \"set\"\nname ??= undefined_fn()\nname"),
+        Value::from_string("set".to_string())
+    );
+
+    assert_eq!(
+        eval("let mut ok = true\nok &&= 1 > 2\nok"),
+        Value::Bool(false)
+    );
+    assert_eq!(
+        eval("let mut ok = false\nok ||= 2 > 1\nok"),
+        Value::Bool(true)
+    );
+    assert_eq!(
+        eval("let mut port = null\nport ??= 8080\nport"),
+        Value::Integer(8080)
+    );
+}
+
+#[test]
+fn test_compound_value_is_the_result() {
+    assert_eq!(eval("let mut x = 1\nx += 2"), Value::Integer(3));
+    assert_eq!(eval("let mut x = 5\nx ??= 6"), Value::Integer(5));
+}
+
+#[test]
+fn test_index_and_field_targets() {
+    let source =
+        "let mut counts = [0, null, 0]\nlet i = 1\ncounts[i + 1] += 5\ncounts[i] ??= 7\ncounts";
+    assert_eq!(
+        eval(source),
+        Value::from_array(vec![
+            Value::Integer(0),
+            Value::Integer(7),
+            Value::Integer(5)
+        ])
+    );
+    let source = "struct Config {\n    retries: i32,\n}\nlet mut config = Config { retries: 3 }\nconfig.retries **= 2\nconfig.retries %= 5\nconfig.retries";
+    assert_eq!(eval(source), Value::Integer(4));
+}
@@ -155,7 +155,6 @@ fn test_single_style(style_name: &str, code: &str, expect_pub: bool) {
 }
 
 #[test]
-#[ignore = "BUG: Class features test failing"]
 fn test_comprehensive_class_features() {
     // Test all features in one comprehensive example
     let code = r"
@@ -0,0 +1,103 @@
+#![allow(missing_docs)]
+//! `DataFrame` engine from Ruchy source: CSV files, filter, select, sort,
+//! head/tail, `group_by(..).agg(..)`, joins and table display.
+
+use ruchy::runtime::interpreter::{Interpreter, Value};
+use ruchy::Parser;
+use std::fs;
+use tempfile::TempDir;
+
+const SALES: &str =
+    "region,rep,amount\neast,ann,120\nwest,bob,80\neast,cid,200\nwest,dee,40\nnorth,eve,95\n";
+
+fn eval(source: &str) -> Value {
+    let ast = Parser::new(source).parse().expect("source should parse");
+    Interpreter::new()
+        .eval_expr(&ast)
+        .unwrap_or_else(|e| panic!("{source:?} should evaluate: {e:?}"))
+}
+
+fn sales_csv(dir: &TempDir) -> String {
+    let path = dir.path().join("sales.csv");
+    fs::write(&path, SALES).unwrap();
+    path.display().to_string()
+}
+
+#[test]
+fn test_from_csv_filter_group_agg_sort() {
+    let dir = TempDir::new().unwrap();
+    let source = format!(
+        r#"let df = DataFrame::from_csv("{}")
+df.filter(|r| r.amount > 50)
+  .group_by("region")
+  .agg({{"amount": ["sum", "count"]}})
+  .sort("amount_sum", true)
+  .to_csv()"#,
+        sales_csv(&dir)
+    );
+    assert_eq!(
+        eval(&source),
+        Value::from_string(
+            "region,amount_sum,amount_count\neast,320,2\nnorth,95,1\nwest,80,1\n".to_string()
+        )
+    );
+}
+
+#[test]
+fn test_head_tail_select_and_multi_key_sort() {
+    let csv = "DataFrame::from_csv_string(\"a,b\\n2,x\\n1,y\\n2,a\\n3,z\")";
+    assert_eq!(
+        eval(&format!(
+            "{csv}.sort([\"a\", \"b\"]).head(2).select(\"b\").to_csv()"
+        )),
+        Value::from_string("b\ny\na\n".to_string())
+    );
+    assert_eq!(
+        eval(&format!("{csv}.tail(1).to_csv()")),
+        Value::from_string("a,b\n3,z\n".to_string())
+    );
+}
+
+#[test]
+fn test_to_csv_path_round_trips() {
+    let dir = TempDir::new().unwrap();
+    let out = dir.path().join("out.csv").display().to_string();
+    let source = format!(
+        r#"let df = DataFrame::from_csv("{}")
+df.select(["rep", "amount"]).head(2).to_csv("{out}")
+DataFrame::from_csv("{out}").rows()"#,
+        sales_csv(&dir)
+    );
+    assert_eq!(eval(&source), Value::Integer(2));
+    assert_eq!(
+        fs::read_to_string(&out).unwrap(),
+        "rep,amount\nann,120\nbob,80\n"
+    );
+}
+
+#[test]
+fn test_group_by_count_and_join() {
+    let source = r#"let people = DataFrame::from_csv_string("id,dept\n1,eng\n2,ops\n3,eng")
+let depts = DataFrame::from_csv_string("dept,floor\neng,4\nops,2")
+let counts = people.group_by("dept").count()
+counts.join(depts, "dept").to_csv()"#;
+    assert_eq!(
+        eval(source),
+        Value::from_string("dept,count,floor_right\neng,2,4\nops,1,2\n".to_string())
+    );
+}
+
+#[test]
+fn test_dataframe_displays_as_table() {
+    let shown = eval("DataFrame::from_csv_string(\"name,age\\nann,31\\nbob,4\")").to_string();
+    assert_eq!(
+        shown,
+        "shape: (2, 2)\n\
+         ┌──────┬─────┐\n\
+         │ name │ age │\n\
+         ├──────┼─────┤\n\
+         │ ann  │  31 │\n\
+         │ bob  │   4 │\n\
+         └──────┴─────┘\n"
+    );
+}
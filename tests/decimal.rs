@@ -0,0 +1,92 @@
+#![allow(missing_docs)]
+//! Exact decimals: `1.23d` literals and `Decimal("1.23")` produce
+//! `Value::Decimal`, rounding takes an explicit mode, and the transpiler
+//! emits `rust_decimal::Decimal`.
+
+use ruchy::runtime::interpreter::{Interpreter, Value};
+use ruchy::{compile, Parser};
+
+fn eval(source: &str) -> Value {
+    let ast = Parser::new(source).parse().expect("source should parse");
+    Interpreter::new()
+        .eval_expr(&ast)
+        .unwrap_or_else(|e| panic!("{source:?} should evaluate: {e:?}"))
+}
+
+fn eval_err(source: &str) -> String {
+    let ast = Parser::new(source).parse().expect("source should parse");
+    match Interpreter::new().eval_expr(&ast) {
+        Ok(value) => panic!("{source:?} should fail, got {value:?}"),
+        Err(e) => e.to_string(),
+    }
+}
+
+#[test]
+fn test_decimal_addition_is_exact() {
+    assert_eq!(eval("0.1d + 0.2d == 0.3d"), Value::Bool(true));
+    assert_eq!(eval("0.1d + 0.2d").to_string(), "0.3");
+    assert_eq!(eval("0.1 + 0.2 == 0.3"), Value::Bool(false));
+}
+
+#[test]
+fn test_decimal_constructor_and_integer_mixing() {
+    let result = eval("Decimal(\"19.99\") * 3");
+    assert!(matches!(result, Value::Decimal(_)), "{result:?}");
+    assert_eq!(result.to_string(), "59.97");
+    assert_eq!(eval("10 - 0.01d").to_string(), "9.99");
+    assert_eq!(eval("Decimal(5) == 5"), Value::Bool(true));
+    assert_eq!(eval("1d / 8").to_string(), "0.125");
+}
+
+#[test]
+fn test_rounding_modes() {
+    assert_eq!(eval("2.345d.round_dp(2)").to_string(), "2.34");
+    assert_eq!(eval("2.345d.round_dp(2, \"half_up\")").to_string(), "2.35");
+    assert_eq!(
+        eval("2.345d.round_dp(2, \"half_down\")").to_string(),
+        "2.34"
+    );
+    assert_eq!(eval("2.341d.round_dp(2, \"up\")").to_string(), "2.35");
+    assert_eq!(eval("(-2.349d).round_dp(2, \"down\")").to_string(), "-2.34");
+    assert_eq!(
+        eval("(-2.341d).round_dp(2, \"floor\")").to_string(),
+        "-2.35"
+    );
+    assert!(eval_err("1.5d.round_dp(0, \"sideways\")").contains("Unknown rounding mode"));
+}
+
+#[test]
+fn test_formatting_helpers() {
+    assert_eq!(eval("2.5d.to_fixed(2)"), Value::from_string("2.50".into()));
+    assert_eq!(
+        eval("1234567.891d.to_grouped(2)"),
+        Value::from_string("1,234,567.89".into())
+    );
+    assert_eq!(
+        eval("(-1000d).to_grouped(0)"),
+        Value::from_string("-1,000".into())
+    );
+}
+
+#[test]
+fn test_float_arithmetic_is_rejected_but_comparison_allowed() {
+    assert!(eval_err("1.5d + 0.5").contains("float"));
+    assert_eq!(eval("1.5d < 2.0"), Value::Bool(true));
+}
+
+#[test]
+fn test_decimal_transpiles_to_rust_decimal() {
+    let rust = compile(
+        "fun main() { let price = 19.99d; println!(\"{}\", price.round_dp(1, \"half_up\")) }",
+    )
+    .unwrap();
+    assert!(rust.contains("rust_decimal :: Decimal"), "{rust}");
+    assert!(rust.contains("\"19.99\" . parse"), "{rust}");
+    assert!(
+        rust.contains("RoundingStrategy :: MidpointAwayFromZero"),
+        "{rust}"
+    );
+
+    let typed = compile("fun total(x: decimal) -> decimal { x * Decimal(\"1.2\") }").unwrap();
+    assert!(typed.contains("x : rust_decimal :: Decimal"), "{typed}");
+}
@@ -0,0 +1,106 @@
+#![allow(missing_docs)]
+//! `ruchy emit-compilationdb` describes each module of a project as JSON:
+//! its source, the modules it imports, the generated Rust and the flags.
+
+use std::fs;
+use tempfile::TempDir;
+
+fn ruchy_cmd() -> assert_cmd::Command {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+}
+
+fn write_project(dir: &TempDir) {
+    let files = [
+        (
+            "Ruchy.toml",
+            "[package]\nname = \"hello\"\nversion = \"0.1.0\"\n\n[dependencies]\nrand = \"0.8\"\n",
+        ),
+        (
+            "src/main.ruchy",
+            "mod greet;\n\nfun main() {\n    println(greet::hello())\n}\n",
+        ),
+        (
+            "src/greet.ruchy",
+            "pub fun hello() -> String {\n    \"hi\"\n}\n",
+        ),
+    ];
+    for (name, contents) in files {
+        let path = dir.path().join(name);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+}
+
+fn emit(args: &[&str], dir: &TempDir) -> serde_json::Value {
+    let output = ruchy_cmd()
+        .current_dir(dir.path())
+        .arg("emit-compilationdb")
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn test_project_database_lists_modules() {
+    let dir = TempDir::new().unwrap();
+    write_project(&dir);
+    let root = fs::canonicalize(dir.path()).unwrap();
+    let db = emit(&[], &dir);
+
+    let entries = db.as_array().unwrap();
+    assert_eq!(entries.len(), 2, "{db:#}");
+    let (main, greet) = (&entries[0], &entries[1]);
+    assert_eq!(main["module"], "crate");
+    assert_eq!(
+        main["source"].as_str().unwrap(),
+        root.join("src/main.ruchy").to_str().unwrap()
+    );
+    assert_eq!(main["imports"][0]["module"], "greet");
+    assert_eq!(greet["source"], main["imports"][0]["source"]);
+    assert_eq!(greet["imports"].as_array().unwrap().len(), 0);
+    assert_eq!(
+        main["artifact"].as_str().unwrap(),
+        root.join("target/ruchy/hello/src/main.rs")
+            .to_str()
+            .unwrap()
+    );
+    assert_eq!(main["arguments"], serde_json::json!(["ruchy", "build"]));
+    assert_eq!(
+        main["flags"],
+        serde_json::json!({ "edition": "2021", "dependencies": ["rand"] })
+    );
+    assert_eq!(main["source_key"].as_str().unwrap().len(), 64);
+}
+
+#[test]
+fn test_output_file_matches_stdout() {
+    let dir = TempDir::new().unwrap();
+    write_project(&dir);
+    ruchy_cmd()
+        .current_dir(dir.path())
+        .arg("emit-compilationdb")
+        .arg("-o")
+        .arg("compile_db.json")
+        .assert()
+        .success()
+        .stdout("");
+    let written: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(dir.path().join("compile_db.json")).unwrap())
+            .unwrap();
+    assert_eq!(written, emit(&["."], &dir));
+}
+
+#[test]
+fn test_missing_module_fails() {
+    let dir = TempDir::new().unwrap();
+    write_project(&dir);
+    fs::remove_file(dir.path().join("src/greet.ruchy")).unwrap();
+    ruchy_cmd()
+        .current_dir(dir.path())
+        .arg("emit-compilationdb")
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("Module `greet` declared in"));
+}
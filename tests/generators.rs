@@ -0,0 +1,65 @@
+#![allow(missing_docs)]
+//! Generator functions: a function containing `yield` returns a lazy
+//! iterator that resumes its body on each pull, and transpiles to a Rust
+//! function returning `impl Iterator`.
+
+use ruchy::runtime::interpreter::{Interpreter, Value};
+use ruchy::{compile, Parser};
+
+fn eval(source: &str) -> Value {
+    let ast = Parser::new(source).parse().expect("source should parse");
+    Interpreter::new()
+        .eval_expr(&ast)
+        .unwrap_or_else(|e| panic!("{source:?} should evaluate: {e:?}"))
+}
+
+fn ints(values: &[i64]) -> Value {
+    Value::from_array(values.iter().copied().map(Value::Integer).collect())
+}
+
+#[test]
+fn test_generator_yields_in_order() {
+    assert_eq!(
+        eval("fun gen() { yield 1; yield 2 }\ngen().collect()"),
+        ints(&[1, 2])
+    );
+}
+
+#[test]
+fn test_next_resumes_after_last_yield() {
+    let source = "fun gen() { yield 1; yield 2 }\nlet g = gen()\ng.next()\ng.next()";
+    assert_eq!(eval(source).to_string(), "Some(2)");
+    let source = "fun gen() { yield 1 }\nlet g = gen()\ng.next()\ng.next()";
+    assert_eq!(eval(source).to_string(), "None");
+}
+
+#[test]
+fn test_generator_with_parameters_and_loops() {
+    let source = "fun evens(limit) { let mut n = 0\n while n < limit { if n % 2 == 0 { yield n }\n n = n + 1 } }\nevens(7).collect()";
+    assert_eq!(eval(source), ints(&[0, 2, 4, 6]));
+    let source = "fun squares(xs) { for x in xs { yield x * x } }\nsquares([1, 2, 3]).map(|x| x + 1).collect()";
+    assert_eq!(eval(source), ints(&[2, 5, 10]));
+}
+
+#[test]
+fn test_infinite_generator_streams_through_adapters() {
+    let source = "fun naturals() { let mut n = 1\n loop { yield n\n n = n + 1 } }\nnaturals().filter(|x| x % 3 == 0).take(3).collect()";
+    assert_eq!(eval(source), ints(&[3, 6, 9]));
+}
+
+#[test]
+fn test_for_loop_over_generator_and_early_return() {
+    let source = "fun upto(n) { for i in 0..100 { if i == n { return }\n yield i } }\nlet mut total = 0\nfor x in upto(4) { total = total + x }\ntotal";
+    assert_eq!(eval(source), Value::Integer(6));
+}
+
+#[test]
+fn test_generator_transpiles_to_iterator_function() {
+    let rust = compile("fun gen() { yield 1; yield 2 }").unwrap();
+    assert!(rust.contains("impl Iterator < Item = i32 >"), "{rust}");
+    assert!(rust.contains("__yielded . push (1"), "{rust}");
+    assert!(rust.contains("__yielded . into_iter ()"), "{rust}");
+
+    let typed = compile("fun words() -> Iterator<String> { yield \"a\".to_string() }").unwrap();
+    assert!(typed.contains("impl Iterator < Item = String >"), "{typed}");
+}
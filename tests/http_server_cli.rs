@@ -500,6 +500,85 @@ fn test_http003_html_coop_header() {
     child.kill().unwrap();
 }
 
+// ============================================================================
+// Evaluation API Tests (--eval-api)
+// ============================================================================
+
+#[test]
+fn test_eval_api_evaluates_snippets_in_isolation() {
+    let test_dir = create_test_dir();
+    let port = find_available_port();
+    let ruchy_bin = assert_cmd::cargo::cargo_bin("ruchy");
+    let mut child = std::process::Command::new(ruchy_bin)
+        .arg("serve")
+        .arg(test_dir.path())
+        .arg("--port")
+        .arg(port.to_string())
+        .arg("--eval-api")
+        .arg("--eval-rate-limit")
+        .arg("4")
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .expect("Failed to spawn server");
+
+    std::thread::sleep(Duration::from_millis(500));
+
+    let client = reqwest::blocking::Client::new();
+    let eval = |source: &str| {
+        client
+            .post(format!("http://127.0.0.1:{port}/api/eval"))
+            .json(&serde_json::json!({ "source": source }))
+            .send()
+            .unwrap()
+    };
+
+    let response = eval("let x = 41\nprintln(\"hi\")\nx + 1");
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().unwrap();
+    assert_eq!(body["success"], true);
+    assert_eq!(body["stdout"], "hi\n");
+    assert_eq!(body["value"], "42");
+
+    // Nothing leaks between requests, and host access is denied
+    let body: serde_json::Value = eval("x").json().unwrap();
+    assert_eq!(body["error"]["kind"], "runtime");
+    let body: serde_json::Value = eval("fs_read(\"/etc/passwd\")").json().unwrap();
+    assert!(body["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("restricted mode"));
+    let forged = "{__type: \"Command\", program: \"id\", args: []}.output()";
+    let body: serde_json::Value = eval(forged).json().unwrap();
+    assert_eq!(body["success"], false);
+    assert!(body["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("restricted mode"));
+
+    // Static files are still served next to the API
+    let index = reqwest::blocking::get(format!("http://127.0.0.1:{port}/index.html")).unwrap();
+    assert_eq!(index.status(), 200);
+
+    let limited = eval("1");
+    assert_eq!(limited.status(), 429);
+    assert!(limited.headers().contains_key("retry-after"));
+
+    child.kill().unwrap();
+}
+
+#[test]
+fn test_eval_api_flags_require_eval_api() {
+    ruchy_cmd()
+        .arg("serve")
+        .arg("--eval-rate-limit")
+        .arg("10")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("--eval-api"));
+}
+
 // ============================================================================
 // RED Phase Validation
 // ============================================================================
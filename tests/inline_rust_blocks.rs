@@ -0,0 +1,80 @@
+#![allow(missing_docs)]
+//! `rust { ... }` blocks pass Rust through the transpiler verbatim, while
+//! the interpreter runs their `else` fallbacks or rejects them.
+
+use std::fs;
+use tempfile::TempDir;
+
+fn ruchy_cmd() -> assert_cmd::Command {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+}
+
+const PROGRAM: &str = r#"
+rust {
+    fn byte_len(text: &str) -> i64 {
+        text.len() as i64
+    }
+} else {
+    fun byte_len(text) {
+        text.len()
+    }
+}
+
+fun main() {
+    let values = [1, 2, 3, 4]
+    let evens = rust(values: &[i64]) -> i64 {
+        values.iter().filter(|v| *v % 2 == 0).sum()
+    } else {
+        values.filter(|v| v % 2 == 0).sum()
+    }
+    println(evens)
+    println(byte_len("hello"))
+}
+"#;
+
+fn write_program(dir: &TempDir, source: &str) -> std::path::PathBuf {
+    let file = dir.path().join("inline.ruchy");
+    fs::write(&file, source).unwrap();
+    file
+}
+
+#[test]
+fn test_transpile_passes_rust_through() {
+    let dir = TempDir::new().unwrap();
+    let file = write_program(&dir, PROGRAM);
+    ruchy_cmd()
+        .arg("transpile")
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("fn byte_len"))
+        .stdout(predicates::str::contains("fn __ruchy_rust_block"))
+        .stdout(predicates::str::contains(".filter(|v| *v % 2 == 0)"));
+}
+
+#[test]
+fn test_interpreter_runs_fallbacks() {
+    let dir = TempDir::new().unwrap();
+    let file = write_program(&dir, PROGRAM);
+    ruchy_cmd()
+        .arg("run")
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout("6\n5\n");
+}
+
+#[test]
+fn test_interpreter_rejects_block_without_fallback() {
+    let dir = TempDir::new().unwrap();
+    let file = write_program(
+        &dir,
+        "fun main() {\n    let n = rust() -> i64 { 40 + 2 }\n    println(n)\n}\n",
+    );
+    ruchy_cmd()
+        .arg("run")
+        .arg(&file)
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("only run in compiled programs"));
+}
@@ -0,0 +1,95 @@
+#![allow(missing_docs)]
+//! Lazy iterators: `.iter()` on lists and ranges builds a pipeline whose
+//! adapters only run as elements are pulled, and pipelines transpile to Rust
+//! iterator chains without intermediate collects.
+
+use ruchy::runtime::interpreter::{Interpreter, Value};
+use ruchy::{compile, Parser};
+
+fn eval(source: &str) -> Value {
+    let ast = Parser::new(source).parse().expect("source should parse");
+    Interpreter::new()
+        .eval_expr(&ast)
+        .unwrap_or_else(|e| panic!("{source:?} should evaluate: {e:?}"))
+}
+
+fn ints(values: &[i64]) -> Value {
+    Value::from_array(values.iter().copied().map(Value::Integer).collect())
+}
+
+#[test]
+fn test_map_filter_collect() {
+    assert_eq!(
+        eval("[1, 2, 3, 4].iter().map(|x| x * 10).filter(|x| x > 15).collect()"),
+        ints(&[20, 30, 40])
+    );
+}
+
+#[test]
+fn test_adapters_are_lazy_over_huge_ranges() {
+    assert_eq!(
+        eval("(0..1000000000).iter().map(|x| x * 2).skip(1).take(3).collect()"),
+        ints(&[2, 4, 6])
+    );
+    assert_eq!(
+        eval("(1..1000000000).filter(|x| x % 7 == 0).find(|x| x > 20)").to_string(),
+        "Some(21)"
+    );
+}
+
+#[test]
+fn test_zip_enumerate_chain_flat_map() {
+    assert_eq!(
+        eval("[1, 2, 3].iter().zip([4, 5]).map(|p| p.0 * p.1).collect()"),
+        ints(&[4, 10])
+    );
+    assert_eq!(
+        eval("[7, 8].iter().enumerate().map(|p| p.0).collect()"),
+        ints(&[0, 1])
+    );
+    assert_eq!(eval("[1].iter().chain(2..=3).collect()"), ints(&[1, 2, 3]));
+    assert_eq!(
+        eval("[1, 2].iter().flat_map(|x| [x, x]).collect()"),
+        ints(&[1, 1, 2, 2])
+    );
+}
+
+#[test]
+fn test_consumers() {
+    assert_eq!(eval("(1..=4).iter().sum()"), Value::Integer(10));
+    assert_eq!(eval("(1..=4).iter().count()"), Value::Integer(4));
+    assert_eq!(
+        eval("[1, 2, 3].iter().fold(0, |acc, x| acc + x)"),
+        Value::Integer(6)
+    );
+    assert_eq!(eval("[1, 2, 3].iter().any(|x| x == 2)"), Value::Bool(true));
+    assert_eq!(eval("[1, 2, 3].iter().all(|x| x > 1)"), Value::Bool(false));
+}
+
+#[test]
+fn test_next_advances_shared_iterator() {
+    let source = "let it = [1, 2, 3].iter()\nit.next()\nit.next()";
+    assert_eq!(eval(source).to_string(), "Some(2)");
+    assert_eq!(eval("let it = [].iter()\nit.next()").to_string(), "None");
+}
+
+#[test]
+fn test_for_loop_pulls_from_iterator() {
+    let source = "let mut total = 0\nfor x in (1..100).iter().map(|x| x * x).take(3) { total = total + x }\ntotal";
+    assert_eq!(eval(source), Value::Integer(14));
+}
+
+#[test]
+fn test_pipeline_transpiles_to_iterator_chain() {
+    let rust = compile(
+        "fun evens(v: Vec<i32>) -> Vec<i32> { v.iter().map(|x| x * 2).filter(|x| x > 2).take(2).collect() }",
+    )
+    .unwrap();
+    assert!(rust.contains(". cloned ()"), "{rust}");
+    assert!(rust.contains("as usize"), "{rust}");
+    assert_eq!(rust.matches("collect").count(), 1, "{rust}");
+
+    let range = compile("fun squares(n: i32) -> i32 { (0..n).map(|x| x * x).sum() }").unwrap();
+    assert!(range.contains(") . map ("), "{range}");
+    assert!(!range.contains("n . map"), "{range}");
+}
@@ -0,0 +1,120 @@
+#![allow(missing_docs)]
+//! The parser's optional lossless CST reproduces the source byte for byte and
+//! supports targeted edits that leave everything else untouched.
+
+use ruchy::frontend::cst::{ConcreteSyntaxTree, CstKind, TextEdit};
+use ruchy::frontend::Token;
+use ruchy::Parser;
+
+const PROGRAM: &str = "#!/usr/bin/env ruchy\n\
+// Configuration\n\
+let   port = 8080 ;\n\
+\n\
+/* helpers */\n\
+fun  double(x)  {\tx * 2 }   // tabs kept\n\
+\n\
+double( port )\n";
+
+fn cst(source: &str) -> ConcreteSyntaxTree {
+    let mut parser = Parser::new(source).with_cst();
+    parser
+        .parse()
+        .unwrap_or_else(|e| panic!("{source:?} should parse: {e}"));
+    parser.take_cst().expect("CST was requested")
+}
+
+#[test]
+fn test_cst_is_only_built_on_request() {
+    let mut parser = Parser::new("1 + 2");
+    parser.parse().unwrap();
+    assert!(parser.take_cst().is_none());
+}
+
+#[test]
+fn test_leaves_reproduce_source_exactly() {
+    let tree = cst(PROGRAM);
+    assert_eq!(tree.to_source_string(), PROGRAM);
+    let mut cursor = 0;
+    for leaf in tree.leaves() {
+        assert_eq!(leaf.span.start, cursor, "gap or overlap before {leaf:?}");
+        cursor = leaf.span.end;
+    }
+    assert_eq!(cursor, PROGRAM.len());
+}
+
+#[test]
+fn test_trivia_leaves_are_kept() {
+    let tree = cst(PROGRAM);
+    assert_eq!(tree.leaves()[0].kind, CstKind::Shebang);
+    assert!(tree
+        .leaves()
+        .iter()
+        .any(|leaf| matches!(leaf.kind, CstKind::Token(Token::BlockComment(_)))));
+    let tab = PROGRAM.find('\t').unwrap();
+    let leaf = tree.leaf_at(tab).unwrap();
+    assert_eq!(leaf.kind, CstKind::Whitespace);
+    assert!(leaf.is_trivia());
+}
+
+#[test]
+fn test_items_cover_top_level_expressions() {
+    let tree = cst(PROGRAM);
+    let texts: Vec<&str> = tree
+        .items()
+        .iter()
+        .map(|item| tree.text(item.span))
+        .collect();
+    assert_eq!(texts.len(), 3, "{texts:#?}");
+    assert!(texts[0].ends_with("let   port = 8080 ;"), "{:?}", texts[0]);
+    assert!(
+        texts[1].contains("fun  double(x)  {\tx * 2 }"),
+        "{:?}",
+        texts[1]
+    );
+    assert_eq!(texts[2], "double( port )");
+}
+
+#[test]
+fn test_item_edit_leaves_other_bytes_untouched() {
+    let tree = cst(PROGRAM);
+    let call = &tree.items()[2];
+    let edited = tree
+        .apply_edits(&[TextEdit::replace(call.span, "double(port + 1)")])
+        .unwrap();
+    assert_eq!(
+        edited,
+        PROGRAM.replace("double( port )", "double(port + 1)")
+    );
+    assert_eq!(cst(&edited).to_source_string(), edited);
+}
+
+#[test]
+fn test_token_edits_are_byte_exact() {
+    let source = "let x = 1  // keep\nx";
+    let tree = cst(source);
+    let one = tree
+        .leaves()
+        .iter()
+        .find(|leaf| leaf.kind == CstKind::Token(Token::Integer("1".to_string())))
+        .map(|leaf| leaf.span);
+    let Some(one) = one else {
+        panic!("integer leaf not found: {:#?}", tree.leaves());
+    };
+    let edited = tree
+        .apply_edits(&[
+            TextEdit::replace(one, "42"),
+            TextEdit::insert(source.len(), " + 1"),
+        ])
+        .unwrap();
+    assert_eq!(edited, "let x = 42  // keep\nx + 1");
+}
+
+#[test]
+fn test_overlapping_edits_are_rejected() {
+    let tree = ConcreteSyntaxTree::new("let x = 1");
+    let overlapping = [
+        TextEdit::delete(ruchy::frontend::Span::new(0, 5)),
+        TextEdit::delete(ruchy::frontend::Span::new(4, 6)),
+    ];
+    assert!(tree.apply_edits(&overlapping).is_err());
+}
@@ -0,0 +1,57 @@
+#![allow(missing_docs)]
+//! Digit separators, octal/binary literals and char escapes work end to
+//! end: in the interpreter, in match patterns and in transpiled Rust.
+
+use std::fs;
+use tempfile::TempDir;
+
+fn ruchy_cmd() -> assert_cmd::Command {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+}
+
+const PROGRAM: &str = r#"
+fun kind(mode) {
+    match mode & 0o777 {
+        0o755 => "executable",
+        0o600..=0o644 => "private",
+        _ => "other",
+    }
+}
+
+fun main() {
+    let flags = 0b1010 | 0b0101
+    println(flags)
+    println(1_000_000 + 0xFF_FF)
+    println(kind(0o100755))
+    println(kind(0o640))
+    println('\u{e9}')
+    println('\x41')
+}
+"#;
+
+#[test]
+fn test_run_literal_forms() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("literals.ruchy");
+    fs::write(&file, PROGRAM).unwrap();
+    ruchy_cmd()
+        .arg("run")
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout("15\n1065535\nexecutable\nprivate\né\nA\n");
+}
+
+#[test]
+fn test_transpile_literal_forms() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("literals.ruchy");
+    fs::write(&file, "fun main() {\n    let mask = 0b1111_0000u8\n    let c = '\\u{e9}'\n    println(mask)\n    println(c)\n}\n").unwrap();
+    ruchy_cmd()
+        .arg("transpile")
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("240u8"))
+        .stdout(predicates::str::contains("'é'"));
+}
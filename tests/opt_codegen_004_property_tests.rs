@@ -33,6 +33,8 @@ fn property_inline_idempotent() {
                         span: Span::default(),
                         is_mutable: false,
                         default_value: None,
+                        leading_comments: Vec::new(),
+                        trailing_comment: None,
                     },
                     Param {
                         pattern: Pattern::Identifier("y".to_string()),
@@ -43,6 +45,8 @@ fn property_inline_idempotent() {
                         span: Span::default(),
                         is_mutable: false,
                         default_value: None,
+                        leading_comments: Vec::new(),
+                        trailing_comment: None,
                     },
                 ],
                 return_type: None,
@@ -124,6 +128,8 @@ fn property_recursive_never_inlined() {
                     span: Span::default(),
                     is_mutable: false,
                     default_value: None,
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 }],
                 return_type: None,
                 body: Box::new(Expr::new(
@@ -225,6 +231,8 @@ fn property_large_functions_not_inlined() {
                     span: Span::default(),
                     is_mutable: false,
                     default_value: None,
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 }],
                 return_type: None,
                 body: Box::new(nested_let),
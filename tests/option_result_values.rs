@@ -0,0 +1,79 @@
+#![allow(missing_docs)]
+//! Option and Result values: `Some`, `None`, `Ok` and `Err` build built-in
+//! enum values whose methods, patterns, `?` and equality behave like Rust.
+
+use ruchy::runtime::eval_option_result::{err, none, ok, some};
+use ruchy::runtime::interpreter::{Interpreter, Value};
+use ruchy::Parser;
+
+fn eval(source: &str) -> Value {
+    let ast = Parser::new(source).parse().expect("source should parse");
+    Interpreter::new()
+        .eval_expr(&ast)
+        .unwrap_or_else(|e| panic!("{source:?} should evaluate: {e:?}"))
+}
+
+fn eval_err(source: &str) -> String {
+    let ast = Parser::new(source).parse().expect("source should parse");
+    match Interpreter::new().eval_expr(&ast) {
+        Ok(value) => panic!("{source:?} should fail, got {value}"),
+        Err(e) => e.to_string(),
+    }
+}
+
+#[test]
+fn test_option_combinators() {
+    assert_eq!(eval("Some(2).map(|x| x * 10)"), some(Value::Integer(20)));
+    assert_eq!(eval("None.unwrap_or(3)"), Value::Integer(3));
+    assert_eq!(eval("Some(4).filter(|x| x > 5)"), none());
+    assert_eq!(
+        eval("None.ok_or(\"missing\")"),
+        err(Value::from_string("missing".to_string()))
+    );
+    assert_eq!(
+        eval("Some(1).and_then(|x| Some(x + 1)).is_some()"),
+        Value::Bool(true)
+    );
+}
+
+#[test]
+fn test_result_combinators() {
+    assert_eq!(
+        eval("Ok(5).and_then(|x| Ok(x * 2))"),
+        ok(Value::Integer(10))
+    );
+    assert_eq!(eval("Err(1).map_err(|e| e + 1)"), err(Value::Integer(2)));
+    assert_eq!(eval("Err(1).unwrap_or_else(|e| e * 7)"), Value::Integer(7));
+    assert_eq!(eval("Ok(3).ok()"), some(Value::Integer(3)));
+    assert_eq!(eval("Err(3).ok()"), none());
+}
+
+#[test]
+fn test_unwrap_reports_rust_panic_messages() {
+    assert!(eval_err("None.unwrap()").contains("called `Option::unwrap()` on a `None` value"));
+    assert!(eval_err("Err(\"boom\").expect(\"loading config\")").contains("loading config: boom"));
+}
+
+#[test]
+fn test_match_patterns() {
+    let source = "fun describe(r) {\n    match r {\n        Ok(x) => x + 1,\n        Err(e) => e * 100,\n    }\n}\ndescribe(Ok(1)) + describe(Err(2))";
+    assert_eq!(eval(source), Value::Integer(202));
+    let source = "match Some(5) {\n    Some(x) => x,\n    None => 0,\n}";
+    assert_eq!(eval(source), Value::Integer(5));
+}
+
+#[test]
+fn test_question_mark_on_option_returns_none() {
+    let source = "fun inc(x) {\n    let v = x?\n    Some(v + 1)\n}\n[inc(Some(1)), inc(None)]";
+    assert_eq!(
+        eval(source),
+        Value::from_array(vec![some(Value::Integer(2)), none()])
+    );
+}
+
+#[test]
+fn test_equality() {
+    assert_eq!(eval("Ok(1) == Ok(1)"), Value::Bool(true));
+    assert_eq!(eval("Ok(1) == Err(1)"), Value::Bool(false));
+    assert_eq!(eval("Some(2) != None"), Value::Bool(true));
+}
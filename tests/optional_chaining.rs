@@ -0,0 +1,94 @@
+#![allow(missing_docs)]
+//! Optional chaining: `obj?.field`, `obj?.method()` and `arr?[i]` short-circuit
+//! to nil when the receiver is nil or `None`, and transpile to `Option::map`.
+
+use ruchy::frontend::ast::ExprKind;
+use ruchy::runtime::interpreter::{Interpreter, Value};
+use ruchy::{compile, Parser};
+
+fn eval(source: &str) -> Value {
+    let ast = Parser::new(source).parse().expect("source should parse");
+    Interpreter::new()
+        .eval_expr(&ast)
+        .unwrap_or_else(|e| panic!("{source:?} should evaluate: {e:?}"))
+}
+
+const USER: &str = r#"
+    struct User { name: String }
+    let user = User { name: "Ada" }
+"#;
+
+#[test]
+fn test_index_parses_as_optional_index_access() {
+    let ast = Parser::new("arr?[0]").parse().unwrap();
+    assert!(
+        matches!(ast.kind, ExprKind::OptionalIndexAccess { .. }),
+        "{:?}",
+        ast.kind
+    );
+}
+
+#[test]
+fn test_ternary_with_list_branch_is_not_optional_index() {
+    let ast = Parser::new("flag ? [1] : [2]").parse().unwrap();
+    assert!(
+        matches!(ast.kind, ExprKind::Ternary { .. }),
+        "{:?}",
+        ast.kind
+    );
+}
+
+#[test]
+fn test_field_access_on_present_value() {
+    let source = format!("{USER}\nuser?.name");
+    assert_eq!(eval(&source), Value::from_string("Ada".to_string()));
+}
+
+#[test]
+fn test_field_access_unwraps_some() {
+    let source = format!("{USER}\nlet maybe = Some(user)\nmaybe?.name");
+    assert_eq!(eval(&source), Value::from_string("Ada".to_string()));
+}
+
+#[test]
+fn test_field_access_short_circuits_on_nil_and_none() {
+    assert_eq!(eval("let user = null\nuser?.name"), Value::Nil);
+    assert_eq!(eval("let user = None\nuser?.name"), Value::Nil);
+}
+
+#[test]
+fn test_chained_method_call() {
+    let source = format!("{USER}\nuser?.name?.len()");
+    assert_eq!(eval(&source), Value::Integer(3));
+    assert_eq!(eval("let user = None\nuser?.name?.len()"), Value::Nil);
+}
+
+#[test]
+fn test_method_arguments_are_not_evaluated_on_short_circuit() {
+    assert_eq!(eval("let s = null\ns?.contains(missing_value)"), Value::Nil);
+}
+
+#[test]
+fn test_index_access() {
+    assert_eq!(eval("let arr = [10, 20]\narr?[1]"), Value::Integer(20));
+    assert_eq!(
+        eval("let arr = Some([10, 20])\narr?[0]"),
+        Value::Integer(10)
+    );
+    assert_eq!(eval("let arr = None\narr?[0]"), Value::Nil);
+}
+
+#[test]
+fn test_transpiles_to_option_map() {
+    let field = compile("fun name(user: Option<User>) { user?.name }").unwrap();
+    assert!(field.contains("as_ref"), "{field}");
+    assert!(field.contains("map"), "{field}");
+
+    let index = compile("fun first(v: Option<Vec<i32>>) { v?[0] }").unwrap();
+    assert!(index.contains("as_ref"), "{index}");
+    assert!(index.contains("as usize"), "{index}");
+
+    let method = compile("fun size(s: Option<String>) { s?.len() }").unwrap();
+    assert!(method.contains("len"), "{method}");
+    assert!(method.contains("map"), "{method}");
+}
@@ -23,7 +23,11 @@ sum
     let mut repl = Repl::new(std::env::temp_dir()).unwrap();
     let result = repl.eval(code);
     assert!(result.is_ok(), "Basic loop should execute: {result:?}");
-    assert_eq!(result.unwrap(), "499500");
+    // `sum` is defined at top level, so it replaces the builtin
+    assert_eq!(
+        result.unwrap(),
+        "Warning: 'sum' replaces the standard library definition; :undef sum restores it\n499500"
+    );
 }
 
 #[test]
@@ -297,7 +297,6 @@ fn p0_struct_definition() {
 }
 
 #[test]
-#[ignore = "Class runtime not implemented - tracking issue"]
 fn p0_class_definition() {
     let code = r"
         class Rectangle {
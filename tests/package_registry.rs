@@ -0,0 +1,129 @@
+#![allow(missing_docs)]
+//! `ruchy publish` uploads a package to a registry and `ruchy add` in a
+//! `Ruchy.toml` project resolves, locks and vendors it so `mod name;` loads
+//! it. A registry directory stands in for the HTTP registry.
+
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn ruchy_cmd() -> assert_cmd::Command {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+}
+
+fn write(dir: &Path, name: &str, contents: &str) {
+    let path = dir.join(name);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+fn publish_text_utils(registry: &Path, version: &str) {
+    let package = TempDir::new().unwrap();
+    write(
+        package.path(),
+        "Ruchy.toml",
+        &format!(
+            "[package]\nname = \"text-utils\"\nversion = \"{version}\"\n\
+             authors = [\"Ann\"]\ndescription = \"Text helpers\"\nlicense = \"MIT\"\n"
+        ),
+    );
+    write(
+        package.path(),
+        "src/lib.ruchy",
+        "pub fun greet(name) {\n    \"Hello, \" + name\n}\n",
+    );
+    ruchy_cmd()
+        .args(["publish", "--allow-dirty", "--registry"])
+        .arg(registry)
+        .current_dir(package.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(format!(
+            "Successfully published text-utils v{version}"
+        )));
+}
+
+#[test]
+fn test_publish_then_add_vendors_package() {
+    let registry = TempDir::new().unwrap();
+    publish_text_utils(registry.path(), "0.1.0");
+    publish_text_utils(registry.path(), "0.2.0");
+
+    let app = TempDir::new().unwrap();
+    write(
+        app.path(),
+        "Ruchy.toml",
+        "# The app\n[package]\nname = \"app\"\nversion = \"0.1.0\"\n",
+    );
+    write(
+        app.path(),
+        "src/main.ruchy",
+        "mod text_utils;\n\nfun main() {\n    println(text_utils::greet(\"registry\"))\n}\n",
+    );
+    ruchy_cmd()
+        .args(["add", "text-utils", "--registry"])
+        .arg(registry.path())
+        .current_dir(app.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("Added text-utils v0.2.0"));
+
+    let manifest = fs::read_to_string(app.path().join("Ruchy.toml")).unwrap();
+    assert!(manifest.starts_with("# The app\n"), "{manifest}");
+    assert!(
+        manifest.contains("[packages]\ntext-utils = \"^0.2.0\""),
+        "{manifest}"
+    );
+    let lock = fs::read_to_string(app.path().join("Ruchy.lock")).unwrap();
+    assert!(lock.contains("name = \"text-utils\""), "{lock}");
+    assert!(lock.contains("version = \"0.2.0\""), "{lock}");
+    assert!(app.path().join("vendor/text_utils/mod.ruchy").is_file());
+
+    ruchy_cmd()
+        .args(["run", "src/main.ruchy"])
+        .current_dir(app.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("Hello, registry"));
+}
+
+#[test]
+fn test_publish_rejects_republishing_a_version() {
+    let registry = TempDir::new().unwrap();
+    publish_text_utils(registry.path(), "1.0.0");
+
+    let package = TempDir::new().unwrap();
+    write(
+        package.path(),
+        "Ruchy.toml",
+        "[package]\nname = \"text-utils\"\nversion = \"1.0.0\"\n\
+         authors = [\"Ann\"]\ndescription = \"Text helpers\"\nlicense = \"MIT\"\n",
+    );
+    write(package.path(), "src/lib.ruchy", "pub fun other() { 2 }\n");
+    ruchy_cmd()
+        .args(["publish", "--allow-dirty", "--registry"])
+        .arg(registry.path())
+        .current_dir(package.path())
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("already published"));
+}
+
+#[test]
+fn test_add_reports_unknown_package() {
+    let registry = TempDir::new().unwrap();
+    let app = TempDir::new().unwrap();
+    write(
+        app.path(),
+        "Ruchy.toml",
+        "[package]\nname = \"app\"\nversion = \"0.1.0\"\n",
+    );
+    ruchy_cmd()
+        .args(["add", "missing-pkg", "--registry"])
+        .arg(registry.path())
+        .current_dir(app.path())
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("`missing-pkg` not found"));
+    assert!(!app.path().join("Ruchy.lock").exists());
+}
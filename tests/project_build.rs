@@ -0,0 +1,134 @@
+#![allow(missing_docs)]
+//! `ruchy build` in a directory with a `Ruchy.toml` compiles the multi-file
+//! project through a generated cargo package into `target/<profile>/<name>`.
+
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn ruchy_cmd() -> assert_cmd::Command {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+}
+
+fn write(dir: &TempDir, name: &str, contents: &str) {
+    let path = dir.path().join(name);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+#[test]
+fn test_build_multi_file_project() {
+    let dir = TempDir::new().unwrap();
+    write(
+        &dir,
+        "Ruchy.toml",
+        "[package]\nname = \"greeter\"\nversion = \"0.1.0\"\n",
+    );
+    write(
+        &dir,
+        "src/main.ruchy",
+        "mod greet;\n\nfun main() {\n    println(greet::hello(\"project\"))\n}\n",
+    );
+    write(
+        &dir,
+        "src/greet.ruchy",
+        "pub fun hello(name: String) -> String {\n    \"Hello, \" + name\n}\n",
+    );
+
+    ruchy_cmd()
+        .arg("build")
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("Build complete (debug mode)"));
+
+    let binary = dir
+        .path()
+        .join("target/debug")
+        .join(format!("greeter{}", std::env::consts::EXE_SUFFIX));
+    let output = Command::new(&binary).output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "Hello, project\n");
+    assert!(dir.path().join("target/ruchy/greeter/Cargo.toml").is_file());
+}
+
+#[test]
+fn test_build_rejects_invalid_manifest() {
+    let dir = TempDir::new().unwrap();
+    write(
+        &dir,
+        "Ruchy.toml",
+        "[package]\nname = \"bad name\"\nversion = \"0.1.0\"\n",
+    );
+
+    ruchy_cmd()
+        .arg("build")
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("Invalid package name"));
+}
+
+#[test]
+fn test_build_links_extern_rust_crate() {
+    let dir = TempDir::new().unwrap();
+    write(
+        &dir,
+        "Ruchy.toml",
+        "[package]\nname = \"shouter\"\nversion = \"0.1.0\"\n\n\
+         [dependencies]\ntextkit = { path = \"textkit\" }\n",
+    );
+    write(
+        &dir,
+        "textkit/Cargo.toml",
+        "[package]\nname = \"textkit\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[workspace]\n",
+    );
+    write(
+        &dir,
+        "textkit/src/lib.rs",
+        "pub fn shout(text: &str) -> String {\n    text.to_uppercase() + \"!\"\n}\n\n\
+         pub fn width(text: &str) -> usize {\n    text.chars().count()\n}\n",
+    );
+    write(
+        &dir,
+        "src/main.ruchy",
+        "extern \"rust\" textkit {\n    fun shout(text: &str) -> String\n    fun width(text: &str) -> usize\n}\n\n\
+         fun main() {\n    let name = \"ruchy\"\n    println(shout(name))\n    println(width(name) + 1)\n}\n",
+    );
+
+    ruchy_cmd()
+        .arg("build")
+        .current_dir(dir.path())
+        .assert()
+        .success();
+
+    let binary = dir
+        .path()
+        .join("target/debug")
+        .join(format!("shouter{}", std::env::consts::EXE_SUFFIX));
+    let output = Command::new(&binary).output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "RUCHY!\n6\n");
+}
+
+#[test]
+fn test_build_rejects_extern_crate_missing_from_dependencies() {
+    let dir = TempDir::new().unwrap();
+    write(
+        &dir,
+        "Ruchy.toml",
+        "[package]\nname = \"shouter\"\nversion = \"0.1.0\"\n",
+    );
+    write(
+        &dir,
+        "src/main.ruchy",
+        "extern \"rust\" textkit {\n    fun shout(text: &str) -> String\n}\n\nfun main() {\n    println(shout(\"hi\"))\n}\n",
+    );
+
+    ruchy_cmd()
+        .arg("build")
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("`textkit` is not a dependency"));
+}
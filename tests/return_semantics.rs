@@ -0,0 +1,182 @@
+#![allow(missing_docs)]
+//! `return` exits the innermost function, method, constructor or closure
+//! from any block-like construct, and implicit last-expression values mix
+//! with it the same way in the interpreter and in transpiled Rust.
+
+use std::fs;
+use tempfile::TempDir;
+
+fn ruchy_cmd() -> assert_cmd::Command {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+}
+
+fn run(program: &str, expected: &str) {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("returns.ruchy");
+    fs::write(&file, program).unwrap();
+    ruchy_cmd()
+        .arg("run")
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(expected.to_string());
+}
+
+#[test]
+fn test_return_from_loops() {
+    run(
+        r"
+fun find_for(values, target) {
+    for v in values {
+        if v == target { return 1 }
+    }
+    0
+}
+
+fun find_while(limit) {
+    let mut i = 0
+    while true {
+        if i == limit { return i * 10 }
+        i = i + 1
+    }
+    -1
+}
+
+fun find_loop() {
+    let mut n = 1
+    loop {
+        n = n * 2
+        if n > 50 { return n }
+    }
+}
+
+fun main() {
+    println(find_for([1, 2, 3], 2))
+    println(find_for([1, 2, 3], 9))
+    println(find_while(4))
+    println(find_loop())
+}
+",
+        "1\n0\n40\n64\n",
+    );
+}
+
+#[test]
+fn test_return_from_match_and_nested_blocks() {
+    run(
+        r#"
+fun describe(n) {
+    let label = match n {
+        0 => return "zero",
+        _ => "some",
+    }
+    {
+        {
+            if n > 100 { return "big" }
+        }
+    }
+    label
+}
+
+fun main() {
+    println(describe(0))
+    println(describe(5))
+    println(describe(500))
+}
+"#,
+        "zero\nsome\nbig\n",
+    );
+}
+
+#[test]
+fn test_return_in_closure_exits_only_the_closure() {
+    run(
+        r"
+fun clamp_all(values) {
+    let clamped = values.map(|v| {
+        if v > 10 { return 10 }
+        v
+    })
+    clamped.len()
+}
+
+fun main() {
+    let values = [3, 30, 7]
+    println(values.map(|v| { if v > 10 { return 10 }; v }))
+    println(clamp_all(values))
+}
+",
+        "[3, 10, 7]\n3\n",
+    );
+}
+
+#[test]
+fn test_return_from_try_runs_finally() {
+    run(
+        r#"
+fun guarded(n) {
+    try {
+        if n > 0 { return "positive" }
+        "other"
+    } catch (e) {
+        "error"
+    } finally {
+        println("cleanup")
+    }
+}
+
+fun main() {
+    println(guarded(1))
+    println(guarded(-1))
+}
+"#,
+        "cleanup\npositive\ncleanup\nother\n",
+    );
+}
+
+#[test]
+fn test_return_from_method_does_not_exit_caller() {
+    run(
+        r"
+class Counter {
+    count: i32
+
+    pub new(count: i32) {
+        self.count = count
+    }
+
+    pub fun sign(&self) {
+        if self.count < 0 { return -1 }
+        if self.count == 0 { return 0 }
+        1
+    }
+}
+
+fun main() {
+    let values = [Counter::new(-5), Counter::new(0), Counter::new(8)]
+    for c in values {
+        println(c.sign())
+    }
+    println(99)
+}
+",
+        "-1\n0\n1\n99\n",
+    );
+}
+
+#[test]
+fn test_transpiled_try_return_exits_function() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("try_return.ruchy");
+    fs::write(
+        &file,
+        "fun guarded(n: i32) -> i32 {\n    try {\n        if n > 0 { return 1 }\n        0\n    } catch (e) {\n        -1\n    }\n}\n\nfun main() {\n    println(guarded(2))\n}\n",
+    )
+    .unwrap();
+    ruchy_cmd()
+        .arg("transpile")
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("ControlFlow"));
+}
@@ -0,0 +1,134 @@
+#![allow(missing_docs)]
+//! `ruchy run --record` writes a trace of a script's inputs and output, and
+//! `ruchy replay` re-executes it with the same seed, clock reads and stdin.
+
+use predicates::prelude::*;
+use ruchy::runtime::replay_trace::{RunTrace, TraceEvent};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+fn ruchy_cmd() -> assert_cmd::Command {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+}
+
+const PROGRAM: &str = r"
+let start = timestamp()
+let roll = random()
+for line in io::records() {
+    println(line.to_uppercase())
+}
+println(start)
+println(roll)
+";
+
+fn record(dir: &TempDir, source: &str, stdin: &str) -> (PathBuf, String) {
+    let file = dir.path().join("program.ruchy");
+    fs::write(&file, source).unwrap();
+    let trace = dir.path().join("program.replay");
+    let output = ruchy_cmd()
+        .arg("run")
+        .arg("--record")
+        .arg(&trace)
+        .arg(&file)
+        .write_stdin(stdin)
+        .output()
+        .unwrap();
+    (trace, String::from_utf8(output.stdout).unwrap())
+}
+
+fn write_trace(path: &Path, trace: &RunTrace) {
+    let mut text = serde_json::to_string(&trace.header).unwrap();
+    for event in &trace.events {
+        text.push('\n');
+        text.push_str(&serde_json::to_string(event).unwrap());
+    }
+    fs::write(path, text).unwrap();
+}
+
+#[test]
+fn test_replay_reproduces_recorded_run() {
+    let dir = TempDir::new().unwrap();
+    let (trace, recorded) = record(&dir, PROGRAM, "ab\ncd\n");
+    assert!(recorded.starts_with("AB\nCD\n"), "{recorded}");
+
+    let trace_data = RunTrace::load(&trace).unwrap();
+    assert_eq!(trace_data.header.source, PROGRAM);
+    assert_eq!(trace_data.exit(), Some((0, None)));
+    assert!(trace_data
+        .events
+        .iter()
+        .any(|event| matches!(event, TraceEvent::Clock { .. })));
+
+    ruchy_cmd()
+        .arg("replay")
+        .arg(&trace)
+        .write_stdin("different\n")
+        .assert()
+        .success()
+        .stdout(recorded);
+}
+
+#[test]
+fn test_replay_reports_divergence() {
+    let dir = TempDir::new().unwrap();
+    let (trace, _) = record(&dir, PROGRAM, "ab\n");
+    let mut trace_data = RunTrace::load(&trace).unwrap();
+    for event in &mut trace_data.events {
+        if let TraceEvent::Output { text } = event {
+            if text == "AB\n" {
+                *text = "XY\n".to_string();
+            }
+        }
+    }
+    write_trace(&trace, &trace_data);
+
+    ruchy_cmd()
+        .arg("replay")
+        .arg(&trace)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Replay diverged"))
+        .stderr(predicate::str::contains("\"XY\\n\""));
+}
+
+#[test]
+fn test_replay_repeats_failure_status() {
+    let dir = TempDir::new().unwrap();
+    let (trace, recorded) = record(&dir, "println(\"before\")\nlet x = missing_value + 1\n", "");
+    assert_eq!(recorded, "before\n");
+    let (code, error) = RunTrace::load(&trace)
+        .unwrap()
+        .exit()
+        .map(|(code, error)| (code, error.map(str::to_string)))
+        .unwrap();
+    assert_eq!(code, 1);
+    assert!(error.is_some());
+
+    ruchy_cmd()
+        .arg("replay")
+        .arg(&trace)
+        .assert()
+        .code(1)
+        .stdout("before\n")
+        .stderr(predicate::str::contains("Replay diverged").not());
+}
+
+#[test]
+fn test_record_requires_ast_interpreter() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("program.ruchy");
+    fs::write(&file, "println(1)\n").unwrap();
+    ruchy_cmd()
+        .arg("--vm-mode")
+        .arg("bytecode")
+        .arg("run")
+        .arg("--record")
+        .arg(dir.path().join("program.replay"))
+        .arg(&file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--record needs the AST interpreter",
+        ));
+}
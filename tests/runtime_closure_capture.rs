@@ -0,0 +1,109 @@
+//! Closures capture variables by reference: mutations inside a closure are
+//! seen by the enclosing code and persist between calls, and the transpiled
+//! program prints the same as the interpreter
+
+use ruchy::backend::transpiler::Transpiler;
+use ruchy::frontend::parser::Parser;
+use std::process::Command;
+
+fn interpret(code: &str) -> String {
+    let output = assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(code)
+        .output()
+        .expect("Failed to run ruchy");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn compile_and_run(code: &str) -> String {
+    let ast = Parser::new(code).parse().expect("Parse failed");
+    let rust_code = Transpiler::new()
+        .transpile_to_program(&ast)
+        .expect("Transpilation failed")
+        .to_string();
+
+    let dir = tempfile::tempdir().expect("temp dir");
+    let source = dir.path().join("closures.rs");
+    let binary = dir.path().join("closures");
+    std::fs::write(&source, &rust_code).expect("Failed to write temp file");
+    let output = Command::new("rustc")
+        .arg("--edition=2021")
+        .arg(&source)
+        .arg("-o")
+        .arg(&binary)
+        .output()
+        .expect("Failed to execute rustc");
+    assert!(
+        output.status.success(),
+        "Rust:\n{rust_code}\n\nErrors:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let run = Command::new(&binary)
+        .output()
+        .expect("Failed to run binary");
+    String::from_utf8_lossy(&run.stdout).into_owned()
+}
+
+#[test]
+fn test_counter_closure_updates_captured_variable() {
+    let code = "let mut count = 0; let inc = || { count += 1 }; inc(); inc(); println(count)";
+    assert_eq!(interpret(code), "2\n");
+}
+
+#[test]
+fn test_counter_factory_keeps_count_between_calls() {
+    let code = "fun make_counter() { let mut c = 0; || { c += 1; c } }; let a = make_counter(); let b = make_counter(); a(); a(); b(); println(a()); println(b())";
+    assert_eq!(interpret(code), "3\n2\n");
+}
+
+#[test]
+fn test_accumulator_closure_sums_into_captured_total() {
+    let code = "let mut total = 0; let add = |n| { total += n }; for x in [1, 2, 3] { add(x) }; println(total)";
+    assert_eq!(interpret(code), "6\n");
+}
+
+#[test]
+fn test_closure_from_nested_block_sees_function_locals() {
+    let code = "fun make_stepper(flag) { let mut n = 0; if flag { let step = 5; || { n += step; n } } else { || n } }; let s = make_stepper(true); s(); println(s())";
+    assert_eq!(interpret(code), "10\n");
+}
+
+#[test]
+fn test_counter_closure_on_its_own_line_after_let() {
+    let code = "fun make_counter() {\n    let mut count = 0\n    || { count += 1; count }\n}\n\nfun main() {\n    let counter = make_counter()\n    counter()\n    println(counter())\n}\n\nmain()\n";
+    assert_eq!(interpret(code), "2\n");
+    assert_eq!(compile_and_run(code), "2\n");
+}
+
+#[test]
+fn test_closures_made_in_a_loop_capture_that_iteration() {
+    let code = "fun main() {\n    let mut fs = []\n    for i in 0..3 {\n        fs.push(|| i)\n    }\n    for f in fs {\n        println(f())\n    }\n}\n\nmain()\n";
+    assert_eq!(interpret(code), "0\n1\n2\n");
+    assert_eq!(compile_and_run(code), "0\n1\n2\n");
+}
+
+#[test]
+fn test_closure_sees_later_assignment() {
+    let code = "fun main() {\n    let mut x = 1\n    let get = || x\n    x = 5\n    println(get())\n}\n\nmain()\n";
+    assert_eq!(interpret(code), "5\n");
+    assert_eq!(compile_and_run(code), "5\n");
+}
+
+#[test]
+fn test_closure_and_block_share_counter() {
+    let code = "fun main() {\n    let mut count = 0\n    let inc = || { count += 1 }\n    inc()\n    println(count)\n    count += 10\n    inc()\n    println(count)\n}\n\nmain()\n";
+    assert_eq!(interpret(code), "1\n12\n");
+    assert_eq!(compile_and_run(code), "1\n12\n");
+}
+
+#[test]
+fn test_returned_closure_result_type_is_inferred() {
+    let code = "fun make_scaler() {\n    let factor = 1.5\n    |x: f64| x * factor\n}\n\nfun main() {\n    let scale = make_scaler()\n    println(scale(3.0))\n}\n\nmain()\n";
+    assert_eq!(interpret(code), "4.5\n");
+    assert_eq!(compile_and_run(code), "4.5\n");
+}
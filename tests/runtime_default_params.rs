@@ -100,3 +100,47 @@ fn test_default_param_examples_02_functions() {
         .stdout(predicate::str::contains("Hello, Mr. Smith!"))
         .stdout(predicate::str::contains("Hello, Dr. Johnson!"));
 }
+
+// ============================================================================
+// NAMED ARGUMENTS: `name: value` binds by parameter name
+// ============================================================================
+
+#[test]
+fn test_named_arg_with_default() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r#"fun greet(name, greeting = "Hello") { println(f"{greeting}, {name}!") }; greet(name: "Ana")"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Hello, Ana!"));
+}
+
+#[test]
+fn test_named_arg_skips_middle_default() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r#"fun connect(host, port = 8080, retries = 3) { println(f"{host}:{port} x{retries}") }; connect("db", retries: 5)"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("db:8080 x5"));
+}
+
+#[test]
+fn test_named_args_any_order() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r"fun sub(a, b) { println(a - b) }; sub(b: 1, a: 10)")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("9"));
+}
+
+#[test]
+fn test_named_arg_unknown_name_is_error() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r#"fun greet(name) { println(name) }; greet(nme: "Ana")"#)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown named argument 'nme'"));
+}
@@ -0,0 +1,44 @@
+//! `defer expr` runs `expr` when the enclosing scope exits, last deferred
+//! first, however the scope is left
+
+use predicates::prelude::*;
+
+#[test]
+fn test_deferred_expressions_run_last_first_after_body() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r#"fun main() { defer println("close file"); defer println("stop process"); println("work") }; main()"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("work\nstop process\nclose file"));
+}
+
+#[test]
+fn test_defer_runs_before_early_return_completes() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r#"fun check(n) { defer println(f"cleanup {n}"); if n > 0 { return "positive" }; "other" }; println(check(3))"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("cleanup 3\npositive"));
+}
+
+#[test]
+fn test_defer_runs_when_scope_fails() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r#"fun risky() { defer println("released"); throw "failed" }; try { risky() } catch e { println(f"caught {e}") }"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("released\ncaught failed"));
+}
+
+#[test]
+fn test_defer_in_loop_body_runs_each_iteration() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r#"for i in 0..2 { defer println(f"end {i}"); println(f"start {i}") }"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("start 0\nend 0\nstart 1\nend 1"));
+}
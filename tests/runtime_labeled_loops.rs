@@ -0,0 +1,44 @@
+//! Labeled loops: `'outer: for ... { break 'outer }` and `continue 'outer`
+//! leave or restart an enclosing loop, and undefined labels are rejected
+
+use predicates::prelude::*;
+
+#[test]
+fn test_break_outer_leaves_both_loops() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r"let mut found = (0, 0); 'outer: for i in 1..5 { for j in 1..5 { if i * j == 6 { found = (i, j); break 'outer } } }; println(found)")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("(2, 3)"));
+}
+
+#[test]
+fn test_continue_outer_skips_rest_of_inner_loop() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r"let mut count = 0; 'rows: for i in 0..3 { for j in 0..3 { if j > i { continue 'rows }; count += 1 } }; println(count)")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("6"));
+}
+
+#[test]
+fn test_labeled_break_leaves_while_let_and_loop() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r"let mut n = 0; let v = 'outer: loop { while let Some(x) = Some(n) { n += 1; if x == 3 { break 'outer x * 10 } } }; println(v)")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("30"));
+}
+
+#[test]
+fn test_undefined_label_is_an_error() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r"'outer: for i in 0..3 { for j in 0..3 { break 'inner } }")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Undefined loop label 'inner"));
+}
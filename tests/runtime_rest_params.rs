@@ -0,0 +1,64 @@
+//! Rest parameters (`fun log(...args)`) and spread arguments (`f(...list)`)
+
+use predicates::prelude::*;
+
+#[test]
+fn test_rest_param_collects_arguments() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r"fun count(...items) { println(items.len()) }; count(1, 2, 3)")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("3"));
+}
+
+#[test]
+fn test_rest_param_after_fixed_params() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r#"fun log_all(prefix, ...parts) { println(f"{prefix}: {parts.len()}") }; log_all("warn", "a", "b"); log_all("info")"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("warn: 2"))
+        .stdout(predicate::str::contains("info: 0"));
+}
+
+#[test]
+fn test_spread_fills_fixed_params() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r"fun add(a, b, c) { println(a + b + c) }; let xs = [2, 3]; add(1, ...xs)")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("6"));
+}
+
+#[test]
+fn test_spread_into_rest_param() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r"fun total(...nums) { let mut sum = 0; for n in nums { sum = sum + n }; println(sum) }; let xs = [1, 2, 3]; total(...xs, 4)")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("10"));
+}
+
+#[test]
+fn test_rest_param_must_be_last() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r"fun bad(...items, last) { items }")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("must be the last parameter"));
+}
+
+#[test]
+fn test_user_function_shadows_builtin_of_same_name() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r#"fun log(...args) { println(args.len()) }; log("a", "b"); log("x")"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::diff("2\n1\n"));
+}
@@ -0,0 +1,45 @@
+//! String patterns in `match`: `"prefix" ++ rest`, `name ++ ".suffix"` and
+//! `re"..."` regexes binding their named groups
+
+use predicates::prelude::*;
+
+#[test]
+fn test_prefix_pattern_binds_rest() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r#"let cmd = "git:status"; match cmd { "git:" ++ sub => println(f"git {sub}"), _ => println("other") }"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("git status"));
+}
+
+#[test]
+fn test_suffix_pattern_binds_stem() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r#"fun kind(file) { match file { stem ++ ".rs" => f"rust {stem}", stem ++ ".py" => f"python {stem}", _ => "unknown" } }; println(kind("main.rs")); println(kind("notes.txt"))"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("rust main"))
+        .stdout(predicate::str::contains("unknown"));
+}
+
+#[test]
+fn test_regex_pattern_binds_named_groups() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r#"let date = "2024-05-17"; match date { re"(?P<year>\d{4})-(?P<month>\d{2})-\d{2}" => println(f"{month}/{year}"), _ => println("no date") }"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("05/2024"));
+}
+
+#[test]
+fn test_regex_pattern_must_match_whole_string() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(r#"match "id 42" { re"\d+" => println("number"), _ => println("text") }"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("text"));
+}
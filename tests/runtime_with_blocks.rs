@@ -0,0 +1,65 @@
+//! Resource blocks: `with r = open(...) { ... }` (or `using`) calls
+//! `r.close()` when the block exits, including when it fails
+
+use predicates::prelude::*;
+
+/// A resource that reports when it is closed
+const RESOURCE: &str = r#"
+struct Res { name: String }
+impl Res {
+    fun close(&self) { println(f"closed {self.name}") }
+}
+fun connect(name) { Res { name: name } }
+"#;
+
+#[test]
+fn test_with_block_closes_after_body() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(format!(
+            r#"{RESOURCE} let n = with r = connect("db") {{ println(f"using {{r.name}}"); 42 }}; println(n)"#
+        ))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("using db\nclosed db\n42"));
+}
+
+#[test]
+fn test_with_block_closes_on_error_and_error_reaches_catch() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(format!(
+            r#"{RESOURCE} try {{ with r = connect("db") {{ throw "boom" }} }} catch e {{ println(f"caught {{e}}") }}"#
+        ))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("closed db\ncaught boom"));
+}
+
+#[test]
+fn test_using_closes_resources_last_opened_first() {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(format!(
+            r#"{RESOURCE} using a = connect("src"), b = connect("dst") {{ println("copy") }}"#
+        ))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("copy\nclosed dst\nclosed src"));
+}
+
+#[test]
+fn test_with_block_closes_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("data.txt");
+    std::fs::write(&path, "hello").unwrap();
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("-e")
+        .arg(format!(
+            r#"let file = File.open("{}"); with f = file {{ println(f.read()) }}; println(file.closed)"#,
+            path.display()
+        ))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("hello\ntrue"));
+}
@@ -2936,11 +2936,11 @@ fn test_sqlite_569_labeled_for() {
 }
 #[test]
 fn test_sqlite_570_break_label() {
-    assert_parses("break 'outer");
+    assert_parses("'outer: loop { break 'outer }");
 }
 #[test]
 fn test_sqlite_571_continue_label() {
-    assert_parses("continue 'outer");
+    assert_parses("'outer: loop { continue 'outer }");
 }
 
 // ============================================================================
@@ -3278,7 +3278,7 @@ fn test_sqlite_634_break_value() {
 }
 #[test]
 fn test_sqlite_635_break_label_value() {
-    assert_parses("break 'outer 42");
+    assert_parses("'outer: loop { break 'outer 42 }");
 }
 #[test]
 fn test_sqlite_636_implicit_return() {
@@ -3333,7 +3333,6 @@ fn test_sqlite_644_try_in_expr() {
 fn test_sqlite_645_try_field_access() {
     assert_parses("obj?.field");
 }
-#[ignore = "Parser limitation: Try operator with indexing not supported - needs [PARSER-214] ticket"]
 #[test]
 fn test_sqlite_646_try_index() {
     assert_parses("arr?[0]");
@@ -5590,11 +5589,11 @@ fn test_sqlite_1094_labeled_for() {
 }
 #[test]
 fn test_sqlite_1095_break_label() {
-    assert_parses("loop { break 'outer; }");
+    assert_parses("'outer: loop { loop { break 'outer; } }");
 }
 #[test]
 fn test_sqlite_1096_continue_label() {
-    assert_parses("loop { continue 'outer; }");
+    assert_parses("'outer: loop { loop { continue 'outer; } }");
 }
 
 // Category 169: Literal Patterns Extended
@@ -402,12 +402,16 @@ fn test_sqlite_061_function_too_few_args() {
 /// Test calling undefined function
 #[test]
 fn test_sqlite_062_undefined_function() {
-    // Note: Ruchy treats undefined identifiers followed by () as message constructors
-    // This is a design decision, not an error. The test verifies no panic occurs.
-    let result = execute_program("undefined_function()");
+    // Note: only capitalized undefined identifiers followed by () are message
+    // constructors; a lowercase one is a misspelled or missing function
+    assert_runtime_error(
+        "undefined_function()",
+        &["Undefined variable", "undefined_function"],
+    );
+    let result = execute_program("UndefinedMessage()");
     assert!(
         result.is_ok(),
-        "Undefined function call should not panic (message constructor behavior)"
+        "Capitalized undefined call should construct a message: {result:?}"
     );
 }
 
@@ -461,7 +465,6 @@ fn test_sqlite_072_return_outside_function() {
 
 /// Test nested break with wrong label
 #[test]
-#[ignore = "Runtime limitation: labeled break validation not implemented - needs [RUNTIME-005] ticket"]
 fn test_sqlite_073_break_wrong_label() {
     assert_runtime_error(
         r"
@@ -1664,7 +1667,6 @@ fn test_sqlite_1256_iterator_custom() {
 }
 
 #[test]
-#[ignore = "Runtime limitation: iterator map not implemented - needs [RUNTIME-1110] ticket"]
 fn test_sqlite_1257_iterator_map() {
     let result = execute_program(
         r"
@@ -1676,7 +1678,6 @@ fn test_sqlite_1257_iterator_map() {
 }
 
 #[test]
-#[ignore = "Runtime limitation: iterator filter not implemented - needs [RUNTIME-1111] ticket"]
 fn test_sqlite_1258_iterator_filter() {
     let result = execute_program(
         r"
@@ -1688,7 +1689,6 @@ fn test_sqlite_1258_iterator_filter() {
 }
 
 #[test]
-#[ignore = "Runtime limitation: iterator fold not implemented - needs [RUNTIME-1112] ticket"]
 fn test_sqlite_1259_iterator_fold() {
     let result = execute_program(
         r"
@@ -3434,7 +3434,6 @@ fn test_sqlite_1370_slice_iter() {
 // =============================================================================
 
 #[test]
-#[ignore = "Runtime limitation: iter map not implemented - needs [RUNTIME-1224] ticket"]
 fn test_sqlite_1371_iter_map() {
     let result = execute_program(
         r"
@@ -3446,7 +3445,6 @@ fn test_sqlite_1371_iter_map() {
 }
 
 #[test]
-#[ignore = "Runtime limitation: iter filter not implemented - needs [RUNTIME-1225] ticket"]
 fn test_sqlite_1372_iter_filter() {
     let result = execute_program(
         r"
@@ -3458,7 +3456,6 @@ fn test_sqlite_1372_iter_filter() {
 }
 
 #[test]
-#[ignore = "Runtime limitation: iter fold not implemented - needs [RUNTIME-1226] ticket"]
 fn test_sqlite_1373_iter_fold() {
     let result = execute_program(
         r"
@@ -4611,7 +4608,6 @@ fn test_sqlite_1030_drop_vec() {
 
 // Category 199: Iterator Combinators Runtime
 #[test]
-#[ignore = "Runtime limitation: iterator map runtime not implemented - needs [RUNTIME-884] ticket"]
 fn test_sqlite_1031_iter_map_runtime() {
     let result = execute_program(
         r"
@@ -4623,7 +4619,6 @@ fn test_sqlite_1031_iter_map_runtime() {
 }
 
 #[test]
-#[ignore = "Runtime limitation: iterator filter runtime not implemented - needs [RUNTIME-885] ticket"]
 fn test_sqlite_1032_iter_filter_runtime() {
     let result = execute_program(
         r"
@@ -4635,7 +4630,6 @@ fn test_sqlite_1032_iter_filter_runtime() {
 }
 
 #[test]
-#[ignore = "Runtime limitation: iterator fold runtime not implemented - needs [RUNTIME-886] ticket"]
 fn test_sqlite_1033_iter_fold_runtime() {
     let result = execute_program(
         r"
@@ -6132,7 +6126,6 @@ fn test_sqlite_209_deeply_nested_control() {
 
 /// Test labeled break with invalid label
 #[test]
-#[ignore = "Runtime limitation: labeled break validation - needs [RUNTIME-066] ticket"]
 fn test_sqlite_210_invalid_loop_label() {
     let result = execute_program(
         r"
@@ -12159,7 +12152,6 @@ fn test_sqlite_585_raw_ptr_conv() {
 
 /// Test zero-cost abstraction
 #[test]
-#[ignore = "Runtime limitation: zero-cost abstraction verification not implemented - needs [RUNTIME-439] ticket"]
 fn test_sqlite_586_zero_cost() {
     let result = execute_program(
         r"
@@ -12219,7 +12211,6 @@ fn test_sqlite_589_simd() {
 
 /// Test lazy evaluation
 #[test]
-#[ignore = "Runtime limitation: lazy evaluation not implemented - needs [RUNTIME-443] ticket"]
 fn test_sqlite_590_lazy_eval() {
     let result = execute_program(
         r"
@@ -13683,7 +13674,6 @@ fn test_sqlite_690_tail_call() {
 // Category 131: Iterator Runtime Advanced
 /// Test iterator map
 #[test]
-#[ignore = "Runtime limitation: iterator map not implemented - needs [RUNTIME-544] ticket"]
 fn test_sqlite_691_iter_map() {
     let result = execute_program(
         r"
@@ -13696,7 +13686,6 @@ fn test_sqlite_691_iter_map() {
 
 /// Test iterator filter
 #[test]
-#[ignore = "Runtime limitation: iterator filter not implemented - needs [RUNTIME-545] ticket"]
 fn test_sqlite_692_iter_filter() {
     let result = execute_program(
         r"
@@ -13709,7 +13698,6 @@ fn test_sqlite_692_iter_filter() {
 
 /// Test iterator fold
 #[test]
-#[ignore = "Runtime limitation: iterator fold not implemented - needs [RUNTIME-546] ticket"]
 fn test_sqlite_693_iter_fold() {
     let result = execute_program(
         r"
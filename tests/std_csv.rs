@@ -0,0 +1,88 @@
+#![allow(missing_docs)]
+//! `std::csv::read` / `std::csv::write`: CSV files as DataFrames or arrays of
+//! objects, with delimiter and header options, transpiled to the csv crate.
+
+use ruchy::runtime::interpreter::{Interpreter, Value};
+use ruchy::{compile, Parser};
+use std::fs;
+use tempfile::TempDir;
+
+fn eval(source: &str) -> Value {
+    let ast = Parser::new(source).parse().expect("source should parse");
+    Interpreter::new()
+        .eval_expr(&ast)
+        .unwrap_or_else(|e| panic!("{source:?} should evaluate: {e:?}"))
+}
+
+fn file(dir: &TempDir, name: &str, contents: &str) -> String {
+    let path = dir.path().join(name);
+    fs::write(&path, contents).unwrap();
+    path.display().to_string()
+}
+
+#[test]
+fn test_read_returns_dataframe_with_quoted_fields() {
+    let dir = TempDir::new().unwrap();
+    let path = file(
+        &dir,
+        "people.csv",
+        "name,city,age\n\"Smith, Ann\",Oslo,31\nBob,\"Rio \"\"RJ\"\"\",\n",
+    );
+    let read = |expr: &str| eval(&format!("let df = std::csv::read(\"{path}\")\n{expr}"));
+    assert_eq!(read("df.rows()"), Value::Integer(2));
+    assert_eq!(read("df.columns()"), Value::Integer(3));
+    assert_eq!(
+        read("df.get(\"name\", 0)"),
+        Value::from_string("Smith, Ann".to_string())
+    );
+    assert_eq!(
+        read("df.get(\"city\", 1)"),
+        Value::from_string("Rio \"RJ\"".to_string())
+    );
+    assert_eq!(read("df.get(\"age\", 0)"), Value::Integer(31));
+    assert_eq!(read("df.get(\"age\", 1)"), Value::Nil);
+}
+
+#[test]
+fn test_read_records_with_delimiter_and_no_header() {
+    let dir = TempDir::new().unwrap();
+    let path = file(&dir, "data.tsv", "a\t1\nb\t2\n");
+    let source = format!(
+        r#"let rows = std::csv::read("{path}", {{"delimiter": "\t", "header": false, "records": true}})
+rows.map(|r| r.column_1 + "=" + r.column_2.to_string()).join(",")"#
+    );
+    assert_eq!(eval(&source), Value::from_string("a=1,b=2".to_string()));
+}
+
+#[test]
+fn test_write_records_and_rows() {
+    let dir = TempDir::new().unwrap();
+    let out = dir.path().join("out.csv").display().to_string();
+    let raw = dir.path().join("raw.csv").display().to_string();
+    let source = format!(
+        r#"std::csv::write("{out}", [{{"name": "ann", "note": "a;b"}}, {{"name": "bob", "note": nil}}], {{"delimiter": ";"}})
+std::csv::write("{raw}", [[1, "x"], [2, "y"]])
+std::csv::read("{out}", {{"delimiter": ";", "records": true}})[0].note"#
+    );
+    assert_eq!(eval(&source), Value::from_string("a;b".to_string()));
+    assert_eq!(
+        fs::read_to_string(&out).unwrap(),
+        "name;note\nann;\"a;b\"\nbob;\n"
+    );
+    assert_eq!(fs::read_to_string(&raw).unwrap(), "1,x\n2,y\n");
+}
+
+#[test]
+fn test_csv_calls_transpile_to_csv_crate() {
+    let rust = compile(
+        r#"fun main() {
+    let rows = std::csv::read("in.csv", {"delimiter": ";"})
+    std::csv::write("out.csv", [["a", "b"]])
+    println!("{}", rows.len())
+}"#,
+    )
+    .unwrap();
+    assert!(rust.contains("csv :: ReaderBuilder"), "{rust}");
+    assert!(rust.contains("delimiter (59u8)"), "{rust}");
+    assert!(rust.contains("csv :: WriterBuilder"), "{rust}");
+}
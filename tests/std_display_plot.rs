@@ -0,0 +1,79 @@
+#![allow(missing_docs)]
+//! `std::display` and `std::plot` return MIME-typed display objects that the
+//! notebook renders as HTML, markdown, SVG or PNG.
+
+use ruchy::runtime::eval_rich_output::{
+    display_data, IMAGE_PNG, IMAGE_SVG, TEXT_HTML, TEXT_MARKDOWN,
+};
+use ruchy::runtime::interpreter::{Interpreter, Value};
+use ruchy::Parser;
+use std::fs;
+use tempfile::TempDir;
+
+fn eval(source: &str) -> Result<Value, String> {
+    let ast = Parser::new(source).parse().expect("source should parse");
+    Interpreter::new()
+        .eval_expr(&ast)
+        .map_err(|e| e.to_string())
+}
+
+fn display(source: &str) -> (String, String) {
+    let value = eval(source).unwrap_or_else(|e| panic!("{source:?} should evaluate: {e}"));
+    let (mime, data) = display_data(&value)
+        .unwrap_or_else(|| panic!("{source:?} should be a display object, got {value}"));
+    (mime.to_string(), data.to_string())
+}
+
+#[test]
+fn test_display_text_types() {
+    assert_eq!(
+        display("std::display::html(\"<b>x</b>\")"),
+        (TEXT_HTML.to_string(), "<b>x</b>".to_string())
+    );
+    assert_eq!(
+        display("std::display::markdown(\"# Title\")").0,
+        TEXT_MARKDOWN
+    );
+    assert_eq!(display("std::display::svg(\"<svg/>\")").0, IMAGE_SVG);
+    // Outside a notebook, display objects print as their text
+    assert_eq!(
+        eval("std::display::markdown(\"# Title\")")
+            .unwrap()
+            .to_string(),
+        "# Title"
+    );
+}
+
+#[test]
+fn test_display_png_from_file() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("pixel.png");
+    fs::write(&path, b"\x89PNG\r\n\x1a\nrest").unwrap();
+    let (mime, data) = display(&format!("std::display::png(\"{}\")", path.display()));
+    assert_eq!(
+        (mime.as_str(), data.as_str()),
+        (IMAGE_PNG, "iVBORw0KGgpyZXN0")
+    );
+
+    fs::write(&path, b"not a png").unwrap();
+    let err = eval(&format!("std::display::png(\"{}\")", path.display())).unwrap_err();
+    assert!(err.contains("not a PNG image"), "{err}");
+}
+
+#[test]
+fn test_plots_render_svg() {
+    let (mime, svg) = display("std::plot::line([1, 2, 3], [2, 4, 8], {\"title\": \"Growth\"})");
+    assert_eq!(mime, IMAGE_SVG);
+    assert!(svg.contains("<polyline"), "{svg}");
+    assert!(svg.contains(">Growth</text>"), "{svg}");
+
+    let (_, svg) = display("std::plot::scatter([1, 2], [3.5, 1.0])");
+    assert_eq!(svg.matches("<circle").count(), 2);
+
+    let (_, svg) = display("std::plot::bar([\"a\", \"b\", \"c\"], [3, 1, 2], {\"width\": 600})");
+    assert!(svg.contains("width=\"600\""), "{svg}");
+    assert_eq!(svg.matches("<rect").count(), 3);
+
+    let err = eval("std::plot::bar([\"a\"], [1, 2])").unwrap_err();
+    assert!(err.contains("differ in length"), "{err}");
+}
@@ -0,0 +1,76 @@
+#![allow(missing_docs)]
+//! String methods common in TypeScript ports (`pad_start`, `pad_end`,
+//! `replace_all`, `find`, `repeat`, ...) and the `format()` free function work
+//! in the interpreter and transpile to equivalent Rust.
+
+use ruchy::runtime::interpreter::{Interpreter, Value};
+use ruchy::{compile, Parser};
+
+fn eval(source: &str) -> Value {
+    let ast = Parser::new(source).parse().expect("source should parse");
+    Interpreter::new()
+        .eval_expr(&ast)
+        .unwrap_or_else(|e| panic!("{source:?} should evaluate: {e:?}"))
+}
+
+fn string(s: &str) -> Value {
+    Value::from_string(s.to_string())
+}
+
+#[test]
+fn test_pad_start_and_pad_end() {
+    assert_eq!(eval(r#""7".pad_start(3, "0")"#), string("007"));
+    assert_eq!(eval(r#""ab".pad_end(4)"#), string("ab  "));
+    assert_eq!(eval(r#""abc".pad_start(6, "12")"#), string("121abc"));
+    assert_eq!(eval(r#""long".pad_start(2, "*")"#), string("long"));
+}
+
+#[test]
+fn test_replace_and_replace_all() {
+    assert_eq!(eval(r#""a-b-c".replace_all("-", "+")"#), string("a+b+c"));
+    assert_eq!(eval(r#""a-b-c".replace("-", "")"#), string("abc"));
+}
+
+#[test]
+fn test_find_returns_option() {
+    assert_eq!(eval(r#""hello".find("ll").unwrap()"#), Value::Integer(2));
+    assert_eq!(eval(r#""hello".find("z").is_none()"#), Value::Bool(true));
+}
+
+#[test]
+fn test_search_predicates_chars_bytes_and_repeat() {
+    assert_eq!(eval(r#""hello".contains("ell")"#), Value::Bool(true));
+    assert_eq!(eval(r#""hello".starts_with("he")"#), Value::Bool(true));
+    assert_eq!(eval(r#""hello".ends_with("lo")"#), Value::Bool(true));
+    assert_eq!(eval(r#""héllo".chars().len()"#), Value::Integer(5));
+    assert_eq!(eval(r#""AB".bytes()[1]"#), Value::Integer(66));
+    assert_eq!(eval(r#""ab".repeat(3)"#), string("ababab"));
+}
+
+#[test]
+fn test_format_function_with_positional_placeholders() {
+    assert_eq!(
+        eval(r#"format("{} has {} items", "cart", 3)"#),
+        string("cart has 3 items")
+    );
+    assert_eq!(
+        eval(r#"format("{1}, {0}! {1}", "world", "hello")"#),
+        string("hello, world! hello")
+    );
+    assert_eq!(eval(r#""{0}{0}".format("ab")"#), string("abab"));
+}
+
+#[test]
+fn test_transpiles_to_rust_string_operations() {
+    let pad = compile(r#"fun f(s: String) -> String { s.pad_start(5, "0") }"#).unwrap();
+    assert!(pad.contains("cycle"), "{pad}");
+
+    let replace_all = compile(r#"fun f(s: String) -> String { s.replace_all("a", "b") }"#).unwrap();
+    assert!(replace_all.contains(". replace ("), "{replace_all}");
+
+    let repeat = compile(r"fun f(s: String, n: i32) -> String { s.repeat(n) }").unwrap();
+    assert!(repeat.contains("as usize"), "{repeat}");
+
+    let format = compile(r#"fun f(name: String) -> String { format("hi {0}", name) }"#).unwrap();
+    assert!(format.contains("format !"), "{format}");
+}
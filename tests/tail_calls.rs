@@ -0,0 +1,32 @@
+#![allow(missing_docs)]
+//! Self-tail-calls run as loops in the interpreter, so tail-recursive
+//! functions don't exhaust the stack or the recursion limit.
+
+use ruchy::runtime::interpreter::{Interpreter, Value};
+use ruchy::Parser;
+
+fn eval(source: &str) -> Value {
+    let ast = Parser::new(source).parse().expect("source should parse");
+    Interpreter::new()
+        .eval_expr(&ast)
+        .unwrap_or_else(|e| panic!("{source:?} should evaluate: {e:?}"))
+}
+
+#[test]
+fn test_million_deep_tail_recursion_completes() {
+    let source = "fun sum_to(n, acc) {
+    if n == 0 { acc } else { sum_to(n - 1, acc + n) }
+}
+sum_to(1000000, 0)";
+    assert_eq!(eval(source), Value::Integer(500_000_500_000));
+}
+
+#[test]
+fn test_tail_call_after_early_return_statement() {
+    let source = "fun repeat(s, n, out) {
+    if n == 0 { return out }
+    repeat(s, n - 1, out + s)
+}
+repeat(\"ab\", 3, \"\")";
+    assert_eq!(eval(source), Value::from_string("ababab".to_string()));
+}
@@ -0,0 +1,59 @@
+//! Transpiled `defer` runs from a drop guard, on early returns too, without
+//! holding a borrow across the rest of its block
+
+use ruchy::backend::transpiler::Transpiler;
+use ruchy::frontend::parser::Parser;
+use std::process::Command;
+
+#[test]
+fn test_transpiled_defer_runs_on_early_return_and_compiles_with_used_values() {
+    let code = r#"
+fun work(fail: bool) -> i32 {
+    defer println("cleanup")
+    if fail { return 1 }
+    2
+}
+
+fun main() {
+    let id = 7
+    defer println(id)
+    let mut count = 0
+    defer println(count)
+    count += 1
+    println(work(true))
+    println(work(false))
+    println(count)
+}
+"#;
+    let ast = Parser::new(code).parse().expect("Parse failed");
+    let rust_code = Transpiler::new()
+        .transpile_to_program(&ast)
+        .expect("Transpilation failed")
+        .to_string();
+
+    let dir = tempfile::tempdir().expect("temp dir");
+    let source = dir.path().join("defer.rs");
+    let binary = dir.path().join("defer");
+    std::fs::write(&source, &rust_code).expect("Failed to write temp file");
+    let output = Command::new("rustc")
+        .arg("--edition=2021")
+        .arg(&source)
+        .arg("-o")
+        .arg(&binary)
+        .output()
+        .expect("Failed to execute rustc");
+    assert!(
+        output.status.success(),
+        "Rust:\n{rust_code}\n\nErrors:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let run = Command::new(&binary)
+        .output()
+        .expect("Failed to run binary");
+    // The guard keeps `count` as it was at the `defer`
+    assert_eq!(
+        String::from_utf8_lossy(&run.stdout),
+        "cleanup\n1\ncleanup\n2\n1\n0\n7\n"
+    );
+}
@@ -23,6 +23,8 @@ fn test_pub_fun_not_inlined_simple() {
                     span: Span::default(),
                     is_mutable: false,
                     default_value: None,
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
                 Param {
                     pattern: Pattern::Identifier("y".to_string()),
@@ -33,6 +35,8 @@ fn test_pub_fun_not_inlined_simple() {
                     span: Span::default(),
                     is_mutable: false,
                     default_value: None,
+                    leading_comments: Vec::new(),
+                    trailing_comment: None,
                 },
             ],
             return_type: Some(Type {
@@ -101,6 +105,8 @@ fn test_private_fun_still_inlined() {
                 span: Span::default(),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             }],
             return_type: Some(Type {
                 kind: TypeKind::Named("i32".to_string()),
@@ -198,6 +204,8 @@ fn test_pub_fun_library_crate() {
                 span: Span::default(),
                 is_mutable: false,
                 default_value: None,
+                leading_comments: Vec::new(),
+                trailing_comment: None,
             }],
             return_type: Some(Type {
                 kind: TypeKind::Named("String".to_string()),
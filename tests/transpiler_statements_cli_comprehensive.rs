@@ -745,8 +745,9 @@ fn edge_case_loop_labels() {
         .success();
 
     let output = String::from_utf8_lossy(&result.get_output().stdout);
-    // Transpiler generates "break outer;" - verify label name appears
-    assert!(output.contains("outer") && output.contains("break"));
+    // Labels are emitted as Rust loop labels
+    assert!(output.contains("'outer: for"), "{output}");
+    assert!(output.contains("break 'outer"), "{output}");
 }
 
 #[test]
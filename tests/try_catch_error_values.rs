@@ -0,0 +1,216 @@
+#![allow(missing_docs)]
+//! try/catch/finally end to end: runtime failures are caught as error values
+//! carrying a message and stack trace, user-defined error types are thrown
+//! and caught by type, and everything transpiles to Result-based Rust.
+
+use ruchy::runtime::interpreter::{Interpreter, InterpreterError, Value};
+use ruchy::{compile, Parser};
+
+fn eval(source: &str) -> Result<Value, InterpreterError> {
+    let ast = Parser::new(source).parse().expect("source should parse");
+    Interpreter::new().eval_expr(&ast)
+}
+
+fn string(s: &str) -> Value {
+    Value::from_string(s.to_string())
+}
+
+#[test]
+fn test_runtime_error_is_caught_as_error_value() {
+    let value = eval("try { missing_value + 1 } catch e { e }").unwrap();
+    let Value::Error { kind, message, .. } = value else {
+        panic!("expected an error value, got {value:?}");
+    };
+    assert_eq!(kind, "RuntimeError");
+    assert!(message.contains("missing_value"), "{message}");
+}
+
+#[test]
+fn test_error_fields_are_accessible() {
+    assert_eq!(
+        eval("try { missing_value } catch e { e.kind }").unwrap(),
+        string("RuntimeError")
+    );
+    let message = eval("try { missing_value } catch e { e.message }").unwrap();
+    assert!(message.to_string().contains("missing_value"), "{message}");
+}
+
+#[test]
+fn test_stack_trace_lists_calls_innermost_first() {
+    let source = r"
+        fun inner() { missing_value }
+        fun outer() { inner() }
+        try { outer() } catch e { e.stack }
+    ";
+    assert_eq!(
+        eval(source).unwrap(),
+        Value::from_array(vec![string("inner"), string("outer")])
+    );
+}
+
+#[test]
+fn test_thrown_value_is_caught_unchanged() {
+    assert_eq!(
+        eval(r#"try { throw "boom" } catch e { e }"#).unwrap(),
+        string("boom")
+    );
+}
+
+#[test]
+fn test_finally_runs_on_success_and_failure() {
+    let source = r#"
+        let mut log = 0
+        let a = try { 1 } catch e { 0 } finally { log = log + 10 }
+        let b = try { throw "boom" } catch e { 2 } finally { log = log + 100 }
+        log + a + b
+    "#;
+    assert_eq!(eval(source).unwrap(), Value::Integer(113));
+}
+
+#[test]
+fn test_try_finally_without_catch_keeps_error() {
+    let source = r#"
+        let mut cleaned = false
+        let outcome = try {
+            try { missing_value } finally { cleaned = true }
+        } catch e { e.kind }
+        f"{outcome} {cleaned}"
+    "#;
+    assert_eq!(eval(source).unwrap(), string("RuntimeError true"));
+    // The error is not turned into a thrown value on the way through
+    let err = eval("try { missing_value } finally { 1 }").unwrap_err();
+    assert!(!matches!(err, InterpreterError::Throw(_)), "{err:?}");
+}
+
+#[test]
+fn test_return_passes_through_try() {
+    let source = r"
+        fun early() {
+            try { return 7 } catch e { 0 }
+            1
+        }
+        early()
+    ";
+    assert_eq!(eval(source).unwrap(), Value::Integer(7));
+}
+
+#[test]
+fn test_rethrown_error_reports_stack() {
+    let source = r"
+        fun guarded() {
+            try { missing_value } catch e { throw e }
+        }
+        guarded()
+    ";
+    let message = eval(source).unwrap_err().to_string();
+    assert!(message.starts_with("Uncaught RuntimeError:"), "{message}");
+    assert!(message.contains("at guarded"), "{message}");
+}
+
+const ERROR_TYPES: &str = r"
+    struct NotFound { path: String }
+    struct Denied { user: String }
+    enum AppError {
+        Io(String),
+        Parse(i32)
+    }
+";
+
+#[test]
+fn test_struct_error_is_caught_by_type() {
+    let source = format!(
+        r#"{ERROR_TYPES}
+        fun load(p) {{ throw NotFound {{ path: p }} }}
+        try {{ load("a.txt") }} catch (e: Denied) {{ e.user }} catch (e: NotFound) {{ e.path }}
+        "#
+    );
+    assert_eq!(eval(&source).unwrap(), string("a.txt"));
+}
+
+#[test]
+fn test_enum_error_variants_are_destructured() {
+    let source = format!(
+        r#"{ERROR_TYPES}
+        try {{ throw AppError::Parse(3) }}
+        catch (AppError::Io(msg)) {{ 0 }}
+        catch (AppError::Parse(line)) {{ line }}
+        "#
+    );
+    assert_eq!(eval(&source).unwrap(), Value::Integer(3));
+}
+
+#[test]
+fn test_enum_type_catches_every_variant() {
+    let source = format!(
+        r#"{ERROR_TYPES}
+        try {{ throw AppError::Io("disk") }} catch e: AppError {{ 1 }} catch e {{ 2 }}
+        "#
+    );
+    assert_eq!(eval(&source).unwrap(), Value::Integer(1));
+}
+
+#[test]
+fn test_unmatched_error_type_propagates() {
+    let source = format!(
+        r#"{ERROR_TYPES}
+        try {{ throw NotFound {{ path: "x" }} }} catch (e: Denied) {{ 0 }}
+        "#
+    );
+    assert!(matches!(
+        eval(&source),
+        Err(InterpreterError::Throw(Value::Struct { .. }))
+    ));
+}
+
+#[test]
+fn test_runtime_error_kind_is_catchable_by_type() {
+    let message = eval(
+        "try { missing_value } catch (e: TypeError) { 0 } catch (RuntimeError { message }) { message }",
+    )
+    .unwrap();
+    assert!(message.to_string().contains("missing_value"), "{message}");
+}
+
+#[test]
+fn test_try_catch_transpiles_to_result() {
+    let rust = compile(
+        r#"
+        fun risky(fail) {
+            try {
+                if fail { throw "bad input" }
+                42
+            } catch e {
+                println(e)
+                0
+            } finally {
+                println("done")
+            }
+        }
+    "#,
+    )
+    .unwrap();
+    assert!(rust.contains("catch_unwind"), "{rust}");
+    assert!(rust.contains("return Err"), "{rust}");
+    assert!(rust.contains("let e : String"), "{rust}");
+}
+
+#[test]
+fn test_typed_catch_transpiles_to_downcast() {
+    let rust = compile(&format!(
+        r#"{ERROR_TYPES}
+        fun run() {{
+            try {{
+                throw NotFound {{ path: "x" }}
+            }} catch (e: NotFound) {{
+                println(e.path)
+            }} catch (AppError::Io(msg)) {{
+                println(msg)
+            }}
+        }}
+        "#
+    ))
+    .unwrap();
+    assert!(rust.contains("downcast :: < NotFound >"), "{rust}");
+    assert!(rust.contains("downcast :: < AppError >"), "{rust}");
+    assert!(rust.contains("resume_unwind"), "{rust}");
+}
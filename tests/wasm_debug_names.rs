@@ -0,0 +1,43 @@
+#![allow(missing_docs)]
+//! `ruchy wasm --debug` adds a `name` section with Ruchy function names.
+
+use ruchy::backend::wasm::metadata::custom_sections;
+use std::fs;
+use tempfile::TempDir;
+
+fn compile(extra: &[&str]) -> Vec<u8> {
+    let dir = TempDir::new().unwrap();
+    let source = dir.path().join("prog.ruchy");
+    let output = dir.path().join("prog.wasm");
+    fs::write(&source, "fun square(n) { n * n }\nsquare(4)\n").unwrap();
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("wasm")
+        .arg(&source)
+        .arg("--output")
+        .arg(&output)
+        .args(extra)
+        .assert()
+        .success();
+    fs::read(&output).unwrap()
+}
+
+fn name_section(wasm: &[u8]) -> Option<Vec<u8>> {
+    custom_sections(wasm)
+        .unwrap()
+        .into_iter()
+        .find(|(name, _)| name == "name")
+        .map(|(_, data)| data.to_vec())
+}
+
+#[test]
+fn test_debug_flag_emits_function_names() {
+    let names = name_section(&compile(&["--debug"])).expect("name section");
+    let contains = |needle: &[u8]| names.windows(needle.len()).any(|w| w == needle);
+    assert!(contains(b"square"));
+    assert!(contains(b"__top_level"));
+}
+
+#[test]
+fn test_release_build_has_no_name_section() {
+    assert!(name_section(&compile(&[])).is_none());
+}
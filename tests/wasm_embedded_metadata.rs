@@ -0,0 +1,61 @@
+#![allow(missing_docs)]
+//! `ruchy wasm --embed-metadata` stores the AST, source hash, compiler
+//! version and lint diagnostics in `ruchy.*` custom sections, and
+//! `WasmMetadata::read` recovers them.
+
+use ruchy::backend::wasm::metadata::{custom_sections, AST_SECTION, COMPILER_SECTION};
+use ruchy::backend::wasm::WasmMetadata;
+use std::fs;
+use tempfile::TempDir;
+
+const SOURCE: &str = "fun add(a, b) {\n    let unused = 0\n    a + b\n}\nadd(1, 2)\n";
+
+fn compile(dir: &TempDir, extra: &[&str]) -> Vec<u8> {
+    let source = dir.path().join("prog.ruchy");
+    let output = dir.path().join("prog.wasm");
+    fs::write(&source, SOURCE).unwrap();
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+        .arg("wasm")
+        .arg(&source)
+        .arg("--output")
+        .arg(&output)
+        .args(extra)
+        .assert()
+        .success();
+    fs::read(&output).unwrap()
+}
+
+#[test]
+fn test_embedded_metadata_round_trips() {
+    let dir = TempDir::new().unwrap();
+    let wasm = compile(&dir, &["--embed-metadata"]);
+
+    let names: Vec<String> = custom_sections(&wasm)
+        .unwrap()
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect();
+    assert!(names.iter().any(|n| n == COMPILER_SECTION), "{names:?}");
+    assert!(names.iter().any(|n| n == AST_SECTION), "{names:?}");
+
+    let metadata = WasmMetadata::read(&wasm).unwrap().expect("metadata");
+    assert_eq!(metadata.compiler_version, env!("CARGO_PKG_VERSION"));
+    assert!(metadata.matches_source(SOURCE));
+    assert!(!metadata.matches_source("add(1, 3)"));
+    assert_eq!(
+        metadata.ast.ast,
+        ruchy::Parser::new(SOURCE).parse().unwrap()
+    );
+    assert!(
+        metadata.diagnostics.iter().any(|d| d.name == "unused"),
+        "{:?}",
+        metadata.diagnostics
+    );
+}
+
+#[test]
+fn test_plain_module_has_no_metadata() {
+    let dir = TempDir::new().unwrap();
+    let wasm = compile(&dir, &[]);
+    assert_eq!(WasmMetadata::read(&wasm).unwrap(), None);
+}
@@ -0,0 +1,55 @@
+#![allow(missing_docs)]
+#![cfg(feature = "notebook")]
+//! `WasmEmitter::with_fuel` meters calls and loop iterations: a runaway loop
+//! traps instead of hanging the host, and the `ruchy_fuel` export reports
+//! what is left.
+
+use ruchy::backend::wasm::emitter::FUEL_EXPORT_NAME;
+use ruchy::backend::wasm::validate::validate_streaming;
+use ruchy::{Parser, WasmEmitter};
+use wasmtime::{Engine, Instance, Module, Store};
+
+fn instantiate(source: &str, fuel: u64) -> (Store<()>, Instance) {
+    let ast = Parser::new(source).parse().expect("source should parse");
+    let wasm = WasmEmitter::new()
+        .with_fuel(Some(fuel))
+        .emit(&ast)
+        .expect("source should compile");
+    validate_streaming(&wasm).expect("metered module should validate");
+    let engine = Engine::default();
+    let module = Module::new(&engine, &wasm).expect("module should load");
+    let mut store = Store::new(&engine, ());
+    let instance = Instance::new(&mut store, &module, &[]).expect("module should instantiate");
+    (store, instance)
+}
+
+fn remaining(store: &mut Store<()>, instance: &Instance) -> i64 {
+    instance
+        .get_global(&mut *store, FUEL_EXPORT_NAME)
+        .expect("fuel global should be exported")
+        .get(&mut *store)
+        .i64()
+        .expect("fuel should be an i64")
+}
+
+#[test]
+fn test_infinite_loop_traps_when_fuel_runs_out() {
+    let (mut store, instance) = instantiate("fun main() { while true { } }", 1_000);
+    let main = instance
+        .get_typed_func::<(), ()>(&mut store, "main")
+        .expect("main should be exported");
+    assert!(main.call(&mut store, ()).is_err());
+    assert_eq!(remaining(&mut store, &instance), 0);
+}
+
+#[test]
+fn test_finite_loop_spends_fuel_per_iteration() {
+    let source = "fun main() {\n    let mut i = 0\n    while i < 10 {\n        i = i + 1\n    }\n}";
+    let (mut store, instance) = instantiate(source, 1_000);
+    let main = instance
+        .get_typed_func::<(), ()>(&mut store, "main")
+        .expect("main should be exported");
+    main.call(&mut store, ()).expect("loop should finish");
+    // One unit for the call, one per loop header (10 iterations + exit check)
+    assert_eq!(remaining(&mut store, &instance), 1_000 - 12);
+}
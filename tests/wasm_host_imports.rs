@@ -0,0 +1,74 @@
+#![allow(missing_docs)]
+#![cfg(feature = "notebook")]
+//! Emitted modules import `println`, clock and random from the `ruchy` host
+//! module; the wasmtime host shim runs them with visible output.
+
+use ruchy::backend::wasm::host::{add_to_linker, HOST_MODULE};
+use ruchy::backend::wasm::validate::validate_streaming;
+use ruchy::{Parser, WasmEmitter};
+use std::sync::{Arc, Mutex};
+use wasmtime::{Engine, Linker, Module, Store};
+
+fn compile(source: &str) -> Vec<u8> {
+    let ast = Parser::new(source).parse().expect("source should parse");
+    let wasm = WasmEmitter::new()
+        .emit(&ast)
+        .expect("source should compile");
+    validate_streaming(&wasm).expect("module should validate");
+    wasm
+}
+
+/// Run `main` and return the printed lines
+fn run(source: &str) -> Vec<String> {
+    let engine = Engine::default();
+    let module = Module::new(&engine, compile(source)).expect("module should load");
+    let lines = Arc::new(Mutex::new(Vec::new()));
+    let mut linker = Linker::new(&engine);
+    let sink = Arc::clone(&lines);
+    add_to_linker(&mut linker, move |line| {
+        sink.lock().unwrap().push(line.to_string());
+    })
+    .unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .expect("host should satisfy every import");
+    let main = instance
+        .get_func(&mut store, "main")
+        .expect("module should export main");
+    let mut results = vec![wasmtime::Val::I32(0); main.ty(&store).results().len()];
+    main.call(&mut store, &[], &mut results)
+        .expect("main should run");
+    let lines = lines.lock().unwrap().clone();
+    lines
+}
+
+#[test]
+fn test_println_prints_text_ints_and_floats() {
+    let lines = run(
+        "fun main() {\n    println(\"Hello, World!\")\n    println(40 + 2)\n    println(1.5)\n    println(\"Hello, World!\")\n}",
+    );
+    assert_eq!(lines, ["Hello, World!", "42", "1.5", "Hello, World!"]);
+}
+
+#[test]
+fn test_println_inside_loops_and_with_allocations() {
+    let source = "fun main() {\n    let xs = [1, 2, 3]\n    let mut i = 0\n    while i < 3 {\n        println(\"tick\")\n        i = i + 1\n    }\n    println(i)\n}";
+    assert_eq!(run(source), ["tick", "tick", "tick", "3"]);
+}
+
+#[test]
+fn test_random_and_clock_come_from_host() {
+    let lines = run(
+        "fun main() {\n    let r = random()\n    println(r < 1.0)\n    println(r >= 0.0)\n    println(timestamp() >= 0)\n}",
+    );
+    assert_eq!(lines, ["1", "1", "1"]);
+
+    let module = Module::new(&Engine::default(), compile("println(random())")).unwrap();
+    let imports: Vec<_> = module
+        .imports()
+        .map(|import| (import.module().to_string(), import.name().to_string()))
+        .collect();
+    assert!(imports.iter().all(|(module, _)| module == HOST_MODULE));
+    assert!(imports.iter().any(|(_, name)| name == "ruchy_random"));
+}
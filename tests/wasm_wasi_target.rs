@@ -0,0 +1,281 @@
+#![allow(missing_docs)]
+#![cfg(feature = "notebook")]
+//! `WasmEmitter::with_wasi` modules import only `wasi_snapshot_preview1` and
+//! run from `_start`. A small in-memory WASI host stands in for a runtime:
+//! it captures stdout and stderr, serves files from a map, and has a clock
+//! that advances a millisecond per reading.
+
+use ruchy::backend::wasm::validate::validate_streaming;
+use ruchy::backend::wasm::wasi::{START_EXPORT_NAME, WASI_MODULE};
+use ruchy::{Parser, WasmEmitter};
+use std::collections::HashMap;
+use wasmtime::{Caller, Engine, Extern, Linker, Memory, Module, Store};
+
+/// Errno values of the fake host
+const EBADF: i32 = 8;
+const ENOENT: i32 = 44;
+
+#[derive(Default)]
+struct FakeWasi {
+    stdout: Vec<u8>,
+    stderr: Vec<u8>,
+    files: HashMap<String, Vec<u8>>,
+    /// Open files: contents and read position
+    open: HashMap<i32, (Vec<u8>, usize)>,
+    clock_ns: u64,
+}
+
+fn memory(caller: &mut Caller<'_, FakeWasi>) -> Memory {
+    caller
+        .get_export("memory")
+        .and_then(Extern::into_memory)
+        .expect("module exports memory")
+}
+
+fn read_u32(memory: &Memory, caller: &Caller<'_, FakeWasi>, address: i32) -> usize {
+    let mut word = [0; 4];
+    memory.read(caller, address as usize, &mut word).unwrap();
+    u32::from_le_bytes(word) as usize
+}
+
+/// The `(address, length)` pairs of an iovec array
+fn iovecs(
+    memory: &Memory,
+    caller: &Caller<'_, FakeWasi>,
+    iovs: i32,
+    count: i32,
+) -> Vec<(usize, usize)> {
+    (0..count)
+        .map(|i| {
+            let iov = iovs + i * 8;
+            (
+                read_u32(memory, caller, iov),
+                read_u32(memory, caller, iov + 4),
+            )
+        })
+        .collect()
+}
+
+fn add_fake_wasi(linker: &mut Linker<FakeWasi>) -> wasmtime::Result<()> {
+    linker.func_wrap(
+        WASI_MODULE,
+        "fd_write",
+        |mut caller: Caller<'_, FakeWasi>, fd: i32, iovs: i32, count: i32, written: i32| {
+            let memory = memory(&mut caller);
+            let mut bytes = Vec::new();
+            for (address, len) in iovecs(&memory, &caller, iovs, count) {
+                bytes.extend_from_slice(&memory.data(&caller)[address..address + len]);
+            }
+            memory
+                .write(
+                    &mut caller,
+                    written as usize,
+                    &(bytes.len() as u32).to_le_bytes(),
+                )
+                .unwrap();
+            match fd {
+                1 => caller.data_mut().stdout.extend(bytes),
+                2 => caller.data_mut().stderr.extend(bytes),
+                _ => return EBADF,
+            }
+            0
+        },
+    )?;
+    linker.func_wrap(
+        WASI_MODULE,
+        "fd_read",
+        |mut caller: Caller<'_, FakeWasi>, fd: i32, iovs: i32, count: i32, read: i32| {
+            let memory = memory(&mut caller);
+            let mut total = 0;
+            for (address, len) in iovecs(&memory, &caller, iovs, count) {
+                let Some((contents, pos)) = caller.data_mut().open.get_mut(&fd) else {
+                    return EBADF;
+                };
+                let chunk = contents[*pos..]
+                    .iter()
+                    .take(len)
+                    .copied()
+                    .collect::<Vec<_>>();
+                *pos += chunk.len();
+                memory.write(&mut caller, address, &chunk).unwrap();
+                total += chunk.len();
+            }
+            memory
+                .write(&mut caller, read as usize, &(total as u32).to_le_bytes())
+                .unwrap();
+            0
+        },
+    )?;
+    linker.func_wrap(
+        WASI_MODULE,
+        "fd_close",
+        |mut caller: Caller<'_, FakeWasi>, fd: i32| {
+            if caller.data_mut().open.remove(&fd).is_some() {
+                0
+            } else {
+                EBADF
+            }
+        },
+    )?;
+    linker.func_wrap(
+        WASI_MODULE,
+        "fd_filestat_get",
+        |mut caller: Caller<'_, FakeWasi>, fd: i32, buf: i32| {
+            let Some((contents, _)) = caller.data().open.get(&fd) else {
+                return EBADF;
+            };
+            let mut filestat = [0; 64];
+            filestat[32..40].copy_from_slice(&(contents.len() as u64).to_le_bytes());
+            let memory = memory(&mut caller);
+            memory.write(&mut caller, buf as usize, &filestat).unwrap();
+            0
+        },
+    )?;
+    linker.func_wrap(
+        WASI_MODULE,
+        "path_open",
+        |mut caller: Caller<'_, FakeWasi>,
+         dir: i32,
+         _lookup: i32,
+         path: i32,
+         path_len: i32,
+         _oflags: i32,
+         _rights: i64,
+         _inheriting: i64,
+         _fdflags: i32,
+         opened: i32| {
+            if dir != 3 {
+                return EBADF;
+            }
+            let memory = memory(&mut caller);
+            let start = path as usize;
+            let name =
+                String::from_utf8_lossy(&memory.data(&caller)[start..start + path_len as usize])
+                    .into_owned();
+            let Some(contents) = caller.data().files.get(&name).cloned() else {
+                return ENOENT;
+            };
+            let fd = 4 + caller.data().open.len() as i32;
+            caller.data_mut().open.insert(fd, (contents, 0));
+            memory
+                .write(&mut caller, opened as usize, &fd.to_le_bytes())
+                .unwrap();
+            0
+        },
+    )?;
+    linker.func_wrap(
+        WASI_MODULE,
+        "clock_time_get",
+        |mut caller: Caller<'_, FakeWasi>, _clock: i32, _precision: i64, time: i32| {
+            caller.data_mut().clock_ns += 1_000_000;
+            let now = caller.data().clock_ns;
+            let memory = memory(&mut caller);
+            memory
+                .write(&mut caller, time as usize, &now.to_le_bytes())
+                .unwrap();
+            0
+        },
+    )?;
+    linker.func_wrap(
+        WASI_MODULE,
+        "random_get",
+        |mut caller: Caller<'_, FakeWasi>, buf: i32, len: i32| {
+            let memory = memory(&mut caller);
+            let bytes = vec![0xFF; len as usize];
+            memory.write(&mut caller, buf as usize, &bytes).unwrap();
+            0
+        },
+    )?;
+    Ok(())
+}
+
+fn compile(source: &str) -> Vec<u8> {
+    let ast = Parser::new(source).parse().expect("source should parse");
+    let wasm = WasmEmitter::new()
+        .with_wasi(true)
+        .emit(&ast)
+        .expect("source should compile");
+    validate_streaming(&wasm).expect("module should validate");
+    wasm
+}
+
+/// Run `_start` with `files` available; stdout and stderr on success
+fn run_with_files(source: &str, files: &[(&str, &[u8])]) -> wasmtime::Result<(String, String)> {
+    let engine = Engine::default();
+    let module = Module::new(&engine, compile(source))?;
+    assert!(
+        module
+            .imports()
+            .all(|import| import.module() == WASI_MODULE),
+        "only WASI imports"
+    );
+    let mut linker = Linker::new(&engine);
+    add_fake_wasi(&mut linker)?;
+    let host = FakeWasi {
+        files: files
+            .iter()
+            .map(|(name, contents)| (name.to_string(), contents.to_vec()))
+            .collect(),
+        clock_ns: 7_000_000_000,
+        ..FakeWasi::default()
+    };
+    let mut store = Store::new(&engine, host);
+    let instance = linker.instantiate(&mut store, &module)?;
+    let start = instance.get_typed_func::<(), ()>(&mut store, START_EXPORT_NAME)?;
+    start.call(&mut store, ())?;
+    let host = store.into_data();
+    Ok((
+        String::from_utf8(host.stdout).unwrap(),
+        String::from_utf8(host.stderr).unwrap(),
+    ))
+}
+
+fn run(source: &str) -> (String, String) {
+    run_with_files(source, &[]).expect("program should run")
+}
+
+#[test]
+fn test_prints_go_to_stdout_and_stderr() {
+    let source = "fun main() {\n    println(42)\n    println(0)\n    println(-7)\n    println(2.5)\n    println(0.0 - 0.125)\n    println(3.0)\n    println(\"hello\")\n    eprintln(\"oops\")\n}";
+    let (stdout, stderr) = run(source);
+    assert_eq!(stdout, "42\n0\n-7\n2.5\n-0.125\n3\nhello\n");
+    assert_eq!(stderr, "oops\n");
+}
+
+#[test]
+fn test_heap_strings_print() {
+    let source = "fun greet(name: String) {\n    \"Hello, \" + name\n}\nfun main() {\n    println(greet(\"WASI\"))\n}";
+    assert_eq!(run(source).0, "Hello, WASI\n");
+}
+
+#[test]
+fn test_top_level_code_runs_from_start() {
+    assert_eq!(run("println(1 + 2)\n10").0, "3\n");
+}
+
+#[test]
+fn test_clock_and_random() {
+    let source = "fun main() {\n    let start = timestamp()\n    let end = timestamp()\n    println(end - start)\n    println(start)\n    let r = random()\n    println(r < 1.0)\n}";
+    assert_eq!(run(source).0, "1\n7001\n1\n");
+}
+
+#[test]
+fn test_read_file_returns_contents() {
+    let source = "fun main() {\n    let text = read_file(\"greeting.txt\")\n    println(text)\n    println(text.len())\n}";
+    let (stdout, _) = run_with_files(source, &[("greeting.txt", b"hello from disk")]).unwrap();
+    assert_eq!(stdout, "hello from disk\n15\n");
+}
+
+#[test]
+fn test_read_file_grows_memory_for_large_files() {
+    let big = vec![b'a'; 200_000];
+    let source = "fun main() {\n    println(read_file(\"big.txt\").len())\n}";
+    let (stdout, _) = run_with_files(source, &[("big.txt", &big)]).unwrap();
+    assert_eq!(stdout, "200000\n");
+}
+
+#[test]
+fn test_read_file_of_missing_file_traps() {
+    let source = "fun main() {\n    println(read_file(\"missing.txt\"))\n}";
+    assert!(run_with_files(source, &[]).is_err());
+}
@@ -0,0 +1,95 @@
+#![allow(missing_docs)]
+//! `--whole-program` propagates constants across functions, folds the
+//! branches they decide and drops functions nothing reaches any more.
+
+use std::fs;
+use tempfile::TempDir;
+
+fn ruchy_cmd() -> assert_cmd::Command {
+    assert_cmd::cargo::cargo_bin_cmd!("ruchy")
+}
+
+const PROGRAM: &str = r"
+fun dump_state(n: i64) {
+    println(n)
+}
+
+fun step(n: i64, verbose: bool) -> i64 {
+    if verbose {
+        dump_state(n)
+    }
+    n * 3
+}
+
+fun report(n: i64) {
+    println(step(n, false))
+    println(step(n * 2, false))
+}
+
+fun main() {
+    report(7)
+    report(8)
+}
+";
+
+fn write_program(dir: &TempDir) -> std::path::PathBuf {
+    let file = dir.path().join("program.ruchy");
+    fs::write(&file, PROGRAM).unwrap();
+    file
+}
+
+fn transpile(file: &std::path::Path, whole_program: bool) -> String {
+    let mut cmd = ruchy_cmd();
+    cmd.arg("transpile").arg(file);
+    if whole_program {
+        cmd.arg("--whole-program");
+    }
+    let output = cmd.output().unwrap();
+    assert!(output.status.success(), "{output:?}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_transpile_drops_unreachable_functions() {
+    let dir = TempDir::new().unwrap();
+    let file = write_program(&dir);
+    let plain = transpile(&file, false);
+    let optimized = transpile(&file, true);
+    assert!(plain.contains("fn dump_state"));
+    assert!(!optimized.contains("fn dump_state"), "{optimized}");
+    assert!(!optimized.contains("verbose"), "{optimized}");
+    assert!(optimized.len() < plain.len());
+}
+
+#[test]
+fn test_wasm_output_shrinks() {
+    let dir = TempDir::new().unwrap();
+    let file = write_program(&dir);
+    let compile = |name: &str, whole_program: bool| {
+        let output = dir.path().join(name);
+        let mut cmd = ruchy_cmd();
+        cmd.arg("wasm").arg(&file).arg("-o").arg(&output);
+        if whole_program {
+            cmd.arg("--whole-program");
+        }
+        cmd.assert().success();
+        fs::read(output).unwrap().len()
+    };
+    assert!(compile("optimized.wasm", true) < compile("plain.wasm", false));
+}
+
+#[test]
+fn test_verbose_reports_changes() {
+    let dir = TempDir::new().unwrap();
+    let file = write_program(&dir);
+    ruchy_cmd()
+        .arg("--verbose")
+        .arg("transpile")
+        .arg("--whole-program")
+        .arg(&file)
+        .assert()
+        .success()
+        .stderr(predicates::str::contains(
+            "1 constant parameter(s), 0 folded call(s), 1 unreachable function(s) removed",
+        ));
+}